@@ -14,37 +14,132 @@ const CONNECT_RETRIES: usize = 20;
 const RETRY_DELAY_MS: u64 = 50;
 static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 
+/// Opens files in the running instance, starting it first if needed.
+///
+/// `args` is the full command line: `dbflux [--profile <name>] [file...]`.
 pub fn run(args: &[String]) -> i32 {
-    let path = args.get(1).map(PathBuf::from);
+    match parse_open_args(&args[1..]) {
+        Ok(messages) => deliver(&messages),
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("Usage: dbflux [--profile <name>] [file...]");
+            1
+        }
+    }
+}
 
-    match try_send(path.as_ref()) {
-        Ok(_) => 0,
-        Err(_) => {
+/// `dbflux connect <profile>`: connects a profile in the running instance,
+/// starting it first if needed. `args` are the arguments after `connect`.
+pub fn run_connect(args: &[String]) -> i32 {
+    match parse_connect_args(args) {
+        Some(message) => deliver(&[message]),
+        None => {
+            eprintln!("Usage: dbflux connect <profile>");
+            eprintln!();
+            eprintln!("Connect a profile (by name or id) in the running DBFlux and focus it");
+            eprintln!("in the sidebar, starting DBFlux first if needed.");
+            1
+        }
+    }
+}
+
+/// The `ConnectProfile` request for `dbflux connect <profile>`, or `None`
+/// unless exactly one profile that does not look like a flag is given.
+fn parse_connect_args(args: &[String]) -> Option<IpcMessage> {
+    match args {
+        [profile] if !profile.starts_with('-') => Some(IpcMessage::ConnectProfile {
+            profile: profile.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Sends `messages` to the running instance, spawning one when none answers.
+fn deliver(messages: &[IpcMessage]) -> i32 {
+    let result = match try_send(messages) {
+        Err(SendError::Unreachable(_)) => {
             if let Err(e) = spawn_gui() {
                 eprintln!("Failed to spawn GUI: {}", e);
                 return 1;
             }
+            retry_send(messages)
+        }
+        result => result,
+    };
+
+    match result {
+        Ok(()) => 0,
+        Err(SendError::Unreachable(e)) => {
+            eprintln!("Failed to connect after spawn: {}", e);
+            1
+        }
+        Err(SendError::Rejected(message)) => {
+            eprintln!("DBFlux: {}", message);
+            1
+        }
+    }
+}
 
-            match retry_send(path.as_ref()) {
-                Ok(_) => 0,
-                Err(e) => {
-                    eprintln!("Failed to connect after spawn: {}", e);
-                    1
+/// Why a request did not go through.
+enum SendError {
+    /// No instance answered on the socket.
+    Unreachable(io::Error),
+    /// The running instance answered with an error.
+    Rejected(String),
+}
+
+/// One `OpenFile` request per file, or a `Focus` request when no file is
+/// given. Paths are made absolute here, since the running instance may have
+/// a different working directory.
+fn parse_open_args(args: &[String]) -> Result<Vec<IpcMessage>, String> {
+    let mut profile = None;
+    let mut paths = Vec::new();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--profile" => {
+                profile = Some(iter.next().cloned().ok_or("--profile needs a value")?);
+            }
+            _ => {
+                let path = PathBuf::from(arg);
+                if !path.is_file() {
+                    return Err(format!("No such file: {}", path.display()));
                 }
+                paths.push(std::path::absolute(&path).unwrap_or(path));
             }
         }
     }
+
+    if paths.is_empty() {
+        return match profile {
+            Some(_) => Err("--profile needs a file to open".to_string()),
+            None => Ok(vec![IpcMessage::Focus]),
+        };
+    }
+
+    Ok(paths
+        .into_iter()
+        .map(|path| IpcMessage::OpenFile {
+            path,
+            profile: profile.clone(),
+        })
+        .collect())
+}
+
+fn try_send(messages: &[IpcMessage]) -> Result<(), SendError> {
+    messages.iter().try_for_each(|message| {
+        match send_message(message.clone()).map_err(SendError::Unreachable)? {
+            IpcResponse::Error { message } => Err(SendError::Rejected(message)),
+            _ => Ok(()),
+        }
+    })
 }
 
-fn try_send(path: Option<&PathBuf>) -> io::Result<()> {
+fn send_message(msg: IpcMessage) -> io::Result<IpcResponse> {
     let name = socket_name()?;
     let mut stream = IpcStream::connect(name)?;
 
-    let msg = match path {
-        Some(p) => IpcMessage::OpenScript { path: p.clone() },
-        None => IpcMessage::Focus,
-    };
-
     let auth_token = read_app_control_token()?;
     let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
     let request = AppControlRequest::new(request_id, Some(auth_token), msg);
@@ -65,20 +160,20 @@ fn try_send(path: Option<&PathBuf>) -> io::Result<()> {
         return Err(io::Error::other("mismatched app-control response id"));
     }
 
-    match response.body {
-        IpcResponse::Error { message } => Err(io::Error::other(message)),
-        _ => Ok(()),
-    }
+    Ok(response.body)
 }
 
-fn retry_send(path: Option<&PathBuf>) -> io::Result<()> {
+fn retry_send(messages: &[IpcMessage]) -> Result<(), SendError> {
     for _ in 0..CONNECT_RETRIES {
         std::thread::sleep(Duration::from_millis(RETRY_DELAY_MS));
-        if try_send(path).is_ok() {
-            return Ok(());
+        match try_send(messages) {
+            Err(SendError::Unreachable(_)) => continue,
+            result => return result,
         }
     }
-    Err(io::Error::other("connection timeout"))
+    Err(SendError::Unreachable(io::Error::other(
+        "connection timeout",
+    )))
 }
 
 fn spawn_gui() -> io::Result<()> {
@@ -106,3 +201,51 @@ fn spawn_gui() -> io::Result<()> {
     cmd.spawn()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_file_focuses_the_running_instance() {
+        let messages = parse_open_args(&[]).unwrap();
+        assert!(matches!(messages.as_slice(), [IpcMessage::Focus]));
+
+        assert!(parse_open_args(&["--profile".to_string(), "prod".to_string()]).is_err());
+    }
+
+    #[test]
+    fn files_become_absolute_open_requests_with_the_profile() {
+        let file = std::env::temp_dir().join(format!("dbflux-cli-{}.sql", std::process::id()));
+        std::fs::write(&file, "SELECT 1;").unwrap();
+
+        let messages = parse_open_args(&[
+            "--profile".to_string(),
+            "prod".to_string(),
+            file.display().to_string(),
+        ]);
+        let _ = std::fs::remove_file(&file);
+
+        match messages.unwrap().as_slice() {
+            [IpcMessage::OpenFile { path, profile }] => {
+                assert!(path.is_absolute());
+                assert_eq!(profile.as_deref(), Some("prod"));
+            }
+            other => panic!("unexpected messages: {other:?}"),
+        }
+
+        assert!(parse_open_args(&["/nonexistent/dbflux.sql".to_string()]).is_err());
+    }
+
+    #[test]
+    fn connect_takes_exactly_one_profile() {
+        match parse_connect_args(&["prod".to_string()]) {
+            Some(IpcMessage::ConnectProfile { profile }) => assert_eq!(profile, "prod"),
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        assert!(parse_connect_args(&[]).is_none());
+        assert!(parse_connect_args(&["prod".to_string(), "dev".to_string()]).is_none());
+        assert!(parse_connect_args(&["--help".to_string()]).is_none());
+    }
+}
@@ -3,6 +3,7 @@
 
 mod cli;
 
+use dbflux_app::export_command::run_export_command;
 use dbflux_app::mcp_command::run_mcp_command;
 use dbflux_audit::AuditService;
 use dbflux_core::ShutdownPhase;
@@ -23,7 +24,7 @@ use dbflux_ui::ipc_server::IpcServer;
 use dbflux_ui::keymap::{input_context_keybindings, workspace_keybindings};
 use dbflux_ui::platform;
 use dbflux_ui::ui::overlays::command_palette::command_palette_keybindings;
-use dbflux_ui::ui::views::workspace::Workspace;
+use dbflux_ui::ui::views::workspace::{QuitConfirmed, Workspace};
 use gpui::*;
 use gpui_component::Root;
 use interprocess::local_socket::{
@@ -157,6 +158,15 @@ fn main() {
         std::process::exit(exit_code);
     }
 
+    if args.get(1).map(|s| s.as_str()) == Some("export") {
+        let exit_code = run_export_command(&args[2..]);
+        std::process::exit(exit_code);
+    }
+
+    if args.get(1).map(|s| s.as_str()) == Some("connect") {
+        std::process::exit(cli::run_connect(&args[2..]));
+    }
+
     if args.get(1).map(|s| s.as_str()) == Some("--gui") {
         run_gui();
         return;
@@ -310,12 +320,25 @@ fn run_gui() {
         emit_system_startup(&audit_service);
 
         let general_settings = app_state.read(cx).general_settings().clone();
-        let theme_setting = general_settings.theme;
-        let style_setting = general_settings.style;
 
-        // Set up the density global and apply the persisted theme+style so
-        // radius tokens are correct from the very first frame.
-        dbflux_ui::theme::init_with_settings(theme_setting, style_setting, cx);
+        dbflux_core::i18n::set_language(general_settings.language);
+        dbflux_core::timezone::set_display_timezone(general_settings.display_timezone);
+        dbflux_core::null_display::set_null_marker(&general_settings.null_marker);
+        dbflux_core::null_display::set_null_style(general_settings.null_style);
+        dbflux_core::display_format::set_display_format(general_settings.display_format.clone());
+
+        // Set up the density and zoom globals and apply the persisted
+        // theme+style so radius tokens and sizes are correct from the very
+        // first frame.
+        dbflux_ui::theme::init_with_settings(&general_settings, cx);
+
+        // User themes may replace the built-in one; the themes folder and the
+        // OS appearance are watched from here on.
+        dbflux_ui::user_themes::init(app_state.clone(), cx);
+
+        // External driver services added or edited while running are picked
+        // up without a restart.
+        dbflux_ui::rpc_driver_watch::init(app_state.clone(), cx);
 
         let channel = dbflux_core::ReleaseChannel::current();
         let mut main_window_options = WindowOptions {
@@ -331,6 +354,7 @@ fn run_gui() {
         };
         platform::apply_window_options(&mut main_window_options, 800.0, 600.0);
 
+        let mut main_workspace = None;
         let window_handle = cx
             .open_window(main_window_options, |window, cx| {
                 cx.bind_keys(command_palette_keybindings());
@@ -339,17 +363,34 @@ fn run_gui() {
 
                 let workspace = cx.new(|cx| Workspace::new(app_state.clone(), window, cx));
 
-                IpcServer::start_with_listener(listener, workspace.clone(), auth_token, cx);
+                IpcServer::start_with_listener(
+                    listener,
+                    workspace.clone(),
+                    window.window_handle(),
+                    auth_token,
+                    cx,
+                );
                 info!("IPC server started");
 
+                main_workspace = Some(workspace.clone());
                 cx.new(|cx| Root::new(workspace, window, cx))
             })
             .expect("Failed to open main window");
 
+        let main_workspace = main_workspace.expect("main window builds a workspace");
+
+        // Unsaved changes are resolved in the workspace; shutdown starts once
+        // they have been saved or discarded.
+        let app_state_for_quit = app_state.clone();
+        cx.subscribe(&main_workspace, move |_, _: &QuitConfirmed, cx| {
+            start_graceful_shutdown(&app_state_for_quit, cx);
+        })
+        .detach();
+
         let app_state_for_close = app_state.clone();
         window_handle
             .update(cx, |_root, window, cx| {
-                window.on_window_should_close(cx, move |_window, cx| {
+                window.on_window_should_close(cx, move |window, cx| {
                     let already_shutting_down = app_state_for_close.read(cx).is_shutting_down();
                     if already_shutting_down {
                         let phase = app_state_for_close.read(cx).shutdown_phase();
@@ -359,21 +400,14 @@ fn run_gui() {
                         return false;
                     }
 
-                    info!("Starting graceful shutdown...");
-                    let initiated_shutdown =
-                        app_state_for_close.update(cx, |state, _| state.begin_shutdown());
-
-                    if initiated_shutdown {
-                        let audit_service = app_state_for_close.read(cx).audit_service().clone();
-                        emit_system_shutdown(&audit_service);
-
-                        let app_state_shutdown = app_state_for_close.clone();
-                        cx.spawn(async move |cx| {
-                            run_shutdown_sequence(app_state_shutdown, cx).await;
-                        })
-                        .detach();
+                    let can_quit =
+                        main_workspace.update(cx, |workspace, cx| workspace.confirm_quit(cx));
+                    if !can_quit {
+                        window.activate_window();
+                        return false;
                     }
 
+                    start_graceful_shutdown(&app_state_for_close, cx);
                     false
                 });
             })
@@ -383,6 +417,24 @@ fn run_gui() {
     });
 }
 
+/// Starts the shutdown sequence in the background; the window closes once it
+/// reaches `Complete` or `Failed`.
+fn start_graceful_shutdown(app_state: &Entity<AppStateEntity>, cx: &mut App) {
+    info!("Starting graceful shutdown...");
+    let initiated_shutdown = app_state.update(cx, |state, _| state.begin_shutdown());
+
+    if initiated_shutdown {
+        let audit_service = app_state.read(cx).audit_service().clone();
+        emit_system_shutdown(&audit_service);
+
+        let app_state_shutdown = app_state.clone();
+        cx.spawn(async move |cx| {
+            run_shutdown_sequence(app_state_shutdown, cx).await;
+        })
+        .detach();
+    }
+}
+
 async fn run_shutdown_sequence(app_state: Entity<AppStateEntity>, cx: &mut AsyncApp) {
     let start = Instant::now();
 
@@ -11,15 +11,19 @@ use dbflux_core::observability::{
 };
 use dbflux_core::secrecy::SecretString;
 use dbflux_core::{
-    AuthProfile, CancelToken, Connection, ConnectionHook, ConnectionHooks, ConnectionProfile,
-    DbDriver, DbSchemaInfo, DriverKey, EffectiveSettings, FetchCollectionChildrenParams,
-    FormValues, GeneralSettings, GlobalOverrides, HistoryEntry, HookContext, HookPhase,
-    ProfileManager, ProxyProfile, SavedQuery, SchemaForeignKeyInfo, SchemaIndexInfo,
+    AuthProfile, CancelToken, CapturedPlan, ColumnLayout, Connection, ConnectionHook,
+    ConnectionHooks, ConnectionProfile, DRIVER_MANIFESTS_DIR, DbDriver, DbSchemaInfo,
+    DriverCapabilities, DriverKey, EffectiveSettings, FetchCollectionChildrenParams, FormValues,
+    GeneralSettings, GlobalOverrides, HistoryEntry, HistoryFilter, HookContext, HookPhase,
+    ProfileManager, ProxyProfile, QueryVariableScope, SavedQuery, SavedQueryFolder,
+    SavedQueryParam, ScheduledExport, ScheduledExportRun, SchemaForeignKeyInfo, SchemaIndexInfo,
     SchemaSnapshot, ScriptsDirectory, SecretStore, ServiceConfig, SessionFacade, ShutdownPhase,
     SshTunnelProfile, TaskId, TaskKind, TaskSnapshot,
 };
 use dbflux_storage::SavedQueryRepo;
 use dbflux_storage::bootstrap::StorageRuntime;
+use dbflux_storage::repositories::query_variables::QueryVariableDto;
+use dbflux_storage::repositories::scheduled_exports::{ScheduledExportDto, ScheduledExportRunDto};
 use dbflux_storage::repositories::viz_dashboard_panels::DashboardPanelsRepository;
 use dbflux_storage::repositories::viz_dashboards::DashboardsRepository;
 use dbflux_storage::repositories::viz_saved_chart_binding_y::SavedChartBindingYRepository;
@@ -63,22 +67,25 @@ use dbflux_driver_influxdb::InfluxDriver;
 #[cfg(feature = "mssql")]
 use dbflux_driver_mssql::MssqlDriver;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::RwLock;
 use uuid::Uuid;
 
 use crate::auth_provider_registry::{AuthProviderRegistry, RegistryAuthProviderWrapper};
+use crate::driver_manifests::DriverManifestCatalog;
 use crate::rpc_services::external_audit::{ExternalAuditSink, NoOpContextProvider};
 use crate::rpc_services::{
-    AuthProviderServiceAdaptation, DriverServiceAdaptation, ExternalDriverDiagnostic,
-    RpcServiceDiscovery, adapt_auth_provider_service, adapt_driver_service, discover_services,
+    AuthProviderServiceAdaptation, DriverService, ExternalDriverDiagnostic, ProbedRpcDrivers,
+    RpcDriverReload, RpcServiceDiscovery, adapt_auth_provider_service, diff_driver_services,
+    discover_services, driver_services, launch_driver_services, rpc_registry_id,
 };
 
 #[cfg(test)]
 use crate::rpc_services::{
-    DriverProbe, adapt_auth_provider_service_with, adapt_driver_service_with,
+    DriverProbe, DriverServiceAdaptation, adapt_auth_provider_service_with,
+    adapt_driver_service_with,
 };
 
 #[cfg(test)]
@@ -90,6 +97,11 @@ pub use dbflux_core::{
     FetchSchemaTypesParams, FetchTableDetailsParams, SwitchDatabaseParams,
 };
 
+/// `ui_state` key of the commands last run from the command palette.
+const RECENT_PALETTE_COMMANDS_KEY: &str = "palette:recent_commands";
+
+const MAX_RECENT_PALETTE_COMMANDS: usize = 5;
+
 struct BuiltDrivers {
     drivers: HashMap<String, Arc<dyn DbDriver>>,
     external_driver_diagnostics: HashMap<String, ExternalDriverDiagnostic>,
@@ -103,6 +115,10 @@ struct BuiltDrivers {
 pub struct AppState {
     pub facade: SessionFacade,
     external_driver_diagnostics: HashMap<String, ExternalDriverDiagnostic>,
+    /// Driver services as last applied, keyed by socket id. Compared against
+    /// storage by [`Self::pending_rpc_driver_reload`].
+    rpc_driver_services: HashMap<String, DriverService>,
+    rpc_audit_emitter: Arc<dyn dbflux_ipc::ExternalAuditEmitter>,
     general_settings: GeneralSettings,
     driver_overrides: HashMap<DriverKey, GlobalOverrides>,
     driver_settings: HashMap<DriverKey, FormValues>,
@@ -148,6 +164,11 @@ pub struct AppState {
     pub dashboard_panels_repo: Arc<DashboardPanelsRepository>,
     /// Repository for `qry_saved_queries` and its child tables.
     pub saved_query_repo: Arc<SavedQueryRepo>,
+    /// `{{name}}` query variable values, keyed by scope then name. Mirrors
+    /// `cfg_query_variables`; every mutation writes through to SQLite.
+    query_variables: HashMap<QueryVariableScope, BTreeMap<String, String>>,
+    /// Saved queries exported on a schedule. Mirrors `cfg_scheduled_exports`.
+    scheduled_exports: Vec<ScheduledExport>,
 }
 
 impl AppState {
@@ -238,11 +259,22 @@ impl AppState {
                 crate::rpc_services::external_audit::ExternalAuditConfig::default(),
             ));
 
-        if !services.is_empty() {
-            Self::launch_rpc_services(
+        let manifests = DriverManifestCatalog::load(&Self::manifests_dir(&storage_runtime));
+        for error in &manifests.errors {
+            log::warn!("Driver manifest: {error}");
+        }
+
+        let startup_driver_services = driver_services(&services, &manifests);
+        let rpc_driver_services = startup_driver_services
+            .iter()
+            .map(|service| (service.config.socket_id.clone(), service.clone()))
+            .collect();
+
+        if !startup_driver_services.is_empty() {
+            launch_driver_services(
                 &mut drivers,
                 &mut external_driver_diagnostics,
-                services.clone(),
+                startup_driver_services,
                 Some(audit_emitter.clone()),
             );
         }
@@ -260,7 +292,7 @@ impl AppState {
             Self::launch_rpc_auth_providers(
                 &mut auth_provider_registry,
                 services,
-                Some(audit_emitter),
+                Some(audit_emitter.clone()),
             );
         }
 
@@ -287,7 +319,11 @@ impl AppState {
 
         let mut history_manager =
             crate::history_manager_sqlite::HistoryManager::new(&storage_runtime);
-        history_manager.set_max_entries(general_settings.max_history_entries);
+        history_manager.set_retention(
+            general_settings.max_history_entries,
+            general_settings.history_max_age_days,
+            general_settings.history_collapse_duplicates,
+        );
 
         #[cfg(feature = "mcp")]
         let mcp_runtime = {
@@ -317,10 +353,14 @@ impl AppState {
         let dashboards_repo = Arc::new(DashboardsRepository::new(Arc::clone(&viz_conn)));
         let dashboard_panels_repo = Arc::new(DashboardPanelsRepository::new(Arc::clone(&viz_conn)));
         let saved_query_repo = Arc::new(SavedQueryRepo::new(Arc::clone(&viz_conn)));
+        let query_variables = Self::load_query_variables(&storage_runtime);
+        let scheduled_exports = Self::load_scheduled_exports(&storage_runtime);
 
         let mut state = Self {
             facade,
             external_driver_diagnostics,
+            rpc_driver_services,
+            rpc_audit_emitter: audit_emitter,
             general_settings,
             driver_overrides,
             driver_settings,
@@ -345,6 +385,8 @@ impl AppState {
             dashboards_repo,
             dashboard_panels_repo,
             saved_query_repo,
+            query_variables,
+            scheduled_exports,
         };
 
         #[cfg(feature = "mcp")]
@@ -863,65 +905,6 @@ impl AppState {
         )
     }
 
-    fn launch_rpc_services(
-        drivers: &mut HashMap<String, Arc<dyn DbDriver>>,
-        diagnostics: &mut HashMap<String, ExternalDriverDiagnostic>,
-        services: Vec<ServiceConfig>,
-        audit_emitter: Option<Arc<dyn dbflux_ipc::ExternalAuditEmitter>>,
-    ) {
-        for discovery in discover_services(services) {
-            let descriptor = match discovery {
-                RpcServiceDiscovery::Descriptor(descriptor) => descriptor,
-                RpcServiceDiscovery::InvalidConfig { diagnostic } => {
-                    log::warn!(
-                        "Skipping RPC service '{}': invalid launch configuration: {}",
-                        diagnostic.socket_id,
-                        diagnostic.summary
-                    );
-                    diagnostics.insert(diagnostic.socket_id.clone(), diagnostic);
-                    continue;
-                }
-            };
-
-            match adapt_driver_service(
-                descriptor,
-                |driver_id| drivers.contains_key(driver_id),
-                audit_emitter.clone(),
-            ) {
-                DriverServiceAdaptation::Registered { driver_id, service } => {
-                    if let Some(socket_id) = driver_id.strip_prefix("rpc:") {
-                        diagnostics.remove(socket_id);
-                    }
-                    drivers.insert(driver_id, service);
-                }
-                DriverServiceAdaptation::SkippedDisabled { socket_id } => {
-                    log::info!("Skipping disabled service '{}'", socket_id);
-                }
-                DriverServiceAdaptation::SkippedNonDriver { socket_id, kind } => {
-                    log::info!(
-                        "Deferring non-driver RPC service '{}' of kind {:?}",
-                        socket_id,
-                        kind
-                    );
-                }
-                DriverServiceAdaptation::SkippedDuplicate { socket_id } => {
-                    log::warn!(
-                        "Skipping external RPC service '{}': driver id already exists",
-                        socket_id
-                    );
-                }
-                DriverServiceAdaptation::ProbeFailed { diagnostic } => {
-                    log::warn!(
-                        "Skipping RPC service '{}': {}",
-                        diagnostic.socket_id,
-                        diagnostic.summary
-                    );
-                    diagnostics.insert(diagnostic.socket_id.clone(), diagnostic);
-                }
-            }
-        }
-    }
-
     fn launch_rpc_auth_providers(
         registry: &mut AuthProviderRegistry,
         services: Vec<ServiceConfig>,
@@ -1261,6 +1244,18 @@ impl AppState {
             .set_dependents(profile_id, database, table, deps);
     }
 
+    pub fn set_table_statistics(
+        &mut self,
+        profile_id: Uuid,
+        database: String,
+        table: String,
+        statistics: dbflux_core::TableStatistics,
+    ) {
+        self.facade
+            .connections
+            .set_table_statistics(profile_id, database, table, statistics);
+    }
+
     #[allow(dead_code)]
     pub fn needs_table_details(&self, profile_id: Uuid, database: &str, table: &str) -> bool {
         self.facade
@@ -1841,6 +1836,8 @@ impl AppState {
             log::error!("Failed to delete profile from storage: {}", e);
         }
 
+        self.clear_query_variable_scope(QueryVariableScope::Connection(removed.id));
+
         Some(removed)
     }
 
@@ -2317,21 +2314,75 @@ impl AppState {
         self.facade.tree.set_folder_collapsed(folder_id, collapsed);
     }
 
+    /// Stars or unstars an object for a profile; returns whether it is now
+    /// a favorite.
+    pub fn toggle_favorite(
+        &mut self,
+        profile_id: Uuid,
+        target: dbflux_core::FavoriteTarget,
+    ) -> bool {
+        self.facade.tree.toggle_favorite(profile_id, target)
+    }
+
+    pub fn remove_favorite(&mut self, favorite_id: Uuid) -> bool {
+        self.facade.tree.remove_favorite(favorite_id)
+    }
+
+    pub fn move_favorite(&mut self, favorite_id: Uuid, target_id: Uuid, after: bool) -> bool {
+        self.facade
+            .tree
+            .move_favorite(favorite_id, target_id, after)
+    }
+
     // --- HistoryManager (SQLite-backed via history_manager_sqlite) ---
 
-    pub fn history_entries(&self) -> &[HistoryEntry] {
-        self.history_manager.entries()
+    /// One page of query history matching `filter`, newest first, with
+    /// `connection_name` resolved from the current profiles.
+    pub fn history_page(
+        &self,
+        filter: &HistoryFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<HistoryEntry> {
+        let mut entries = self.history_manager.history_page(filter, offset, limit);
+
+        for entry in &mut entries {
+            entry.connection_name = entry.connection_profile_id.and_then(|profile_id| {
+                self.profiles()
+                    .iter()
+                    .find(|profile| profile.id == profile_id)
+                    .map(|profile| profile.name.clone())
+            });
+        }
+
+        entries
+    }
+
+    pub fn history_count(&self, filter: &HistoryFilter) -> usize {
+        self.history_manager.history_count(filter)
     }
 
     pub fn add_history_entry(&mut self, entry: HistoryEntry) {
         self.history_manager.add(entry);
     }
 
+    pub fn add_captured_plan(&self, plan: &CapturedPlan) {
+        self.history_manager.add_captured_plan(plan);
+    }
+
+    pub fn captured_plans(&self, profile_id: Option<Uuid>, statement: &str) -> Vec<CapturedPlan> {
+        self.history_manager.captured_plans(profile_id, statement)
+    }
+
     #[allow(dead_code)]
     pub fn toggle_history_favorite(&mut self, id: Uuid) -> bool {
         self.history_manager.toggle_favorite(id)
     }
 
+    pub fn toggle_history_pinned(&mut self, id: Uuid) -> bool {
+        self.history_manager.toggle_pinned(id)
+    }
+
     #[allow(dead_code)]
     pub fn remove_history_entry(&mut self, id: Uuid) {
         self.history_manager.remove(id);
@@ -2353,11 +2404,45 @@ impl AppState {
     }
 
     pub fn remove_saved_query(&mut self, id: Uuid) -> bool {
-        self.history_manager.remove_saved_query(id)
+        let removed = self.history_manager.remove_saved_query(id);
+
+        let target = dbflux_core::FavoriteTarget::SavedQuery { query_id: id };
+        let favorite_id = self
+            .connection_tree()
+            .favorites
+            .iter()
+            .find(|f| f.target == target)
+            .map(|f| f.id);
+        if let Some(favorite_id) = favorite_id {
+            self.facade.tree.remove_favorite(favorite_id);
+        }
+
+        let orphaned: Vec<Uuid> = self
+            .scheduled_exports
+            .iter()
+            .filter(|export| export.saved_query_id == id)
+            .map(|export| export.id)
+            .collect();
+        for export_id in orphaned {
+            self.remove_scheduled_export(export_id);
+        }
+
+        removed
     }
 
+    /// Toggles a saved query's star. Starred queries of a connection are
+    /// also listed in that connection's sidebar favorites.
     pub fn toggle_saved_query_favorite(&mut self, id: Uuid) -> bool {
-        self.history_manager.toggle_saved_query_favorite(id)
+        let starred = self.history_manager.toggle_saved_query_favorite(id);
+
+        if let Some(profile_id) = self.get_saved_query(id).and_then(|q| q.connection_id) {
+            let target = dbflux_core::FavoriteTarget::SavedQuery { query_id: id };
+            if starred != self.connection_tree().is_favorite(profile_id, &target) {
+                self.facade.tree.toggle_favorite(profile_id, target);
+            }
+        }
+
+        starred
     }
 
     pub fn update_saved_query_last_used(&mut self, id: Uuid) -> bool {
@@ -2374,7 +2459,6 @@ impl AppState {
         self.history_manager.update_saved_query_name(id, name)
     }
 
-    #[allow(dead_code)]
     pub fn get_saved_query(&self, id: Uuid) -> Option<&SavedQuery> {
         self.history_manager.get_saved_query(id)
     }
@@ -2383,6 +2467,37 @@ impl AppState {
         self.history_manager.saved_queries_list()
     }
 
+    pub fn update_saved_query_organization(
+        &mut self,
+        id: Uuid,
+        folder_id: Option<Uuid>,
+        tags: Vec<String>,
+    ) -> bool {
+        self.history_manager
+            .update_saved_query_organization(id, folder_id, tags)
+    }
+
+    pub fn update_saved_query_params(&mut self, id: Uuid, params: Vec<SavedQueryParam>) -> bool {
+        self.history_manager.update_saved_query_params(id, params)
+    }
+
+    pub fn record_saved_query_param_values(
+        &mut self,
+        id: Uuid,
+        values: &HashMap<String, String>,
+    ) -> bool {
+        self.history_manager
+            .record_saved_query_param_values(id, values)
+    }
+
+    pub fn saved_query_folders(&self) -> &[SavedQueryFolder] {
+        self.history_manager.saved_query_folders_list()
+    }
+
+    pub fn ensure_saved_query_folder_path(&mut self, path: &str) -> Option<Uuid> {
+        self.history_manager.ensure_saved_query_folder_path(path)
+    }
+
     // --- RecentFiles (SQLite-backed) ---
 
     #[allow(dead_code)]
@@ -2399,6 +2514,326 @@ impl AppState {
         self.history_manager.remove_recent_file(path);
     }
 
+    // --- Query variables (SQLite-backed) ---
+
+    fn load_query_variables(
+        storage_runtime: &StorageRuntime,
+    ) -> HashMap<QueryVariableScope, BTreeMap<String, String>> {
+        let mut variables: HashMap<QueryVariableScope, BTreeMap<String, String>> = HashMap::new();
+
+        let rows = match storage_runtime.query_variables().all() {
+            Ok(rows) => rows,
+            Err(e) => {
+                log::warn!("Failed to load query variables: {}", e);
+                return variables;
+            }
+        };
+
+        for row in rows {
+            let Some(scope) = QueryVariableScope::from_storage_key(&row.scope) else {
+                log::warn!("Skipping query variable with unknown scope '{}'", row.scope);
+                continue;
+            };
+
+            variables
+                .entry(scope)
+                .or_default()
+                .insert(row.name, row.value);
+        }
+
+        variables
+    }
+
+    /// Variables stored directly in `scope`, sorted by name.
+    pub fn query_variables_in_scope(&self, scope: QueryVariableScope) -> Vec<(String, String)> {
+        self.query_variables
+            .get(&scope)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Effective variable values for a query running on `connection_id`:
+    /// workspace values overlaid with that connection's values.
+    pub fn resolved_query_variables(&self, connection_id: Option<Uuid>) -> HashMap<String, String> {
+        let mut resolved: HashMap<String, String> = self
+            .query_variables
+            .get(&QueryVariableScope::Workspace)
+            .map(|values| values.clone().into_iter().collect())
+            .unwrap_or_default();
+
+        if let Some(values) = connection_id.and_then(|id| {
+            self.query_variables
+                .get(&QueryVariableScope::Connection(id))
+        }) {
+            resolved.extend(values.clone());
+        }
+
+        resolved
+    }
+
+    pub fn set_query_variable(&mut self, scope: QueryVariableScope, name: &str, value: &str) {
+        let dto = QueryVariableDto {
+            scope: scope.storage_key(),
+            name: name.to_string(),
+            value: value.to_string(),
+        };
+
+        if let Err(e) = self.storage_runtime.query_variables().upsert(&dto) {
+            log::error!("Failed to save query variable '{}': {}", name, e);
+        }
+
+        self.query_variables
+            .entry(scope)
+            .or_default()
+            .insert(dto.name, dto.value);
+    }
+
+    pub fn remove_query_variable(&mut self, scope: QueryVariableScope, name: &str) {
+        if let Err(e) = self
+            .storage_runtime
+            .query_variables()
+            .delete(&scope.storage_key(), name)
+        {
+            log::error!("Failed to delete query variable '{}': {}", name, e);
+        }
+
+        if let Some(values) = self.query_variables.get_mut(&scope) {
+            values.remove(name);
+        }
+    }
+
+    fn clear_query_variable_scope(&mut self, scope: QueryVariableScope) {
+        if self.query_variables.remove(&scope).is_none() {
+            return;
+        }
+
+        if let Err(e) = self
+            .storage_runtime
+            .query_variables()
+            .delete_scope(&scope.storage_key())
+        {
+            log::error!("Failed to delete query variables: {}", e);
+        }
+    }
+
+    // --- Table column layouts (SQLite-backed) ---
+
+    /// Column layout saved for the table behind `key` (see
+    /// [`ColumnLayout::storage_key`]).
+    pub fn table_column_layout(&self, key: &str) -> Option<ColumnLayout> {
+        let json = match self.storage_runtime.ui_state().get(key) {
+            Ok(json) => json?,
+            Err(e) => {
+                log::warn!("Failed to load column layout '{}': {}", key, e);
+                return None;
+            }
+        };
+
+        match ColumnLayout::from_json(&json) {
+            Ok(layout) => Some(layout),
+            Err(e) => {
+                log::warn!("Ignoring column layout '{}': {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// Saves the column layout for `key`; `None` forgets it so the table
+    /// opens with its natural column order again.
+    pub fn set_table_column_layout(&mut self, key: &str, layout: Option<&ColumnLayout>) {
+        let outcome = match layout {
+            Some(layout) => match layout.to_json() {
+                Ok(json) => self.storage_runtime.ui_state().set(key, &json),
+                Err(e) => {
+                    log::error!("Failed to serialize column layout '{}': {}", key, e);
+                    return;
+                }
+            },
+            None => self.storage_runtime.ui_state().delete(key),
+        };
+
+        if let Err(e) = outcome {
+            log::error!("Failed to save column layout '{}': {}", key, e);
+        }
+    }
+
+    // --- Recent palette commands (SQLite-backed) ---
+
+    /// Ids of the commands last run from the command palette, most recent
+    /// first.
+    pub fn recent_palette_commands(&self) -> Vec<String> {
+        let json = match self
+            .storage_runtime
+            .ui_state()
+            .get(RECENT_PALETTE_COMMANDS_KEY)
+        {
+            Ok(json) => json,
+            Err(e) => {
+                log::warn!("Failed to load recent palette commands: {}", e);
+                None
+            }
+        };
+
+        json.and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Moves `id` to the front of the recent palette commands.
+    pub fn record_palette_command(&mut self, id: &str) {
+        let mut recent = self.recent_palette_commands();
+        recent.retain(|recent_id| recent_id != id);
+        recent.insert(0, id.to_string());
+        recent.truncate(MAX_RECENT_PALETTE_COMMANDS);
+
+        let outcome = serde_json::to_string(&recent)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                self.storage_runtime
+                    .ui_state()
+                    .set(RECENT_PALETTE_COMMANDS_KEY, &json)
+                    .map_err(|e| e.to_string())
+            });
+
+        if let Err(e) = outcome {
+            log::error!("Failed to save recent palette commands: {}", e);
+        }
+    }
+
+    // --- Scheduled exports (SQLite-backed) ---
+
+    fn load_scheduled_exports(storage_runtime: &StorageRuntime) -> Vec<ScheduledExport> {
+        let rows = match storage_runtime.scheduled_exports().all() {
+            Ok(rows) => rows,
+            Err(e) => {
+                log::warn!("Failed to load scheduled exports: {}", e);
+                return Vec::new();
+            }
+        };
+
+        rows.into_iter()
+            .filter_map(|row| {
+                let (Ok(id), Ok(saved_query_id)) = (
+                    Uuid::parse_str(&row.id),
+                    Uuid::parse_str(&row.saved_query_id),
+                ) else {
+                    log::warn!("Skipping scheduled export with invalid id '{}'", row.id);
+                    return None;
+                };
+
+                Some(ScheduledExport {
+                    id,
+                    name: row.name,
+                    saved_query_id,
+                    schedule: row.schedule,
+                    format: row.format,
+                    output_dir: PathBuf::from(row.output_dir),
+                    enabled: row.enabled,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    pub fn scheduled_exports(&self) -> &[ScheduledExport] {
+        &self.scheduled_exports
+    }
+
+    pub fn get_scheduled_export(&self, id: Uuid) -> Option<&ScheduledExport> {
+        self.scheduled_exports.iter().find(|export| export.id == id)
+    }
+
+    /// Adds `export`, or replaces the one with the same id.
+    pub fn save_scheduled_export(&mut self, export: ScheduledExport) {
+        let dto = ScheduledExportDto {
+            id: export.id.to_string(),
+            name: export.name.clone(),
+            saved_query_id: export.saved_query_id.to_string(),
+            schedule: export.schedule.clone(),
+            format: export.format.clone(),
+            output_dir: export.output_dir.to_string_lossy().into_owned(),
+            enabled: export.enabled,
+            created_at: export.created_at,
+        };
+
+        if let Err(e) = self.storage_runtime.scheduled_exports().upsert(&dto) {
+            log::error!("Failed to save scheduled export '{}': {}", export.name, e);
+        }
+
+        match self
+            .scheduled_exports
+            .iter_mut()
+            .find(|existing| existing.id == export.id)
+        {
+            Some(existing) => *existing = export,
+            None => self.scheduled_exports.push(export),
+        }
+    }
+
+    pub fn remove_scheduled_export(&mut self, id: Uuid) -> bool {
+        let Some(index) = self
+            .scheduled_exports
+            .iter()
+            .position(|export| export.id == id)
+        else {
+            return false;
+        };
+
+        if let Err(e) = self
+            .storage_runtime
+            .scheduled_exports()
+            .delete(&id.to_string())
+        {
+            log::error!("Failed to delete scheduled export: {}", e);
+        }
+
+        self.scheduled_exports.remove(index);
+        true
+    }
+
+    pub fn record_scheduled_export_run(&self, run: &ScheduledExportRun) {
+        let dto = ScheduledExportRunDto {
+            schedule_id: run.schedule_id.to_string(),
+            started_at: run.started_at,
+            duration_ms: run.duration_ms.min(i64::MAX as u64) as i64,
+            succeeded: run.succeeded,
+            detail: run.detail.clone(),
+        };
+
+        if let Err(e) = self.storage_runtime.scheduled_exports().record_run(&dto) {
+            log::error!("Failed to record scheduled export run: {}", e);
+        }
+    }
+
+    /// The latest runs of a scheduled export, newest first.
+    pub fn scheduled_export_runs(&self, id: Uuid, limit: usize) -> Vec<ScheduledExportRun> {
+        let rows = match self
+            .storage_runtime
+            .scheduled_exports()
+            .recent_runs(&id.to_string(), limit)
+        {
+            Ok(rows) => rows,
+            Err(e) => {
+                log::warn!("Failed to load scheduled export runs: {}", e);
+                return Vec::new();
+            }
+        };
+
+        rows.into_iter()
+            .map(|row| ScheduledExportRun {
+                schedule_id: id,
+                started_at: row.started_at,
+                duration_ms: row.duration_ms.max(0) as u64,
+                succeeded: row.succeeded,
+                detail: row.detail,
+            })
+            .collect()
+    }
+
     // --- ScriptsDirectory ---
 
     pub fn scripts_directory(&self) -> Option<&ScriptsDirectory> {
@@ -2425,6 +2860,10 @@ impl AppState {
         self.storage_runtime.shadow_path(doc_id)
     }
 
+    pub fn results_path(&self, doc_id: &str) -> std::path::PathBuf {
+        self.storage_runtime.results_path(doc_id)
+    }
+
     // --- TaskManager ---
 
     pub fn start_task(
@@ -2446,6 +2885,36 @@ impl AppState {
             .start_for_target(kind, description, target)
     }
 
+    /// Starts a task that queues behind other queued tasks on the same
+    /// connection. Connections whose driver serves concurrent requests run
+    /// several at once; the others run them one at a time.
+    pub fn enqueue_task_for_target(
+        &mut self,
+        kind: TaskKind,
+        description: impl Into<String>,
+        target: dbflux_core::TaskTarget,
+    ) -> (TaskId, CancelToken, dbflux_core::TaskAdmission) {
+        let concurrent = self
+            .connections()
+            .get(&target.profile_id)
+            .is_some_and(|connected| {
+                connected
+                    .connection
+                    .metadata()
+                    .capabilities
+                    .contains(DriverCapabilities::CONCURRENT_REQUESTS)
+            });
+        let limit = if concurrent {
+            dbflux_core::CONCURRENT_CONNECTION_CONCURRENCY
+        } else {
+            dbflux_core::DEFAULT_CONNECTION_CONCURRENCY
+        };
+
+        let tasks = &mut self.facade.tasks;
+        tasks.set_concurrency_limit(target.profile_id, limit);
+        tasks.enqueue_for_target(kind, description, target)
+    }
+
     pub fn start_task_for_profile(
         &mut self,
         kind: TaskKind,
@@ -2504,6 +2973,10 @@ impl AppState {
         self.facade.tasks.cancel(id)
     }
 
+    pub fn move_queued_task(&mut self, id: TaskId, earlier: bool) -> bool {
+        self.facade.tasks.move_queued(id, earlier)
+    }
+
     pub fn register_detached_hook_task(&mut self, profile_id: Uuid, task_id: TaskId) {
         self.detached_hook_tasks
             .entry(profile_id)
@@ -2564,6 +3037,21 @@ impl AppState {
         self.facade.shutdown_phase()
     }
 
+    /// Marks that the user is being asked about unsaved changes before
+    /// quitting. Returns `false` if a prompt is already open or shutdown has
+    /// started.
+    pub fn begin_shutdown_confirmation(&self) -> bool {
+        self.facade.begin_shutdown_confirmation()
+    }
+
+    pub fn cancel_shutdown_confirmation(&self) -> bool {
+        self.facade.cancel_shutdown_confirmation()
+    }
+
+    pub fn is_confirming_shutdown(&self) -> bool {
+        self.facade.is_confirming_shutdown()
+    }
+
     pub fn cancel_all_tasks(&mut self) -> usize {
         self.facade.cancel_all_tasks()
     }
@@ -2574,9 +3062,17 @@ impl AppState {
     }
 
     pub fn complete_shutdown(&self) {
+        self.storage_runtime.artifacts().clear_running_marker();
         self.facade.complete_shutdown();
     }
 
+    /// Marks this run as in progress. Returns `true` if the previous run
+    /// ended without a clean shutdown, so its unsaved buffers may need
+    /// recovering.
+    pub fn mark_session_running(&self) -> bool {
+        self.storage_runtime.artifacts().mark_running()
+    }
+
     #[allow(dead_code)]
     pub fn fail_shutdown(&self) {
         self.facade.fail_shutdown();
@@ -2602,6 +3098,71 @@ impl AppState {
         self.external_driver_diagnostics.get(socket_id)
     }
 
+    /// The external driver registry folder, next to the database.
+    pub fn driver_manifests_dir(&self) -> PathBuf {
+        Self::manifests_dir(&self.storage_runtime)
+    }
+
+    fn manifests_dir(storage_runtime: &StorageRuntime) -> PathBuf {
+        storage_runtime
+            .dbflux_db_path()
+            .parent()
+            .unwrap_or(std::path::Path::new("."))
+            .join(DRIVER_MANIFESTS_DIR)
+    }
+
+    /// Reads the external driver registry.
+    pub fn driver_manifests(&self) -> DriverManifestCatalog {
+        DriverManifestCatalog::load(&self.driver_manifests_dir())
+    }
+
+    /// Reads the configured services and driver manifests and returns the
+    /// driver service changes since the last reload, if any. Probe the result
+    /// off the main thread and hand it to [`Self::apply_rpc_driver_reload`].
+    pub fn pending_rpc_driver_reload(&self) -> Option<RpcDriverReload> {
+        let services = driver_services(
+            &dbflux_storage::load_service_configs(&self.storage_runtime),
+            &self.driver_manifests(),
+        );
+        let changes = diff_driver_services(&self.rpc_driver_services, &services);
+        if changes.is_empty() {
+            return None;
+        }
+
+        Some(RpcDriverReload {
+            services: services
+                .into_iter()
+                .map(|service| (service.config.socket_id.clone(), service))
+                .collect(),
+            changes,
+            audit_emitter: Some(self.rpc_audit_emitter.clone()),
+        })
+    }
+
+    /// Unregisters the drivers of removed or edited services and registers
+    /// the probed ones. Returns the ids of the newly registered drivers.
+    ///
+    /// Open connections keep the driver they were made with; only new
+    /// connections see the change.
+    pub fn apply_rpc_driver_reload(&mut self, probed: ProbedRpcDrivers) -> Vec<String> {
+        let registered = probed.registered_driver_ids();
+        let drivers = &mut self.facade.connections.drivers;
+
+        for socket_id in &probed.removed {
+            drivers.remove(&rpc_registry_id(socket_id));
+            self.external_driver_diagnostics.remove(socket_id);
+        }
+
+        for (driver_id, driver) in probed.drivers {
+            log::info!("Registered external RPC driver '{driver_id}'");
+            drivers.insert(driver_id, driver);
+        }
+        self.external_driver_diagnostics.extend(probed.diagnostics);
+        self.rpc_driver_services = probed.services;
+
+        registered
+    }
+
     pub fn driver_for_profile(&self, profile: &ConnectionProfile) -> Option<Arc<dyn DbDriver>> {
         self.facade
             .connections
@@ -2680,6 +3241,28 @@ impl AppState {
         cancelled
     }
 
+    /// Cancels every running and queued task of `profile_id`. Running
+    /// queries are also cancelled on the server, and a pending connect is
+    /// abandoned. Returns the number of tasks cancelled.
+    pub fn cancel_tasks_for_profile(&mut self, profile_id: Uuid) -> usize {
+        let mut query_targets: Vec<dbflux_core::TaskTarget> = Vec::new();
+        for task in self.facade.tasks.active_tasks_for_profile(profile_id) {
+            if task.kind == TaskKind::Query
+                && let Some(target) = task.target
+                && !query_targets.contains(&target)
+            {
+                query_targets.push(target);
+            }
+        }
+
+        for target in &query_targets {
+            self.cancel_query_for_target(target);
+        }
+
+        self.cancel_running_connect_tasks_for_profile(profile_id)
+            + self.facade.tasks.cancel_for_profile(profile_id)
+    }
+
     pub fn connections_mut(&mut self) -> &mut HashMap<Uuid, ConnectedProfile> {
         &mut self.facade.connections.connections
     }
@@ -2853,6 +3436,31 @@ impl AppState {
         )
     }
 
+    /// Shows or hides system schemas and internal objects for one
+    /// connection. Choosing the global default clears the override, so the
+    /// connection follows later changes to the default.
+    pub fn set_hide_system_objects(&mut self, profile_id: Uuid, hide: bool) {
+        let Some(mut profile) = self
+            .profiles()
+            .iter()
+            .find(|profile| profile.id == profile_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        let mut overrides = profile.settings_overrides.take().unwrap_or_default();
+        overrides.hide_system_objects =
+            (hide != self.general_settings.hide_system_objects).then_some(hide);
+        profile.settings_overrides = (!overrides.is_empty()).then_some(overrides);
+
+        if let Some(connected) = self.connections_mut().get_mut(&profile_id) {
+            connected.profile.settings_overrides = profile.settings_overrides.clone();
+        }
+
+        self.update_profile(profile);
+    }
+
     #[allow(dead_code)]
     pub fn driver_overrides(&self) -> &HashMap<DriverKey, GlobalOverrides> {
         &self.driver_overrides
@@ -2869,8 +3477,11 @@ impl AppState {
     }
 
     pub fn update_general_settings(&mut self, settings: GeneralSettings) {
-        self.history_manager
-            .set_max_entries(settings.max_history_entries);
+        self.history_manager.set_retention(
+            settings.max_history_entries,
+            settings.history_max_age_days,
+            settings.history_collapse_duplicates,
+        );
 
         self.general_settings = settings;
     }
@@ -3516,6 +4127,7 @@ mod tests {
             startup_timeout_ms: Some(1_000),
             kind,
             api_contract: None,
+            remote: None,
         }
     }
 
@@ -3761,6 +4373,7 @@ mod tests {
             startup_timeout_ms: Some(1_000),
             kind: RpcServiceKind::Driver,
             api_contract: None,
+            remote: None,
         };
 
         AppState::launch_rpc_services_with(
@@ -4063,6 +4676,203 @@ mod tests {
         );
     }
 
+    #[test]
+    fn connection_query_variables_shadow_workspace_values() {
+        let storage_runtime =
+            dbflux_storage::bootstrap::StorageRuntime::in_memory().expect("in-memory storage");
+        let mut state =
+            AppState::new_with_storage_runtime(storage_runtime).expect("test storage setup");
+        let profile_id = Uuid::new_v4();
+        let connection_scope = QueryVariableScope::Connection(profile_id);
+
+        state.set_query_variable(QueryVariableScope::Workspace, "env", "'prod'");
+        state.set_query_variable(QueryVariableScope::Workspace, "limit", "10");
+        state.set_query_variable(connection_scope, "env", "'staging'");
+
+        let resolved = state.resolved_query_variables(Some(profile_id));
+        assert_eq!(resolved.get("env").map(String::as_str), Some("'staging'"));
+        assert_eq!(resolved.get("limit").map(String::as_str), Some("10"));
+
+        let workspace_only = state.resolved_query_variables(None);
+        assert_eq!(
+            workspace_only.get("env").map(String::as_str),
+            Some("'prod'")
+        );
+
+        state.remove_query_variable(connection_scope, "env");
+        let resolved = state.resolved_query_variables(Some(profile_id));
+        assert_eq!(resolved.get("env").map(String::as_str), Some("'prod'"));
+
+        let persisted = state
+            .storage_runtime
+            .query_variables()
+            .all()
+            .expect("list query variables");
+        assert_eq!(persisted.len(), 2);
+    }
+
+    #[test]
+    fn scheduled_exports_persist_and_follow_their_saved_query() {
+        let storage_runtime =
+            dbflux_storage::bootstrap::StorageRuntime::in_memory().expect("in-memory storage");
+        let mut state =
+            AppState::new_with_storage_runtime(storage_runtime).expect("test storage setup");
+
+        let query = SavedQuery::new("Daily sales".to_string(), "SELECT 1".to_string(), None);
+        let query_id = query.id;
+        state.add_saved_query(query);
+
+        let mut export = ScheduledExport::new(
+            "Daily sales".to_string(),
+            query_id,
+            "0 6 * * *".to_string(),
+            "csv".to_string(),
+            PathBuf::from("/tmp/exports"),
+        );
+        state.save_scheduled_export(export.clone());
+        export.enabled = false;
+        state.save_scheduled_export(export.clone());
+
+        assert_eq!(state.scheduled_exports(), std::slice::from_ref(&export));
+        assert_eq!(
+            AppState::load_scheduled_exports(&state.storage_runtime),
+            vec![export.clone()]
+        );
+
+        state.record_scheduled_export_run(&ScheduledExportRun {
+            schedule_id: export.id,
+            started_at: 1_700_000_000,
+            duration_ms: 42,
+            succeeded: false,
+            detail: "connection is not open".to_string(),
+        });
+        let runs = state.scheduled_export_runs(export.id, 10);
+        assert_eq!(runs.len(), 1);
+        assert!(!runs[0].succeeded);
+
+        state.remove_saved_query(query_id);
+        assert!(state.scheduled_exports().is_empty());
+        assert!(state.scheduled_export_runs(export.id, 10).is_empty());
+    }
+
+    #[test]
+    fn table_column_layouts_round_trip_through_storage() {
+        let storage_runtime =
+            dbflux_storage::bootstrap::StorageRuntime::in_memory().expect("in-memory storage");
+        let mut state =
+            AppState::new_with_storage_runtime(storage_runtime).expect("test storage setup");
+        let key = ColumnLayout::storage_key(
+            Uuid::new_v4(),
+            Some("app"),
+            &dbflux_core::TableRef::new("users"),
+        );
+        let layout = ColumnLayout {
+            order: vec!["email".to_string(), "id".to_string()],
+            hidden: vec!["password_hash".to_string()],
+            frozen: 1,
+        };
+
+        assert_eq!(state.table_column_layout(&key), None);
+
+        state.set_table_column_layout(&key, Some(&layout));
+        assert_eq!(state.table_column_layout(&key), Some(layout));
+
+        state.set_table_column_layout(&key, None);
+        assert_eq!(state.table_column_layout(&key), None);
+    }
+
+    #[test]
+    fn history_page_filters_by_status_and_resolves_connection_names() {
+        let storage_runtime =
+            dbflux_storage::bootstrap::StorageRuntime::in_memory().expect("in-memory storage");
+        let mut state =
+            AppState::new_with_storage_runtime(storage_runtime).expect("test storage setup");
+        let profile = ConnectionProfile::new("analytics", DbConfig::default_postgres());
+        let profile_id = profile.id;
+        state.add_profile_in_folder(profile, None);
+
+        state.add_history_entry(
+            HistoryEntry::new(
+                "SELECT * FROM events".to_string(),
+                Some("warehouse".to_string()),
+                None,
+                std::time::Duration::from_millis(12),
+                Some(3),
+            )
+            .with_connection_profile(profile_id),
+        );
+        state.add_history_entry(
+            HistoryEntry::new(
+                "SELEC 1".to_string(),
+                None,
+                None,
+                std::time::Duration::ZERO,
+                None,
+            )
+            .with_error("syntax error".to_string()),
+        );
+
+        assert_eq!(state.history_count(&HistoryFilter::default()), 2);
+
+        let failed = HistoryFilter {
+            succeeded: Some(false),
+            ..Default::default()
+        };
+        let entries = state.history_page(&failed, 0, 10);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].error.as_deref(), Some("syntax error"));
+
+        let by_profile = HistoryFilter {
+            connection_profile_id: Some(profile_id),
+            text: "events".to_string(),
+            ..Default::default()
+        };
+        let entries = state.history_page(&by_profile, 0, 10);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].connection_name.as_deref(), Some("analytics"));
+        assert_eq!(entries[0].database.as_deref(), Some("warehouse"));
+    }
+
+    #[test]
+    fn history_retention_collapses_reruns_and_keeps_pinned_entries() {
+        let storage_runtime =
+            dbflux_storage::bootstrap::StorageRuntime::in_memory().expect("in-memory storage");
+        let mut state =
+            AppState::new_with_storage_runtime(storage_runtime).expect("test storage setup");
+        let entry = |sql: &str, days_ago: i64| {
+            let mut entry =
+                HistoryEntry::new(sql.to_string(), None, None, std::time::Duration::ZERO, None);
+            entry.timestamp -= days_ago * 86_400;
+            entry
+        };
+
+        let pinned = entry("SELECT 'keep'", 90);
+        let pinned_id = pinned.id;
+        state.add_history_entry(pinned);
+        assert!(state.toggle_history_pinned(pinned_id));
+
+        state.add_history_entry(entry("SELECT 'old'", 60));
+        state.add_history_entry(entry("SELECT 1", 1));
+        state.add_history_entry(entry("SELECT 1", 0));
+
+        let all = state.history_page(&HistoryFilter::default(), 0, 10);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].sql, "SELECT 1");
+        assert_eq!(all[0].run_count, 2);
+
+        state.update_general_settings(GeneralSettings {
+            history_max_age_days: 30,
+            ..state.general_settings().clone()
+        });
+
+        let remaining: Vec<String> = state
+            .history_page(&HistoryFilter::default(), 0, 10)
+            .into_iter()
+            .map(|entry| entry.sql)
+            .collect();
+        assert_eq!(remaining, vec!["SELECT 1", "SELECT 'keep'"]);
+    }
+
     // --- T-3.6: list_auth_profiles() union seam ---
     // Tests for a driver whose `driver_key()` is `"builtin:influxdb"`.
 
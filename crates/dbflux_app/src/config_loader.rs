@@ -32,7 +32,7 @@ pub fn save_general_settings(
     let repo = runtime.general_settings();
     let dto = GeneralSettingsDto {
         id: 1,
-        theme: general_settings_theme_to_storage(settings.theme).to_string(),
+        theme: theme_to_storage(settings),
         restore_session_on_startup: if settings.restore_session_on_startup {
             1
         } else {
@@ -84,6 +84,42 @@ pub fn save_general_settings(
             dbflux_core::AppStyle::Default => "default".to_string(),
             dbflux_core::AppStyle::Compact => "compact".to_string(),
         },
+        editor_vim_mode: if settings.editor_vim_mode { 1 } else { 0 },
+        history_max_age_days: i64::from(settings.history_max_age_days),
+        history_collapse_duplicates: if settings.history_collapse_duplicates {
+            1
+        } else {
+            0
+        },
+        hide_system_objects: if settings.hide_system_objects { 1 } else { 0 },
+        schema_refresh_interval_secs: i64::from(settings.schema_refresh_interval_secs),
+        schema_refresh_on_focus: if settings.schema_refresh_on_focus {
+            1
+        } else {
+            0
+        },
+        notify_long_query_secs: i64::from(settings.notify_long_query_secs),
+        ui_zoom_percent: i64::from(settings.ui_zoom_percent),
+        editor_font_size: i64::from(settings.editor_font_size),
+        grid_font_size: i64::from(settings.grid_font_size),
+        language: settings.language.code().to_string(),
+        display_timezone: settings.display_timezone.code(),
+        null_marker: settings.null_marker.clone(),
+        null_style: settings.null_style.code().to_string(),
+        thousands_separator: settings
+            .display_format
+            .thousands_separator
+            .map(String::from)
+            .unwrap_or_default(),
+        decimal_places: settings.display_format.decimal_places.map_or(-1, i64::from),
+        date_format: settings.display_format.date_format.clone(),
+        time_format: settings.display_format.time_format.clone(),
+        timestamp_format: settings.display_format.timestamp_format.clone(),
+        scientific_threshold: settings
+            .display_format
+            .scientific_threshold
+            .map_or(-1, i64::from),
+        formatted_output: i64::from(settings.display_format.formatted_output),
         updated_at: String::new(),
     };
     repo.upsert(&dto)?;
@@ -258,6 +294,9 @@ pub fn save_services(
                 .map(|contract| contract.family.clone()),
             api_major: api_contract.as_ref().map(|contract| contract.major as i64),
             api_minor: api_contract.as_ref().map(|contract| contract.minor as i64),
+            remote_address: svc.remote.as_ref().map(|remote| remote.address.clone()),
+            remote_tls: svc.remote.as_ref().is_some_and(|remote| remote.tls),
+            remote_token_env: svc.remote.as_ref().map(|remote| remote.token_env.clone()),
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -531,6 +570,13 @@ fn save_global_overrides(
             Some(v.to_string()),
         ))?;
     }
+    if let Some(v) = ov.hide_system_objects {
+        settings_repo.upsert(&ConnectionProfileSettingDto::new(
+            profile_id.to_string(),
+            "overrides.hide_system_objects".to_string(),
+            Some(v.to_string()),
+        ))?;
+    }
     Ok(())
 }
 
@@ -969,8 +1015,34 @@ fn load_general_settings(
     };
 
     GeneralSettings {
+        language: dbflux_core::i18n::Language::from_code(&dto.language).unwrap_or_default(),
         theme: theme_setting_from_storage(&dto.theme),
+        custom_theme: dto
+            .theme
+            .strip_prefix(CUSTOM_THEME_PREFIX)
+            .map(str::to_string),
         style: app_style_from_storage(&dto.style),
+        ui_zoom_percent: dto.ui_zoom_percent.clamp(
+            i64::from(dbflux_core::MIN_UI_ZOOM_PERCENT),
+            i64::from(dbflux_core::MAX_UI_ZOOM_PERCENT),
+        ) as u16,
+        editor_font_size: dto.editor_font_size.clamp(
+            i64::from(dbflux_core::MIN_EDITOR_FONT_SIZE),
+            i64::from(dbflux_core::MAX_EDITOR_FONT_SIZE),
+        ) as u16,
+        grid_font_size: dto.grid_font_size.clamp(
+            i64::from(dbflux_core::MIN_GRID_FONT_SIZE),
+            i64::from(dbflux_core::MAX_GRID_FONT_SIZE),
+        ) as u16,
+        display_timezone: dbflux_core::timezone::DisplayTimezone::from_code(&dto.display_timezone)
+            .unwrap_or_default(),
+        null_marker: match dto.null_marker.trim() {
+            "" => dbflux_core::null_display::DEFAULT_NULL_MARKER.to_string(),
+            marker => marker.to_string(),
+        },
+        null_style: dbflux_core::null_display::NullStyle::from_code(&dto.null_style)
+            .unwrap_or_default(),
+        display_format: display_format_from_storage(&dto),
         restore_session_on_startup: dto.restore_session_on_startup != 0,
         reopen_last_connections: dto.reopen_last_connections != 0,
         default_focus_on_startup: match dto.default_focus_on_startup.as_str() {
@@ -990,22 +1062,75 @@ fn load_general_settings(
         confirm_dangerous_queries: dto.confirm_dangerous_queries != 0,
         dangerous_requires_where: dto.dangerous_requires_where != 0,
         dangerous_requires_preview: dto.dangerous_requires_preview != 0,
+        editor_vim_mode: dto.editor_vim_mode != 0,
+        history_max_age_days: dto.history_max_age_days.clamp(0, u32::MAX as i64) as u32,
+        history_collapse_duplicates: dto.history_collapse_duplicates != 0,
+        hide_system_objects: dto.hide_system_objects != 0,
+        schema_refresh_interval_secs: dto.schema_refresh_interval_secs.clamp(0, u32::MAX as i64)
+            as u32,
+        schema_refresh_on_focus: dto.schema_refresh_on_focus != 0,
+        notify_long_query_secs: dto.notify_long_query_secs.clamp(0, u32::MAX as i64) as u32,
         workspace_inspector_width_px: None,
     }
 }
 
+/// Prefix of the stored `theme` value when a user theme is selected; the
+/// rest of the value is the theme name.
+const CUSTOM_THEME_PREFIX: &str = "custom:";
+
+/// Out-of-range numbers and invalid patterns fall back to the defaults.
+fn display_format_from_storage(
+    dto: &GeneralSettingsDto,
+) -> dbflux_core::display_format::DisplayFormat {
+    use dbflux_core::display_format::{DisplayFormat, is_valid_pattern};
+
+    let defaults = DisplayFormat::default();
+    let pattern = |stored: &str, default: String| {
+        if is_valid_pattern(stored) {
+            stored.to_string()
+        } else {
+            default
+        }
+    };
+
+    DisplayFormat {
+        thousands_separator: dto.thousands_separator.chars().next(),
+        decimal_places: u8::try_from(dto.decimal_places)
+            .ok()
+            .filter(|places| *places <= 10),
+        date_format: pattern(&dto.date_format, defaults.date_format),
+        time_format: pattern(&dto.time_format, defaults.time_format),
+        timestamp_format: pattern(&dto.timestamp_format, defaults.timestamp_format),
+        scientific_threshold: u8::try_from(dto.scientific_threshold)
+            .ok()
+            .filter(|exponent| (1..=20).contains(exponent)),
+        formatted_output: dto.formatted_output != 0,
+    }
+}
+
+fn theme_to_storage(settings: &GeneralSettings) -> String {
+    match &settings.custom_theme {
+        Some(name) => format!("{CUSTOM_THEME_PREFIX}{name}"),
+        None => general_settings_theme_to_storage(settings.theme).to_string(),
+    }
+}
+
 fn general_settings_theme_to_storage(theme: dbflux_core::ThemeSetting) -> &'static str {
     match theme {
         dbflux_core::ThemeSetting::Dark => "dark",
         dbflux_core::ThemeSetting::Mirage => "mirage",
         dbflux_core::ThemeSetting::Light => "light",
+        dbflux_core::ThemeSetting::System => "system",
     }
 }
 
+/// Built-in theme for a stored value. User themes store no built-in and
+/// fall back to Dark when their file is gone.
 fn theme_setting_from_storage(theme: &str) -> dbflux_core::ThemeSetting {
     match theme {
         "light" => dbflux_core::ThemeSetting::Light,
         "mirage" => dbflux_core::ThemeSetting::Mirage,
+        "system" => dbflux_core::ThemeSetting::System,
         _ => dbflux_core::ThemeSetting::Dark,
     }
 }
@@ -1051,6 +1176,7 @@ fn load_driver_maps(
                 confirm_dangerous: entry.confirm_dangerous.map(|v| v != 0),
                 requires_where: entry.requires_where.map(|v| v != 0),
                 requires_preview: entry.requires_preview.map(|v| v != 0),
+                hide_system_objects: None,
             };
 
             if !ov.is_empty() {
@@ -1187,6 +1313,11 @@ fn load_profile_settings(
                         settings_overrides.requires_preview = v.parse().ok();
                     }
                 }
+                "overrides.hide_system_objects" => {
+                    if let Some(v) = value {
+                        settings_overrides.hide_system_objects = v.parse().ok();
+                    }
+                }
                 _ => {}
             }
         } else if key.starts_with("conn.") {
@@ -1646,6 +1777,10 @@ mod tests {
         default_db_config_for_kind, general_settings_theme_to_storage, load_config, save_profiles,
         save_services, save_ssh_tunnels, theme_setting_from_storage,
     };
+    use dbflux_core::display_format::DisplayFormat;
+    use dbflux_core::i18n::Language;
+    use dbflux_core::null_display::NullStyle;
+    use dbflux_core::timezone::DisplayTimezone;
     use dbflux_core::{
         AccessKind, ConnectionProfile, DbConfig, DbKind, GeneralSettings, RpcServiceKind,
         ServiceConfig, SshAuthMethod, SshTunnelConfig, SshTunnelProfile, ThemeSetting,
@@ -1675,7 +1810,7 @@ mod tests {
     }
 
     #[test]
-    fn theme_setting_storage_round_trip_supports_exactly_three_ayu_values() {
+    fn theme_setting_storage_round_trip_covers_every_setting() {
         assert_eq!(
             general_settings_theme_to_storage(ThemeSetting::Dark),
             "dark"
@@ -1692,6 +1827,191 @@ mod tests {
         assert_eq!(theme_setting_from_storage("dark"), ThemeSetting::Dark);
         assert_eq!(theme_setting_from_storage("mirage"), ThemeSetting::Mirage);
         assert_eq!(theme_setting_from_storage("light"), ThemeSetting::Light);
+
+        assert_eq!(
+            general_settings_theme_to_storage(ThemeSetting::System),
+            "system"
+        );
+        assert_eq!(theme_setting_from_storage("system"), ThemeSetting::System);
+    }
+
+    #[test]
+    fn custom_theme_round_trips_through_save_and_load() {
+        let settings = GeneralSettings {
+            custom_theme: Some("solarized".to_string()),
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save custom theme");
+
+        let dto = runtime
+            .general_settings()
+            .get()
+            .expect("load saved dto")
+            .expect("general settings row");
+        assert_eq!(dto.theme, "custom:solarized");
+
+        let loaded = load_config(&runtime);
+        assert_eq!(
+            loaded.general_settings.custom_theme.as_deref(),
+            Some("solarized")
+        );
+        assert_eq!(loaded.general_settings.theme, ThemeSetting::Dark);
+    }
+
+    #[test]
+    fn font_scale_round_trips_and_clamps_out_of_range_values() {
+        let settings = GeneralSettings {
+            ui_zoom_percent: 130,
+            editor_font_size: 16,
+            grid_font_size: 12,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save font scale");
+
+        let loaded = load_config(&runtime).general_settings;
+        assert_eq!(loaded.ui_zoom_percent, 130);
+        assert_eq!(loaded.editor_font_size, 16);
+        assert_eq!(loaded.grid_font_size, 12);
+
+        let mut dto = runtime
+            .general_settings()
+            .get()
+            .expect("load saved dto")
+            .expect("general settings row");
+        dto.ui_zoom_percent = 1000;
+        dto.editor_font_size = -4;
+        runtime.general_settings().upsert(&dto).expect("upsert dto");
+
+        let loaded = load_config(&runtime).general_settings;
+        assert_eq!(loaded.ui_zoom_percent, dbflux_core::MAX_UI_ZOOM_PERCENT);
+        assert_eq!(loaded.editor_font_size, dbflux_core::MIN_EDITOR_FONT_SIZE);
+    }
+
+    #[test]
+    fn language_round_trips_and_unknown_codes_fall_back_to_english() {
+        let settings = GeneralSettings {
+            language: Language::Spanish,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save language");
+        assert_eq!(
+            load_config(&runtime).general_settings.language,
+            Language::Spanish
+        );
+
+        let mut dto = runtime
+            .general_settings()
+            .get()
+            .expect("load saved dto")
+            .expect("general settings row");
+        dto.language = "tlh".to_string();
+        runtime.general_settings().upsert(&dto).expect("upsert dto");
+
+        assert_eq!(
+            load_config(&runtime).general_settings.language,
+            Language::English
+        );
+    }
+
+    #[test]
+    fn display_timezone_round_trips_and_unknown_codes_fall_back_to_utc() {
+        let settings = GeneralSettings {
+            display_timezone: DisplayTimezone::from_code("-03:30").expect("valid offset"),
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save display time zone");
+        assert_eq!(
+            load_config(&runtime).general_settings.display_timezone,
+            settings.display_timezone
+        );
+
+        let mut dto = runtime
+            .general_settings()
+            .get()
+            .expect("load saved dto")
+            .expect("general settings row");
+        dto.display_timezone = "Mars/Olympus_Mons".to_string();
+        runtime.general_settings().upsert(&dto).expect("upsert dto");
+
+        assert_eq!(
+            load_config(&runtime).general_settings.display_timezone,
+            DisplayTimezone::Utc
+        );
+    }
+
+    #[test]
+    fn null_display_round_trips_and_bad_values_fall_back_to_defaults() {
+        let settings = GeneralSettings {
+            null_marker: "<null>".to_string(),
+            null_style: NullStyle::Badge,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save NULL display");
+        let loaded = load_config(&runtime).general_settings;
+        assert_eq!(loaded.null_marker, "<null>");
+        assert_eq!(loaded.null_style, NullStyle::Badge);
+
+        let mut dto = runtime
+            .general_settings()
+            .get()
+            .expect("load saved dto")
+            .expect("general settings row");
+        dto.null_marker = "  ".to_string();
+        dto.null_style = "blinking".to_string();
+        runtime.general_settings().upsert(&dto).expect("upsert dto");
+
+        let loaded = load_config(&runtime).general_settings;
+        assert_eq!(loaded.null_marker, "NULL");
+        assert_eq!(loaded.null_style, NullStyle::Muted);
+    }
+
+    #[test]
+    fn display_format_round_trips_and_invalid_values_fall_back_to_defaults() {
+        let settings = GeneralSettings {
+            display_format: DisplayFormat {
+                thousands_separator: Some('.'),
+                decimal_places: Some(3),
+                date_format: "%d/%m/%Y".to_string(),
+                scientific_threshold: Some(12),
+                formatted_output: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save display format");
+        assert_eq!(
+            load_config(&runtime).general_settings.display_format,
+            settings.display_format
+        );
+
+        let mut dto = runtime
+            .general_settings()
+            .get()
+            .expect("load saved dto")
+            .expect("general settings row");
+        dto.decimal_places = 99;
+        dto.date_format = "%Q".to_string();
+        dto.scientific_threshold = 0;
+        runtime.general_settings().upsert(&dto).expect("upsert dto");
+
+        let loaded = load_config(&runtime).general_settings.display_format;
+        assert_eq!(loaded.thousands_separator, Some('.'));
+        assert_eq!(loaded.decimal_places, None);
+        assert_eq!(loaded.date_format, "%Y-%m-%d");
+        assert_eq!(loaded.scientific_threshold, None);
+        assert!(loaded.formatted_output);
     }
 
     #[test]
@@ -1713,6 +2033,27 @@ mod tests {
             dangerous_requires_where: 0,
             dangerous_requires_preview: 1,
             style: "default".to_string(),
+            editor_vim_mode: 0,
+            history_max_age_days: 0,
+            history_collapse_duplicates: 1,
+            hide_system_objects: 1,
+            schema_refresh_interval_secs: 0,
+            schema_refresh_on_focus: 0,
+            notify_long_query_secs: 30,
+            ui_zoom_percent: 100,
+            editor_font_size: 14,
+            grid_font_size: 13,
+            language: "en".to_string(),
+            display_timezone: "utc".to_string(),
+            null_marker: "NULL".to_string(),
+            null_style: "muted".to_string(),
+            thousands_separator: String::new(),
+            decimal_places: -1,
+            date_format: "%Y-%m-%d".to_string(),
+            time_format: "%H:%M:%S".to_string(),
+            timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            scientific_threshold: -1,
+            formatted_output: 0,
             updated_at: String::new(),
         };
 
@@ -1802,6 +2143,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn editor_vim_mode_round_trips_through_save_and_load() {
+        let mut settings = GeneralSettings {
+            editor_vim_mode: true,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save vim mode on");
+
+        let loaded = load_config(&runtime);
+        assert!(loaded.general_settings.editor_vim_mode);
+
+        settings.editor_vim_mode = false;
+        super::save_general_settings(&runtime, &settings).expect("save vim mode off");
+
+        let loaded = load_config(&runtime);
+        assert!(!loaded.general_settings.editor_vim_mode);
+    }
+
+    #[test]
+    fn history_retention_settings_round_trip_through_save_and_load() {
+        let settings = GeneralSettings {
+            history_max_age_days: 90,
+            history_collapse_duplicates: false,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save retention settings");
+
+        let loaded = load_config(&runtime);
+        assert_eq!(loaded.general_settings.history_max_age_days, 90);
+        assert!(!loaded.general_settings.history_collapse_duplicates);
+    }
+
+    #[test]
+    fn hide_system_objects_round_trips_through_save_and_load() {
+        let settings = GeneralSettings {
+            hide_system_objects: false,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save hide system objects");
+
+        let loaded = load_config(&runtime);
+        assert!(!loaded.general_settings.hide_system_objects);
+    }
+
+    #[test]
+    fn schema_refresh_settings_round_trip_through_save_and_load() {
+        let settings = GeneralSettings {
+            schema_refresh_interval_secs: 900,
+            schema_refresh_on_focus: true,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings).expect("save schema refresh settings");
+
+        let loaded = load_config(&runtime);
+        assert_eq!(loaded.general_settings.schema_refresh_interval_secs, 900);
+        assert!(loaded.general_settings.schema_refresh_on_focus);
+    }
+
+    #[test]
+    fn long_query_notification_threshold_round_trips_through_save_and_load() {
+        let settings = GeneralSettings {
+            notify_long_query_secs: 300,
+            ..Default::default()
+        };
+
+        let runtime = StorageRuntime::in_memory().expect("in-memory storage runtime");
+        super::save_general_settings(&runtime, &settings)
+            .expect("save long query notification threshold");
+
+        let loaded = load_config(&runtime);
+        assert_eq!(loaded.general_settings.notify_long_query_secs, 300);
+    }
+
     #[test]
     fn unknown_style_string_in_db_falls_back_to_default() {
         use dbflux_core::AppStyle;
@@ -1826,6 +2248,27 @@ mod tests {
             dangerous_requires_where: 1,
             dangerous_requires_preview: 0,
             style: "ultracompact".to_string(), // unknown value
+            editor_vim_mode: 0,
+            history_max_age_days: 0,
+            history_collapse_duplicates: 1,
+            hide_system_objects: 1,
+            schema_refresh_interval_secs: 0,
+            schema_refresh_on_focus: 0,
+            notify_long_query_secs: 30,
+            ui_zoom_percent: 100,
+            editor_font_size: 14,
+            grid_font_size: 13,
+            language: "en".to_string(),
+            display_timezone: "utc".to_string(),
+            null_marker: "NULL".to_string(),
+            null_style: "muted".to_string(),
+            thousands_separator: String::new(),
+            decimal_places: -1,
+            date_format: "%Y-%m-%d".to_string(),
+            time_format: "%H:%M:%S".to_string(),
+            timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            scientific_threshold: -1,
+            formatted_output: 0,
             updated_at: String::new(),
         };
         runtime
@@ -1947,6 +2390,7 @@ mod tests {
             startup_timeout_ms: Some(5_000),
             kind: RpcServiceKind::AuthProvider,
             api_contract: Some(dbflux_core::ServiceRpcApiContract::new(
+            remote: None,
                 "auth_provider_rpc",
                 1,
                 0,
@@ -0,0 +1,191 @@
+//! The external driver registry: one [`DriverManifest`] JSON file per driver
+//! in the `drivers` folder next to the database.
+//!
+//! ```json
+//! {
+//!   "name": "Mock Database",
+//!   "socket": "mockdb.sock",
+//!   "binary": "/opt/mockdb/custom-driver",
+//!   "args": ["--socket", "mockdb.sock"],
+//!   "capabilities": ["transactions", "export_csv"],
+//!   "icon": "Database"
+//! }
+//! ```
+//!
+//! The folder is read whenever the driver services are (re)loaded, so adding,
+//! editing or removing a file takes effect without a restart.
+
+use dbflux_core::DriverManifest;
+use std::path::{Path, PathBuf};
+
+/// A manifest and the file it was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriverManifestEntry {
+    pub path: PathBuf,
+    pub manifest: DriverManifest,
+}
+
+/// The manifests that loaded, and what went wrong with the rest.
+#[derive(Debug, Clone, Default)]
+pub struct DriverManifestCatalog {
+    pub entries: Vec<DriverManifestEntry>,
+    pub errors: Vec<String>,
+}
+
+impl DriverManifestCatalog {
+    /// Loads every `*.json` file in `dir`, sorted by file name. A missing
+    /// folder is an empty catalog. Two manifests for the same socket keep the
+    /// first one.
+    pub fn load(dir: &Path) -> Self {
+        let mut catalog = Self::default();
+
+        for path in manifest_files(dir) {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| DriverManifest::parse(&json));
+
+            match parsed {
+                Ok(manifest) if catalog.find_socket(&manifest.socket).is_some() => {
+                    catalog.errors.push(format!(
+                        "{file_name}: another manifest already uses socket \"{}\"",
+                        manifest.socket
+                    ));
+                }
+                Ok(manifest) => catalog.entries.push(DriverManifestEntry { path, manifest }),
+                Err(e) => catalog.errors.push(format!("{file_name}: {e}")),
+            }
+        }
+
+        catalog
+    }
+
+    pub fn find_socket(&self, socket: &str) -> Option<&DriverManifestEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.manifest.socket == socket)
+    }
+
+    pub fn manifests(&self) -> impl Iterator<Item = &DriverManifest> {
+        self.entries.iter().map(|entry| &entry.manifest)
+    }
+}
+
+/// Validates the manifest at `source` and copies it into `dir`, creating the
+/// folder if needed. Returns the installed file.
+pub fn install_driver_manifest(dir: &Path, source: &Path) -> Result<PathBuf, String> {
+    let json = std::fs::read_to_string(source).map_err(|e| e.to_string())?;
+    let manifest = DriverManifest::parse(&json)?;
+
+    let Some(file_name) = source.file_name() else {
+        return Err(format!("{} is not a file", source.display()));
+    };
+    let target = dir.join(file_name).with_extension("json");
+
+    if target.exists() {
+        return Err(format!(
+            "a manifest named {} is already installed",
+            target.file_name().unwrap_or_default().to_string_lossy()
+        ));
+    }
+
+    if let Some(existing) = DriverManifestCatalog::load(dir).find_socket(&manifest.socket) {
+        return Err(format!(
+            "\"{}\" already uses socket \"{}\"",
+            existing.manifest.name, manifest.socket
+        ));
+    }
+
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    std::fs::write(&target, json).map_err(|e| e.to_string())?;
+
+    Ok(target)
+}
+
+/// Rewrites the manifest at `path` with `enabled` set.
+pub fn set_driver_manifest_enabled(path: &Path, enabled: bool) -> Result<(), String> {
+    let json = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let mut manifest = DriverManifest::parse(&json)?;
+    manifest.enabled = enabled;
+
+    let json = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    std::fs::write(path, json + "\n").map_err(|e| e.to_string())
+}
+
+/// Deletes the manifest at `path`, unregistering its driver.
+pub fn remove_driver_manifest(path: &Path) -> Result<(), String> {
+    std::fs::remove_file(path).map_err(|e| e.to_string())
+}
+
+fn manifest_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<PathBuf> = read_dir
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("dbflux-drivers-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn manifest_json(name: &str, socket: &str) -> String {
+        format!(r#"{{ "name": "{name}", "socket": "{socket}" }}"#)
+    }
+
+    #[test]
+    fn load_reports_invalid_and_duplicate_manifests() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("a.json"), manifest_json("Mock", "mock.sock")).unwrap();
+        std::fs::write(dir.join("b.json"), manifest_json("Mock copy", "mock.sock")).unwrap();
+        std::fs::write(dir.join("c.json"), "{ not json").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let catalog = DriverManifestCatalog::load(&dir);
+
+        assert_eq!(catalog.entries.len(), 1);
+        assert_eq!(catalog.entries[0].manifest.name, "Mock");
+        assert_eq!(catalog.errors.len(), 2, "{:?}", catalog.errors);
+        assert!(catalog.errors[0].starts_with("b.json: another manifest"));
+        assert!(catalog.errors[1].starts_with("c.json: "));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn install_toggle_and_remove_round_trip() {
+        let source_dir = temp_dir();
+        let source = source_dir.join("mock.json");
+        std::fs::write(&source, manifest_json("Mock", "mock.sock")).unwrap();
+
+        let dir = source_dir.join("drivers");
+        let installed = install_driver_manifest(&dir, &source).expect("installs");
+        assert!(
+            install_driver_manifest(&dir, &source)
+                .unwrap_err()
+                .contains("already installed")
+        );
+
+        set_driver_manifest_enabled(&installed, false).expect("disables");
+        let catalog = DriverManifestCatalog::load(&dir);
+        assert!(!catalog.entries[0].manifest.enabled);
+
+        remove_driver_manifest(&installed).expect("removes");
+        assert!(DriverManifestCatalog::load(&dir).entries.is_empty());
+
+        std::fs::remove_dir_all(&source_dir).unwrap();
+    }
+}
@@ -0,0 +1,405 @@
+//! `dbflux export`: runs a table or saved query against a connection
+//! profile and writes the result with one of the built-in exporters, without
+//! opening the GUI. Meant for cron jobs and scripts.
+
+use crate::app_state::AppState;
+use dbflux_core::{
+    ConnectionProfile, PrepareConnectError, QueryRequest, QueryResultShape, SavedQuery, TableRef,
+    bind_saved_query_params, substitute_query_variables,
+};
+use dbflux_export::ExportFormat;
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Formats accepted by `--format`: the ones offered for table results.
+fn formats() -> &'static [ExportFormat] {
+    dbflux_export::available_formats(&QueryResultShape::Table)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ExportSource {
+    Table(TableRef),
+    SavedQuery(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExportArgs {
+    profile: Option<String>,
+    source: ExportSource,
+    database: Option<String>,
+    format: ExportFormat,
+    /// `None` writes to standard output.
+    output: Option<PathBuf>,
+}
+
+pub fn run_export_command(args: &[String]) -> i32 {
+    let export_args = match parse_export_args(args) {
+        Ok(Some(export_args)) => export_args,
+        Ok(None) => {
+            print_export_help();
+            return 0;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!();
+            print_export_help();
+            return 1;
+        }
+    };
+
+    let result = run_export(&export_args);
+
+    let stopped = dbflux_driver_ipc::shutdown_managed_hosts();
+    if stopped > 0 {
+        log::info!("Stopped {} managed RPC host process(es)", stopped);
+    }
+    dbflux_ipc::shutdown_managed_auth_provider_hosts();
+
+    match result {
+        Ok(summary) => {
+            eprintln!("{}", summary);
+            0
+        }
+        Err(e) => {
+            eprintln!("Export failed: {}", e);
+            1
+        }
+    }
+}
+
+/// Parses the arguments after `export`. `Ok(None)` means help was asked for.
+fn parse_export_args(args: &[String]) -> Result<Option<ExportArgs>, String> {
+    let mut profile = None;
+    let mut table = None;
+    let mut saved_query = None;
+    let mut database = None;
+    let mut format = None;
+    let mut output = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("{} needs a value", arg))
+        };
+
+        match arg.as_str() {
+            "--profile" => profile = Some(value()?),
+            "--table" => table = Some(value()?),
+            "--saved-query" => saved_query = Some(value()?),
+            "--database" => database = Some(value()?),
+            "--format" => format = Some(value()?),
+            "--output" | "-o" => output = Some(value()?),
+            "--help" | "-h" => return Ok(None),
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+
+    let source = match (table, saved_query) {
+        (Some(table), None) => ExportSource::Table(TableRef::from_qualified(&table)),
+        (None, Some(name)) => ExportSource::SavedQuery(name),
+        (Some(_), Some(_)) => {
+            return Err("--table and --saved-query cannot be used together".to_string());
+        }
+        (None, None) => return Err("--table or --saved-query is required".to_string()),
+    };
+
+    if profile.is_none() && matches!(source, ExportSource::Table(_)) {
+        return Err("--profile is required with --table".to_string());
+    }
+
+    let format = match format {
+        Some(key) => formats()
+            .iter()
+            .copied()
+            .find(|format| format.key() == key)
+            .ok_or_else(|| format!("Unknown format: {} (expected {})", key, format_keys()))?,
+        None => return Err("--format is required".to_string()),
+    };
+
+    let output = match output {
+        Some(path) if path == "-" => None,
+        Some(path) => Some(PathBuf::from(path)),
+        None => return Err("--output is required (use - for standard output)".to_string()),
+    };
+
+    Ok(Some(ExportArgs {
+        profile,
+        source,
+        database,
+        format,
+        output,
+    }))
+}
+
+fn run_export(args: &ExportArgs) -> Result<String, String> {
+    let state = AppState::new().map_err(|e| format!("Cannot open DBFlux storage: {}", e))?;
+
+    let saved_query = match &args.source {
+        ExportSource::SavedQuery(name) => Some(find_saved_query(state.saved_queries(), name)?),
+        ExportSource::Table(_) => None,
+    };
+
+    let profile = match (&args.profile, saved_query) {
+        (Some(profile), _) => find_profile(state.profiles(), profile)?,
+        (None, Some(query)) => {
+            let profile_id = query.connection_id.ok_or_else(|| {
+                format!(
+                    "Saved query '{}' is not tied to a connection; pass --profile",
+                    query.name
+                )
+            })?;
+            state
+                .profiles()
+                .iter()
+                .find(|profile| profile.id == profile_id)
+                .ok_or_else(|| {
+                    format!(
+                        "The connection of saved query '{}' no longer exists",
+                        query.name
+                    )
+                })?
+        }
+        (None, None) => unreachable!("parse_export_args requires --profile with --table"),
+    };
+
+    if state.profile_uses_connect_pipeline(profile) {
+        return Err(format!(
+            "'{}' connects through an auth profile or managed access, which the command line \
+             does not support yet",
+            profile.name
+        ));
+    }
+
+    let params = state
+        .prepare_connect_profile(profile.id)
+        .map_err(|e| prepare_error_message(&e, profile))?;
+    let connected = params.execute(Some(crate::proxy::create_proxy_tunnel))?;
+    let conn = connected.connection;
+
+    let sql = match (&args.source, saved_query) {
+        (ExportSource::Table(table), _) => {
+            format!("SELECT * FROM {}", table.quoted_with(conn.dialect()))
+        }
+        (ExportSource::SavedQuery(_), Some(query)) => {
+            let mut sql = query.sql.clone();
+            if !query.params.is_empty() {
+                let inputs: HashMap<String, String> = query
+                    .params
+                    .iter()
+                    .map(|param| (param.name.clone(), param.initial_value().to_string()))
+                    .collect();
+                sql = bind_saved_query_params(&sql, &query.params, &inputs, conn.dialect())
+                    .map_err(|e| e.to_string())?;
+            }
+            substitute_query_variables(&sql, &state.resolved_query_variables(Some(profile.id)))
+                .map_err(|e| e.to_string())?
+        }
+        (ExportSource::SavedQuery(_), None) => unreachable!("saved query resolved above"),
+    };
+
+    let request = QueryRequest::new(sql).with_database(args.database.clone());
+    let result = conn.execute(&request).map_err(|e| e.to_string())?;
+
+    let written = match &args.output {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .map_err(|e| format!("Cannot create {}: {}", path.display(), e))?;
+            let mut writer = BufWriter::new(file);
+            dbflux_export::export(&result, args.format, &mut writer).map_err(|e| e.to_string())?;
+            writer.flush().map_err(|e| e.to_string())?;
+            path.display().to_string()
+        }
+        None => {
+            let mut writer = BufWriter::new(std::io::stdout().lock());
+            dbflux_export::export(&result, args.format, &mut writer).map_err(|e| e.to_string())?;
+            writer.flush().map_err(|e| e.to_string())?;
+            "standard output".to_string()
+        }
+    };
+
+    Ok(format!(
+        "{} rows written to {}",
+        result.row_count(),
+        written
+    ))
+}
+
+/// Finds a profile by id or, failing that, by exact name.
+fn find_profile<'a>(
+    profiles: &'a [ConnectionProfile],
+    key: &str,
+) -> Result<&'a ConnectionProfile, String> {
+    if let Ok(id) = key.parse::<Uuid>()
+        && let Some(profile) = profiles.iter().find(|profile| profile.id == id)
+    {
+        return Ok(profile);
+    }
+
+    let mut matches = profiles.iter().filter(|profile| profile.name == key);
+    match (matches.next(), matches.next()) {
+        (Some(profile), None) => Ok(profile),
+        (Some(_), Some(_)) => Err(format!(
+            "Several connection profiles are named '{}'; pass its id instead",
+            key
+        )),
+        (None, _) => Err(format!("No connection profile named '{}'", key)),
+    }
+}
+
+/// Finds a saved query by id or, failing that, by exact name.
+fn find_saved_query<'a>(queries: &'a [SavedQuery], key: &str) -> Result<&'a SavedQuery, String> {
+    if let Ok(id) = key.parse::<Uuid>()
+        && let Some(query) = queries.iter().find(|query| query.id == id)
+    {
+        return Ok(query);
+    }
+
+    let mut matches = queries.iter().filter(|query| query.name == key);
+    match (matches.next(), matches.next()) {
+        (Some(query), None) => Ok(query),
+        (Some(_), Some(_)) => Err(format!(
+            "Several saved queries are named '{}'; pass its id instead",
+            key
+        )),
+        (None, _) => Err(format!("No saved query named '{}'", key)),
+    }
+}
+
+fn prepare_error_message(error: &PrepareConnectError, profile: &ConnectionProfile) -> String {
+    match error {
+        PrepareConnectError::ProfileNotFound => {
+            format!("Connection profile '{}' not found", profile.name)
+        }
+        PrepareConnectError::AlreadyConnected => format!("'{}' is already connected", profile.name),
+        PrepareConnectError::DriverNotRegistered { driver_id } => {
+            format!("No driver registered for '{}'", driver_id)
+        }
+        PrepareConnectError::ExternalDriverUnavailable {
+            driver_id,
+            socket_id,
+        } => format!(
+            "External driver '{}' did not start (socket '{}')",
+            driver_id, socket_id
+        ),
+    }
+}
+
+fn format_keys() -> String {
+    formats()
+        .iter()
+        .map(|format| format.key())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_export_help() {
+    eprintln!(
+        "Usage: dbflux export [--profile <name>] (--table <schema.table> | --saved-query <name>)"
+    );
+    eprintln!("                     --format <format> --output <path> [options]");
+    eprintln!();
+    eprintln!("Export a table or saved query using a DBFlux connection profile, without the GUI.");
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --profile <name>       Connection profile, by name or id");
+    eprintln!("                         (defaults to the saved query's connection)");
+    eprintln!("  --table <table>        Table to export, optionally schema-qualified");
+    eprintln!("  --saved-query <name>   Saved query to run, by name or id");
+    eprintln!("  --database <name>      Database to run against (default: the profile's)");
+    eprintln!("  --format <format>      One of: {}", format_keys());
+    eprintln!("  --output, -o <path>    File to write, or - for standard output");
+    eprintln!("  --help, -h             Show this help message");
+    eprintln!();
+    eprintln!("Saved query parameters use their default or last-used values.");
+    eprintln!();
+    eprintln!("Example:");
+    eprintln!(
+        "  dbflux export --profile prod --table public.orders --format parquet --output orders.parquet"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_table_export() {
+        let parsed = parse_export_args(&args(&[
+            "--profile",
+            "prod",
+            "--table",
+            "public.orders",
+            "--format",
+            "parquet",
+            "--output",
+            "orders.parquet",
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(parsed.profile.as_deref(), Some("prod"));
+        assert_eq!(
+            parsed.source,
+            ExportSource::Table(TableRef::with_schema("public", "orders"))
+        );
+        assert_eq!(parsed.format, ExportFormat::Parquet);
+        assert_eq!(parsed.output, Some(PathBuf::from("orders.parquet")));
+    }
+
+    #[test]
+    fn saved_query_may_omit_profile_and_write_to_stdout() {
+        let parsed = parse_export_args(&args(&[
+            "--saved-query",
+            "Daily revenue",
+            "--format",
+            "csv",
+            "-o",
+            "-",
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(parsed.profile, None);
+        assert_eq!(
+            parsed.source,
+            ExportSource::SavedQuery("Daily revenue".to_string())
+        );
+        assert_eq!(parsed.output, None);
+    }
+
+    #[test]
+    fn rejects_incomplete_or_conflicting_arguments() {
+        let base = ["--format", "csv", "--output", "out.csv"];
+        let with = |extra: &[&str]| {
+            let mut list = extra.to_vec();
+            list.extend(base);
+            parse_export_args(&args(&list))
+        };
+
+        assert!(with(&[]).is_err());
+        assert!(with(&["--table", "orders"]).is_err());
+        assert!(with(&["--profile", "p", "--table", "t", "--saved-query", "q"]).is_err());
+        assert!(with(&["--profile", "p", "--table", "t", "--bogus"]).is_err());
+        assert!(
+            parse_export_args(&args(&[
+                "--profile",
+                "p",
+                "--table",
+                "t",
+                "--format",
+                "xlsx"
+            ]))
+            .is_err()
+        );
+        assert!(parse_export_args(&args(&["--profile"])).is_err());
+        assert!(parse_export_args(&args(&["--help"])).unwrap().is_none());
+    }
+}
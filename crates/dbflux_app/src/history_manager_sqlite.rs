@@ -1,17 +1,28 @@
 //! Repository-backed history manager using `dbflux.db`.
 //!
-//! This replaces the JSON-based `HistoryStore` with SQLite-backed storage
-//! while preserving the same external interface.
+//! This replaces the JSON-based `HistoryStore` with SQLite-backed storage.
+//! Query history is not cached: the history panel pages through it with
+//! [`HistoryManager::history_page`], so the table can grow to tens of
+//! thousands of entries. Saved queries and recent files stay cached in memory.
+
+use std::collections::HashMap;
 
 use dbflux_core::chrono::Utc;
-use dbflux_core::{HistoryEntry, SavedQuery};
+use dbflux_core::{
+    CapturedPlan, HistoryEntry, HistoryFilter, SavedQuery, SavedQueryFolder, SavedQueryParam,
+    carry_saved_query_param_values, normalize_tags, plan_statement_key,
+};
 use dbflux_storage::bootstrap::StorageRuntime;
-use dbflux_storage::repositories::state::query_history::QueryHistoryRepository;
+use dbflux_storage::repositories::state::explain_plans::{ExplainPlanDto, ExplainPlansRepository};
+use dbflux_storage::repositories::state::query_history::{
+    QueryHistoryDto, QueryHistoryFilter, QueryHistoryRepository,
+};
 use dbflux_storage::repositories::state::recent_items::RecentItemDto;
 use dbflux_storage::repositories::state::recent_items::RecentItemsRepository;
-use dbflux_storage::repositories::state::saved_queries::SavedQueriesRepository;
+use dbflux_storage::repositories::state::saved_queries::{
+    SavedQueriesRepository, SavedQueryFolderDto,
+};
 use log::{error, info};
-use std::time::Duration;
 use uuid::Uuid;
 
 /// Re-export for backwards compatibility.
@@ -22,11 +33,15 @@ pub struct HistoryManager {
     history_repo: QueryHistoryRepository,
     saved_queries_repo: SavedQueriesRepository,
     recent_items_repo: RecentItemsRepository,
+    explain_plans_repo: ExplainPlansRepository,
     // Cached in-memory entries for fast reads
-    history_entries: Vec<HistoryEntry>,
     saved_queries: Vec<SavedQuery>,
+    saved_query_folders: Vec<SavedQueryFolder>,
     recent_files: Vec<RecentFile>,
-    max_history_entries: usize,
+    /// Entries older than this many days are pruned; 0 keeps them forever.
+    history_max_age_days: u32,
+    /// Fold re-runs of the newest entry into it instead of adding a new one.
+    collapse_duplicates: bool,
 }
 
 impl HistoryManager {
@@ -38,13 +53,12 @@ impl HistoryManager {
         let saved_queries_repo = runtime.saved_queries();
         let recent_items_repo = runtime.recent_items();
 
-        let history_entries = Self::load_history(&history_repo);
         let saved_queries = Self::load_saved_queries(&saved_queries_repo);
+        let saved_query_folders = Self::load_saved_query_folders(&saved_queries_repo);
         let recent_files = Self::load_recent_files(&recent_items_repo);
 
         info!(
-            "Loaded {} history entries, {} saved queries, {} recent files from dbflux.db",
-            history_entries.len(),
+            "Loaded {} saved queries, {} recent files from dbflux.db",
             saved_queries.len(),
             recent_files.len()
         );
@@ -53,51 +67,95 @@ impl HistoryManager {
             history_repo,
             saved_queries_repo,
             recent_items_repo,
-            history_entries,
+            explain_plans_repo: runtime.explain_plans(),
             saved_queries,
+            saved_query_folders,
             recent_files,
-            max_history_entries: 1000,
+            history_max_age_days: 0,
+            collapse_duplicates: true,
         }
     }
 
-    pub fn set_max_entries(&mut self, max: usize) {
-        self.max_history_entries = max.max(10);
+    /// Applies the history retention settings and prunes entries that are
+    /// already past `max_age_days`. Pinned and favorite entries are never
+    /// pruned.
+    ///
+    /// Age-based pruning runs here, at startup and whenever the settings
+    /// change, rather than on every recorded execution.
+    pub fn set_retention(&mut self, max_entries: usize, max_age_days: u32, collapse: bool) {
+        self.history_repo.set_max_entries(max_entries.max(10));
+        self.history_max_age_days = max_age_days;
+        self.collapse_duplicates = collapse;
+
+        self.prune_expired_history();
+    }
+
+    /// Returns one page of history entries matching `filter`, newest first.
+    pub fn history_page(
+        &self,
+        filter: &HistoryFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<HistoryEntry> {
+        match self
+            .history_repo
+            .page(&query_history_filter(filter), offset, limit)
+        {
+            Ok(entries) => entries.into_iter().map(history_entry_from_dto).collect(),
+            Err(e) => {
+                log::warn!("Failed to load history page from dbflux.db: {}", e);
+                Vec::new()
+            }
+        }
     }
 
-    pub fn entries(&self) -> &[HistoryEntry] {
-        &self.history_entries
+    /// Counts history entries matching `filter`.
+    pub fn history_count(&self, filter: &HistoryFilter) -> usize {
+        self.history_repo
+            .count(&query_history_filter(filter))
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to count history in dbflux.db: {}", e);
+                0
+            })
     }
 
+    /// Records an execution. With duplicate collapsing enabled, re-running
+    /// the newest entry's statement bumps its run count instead of adding a
+    /// row.
     pub fn add(&mut self, entry: HistoryEntry) {
         let dto = query_history_dto_from_entry(&entry);
-        self.history_entries.insert(0, entry);
 
-        const MAX_IN_MEMORY: usize = 500;
-        if self.history_entries.len() > MAX_IN_MEMORY {
-            self.history_entries.truncate(MAX_IN_MEMORY);
-        }
+        let collapsed = self.collapse_duplicates
+            && self
+                .history_repo
+                .collapse_into_latest(&dto)
+                .unwrap_or_else(|e| {
+                    error!("Failed to collapse history entry: {:?}", e);
+                    false
+                });
 
-        if let Err(e) = self.history_repo.add(&dto) {
+        if !collapsed && let Err(e) = self.history_repo.add(&dto) {
             error!("Failed to save history entry: {:?}", e);
         }
     }
 
     pub fn toggle_favorite(&mut self, id: Uuid) -> bool {
-        if let Some(entry) = self.history_entries.iter_mut().find(|e| e.id == id) {
-            entry.is_favorite = !entry.is_favorite;
-        }
+        self.history_repo
+            .toggle_favorite(&id.to_string())
+            .unwrap_or(false)
+    }
 
-        if let Ok(new_state) = self.history_repo.toggle_favorite(&id.to_string()) {
-            if let Some(entry) = self.history_entries.iter_mut().find(|e| e.id == id) {
-                entry.is_favorite = new_state;
-            }
-            return new_state;
-        }
-        false
+    /// Toggles whether an entry is exempt from pruning; returns the new state.
+    pub fn toggle_pinned(&mut self, id: Uuid) -> bool {
+        self.history_repo
+            .toggle_pinned(&id.to_string())
+            .unwrap_or_else(|e| {
+                error!("Failed to toggle history pin: {:?}", e);
+                false
+            })
     }
 
     pub fn remove(&mut self, id: Uuid) {
-        self.history_entries.retain(|e| e.id != id);
         if let Err(e) = self.history_repo.remove(&id.to_string()) {
             error!("Failed to remove history entry: {:?}", e);
         }
@@ -208,6 +266,115 @@ impl HistoryManager {
         &self.saved_queries
     }
 
+    /// Moves a saved query into `folder_id` (`None` for the top level) and
+    /// replaces its tags.
+    pub fn update_saved_query_organization(
+        &mut self,
+        id: Uuid,
+        folder_id: Option<Uuid>,
+        tags: Vec<String>,
+    ) -> bool {
+        if let Some(entry) = self.saved_queries.iter_mut().find(|e| e.id == id) {
+            entry.folder_id = folder_id;
+            entry.tags = normalize_tags(tags);
+
+            let dto = saved_query_dto_from_query(entry);
+            if let Err(e) = self.saved_queries_repo.update(&dto) {
+                error!("Failed to update saved query folder and tags: {:?}", e);
+                return false;
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Replaces the parameter declarations of a saved query. Parameters that
+    /// keep their name and type keep their last-used value.
+    pub fn update_saved_query_params(&mut self, id: Uuid, params: Vec<SavedQueryParam>) -> bool {
+        if let Some(entry) = self.saved_queries.iter_mut().find(|e| e.id == id) {
+            entry.params = carry_saved_query_param_values(params, &entry.params);
+
+            let dto = saved_query_dto_from_query(entry);
+            if let Err(e) = self.saved_queries_repo.update(&dto) {
+                error!("Failed to update saved query parameters: {:?}", e);
+                return false;
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Remembers the values a saved query was last run with so the next run
+    /// prompt starts from them, and marks the query as used.
+    pub fn record_saved_query_param_values(
+        &mut self,
+        id: Uuid,
+        values: &HashMap<String, String>,
+    ) -> bool {
+        if let Some(entry) = self.saved_queries.iter_mut().find(|e| e.id == id) {
+            for param in &mut entry.params {
+                if let Some(value) = values.get(&param.name) {
+                    param.last_value = Some(value.clone());
+                }
+            }
+            entry.last_used_at = Utc::now().timestamp();
+
+            let dto = saved_query_dto_from_query(entry);
+            if let Err(e) = self.saved_queries_repo.update(&dto) {
+                error!("Failed to record saved query parameter values: {:?}", e);
+                return false;
+            }
+            return true;
+        }
+        false
+    }
+
+    // --- Saved Query Folders ---
+
+    pub fn saved_query_folders_list(&self) -> &[SavedQueryFolder] {
+        &self.saved_query_folders
+    }
+
+    /// Resolves a `Parent/Child` folder path, creating the missing folders.
+    ///
+    /// Segments are matched case-insensitively against existing siblings.
+    /// Returns `None` for an empty path (the top level) or when a folder
+    /// could not be created.
+    pub fn ensure_saved_query_folder_path(&mut self, path: &str) -> Option<Uuid> {
+        let mut parent_id = None;
+
+        for segment in path.split('/').map(str::trim).filter(|s| !s.is_empty()) {
+            let existing = self.saved_query_folders.iter().find(|folder| {
+                folder.parent_id == parent_id && folder.name.eq_ignore_ascii_case(segment)
+            });
+
+            if let Some(folder) = existing {
+                parent_id = Some(folder.id);
+                continue;
+            }
+
+            let mut folder = SavedQueryFolder::new(segment.to_string(), parent_id);
+            folder.position = self
+                .saved_query_folders
+                .iter()
+                .filter(|f| f.parent_id == parent_id)
+                .count();
+
+            if let Err(e) = self
+                .saved_queries_repo
+                .create_folder(&saved_query_folder_dto(&folder))
+            {
+                error!("Failed to create saved query folder: {:?}", e);
+                return None;
+            }
+
+            parent_id = Some(folder.id);
+            self.saved_query_folders.push(folder);
+        }
+
+        parent_id
+    }
+
     // --- Recent Files (delegated) ---
 
     pub fn recent_files_entries(&self) -> &[RecentFile] {
@@ -252,35 +419,67 @@ impl HistoryManager {
         }
     }
 
-    // --- Helpers ---
+    // --- Execution plan history ---
 
-    fn load_history(repo: &QueryHistoryRepository) -> Vec<HistoryEntry> {
-        match repo.recent(500) {
-            Ok(entries) => entries
+    /// Adds an EXPLAIN plan to its statement's plan history.
+    pub fn add_captured_plan(&self, plan: &CapturedPlan) {
+        let plan_json = match serde_json::to_string(&plan.plan) {
+            Ok(json) => json,
+            Err(e) => {
+                error!("Failed to serialize execution plan: {:?}", e);
+                return;
+            }
+        };
+
+        let dto = ExplainPlanDto {
+            id: plan.id.to_string(),
+            connection_profile_id: plan.connection_profile_id.map(|id| id.to_string()),
+            statement: plan.statement.clone(),
+            captured_at: chrono_utc_to_rfc3339(plan.captured_at),
+            plan_json,
+        };
+
+        if let Err(e) = self.explain_plans_repo.add(&dto) {
+            error!("Failed to save execution plan: {:?}", e);
+        }
+    }
+
+    /// Plans captured for `statement` on a profile, newest first.
+    pub fn captured_plans(&self, profile_id: Option<Uuid>, statement: &str) -> Vec<CapturedPlan> {
+        let profile_id = profile_id.map(|id| id.to_string());
+
+        match self
+            .explain_plans_repo
+            .for_statement(profile_id.as_deref(), &plan_statement_key(statement))
+        {
+            Ok(dtos) => dtos
                 .into_iter()
-                .map(|dto| {
-                    let id = Uuid::parse_str(&dto.id).unwrap_or_else(|_| Uuid::new_v4());
-                    let duration = dto.duration_ms.map(|ms| Duration::from_millis(ms as u64));
-                    let timestamp = parse_rfc3339(&dto.executed_at);
-                    HistoryEntry {
-                        id,
-                        sql: dto.query_text,
-                        timestamp,
-                        database: dto.database_name,
-                        connection_name: dto.connection_profile_id,
-                        execution_time_ms: duration.map(|d| d.as_millis() as u64).unwrap_or(0),
-                        row_count: dto.row_count.map(|n| n as usize),
-                        is_favorite: dto.is_favorite,
-                    }
-                })
+                .filter_map(captured_plan_from_dto)
                 .collect(),
             Err(e) => {
-                log::warn!("Failed to load history from dbflux.db: {}", e);
+                log::warn!("Failed to load execution plans from dbflux.db: {}", e);
                 Vec::new()
             }
         }
     }
 
+    // --- Helpers ---
+
+    fn prune_expired_history(&self) {
+        if self.history_max_age_days == 0 {
+            return;
+        }
+
+        let cutoff = Utc::now().timestamp() - i64::from(self.history_max_age_days) * 86_400;
+
+        if let Err(e) = self
+            .history_repo
+            .prune_older_than(&chrono_utc_to_rfc3339(cutoff))
+        {
+            error!("Failed to prune expired history: {:?}", e);
+        }
+    }
+
     fn load_saved_queries(repo: &SavedQueriesRepository) -> Vec<SavedQuery> {
         match repo.all() {
             Ok(entries) => entries
@@ -301,6 +500,12 @@ impl HistoryManager {
                         connection_id,
                         created_at,
                         last_used_at,
+                        folder_id: dto
+                            .folder_id
+                            .as_deref()
+                            .and_then(|s| Uuid::parse_str(s).ok()),
+                        tags: dto.tags,
+                        params: dto.params,
                     }
                 })
                 .collect(),
@@ -311,6 +516,29 @@ impl HistoryManager {
         }
     }
 
+    fn load_saved_query_folders(repo: &SavedQueriesRepository) -> Vec<SavedQueryFolder> {
+        match repo.folders() {
+            Ok(folders) => folders
+                .into_iter()
+                .filter_map(|dto| {
+                    Some(SavedQueryFolder {
+                        id: Uuid::parse_str(&dto.id).ok()?,
+                        parent_id: dto
+                            .parent_id
+                            .as_deref()
+                            .and_then(|s| Uuid::parse_str(s).ok()),
+                        name: dto.name,
+                        position: dto.position,
+                    })
+                })
+                .collect(),
+            Err(e) => {
+                log::warn!("Failed to load saved query folders from dbflux.db: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
     fn load_recent_files(repo: &RecentItemsRepository) -> Vec<RecentFile> {
         match repo.all() {
             Ok(entries) => entries
@@ -352,22 +580,73 @@ fn chrono_utc_to_rfc3339(timestamp: i64) -> String {
     dbflux_core::chrono::DateTime::<Utc>::from(duration).to_rfc3339()
 }
 
-fn query_history_dto_from_entry(
-    entry: &HistoryEntry,
-) -> dbflux_storage::repositories::state::query_history::QueryHistoryDto {
-    dbflux_storage::repositories::state::query_history::QueryHistoryDto {
+fn query_history_dto_from_entry(entry: &HistoryEntry) -> QueryHistoryDto {
+    QueryHistoryDto {
         id: entry.id.to_string(),
-        connection_profile_id: None,
+        connection_profile_id: entry.connection_profile_id.map(|id| id.to_string()),
         driver_id: None,
         database_name: entry.database.clone(),
         query_text: entry.sql.clone(),
         query_kind: "select".to_string(),
-        executed_at: Utc::now().to_rfc3339(),
+        executed_at: chrono_utc_to_rfc3339(entry.timestamp),
         duration_ms: Some(entry.execution_time_ms as i64),
-        succeeded: true,
-        error_summary: None,
+        succeeded: entry.succeeded,
+        error_summary: entry.error.clone(),
         row_count: entry.row_count.map(|n| n as i64),
         is_favorite: entry.is_favorite,
+        is_pinned: entry.is_pinned,
+        run_count: i64::from(entry.run_count),
+        schema_name: entry.schema.clone(),
+    }
+}
+
+/// The connection name is not stored; callers resolve it from the profile id.
+fn history_entry_from_dto(dto: QueryHistoryDto) -> HistoryEntry {
+    HistoryEntry {
+        id: Uuid::parse_str(&dto.id).unwrap_or_else(|_| Uuid::new_v4()),
+        sql: dto.query_text,
+        timestamp: parse_rfc3339(&dto.executed_at),
+        database: dto.database_name,
+        connection_name: None,
+        execution_time_ms: dto.duration_ms.map(|ms| ms.max(0) as u64).unwrap_or(0),
+        row_count: dto.row_count.map(|n| n as usize),
+        is_favorite: dto.is_favorite,
+        connection_profile_id: dto
+            .connection_profile_id
+            .as_deref()
+            .and_then(|id| Uuid::parse_str(id).ok()),
+        succeeded: dto.succeeded,
+        error: dto.error_summary,
+        is_pinned: dto.is_pinned,
+        run_count: dto.run_count.clamp(1, u32::MAX as i64) as u32,
+        schema: dto.schema_name,
+    }
+}
+
+/// Rows whose plan no longer deserializes are skipped.
+fn captured_plan_from_dto(dto: ExplainPlanDto) -> Option<CapturedPlan> {
+    Some(CapturedPlan {
+        id: Uuid::parse_str(&dto.id).unwrap_or_else(|_| Uuid::new_v4()),
+        connection_profile_id: dto
+            .connection_profile_id
+            .as_deref()
+            .and_then(|id| Uuid::parse_str(id).ok()),
+        statement: dto.statement,
+        captured_at: parse_rfc3339(&dto.captured_at),
+        plan: serde_json::from_str(&dto.plan_json).ok()?,
+    })
+}
+
+fn query_history_filter(filter: &HistoryFilter) -> QueryHistoryFilter {
+    let text = filter.text.trim();
+
+    QueryHistoryFilter {
+        text: (!text.is_empty()).then(|| text.to_string()),
+        connection_profile_id: filter.connection_profile_id.map(|id| id.to_string()),
+        database_name: filter.database.clone(),
+        executed_after: filter.since.map(chrono_utc_to_rfc3339),
+        executed_before: filter.until.map(chrono_utc_to_rfc3339),
+        succeeded: filter.succeeded,
     }
 }
 
@@ -376,12 +655,27 @@ fn saved_query_dto_from_query(
 ) -> dbflux_storage::repositories::state::saved_queries::SavedQueryDto {
     dbflux_storage::repositories::state::saved_queries::SavedQueryDto {
         id: query.id.to_string(),
-        folder_id: None,
+        folder_id: query.folder_id.map(|u| u.to_string()),
         name: query.name.clone(),
         sql: query.sql.clone(),
         is_favorite: query.is_favorite,
         connection_id: query.connection_id.map(|u| u.to_string()),
         created_at: chrono_utc_to_rfc3339(query.created_at),
         last_used_at: chrono_utc_to_rfc3339(query.last_used_at),
+        tags: query.tags.clone(),
+        params: query.params.clone(),
+    }
+}
+
+fn saved_query_folder_dto(folder: &SavedQueryFolder) -> SavedQueryFolderDto {
+    let now = Utc::now().to_rfc3339();
+
+    SavedQueryFolderDto {
+        id: folder.id.to_string(),
+        parent_id: folder.parent_id.map(|u| u.to_string()),
+        name: folder.name.clone(),
+        position: folder.position,
+        created_at: now.clone(),
+        updated_at: now,
     }
 }
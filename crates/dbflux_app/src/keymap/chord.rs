@@ -80,6 +80,14 @@ impl Modifiers {
         }
     }
 
+    /// Primary + Alt: Cmd+Option on macOS, Ctrl+Alt elsewhere.
+    pub fn primary_alt() -> Self {
+        Self {
+            alt: true,
+            ..Self::primary()
+        }
+    }
+
     #[allow(dead_code)]
     pub fn has_any(&self) -> bool {
         self.ctrl || self.alt || self.shift || self.platform
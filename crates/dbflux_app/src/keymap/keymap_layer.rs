@@ -6,6 +6,9 @@ use super::{Command, ContextId, KeyChord};
 pub struct KeymapLayer {
     context: ContextId,
     bindings: HashMap<KeyChord, Command>,
+    /// Multi-key bindings such as `g g`, keyed by their full chord sequence
+    /// (always two chords or more).
+    sequences: HashMap<Vec<KeyChord>, Command>,
 }
 
 impl KeymapLayer {
@@ -13,6 +16,7 @@ impl KeymapLayer {
         Self {
             context,
             bindings: HashMap::new(),
+            sequences: HashMap::new(),
         }
     }
 
@@ -20,6 +24,38 @@ impl KeymapLayer {
         self.bindings.insert(chord, command);
     }
 
+    /// Binds a chord sequence; a single chord is bound as with [`Self::bind`].
+    pub fn bind_sequence(&mut self, keys: Vec<KeyChord>, command: Command) {
+        match <[KeyChord; 1]>::try_from(keys) {
+            Ok([chord]) => self.bind(chord, command),
+            Err(keys) if !keys.is_empty() => {
+                self.sequences.insert(keys, command);
+            }
+            Err(_) => {}
+        }
+    }
+
+    /// Removes the binding of a chord or chord sequence, returning the command
+    /// it had.
+    pub fn unbind(&mut self, keys: &[KeyChord]) -> Option<Command> {
+        match keys {
+            [chord] => self.bindings.remove(chord),
+            _ => self.sequences.remove(keys),
+        }
+    }
+
+    /// Command bound to a chord or chord sequence in this layer.
+    pub fn get_sequence(&self, keys: &[KeyChord]) -> Option<Command> {
+        match keys {
+            [chord] => self.get(chord),
+            _ => self.sequences.get(keys).copied(),
+        }
+    }
+
+    pub fn sequences(&self) -> &HashMap<Vec<KeyChord>, Command> {
+        &self.sequences
+    }
+
     pub fn get(&self, chord: &KeyChord) -> Option<Command> {
         self.bindings.get(chord).copied()
     }
@@ -35,6 +71,18 @@ impl KeymapLayer {
     }
 }
 
+/// Result of looking up the keys typed so far, see
+/// [`KeymapStack::resolve_sequence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceMatch {
+    /// The keys complete a binding.
+    Command(Command),
+    /// The keys start one or more longer sequences; wait for the next chord.
+    Prefix,
+    /// Nothing is bound to these keys.
+    None,
+}
+
 /// Manages keybindings across all contexts with hierarchical resolution.
 ///
 /// When resolving a key chord, the stack first checks the current context,
@@ -72,6 +120,79 @@ impl KeymapStack {
         None
     }
 
+    /// Mutable access to the layer of a context, creating an empty one if
+    /// the context has none yet.
+    pub fn layer_mut(&mut self, context: ContextId) -> &mut KeymapLayer {
+        self.layers
+            .entry(context)
+            .or_insert_with(|| KeymapLayer::new(context))
+    }
+
+    pub fn layer(&self, context: ContextId) -> Option<&KeymapLayer> {
+        self.layers.get(&context)
+    }
+
+    /// Looks up the chords typed so far. A sequence prefix in the current
+    /// context wins over a single-chord binding inherited from a parent, so
+    /// `space d t` in the sidebar still works when Global binds `space`.
+    pub fn resolve_sequence(&self, context: ContextId, keys: &[KeyChord]) -> SequenceMatch {
+        let mut current = Some(context);
+
+        while let Some(ctx) = current {
+            if let Some(layer) = self.layers.get(&ctx) {
+                if let Some(cmd) = layer.get_sequence(keys) {
+                    return SequenceMatch::Command(cmd);
+                }
+
+                if layer
+                    .sequences
+                    .keys()
+                    .any(|seq| seq.len() > keys.len() && seq.starts_with(keys))
+                {
+                    return SequenceMatch::Prefix;
+                }
+            }
+            current = ctx.parent();
+        }
+
+        SequenceMatch::None
+    }
+
+    /// Sequences that continue `prefix` in a context, including inherited
+    /// ones, as the remaining chords and their command. Sorted for display.
+    pub fn continuations(
+        &self,
+        context: ContextId,
+        prefix: &[KeyChord],
+    ) -> Vec<(Vec<KeyChord>, Command)> {
+        let mut result: Vec<(Vec<KeyChord>, Command)> = Vec::new();
+        let mut current = Some(context);
+
+        while let Some(ctx) = current {
+            if let Some(layer) = self.layers.get(&ctx) {
+                for (seq, cmd) in &layer.sequences {
+                    if seq.len() <= prefix.len() || !seq.starts_with(prefix) {
+                        continue;
+                    }
+
+                    let rest = seq[prefix.len()..].to_vec();
+                    if !result.iter().any(|(seen, _)| *seen == rest) {
+                        result.push((rest, *cmd));
+                    }
+                }
+            }
+            current = ctx.parent();
+        }
+
+        result.sort_by_key(|(rest, _)| {
+            rest.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        result
+    }
+
     /// Returns all keybindings for a given context, including inherited ones.
     #[allow(dead_code)]
     pub fn bindings_for_context(&self, context: ContextId) -> Vec<(KeyChord, Command, ContextId)> {
@@ -96,14 +217,36 @@ impl KeymapStack {
     /// Returns the shortcut string for a command in the given context, if any.
     #[allow(dead_code)]
     pub fn shortcut_for_command(&self, context: ContextId, command: Command) -> Option<String> {
+        self.keys_for_command(context, command)
+            .map(|keys| super::format_sequence(&keys))
+    }
+
+    /// Keys bound to a command in the given context or its parents, nearest
+    /// context first. A single chord is preferred over a sequence; among
+    /// several, the shortest label wins so the choice is stable.
+    pub fn keys_for_command(&self, context: ContextId, command: Command) -> Option<Vec<KeyChord>> {
         let mut current = Some(context);
 
         while let Some(ctx) = current {
             if let Some(layer) = self.layers.get(&ctx) {
-                for (chord, cmd) in layer.bindings() {
-                    if *cmd == command {
-                        return Some(chord.to_string());
-                    }
+                let chords = layer
+                    .bindings
+                    .iter()
+                    .filter(|(_, cmd)| **cmd == command)
+                    .map(|(chord, _)| vec![chord.clone()]);
+                let sequences = layer
+                    .sequences
+                    .iter()
+                    .filter(|(_, cmd)| **cmd == command)
+                    .map(|(keys, _)| keys.clone());
+
+                let best = chords.chain(sequences).min_by_key(|keys| {
+                    let label = super::format_sequence(keys);
+                    (keys.len(), label.len(), label)
+                });
+
+                if best.is_some() {
+                    return best;
                 }
             }
             current = ctx.parent();
@@ -172,6 +315,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_sequence_prefix_and_command() {
+        let mut stack = KeymapStack::new();
+
+        let g = KeyChord::new("g", Modifiers::none());
+        let d = KeyChord::new("d", Modifiers::none());
+
+        let mut sidebar = KeymapLayer::new(ContextId::Sidebar);
+        sidebar.bind_sequence(vec![g.clone(), g.clone()], Command::SelectFirst);
+        sidebar.bind_sequence(vec![g.clone(), d.clone()], Command::FocusEditor);
+        stack.add_layer(sidebar);
+
+        assert_eq!(
+            stack.resolve_sequence(ContextId::Sidebar, &[g.clone()]),
+            SequenceMatch::Prefix
+        );
+        assert_eq!(
+            stack.resolve_sequence(ContextId::Sidebar, &[g.clone(), g.clone()]),
+            SequenceMatch::Command(Command::SelectFirst)
+        );
+        assert_eq!(
+            stack.resolve_sequence(ContextId::Sidebar, &[g.clone(), g.clone(), g.clone()]),
+            SequenceMatch::None
+        );
+        assert_eq!(
+            stack.resolve_sequence(ContextId::Editor, &[g.clone()]),
+            SequenceMatch::None
+        );
+
+        let next: Vec<Command> = stack
+            .continuations(ContextId::Sidebar, &[g])
+            .into_iter()
+            .map(|(_, cmd)| cmd)
+            .collect();
+        assert_eq!(next, vec![Command::FocusEditor, Command::SelectFirst]);
+    }
+
+    #[test]
+    fn test_keys_for_command_prefers_single_chords() {
+        let mut global = KeymapLayer::new(ContextId::Global);
+        global.bind_sequence(
+            vec![
+                KeyChord::new("g", Modifiers::none()),
+                KeyChord::new("e", Modifiers::none()),
+            ],
+            Command::FocusEditor,
+        );
+        global.bind(
+            KeyChord::new("2", Modifiers::ctrl_shift()),
+            Command::FocusEditor,
+        );
+        global.bind(KeyChord::new("e", Modifiers::alt()), Command::FocusEditor);
+        global.bind_sequence(
+            vec![
+                KeyChord::new("g", Modifiers::none()),
+                KeyChord::new("s", Modifiers::none()),
+            ],
+            Command::FocusSidebar,
+        );
+
+        let mut stack = KeymapStack::new();
+        stack.add_layer(global);
+
+        assert_eq!(
+            stack.shortcut_for_command(ContextId::Sidebar, Command::FocusEditor),
+            Some("Alt+e".to_string())
+        );
+        assert_eq!(
+            stack.shortcut_for_command(ContextId::Sidebar, Command::FocusSidebar),
+            Some("g s".to_string())
+        );
+        assert_eq!(
+            stack.keys_for_command(ContextId::Global, Command::SelectFirst),
+            None
+        );
+    }
+
+    #[test]
+    fn test_single_chord_sequence_binds_a_chord() {
+        let mut layer = KeymapLayer::new(ContextId::Global);
+        let chord = KeyChord::new("k", Modifiers::ctrl());
+
+        layer.bind_sequence(vec![chord.clone()], Command::QuickOpen);
+
+        assert_eq!(layer.get(&chord), Some(Command::QuickOpen));
+        assert!(layer.sequences().is_empty());
+        assert_eq!(layer.unbind(&[chord.clone()]), Some(Command::QuickOpen));
+        assert_eq!(layer.get(&chord), None);
+    }
+
     #[test]
     fn test_modal_no_fallback() {
         let mut stack = KeymapStack::new();
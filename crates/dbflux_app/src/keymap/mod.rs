@@ -5,8 +5,13 @@
 mod chord;
 mod focus;
 mod keymap_layer;
+mod user_keymap;
 
 pub use chord::{KeyChord, Modifiers, ParseError};
 pub use dbflux_core::keymap_types::{Command, ContextId};
 pub use focus::FocusTarget;
-pub use keymap_layer::{KeymapLayer, KeymapStack};
+pub use keymap_layer::{KeymapLayer, KeymapStack, SequenceMatch};
+pub use user_keymap::{
+    ConflictKind, KeymapConflict, UserBinding, UserKeymap, UserKeymapReport, format_sequence,
+    load_user_keymap, parse_sequence,
+};
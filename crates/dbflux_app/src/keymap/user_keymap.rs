@@ -0,0 +1,498 @@
+//! User keymap file: overrides of the default bindings, loaded at startup.
+//!
+//! The file is JSON with a list of bindings:
+//!
+//! ```json
+//! {
+//!   "bindings": [
+//!     { "keys": "space d t", "command": "Toggle Tasks Panel" },
+//!     { "keys": "ctrl+k ctrl+s", "command": "toggle_sidebar" },
+//!     { "keys": "ctrl+shift+t", "command": null }
+//!   ]
+//! }
+//! ```
+//!
+//! `keys` is one chord or a space-separated sequence of chords. `command` is
+//! a command palette id or a command's display name; `null` removes the
+//! binding. `context` defaults to Global.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{Command, ContextId, KeyChord, KeymapStack};
+
+/// A binding read from the user keymap file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserBinding {
+    pub context: ContextId,
+    pub keys: Vec<KeyChord>,
+    /// `None` removes whatever is bound to `keys`.
+    pub command: Option<Command>,
+}
+
+/// Parsed user keymap. Invalid entries are skipped and reported in `errors`.
+#[derive(Debug, Clone, Default)]
+pub struct UserKeymap {
+    pub bindings: Vec<UserBinding>,
+    pub errors: Vec<String>,
+}
+
+/// A user binding that clashes with another binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConflict {
+    pub context: ContextId,
+    pub keys: Vec<KeyChord>,
+    pub kind: ConflictKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictKind {
+    /// Replaces a default binding of another command.
+    ReplacesDefault { default: Command, command: Command },
+    /// Bound more than once in the file; the last entry wins.
+    Duplicate { first: Command, last: Command },
+    /// Can never be typed: a shorter prefix of it is bound to a command.
+    ShadowedByPrefix {
+        prefix: Vec<KeyChord>,
+        command: Command,
+    },
+}
+
+impl KeymapConflict {
+    pub fn describe(&self) -> String {
+        let keys = format_sequence(&self.keys);
+        let context = self.context.display_name();
+
+        match &self.kind {
+            ConflictKind::ReplacesDefault { default, command } => format!(
+                "{keys} ({context}): \"{}\" replaces the default \"{}\"",
+                command.display_name(),
+                default.display_name()
+            ),
+            ConflictKind::Duplicate { first, last } => format!(
+                "{keys} ({context}): bound to both \"{}\" and \"{}\"; the last one wins",
+                first.display_name(),
+                last.display_name()
+            ),
+            ConflictKind::ShadowedByPrefix { prefix, command } => format!(
+                "{keys} ({context}): unreachable, {} already runs \"{}\"",
+                format_sequence(prefix),
+                command.display_name()
+            ),
+        }
+    }
+}
+
+/// Outcome of loading the user keymap file, shown in settings.
+#[derive(Debug, Clone, Default)]
+pub struct UserKeymapReport {
+    pub path: Option<PathBuf>,
+    /// Whether the file exists.
+    pub found: bool,
+    pub errors: Vec<String>,
+    pub conflicts: Vec<KeymapConflict>,
+}
+
+/// Parses a key sequence such as `"space d t"` or `"Ctrl+K Ctrl+S"`.
+pub fn parse_sequence(s: &str) -> Result<Vec<KeyChord>, String> {
+    let keys = s
+        .split_whitespace()
+        .map(|part| KeyChord::parse(part).map_err(|e| format!("\"{part}\": {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if keys.is_empty() {
+        return Err("empty key sequence".to_string());
+    }
+
+    Ok(keys)
+}
+
+/// Formats a key sequence the way [`parse_sequence`] reads it.
+pub fn format_sequence(keys: &[KeyChord]) -> String {
+    keys.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl UserKeymap {
+    /// Parses the keymap file. `lookup_command` resolves the `command` field.
+    ///
+    /// Fails only when the file is not a JSON object with a `bindings` list;
+    /// bad entries are collected in `errors`.
+    pub fn parse(
+        content: &str,
+        lookup_command: impl Fn(&str) -> Option<Command>,
+    ) -> Result<Self, String> {
+        let root: Value =
+            serde_json::from_str(content).map_err(|e| format!("Invalid keymap file: {e}"))?;
+
+        let entries = root
+            .get("bindings")
+            .and_then(Value::as_array)
+            .ok_or_else(|| "Invalid keymap file: expected a \"bindings\" list".to_string())?;
+
+        let mut keymap = Self::default();
+
+        for (idx, entry) in entries.iter().enumerate() {
+            match parse_binding(entry, &lookup_command) {
+                Ok(binding) => keymap.bindings.push(binding),
+                Err(error) => keymap.errors.push(format!("Binding {}: {error}", idx + 1)),
+            }
+        }
+
+        Ok(keymap)
+    }
+
+    /// Applies the bindings on top of `stack`, in file order.
+    pub fn apply(&self, stack: &mut KeymapStack) -> Vec<KeymapConflict> {
+        let mut conflicts = Vec::new();
+        let mut seen: HashSet<(ContextId, Vec<KeyChord>)> = HashSet::new();
+
+        for binding in &self.bindings {
+            let layer = stack.layer_mut(binding.context);
+            let key = (binding.context, binding.keys.clone());
+            let previous = layer.get_sequence(&binding.keys);
+
+            match (seen.contains(&key), previous, binding.command) {
+                (true, Some(first), Some(last)) if first != last => {
+                    conflicts.push(KeymapConflict {
+                        context: binding.context,
+                        keys: binding.keys.clone(),
+                        kind: ConflictKind::Duplicate { first, last },
+                    });
+                }
+                (false, Some(default), Some(command)) if default != command => {
+                    conflicts.push(KeymapConflict {
+                        context: binding.context,
+                        keys: binding.keys.clone(),
+                        kind: ConflictKind::ReplacesDefault { default, command },
+                    });
+                }
+                _ => {}
+            }
+
+            match binding.command {
+                Some(command) => layer.bind_sequence(binding.keys.clone(), command),
+                None => {
+                    layer.unbind(&binding.keys);
+                }
+            }
+
+            seen.insert(key);
+        }
+
+        let mut reported = HashSet::new();
+        for binding in &self.bindings {
+            if binding.command.is_none() || !reported.insert((binding.context, &binding.keys)) {
+                continue;
+            }
+
+            let Some(layer) = stack.layer(binding.context) else {
+                continue;
+            };
+
+            // Removed again by a later entry.
+            if layer.get_sequence(&binding.keys).is_none() {
+                continue;
+            }
+
+            let shadow = (1..binding.keys.len()).find_map(|len| {
+                let prefix = &binding.keys[..len];
+                layer
+                    .get_sequence(prefix)
+                    .map(|command| (prefix.to_vec(), command))
+            });
+
+            if let Some((prefix, command)) = shadow {
+                conflicts.push(KeymapConflict {
+                    context: binding.context,
+                    keys: binding.keys.clone(),
+                    kind: ConflictKind::ShadowedByPrefix { prefix, command },
+                });
+            }
+        }
+
+        conflicts
+    }
+}
+
+/// Loads the keymap file at `path` on top of `stack`. A missing file leaves
+/// the stack untouched.
+///
+/// Commands are looked up by palette id first, then by display name among
+/// the commands `stack` binds.
+pub fn load_user_keymap(path: &Path, stack: &mut KeymapStack) -> UserKeymapReport {
+    let mut report = UserKeymapReport {
+        path: Some(path.to_path_buf()),
+        ..Default::default()
+    };
+
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return report,
+        Err(e) => {
+            report.found = true;
+            report
+                .errors
+                .push(format!("Failed to read keymap file: {e}"));
+            return report;
+        }
+    };
+    report.found = true;
+
+    let names = command_names(stack);
+    let lookup = |name: &str| {
+        Command::from_palette_id(name).or_else(|| match names.get(&normalize_name(name)) {
+            Some(commands) if commands.len() == 1 => Some(commands[0]),
+            _ => None,
+        })
+    };
+
+    match UserKeymap::parse(&content, lookup) {
+        Ok(keymap) => {
+            report.conflicts = keymap.apply(stack);
+            report.errors = keymap.errors;
+        }
+        Err(error) => report.errors.push(error),
+    }
+
+    report
+}
+
+fn parse_binding(
+    entry: &Value,
+    lookup_command: &impl Fn(&str) -> Option<Command>,
+) -> Result<UserBinding, String> {
+    let keys = entry
+        .get("keys")
+        .and_then(Value::as_str)
+        .ok_or("missing \"keys\"")?;
+    let keys = parse_sequence(keys)?;
+
+    let context = match entry.get("context") {
+        None | Some(Value::Null) => ContextId::Global,
+        Some(Value::String(name)) => {
+            parse_context(name).ok_or_else(|| format!("unknown context \"{name}\""))?
+        }
+        Some(_) => return Err("\"context\" must be a string".to_string()),
+    };
+
+    let command = match entry.get("command") {
+        Some(Value::Null) => None,
+        Some(Value::String(name)) => Some(
+            lookup_command(name)
+                .ok_or_else(|| format!("unknown or ambiguous command \"{name}\""))?,
+        ),
+        Some(_) => return Err("\"command\" must be a string or null".to_string()),
+        None => return Err("missing \"command\"".to_string()),
+    };
+
+    Ok(UserBinding {
+        context,
+        keys,
+        command,
+    })
+}
+
+fn parse_context(name: &str) -> Option<ContextId> {
+    let name = normalize_name(name);
+
+    ContextId::all_variants().iter().copied().find(|context| {
+        normalize_name(context.display_name()) == name
+            || normalize_name(context.as_gpui_context()) == name
+    })
+}
+
+/// Commands bound anywhere in `stack`, by normalized display name. Several
+/// commands can share a name (e.g. the "Switch to Tab" variants).
+fn command_names(stack: &KeymapStack) -> HashMap<String, Vec<Command>> {
+    let mut names: HashMap<String, Vec<Command>> = HashMap::new();
+
+    for context in ContextId::all_variants() {
+        let Some(layer) = stack.layer(*context) else {
+            continue;
+        };
+
+        let commands = layer.bindings().values().chain(layer.sequences().values());
+
+        for command in commands {
+            let entry = names
+                .entry(normalize_name(command.display_name()))
+                .or_default();
+            if !entry.contains(command) {
+                entry.push(*command);
+            }
+        }
+    }
+
+    names
+}
+
+/// Lowercase letters and digits only, so "Toggle Tasks Panel",
+/// "toggle-tasks-panel" and "toggle_tasks_panel" compare equal.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::{KeymapLayer, Modifiers, SequenceMatch};
+
+    fn chord(s: &str) -> KeyChord {
+        KeyChord::parse(s).unwrap()
+    }
+
+    fn lookup(name: &str) -> Option<Command> {
+        match name {
+            "select_first" => Some(Command::SelectFirst),
+            "focus_editor" => Some(Command::FocusEditor),
+            "toggle_command_palette" => Some(Command::ToggleCommandPalette),
+            _ => None,
+        }
+    }
+
+    fn base_stack() -> KeymapStack {
+        let mut global = KeymapLayer::new(ContextId::Global);
+        global.bind(
+            KeyChord::new("p", Modifiers::ctrl_shift()),
+            Command::ToggleCommandPalette,
+        );
+
+        let mut stack = KeymapStack::new();
+        stack.add_layer(global);
+        stack
+    }
+
+    #[test]
+    fn parses_sequences_contexts_and_unbinds() {
+        let json = r#"{
+            "bindings": [
+                { "keys": "g g", "command": "select_first", "context": "sidebar" },
+                { "keys": "space d e", "command": "focus_editor" },
+                { "keys": "ctrl+shift+p", "command": null }
+            ]
+        }"#;
+
+        let keymap = UserKeymap::parse(json, lookup).unwrap();
+
+        assert!(keymap.errors.is_empty());
+        assert_eq!(
+            keymap.bindings,
+            vec![
+                UserBinding {
+                    context: ContextId::Sidebar,
+                    keys: vec![chord("g"), chord("g")],
+                    command: Some(Command::SelectFirst),
+                },
+                UserBinding {
+                    context: ContextId::Global,
+                    keys: vec![chord("space"), chord("d"), chord("e")],
+                    command: Some(Command::FocusEditor),
+                },
+                UserBinding {
+                    context: ContextId::Global,
+                    keys: vec![chord("ctrl+shift+p")],
+                    command: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_entries_are_reported_and_skipped() {
+        let json = r#"{
+            "bindings": [
+                { "keys": "g g", "command": "no_such_command" },
+                { "keys": "hyper+x", "command": "focus_editor" },
+                { "keys": "x", "command": "focus_editor", "context": "nowhere" },
+                { "keys": "z", "command": "focus_editor" }
+            ]
+        }"#;
+
+        let keymap = UserKeymap::parse(json, lookup).unwrap();
+
+        assert_eq!(keymap.bindings.len(), 1);
+        assert_eq!(keymap.errors.len(), 3);
+        assert!(keymap.errors[0].starts_with("Binding 1:"));
+        assert!(UserKeymap::parse("[]", lookup).is_err());
+    }
+
+    #[test]
+    fn apply_overrides_defaults_and_reports_conflicts() {
+        let json = r#"{
+            "bindings": [
+                { "keys": "ctrl+shift+p", "command": "focus_editor" },
+                { "keys": "space", "command": "select_first" },
+                { "keys": "space e", "command": "focus_editor" },
+                { "keys": "g g", "command": "select_first" },
+                { "keys": "g g", "command": "focus_editor" }
+            ]
+        }"#;
+
+        let keymap = UserKeymap::parse(json, lookup).unwrap();
+        let mut stack = base_stack();
+        let conflicts = keymap.apply(&mut stack);
+
+        let kinds: Vec<&ConflictKind> = conflicts.iter().map(|c| &c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &ConflictKind::ReplacesDefault {
+                    default: Command::ToggleCommandPalette,
+                    command: Command::FocusEditor,
+                },
+                &ConflictKind::Duplicate {
+                    first: Command::SelectFirst,
+                    last: Command::FocusEditor,
+                },
+                &ConflictKind::ShadowedByPrefix {
+                    prefix: vec![chord("space")],
+                    command: Command::SelectFirst,
+                },
+            ]
+        );
+
+        assert_eq!(
+            stack.resolve(ContextId::Global, &chord("ctrl+shift+p")),
+            Some(Command::FocusEditor)
+        );
+        assert_eq!(
+            stack.resolve_sequence(ContextId::Sidebar, &[chord("g"), chord("g")]),
+            SequenceMatch::Command(Command::FocusEditor)
+        );
+    }
+
+    #[test]
+    fn load_looks_up_commands_by_display_name() {
+        let dir = std::env::temp_dir().join(format!("dbflux-keymap-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keymap.json");
+        std::fs::write(
+            &path,
+            r#"{ "bindings": [{ "keys": "space p", "command": "Toggle Command Palette" }] }"#,
+        )
+        .unwrap();
+
+        let mut stack = base_stack();
+        let report = load_user_keymap(&path, &mut stack);
+
+        assert!(report.found);
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert_eq!(
+            stack.resolve_sequence(ContextId::Global, &[chord("space"), chord("p")]),
+            SequenceMatch::Command(Command::ToggleCommandPalette)
+        );
+
+        let missing = load_user_keymap(&dir.join("none.json"), &mut base_stack());
+        assert!(!missing.found);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
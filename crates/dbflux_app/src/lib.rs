@@ -8,6 +8,8 @@ pub mod app_state;
 pub mod auth_provider_registry;
 pub mod aws_config_reflect_migration;
 pub mod config_loader;
+pub mod driver_manifests;
+pub mod export_command;
 pub mod history_manager_sqlite;
 pub mod hook_executor;
 pub mod keymap;
@@ -24,4 +26,6 @@ pub use auth_provider_registry::{AuthProviderRegistry, RegistryAuthProviderWrapp
 pub use hook_executor::CompositeExecutor;
 pub use metric_catalog_cache::{MetricCatalogCache, MetricsPageView};
 pub use remote_dashboard_cache::RemoteDashboardCache;
-pub use rpc_services::{ExternalDriverDiagnostic, ExternalDriverStage};
+pub use rpc_services::{
+    ExternalDriverDiagnostic, ExternalDriverStage, ProbedRpcDrivers, RpcDriverReload,
+};
@@ -1,17 +1,20 @@
 pub(crate) mod external_audit;
 
 use dbflux_core::{
-    DbDriver, DbError, DbKind, DriverFormDef, DriverMetadata, RpcServiceKind, ServiceConfig,
-    ServiceRpcApiContract,
+    DbDriver, DbError, DbKind, DriverFormDef, DriverManifest, DriverMetadata, RemoteServiceConfig,
+    RpcServiceKind, ServiceConfig, ServiceRpcApiContract,
 };
 use dbflux_driver_ipc::{IpcDriver, driver::IpcDriverLaunchConfig};
 use dbflux_ipc::{
     AUTH_PROVIDER_RPC_API_CONTRACT, ExternalAuditEmitter, IpcServiceLaunchConfig, RpcAuthProvider,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use dbflux_core::auth::DynAuthProvider;
 
+use crate::driver_manifests::DriverManifestCatalog;
+
 pub(crate) type DriverProbe = (DbKind, DriverMetadata, DriverFormDef, Option<DriverFormDef>);
 
 #[derive(Clone, Debug)]
@@ -124,10 +127,228 @@ pub(crate) enum AuthProviderServiceAdaptation<T> {
     },
 }
 
+/// A driver service to register: configured in the settings, or backed by a
+/// driver manifest whose overrides apply on top of the handshake.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DriverService {
+    pub(crate) config: ServiceConfig,
+    pub(crate) manifest: Option<DriverManifest>,
+}
+
+/// The driver services among `services` followed by those of the enabled and
+/// disabled manifests. A manifest whose socket is already configured in the
+/// settings is skipped.
+pub(crate) fn driver_services(
+    services: &[ServiceConfig],
+    manifests: &DriverManifestCatalog,
+) -> Vec<DriverService> {
+    let mut driver_services: Vec<DriverService> = services
+        .iter()
+        .filter(|service| service.kind == RpcServiceKind::Driver)
+        .map(|service| DriverService {
+            config: service.clone(),
+            manifest: None,
+        })
+        .collect();
+
+    for manifest in manifests.manifests() {
+        if driver_services
+            .iter()
+            .any(|service| service.config.socket_id == manifest.socket)
+        {
+            log::warn!(
+                "Skipping driver manifest '{}': socket '{}' is already configured as an RPC service",
+                manifest.name,
+                manifest.socket
+            );
+            continue;
+        }
+
+        driver_services.push(DriverService {
+            config: manifest.service_config(),
+            manifest: Some(manifest.clone()),
+        });
+    }
+
+    driver_services
+}
+
+/// Driver services whose configuration differs from the applied one.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct DriverServiceChanges {
+    /// Socket ids whose driver must be unregistered: removed, disabled or
+    /// edited services.
+    pub(crate) removed: Vec<String>,
+    /// New or edited services to discover and probe.
+    pub(crate) added: Vec<DriverService>,
+}
+
+impl DriverServiceChanges {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+/// Compares `services` with the `applied` ones, by socket id. An edited
+/// service is both removed and added again.
+pub(crate) fn diff_driver_services(
+    applied: &HashMap<String, DriverService>,
+    services: &[DriverService],
+) -> DriverServiceChanges {
+    let current: HashMap<&str, &DriverService> = services
+        .iter()
+        .map(|service| (service.config.socket_id.as_str(), service))
+        .collect();
+
+    let mut removed: Vec<String> = applied
+        .iter()
+        .filter(|(socket_id, old)| current.get(socket_id.as_str()) != Some(old))
+        .map(|(socket_id, _)| socket_id.clone())
+        .collect();
+    removed.sort();
+
+    let mut added: Vec<DriverService> = current
+        .into_iter()
+        .filter(|(socket_id, new)| applied.get(*socket_id) != Some(*new))
+        .map(|(_, new)| new.clone())
+        .collect();
+    added.sort_by(|a, b| a.config.socket_id.cmp(&b.config.socket_id));
+
+    DriverServiceChanges { removed, added }
+}
+
+/// Driver service changes found in storage and the manifests folder, to be
+/// probed off the main thread with [`Self::probe`] and then applied with
+/// [`crate::AppState::apply_rpc_driver_reload`].
+pub struct RpcDriverReload {
+    pub(crate) services: HashMap<String, DriverService>,
+    pub(crate) changes: DriverServiceChanges,
+    pub(crate) audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
+}
+
+/// The outcome of [`RpcDriverReload::probe`].
+pub struct ProbedRpcDrivers {
+    pub(crate) services: HashMap<String, DriverService>,
+    pub(crate) removed: Vec<String>,
+    pub(crate) drivers: HashMap<String, Arc<dyn DbDriver>>,
+    pub(crate) diagnostics: HashMap<String, ExternalDriverDiagnostic>,
+}
+
+impl RpcDriverReload {
+    /// Stops the managed hosts of removed services and handshakes with the
+    /// new ones, launching them when needed. Blocks for as long as the
+    /// hosts take to start.
+    pub fn probe(self) -> ProbedRpcDrivers {
+        for socket_id in &self.changes.removed {
+            if dbflux_driver_ipc::driver::stop_managed_host(socket_id) {
+                log::info!("Stopped RPC host for removed or edited service '{socket_id}'");
+            }
+        }
+
+        let mut drivers = HashMap::new();
+        let mut diagnostics = HashMap::new();
+        launch_driver_services(
+            &mut drivers,
+            &mut diagnostics,
+            self.changes.added,
+            self.audit_emitter,
+        );
+
+        ProbedRpcDrivers {
+            services: self.services,
+            removed: self.changes.removed,
+            drivers,
+            diagnostics,
+        }
+    }
+}
+
+impl ProbedRpcDrivers {
+    /// Ids of the drivers the reload registers.
+    pub fn registered_driver_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.drivers.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+}
+
 pub(crate) fn rpc_registry_id(socket_id: &str) -> String {
     format!("rpc:{}", socket_id)
 }
 
+/// Handshakes with `services` and registers the ones that answer in
+/// `drivers`; failures land in `diagnostics`.
+pub(crate) fn launch_driver_services(
+    drivers: &mut HashMap<String, Arc<dyn DbDriver>>,
+    diagnostics: &mut HashMap<String, ExternalDriverDiagnostic>,
+    services: Vec<DriverService>,
+    audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
+) {
+    let mut manifests: HashMap<String, DriverManifest> = HashMap::new();
+    let mut configs = Vec::with_capacity(services.len());
+    for service in services {
+        if let Some(manifest) = service.manifest {
+            manifests.insert(service.config.socket_id.clone(), manifest);
+        }
+        configs.push(service.config);
+    }
+
+    for discovery in discover_services(configs) {
+        let descriptor = match discovery {
+            RpcServiceDiscovery::Descriptor(descriptor) => descriptor,
+            RpcServiceDiscovery::InvalidConfig { diagnostic } => {
+                log::warn!(
+                    "Skipping RPC service '{}': invalid launch configuration: {}",
+                    diagnostic.socket_id,
+                    diagnostic.summary
+                );
+                diagnostics.insert(diagnostic.socket_id.clone(), diagnostic);
+                continue;
+            }
+        };
+
+        let manifest = manifests.remove(&descriptor.config.socket_id);
+
+        match adapt_driver_service(
+            descriptor,
+            manifest,
+            |driver_id| drivers.contains_key(driver_id),
+            audit_emitter.clone(),
+        ) {
+            DriverServiceAdaptation::Registered { driver_id, service } => {
+                if let Some(socket_id) = driver_id.strip_prefix("rpc:") {
+                    diagnostics.remove(socket_id);
+                }
+                drivers.insert(driver_id, service);
+            }
+            DriverServiceAdaptation::SkippedDisabled { socket_id } => {
+                log::info!("Skipping disabled service '{}'", socket_id);
+            }
+            DriverServiceAdaptation::SkippedNonDriver { socket_id, kind } => {
+                log::info!(
+                    "Deferring non-driver RPC service '{}' of kind {:?}",
+                    socket_id,
+                    kind
+                );
+            }
+            DriverServiceAdaptation::SkippedDuplicate { socket_id } => {
+                log::warn!(
+                    "Skipping external RPC service '{}': driver id already exists",
+                    socket_id
+                );
+            }
+            DriverServiceAdaptation::ProbeFailed { diagnostic } => {
+                log::warn!(
+                    "Skipping RPC service '{}': {}",
+                    diagnostic.socket_id,
+                    diagnostic.summary
+                );
+                diagnostics.insert(diagnostic.socket_id.clone(), diagnostic);
+            }
+        }
+    }
+}
+
 pub(crate) fn discover_services(services: Vec<ServiceConfig>) -> Vec<RpcServiceDiscovery> {
     services
         .into_iter()
@@ -146,13 +367,36 @@ pub(crate) fn discover_services(services: Vec<ServiceConfig>) -> Vec<RpcServiceD
 
 pub(crate) fn adapt_driver_service(
     descriptor: RpcServiceDescriptor,
+    manifest: Option<DriverManifest>,
     driver_exists: impl FnOnce(&str) -> bool,
     audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
 ) -> DriverServiceAdaptation<Arc<dyn DbDriver>> {
+    let remote = descriptor.config.remote.clone();
+    let probe_remote = remote.clone();
+
     adapt_driver_service_with(
         descriptor,
         driver_exists,
-        |socket_id, launch| IpcDriver::probe_driver(socket_id, launch).map_err(Box::new),
+        move |socket_id, launch| {
+            let mut probe = match &probe_remote {
+                Some(remote) => IpcDriver::probe_remote_driver(socket_id, remote),
+                None => IpcDriver::probe_driver(socket_id, launch),
+            }
+            .map_err(Box::new)?;
+
+            if let Some(manifest) = &manifest {
+                let missing = manifest.apply(&mut probe.1, &mut probe.2);
+                if !missing.is_empty() {
+                    log::warn!(
+                        "Driver '{}' does not report capabilities its manifest declares: {}",
+                        manifest.name,
+                        DriverManifest::capability_names(missing).join(", ")
+                    );
+                }
+            }
+
+            Ok(probe)
+        },
         |_, socket_id, (kind, metadata, form_definition, settings_schema), launch| {
             let driver =
                 IpcDriver::new(socket_id, kind, metadata, form_definition, settings_schema);
@@ -160,6 +404,10 @@ pub(crate) fn adapt_driver_service(
                 Some(launch) => driver.with_launch_config(launch),
                 None => driver,
             };
+            let driver = match remote {
+                Some(remote) => driver.with_remote(remote),
+                None => driver,
+            };
             let driver = match audit_emitter {
                 Some(ref emitter) => driver.with_audit_emitter(emitter.clone()),
                 None => driver,
@@ -297,6 +545,10 @@ where
 fn build_service_launch_config(
     config: &ServiceConfig,
 ) -> Result<Option<RpcServiceLaunch>, Box<DbError>> {
+    if let Some(remote) = &config.remote {
+        return validate_remote_service(config, remote).map(|()| None);
+    }
+
     match config.kind {
         RpcServiceKind::Driver => IpcDriver::build_launch_config(
             &config.socket_id,
@@ -319,6 +571,35 @@ fn build_service_launch_config(
     }
 }
 
+/// A remote service is a driver host someone else runs, so there is nothing
+/// to launch.
+fn validate_remote_service(
+    config: &ServiceConfig,
+    remote: &RemoteServiceConfig,
+) -> Result<(), Box<DbError>> {
+    if config.kind != RpcServiceKind::Driver {
+        return Err(Box::new(DbError::ConnectionFailed(
+            format!(
+                "Service '{}' has a remote endpoint, which only driver services support",
+                config.socket_id
+            )
+            .into(),
+        )));
+    }
+
+    if config.command.is_some() || !config.args.is_empty() {
+        return Err(Box::new(DbError::ConnectionFailed(
+            format!(
+                "Service '{}' has both a remote endpoint and a launch command",
+                config.socket_id
+            )
+            .into(),
+        )));
+    }
+
+    IpcDriver::validate_remote(remote).map_err(Box::new)
+}
+
 fn classify_driver_probe_failure_stage(
     launch: Option<&IpcDriverLaunchConfig>,
     error: &DbError,
@@ -450,7 +731,6 @@ fn auth_provider_diagnostic_from_error(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
 
     use dbflux_core::auth::{
         AuthFormDef, AuthProfile, AuthSession, AuthSessionState, ResolvedCredentials, UrlCallback,
@@ -484,6 +764,7 @@ mod tests {
             startup_timeout_ms: Some(7_500),
             kind,
             api_contract: None,
+            remote: None,
         }
     }
 
@@ -497,6 +778,7 @@ mod tests {
             startup_timeout_ms: None,
             kind: RpcServiceKind::Driver,
             api_contract: None,
+            remote: None,
         }
     }
 
@@ -594,6 +876,7 @@ mod tests {
             startup_timeout_ms: Some(1_000),
             kind: RpcServiceKind::Driver,
             api_contract: None,
+            remote: None,
         };
 
         let discovery = discover_services(vec![invalid_service])
@@ -627,6 +910,7 @@ mod tests {
             startup_timeout_ms: Some(1_000),
             kind: RpcServiceKind::Driver,
             api_contract: None,
+            remote: None,
         };
 
         let discovery = discover_services(vec![invalid_service])
@@ -663,6 +947,7 @@ mod tests {
             startup_timeout_ms: Some(1_000),
             kind: RpcServiceKind::Driver,
             api_contract: None,
+            remote: None,
         };
 
         let launch: Result<Option<RpcServiceLaunch>, Box<DbError>> =
@@ -672,6 +957,30 @@ mod tests {
         assert!(error.to_string().contains("--driver"));
     }
 
+    #[test]
+    fn build_service_launch_config_skips_launch_for_remote_drivers() {
+        let mut service = test_service(RpcServiceKind::Driver, true);
+        service.command = None;
+        service.args.clear();
+        service.remote = Some(RemoteServiceConfig {
+            address: "db-box.internal:7420".to_string(),
+            tls: true,
+            token_env: "DBFLUX_REMOTE_TOKEN".to_string(),
+        });
+
+        let launch = build_service_launch_config(&service).expect("remote service is valid");
+        assert!(launch.is_none());
+
+        service.command = Some("dbflux-driver-host".to_string());
+        let error = build_service_launch_config(&service).expect_err("command and remote clash");
+        assert!(error.to_string().contains("launch command"));
+
+        service.command = None;
+        service.kind = RpcServiceKind::AuthProvider;
+        let error = build_service_launch_config(&service).expect_err("only drivers go remote");
+        assert!(error.to_string().contains("only driver services"));
+    }
+
     #[test]
     fn discover_and_adapt_driver_service_preserves_rpc_registry_id() {
         let descriptor = discover_services(vec![test_service(RpcServiceKind::Driver, true)])
@@ -803,6 +1112,7 @@ mod tests {
             startup_timeout_ms: Some(1_000),
             kind: RpcServiceKind::AuthProvider,
             api_contract: None,
+            remote: None,
         };
 
         let discovery = discover_services(vec![invalid_service])
@@ -980,4 +1290,103 @@ mod tests {
             _ => panic!("expected duplicate provider rejection"),
         }
     }
+
+    fn manifest_catalog(manifests: &[DriverManifest]) -> DriverManifestCatalog {
+        DriverManifestCatalog {
+            entries: manifests
+                .iter()
+                .map(|manifest| crate::driver_manifests::DriverManifestEntry {
+                    path: format!("{}.json", manifest.socket).into(),
+                    manifest: manifest.clone(),
+                })
+                .collect(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn applied(services: &[DriverService]) -> HashMap<String, DriverService> {
+        services
+            .iter()
+            .map(|service| (service.config.socket_id.clone(), service.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn driver_services_append_manifests_unless_their_socket_is_configured() {
+        let mut auth = test_service(RpcServiceKind::AuthProvider, true);
+        auth.socket_id = "auth-socket".to_string();
+        let shadowed =
+            DriverManifest::parse(r#"{ "name": "Shadowed", "socket": "manual-socket" }"#).unwrap();
+        let mock = DriverManifest::parse(r#"{ "name": "Mock", "socket": "mock.sock" }"#).unwrap();
+
+        let services = driver_services(
+            &[manual_service(), auth],
+            &manifest_catalog(&[shadowed, mock.clone()]),
+        );
+
+        assert_eq!(
+            services,
+            vec![
+                DriverService {
+                    config: manual_service(),
+                    manifest: None,
+                },
+                DriverService {
+                    config: mock.service_config(),
+                    manifest: Some(mock),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_driver_services_reports_added_removed_and_edited_drivers() {
+        let kept = manual_service();
+        let mut edited = test_service(RpcServiceKind::Driver, true);
+        let mut gone = manual_service();
+        gone.socket_id = "gone-socket".to_string();
+        let mut manifest =
+            DriverManifest::parse(r#"{ "name": "Mock", "socket": "mock.sock" }"#).unwrap();
+
+        let before = driver_services(
+            &[kept.clone(), edited.clone(), gone],
+            &manifest_catalog(std::slice::from_ref(&manifest)),
+        );
+
+        edited.args.push("--verbose".to_string());
+        manifest.icon = Some(dbflux_core::Icon::Database);
+        let mut added = manual_service();
+        added.socket_id = "added-socket".to_string();
+
+        let after = driver_services(
+            &[kept, edited.clone(), added.clone()],
+            &manifest_catalog(std::slice::from_ref(&manifest)),
+        );
+        let changes = diff_driver_services(&applied(&before), &after);
+
+        assert_eq!(
+            changes.removed,
+            vec![
+                "gone-socket".to_string(),
+                "mock.sock".to_string(),
+                "svc-socket".to_string()
+            ]
+        );
+        let added_sockets: Vec<&str> = changes
+            .added
+            .iter()
+            .map(|service| service.config.socket_id.as_str())
+            .collect();
+        assert_eq!(
+            added_sockets,
+            vec!["added-socket", "mock.sock", "svc-socket"]
+        );
+    }
+
+    #[test]
+    fn diff_driver_services_is_empty_when_nothing_changed() {
+        let services = driver_services(&[manual_service()], &DriverManifestCatalog::default());
+
+        assert!(diff_driver_services(&applied(&services), &services).is_empty());
+    }
 }
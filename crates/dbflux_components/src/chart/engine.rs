@@ -5,7 +5,7 @@
 //! stored `RenderModel`.
 
 use std::cell::RefCell;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 use gpui::prelude::*;
@@ -17,6 +17,7 @@ use gpui_component::ActiveTheme;
 
 use crate::chart::axis::{TickLabel, ticks_log, ticks_numeric, ticks_time};
 use crate::chart::decimate::{lttb, lttb_with_indices};
+use crate::chart::group;
 use crate::chart::spec::{AxisKind, ChartSpec, YScale};
 use crate::chart::stats::{
     SeriesStats, compute_series_stats, hit_test_focused_series, interpolate_y_at_x,
@@ -55,8 +56,12 @@ pub enum ChartBuildError {
 /// (unreachable in practice) fall back to a neutral mid-grey so paint does not
 /// crash even if the series list is somehow malformed.
 #[inline]
+/// Number of distinct series colours in the theme palette; colour slots
+/// wrap modulo this.
+pub const PALETTE_SLOTS: u8 = 5;
+
 fn theme_chart_color(theme: &gpui_component::theme::Theme, slot: u8) -> Hsla {
-    match slot % 5 {
+    match slot % PALETTE_SLOTS {
         0 => theme.chart_1,
         1 => theme.chart_2,
         2 => theme.chart_3,
@@ -152,11 +157,24 @@ impl ChartView {
     /// - Applies LTTB decimation when `len > spec.decimation_threshold`.
     /// - Generates axis ticks.
     /// - Resolves palette colours.
+    ///
+    /// When the binding has a group column or an aggregation, the result is
+    /// first pivoted into one series per group (see `chart::group`).
     pub fn build(result: &QueryResult, spec: ChartSpec) -> Result<Self, ChartBuildError> {
         if result.rows.is_empty() {
             return Err(ChartBuildError::Empty);
         }
 
+        if group::needs_pivot(&spec) {
+            let (pivoted, spec) =
+                group::pivot_by_group(result, &spec).ok_or(ChartBuildError::NoUsableData)?;
+            return Self::build_series(&pivoted, spec);
+        }
+
+        Self::build_series(result, spec)
+    }
+
+    fn build_series(result: &QueryResult, spec: ChartSpec) -> Result<Self, ChartBuildError> {
         let x_col = spec.x_axis.column_index;
         if x_col >= result.columns.len() {
             return Err(ChartBuildError::InvalidXColumn(x_col));
@@ -405,6 +423,30 @@ impl ChartView {
         }
     }
 
+    /// Apply palette slots keyed by series label, e.g. colours the user picked
+    /// before a rebuild. Labels without an entry keep their slot.
+    pub fn with_series_colors(mut self, overrides: &HashMap<String, u8>) -> Self {
+        for (idx, series) in self.spec.series.iter_mut().enumerate() {
+            if let Some(&slot) = overrides.get(&series.label) {
+                series.color_slot = slot % PALETTE_SLOTS;
+                self.render_model.palette_slots[idx] = series.color_slot;
+            }
+        }
+        self
+    }
+
+    /// Recolour the series at `idx` with a palette slot. Cheap — colours are
+    /// resolved from the theme at render time.
+    pub fn set_series_color(&mut self, idx: usize, slot: u8, cx: &mut Context<Self>) {
+        if let Some(series) = self.spec.series.get_mut(idx) {
+            series.color_slot = slot % PALETTE_SLOTS;
+        }
+        if let Some(current) = self.render_model.palette_slots.get_mut(idx) {
+            *current = slot % PALETTE_SLOTS;
+            cx.notify();
+        }
+    }
+
     /// The current chart kind.
     pub fn kind(&self) -> crate::chart::spec::ChartKind {
         self.spec.kind
@@ -565,6 +607,16 @@ impl ChartView {
         Some(self.render_model.x_min + (rel_x as f64 / plot_w as f64) * x_range)
     }
 
+    /// Whether the series at `idx` is currently hidden from the plot.
+    pub fn is_series_hidden(&self, idx: usize) -> bool {
+        self.hidden.contains(&idx)
+    }
+
+    /// Whether the Y axis is drawn on a log scale.
+    pub fn y_is_log(&self) -> bool {
+        self.render_model.y_is_log
+    }
+
     /// Replace the set of hidden series indices.
     ///
     /// If `focused_series_idx` is in the new hidden set, it is reset to the
@@ -2588,6 +2640,8 @@ fn extract_f64(value: &Value, is_time: bool) -> Option<f64> {
         // happens to carry a datetime value, instead of blowing the value-axis
         // scale up to ~1e12.
         Value::DateTime(dt) if is_time => Some(dt.timestamp_millis() as f64),
+        // Like Date, a timestamp without a time zone is placed as if it were UTC.
+        Value::NaiveDateTime(dt) if is_time => Some(dt.and_utc().timestamp_millis() as f64),
         // Date maps to midnight UTC for a consistent, unambiguous epoch-ms value.
         Value::Date(d) if is_time => d
             .and_hms_opt(0, 0, 0)
@@ -3170,6 +3224,36 @@ mod tests {
         assert_eq!(view.render_model.palette_slots.len(), 2);
     }
 
+    #[test]
+    fn build_splits_series_by_group_column() {
+        let rows = vec![
+            vec![Value::Int(0), Value::Text("a".into()), Value::Float(1.0)],
+            vec![Value::Int(0), Value::Text("b".into()), Value::Float(2.0)],
+            vec![Value::Int(1000), Value::Text("a".into()), Value::Float(3.0)],
+        ];
+        let result = QueryResult::table(
+            vec![
+                make_col("t", ColumnKind::Timestamp),
+                make_col("host", ColumnKind::Text),
+                make_col("v", ColumnKind::Float),
+            ],
+            rows,
+            None,
+            Duration::ZERO,
+        );
+        let mut spec = simple_spec(0, &[2]);
+        spec.binding.group_by = Some(1);
+
+        let view = ChartView::build(&result, spec).expect("build should succeed");
+        assert_eq!(view.series_label(0), "a");
+        assert_eq!(view.series_label(1), "b");
+        assert_eq!(view.render_model.palette_slots, vec![0, 1]);
+
+        let overrides = HashMap::from([("b".to_string(), 4)]);
+        let view = view.with_series_colors(&overrides);
+        assert_eq!(view.render_model.palette_slots, vec![0, 4]);
+    }
+
     #[test]
     fn build_records_series_stats_post_decimation() {
         // Known input: 4 rows, below decimation threshold so stats are over all points.
@@ -0,0 +1,727 @@
+//! Static image export for charts.
+//!
+//! `ChartView::render_svg` draws the current chart — visible series only, with
+//! its kind, scale and palette — into a standalone SVG document. The SVG is
+//! the export format for vector output and the source `rasterize_svg` renders
+//! to PNG at the requested pixel size.
+
+use std::fmt::Write as _;
+
+use gpui::{App, Hsla, Rgba};
+use gpui_component::ActiveTheme;
+
+use crate::chart::axis::{ticks_log, ticks_numeric, ticks_time};
+use crate::chart::engine::{ChartView, format_y_value};
+use crate::chart::spec::ChartKind;
+
+/// File format of an exported chart image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartImageFormat {
+    Png,
+    Svg,
+}
+
+impl ChartImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Svg => "svg",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Png => "PNG",
+            Self::Svg => "SVG",
+        }
+    }
+}
+
+/// One entry of the export menu: a format and the output size in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChartExportOption {
+    pub format: ChartImageFormat,
+    pub width: u32,
+    pub height: u32,
+    pub label: &'static str,
+}
+
+impl ChartExportOption {
+    /// Full-HD PNG, used where there is no menu to pick a size from.
+    pub const DEFAULT_PNG: Self = Self {
+        format: ChartImageFormat::Png,
+        width: 1920,
+        height: 1080,
+        label: "PNG · 1920×1080",
+    };
+}
+
+/// Export choices offered by the chart toolbar, in menu order.
+pub const CHART_EXPORT_OPTIONS: &[ChartExportOption] = &[
+    ChartExportOption {
+        format: ChartImageFormat::Png,
+        width: 1280,
+        height: 720,
+        label: "PNG · 1280×720",
+    },
+    ChartExportOption::DEFAULT_PNG,
+    ChartExportOption {
+        format: ChartImageFormat::Png,
+        width: 3840,
+        height: 2160,
+        label: "PNG · 3840×2160",
+    },
+    ChartExportOption {
+        format: ChartImageFormat::Svg,
+        width: 1280,
+        height: 720,
+        label: "SVG · 1280×720",
+    },
+];
+
+/// Errors from turning an exported SVG into image bytes.
+#[derive(Debug, thiserror::Error)]
+pub enum ChartExportError {
+    #[error("failed to parse the chart SVG: {0}")]
+    Svg(String),
+
+    #[error("invalid image size {0}×{1}")]
+    InvalidSize(u32, u32),
+
+    #[error("failed to encode the PNG: {0}")]
+    Png(String),
+}
+
+/// Encode `svg` in the option's format: the SVG text itself, or a PNG
+/// rasterized at the option's size.
+pub fn encode_chart_image(
+    svg: &str,
+    option: &ChartExportOption,
+) -> Result<Vec<u8>, ChartExportError> {
+    match option.format {
+        ChartImageFormat::Svg => Ok(svg.as_bytes().to_vec()),
+        ChartImageFormat::Png => rasterize_svg(svg, option.width, option.height),
+    }
+}
+
+/// Rasterize an SVG document to PNG bytes of exactly `width`×`height` pixels.
+///
+/// Loads the system fonts for the labels, so call it off the UI thread.
+pub fn rasterize_svg(svg: &str, width: u32, height: u32) -> Result<Vec<u8>, ChartExportError> {
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+
+    let tree =
+        usvg::Tree::from_str(svg, &options).map_err(|e| ChartExportError::Svg(e.to_string()))?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or(ChartExportError::InvalidSize(width, height))?;
+
+    let size = tree.size();
+    let transform = tiny_skia::Transform::from_scale(
+        width as f32 / size.width(),
+        height as f32 / size.height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    pixmap
+        .encode_png()
+        .map_err(|e| ChartExportError::Png(e.to_string()))
+}
+
+impl ChartView {
+    /// Draw the chart as an SVG document of `width`×`height` user units,
+    /// with `title` above the plot and a legend below it when there is more
+    /// than one visible series. Colours come from the active theme.
+    pub fn render_svg(&self, title: &str, width: u32, height: u32, cx: &App) -> String {
+        let theme = cx.theme();
+        let palette = self.resolved_palette(cx);
+
+        let series = (0..self.spec_series().len())
+            .filter(|&idx| !self.is_series_hidden(idx))
+            .map(|idx| SvgSeries {
+                label: self.series_label(idx).to_string(),
+                color: palette.get(idx).copied().map(hex_color).unwrap_or_default(),
+                points: self
+                    .render_model_decimated_series(idx)
+                    .map(<[_]>::to_vec)
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let chart = SvgChart {
+            title: title.to_string(),
+            kind: self.kind(),
+            x_is_time: self.x_is_time(),
+            y_is_log: self.y_is_log(),
+            series,
+            colors: SvgColors {
+                background: hex_color(theme.background),
+                foreground: hex_color(theme.foreground),
+                muted: hex_color(theme.muted_foreground),
+                grid: hex_color(theme.border),
+            },
+        };
+
+        svg_document(&chart, width as f64, height as f64)
+    }
+}
+
+fn hex_color(color: Hsla) -> String {
+    let rgba = Rgba::from(color);
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(rgba.r),
+        channel(rgba.g),
+        channel(rgba.b)
+    )
+}
+
+struct SvgChart {
+    title: String,
+    kind: ChartKind,
+    x_is_time: bool,
+    y_is_log: bool,
+    series: Vec<SvgSeries>,
+    colors: SvgColors,
+}
+
+struct SvgSeries {
+    label: String,
+    color: String,
+    points: Vec<(f64, f64)>,
+}
+
+struct SvgColors {
+    background: String,
+    foreground: String,
+    muted: String,
+    grid: String,
+}
+
+/// Pixel rectangle of the plot area inside the document.
+#[derive(Clone, Copy)]
+struct Plot {
+    left: f64,
+    top: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Plot {
+    fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    fn right(&self) -> f64 {
+        self.left + self.width
+    }
+}
+
+/// Linear map from a data range onto a pixel range.
+#[derive(Clone, Copy)]
+struct Scale {
+    min: f64,
+    max: f64,
+    from: f64,
+    to: f64,
+}
+
+impl Scale {
+    fn map(&self, value: f64) -> f64 {
+        if self.max <= self.min {
+            return (self.from + self.to) / 2.0;
+        }
+        self.from + (value - self.min) / (self.max - self.min) * (self.to - self.from)
+    }
+}
+
+fn svg_document(chart: &SvgChart, width: f64, height: f64) -> String {
+    let font = (height / 48.0).clamp(10.0, 36.0);
+    let colors = &chart.colors;
+    let mut svg = String::new();
+
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif">"#,
+        w = width,
+        h = height
+    );
+    let _ = write!(
+        svg,
+        r#"<rect width="{}" height="{}" fill="{}"/>"#,
+        width, height, colors.background
+    );
+    let _ = write!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="{:.1}" font-weight="bold" fill="{}">{}</text>"#,
+        font * 1.5,
+        font * 2.0,
+        font * 1.2,
+        colors.foreground,
+        escape(&chart.title)
+    );
+
+    let legend_rows = if chart.series.len() > 1 { 1.0 } else { 0.0 };
+    let axes = !matches!(chart.kind, ChartKind::Pie | ChartKind::Number);
+
+    let plot = Plot {
+        left: if axes { font * 6.0 } else { font * 1.5 },
+        top: font * 3.5,
+        width: width - if axes { font * 7.5 } else { font * 3.0 },
+        height: height - font * (3.5 + if axes { 2.5 } else { 1.0 } + legend_rows * 2.5),
+    };
+
+    if chart.series.iter().all(|s| s.points.is_empty()) || plot.width <= 0.0 || plot.height <= 0.0 {
+        svg.push_str("</svg>");
+        return svg;
+    }
+
+    match chart.kind {
+        ChartKind::Pie => draw_pie(&mut svg, chart, plot, font),
+        ChartKind::Number => draw_numbers(&mut svg, chart, plot, font),
+        _ => draw_cartesian(&mut svg, chart, plot, font),
+    }
+
+    if legend_rows > 0.0 {
+        draw_legend(&mut svg, chart, font, height - font * 1.5);
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+fn draw_cartesian(svg: &mut String, chart: &SvgChart, plot: Plot, font: f64) {
+    let colors = &chart.colors;
+    let is_bar = matches!(chart.kind, ChartKind::Bar | ChartKind::StackedBar);
+    let stacked = chart.kind == ChartKind::StackedBar;
+
+    let finite = || {
+        chart
+            .series
+            .iter()
+            .flat_map(|s| s.points.iter())
+            .filter(|(_, y)| y.is_finite())
+    };
+
+    let (mut x_min, mut x_max) = finite().fold((f64::INFINITY, f64::NEG_INFINITY), |acc, p| {
+        (acc.0.min(p.0), acc.1.max(p.0))
+    });
+    let (mut y_min, mut y_max) = finite().fold((f64::INFINITY, f64::NEG_INFINITY), |acc, p| {
+        (acc.0.min(p.1), acc.1.max(p.1))
+    });
+
+    if !x_min.is_finite() {
+        return;
+    }
+
+    let point_count = chart
+        .series
+        .iter()
+        .map(|s| s.points.len())
+        .max()
+        .unwrap_or(0);
+
+    if stacked {
+        y_max = (0..point_count)
+            .map(|i| {
+                chart
+                    .series
+                    .iter()
+                    .filter_map(|s| s.points.get(i))
+                    .map(|(_, y)| if y.is_finite() { y.max(0.0) } else { 0.0 })
+                    .sum::<f64>()
+            })
+            .fold(y_max, f64::max);
+    }
+
+    // Bars and areas grow from zero; leave headroom above the tallest value.
+    if (is_bar || chart.kind == ChartKind::Area) && y_min > 0.0 {
+        y_min = 0.0;
+    }
+    y_max += (y_max - y_min).abs() * 0.08;
+
+    // Half a bar slot of padding on each side keeps the outer bars inside.
+    let slot = if point_count > 1 {
+        (x_max - x_min) / (point_count - 1) as f64
+    } else {
+        1.0
+    };
+    if is_bar {
+        x_min -= slot / 2.0;
+        x_max += slot / 2.0;
+    }
+
+    let to_y = |y: f64| {
+        if chart.y_is_log {
+            (y.max(0.0) + 1.0).ln()
+        } else {
+            y
+        }
+    };
+
+    let x_scale = Scale {
+        min: x_min,
+        max: x_max,
+        from: plot.left,
+        to: plot.right(),
+    };
+    let y_scale = Scale {
+        min: to_y(y_min),
+        max: to_y(y_max),
+        from: plot.bottom(),
+        to: plot.top,
+    };
+
+    // Grid and Y labels.
+    let y_ticks = if chart.y_is_log {
+        ticks_log(y_min, y_max, 5)
+    } else {
+        ticks_numeric(y_min, y_max, 5)
+    };
+    for tick in y_ticks {
+        let y = y_scale.map(tick.value);
+        let _ = write!(
+            svg,
+            r#"<line x1="{:.1}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="{}" stroke-width="1"/>"#,
+            plot.left,
+            plot.right(),
+            colors.grid
+        );
+        let _ = write!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" font-size="{:.1}" text-anchor="end" fill="{}">{}</text>"#,
+            plot.left - font * 0.5,
+            y + font * 0.35,
+            font * 0.85,
+            colors.muted,
+            escape(&tick.label)
+        );
+    }
+
+    // X labels.
+    let x_ticks = if chart.x_is_time {
+        ticks_time(x_min, x_max, 6)
+    } else {
+        ticks_numeric(x_min, x_max, 6)
+    };
+    for tick in x_ticks {
+        let _ = write!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" font-size="{:.1}" text-anchor="middle" fill="{}">{}</text>"#,
+            x_scale.map(tick.value),
+            plot.bottom() + font * 1.5,
+            font * 0.85,
+            colors.muted,
+            escape(&tick.label)
+        );
+    }
+
+    let _ = write!(
+        svg,
+        r#"<line x1="{l:.1}" y1="{b:.1}" x2="{r:.1}" y2="{b:.1}" stroke="{}" stroke-width="1"/>"#,
+        colors.muted,
+        l = plot.left,
+        r = plot.right(),
+        b = plot.bottom()
+    );
+
+    let baseline = y_scale.map(to_y(0.0_f64.max(y_min)));
+
+    match chart.kind {
+        ChartKind::Bar | ChartKind::StackedBar => {
+            let slot_px = (x_scale.map(x_min + slot) - x_scale.map(x_min)).abs();
+            let group_px = slot_px * 0.8;
+            let bar_px = if stacked {
+                group_px
+            } else {
+                group_px / chart.series.len().max(1) as f64
+            };
+            let mut stack_tops = vec![0.0_f64; point_count];
+
+            for (series_idx, series) in chart.series.iter().enumerate() {
+                for (point_idx, &(x, y)) in series.points.iter().enumerate() {
+                    if !y.is_finite() {
+                        continue;
+                    }
+                    let (from, to) = if stacked {
+                        let from = stack_tops[point_idx];
+                        stack_tops[point_idx] += y.max(0.0);
+                        (from, stack_tops[point_idx])
+                    } else {
+                        (0.0_f64.max(y_min), y)
+                    };
+                    let left = if stacked {
+                        x_scale.map(x) - group_px / 2.0
+                    } else {
+                        x_scale.map(x) - group_px / 2.0 + bar_px * series_idx as f64
+                    };
+                    let top = y_scale.map(to_y(from.max(to)));
+                    let bottom = y_scale.map(to_y(from.min(to)));
+                    let _ = write!(
+                        svg,
+                        r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"/>"#,
+                        left,
+                        top,
+                        bar_px.max(1.0),
+                        (bottom - top).max(0.5),
+                        series.color
+                    );
+                }
+            }
+        }
+        ChartKind::Scatter => {
+            for series in &chart.series {
+                for &(x, y) in series.points.iter().filter(|(_, y)| y.is_finite()) {
+                    let _ = write!(
+                        svg,
+                        r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}"/>"#,
+                        x_scale.map(x),
+                        y_scale.map(to_y(y)),
+                        font * 0.25,
+                        series.color
+                    );
+                }
+            }
+        }
+        _ => {
+            for series in &chart.series {
+                for run in finite_runs(&series.points) {
+                    let path: Vec<String> = run
+                        .iter()
+                        .map(|&(x, y)| format!("{:.1},{:.1}", x_scale.map(x), y_scale.map(to_y(y))))
+                        .collect();
+
+                    if chart.kind == ChartKind::Area
+                        && let (Some(first), Some(last)) = (run.first(), run.last())
+                    {
+                        let _ = write!(
+                            svg,
+                            r#"<polygon points="{:.1},{baseline:.1} {} {:.1},{baseline:.1}" fill="{}" fill-opacity="0.25"/>"#,
+                            x_scale.map(first.0),
+                            path.join(" "),
+                            x_scale.map(last.0),
+                            series.color
+                        );
+                    }
+
+                    let _ = write!(
+                        svg,
+                        r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linejoin="round"/>"#,
+                        path.join(" "),
+                        series.color,
+                        (font / 8.0).max(1.5)
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Consecutive runs of finite points; NaN gaps split a line.
+fn finite_runs(points: &[(f64, f64)]) -> Vec<&[(f64, f64)]> {
+    points
+        .split(|(_, y)| !y.is_finite())
+        .filter(|run| !run.is_empty())
+        .collect()
+}
+
+fn draw_pie(svg: &mut String, chart: &SvgChart, plot: Plot, font: f64) {
+    let totals: Vec<f64> = chart
+        .series
+        .iter()
+        .map(|s| {
+            s.points
+                .iter()
+                .map(|(_, y)| if y.is_finite() { y.max(0.0) } else { 0.0 })
+                .sum()
+        })
+        .collect();
+    let total: f64 = totals.iter().sum();
+    if total <= 0.0 {
+        return;
+    }
+
+    let cx = plot.left + plot.width / 2.0;
+    let cy = plot.top + plot.height / 2.0;
+    let radius = plot.width.min(plot.height) / 2.0 * 0.9;
+    let mut angle = -std::f64::consts::FRAC_PI_2;
+
+    for (series, value) in chart.series.iter().zip(&totals) {
+        let sweep = value / total * std::f64::consts::TAU;
+        if sweep <= 0.0 {
+            continue;
+        }
+
+        if sweep >= std::f64::consts::TAU - 1e-9 {
+            let _ = write!(
+                svg,
+                r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="{radius:.1}" fill="{}"/>"#,
+                series.color
+            );
+        } else {
+            let end = angle + sweep;
+            let _ = write!(
+                svg,
+                r#"<path d="M{cx:.1},{cy:.1} L{:.1},{:.1} A{radius:.1},{radius:.1} 0 {} 1 {:.1},{:.1} Z" fill="{}" stroke="{}" stroke-width="{:.1}"/>"#,
+                cx + radius * angle.cos(),
+                cy + radius * angle.sin(),
+                u8::from(sweep > std::f64::consts::PI),
+                cx + radius * end.cos(),
+                cy + radius * end.sin(),
+                series.color,
+                chart.colors.background,
+                (font / 8.0).max(1.0)
+            );
+            angle = end;
+        }
+    }
+}
+
+fn draw_numbers(svg: &mut String, chart: &SvgChart, plot: Plot, font: f64) {
+    let column = plot.width / chart.series.len().max(1) as f64;
+
+    for (idx, series) in chart.series.iter().enumerate() {
+        let value = series
+            .points
+            .iter()
+            .rev()
+            .find(|(_, y)| y.is_finite())
+            .map(|&(_, y)| format_y_value(y))
+            .unwrap_or_else(|| "—".to_string());
+        let x = plot.left + column * (idx as f64 + 0.5);
+        let y = plot.top + plot.height / 2.0;
+
+        let _ = write!(
+            svg,
+            r#"<text x="{x:.1}" y="{y:.1}" font-size="{:.1}" font-weight="bold" text-anchor="middle" fill="{}">{}</text>"#,
+            font * 3.0,
+            series.color,
+            escape(&value)
+        );
+        let _ = write!(
+            svg,
+            r#"<text x="{x:.1}" y="{:.1}" font-size="{:.1}" text-anchor="middle" fill="{}">{}</text>"#,
+            y + font * 2.0,
+            font,
+            chart.colors.muted,
+            escape(&series.label)
+        );
+    }
+}
+
+fn draw_legend(svg: &mut String, chart: &SvgChart, font: f64, baseline: f64) {
+    let mut x = font * 1.5;
+
+    for series in &chart.series {
+        let _ = write!(
+            svg,
+            r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}"/>"#,
+            x + font * 0.4,
+            baseline - font * 0.35,
+            font * 0.4,
+            series.color
+        );
+        let _ = write!(
+            svg,
+            r#"<text x="{:.1}" y="{baseline:.1}" font-size="{:.1}" fill="{}">{}</text>"#,
+            x + font * 1.2,
+            font * 0.9,
+            chart.colors.foreground,
+            escape(&series.label)
+        );
+        // Rough advance; the legend is a single line in the export.
+        x += font * (2.5 + series.label.chars().count() as f64 * 0.55);
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart(kind: ChartKind, series: Vec<(&str, Vec<(f64, f64)>)>) -> SvgChart {
+        SvgChart {
+            title: "Orders <daily>".to_string(),
+            kind,
+            x_is_time: false,
+            y_is_log: false,
+            series: series
+                .into_iter()
+                .map(|(label, points)| SvgSeries {
+                    label: label.to_string(),
+                    color: "#ff0000".to_string(),
+                    points,
+                })
+                .collect(),
+            colors: SvgColors {
+                background: "#000000".to_string(),
+                foreground: "#ffffff".to_string(),
+                muted: "#888888".to_string(),
+                grid: "#333333".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn line_chart_breaks_at_gaps_and_escapes_the_title() {
+        let svg = svg_document(
+            &chart(
+                ChartKind::Line,
+                vec![(
+                    "a",
+                    vec![(0.0, 1.0), (1.0, 2.0), (2.0, f64::NAN), (3.0, 4.0)],
+                )],
+            ),
+            800.0,
+            450.0,
+        );
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains("Orders &lt;daily&gt;"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+    }
+
+    #[test]
+    fn stacked_bars_and_legend_cover_every_series() {
+        let svg = svg_document(
+            &chart(
+                ChartKind::StackedBar,
+                vec![
+                    ("a", vec![(0.0, 1.0), (1.0, 2.0)]),
+                    ("b", vec![(0.0, 3.0), (1.0, 4.0)]),
+                ],
+            ),
+            800.0,
+            450.0,
+        );
+
+        // The background rect plus one bar per point per series.
+        assert_eq!(svg.matches("<rect").count(), 1 + 4);
+        assert!(svg.contains(">a</text>") && svg.contains(">b</text>"));
+    }
+
+    #[test]
+    fn pie_draws_one_wedge_per_series() {
+        let svg = svg_document(
+            &chart(
+                ChartKind::Pie,
+                vec![("a", vec![(0.0, 1.0)]), ("b", vec![(0.0, 3.0)])],
+            ),
+            800.0,
+            450.0,
+        );
+
+        assert_eq!(svg.matches("<path").count(), 2);
+    }
+}
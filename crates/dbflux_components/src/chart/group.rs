@@ -0,0 +1,324 @@
+//! Group-by pivot for the AxisBar binding.
+//!
+//! A long-format result (`time, host, cpu`) is reshaped into one row per X
+//! value and one numeric column per distinct group (`time, db-1, db-2`), so
+//! the engine can plot each group as its own series. Rows that share an X and
+//! a group are merged with the binding's aggregation.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use dbflux_core::{ColumnKind, ColumnMeta, QueryResult, Value};
+
+use crate::chart::spec::{AggKind, ChartSpec, SeriesSpec};
+
+/// Most groups turned into series; rows of later groups are dropped so a
+/// high-cardinality column cannot flood the legend.
+pub const MAX_GROUP_SERIES: usize = 12;
+
+/// Whether `spec` needs [`pivot_by_group`] before it can be plotted.
+pub fn needs_pivot(spec: &ChartSpec) -> bool {
+    spec.binding.group_by.is_some() || spec.binding.aggregation != AggKind::None
+}
+
+/// Reshape `result` by the spec's group column and aggregation.
+///
+/// Returns the pivoted result and a spec whose series point at its columns.
+/// Without a group column every row falls in one group, which just merges
+/// duplicate X values. Returns `None` when the group column is out of range
+/// or no row has an X value.
+pub fn pivot_by_group(result: &QueryResult, spec: &ChartSpec) -> Option<(QueryResult, ChartSpec)> {
+    let x_col = spec.x_axis.column_index;
+    let group_col = spec.binding.group_by;
+
+    if x_col >= result.columns.len()
+        || group_col.is_some_and(|col| col >= result.columns.len())
+        || spec
+            .series
+            .iter()
+            .any(|s| s.column_index >= result.columns.len())
+    {
+        return None;
+    }
+
+    let mut x_values: Vec<Value> = Vec::new();
+    let mut x_positions: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<String> = Vec::new();
+    let mut group_positions: HashMap<String, usize> = HashMap::new();
+
+    // (x position, group position, series position) -> accumulated values.
+    let mut cells: HashMap<(usize, usize, usize), Accumulator> = HashMap::new();
+
+    for row in &result.rows {
+        let x = &row[x_col];
+        if x.is_null() {
+            continue;
+        }
+
+        let group_key = group_col
+            .map(|col| match &row[col] {
+                Value::Null => "(null)".to_string(),
+                value => value.as_shown_string(),
+            })
+            .unwrap_or_default();
+
+        let group_pos = match group_positions.get(&group_key) {
+            Some(&pos) => pos,
+            None if groups.len() < MAX_GROUP_SERIES => {
+                group_positions.insert(group_key.clone(), groups.len());
+                groups.push(group_key);
+                groups.len() - 1
+            }
+            None => continue,
+        };
+
+        let x_key = x.as_display_string();
+        let x_pos = *x_positions.entry(x_key).or_insert_with(|| {
+            x_values.push(x.clone());
+            x_values.len() - 1
+        });
+
+        for (series_pos, series) in spec.series.iter().enumerate() {
+            if let Some(y) = numeric_value(&row[series.column_index]) {
+                cells
+                    .entry((x_pos, group_pos, series_pos))
+                    .or_default()
+                    .push(y);
+            }
+        }
+    }
+
+    if x_values.is_empty() {
+        return None;
+    }
+
+    // One output column per (group, series) pair, groups outermost so a
+    // group's series sit next to each other in the legend.
+    let mut columns = vec![result.columns[x_col].clone()];
+    let mut series = Vec::with_capacity(groups.len() * spec.series.len());
+
+    for (group_pos, group) in groups.iter().enumerate() {
+        for (series_pos, source) in spec.series.iter().enumerate() {
+            let label = match (group_col, spec.series.len()) {
+                (None, _) => source.label.clone(),
+                (Some(_), 1) => group.clone(),
+                (Some(_), _) => format!("{} · {}", group, source.label),
+            };
+
+            series.push(SeriesSpec {
+                column_index: columns.len(),
+                label: label.clone(),
+                color_slot: (group_pos * spec.series.len() + series_pos) as u8,
+            });
+            columns.push(ColumnMeta {
+                name: label,
+                type_name: "float8".to_string(),
+                kind: ColumnKind::Float,
+                nullable: true,
+                is_primary_key: false,
+            });
+        }
+    }
+
+    let rows = x_values
+        .into_iter()
+        .enumerate()
+        .map(|(x_pos, x)| {
+            let mut row = Vec::with_capacity(columns.len());
+            row.push(x);
+
+            for group_pos in 0..groups.len() {
+                for series_pos in 0..spec.series.len() {
+                    let value = cells
+                        .get(&(x_pos, group_pos, series_pos))
+                        .and_then(|acc| acc.finish(spec.binding.aggregation));
+                    row.push(value.map(Value::Float).unwrap_or(Value::Null));
+                }
+            }
+
+            row
+        })
+        .collect();
+
+    let mut pivoted_spec = spec.clone();
+    pivoted_spec.x_axis.column_index = 0;
+    pivoted_spec.series = series;
+    // Pivoted rows no longer map onto source rows one to one.
+    pivoted_spec.track_source_indices = false;
+
+    Some((
+        QueryResult::table(columns, rows, None, Duration::ZERO),
+        pivoted_spec,
+    ))
+}
+
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int(i) => Some(*i as f64),
+        Value::Float(f) => f.is_finite().then_some(*f),
+        Value::Decimal(s) => s.parse::<f64>().ok().filter(|f| f.is_finite()),
+        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct Accumulator {
+    last: f64,
+    sum: f64,
+    min: f64,
+    max: f64,
+    count: usize,
+}
+
+impl Accumulator {
+    fn push(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.last = value;
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn finish(&self, aggregation: AggKind) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(match aggregation {
+            AggKind::None => self.last,
+            AggKind::Sum => self.sum,
+            AggKind::Avg => self.sum / self.count as f64,
+            AggKind::Min => self.min,
+            AggKind::Max => self.max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::spec::{AxisKind, AxisSpec, BindingSpec, ChartKind, YScale};
+
+    fn col(name: &str, kind: ColumnKind) -> ColumnMeta {
+        ColumnMeta {
+            name: name.to_string(),
+            type_name: "t".to_string(),
+            kind,
+            nullable: true,
+            is_primary_key: false,
+        }
+    }
+
+    fn long_result() -> QueryResult {
+        let row = |t: i64, host: &str, cpu: f64| {
+            vec![
+                Value::Int(t),
+                Value::Text(host.to_string()),
+                Value::Float(cpu),
+            ]
+        };
+
+        QueryResult::table(
+            vec![
+                col("t", ColumnKind::Integer),
+                col("host", ColumnKind::Text),
+                col("cpu", ColumnKind::Float),
+            ],
+            vec![
+                row(1, "a", 10.0),
+                row(1, "b", 20.0),
+                row(2, "a", 30.0),
+                row(2, "a", 50.0),
+                row(3, "b", 40.0),
+            ],
+            None,
+            Duration::ZERO,
+        )
+    }
+
+    fn spec(group_by: Option<usize>, aggregation: AggKind) -> ChartSpec {
+        ChartSpec {
+            kind: ChartKind::StackedBar,
+            x_axis: AxisSpec {
+                column_index: 0,
+                label: "t".to_string(),
+                kind: AxisKind::Numeric,
+                unit: None,
+            },
+            series: vec![SeriesSpec {
+                column_index: 2,
+                label: "cpu".to_string(),
+                color_slot: 0,
+            }],
+            legend_visible: false,
+            decimation_threshold: 10_000,
+            binding: BindingSpec {
+                x: 0,
+                y: vec![2],
+                group_by,
+                filter: None,
+                aggregation,
+            },
+            track_source_indices: true,
+            y_scale: YScale::Linear,
+        }
+    }
+
+    #[test]
+    fn pivot_makes_one_series_per_group() {
+        let (result, spec) = pivot_by_group(&long_result(), &spec(Some(1), AggKind::Sum)).unwrap();
+
+        let labels: Vec<&str> = spec.series.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(labels, ["a", "b"]);
+        assert!(!spec.track_source_indices);
+
+        assert_eq!(
+            result.rows,
+            vec![
+                vec![Value::Int(1), Value::Float(10.0), Value::Float(20.0)],
+                vec![Value::Int(2), Value::Float(80.0), Value::Null],
+                vec![Value::Int(3), Value::Null, Value::Float(40.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn aggregation_without_group_merges_duplicate_x_values() {
+        let (result, spec) = pivot_by_group(&long_result(), &spec(None, AggKind::Max)).unwrap();
+
+        assert_eq!(spec.series.len(), 1);
+        assert_eq!(spec.series[0].label, "cpu");
+        assert_eq!(
+            result.rows,
+            vec![
+                vec![Value::Int(1), Value::Float(20.0)],
+                vec![Value::Int(2), Value::Float(50.0)],
+                vec![Value::Int(3), Value::Float(40.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn pivot_caps_the_number_of_groups() {
+        let rows = (0..MAX_GROUP_SERIES + 3)
+            .map(|i| {
+                vec![
+                    Value::Int(1),
+                    Value::Text(format!("host-{}", i)),
+                    Value::Float(i as f64),
+                ]
+            })
+            .collect();
+        let result = QueryResult::table(long_result().columns, rows, None, Duration::ZERO);
+
+        let (_, spec) = pivot_by_group(&result, &spec(Some(1), AggKind::None)).unwrap();
+        assert_eq!(spec.series.len(), MAX_GROUP_SERIES);
+    }
+}
@@ -1,7 +1,8 @@
 //! Legend element factory for line charts.
 //!
 //! `legend_element` renders a row of clickable chips below the canvas, one per
-//! series. Clicking a chip toggles its visibility via `on_toggle_hidden`.
+//! series. Clicking a chip toggles its visibility via `on_toggle_hidden`;
+//! clicking its colour dot cycles the series colour via `on_cycle_color`.
 
 use std::collections::HashSet;
 
@@ -17,13 +18,15 @@ use crate::tokens::{ChartGeometry, Spacing};
 ///
 /// # Parameters
 /// - `series`: series specifications, one chip per entry.
-/// - `palette`: resolved `Hsla` colours indexed by `SeriesSpec::color_slot`.
+/// - `palette`: resolved `Hsla` colours, one per series (see `ChartView::resolved_palette`).
 /// - `stats`: per-series statistics (parallel to `series`); may be `None` for empty series.
 /// - `hidden`: set of hidden series indices; chips for hidden indices are rendered at 40% opacity.
 /// - `focused_series_idx`: currently focused series (chip highlighted with a border).
 /// - `colors`: semantic chart colors for the active theme.
 /// - `on_toggle_hidden`: called with the series index when the chip is clicked.
-pub fn legend_element<F>(
+/// - `on_cycle_color`: called with the series index when the colour dot is clicked.
+#[allow(clippy::too_many_arguments)]
+pub fn legend_element<F, G>(
     series: &[SeriesSpec],
     palette: &[Hsla],
     stats: &[Option<SeriesStats>],
@@ -31,20 +34,26 @@ pub fn legend_element<F>(
     focused_series_idx: usize,
     colors: &ChartColors,
     on_toggle_hidden: Option<F>,
+    on_cycle_color: Option<G>,
 ) -> impl IntoElement
 where
     F: Fn(usize, &mut gpui::Window, &mut gpui::App) + Clone + Send + Sync + 'static,
+    G: Fn(usize, &mut gpui::Window, &mut gpui::App) + Clone + Send + Sync + 'static,
 {
     let total = series.len();
     let visible = total - hidden.len();
-    let counter: SharedString = format!("{} of {} visible · click to hide", visible, total).into();
+    let counter: SharedString = format!(
+        "{} of {} visible · click to hide · click a dot to recolour",
+        visible, total
+    )
+    .into();
 
     let chips: Vec<AnyElement> = series
         .iter()
         .enumerate()
         .map(|(i, s)| {
             let color = palette
-                .get(s.color_slot as usize % palette.len().max(1))
+                .get(i)
                 .copied()
                 .unwrap_or(gpui::hsla(0.6, 0.6, 0.5, 1.0)); // guardrail-allow: OOB palette slot neutral fallback, no semantic token for this case
 
@@ -57,6 +66,24 @@ where
                 opt.map(|st| format!("avg {:.2} · last {:.2}", st.avg, st.last).into())
             });
 
+            let mut swatch = div()
+                .w(Spacing::XXS)
+                .h(Spacing::XXS)
+                .rounded_full()
+                .bg(color);
+
+            if let Some(ref handler) = on_cycle_color {
+                let handler = handler.clone();
+                swatch = swatch.cursor_pointer().on_mouse_down(
+                    gpui::MouseButton::Left,
+                    move |_ev, window, cx| {
+                        // Keep the chip's hide toggle from firing as well.
+                        cx.stop_propagation();
+                        handler(i, window, cx);
+                    },
+                );
+            }
+
             let mut chip = div()
                 .flex()
                 .flex_row()
@@ -67,13 +94,7 @@ where
                 .text_size(ChartGeometry::FONT_LABEL)
                 .when(is_focused, |d| d.font_weight(gpui::FontWeight::SEMIBOLD))
                 .when(is_hidden, |d| d.opacity(0.4))
-                .child(
-                    div()
-                        .w(Spacing::XXS)
-                        .h(Spacing::XXS)
-                        .rounded_full()
-                        .bg(color),
-                )
+                .child(swatch)
                 .child(div().child(label));
 
             if let Some(stat) = stat_str {
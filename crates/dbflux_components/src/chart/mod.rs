@@ -9,9 +9,11 @@
 //!    detection-driven and manual column selection.
 //! 3. **`decimate`** — LTTB downsampling to keep paint fast on large datasets.
 //! 4. **`axis`** — tick generation and label formatting for numeric and time axes.
-//! 5. **`legend`** — pure element factory for the legend pill row.
-//! 6. **`engine`** — `ChartView`, the GPUI entity that owns state and renders
+//! 5. **`group`** — pivots a long-format result into one series per group.
+//! 6. **`legend`** — pure element factory for the legend pill row.
+//! 7. **`engine`** — `ChartView`, the GPUI entity that owns state and renders
 //!    the canvas.
+//! 8. **`export`** — SVG rendering and PNG rasterization for image export.
 
 pub mod axis;
 pub mod axis_bar;
@@ -19,6 +21,8 @@ pub mod data_source;
 pub mod decimate;
 pub mod detect;
 pub mod engine;
+pub mod export;
+pub mod group;
 pub mod legend;
 pub mod point_inspector;
 pub mod spec;
@@ -31,7 +35,11 @@ pub use data_source::{
     resolve_source,
 };
 pub use detect::{ChartDetection, detect_chart_columns};
-pub use engine::{ChartBuildError, ChartView, format_x_value, format_y_value};
+pub use engine::{ChartBuildError, ChartView, PALETTE_SLOTS, format_x_value, format_y_value};
+pub use export::{
+    CHART_EXPORT_OPTIONS, ChartExportError, ChartExportOption, ChartImageFormat,
+    encode_chart_image, rasterize_svg,
+};
 pub use legend::legend_element;
 pub use point_inspector::{DataPointRef, SourceRowRef, point_inspector_element};
 pub use spec::{
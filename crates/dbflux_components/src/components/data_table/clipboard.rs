@@ -1,5 +1,6 @@
 use super::model::{CellKind, CellValue, TableModel};
 use super::selection::{CellCoord, SelectionState};
+use dbflux_core::display_format::display_format;
 
 /// Format a single cell value for clipboard (TSV compatible).
 pub fn format_cell(cell: &CellValue) -> String {
@@ -15,6 +16,27 @@ pub fn format_cell(cell: &CellValue) -> String {
     }
 }
 
+/// Format a cell for clipboard as displayed when `formatted_output` is set and
+/// the cell has a formatted display text, as stored otherwise.
+pub fn format_cell_as(cell: &CellValue, formatted_output: bool) -> String {
+    if formatted_output && cell.is_formatted() {
+        escape_tsv(&cell.display_text())
+    } else {
+        format_cell(cell)
+    }
+}
+
+/// The value as the grid shows it, for "Copy Display Value": numbers and
+/// dates in the display format, text in full rather than the truncated
+/// preview. NULL copies as an empty string, as its marker is display-only.
+pub fn display_value(cell: &CellValue) -> String {
+    match &cell.kind {
+        CellKind::Null => String::new(),
+        CellKind::Text(s) | CellKind::Json(s) if !cell.is_formatted() => s.to_string(),
+        _ => cell.display_text().to_string(),
+    }
+}
+
 /// Escape a string for TSV format.
 /// Replaces tabs and newlines with spaces.
 fn escape_tsv(s: &str) -> String {
@@ -24,17 +46,21 @@ fn escape_tsv(s: &str) -> String {
 /// Copy a single cell to clipboard format.
 #[allow(dead_code)]
 pub fn copy_cell(model: &TableModel, coord: CellCoord) -> Option<String> {
-    model.cell(coord.row, coord.col).map(format_cell)
+    model
+        .cell(coord.row, coord.col)
+        .map(|cell| format_cell(&cell))
 }
 
 /// Copy an entire row to clipboard format (TSV).
 #[allow(dead_code)]
 pub fn copy_row(model: &TableModel, row: usize) -> Option<String> {
-    model.rows.get(row).map(|row_data| {
-        row_data
-            .cells
-            .iter()
-            .map(format_cell)
+    (row < model.row_count()).then(|| {
+        (0..model.col_count())
+            .map(|col| {
+                model
+                    .cell(row, col)
+                    .map_or_else(String::new, |cell| format_cell(&cell))
+            })
             .collect::<Vec<_>>()
             .join("\t")
     })
@@ -43,13 +69,14 @@ pub fn copy_row(model: &TableModel, row: usize) -> Option<String> {
 /// Copy the current selection to clipboard format (TSV).
 pub fn copy_selection(model: &TableModel, selection: &SelectionState) -> Option<String> {
     let range = selection.selected_range()?;
+    let formatted_output = display_format().formatted_output;
 
     let mut lines = Vec::new();
     for row in range.start.row..=range.end.row {
         let mut cells = Vec::new();
         for col in range.start.col..=range.end.col {
             if let Some(cell) = model.cell(row, col) {
-                cells.push(format_cell(cell));
+                cells.push(format_cell_as(&cell, formatted_output));
             } else {
                 cells.push(String::new());
             }
@@ -60,6 +87,56 @@ pub fn copy_selection(model: &TableModel, selection: &SelectionState) -> Option<
     Some(lines.join("\n"))
 }
 
+/// Split clipboard text copied from a spreadsheet or CSV file into rows of
+/// fields. Tab-separated when the text contains a tab, comma-separated
+/// otherwise. Quoted fields may contain the delimiter, newlines and `""`
+/// escapes. Blank lines are skipped.
+pub fn parse_tabular(text: &str) -> Vec<Vec<String>> {
+    let delimiter = if text.contains('\t') { '\t' } else { ',' };
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                push_tabular_row(&mut rows, std::mem::take(&mut row));
+            }
+            c if c == delimiter => row.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    row.push(field);
+    push_tabular_row(&mut rows, row);
+
+    rows
+}
+
+fn push_tabular_row(rows: &mut Vec<Vec<String>>, row: Vec<String>) {
+    if !(row.len() == 1 && row[0].is_empty()) {
+        rows.push(row);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,9 +150,56 @@ mod tests {
         assert_eq!(format_cell(&CellValue::text("hello")), "hello");
     }
 
+    #[test]
+    fn test_format_cell_as_formatted_output() {
+        let date = CellValue::formatted("2026-03-01", "01/03/2026");
+        assert_eq!(format_cell_as(&date, false), "2026-03-01");
+        assert_eq!(format_cell_as(&date, true), "01/03/2026");
+
+        let text = CellValue::text("a\tb");
+        assert_eq!(format_cell_as(&text, true), "a b");
+    }
+
+    #[test]
+    fn test_display_value_is_untruncated_and_formatted() {
+        let long = "word ".repeat(100);
+        assert_eq!(display_value(&CellValue::text(&long)), long);
+
+        let date = CellValue::formatted("2026-03-01", "01/03/2026");
+        assert_eq!(display_value(&date), "01/03/2026");
+        assert_eq!(display_value(&CellValue::null()), "");
+    }
+
     #[test]
     fn test_escape_tsv() {
         assert_eq!(escape_tsv("hello\tworld"), "hello world");
         assert_eq!(escape_tsv("line1\nline2"), "line1 line2");
     }
+
+    #[test]
+    fn test_parse_tabular_tsv() {
+        assert_eq!(
+            parse_tabular("id\tname\r\n1\tAda\r\n2\t\r\n"),
+            vec![
+                vec!["id".to_string(), "name".to_string()],
+                vec!["1".to_string(), "Ada".to_string()],
+                vec!["2".to_string(), String::new()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_tabular_quoted_csv() {
+        assert_eq!(
+            parse_tabular("1,\"Lovelace, Ada\",\"say \"\"hi\"\"\"\n2,\"two\nlines\",x"),
+            vec![
+                vec![
+                    "1".to_string(),
+                    "Lovelace, Ada".to_string(),
+                    "say \"hi\"".to_string()
+                ],
+                vec!["2".to_string(), "two\nlines".to_string(), "x".to_string()],
+            ]
+        );
+    }
 }
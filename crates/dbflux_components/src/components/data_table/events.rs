@@ -10,6 +10,9 @@ pub enum Direction {
     Down,
     Left,
     Right,
+    /// One screen of rows up or down.
+    PageUp,
+    PageDown,
 }
 
 /// Edge for navigation (Home/End, Ctrl+Home/Ctrl+End).
@@ -59,23 +62,45 @@ pub enum FilterOperator {
     Like,
 }
 
+/// Clipboard formats offered by "Copy Selection As".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionCopyFormat {
+    Csv,
+    Json,
+    Markdown,
+    /// One INSERT per row, rendered by the driver's query generator.
+    Insert,
+}
+
 /// Actions available in the context menu.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ContextMenuAction {
     /// Copy the selected cell value to clipboard.
     Copy,
+    /// Copy the clicked cell's value exactly as read from the server.
+    CopyRawValue,
+    /// Copy the clicked cell's value as the grid displays it.
+    CopyDisplayValue,
     /// Paste from clipboard into the selected cell.
     Paste,
     /// Start inline editing of the selected cell.
     Edit,
     /// Open modal editor for the selected cell.
     EditInModal,
+    /// Show the selected cell's full value in the read-only viewer.
+    ViewValue,
     /// Set the cell to its column's default value.
     SetDefault,
     /// Set the cell to NULL.
     SetNull,
+    /// Set the cell to an empty string.
+    SetEmpty,
+    /// Write one value to the column for every selected row.
+    SetValueInSelection,
     /// Insert a new row.
     AddRow,
+    /// Insert the clipboard's tabular rows as new rows.
+    PasteAsRows,
     /// Duplicate the current row.
     DuplicateRow,
     /// Delete the current row.
@@ -94,6 +119,8 @@ pub enum ContextMenuAction {
     CopyAsUpdate,
     /// Copy DELETE to clipboard via query generator.
     CopyAsDelete,
+    /// Copy the selected rows to clipboard in the given format.
+    CopySelectionAs(SelectionCopyFormat),
     /// Filter by cell value with an operator.
     FilterByValue(FilterOperator),
     /// Filter: column IS NULL.
@@ -108,15 +135,30 @@ pub enum ContextMenuAction {
     RemoveOrdering,
     /// Open the row inspector panel for the selected row.
     InspectRow,
+    /// Open the table the cell's foreign key points at, filtered to that row.
+    GoToReferencedRow,
+    /// Open the tables whose foreign keys point at the cell's row, filtered
+    /// to the rows that reference it.
+    ShowReferencingRows,
     /// Open a new ChartDocument seeded with the current query and result columns.
     ChartThisQuery,
+    /// Freeze every column up to and including the clicked one.
+    FreezeColumns,
+    /// Let all columns scroll again.
+    UnfreezeColumns,
+    /// Hide the clicked column.
+    HideColumn,
+    /// Show every hidden column again.
+    ShowAllColumns,
+    /// Show count, NULLs, distinct, min/max and top values of the clicked column.
+    ColumnStats,
 }
 
 /// Events emitted by the DataTable component.
 #[derive(Debug, Clone)]
 pub enum DataTableEvent {
-    /// Sort state changed (None means no sort).
-    SortChanged(Option<SortState>),
+    /// Sort keys changed, highest priority first (empty means no sort).
+    SortChanged(Vec<SortState>),
 
     /// Selection changed.
     #[allow(dead_code)]
@@ -159,6 +201,16 @@ pub enum DataTableEvent {
         is_json: bool,
     },
 
+    /// Request to show a cell's full value in the read-only viewer.
+    ViewCellRequested {
+        row: usize,
+        col: usize,
+        value: String,
+        is_json: bool,
+        /// The cell is NULL rather than an empty string.
+        is_null: bool,
+    },
+
     /// Request to commit a pending insert (insert_idx in pending_inserts list).
     CommitInsertRequested(usize),
 
@@ -171,4 +223,7 @@ pub enum DataTableEvent {
         pending_inserts: Vec<usize>,
         dirty_rows: Vec<usize>,
     },
+
+    /// Columns were reordered, hidden, shown, frozen or unfrozen.
+    ColumnLayoutChanged,
 }
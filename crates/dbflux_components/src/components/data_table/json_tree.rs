@@ -0,0 +1,230 @@
+//! Expandable tree of a JSON cell, shown in a popover anchored to the cell so
+//! nested structure can be browsed without opening the value viewer.
+
+use std::collections::HashSet;
+
+use gpui::{Pixels, Point};
+use serde_json::Value;
+
+use super::selection::CellCoord;
+
+/// Longest scalar rendered on a tree line before it is cut with an ellipsis.
+const MAX_SCALAR_CHARS: usize = 200;
+
+/// Upper bound on lines built for one tree; larger expansions are cut.
+const MAX_TREE_ROWS: usize = 1_000;
+
+/// Path of the root node. Children append `/key` with JSON Pointer escaping.
+pub(super) const ROOT_PATH: &str = "";
+
+/// Open JSON tree popover of one cell.
+pub(super) struct JsonCellPreview {
+    pub(super) coord: CellCoord,
+    /// Window position the popover is anchored at.
+    pub(super) position: Point<Pixels>,
+    value: Value,
+    expanded: HashSet<String>,
+}
+
+/// One visible line of the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct JsonTreeRow {
+    pub(super) path: String,
+    pub(super) depth: usize,
+    /// Object key or array index; `None` for the root.
+    pub(super) label: Option<String>,
+    /// Scalar value, or a `{…}`/`[…]` size summary for containers.
+    pub(super) summary: String,
+    pub(super) expandable: bool,
+    pub(super) expanded: bool,
+}
+
+impl JsonCellPreview {
+    /// Parses `text` into a preview, or `None` when it is not a JSON object
+    /// or array (scalars have no structure to expand).
+    pub(super) fn parse(coord: CellCoord, position: Point<Pixels>, text: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(text).ok()?;
+
+        if !matches!(value, Value::Object(_) | Value::Array(_)) {
+            return None;
+        }
+
+        Some(Self {
+            coord,
+            position,
+            value,
+            expanded: HashSet::from([ROOT_PATH.to_string()]),
+        })
+    }
+
+    pub(super) fn toggle(&mut self, path: &str) {
+        if !self.expanded.remove(path) {
+            self.expanded.insert(path.to_string());
+        }
+    }
+
+    pub(super) fn rows(&self) -> Vec<JsonTreeRow> {
+        let mut rows = Vec::new();
+        push_rows(&self.value, ROOT_PATH, None, 0, &self.expanded, &mut rows);
+        rows
+    }
+}
+
+fn child_path(parent: &str, key: &str) -> String {
+    format!("{}/{}", parent, key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn summarize(value: &Value) -> String {
+    match value {
+        Value::Object(map) => match map.len() {
+            1 => "{…} 1 key".to_string(),
+            n => format!("{{…}} {} keys", n),
+        },
+        Value::Array(items) => match items.len() {
+            1 => "[…] 1 item".to_string(),
+            n => format!("[…] {} items", n),
+        },
+        scalar => {
+            let text = scalar.to_string();
+            if text.chars().count() > MAX_SCALAR_CHARS {
+                let cut: String = text.chars().take(MAX_SCALAR_CHARS).collect();
+                format!("{}…", cut)
+            } else {
+                text
+            }
+        }
+    }
+}
+
+fn push_rows(
+    value: &Value,
+    path: &str,
+    label: Option<String>,
+    depth: usize,
+    expanded: &HashSet<String>,
+    rows: &mut Vec<JsonTreeRow>,
+) {
+    if rows.len() >= MAX_TREE_ROWS {
+        return;
+    }
+
+    let expandable = match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    };
+    let is_expanded = expandable && expanded.contains(path);
+
+    rows.push(JsonTreeRow {
+        path: path.to_string(),
+        depth,
+        label,
+        summary: summarize(value),
+        expandable,
+        expanded: is_expanded,
+    });
+
+    if !is_expanded {
+        return;
+    }
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = child_path(path, key);
+                push_rows(
+                    child,
+                    &child_path,
+                    Some(key.clone()),
+                    depth + 1,
+                    expanded,
+                    rows,
+                );
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                let key = index.to_string();
+                let child_path = child_path(path, &key);
+                push_rows(child, &child_path, Some(key), depth + 1, expanded, rows);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::point;
+
+    fn preview(text: &str) -> Option<JsonCellPreview> {
+        JsonCellPreview::parse(
+            CellCoord::new(0, 0),
+            point(Pixels::ZERO, Pixels::ZERO),
+            text,
+        )
+    }
+
+    fn lines(preview: &JsonCellPreview) -> Vec<String> {
+        preview
+            .rows()
+            .iter()
+            .map(|row| {
+                format!(
+                    "{}{}{}",
+                    "  ".repeat(row.depth),
+                    row.label
+                        .as_ref()
+                        .map(|label| format!("{label}: "))
+                        .unwrap_or_default(),
+                    row.summary
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scalars_and_invalid_json_have_no_tree() {
+        assert!(preview("42").is_none());
+        assert!(preview("\"text\"").is_none());
+        assert!(preview("{broken").is_none());
+        assert!(preview("[]").is_some());
+    }
+
+    #[test]
+    fn root_starts_expanded_and_children_collapsed() {
+        let preview = preview(r#"{"id":7,"owner":{"name":"ann"},"tags":["a","b"]}"#).unwrap();
+
+        assert_eq!(
+            lines(&preview),
+            vec![
+                "{…} 3 keys",
+                "  id: 7",
+                "  owner: {…} 1 key",
+                "  tags: […] 2 items",
+            ]
+        );
+    }
+
+    #[test]
+    fn toggling_a_path_expands_and_collapses_it() {
+        let mut preview = preview(r#"{"a/b":{"c":[true,null]}}"#).unwrap();
+
+        preview.toggle("/a~1b");
+        preview.toggle("/a~1b/c");
+        assert_eq!(
+            lines(&preview),
+            vec![
+                "{…} 1 key",
+                "  a/b: {…} 1 key",
+                "    c: […] 2 items",
+                "      0: true",
+                "      1: null",
+            ]
+        );
+
+        preview.toggle(ROOT_PATH);
+        assert_eq!(lines(&preview), vec!["{…} 1 key"]);
+    }
+}
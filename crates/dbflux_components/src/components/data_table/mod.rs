@@ -1,12 +1,16 @@
 pub mod clipboard;
 mod events;
+mod json_tree;
 pub mod model;
 pub mod selection;
 mod state;
 mod table;
 mod theme;
 
-pub use events::{ContextMenuAction, DataTableEvent, Direction, Edge, FilterOperator, SortState};
+pub use events::{
+    ContextMenuAction, DataTableEvent, Direction, Edge, FilterOperator, SelectionCopyFormat,
+    SortState,
+};
 pub use model::TableModel;
 pub use state::DataTableState;
 pub use table::{DataTable, init};
@@ -1,13 +1,21 @@
 use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::Duration;
 
-use dbflux_core::{ColumnMeta, QueryResult, RowState, Value};
+use dbflux_core::display_format::display_format;
+use dbflux_core::timezone::{self, DISPLAY_TIMESTAMP_FORMAT};
+use dbflux_core::{ColumnMeta, QueryResult, Row, RowState, Value};
 use gpui::TextAlign;
 
+/// Column specs over a result shared with the grid that owns it. Cells are
+/// built on demand, so a frame only pays for the cells in view.
 #[derive(Debug, Clone)]
 pub struct TableModel {
     pub columns: Vec<ColumnSpec>,
-    pub rows: Vec<RowData>,
+    result: Arc<QueryResult>,
+    /// Rows replaced since the result was loaded (e.g. by RETURNING), by index.
+    updated_rows: HashMap<usize, Arc<[Value]>>,
 }
 
 #[allow(dead_code)]
@@ -35,17 +43,16 @@ pub enum ColumnKind {
     Unknown,
 }
 
-#[derive(Debug, Clone)]
-pub struct RowData {
-    pub cells: Vec<CellValue>,
-}
-
 /// Cell values with pre-computed display strings.
-/// The display_text is computed once at construction to avoid per-frame allocation.
+/// The display_text is computed once at construction, so it can be read
+/// repeatedly while the cell is rendered without allocating.
 #[derive(Debug, Clone)]
 pub struct CellValue {
     pub kind: CellKind,
     display_text: Arc<str>,
+    /// The display text is the value in the display format rather than a
+    /// preview, so formatted copies can use it as is.
+    formatted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -65,58 +72,67 @@ pub enum CellKind {
 
 impl TableModel {
     #[allow(dead_code)]
-    pub fn new(columns: Vec<ColumnSpec>, rows: Vec<RowData>) -> Self {
-        Self { columns, rows }
+    pub fn new(columns: Vec<ColumnSpec>, rows: Vec<Row>) -> Self {
+        Self {
+            columns,
+            result: Arc::new(QueryResult::table(Vec::new(), rows, None, Duration::ZERO)),
+            updated_rows: HashMap::new(),
+        }
     }
 
     #[allow(dead_code)]
     pub fn empty() -> Self {
         Self {
             columns: Vec::new(),
-            rows: Vec::new(),
+            result: Arc::new(QueryResult::empty()),
+            updated_rows: HashMap::new(),
         }
     }
 
     pub fn row_count(&self) -> usize {
-        self.rows.len()
+        self.result.rows.len()
     }
 
     pub fn col_count(&self) -> usize {
         self.columns.len()
     }
 
-    pub fn cell(&self, row: usize, col: usize) -> Option<&CellValue> {
-        self.rows.get(row).and_then(|r| r.cells.get(col))
+    /// Builds the cell at `row`, `col` from the shared result.
+    pub fn cell(&self, row: usize, col: usize) -> Option<CellValue> {
+        self.row_values(row)?.get(col).map(CellValue::from)
+    }
+
+    fn row_values(&self, row: usize) -> Option<&[Value]> {
+        match self.updated_rows.get(&row) {
+            Some(values) => Some(&values[..]),
+            None => self.result.rows.get(row).map(Vec::as_slice),
+        }
     }
 
     /// Update a row with new values from the database (e.g., after RETURNING).
     ///
-    /// Returns a new TableModel with the updated row data.
-    pub fn with_row_updated(&self, row_idx: usize, values: &[dbflux_core::Value]) -> Self {
-        let mut new_rows = self.rows.clone();
-        if let Some(row) = new_rows.get_mut(row_idx) {
-            row.cells = values.iter().map(CellValue::from).collect();
+    /// Returns a new TableModel with the updated row data. The result itself
+    /// is shared, not copied.
+    pub fn with_row_updated(&self, row_idx: usize, values: &[Value]) -> Self {
+        let mut updated_rows = self.updated_rows.clone();
+        if row_idx < self.row_count() {
+            updated_rows.insert(row_idx, values.into());
         }
         Self {
             columns: self.columns.clone(),
-            rows: new_rows,
+            result: self.result.clone(),
+            updated_rows,
         }
     }
 }
 
-impl From<&QueryResult> for TableModel {
-    fn from(result: &QueryResult) -> Self {
-        let columns = result.columns.iter().map(ColumnSpec::from).collect();
-
-        let rows = result
-            .rows
-            .iter()
-            .map(|row| RowData {
-                cells: row.iter().map(CellValue::from).collect(),
-            })
-            .collect();
-
-        Self { columns, rows }
+impl From<Arc<QueryResult>> for TableModel {
+    fn from(result: Arc<QueryResult>) -> Self {
+        Self {
+            columns: result.columns.iter().map(ColumnSpec::from).collect(),
+            result,
+            updated_rows: HashMap::new(),
+        }
     }
 }
 
@@ -221,10 +237,28 @@ impl From<&Value> for CellValue {
             Value::Float(f) => CellValue::float(*f),
             Value::Text(s) => CellValue::text(s.as_str()),
             Value::Bytes(b) => CellValue::bytes(b.len()),
-            Value::Decimal(s) => CellValue::text(s.as_str()),
-            Value::DateTime(dt) => CellValue::text(&dt.format("%Y-%m-%d %H:%M:%S").to_string()),
-            Value::Date(d) => CellValue::text(&d.format("%Y-%m-%d").to_string()),
-            Value::Time(t) => CellValue::text(&t.format("%H:%M:%S").to_string()),
+            Value::Decimal(s) => {
+                CellValue::formatted(s.as_str(), &display_format().format_decimal(s))
+            }
+            Value::DateTime(dt) => CellValue::formatted(
+                &dt.format(DISPLAY_TIMESTAMP_FORMAT).to_string(),
+                &timezone::format_timestamp(dt),
+            ),
+            Value::NaiveDateTime(dt) => CellValue::formatted(
+                &dt.format(DISPLAY_TIMESTAMP_FORMAT).to_string(),
+                &display_format().format_datetime(dt),
+            ),
+            Value::Date(d) => CellValue::formatted(
+                &d.format("%Y-%m-%d").to_string(),
+                &display_format().format_date(d),
+            ),
+            Value::Time(t) => CellValue::formatted(
+                &t.format("%H:%M:%S").to_string(),
+                &display_format().format_time(t),
+            ),
+            Value::Uuid(uuid) => CellValue::text(&uuid.to_string()),
+            Value::Interval(interval) => CellValue::text(&interval.to_string()),
+            Value::Enum(label) => CellValue::text(label.as_str()),
             Value::ObjectId(id) => CellValue::text(id.as_str()),
             Value::Unsupported(type_name) => CellValue::unsupported(type_name),
 
@@ -241,7 +275,8 @@ impl CellValue {
     pub fn null() -> Self {
         Self {
             kind: CellKind::Null,
-            display_text: "NULL".into(),
+            display_text: dbflux_core::null_display::null_marker().into(),
+            formatted: false,
         }
     }
 
@@ -249,25 +284,23 @@ impl CellValue {
         Self {
             kind: CellKind::Bool(b),
             display_text: if b { "true" } else { "false" }.into(),
+            formatted: false,
         }
     }
 
     pub fn int(i: i64) -> Self {
         Self {
             kind: CellKind::Int(i),
-            display_text: i.to_string().into(),
+            display_text: display_format().format_int(i).into(),
+            formatted: true,
         }
     }
 
     pub fn float(f: f64) -> Self {
-        let display = if f.fract() == 0.0 && f.abs() < 1e15 {
-            format!("{:.1}", f)
-        } else {
-            f.to_string()
-        };
         Self {
             kind: CellKind::Float(f),
-            display_text: display.into(),
+            display_text: display_format().format_float(f).into(),
+            formatted: true,
         }
     }
 
@@ -288,6 +321,18 @@ impl CellValue {
         Self {
             kind: CellKind::Text(s.into()),
             display_text,
+            formatted: false,
+        }
+    }
+
+    /// A date, time, timestamp or decimal shown in the display format and
+    /// time zone. Editing and copying the cell use `stored`, the value as
+    /// read from the database.
+    pub fn formatted(stored: &str, display: &str) -> Self {
+        Self {
+            kind: CellKind::Text(stored.into()),
+            display_text: display.into(),
+            formatted: true,
         }
     }
 
@@ -295,6 +340,7 @@ impl CellValue {
         Self {
             kind: CellKind::Bytes(len),
             display_text: format!("<{} bytes>", len).into(),
+            formatted: false,
         }
     }
 
@@ -304,6 +350,7 @@ impl CellValue {
         Self {
             kind: CellKind::Unsupported(type_name.into()),
             display_text: label.into(),
+            formatted: false,
         }
     }
 
@@ -324,6 +371,7 @@ impl CellValue {
         Self {
             kind: CellKind::Json(s.into()),
             display_text,
+            formatted: false,
         }
     }
 
@@ -332,6 +380,7 @@ impl CellValue {
         Self {
             kind: CellKind::AutoGenerated(expression.into()),
             display_text: expression.into(),
+            formatted: false,
         }
     }
 
@@ -344,6 +393,11 @@ impl CellValue {
         matches!(self.kind, CellKind::Null)
     }
 
+    /// Whether the display text is the value in the display format.
+    pub fn is_formatted(&self) -> bool {
+        self.formatted
+    }
+
     pub fn is_json(&self) -> bool {
         matches!(self.kind, CellKind::Json(_))
     }
@@ -409,7 +463,7 @@ pub struct PendingInsert {
 /// Represents what a visual row index maps to.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VisualRowSource {
-    /// A row from the base model (index into model.rows).
+    /// A row from the base model (index into the model's rows).
     Base(usize),
     /// A pending insert (index into pending_inserts).
     Insert(usize),
@@ -706,11 +760,35 @@ impl EditBuffer {
         result
     }
 
+    /// Visual row sources for `range` only, clamped to the visual row count.
+    ///
+    /// Without pending inserts every visual row is its base row, so this costs
+    /// only the size of `range` — the body renders each frame from it, and a
+    /// full [`compute_visual_order`](Self::compute_visual_order) would be
+    /// proportional to the whole table.
+    pub fn visual_order_range(&self, range: Range<usize>) -> Vec<VisualRowSource> {
+        if self.pending_inserts.is_empty() {
+            return range
+                .take_while(|&ix| ix < self.base_row_count)
+                .map(VisualRowSource::Base)
+                .collect();
+        }
+
+        let order = self.compute_visual_order();
+        let end = range.end.min(order.len());
+        let start = range.start.min(end);
+        order[start..end].to_vec()
+    }
+
     /// Map a visual row index to its source.
-    /// This is a convenience wrapper that computes the full order and indexes into it.
-    /// For performance with large tables, callers should cache compute_visual_order().
-    #[allow(dead_code)]
+    ///
+    /// Constant time without pending inserts; otherwise computes the full
+    /// order, so loops over many rows should cache `compute_visual_order()`.
     pub fn visual_row_source(&self, visual_idx: usize) -> Option<VisualRowSource> {
+        if self.pending_inserts.is_empty() {
+            return (visual_idx < self.base_row_count).then_some(VisualRowSource::Base(visual_idx));
+        }
+
         self.compute_visual_order().get(visual_idx).copied()
     }
 
@@ -1063,7 +1141,40 @@ impl EditBuffer {
 
 #[cfg(test)]
 mod tests {
-    use super::{CellValue, EditBuffer, VisualRowSource};
+    use super::{CellValue, EditBuffer, TableModel, VisualRowSource};
+    use dbflux_core::{QueryResult, Value};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn cells_are_read_from_the_shared_result() {
+        let result = Arc::new(QueryResult::table(
+            Vec::new(),
+            vec![
+                vec![Value::Int(1), Value::Text("alice".into())],
+                vec![Value::Int(2), Value::Null],
+            ],
+            None,
+            Duration::ZERO,
+        ));
+
+        let model = TableModel::from(result.clone());
+        let updated = model.with_row_updated(1, &[Value::Int(2), Value::Text("bob".into())]);
+
+        assert_eq!(Arc::strong_count(&result), 3);
+        assert_eq!(model.row_count(), 2);
+        assert_eq!(
+            model.cell(0, 1).map(|c| c.edit_text()).as_deref(),
+            Some("alice")
+        );
+        assert!(model.cell(1, 1).is_some_and(|c| c.is_null()));
+        assert_eq!(
+            updated.cell(1, 1).map(|c| c.edit_text()).as_deref(),
+            Some("bob")
+        );
+        assert!(model.cell(2, 0).is_none());
+        assert!(model.cell(0, 2).is_none());
+    }
 
     #[test]
     fn compute_visual_order_interleaves_pending_inserts() {
@@ -1088,6 +1199,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn visual_order_range_matches_full_order() {
+        let mut buffer = EditBuffer::new();
+        buffer.set_base_row_count(4);
+
+        assert_eq!(
+            buffer.visual_order_range(2..10),
+            vec![VisualRowSource::Base(2), VisualRowSource::Base(3)]
+        );
+        assert_eq!(buffer.visual_row_source(4), None);
+
+        buffer.add_pending_insert_after(1, vec![CellValue::text("a")]);
+
+        let full = buffer.compute_visual_order();
+        assert_eq!(buffer.visual_order_range(1..4), full[1..4].to_vec());
+        assert_eq!(
+            buffer.visual_row_source(2),
+            Some(VisualRowSource::Insert(0))
+        );
+        assert!(buffer.visual_order_range(7..9).is_empty());
+    }
+
     #[test]
     fn undo_redo_insert_and_insert_cell_workflow() {
         let mut buffer = EditBuffer::new();
@@ -1123,6 +1256,18 @@ mod tests {
         assert!(buffer.get_pending_insert_by_idx(insert_idx).is_none());
     }
 
+    #[test]
+    fn formatted_cells_edit_and_copy_the_stored_value() {
+        let cell = CellValue::formatted("2026-03-01 22:15:00", "2026-03-02 03:45:00");
+
+        assert_eq!(cell.display_text().as_ref(), "2026-03-02 03:45:00");
+        assert_eq!(cell.edit_text(), "2026-03-01 22:15:00");
+        assert_eq!(
+            super::super::clipboard::format_cell(&cell),
+            "2026-03-01 22:15:00"
+        );
+    }
+
     #[test]
     fn dirty_rows_and_pending_deletes_are_tracked_separately() {
         let mut buffer = EditBuffer::new();
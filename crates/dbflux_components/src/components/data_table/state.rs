@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::ops::Range;
 use std::sync::Arc;
 
 use crate::controls::{InputEvent, InputState};
@@ -9,10 +10,12 @@ use gpui::{
 
 use super::clipboard;
 use super::events::{DataTableEvent, Direction, Edge, SortState};
+use super::json_tree::JsonCellPreview;
 use super::model::{EditBuffer, TableModel};
 use super::selection::{CellCoord, SelectionState};
-use super::theme::{DEFAULT_COLUMN_WIDTH, MIN_COLUMN_WIDTH, SCROLLBAR_WIDTH};
+use super::theme::{DEFAULT_COLUMN_WIDTH, HEADER_HEIGHT, MIN_COLUMN_WIDTH, SCROLLBAR_WIDTH};
 use crate::controls::{Dropdown, DropdownDismissed, DropdownItem, DropdownSelectionChanged};
+use crate::density;
 
 /// Main state for the DataTable component.
 pub struct DataTableState {
@@ -22,11 +25,22 @@ pub struct DataTableState {
     /// Width of each column.
     column_widths: Vec<f32>,
 
-    /// Prefix sums of column widths for hit-testing: [0, w0, w0+w1, ...].
+    /// Shown column indices in display order. Hidden columns are absent.
+    display_columns: Vec<usize>,
+
+    /// Number of leading `display_columns` that stay in place while the
+    /// table scrolls horizontally.
+    frozen_columns: usize,
+
+    /// Prefix sums of the shown columns' widths in display order:
+    /// [0, w0, w0+w1, ...].
     column_offsets: Vec<f32>,
 
-    /// Current sort state.
-    sort: Option<SortState>,
+    /// Current sort keys, highest priority first.
+    sort: Vec<SortState>,
+
+    /// Lowercased search text; cells containing it are highlighted.
+    highlight: Option<String>,
 
     /// Viewport size (updated on layout).
     viewport_size: Size<Pixels>,
@@ -88,6 +102,9 @@ pub struct DataTableState {
 
     /// Enum/set options per column index.
     enum_options: std::collections::HashMap<usize, Vec<String>>,
+
+    /// JSON tree popover opened from a JSON cell.
+    json_preview: Option<JsonCellPreview>,
 }
 
 impl DataTableState {
@@ -106,7 +123,8 @@ impl DataTableState {
                 Self::initial_column_width(name_len)
             })
             .collect();
-        let column_offsets = Self::calculate_offsets(&column_widths);
+        let display_columns: Vec<usize> = (0..col_count).collect();
+        let column_offsets = Self::calculate_offsets(&column_widths, &display_columns);
 
         let mut edit_buffer = EditBuffer::new();
         edit_buffer.set_base_row_count(row_count);
@@ -114,8 +132,11 @@ impl DataTableState {
         Self {
             model,
             column_widths,
+            display_columns,
+            frozen_columns: 0,
             column_offsets,
-            sort: None,
+            sort: Vec::new(),
+            highlight: None,
             viewport_size: Size::default(),
             selection: SelectionState::new(),
             focus_handle: cx.focus_handle(),
@@ -133,6 +154,7 @@ impl DataTableState {
             is_editable: false,
             is_insertable: false,
             enum_options: std::collections::HashMap::new(),
+            json_preview: None,
         }
     }
 
@@ -148,11 +170,11 @@ impl DataTableState {
         name_width.max(DEFAULT_COLUMN_WIDTH).max(MIN_COLUMN_WIDTH)
     }
 
-    fn calculate_offsets(widths: &[f32]) -> Vec<f32> {
+    fn calculate_offsets(widths: &[f32], display_columns: &[usize]) -> Vec<f32> {
         let mut offsets = vec![0.0];
         let mut sum = 0.0;
-        for w in widths {
-            sum += w;
+        for &col in display_columns {
+            sum += widths.get(col).copied().unwrap_or(0.0);
             offsets.push(sum);
         }
         offsets
@@ -175,6 +197,7 @@ impl DataTableState {
     #[allow(dead_code)]
     pub fn set_model(&mut self, model: Arc<TableModel>, cx: &mut Context<Self>) {
         self.model = model;
+        self.json_preview = None;
         // Emit SelectionChanged so the audit viewer's subscription can validate
         // that the selected row is still valid in the new model. If the row count
         // decreased, the selection may now be out of bounds.
@@ -207,7 +230,8 @@ impl DataTableState {
         if col < self.column_widths.len() {
             let min_width = super::theme::MIN_COLUMN_WIDTH;
             self.column_widths[col] = width.max(min_width);
-            self.column_offsets = Self::calculate_offsets(&self.column_widths);
+            self.column_offsets =
+                Self::calculate_offsets(&self.column_widths, &self.display_columns);
             cx.notify();
         }
     }
@@ -216,6 +240,228 @@ impl DataTableState {
         *self.column_offsets.last().unwrap_or(&0.0)
     }
 
+    /// Display positions of the scrolling columns that intersect the
+    /// horizontal viewport, plus one column of overscan on each side. Frozen
+    /// columns are never included; they are drawn over the left edge of the
+    /// viewport. Every column counts as visible until the first layout
+    /// reports a viewport size.
+    pub fn visible_columns(&self) -> Range<usize> {
+        let frozen = self.frozen_columns;
+
+        if self.viewport_size.width <= px(0.0) {
+            return frozen..self.display_columns.len();
+        }
+
+        let frozen_width = self.frozen_width();
+        let visible = visible_column_range(
+            &self.column_offsets,
+            f32::from(self.horizontal_offset) + frozen_width,
+            f32::from(self.viewport_size.width) - frozen_width,
+        );
+
+        visible.start.max(frozen)..visible.end.max(frozen)
+    }
+
+    /// Left edge of the column at display position `position` in content
+    /// coordinates.
+    pub fn column_offset(&self, position: usize) -> f32 {
+        self.column_offsets.get(position).copied().unwrap_or(0.0)
+    }
+
+    /// Combined width of the frozen columns.
+    pub fn frozen_width(&self) -> f32 {
+        self.column_offset(self.frozen_columns)
+    }
+
+    // --- Column Arrangement ---
+
+    /// Shown column indices in display order.
+    pub fn display_columns(&self) -> &[usize] {
+        &self.display_columns
+    }
+
+    /// Number of leading display columns that stay in place while scrolling.
+    pub fn frozen_column_count(&self) -> usize {
+        self.frozen_columns
+    }
+
+    /// Display position of column `col`, or `None` while it is hidden.
+    pub fn display_position(&self, col: usize) -> Option<usize> {
+        self.display_columns.iter().position(|&shown| shown == col)
+    }
+
+    pub fn hidden_column_count(&self) -> usize {
+        self.col_count().saturating_sub(self.display_columns.len())
+    }
+
+    /// Whether the columns are shown in their natural order, none hidden or
+    /// frozen.
+    pub fn has_default_column_layout(&self) -> bool {
+        self.frozen_columns == 0 && self.display_columns.iter().copied().eq(0..self.col_count())
+    }
+
+    /// Apply a column arrangement without emitting an event (for initial
+    /// state). Ignored unless `display_columns` lists each column at most once.
+    pub fn set_column_layout_without_emit(&mut self, display_columns: Vec<usize>, frozen: usize) {
+        let col_count = self.col_count();
+        let mut seen = HashSet::new();
+
+        if display_columns.is_empty()
+            || !display_columns
+                .iter()
+                .all(|&col| col < col_count && seen.insert(col))
+        {
+            return;
+        }
+
+        self.frozen_columns = frozen.min(display_columns.len());
+        self.display_columns = display_columns;
+        self.column_offsets = Self::calculate_offsets(&self.column_widths, &self.display_columns);
+    }
+
+    /// Freeze every column from the left edge up to and including `col`.
+    pub fn freeze_columns_through(&mut self, col: usize, cx: &mut Context<Self>) {
+        if let Some(position) = self.display_position(col) {
+            let display_columns = self.display_columns.clone();
+            self.apply_column_layout(display_columns, position + 1, cx);
+        }
+    }
+
+    pub fn unfreeze_columns(&mut self, cx: &mut Context<Self>) {
+        let display_columns = self.display_columns.clone();
+        self.apply_column_layout(display_columns, 0, cx);
+    }
+
+    /// Hide column `col`. The last shown column cannot be hidden.
+    pub fn hide_column(&mut self, col: usize, cx: &mut Context<Self>) {
+        let Some(position) = self.display_position(col) else {
+            return;
+        };
+
+        if self.display_columns.len() == 1 {
+            return;
+        }
+
+        if self.editing_cell.is_some_and(|cell| cell.col == col) {
+            self.stop_editing(false, cx);
+        }
+
+        if self.selection.active.is_some_and(|cell| cell.col == col) {
+            self.clear_selection(cx);
+        }
+
+        let mut display_columns = self.display_columns.clone();
+        display_columns.remove(position);
+
+        let frozen = if position < self.frozen_columns {
+            self.frozen_columns - 1
+        } else {
+            self.frozen_columns
+        };
+
+        self.apply_column_layout(display_columns, frozen, cx);
+    }
+
+    /// Show every hidden column again, each next to its neighbours in the
+    /// natural column order.
+    pub fn show_all_columns(&mut self, cx: &mut Context<Self>) {
+        let mut display_columns = self.display_columns.clone();
+        let mut frozen = self.frozen_columns;
+
+        for col in 0..self.col_count() {
+            if display_columns.contains(&col) {
+                continue;
+            }
+
+            let position = display_columns
+                .iter()
+                .rposition(|&shown| shown < col)
+                .map_or(0, |ix| ix + 1);
+
+            if position < frozen {
+                frozen += 1;
+            }
+
+            display_columns.insert(position, col);
+        }
+
+        self.apply_column_layout(display_columns, frozen, cx);
+    }
+
+    /// Move column `col` into the display position of column `target`.
+    /// Dropping onto a frozen column freezes the moved column; dropping a
+    /// frozen column onto a scrolling one unfreezes it.
+    pub fn move_column(&mut self, col: usize, target: usize, cx: &mut Context<Self>) {
+        let (Some(from), Some(to)) = (self.display_position(col), self.display_position(target))
+        else {
+            return;
+        };
+
+        if from == to {
+            return;
+        }
+
+        let mut display_columns = self.display_columns.clone();
+        display_columns.remove(from);
+        display_columns.insert(to, col);
+
+        let frozen = match (from < self.frozen_columns, to < self.frozen_columns) {
+            (true, false) => self.frozen_columns - 1,
+            (false, true) => self.frozen_columns + 1,
+            _ => self.frozen_columns,
+        };
+
+        self.apply_column_layout(display_columns, frozen, cx);
+    }
+
+    /// Show every column in its natural order with none frozen.
+    pub fn reset_column_layout(&mut self, cx: &mut Context<Self>) {
+        self.apply_column_layout((0..self.col_count()).collect(), 0, cx);
+    }
+
+    fn apply_column_layout(
+        &mut self,
+        display_columns: Vec<usize>,
+        frozen: usize,
+        cx: &mut Context<Self>,
+    ) {
+        let frozen = frozen.min(display_columns.len());
+
+        if self.display_columns == display_columns && self.frozen_columns == frozen {
+            return;
+        }
+
+        self.display_columns = display_columns;
+        self.frozen_columns = frozen;
+        self.column_offsets = Self::calculate_offsets(&self.column_widths, &self.display_columns);
+
+        // The content may have become narrower than the current scroll offset.
+        self.sync_horizontal_offset(cx);
+
+        cx.emit(DataTableEvent::ColumnLayoutChanged);
+        cx.notify();
+    }
+
+    /// Column shown `step` display positions away from `col`, clamped to the
+    /// first and last shown columns. A hidden `col` resolves to the first
+    /// shown column.
+    fn neighbour_column(&self, col: usize, step: isize) -> usize {
+        let last = self.display_columns.len().saturating_sub(1);
+        let position = self
+            .display_position(col)
+            .map_or(0, |position| position.saturating_add_signed(step).min(last));
+
+        self.display_columns.get(position).copied().unwrap_or(col)
+    }
+
+    fn first_display_column(&self) -> usize {
+        self.display_columns.first().copied().unwrap_or(0)
+    }
+
+    fn last_display_column(&self) -> usize {
+        self.display_columns.last().copied().unwrap_or(0)
+    }
+
     // --- Viewport ---
 
     pub fn viewport_size(&self) -> Size<Pixels> {
@@ -231,30 +477,51 @@ impl DataTableState {
 
     // --- Sort ---
 
-    pub fn sort(&self) -> Option<&SortState> {
-        self.sort.as_ref()
+    pub fn sort(&self) -> &[SortState] {
+        &self.sort
     }
 
-    pub fn set_sort(&mut self, sort: Option<SortState>, cx: &mut Context<Self>) {
+    pub fn set_sort(&mut self, sort: Vec<SortState>, cx: &mut Context<Self>) {
         if self.sort != sort {
-            self.sort = sort;
+            self.sort = sort.clone();
             cx.emit(DataTableEvent::SortChanged(sort));
             cx.notify();
         }
     }
 
     /// Set sort state without emitting an event (for initial state).
-    pub fn set_sort_without_emit(&mut self, sort: SortState) {
-        self.sort = Some(sort);
+    pub fn set_sort_without_emit(&mut self, sort: Vec<SortState>) {
+        self.sort = sort;
     }
 
-    /// Cycle sort state for a column: none -> asc -> desc -> none
-    pub fn cycle_sort(&mut self, col_ix: usize, cx: &mut Context<Self>) {
-        let new_sort = next_sort_state(self.sort, col_ix);
+    /// Cycle sort state for a column: none -> asc -> desc -> none.
+    ///
+    /// With `additive` (shift-click) the column is added to or cycled within
+    /// the existing sort keys instead of replacing them.
+    pub fn cycle_sort(&mut self, col_ix: usize, additive: bool, cx: &mut Context<Self>) {
+        let new_sort = next_sort_state(&self.sort, col_ix, additive);
 
         self.set_sort(new_sort, cx);
     }
 
+    // --- Search Highlight ---
+
+    pub fn highlight(&self) -> Option<&str> {
+        self.highlight.as_deref()
+    }
+
+    /// Highlight cells whose text contains `text` (case-insensitive). Blank
+    /// text clears the highlight.
+    pub fn set_highlight(&mut self, text: &str, cx: &mut Context<Self>) {
+        let text = text.trim();
+        let highlight = (!text.is_empty()).then(|| text.to_lowercase());
+
+        if self.highlight != highlight {
+            self.highlight = highlight;
+            cx.notify();
+        }
+    }
+
     // --- Selection ---
 
     pub fn selection(&self) -> &SelectionState {
@@ -301,16 +568,28 @@ impl DataTableState {
 
         // No selection yet - select first cell
         let Some(current) = self.selection.active else {
-            self.select_cell(CellCoord::new(0, 0), cx);
-            self.scroll_to_cell(0, 0);
+            let first_col = self.first_display_column();
+            self.select_cell(CellCoord::new(0, first_col), cx);
+            self.scroll_to_cell(0, first_col);
             return;
         };
 
+        let page = page_row_count(
+            self.viewport_size.height,
+            density::zoomed(cx, HEADER_HEIGHT) + SCROLLBAR_WIDTH,
+            density::grid_row_height(cx),
+        );
+
+        // Left and right follow the display order, skipping hidden columns.
         let new_coord = match direction {
             Direction::Up => CellCoord::new(current.row.saturating_sub(1), current.col),
             Direction::Down => CellCoord::new((current.row + 1).min(row_count - 1), current.col),
-            Direction::Left => CellCoord::new(current.row, current.col.saturating_sub(1)),
-            Direction::Right => CellCoord::new(current.row, (current.col + 1).min(col_count - 1)),
+            Direction::Left => CellCoord::new(current.row, self.neighbour_column(current.col, -1)),
+            Direction::Right => CellCoord::new(current.row, self.neighbour_column(current.col, 1)),
+            Direction::PageUp => CellCoord::new(current.row.saturating_sub(page), current.col),
+            Direction::PageDown => {
+                CellCoord::new((current.row + page).min(row_count - 1), current.col)
+            }
         };
 
         if extend {
@@ -331,14 +610,19 @@ impl DataTableState {
             return;
         }
 
-        let current = self.selection.active.unwrap_or(CellCoord::new(0, 0));
+        let first_col = self.first_display_column();
+        let last_col = self.last_display_column();
+        let current = self
+            .selection
+            .active
+            .unwrap_or(CellCoord::new(0, first_col));
         let new_coord = match edge {
             Edge::Top => CellCoord::new(0, current.col),
             Edge::Bottom => CellCoord::new(row_count - 1, current.col),
-            Edge::Left => CellCoord::new(current.row, 0),
-            Edge::Right => CellCoord::new(current.row, col_count - 1),
-            Edge::Home => CellCoord::new(0, 0),
-            Edge::End => CellCoord::new(row_count - 1, col_count - 1),
+            Edge::Left => CellCoord::new(current.row, first_col),
+            Edge::Right => CellCoord::new(current.row, last_col),
+            Edge::Home => CellCoord::new(0, first_col),
+            Edge::End => CellCoord::new(row_count - 1, last_col),
         };
 
         if extend {
@@ -350,6 +634,48 @@ impl DataTableState {
         self.scroll_to_cell(new_coord.row, new_coord.col);
     }
 
+    // --- Accessibility ---
+
+    /// Where the cursor is and what it holds, as one readable line:
+    /// `Row 3 of 120, email (varchar): alice@example.com`, followed by the
+    /// size of a multi-cell selection. `None` without an active cell.
+    ///
+    /// GPUI has no accessibility tree, so hosts show this text where a
+    /// screen reader or magnifier user can find it.
+    pub fn active_cell_description(&self) -> Option<String> {
+        const MAX_VALUE_CHARS: usize = 80;
+
+        let active = self.selection.active?;
+        let column = self.model.columns.get(active.col)?;
+        let cell = self.visual_cell(active)?;
+
+        let text = cell.display_text();
+        let mut value: String = text.chars().take(MAX_VALUE_CHARS).collect();
+        if text.chars().count() > MAX_VALUE_CHARS {
+            value.push('…');
+        }
+
+        let mut description = format!(
+            "Row {} of {}, {}",
+            active.row + 1,
+            self.row_count(),
+            column.title
+        );
+        if !column.type_name.is_empty() {
+            description.push_str(&format!(" ({})", column.type_name));
+        }
+        description.push_str(&format!(": {value}"));
+
+        if let Some(range) = self.selection.selected_range() {
+            let cells = range.row_count() * range.col_count();
+            if cells > 1 {
+                description.push_str(&format!(", {cells} cells selected"));
+            }
+        }
+
+        Some(description)
+    }
+
     // --- Clipboard ---
 
     pub fn copy_selection(&self) -> Option<String> {
@@ -417,29 +743,36 @@ impl DataTableState {
             .scroll_to_item(row, gpui::ScrollStrategy::Center);
     }
 
-    /// Scroll to ensure the given column is visible.
+    /// Scroll to ensure the given column is visible. Frozen and hidden
+    /// columns never scroll.
     pub fn scroll_to_column(&self, col: usize) {
-        if col >= self.column_offsets.len() {
+        let Some(position) = self.display_position(col) else {
+            return;
+        };
+
+        if position < self.frozen_columns {
             return;
         }
 
-        let col_left = px(self.column_offsets[col]);
+        let col_left = px(self.column_offsets[position]);
         let col_right = px(*self
             .column_offsets
-            .get(col + 1)
-            .unwrap_or(&self.column_offsets[col]));
+            .get(position + 1)
+            .unwrap_or(&self.column_offsets[position]));
 
         let viewport_width = self.viewport_size.width - SCROLLBAR_WIDTH;
         if viewport_width <= px(0.0) {
             return;
         }
 
+        // Frozen columns cover the left edge of the viewport.
+        let frozen_width = px(self.frozen_width());
         let current_offset = self.horizontal_offset;
-        let visible_left = current_offset;
+        let visible_left = current_offset + frozen_width;
         let visible_right = current_offset + viewport_width;
 
         let new_offset = if col_left < visible_left {
-            col_left
+            col_left - frozen_width
         } else if col_right > visible_right {
             col_right - viewport_width
         } else {
@@ -588,10 +921,7 @@ impl DataTableState {
             .unwrap_or(ColumnKind::Unknown);
 
         // Translate visual row to source (base or pending insert)
-        let visual_order = self.edit_buffer.compute_visual_order();
-        let null_cell = super::model::CellValue::null();
-
-        let row_source = visual_order.get(coord.row).copied();
+        let row_source = self.edit_buffer.visual_row_source(coord.row);
 
         // Check if editing is allowed for this row and column.
         let can_edit = match row_source {
@@ -610,9 +940,11 @@ impl DataTableState {
 
         let (initial_value, needs_modal, is_json_cell, is_unsupported_cell) = match row_source {
             Some(VisualRowSource::Base(base_idx)) => {
-                let base_cell = self.model.cell(base_idx, coord.col);
-                let base = base_cell.unwrap_or(&null_cell);
-                let cell = self.edit_buffer.get_cell(base_idx, coord.col, base);
+                let base = self
+                    .model
+                    .cell(base_idx, coord.col)
+                    .unwrap_or_else(super::model::CellValue::null);
+                let cell = self.edit_buffer.get_cell(base_idx, coord.col, &base);
 
                 (
                     cell.edit_text(),
@@ -742,6 +1074,104 @@ impl DataTableState {
         true
     }
 
+    /// Asks for the full value of a cell to be shown in the read-only viewer.
+    /// Returns false for cells with nothing to show (unsupported and
+    /// auto-generated values).
+    /// Note: `coord` uses visual row indices (accounting for pending inserts).
+    pub fn request_cell_view(&mut self, coord: CellCoord, cx: &mut Context<Self>) -> bool {
+        use super::model::{CellKind, ColumnKind};
+
+        let Some(cell) = self.visual_cell(coord) else {
+            return false;
+        };
+
+        if matches!(
+            cell.kind,
+            CellKind::Unsupported(_) | CellKind::AutoGenerated(_)
+        ) {
+            return false;
+        }
+
+        let is_json = cell.is_json()
+            || self
+                .model
+                .columns
+                .get(coord.col)
+                .is_some_and(|column| column.kind == ColumnKind::Json);
+
+        cx.emit(DataTableEvent::ViewCellRequested {
+            row: coord.row,
+            col: coord.col,
+            value: cell.edit_text(),
+            is_json,
+            is_null: cell.is_null(),
+        });
+        true
+    }
+
+    /// Current value of a cell, including unsaved edits and pending inserts.
+    /// Note: `coord` uses visual row indices (accounting for pending inserts).
+    fn visual_cell(&self, coord: CellCoord) -> Option<super::model::CellValue> {
+        use super::model::{CellValue, VisualRowSource};
+
+        let null_cell = CellValue::null();
+
+        match self.edit_buffer.visual_row_source(coord.row)? {
+            VisualRowSource::Base(base_idx) => {
+                let base = self.model.cell(base_idx, coord.col).unwrap_or(null_cell);
+                Some(
+                    self.edit_buffer
+                        .get_cell(base_idx, coord.col, &base)
+                        .clone(),
+                )
+            }
+            VisualRowSource::Insert(insert_idx) => Some(
+                self.edit_buffer
+                    .get_pending_insert_by_idx(insert_idx)
+                    .and_then(|insert_data| insert_data.get(coord.col))
+                    .cloned()
+                    .unwrap_or(null_cell),
+            ),
+        }
+    }
+
+    // --- JSON tree popover ---
+
+    pub(super) fn json_preview(&self) -> Option<&JsonCellPreview> {
+        self.json_preview.as_ref()
+    }
+
+    /// Opens the JSON tree of a cell at `position`. Returns false when the
+    /// cell does not hold a JSON object or array.
+    pub fn open_json_preview(
+        &mut self,
+        coord: CellCoord,
+        position: Point<Pixels>,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(cell) = self.visual_cell(coord).filter(|cell| cell.is_json()) else {
+            return false;
+        };
+
+        self.json_preview = JsonCellPreview::parse(coord, position, &cell.edit_text());
+        cx.notify();
+        self.json_preview.is_some()
+    }
+
+    pub fn close_json_preview(&mut self, cx: &mut Context<Self>) {
+        if self.json_preview.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    /// Expands or collapses a node of the open JSON tree.
+    pub(super) fn toggle_json_preview_node(&mut self, path: &str, cx: &mut Context<Self>) {
+        if let Some(preview) = self.json_preview.as_mut() {
+            preview.toggle(path);
+            cx.notify();
+        }
+    }
+
     /// Get the cell input state if currently editing.
     pub fn cell_input(&self) -> Option<&Entity<InputState>> {
         self.cell_input.as_ref()
@@ -764,15 +1194,13 @@ impl DataTableState {
         self.editing_cell = None;
         self.enum_dropdown = None;
 
-        let visual_order = self.edit_buffer.compute_visual_order();
-
         let cell_value = if value == Self::NULL_SENTINEL {
             super::model::CellValue::null()
         } else {
             super::model::CellValue::text(value)
         };
 
-        match visual_order.get(coord.row).copied() {
+        match self.edit_buffer.visual_row_source(coord.row) {
             Some(VisualRowSource::Base(base_idx)) => {
                 self.edit_buffer.set_cell(base_idx, coord.col, cell_value);
             }
@@ -835,14 +1263,12 @@ impl DataTableState {
                 let value_str = input.read(cx).value().to_string();
 
                 // Translate visual row to source
-                let visual_order = self.edit_buffer.compute_visual_order();
-
-                match visual_order.get(coord.row).copied() {
+                match self.edit_buffer.visual_row_source(coord.row) {
                     Some(VisualRowSource::Base(base_idx)) => {
                         let original = self
                             .model
                             .cell(base_idx, coord.col)
-                            .map(|c| c.display_text().to_string())
+                            .map(|c| c.edit_text())
                             .unwrap_or_default();
 
                         if value_str != original {
@@ -898,8 +1324,7 @@ impl DataTableState {
         use super::model::VisualRowSource;
 
         if let Some(coord) = self.selection.active {
-            let visual_order = self.edit_buffer.compute_visual_order();
-            match visual_order.get(coord.row).copied() {
+            match self.edit_buffer.visual_row_source(coord.row) {
                 Some(VisualRowSource::Base(base_idx)) => {
                     let row_state = self.edit_buffer.row_state(base_idx);
                     if row_state.is_pending_delete() {
@@ -971,20 +1396,61 @@ impl DataTableState {
     }
 }
 
-fn next_sort_state(current: Option<SortState>, col_ix: usize) -> Option<SortState> {
-    match current {
-        Some(SortState {
-            column_ix,
-            direction,
-        }) if column_ix == col_ix => {
-            use dbflux_core::SortDirection::*;
-            match direction {
-                Ascending => Some(SortState::descending(col_ix)),
-                Descending => None,
+/// Columns of `offsets` (prefix sums of the widths) that intersect
+/// `left..left + width`, widened by one column on each side.
+/// Rows one page up/down moves: the body's visible rows less one, so the
+/// row at the edge stays on screen. Before the first layout, when the height
+/// is unknown, a page is 20 rows.
+fn page_row_count(viewport_height: Pixels, chrome_height: Pixels, row_height: Pixels) -> usize {
+    const UNMEASURED_PAGE_ROWS: usize = 20;
+
+    if viewport_height <= px(0.0) || row_height <= px(0.0) {
+        return UNMEASURED_PAGE_ROWS;
+    }
+
+    let visible_rows = ((viewport_height - chrome_height) / row_height).floor();
+    (visible_rows as usize).saturating_sub(1).max(1)
+}
+
+fn visible_column_range(offsets: &[f32], left: f32, width: f32) -> Range<usize> {
+    let col_count = offsets.len().saturating_sub(1);
+    let right = left + width;
+
+    // Offsets are sorted, so both bounds are binary searches.
+    let first = offsets[1..].partition_point(|&end| end <= left);
+    let last = offsets[..col_count].partition_point(|&start| start < right);
+
+    first.saturating_sub(1)..(last + 1).min(col_count)
+}
+
+fn next_sort_state(current: &[SortState], col_ix: usize, additive: bool) -> Vec<SortState> {
+    use dbflux_core::SortDirection::*;
+
+    let existing = current.iter().position(|s| s.column_ix == col_ix);
+
+    if !additive {
+        return match (current, existing) {
+            ([only], Some(_)) => match only.direction {
+                Ascending => vec![SortState::descending(col_ix)],
+                Descending => Vec::new(),
+            },
+            _ => vec![SortState::ascending(col_ix)],
+        };
+    }
+
+    let mut next = current.to_vec();
+
+    match existing {
+        Some(ix) => match next[ix].direction {
+            Ascending => next[ix] = SortState::descending(col_ix),
+            Descending => {
+                next.remove(ix);
             }
-        }
-        _ => Some(SortState::ascending(col_ix)),
+        },
+        None => next.push(SortState::ascending(col_ix)),
     }
+
+    next
 }
 
 impl EventEmitter<DataTableEvent> for DataTableState {}
@@ -997,28 +1463,66 @@ impl Focusable for DataTableState {
 
 #[cfg(test)]
 mod tests {
-    use super::next_sort_state;
+    use super::{next_sort_state, page_row_count, visible_column_range};
     use crate::components::data_table::events::SortState;
+    use gpui::px;
 
     #[test]
     fn next_sort_state_cycles_none_asc_desc_none() {
         let column = 3;
 
-        let step1 = next_sort_state(None, column);
-        assert_eq!(step1, Some(SortState::ascending(column)));
+        let step1 = next_sort_state(&[], column, false);
+        assert_eq!(step1, vec![SortState::ascending(column)]);
 
-        let step2 = next_sort_state(step1, column);
-        assert_eq!(step2, Some(SortState::descending(column)));
+        let step2 = next_sort_state(&step1, column, false);
+        assert_eq!(step2, vec![SortState::descending(column)]);
 
-        let step3 = next_sort_state(step2, column);
-        assert_eq!(step3, None);
+        let step3 = next_sort_state(&step2, column, false);
+        assert!(step3.is_empty());
     }
 
     #[test]
     fn next_sort_state_switches_to_new_column_ascending() {
-        let current = Some(SortState::descending(1));
-        let next = next_sort_state(current, 5);
-        assert_eq!(next, Some(SortState::ascending(5)));
+        let current = [SortState::descending(1)];
+        let next = next_sort_state(&current, 5, false);
+        assert_eq!(next, vec![SortState::ascending(5)]);
+    }
+
+    #[test]
+    fn next_sort_state_additive_appends_and_cycles_in_place() {
+        let step1 = next_sort_state(&[SortState::descending(1)], 5, true);
+        assert_eq!(
+            step1,
+            vec![SortState::descending(1), SortState::ascending(5)]
+        );
+
+        let step2 = next_sort_state(&step1, 1, true);
+        assert_eq!(step2, vec![SortState::ascending(5)]);
+
+        // A plain click on a multi-key sort starts over from that column.
+        let step3 = next_sort_state(&step1, 5, false);
+        assert_eq!(step3, vec![SortState::ascending(5)]);
+    }
+
+    #[test]
+    fn page_row_count_keeps_one_row_of_context() {
+        // 32px header + 12px scrollbar leave 280px: ten 28px rows.
+        assert_eq!(page_row_count(px(324.0), px(44.0), px(28.0)), 9);
+        // A table shorter than two rows still moves by one.
+        assert_eq!(page_row_count(px(60.0), px(44.0), px(28.0)), 1);
+        // Before layout the viewport is empty.
+        assert_eq!(page_row_count(px(0.0), px(44.0), px(28.0)), 20);
+    }
+
+    #[test]
+    fn visible_column_range_covers_viewport_with_overscan() {
+        // Five 100px columns.
+        let offsets = [0.0, 100.0, 200.0, 300.0, 400.0, 500.0];
+
+        assert_eq!(visible_column_range(&offsets, 0.0, 150.0), 0..3);
+        assert_eq!(visible_column_range(&offsets, 250.0, 100.0), 1..5);
+        assert_eq!(visible_column_range(&offsets, 400.0, 300.0), 3..5);
+        assert_eq!(visible_column_range(&[0.0], 0.0, 100.0), 0..0);
     }
 
     // =========================================================================
@@ -1049,9 +1553,8 @@ mod tests {
     }
 
     fn one_row_model() -> std::sync::Arc<super::super::model::TableModel> {
-        use crate::components::data_table::model::{
-            CellValue, ColumnKind, ColumnSpec, RowData, TableModel,
-        };
+        use crate::components::data_table::model::{ColumnKind, ColumnSpec, TableModel};
+        use dbflux_core::Value;
         use gpui::TextAlign;
 
         let columns = vec![
@@ -1070,16 +1573,13 @@ mod tests {
                 type_name: "text".into(),
             },
         ];
-        let rows = vec![RowData {
-            cells: vec![CellValue::int(1), CellValue::text("alice")],
-        }];
+        let rows = vec![vec![Value::Int(1), Value::Text("alice".into())]];
         std::sync::Arc::new(TableModel::new(columns, rows))
     }
 
     fn two_row_model() -> std::sync::Arc<super::super::model::TableModel> {
-        use crate::components::data_table::model::{
-            CellValue, ColumnKind, ColumnSpec, RowData, TableModel,
-        };
+        use crate::components::data_table::model::{ColumnKind, ColumnSpec, TableModel};
+        use dbflux_core::Value;
         use gpui::TextAlign;
 
         let columns = vec![
@@ -1099,12 +1599,8 @@ mod tests {
             },
         ];
         let rows = vec![
-            RowData {
-                cells: vec![CellValue::int(1), CellValue::text("alice")],
-            },
-            RowData {
-                cells: vec![CellValue::int(2), CellValue::text("bob")],
-            },
+            vec![Value::Int(1), Value::Text("alice".into())],
+            vec![Value::Int(2), Value::Text("bob".into())],
         ];
         std::sync::Arc::new(TableModel::new(columns, rows))
     }
@@ -1200,6 +1696,102 @@ mod tests {
         );
     }
 
+    /// Moving, freezing and hiding columns keep the display order, the frozen
+    /// prefix and keyboard navigation consistent with each other.
+    #[gpui::test]
+    fn column_arrangement_drives_display_order_and_navigation(cx: &mut gpui::TestAppContext) {
+        use super::super::events::Edge;
+        use super::super::selection::CellCoord;
+
+        let state_holder = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let holder_clone = state_holder.clone();
+
+        let (_, window) = cx.add_window_view(move |_window, cx| {
+            let model = one_row_model();
+            let state = cx.new(|cx| super::DataTableState::new(model, cx));
+            holder_clone.replace(Some(state.clone()));
+            StateHarness { state }
+        });
+
+        let state = state_holder
+            .borrow()
+            .clone()
+            .expect("state entity must be created");
+
+        window.update(|_window, app| {
+            state.update(app, |s, cx| {
+                s.move_column(1, 0, cx);
+                assert_eq!(s.display_columns(), &[1, 0]);
+
+                s.freeze_columns_through(1, cx);
+                assert_eq!(s.frozen_column_count(), 1);
+
+                // Dropping the frozen column onto a scrolling one unfreezes it.
+                s.move_column(1, 0, cx);
+                assert_eq!(s.display_columns(), &[0, 1]);
+                assert_eq!(s.frozen_column_count(), 0);
+
+                s.hide_column(0, cx);
+                assert_eq!(s.display_columns(), &[1]);
+
+                // The last shown column cannot be hidden.
+                s.hide_column(1, cx);
+                assert_eq!(s.display_columns(), &[1]);
+
+                // Navigation skips hidden columns.
+                s.move_to_edge(Edge::Home, false, cx);
+                assert_eq!(s.selection().active, Some(CellCoord::new(0, 1)));
+
+                s.show_all_columns(cx);
+                assert!(s.has_default_column_layout());
+            })
+        });
+    }
+
+    #[gpui::test]
+    fn page_moves_and_active_cell_description(cx: &mut gpui::TestAppContext) {
+        use super::super::events::Direction;
+        use super::super::selection::CellCoord;
+
+        let state_holder = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let holder_clone = state_holder.clone();
+
+        let (_, window) = cx.add_window_view(move |_window, cx| {
+            let model = two_row_model();
+            let state = cx.new(|cx| super::DataTableState::new(model, cx));
+            holder_clone.replace(Some(state.clone()));
+            StateHarness { state }
+        });
+
+        let state = state_holder
+            .borrow()
+            .clone()
+            .expect("state entity must be created");
+
+        window.update(|_window, app| {
+            state.update(app, |s, cx| {
+                assert_eq!(s.active_cell_description(), None);
+
+                s.select_cell(CellCoord::new(0, 1), cx);
+                assert_eq!(
+                    s.active_cell_description().as_deref(),
+                    Some("Row 1 of 2, name (text): alice")
+                );
+
+                // A page past the end stops on the last row.
+                s.move_active(Direction::PageDown, true, cx);
+                assert_eq!(s.selection().active, Some(CellCoord::new(1, 1)));
+                assert_eq!(
+                    s.active_cell_description().as_deref(),
+                    Some("Row 2 of 2, name (text): bob, 2 cells selected")
+                );
+
+                s.move_active(Direction::PageUp, false, cx);
+                assert_eq!(s.selection().active, Some(CellCoord::new(0, 1)));
+            })
+        });
+    }
+
     /// Regression: switching the inline editor to another cell must drop the
     /// previous editor's subscriptions. Otherwise a late `Blur` from the old
     /// input is delivered to its still-live subscription and calls
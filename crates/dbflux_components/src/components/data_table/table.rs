@@ -2,27 +2,30 @@ use std::ops::Range;
 use std::sync::{Arc, Mutex};
 
 use crate::controls::{GpuiInput as Input, InputState};
-use crate::primitives::Text;
-use crate::tokens::{FontSizes, RowColors};
-use gpui::ElementId;
+use crate::density;
+use crate::icons::AppIcon;
+use crate::primitives::{Icon, Text};
+use crate::tokens::{FontSizes, Heights, Radii, RowColors, Spacing};
 use gpui::prelude::FluentBuilder;
 use gpui::{
     AnyElement, App, ClickEvent, Context, Entity, InteractiveElement, IntoElement, KeyBinding,
     ListSizingBehavior, MouseButton, MouseDownEvent, ParentElement, ScrollWheelEvent, SharedString,
     StatefulInteractiveElement, Styled, Window, actions, canvas, div, px, uniform_list,
 };
+use gpui::{AppContext, ElementId, EntityId, Pixels, anchored, deferred};
 use gpui_component::scroll::{Scrollbar, ScrollbarShow};
 use gpui_component::{ActiveTheme, Sizable};
 
 use super::events::{DataTableEvent, Direction, Edge};
+use super::json_tree::JsonCellPreview;
 use super::model::TableModel;
 use super::selection::{CellCoord, SelectionState};
 use super::state::DataTableState;
 use super::theme::{
-    CELL_PADDING_X, HEADER_HEIGHT, ROW_HEIGHT, SCROLLBAR_WIDTH, SORT_INDICATOR_ASC,
-    SORT_INDICATOR_DESC,
+    CELL_PADDING_X, HEADER_HEIGHT, SCROLLBAR_WIDTH, SORT_INDICATOR_ASC, SORT_INDICATOR_DESC,
 };
 use dbflux_core::SortDirection;
+use dbflux_core::null_display::{self, NullStyle};
 
 /// Cached scroll state to prevent unnecessary syncs
 #[derive(Clone)]
@@ -51,6 +54,10 @@ actions!(
         SelectDown,
         SelectLeft,
         SelectRight,
+        MovePageUp,
+        MovePageDown,
+        SelectPageUp,
+        SelectPageDown,
         MoveToLineStart,
         MoveToLineEnd,
         MoveToTop,
@@ -96,6 +103,10 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("shift-down", SelectDown, Some(CONTEXT)),
         KeyBinding::new("shift-left", SelectLeft, Some(CONTEXT)),
         KeyBinding::new("shift-right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("pageup", MovePageUp, Some(CONTEXT)),
+        KeyBinding::new("pagedown", MovePageDown, Some(CONTEXT)),
+        KeyBinding::new("shift-pageup", SelectPageUp, Some(CONTEXT)),
+        KeyBinding::new("shift-pagedown", SelectPageDown, Some(CONTEXT)),
         KeyBinding::new("home", MoveToLineStart, Some(CONTEXT)),
         KeyBinding::new("end", MoveToLineEnd, Some(CONTEXT)),
         KeyBinding::new("ctrl-home", MoveToTop, Some(CONTEXT)),
@@ -157,6 +168,60 @@ impl Default for ResizeDragState {
     }
 }
 
+/// Drag payload and preview for moving a column by its header.
+#[derive(Clone)]
+struct DraggedColumn {
+    /// Table the header belongs to; drops on other tables are ignored.
+    table: EntityId,
+    col: usize,
+    title: SharedString,
+}
+
+impl gpui::Render for DraggedColumn {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+
+        div()
+            .bg(theme.table_head)
+            .border_1()
+            .border_color(theme.drag_border)
+            .rounded(Radii::SM)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .shadow_md()
+            .child(Text::label_sm(self.title.clone()))
+    }
+}
+
+/// The shown columns as the body rows need them for one frame.
+struct DisplayColumns<'a> {
+    /// Column indices in display order.
+    display: &'a [usize],
+    /// Left edges of the frozen columns in content coordinates.
+    frozen_lefts: Vec<f32>,
+    /// Display positions of the scrolling columns to build.
+    visible: Range<usize>,
+    /// Width of the scrolling columns left of `visible`, frozen ones included.
+    leading_width: f32,
+    h_offset: Pixels,
+}
+
+impl<'a> DisplayColumns<'a> {
+    fn of(state: &'a DataTableState) -> Self {
+        let visible = state.visible_columns();
+
+        Self {
+            display: state.display_columns(),
+            frozen_lefts: (0..state.frozen_column_count())
+                .map(|position| state.column_offset(position))
+                .collect(),
+            leading_width: state.column_offset(visible.start),
+            visible,
+            h_offset: state.horizontal_offset(),
+        }
+    }
+}
+
 pub struct DataTable {
     id: ElementId,
     state: Entity<DataTableState>,
@@ -194,6 +259,7 @@ impl gpui::Render for DataTable {
         let focus_handle = state.focus_handle().clone();
 
         let total_width = state.total_content_width();
+        let header_height = density::zoomed(cx, HEADER_HEIGHT);
 
         // Build header
         let header = self.render_header(state, total_width, theme, cx);
@@ -201,6 +267,10 @@ impl gpui::Render for DataTable {
         // Build body using uniform_list for virtualization
         let body = self.render_body(row_count, total_width, cx);
 
+        let json_tree = state
+            .json_preview()
+            .map(|preview| render_json_tree(&self.state, preview, theme));
+
         // Clone state entity for callbacks
         let state_entity = self.state.clone();
 
@@ -246,6 +316,30 @@ impl gpui::Render for DataTable {
             });
         };
         let s = self.state.clone();
+        let on_page_up = move |_: &MovePageUp, _: &mut Window, cx: &mut App| {
+            s.update(cx, |state, cx| {
+                state.move_active(Direction::PageUp, false, cx)
+            });
+        };
+        let s = self.state.clone();
+        let on_page_down = move |_: &MovePageDown, _: &mut Window, cx: &mut App| {
+            s.update(cx, |state, cx| {
+                state.move_active(Direction::PageDown, false, cx)
+            });
+        };
+        let s = self.state.clone();
+        let on_select_page_up = move |_: &SelectPageUp, _: &mut Window, cx: &mut App| {
+            s.update(cx, |state, cx| {
+                state.move_active(Direction::PageUp, true, cx)
+            });
+        };
+        let s = self.state.clone();
+        let on_select_page_down = move |_: &SelectPageDown, _: &mut Window, cx: &mut App| {
+            s.update(cx, |state, cx| {
+                state.move_active(Direction::PageDown, true, cx)
+            });
+        };
+        let s = self.state.clone();
         let on_line_start = move |_: &MoveToLineStart, _: &mut Window, cx: &mut App| {
             s.update(cx, |state, cx| state.move_to_edge(Edge::Left, false, cx));
         };
@@ -286,6 +380,8 @@ impl gpui::Render for DataTable {
             s.update(cx, |state, cx| {
                 if state.is_editing() {
                     state.stop_editing(false, cx);
+                } else if state.json_preview().is_some() {
+                    state.close_json_preview(cx);
                 } else {
                     state.clear_selection(cx);
                 }
@@ -305,8 +401,11 @@ impl gpui::Render for DataTable {
                 if state.is_editing() {
                     return;
                 }
-                if let Some(coord) = state.selection().active {
-                    state.start_editing(coord, window, cx);
+                // Read-only cells open in the viewer instead.
+                if let Some(coord) = state.selection().active
+                    && !state.start_editing(coord, window, cx)
+                {
+                    state.request_cell_view(coord, cx);
                 }
             });
         };
@@ -534,6 +633,10 @@ impl gpui::Render for DataTable {
             .on_action(on_select_down)
             .on_action(on_select_left)
             .on_action(on_select_right)
+            .on_action(on_page_up)
+            .on_action(on_page_down)
+            .on_action(on_select_page_up)
+            .on_action(on_select_page_down)
             .on_action(on_line_start)
             .on_action(on_line_end)
             .on_action(on_top)
@@ -647,7 +750,7 @@ impl gpui::Render for DataTable {
             .child(
                 div()
                     .absolute()
-                    .top(HEADER_HEIGHT)
+                    .top(header_height)
                     .right_0()
                     .bottom_0()
                     .w(SCROLLBAR_WIDTH)
@@ -671,6 +774,7 @@ impl gpui::Render for DataTable {
                             .scrollbar_show(ScrollbarShow::Always),
                     ),
             )
+            .children(json_tree)
     }
 }
 
@@ -680,9 +784,10 @@ impl DataTable {
         state: &DataTableState,
         total_width: f32,
         theme: &gpui_component::theme::Theme,
-        _cx: &gpui::App,
+        cx: &gpui::App,
     ) -> impl IntoElement {
         let model = state.model();
+        let header_height = density::zoomed(cx, HEADER_HEIGHT);
         let sort = state.sort();
         let column_widths = state.column_widths();
         let h_offset = state.horizontal_offset();
@@ -692,147 +797,200 @@ impl DataTable {
         let pk_cols = state.pk_columns().to_vec();
         let fk_cols = state.fk_columns().clone();
 
-        let header_cells: Vec<_> = model
-            .columns
-            .iter()
-            .enumerate()
-            .map(|(col_ix, col_spec)| {
-                let width = column_widths.get(col_ix).copied().unwrap_or(120.0);
-                let is_sorted = sort.map(|s| s.column_ix == col_ix).unwrap_or(false);
-                let sort_indicator = if is_sorted {
-                    match sort.unwrap().direction {
+        // Only columns in (or next to) the viewport are built; a spacer stands
+        // in for the ones scrolled off to the left.
+        let display_columns = state.display_columns();
+        let frozen_count = state.frozen_column_count();
+        let visible_columns = state.visible_columns();
+        let leading_width = state.column_offset(visible_columns.start);
+        let table_id = self.state.entity_id();
+        let drop_target_bg = theme.drop_target;
+
+        let header_cell = |position: usize| {
+            let col_ix = display_columns[position];
+            let col_spec = &model.columns[col_ix];
+            let width = column_widths.get(col_ix).copied().unwrap_or(120.0);
+            let sort_position = sort.iter().position(|s| s.column_ix == col_ix);
+            let is_sorted = sort_position.is_some();
+            let sort_indicator: SharedString = match sort_position {
+                Some(position) => {
+                    let arrow = match sort[position].direction {
                         SortDirection::Ascending => SORT_INDICATOR_ASC,
                         SortDirection::Descending => SORT_INDICATOR_DESC,
+                    };
+
+                    // Show the key's priority once more than one column sorts.
+                    if sort.len() > 1 {
+                        format!("{}{}", arrow, position + 1).into()
+                    } else {
+                        arrow.into()
                     }
-                } else {
-                    ""
-                };
+                }
+                None => "".into(),
+            };
 
-                let is_pk = pk_cols.contains(&col_ix);
-                let is_fk = fk_cols.contains(&col_ix);
+            let is_pk = pk_cols.contains(&col_ix);
+            let is_fk = fk_cols.contains(&col_ix);
 
-                let type_label: SharedString = col_spec.type_name.clone().into();
+            let type_label: SharedString = col_spec.type_name.clone().into();
 
-                let state_for_click = state_entity.clone();
-                let resize_drag_for_down = resize_drag.clone();
+            let state_for_click = state_entity.clone();
+            let state_for_drop = state_entity.clone();
+            let resize_drag_for_down = resize_drag.clone();
+            let dragged = DraggedColumn {
+                table: table_id,
+                col: col_ix,
+                title: col_spec.title.clone().into(),
+            };
 
-                div()
-                    .id(("header-col", col_ix))
-                    .relative()
-                    .flex()
-                    .flex_shrink_0()
-                    .items_center()
-                    .justify_between()
-                    .h(HEADER_HEIGHT)
-                    .w(px(width))
-                    .px(CELL_PADDING_X)
-                    .overflow_hidden()
-                    .border_r_1()
-                    .border_color(theme.border)
-                    .bg(theme.table_head)
-                    .hover(|s| s.bg(theme.table_hover))
-                    .cursor_pointer()
-                    .on_click(move |_event: &ClickEvent, _window, cx| {
-                        state_for_click.update(cx, |state, cx| {
-                            state.cycle_sort(col_ix, cx);
+            div()
+                .id(("header-col", col_ix))
+                .relative()
+                .flex()
+                .flex_shrink_0()
+                .items_center()
+                .justify_between()
+                .h(header_height)
+                .w(px(width))
+                .px(CELL_PADDING_X)
+                .overflow_hidden()
+                .border_r_1()
+                .border_color(theme.border)
+                .bg(theme.table_head)
+                .hover(|s| s.bg(theme.table_hover))
+                .cursor_pointer()
+                .on_click(move |event: &ClickEvent, _window, cx| {
+                    let additive = event.modifiers().shift;
+                    state_for_click.update(cx, |state, cx| {
+                        state.cycle_sort(col_ix, additive, cx);
+                    });
+                })
+                // Dragging a header onto another one moves the column
+                // into that position.
+                .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+                .drag_over::<DraggedColumn>(move |style, dragged, _, _| {
+                    if dragged.table == table_id {
+                        style.bg(drop_target_bg)
+                    } else {
+                        style
+                    }
+                })
+                .on_drop(move |dragged: &DraggedColumn, _, cx| {
+                    if dragged.table == table_id {
+                        state_for_drop.update(cx, |state, cx| {
+                            state.move_column(dragged.col, col_ix, cx);
                         });
-                    })
-                    .child(
-                        div()
-                            .flex()
-                            .flex_row()
-                            .items_center()
-                            .gap_1()
-                            .min_w_0()
-                            .flex_1()
-                            .overflow_hidden()
-                            // PK / FK badges — secondary metadata, same dim styling
-                            // as the type label so they sit clearly below the name
-                            // in the visual hierarchy.
-                            .when(is_pk, |d| {
-                                d.child(
-                                    div().flex_shrink_0().child(
-                                        Text::body("PK")
-                                            .font_size(FontSizes::XS)
-                                            .color(theme.muted_foreground.opacity(0.6)),
-                                    ),
-                                )
-                            })
-                            .when(is_fk, |d| {
+                    }
+                })
+                .child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_1()
+                        .min_w_0()
+                        .flex_1()
+                        .overflow_hidden()
+                        // PK / FK badges — secondary metadata, same dim styling
+                        // as the type label so they sit clearly below the name
+                        // in the visual hierarchy.
+                        .when(is_pk, |d| {
+                            d.child(
+                                div().flex_shrink_0().child(
+                                    Text::body("PK")
+                                        .font_size(FontSizes::XS)
+                                        .color(theme.muted_foreground.opacity(0.6)),
+                                ),
+                            )
+                        })
+                        .when(is_fk, |d| {
+                            d.child(
+                                div().flex_shrink_0().child(
+                                    Text::body("FK")
+                                        .font_size(FontSizes::XS)
+                                        .color(theme.muted_foreground.opacity(0.6)),
+                                ),
+                            )
+                        })
+                        // Column name — primary affordance, never shrinks.
+                        // It pushes the (secondary) type label out of the cell
+                        // before its own characters get truncated.
+                        .child(div().flex_shrink_0().whitespace_nowrap().child(
+                            Text::label_sm(col_spec.title.clone()).color(if is_sorted {
+                                theme.primary
+                            } else {
+                                theme.foreground
+                            }),
+                        ))
+                        // Type label — dimmed metadata. Shrinks and truncates
+                        // first when the cell runs out of horizontal space.
+                        .when_some(
+                            (!type_label.is_empty()).then_some(type_label),
+                            |d, label| {
                                 d.child(
-                                    div().flex_shrink_0().child(
-                                        Text::body("FK")
-                                            .font_size(FontSizes::XS)
-                                            .color(theme.muted_foreground.opacity(0.6)),
-                                    ),
+                                    div()
+                                        .flex()
+                                        .min_w_0()
+                                        .flex_1()
+                                        .overflow_hidden()
+                                        .text_ellipsis()
+                                        .whitespace_nowrap()
+                                        .child(
+                                            Text::body(label)
+                                                .font_size(FontSizes::XS)
+                                                .color(theme.muted_foreground.opacity(0.6)),
+                                        ),
                                 )
-                            })
-                            // Column name — primary affordance, never shrinks.
-                            // It pushes the (secondary) type label out of the cell
-                            // before its own characters get truncated.
-                            .child(div().flex_shrink_0().whitespace_nowrap().child(
-                                Text::label_sm(col_spec.title.clone()).color(if is_sorted {
-                                    theme.primary
-                                } else {
-                                    theme.foreground
-                                }),
-                            ))
-                            // Type label — dimmed metadata. Shrinks and truncates
-                            // first when the cell runs out of horizontal space.
-                            .when_some(
-                                (!type_label.is_empty()).then_some(type_label),
-                                |d, label| {
-                                    d.child(
-                                        div()
-                                            .flex()
-                                            .min_w_0()
-                                            .flex_1()
-                                            .overflow_hidden()
-                                            .text_ellipsis()
-                                            .whitespace_nowrap()
-                                            .child(
-                                                Text::body(label)
-                                                    .font_size(FontSizes::XS)
-                                                    .color(theme.muted_foreground.opacity(0.6)),
-                                            ),
-                                    )
-                                },
-                            ),
-                    )
-                    .child(div().child(if is_sorted {
-                        Text::body(sort_indicator)
-                            .font_size(FontSizes::SM)
-                            .color(theme.primary)
-                    } else {
-                        Text::body(sort_indicator)
-                            .font_size(FontSizes::SM)
-                            .color(theme.muted_foreground)
-                    }))
-                    // Resize handle: mouse-down starts the drag; move/up are
-                    // handled on the DataTable root div so the drag survives
-                    // the cursor leaving this 6px strip.
-                    .child(
-                        div()
-                            .id(("resize-handle", col_ix))
-                            .absolute()
-                            .right_0()
-                            .top_0()
-                            .bottom_0()
-                            .w(px(6.0)) // guardrail-allow: resize handle width, not spacing
-                            .cursor_col_resize()
-                            .hover(|s| s.bg(theme.primary.opacity(0.3)))
-                            .on_mouse_down(
-                                MouseButton::Left,
-                                move |event: &MouseDownEvent, _window, cx| {
-                                    cx.stop_propagation();
-                                    if let Ok(mut drag) = resize_drag_for_down.lock() {
-                                        drag.col = Some(col_ix);
-                                        drag.start_x = event.position.x;
-                                        drag.original_width = width;
-                                    }
-                                },
-                            ),
-                    )
+                            },
+                        ),
+                )
+                .child(div().child(if is_sorted {
+                    Text::body(sort_indicator)
+                        .font_size(FontSizes::SM)
+                        .color(theme.primary)
+                } else {
+                    Text::body(sort_indicator)
+                        .font_size(FontSizes::SM)
+                        .color(theme.muted_foreground)
+                }))
+                // Resize handle: mouse-down starts the drag; move/up are
+                // handled on the DataTable root div so the drag survives
+                // the cursor leaving this 6px strip.
+                .child(
+                    div()
+                        .id(("resize-handle", col_ix))
+                        .absolute()
+                        .right_0()
+                        .top_0()
+                        .bottom_0()
+                        .w(px(6.0)) // guardrail-allow: resize handle width, not spacing
+                        .cursor_col_resize()
+                        .hover(|s| s.bg(theme.primary.opacity(0.3)))
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            move |event: &MouseDownEvent, _window, cx| {
+                                cx.stop_propagation();
+                                if let Ok(mut drag) = resize_drag_for_down.lock() {
+                                    drag.col = Some(col_ix);
+                                    drag.start_x = event.position.x;
+                                    drag.original_width = width;
+                                }
+                            },
+                        ),
+                )
+        };
+
+        let header_cells: Vec<_> = visible_columns.map(&header_cell).collect();
+
+        // Frozen headers are laid over the left edge of the viewport, which
+        // sits `h_offset` into the shifted header content.
+        let frozen_cells: Vec<_> = (0..frozen_count)
+            .map(|position| {
+                header_cell(position)
+                    .absolute()
+                    .top_0()
+                    .left(h_offset + px(state.column_offset(position)))
+                    .when(position + 1 == frozen_count, |d| d.border_r_2())
             })
             .collect();
 
@@ -841,17 +999,22 @@ impl DataTable {
         div()
             .id("table-header")
             .flex_shrink_0()
-            .h(HEADER_HEIGHT)
+            .h(header_height)
             .overflow_hidden()
             .border_b_1()
             .border_color(theme.border)
             .child(
                 div()
+                    .relative()
                     .flex()
                     .min_w(px(total_width))
                     .ml(-h_offset)
                     .bg(theme.table_head)
-                    .children(header_cells),
+                    .when(leading_width > 0.0, |d| {
+                        d.child(div().flex_shrink_0().w(px(leading_width)))
+                    })
+                    .children(header_cells)
+                    .children(frozen_cells),
             )
     }
 
@@ -870,10 +1033,14 @@ impl DataTable {
             "table-rows",
             row_count,
             move |visible_range: Range<usize>, _window: &mut Window, cx: &mut App| {
+                let row_height = density::grid_row_height(cx);
+                let cell_font_size = density::grid_font_size(cx);
                 let theme = cx.theme();
                 // Read state INSIDE closure - only when actually rendering
                 let state = state_entity.read(cx);
 
+                let highlight = state.highlight();
+                let columns = DisplayColumns::of(state);
                 let editing_cell = state.editing_cell();
                 let cell_input = state.cell_input().cloned();
                 let enum_dropdown = state.enum_dropdown().cloned();
@@ -884,12 +1051,16 @@ impl DataTable {
                     visible_range,
                     &model,
                     state.column_widths(),
+                    &columns,
                     state.selection(),
                     editing_cell,
                     cell_input.as_ref(),
                     enum_dropdown.as_ref(),
                     edit_buffer,
+                    highlight,
                     total_width,
+                    row_height,
+                    cell_font_size,
                     theme,
                 )
             },
@@ -925,38 +1096,47 @@ fn render_rows(
     visible_range: Range<usize>,
     model: &TableModel,
     column_widths: &[f32],
+    columns: &DisplayColumns<'_>,
     selection: &SelectionState,
     editing_cell: Option<CellCoord>,
     cell_input: Option<&Entity<InputState>>,
     enum_dropdown: Option<&Entity<crate::controls::Dropdown>>,
     edit_buffer: &super::model::EditBuffer,
+    highlight: Option<&str>,
     total_width: f32,
+    row_height: Pixels,
+    cell_font_size: Pixels,
     theme: &gpui_component::theme::Theme,
 ) -> Vec<AnyElement> {
     use super::model::VisualRowSource;
 
-    // Compute visual ordering once for this render pass
-    let visual_order = edit_buffer.compute_visual_order();
+    // Resolve only the visible rows; the full visual order is proportional to
+    // the table size and would dominate each frame on large results.
+    let first_visual_ix = visible_range.start;
+    let visual_order = edit_buffer.visual_order_range(visible_range.clone());
+
+    // Read at render time so a changed NULL setting applies to open grids.
+    let null_style = null_display::null_style();
+    let null_marker: Arc<str> = null_display::null_marker().into();
 
     visible_range
         .map(|visual_ix| {
             // Map visual index to actual data source
-            let source = visual_order.get(visual_ix).copied();
+            let source = visual_order.get(visual_ix - first_visual_ix).copied();
 
             // Get row data and state based on source type
-            let (row_data, pending_insert_data, row_state, data_row_ix) = match source {
+            let (is_base_row, pending_insert_data, row_state, data_row_ix) = match source {
                 Some(VisualRowSource::Base(base_idx)) => {
-                    let row = model.rows.get(base_idx);
                     let state = edit_buffer.row_state(base_idx);
-                    (row, None, state.clone(), base_idx)
+                    (true, None, state.clone(), base_idx)
                 }
                 Some(VisualRowSource::Insert(insert_idx)) => {
                     let data = edit_buffer.get_pending_insert_by_idx(insert_idx);
-                    (None, data, dbflux_core::RowState::PendingInsert, visual_ix)
+                    (false, data, dbflux_core::RowState::PendingInsert, visual_ix)
                 }
                 None => {
                     // Should not happen, but handle gracefully
-                    (None, None, dbflux_core::RowState::Clean, visual_ix)
+                    (false, None, dbflux_core::RowState::Clean, visual_ix)
                 }
             };
 
@@ -982,159 +1162,239 @@ fn render_rows(
 
             let is_pending_delete = row_state.is_pending_delete();
 
-            let cells: Vec<AnyElement> = (0..model.col_count())
-                .map(|col_ix| {
-                    // Get cell either from model or from pending insert
-                    let cell = if let Some(insert_data) = pending_insert_data {
-                        insert_data.get(col_ix)
-                    } else {
-                        row_data.and_then(|r| r.cells.get(col_ix))
-                    };
-                    let width = column_widths.get(col_ix).copied().unwrap_or(120.0);
-                    let coord = CellCoord::new(row_ix, col_ix);
-                    let is_selected = selection.is_selected(coord);
-                    let is_active = selection.active == Some(coord);
-                    let is_editing = editing_cell == Some(coord);
-
-                    if is_editing {
-                        if let Some(dropdown) = enum_dropdown {
-                            return div()
-                                .id(("cell", row_ix * 10000 + col_ix))
-                                .flex()
-                                .flex_shrink_0()
-                                .items_center()
-                                .h(ROW_HEIGHT)
-                                .w(px(width))
-                                .overflow_hidden()
-                                .border_r_1()
-                                .border_1()
-                                .border_color(theme.ring)
-                                .bg(theme.background)
-                                .child(dropdown.clone())
-                                .into_any_element();
-                        }
+            let cell = |col_ix: usize| {
+                // Get cell either from pending insert or, built on demand, from
+                // the model
+                let cell = if let Some(insert_data) = pending_insert_data {
+                    insert_data.get(col_ix).cloned()
+                } else if is_base_row {
+                    model.cell(data_row_ix, col_ix)
+                } else {
+                    None
+                };
+                let width = column_widths.get(col_ix).copied().unwrap_or(120.0);
+                let coord = CellCoord::new(row_ix, col_ix);
+                let is_selected = selection.is_selected(coord);
+                let is_active = selection.active == Some(coord);
+                let is_editing = editing_cell == Some(coord);
+
+                if is_editing {
+                    if let Some(dropdown) = enum_dropdown {
+                        return div()
+                            .id(("cell", row_ix * 10000 + col_ix))
+                            .flex()
+                            .flex_shrink_0()
+                            .items_center()
+                            .h(row_height)
+                            .w(px(width))
+                            .overflow_hidden()
+                            .border_r_1()
+                            .border_1()
+                            .border_color(theme.ring)
+                            .bg(theme.background)
+                            .child(dropdown.clone());
+                    }
 
-                        if let Some(input_state) = cell_input {
-                            return div()
-                                .id(("cell", row_ix * 10000 + col_ix))
-                                .flex()
-                                .flex_shrink_0()
-                                .items_center()
-                                .h(ROW_HEIGHT)
-                                .w(px(width))
-                                .overflow_hidden()
-                                .border_r_1()
-                                .border_1()
-                                .border_color(theme.ring)
-                                .bg(theme.background)
-                                .child(Input::new(input_state).small())
-                                .into_any_element();
-                        }
+                    if let Some(input_state) = cell_input {
+                        return div()
+                            .id(("cell", row_ix * 10000 + col_ix))
+                            .flex()
+                            .flex_shrink_0()
+                            .items_center()
+                            .h(row_height)
+                            .w(px(width))
+                            .overflow_hidden()
+                            .border_r_1()
+                            .border_1()
+                            .border_color(theme.ring)
+                            .bg(theme.background)
+                            .child(Input::new(input_state).small());
                     }
+                }
 
-                    // For edit buffer access, use the data row index (model index for base rows)
-                    let is_cell_dirty = if is_pending_insert_row {
-                        false // Pending inserts don't have cell-level dirty tracking
-                    } else {
-                        edit_buffer.is_cell_dirty(data_row_ix, col_ix)
-                    };
-                    let null_value = super::model::CellValue::null();
-                    let base_value = cell.unwrap_or(&null_value);
-                    let display_value = if is_pending_insert_row {
-                        base_value // For pending inserts, just use the cell value directly
+                // For edit buffer access, use the data row index (model index for base rows)
+                let is_cell_dirty = if is_pending_insert_row {
+                    false // Pending inserts don't have cell-level dirty tracking
+                } else {
+                    edit_buffer.is_cell_dirty(data_row_ix, col_ix)
+                };
+                let base_value = cell.unwrap_or_else(super::model::CellValue::null);
+                let display_value = if is_pending_insert_row {
+                    &base_value // For pending inserts, just use the cell value directly
+                } else {
+                    edit_buffer.get_cell(data_row_ix, col_ix, &base_value)
+                };
+                let is_null = display_value.is_null();
+                let display_text = if is_null {
+                    null_marker.clone()
+                } else {
+                    display_value.display_text()
+                };
+                let null_badge = is_null && null_style == NullStyle::Badge;
+                let muted_null = is_null && null_style == NullStyle::Muted;
+                let is_auto_generated = display_value.is_auto_generated();
+                let is_search_match = !is_null
+                    && highlight.is_some_and(|needle| display_text.to_lowercase().contains(needle));
+                let is_json = display_value.is_json();
+
+                let state_for_click = state_entity.clone();
+                let state_for_context = state_entity.clone();
+                let state_for_tree = state_entity.clone();
+
+                let text = Text::body(display_text.to_string())
+                    .font_size(cell_font_size)
+                    .color(if is_pending_delete || muted_null || is_auto_generated {
+                        theme.muted_foreground
+                    } else if is_search_match {
+                        theme.primary
                     } else {
-                        edit_buffer.get_cell(data_row_ix, col_ix, base_value)
-                    };
-                    let display_text = display_value.display_text();
-                    let is_null = display_value.is_null();
-                    let is_auto_generated = display_value.is_auto_generated();
+                        theme.foreground
+                    });
 
-                    let state_for_click = state_entity.clone();
-                    let state_for_context = state_entity.clone();
+                div()
+                    .id(("cell", row_ix * 10000 + col_ix))
+                    .flex()
+                    .flex_shrink_0()
+                    .items_center()
+                    .h(row_height)
+                    .w(px(width))
+                    .px(CELL_PADDING_X)
+                    .overflow_hidden()
+                    .border_r_1()
+                    .border_color(theme.border)
+                    .cursor_pointer()
+                    // Highlight individual dirty cells (like DBeaver).
+                    // Uses RowColors::dirty for the background and the
+                    // theme warning for the 2px left accent stroke.
+                    .when(is_cell_dirty, |d| {
+                        d.bg(RowColors::dirty(theme))
+                            .border_l_2()
+                            .border_color(theme.warning)
+                    })
+                    .when(is_search_match, |d| d.bg(RowColors::search_match(theme)))
+                    .when(is_selected, |d| {
+                        d.bg(theme.table_active)
+                            .border_color(theme.table_active_border)
+                    })
+                    .when(is_active, |d| d.border_1().border_color(theme.ring))
+                    .when(
+                        (is_null && null_style != NullStyle::Plain) || is_auto_generated,
+                        |d| d.italic(),
+                    )
+                    .when(is_pending_delete, |d| d.line_through())
+                    .on_click(move |event: &ClickEvent, window, cx| {
+                        state_for_click.update(cx, |state, cx| {
+                            state.focus(window, cx);
+                        });
 
-                    div()
-                        .id(("cell", row_ix * 10000 + col_ix))
-                        .flex()
-                        .flex_shrink_0()
-                        .items_center()
-                        .h(ROW_HEIGHT)
-                        .w(px(width))
-                        .px(CELL_PADDING_X)
-                        .overflow_hidden()
-                        .border_r_1()
-                        .border_color(theme.border)
-                        .cursor_pointer()
-                        // Highlight individual dirty cells (like DBeaver).
-                        // Uses RowColors::dirty for the background and the
-                        // theme warning for the 2px left accent stroke.
-                        .when(is_cell_dirty, |d| {
-                            d.bg(RowColors::dirty(theme))
-                                .border_l_2()
-                                .border_color(theme.warning)
-                        })
-                        .when(is_selected, |d| {
-                            d.bg(theme.table_active)
-                                .border_color(theme.table_active_border)
-                        })
-                        .when(is_active, |d| d.border_1().border_color(theme.ring))
-                        .when(is_null || is_auto_generated, |d| d.italic())
-                        .when(is_pending_delete, |d| d.line_through())
-                        .on_click(move |event: &ClickEvent, window, cx| {
+                        if event.click_count() == 2 {
                             state_for_click.update(cx, |state, cx| {
-                                state.focus(window, cx);
+                                if !state.start_editing(coord, window, cx) {
+                                    state.request_cell_view(coord, cx);
+                                }
                             });
+                            return;
+                        }
 
-                            if event.click_count() == 2 {
-                                state_for_click.update(cx, |state, cx| {
-                                    state.start_editing(coord, window, cx);
+                        if event.modifiers().shift {
+                            state_for_click.update(cx, |state, cx| {
+                                state.extend_selection(coord, cx);
+                            });
+                        } else {
+                            state_for_click.update(cx, |state, cx| {
+                                state.select_cell(coord, cx);
+                            });
+                        }
+                    })
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        move |event: &MouseDownEvent, window, cx| {
+                            cx.stop_propagation();
+                            state_for_context.update(cx, |state, cx| {
+                                state.focus(window, cx);
+                                state.select_cell(coord, cx);
+                                cx.emit(DataTableEvent::ContextMenuRequested {
+                                    row: coord.row,
+                                    col: coord.col,
+                                    position: event.position,
                                 });
-                                return;
-                            }
+                            });
+                        },
+                    )
+                    // JSON cells get a toggle that opens their tree in a popover.
+                    .when(is_json, |d| {
+                        d.gap(Spacing::XS).child(
+                            div()
+                                .id(("json-tree-toggle", row_ix * 10000 + col_ix))
+                                .flex_shrink_0()
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    move |event: &MouseDownEvent, window, cx| {
+                                        cx.stop_propagation();
+                                        state_for_tree.update(cx, |state, cx| {
+                                            state.focus(window, cx);
+                                            state.select_cell(coord, cx);
+                                            state.open_json_preview(coord, event.position, cx);
+                                        });
+                                    },
+                                )
+                                .child(
+                                    Icon::new(AppIcon::ChevronRight)
+                                        .size(Heights::ICON_SM)
+                                        .muted(),
+                                ),
+                        )
+                    })
+                    .child(if null_badge {
+                        div()
+                            .px(Spacing::XS)
+                            .rounded(Radii::SM)
+                            .bg(theme.muted)
+                            .child(text)
+                            .into_any_element()
+                    } else {
+                        text.into_any_element()
+                    })
+            };
 
-                            if event.modifiers().shift {
-                                state_for_click.update(cx, |state, cx| {
-                                    state.extend_selection(coord, cx);
-                                });
-                            } else {
-                                state_for_click.update(cx, |state, cx| {
-                                    state.select_cell(coord, cx);
-                                });
-                            }
+            let cells: Vec<AnyElement> = columns
+                .visible
+                .clone()
+                .map(|position| cell(columns.display[position]).into_any_element())
+                .collect();
+
+            // Frozen cells are laid over the left edge of the viewport with an
+            // opaque background so scrolled cells don't show through.
+            let frozen_bg = row_bg.unwrap_or(if row_ix % 2 == 1 {
+                theme.table_even
+            } else {
+                theme.table
+            });
+            let frozen_cells: Vec<AnyElement> = columns
+                .frozen_lefts
+                .iter()
+                .enumerate()
+                .map(|(position, &left)| {
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left(columns.h_offset + px(left))
+                        .bg(frozen_bg)
+                        .when(position + 1 == columns.frozen_lefts.len(), |d| {
+                            d.border_r_1().border_color(theme.border)
                         })
-                        .on_mouse_down(
-                            MouseButton::Right,
-                            move |event: &MouseDownEvent, window, cx| {
-                                cx.stop_propagation();
-                                state_for_context.update(cx, |state, cx| {
-                                    state.focus(window, cx);
-                                    state.select_cell(coord, cx);
-                                    cx.emit(DataTableEvent::ContextMenuRequested {
-                                        row: coord.row,
-                                        col: coord.col,
-                                        position: event.position,
-                                    });
-                                });
-                            },
-                        )
-                        .child(
-                            Text::body(display_text.to_string())
-                                .font_size(FontSizes::SM)
-                                .color(if is_pending_delete || is_null || is_auto_generated {
-                                    theme.muted_foreground
-                                } else {
-                                    theme.foreground
-                                }),
-                        )
+                        .child(cell(columns.display[position]))
                         .into_any_element()
                 })
                 .collect();
 
             div()
                 .id(("row", row_ix))
+                .relative()
                 .flex()
                 .flex_shrink_0()
                 .w(px(total_width))
-                .h(ROW_HEIGHT)
+                .h(row_height)
                 .overflow_hidden()
                 .border_b_1()
                 .border_color(theme.table_row_border)
@@ -1144,8 +1404,106 @@ fn render_rows(
                 .when(row_bg.is_none() && row_ix % 2 == 1, |d| {
                     d.bg(theme.table_even)
                 })
+                .when(columns.leading_width > 0.0, |d| {
+                    d.child(div().flex_shrink_0().w(px(columns.leading_width)))
+                })
                 .children(cells)
+                .children(frozen_cells)
                 .into_any_element()
         })
         .collect()
 }
+
+/// Popover listing the open JSON cell as an expandable tree. Clicking a
+/// container line expands or collapses it; clicking outside closes it.
+fn render_json_tree(
+    state_entity: &Entity<DataTableState>,
+    preview: &JsonCellPreview,
+    theme: &gpui_component::theme::Theme,
+) -> AnyElement {
+    let lines: Vec<AnyElement> = preview
+        .rows()
+        .into_iter()
+        .enumerate()
+        .map(|(ix, row)| {
+            let state = state_entity.clone();
+            let path = row.path.clone();
+
+            let chevron = if !row.expandable {
+                div().flex_shrink_0().w(Heights::ICON_SM).into_any_element()
+            } else {
+                Icon::new(if row.expanded {
+                    AppIcon::ChevronDown
+                } else {
+                    AppIcon::ChevronRight
+                })
+                .size(Heights::ICON_SM)
+                .muted()
+                .into_any_element()
+            };
+
+            div()
+                .id(("json-tree-line", ix))
+                .flex()
+                .items_start()
+                .gap(Spacing::XS)
+                .pl(Spacing::MD * row.depth as f32)
+                .pr(Spacing::SM)
+                .py(px(2.0))
+                .rounded(Radii::SM)
+                .when(row.expandable, |d| {
+                    d.cursor_pointer()
+                        .hover(|s| s.bg(theme.accent.opacity(0.1)))
+                        .on_click(move |_, _, cx| {
+                            state.update(cx, |state, cx| {
+                                state.toggle_json_preview_node(&path, cx);
+                            });
+                        })
+                })
+                .child(chevron)
+                .when_some(row.label, |d, label| {
+                    d.child(
+                        Text::body(format!("{label}:"))
+                            .font_size(FontSizes::SM)
+                            .color(theme.muted_foreground),
+                    )
+                })
+                .child(
+                    div().min_w_0().child(
+                        Text::body(row.summary)
+                            .font_size(FontSizes::SM)
+                            .color(theme.foreground),
+                    ),
+                )
+                .into_any_element()
+        })
+        .collect();
+
+    let state_for_close = state_entity.clone();
+
+    deferred(
+        anchored()
+            .position(preview.position)
+            .snap_to_window()
+            .child(
+                div()
+                    .id("json-tree-popover")
+                    .w(px(420.0))
+                    .max_h(px(360.0))
+                    .overflow_y_scroll()
+                    .p(Spacing::XS)
+                    .border_1()
+                    .border_color(theme.border)
+                    .bg(theme.popover)
+                    .rounded(Radii::MD)
+                    .shadow_lg()
+                    .occlude()
+                    .on_mouse_down_out(move |_, _, cx| {
+                        state_for_close.update(cx, |state, cx| state.close_json_preview(cx));
+                    })
+                    .children(lines),
+            ),
+    )
+    .with_priority(1)
+    .into_any_element()
+}
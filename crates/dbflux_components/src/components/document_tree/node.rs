@@ -101,8 +101,11 @@ impl NodeValue {
                 Value::Int(_) => "int",
                 Value::Float(_) => "float",
                 Value::Text(_) => "string",
+                Value::Enum(_) => "enum",
+                Value::Uuid(_) => "uuid",
+                Value::Interval(_) => "interval",
                 Value::ObjectId(_) => "ObjectId",
-                Value::DateTime(_) => "datetime",
+                Value::DateTime(_) | Value::NaiveDateTime(_) => "datetime",
                 Value::Date(_) => "date",
                 Value::Time(_) => "time",
                 Value::Bytes(_) => "bytes",
@@ -205,8 +208,12 @@ fn format_value_preview(value: &Value, truncate: bool) -> String {
         }
         Value::ObjectId(id) => format!("ObjectId(\"{}\")", id),
         Value::DateTime(dt) => format_datetime_relative(dt),
+        Value::NaiveDateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
         Value::Date(d) => format_date_relative(d),
         Value::Time(t) => t.format("%H:%M:%S").to_string(),
+        Value::Uuid(uuid) => format!("UUID(\"{}\")", uuid),
+        Value::Interval(interval) => interval.to_string(),
+        Value::Enum(label) => format!("\"{}\"", label),
         Value::Bytes(b) => format!("<{} bytes>", b.len()),
         Value::Decimal(d) => d.to_string(),
         Value::Unsupported(type_name) => format!("UNSUPPORTED<{}>", type_name),
@@ -239,7 +246,7 @@ fn format_datetime_relative(dt: &DateTime<Utc>) -> String {
 
     // For dates in the future or very far in the past, use absolute format
     if diff.num_seconds() < 0 || diff.num_days() > 30 {
-        return dt.format("%Y-%m-%d %H:%M:%S").to_string();
+        return dbflux_core::timezone::format_timestamp(dt);
     }
 
     let seconds = diff.num_seconds();
@@ -998,8 +998,12 @@ fn format_value_for_edit(value: &Value) -> String {
         Value::Text(s) => s.clone(),
         Value::ObjectId(id) => id.clone(),
         Value::DateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+        Value::NaiveDateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
         Value::Date(d) => d.format("%Y-%m-%d").to_string(),
         Value::Time(t) => t.format("%H:%M:%S").to_string(),
+        Value::Uuid(uuid) => uuid.to_string(),
+        Value::Interval(interval) => interval.to_string(),
+        Value::Enum(label) => label.clone(),
         Value::Bytes(_) => String::new(),
         Value::Decimal(d) => d.clone(),
         Value::Json(j) => j.clone(),
@@ -1016,11 +1020,14 @@ fn value_to_json(value: &Value) -> serde_json::Value {
         Value::Float(f) => serde_json::Number::from_f64(*f)
             .map(serde_json::Value::Number)
             .unwrap_or(serde_json::Value::Null),
-        Value::Text(s) => serde_json::Value::String(s.clone()),
+        Value::Text(s) | Value::Enum(s) => serde_json::Value::String(s.clone()),
         Value::ObjectId(id) => {
             serde_json::json!({ "$oid": id })
         }
+        Value::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
+        Value::Interval(interval) => serde_json::Value::String(interval.to_string()),
         Value::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
+        Value::NaiveDateTime(dt) => serde_json::Value::String(dt.to_string()),
         Value::Date(d) => serde_json::Value::String(d.to_string()),
         Value::Time(t) => serde_json::Value::String(t.to_string()),
         Value::Bytes(b) => {
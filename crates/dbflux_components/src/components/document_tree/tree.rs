@@ -705,11 +705,15 @@ fn get_type_color(value: &NodeValue, theme: &gpui_component::Theme) -> Hsla {
             dbflux_core::Value::Float(_) | dbflux_core::Value::Decimal(_) => {
                 hsla(150.0 / 360.0, 0.5, 0.5, 1.0) // guardrail-allow: JSON type color
             }
-            dbflux_core::Value::Text(_) => hsla(30.0 / 360.0, 0.7, 0.6, 1.0), // guardrail-allow: JSON type color
-            dbflux_core::Value::ObjectId(_) => theme.primary,
+            dbflux_core::Value::Text(_) | dbflux_core::Value::Enum(_) => {
+                hsla(30.0 / 360.0, 0.7, 0.6, 1.0) // guardrail-allow: JSON type color
+            }
+            dbflux_core::Value::ObjectId(_) | dbflux_core::Value::Uuid(_) => theme.primary,
             dbflux_core::Value::DateTime(_)
+            | dbflux_core::Value::NaiveDateTime(_)
             | dbflux_core::Value::Date(_)
-            | dbflux_core::Value::Time(_) => hsla(200.0 / 360.0, 0.6, 0.5, 1.0), // guardrail-allow: JSON type color
+            | dbflux_core::Value::Time(_)
+            | dbflux_core::Value::Interval(_) => hsla(200.0 / 360.0, 0.6, 0.5, 1.0), // guardrail-allow: JSON type color
             dbflux_core::Value::Bytes(_) => theme.warning,
             dbflux_core::Value::Json(_) => theme.muted_foreground,
             _ => theme.foreground,
@@ -0,0 +1,214 @@
+//! Flat 2D sketch of a spatial value: points, lines and polygons drawn on a
+//! canvas, scaled to fit with north up. There is no basemap or projection;
+//! coordinates are plotted as plain X/Y.
+
+use dbflux_core::{Coord, Geometry};
+use gpui::{
+    App, Bounds, Hsla, IntoElement, PathBuilder, Pixels, Styled, Window, canvas, point, px,
+};
+use gpui_component::ActiveTheme;
+
+/// Gap kept between the shapes and the canvas edges, in pixels.
+const PREVIEW_PADDING: f32 = 12.0;
+const POINT_RADIUS: f32 = 3.0;
+const STROKE_WIDTH: f32 = 1.5;
+
+/// Maps geometry coordinates onto a canvas, keeping the aspect ratio and
+/// centring the shapes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Projection {
+    min_x: f64,
+    max_y: f64,
+    scale: f64,
+    offset_x: f64,
+    offset_y: f64,
+}
+
+impl Projection {
+    /// Fits `extent` (`min_x, min_y, max_x, max_y`) into a `width` by
+    /// `height` canvas. A zero-sized extent, such as a single point, lands in
+    /// the middle.
+    fn fit(extent: (f64, f64, f64, f64), width: f64, height: f64) -> Self {
+        let (min_x, min_y, max_x, max_y) = extent;
+        let padding = f64::from(PREVIEW_PADDING);
+        let span_x = max_x - min_x;
+        let span_y = max_y - min_y;
+        let room_x = (width - 2.0 * padding).max(1.0);
+        let room_y = (height - 2.0 * padding).max(1.0);
+
+        let scale = match (span_x > 0.0, span_y > 0.0) {
+            (true, true) => (room_x / span_x).min(room_y / span_y),
+            (true, false) => room_x / span_x,
+            (false, true) => room_y / span_y,
+            (false, false) => 1.0,
+        };
+
+        Self {
+            min_x,
+            max_y,
+            scale,
+            offset_x: (width - span_x * scale) / 2.0,
+            offset_y: (height - span_y * scale) / 2.0,
+        }
+    }
+
+    /// Canvas position of `coord`, relative to the canvas origin.
+    fn project(&self, coord: &Coord) -> (f32, f32) {
+        (
+            (self.offset_x + (coord.x - self.min_x) * self.scale) as f32,
+            (self.offset_y + (self.max_y - coord.y) * self.scale) as f32,
+        )
+    }
+}
+
+struct Painter {
+    bounds: Bounds<Pixels>,
+    projection: Projection,
+    stroke: Hsla,
+    fill: Hsla,
+    background: Hsla,
+}
+
+impl Painter {
+    fn at(&self, coord: &Coord) -> gpui::Point<Pixels> {
+        let (x, y) = self.projection.project(coord);
+        point(self.bounds.origin.x + px(x), self.bounds.origin.y + px(y))
+    }
+
+    fn path(
+        &self,
+        mut builder: PathBuilder,
+        coords: &[Coord],
+        close: bool,
+    ) -> Option<gpui::Path<Pixels>> {
+        let (first, rest) = coords.split_first()?;
+
+        builder.move_to(self.at(first));
+        for coord in rest {
+            builder.line_to(self.at(coord));
+        }
+        if close {
+            builder.close();
+        }
+
+        builder.build().ok()
+    }
+
+    fn line(&self, window: &mut Window, coords: &[Coord]) {
+        if let Some(path) = self.path(PathBuilder::stroke(px(STROKE_WIDTH)), coords, false) {
+            window.paint_path(path, self.stroke);
+        }
+    }
+
+    fn point(&self, window: &mut Window, coord: &Coord) {
+        let center = self.at(coord);
+        let radii = point(px(POINT_RADIUS), px(POINT_RADIUS));
+        let right = point(center.x + px(POINT_RADIUS), center.y);
+        let left = point(center.x - px(POINT_RADIUS), center.y);
+
+        // PathBuilder has no circle, so the dot is two half-arcs.
+        let mut builder = PathBuilder::fill();
+        builder.move_to(right);
+        builder.arc_to(radii, px(0.0), false, true, left);
+        builder.arc_to(radii, px(0.0), false, true, right);
+        builder.close();
+
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, self.stroke);
+        }
+    }
+
+    /// Fills the exterior ring, punches the holes with the background and
+    /// outlines every ring.
+    fn polygon(&self, window: &mut Window, rings: &[Vec<Coord>]) {
+        for (ix, ring) in rings.iter().enumerate() {
+            let color = if ix == 0 { self.fill } else { self.background };
+            if let Some(path) = self.path(PathBuilder::fill(), ring, true) {
+                window.paint_path(path, color);
+            }
+        }
+
+        for ring in rings {
+            self.line(window, ring);
+        }
+    }
+
+    fn geometry(&self, window: &mut Window, geometry: &Geometry) {
+        match geometry {
+            Geometry::Point(coord) => coord.iter().for_each(|coord| self.point(window, coord)),
+            Geometry::MultiPoint(coords) => {
+                coords.iter().for_each(|coord| self.point(window, coord))
+            }
+            Geometry::LineString(coords) => self.line(window, coords),
+            Geometry::MultiLineString(lines) => {
+                lines.iter().for_each(|line| self.line(window, line))
+            }
+            Geometry::Polygon(rings) => self.polygon(window, rings),
+            Geometry::MultiPolygon(polygons) => polygons
+                .iter()
+                .for_each(|rings| self.polygon(window, rings)),
+            Geometry::GeometryCollection(members) => members
+                .iter()
+                .for_each(|member| self.geometry(window, member)),
+        }
+    }
+}
+
+/// Canvas that draws `geometry` scaled to fill the element. Empty
+/// geometries draw nothing.
+pub fn geometry_preview(geometry: Geometry) -> impl IntoElement {
+    canvas(
+        |_, _, _| (),
+        move |bounds, _, window, cx: &mut App| {
+            let Some(extent) = geometry.bounds() else {
+                return;
+            };
+
+            let theme = cx.theme();
+            let painter = Painter {
+                bounds,
+                projection: Projection::fit(
+                    extent,
+                    f64::from(f32::from(bounds.size.width)),
+                    f64::from(f32::from(bounds.size.height)),
+                ),
+                stroke: theme.primary,
+                fill: theme.primary.opacity(0.2),
+                background: theme.background,
+            };
+
+            painter.geometry(window, &geometry);
+        },
+    )
+    .size_full()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(x: f64, y: f64) -> Coord {
+        Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    #[test]
+    fn projection_fits_extent_with_north_up() {
+        // A 10×5 extent in a 224×124 canvas scales by 20 once padded.
+        let projection = Projection::fit((0.0, 0.0, 10.0, 5.0), 224.0, 124.0);
+
+        assert_eq!(projection.project(&coord(0.0, 5.0)), (12.0, 12.0));
+        assert_eq!(projection.project(&coord(10.0, 0.0)), (212.0, 112.0));
+    }
+
+    #[test]
+    fn single_point_lands_in_the_middle() {
+        let projection = Projection::fit((3.0, 4.0, 3.0, 4.0), 200.0, 100.0);
+
+        assert_eq!(projection.project(&coord(3.0, 4.0)), (100.0, 50.0));
+    }
+}
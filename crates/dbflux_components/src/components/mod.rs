@@ -4,6 +4,7 @@ pub mod document_tree;
 pub mod filter_bar;
 pub mod form_navigation;
 pub mod form_renderer;
+pub mod geometry_preview;
 pub mod json_editor_view;
 pub mod multi_select;
 pub mod tree_nav;
@@ -469,7 +469,7 @@ impl Dropdown {
         let is_disabled = self.disabled;
         let chrome = dropdown_menu_chrome();
 
-        let menu_font_size = density::font_base(cx);
+        let menu_font_size = density::zoomed(cx, density::font_base(cx));
 
         let items: Vec<gpui::AnyElement> = self
             .items
@@ -568,8 +568,8 @@ impl Dropdown {
                     .hover(|s| s.bg(theme.accent.opacity(0.1)))
             });
 
-        let font_sm = density::font_sm(cx);
-        let font_base = density::font_base(cx);
+        let font_sm = density::zoomed(cx, density::font_sm(cx));
+        let font_base = density::zoomed(cx, density::font_base(cx));
 
         match variant {
             DropdownTriggerVariant::Compact => {
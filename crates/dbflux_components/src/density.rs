@@ -18,10 +18,19 @@
 //! | `radius_sm`        |  0 px      |  2 px      |
 //! | `radius_md`        |  0 px      |  2 px      |
 //! | `radius_lg`        |  0 px      |  3 px      |
+//!
+//! Font-size accessors return unzoomed sizes. The user's UI zoom and the
+//! editor and grid font sizes live in [`FontScale`]; `Text` applies the zoom
+//! when it renders, and raw `text_size` sites wrap their size in [`zoomed`].
 
-use dbflux_core::AppStyle;
+use dbflux_core::{
+    AppStyle, DEFAULT_EDITOR_FONT_SIZE, DEFAULT_GRID_FONT_SIZE, DEFAULT_UI_ZOOM_PERCENT,
+    GeneralSettings,
+};
 use gpui::{App, Global, Pixels, px};
 
+use crate::components::data_table::ROW_HEIGHT;
+
 /// GPUI global that stores the active `AppStyle`.
 ///
 /// Registered by `density::init`. Accessors fall back to `AppStyle::Default`
@@ -53,6 +62,79 @@ pub fn active_style(cx: &App) -> AppStyle {
         .unwrap_or(AppStyle::Default)
 }
 
+// ---------------------------------------------------------------------------
+// Zoom and content font sizes
+// ---------------------------------------------------------------------------
+
+/// GPUI global with the user's UI zoom and content font sizes.
+///
+/// Accessors fall back to the defaults when the global is absent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontScale {
+    pub zoom_percent: u16,
+    pub editor_font_size: u16,
+    pub grid_font_size: u16,
+}
+
+impl Global for FontScale {}
+
+impl Default for FontScale {
+    fn default() -> Self {
+        Self {
+            zoom_percent: DEFAULT_UI_ZOOM_PERCENT,
+            editor_font_size: DEFAULT_EDITOR_FONT_SIZE,
+            grid_font_size: DEFAULT_GRID_FONT_SIZE,
+        }
+    }
+}
+
+impl FontScale {
+    pub fn from_settings(settings: &GeneralSettings) -> Self {
+        Self {
+            zoom_percent: settings.ui_zoom_percent,
+            editor_font_size: settings.editor_font_size,
+            grid_font_size: settings.grid_font_size,
+        }
+    }
+}
+
+/// Register or replace the font scale global.
+pub fn set_font_scale(cx: &mut App, scale: FontScale) {
+    cx.set_global(scale);
+}
+
+fn font_scale(cx: &App) -> FontScale {
+    cx.try_global::<FontScale>().copied().unwrap_or_default()
+}
+
+/// The UI zoom factor: 1.0 at 100%.
+pub fn zoom(cx: &App) -> f32 {
+    f32::from(font_scale(cx).zoom_percent) / 100.0
+}
+
+/// `size` scaled by the UI zoom.
+pub fn zoomed(cx: &App, size: Pixels) -> Pixels {
+    size * zoom(cx)
+}
+
+/// Code editor font size, before zoom.
+pub fn editor_font_size(cx: &App) -> Pixels {
+    px(f32::from(font_scale(cx).editor_font_size))
+}
+
+/// Result grid cell font size, before zoom.
+pub fn grid_font_size(cx: &App) -> Pixels {
+    px(f32::from(font_scale(cx).grid_font_size))
+}
+
+/// Result grid row height: `ROW_HEIGHT` grown or shrunk with the grid font
+/// size, then zoomed.
+pub fn grid_row_height(cx: &App) -> Pixels {
+    let scale = font_scale(cx);
+    let font_ratio = f32::from(scale.grid_font_size) / f32::from(DEFAULT_GRID_FONT_SIZE);
+    (ROW_HEIGHT * font_ratio * zoom(cx)).round()
+}
+
 // ---------------------------------------------------------------------------
 // Font-size accessors
 // ---------------------------------------------------------------------------
@@ -201,4 +283,35 @@ mod tests {
             assert_eq!(font_xs(cx), px(11.0));
         });
     }
+
+    #[gpui::test]
+    fn font_scale_defaults_to_unzoomed_sizes_when_global_absent(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            assert_eq!(zoom(cx), 1.0);
+            assert_eq!(editor_font_size(cx), px(14.0));
+            assert_eq!(grid_font_size(cx), px(13.0));
+            assert_eq!(grid_row_height(cx), ROW_HEIGHT);
+        });
+    }
+
+    #[gpui::test]
+    fn font_scale_zooms_sizes_and_grows_grid_rows(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            set_font_scale(
+                cx,
+                FontScale {
+                    zoom_percent: 150,
+                    editor_font_size: 16,
+                    grid_font_size: 26,
+                },
+            );
+
+            assert_eq!(zoom(cx), 1.5);
+            assert_eq!(zoomed(cx, px(12.0)), px(18.0));
+            assert_eq!(editor_font_size(cx), px(16.0));
+            assert_eq!(grid_row_height(cx), (ROW_HEIGHT * 3.0).round());
+            // Tier accessors stay unzoomed; `Text` applies the zoom.
+            assert_eq!(font_base(cx), px(14.0));
+        });
+    }
 }
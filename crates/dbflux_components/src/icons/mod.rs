@@ -21,6 +21,7 @@ pub enum AppIcon {
 
     // Actions
     Play,
+    Pause,
     SquarePlay,
     Plus,
     Power,
@@ -48,6 +49,7 @@ pub enum AppIcon {
     CircleCheck,
     CircleX,
     TriangleAlert,
+    Bell,
     ExternalLink,
     Globe,
     Code,
@@ -61,11 +63,14 @@ pub enum AppIcon {
     Zap,
     Hash,
     Lock,
+    Pin,
     Layers,
     Keyboard,
     FingerprintPattern,
     Maximize2,
     Minimize2,
+    ZoomIn,
+    ZoomOut,
     PanelBottomClose,
     PanelBottomOpen,
     FileSpreadsheet,
@@ -135,6 +140,7 @@ impl AppIcon {
             Self::ChevronRight => "icons/ui/chevron-right.svg",
             Self::ChevronUp => "icons/ui/chevron-up.svg",
             Self::Play => "icons/ui/play.svg",
+            Self::Pause => "icons/ui/pause.svg",
             Self::SquarePlay => "icons/ui/square-play.svg",
             Self::Plus => "icons/ui/plus.svg",
             Self::Power => "icons/ui/power.svg",
@@ -160,6 +166,7 @@ impl AppIcon {
             Self::CircleCheck => "icons/ui/circle-check.svg",
             Self::CircleX => "icons/ui/circle-x.svg",
             Self::TriangleAlert => "icons/ui/triangle-alert.svg",
+            Self::Bell => "icons/ui/bell.svg",
             Self::ExternalLink => "icons/ui/external-link.svg",
             Self::Globe => "icons/ui/globe.svg",
             Self::Code => "icons/ui/code.svg",
@@ -173,11 +180,14 @@ impl AppIcon {
             Self::Zap => "icons/ui/zap.svg",
             Self::Hash => "icons/ui/hash.svg",
             Self::Lock => "icons/ui/lock.svg",
+            Self::Pin => "icons/ui/pin.svg",
             Self::Layers => "icons/ui/layers.svg",
             Self::Keyboard => "icons/ui/keyboard.svg",
             Self::FingerprintPattern => "icons/ui/fingerprint-pattern.svg",
             Self::Maximize2 => "icons/ui/maximize-2.svg",
             Self::Minimize2 => "icons/ui/minimize-2.svg",
+            Self::ZoomIn => "icons/ui/zoom-in.svg",
+            Self::ZoomOut => "icons/ui/zoom-out.svg",
             Self::PanelBottomClose => "icons/ui/panel-bottom-close.svg",
             Self::PanelBottomOpen => "icons/ui/panel-bottom-open.svg",
             Self::FileSpreadsheet => "icons/ui/file-spreadsheet.svg",
@@ -27,6 +27,7 @@ pub mod result_panel;
 pub mod result_view;
 pub mod saved_chart;
 pub mod theme;
+pub mod user_theme;
 
 pub mod sql_preview;
 
@@ -0,0 +1,1388 @@
+use crate::components::geometry_preview::geometry_preview;
+use crate::components::json_editor_view;
+use crate::composites::ModalFrame;
+use crate::controls::{
+    GpuiInput as Input, InputEvent, InputPosition, InputState, ReadonlyTextView,
+};
+use crate::icon::IconSource;
+use crate::icons::AppIcon;
+use crate::primitives::{Icon, Text};
+use crate::tokens::{FontSizes, Heights, Spacing};
+use crate::typography::AppFonts;
+use dbflux_core::SpatialValue;
+use dbflux_core::keymap_types::ContextId;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariant, ButtonVariants};
+use gpui_component::highlighter::{
+    Diagnostic as InputDiagnostic, DiagnosticSeverity as InputDiagnosticSeverity,
+};
+use gpui_component::{ActiveTheme, Disableable};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Upper bound on highlighted matches, mirroring the editor's find bar.
+const MAX_VIEWER_MATCHES: usize = 10_000;
+
+/// Bytes shown per line of the hex view.
+const HEX_BYTES_PER_LINE: usize = 16;
+
+/// Area an image preview is fitted into when the viewer opens.
+const IMAGE_FIT_WIDTH: f32 = 860.0;
+const IMAGE_FIT_HEIGHT: f32 = 420.0;
+
+/// Zoom levels the image preview steps through.
+const IMAGE_ZOOM_STEPS: [f32; 11] = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0];
+
+/// Event emitted when the user asks to save the shown value to a file.
+#[derive(Clone)]
+pub struct CellViewerSaveEvent {
+    pub column: String,
+    pub contents: String,
+    /// File extension matching the detected format, without the dot.
+    pub extension: &'static str,
+}
+
+/// Event emitted when the user asks to save a binary value to a file,
+/// either as raw bytes or hex-encoded.
+#[derive(Clone)]
+pub struct CellViewerSaveBytesEvent {
+    pub column: String,
+    pub bytes: Arc<[u8]>,
+    pub as_hex: bool,
+    /// Extension for raw saves of a recognised image, e.g. `png`.
+    pub image_extension: Option<&'static str>,
+}
+
+/// Event emitted when the viewer is closed.
+#[derive(Clone)]
+pub struct CellViewerClosedEvent;
+
+/// How the viewer presents a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueFormat {
+    Json,
+    Xml,
+    Text,
+    /// Spatial value as (E)WKT.
+    Wkt,
+    /// Spatial value as a GeoJSON geometry.
+    GeoJson,
+}
+
+impl ValueFormat {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Xml => "XML",
+            Self::Text => "Text",
+            Self::Wkt => "WKT",
+            Self::GeoJson => "GeoJSON",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Xml => "xml",
+            Self::Text => "txt",
+            Self::Wkt => "wkt",
+            Self::GeoJson => "geojson",
+        }
+    }
+
+    fn language(self) -> &'static str {
+        match self {
+            Self::Json | Self::GeoJson => "json",
+            Self::Xml | Self::Text | Self::Wkt => "text",
+        }
+    }
+}
+
+/// Text of `value` in `format`, which is either WKT or GeoJSON.
+fn spatial_text(value: &SpatialValue, format: ValueFormat) -> String {
+    match format {
+        ValueFormat::GeoJson => {
+            serde_json::to_string_pretty(&value.to_geojson()).unwrap_or_else(|_| value.to_ewkt())
+        }
+        _ => value.to_ewkt(),
+    }
+}
+
+/// Detects the format of `value` and pretty-prints it.
+///
+/// Values that look like JSON or XML but fail to parse are shown verbatim
+/// as text, so a malformed document is never altered.
+pub fn pretty_print(value: &str, is_json: bool) -> (ValueFormat, String) {
+    let trimmed = value.trim();
+
+    if (is_json || trimmed.starts_with('{') || trimmed.starts_with('['))
+        && let Some(formatted) = json_editor_view::format_json(trimmed)
+    {
+        return (ValueFormat::Json, formatted);
+    }
+
+    if trimmed.starts_with('<')
+        && let Some(formatted) = format_xml(trimmed)
+    {
+        return (ValueFormat::Xml, formatted);
+    }
+
+    (ValueFormat::Text, value.to_string())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum XmlToken<'a> {
+    Open {
+        name: &'a str,
+        raw: &'a str,
+    },
+    Close {
+        name: &'a str,
+    },
+    /// Self-closing tags, comments, CDATA, declarations and processing
+    /// instructions: anything that neither opens nor closes an element.
+    Leaf(&'a str),
+    Text(&'a str),
+}
+
+fn xml_tag_name(inner: &str) -> &str {
+    let end = inner
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .unwrap_or(inner.len());
+    &inner[..end]
+}
+
+/// Byte offset just past the `>` closing the tag that starts `source`,
+/// skipping any `>` inside quoted attribute values.
+fn xml_tag_end(source: &str) -> Option<usize> {
+    let mut quote = None;
+
+    for (index, c) in source.char_indices() {
+        match (quote, c) {
+            (Some(open), _) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(index + 1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn tokenize_xml(source: &str) -> Option<Vec<XmlToken<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+
+    while !rest.is_empty() {
+        let len = if !rest.starts_with('<') {
+            let len = rest.find('<').unwrap_or(rest.len());
+            let text = rest[..len].trim();
+            if !text.is_empty() {
+                tokens.push(XmlToken::Text(text));
+            }
+            len
+        } else {
+            let terminator = [("<!--", "-->"), ("<![CDATA[", "]]>"), ("<?", "?>")]
+                .into_iter()
+                .find(|(open, _)| rest.starts_with(open));
+
+            let len = match terminator {
+                Some((_, close)) => rest.find(close)? + close.len(),
+                None => xml_tag_end(rest)?,
+            };
+            let raw = &rest[..len];
+
+            if let Some(inner) = raw.strip_prefix("</") {
+                tokens.push(XmlToken::Close {
+                    name: xml_tag_name(inner.trim_start()),
+                });
+            } else if terminator.is_some() || raw.starts_with("<!") || raw.ends_with("/>") {
+                tokens.push(XmlToken::Leaf(raw));
+            } else {
+                let name = xml_tag_name(&raw[1..]);
+                if name.is_empty() {
+                    return None;
+                }
+                tokens.push(XmlToken::Open { name, raw });
+            }
+
+            len
+        };
+
+        rest = &rest[len..];
+    }
+
+    Some(tokens)
+}
+
+/// Pretty-prints an XML document with two-space indentation. Elements that
+/// only hold text stay on one line. Returns `None` if the tags are not
+/// balanced or the input contains no element.
+pub fn format_xml(source: &str) -> Option<String> {
+    let tokens = tokenize_xml(source)?;
+    let mut lines: Vec<String> = Vec::new();
+    let mut open: Vec<&str> = Vec::new();
+    let mut has_element = false;
+    let mut index = 0;
+
+    let mut push_line = |depth: usize, line: String| {
+        lines.push(format!("{}{}", "  ".repeat(depth), line));
+    };
+
+    while index < tokens.len() {
+        let depth = open.len();
+
+        match tokens[index] {
+            XmlToken::Open { name, raw } => {
+                has_element = true;
+
+                match (tokens.get(index + 1), tokens.get(index + 2)) {
+                    (Some(XmlToken::Close { name: close }), _) if *close == name => {
+                        push_line(depth, format!("{raw}</{name}>"));
+                        index += 2;
+                        continue;
+                    }
+                    (Some(XmlToken::Text(text)), Some(XmlToken::Close { name: close }))
+                        if *close == name =>
+                    {
+                        push_line(depth, format!("{raw}{text}</{name}>"));
+                        index += 3;
+                        continue;
+                    }
+                    _ => {
+                        push_line(depth, raw.to_string());
+                        open.push(name);
+                    }
+                }
+            }
+            XmlToken::Close { name } => {
+                if open.pop() != Some(name) {
+                    return None;
+                }
+                push_line(depth - 1, format!("</{name}>"));
+            }
+            XmlToken::Leaf(raw) => {
+                has_element |= raw.ends_with("/>");
+                push_line(depth, raw.to_string());
+            }
+            XmlToken::Text(text) => push_line(depth, text.to_string()),
+        }
+
+        index += 1;
+    }
+
+    (open.is_empty() && has_element).then(|| lines.join("\n"))
+}
+
+/// Returns the byte ranges of every case-insensitive occurrence of `query`.
+pub fn find_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    // ASCII lowercasing keeps byte offsets identical to `text`.
+    let haystack = text.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+
+    haystack
+        .match_indices(&needle)
+        .take(MAX_VIEWER_MATCHES)
+        .map(|(start, found)| start..start + found.len())
+        .collect()
+}
+
+/// Converts a byte offset into a zero-based `(line, character)` position with
+/// UTF-16 character units, as expected by the input.
+fn position_for_offset(text: &str, byte_offset: usize) -> (u32, u32) {
+    let prefix = &text[..byte_offset.min(text.len())];
+    let line = prefix.matches('\n').count();
+    let line_start = prefix.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    let character: usize = prefix[line_start..].chars().map(char::len_utf16).sum();
+
+    (line as u32, character as u32)
+}
+
+/// Parses a byte offset typed as decimal (`512`) or hexadecimal (`0x200`).
+pub fn parse_offset(input: &str) -> Option<usize> {
+    let input = input.trim();
+
+    match input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => input.parse().ok(),
+    }
+}
+
+/// Byte range shown on `line` of the hex view of `len` bytes.
+fn hex_line_range(len: usize, line: usize) -> Range<usize> {
+    let start = (line * HEX_BYTES_PER_LINE).min(len);
+    start..(start + HEX_BYTES_PER_LINE).min(len)
+}
+
+/// Character shown for `byte` in the ASCII column; non-printable bytes
+/// become `.`.
+fn ascii_column_char(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Image formats recognised in binary values by their magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageKind {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+}
+
+impl ImageKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Png => "PNG",
+            Self::Jpeg => "JPEG",
+            Self::Gif => "GIF",
+            Self::Webp => "WebP",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+        }
+    }
+
+    fn format(self) -> ImageFormat {
+        match self {
+            Self::Png => ImageFormat::Png,
+            Self::Jpeg => ImageFormat::Jpeg,
+            Self::Gif => ImageFormat::Gif,
+            Self::Webp => ImageFormat::Webp,
+        }
+    }
+}
+
+/// Format and pixel size of an image stored in a binary value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub kind: ImageKind,
+    pub width: u32,
+    pub height: u32,
+}
+
+fn be_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 2)?;
+    Some(u32::from(u16::from_be_bytes([b[0], b[1]])))
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 2)?;
+    Some(u32::from(u16::from_le_bytes([b[0], b[1]])))
+}
+
+fn le_u24(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 3)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], 0]))
+}
+
+/// Size of a JPEG from its first start-of-frame segment.
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut at = 2;
+
+    loop {
+        if *bytes.get(at)? != 0xFF {
+            return None;
+        }
+
+        let marker = *bytes.get(at + 1)?;
+
+        match marker {
+            // Fill bytes before a marker.
+            0xFF => at += 1,
+            // Markers without a length field.
+            0x01 | 0xD0..=0xD9 => at += 2,
+            // Start of frame; DHT (C4), JPG (C8) and DAC (CC) share the range.
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                return Some((be_u16(bytes, at + 7)?, be_u16(bytes, at + 5)?));
+            }
+            _ => at += 2 + be_u16(bytes, at + 2)? as usize,
+        }
+    }
+}
+
+/// Size of a WebP from its first chunk, for lossy, lossless and extended
+/// files.
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8 " => {
+            if bytes.get(23..26)? != [0x9D, 0x01, 0x2A] {
+                return None;
+            }
+            Some((le_u16(bytes, 26)? & 0x3FFF, le_u16(bytes, 28)? & 0x3FFF))
+        }
+        b"VP8L" => {
+            if *bytes.get(20)? != 0x2F {
+                return None;
+            }
+            let b = bytes
+                .get(21..25)?
+                .iter()
+                .map(|&b| u32::from(b))
+                .collect::<Vec<_>>();
+            let width = 1 + (((b[1] & 0x3F) << 8) | b[0]);
+            let height = 1 + (((b[3] & 0x0F) << 10) | (b[2] << 2) | ((b[1] & 0xC0) >> 6));
+            Some((width, height))
+        }
+        b"VP8X" => Some((1 + le_u24(bytes, 24)?, 1 + le_u24(bytes, 27)?)),
+        _ => None,
+    }
+}
+
+/// Recognises a PNG, JPEG, GIF or WebP image in `bytes` and reads its size
+/// from the header. Returns `None` for anything else, including truncated
+/// headers.
+pub fn detect_image(bytes: &[u8]) -> Option<ImageInfo> {
+    let (kind, (width, height)) = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        if bytes.get(12..16)? != b"IHDR" {
+            return None;
+        }
+        let width = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+        let height = u32::from_be_bytes(bytes.get(20..24)?.try_into().ok()?);
+        (ImageKind::Png, (width, height))
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        (ImageKind::Jpeg, jpeg_dimensions(bytes)?)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        (ImageKind::Gif, (le_u16(bytes, 6)?, le_u16(bytes, 8)?))
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        (ImageKind::Webp, webp_dimensions(bytes)?)
+    } else {
+        return None;
+    };
+
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    Some(ImageInfo {
+        kind,
+        width,
+        height,
+    })
+}
+
+/// Zoom that fits `info` into the preview area without enlarging it.
+fn fit_zoom(info: &ImageInfo) -> f32 {
+    (IMAGE_FIT_WIDTH / info.width as f32)
+        .min(IMAGE_FIT_HEIGHT / info.height as f32)
+        .min(1.0)
+}
+
+/// Next zoom step above (`zoom_in`) or below `current`, staying at the
+/// ends of the range.
+fn step_zoom(current: f32, zoom_in: bool) -> f32 {
+    let next = if zoom_in {
+        IMAGE_ZOOM_STEPS
+            .iter()
+            .find(|&&step| step > current + 0.001)
+    } else {
+        IMAGE_ZOOM_STEPS
+            .iter()
+            .rev()
+            .find(|&&step| step < current - 0.001)
+    };
+
+    next.copied().unwrap_or(current)
+}
+
+/// One line of the hex view: offset, hex pairs and the ASCII column.
+fn render_hex_line(
+    bytes: &[u8],
+    line: usize,
+    selected: Option<usize>,
+    theme: &gpui_component::Theme,
+) -> AnyElement {
+    let range = hex_line_range(bytes.len(), line);
+    let padding = HEX_BYTES_PER_LINE - range.len();
+
+    let byte_cell = |offset: Option<usize>, text: String| {
+        div()
+            .when(offset.is_some() && offset == selected, |d| {
+                d.bg(theme.primary.opacity(0.25)).text_color(theme.primary)
+            })
+            .child(text)
+    };
+
+    let hex_cells = range
+        .clone()
+        .map(|offset| byte_cell(Some(offset), format!("{:02x}", bytes[offset])))
+        .chain((0..padding).map(|_| byte_cell(None, "  ".to_string())))
+        .enumerate()
+        .map(|(ix, cell)| cell.when(ix == HEX_BYTES_PER_LINE / 2, |d| d.ml(Spacing::SM)));
+
+    let ascii_cells = range
+        .clone()
+        .map(|offset| byte_cell(Some(offset), ascii_column_char(bytes[offset]).to_string()));
+
+    div()
+        .flex()
+        .gap(Spacing::LG)
+        .font_family(AppFonts::MONO)
+        .text_size(FontSizes::SM)
+        .child(
+            div()
+                .text_color(theme.muted_foreground)
+                .child(format!("{:08x}", range.start)),
+        )
+        .child(div().flex().gap(Spacing::XS).children(hex_cells))
+        .child(div().flex().children(ascii_cells))
+        .into_any_element()
+}
+
+/// Read-only viewer for a single cell value, with pretty-printing, search,
+/// copy and save. Binary values are shown as a hex dump instead.
+pub struct CellViewerModal {
+    visible: bool,
+    column: String,
+    format: ValueFormat,
+    content: Entity<InputState>,
+    search_input: Entity<InputState>,
+    matches: Vec<Range<usize>>,
+    active_match: Option<usize>,
+    /// Raw bytes of a binary value; `None` when a text value is shown.
+    bytes: Option<Arc<[u8]>>,
+    /// The value is NULL, shown with the NULL marker instead of text.
+    is_null: bool,
+    /// Decoded spatial value, drawn next to its WKT or GeoJSON text.
+    geometry: Option<SpatialValue>,
+    /// Decoded preview when the binary value is a recognised image.
+    image: Option<(ImageInfo, Arc<Image>)>,
+    image_zoom: f32,
+    /// Whether an image value is shown as a picture rather than hex.
+    show_image: bool,
+    offset_input: Entity<InputState>,
+    /// Byte jumped to with the offset input, highlighted in the hex view.
+    selected_offset: Option<usize>,
+    offset_error: bool,
+    hex_scroll: UniformListScrollHandle,
+    focus_handle: FocusHandle,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CellViewerModal {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let content = Self::content_state(ValueFormat::Text, window, cx);
+        let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Find in value"));
+
+        let search_sub = cx.subscribe_in(
+            &search_input,
+            window,
+            |this, _, event: &InputEvent, window, cx| match event {
+                InputEvent::Change => {
+                    this.active_match = None;
+                    this.refresh_matches(window, cx);
+                }
+                InputEvent::PressEnter { .. } => this.step_match(true, window, cx),
+                InputEvent::Focus | InputEvent::Blur => {}
+            },
+        );
+
+        let offset_input = cx
+            .new(|cx| InputState::new(window, cx).placeholder("Go to offset (e.g. 512 or 0x200)"));
+
+        let offset_sub = cx.subscribe_in(
+            &offset_input,
+            window,
+            |this, _, event: &InputEvent, _window, cx| match event {
+                InputEvent::Change => {
+                    this.offset_error = false;
+                    cx.notify();
+                }
+                InputEvent::PressEnter { .. } => this.go_to_offset(cx),
+                InputEvent::Focus | InputEvent::Blur => {}
+            },
+        );
+
+        Self {
+            visible: false,
+            column: String::new(),
+            format: ValueFormat::Text,
+            content,
+            search_input,
+            matches: Vec::new(),
+            active_match: None,
+            bytes: None,
+            is_null: false,
+            geometry: None,
+            image: None,
+            image_zoom: 1.0,
+            show_image: false,
+            offset_input,
+            selected_offset: None,
+            offset_error: false,
+            hex_scroll: UniformListScrollHandle::new(),
+            focus_handle: cx.focus_handle(),
+            _subscriptions: vec![search_sub, offset_sub],
+        }
+    }
+
+    fn content_state(
+        format: ValueFormat,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<InputState> {
+        cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(format.language())
+                .line_number(true)
+                .soft_wrap(true)
+        })
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows `value` from `column`, pretty-printed when it is JSON or XML.
+    pub fn open(
+        &mut self,
+        column: String,
+        value: String,
+        is_json: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (format, formatted) = pretty_print(&value, is_json);
+        self.show_text(column, format, formatted, window, cx);
+    }
+
+    /// Shows a NULL value from `column`, labelled so it cannot be mistaken
+    /// for an empty string.
+    pub fn open_null(&mut self, column: String, window: &mut Window, cx: &mut Context<Self>) {
+        self.show_text(column, ValueFormat::Text, String::new(), window, cx);
+        self.is_null = true;
+    }
+
+    /// Shows a spatial value from `column` as WKT with a 2D sketch of its
+    /// shapes.
+    pub fn open_geometry(
+        &mut self,
+        column: String,
+        value: SpatialValue,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let text = spatial_text(&value, ValueFormat::Wkt);
+        self.show_text(column, ValueFormat::Wkt, text, window, cx);
+        self.geometry = Some(value);
+    }
+
+    /// Switches a spatial value between WKT and GeoJSON.
+    fn toggle_geojson(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(value) = self.geometry.take() else {
+            return;
+        };
+
+        let format = if self.format == ValueFormat::GeoJson {
+            ValueFormat::Wkt
+        } else {
+            ValueFormat::GeoJson
+        };
+
+        let text = spatial_text(&value, format);
+        self.show_text(self.column.clone(), format, text, window, cx);
+        self.geometry = Some(value);
+    }
+
+    fn show_text(
+        &mut self,
+        column: String,
+        format: ValueFormat,
+        formatted: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // The highlighter is fixed when the input is built, so each value
+        // gets a fresh input for its format.
+        self.content = Self::content_state(format, window, cx);
+        self.content.update(cx, |state, cx| {
+            state.set_value(&formatted, window, cx);
+        });
+
+        self.column = column;
+        self.format = format;
+        self.bytes = None;
+        self.is_null = false;
+        self.geometry = None;
+        self.image = None;
+        self.visible = true;
+        self.active_match = None;
+        self.refresh_matches(window, cx);
+
+        self.search_input
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    /// Shows a binary value from `column` as a hex dump, or as a picture
+    /// when it holds a PNG, JPEG, GIF or WebP image.
+    pub fn open_binary(
+        &mut self,
+        column: String,
+        bytes: Arc<[u8]>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.image = detect_image(&bytes).map(|info| {
+            let image = Image::from_bytes(info.kind.format(), bytes.to_vec());
+            (info, Arc::new(image))
+        });
+        self.image_zoom = self.image.as_ref().map_or(1.0, |(info, _)| fit_zoom(info));
+        self.show_image = self.image.is_some();
+
+        self.column = column;
+        self.bytes = Some(bytes);
+        self.is_null = false;
+        self.geometry = None;
+        self.selected_offset = None;
+        self.offset_error = false;
+        self.visible = true;
+        self.hex_scroll = UniformListScrollHandle::new();
+
+        self.offset_input.update(cx, |state, cx| {
+            state.set_value("", window, cx);
+            state.focus(window, cx);
+        });
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        let was_visible = self.visible;
+        self.visible = false;
+
+        if was_visible {
+            cx.emit(CellViewerClosedEvent);
+        }
+
+        cx.notify();
+    }
+
+    fn refresh_matches(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.content.read(cx).value().to_string();
+        let query = self.search_input.read(cx).value().to_string();
+
+        self.matches = find_matches(&text, &query);
+        self.active_match = self
+            .active_match
+            .filter(|index| *index < self.matches.len())
+            .or((!self.matches.is_empty()).then_some(0));
+
+        self.highlight_matches(window, cx);
+    }
+
+    fn step_match(&mut self, forward: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let count = self.matches.len();
+        if count == 0 {
+            return;
+        }
+
+        self.active_match = Some(match (self.active_match, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+        });
+
+        self.highlight_matches(window, cx);
+    }
+
+    /// Marks every match in the content and scrolls to the active one.
+    fn highlight_matches(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let matches = self.matches.clone();
+        let active = self.active_match;
+
+        self.content.update(cx, |state, cx| {
+            let value = state.value().to_string();
+            let text = state.text().clone();
+
+            if let Some(diagnostic_set) = state.diagnostics_mut() {
+                diagnostic_set.reset(&text);
+
+                for (index, range) in matches.iter().enumerate() {
+                    let (start_line, start_character) = position_for_offset(&value, range.start);
+                    let (end_line, end_character) = position_for_offset(&value, range.end);
+
+                    let severity = if active == Some(index) {
+                        InputDiagnosticSeverity::Info
+                    } else {
+                        InputDiagnosticSeverity::Hint
+                    };
+
+                    diagnostic_set.push(
+                        InputDiagnostic::new(
+                            InputPosition::new(start_line, start_character)
+                                ..InputPosition::new(end_line, end_character),
+                            format!("Match {} of {}", index + 1, matches.len()),
+                        )
+                        .with_severity(severity),
+                    );
+                }
+            }
+
+            if let Some(range) = active.and_then(|index| matches.get(index)) {
+                let (line, character) = position_for_offset(&value, range.start);
+                state.set_cursor_position(InputPosition::new(line, character), window, cx);
+            }
+
+            cx.notify();
+        });
+
+        cx.notify();
+    }
+
+    /// Scrolls the hex view to the offset typed in the offset input.
+    fn go_to_offset(&mut self, cx: &mut Context<Self>) {
+        let Some(len) = self.bytes.as_ref().map(|bytes| bytes.len()) else {
+            return;
+        };
+
+        match parse_offset(&self.offset_input.read(cx).value()).filter(|offset| *offset < len) {
+            Some(offset) => {
+                self.selected_offset = Some(offset);
+                self.offset_error = false;
+                self.hex_scroll
+                    .scroll_to_item(offset / HEX_BYTES_PER_LINE, ScrollStrategy::Center);
+            }
+            None => self.offset_error = true,
+        }
+
+        cx.notify();
+    }
+
+    fn zoom_image(&mut self, zoom: f32, cx: &mut Context<Self>) {
+        self.image_zoom = zoom;
+        cx.notify();
+    }
+
+    fn toggle_image(&mut self, cx: &mut Context<Self>) {
+        self.show_image = !self.show_image && self.image.is_some();
+        cx.notify();
+    }
+
+    /// Toolbar button switching an image value between picture and hex.
+    fn image_toggle(&self, cx: &Context<Self>) -> Option<Button> {
+        self.image.as_ref()?;
+
+        Some(
+            Button::new("cell-viewer-image-toggle")
+                .label(if self.show_image {
+                    "Show Hex"
+                } else {
+                    "Show Image"
+                })
+                .small()
+                .with_variant(ButtonVariant::Ghost)
+                .on_click(cx.listener(|this, _, _, cx| this.toggle_image(cx))),
+        )
+    }
+
+    fn render_hex_view(&self, bytes: Arc<[u8]>, cx: &Context<Self>) -> (Div, AnyElement, String) {
+        let theme = cx.theme();
+        let selected = self.selected_offset;
+        let summary = format!("Binary · {} bytes", bytes.len());
+
+        let toolbar = div()
+            .px(Spacing::MD)
+            .py(Spacing::SM)
+            .border_b_1()
+            .border_color(theme.border)
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(Icon::new(AppIcon::Search).size(Heights::ICON_SM).muted())
+            .child(div().flex_1().child(Input::new(&self.offset_input).small()))
+            .when(self.offset_error, |d| {
+                d.child(
+                    Text::caption(format!(
+                        "Offset must be below {} (0x{:x})",
+                        bytes.len(),
+                        bytes.len()
+                    ))
+                    .font_size(FontSizes::XS)
+                    .danger(),
+                )
+            })
+            .child(
+                Button::new("cell-viewer-go")
+                    .label("Go")
+                    .small()
+                    .with_variant(ButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, _, cx| this.go_to_offset(cx))),
+            )
+            .children(self.image_toggle(cx));
+
+        let line_count = bytes.len().div_ceil(HEX_BYTES_PER_LINE);
+        let content = uniform_list("cell-viewer-hex", line_count, move |range, _window, cx| {
+            let theme = cx.theme();
+            range
+                .map(|line| render_hex_line(&bytes, line, selected, theme))
+                .collect()
+        })
+        .track_scroll(self.hex_scroll.clone())
+        .size_full()
+        .into_any_element();
+
+        (toolbar, content, summary)
+    }
+
+    fn render_image_view(&self, cx: &Context<Self>) -> (Div, AnyElement, String) {
+        let theme = cx.theme();
+        let Some((info, image)) = self.image.clone() else {
+            return (div(), div().into_any_element(), String::new());
+        };
+
+        let zoom = self.image_zoom;
+        let summary = format!(
+            "{} image · {}×{} · {} bytes",
+            info.kind.label(),
+            info.width,
+            info.height,
+            self.bytes.as_ref().map_or(0, |bytes| bytes.len())
+        );
+
+        let toolbar = div()
+            .px(Spacing::MD)
+            .py(Spacing::SM)
+            .border_b_1()
+            .border_color(theme.border)
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(
+                Button::new("cell-viewer-zoom-out")
+                    .icon(AppIcon::ZoomOut)
+                    .small()
+                    .with_variant(ButtonVariant::Ghost)
+                    .disabled(step_zoom(zoom, false) == zoom)
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.zoom_image(step_zoom(zoom, false), cx)
+                    })),
+            )
+            .child(Text::caption(format!("{:.0}%", zoom * 100.0)).font_size(FontSizes::XS))
+            .child(
+                Button::new("cell-viewer-zoom-in")
+                    .icon(AppIcon::ZoomIn)
+                    .small()
+                    .with_variant(ButtonVariant::Ghost)
+                    .disabled(step_zoom(zoom, true) == zoom)
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.zoom_image(step_zoom(zoom, true), cx)
+                    })),
+            )
+            .child(
+                Button::new("cell-viewer-zoom-fit")
+                    .label("Fit")
+                    .small()
+                    .with_variant(ButtonVariant::Ghost)
+                    .on_click(
+                        cx.listener(move |this, _, _, cx| this.zoom_image(fit_zoom(&info), cx)),
+                    ),
+            )
+            .child(
+                Button::new("cell-viewer-zoom-actual")
+                    .label("100%")
+                    .small()
+                    .with_variant(ButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, _, cx| this.zoom_image(1.0, cx))),
+            )
+            .child(div().flex_1())
+            .children(self.image_toggle(cx));
+
+        let content = div()
+            .id("cell-viewer-image")
+            .size_full()
+            .overflow_scroll()
+            .bg(theme.muted)
+            .child(
+                div()
+                    .min_w_full()
+                    .min_h_full()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        img(image)
+                            .w(px(info.width as f32 * zoom))
+                            .h(px(info.height as f32 * zoom)),
+                    ),
+            )
+            .into_any_element();
+
+        (toolbar, content, summary)
+    }
+
+    fn copy(&mut self, cx: &mut Context<Self>) {
+        let text = match &self.bytes {
+            Some(bytes) => hex_encode(bytes),
+            None => self.content.read(cx).value().to_string(),
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+    }
+
+    fn save(&mut self, as_hex: bool, cx: &mut Context<Self>) {
+        match &self.bytes {
+            Some(bytes) => cx.emit(CellViewerSaveBytesEvent {
+                column: self.column.clone(),
+                bytes: bytes.clone(),
+                as_hex,
+                image_extension: self
+                    .image
+                    .as_ref()
+                    .filter(|_| !as_hex)
+                    .map(|(info, _)| info.kind.extension()),
+            }),
+            None => cx.emit(CellViewerSaveEvent {
+                column: self.column.clone(),
+                contents: self.content.read(cx).value().to_string(),
+                extension: self.format.extension(),
+            }),
+        }
+    }
+
+    fn match_label(&self, cx: &App) -> Option<String> {
+        if self.search_input.read(cx).value().is_empty() {
+            return None;
+        }
+
+        Some(match self.active_match {
+            Some(index) => format!("{} of {}", index + 1, self.matches.len()),
+            None => "No matches".to_string(),
+        })
+    }
+}
+
+impl EventEmitter<CellViewerSaveEvent> for CellViewerModal {}
+impl EventEmitter<CellViewerSaveBytesEvent> for CellViewerModal {}
+impl EventEmitter<CellViewerClosedEvent> for CellViewerModal {}
+
+impl Render for CellViewerModal {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let entity = cx.entity().downgrade();
+        let close = move |_window: &mut Window, cx: &mut App| {
+            entity.update(cx, |this, cx| this.close(cx)).ok();
+        };
+
+        let theme = cx.theme();
+
+        let (toolbar, content, summary) = match &self.bytes {
+            Some(_) if self.show_image => self.render_image_view(cx),
+            Some(bytes) => self.render_hex_view(bytes.clone(), cx),
+            None => {
+                let has_matches = !self.matches.is_empty();
+                let text = self.content.read(cx).value();
+                let mut summary = if self.is_null {
+                    "NULL · no value".to_string()
+                } else if text.is_empty() {
+                    format!("{} · empty string", self.format.label())
+                } else {
+                    format!(
+                        "{} · {} characters · {} lines",
+                        self.format.label(),
+                        text.chars().count(),
+                        text.lines().count().max(1)
+                    )
+                };
+                if let Some(srid) = self.geometry.as_ref().and_then(|value| value.srid) {
+                    summary.push_str(&format!(" · SRID {}", srid));
+                }
+
+                let toolbar =
+                    div()
+                        .px(Spacing::MD)
+                        .py(Spacing::SM)
+                        .border_b_1()
+                        .border_color(theme.border)
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::SM)
+                        .child(Icon::new(AppIcon::Search).size(Heights::ICON_SM).muted())
+                        .child(div().flex_1().child(Input::new(&self.search_input).small()))
+                        .when_some(self.match_label(cx), |d, label| {
+                            d.child(Text::caption(label).font_size(FontSizes::XS))
+                        })
+                        .child(
+                            Button::new("cell-viewer-prev")
+                                .icon(AppIcon::ChevronUp)
+                                .small()
+                                .with_variant(ButtonVariant::Ghost)
+                                .disabled(!has_matches)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.step_match(false, window, cx)
+                                })),
+                        )
+                        .child(
+                            Button::new("cell-viewer-next")
+                                .icon(AppIcon::ChevronDown)
+                                .small()
+                                .with_variant(ButtonVariant::Ghost)
+                                .disabled(!has_matches)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.step_match(true, window, cx)
+                                })),
+                        )
+                        .when(self.geometry.is_some(), |d| {
+                            d.child(
+                                Button::new("cell-viewer-geojson")
+                                    .label(if self.format == ValueFormat::GeoJson {
+                                        "Show WKT"
+                                    } else {
+                                        "Show GeoJSON"
+                                    })
+                                    .small()
+                                    .with_variant(ButtonVariant::Ghost)
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.toggle_geojson(window, cx)
+                                    })),
+                            )
+                        });
+
+                let text_view = ReadonlyTextView::new(&self.content).w_full().h_full();
+
+                let content = match &self.geometry {
+                    _ if self.is_null => div()
+                        .size_full()
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .italic()
+                        .child(
+                            Text::body(dbflux_core::null_display::null_marker()).muted_foreground(),
+                        )
+                        .into_any_element(),
+                    Some(value) => div()
+                        .size_full()
+                        .flex()
+                        .gap(Spacing::MD)
+                        .child(div().flex_1().h_full().child(text_view))
+                        .child(
+                            div()
+                                .w(px(320.0))
+                                .h_full()
+                                .border_1()
+                                .border_color(theme.border)
+                                .bg(theme.background)
+                                .child(geometry_preview(value.geometry.clone())),
+                        )
+                        .into_any_element(),
+                    None => text_view.into_any_element(),
+                };
+
+                (toolbar, content, summary)
+            }
+        };
+
+        let is_binary = self.bytes.is_some();
+
+        let footer = div()
+            .px(Spacing::MD)
+            .py(Spacing::SM)
+            .border_t_1()
+            .border_color(theme.border)
+            .flex()
+            .items_center()
+            .justify_between()
+            .child(Text::caption(summary).font_size(FontSizes::XS))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::SM)
+                    .child(
+                        Button::new("cell-viewer-copy")
+                            .label(if is_binary { "Copy Hex" } else { "Copy" })
+                            .icon(AppIcon::Copy)
+                            .small()
+                            .with_variant(ButtonVariant::Ghost)
+                            .on_click(cx.listener(|this, _, _, cx| this.copy(cx))),
+                    )
+                    .when(is_binary, |d| {
+                        d.child(
+                            Button::new("cell-viewer-save-hex")
+                                .label("Save Hex…")
+                                .small()
+                                .with_variant(ButtonVariant::Ghost)
+                                .on_click(cx.listener(|this, _, _, cx| this.save(true, cx))),
+                        )
+                    })
+                    .child(
+                        Button::new("cell-viewer-save")
+                            .label("Save…")
+                            .icon(AppIcon::Download)
+                            .small()
+                            .with_variant(ButtonVariant::Ghost)
+                            .on_click(cx.listener(|this, _, _, cx| this.save(false, cx))),
+                    )
+                    .child(
+                        Button::new("cell-viewer-close")
+                            .label("Close")
+                            .small()
+                            .with_variant(ButtonVariant::Primary)
+                            .on_click(cx.listener(|this, _, _, cx| this.close(cx))),
+                    ),
+            );
+
+        let body = div()
+            .flex_1()
+            .flex()
+            .flex_col()
+            .child(toolbar)
+            .child(
+                div()
+                    .flex_1()
+                    .p(Spacing::MD)
+                    .min_h(px(300.0))
+                    .overflow_hidden()
+                    .child(content),
+            )
+            .child(footer);
+
+        ModalFrame::new("cell-viewer-modal", &self.focus_handle, close)
+            .key_context(ContextId::SqlPreviewModal.as_gpui_context())
+            .close_icon(IconSource::Svg(AppIcon::X.path().into()))
+            .header_leading(Icon::new(AppIcon::Eye).size(Heights::ICON_SM).primary())
+            .title(self.column.clone())
+            .width(px(900.0))
+            .height(px(600.0))
+            .child(body.into_any_element())
+            .render(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_print_detects_json_and_xml() {
+        let (format, json) = pretty_print(r#"{"a":[1,2]}"#, false);
+        assert_eq!(format, ValueFormat::Json);
+        assert_eq!(json, "{\n  \"a\": [\n    1,\n    2\n  ]\n}");
+
+        let (format, xml) = pretty_print("<a><b>1</b><c/></a>", false);
+        assert_eq!(format, ValueFormat::Xml);
+        assert_eq!(xml, "<a>\n  <b>1</b>\n  <c/>\n</a>");
+
+        let (format, text) = pretty_print("{not json", false);
+        assert_eq!(format, ValueFormat::Text);
+        assert_eq!(text, "{not json");
+    }
+
+    #[test]
+    fn format_xml_keeps_prolog_comments_and_quoted_brackets() {
+        let source = r#"<?xml version="1.0"?><!-- note --><root attr="a>b"><![CDATA[<raw>]]><item></item></root>"#;
+
+        assert_eq!(
+            format_xml(source).as_deref(),
+            Some(
+                "<?xml version=\"1.0\"?>\n<!-- note -->\n<root attr=\"a>b\">\n  <![CDATA[<raw>]]>\n  <item></item>\n</root>"
+            )
+        );
+    }
+
+    #[test]
+    fn format_xml_rejects_unbalanced_or_elementless_input() {
+        assert_eq!(format_xml("<a><b></a></b>"), None);
+        assert_eq!(format_xml("<a>"), None);
+        assert_eq!(format_xml("<!-- only a comment -->"), None);
+        assert_eq!(format_xml("< not a tag"), None);
+    }
+
+    #[test]
+    fn parse_offset_accepts_decimal_and_hex() {
+        assert_eq!(parse_offset("512"), Some(512));
+        assert_eq!(parse_offset(" 0x200 "), Some(512));
+        assert_eq!(parse_offset("0XfF"), Some(255));
+        assert_eq!(parse_offset("0x"), None);
+        assert_eq!(parse_offset("ten"), None);
+    }
+
+    #[test]
+    fn hex_lines_cover_every_byte() {
+        assert_eq!(hex_line_range(20, 0), 0..16);
+        assert_eq!(hex_line_range(20, 1), 16..20);
+        assert_eq!(hex_line_range(20, 2), 20..20);
+        assert_eq!(hex_encode(&[0xde, 0xad, 0x00]), "dead00");
+        assert_eq!(
+            [b'a', b' ', 0x00, 0x7f, 0xff].map(ascii_column_char),
+            ['a', ' ', '.', '.', '.']
+        );
+    }
+
+    #[test]
+    fn detect_image_reads_format_and_size() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+
+        let gif = b"GIF89a\x20\x00\x10\x00".to_vec();
+
+        // SOI, an APP0 segment to skip, then a baseline SOF0 of 300×200.
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00,
+            0xC8, 0x01, 0x2C,
+        ];
+
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X\0\0\0\0\0\0\0\0".to_vec();
+        webp.extend_from_slice(&[0x7F, 0x00, 0x00, 0x3F, 0x00, 0x00]);
+
+        let size =
+            |bytes: &[u8]| detect_image(bytes).map(|info| (info.kind, info.width, info.height));
+
+        assert_eq!(size(&png), Some((ImageKind::Png, 640, 480)));
+        assert_eq!(size(&gif), Some((ImageKind::Gif, 32, 16)));
+        assert_eq!(size(&jpeg), Some((ImageKind::Jpeg, 300, 200)));
+        assert_eq!(size(&webp), Some((ImageKind::Webp, 128, 64)));
+        assert_eq!(size(&png[..20]), None);
+        assert_eq!(size(b"plain bytes"), None);
+    }
+
+    #[test]
+    fn image_zoom_fits_and_steps() {
+        let info = |width, height| ImageInfo {
+            kind: ImageKind::Png,
+            width,
+            height,
+        };
+
+        assert_eq!(fit_zoom(&info(100, 100)), 1.0);
+        assert_eq!(fit_zoom(&info(1720, 420)), 0.5);
+        assert_eq!(step_zoom(1.0, true), 1.5);
+        assert_eq!(step_zoom(0.6, false), 0.5);
+        assert_eq!(step_zoom(8.0, true), 8.0);
+        assert_eq!(step_zoom(0.1, false), 0.1);
+    }
+
+    #[test]
+    fn find_matches_ignores_ascii_case() {
+        assert_eq!(find_matches("Foo foo FOO", "foo"), vec![0..3, 4..7, 8..11]);
+        assert!(find_matches("abc", "").is_empty());
+        assert_eq!(position_for_offset("ab\ncé", 6), (1, 2));
+    }
+}
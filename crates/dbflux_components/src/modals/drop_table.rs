@@ -5,7 +5,7 @@ use crate::tokens::{FontSizes, Spacing};
 use crate::typography::AppFonts;
 use dbflux_core::{RelationKind, RelationRef};
 use gpui::prelude::*;
-use gpui::{Context, Entity, EventEmitter, Subscription, Window, div, px};
+use gpui::{App, Context, Div, Entity, EventEmitter, Subscription, Window, div, px};
 use gpui_component::ActiveTheme;
 use gpui_component::button::{Button, ButtonVariants};
 
@@ -61,6 +61,46 @@ fn relation_kind_label(kind: &RelationKind) -> &'static str {
     }
 }
 
+/// A heading followed by one row per dependent object, its kind as a badge.
+pub(super) fn dependents_list(heading: &'static str, dependents: &[RelationRef], cx: &App) -> Div {
+    let theme = cx.theme();
+
+    let mut list = div().flex().flex_col().gap(Spacing::XS).child(
+        div()
+            .text_size(FontSizes::XS)
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(theme.muted_foreground)
+            .child(heading),
+    );
+
+    for dep in dependents {
+        list = list.child(
+            div()
+                .flex()
+                .items_center()
+                .gap(Spacing::SM)
+                .child(
+                    div()
+                        .text_size(FontSizes::XS)
+                        .text_color(theme.muted_foreground)
+                        .bg(theme.secondary)
+                        .px(Spacing::XS)
+                        .rounded(px(2.0))
+                        .child(relation_kind_label(&dep.kind)),
+                )
+                .child(
+                    div()
+                        .text_size(FontSizes::XS)
+                        .font_family(AppFonts::MONO)
+                        .text_color(theme.foreground)
+                        .child(dep.qualified_name.clone()),
+                ),
+        );
+    }
+
+    list
+}
+
 /// Modal entity for "drop table" with TypeToConfirm gate.
 ///
 /// Uses `ModalShell::Danger` (560 px). The "Drop table" button is disabled
@@ -166,46 +206,11 @@ impl Render for ModalDropTable {
             );
 
         // Dependents section.
-        let dependents_section = if has_deps {
-            let mut dep_list = div().flex().flex_col().gap(Spacing::XS).child(
-                div()
-                    .text_size(FontSizes::XS)
-                    .font_weight(gpui::FontWeight::SEMIBOLD)
-                    .text_color(theme.muted_foreground)
-                    .child("Dependent objects will also be dropped (CASCADE):"),
-            );
-
-            for dep in &dependents {
-                let kind_label = relation_kind_label(&dep.kind);
-                let dep_name = dep.qualified_name.clone();
-                dep_list = dep_list.child(
-                    div()
-                        .flex()
-                        .items_center()
-                        .gap(Spacing::SM)
-                        .child(
-                            div()
-                                .text_size(FontSizes::XS)
-                                .text_color(theme.muted_foreground)
-                                .bg(theme.secondary)
-                                .px(Spacing::XS)
-                                .rounded(px(2.0))
-                                .child(kind_label),
-                        )
-                        .child(
-                            div()
-                                .text_size(FontSizes::XS)
-                                .font_family(AppFonts::MONO)
-                                .text_color(theme.foreground)
-                                .child(dep_name),
-                        ),
-                );
-            }
-
-            dep_list.into_any_element()
-        } else {
-            div().into_any_element()
-        };
+        let dependents_section = dependents_list(
+            "Dependent objects will also be dropped (CASCADE):",
+            &dependents,
+            cx,
+        );
 
         // SQL preview.
         let sql_block = surface_raised(cx)
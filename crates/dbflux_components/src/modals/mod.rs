@@ -1,10 +1,12 @@
 pub mod active_query;
 pub mod cell_editor;
+pub mod cell_viewer;
 pub mod delete_connection;
 pub mod document_preview;
 pub mod drop_table;
 pub mod import_dashboard;
 pub mod mutation_confirm;
+pub mod rename_object;
 pub mod schema_drift;
 pub mod shell;
 pub mod tunnel_auth;
@@ -14,6 +16,9 @@ pub use active_query::{
     ActiveQueryOutcome, ActiveQueryRequest, ActiveQueryTrigger, ModalActiveQuery,
 };
 pub use cell_editor::{CellEditorClosedEvent, CellEditorModal, CellEditorSaveEvent};
+pub use cell_viewer::{
+    CellViewerClosedEvent, CellViewerModal, CellViewerSaveBytesEvent, CellViewerSaveEvent,
+};
 pub use delete_connection::{
     DeleteConnectionOutcome, DeleteConnectionRequest, ModalDeleteConnection,
 };
@@ -28,6 +33,10 @@ pub use mutation_confirm::{
     ModalMutationConfirm, ModalMutationConfirmHard, MutationConfirmHardRequest,
     MutationConfirmOutcome, MutationConfirmRequest,
 };
+pub use rename_object::{
+    ModalRenameObject, RenameObjectKind, RenameObjectOutcome, RenameObjectRequest,
+    RenameStatementFn,
+};
 pub use schema_drift::{
     ModalSchemaDrift, SchemaDriftContinue, SchemaDriftDismissed, SchemaDriftRefresh,
 };
@@ -0,0 +1,276 @@
+use crate::controls::{GpuiInput as Input, InputEvent, InputState};
+use crate::modals::drop_table::dependents_list;
+use crate::modals::shell::ModalShell;
+use crate::primitives::{Text, surface_raised};
+use crate::tokens::{FontSizes, Spacing};
+use crate::typography::AppFonts;
+use dbflux_core::RelationRef;
+use gpui::prelude::*;
+use gpui::{Context, Entity, EventEmitter, Subscription, Window, div, px};
+use gpui_component::ActiveTheme;
+use gpui_component::Disableable;
+use gpui_component::button::{Button, ButtonVariants};
+use std::sync::Arc;
+
+/// Outcome emitted when the user resolves the modal.
+#[derive(Clone, Debug)]
+pub enum RenameObjectOutcome {
+    Confirmed { new_name: String },
+    Cancelled,
+}
+
+/// Whether a table or one of its columns is being renamed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenameObjectKind {
+    Table,
+    Column,
+}
+
+impl RenameObjectKind {
+    fn noun(self) -> &'static str {
+        match self {
+            Self::Table => "table",
+            Self::Column => "column",
+        }
+    }
+}
+
+/// Builds the rename statement for a candidate name, or `None` when the
+/// driver cannot express it.
+pub type RenameStatementFn = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// Request payload for opening the rename modal.
+#[derive(Clone)]
+pub struct RenameObjectRequest {
+    pub kind: RenameObjectKind,
+    /// Name pre-filled into the input.
+    pub current_name: String,
+    /// Name shown in the badge, e.g. `public.users` or `users.email`.
+    pub qualified_name: String,
+    /// Objects known to reference the renamed one — empty if none.
+    pub dependents: Vec<RelationRef>,
+    pub statement: RenameStatementFn,
+}
+
+impl RenameObjectRequest {
+    /// Statement for `new_name`, once it differs from the current name.
+    pub fn statement_for(&self, new_name: &str) -> Option<String> {
+        let new_name = new_name.trim();
+
+        if new_name.is_empty() || new_name == self.current_name {
+            return None;
+        }
+
+        (self.statement)(new_name)
+    }
+}
+
+/// Modal entity for renaming a table or column.
+///
+/// Shows the statement the new name produces and the objects that reference
+/// the renamed one, which the server may or may not update on its own. The
+/// "Rename" button stays disabled until the name changes.
+pub struct ModalRenameObject {
+    request: Option<RenameObjectRequest>,
+    visible: bool,
+    name_input: Entity<InputState>,
+    statement: Option<String>,
+    _subscription: Option<Subscription>,
+}
+
+impl ModalRenameObject {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("New name"));
+        Self {
+            request: None,
+            visible: false,
+            name_input,
+            statement: None,
+            _subscription: None,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(
+        &mut self,
+        request: RenameObjectRequest,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let current_name = request.current_name.clone();
+        self.name_input.update(cx, |input, cx| {
+            input.set_value(&current_name, window, cx);
+            input.focus(window, cx);
+        });
+        self.statement = None;
+
+        let input = self.name_input.clone();
+        let subscription = cx.subscribe_in(
+            &input,
+            window,
+            |this, input_state, event: &InputEvent, _, cx| match event {
+                InputEvent::Change => {
+                    let typed = input_state.read(cx).value().to_string();
+                    this.statement = this
+                        .request
+                        .as_ref()
+                        .and_then(|request| request.statement_for(&typed));
+                    cx.notify();
+                }
+                InputEvent::PressEnter { .. } => this.confirm(cx),
+                _ => {}
+            },
+        );
+
+        self.request = Some(request);
+        self.visible = true;
+        self._subscription = Some(subscription);
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.visible = false;
+        self.request = None;
+        self.statement = None;
+        self._subscription = None;
+        cx.notify();
+    }
+
+    fn confirm(&mut self, cx: &mut Context<Self>) {
+        if self.statement.is_none() {
+            return;
+        }
+
+        let new_name = self.name_input.read(cx).value().trim().to_string();
+        cx.emit(RenameObjectOutcome::Confirmed { new_name });
+        self.close(cx);
+    }
+}
+
+impl EventEmitter<RenameObjectOutcome> for ModalRenameObject {}
+
+impl Render for ModalRenameObject {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let Some(ref request) = self.request else {
+            return div().into_any_element();
+        };
+
+        let theme = cx.theme();
+        let noun = request.kind.noun();
+        let has_deps = !request.dependents.is_empty();
+        let statement = self.statement.clone();
+        let rename_enabled = statement.is_some();
+
+        let name_badge = surface_raised(cx)
+            .w_full()
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .child(
+                div()
+                    .text_size(FontSizes::SM)
+                    .font_family(AppFonts::MONO)
+                    .text_color(theme.foreground)
+                    .child(request.qualified_name.clone()),
+            );
+
+        let dependents_section = dependents_list(
+            "These objects reference it and may need updating:",
+            &request.dependents,
+            cx,
+        );
+
+        let sql_block = surface_raised(cx)
+            .w_full()
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .child(
+                div()
+                    .text_size(FontSizes::XS)
+                    .font_family(AppFonts::MONO)
+                    .text_color(if rename_enabled {
+                        theme.foreground
+                    } else {
+                        theme.muted_foreground
+                    })
+                    .child(
+                        statement.unwrap_or_else(|| format!("Type a new name for the {}", noun)),
+                    ),
+            );
+
+        let body = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::MD)
+            .child(Text::body(format!("Rename the {}:", noun)).into_any_element())
+            .child(name_badge)
+            .child(Input::new(&self.name_input))
+            .when(has_deps, |el| el.child(dependents_section))
+            .child(sql_block);
+
+        let on_cancel = cx.listener(|this, _: &gpui::ClickEvent, _, cx| {
+            cx.emit(RenameObjectOutcome::Cancelled);
+            this.close(cx);
+        });
+
+        let on_rename = cx.listener(|this, _: &gpui::ClickEvent, _, cx| this.confirm(cx));
+
+        let footer = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(
+                Button::new("rename-object-cancel")
+                    .label("Cancel")
+                    .on_click(on_cancel),
+            )
+            .child(
+                Button::new("rename-object-confirm")
+                    .label(format!("Rename {}", noun))
+                    .primary()
+                    .disabled(!rename_enabled)
+                    .on_click(on_rename),
+            );
+
+        ModalShell::new(
+            format!("Rename {}", noun),
+            body.into_any_element(),
+            footer.into_any_element(),
+        )
+        .width(px(560.0))
+        .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> RenameObjectRequest {
+        RenameObjectRequest {
+            kind: RenameObjectKind::Table,
+            current_name: "users".to_string(),
+            qualified_name: "public.users".to_string(),
+            dependents: Vec::new(),
+            statement: Arc::new(|new_name| Some(format!("RENAME TO {}", new_name))),
+        }
+    }
+
+    #[test]
+    fn statement_needs_a_new_non_empty_name() {
+        let request = request();
+
+        assert_eq!(request.statement_for("   "), None);
+        assert_eq!(request.statement_for("users"), None);
+        assert_eq!(
+            request.statement_for(" members "),
+            Some("RENAME TO members".to_string())
+        );
+    }
+}
@@ -78,7 +78,7 @@ impl RenderOnce for Badge {
                 .py(px(2.0))
                 .rounded(density::radius_sm(cx))
                 .bg(bg)
-                .text_size(density::font_xs(cx))
+                .text_size(density::zoomed(cx, density::font_xs(cx)))
                 .font_weight(gpui::FontWeight::MEDIUM)
                 .text_color(text_color)
                 .child(self.label)
@@ -479,9 +479,11 @@ impl RenderOnce for Text {
         let theme = cx.theme();
         let contract = self.variant.role_contract();
 
-        let size = self
-            .size_override
-            .unwrap_or_else(|| self.variant.density_size(cx));
+        let size = crate::density::zoomed(
+            cx,
+            self.size_override
+                .unwrap_or_else(|| self.variant.density_size(cx)),
+        );
         let weight = self.weight_override.unwrap_or(contract.weight);
         let color = self
             .color_override
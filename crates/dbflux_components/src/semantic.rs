@@ -270,8 +270,8 @@ impl RowStateColors {
     ///
     /// Reads `ThemeSettingGlobal` from `cx`; falls back to Dark when absent.
     pub fn for_current(cx: &App) -> Self {
-        match ThemeSettingGlobal::get(cx) {
-            ThemeSetting::Dark => Self::dark(),
+        match crate::theme::resolve_setting(ThemeSettingGlobal::get(cx), cx) {
+            ThemeSetting::Dark | ThemeSetting::System => Self::dark(),
             ThemeSetting::Mirage => Self::mirage(),
             ThemeSetting::Light => Self::light(),
         }
@@ -379,8 +379,8 @@ impl ChartColors {
     ///
     /// Reads `ThemeSettingGlobal` from `cx`; falls back to Dark when absent.
     pub fn for_current(cx: &App) -> Self {
-        match ThemeSettingGlobal::get(cx) {
-            ThemeSetting::Dark => Self::dark(),
+        match crate::theme::resolve_setting(ThemeSettingGlobal::get(cx), cx) {
+            ThemeSetting::Dark | ThemeSetting::System => Self::dark(),
             ThemeSetting::Mirage => Self::mirage(),
             ThemeSetting::Light => Self::light(),
         }
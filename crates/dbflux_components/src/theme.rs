@@ -1,7 +1,7 @@
 pub use crate::typography::AppFonts;
 use crate::typography::load_bundled_fonts;
-use dbflux_core::{AppStyle, ThemeSetting};
-use gpui::{App, Hsla, SharedString, Window, hsla, px};
+use dbflux_core::{AppStyle, GeneralSettings, ThemeSetting};
+use gpui::{App, Hsla, SharedString, Window, WindowAppearance, hsla, px};
 use gpui_component::{
     highlighter::HighlightTheme,
     theme::{Theme, ThemeMode},
@@ -21,14 +21,16 @@ pub fn init(cx: &mut App) {
     apply_theme(ThemeSetting::Dark, AppStyle::Default, None, cx);
 }
 
-/// Initialize the theme and density global from persisted settings.
+/// Initialize the theme, density and font scale globals from persisted
+/// settings.
 ///
 /// Call this after `init` and after the config has been loaded, before
-/// the first window opens. This sets up the correct radius tokens and
-/// density global for the first frame.
-pub fn init_with_settings(setting: ThemeSetting, style: AppStyle, cx: &mut App) {
-    crate::density::init(cx, style);
-    apply_theme(setting, style, None, cx);
+/// the first window opens. This sets up the correct radius tokens, density
+/// global and zoom for the first frame.
+pub fn init_with_settings(settings: &GeneralSettings, cx: &mut App) {
+    crate::density::init(cx, settings.style);
+    crate::density::set_font_scale(cx, crate::density::FontScale::from_settings(settings));
+    apply_theme(settings.theme, settings.style, None, cx);
 }
 
 pub fn apply_theme(
@@ -50,6 +52,37 @@ pub fn apply_theme(
             Theme::change(ThemeMode::Light, window, cx);
             apply_ayu_light(style, cx);
         }
+        ThemeSetting::System => {
+            let resolved = resolve_setting(setting, cx);
+            apply_theme(resolved, style, window, cx);
+        }
+    }
+
+    apply_ui_zoom(cx);
+}
+
+/// Root font size at 100% zoom; rem-based sizes scale from it.
+const BASE_ROOT_FONT_SIZE: f32 = 16.0;
+
+/// Scales the theme's root font size by the UI zoom, so rem-based sizes
+/// follow the zoom along with `Text`.
+///
+/// `apply_theme` calls this; call it directly after changing the zoom
+/// without switching themes.
+pub fn apply_ui_zoom(cx: &mut App) {
+    let zoom = crate::density::zoom(cx);
+    Theme::global_mut(cx).font_size = px(BASE_ROOT_FONT_SIZE * zoom);
+}
+
+/// The built-in theme `setting` stands for right now: `System` becomes Ayu
+/// Dark or Ayu Light from the OS appearance, anything else is returned as is.
+pub fn resolve_setting(setting: ThemeSetting, cx: &App) -> ThemeSetting {
+    match setting {
+        ThemeSetting::System => match cx.window_appearance() {
+            WindowAppearance::Light | WindowAppearance::VibrantLight => ThemeSetting::Light,
+            WindowAppearance::Dark | WindowAppearance::VibrantDark => ThemeSetting::Dark,
+        },
+        other => other,
     }
 }
 
@@ -88,7 +121,7 @@ fn rgb_to_hsla(hex: u32) -> Hsla {
     hsla(h / 6.0, s, l, 1.0)
 }
 
-fn rgb_to_hsla_alpha(hex: u32, alpha: f32) -> Hsla {
+pub(crate) fn rgb_to_hsla_alpha(hex: u32, alpha: f32) -> Hsla {
     let mut hsla = rgb_to_hsla(hex);
     hsla.a = alpha;
     hsla
@@ -298,6 +298,11 @@ impl RowColors {
     pub fn saving(_theme: &gpui_component::Theme) -> Hsla {
         gpui::hsla(33.0 / 360.0, 1.0, 0.66, 0.10)
     }
+
+    /// Cell matching the grid search: faint tint of the theme `primary`.
+    pub fn search_match(theme: &gpui_component::Theme) -> Hsla {
+        theme.primary.opacity(0.15)
+    }
 }
 
 /// Status-dot palette colors for connection/task indicators.
@@ -0,0 +1,255 @@
+//! User themes: JSON files that start from a built-in Ayu palette and
+//! override individual theme colors.
+//!
+//! ```json
+//! {
+//!   "name": "Solarized",
+//!   "base": "light",
+//!   "colors": { "background": "#FDF6E3", "primary": "#268BD2" }
+//! }
+//! ```
+//!
+//! `base` is `dark`, `mirage` or `light` (default `dark`). Colors are
+//! `#RRGGBB` or `#RRGGBBAA`; the accepted keys are listed in
+//! [`USER_THEME_COLOR_KEYS`].
+
+use crate::theme::{apply_theme, rgb_to_hsla_alpha};
+use dbflux_core::{AppStyle, ThemeSetting};
+use gpui::{App, Hsla, Window};
+use gpui_component::theme::Theme;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+macro_rules! color_keys {
+    ($($field:ident),* $(,)?) => {
+        /// Theme fields a user theme may override, by JSON key.
+        const COLOR_SETTERS: &[(&str, fn(&mut Theme, Hsla))] = &[
+            $((stringify!($field), |theme, color| theme.$field = color)),*
+        ];
+
+        /// Color keys accepted in a user theme's `colors` object.
+        pub const USER_THEME_COLOR_KEYS: &[&str] = &[$(stringify!($field)),*];
+    };
+}
+
+color_keys!(
+    background,
+    foreground,
+    border,
+    caret,
+    muted,
+    muted_foreground,
+    primary,
+    primary_hover,
+    primary_active,
+    primary_foreground,
+    secondary,
+    secondary_hover,
+    secondary_active,
+    secondary_foreground,
+    accent,
+    accent_foreground,
+    danger,
+    success,
+    warning,
+    info,
+    popover,
+    popover_foreground,
+    selection,
+    ring,
+    input,
+    link,
+    scrollbar_thumb,
+    sidebar,
+    sidebar_foreground,
+    sidebar_border,
+    tab,
+    tab_bar,
+    tab_foreground,
+    tab_active,
+    tab_active_foreground,
+    table,
+    table_head,
+    table_head_foreground,
+    table_even,
+    table_hover,
+    table_active,
+    list,
+    list_hover,
+    list_active,
+    title_bar,
+    title_bar_border,
+    chart_1,
+    chart_2,
+    chart_3,
+    chart_4,
+    chart_5,
+    red,
+    green,
+    blue,
+    yellow,
+    magenta,
+    cyan,
+);
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UserThemeFile {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+/// A parsed user theme, ready to apply.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserTheme {
+    pub name: String,
+    pub base: ThemeSetting,
+    colors: Vec<(usize, Hsla)>,
+}
+
+impl UserTheme {
+    /// Parses a theme file. `fallback_name` (usually the file stem) names
+    /// themes that don't set `name`.
+    pub fn parse(fallback_name: &str, json: &str) -> Result<Self, String> {
+        let file: UserThemeFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+        let base = match file.base.as_deref().unwrap_or("dark") {
+            "dark" => ThemeSetting::Dark,
+            "mirage" => ThemeSetting::Mirage,
+            "light" => ThemeSetting::Light,
+            other => {
+                return Err(format!(
+                    "unknown base \"{other}\" (expected dark, mirage or light)"
+                ));
+            }
+        };
+
+        let colors = file
+            .colors
+            .iter()
+            .map(|(key, value)| {
+                let index = COLOR_SETTERS
+                    .iter()
+                    .position(|(name, _)| name == key)
+                    .ok_or_else(|| format!("unknown color key \"{key}\""))?;
+                let color = parse_color(value)
+                    .ok_or_else(|| format!("invalid color \"{value}\" for \"{key}\""))?;
+                Ok((index, color))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let name = file
+            .name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| fallback_name.to_string());
+
+        Ok(Self { name, base, colors })
+    }
+
+    /// Whether the theme renders on a light background.
+    pub fn is_light(&self) -> bool {
+        self.base == ThemeSetting::Light
+    }
+
+    /// The override for `key`, if the theme sets one.
+    pub fn color(&self, key: &str) -> Option<Hsla> {
+        self.colors
+            .iter()
+            .find(|(index, _)| COLOR_SETTERS[*index].0 == key)
+            .map(|(_, color)| *color)
+    }
+}
+
+/// Applies the theme's base palette, then its color overrides.
+pub fn apply_user_theme(
+    user_theme: &UserTheme,
+    style: AppStyle,
+    window: Option<&mut Window>,
+    cx: &mut App,
+) {
+    apply_theme(user_theme.base, style, window, cx);
+
+    let theme = Theme::global_mut(cx);
+    for (index, color) in &user_theme.colors {
+        (COLOR_SETTERS[*index].1)(theme, *color);
+    }
+}
+
+/// Parses `#RRGGBB` or `#RRGGBBAA`.
+fn parse_color(value: &str) -> Option<Hsla> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    match hex.len() {
+        6 => Some(rgb_to_hsla_alpha(u32::from_str_radix(hex, 16).ok()?, 1.0)),
+        8 => {
+            let rgba = u32::from_str_radix(hex, 16).ok()?;
+            Some(rgb_to_hsla_alpha(rgba >> 8, (rgba & 0xFF) as f32 / 255.0))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_base_name_and_colors() {
+        let theme = UserTheme::parse(
+            "file-stem",
+            r##"{ "name": "Solarized", "base": "light",
+                 "colors": { "background": "#FDF6E3", "primary": "#268BD280" } }"##,
+        )
+        .expect("valid theme");
+
+        assert_eq!(theme.name, "Solarized");
+        assert_eq!(theme.base, ThemeSetting::Light);
+        assert!(theme.is_light());
+        assert_eq!(
+            theme.color("background"),
+            Some(rgb_to_hsla_alpha(0xFDF6E3, 1.0))
+        );
+        assert_eq!(
+            theme.color("primary"),
+            Some(rgb_to_hsla_alpha(0x268BD2, 128.0 / 255.0))
+        );
+        assert_eq!(theme.color("foreground"), None);
+    }
+
+    #[test]
+    fn missing_name_and_base_fall_back_to_file_stem_and_dark() {
+        let theme = UserTheme::parse("midnight", "{}").expect("empty theme is valid");
+
+        assert_eq!(theme.name, "midnight");
+        assert_eq!(theme.base, ThemeSetting::Dark);
+    }
+
+    #[test]
+    fn rejects_unknown_keys_bases_and_bad_colors() {
+        let unknown_key = UserTheme::parse("t", r##"{ "colors": { "bg": "#000000" } }"##);
+        assert!(
+            unknown_key
+                .unwrap_err()
+                .contains("unknown color key \"bg\"")
+        );
+
+        let bad_base = UserTheme::parse("t", r#"{ "base": "system" }"#);
+        assert!(bad_base.unwrap_err().contains("unknown base"));
+
+        for color in ["000000", "#12345", "#GGGGGG", "#1234567"] {
+            let json = format!(r#"{{ "colors": {{ "border": "{color}" }} }}"#);
+            assert!(UserTheme::parse("t", &json).is_err(), "{color} accepted");
+        }
+
+        let unknown_field = UserTheme::parse("t", r#"{ "palette": {} }"#);
+        assert!(unknown_field.is_err());
+    }
+}
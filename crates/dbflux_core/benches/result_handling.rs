@@ -0,0 +1,116 @@
+//! Benchmarks for building, formatting and sorting large query results.
+//!
+//! Run with `cargo bench -p dbflux_core`.
+
+use std::hint::black_box;
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use dbflux_core::{ColumnKind, ColumnMeta, QueryResult, Row, Value};
+
+const ROW_COUNTS: [usize; 2] = [1_000, 50_000];
+
+fn columns() -> Vec<ColumnMeta> {
+    [
+        ("id", "int8", ColumnKind::Integer),
+        ("email", "text", ColumnKind::Text),
+        ("balance", "numeric", ColumnKind::Float),
+        ("created_at", "timestamptz", ColumnKind::Timestamp),
+        ("profile", "jsonb", ColumnKind::Unknown),
+        ("avatar", "bytea", ColumnKind::Unknown),
+    ]
+    .into_iter()
+    .map(|(name, type_name, kind)| ColumnMeta {
+        name: name.to_string(),
+        type_name: type_name.to_string(),
+        kind,
+        nullable: true,
+        is_primary_key: name == "id",
+    })
+    .collect()
+}
+
+fn rows(count: usize) -> Vec<Row> {
+    let epoch = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+    (0..count as i64)
+        .map(|i| {
+            vec![
+                Value::Int(i),
+                Value::Text(format!("customer-{}@example.com", i % 997)),
+                Value::Float(i as f64 * 1.25),
+                Value::DateTime(epoch + chrono::Duration::seconds(i)),
+                Value::Json(format!(r#"{{"tier":{},"tags":["a","b"]}}"#, i % 5)),
+                if i % 10 == 0 {
+                    Value::Null
+                } else {
+                    Value::Bytes(vec![(i % 256) as u8; 64])
+                },
+            ]
+        })
+        .collect()
+}
+
+fn bench_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("query_result/table");
+
+    for count in ROW_COUNTS {
+        let rows = rows(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &rows, |b, rows| {
+            b.iter(|| {
+                QueryResult::table(
+                    columns(),
+                    black_box(rows.clone()),
+                    None,
+                    Duration::from_millis(3),
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_display(c: &mut Criterion) {
+    let mut group = c.benchmark_group("value/as_display_string");
+
+    for count in ROW_COUNTS {
+        let rows = rows(count);
+        group.throughput(Throughput::Elements((count * rows[0].len()) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &rows, |b, rows| {
+            b.iter(|| {
+                for row in rows {
+                    for value in row {
+                        black_box(value.as_display_string());
+                    }
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_sort(c: &mut Criterion) {
+    let mut group = c.benchmark_group("value/sort_rows");
+
+    for count in ROW_COUNTS {
+        let mut rows = rows(count);
+        rows.reverse();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &rows, |b, rows| {
+            b.iter(|| {
+                let mut rows = rows.clone();
+                rows.sort_by(|a, b| a[1].cmp(&b[1]));
+                black_box(rows)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_construction, bench_display, bench_sort);
+criterion_main!(benches);
@@ -0,0 +1,164 @@
+# DBFlux — English (reference locale)
+#
+# Every other locale must define the same message ids with the same
+# placeables; `cargo test -p dbflux_core i18n` checks this.
+
+## Command palette
+
+palette-placeholder = Search commands, connections, tables, scripts...
+palette-no-matches = No matching items
+
+palette-section-recent = Recently Used
+palette-section-connections = Connections
+palette-section-commands = Commands
+palette-section-charts = Charts
+palette-section-tables = Tables
+palette-section-scripts = Scripts
+palette-section-saved-queries = Saved Queries
+
+command-category-editor = Editor
+command-category-tabs = Tabs
+command-category-results = Results
+command-category-connections = Connections
+command-category-focus = Focus
+command-category-view = View
+command-category-charts = Charts
+command-category-dashboards = Dashboards
+
+command-new-query-tab = New Query Tab
+command-new-notebook = New Notebook
+command-run-query = Run Query
+command-run-query-in-new-tab = Run Query in New Tab
+command-save-query = Save Query
+command-save-file-as = Save File As
+command-open-script-file = Open Script File
+command-find-in-editor = Find in Editor
+command-replace-in-editor = Find and Replace in Editor
+command-toggle-query-variables = Toggle Query Variables
+command-find-saved-query = Find Saved Query...
+command-open-history = Open Query History
+command-cancel-query = Cancel Running Query
+command-close-tab = Close Current Tab
+command-next-tab = Next Tab
+command-prev-tab = Previous Tab
+command-move-tab-to-group-1 = Move Tab to Group 1
+command-move-tab-to-group-2 = Move Tab to Group 2
+command-move-tab-to-group-3 = Move Tab to Group 3
+command-move-tab-to-group-4 = Move Tab to Group 4
+command-reopen-closed-tab = Reopen Closed Tab
+command-navigate-back = Go Back
+command-navigate-forward = Go Forward
+command-export-results = Export Results
+command-scheduled-exports = Scheduled Exports...
+command-toggle-row-inspector = Toggle Row Inspector
+command-open-connection-manager = Open Connection Manager
+command-disconnect = Disconnect Current
+command-refresh-schema = Refresh Schema
+command-search-data = Search Data...
+command-index-advisor = Index Advisor...
+command-quick-open = Quick Open Object...
+command-switch-connection = Switch Connection or Database...
+command-focus-sidebar = Focus Sidebar
+command-focus-editor = Focus Editor
+command-focus-results = Focus Results
+command-focus-tasks = Focus Tasks Panel
+command-toggle-sidebar = Toggle Sidebar
+command-toggle-editor = Toggle Editor Panel
+command-toggle-results = Toggle Results Panel
+command-toggle-tasks = Toggle Tasks Panel
+command-new-window = New Window
+command-split-right = Split Right
+command-split-down = Split Down
+command-focus-other-pane = Focus Other Pane
+command-move-to-other-pane = Move Document to Other Pane
+command-close-split = Close Split
+command-open-settings = Open Settings
+command-open-login-modal = Open Auth Profile Login
+command-open-sso-wizard = Open AWS SSO Wizard
+command-open-mcp-approvals = Open MCP Approvals
+command-refresh-mcp-governance = Refresh MCP Governance
+command-open-audit-viewer = Open Audit Viewer
+command-open-notifications = Open Notifications...
+command-zoom-in = Zoom In
+command-zoom-out = Zoom Out
+command-reset-zoom = Reset Zoom
+command-open-saved-chart = Open Chart...
+command-new-dashboard = New Dashboard...
+
+## Settings navigation
+
+settings-nav-general-group = General
+settings-nav-general = General
+settings-nav-keybindings = Keybindings
+settings-nav-audit = Audit
+settings-nav-about = About
+settings-nav-network = Network
+settings-nav-ssh-tunnels = SSH Tunnels
+settings-nav-proxies = Proxy
+settings-nav-auth-profiles = Access Providers
+settings-nav-connection = Connection
+settings-nav-hooks = Hooks
+settings-nav-drivers = Drivers
+settings-nav-services = RPC Services
+settings-nav-external-drivers = External Drivers
+settings-nav-mcp-governance = MCP Governance
+settings-nav-mcp-clients = Clients
+settings-nav-mcp-roles = Roles
+settings-nav-mcp-policies = Policies
+
+## Settings: General
+
+settings-save = Save
+settings-saved-toast = Settings saved. Some changes apply on next startup.
+
+settings-general-title = General
+settings-general-description = Configure startup, session, refresh, and safety behavior
+
+settings-group-appearance = Appearance
+settings-group-startup = Startup & Session
+settings-group-refresh = Refresh & Background
+settings-group-safety = Execution Safety
+settings-group-editor = Editor
+settings-group-sidebar = Sidebar
+settings-group-notifications = Notifications
+settings-group-storage = Storage
+
+settings-language = Language
+settings-theme = Theme
+settings-style = Style
+settings-ui-zoom = UI zoom
+settings-editor-font-size = Editor font size
+settings-grid-font-size = Grid font size
+settings-display-timezone = Display time zone
+settings-null-marker = NULL marker
+settings-null-style = NULL style
+settings-thousands-separator = Thousands separator
+settings-decimal-places = Decimal places
+settings-date-format = Date format
+settings-time-format = Time format
+settings-timestamp-format = Timestamp format
+settings-scientific-threshold = Scientific notation
+settings-formatted-output = Copy and export values as displayed
+settings-custom-themes-hint = Custom themes are JSON files in { $folder }; edits apply as you save them.
+settings-restore-session = Restore session on startup
+settings-reopen-connections = Reopen last connections
+settings-default-focus = Default focus
+settings-max-history = Max history entries
+settings-history-max-age = History max age (days, 0 = forever)
+settings-collapse-history = Collapse repeated history entries
+settings-auto-save-interval = Auto-save interval (ms)
+settings-refresh-policy = Default refresh policy
+settings-refresh-interval = Default refresh interval (seconds)
+settings-max-background-tasks = Max concurrent background tasks
+settings-pause-refresh-on-error = Pause auto-refresh on error
+settings-refresh-only-if-visible = Auto-refresh only if tab is visible
+settings-confirm-dangerous = Confirm dangerous queries
+settings-requires-where = Require WHERE for DELETE/UPDATE
+settings-requires-preview = Always require preview (ignore suppressions)
+settings-vim-mode = Vim keybindings
+settings-hide-system-objects = Hide system schemas and objects
+settings-schema-refresh = Schema auto-refresh
+settings-schema-refresh-on-focus = Refresh schemas when the window regains focus
+settings-long-query-notification = Long query notification
+settings-share-stable-db = Use the stable database
+settings-share-stable-db-hint = Applied on next launch. Shares the stable database (dbflux.db) instead of this nightly build's dbflux-nightly.db.
@@ -0,0 +1,161 @@
+# DBFlux — Español
+
+## Command palette
+
+palette-placeholder = Buscar comandos, conexiones, tablas, scripts...
+palette-no-matches = No hay coincidencias
+
+palette-section-recent = Usados recientemente
+palette-section-connections = Conexiones
+palette-section-commands = Comandos
+palette-section-charts = Gráficos
+palette-section-tables = Tablas
+palette-section-scripts = Scripts
+palette-section-saved-queries = Consultas guardadas
+
+command-category-editor = Editor
+command-category-tabs = Pestañas
+command-category-results = Resultados
+command-category-connections = Conexiones
+command-category-focus = Foco
+command-category-view = Vista
+command-category-charts = Gráficos
+command-category-dashboards = Paneles
+
+command-new-query-tab = Nueva pestaña de consulta
+command-new-notebook = Nuevo cuaderno
+command-run-query = Ejecutar consulta
+command-run-query-in-new-tab = Ejecutar consulta en una pestaña nueva
+command-save-query = Guardar consulta
+command-save-file-as = Guardar archivo como
+command-open-script-file = Abrir archivo de script
+command-find-in-editor = Buscar en el editor
+command-replace-in-editor = Buscar y reemplazar en el editor
+command-toggle-query-variables = Mostrar u ocultar variables de consulta
+command-find-saved-query = Buscar consulta guardada...
+command-open-history = Abrir historial de consultas
+command-cancel-query = Cancelar consulta en curso
+command-close-tab = Cerrar pestaña actual
+command-next-tab = Pestaña siguiente
+command-prev-tab = Pestaña anterior
+command-move-tab-to-group-1 = Mover pestaña al grupo 1
+command-move-tab-to-group-2 = Mover pestaña al grupo 2
+command-move-tab-to-group-3 = Mover pestaña al grupo 3
+command-move-tab-to-group-4 = Mover pestaña al grupo 4
+command-reopen-closed-tab = Reabrir pestaña cerrada
+command-navigate-back = Atrás
+command-navigate-forward = Adelante
+command-export-results = Exportar resultados
+command-scheduled-exports = Exportaciones programadas...
+command-toggle-row-inspector = Mostrar u ocultar inspector de filas
+command-open-connection-manager = Abrir gestor de conexiones
+command-disconnect = Desconectar la actual
+command-refresh-schema = Actualizar esquema
+command-search-data = Buscar datos...
+command-index-advisor = Asesor de índices...
+command-quick-open = Abrir objeto rápidamente...
+command-switch-connection = Cambiar de conexión o base de datos...
+command-focus-sidebar = Enfocar barra lateral
+command-focus-editor = Enfocar editor
+command-focus-results = Enfocar resultados
+command-focus-tasks = Enfocar panel de tareas
+command-toggle-sidebar = Mostrar u ocultar barra lateral
+command-toggle-editor = Mostrar u ocultar panel del editor
+command-toggle-results = Mostrar u ocultar panel de resultados
+command-toggle-tasks = Mostrar u ocultar panel de tareas
+command-new-window = Nueva ventana
+command-split-right = Dividir a la derecha
+command-split-down = Dividir hacia abajo
+command-focus-other-pane = Enfocar el otro panel
+command-move-to-other-pane = Mover documento al otro panel
+command-close-split = Cerrar división
+command-open-settings = Abrir configuración
+command-open-login-modal = Iniciar sesión con perfil de autenticación
+command-open-sso-wizard = Abrir asistente de AWS SSO
+command-open-mcp-approvals = Abrir aprobaciones MCP
+command-refresh-mcp-governance = Actualizar gobernanza MCP
+command-open-audit-viewer = Abrir visor de auditoría
+command-open-notifications = Abrir notificaciones...
+command-zoom-in = Acercar
+command-zoom-out = Alejar
+command-reset-zoom = Restablecer zoom
+command-open-saved-chart = Abrir gráfico...
+command-new-dashboard = Nuevo panel...
+
+## Settings navigation
+
+settings-nav-general-group = General
+settings-nav-general = General
+settings-nav-keybindings = Atajos de teclado
+settings-nav-audit = Auditoría
+settings-nav-about = Acerca de
+settings-nav-network = Red
+settings-nav-ssh-tunnels = Túneles SSH
+settings-nav-proxies = Proxy
+settings-nav-auth-profiles = Proveedores de acceso
+settings-nav-connection = Conexión
+settings-nav-hooks = Hooks
+settings-nav-drivers = Controladores
+settings-nav-services = Servicios RPC
+settings-nav-external-drivers = Drivers externos
+settings-nav-mcp-governance = Gobernanza MCP
+settings-nav-mcp-clients = Clientes
+settings-nav-mcp-roles = Roles
+settings-nav-mcp-policies = Políticas
+
+## Settings: General
+
+settings-save = Guardar
+settings-saved-toast = Configuración guardada. Algunos cambios se aplican en el próximo inicio.
+
+settings-general-title = General
+settings-general-description = Configura el inicio, la sesión, la actualización y la seguridad
+
+settings-group-appearance = Apariencia
+settings-group-startup = Inicio y sesión
+settings-group-refresh = Actualización y segundo plano
+settings-group-safety = Seguridad de ejecución
+settings-group-editor = Editor
+settings-group-sidebar = Barra lateral
+settings-group-notifications = Notificaciones
+settings-group-storage = Almacenamiento
+
+settings-language = Idioma
+settings-theme = Tema
+settings-style = Estilo
+settings-ui-zoom = Zoom de la interfaz
+settings-editor-font-size = Tamaño de fuente del editor
+settings-grid-font-size = Tamaño de fuente de la tabla
+settings-display-timezone = Zona horaria de visualización
+settings-null-marker = Marcador de NULL
+settings-null-style = Estilo de NULL
+settings-thousands-separator = Separador de miles
+settings-decimal-places = Decimales
+settings-date-format = Formato de fecha
+settings-time-format = Formato de hora
+settings-timestamp-format = Formato de fecha y hora
+settings-scientific-threshold = Notación científica
+settings-formatted-output = Copiar y exportar los valores como se muestran
+settings-custom-themes-hint = Los temas personalizados son archivos JSON en { $folder }; los cambios se aplican al guardarlos.
+settings-restore-session = Restaurar la sesión al iniciar
+settings-reopen-connections = Reabrir las últimas conexiones
+settings-default-focus = Foco inicial
+settings-max-history = Máximo de entradas en el historial
+settings-history-max-age = Antigüedad máxima del historial (días, 0 = siempre)
+settings-collapse-history = Agrupar entradas repetidas del historial
+settings-auto-save-interval = Intervalo de autoguardado (ms)
+settings-refresh-policy = Política de actualización predeterminada
+settings-refresh-interval = Intervalo de actualización predeterminado (segundos)
+settings-max-background-tasks = Máximo de tareas en segundo plano simultáneas
+settings-pause-refresh-on-error = Pausar la actualización automática ante errores
+settings-refresh-only-if-visible = Actualizar automáticamente solo si la pestaña es visible
+settings-confirm-dangerous = Confirmar consultas peligrosas
+settings-requires-where = Exigir WHERE en DELETE/UPDATE
+settings-requires-preview = Exigir siempre vista previa (ignorar exclusiones)
+settings-vim-mode = Atajos de Vim
+settings-hide-system-objects = Ocultar esquemas y objetos del sistema
+settings-schema-refresh = Actualización automática del esquema
+settings-schema-refresh-on-focus = Actualizar esquemas cuando la ventana recupera el foco
+settings-long-query-notification = Aviso de consultas largas
+settings-share-stable-db = Usar la base de datos estable
+settings-share-stable-db-hint = Se aplica en el próximo inicio. Comparte la base de datos estable (dbflux.db) en lugar de la dbflux-nightly.db de esta versión nightly.
@@ -1,5 +1,9 @@
 use crate::ConnectionHook;
+use crate::display_format::DisplayFormat;
 use crate::driver::form::FormValues;
+use crate::i18n::Language;
+use crate::null_display::{DEFAULT_NULL_MARKER, NullStyle};
+use crate::timezone::DisplayTimezone;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -181,6 +185,9 @@ pub struct GlobalOverrides {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requires_preview: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_system_objects: Option<bool>,
 }
 
 impl GlobalOverrides {
@@ -190,6 +197,7 @@ impl GlobalOverrides {
             && self.confirm_dangerous.is_none()
             && self.requires_where.is_none()
             && self.requires_preview.is_none()
+            && self.hide_system_objects.is_none()
     }
 }
 
@@ -206,6 +214,7 @@ pub struct EffectiveSettings {
     pub confirm_dangerous: bool,
     pub requires_where: bool,
     pub requires_preview: bool,
+    pub hide_system_objects: bool,
 
     /// Driver-owned settings from its settings schema.
     pub driver_values: crate::FormValues,
@@ -251,6 +260,8 @@ impl EffectiveSettings {
 
         let requires_preview = resolve_field!(requires_preview, global.dangerous_requires_preview);
 
+        let hide_system_objects = resolve_field!(hide_system_objects, global.hide_system_objects);
+
         let merged_values = match conn_values {
             Some(cv) => {
                 let mut merged = driver_values.clone();
@@ -272,6 +283,7 @@ impl EffectiveSettings {
             confirm_dangerous,
             requires_where,
             requires_preview,
+            hide_system_objects,
             driver_values: merged_values,
         }
     }
@@ -293,12 +305,50 @@ impl EffectiveSettings {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GeneralSettings {
     // -- Appearance --
+    /// Language of the translated UI strings.
+    #[serde(default)]
+    pub language: Language,
+
     #[serde(default)]
     pub theme: ThemeSetting,
 
+    /// User theme from the themes directory, by name. Takes precedence over
+    /// `theme` while set; a missing file falls back to `theme`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_theme: Option<String>,
+
     #[serde(default)]
     pub style: AppStyle,
 
+    /// Application-wide zoom, in percent of the style's base sizes.
+    #[serde(default = "default_ui_zoom_percent")]
+    pub ui_zoom_percent: u16,
+
+    /// Font size of code editors, in pixels at 100% zoom.
+    #[serde(default = "default_editor_font_size")]
+    pub editor_font_size: u16,
+
+    /// Font size of result grid cells, in pixels at 100% zoom. Row heights
+    /// scale with it.
+    #[serde(default = "default_grid_font_size")]
+    pub grid_font_size: u16,
+
+    /// Time zone timestamps with a time zone are shown in.
+    #[serde(default)]
+    pub display_timezone: DisplayTimezone,
+
+    /// Text shown for NULL cells in result grids and the cell viewer.
+    #[serde(default = "default_null_marker")]
+    pub null_marker: String,
+
+    /// How the NULL marker is styled in result grids.
+    #[serde(default)]
+    pub null_style: NullStyle,
+
+    /// Number and date formats of result grids and the status bar.
+    #[serde(default)]
+    pub display_format: DisplayFormat,
+
     // -- Startup & Session --
     #[serde(default = "default_true")]
     pub restore_session_on_startup: bool,
@@ -312,6 +362,14 @@ pub struct GeneralSettings {
     #[serde(default = "default_max_history_entries")]
     pub max_history_entries: usize,
 
+    /// Days to keep unpinned history entries; 0 keeps them forever.
+    #[serde(default)]
+    pub history_max_age_days: u32,
+
+    /// Collapse consecutive identical executions into one history entry.
+    #[serde(default = "default_true")]
+    pub history_collapse_duplicates: bool,
+
     #[serde(default = "default_auto_save_interval_ms")]
     pub auto_save_interval_ms: u64,
 
@@ -341,6 +399,31 @@ pub struct GeneralSettings {
     #[serde(default)]
     pub dangerous_requires_preview: bool,
 
+    // -- Editor --
+    /// Modal (Vim-style) editing in code editors.
+    #[serde(default)]
+    pub editor_vim_mode: bool,
+
+    // -- Sidebar --
+    /// Hide system schemas and internal objects from the sidebar and quick open.
+    #[serde(default = "default_true")]
+    pub hide_system_objects: bool,
+
+    /// Seconds between background re-introspections of connected schemas;
+    /// 0 leaves schema refreshes manual.
+    #[serde(default)]
+    pub schema_refresh_interval_secs: u32,
+
+    /// Re-introspect connected schemas whenever the window regains focus.
+    #[serde(default)]
+    pub schema_refresh_on_focus: bool,
+
+    // -- Notifications --
+    /// Seconds a query must run before its completion raises a desktop
+    /// notification when its tab is not focused; 0 turns this off.
+    #[serde(default = "default_notify_long_query_secs")]
+    pub notify_long_query_secs: u32,
+
     // -- Inspector --
     /// Persisted width (in CSS pixels) of the workspace-level inspector rail.
     /// `None` → use `INSPECTOR_DEFAULT_WIDTH`.
@@ -348,15 +431,40 @@ pub struct GeneralSettings {
     pub workspace_inspector_width_px: Option<f32>,
 }
 
+pub const DEFAULT_UI_ZOOM_PERCENT: u16 = 100;
+pub const MIN_UI_ZOOM_PERCENT: u16 = 50;
+pub const MAX_UI_ZOOM_PERCENT: u16 = 200;
+/// Zoom change of one zoom-in or zoom-out step.
+pub const UI_ZOOM_STEP_PERCENT: u16 = 10;
+
+pub const DEFAULT_EDITOR_FONT_SIZE: u16 = 14;
+pub const MIN_EDITOR_FONT_SIZE: u16 = 10;
+pub const MAX_EDITOR_FONT_SIZE: u16 = 28;
+
+pub const DEFAULT_GRID_FONT_SIZE: u16 = 13;
+pub const MIN_GRID_FONT_SIZE: u16 = 10;
+pub const MAX_GRID_FONT_SIZE: u16 = 24;
+
 impl Default for GeneralSettings {
     fn default() -> Self {
         Self {
+            language: Language::English,
             theme: ThemeSetting::Dark,
+            custom_theme: None,
             style: AppStyle::Default,
+            ui_zoom_percent: DEFAULT_UI_ZOOM_PERCENT,
+            editor_font_size: DEFAULT_EDITOR_FONT_SIZE,
+            grid_font_size: DEFAULT_GRID_FONT_SIZE,
+            display_timezone: DisplayTimezone::Utc,
+            null_marker: DEFAULT_NULL_MARKER.to_string(),
+            null_style: NullStyle::Muted,
+            display_format: DisplayFormat::default(),
             restore_session_on_startup: true,
             reopen_last_connections: false,
             default_focus_on_startup: StartupFocus::Sidebar,
             max_history_entries: 1000,
+            history_max_age_days: 0,
+            history_collapse_duplicates: true,
             auto_save_interval_ms: 2000,
 
             default_refresh_policy: RefreshPolicySetting::Manual,
@@ -368,6 +476,11 @@ impl Default for GeneralSettings {
             confirm_dangerous_queries: true,
             dangerous_requires_where: true,
             dangerous_requires_preview: false,
+            editor_vim_mode: false,
+            hide_system_objects: true,
+            schema_refresh_interval_secs: 0,
+            schema_refresh_on_focus: false,
+            notify_long_query_secs: 30,
             workspace_inspector_width_px: None,
         }
     }
@@ -394,6 +507,8 @@ pub enum ThemeSetting {
     Dark,
     Mirage,
     Light,
+    /// Ayu Dark or Ayu Light, following the OS appearance.
+    System,
 }
 
 /// Controls global layout density.
@@ -446,6 +561,26 @@ fn default_max_concurrent_background_tasks() -> usize {
     8
 }
 
+fn default_notify_long_query_secs() -> u32 {
+    30
+}
+
+fn default_ui_zoom_percent() -> u16 {
+    DEFAULT_UI_ZOOM_PERCENT
+}
+
+fn default_editor_font_size() -> u16 {
+    DEFAULT_EDITOR_FONT_SIZE
+}
+
+fn default_grid_font_size() -> u16 {
+    DEFAULT_GRID_FONT_SIZE
+}
+
+fn default_null_marker() -> String {
+    DEFAULT_NULL_MARKER.to_string()
+}
+
 impl GeneralSettings {
     pub fn resolve_refresh_policy(&self) -> crate::RefreshPolicy {
         match self.default_refresh_policy {
@@ -456,6 +591,14 @@ impl GeneralSettings {
         }
     }
 
+    /// Policy of the periodic sidebar schema refresh.
+    pub fn resolve_schema_refresh_policy(&self) -> crate::RefreshPolicy {
+        match self.schema_refresh_interval_secs {
+            0 => crate::RefreshPolicy::Manual,
+            every_secs => crate::RefreshPolicy::Interval { every_secs },
+        }
+    }
+
     /// Evaluate a detected dangerous query kind against the safety settings.
     ///
     /// Returns:
@@ -499,7 +642,7 @@ pub enum DangerousAction {
     Block(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServiceConfig {
     pub socket_id: String,
 
@@ -523,6 +666,27 @@ pub struct ServiceConfig {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub api_contract: Option<ServiceRpcApiContract>,
+
+    /// Reach the service over TCP instead of the local socket. `socket_id`
+    /// then only names the service; nothing is launched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<RemoteServiceConfig>,
+}
+
+/// A driver host listening on another machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteServiceConfig {
+    /// `host:port` the driver host listens on.
+    pub address: String,
+
+    /// Wrap the connection in TLS, verifying the host's certificate.
+    #[serde(default)]
+    pub tls: bool,
+
+    /// Environment variable holding the token the host expects in `Hello`.
+    /// The token DBFlux generates for the hosts it launches means nothing
+    /// to a host started elsewhere.
+    pub token_env: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -625,6 +789,10 @@ mod tests {
                 requires_preview: Some(true),
                 ..Default::default()
             },
+            GlobalOverrides {
+                hide_system_objects: Some(true),
+                ..Default::default()
+            },
         ];
 
         for (i, ov) in cases.iter().enumerate() {
@@ -640,6 +808,7 @@ mod tests {
             confirm_dangerous: None,
             requires_where: Some(false),
             requires_preview: None,
+            hide_system_objects: Some(true),
         };
 
         let json = serde_json::to_string(&ov).unwrap();
@@ -662,6 +831,7 @@ mod tests {
         assert!(!json.contains("confirm_dangerous"));
         assert!(!json.contains("requires_where"));
         assert!(!json.contains("requires_preview"));
+        assert!(!json.contains("hide_system_objects"));
     }
 
     #[test]
@@ -724,6 +894,7 @@ mod tests {
             confirm_dangerous: None,
             requires_where: None,
             requires_preview: Some(true),
+            hide_system_objects: None,
         };
         let values = HashMap::new();
 
@@ -747,6 +918,7 @@ mod tests {
             confirm_dangerous: Some(false),
             requires_where: Some(false),
             requires_preview: Some(true),
+            hide_system_objects: Some(true),
         };
         let values = HashMap::new();
 
@@ -757,6 +929,7 @@ mod tests {
         assert!(!effective.confirm_dangerous);
         assert!(!effective.requires_where);
         assert!(effective.requires_preview);
+        assert!(effective.hide_system_objects);
     }
 
     #[test]
@@ -809,6 +982,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resolve_schema_refresh_policy_treats_zero_as_manual() {
+        let mut settings = GeneralSettings::default();
+        assert_eq!(
+            settings.resolve_schema_refresh_policy(),
+            crate::RefreshPolicy::Manual
+        );
+
+        settings.schema_refresh_interval_secs = 300;
+        assert_eq!(
+            settings.resolve_schema_refresh_policy(),
+            crate::RefreshPolicy::Interval { every_secs: 300 }
+        );
+    }
+
     // =========================================================================
     // EffectiveSettings::resolve — connection-level overrides (3-layer)
     // =========================================================================
@@ -1065,6 +1253,7 @@ mod tests {
             startup_timeout_ms: Some(5_000),
             kind: RpcServiceKind::AuthProvider,
             api_contract: Some(ServiceRpcApiContract::new("auth_provider_rpc", 1, 2)),
+            remote: None,
         };
 
         let json = serde_json::to_value(&service).unwrap();
@@ -1079,6 +1268,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn service_config_reads_remote_endpoint() {
+        let json = r#"{
+            "socket_id": "remote-postgres",
+            "remote": { "address": "db-jump:7400", "tls": true, "token_env": "PG_HOST_TOKEN" }
+        }"#;
+
+        let service: ServiceConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            service.remote,
+            Some(RemoteServiceConfig {
+                address: "db-jump:7400".to_string(),
+                tls: true,
+                token_env: "PG_HOST_TOKEN".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn service_config_defaults_missing_api_contract_from_kind() {
         let service = ServiceConfig {
@@ -1090,6 +1298,7 @@ mod tests {
             startup_timeout_ms: None,
             kind: RpcServiceKind::Driver,
             api_contract: None,
+            remote: None,
         };
 
         assert_eq!(
@@ -1261,5 +1470,8 @@ mod tests {
             settings.workspace_inspector_width_px, None,
             "missing field must deserialize to None"
         );
+        assert_eq!(settings.ui_zoom_percent, DEFAULT_UI_ZOOM_PERCENT);
+        assert_eq!(settings.editor_font_size, DEFAULT_EDITOR_FONT_SIZE);
+        assert_eq!(settings.grid_font_size, DEFAULT_GRID_FONT_SIZE);
     }
 }
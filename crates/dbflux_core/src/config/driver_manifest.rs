@@ -0,0 +1,233 @@
+//! Manifests for third-party drivers.
+//!
+//! A manifest is a JSON file in the [`DRIVER_MANIFESTS_DIR`] folder of the
+//! data directory describing one external driver: where it listens, how to
+//! launch it, and how it should appear in the connection manager. Each
+//! enabled manifest becomes a driver [`ServiceConfig`], so manifest drivers
+//! go through the same handshake as services added in the settings.
+
+use crate::{
+    DriverCapabilities, DriverFormDef, DriverMetadata, Icon, RpcServiceKind, ServiceConfig,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Name of the driver manifests folder in the data directory.
+pub const DRIVER_MANIFESTS_DIR: &str = "drivers";
+
+/// One third-party driver, as described by its manifest file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriverManifest {
+    /// Name shown in the connection manager, replacing the one the driver
+    /// reports.
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Socket the driver listens on. The driver is registered as
+    /// `rpc:<socket>`.
+    pub socket: String,
+
+    /// Program DBFlux launches to start the driver. Without it, the driver
+    /// must already be running.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub startup_timeout_ms: Option<u64>,
+
+    /// Capability names (e.g. `"transactions"`, `"export_csv"`) the driver
+    /// supports. When set, DBFlux only enables the listed capabilities, even
+    /// if the driver reports more.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<Icon>,
+
+    /// Connection form, replacing the one the driver reports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form: Option<DriverFormDef>,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl DriverManifest {
+    /// Parses and validates a manifest file's contents.
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let manifest: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("\"name\" must not be empty".to_string());
+        }
+
+        if self.socket.trim().is_empty() {
+            return Err("\"socket\" must not be empty".to_string());
+        }
+
+        self.declared_capabilities().map(|_| ())
+    }
+
+    /// The listed capabilities as flags, or `None` when the manifest does
+    /// not list any.
+    pub fn declared_capabilities(&self) -> Result<Option<DriverCapabilities>, String> {
+        if self.capabilities.is_empty() {
+            return Ok(None);
+        }
+
+        self.capabilities
+            .iter()
+            .try_fold(DriverCapabilities::empty(), |declared, name| {
+                DriverCapabilities::from_name(&name.trim().to_ascii_uppercase())
+                    .map(|flag| declared | flag)
+                    .ok_or_else(|| format!("unknown capability \"{name}\""))
+            })
+            .map(Some)
+    }
+
+    /// Lowercase names of the capabilities in `capabilities`, for display.
+    pub fn capability_names(capabilities: DriverCapabilities) -> Vec<String> {
+        capabilities
+            .iter_names()
+            .map(|(name, _)| name.to_ascii_lowercase())
+            .collect()
+    }
+
+    /// The driver service this manifest stands for.
+    pub fn service_config(&self) -> ServiceConfig {
+        ServiceConfig {
+            socket_id: self.socket.clone(),
+            enabled: self.enabled,
+            command: self.binary.clone(),
+            args: self.args.clone(),
+            env: self
+                .env
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            startup_timeout_ms: self.startup_timeout_ms,
+            kind: RpcServiceKind::Driver,
+            api_contract: None,
+            remote: None,
+        }
+    }
+
+    /// Replaces what the driver reported in `Hello` with the manifest's
+    /// name, description, icon and form, and narrows its capabilities to
+    /// the declared ones.
+    ///
+    /// Returns the declared capabilities the driver did not report; those
+    /// stay disabled.
+    pub fn apply(
+        &self,
+        metadata: &mut DriverMetadata,
+        form: &mut DriverFormDef,
+    ) -> DriverCapabilities {
+        metadata.display_name = self.name.clone();
+
+        if let Some(description) = &self.description {
+            metadata.description = description.clone();
+        }
+
+        if let Some(icon) = self.icon {
+            metadata.icon = icon;
+        }
+
+        if let Some(manifest_form) = &self.form {
+            *form = manifest_form.clone();
+        }
+
+        let Ok(Some(declared)) = self.declared_capabilities() else {
+            return DriverCapabilities::empty();
+        };
+
+        let missing = declared.difference(metadata.capabilities);
+        metadata.capabilities &= declared;
+        missing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DatabaseCategory, DriverMetadataBuilder, QueryLanguage};
+
+    const MANIFEST: &str = r#"{
+        "name": "Mock Database",
+        "socket": "mockdb.sock",
+        "binary": "/opt/mockdb/custom-driver",
+        "args": ["--socket", "mockdb.sock"],
+        "capabilities": ["transactions", "EXPORT_CSV"],
+        "icon": "Database"
+    }"#;
+
+    #[test]
+    fn parse_reads_a_manifest_and_defaults_to_enabled() {
+        let manifest = DriverManifest::parse(MANIFEST).expect("valid manifest");
+
+        assert!(manifest.enabled);
+        assert_eq!(
+            manifest.declared_capabilities(),
+            Ok(Some(
+                DriverCapabilities::TRANSACTIONS | DriverCapabilities::EXPORT_CSV
+            ))
+        );
+
+        let service = manifest.service_config();
+        assert_eq!(service.socket_id, "mockdb.sock");
+        assert_eq!(
+            service.command.as_deref(),
+            Some("/opt/mockdb/custom-driver")
+        );
+        assert_eq!(service.kind, RpcServiceKind::Driver);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_capabilities_and_missing_socket() {
+        let unknown = MANIFEST.replace("EXPORT_CSV", "time_travel");
+        assert_eq!(
+            DriverManifest::parse(&unknown),
+            Err("unknown capability \"time_travel\"".to_string())
+        );
+
+        let no_socket = r#"{ "name": "Mock Database", "socket": " " }"#;
+        assert!(DriverManifest::parse(no_socket).is_err());
+    }
+
+    #[test]
+    fn apply_overrides_presentation_and_narrows_capabilities() {
+        let manifest = DriverManifest::parse(MANIFEST).expect("valid manifest");
+        let mut metadata = DriverMetadataBuilder::new(
+            "mockdb",
+            "mockdb",
+            DatabaseCategory::Relational,
+            QueryLanguage::Sql,
+        )
+        .capabilities(DriverCapabilities::TRANSACTIONS | DriverCapabilities::VIEWS)
+        .build();
+        let mut form = DriverFormDef { tabs: vec![] };
+
+        let missing = manifest.apply(&mut metadata, &mut form);
+
+        assert_eq!(metadata.display_name, "Mock Database");
+        assert_eq!(metadata.icon, Icon::Database);
+        assert_eq!(metadata.capabilities, DriverCapabilities::TRANSACTIONS);
+        assert_eq!(missing, DriverCapabilities::EXPORT_CSV);
+    }
+}
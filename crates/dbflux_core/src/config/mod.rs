@@ -1,14 +1,19 @@
 pub(crate) mod app;
+pub(crate) mod driver_manifest;
 pub(crate) mod refresh_policy;
 pub(crate) mod scripts_directory;
 
 pub use app::{
-    AppConfig, AppConfigWarning, AppStyle, DangerousAction, DriverKey,
-    EXTERNAL_SERVICES_CONFIG_KEY, EffectiveSettings, GeneralSettings, GlobalOverrides,
-    GovernanceSettings, LoadedAppConfig, PolicyRoleConfig, RefreshPolicySetting, RpcServiceKind,
-    ServiceConfig, ServiceRpcApiContract, StartupFocus, ThemeSetting, ToolPolicyConfig,
-    TrustedClientConfig, driver_maps_differ, migrate_app_config,
+    AppConfig, AppConfigWarning, AppStyle, DEFAULT_EDITOR_FONT_SIZE, DEFAULT_GRID_FONT_SIZE,
+    DEFAULT_UI_ZOOM_PERCENT, DangerousAction, DriverKey, EXTERNAL_SERVICES_CONFIG_KEY,
+    EffectiveSettings, GeneralSettings, GlobalOverrides, GovernanceSettings, LoadedAppConfig,
+    MAX_EDITOR_FONT_SIZE, MAX_GRID_FONT_SIZE, MAX_UI_ZOOM_PERCENT, MIN_EDITOR_FONT_SIZE,
+    MIN_GRID_FONT_SIZE, MIN_UI_ZOOM_PERCENT, PolicyRoleConfig, RefreshPolicySetting,
+    RemoteServiceConfig, RpcServiceKind, ServiceConfig, ServiceRpcApiContract, StartupFocus,
+    ThemeSetting, ToolPolicyConfig, TrustedClientConfig, UI_ZOOM_STEP_PERCENT, driver_maps_differ,
+    migrate_app_config,
 };
+pub use driver_manifest::{DRIVER_MANIFESTS_DIR, DriverManifest};
 pub use refresh_policy::RefreshPolicy;
 pub use scripts_directory::{
     ScriptEntry, ScriptsDirectory, all_script_extensions, filter_entries, hook_script_path,
@@ -301,10 +301,11 @@ pub fn hook_script_path(hooks_dir: &Path, hook_id: &str, language: ScriptLanguag
     hooks_dir.join(format!("{}.{}", hook_id, language.extension()))
 }
 
-/// Extensions openable in the code editor (recognized by `QueryLanguage::from_path`).
+/// Extensions openable in a document tab: everything `QueryLanguage::from_path`
+/// recognizes plus notebook files.
 const OPENABLE_EXTENSIONS: &[&str] = &[
     "sql", "js", "mongodb", "redis", "red", "cypher", "cyp", "influxql", "flux", "cql", "lua",
-    "py", "sh", "bash",
+    "py", "sh", "bash", "dbnb",
 ];
 
 fn has_file_extension(path: &Path) -> bool {
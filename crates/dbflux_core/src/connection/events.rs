@@ -0,0 +1,93 @@
+//! Events a connection reports on its own, outside any request.
+//!
+//! Live features (LISTEN/NOTIFY, change streams, monitoring) subscribe with
+//! [`Connection::subscribe_events`](crate::Connection::subscribe_events)
+//! instead of polling. Drivers deliver events from their own threads, so
+//! sinks must be cheap and must not call back into the connection.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Severity of a server notice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NoticeSeverity {
+    Info,
+    Warning,
+}
+
+/// Something the database told the connection without being asked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionEvent {
+    /// An informational message or warning (e.g. a PostgreSQL `NOTICE`).
+    Notice {
+        severity: NoticeSeverity,
+        message: String,
+    },
+    /// A payload published on a channel (e.g. PostgreSQL `NOTIFY`, a
+    /// MongoDB change stream document, a Redis pub/sub message).
+    Notification { channel: String, payload: String },
+    /// The schema changed and cached metadata may be stale. `database` is
+    /// `None` when the driver cannot tell which one.
+    SchemaChanged { database: Option<String> },
+    /// The connection to the database broke; later requests will fail until
+    /// it is reconnected.
+    ConnectionLost { reason: String },
+}
+
+/// The kinds of [`ConnectionEvent`], for choosing what to subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConnectionEventKind {
+    Notice,
+    Notification,
+    SchemaChanged,
+    ConnectionLost,
+}
+
+impl ConnectionEventKind {
+    pub const ALL: [ConnectionEventKind; 4] = [
+        ConnectionEventKind::Notice,
+        ConnectionEventKind::Notification,
+        ConnectionEventKind::SchemaChanged,
+        ConnectionEventKind::ConnectionLost,
+    ];
+}
+
+impl ConnectionEvent {
+    pub fn kind(&self) -> ConnectionEventKind {
+        match self {
+            ConnectionEvent::Notice { .. } => ConnectionEventKind::Notice,
+            ConnectionEvent::Notification { .. } => ConnectionEventKind::Notification,
+            ConnectionEvent::SchemaChanged { .. } => ConnectionEventKind::SchemaChanged,
+            ConnectionEvent::ConnectionLost { .. } => ConnectionEventKind::ConnectionLost,
+        }
+    }
+}
+
+/// Receives the events of one subscription.
+pub type ConnectionEventSink = Arc<dyn Fn(ConnectionEvent) + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_event_reports_a_listed_kind() {
+        let events = [
+            ConnectionEvent::Notice {
+                severity: NoticeSeverity::Warning,
+                message: "implicit index".to_string(),
+            },
+            ConnectionEvent::Notification {
+                channel: "orders".to_string(),
+                payload: "42".to_string(),
+            },
+            ConnectionEvent::SchemaChanged { database: None },
+            ConnectionEvent::ConnectionLost {
+                reason: "server closed the connection".to_string(),
+            },
+        ];
+
+        let kinds: Vec<_> = events.iter().map(ConnectionEvent::kind).collect();
+        assert_eq!(kinds, ConnectionEventKind::ALL);
+    }
+}
@@ -1,10 +1,11 @@
 use crate::LogErr;
 use crate::{
     CollectionChildrenCache, CollectionChildrenPage, CollectionChildrenRequest, CollectionRef,
-    Connection, ConnectionHooks, ConnectionProfile, CustomTypeInfo, DbDriver, DbKind, DbSchemaInfo,
-    HookContext, ProxyProfile, RelationRef, RoutineInfo, SchemaForeignKeyInfo, SchemaIndexInfo,
-    SchemaLoadingStrategy, SchemaSnapshot, SecretStore, ShutdownCoordinator, ShutdownPhase,
-    SshTunnelProfile, TableInfo, TaskTarget,
+    Connection, ConnectionHooks, ConnectionProfile, CustomTypeInfo, DataStructure, DbDriver,
+    DbKind, DbSchemaInfo, HookContext, IndexData, ProxyProfile, RelationKind, RelationRef,
+    RoutineInfo, SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaSnapshot,
+    SecretStore, ShutdownCoordinator, ShutdownPhase, SshTunnelProfile, TableInfo, TableStatistics,
+    TaskTarget,
 };
 use log::{error, info};
 use secrecy::SecretString;
@@ -333,6 +334,8 @@ pub struct ConnectedProfile {
     pub schema_routines: HashMap<SchemaCacheKey, Vec<RoutineInfo>>,
     /// Dependent objects (views, FK children, triggers) per table, keyed by `(database, table)`.
     pub dependents_cache: HashMap<(String, String), Vec<RelationRef>>,
+    /// Approximate row counts and sizes per table, keyed like `table_details`.
+    pub table_statistics: HashMap<(String, String), TableStatistics>,
     /// Active database for query context (MySQL/MariaDB USE).
     pub active_database: Option<String>,
     pub redis_key_cache: RedisKeyCache,
@@ -510,6 +513,270 @@ impl ConnectedProfile {
             .insert((database.into(), table.into()), deps);
     }
 
+    /// Objects known to reference `table`, or its `column` when set: the
+    /// cached dependents plus the cached foreign keys pointing at it. Only
+    /// what has already been loaded is consulted, so the list may be partial.
+    pub fn rename_dependents(
+        &self,
+        database: &str,
+        schema: &str,
+        table: &str,
+        column: Option<&str>,
+    ) -> Vec<RelationRef> {
+        let references = |ref_schema: Option<&str>, ref_table: &str, ref_columns: &[String]| {
+            ref_table == table
+                && ref_schema.is_none_or(|ref_schema| ref_schema == schema)
+                && column.is_none_or(|column| ref_columns.iter().any(|c| c == column))
+        };
+        let qualify = |schema: Option<&str>, name: &str| match schema {
+            Some(schema) => format!("{}.{}", schema, name),
+            None => name.to_string(),
+        };
+
+        let mut children: Vec<String> = self
+            .table_details
+            .iter()
+            .filter(|((cache_db, _), _)| cache_db == database)
+            .filter(|(_, details)| {
+                details.foreign_keys.as_deref().is_some_and(|fks| {
+                    fks.iter().any(|fk| {
+                        references(
+                            fk.referenced_schema.as_deref(),
+                            &fk.referenced_table,
+                            &fk.referenced_columns,
+                        )
+                    })
+                })
+            })
+            .map(|(_, details)| qualify(details.schema.as_deref(), &details.name))
+            .collect();
+
+        children.extend(
+            self.schema_foreign_keys
+                .iter()
+                .filter(|(key, _)| key.database == database)
+                .flat_map(|(key, fks)| {
+                    fks.iter()
+                        .filter(|fk| {
+                            references(
+                                fk.referenced_schema.as_deref(),
+                                &fk.referenced_table,
+                                &fk.referenced_columns,
+                            )
+                        })
+                        .map(|fk| qualify(key.schema.as_deref(), &fk.table_name))
+                }),
+        );
+
+        // Cached foreign-key children only name the referencing table, so a
+        // column rename relies on the foreign keys above for those.
+        let mut dependents: Vec<RelationRef> = self
+            .dependents(database, table)
+            .into_iter()
+            .filter(|dep| column.is_none() || dep.kind != RelationKind::ForeignKeyChild)
+            .collect();
+
+        for child in children {
+            let known = dependents.iter().any(|dep| {
+                dep.kind == RelationKind::ForeignKeyChild && dep.qualified_name == child
+            });
+            if !known {
+                dependents.push(RelationRef {
+                    kind: RelationKind::ForeignKeyChild,
+                    qualified_name: child,
+                });
+            }
+        }
+
+        dependents
+    }
+
+    /// Applies a table rename that succeeded on the server to the cached
+    /// listings, details and foreign keys, so the tree shows the new name
+    /// without re-introspecting.
+    pub fn rename_cached_table(
+        &mut self,
+        database: &str,
+        schema: &str,
+        table: &str,
+        new_name: &str,
+    ) {
+        let rename_listed = |listing: &mut DbSchemaInfo| {
+            for entry in listing
+                .tables
+                .iter_mut()
+                .filter(|entry| entry.name == table)
+            {
+                entry.name = new_name.to_string();
+            }
+        };
+        let rename_in_snapshot = |snapshot: &mut SchemaSnapshot| {
+            if let DataStructure::Relational(relational) = &mut snapshot.structure {
+                relational
+                    .schemas
+                    .iter_mut()
+                    .filter(|listing| listing.name == schema)
+                    .for_each(rename_listed);
+                for entry in relational
+                    .tables
+                    .iter_mut()
+                    .filter(|entry| entry.name == table)
+                {
+                    entry.name = new_name.to_string();
+                }
+            }
+        };
+
+        if let Some(listing) = self.database_schemas.get_mut(database) {
+            rename_listed(listing);
+        }
+
+        match self.database_connections.get_mut(database) {
+            Some(db_conn) => {
+                if let Some(snapshot) = db_conn.schema.as_mut() {
+                    rename_in_snapshot(snapshot);
+                }
+            }
+            None => {
+                if let Some(snapshot) = self.schema.as_mut() {
+                    rename_in_snapshot(snapshot);
+                }
+            }
+        }
+
+        let old_key = (database.to_string(), table.to_string());
+        let new_key = (database.to_string(), new_name.to_string());
+
+        if let Some(mut details) = self.table_details.remove(&old_key) {
+            details.name = new_name.to_string();
+            self.table_details.insert(new_key.clone(), details);
+        }
+        if let Some(statistics) = self.table_statistics.remove(&old_key) {
+            self.table_statistics.insert(new_key.clone(), statistics);
+        }
+        if let Some(dependents) = self.dependents_cache.remove(&old_key) {
+            self.dependents_cache.insert(new_key, dependents);
+        }
+
+        for ((cache_db, _), details) in self.table_details.iter_mut() {
+            if cache_db != database {
+                continue;
+            }
+            for fk in details.foreign_keys.iter_mut().flatten() {
+                if fk.referenced_table == table
+                    && fk.referenced_schema.as_deref().is_none_or(|s| s == schema)
+                {
+                    fk.referenced_table = new_name.to_string();
+                }
+            }
+        }
+
+        for (key, indexes) in self.schema_indexes.iter_mut() {
+            if key.database == database && key.schema.as_deref().is_none_or(|s| s == schema) {
+                for index in indexes.iter_mut().filter(|index| index.table_name == table) {
+                    index.table_name = new_name.to_string();
+                }
+            }
+        }
+
+        for (key, fks) in self.schema_foreign_keys.iter_mut() {
+            if key.database != database {
+                continue;
+            }
+            for fk in fks.iter_mut() {
+                if fk.table_name == table && key.schema.as_deref().is_none_or(|s| s == schema) {
+                    fk.table_name = new_name.to_string();
+                }
+                if fk.referenced_table == table
+                    && fk.referenced_schema.as_deref().is_none_or(|s| s == schema)
+                {
+                    fk.referenced_table = new_name.to_string();
+                }
+            }
+        }
+    }
+
+    /// Applies a column rename that succeeded on the server to the cached
+    /// details of its table and to the foreign keys that reference it.
+    pub fn rename_cached_column(
+        &mut self,
+        database: &str,
+        table: &str,
+        column: &str,
+        new_name: &str,
+    ) {
+        let rename = |columns: &mut Vec<String>| {
+            for name in columns.iter_mut().filter(|name| *name == column) {
+                *name = new_name.to_string();
+            }
+        };
+
+        let mut schema = None;
+
+        if let Some(details) = self
+            .table_details
+            .get_mut(&(database.to_string(), table.to_string()))
+        {
+            schema = details.schema.clone();
+
+            for entry in details.columns.iter_mut().flatten() {
+                if entry.name == column {
+                    entry.name = new_name.to_string();
+                }
+            }
+            if let Some(IndexData::Relational(indexes)) = details.indexes.as_mut() {
+                for index in indexes {
+                    rename(&mut index.columns);
+                }
+            }
+            for fk in details.foreign_keys.iter_mut().flatten() {
+                rename(&mut fk.columns);
+            }
+            for constraint in details.constraints.iter_mut().flatten() {
+                rename(&mut constraint.columns);
+            }
+        }
+
+        let references_table = |ref_schema: Option<&str>, ref_table: &str| {
+            ref_table == table
+                && ref_schema
+                    .is_none_or(|ref_schema| schema.as_deref().is_none_or(|s| s == ref_schema))
+        };
+
+        for ((cache_db, _), details) in self.table_details.iter_mut() {
+            if cache_db != database {
+                continue;
+            }
+            for fk in details.foreign_keys.iter_mut().flatten() {
+                if references_table(fk.referenced_schema.as_deref(), &fk.referenced_table) {
+                    rename(&mut fk.referenced_columns);
+                }
+            }
+        }
+
+        for (key, indexes) in self.schema_indexes.iter_mut() {
+            if key.database == database {
+                for index in indexes.iter_mut().filter(|index| index.table_name == table) {
+                    rename(&mut index.columns);
+                }
+            }
+        }
+
+        for (key, fks) in self.schema_foreign_keys.iter_mut() {
+            if key.database != database {
+                continue;
+            }
+            for fk in fks.iter_mut() {
+                if fk.table_name == table {
+                    rename(&mut fk.columns);
+                }
+                if references_table(fk.referenced_schema.as_deref(), &fk.referenced_table) {
+                    rename(&mut fk.referenced_columns);
+                }
+            }
+        }
+    }
+
     /// Resolve the effective connection for query execution.
     ///
     /// For `ConnectionPerDatabase` strategies, this selects a per-database
@@ -679,6 +946,7 @@ impl ConnectionManager {
                 schema_foreign_keys: HashMap::new(),
                 schema_routines: HashMap::new(),
                 dependents_cache: HashMap::new(),
+                table_statistics: HashMap::new(),
                 active_database: None,
                 redis_key_cache: RedisKeyCache::default(),
                 database_connections: HashMap::new(),
@@ -768,6 +1036,25 @@ impl ConnectionManager {
         }
     }
 
+    /// Caches `statistics` unless the driver reported nothing.
+    pub fn set_table_statistics(
+        &mut self,
+        profile_id: Uuid,
+        database: String,
+        table: String,
+        statistics: TableStatistics,
+    ) {
+        if statistics.is_empty() {
+            return;
+        }
+
+        if let Some(connected) = self.connections.get_mut(&profile_id) {
+            connected
+                .table_statistics
+                .insert((database, table), statistics);
+        }
+    }
+
     pub fn set_dependents(
         &mut self,
         profile_id: Uuid,
@@ -1296,6 +1583,7 @@ impl ConnectionManager {
                 schema_foreign_keys: HashMap::new(),
                 schema_routines: HashMap::new(),
                 dependents_cache: HashMap::new(),
+                table_statistics: HashMap::new(),
                 active_database: None,
                 redis_key_cache: RedisKeyCache::default(),
                 database_connections: prev_db_connections,
@@ -1610,10 +1898,16 @@ impl ConnectProfileParams {
             }
         };
 
+        let database_schemas = schema
+            .as_ref()
+            .map(|schema| crate::prefetch_database_schemas(connection.as_ref(), schema))
+            .unwrap_or_default();
+
         Ok(ConnectProfileResult {
             profile,
             connection: connection.into(),
             schema,
+            database_schemas,
             proxy_tunnel,
         })
     }
@@ -1646,6 +1940,8 @@ pub struct ConnectProfileResult {
     pub profile: ConnectionProfile,
     pub connection: Arc<dyn Connection>,
     pub schema: Option<SchemaSnapshot>,
+    /// Database schemas listed eagerly on connect (`LazyPerDatabase` drivers).
+    pub database_schemas: Vec<DbSchemaInfo>,
     /// Type-erased proxy tunnel handle kept alive for RAII drop semantics.
     pub proxy_tunnel: Option<Box<dyn Any + Send + Sync>>,
 }
@@ -1773,12 +2069,18 @@ impl FetchTableDetailsParams {
             .fetch_dependents(&self.database, self.schema.as_deref(), &self.table)
             .unwrap_or_default();
 
+        let statistics = self
+            .connection
+            .table_statistics(&self.database, self.schema.as_deref(), &self.table)
+            .unwrap_or_default();
+
         Ok(FetchTableDetailsResult {
             profile_id: self.profile_id,
             database: self.database,
             table: self.table,
             details,
             dependents,
+            statistics,
         })
     }
 }
@@ -1792,6 +2094,8 @@ pub struct FetchTableDetailsResult {
     /// Dependent objects fetched alongside the table details (views, FK children, triggers).
     /// Empty when the driver does not support dependent introspection.
     pub dependents: Vec<RelationRef>,
+    /// Approximate size of the table; empty when the driver cannot tell.
+    pub statistics: TableStatistics,
 }
 
 pub struct FetchCollectionChildrenParams {
@@ -2061,6 +2365,7 @@ mod tests {
             schema_foreign_keys: HashMap::new(),
             schema_routines: HashMap::new(),
             dependents_cache: HashMap::new(),
+            table_statistics: HashMap::new(),
             active_database: None,
             redis_key_cache: RedisKeyCache::default(),
             database_connections,
@@ -2690,6 +2995,147 @@ mod tests {
         );
     }
 
+    fn table_with_fk(name: &str, column: &str, referenced: (&str, &str)) -> TableInfo {
+        TableInfo {
+            name: name.to_string(),
+            schema: Some("public".to_string()),
+            columns: Some(vec![crate::ColumnInfo {
+                name: column.to_string(),
+                type_name: "integer".to_string(),
+                nullable: false,
+                is_primary_key: false,
+                default_value: None,
+                enum_values: None,
+                comment: None,
+                definition: None,
+            }]),
+            indexes: None,
+            foreign_keys: Some(vec![crate::ForeignKeyInfo {
+                name: format!("{}_{}_fkey", name, column),
+                columns: vec![column.to_string()],
+                referenced_table: referenced.0.to_string(),
+                referenced_schema: Some("public".to_string()),
+                referenced_columns: vec![referenced.1.to_string()],
+                on_delete: None,
+                on_update: None,
+            }]),
+            constraints: None,
+            sample_fields: None,
+            presentation: Default::default(),
+            child_items: None,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn rename_dependents_merges_cached_dependents_and_foreign_keys() {
+        let profile = ConnectionProfile::new("pg", DbConfig::default_postgres());
+        let connection = make_connection(
+            DbKind::Postgres,
+            SchemaLoadingStrategy::ConnectionPerDatabase,
+        );
+        let mut connected = connected_profile(profile, connection, None, HashMap::new());
+
+        connected.populate_dependents(
+            "mydb",
+            "users",
+            vec![
+                RelationRef {
+                    kind: RelationKind::View,
+                    qualified_name: "public.user_summary".to_string(),
+                },
+                RelationRef {
+                    kind: RelationKind::ForeignKeyChild,
+                    qualified_name: "public.orders".to_string(),
+                },
+            ],
+        );
+        connected.table_details.insert(
+            ("mydb".to_string(), "orders".to_string()),
+            table_with_fk("orders", "user_id", ("users", "id")),
+        );
+        connected.table_details.insert(
+            ("mydb".to_string(), "reviews".to_string()),
+            table_with_fk("reviews", "author_email", ("users", "email")),
+        );
+
+        let names = |deps: Vec<RelationRef>| {
+            let mut names: Vec<String> = deps.into_iter().map(|d| d.qualified_name).collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            names(connected.rename_dependents("mydb", "public", "users", None)),
+            vec!["public.orders", "public.reviews", "public.user_summary"]
+        );
+        assert_eq!(
+            names(connected.rename_dependents("mydb", "public", "users", Some("id"))),
+            vec!["public.orders", "public.user_summary"]
+        );
+        assert!(
+            connected
+                .rename_dependents("otherdb", "public", "users", None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn renamed_tables_and_columns_update_the_cache() {
+        let profile = ConnectionProfile::new("pg", DbConfig::default_postgres());
+        let connection = make_connection(
+            DbKind::Postgres,
+            SchemaLoadingStrategy::ConnectionPerDatabase,
+        );
+        let schema = SchemaSnapshot::relational(crate::RelationalSchema {
+            current_database: Some("mydb".to_string()),
+            schemas: vec![DbSchemaInfo {
+                name: "public".to_string(),
+                tables: vec![
+                    table_with_fk("orders", "user_id", ("users", "id")),
+                    table_with_fk("users", "id", ("accounts", "id")),
+                ],
+                views: Vec::new(),
+                custom_types: None,
+            }],
+            ..Default::default()
+        });
+        let mut connected = connected_profile(profile, connection, Some(schema), HashMap::new());
+        connected.table_details.insert(
+            ("mydb".to_string(), "orders".to_string()),
+            table_with_fk("orders", "user_id", ("users", "id")),
+        );
+        connected.table_details.insert(
+            ("mydb".to_string(), "users".to_string()),
+            table_with_fk("users", "id", ("accounts", "id")),
+        );
+
+        connected.rename_cached_table("mydb", "public", "users", "members");
+        connected.rename_cached_column("mydb", "members", "id", "member_id");
+
+        let listed: Vec<&str> = connected.schema.as_ref().unwrap().schemas()[0]
+            .tables
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(listed, vec!["orders", "members"]);
+
+        let members = &connected.table_details[&("mydb".to_string(), "members".to_string())];
+        assert_eq!(members.name, "members");
+        assert_eq!(members.columns.as_ref().unwrap()[0].name, "member_id");
+        assert_eq!(
+            members.foreign_keys.as_ref().unwrap()[0].columns,
+            vec!["member_id"]
+        );
+
+        let orders_fk = &connected.table_details[&("mydb".to_string(), "orders".to_string())]
+            .foreign_keys
+            .as_ref()
+            .unwrap()[0];
+        assert_eq!(orders_fk.referenced_table, "members");
+        assert_eq!(orders_fk.referenced_columns, vec!["member_id"]);
+    }
+
     #[test]
     fn schema_routines_cache_roundtrip() {
         use crate::RoutineInfo;
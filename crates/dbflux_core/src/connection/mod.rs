@@ -1,12 +1,14 @@
 pub(crate) mod context;
 pub mod dashboard_import;
 pub mod dashboard_source;
+pub mod events;
 pub(crate) mod hook;
 pub mod instance_catalog;
 pub(crate) mod item_manager;
 pub mod manager;
 pub mod metric_catalog;
 
+pub use events::{ConnectionEvent, ConnectionEventKind, ConnectionEventSink, NoticeSeverity};
 pub use metric_catalog::{
     DimensionFilter, MetricCatalog, MetricCatalogPage, MetricDescriptor, MetricNamespace,
 };
@@ -71,5 +73,7 @@ pub use profile_manager::ProfileManager;
 pub use proxy::{ProxyAuth, ProxyKind, ProxyProfile, host_matches_no_proxy};
 pub use proxy_manager::ProxyManager;
 pub use ssh_tunnel_manager::SshTunnelManager;
-pub use tree::{ConnectionTree, ConnectionTreeNode, ConnectionTreeNodeKind};
+pub use tree::{
+    ConnectionTree, ConnectionTreeNode, ConnectionTreeNodeKind, FavoriteObject, FavoriteTarget,
+};
 pub use tree_manager::ConnectionTreeManager;
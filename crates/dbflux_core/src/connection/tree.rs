@@ -80,6 +80,42 @@ impl ConnectionTreeNode {
     }
 }
 
+/// What a sidebar favorite points at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FavoriteTarget {
+    Table {
+        database: Option<String>,
+        schema: String,
+        name: String,
+    },
+    View {
+        database: Option<String>,
+        schema: String,
+        name: String,
+    },
+    SavedQuery {
+        query_id: Uuid,
+    },
+}
+
+/// A starred object, listed in the "Favorites" section of its connection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FavoriteObject {
+    pub id: Uuid,
+    pub profile_id: Uuid,
+    pub target: FavoriteTarget,
+}
+
+impl FavoriteObject {
+    pub fn new(profile_id: Uuid, target: FavoriteTarget) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            profile_id,
+            target,
+        }
+    }
+}
+
 /// The connection tree structure containing all folder and connection nodes.
 ///
 /// This tree organizes connection profiles into a hierarchical folder structure.
@@ -93,6 +129,10 @@ pub struct ConnectionTree {
     /// Version number for potential future migrations.
     #[serde(default)]
     pub version: u32,
+
+    /// Starred objects of every profile, in display order.
+    #[serde(default)]
+    pub favorites: Vec<FavoriteObject>,
 }
 
 /// Gap between sort indices to allow easy insertion without reordering.
@@ -104,6 +144,7 @@ impl ConnectionTree {
         Self {
             nodes: Vec::new(),
             version: 1,
+            favorites: Vec::new(),
         }
     }
 
@@ -238,6 +279,9 @@ impl ConnectionTree {
             }
         });
 
+        self.favorites
+            .retain(|favorite| profile_ids.contains(&favorite.profile_id));
+
         // Add missing profiles as root-level connection refs
         for &profile_id in profile_ids {
             if self.find_by_profile(profile_id).is_none() {
@@ -480,6 +524,77 @@ impl ConnectionTree {
 
         children_ids
     }
+
+    /// Favorites of a profile, in display order.
+    pub fn favorites_of(&self, profile_id: Uuid) -> Vec<&FavoriteObject> {
+        self.favorites
+            .iter()
+            .filter(|f| f.profile_id == profile_id)
+            .collect()
+    }
+
+    /// Finds a favorite by its ID.
+    pub fn find_favorite(&self, favorite_id: Uuid) -> Option<&FavoriteObject> {
+        self.favorites.iter().find(|f| f.id == favorite_id)
+    }
+
+    /// Returns `true` if `target` is starred for the profile.
+    pub fn is_favorite(&self, profile_id: Uuid, target: &FavoriteTarget) -> bool {
+        self.favorites
+            .iter()
+            .any(|f| f.profile_id == profile_id && f.target == *target)
+    }
+
+    /// Stars `target` for the profile, or unstars it when it already is.
+    ///
+    /// Returns `true` if the target is a favorite afterwards.
+    pub fn toggle_favorite(&mut self, profile_id: Uuid, target: FavoriteTarget) -> bool {
+        let before = self.favorites.len();
+        self.favorites
+            .retain(|f| !(f.profile_id == profile_id && f.target == target));
+
+        if self.favorites.len() != before {
+            return false;
+        }
+
+        self.favorites.push(FavoriteObject::new(profile_id, target));
+        true
+    }
+
+    /// Removes a favorite. Returns `true` if it existed.
+    pub fn remove_favorite(&mut self, favorite_id: Uuid) -> bool {
+        let before = self.favorites.len();
+        self.favorites.retain(|f| f.id != favorite_id);
+        self.favorites.len() != before
+    }
+
+    /// Moves a favorite next to another favorite of the same profile, before
+    /// it or after it.
+    ///
+    /// Returns `true` if the order changed.
+    pub fn move_favorite(&mut self, favorite_id: Uuid, target_id: Uuid, after: bool) -> bool {
+        if favorite_id == target_id {
+            return false;
+        }
+
+        let Some(from) = self.favorites.iter().position(|f| f.id == favorite_id) else {
+            return false;
+        };
+        let favorite = self.favorites.remove(from);
+
+        let Some(target_pos) = self
+            .favorites
+            .iter()
+            .position(|f| f.id == target_id && f.profile_id == favorite.profile_id)
+        else {
+            self.favorites.insert(from, favorite);
+            return false;
+        };
+
+        let to = if after { target_pos + 1 } else { target_pos };
+        self.favorites.insert(to, favorite);
+        to != from
+    }
 }
 
 #[cfg(test)]
@@ -744,4 +859,71 @@ mod tests {
         // Can't move after self
         assert!(!tree.move_node_to_position(n1_id, None, Some(n1_id)));
     }
+
+    fn table(name: &str) -> FavoriteTarget {
+        FavoriteTarget::Table {
+            database: None,
+            schema: "public".to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_toggle_favorite() {
+        let mut tree = ConnectionTree::new();
+        let profile_id = Uuid::new_v4();
+
+        assert!(tree.toggle_favorite(profile_id, table("users")));
+        assert!(tree.is_favorite(profile_id, &table("users")));
+        assert!(!tree.is_favorite(Uuid::new_v4(), &table("users")));
+
+        assert!(!tree.toggle_favorite(profile_id, table("users")));
+        assert!(tree.favorites_of(profile_id).is_empty());
+    }
+
+    #[test]
+    fn test_move_favorite() {
+        let mut tree = ConnectionTree::new();
+        let profile_id = Uuid::new_v4();
+        let other_profile = Uuid::new_v4();
+
+        for name in ["a", "b", "c"] {
+            tree.toggle_favorite(profile_id, table(name));
+        }
+        tree.toggle_favorite(other_profile, table("x"));
+
+        let ids: Vec<Uuid> = tree.favorites_of(profile_id).iter().map(|f| f.id).collect();
+        let other_id = tree.favorites_of(other_profile)[0].id;
+
+        // Move "c" before "a"
+        assert!(tree.move_favorite(ids[2], ids[0], false));
+        // Move "c" after "b"
+        assert!(tree.move_favorite(ids[2], ids[1], true));
+        let order: Vec<Uuid> = tree.favorites_of(profile_id).iter().map(|f| f.id).collect();
+        assert_eq!(order, vec![ids[0], ids[1], ids[2]]);
+
+        // Favorites never move across profiles
+        assert!(!tree.move_favorite(ids[0], other_id, false));
+        assert!(!tree.move_favorite(ids[0], ids[0], true));
+    }
+
+    #[test]
+    fn test_sync_with_profiles_drops_orphaned_favorites() {
+        let mut tree = ConnectionTree::new();
+        let kept = Uuid::new_v4();
+        let removed = Uuid::new_v4();
+
+        tree.toggle_favorite(kept, table("users"));
+        tree.toggle_favorite(
+            removed,
+            FavoriteTarget::SavedQuery {
+                query_id: Uuid::new_v4(),
+            },
+        );
+
+        tree.sync_with_profiles(&[kept]);
+
+        assert_eq!(tree.favorites.len(), 1);
+        assert_eq!(tree.favorites[0].profile_id, kept);
+    }
 }
@@ -1,6 +1,6 @@
 use crate::connection::TreeLoadResult;
 use crate::connection::TreeStore;
-use crate::{ConnectionTree, ConnectionTreeNode};
+use crate::{ConnectionTree, ConnectionTreeNode, FavoriteTarget};
 use log::{error, info};
 use uuid::Uuid;
 
@@ -58,10 +58,11 @@ impl ConnectionTreeManager {
 
     pub fn sync_with_profiles(&mut self, profile_ids: &[Uuid]) {
         let nodes_before = self.tree.nodes.len();
+        let favorites_before = self.tree.favorites.len();
         self.tree.sync_with_profiles(profile_ids);
         let nodes_after = self.tree.nodes.len();
 
-        if nodes_before != nodes_after {
+        if nodes_before != nodes_after || favorites_before != self.tree.favorites.len() {
             if matches!(
                 self.load_state,
                 TreeLoadState::Recovered | TreeLoadState::Failed
@@ -176,6 +177,30 @@ impl ConnectionTreeManager {
         self.tree.set_folder_collapsed(folder_id, collapsed);
         self.save();
     }
+
+    pub fn toggle_favorite(&mut self, profile_id: Uuid, target: FavoriteTarget) -> bool {
+        let starred = self.tree.toggle_favorite(profile_id, target);
+        self.save();
+        starred
+    }
+
+    pub fn remove_favorite(&mut self, favorite_id: Uuid) -> bool {
+        if self.tree.remove_favorite(favorite_id) {
+            self.save();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn move_favorite(&mut self, favorite_id: Uuid, target_id: Uuid, after: bool) -> bool {
+        if self.tree.move_favorite(favorite_id, target_id, after) {
+            self.save();
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl Default for ConnectionTreeManager {
@@ -0,0 +1,378 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+/// A time span such as PostgreSQL's `interval`.
+///
+/// Months, days and microseconds are kept apart because a month has no fixed
+/// number of days and a day is not always 24 hours across a DST change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl Interval {
+    pub const fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Length in microseconds with 30-day months and 24-hour days, the
+    /// approximation PostgreSQL orders intervals by.
+    pub fn approximate_microseconds(&self) -> i128 {
+        (i128::from(self.months) * 30 + i128::from(self.days)) * i128::from(MICROS_PER_DAY)
+            + i128::from(self.microseconds)
+    }
+
+    /// Parses the PostgreSQL interval syntax (`1 year 2 mons 3 days 04:05:06`,
+    /// `90 minutes`, `@ 2 days ago`) or an ISO 8601 duration (`P1DT2H`).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let lower = text.trim().to_ascii_lowercase();
+        let invalid = || format!("\"{}\" is not a valid interval", text.trim());
+
+        let mut builder = IntervalBuilder::default();
+        let parsed = match lower.strip_prefix('p') {
+            Some(iso) => builder.parse_iso(iso),
+            None => builder.parse_verbose(&lower),
+        };
+
+        parsed.ok_or_else(invalid)?;
+        builder.build().ok_or_else(invalid)
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.approximate_microseconds()
+            .cmp(&other.approximate_microseconds())
+            .then_with(|| {
+                (self.months, self.days, self.microseconds).cmp(&(
+                    other.months,
+                    other.days,
+                    other.microseconds,
+                ))
+            })
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats like PostgreSQL's default `IntervalStyle`:
+/// `1 year 2 mons 3 days 04:05:06.5`.
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+
+        for (count, unit) in [
+            (self.months / 12, "year"),
+            (self.months % 12, "mon"),
+            (self.days, "day"),
+        ] {
+            if count != 0 {
+                let plural = if count == 1 { "" } else { "s" };
+                parts.push(format!("{count} {unit}{plural}"));
+            }
+        }
+
+        if self.microseconds != 0 || parts.is_empty() {
+            let sign = if self.microseconds < 0 { "-" } else { "" };
+            let micros = self.microseconds.unsigned_abs();
+            let hours = micros / MICROS_PER_HOUR as u64;
+            let minutes = micros / MICROS_PER_MINUTE as u64 % 60;
+            let seconds = micros / MICROS_PER_SECOND as u64 % 60;
+            let fraction = micros % MICROS_PER_SECOND as u64;
+
+            let mut clock = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+            if fraction != 0 {
+                let digits = format!("{fraction:06}");
+                clock.push('.');
+                clock.push_str(digits.trim_end_matches('0'));
+            }
+            parts.push(clock);
+        }
+
+        f.write_str(&parts.join(" "))
+    }
+}
+
+/// Parts of an interval being parsed. Fractions of a month or a day carry
+/// down to the next field, as PostgreSQL does.
+#[derive(Default)]
+struct IntervalBuilder {
+    months: f64,
+    days: f64,
+    microseconds: f64,
+}
+
+impl IntervalBuilder {
+    fn add_months(&mut self, months: f64) {
+        self.months += months.trunc();
+        self.add_days(months.fract() * 30.0);
+    }
+
+    fn add_days(&mut self, days: f64) {
+        self.days += days.trunc();
+        self.microseconds += days.fract() * MICROS_PER_DAY as f64;
+    }
+
+    fn add(&mut self, amount: f64, unit: &str) -> Option<()> {
+        let micros = |per_unit: i64| amount * per_unit as f64;
+
+        match unit {
+            "millennium" | "millennia" | "millenniums" => self.add_months(amount * 12_000.0),
+            "century" | "centuries" => self.add_months(amount * 1_200.0),
+            "decade" | "decades" => self.add_months(amount * 120.0),
+            "y" | "yr" | "yrs" | "year" | "years" => self.add_months(amount * 12.0),
+            "mon" | "mons" | "month" | "months" => self.add_months(amount),
+            "w" | "week" | "weeks" => self.add_days(amount * 7.0),
+            "d" | "day" | "days" => self.add_days(amount),
+            "h" | "hr" | "hrs" | "hour" | "hours" => {
+                self.microseconds += micros(MICROS_PER_HOUR);
+            }
+            "m" | "min" | "mins" | "minute" | "minutes" => {
+                self.microseconds += micros(MICROS_PER_MINUTE);
+            }
+            "s" | "sec" | "secs" | "second" | "seconds" => {
+                self.microseconds += micros(MICROS_PER_SECOND);
+            }
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => {
+                self.microseconds += micros(1_000);
+            }
+            "us" | "usec" | "usecs" | "microsecond" | "microseconds" => {
+                self.microseconds += amount;
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// `[@] <amount> <unit> ... [hh:mm[:ss]] [ago]`; a bare number is seconds.
+    fn parse_verbose(&mut self, text: &str) -> Option<()> {
+        let text = text.strip_prefix('@').unwrap_or(text).trim();
+        let (text, ago) = match text.strip_suffix("ago") {
+            Some(rest) => (rest.trim_end(), true),
+            None => (text, false),
+        };
+
+        let mut tokens = text.split_whitespace().peekable();
+        tokens.peek()?;
+
+        while let Some(token) = tokens.next() {
+            if token.contains(':') {
+                self.microseconds += parse_clock(token)?;
+                continue;
+            }
+
+            let split = token
+                .find(|c: char| c.is_ascii_alphabetic())
+                .unwrap_or(token.len());
+            let (number, unit) = token.split_at(split);
+            let amount: f64 = number.parse().ok()?;
+
+            let unit = match unit {
+                "" => match tokens.peek() {
+                    Some(next) if next.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                        tokens.next()?
+                    }
+                    _ => "s",
+                },
+                unit => unit,
+            };
+            self.add(amount, unit)?;
+        }
+
+        if ago {
+            self.months = -self.months;
+            self.days = -self.days;
+            self.microseconds = -self.microseconds;
+        }
+
+        Some(())
+    }
+
+    /// The part of an ISO 8601 duration after `P`, e.g. `1y2m3dt4h5m6.5s`.
+    fn parse_iso(&mut self, text: &str) -> Option<()> {
+        let (date, time) = match text.split_once('t') {
+            Some((date, time)) => (date, Some(time)),
+            None => (text, None),
+        };
+
+        if date.is_empty() && time.is_none_or(str::is_empty) {
+            return None;
+        }
+
+        for (amount, designator) in iso_components(date)? {
+            let unit = match designator {
+                'y' => "years",
+                'm' => "months",
+                'w' => "weeks",
+                'd' => "days",
+                _ => return None,
+            };
+            self.add(amount, unit)?;
+        }
+
+        for (amount, designator) in iso_components(time.unwrap_or_default())? {
+            let unit = match designator {
+                'h' => "hours",
+                'm' => "minutes",
+                's' => "seconds",
+                _ => return None,
+            };
+            self.add(amount, unit)?;
+        }
+
+        Some(())
+    }
+
+    fn build(&self) -> Option<Interval> {
+        let whole = |value: f64| (value.is_finite()).then(|| value.round() as i64);
+
+        Some(Interval {
+            months: i32::try_from(whole(self.months)?).ok()?,
+            days: i32::try_from(whole(self.days)?).ok()?,
+            microseconds: whole(self.microseconds)
+                .filter(|micros| *micros > i64::MIN && *micros < i64::MAX)?,
+        })
+    }
+}
+
+/// Splits `1y2.5m` into `[(1.0, 'y'), (2.5, 'm')]`.
+fn iso_components(text: &str) -> Option<Vec<(f64, char)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+
+    for (index, c) in text.char_indices() {
+        if c.is_ascii_alphabetic() {
+            let amount = text.get(start..index)?.parse().ok()?;
+            components.push((amount, c));
+            start = index + 1;
+        }
+    }
+
+    (start == text.len()).then_some(components)
+}
+
+/// `[-]hh:mm[:ss[.ffffff]]` in microseconds.
+fn parse_clock(token: &str) -> Option<f64> {
+    let (negative, clock) = match token.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+
+    let mut fields = clock.split(':');
+    let hours: u64 = fields.next()?.parse().ok()?;
+    let minutes: u64 = fields.next()?.parse().ok()?;
+    let seconds: f64 = match fields.next() {
+        Some(seconds) if seconds.starts_with(|c: char| c.is_ascii_digit()) => {
+            seconds.parse().ok()?
+        }
+        Some(_) => return None,
+        None => 0.0,
+    };
+
+    if fields.next().is_some() || minutes >= 60 || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+
+    let micros = hours as f64 * MICROS_PER_HOUR as f64
+        + minutes as f64 * MICROS_PER_MINUTE as f64
+        + seconds * MICROS_PER_SECOND as f64;
+
+    Some(if negative { -micros } else { micros })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hms(hours: i64, minutes: i64, seconds: i64) -> i64 {
+        hours * MICROS_PER_HOUR + minutes * MICROS_PER_MINUTE + seconds * MICROS_PER_SECOND
+    }
+
+    #[test]
+    fn displays_like_postgres_and_parses_back() {
+        let cases = [
+            (
+                Interval::new(14, 3, hms(4, 5, 6) + 500_000),
+                "1 year 2 mons 3 days 04:05:06.5",
+            ),
+            (Interval::new(0, -1, 0), "-1 days"),
+            (Interval::new(1, 1, 0), "1 mon 1 day"),
+            (Interval::new(0, 1, -hms(1, 0, 0)), "1 day -01:00:00"),
+            (Interval::default(), "00:00:00"),
+            (Interval::new(0, 0, hms(100, 0, 0)), "100:00:00"),
+        ];
+
+        for (interval, text) in cases {
+            assert_eq!(interval.to_string(), text);
+            assert_eq!(Interval::parse(text), Ok(interval), "{text}");
+        }
+    }
+
+    #[test]
+    fn parses_units_abbreviations_and_iso_durations() {
+        assert_eq!(
+            Interval::parse("90 minutes"),
+            Ok(Interval::new(0, 0, hms(1, 30, 0)))
+        );
+        assert_eq!(
+            Interval::parse("@ 2 days 3 hours ago"),
+            Ok(Interval::new(0, -2, -hms(3, 0, 0)))
+        );
+        assert_eq!(Interval::parse("1.5 years"), Ok(Interval::new(18, 0, 0)));
+        assert_eq!(Interval::parse("1w 2d"), Ok(Interval::new(0, 9, 0)));
+        assert_eq!(
+            Interval::parse("45"),
+            Ok(Interval::new(0, 0, hms(0, 0, 45)))
+        );
+        assert_eq!(
+            Interval::parse("P1Y2M3DT4H5M6S"),
+            Ok(Interval::new(14, 3, hms(4, 5, 6)))
+        );
+        assert_eq!(Interval::parse("PT0.5S"), Ok(Interval::new(0, 0, 500_000)));
+    }
+
+    #[test]
+    fn rejects_malformed_intervals() {
+        for text in [
+            "",
+            "abc",
+            "1 fortnight",
+            "12:60",
+            "1:2:3:4",
+            "P",
+            "P1X",
+            "days",
+        ] {
+            assert!(Interval::parse(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn orders_by_approximate_length() {
+        let month = Interval::new(1, 0, 0);
+        let thirty_one_days = Interval::new(0, 31, 0);
+        let day = Interval::new(0, 1, 0);
+        let twenty_five_hours = Interval::new(0, 0, hms(25, 0, 0));
+
+        assert!(month < thirty_one_days);
+        assert!(day < twenty_five_hours);
+        assert_eq!(month.cmp(&Interval::new(0, 30, 0)), Ordering::Greater);
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod error;
 pub(crate) mod error_formatter;
+pub(crate) mod interval;
 pub(crate) mod log_err;
 pub(crate) mod shutdown;
 pub(crate) mod task;
@@ -8,13 +9,16 @@ pub(crate) mod value;
 
 pub use error::DbError;
 pub use error_formatter::{
-    ConnectionErrorFormatter, DefaultErrorFormatter, ErrorLocation, FormattedError,
-    QueryErrorFormatter, sanitize_uri,
+    ConnectionErrorFix, ConnectionErrorFormatter, ConnectionFixTarget, DefaultErrorFormatter,
+    ErrorLocation, ErrorSeverity, FormattedError, QueryErrorFormatter, sanitize_uri,
 };
+pub use interval::Interval;
 pub use log_err::LogErr;
 pub use shutdown::{ShutdownCoordinator, ShutdownPhase};
 pub use task::{
-    CancelToken, TaskId, TaskKind, TaskManager, TaskSlot, TaskSnapshot, TaskStatus, TaskTarget,
+    CONCURRENT_CONNECTION_CONCURRENCY, CancelToken, DEFAULT_CONNECTION_CONCURRENCY, ProgressUnit,
+    TaskAdmission, TaskId, TaskKind, TaskManager, TaskSlot, TaskSnapshot, TaskStatus, TaskTarget,
+    TaskUnits,
 };
 pub use traits::{
     CodeGenScope, CodeGeneratorInfo, Connection, ConnectionExt, ConnectionOverrides, DbDriver,
@@ -23,3 +27,4 @@ pub use traits::{
     SchemaLoadingStrategy, SchemaObjectKind, SourceContextSpec, SourceQueryMode,
 };
 pub use value::Value;
+pub(crate) use value::is_plain_decimal;
@@ -13,6 +13,9 @@ pub enum ShutdownPhase {
     FlushingLogs = 4,
     Complete = 5,
     Failed = 6,
+    /// The user is being asked what to do with unsaved changes; shutdown has
+    /// not started and can still be called off.
+    ConfirmingUnsaved = 7,
 }
 
 impl ShutdownPhase {
@@ -24,6 +27,7 @@ impl ShutdownPhase {
             3 => ShutdownPhase::ClosingConnections,
             4 => ShutdownPhase::FlushingLogs,
             5 => ShutdownPhase::Complete,
+            7 => ShutdownPhase::ConfirmingUnsaved,
             _ => ShutdownPhase::Failed,
         }
     }
@@ -38,6 +42,7 @@ impl ShutdownPhase {
             ShutdownPhase::FlushingLogs => "Flushing logs...",
             ShutdownPhase::Complete => "Shutdown complete",
             ShutdownPhase::Failed => "Shutdown failed",
+            ShutdownPhase::ConfirmingUnsaved => "Waiting for unsaved changes...",
         }
     }
 
@@ -91,6 +96,41 @@ impl ShutdownCoordinator {
         }
     }
 
+    /// Enter `ConfirmingUnsaved` before requesting shutdown, while the user
+    /// decides about unsaved changes.
+    ///
+    /// Returns `true` if this call started the confirmation, `false` if one
+    /// is already open or shutdown is under way.
+    pub fn begin_confirmation(&self) -> bool {
+        self.phase
+            .compare_exchange(
+                ShutdownPhase::NotStarted as u8,
+                ShutdownPhase::ConfirmingUnsaved as u8,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+    }
+
+    /// Call off a pending confirmation; the application keeps running.
+    ///
+    /// Returns `true` if a confirmation was pending.
+    pub fn cancel_confirmation(&self) -> bool {
+        self.phase
+            .compare_exchange(
+                ShutdownPhase::ConfirmingUnsaved as u8,
+                ShutdownPhase::NotStarted as u8,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+    }
+
+    /// Check if the user is being asked about unsaved changes.
+    pub fn is_confirming(&self) -> bool {
+        self.phase() == ShutdownPhase::ConfirmingUnsaved
+    }
+
     /// Check if shutdown has been requested.
     pub fn is_shutdown_requested(&self) -> bool {
         self.shutdown_requested.load(Ordering::SeqCst)
@@ -208,6 +248,28 @@ mod tests {
         assert!(coord.is_complete());
     }
 
+    #[test]
+    fn confirmation_precedes_shutdown_and_can_be_cancelled() {
+        let coord = ShutdownCoordinator::new();
+
+        assert!(coord.begin_confirmation());
+        assert!(!coord.begin_confirmation());
+        assert!(coord.is_confirming());
+        assert!(!coord.is_shutdown_requested());
+        assert!(!coord.phase().is_active());
+
+        assert!(coord.cancel_confirmation());
+        assert!(!coord.cancel_confirmation());
+        assert_eq!(coord.phase(), ShutdownPhase::NotStarted);
+
+        assert!(coord.begin_confirmation());
+        assert!(coord.request_shutdown());
+        assert_eq!(coord.phase(), ShutdownPhase::SignalSent);
+        assert!(!coord.is_confirming());
+        assert!(!coord.begin_confirmation());
+        assert!(!coord.cancel_confirmation());
+    }
+
     #[test]
     fn clone_shares_state() {
         let coord1 = ShutdownCoordinator::new();
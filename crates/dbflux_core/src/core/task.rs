@@ -1,15 +1,28 @@
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
 use std::time::Instant;
 
+use futures::channel::oneshot;
 use uuid::Uuid;
 
 use crate::HookPhase;
+use crate::schema::types::{format_byte_size, group_thousands};
 
 const MAX_TASK_DETAILS_BYTES: usize = 4 * 1024 * 1024;
 const TASK_DETAILS_TRUNCATED_NOTICE: &str = "\n[output truncated]\n";
 
+/// Queued tasks that may run at once on a connection whose driver handles
+/// one request at a time.
+pub const DEFAULT_CONNECTION_CONCURRENCY: usize = 1;
+
+/// Queued tasks that may run at once on a connection whose driver serves
+/// concurrent requests (`DriverCapabilities::CONCURRENT_REQUESTS`).
+pub const CONCURRENT_CONNECTION_CONCURRENCY: usize = 4;
+
 pub type TaskId = Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,10 +44,16 @@ pub enum TaskKind {
     LoadSchema,
     SchemaRefresh,
     SchemaDrop,
+    /// Renaming a table or column from the sidebar.
+    SchemaRename,
     Export,
     KeyScan,
     KeyGet,
     KeyMutation,
+    /// Cross-table search for a text in the rows of a database.
+    DataSearch,
+    /// Index suggestions drawn from a connection's slow queries.
+    IndexAdvice,
 }
 
 impl TaskKind {
@@ -48,16 +67,79 @@ impl TaskKind {
             TaskKind::LoadSchema => "Load Schema",
             TaskKind::SchemaRefresh => "Schema Refresh",
             TaskKind::SchemaDrop => "Schema Drop",
+            TaskKind::SchemaRename => "Schema Rename",
             TaskKind::Export => "Export",
             TaskKind::KeyScan => "Key Scan",
             TaskKind::KeyGet => "Key Get",
             TaskKind::KeyMutation => "Key Mutation",
+            TaskKind::DataSearch => "Data Search",
+            TaskKind::IndexAdvice => "Index Advice",
+        }
+    }
+}
+
+/// What the counters of a task's [`TaskUnits`] count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressUnit {
+    Rows,
+    Statements,
+    Bytes,
+    Tables,
+}
+
+impl ProgressUnit {
+    /// `1 row`, `12,400 rows`, `3.1 MB`.
+    pub fn format_count(self, count: u64) -> String {
+        match self {
+            ProgressUnit::Bytes => format_byte_size(count),
+            _ => format!("{} {}", group_thousands(count), self.noun(count == 1)),
+        }
+    }
+
+    /// `1,200 / 5,000 rows`, `1.2 MB / 3.1 MB`.
+    pub fn format_fraction(self, done: u64, total: u64) -> String {
+        match self {
+            ProgressUnit::Bytes => {
+                format!("{} / {}", format_byte_size(done), format_byte_size(total))
+            }
+            _ => format!("{} / {}", group_thousands(done), self.format_count(total)),
+        }
+    }
+
+    /// `850 rows/s`, `1.5 tables/s`, `2.4 MB/s`.
+    pub fn format_rate(self, per_sec: f64) -> String {
+        match self {
+            ProgressUnit::Bytes => format!("{}/s", format_byte_size(per_sec as u64)),
+            _ if per_sec < 10.0 => format!("{:.1} {}/s", per_sec, self.noun(false)),
+            _ => format!("{} {}/s", group_thousands(per_sec as u64), self.noun(false)),
+        }
+    }
+
+    fn noun(self, singular: bool) -> &'static str {
+        match (self, singular) {
+            (ProgressUnit::Rows, true) => "row",
+            (ProgressUnit::Rows, false) => "rows",
+            (ProgressUnit::Statements, true) => "statement",
+            (ProgressUnit::Statements, false) => "statements",
+            (ProgressUnit::Bytes, _) => "bytes",
+            (ProgressUnit::Tables, true) => "table",
+            (ProgressUnit::Tables, false) => "tables",
         }
     }
 }
 
+/// Units of work a task has done, out of a total when it is known up front.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskUnits {
+    pub unit: ProgressUnit,
+    pub done: u64,
+    pub total: Option<u64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskStatus {
+    /// Waiting for a free slot on its connection.
+    Queued,
     Running,
     Completed,
     Failed(String),
@@ -66,7 +148,23 @@ pub enum TaskStatus {
 
 impl TaskStatus {
     pub fn is_terminal(&self) -> bool {
-        !matches!(self, TaskStatus::Running)
+        !matches!(self, TaskStatus::Queued | TaskStatus::Running)
+    }
+}
+
+/// Resolves once a queued task may start: `true` when it was admitted,
+/// `false` when it was cancelled (or dropped) while still waiting.
+pub struct TaskAdmission {
+    receiver: oneshot::Receiver<()>,
+}
+
+impl Future for TaskAdmission {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.is_ok())
     }
 }
 
@@ -116,9 +214,13 @@ pub struct Task {
     pub started_at: Instant,
     pub completed_at: Option<Instant>,
     pub progress: Option<f32>,
+    pub units: Option<TaskUnits>,
     pub details: Option<String>,
     pub profile_id: Option<Uuid>,
     pub target: Option<TaskTarget>,
+    /// Started through the queue, so it holds one of its connection's slots
+    /// while running.
+    queued: bool,
     cancel_token: CancelToken,
 }
 
@@ -135,7 +237,11 @@ impl Task {
     }
 
     pub fn is_cancellable(&self) -> bool {
-        self.status == TaskStatus::Running
+        matches!(self.status, TaskStatus::Queued | TaskStatus::Running)
+    }
+
+    fn holds_slot_on(&self, profile_id: Uuid) -> bool {
+        self.queued && self.status == TaskStatus::Running && self.profile_id == Some(profile_id)
     }
 }
 
@@ -147,12 +253,53 @@ pub struct TaskSnapshot {
     pub status: TaskStatus,
     pub elapsed_secs: f64,
     pub progress: Option<f32>,
+    pub units: Option<TaskUnits>,
     pub details: Option<String>,
     pub is_cancellable: bool,
     pub profile_id: Option<Uuid>,
     pub target: Option<TaskTarget>,
 }
 
+impl TaskSnapshot {
+    /// Units done per second since the task started.
+    pub fn throughput(&self) -> Option<f64> {
+        let units = self.units?;
+        (units.done > 0 && self.elapsed_secs > 0.0).then(|| units.done as f64 / self.elapsed_secs)
+    }
+
+    /// Seconds left at the current throughput. Only for running tasks whose
+    /// total is known.
+    pub fn eta_secs(&self) -> Option<f64> {
+        if self.status != TaskStatus::Running {
+            return None;
+        }
+
+        let units = self.units?;
+        let remaining = units.total?.saturating_sub(units.done);
+        let throughput = self.throughput()?;
+        Some(remaining as f64 / throughput)
+    }
+
+    /// `1,200 / 5,000 rows · 850 rows/s`, or `None` without units.
+    pub fn units_summary(&self) -> Option<String> {
+        let units = self.units?;
+
+        let mut summary = match units.total {
+            Some(total) => units.unit.format_fraction(units.done, total),
+            None => units.unit.format_count(units.done),
+        };
+
+        if self.status == TaskStatus::Running
+            && let Some(throughput) = self.throughput()
+        {
+            summary.push_str(" · ");
+            summary.push_str(&units.unit.format_rate(throughput));
+        }
+
+        Some(summary)
+    }
+}
+
 impl From<&Task> for TaskSnapshot {
     fn from(task: &Task) -> Self {
         Self {
@@ -162,6 +309,7 @@ impl From<&Task> for TaskSnapshot {
             status: task.status.clone(),
             elapsed_secs: task.elapsed().as_secs_f64(),
             progress: task.progress,
+            units: task.units,
             details: task.details.clone(),
             is_cancellable: task.is_cancellable(),
             profile_id: task.profile_id,
@@ -173,13 +321,18 @@ impl From<&Task> for TaskSnapshot {
 #[derive(Default)]
 pub struct TaskManager {
     tasks: HashMap<TaskId, Task>,
+    /// Queued tasks of every connection, in admission order.
+    queue: Vec<TaskId>,
+    /// Signals the waiting caller when a queued task is admitted.
+    admissions: HashMap<TaskId, oneshot::Sender<()>>,
+    /// Per-connection limit on running queued tasks; connections without an
+    /// entry use `DEFAULT_CONNECTION_CONCURRENCY`.
+    concurrency_limits: HashMap<Uuid, usize>,
 }
 
 impl TaskManager {
     pub fn new() -> Self {
-        Self {
-            tasks: HashMap::new(),
-        }
+        Self::default()
     }
 
     pub fn start(
@@ -208,9 +361,11 @@ impl TaskManager {
             started_at: Instant::now(),
             completed_at: None,
             progress: None,
+            units: None,
             details: None,
             profile_id,
             target,
+            queued: false,
             cancel_token: cancel_token.clone(),
         };
 
@@ -218,28 +373,150 @@ impl TaskManager {
         (id, cancel_token)
     }
 
-    pub fn complete(&mut self, id: TaskId) {
-        if let Some(task) = self.tasks.get_mut(&id)
-            && task.status == TaskStatus::Running
-        {
-            task.status = TaskStatus::Completed;
-            task.completed_at = Some(Instant::now());
+    /// Starts a task that waits its turn on the target connection: it runs
+    /// right away while fewer than the connection's concurrency limit of
+    /// queued tasks are running there, and stays `Queued` otherwise.
+    ///
+    /// The caller awaits the returned [`TaskAdmission`] before doing the
+    /// work and bails out when it resolves to `false`.
+    pub fn enqueue_for_target(
+        &mut self,
+        kind: TaskKind,
+        description: impl Into<String>,
+        target: TaskTarget,
+    ) -> (TaskId, CancelToken, TaskAdmission) {
+        let profile_id = target.profile_id;
+        let (id, cancel_token) = self.start_for_target(kind, description, Some(target));
+        let (sender, receiver) = oneshot::channel();
+
+        if let Some(task) = self.tasks.get_mut(&id) {
+            task.queued = true;
+            task.status = TaskStatus::Queued;
         }
+        self.queue.push(id);
+        self.admissions.insert(id, sender);
+        self.admit_queued(profile_id);
+
+        (id, cancel_token, TaskAdmission { receiver })
     }
 
-    pub fn complete_with_details(&mut self, id: TaskId, details: impl Into<String>) {
-        if let Some(task) = self.tasks.get_mut(&id)
-            && task.status == TaskStatus::Running
-        {
-            task.status = TaskStatus::Completed;
-            task.completed_at = Some(Instant::now());
-
-            let details = details.into();
-            task.details = if details.trim().is_empty() {
-                None
-            } else {
-                Some(details)
+    pub fn concurrency_limit(&self, profile_id: Uuid) -> usize {
+        self.concurrency_limits
+            .get(&profile_id)
+            .copied()
+            .unwrap_or(DEFAULT_CONNECTION_CONCURRENCY)
+    }
+
+    /// Sets how many queued tasks may run at once on a connection (at least
+    /// one), admitting waiting tasks if the limit grew.
+    pub fn set_concurrency_limit(&mut self, profile_id: Uuid, limit: usize) {
+        self.concurrency_limits.insert(profile_id, limit.max(1));
+        self.admit_queued(profile_id);
+    }
+
+    /// Tasks waiting for a slot, in the order they will be admitted.
+    pub fn queued_tasks(&self) -> Vec<TaskSnapshot> {
+        self.queue
+            .iter()
+            .filter_map(|id| self.tasks.get(id))
+            .map(TaskSnapshot::from)
+            .collect()
+    }
+
+    /// Swaps a queued task with its neighbour on the same connection, one
+    /// step earlier (`earlier = true`) or later in the queue. Returns `false`
+    /// when it is not queued or already first/last.
+    pub fn move_queued(&mut self, id: TaskId, earlier: bool) -> bool {
+        let Some(position) = self.queue.iter().position(|queued| *queued == id) else {
+            return false;
+        };
+        let profile_id = self.tasks.get(&id).and_then(|task| task.profile_id);
+        let same_connection =
+            |other: &TaskId| self.tasks.get(other).and_then(|task| task.profile_id) == profile_id;
+
+        let neighbour = if earlier {
+            self.queue[..position].iter().rposition(same_connection)
+        } else {
+            self.queue[position + 1..]
+                .iter()
+                .position(same_connection)
+                .map(|offset| position + 1 + offset)
+        };
+
+        match neighbour {
+            Some(neighbour) => {
+                self.queue.swap(position, neighbour);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Runs queued tasks of `profile_id`, oldest first, while the connection
+    /// has free slots.
+    fn admit_queued(&mut self, profile_id: Uuid) {
+        let limit = self.concurrency_limit(profile_id);
+
+        loop {
+            let running = self
+                .tasks
+                .values()
+                .filter(|task| task.holds_slot_on(profile_id))
+                .count();
+            if running >= limit {
+                return;
+            }
+
+            let Some(position) = self.queue.iter().position(|id| {
+                self.tasks
+                    .get(id)
+                    .is_some_and(|task| task.profile_id == Some(profile_id))
+            }) else {
+                return;
             };
+
+            let id = self.queue.remove(position);
+            if let Some(task) = self.tasks.get_mut(&id) {
+                task.status = TaskStatus::Running;
+                task.started_at = Instant::now();
+            }
+            if let Some(sender) = self.admissions.remove(&id) {
+                let _ = sender.send(());
+            }
+        }
+    }
+
+    /// Moves an unfinished (running or queued) task to its final `status`,
+    /// taking it out of the queue or handing its connection slot to the next
+    /// queued task. Returns the task, or `None` if it had already finished.
+    fn finish(&mut self, id: TaskId, status: TaskStatus) -> Option<&mut Task> {
+        let task = self
+            .tasks
+            .get_mut(&id)
+            .filter(|task| !task.status.is_terminal())?;
+
+        let was_queued = task.status == TaskStatus::Queued;
+        let freed_slot = task.profile_id.filter(|_| task.queued && !was_queued);
+        task.status = status;
+        task.completed_at = Some(Instant::now());
+
+        if was_queued {
+            self.queue.retain(|queued| *queued != id);
+            self.admissions.remove(&id);
+        } else if let Some(profile_id) = freed_slot {
+            self.admit_queued(profile_id);
+        }
+
+        self.tasks.get_mut(&id)
+    }
+
+    pub fn complete(&mut self, id: TaskId) {
+        self.finish(id, TaskStatus::Completed);
+    }
+
+    pub fn complete_with_details(&mut self, id: TaskId, details: impl Into<String>) {
+        if let Some(task) = self.finish(id, TaskStatus::Completed) {
+            task.details = non_blank(details.into());
         }
     }
 
@@ -267,12 +544,7 @@ impl TaskManager {
     }
 
     pub fn fail(&mut self, id: TaskId, error: impl Into<String>) {
-        if let Some(task) = self.tasks.get_mut(&id)
-            && task.status == TaskStatus::Running
-        {
-            task.status = TaskStatus::Failed(error.into());
-            task.completed_at = Some(Instant::now());
-        }
+        self.finish(id, TaskStatus::Failed(error.into()));
     }
 
     pub fn fail_with_details(
@@ -281,42 +553,38 @@ impl TaskManager {
         error: impl Into<String>,
         details: impl Into<String>,
     ) {
-        if let Some(task) = self.tasks.get_mut(&id)
-            && task.status == TaskStatus::Running
-        {
-            task.status = TaskStatus::Failed(error.into());
-            task.completed_at = Some(Instant::now());
-
-            let details = details.into();
-            task.details = if details.trim().is_empty() {
-                None
-            } else {
-                Some(details)
-            };
+        if let Some(task) = self.finish(id, TaskStatus::Failed(error.into())) {
+            task.details = non_blank(details.into());
         }
     }
 
+    /// Cancels a running or queued task. A queued task leaves the queue and
+    /// its admission resolves to `false`.
     pub fn cancel(&mut self, id: TaskId) -> bool {
-        if let Some(task) = self.tasks.get_mut(&id)
-            && task.status == TaskStatus::Running
-        {
-            task.cancel_token.cancel();
-            task.status = TaskStatus::Cancelled;
-            task.completed_at = Some(Instant::now());
-            return true;
+        match self.finish(id, TaskStatus::Cancelled) {
+            Some(task) => {
+                task.cancel_token.cancel();
+                true
+            }
+            None => false,
         }
-        false
     }
 
-    /// Cancel all running tasks.
+    /// Cancel all running and queued tasks.
     ///
     /// Returns the number of tasks that were actually cancelled.
     pub fn cancel_all(&mut self) -> usize {
+        // Queued tasks go first so none is admitted by a cancelled slot.
         let running_ids: Vec<TaskId> = self
-            .tasks
+            .queue
             .iter()
-            .filter(|(_, t)| t.status == TaskStatus::Running)
-            .map(|(id, _)| *id)
+            .copied()
+            .chain(
+                self.tasks
+                    .iter()
+                    .filter(|(_, t)| t.status == TaskStatus::Running)
+                    .map(|(id, _)| *id),
+            )
             .collect();
 
         let mut cancelled_count = 0;
@@ -328,6 +596,35 @@ impl TaskManager {
         cancelled_count
     }
 
+    /// Running and queued tasks bound to `profile_id`.
+    pub fn active_tasks_for_profile(&self, profile_id: Uuid) -> Vec<TaskSnapshot> {
+        self.tasks
+            .values()
+            .filter(|t| !t.status.is_terminal() && t.profile_id == Some(profile_id))
+            .map(TaskSnapshot::from)
+            .collect()
+    }
+
+    /// Cancels every running and queued task bound to `profile_id`.
+    ///
+    /// Returns the number of tasks that were actually cancelled.
+    pub fn cancel_for_profile(&mut self, profile_id: Uuid) -> usize {
+        // Queued tasks go first so none is admitted by a cancelled slot.
+        let ids: Vec<TaskId> = self
+            .queue
+            .iter()
+            .copied()
+            .chain(self.tasks.keys().copied())
+            .filter(|id| {
+                self.tasks
+                    .get(id)
+                    .is_some_and(|task| task.profile_id == Some(profile_id))
+            })
+            .collect();
+
+        ids.into_iter().filter(|id| self.cancel(*id)).count()
+    }
+
     pub fn update_progress(&mut self, id: TaskId, progress: f32) {
         if let Some(task) = self.tasks.get_mut(&id)
             && task.status == TaskStatus::Running
@@ -336,6 +633,20 @@ impl TaskManager {
         }
     }
 
+    /// Records how many units a running task has done. With a known `total`
+    /// the fractional `progress` follows along.
+    pub fn update_units(&mut self, id: TaskId, unit: ProgressUnit, done: u64, total: Option<u64>) {
+        if let Some(task) = self.tasks.get_mut(&id)
+            && task.status == TaskStatus::Running
+        {
+            task.units = Some(TaskUnits { unit, done, total });
+
+            if let Some(total) = total.filter(|total| *total > 0) {
+                task.progress = Some((done as f32 / total as f32).clamp(0.0, 1.0));
+            }
+        }
+    }
+
     pub fn get(&self, id: TaskId) -> Option<TaskSnapshot> {
         self.tasks.get(&id).map(TaskSnapshot::from)
     }
@@ -382,6 +693,7 @@ impl TaskManager {
                             | TaskKind::LoadSchema
                             | TaskKind::SchemaRefresh
                             | TaskKind::SchemaDrop
+                            | TaskKind::SchemaRename
                             | TaskKind::KeyScan
                     )
             })
@@ -405,7 +717,15 @@ impl TaskManager {
     }
 
     pub fn remove(&mut self, id: TaskId) {
-        self.tasks.remove(&id);
+        self.queue.retain(|queued| *queued != id);
+        self.admissions.remove(&id);
+
+        if let Some(task) = self.tasks.remove(&id)
+            && let Some(profile_id) = task.profile_id
+            && task.holds_slot_on(profile_id)
+        {
+            self.admit_queued(profile_id);
+        }
     }
 
     pub fn current_status_message(&self) -> Option<String> {
@@ -432,6 +752,14 @@ impl TaskManager {
     }
 }
 
+fn non_blank(details: String) -> Option<String> {
+    if details.trim().is_empty() {
+        None
+    } else {
+        Some(details)
+    }
+}
+
 fn append_with_limit(target: &mut String, chunk: &str, max_bytes: usize, truncated_notice: &str) {
     if target.contains(truncated_notice) {
         return;
@@ -542,3 +870,177 @@ impl TaskSlot {
         self.active.as_ref().map(|(_, token)| token)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units_drive_progress_throughput_and_eta() {
+        let mut tasks = TaskManager::new();
+        let (id, _) = tasks.start(TaskKind::Export, "Export 12 tables");
+
+        tasks.update_units(id, ProgressUnit::Tables, 3, Some(12));
+
+        let task = tasks.get(id).expect("task exists");
+        assert_eq!(task.progress, Some(0.25));
+        assert_eq!(
+            task.units,
+            Some(TaskUnits {
+                unit: ProgressUnit::Tables,
+                done: 3,
+                total: Some(12),
+            })
+        );
+
+        let snapshot = TaskSnapshot {
+            elapsed_secs: 2.0,
+            ..task
+        };
+        assert_eq!(snapshot.throughput(), Some(1.5));
+        assert_eq!(snapshot.eta_secs(), Some(6.0));
+        assert_eq!(
+            snapshot.units_summary().as_deref(),
+            Some("3 / 12 tables · 1.5 tables/s")
+        );
+
+        tasks.complete(id);
+        tasks.update_units(id, ProgressUnit::Tables, 4, Some(12));
+        let finished = tasks.get(id).expect("task exists");
+        assert_eq!(finished.units.map(|units| units.done), Some(3));
+        assert_eq!(finished.eta_secs(), None);
+    }
+
+    fn target(profile_id: Uuid) -> TaskTarget {
+        TaskTarget {
+            profile_id,
+            database: None,
+        }
+    }
+
+    fn status(tasks: &TaskManager, id: TaskId) -> TaskStatus {
+        tasks.get(id).expect("task exists").status
+    }
+
+    #[test]
+    fn queued_tasks_wait_for_a_free_slot_on_their_connection() {
+        let mut tasks = TaskManager::new();
+        let profile = Uuid::new_v4();
+        let other_profile = Uuid::new_v4();
+
+        let (first, _, first_admission) =
+            tasks.enqueue_for_target(TaskKind::Export, "first", target(profile));
+        let (second, _, mut second_admission) =
+            tasks.enqueue_for_target(TaskKind::Export, "second", target(profile));
+        let (elsewhere, _, _) =
+            tasks.enqueue_for_target(TaskKind::Export, "elsewhere", target(other_profile));
+
+        assert_eq!(status(&tasks, first), TaskStatus::Running);
+        assert_eq!(status(&tasks, second), TaskStatus::Queued);
+        assert_eq!(status(&tasks, elsewhere), TaskStatus::Running);
+        assert!(futures::executor::block_on(first_admission));
+        assert_eq!(
+            second_admission.receiver.try_recv(),
+            Ok(None),
+            "second task is still waiting"
+        );
+
+        // A task can fail before it is admitted, e.g. when its setup fails.
+        let (third, _, third_admission) =
+            tasks.enqueue_for_target(TaskKind::Export, "third", target(profile));
+        tasks.fail(third, "not connected");
+        assert_eq!(
+            status(&tasks, third),
+            TaskStatus::Failed("not connected".into())
+        );
+        assert!(!futures::executor::block_on(third_admission));
+
+        tasks.complete(first);
+        assert_eq!(status(&tasks, second), TaskStatus::Running);
+        assert!(futures::executor::block_on(second_admission));
+        assert!(tasks.queued_tasks().is_empty());
+    }
+
+    #[test]
+    fn queued_tasks_can_be_reordered_and_cancelled() {
+        let mut tasks = TaskManager::new();
+        let profile = Uuid::new_v4();
+        let other_profile = Uuid::new_v4();
+
+        let (running, _, _) =
+            tasks.enqueue_for_target(TaskKind::Export, "running", target(profile));
+        let (a, _, _) = tasks.enqueue_for_target(TaskKind::Export, "a", target(profile));
+        let (elsewhere, _, _) =
+            tasks.enqueue_for_target(TaskKind::Export, "elsewhere", target(other_profile));
+        let (elsewhere_queued, _, _) =
+            tasks.enqueue_for_target(TaskKind::Export, "elsewhere queued", target(other_profile));
+        let (b, b_token, b_admission) =
+            tasks.enqueue_for_target(TaskKind::Export, "b", target(profile));
+
+        let order = |tasks: &TaskManager| -> Vec<TaskId> {
+            tasks.queued_tasks().iter().map(|task| task.id).collect()
+        };
+        assert_eq!(order(&tasks), vec![a, elsewhere_queued, b]);
+
+        // Moves skip over tasks of other connections.
+        assert!(tasks.move_queued(b, true));
+        assert_eq!(order(&tasks), vec![b, elsewhere_queued, a]);
+        assert!(!tasks.move_queued(b, true));
+        assert!(!tasks.move_queued(running, false));
+
+        assert!(tasks.cancel(b));
+        assert!(b_token.is_cancelled());
+        assert!(!futures::executor::block_on(b_admission));
+        assert_eq!(order(&tasks), vec![elsewhere_queued, a]);
+
+        tasks.fail(running, "boom");
+        assert_eq!(status(&tasks, a), TaskStatus::Running);
+
+        tasks.set_concurrency_limit(other_profile, 2);
+        assert_eq!(status(&tasks, elsewhere), TaskStatus::Running);
+        assert_eq!(status(&tasks, elsewhere_queued), TaskStatus::Running);
+        assert!(tasks.queued_tasks().is_empty());
+    }
+
+    #[test]
+    fn cancel_for_profile_leaves_other_connections_alone() {
+        let mut tasks = TaskManager::new();
+        let profile = Uuid::new_v4();
+        let other_profile = Uuid::new_v4();
+
+        let (query, query_token) =
+            tasks.start_for_target(TaskKind::Query, "SELECT 1", Some(target(profile)));
+        let (export, _, _) = tasks.enqueue_for_target(TaskKind::Export, "export", target(profile));
+        let (queued, _, queued_admission) =
+            tasks.enqueue_for_target(TaskKind::Export, "queued", target(profile));
+        let (elsewhere, _) =
+            tasks.start_for_target(TaskKind::Query, "SELECT 2", Some(target(other_profile)));
+        tasks.complete(query);
+        let (late_query, _) =
+            tasks.start_for_target(TaskKind::Query, "SELECT 3", Some(target(profile)));
+
+        assert_eq!(tasks.active_tasks_for_profile(profile).len(), 3);
+        assert_eq!(tasks.cancel_for_profile(profile), 3);
+
+        assert!(!query_token.is_cancelled(), "finished tasks are untouched");
+        assert_eq!(status(&tasks, export), TaskStatus::Cancelled);
+        assert_eq!(status(&tasks, queued), TaskStatus::Cancelled);
+        assert_eq!(status(&tasks, late_query), TaskStatus::Cancelled);
+        assert!(!futures::executor::block_on(queued_admission));
+        assert_eq!(status(&tasks, elsewhere), TaskStatus::Running);
+        assert!(tasks.active_tasks_for_profile(profile).is_empty());
+    }
+
+    #[test]
+    fn unit_counts_and_rates_are_human_readable() {
+        assert_eq!(ProgressUnit::Rows.format_count(1), "1 row");
+        assert_eq!(ProgressUnit::Rows.format_count(12_400), "12,400 rows");
+        assert_eq!(ProgressUnit::Bytes.format_count(3 * 1024 * 1024), "3.0 MB");
+        assert_eq!(
+            ProgressUnit::Statements.format_fraction(2, 40),
+            "2 / 40 statements"
+        );
+        assert_eq!(ProgressUnit::Rows.format_rate(850.4), "850 rows/s");
+        assert_eq!(ProgressUnit::Bytes.format_rate(2048.0), "2.0 KB/s");
+    }
+}
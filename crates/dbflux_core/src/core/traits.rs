@@ -11,8 +11,9 @@ use crate::{
     FormFieldKind, FormValues, LanguageService, NoOpCodeGenerator, QueryHandle, QueryLanguage,
     QueryRequest, QueryResult, RelationRef, RoutineInfo, RowDelete, RowInsert, RowPatch,
     SchemaForeignKeyInfo, SchemaIndexInfo, SchemaSnapshot, SemanticPlan, SemanticPlanner,
-    SemanticRequest, SqlDialect, SqlGenerationRequest, SqlLanguageService, TableBrowseRequest,
-    TableCountRequest, TableInfo, Value, ViewInfo,
+    SemanticRequest, SessionGate, SessionScope, SqlDialect, SqlGenerationRequest,
+    SqlLanguageService, TableBrowseRequest, TableCountRequest, TableInfo, TableStatistics, Value,
+    ViewInfo, WorkloadQuery,
     config::DriverKey,
     data::key_value::{
         HashDeleteRequest, HashSetRequest, KeyBulkGetRequest, KeyDeleteRequest, KeyExistsRequest,
@@ -687,6 +688,35 @@ pub trait Connection: Send + Sync {
         Ok((QueryHandle::new(), result))
     }
 
+    /// Gate this connection's `execute` enters before touching the session.
+    ///
+    /// Drivers that return one get `execute_in_session` for free. `None`
+    /// means session state cannot be kept between statements.
+    fn session_gate(&self) -> Option<&SessionGate> {
+        None
+    }
+
+    /// Execute `req` inside `scope` without letting other callers' statements
+    /// run on this connection in between.
+    fn execute_in_session(
+        &self,
+        scope: &SessionScope,
+        req: &QueryRequest,
+    ) -> Result<QueryResult, DbError> {
+        if scope.is_empty() {
+            return self.execute(req);
+        }
+
+        let Some(gate) = self.session_gate() else {
+            return Err(DbError::NotSupported(
+                "This connection cannot keep session state between statements".to_string(),
+            ));
+        };
+
+        let _session = gate.enter(|request| self.execute(request))?;
+        scope.run(gate, req, |request| self.execute(request))
+    }
+
     /// Cancel a running query using a previously returned handle.
     fn cancel(&self, handle: &QueryHandle) -> Result<(), DbError>;
 
@@ -737,6 +767,19 @@ pub trait Connection: Send + Sync {
         ))
     }
 
+    /// Fetch `schema_for_database()` for several databases, in the order given.
+    ///
+    /// The default issues at most `MAX_PARALLEL_INTROSPECTION` requests at a
+    /// time. Drivers that can list several databases in one round-trip
+    /// override this.
+    fn schemas_for_databases(&self, databases: &[String]) -> Result<Vec<DbSchemaInfo>, DbError> {
+        crate::map_bounded(databases, crate::MAX_PARALLEL_INTROSPECTION, |database| {
+            self.schema_for_database(database)
+        })
+        .into_iter()
+        .collect()
+    }
+
     /// Fetch columns and indexes for a table.
     fn table_details(
         &self,
@@ -877,6 +920,28 @@ pub trait Connection: Send + Sync {
         Ok(Vec::new())
     }
 
+    /// Fetch the approximate row count and on-disk size of `schema.table`,
+    /// preferably from the engine's catalog rather than by scanning it.
+    ///
+    /// Returns empty statistics by default.
+    fn table_statistics(
+        &self,
+        _database: &str,
+        _schema: Option<&str>,
+        _table: &str,
+    ) -> Result<TableStatistics, DbError> {
+        Ok(TableStatistics::default())
+    }
+
+    /// Fetch up to `limit` of the most time-consuming statements the server
+    /// tracked itself, such as PostgreSQL's `pg_stat_statements`.
+    ///
+    /// Returns an empty `Vec` by default, and when the server keeps no such
+    /// statistics.
+    fn query_statistics(&self, _limit: usize) -> Result<Vec<WorkloadQuery>, DbError> {
+        Ok(Vec::new())
+    }
+
     /// Fetch a single row from a table by primary-key match.
     ///
     /// Returns the row's values keyed by column name, or `None` if no row
@@ -908,37 +973,8 @@ pub trait Connection: Send + Sync {
     /// The default implementation builds SQL using `TableBrowseRequest::build_sql_with`.
     fn browse_table(&self, request: &TableBrowseRequest) -> Result<QueryResult, DbError> {
         let sql = if let Some(filter) = request.semantic_filter.as_ref() {
-            let mut sql = format!(
-                "SELECT * FROM {}",
-                request.table.quoted_with(self.dialect())
-            );
-
             let where_clause = render_semantic_filter_sql(filter, self.dialect())?;
-            sql.push_str(" WHERE ");
-            sql.push_str(&where_clause);
-
-            if !request.order_by.is_empty() {
-                sql.push_str(" ORDER BY ");
-                let quoted_cols: Vec<String> = request
-                    .order_by
-                    .iter()
-                    .map(|col| {
-                        let dir = match col.direction {
-                            crate::SortDirection::Ascending => "ASC",
-                            crate::SortDirection::Descending => "DESC",
-                        };
-                        format!("{} {}", col.column.quoted_with(self.dialect()), dir)
-                    })
-                    .collect();
-                sql.push_str(&quoted_cols.join(", "));
-            }
-
-            sql.push_str(&format!(
-                " LIMIT {} OFFSET {}",
-                request.pagination.limit(),
-                request.pagination.offset()
-            ));
-            sql
+            request.build_sql_with_where(self.dialect(), Some(&where_clause))
         } else {
             request.build_sql_with(self.dialect())
         };
@@ -1033,6 +1069,24 @@ pub trait Connection: Send + Sync {
         ))
     }
 
+    /// Starts delivering the events of `kinds` to `sink`, replacing any
+    /// earlier subscription. Returns the kinds this connection will actually
+    /// report, which may be fewer than asked for.
+    ///
+    /// The default implementation reports no events.
+    fn subscribe_events(
+        &self,
+        _kinds: &[crate::ConnectionEventKind],
+        _sink: crate::ConnectionEventSink,
+    ) -> Result<Vec<crate::ConnectionEventKind>, DbError> {
+        Ok(Vec::new())
+    }
+
+    /// Stops the current event subscription, if any.
+    fn unsubscribe_events(&self) -> Result<(), DbError> {
+        Ok(())
+    }
+
     /// Optional source-context controls exposed by this driver in query documents.
     fn source_context_spec(&self) -> Option<SourceContextSpec> {
         None
@@ -1,4 +1,5 @@
-use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use super::interval::Interval;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
@@ -21,10 +22,12 @@ pub enum Value {
     /// JSON/JSONB stored as string for exact round-trip preservation.
     Json(String),
 
-    /// Decimal stored as string to preserve exact precision.
+    /// DECIMAL/NUMERIC stored as its decimal string to preserve exact
+    /// precision; it is never converted through `f64` for display or export.
     Decimal(String),
 
-    /// Timestamp with timezone.
+    /// Timestamp with time zone, normalized to UTC. Displayed in the
+    /// configured [`DisplayTimezone`](crate::timezone::DisplayTimezone).
     DateTime(DateTime<Utc>),
 
     /// Date without time component.
@@ -45,6 +48,22 @@ pub enum Value {
 
     /// Value exists in database but this client cannot decode/render it yet.
     Unsupported(String),
+
+    /// Timestamp without time zone (wall-clock time), shown as stored.
+    ///
+    /// This and the variants below are declared after the original ones so
+    /// the indices of those in the driver RPC encoding stay stable.
+    NaiveDateTime(NaiveDateTime),
+
+    /// UUID (PostgreSQL `uuid`, SQL Server `uniqueidentifier`).
+    Uuid(uuid::Uuid),
+
+    /// Calendar interval (PostgreSQL `interval`).
+    Interval(Interval),
+
+    /// Label of an enumerated type (PostgreSQL enums, MySQL `ENUM`). Sorts
+    /// and compares like text.
+    Enum(String),
 }
 
 impl Value {
@@ -56,13 +75,20 @@ impl Value {
         self.as_display_string_truncated(1000)
     }
 
+    pub fn as_shown_string(&self) -> String {
+        self.as_shown_string_truncated(1000)
+    }
+
+    /// Text of the value with long text cut at `max_len` characters and
+    /// binary values, arrays and documents summarized. Timestamps with a time
+    /// zone are written as RFC 3339 in UTC, whatever the display time zone.
     pub fn as_display_string_truncated(&self, max_len: usize) -> String {
         match self {
             Value::Null => "NULL".to_string(),
             Value::Bool(b) => b.to_string(),
             Value::Int(i) => i.to_string(),
             Value::Float(f) => f.to_string(),
-            Value::Text(s) | Value::Json(s) | Value::Decimal(s) => {
+            Value::Text(s) | Value::Json(s) | Value::Decimal(s) | Value::Enum(s) => {
                 if s.len() <= max_len {
                     s.clone()
                 } else {
@@ -71,9 +97,14 @@ impl Value {
                 }
             }
             Value::Bytes(b) => format!("<{} bytes>", b.len()),
-            Value::DateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            Value::DateTime(dt) => dt.to_rfc3339(),
+            Value::NaiveDateTime(dt) => dt
+                .format(crate::timezone::DISPLAY_TIMESTAMP_FORMAT)
+                .to_string(),
             Value::Date(d) => d.format("%Y-%m-%d").to_string(),
             Value::Time(t) => t.format("%H:%M:%S").to_string(),
+            Value::Uuid(uuid) => uuid.hyphenated().to_string(),
+            Value::Interval(interval) => interval.to_string(),
             Value::Array(arr) => {
                 let preview = format!("[{} items]", arr.len());
                 if preview.len() <= max_len {
@@ -100,6 +131,44 @@ impl Value {
             Value::Unsupported(type_name) => format!("UNSUPPORTED<{}>", type_name),
         }
     }
+
+    /// Like [`Self::as_display_string_truncated`], but timestamps with a time
+    /// zone are shown in the configured display time zone. Only for text the
+    /// user reads; anything stored, exported or compared uses the neutral
+    /// form, so it does not change with that setting.
+    pub fn as_shown_string_truncated(&self, max_len: usize) -> String {
+        match self {
+            Value::DateTime(dt) => crate::timezone::display_timezone()
+                .convert(dt)
+                .format(crate::timezone::DISPLAY_TIMESTAMP_FORMAT)
+                .to_string(),
+            other => other.as_display_string_truncated(max_len),
+        }
+    }
+
+    /// The value as read from the server, for "Copy Raw Value": nothing is
+    /// truncated, converted to the display time zone or rounded. Binary
+    /// values are written as hex and arrays and documents as JSON; NULL is
+    /// an empty string.
+    pub fn as_raw_string(&self) -> String {
+        match self {
+            Value::Null => String::new(),
+            Value::Bool(b) => b.to_string(),
+            Value::Int(i) => i.to_string(),
+            Value::Float(f) => f.to_string(),
+            Value::Text(s) | Value::Json(s) | Value::Decimal(s) | Value::Enum(s) => s.clone(),
+            Value::Bytes(b) => b.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            Value::DateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string(),
+            Value::NaiveDateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+            Value::Date(d) => d.format("%Y-%m-%d").to_string(),
+            Value::Time(t) => t.format("%H:%M:%S%.f").to_string(),
+            Value::Uuid(uuid) => uuid.hyphenated().to_string(),
+            Value::Interval(interval) => interval.to_string(),
+            Value::ObjectId(id) => id.clone(),
+            Value::Array(_) | Value::Document(_) => self.to_json_string(),
+            Value::Unsupported(type_name) => format!("UNSUPPORTED<{}>", type_name),
+        }
+    }
 }
 
 impl std::fmt::Display for Value {
@@ -115,17 +184,19 @@ impl Value {
             Value::Int(_) => 1,
             Value::Float(_) => 2,
             Value::Decimal(_) => 3,
-            Value::Text(_) => 4,
+            Value::Text(_) | Value::Enum(_) => 4,
             Value::Json(_) => 5,
-            Value::DateTime(_) => 6,
+            Value::DateTime(_) | Value::NaiveDateTime(_) => 6,
             Value::Date(_) => 7,
             Value::Time(_) => 8,
-            Value::Bytes(_) => 9,
-            Value::ObjectId(_) => 10,
-            Value::Array(_) => 11,
-            Value::Document(_) => 12,
-            Value::Unsupported(_) => 13,
-            Value::Null => 14,
+            Value::Interval(_) => 9,
+            Value::Uuid(_) => 10,
+            Value::Bytes(_) => 11,
+            Value::ObjectId(_) => 12,
+            Value::Array(_) => 13,
+            Value::Document(_) => 14,
+            Value::Unsupported(_) => 15,
+            Value::Null => 16,
         }
     }
 
@@ -174,7 +245,7 @@ impl Value {
             Value::Bool(b) => serde_json::Value::Bool(*b),
             Value::Int(i) => serde_json::json!(*i),
             Value::Float(f) => serde_json::json!(*f),
-            Value::Text(s) => serde_json::Value::String(s.clone()),
+            Value::Text(s) | Value::Enum(s) => serde_json::Value::String(s.clone()),
             Value::Bytes(b) => {
                 let hex: String = b.iter().map(|byte| format!("{:02x}", byte)).collect();
                 serde_json::json!({"$binary": {"hex": hex}})
@@ -184,8 +255,11 @@ impl Value {
             }
             Value::Decimal(d) => serde_json::Value::String(d.clone()),
             Value::DateTime(dt) => serde_json::json!({"$date": dt.to_rfc3339()}),
+            Value::NaiveDateTime(dt) => serde_json::Value::String(dt.to_string()),
             Value::Date(d) => serde_json::Value::String(d.to_string()),
             Value::Time(t) => serde_json::Value::String(t.to_string()),
+            Value::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
+            Value::Interval(interval) => serde_json::Value::String(interval.to_string()),
             Value::Array(arr) => {
                 serde_json::Value::Array(arr.iter().map(Self::to_serde_json).collect())
             }
@@ -225,10 +299,14 @@ impl Ord for Value {
             (Text(a), Text(b)) => a.cmp(b),
             (Bytes(a), Bytes(b)) => a.cmp(b),
             (Json(a), Json(b)) => a.cmp(b),
-            (Decimal(a), Decimal(b)) => a.cmp(b),
+            (Decimal(a), Decimal(b)) => cmp_decimal(a, b),
             (DateTime(a), DateTime(b)) => a.cmp(b),
+            (NaiveDateTime(a), NaiveDateTime(b)) => a.cmp(b),
             (Date(a), Date(b)) => a.cmp(b),
             (Time(a), Time(b)) => a.cmp(b),
+            (Uuid(a), Uuid(b)) => a.cmp(b),
+            (Interval(a), Interval(b)) => a.cmp(b),
+            (Enum(a), Enum(b)) => a.cmp(b),
             (ObjectId(a), ObjectId(b)) => a.cmp(b),
             (Unsupported(a), Unsupported(b)) => a.cmp(b),
             (Array(a), Array(b)) => a.cmp(b),
@@ -237,6 +315,17 @@ impl Ord for Value {
             // Cross-type numeric promotion
             (Int(a), Float(b)) => (*a as f64).total_cmp(b),
             (Float(a), Int(b)) => a.total_cmp(&(*b as f64)),
+            (Decimal(a), Int(b)) => cmp_decimal(a, &b.to_string()),
+            (Int(a), Decimal(b)) => cmp_decimal(&a.to_string(), b),
+            (Decimal(a), Float(b)) => decimal_to_f64(a).total_cmp(b),
+            (Float(a), Decimal(b)) => a.total_cmp(&decimal_to_f64(b)),
+
+            // Timestamps without a time zone compare as if they were UTC
+            (DateTime(a), NaiveDateTime(b)) => a.naive_utc().cmp(b),
+            (NaiveDateTime(a), DateTime(b)) => a.cmp(&b.naive_utc()),
+
+            // Enum labels compare with text as strings
+            (Enum(a), Text(b)) | (Text(a), Enum(b)) => a.cmp(b),
 
             // Different types: fallback to type order
             _ => self.type_order().cmp(&other.type_order()),
@@ -245,3 +334,232 @@ impl Ord for Value {
 }
 
 impl Eq for Value {}
+
+/// Whether `s` is a plain decimal literal (optional sign, digits, optional
+/// fraction) that SQL can take unquoted. `NaN`, `Infinity` and exponent
+/// notation are not.
+pub(crate) fn is_plain_decimal(s: &str) -> bool {
+    DecimalParts::parse(s).is_some()
+}
+
+/// Compares two decimal strings by numeric value without going through
+/// `f64`, so `"10.10"` sorts after `"9.99"` and large values keep their
+/// precision. Special values (`NaN`, `Infinity`) and exponent notation fall
+/// back to an `f64` comparison.
+fn cmp_decimal(a: &str, b: &str) -> Ordering {
+    let (Some(a), Some(b)) = (DecimalParts::parse(a), DecimalParts::parse(b)) else {
+        return decimal_to_f64(a).total_cmp(&decimal_to_f64(b));
+    };
+
+    match (a.negative, b.negative) {
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (false, false) => a.cmp_magnitude(&b),
+        (true, true) => b.cmp_magnitude(&a),
+    }
+}
+
+fn decimal_to_f64(s: &str) -> f64 {
+    s.trim().parse().unwrap_or(f64::NAN)
+}
+
+/// A plain decimal literal split into sign, integer and fraction digits, with
+/// leading and trailing zeros removed.
+struct DecimalParts<'a> {
+    negative: bool,
+    integer: &'a str,
+    fraction: &'a str,
+}
+
+impl<'a> DecimalParts<'a> {
+    fn parse(s: &'a str) -> Option<Self> {
+        let s = s.trim();
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let integer = integer.trim_start_matches('0');
+        let fraction = fraction.trim_end_matches('0');
+
+        Some(Self {
+            // Negative zero compares equal to zero.
+            negative: negative && !(integer.is_empty() && fraction.is_empty()),
+            integer,
+            fraction,
+        })
+    }
+
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        self.integer
+            .len()
+            .cmp(&other.integer.len())
+            .then_with(|| self.integer.cmp(other.integer))
+            .then_with(|| self.fraction.cmp(other.fraction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(s: &str) -> Value {
+        Value::Decimal(s.to_string())
+    }
+
+    #[test]
+    fn decimals_sort_by_numeric_value() {
+        let mut values = vec![
+            decimal("10.10"),
+            decimal("-0.5"),
+            decimal("9.99"),
+            decimal("123456789012345678901234567890.01"),
+            decimal("-12"),
+            decimal("0.000"),
+        ];
+        values.sort();
+
+        assert_eq!(
+            values,
+            vec![
+                decimal("-12"),
+                decimal("-0.5"),
+                decimal("0.000"),
+                decimal("9.99"),
+                decimal("10.10"),
+                decimal("123456789012345678901234567890.01"),
+            ]
+        );
+    }
+
+    #[test]
+    fn decimals_compare_exactly_beyond_f64_precision() {
+        assert_eq!(
+            decimal("0.30000000000000000001").cmp(&decimal("0.3")),
+            Ordering::Greater
+        );
+        assert_eq!(decimal("1.50").cmp(&decimal("1.5")), Ordering::Equal);
+        assert_eq!(decimal("-0").cmp(&decimal("0")), Ordering::Equal);
+    }
+
+    #[test]
+    fn decimals_compare_with_other_numbers() {
+        assert_eq!(decimal("42.00").cmp(&Value::Int(42)), Ordering::Equal);
+        assert_eq!(Value::Int(9).cmp(&decimal("10.5")), Ordering::Less);
+        assert_eq!(decimal("2.5").cmp(&Value::Float(2.25)), Ordering::Greater);
+        assert_eq!(decimal("NaN").cmp(&decimal("Infinity")), Ordering::Greater);
+    }
+
+    #[test]
+    fn naive_timestamps_keep_their_wall_clock_and_sort_with_utc_ones() {
+        let naive = NaiveDate::from_ymd_opt(2026, 5, 1)
+            .and_then(|date| date.and_hms_opt(9, 30, 0))
+            .expect("valid timestamp");
+        let instant = DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc);
+
+        let value = Value::NaiveDateTime(naive);
+        assert_eq!(value.as_display_string(), "2026-05-01 09:30:00");
+        assert_eq!(value.cmp(&Value::DateTime(instant)), Ordering::Equal);
+        assert_eq!(
+            Value::DateTime(instant + chrono::Duration::seconds(1)).cmp(&value),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn only_the_shown_text_follows_the_display_time_zone() {
+        use crate::timezone::{DisplayTimezone, set_display_timezone};
+        use chrono::FixedOffset;
+
+        let instant = NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|date| date.and_hms_opt(22, 15, 0))
+            .expect("valid timestamp")
+            .and_utc();
+        let value = Value::DateTime(instant);
+
+        set_display_timezone(DisplayTimezone::Offset(
+            FixedOffset::east_opt(2 * 3600).expect("valid offset"),
+        ));
+        let display = value.as_display_string();
+        let shown = value.as_shown_string_truncated(usize::MAX);
+        set_display_timezone(DisplayTimezone::Utc);
+
+        assert_eq!(display, "2026-03-01T22:15:00+00:00");
+        assert_eq!(shown, "2026-03-02 00:15:00");
+    }
+
+    #[test]
+    fn decimals_display_and_serialize_without_rounding() {
+        let money = decimal("19999999999999999.99");
+
+        assert_eq!(money.as_display_string(), "19999999999999999.99");
+        assert_eq!(money.to_json_string(), "\"19999999999999999.99\"");
+    }
+
+    #[test]
+    fn raw_strings_keep_full_text_bytes_and_precision() {
+        let long = "x".repeat(5000);
+        assert_eq!(Value::Text(long.clone()).as_raw_string(), long);
+        assert_eq!(
+            Value::Bytes(vec![0xde, 0xad, 0x01]).as_raw_string(),
+            "dead01"
+        );
+        assert_eq!(Value::Null.as_raw_string(), "");
+
+        let instant = NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|date| date.and_hms_micro_opt(22, 15, 0, 123_456))
+            .map(|naive| DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc))
+            .expect("valid timestamp");
+        assert_eq!(
+            Value::DateTime(instant).as_raw_string(),
+            "2026-03-01 22:15:00.123456+00:00"
+        );
+
+        let document = Value::Document(BTreeMap::from([(
+            "tags".to_string(),
+            Value::Array(vec![Value::Int(1)]),
+        )]));
+        assert_eq!(document.as_raw_string(), r#"{"tags":[1]}"#);
+    }
+
+    #[test]
+    fn uuids_intervals_and_enums_display_and_sort_by_their_type() {
+        let low = uuid::Uuid::from_u128(1);
+        let high = uuid::Uuid::from_u128(u128::MAX);
+        assert_eq!(Value::Uuid(low).cmp(&Value::Uuid(high)), Ordering::Less);
+        assert_eq!(
+            Value::Uuid(low).as_display_string(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+
+        let month = Value::Interval(Interval::new(1, 0, 0));
+        let week = Value::Interval(Interval::new(0, 7, 0));
+        assert_eq!(month.cmp(&week), Ordering::Greater);
+        assert_eq!(month.to_json_string(), "\"1 mon\"");
+
+        let mut labels = vec![
+            Value::Enum("pending".to_string()),
+            Value::Text("archived".to_string()),
+            Value::Enum("active".to_string()),
+        ];
+        labels.sort();
+        assert_eq!(
+            labels,
+            vec![
+                Value::Enum("active".to_string()),
+                Value::Text("archived".to_string()),
+                Value::Enum("pending".to_string()),
+            ]
+        );
+    }
+}
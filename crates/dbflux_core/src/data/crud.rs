@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    Row, SemanticFilter, Value,
+    Interval, Row, SemanticFilter, Value,
     data::key_value::{
         HashDeleteRequest, HashSetRequest, KeyDeleteRequest, KeySetRequest, ListPushRequest,
         ListRemoveRequest, ListSetRequest, SetAddRequest, SetRemoveRequest, StreamAddRequest,
@@ -117,6 +117,27 @@ impl ColumnAssignment {
         self.type_name = type_name;
         self
     }
+    /// Checks an edited text value against the column type for types whose
+    /// syntax is known here (UUIDs and intervals) and converts it to the
+    /// matching [`Value`] variant. Other values are returned unchanged.
+    pub fn validated(mut self) -> Result<Self, String> {
+        let (Value::Text(text), Some(type_name)) = (&self.value, &self.type_name) else {
+            return Ok(self);
+        };
+
+        let type_name = type_name.trim().to_ascii_lowercase();
+
+        if matches!(type_name.as_str(), "uuid" | "uniqueidentifier") {
+            let uuid = uuid::Uuid::parse_str(text.trim())
+                .map_err(|_| format!("{}: \"{}\" is not a valid UUID", self.name, text))?;
+            self.value = Value::Uuid(uuid);
+        } else if type_name.starts_with("interval") {
+            let interval = Interval::parse(text).map_err(|e| format!("{}: {}", self.name, e))?;
+            self.value = Value::Interval(interval);
+        }
+
+        Ok(self)
+    }
 }
 
 impl From<(String, Value)> for ColumnAssignment {
@@ -0,0 +1,364 @@
+//! Formats numbers, dates and timestamps are displayed in.
+//!
+//! Result grids and the status bar show values with the digit grouping,
+//! decimal places, date/time patterns and scientific-notation threshold
+//! picked in the settings. Editing a cell always starts from the stored
+//! value, and copies and exports keep it too unless
+//! [`DisplayFormat::formatted_output`] asks for the displayed text.
+
+use crate::Value;
+use crate::timezone::DISPLAY_TIMESTAMP_FORMAT;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Write};
+use std::sync::{Arc, LazyLock, RwLock};
+
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+pub const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S";
+
+/// How numbers, dates and timestamps are shown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayFormat {
+    /// Placed between groups of three integer digits; `None` leaves digits
+    /// ungrouped. With `.` as the separator the decimal mark becomes `,`.
+    pub thousands_separator: Option<char>,
+
+    /// Fraction digits shown for floats and decimals; `None` shows them as
+    /// stored.
+    pub decimal_places: Option<u8>,
+
+    /// strftime-style patterns for dates, times and timestamps.
+    pub date_format: String,
+    pub time_format: String,
+    pub timestamp_format: String,
+
+    /// Floats at or above `10^n`, or non-zero and below `10^-n`, are shown
+    /// in scientific notation; `None` never uses it.
+    pub scientific_threshold: Option<u8>,
+
+    /// Copies and exports write values as displayed instead of as stored.
+    pub formatted_output: bool,
+}
+
+impl Default for DisplayFormat {
+    fn default() -> Self {
+        Self {
+            thousands_separator: None,
+            decimal_places: None,
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            timestamp_format: DISPLAY_TIMESTAMP_FORMAT.to_string(),
+            scientific_threshold: None,
+            formatted_output: false,
+        }
+    }
+}
+
+impl DisplayFormat {
+    pub fn format_int(&self, value: i64) -> String {
+        self.group_digits(&value.to_string())
+    }
+
+    pub fn format_float(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let magnitude = value.abs();
+        let scientific = self.scientific_threshold.is_some_and(|exponent| {
+            let limit = 10f64.powi(i32::from(exponent));
+            magnitude != 0.0 && (magnitude >= limit || magnitude < limit.recip())
+        });
+
+        let text = match (scientific, self.decimal_places.map(usize::from)) {
+            (true, Some(places)) => format!("{value:.places$e}"),
+            (true, None) => format!("{value:e}"),
+            (false, Some(places)) => format!("{value:.places$}"),
+            (false, None) if value.fract() == 0.0 && magnitude < 1e15 => format!("{value:.1}"),
+            (false, None) => value.to_string(),
+        };
+
+        self.group_digits(&text)
+    }
+
+    /// An exact decimal such as PostgreSQL `numeric`, rounded half away from
+    /// zero when fewer decimal places are shown. Text that is not a plain
+    /// decimal (`NaN`, `Infinity`) is returned unchanged.
+    pub fn format_decimal(&self, text: &str) -> String {
+        let rounded = match self.decimal_places {
+            Some(places) => round_decimal(text, places),
+            None => is_plain_decimal(text).then(|| text.to_string()),
+        };
+
+        match rounded {
+            Some(rounded) => self.group_digits(&rounded),
+            None => text.to_string(),
+        }
+    }
+
+    pub fn format_date(&self, date: &NaiveDate) -> String {
+        render(date.format(&self.date_format))
+            .unwrap_or_else(|| date.format(DEFAULT_DATE_FORMAT).to_string())
+    }
+
+    pub fn format_time(&self, time: &NaiveTime) -> String {
+        render(time.format(&self.time_format))
+            .unwrap_or_else(|| time.format(DEFAULT_TIME_FORMAT).to_string())
+    }
+
+    pub fn format_datetime(&self, datetime: &NaiveDateTime) -> String {
+        render(datetime.format(&self.timestamp_format))
+            .unwrap_or_else(|| datetime.format(DISPLAY_TIMESTAMP_FORMAT).to_string())
+    }
+
+    /// A timestamp already converted to the display time zone.
+    pub fn format_zoned(&self, datetime: &DateTime<FixedOffset>) -> String {
+        render(datetime.format(&self.timestamp_format))
+            .unwrap_or_else(|| datetime.format(DISPLAY_TIMESTAMP_FORMAT).to_string())
+    }
+
+    /// Displayed text of a number, date or timestamp; `None` for values this
+    /// format does not change.
+    pub fn format_value(&self, value: &Value) -> Option<String> {
+        match value {
+            Value::Int(i) => Some(self.format_int(*i)),
+            Value::Float(f) => Some(self.format_float(*f)),
+            Value::Decimal(d) => Some(self.format_decimal(d)),
+            Value::Date(d) => Some(self.format_date(d)),
+            Value::Time(t) => Some(self.format_time(t)),
+            Value::NaiveDateTime(dt) => Some(self.format_datetime(dt)),
+            Value::DateTime(dt) => {
+                Some(self.format_zoned(&crate::timezone::display_timezone().convert(dt)))
+            }
+            _ => None,
+        }
+    }
+
+    fn group_digits(&self, number: &str) -> String {
+        let Some(separator) = self.thousands_separator else {
+            return number.to_string();
+        };
+
+        let (sign, rest) = match number.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", number),
+        };
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (integer, tail) = rest.split_at(split);
+
+        let mut grouped = String::with_capacity(number.len() + integer.len() / 3);
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+
+        let tail = if separator == '.' {
+            tail.replacen('.', ",", 1)
+        } else {
+            tail.to_string()
+        };
+
+        format!("{sign}{grouped}{tail}")
+    }
+}
+
+/// Whether `pattern` is a usable strftime pattern.
+pub fn is_valid_pattern(pattern: &str) -> bool {
+    !pattern.trim().is_empty() && StrftimeItems::new(pattern).all(|item| item != Item::Error)
+}
+
+/// `formatted`, or `None` when the pattern does not apply to the value
+/// (e.g. `%H` in a date pattern).
+fn render(formatted: impl Display) -> Option<String> {
+    let mut text = String::new();
+    write!(text, "{formatted}").ok().map(|()| text)
+}
+
+fn is_plain_decimal(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    !(integer.is_empty() && fraction.is_empty())
+        && integer.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// `text` rounded half away from zero to `places` fraction digits, or
+/// `None` when it is not a plain decimal.
+fn round_decimal(text: &str, places: u8) -> Option<String> {
+    if !is_plain_decimal(text) {
+        return None;
+    }
+
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let places = usize::from(places);
+
+    let mut kept: Vec<u8> = integer
+        .bytes()
+        .chain(fraction.bytes().chain(std::iter::repeat(b'0')).take(places))
+        .collect();
+
+    if fraction.as_bytes().get(places).is_some_and(|d| *d >= b'5') {
+        let mut carry = true;
+        for digit in kept.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            kept.insert(0, b'1');
+        }
+    }
+
+    let (integer, fraction) = kept.split_at(kept.len() - places);
+    let integer = std::str::from_utf8(integer).ok()?.trim_start_matches('0');
+    let fraction = std::str::from_utf8(fraction).ok()?;
+    let is_zero = kept.iter().all(|d| *d == b'0');
+
+    let mut rounded = String::new();
+    if negative && !is_zero {
+        rounded.push('-');
+    }
+    rounded.push_str(if integer.is_empty() { "0" } else { integer });
+    if !fraction.is_empty() {
+        rounded.push('.');
+        rounded.push_str(fraction);
+    }
+
+    Some(rounded)
+}
+
+static DISPLAY_FORMAT: LazyLock<RwLock<Arc<DisplayFormat>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(DisplayFormat::default())));
+
+pub fn set_display_format(format: DisplayFormat) {
+    let format = Arc::new(format);
+    match DISPLAY_FORMAT.write() {
+        Ok(mut active) => *active = format,
+        Err(poisoned) => *poisoned.into_inner() = format,
+    }
+}
+
+pub fn display_format() -> Arc<DisplayFormat> {
+    match DISPLAY_FORMAT.read() {
+        Ok(active) => active.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grouped(separator: char) -> DisplayFormat {
+        DisplayFormat {
+            thousands_separator: Some(separator),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn default_format_matches_the_stored_text() {
+        let format = DisplayFormat::default();
+
+        assert_eq!(format.format_int(-1234567), "-1234567");
+        assert_eq!(format.format_float(3.0), "3.0");
+        assert_eq!(format.format_float(2.5), "2.5");
+        assert_eq!(format.format_float(1e20), "100000000000000000000");
+        assert_eq!(
+            format.format_decimal("19999999999999999.99"),
+            "19999999999999999.99"
+        );
+
+        let datetime = NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|d| d.and_hms_opt(22, 15, 0))
+            .expect("valid datetime");
+        assert_eq!(format.format_datetime(&datetime), "2026-03-01 22:15:00");
+        assert_eq!(format.format_date(&datetime.date()), "2026-03-01");
+        assert_eq!(format.format_time(&datetime.time()), "22:15:00");
+    }
+
+    #[test]
+    fn groups_digits_and_swaps_the_decimal_mark() {
+        assert_eq!(grouped(',').format_int(-1234567), "-1,234,567");
+        assert_eq!(grouped(',').format_int(123), "123");
+        assert_eq!(grouped(',').format_float(1234.5), "1,234.5");
+        assert_eq!(grouped('.').format_float(1234.5), "1.234,5");
+        assert_eq!(grouped(' ').format_decimal("-9876543.21"), "-9 876 543.21");
+    }
+
+    #[test]
+    fn rounds_floats_and_decimals_to_fixed_places() {
+        let format = DisplayFormat {
+            decimal_places: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(format.format_float(2.0), "2.00");
+        assert_eq!(format.format_float(1.23456), "1.23");
+        assert_eq!(format.format_decimal("0.125"), "0.13");
+        assert_eq!(format.format_decimal("-9.995"), "-10.00");
+        assert_eq!(format.format_decimal("-0.001"), "0.00");
+        assert_eq!(format.format_decimal("7"), "7.00");
+        assert_eq!(format.format_decimal("NaN"), "NaN");
+
+        let whole = DisplayFormat {
+            decimal_places: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(whole.format_decimal("99.5"), "100");
+    }
+
+    #[test]
+    fn uses_scientific_notation_past_the_threshold() {
+        let format = DisplayFormat {
+            scientific_threshold: Some(6),
+            ..Default::default()
+        };
+
+        assert_eq!(format.format_float(1_500_000.0), "1.5e6");
+        assert_eq!(format.format_float(0.00000025), "2.5e-7");
+        assert_eq!(format.format_float(0.0000025), "0.0000025");
+        assert_eq!(format.format_float(12345.0), "12345.0");
+        assert_eq!(format.format_float(0.0), "0.0");
+
+        let fixed = DisplayFormat {
+            scientific_threshold: Some(3),
+            decimal_places: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(fixed.format_float(-12345.0), "-1.23e4");
+    }
+
+    #[test]
+    fn applies_date_patterns_and_falls_back_when_they_do_not_fit() {
+        let format = DisplayFormat {
+            date_format: "%d/%m/%Y".to_string(),
+            time_format: "%Y".to_string(),
+            ..Default::default()
+        };
+
+        let date = NaiveDate::from_ymd_opt(2026, 3, 1).expect("valid date");
+        let time = NaiveTime::from_hms_opt(8, 5, 0).expect("valid time");
+        assert_eq!(format.format_date(&date), "01/03/2026");
+        assert_eq!(format.format_time(&time), "08:05:00");
+
+        assert!(is_valid_pattern("%d.%m.%Y %H:%M"));
+        assert!(!is_valid_pattern("%Q"));
+        assert!(!is_valid_pattern("  "));
+    }
+}
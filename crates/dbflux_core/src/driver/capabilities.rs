@@ -18,6 +18,8 @@ pub enum PaginationStyle {
     Cursor,
     /// Page token style pagination (DynamoDB, MongoDB cursor).
     PageToken,
+    /// Keyset (seek) pagination on the primary key for table browsing.
+    Keyset,
 }
 
 // ============================================================================
@@ -566,6 +568,21 @@ bitflags! {
         /// `InstanceCatalog` trait accessor on `Connection`. The sidebar renders
         /// an "Instance Inspector" folder gated exclusively on this bit.
         const INSTANCE_INSPECTOR = 1 << 55;
+
+        /// Driver can hold session-scoped temporary tables created through
+        /// its `SqlDialect`, so a query result can be registered under a name
+        /// and referenced by later statements on the same connection.
+        const TEMP_TABLES = 1 << 56;
+
+        /// Driver's SQL accepts its dialect's `page_clause` appended to a
+        /// single `SELECT`, so query results load in pages on demand.
+        const INCREMENTAL_FETCH = 1 << 57;
+
+        /// Driver's connection serves several requests at once (an internal
+        /// connection pool or a stateless HTTP client). Background tasks on
+        /// such connections may run side by side; on others they are queued
+        /// one at a time.
+        const CONCURRENT_REQUESTS = 1 << 58;
     }
 }
 
@@ -642,6 +659,9 @@ mod capability_bits_tests {
             DriverCapabilities::CHART_AUTHORING,
             DriverCapabilities::INSTANCE_METRICS,
             DriverCapabilities::INSTANCE_INSPECTOR,
+            DriverCapabilities::TEMP_TABLES,
+            DriverCapabilities::INCREMENTAL_FETCH,
+            DriverCapabilities::CONCURRENT_REQUESTS,
         ];
 
         let mut seen_bits: u64 = 0;
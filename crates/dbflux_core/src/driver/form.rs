@@ -97,7 +97,7 @@ pub enum FormFieldKind {
 }
 
 /// Definition of a single form field.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FormFieldDef {
     pub id: String,
     pub label: String,
@@ -124,14 +124,14 @@ pub struct FormFieldDef {
 }
 
 /// A section of related form fields.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FormSection {
     pub title: String,
     pub fields: Vec<FormFieldDef>,
 }
 
 /// A tab containing form sections.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FormTab {
     pub id: String,
     pub label: String,
@@ -139,7 +139,7 @@ pub struct FormTab {
 }
 
 /// Complete form definition for a driver.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DriverFormDef {
     pub tabs: Vec<FormTab>,
 }
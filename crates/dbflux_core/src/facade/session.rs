@@ -252,6 +252,18 @@ impl SessionFacade {
         self.shutdown.phase()
     }
 
+    pub fn begin_shutdown_confirmation(&self) -> bool {
+        self.shutdown.begin_confirmation()
+    }
+
+    pub fn cancel_shutdown_confirmation(&self) -> bool {
+        self.shutdown.cancel_confirmation()
+    }
+
+    pub fn is_confirming_shutdown(&self) -> bool {
+        self.shutdown.is_confirming()
+    }
+
     pub fn cancel_all_tasks(&mut self) -> usize {
         if !self
             .shutdown
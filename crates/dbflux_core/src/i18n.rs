@@ -0,0 +1,315 @@
+//! Translated user-facing strings.
+//!
+//! Messages live in Fluent-style files under `locales/`, one per language,
+//! compiled into the binary:
+//!
+//! ```text
+//! # Comments start with a hash.
+//! settings-save = Save
+//! settings-saved-toast = Saved { $count } settings.
+//! ```
+//!
+//! Only the subset of Fluent DBFlux needs is supported: `key = value`
+//! messages, indented continuation lines and `{ $name }` placeables.
+//! Lookups use the active [`Language`] and fall back to English, then to the
+//! key itself, so a missing translation never blanks out the UI.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// A language the UI is translated into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish,
+}
+
+impl Language {
+    /// Every supported language, in the order the settings list them.
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    /// ISO 639-1 code, as stored in the settings.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code)
+    }
+
+    /// The language's name in that language.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en.ftl"),
+            Language::Spanish => include_str!("../locales/es.ftl"),
+        }
+    }
+}
+
+static ACTIVE_LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+
+static BUNDLES: LazyLock<HashMap<Language, Bundle>> = LazyLock::new(|| {
+    Language::ALL
+        .into_iter()
+        .map(|language| {
+            let bundle = Bundle::parse(language.source()).unwrap_or_else(|error| {
+                log::error!("Locale {}: {error}", language.code());
+                Bundle::default()
+            });
+            (language, bundle)
+        })
+        .collect()
+});
+
+/// Switches the language of every later lookup.
+pub fn set_language(language: Language) {
+    ACTIVE_LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    Language::ALL
+        .into_iter()
+        .find(|language| *language as u8 == ACTIVE_LANGUAGE.load(Ordering::Relaxed))
+        .unwrap_or_default()
+}
+
+/// The message `key` in the active language.
+pub fn tr(key: &'static str) -> &'static str {
+    tr_or(key, key)
+}
+
+/// The message `key` in the active language, or `fallback` when no bundle
+/// has it. For keys built at runtime.
+pub fn tr_or<'a>(key: &str, fallback: &'a str) -> &'a str {
+    lookup(language(), key)
+        .or_else(|| lookup(Language::English, key))
+        .unwrap_or(fallback)
+}
+
+/// The message `key` in the active language with its `{ $name }`
+/// placeables filled from `args`.
+pub fn tr_args(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    format_message(tr(key), args)
+}
+
+fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    BUNDLES
+        .get(&language)?
+        .messages
+        .get(key)
+        .map(String::as_str)
+}
+
+/// Replaces each `{ $name }` in `template` with the matching argument.
+/// Unknown placeables are kept as written.
+fn format_message(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        let placeable = &rest[start..start + len + 1];
+        let name = placeable[1..placeable.len() - 1]
+            .trim()
+            .strip_prefix('$')
+            .unwrap_or_default();
+
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) if !name.is_empty() => output.push_str(&value.to_string()),
+            _ => output.push_str(placeable),
+        }
+
+        rest = &rest[start + len + 1..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// The messages of one locale file.
+#[derive(Debug, Default)]
+struct Bundle {
+    messages: HashMap<String, String>,
+}
+
+impl Bundle {
+    fn parse(source: &str) -> Result<Self, String> {
+        let mut messages: HashMap<String, String> = HashMap::new();
+        let mut current: Option<String> = None;
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                current = None;
+                continue;
+            }
+
+            if line.starts_with([' ', '\t']) {
+                let Some(key) = &current else {
+                    return Err(format!(
+                        "line {line_number}: indented text outside a message"
+                    ));
+                };
+                let value = messages.get_mut(key).expect("current message exists");
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(line.trim());
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {line_number}: expected `key = value`"));
+            };
+
+            let key = key.trim();
+            let valid_key = key.starts_with(|c: char| c.is_ascii_alphabetic())
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid_key {
+                return Err(format!("line {line_number}: invalid message id `{key}`"));
+            }
+
+            if messages
+                .insert(key.to_string(), value.trim().to_string())
+                .is_some()
+            {
+                return Err(format!("line {line_number}: duplicate message id `{key}`"));
+            }
+            current = Some(key.to_string());
+        }
+
+        Ok(Self { messages })
+    }
+
+    /// Placeable names used by each message, for comparing locales.
+    #[cfg(test)]
+    fn placeables(&self, key: &str) -> Vec<String> {
+        let mut names: Vec<String> = self.messages[key]
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}'))
+            .map(|(inner, _)| inner.trim().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_messages_comments_and_continuation_lines() {
+        let bundle = Bundle::parse(
+            "# heading\n\
+             greeting = Hello\n\
+             \n\
+             long = First line\n    second line\n",
+        )
+        .expect("valid source");
+
+        assert_eq!(bundle.messages["greeting"], "Hello");
+        assert_eq!(bundle.messages["long"], "First line\nsecond line");
+        assert_eq!(bundle.messages.len(), 2);
+    }
+
+    #[test]
+    fn rejects_malformed_sources() {
+        assert!(Bundle::parse("no equals sign").is_err());
+        assert!(Bundle::parse("  orphan continuation").is_err());
+        assert!(Bundle::parse("1st = bad id").is_err());
+        assert!(Bundle::parse("a = one\na = two").is_err());
+    }
+
+    #[test]
+    fn format_message_fills_known_placeables_only() {
+        let count = 3;
+        let formatted = format_message(
+            "Saved { $count } of {$total} { $missing } {literal}",
+            &[("count", &count), ("total", &"5")],
+        );
+
+        assert_eq!(formatted, "Saved 3 of 5 { $missing } {literal}");
+        assert_eq!(format_message("unclosed { $x", &[]), "unclosed { $x");
+    }
+
+    #[test]
+    fn language_codes_round_trip() {
+        for language in Language::ALL {
+            assert_eq!(Language::from_code(language.code()), Some(language));
+        }
+        assert_eq!(Language::from_code("fr"), None);
+    }
+
+    #[test]
+    fn bundled_locales_parse_and_translate_every_english_message() {
+        let english = Bundle::parse(Language::English.source()).expect("en.ftl parses");
+
+        for language in Language::ALL {
+            let bundle = Bundle::parse(language.source())
+                .unwrap_or_else(|e| panic!("{}.ftl: {e}", language.code()));
+
+            let mut missing: Vec<_> = english
+                .messages
+                .keys()
+                .filter(|key| !bundle.messages.contains_key(*key))
+                .collect();
+            missing.sort();
+            assert!(
+                missing.is_empty(),
+                "{}.ftl is missing {missing:?}",
+                language.code()
+            );
+
+            for key in bundle.messages.keys() {
+                assert!(
+                    english.messages.contains_key(key),
+                    "{}.ftl has `{key}`, which en.ftl lacks",
+                    language.code()
+                );
+                assert_eq!(
+                    bundle.placeables(key),
+                    english.placeables(key),
+                    "{}.ftl `{key}` uses different placeables",
+                    language.code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_falls_back_to_english_then_to_the_fallback() {
+        let english = lookup(Language::English, "settings-save").expect("bundled key");
+        let spanish = lookup(Language::Spanish, "settings-save").expect("bundled key");
+
+        assert_eq!(english, "Save");
+        assert_eq!(spanish, "Guardar");
+        assert_eq!(lookup(Language::Spanish, "no-such-key"), None);
+        assert_eq!(tr_or("no-such-key", "fallback"), "fallback");
+    }
+}
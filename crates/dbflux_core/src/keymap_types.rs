@@ -8,10 +8,33 @@ pub enum Command {
     // === Global ===
     ToggleCommandPalette,
     NewQueryTab,
+    /// Create a notebook file in the scripts directory and open it.
+    NewNotebook,
+    /// Open the "Search data" modal for the active connection's database.
+    SearchData,
+    /// Open the index advisor for the active connection's database.
+    IndexAdvisor,
+    /// Open the scheduled exports manager.
+    ScheduledExports,
+    /// Open the quick-open palette over the schema objects of every connection.
+    QuickOpen,
+    /// Open the palette over connections and their databases to switch the
+    /// active document's execution context.
+    SwitchConnection,
+    /// Open another workspace window with its own tabs.
+    NewWindow,
     CloseCurrentTab,
     NextTab,
     PrevTab,
     SwitchToTab(usize),
+    /// Move the active tab to tab group N (1-based).
+    MoveTabToGroup(usize),
+    /// Reopen the most recently closed tab.
+    ReopenClosedTab,
+    /// Go back to the previously active tab.
+    NavigateBack,
+    /// Go forward again after `NavigateBack`.
+    NavigateForward,
     OpenTabMenu,
 
     // === Focus Navigation ===
@@ -60,9 +83,21 @@ pub enum Command {
     CancelQuery,
     ToggleHistoryDropdown,
     OpenSavedQueries,
+    /// Edit the folder, tags and parameters of the selected saved query.
+    OrganizeSavedQuery,
+    /// Open the command palette listing only saved queries.
+    FindSavedQuery,
     SaveQuery,
     SaveFileAs,
     OpenScriptFile,
+    /// Open the in-editor find bar.
+    FindInEditor,
+    /// Open the in-editor find bar with the replace row expanded.
+    ReplaceInEditor,
+    FindNext,
+    FindPrevious,
+    /// Show or hide the `{{name}}` query variables panel.
+    ToggleQueryVariables,
 
     // === Results ===
     ExportResults,
@@ -77,6 +112,8 @@ pub enum Command {
     ResultsCopyRow,
     ResultsCopyCell,
     ResultsSetNull,
+    /// Show or hide the selected row in the inspector rail.
+    ToggleRowInspector,
     // Context menu
     OpenContextMenu,
     MenuUp,
@@ -98,10 +135,27 @@ pub enum Command {
     ToggleResults,
     ToggleTasks,
     ToggleSidebar,
+    /// Move the active document into a split pane beside the tab bar's pane.
+    SplitRight,
+    /// Move the active document into a split pane below the tab bar's pane.
+    SplitDown,
+    /// Move keyboard focus between the main pane and the split pane.
+    FocusOtherPane,
+    /// Move the focused document to the other pane, opening the split if needed.
+    MoveToOtherPane,
+    /// Close the split pane and return its document to the tab bar.
+    CloseSplit,
     OpenSettings,
     OpenLoginModal,
     OpenSsoWizard,
     OpenAuditViewer,
+    OpenNotifications,
+    /// Grow every UI size by one zoom step.
+    ZoomIn,
+    /// Shrink every UI size by one zoom step.
+    ZoomOut,
+    /// Return to 100% zoom.
+    ResetZoom,
     #[cfg(feature = "mcp")]
     OpenMcpApprovals,
     #[cfg(feature = "mcp")]
@@ -123,15 +177,34 @@ impl Command {
     pub fn from_palette_id(command_id: &str) -> Option<Self> {
         match command_id {
             "new_query_tab" => Some(Command::NewQueryTab),
+            "new_notebook" => Some(Command::NewNotebook),
+            "search_data" => Some(Command::SearchData),
+            "index_advisor" => Some(Command::IndexAdvisor),
+            "scheduled_exports" => Some(Command::ScheduledExports),
+            "quick_open" => Some(Command::QuickOpen),
+            "switch_connection" => Some(Command::SwitchConnection),
+            "new_window" => Some(Command::NewWindow),
             "run_query" => Some(Command::RunQuery),
             "run_query_in_new_tab" => Some(Command::RunQueryInNewTab),
             "save_query" => Some(Command::SaveQuery),
+            "find_in_editor" => Some(Command::FindInEditor),
+            "replace_in_editor" => Some(Command::ReplaceInEditor),
+            "toggle_query_variables" => Some(Command::ToggleQueryVariables),
+            "find_saved_query" => Some(Command::FindSavedQuery),
             "open_history" => Some(Command::ToggleHistoryDropdown),
             "cancel_query" => Some(Command::CancelQuery),
             "close_tab" => Some(Command::CloseCurrentTab),
             "next_tab" => Some(Command::NextTab),
             "prev_tab" => Some(Command::PrevTab),
+            "move_tab_to_group_1" => Some(Command::MoveTabToGroup(1)),
+            "move_tab_to_group_2" => Some(Command::MoveTabToGroup(2)),
+            "move_tab_to_group_3" => Some(Command::MoveTabToGroup(3)),
+            "move_tab_to_group_4" => Some(Command::MoveTabToGroup(4)),
+            "reopen_closed_tab" => Some(Command::ReopenClosedTab),
+            "navigate_back" => Some(Command::NavigateBack),
+            "navigate_forward" => Some(Command::NavigateForward),
             "export_results" => Some(Command::ExportResults),
+            "toggle_row_inspector" => Some(Command::ToggleRowInspector),
             "open_connection_manager" => Some(Command::OpenConnectionManager),
             "export_connections" => Some(Command::ExportConnections),
             "disconnect" => Some(Command::Disconnect),
@@ -144,10 +217,19 @@ impl Command {
             "toggle_editor" => Some(Command::ToggleEditor),
             "toggle_results" => Some(Command::ToggleResults),
             "toggle_tasks" => Some(Command::ToggleTasks),
+            "split_right" => Some(Command::SplitRight),
+            "split_down" => Some(Command::SplitDown),
+            "focus_other_pane" => Some(Command::FocusOtherPane),
+            "move_to_other_pane" => Some(Command::MoveToOtherPane),
+            "close_split" => Some(Command::CloseSplit),
             "open_settings" => Some(Command::OpenSettings),
             "open_login_modal" => Some(Command::OpenLoginModal),
             "open_sso_wizard" => Some(Command::OpenSsoWizard),
             "open_audit_viewer" => Some(Command::OpenAuditViewer),
+            "open_notifications" => Some(Command::OpenNotifications),
+            "zoom_in" => Some(Command::ZoomIn),
+            "zoom_out" => Some(Command::ZoomOut),
+            "reset_zoom" => Some(Command::ResetZoom),
             #[cfg(feature = "mcp")]
             "open_mcp_approvals" => Some(Command::OpenMcpApprovals),
             #[cfg(feature = "mcp")]
@@ -165,10 +247,21 @@ impl Command {
         match self {
             Command::ToggleCommandPalette => "Toggle Command Palette",
             Command::NewQueryTab => "New Query Tab",
+            Command::NewNotebook => "New Notebook",
+            Command::SearchData => "Search Data...",
+            Command::IndexAdvisor => "Index Advisor...",
+            Command::ScheduledExports => "Scheduled Exports...",
+            Command::QuickOpen => "Quick Open Object...",
+            Command::SwitchConnection => "Switch Connection or Database...",
+            Command::NewWindow => "New Window",
             Command::CloseCurrentTab => "Close Current Tab",
             Command::NextTab => "Next Tab",
             Command::PrevTab => "Previous Tab",
             Command::SwitchToTab(_) => "Switch to Tab",
+            Command::MoveTabToGroup(_) => "Move Tab to Group",
+            Command::ReopenClosedTab => "Reopen Closed Tab",
+            Command::NavigateBack => "Go Back",
+            Command::NavigateForward => "Go Forward",
             Command::OpenTabMenu => "Open Tab Menu",
 
             Command::FocusSidebar => "Focus Sidebar",
@@ -211,9 +304,16 @@ impl Command {
             Command::CancelQuery => "Cancel Query",
             Command::ToggleHistoryDropdown => "Toggle History Dropdown",
             Command::OpenSavedQueries => "Open Saved Queries",
+            Command::OrganizeSavedQuery => "Edit Folder, Tags and Parameters",
+            Command::FindSavedQuery => "Find Saved Query...",
             Command::SaveQuery => "Save",
             Command::SaveFileAs => "Save File As",
             Command::OpenScriptFile => "Open Script File",
+            Command::FindInEditor => "Find",
+            Command::ReplaceInEditor => "Find and Replace",
+            Command::FindNext => "Find Next",
+            Command::FindPrevious => "Find Previous",
+            Command::ToggleQueryVariables => "Toggle Query Variables",
 
             Command::ExportResults => "Export Results",
             Command::ResultsNextPage => "Results Next Page",
@@ -226,6 +326,7 @@ impl Command {
             Command::ResultsCopyRow => "Copy Row",
             Command::ResultsCopyCell => "Copy Cell",
             Command::ResultsSetNull => "Set Cell to NULL",
+            Command::ToggleRowInspector => "Toggle Row Inspector",
             Command::OpenContextMenu => "Open Context Menu",
             Command::MenuUp => "Menu Up",
             Command::MenuDown => "Menu Down",
@@ -244,10 +345,19 @@ impl Command {
             Command::ToggleResults => "Toggle Results Panel",
             Command::ToggleTasks => "Toggle Tasks Panel",
             Command::ToggleSidebar => "Toggle Sidebar",
+            Command::SplitRight => "Split Right",
+            Command::SplitDown => "Split Down",
+            Command::FocusOtherPane => "Focus Other Pane",
+            Command::MoveToOtherPane => "Move Document to Other Pane",
+            Command::CloseSplit => "Close Split",
             Command::OpenSettings => "Open Settings",
             Command::OpenLoginModal => "Open Auth Profile Login",
             Command::OpenSsoWizard => "Open AWS SSO Wizard",
             Command::OpenAuditViewer => "Open Audit Viewer",
+            Command::OpenNotifications => "Open Notifications...",
+            Command::ZoomIn => "Zoom In",
+            Command::ZoomOut => "Zoom Out",
+            Command::ResetZoom => "Reset Zoom",
             #[cfg(feature = "mcp")]
             Command::OpenMcpApprovals => "Open MCP Approvals",
             #[cfg(feature = "mcp")]
@@ -264,10 +374,21 @@ impl Command {
         match self {
             Command::ToggleCommandPalette
             | Command::NewQueryTab
+            | Command::NewNotebook
+            | Command::SearchData
+            | Command::IndexAdvisor
+            | Command::ScheduledExports
+            | Command::QuickOpen
+            | Command::SwitchConnection
+            | Command::NewWindow
             | Command::CloseCurrentTab
             | Command::NextTab
             | Command::PrevTab
             | Command::SwitchToTab(_)
+            | Command::MoveTabToGroup(_)
+            | Command::ReopenClosedTab
+            | Command::NavigateBack
+            | Command::NavigateForward
             | Command::OpenTabMenu => "Global",
 
             Command::FocusSidebar
@@ -308,9 +429,16 @@ impl Command {
             | Command::CancelQuery
             | Command::ToggleHistoryDropdown
             | Command::OpenSavedQueries
+            | Command::OrganizeSavedQuery
+            | Command::FindSavedQuery
             | Command::SaveQuery
             | Command::SaveFileAs
-            | Command::OpenScriptFile => "Editor",
+            | Command::OpenScriptFile
+            | Command::FindInEditor
+            | Command::ReplaceInEditor
+            | Command::FindNext
+            | Command::FindPrevious
+            | Command::ToggleQueryVariables => "Editor",
 
             Command::ExportResults
             | Command::ResultsNextPage
@@ -322,6 +450,7 @@ impl Command {
             | Command::ResultsCopyRow
             | Command::ResultsCopyCell
             | Command::ResultsSetNull
+            | Command::ToggleRowInspector
             | Command::OpenContextMenu
             | Command::MenuUp
             | Command::MenuDown
@@ -341,10 +470,19 @@ impl Command {
             | Command::ToggleTasks
             | Command::ToggleSidebar
             | Command::TogglePanel
+            | Command::SplitRight
+            | Command::SplitDown
+            | Command::FocusOtherPane
+            | Command::MoveToOtherPane
+            | Command::CloseSplit
             | Command::OpenSettings
             | Command::OpenLoginModal
             | Command::OpenSsoWizard
-            | Command::OpenAuditViewer => "View",
+            | Command::OpenAuditViewer
+            | Command::OpenNotifications
+            | Command::ZoomIn
+            | Command::ZoomOut
+            | Command::ResetZoom => "View",
 
             #[cfg(feature = "mcp")]
             Command::OpenMcpApprovals | Command::RefreshMcpGovernance => "View",
@@ -361,12 +499,18 @@ impl Command {
         matches!(
             self,
             Command::ToggleCommandPalette
+                | Command::QuickOpen
+                | Command::SwitchConnection
                 | Command::NewQueryTab
                 | Command::OpenScriptFile
                 | Command::CloseCurrentTab
                 | Command::NextTab
                 | Command::PrevTab
                 | Command::SwitchToTab(_)
+                | Command::MoveTabToGroup(_)
+                | Command::ReopenClosedTab
+                | Command::NavigateBack
+                | Command::NavigateForward
                 | Command::RunQuery
                 | Command::Cancel
                 | Command::FocusSidebar
@@ -386,6 +530,9 @@ impl Command {
                 | Command::OpenLoginModal
                 | Command::OpenSsoWizard
                 | Command::OpenAuditViewer
+                | Command::ZoomIn
+                | Command::ZoomOut
+                | Command::ResetZoom
         ) || {
             #[cfg(feature = "mcp")]
             {
@@ -602,6 +749,20 @@ mod tests {
         assert_eq!(Command::SaveQuery.display_name(), "Save");
     }
 
+    #[test]
+    fn row_inspector_toggle_is_a_results_palette_command() {
+        assert_eq!(
+            Command::from_palette_id("toggle_row_inspector"),
+            Some(Command::ToggleRowInspector)
+        );
+        assert_eq!(
+            Command::ToggleRowInspector.display_name(),
+            "Toggle Row Inspector"
+        );
+        assert_eq!(Command::ToggleRowInspector.category(), "Results");
+        assert!(!Command::ToggleRowInspector.is_global());
+    }
+
     #[test]
     fn history_modal_is_modal() {
         assert!(ContextId::HistoryModal.is_modal());
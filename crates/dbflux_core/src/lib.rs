@@ -6,10 +6,13 @@ mod config;
 mod connection;
 mod core;
 mod data;
+pub mod display_format;
 pub mod document_id;
 mod driver;
 mod facade;
+pub mod i18n;
 pub mod keymap_types;
+pub mod null_display;
 pub mod observability;
 pub mod pipeline;
 mod query;
@@ -17,6 +20,7 @@ pub mod release_channel;
 mod schema;
 mod sql;
 mod storage;
+pub mod timezone;
 pub mod values;
 
 pub use access::{AccessHandle, AccessKind, AccessManager};
@@ -33,12 +37,16 @@ pub use auth::{
 };
 
 pub use config::{
-    AppConfig, AppConfigWarning, AppStyle, DangerousAction, DriverKey,
+    AppConfig, AppConfigWarning, AppStyle, DEFAULT_EDITOR_FONT_SIZE, DEFAULT_GRID_FONT_SIZE,
+    DEFAULT_UI_ZOOM_PERCENT, DRIVER_MANIFESTS_DIR, DangerousAction, DriverKey, DriverManifest,
     EXTERNAL_SERVICES_CONFIG_KEY, EffectiveSettings, GeneralSettings, GlobalOverrides,
-    GovernanceSettings, LoadedAppConfig, PolicyRoleConfig, RefreshPolicy, RefreshPolicySetting,
-    RpcServiceKind, ScriptEntry, ScriptsDirectory, ServiceConfig, ServiceRpcApiContract,
-    StartupFocus, ThemeSetting, ToolPolicyConfig, TrustedClientConfig, all_script_extensions,
-    driver_maps_differ, filter_entries, hook_script_path, is_openable_script, migrate_app_config,
+    GovernanceSettings, LoadedAppConfig, MAX_EDITOR_FONT_SIZE, MAX_GRID_FONT_SIZE,
+    MAX_UI_ZOOM_PERCENT, MIN_EDITOR_FONT_SIZE, MIN_GRID_FONT_SIZE, MIN_UI_ZOOM_PERCENT,
+    PolicyRoleConfig, RefreshPolicy, RefreshPolicySetting, RemoteServiceConfig, RpcServiceKind,
+    ScriptEntry, ScriptsDirectory, ServiceConfig, ServiceRpcApiContract, StartupFocus,
+    ThemeSetting, ToolPolicyConfig, TrustedClientConfig, UI_ZOOM_STEP_PERCENT,
+    all_script_extensions, driver_maps_differ, filter_entries, hook_script_path,
+    is_openable_script, migrate_app_config,
 };
 
 #[allow(deprecated)]
@@ -49,24 +57,27 @@ pub use connection::{
     ConnectionResolutionError, ConnectionTree, ConnectionTreeManager, ConnectionTreeNode,
     ConnectionTreeNodeKind, DatabaseConnection, DbConfig, DbKind, DefaultMutationPolicyResolver,
     DetachedProcessHandle, DetachedProcessReceiver, DetachedProcessSender, ExecutionContext,
-    ExecutionSourceContext, FetchCollectionChildrenParams, FetchCollectionChildrenResult,
-    FetchDatabaseSchemaParams, FetchDatabaseSchemaResult, FetchSchemaForeignKeysParams,
-    FetchSchemaForeignKeysResult, FetchSchemaIndexesParams, FetchSchemaIndexesResult,
-    FetchSchemaRoutinesParams, FetchSchemaRoutinesResult, FetchSchemaTypesParams,
-    FetchSchemaTypesResult, FetchTableDetailsParams, FetchTableDetailsResult, HookContext,
-    HookExecution, HookExecutionContext, HookExecutionMode, HookExecutor, HookFailureMode,
-    HookKind, HookPhase, HookPhaseOutcome, HookResult, HookRunner, Identifiable, InfluxVersion,
-    ItemManager, LuaCapabilities, MetricQuerySeries, MutationPolicy, OutputEvent, OutputReceiver,
-    OutputSender, OutputStreamKind, OwnedCacheEntry, PendingOperation, PrepareConnectError,
-    ProcessExecutionError, ProcessExecutor, ProfileManager, ProfilePolicyResolver, ProxyAuth,
-    ProxyKind, ProxyManager, ProxyProfile, RedisKeyCache, RedisKeyCacheEntry, ResolvedProxy,
-    SchemaCacheKey, ScriptLanguage, ScriptSource, SshAuthMethod, SshTunnelConfig, SshTunnelManager,
-    SshTunnelProfile, SslInfo, SslMode, SwitchDatabaseParams, SwitchDatabaseResult,
-    TestConnectionResult, TreeLoadResult, TreeStore, detached_process_channel,
-    execute_streaming_process, host_matches_no_proxy, output_channel, ssl_mode_from_id,
-    ssl_mode_id_is_cert_active, ssl_mode_id_requires_root_cert, ssl_mode_requires_root_cert,
+    ExecutionSourceContext, FavoriteObject, FavoriteTarget, FetchCollectionChildrenParams,
+    FetchCollectionChildrenResult, FetchDatabaseSchemaParams, FetchDatabaseSchemaResult,
+    FetchSchemaForeignKeysParams, FetchSchemaForeignKeysResult, FetchSchemaIndexesParams,
+    FetchSchemaIndexesResult, FetchSchemaRoutinesParams, FetchSchemaRoutinesResult,
+    FetchSchemaTypesParams, FetchSchemaTypesResult, FetchTableDetailsParams,
+    FetchTableDetailsResult, HookContext, HookExecution, HookExecutionContext, HookExecutionMode,
+    HookExecutor, HookFailureMode, HookKind, HookPhase, HookPhaseOutcome, HookResult, HookRunner,
+    Identifiable, InfluxVersion, ItemManager, LuaCapabilities, MetricQuerySeries, MutationPolicy,
+    OutputEvent, OutputReceiver, OutputSender, OutputStreamKind, OwnedCacheEntry, PendingOperation,
+    PrepareConnectError, ProcessExecutionError, ProcessExecutor, ProfileManager,
+    ProfilePolicyResolver, ProxyAuth, ProxyKind, ProxyManager, ProxyProfile, RedisKeyCache,
+    RedisKeyCacheEntry, ResolvedProxy, SchemaCacheKey, ScriptLanguage, ScriptSource, SshAuthMethod,
+    SshTunnelConfig, SshTunnelManager, SshTunnelProfile, SslInfo, SslMode, SwitchDatabaseParams,
+    SwitchDatabaseResult, TestConnectionResult, TreeLoadResult, TreeStore,
+    detached_process_channel, execute_streaming_process, host_matches_no_proxy, output_channel,
+    ssl_mode_from_id, ssl_mode_id_is_cert_active, ssl_mode_id_requires_root_cert,
+    ssl_mode_requires_root_cert,
 };
 
+pub use connection::{ConnectionEvent, ConnectionEventKind, ConnectionEventSink, NoticeSeverity};
+
 pub use connection::{
     DimensionFilter, MetricCatalog, MetricCatalogPage, MetricDescriptor, MetricNamespace,
 };
@@ -84,14 +95,15 @@ pub use connection::{
 };
 
 pub use core::{
-    CancelToken, CodeGenScope, CodeGeneratorInfo, Connection, ConnectionErrorFormatter,
-    ConnectionExt, ConnectionOverrides, DbDriver, DbError, DefaultErrorFormatter,
-    DocumentConnection, ErrorLocation, EventStreamTarget, FormattedError, KeyValueApi,
-    KeyValueConnection, LogErr, NoopCancelHandle, QueryCancelHandle, QueryErrorFormatter,
-    RelationalConnection, SchemaDropTarget, SchemaFeatures, SchemaLoadingStrategy,
-    SchemaObjectKind, ShutdownCoordinator, ShutdownPhase, SourceContextSpec, SourceQueryMode,
-    TaskId, TaskKind, TaskManager, TaskSlot, TaskSnapshot, TaskStatus, TaskTarget, Value,
-    sanitize_uri,
+    CONCURRENT_CONNECTION_CONCURRENCY, CancelToken, CodeGenScope, CodeGeneratorInfo, Connection,
+    ConnectionErrorFix, ConnectionErrorFormatter, ConnectionExt, ConnectionFixTarget,
+    ConnectionOverrides, DEFAULT_CONNECTION_CONCURRENCY, DbDriver, DbError, DefaultErrorFormatter,
+    DocumentConnection, ErrorLocation, ErrorSeverity, EventStreamTarget, FormattedError, Interval,
+    KeyValueApi, KeyValueConnection, LogErr, NoopCancelHandle, ProgressUnit, QueryCancelHandle,
+    QueryErrorFormatter, RelationalConnection, SchemaDropTarget, SchemaFeatures,
+    SchemaLoadingStrategy, SchemaObjectKind, ShutdownCoordinator, ShutdownPhase, SourceContextSpec,
+    SourceQueryMode, TaskAdmission, TaskId, TaskKind, TaskManager, TaskSlot, TaskSnapshot,
+    TaskStatus, TaskTarget, TaskUnits, Value, sanitize_uri,
 };
 
 pub use data::{
@@ -123,25 +135,34 @@ pub use query::{
     AggFn, AggregateFunction, AggregateRequest, AggregateSpec, AliasOrigin, Assignment,
     AssignmentValue, BoolOp, ClassifiedMutation, CollectionBrowseRequest, CollectionCountRequest,
     CollectionRef, CollectionTemplateRequest, ColumnKind, ColumnMeta, ColumnOrigin, ColumnRef,
-    Comparator, CountSpec, DangerousQueryKind, DescribeRequest, Diagnostic, DiagnosticSeverity,
-    EditableBinding, EditorDiagnostic, ExplainRequest, FilterNode, GeneratedMutation,
-    GeneratedQuery, GeneratorError, GroupByEntry, JoinFilterNode, JoinKind, JoinOn, JoinPredicate,
-    JoinStep, LanguageService, LiteralValue, MutationCategory, MutationKind,
-    MutationTemplateOperation, MutationTemplateRequest, OrderByColumn, Pagination, PlannedQuery,
-    Predicate, PredicateValue, ProjectedColumn, Projection, QueryGenError, QueryGenerator,
-    QueryHandle, QueryRequest, QueryResult, QueryResultShape, ReadTemplateOperation,
-    ReadTemplateRequest, ResolvedWindow, Row, ScalarLiteral, SelectQuery, SemanticFieldRef,
+    Comparator, Coord, CountSpec, DangerousQueryKind, DataSearchHit, DataSearchMode,
+    DescribeRequest, Diagnostic, DiagnosticSeverity, EditableBinding, EditorDiagnostic,
+    ExplainRequest, FilterNode, GeneratedMutation, GeneratedQuery, GeneratorError, Geometry,
+    GeometryDims, GrantSpec, GrantTarget, GroupByEntry, JoinFilterNode, JoinKind, JoinOn,
+    JoinPredicate, JoinStep, LanguageService, LiteralValue, MutationCategory, MutationKind,
+    MutationTemplateOperation, MutationTemplateRequest, OrderByColumn, Pagination, PlanChange,
+    PlanDiffRow, PlanNode, PlannedQuery, Predicate, PredicateValue, ProjectedColumn, Projection,
+    QueryGenError, QueryGenerator, QueryHandle, QueryRequest, QueryResult, QueryResultShape,
+    QueryTiming, QueryVariableRef, QueryVariableScope, ReadTemplateOperation, ReadTemplateRequest,
+    ResolvedWindow, RoleImpersonation, Row, SavedQueryParam, SavedQueryParamError,
+    SavedQueryParamRef, SavedQueryParamType, ScalarLiteral, SelectQuery, SemanticFieldRef,
     SemanticFilter, SemanticPlan, SemanticPlanKind, SemanticPlanner, SemanticPredicate,
-    SemanticRequest, SemanticRequestKind, SortDirection, SortEntry, SourceTable, SpecError,
-    SqlLanguageService, SqlMutationGenerator, TableBrowseRequest, TableCountRequest, TableRef,
-    TextPosition, TextPositionRange, TextRange, TransactionVocab, ValidationResult,
-    VisualAggregateSpec, VisualMutationSpec, VisualQuerySpec, VisualSortDirection,
+    SemanticRequest, SemanticRequestKind, SessionGate, SessionGuard, SessionScope, SortDirection,
+    SortEntry, SourceTable, SpatialValue, SpecError, SqlLanguageService, SqlMutationGenerator,
+    TableBrowseRequest, TableCountRequest, TableRef, TextPosition, TextPositionRange, TextRange,
+    TransactionVocab, UndefinedQueryVariables, UserAccount, UserAdminDialect, UserSpec,
+    ValidationResult, VisualAggregateSpec, VisualMutationSpec, VisualQuerySpec,
+    VisualSortDirection, bind_saved_query_params, carry_saved_query_param_values,
     classify_query_for_governance, classify_query_for_language,
     classify_query_for_language_with_service, classify_sql_execution, classify_visual_mutation,
-    contains_time_macros, detect_dangerous_query, detect_dangerous_sql, infer_column_kind,
-    inline_params, is_safe_read_query, lower_keyset_predicate, parse_semantic_filter_json,
-    project_aggregate_kinds, render_filter_node_sql, render_semantic_filter_sql,
-    strip_leading_comments, substitute_time_macros,
+    collect_search_hits, contains_time_macros, detect_dangerous_query, detect_dangerous_sql,
+    diff_plans, explained_statement, find_query_variables, find_saved_query_params,
+    format_saved_query_params, infer_column_kind, inline_params, is_safe_read_query,
+    is_searchable_text_type, is_spatial_type, like_contains_pattern, lower_keyset_predicate,
+    parse_saved_query_params, parse_semantic_filter_json, plan_statement_key,
+    project_aggregate_kinds, query_variable_names, references_saved_query_params,
+    render_filter_node_sql, render_semantic_filter_sql, search_columns, strip_leading_comments,
+    substitute_query_variables, substitute_time_macros, table_search_query,
 };
 
 pub use query::relational_filter::{
@@ -178,29 +199,35 @@ pub use schema::node_id as schema_node_id;
 pub use schema::{
     CollectionChildInfo, CollectionChildrenCache, CollectionChildrenPage,
     CollectionChildrenRequest, CollectionIndexInfo, CollectionInfo, CollectionPresentation,
-    ColumnDiff, ColumnFamilyInfo, ColumnInfo, ColumnSnapshot, ConstraintInfo, ConstraintKind,
-    ContainerInfo, CustomTypeInfo, CustomTypeKind, DataStructure, DatabaseInfo, DbSchemaInfo,
-    DocumentSchema, DriftOutcome, FieldInfo, ForeignKeyBuilder, ForeignKeyInfo, GraphInfo,
-    GraphSchema, IndexBuilder, IndexData, IndexDirection, IndexInfo, KeyInfo, KeySpaceInfo,
-    KeyValueSchema, MeasurementInfo, MultiModelCapabilities, MultiModelSchema, NodeLabelInfo,
-    ParseSchemaNodeIdError, PropertyInfo, QueryTableRef, RelationKind, RelationRef,
+    ColumnDiff, ColumnFamilyInfo, ColumnInfo, ColumnPredicate, ColumnSnapshot, ConstraintInfo,
+    ConstraintKind, ContainerInfo, CustomTypeInfo, CustomTypeKind, DataStructure, DatabaseInfo,
+    DbSchemaInfo, DocumentSchema, DriftOutcome, FieldInfo, ForeignKeyBuilder, ForeignKeyInfo,
+    GraphInfo, GraphSchema, IndexBuilder, IndexData, IndexDirection, IndexInfo, IndexSuggestion,
+    KeyInfo, KeySpaceInfo, KeyValueSchema, MAX_PARALLEL_INTROSPECTION, MeasurementInfo,
+    MultiModelCapabilities, MultiModelSchema, NodeLabelInfo, PREFETCH_DATABASE_LIMIT,
+    ParseSchemaNodeIdError, PredicateKind, PropertyInfo, QueryTableRef, RelationKind, RelationRef,
     RelationalSchema, RelationshipTypeInfo, RetentionPolicyInfo, RoutineInfo, RoutineKind,
     SchemaChange, SchemaDiff, SchemaDriftDetected, SchemaFingerprint, SchemaForeignKeyBuilder,
     SchemaForeignKeyInfo, SchemaIndexBuilder, SchemaIndexInfo, SchemaNodeId, SchemaNodeKind,
-    SchemaSnapshot, SearchIndexInfo, SearchMappingInfo, SearchSchema, TableInfo,
-    TimeSeriesFieldInfo, TimeSeriesSchema, VectorCollectionInfo, VectorMetadataField, VectorMetric,
-    VectorSchema, ViewInfo, WideColumnInfo, WideColumnKeyspaceInfo, WideColumnSchema,
-    check_drift_sync, check_schema_drift, diff_table_info, extract_referenced_tables,
+    SchemaObjectRef, SchemaSnapshot, SearchIndexInfo, SearchMappingInfo, SearchSchema,
+    SnapshotDiff, TableInfo, TableStatistics, TimeSeriesFieldInfo, TimeSeriesSchema,
+    VectorCollectionInfo, VectorMetadataField, VectorMetric, VectorSchema, ViewInfo,
+    WideColumnInfo, WideColumnKeyspaceInfo, WideColumnSchema, WorkloadQuery, check_drift_sync,
+    check_schema_drift, databases_to_prefetch, diff_snapshots, diff_table_info,
+    extract_column_predicates, extract_referenced_tables, is_internal_object, is_system_schema,
+    map_bounded, prefetch_database_schemas, suggest_indexes,
 };
 
 pub use sql::{
     AddEnumValueRequest, AddForeignKeyRequest, CodeGenCapabilities, CodeGenerator,
     CreateIndexRequest, CreateTypeRequest, DefaultSqlDialect, DropForeignKeyRequest,
-    DropIndexRequest, DropTypeRequest, NoOpCodeGenerator, PlaceholderStyle, ReindexRequest,
-    SqlDialect, SqlGenerationOptions, SqlGenerationRequest, SqlOperation, SqlQueryBuilder,
-    SqlValueMode, TypeAttributeDefinition, TypeDefinition, generate_create_table,
+    DropIndexRequest, DropTypeRequest, FETCH_PAGE_ROWS, FetchCursor, NoOpCodeGenerator,
+    PlaceholderStyle, ReindexRequest, RenameRequest, SetCommentRequest, SqlDialect,
+    SqlGenerationOptions, SqlGenerationRequest, SqlOperation, SqlQueryBuilder, SqlValueMode,
+    TempColumnType, TypeAttributeDefinition, TypeDefinition, generate_create_table,
     generate_delete_template, generate_drop_table, generate_insert_template, generate_select_star,
-    generate_sql, generate_truncate, generate_update_template,
+    generate_sql, generate_truncate, generate_update_template, infer_temp_column_types,
+    normalize_temp_table_name, temp_table_statements,
 };
 
 pub use pipeline::{
@@ -216,11 +243,16 @@ pub use values::{
 pub use chrono;
 pub use secrecy;
 pub use storage::{
-    HasSecretRef, HistoryEntry, KeyringSecretStore, NoopSecretStore, RecentFile, SavedQuery,
-    SecretManager, SecretStore, SessionManifest, SessionStore, SessionTab, SessionTabKind, UiState,
-    UiStateStore, auth_field_secret_ref, connection_secret_ref, create_secret_store,
-    proxy_secret_ref, ssh_tunnel_secret_ref,
+    CapturedPlan, ColumnLayout, CronParseError, CronSchedule, DataTabState, DataTabTarget,
+    DiffLine, HasSecretRef, HistoryEntry, HistoryFilter, KeptResultTab, KeptResults,
+    KeyringSecretStore, NOTEBOOK_EXTENSION, NoopSecretStore, Notebook, NotebookCell,
+    NotebookCellKind, RecentFile, SavedQuery, SavedQueryFolder, SavedQuerySearch, ScheduledExport,
+    ScheduledExportRun, SecretManager, SecretStore, SessionManifest, SessionStore, SessionTab,
+    SessionTabKind, UiState, UiStateStore, auth_field_secret_ref, connection_secret_ref,
+    create_secret_store, diff_counts, is_notebook_path, line_diff, normalize_tags,
+    proxy_secret_ref, recently_used_tables, saved_query_folder_path, ssh_tunnel_secret_ref,
 };
+pub use uuid;
 
 pub use observability::{
     EventActorType, EventCapturePolicy, EventCategory, EventDetail, EventObjectRef, EventOutcome,
@@ -0,0 +1,138 @@
+//! How NULL cells are shown.
+//!
+//! Result grids and the cell viewer draw a NULL with a marker text and a
+//! style picked in the settings, so it never looks like an empty string.
+//! The marker is display-only: copying a NULL cell still yields an empty
+//! string and exports keep their own NULL representation.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::sync::RwLock;
+
+/// Marker shown for NULL unless the settings pick another.
+pub const DEFAULT_NULL_MARKER: &str = "NULL";
+
+/// Markers offered in the settings.
+pub const NULL_MARKERS: &[&str] = &[DEFAULT_NULL_MARKER, "(null)", "<null>", "∅", "—"];
+
+/// Styling of the NULL marker in result grids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullStyle {
+    /// Italic text in the muted foreground color.
+    #[default]
+    Muted,
+
+    /// Italic text on a tinted badge, easy to spot in dense grids.
+    Badge,
+
+    /// Regular text, like any other value.
+    Plain,
+}
+
+impl NullStyle {
+    pub const ALL: [Self; 3] = [Self::Muted, Self::Badge, Self::Plain];
+
+    /// Storage code: `muted`, `badge` or `plain`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Muted => "muted",
+            Self::Badge => "badge",
+            Self::Plain => "plain",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.code().eq_ignore_ascii_case(code.trim()))
+    }
+
+    /// Name shown in the settings.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Muted => "Muted italic",
+            Self::Badge => "Highlighted badge",
+            Self::Plain => "Plain text",
+        }
+    }
+}
+
+impl Serialize for NullStyle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for NullStyle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Self::from_code(&code)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown NULL style \"{code}\"")))
+    }
+}
+
+static NULL_MARKER: RwLock<Cow<'static, str>> = RwLock::new(Cow::Borrowed(DEFAULT_NULL_MARKER));
+static NULL_STYLE: RwLock<NullStyle> = RwLock::new(NullStyle::Muted);
+
+/// Sets the marker shown for NULL; a blank marker restores the default, as
+/// an empty marker would look like an empty string.
+pub fn set_null_marker(marker: &str) {
+    let marker = match marker.trim() {
+        "" => Cow::Borrowed(DEFAULT_NULL_MARKER),
+        marker => Cow::Owned(marker.to_string()),
+    };
+
+    match NULL_MARKER.write() {
+        Ok(mut active) => *active = marker,
+        Err(poisoned) => *poisoned.into_inner() = marker,
+    }
+}
+
+pub fn null_marker() -> String {
+    match NULL_MARKER.read() {
+        Ok(active) => active.to_string(),
+        Err(poisoned) => poisoned.into_inner().to_string(),
+    }
+}
+
+pub fn set_null_style(style: NullStyle) {
+    match NULL_STYLE.write() {
+        Ok(mut active) => *active = style,
+        Err(poisoned) => *poisoned.into_inner() = style,
+    }
+}
+
+pub fn null_style() -> NullStyle {
+    match NULL_STYLE.read() {
+        Ok(active) => *active,
+        Err(poisoned) => *poisoned.into_inner(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn style_codes_round_trip() {
+        for style in NullStyle::ALL {
+            assert_eq!(NullStyle::from_code(style.code()), Some(style));
+        }
+
+        assert_eq!(NullStyle::from_code(" BADGE "), Some(NullStyle::Badge));
+        assert_eq!(NullStyle::from_code("bold"), None);
+        assert_eq!(
+            serde_json::to_string(&NullStyle::Plain).expect("serialize"),
+            "\"plain\""
+        );
+    }
+
+    #[test]
+    fn blank_markers_fall_back_to_the_default() {
+        set_null_marker("  ∅ ");
+        assert_eq!(null_marker(), "∅");
+
+        set_null_marker("   ");
+        assert_eq!(null_marker(), DEFAULT_NULL_MARKER);
+    }
+}
@@ -0,0 +1,295 @@
+//! Cross-table data search: one `SELECT` per table over its text columns,
+//! whose matching rows become hits that link back to the row.
+
+use crate::{ColumnInfo, QueryResult, SqlDialect, TableRef, Value};
+
+/// How the search text is compared with column values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataSearchMode {
+    /// Substring match ignoring case (`ILIKE` where available).
+    #[default]
+    Contains,
+    /// Substring match respecting case where the engine can.
+    MatchCase,
+    /// Regular expression, on engines that have a regex operator.
+    Regex,
+}
+
+impl DataSearchMode {
+    pub const ALL: [DataSearchMode; 3] = [Self::Contains, Self::MatchCase, Self::Regex];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Contains => "Contains",
+            Self::MatchCase => "Match case",
+            Self::Regex => "Regex",
+        }
+    }
+}
+
+/// One matching row: where it is, what matched, and the `WHERE` filter that
+/// selects it in a table document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataSearchHit {
+    pub table: TableRef,
+    pub column: String,
+    pub value: String,
+    pub filter: String,
+}
+
+/// `%text%` with the `LIKE` wildcards of `text` escaped by `!`, for use
+/// with `ESCAPE '!'`.
+pub fn like_contains_pattern(text: &str) -> String {
+    let mut pattern = String::with_capacity(text.len() + 2);
+    pattern.push('%');
+    for ch in text.chars() {
+        if matches!(ch, '%' | '_' | '!') {
+            pattern.push('!');
+        }
+        pattern.push(ch);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// Whether a column of `type_name` holds text the search can match.
+pub fn is_searchable_text_type(type_name: &str) -> bool {
+    let type_name = type_name.to_ascii_lowercase();
+    ["char", "text", "clob", "string"]
+        .iter()
+        .any(|needle| type_name.contains(needle))
+}
+
+/// `SELECT *` of the rows of `table` where any of `columns` matches `text`,
+/// capped at `limit` rows. `None` when there is nothing to search or the
+/// dialect cannot evaluate the mode.
+///
+/// Rows are ordered by `key_columns`, or the first searched column without
+/// a key, which keeps the capped rows stable and gives T-SQL the `ORDER BY`
+/// its row limit requires.
+pub fn table_search_query(
+    dialect: &dyn SqlDialect,
+    table: &TableRef,
+    columns: &[&str],
+    key_columns: &[&str],
+    text: &str,
+    mode: DataSearchMode,
+    limit: u32,
+) -> Option<String> {
+    if columns.is_empty() || text.is_empty() {
+        return None;
+    }
+
+    let predicates = columns
+        .iter()
+        .map(|column| {
+            let column = dialect.quote_identifier(column);
+            match mode {
+                DataSearchMode::Contains => Some(dialect.text_contains_expr(&column, text, false)),
+                DataSearchMode::MatchCase => Some(dialect.text_contains_expr(&column, text, true)),
+                DataSearchMode::Regex => dialect.regex_match_expr(&column, text),
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let order_by: Vec<String> = if key_columns.is_empty() {
+        &columns[..1]
+    } else {
+        key_columns
+    }
+    .iter()
+    .map(|column| dialect.quote_identifier(column))
+    .collect();
+
+    Some(format!(
+        "SELECT * FROM {} WHERE {} ORDER BY {} {}",
+        table.quoted_with(dialect),
+        predicates.join(" OR "),
+        order_by.join(", "),
+        dialect.limit_clause(limit)
+    ))
+}
+
+/// Hits for the rows of a table search `result`. Each hit names the first
+/// searched column whose value satisfies `is_match`, and filters on
+/// `key_columns` when all of them are in the result, or on that column's
+/// value otherwise.
+pub fn collect_search_hits(
+    dialect: &dyn SqlDialect,
+    table: &TableRef,
+    result: &QueryResult,
+    columns: &[&str],
+    key_columns: &[&str],
+    is_match: impl Fn(&str) -> bool,
+) -> Vec<DataSearchHit> {
+    let position = |name: &str| result.columns.iter().position(|c| c.name == name);
+    let searched: Vec<(usize, &str)> = columns
+        .iter()
+        .filter_map(|&name| position(name).map(|ix| (ix, name)))
+        .collect();
+    let keys: Option<Vec<(usize, &str)>> = key_columns
+        .iter()
+        .map(|&name| position(name).map(|ix| (ix, name)))
+        .collect();
+
+    let equals = |name: &str, value: &Value| match value {
+        Value::Null => format!("{} IS NULL", dialect.quote_identifier(name)),
+        value => format!(
+            "{} = {}",
+            dialect.quote_identifier(name),
+            dialect.value_to_literal(value)
+        ),
+    };
+
+    result
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let (ix, column) = searched
+                .iter()
+                .find(|(ix, _)| matches!(row.get(*ix), Some(Value::Text(text)) if is_match(text)))
+                .or_else(|| searched.first())?;
+            let value = row.get(*ix).cloned().unwrap_or(Value::Null);
+
+            let filter = match keys.as_ref().filter(|keys| !keys.is_empty()) {
+                Some(keys) => keys
+                    .iter()
+                    .map(|&(key_ix, name)| equals(name, row.get(key_ix).unwrap_or(&Value::Null)))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+                None => equals(column, &value),
+            };
+
+            Some(DataSearchHit {
+                table: table.clone(),
+                column: column.to_string(),
+                value: value.as_display_string(),
+                filter,
+            })
+        })
+        .collect()
+}
+
+/// Searchable columns of a table, and its primary key.
+pub fn search_columns(columns: &[ColumnInfo]) -> (Vec<&str>, Vec<&str>) {
+    let text = columns
+        .iter()
+        .filter(|c| is_searchable_text_type(&c.type_name))
+        .map(|c| c.name.as_str())
+        .collect();
+    let keys = columns
+        .iter()
+        .filter(|c| c.is_primary_key)
+        .map(|c| c.name.as_str())
+        .collect();
+    (text, keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColumnKind, ColumnMeta, DefaultSqlDialect};
+    use std::time::Duration;
+
+    #[test]
+    fn like_pattern_escapes_wildcards() {
+        assert_eq!(like_contains_pattern("50%_off!"), "%50!%!_off!!%");
+    }
+
+    #[test]
+    fn text_types_are_searchable() {
+        for ty in [
+            "varchar(255)",
+            "TEXT",
+            "character varying",
+            "nvarchar",
+            "citext",
+        ] {
+            assert!(is_searchable_text_type(ty), "{ty}");
+        }
+        for ty in ["integer", "uuid", "timestamp", "jsonb"] {
+            assert!(!is_searchable_text_type(ty), "{ty}");
+        }
+    }
+
+    #[test]
+    fn table_query_ors_every_text_column() {
+        let sql = table_search_query(
+            &DefaultSqlDialect,
+            &TableRef::with_schema("public", "users"),
+            &["name", "email"],
+            &["id"],
+            "ann",
+            DataSearchMode::Contains,
+            50,
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"public\".\"users\" WHERE \
+             LOWER(\"name\") LIKE LOWER('%ann%') ESCAPE '!' OR \
+             LOWER(\"email\") LIKE LOWER('%ann%') ESCAPE '!' ORDER BY \"id\" LIMIT 50"
+        );
+
+        // The default dialect has no regex operator.
+        assert!(
+            table_search_query(
+                &DefaultSqlDialect,
+                &TableRef::new("users"),
+                &["name"],
+                &[],
+                "^a",
+                DataSearchMode::Regex,
+                50,
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn hits_filter_on_the_primary_key() {
+        let column = |name: &str| ColumnMeta {
+            name: name.to_string(),
+            type_name: String::new(),
+            kind: ColumnKind::Text,
+            nullable: true,
+            is_primary_key: false,
+        };
+        let result = QueryResult::table(
+            vec![column("id"), column("name"), column("email")],
+            vec![vec![
+                Value::Int(7),
+                Value::Text("Bob".into()),
+                Value::Text("ann@example.com".into()),
+            ]],
+            None,
+            Duration::ZERO,
+        );
+        let table = TableRef::new("users");
+        let contains_ann = |text: &str| text.to_lowercase().contains("ann");
+
+        let hits = collect_search_hits(
+            &DefaultSqlDialect,
+            &table,
+            &result,
+            &["name", "email"],
+            &["id"],
+            contains_ann,
+        );
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].column, "email");
+        assert_eq!(hits[0].value, "ann@example.com");
+        assert_eq!(hits[0].filter, "\"id\" = 7");
+
+        let keyless = collect_search_hits(
+            &DefaultSqlDialect,
+            &table,
+            &result,
+            &["name", "email"],
+            &[],
+            contains_ann,
+        );
+        assert_eq!(keyless[0].filter, "\"email\" = 'ann@example.com'");
+    }
+}
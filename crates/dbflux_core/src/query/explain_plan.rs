@@ -0,0 +1,553 @@
+//! Execution plans read from EXPLAIN output, and node-level comparison of
+//! two plans captured for the same statement.
+//!
+//! PostgreSQL plans are read from both the default text format and
+//! `FORMAT JSON`. Any other tabular EXPLAIN output (MySQL, SQLite, ...) is
+//! kept as a flat list with one node per row.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{QueryResult, Value};
+
+/// Relative change of a cost or row estimate below which two nodes count
+/// as having the same estimates.
+const ESTIMATE_TOLERANCE: f64 = 0.10;
+
+/// Columns of tabular EXPLAIN output that are not part of a node's label.
+const NON_LABEL_COLUMNS: &[&str] = &["id", "parent", "notused", "rows", "filtered"];
+
+/// One operator of an execution plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanNode {
+    /// Operator and its target, e.g. `Seq Scan on users`.
+    pub label: String,
+    /// Estimated total cost, in the planner's own units.
+    #[serde(default)]
+    pub cost: Option<f64>,
+    /// Estimated number of rows the node produces.
+    #[serde(default)]
+    pub rows: Option<f64>,
+    #[serde(default)]
+    pub children: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            cost: None,
+            rows: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_estimates(mut self, cost: Option<f64>, rows: Option<f64>) -> Self {
+        self.cost = cost;
+        self.rows = rows;
+        self
+    }
+
+    pub fn with_child(mut self, child: PlanNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Reads the plan out of the result of an EXPLAIN statement. Returns
+    /// `None` when the result has no rows to build a plan from.
+    pub fn from_explain_result(result: &QueryResult) -> Option<PlanNode> {
+        if result.rows.is_empty() || result.columns.is_empty() {
+            return None;
+        }
+
+        if result.columns.len() == 1 {
+            let cells: Vec<&Value> = result.rows.iter().filter_map(|row| row.first()).collect();
+
+            if let [cell] = cells.as_slice()
+                && let Some(plan) = json_plan(cell)
+            {
+                return Some(plan);
+            }
+
+            let lines: Vec<String> = cells.iter().map(|cell| cell.as_raw_string()).collect();
+            if let Some(plan) = text_plan(&lines) {
+                return Some(plan);
+            }
+        }
+
+        Some(tabular_plan(result))
+    }
+
+    /// Number of nodes in the plan, this one included.
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(PlanNode::node_count)
+            .sum::<usize>()
+    }
+}
+
+/// `[{"Plan": {...}}]` as produced by PostgreSQL's `EXPLAIN (FORMAT JSON)`.
+fn json_plan(cell: &Value) -> Option<PlanNode> {
+    let json = match cell {
+        Value::Json(text) | Value::Text(text) => serde_json::from_str(text).ok()?,
+        Value::Array(_) | Value::Document(_) => Value::to_serde_json(cell),
+        _ => return None,
+    };
+
+    let root = match &json {
+        serde_json::Value::Array(items) => items.first()?,
+        other => other,
+    };
+
+    json_plan_node(root.get("Plan")?)
+}
+
+fn json_plan_node(node: &serde_json::Value) -> Option<PlanNode> {
+    let node_type = node.get("Node Type")?.as_str()?;
+
+    let label = match node
+        .get("Relation Name")
+        .or_else(|| node.get("Index Name"))
+        .and_then(|target| target.as_str())
+    {
+        Some(target) => format!("{node_type} on {target}"),
+        None => node_type.to_string(),
+    };
+
+    let children = node
+        .get("Plans")
+        .and_then(|plans| plans.as_array())
+        .map(|plans| plans.iter().filter_map(json_plan_node).collect())
+        .unwrap_or_default();
+
+    Some(PlanNode {
+        label,
+        cost: node.get("Total Cost").and_then(|v| v.as_f64()),
+        rows: node.get("Plan Rows").and_then(|v| v.as_f64()),
+        children,
+    })
+}
+
+/// PostgreSQL's text plan: the root on the first line, then one `->` line
+/// per child node, nested by indentation. Detail lines (`Filter:`,
+/// `Sort Key:`, ...) are skipped.
+fn text_plan(lines: &[String]) -> Option<PlanNode> {
+    let (first, rest) = lines.split_first()?;
+    let (label, cost, rows) = parse_text_node(first.trim())?;
+
+    let mut stack: Vec<(usize, PlanNode)> =
+        vec![(0, PlanNode::new(label).with_estimates(cost, rows))];
+
+    for line in rest {
+        let Some(arrow) = line.find("->") else {
+            continue;
+        };
+        if !line[..arrow].trim().is_empty() {
+            continue;
+        }
+
+        let Some((label, cost, rows)) = parse_text_node(line[arrow + 2..].trim()) else {
+            continue;
+        };
+        let indent = arrow + 1;
+
+        while stack.len() > 1 && stack.last().is_some_and(|(depth, _)| *depth >= indent) {
+            let (_, node) = stack.pop()?;
+            stack.last_mut()?.1.children.push(node);
+        }
+
+        stack.push((indent, PlanNode::new(label).with_estimates(cost, rows)));
+    }
+
+    while stack.len() > 1 {
+        let (_, node) = stack.pop()?;
+        stack.last_mut()?.1.children.push(node);
+    }
+
+    stack.pop().map(|(_, root)| root)
+}
+
+/// `Seq Scan on users  (cost=0.00..35.50 rows=2550 width=4)` into its
+/// label, total cost and row estimate. Lines without estimates are not
+/// plan nodes.
+fn parse_text_node(line: &str) -> Option<(String, Option<f64>, Option<f64>)> {
+    let start = line.find("(cost=")?;
+    let label = line[..start].trim().to_string();
+    let estimates = &line[start + "(cost=".len()..];
+    let estimates = &estimates[..estimates.find(')')?];
+
+    let mut cost = None;
+    let mut rows = None;
+
+    for (ix, part) in estimates.split_whitespace().enumerate() {
+        if ix == 0 {
+            cost = part.split("..").last().and_then(|v| v.parse().ok());
+        } else if let Some(value) = part.strip_prefix("rows=") {
+            rows = value.parse().ok();
+        }
+    }
+
+    Some((label, cost, rows))
+}
+
+/// One node per row of any other EXPLAIN output, labelled by the row's
+/// descriptive cells.
+fn tabular_plan(result: &QueryResult) -> PlanNode {
+    let rows_column = result
+        .columns
+        .iter()
+        .position(|column| column.name.eq_ignore_ascii_case("rows"));
+
+    let children = result
+        .rows
+        .iter()
+        .map(|row| {
+            let label = result
+                .columns
+                .iter()
+                .zip(row)
+                .filter(|(column, value)| {
+                    !value.is_null()
+                        && !NON_LABEL_COLUMNS
+                            .iter()
+                            .any(|name| column.name.eq_ignore_ascii_case(name))
+                })
+                .map(|(_, value)| value.as_raw_string())
+                .collect::<Vec<_>>()
+                .join(" · ");
+
+            let rows = rows_column
+                .and_then(|ix| row.get(ix))
+                .and_then(|value| value.as_raw_string().parse().ok());
+
+            PlanNode::new(label).with_estimates(None, rows)
+        })
+        .collect();
+
+    PlanNode {
+        label: "Plan".to_string(),
+        cost: None,
+        rows: None,
+        children,
+    }
+}
+
+/// How a node differs between two plans of the same statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanChange {
+    Unchanged,
+    /// Same operator, but its cost or row estimate moved by more than 10%.
+    Estimates,
+    /// A different operator in the same position of the plan.
+    Replaced,
+    /// Only in the newer plan.
+    Added,
+    /// Only in the older plan.
+    Removed,
+}
+
+impl PlanChange {
+    pub fn label(self) -> &'static str {
+        match self {
+            PlanChange::Unchanged => "",
+            PlanChange::Estimates => "estimates changed",
+            PlanChange::Replaced => "node changed",
+            PlanChange::Added => "added",
+            PlanChange::Removed => "removed",
+        }
+    }
+
+    /// Whether the plan's shape differs at this node.
+    pub fn is_structural(self) -> bool {
+        matches!(
+            self,
+            PlanChange::Replaced | PlanChange::Added | PlanChange::Removed
+        )
+    }
+}
+
+/// One node of a plan comparison, in pre-order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanDiffRow<'a> {
+    pub depth: usize,
+    pub change: PlanChange,
+    pub before: Option<&'a PlanNode>,
+    pub after: Option<&'a PlanNode>,
+}
+
+/// Compares two plans node by node. Children are paired by position, so a
+/// node inserted in the middle of a list shows as changes to the nodes
+/// after it.
+pub fn diff_plans<'a>(before: &'a PlanNode, after: &'a PlanNode) -> Vec<PlanDiffRow<'a>> {
+    let mut rows = Vec::new();
+    diff_nodes(Some(before), Some(after), 0, &mut rows);
+    rows
+}
+
+fn diff_nodes<'a>(
+    before: Option<&'a PlanNode>,
+    after: Option<&'a PlanNode>,
+    depth: usize,
+    rows: &mut Vec<PlanDiffRow<'a>>,
+) {
+    let change = match (before, after) {
+        (Some(b), Some(a)) if b.label != a.label => PlanChange::Replaced,
+        (Some(b), Some(a))
+            if estimate_changed(b.cost, a.cost) || estimate_changed(b.rows, a.rows) =>
+        {
+            PlanChange::Estimates
+        }
+        (Some(_), Some(_)) => PlanChange::Unchanged,
+        (Some(_), None) => PlanChange::Removed,
+        (None, Some(_)) => PlanChange::Added,
+        (None, None) => return,
+    };
+
+    rows.push(PlanDiffRow {
+        depth,
+        change,
+        before,
+        after,
+    });
+
+    let before_children = before.map(|node| node.children.as_slice()).unwrap_or(&[]);
+    let after_children = after.map(|node| node.children.as_slice()).unwrap_or(&[]);
+
+    for ix in 0..before_children.len().max(after_children.len()) {
+        diff_nodes(
+            before_children.get(ix),
+            after_children.get(ix),
+            depth + 1,
+            rows,
+        );
+    }
+}
+
+fn estimate_changed(before: Option<f64>, after: Option<f64>) -> bool {
+    match (before, after) {
+        (Some(b), Some(a)) => {
+            let scale = b.abs().max(a.abs());
+            scale > 0.0 && (a - b).abs() / scale > ESTIMATE_TOLERANCE
+        }
+        (None, None) => false,
+        _ => true,
+    }
+}
+
+/// The statement an EXPLAIN query explains, without the `EXPLAIN` keyword
+/// and its options (`ANALYZE`, `VERBOSE`, `(FORMAT JSON)`, `QUERY PLAN`,
+/// `FORMAT=JSON`). `None` when `sql` is not an EXPLAIN.
+pub fn explained_statement(sql: &str) -> Option<&str> {
+    let trimmed = sql.trim_start();
+    let keyword = trimmed.get(..7)?;
+    if !keyword.eq_ignore_ascii_case("explain") {
+        return None;
+    }
+
+    let mut rest = &trimmed[7..];
+    if !rest.starts_with(|c: char| c.is_whitespace() || c == '(') {
+        return None;
+    }
+
+    loop {
+        rest = rest.trim_start();
+
+        if rest.starts_with('(') {
+            rest = &rest[rest.find(')')? + 1..];
+            continue;
+        }
+
+        let word_end = rest.find(|c: char| c.is_whitespace()).unwrap_or(rest.len());
+        let word = &rest[..word_end];
+
+        let is_option = ["analyze", "analyse", "verbose", "query", "plan", "extended"]
+            .iter()
+            .any(|option| word.eq_ignore_ascii_case(option))
+            || word
+                .get(..7)
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case("format="));
+
+        if !is_option || word.is_empty() {
+            break;
+        }
+
+        rest = &rest[word_end..];
+    }
+
+    let statement = rest.trim();
+    (!statement.is_empty()).then_some(statement)
+}
+
+/// Key under which plans of the same statement are grouped: whitespace
+/// runs collapsed and a trailing `;` dropped, so reformatting a query keeps
+/// its plan history.
+pub fn plan_statement_key(sql: &str) -> String {
+    sql.trim()
+        .trim_end_matches(';')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColumnKind, ColumnMeta};
+    use std::time::Duration;
+
+    fn column(name: &str) -> ColumnMeta {
+        ColumnMeta {
+            name: name.to_string(),
+            type_name: "text".to_string(),
+            kind: ColumnKind::Text,
+            nullable: true,
+            is_primary_key: false,
+        }
+    }
+
+    fn text_result(lines: &[&str]) -> QueryResult {
+        QueryResult::table(
+            vec![column("QUERY PLAN")],
+            lines
+                .iter()
+                .map(|line| vec![Value::Text(line.to_string())])
+                .collect(),
+            None,
+            Duration::ZERO,
+        )
+    }
+
+    #[test]
+    fn parses_postgres_text_plan() {
+        let result = text_result(&[
+            "Hash Join  (cost=10.50..45.20 rows=120 width=40)",
+            "  Hash Cond: (o.user_id = u.id)",
+            "  ->  Seq Scan on orders o  (cost=0.00..30.40 rows=2040 width=16)",
+            "  ->  Hash  (cost=8.00..8.00 rows=200 width=24)",
+            "        ->  Index Scan using users_pkey on users u  (cost=0.15..8.00 rows=200 width=24)",
+        ]);
+
+        let plan = PlanNode::from_explain_result(&result).expect("plan");
+
+        assert_eq!(plan.label, "Hash Join");
+        assert_eq!(plan.cost, Some(45.2));
+        assert_eq!(plan.rows, Some(120.0));
+        assert_eq!(plan.children.len(), 2);
+        assert_eq!(plan.children[0].label, "Seq Scan on orders o");
+        assert_eq!(plan.children[1].label, "Hash");
+        assert_eq!(
+            plan.children[1].children[0].label,
+            "Index Scan using users_pkey on users u"
+        );
+        assert_eq!(plan.node_count(), 4);
+    }
+
+    #[test]
+    fn parses_postgres_json_plan() {
+        let json = r#"[{"Plan": {"Node Type": "Limit", "Total Cost": 1.5, "Plan Rows": 10,
+            "Plans": [{"Node Type": "Seq Scan", "Relation Name": "users",
+                       "Total Cost": 35.5, "Plan Rows": 2550}]}}]"#;
+        let result = QueryResult::table(
+            vec![column("QUERY PLAN")],
+            vec![vec![Value::Json(json.to_string())]],
+            None,
+            Duration::ZERO,
+        );
+
+        let plan = PlanNode::from_explain_result(&result).expect("plan");
+
+        assert_eq!(plan.label, "Limit");
+        assert_eq!(plan.children[0].label, "Seq Scan on users");
+        assert_eq!(plan.children[0].rows, Some(2550.0));
+    }
+
+    #[test]
+    fn tabular_plan_has_a_node_per_row() {
+        let result = QueryResult::table(
+            vec![
+                column("id"),
+                column("table"),
+                column("type"),
+                column("rows"),
+            ],
+            vec![vec![
+                Value::Int(1),
+                Value::Text("users".to_string()),
+                Value::Text("ALL".to_string()),
+                Value::Int(2550),
+            ]],
+            None,
+            Duration::ZERO,
+        );
+
+        let plan = PlanNode::from_explain_result(&result).expect("plan");
+
+        assert_eq!(plan.children.len(), 1);
+        assert_eq!(plan.children[0].label, "users · ALL");
+        assert_eq!(plan.children[0].rows, Some(2550.0));
+    }
+
+    #[test]
+    fn diff_reports_replaced_added_and_estimate_changes() {
+        let before = PlanNode::new("Sort")
+            .with_estimates(Some(100.0), Some(50.0))
+            .with_child(PlanNode::new("Seq Scan on users").with_estimates(Some(90.0), Some(50.0)));
+        let after = PlanNode::new("Sort")
+            .with_estimates(Some(400.0), Some(52.0))
+            .with_child(PlanNode::new("Index Scan on users").with_estimates(Some(8.0), Some(50.0)))
+            .with_child(PlanNode::new("Materialize"));
+
+        let changes: Vec<PlanChange> = diff_plans(&before, &after)
+            .iter()
+            .map(|row| row.change)
+            .collect();
+
+        assert_eq!(
+            changes,
+            vec![
+                PlanChange::Estimates,
+                PlanChange::Replaced,
+                PlanChange::Added
+            ]
+        );
+        assert_eq!(
+            diff_plans(&before, &before)
+                .iter()
+                .filter(|row| row.change != PlanChange::Unchanged)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn explained_statement_strips_explain_options() {
+        assert_eq!(explained_statement("EXPLAIN SELECT 1"), Some("SELECT 1"));
+        assert_eq!(
+            explained_statement("explain (analyze, format json) select * from t"),
+            Some("select * from t")
+        );
+        assert_eq!(
+            explained_statement("EXPLAIN ANALYZE VERBOSE SELECT 1"),
+            Some("SELECT 1")
+        );
+        assert_eq!(
+            explained_statement("EXPLAIN QUERY PLAN SELECT 1"),
+            Some("SELECT 1")
+        );
+        assert_eq!(
+            explained_statement("EXPLAIN FORMAT=JSON SELECT 1"),
+            Some("SELECT 1")
+        );
+        assert_eq!(explained_statement("SELECT 1"), None);
+        assert_eq!(explained_statement("EXPLAINED"), None);
+    }
+
+    #[test]
+    fn statement_key_ignores_formatting() {
+        assert_eq!(
+            plan_statement_key("SELECT *\n  FROM users;\n"),
+            plan_statement_key("SELECT * FROM users")
+        );
+    }
+}
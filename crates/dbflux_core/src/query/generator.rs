@@ -1962,6 +1962,8 @@ mod tests {
                 is_primary_key: true,
                 default_value: None,
                 enum_values: None,
+                comment: None,
+                definition: None,
             },
             ColumnInfo {
                 name: "name".to_string(),
@@ -1970,6 +1972,8 @@ mod tests {
                 is_primary_key: false,
                 default_value: None,
                 enum_values: None,
+                comment: None,
+                definition: None,
             },
         ];
 
@@ -2002,6 +2006,8 @@ mod tests {
                 is_primary_key: true,
                 default_value: None,
                 enum_values: None,
+                comment: None,
+                definition: None,
             },
             ColumnInfo {
                 name: "name".to_string(),
@@ -2010,6 +2016,8 @@ mod tests {
                 is_primary_key: false,
                 default_value: None,
                 enum_values: None,
+                comment: None,
+                definition: None,
             },
         ];
 
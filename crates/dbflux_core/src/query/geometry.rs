@@ -0,0 +1,822 @@
+//! Spatial values (PostGIS `geometry`/`geography`, MySQL `GEOMETRY`) decoded
+//! from their binary or text forms so they can be shown as WKT or GeoJSON
+//! instead of opaque bytes.
+
+use std::fmt::Write as _;
+
+use crate::Value;
+
+/// Type names, lowercased, that hold spatial values.
+const SPATIAL_TYPES: &[&str] = &[
+    "geometry",
+    "geography",
+    "point",
+    "linestring",
+    "polygon",
+    "multipoint",
+    "multilinestring",
+    "multipolygon",
+    "geometrycollection",
+    "geomcollection",
+];
+
+/// EWKB flags PostGIS sets in the high bits of the geometry type.
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Whether a column of `type_name` holds spatial values.
+pub fn is_spatial_type(type_name: &str) -> bool {
+    let name = type_name.trim().to_ascii_lowercase();
+    SPATIAL_TYPES.contains(&name.as_str())
+}
+
+/// Ordinates a geometry carries besides X and Y.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeometryDims {
+    pub z: bool,
+    pub m: bool,
+}
+
+impl GeometryDims {
+    fn ordinates(self) -> usize {
+        2 + usize::from(self.z) + usize::from(self.m)
+    }
+
+    fn wkt_tag(self) -> &'static str {
+        match (self.z, self.m) {
+            (false, false) => "",
+            (true, false) => " Z",
+            (false, true) => " M",
+            (true, true) => " ZM",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+    pub m: Option<f64>,
+}
+
+impl Coord {
+    fn from_ordinates(ordinates: &[f64], dims: GeometryDims) -> Self {
+        let extra = |ix: usize| ordinates.get(ix).copied();
+
+        Self {
+            x: ordinates[0],
+            y: ordinates[1],
+            z: dims.z.then(|| extra(2)).flatten(),
+            m: dims.m.then(|| extra(if dims.z { 3 } else { 2 })).flatten(),
+        }
+    }
+}
+
+/// A simple-features geometry. Rings and line strings are lists of
+/// coordinates; `Point(None)` is `POINT EMPTY`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Option<Coord>),
+    LineString(Vec<Coord>),
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+impl Geometry {
+    fn wkt_name(&self) -> &'static str {
+        match self {
+            Self::Point(_) => "POINT",
+            Self::LineString(_) => "LINESTRING",
+            Self::Polygon(_) => "POLYGON",
+            Self::MultiPoint(_) => "MULTIPOINT",
+            Self::MultiLineString(_) => "MULTILINESTRING",
+            Self::MultiPolygon(_) => "MULTIPOLYGON",
+            Self::GeometryCollection(_) => "GEOMETRYCOLLECTION",
+        }
+    }
+
+    fn geojson_name(&self) -> &'static str {
+        match self {
+            Self::Point(_) => "Point",
+            Self::LineString(_) => "LineString",
+            Self::Polygon(_) => "Polygon",
+            Self::MultiPoint(_) => "MultiPoint",
+            Self::MultiLineString(_) => "MultiLineString",
+            Self::MultiPolygon(_) => "MultiPolygon",
+            Self::GeometryCollection(_) => "GeometryCollection",
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Point(coord) => coord.is_none(),
+            Self::LineString(coords) | Self::MultiPoint(coords) => coords.is_empty(),
+            Self::Polygon(rings) | Self::MultiLineString(rings) => rings.is_empty(),
+            Self::MultiPolygon(polygons) => polygons.is_empty(),
+            Self::GeometryCollection(geometries) => geometries.is_empty(),
+        }
+    }
+
+    /// Calls `f` with every coordinate of the geometry.
+    pub fn for_each_coord(&self, f: &mut impl FnMut(&Coord)) {
+        match self {
+            Self::Point(coord) => coord.iter().for_each(f),
+            Self::LineString(coords) | Self::MultiPoint(coords) => coords.iter().for_each(f),
+            Self::Polygon(rings) | Self::MultiLineString(rings) => {
+                rings.iter().flatten().for_each(f)
+            }
+            Self::MultiPolygon(polygons) => polygons.iter().flatten().flatten().for_each(f),
+            Self::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    geometry.for_each_coord(f);
+                }
+            }
+        }
+    }
+
+    /// `(min_x, min_y, max_x, max_y)` of the geometry, or `None` when empty.
+    pub fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let mut bounds: Option<(f64, f64, f64, f64)> = None;
+
+        self.for_each_coord(&mut |coord| {
+            bounds = Some(match bounds {
+                None => (coord.x, coord.y, coord.x, coord.y),
+                Some((min_x, min_y, max_x, max_y)) => (
+                    min_x.min(coord.x),
+                    min_y.min(coord.y),
+                    max_x.max(coord.x),
+                    max_y.max(coord.y),
+                ),
+            });
+        });
+
+        bounds
+    }
+}
+
+/// A geometry with its spatial reference and dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialValue {
+    pub srid: Option<u32>,
+    pub dims: GeometryDims,
+    pub geometry: Geometry,
+}
+
+impl SpatialValue {
+    /// Decodes a cell of a spatial column: WKT or EWKT text, hex-encoded
+    /// EWKB text, or WKB/EWKB/MySQL-internal bytes.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Text(text) => Self::from_wkt(text).or_else(|| {
+                let bytes = decode_hex(text.trim())?;
+                Self::from_wkb(&bytes)
+            }),
+            Value::Bytes(bytes) => Self::from_wkb(bytes).or_else(|| Self::from_mysql(bytes)),
+            _ => None,
+        }
+    }
+
+    /// Decodes OGC WKB, ISO WKB with Z/M type codes, or PostGIS EWKB.
+    /// Trailing bytes make the input invalid.
+    pub fn from_wkb(bytes: &[u8]) -> Option<Self> {
+        let mut reader = WkbReader { bytes, pos: 0 };
+        let (srid, dims, geometry) = reader.read_geometry()?;
+
+        (reader.pos == bytes.len()).then_some(Self {
+            srid,
+            dims,
+            geometry,
+        })
+    }
+
+    /// Decodes MySQL's internal format: a little-endian SRID followed by WKB.
+    pub fn from_mysql(bytes: &[u8]) -> Option<Self> {
+        let srid = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?);
+        let value = Self::from_wkb(&bytes[4..])?;
+
+        Some(Self {
+            srid: Some(srid).filter(|srid| *srid != 0),
+            ..value
+        })
+    }
+
+    /// Parses WKT, optionally prefixed with `SRID=<n>;` as in PostGIS EWKT.
+    pub fn from_wkt(text: &str) -> Option<Self> {
+        let text = text.trim();
+
+        let (srid, wkt) = match text.get(..5) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("SRID=") => {
+                let (srid, wkt) = text[5..].split_once(';')?;
+                (Some(srid.trim().parse().ok()?), wkt)
+            }
+            _ => (None, text),
+        };
+
+        let mut parser = WktParser {
+            tokens: tokenize_wkt(wkt)?,
+            pos: 0,
+        };
+        let (dims, geometry) = parser.parse_geometry(None)?;
+
+        (parser.pos == parser.tokens.len()).then_some(Self {
+            srid,
+            dims,
+            geometry,
+        })
+    }
+
+    /// WKT text, prefixed with `SRID=<n>;` when the value has a spatial
+    /// reference. PostGIS accepts this form as input.
+    pub fn to_ewkt(&self) -> String {
+        let mut out = String::new();
+
+        if let Some(srid) = self.srid.filter(|srid| *srid != 0) {
+            let _ = write!(out, "SRID={};", srid);
+        }
+
+        write_wkt(&mut out, &self.geometry, self.dims);
+        out
+    }
+
+    /// GeoJSON geometry object. GeoJSON has no M ordinate, so M values are
+    /// dropped.
+    pub fn to_geojson(&self) -> serde_json::Value {
+        geojson(&self.geometry)
+    }
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.is_empty() || !text.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..text.len())
+        .step_by(2)
+        .map(|ix| u8::from_str_radix(text.get(ix..ix + 2)?, 16).ok())
+        .collect()
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let chunk = self.bytes.get(self.pos..self.pos + N)?;
+        self.pos += N;
+        chunk.try_into().ok()
+    }
+
+    fn read_u32(&mut self, little: bool) -> Option<u32> {
+        let bytes = self.take::<4>()?;
+        Some(if little {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, little: bool) -> Option<f64> {
+        let bytes = self.take::<8>()?;
+        Some(if little {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Reads an element count, rejecting counts the remaining bytes cannot
+    /// hold so corrupt input cannot trigger huge allocations.
+    fn read_count(&mut self, little: bool, min_element_size: usize) -> Option<usize> {
+        let count = self.read_u32(little)? as usize;
+        let remaining = self.bytes.len() - self.pos;
+
+        (count.checked_mul(min_element_size)? <= remaining).then_some(count)
+    }
+
+    fn read_coord(&mut self, little: bool, dims: GeometryDims) -> Option<Coord> {
+        let mut ordinates = [0.0; 4];
+
+        for ordinate in ordinates.iter_mut().take(dims.ordinates()) {
+            *ordinate = self.read_f64(little)?;
+        }
+
+        Some(Coord::from_ordinates(&ordinates[..dims.ordinates()], dims))
+    }
+
+    fn read_coords(&mut self, little: bool, dims: GeometryDims) -> Option<Vec<Coord>> {
+        let count = self.read_count(little, dims.ordinates() * 8)?;
+        (0..count).map(|_| self.read_coord(little, dims)).collect()
+    }
+
+    fn read_rings(&mut self, little: bool, dims: GeometryDims) -> Option<Vec<Vec<Coord>>> {
+        let count = self.read_count(little, 4)?;
+        (0..count).map(|_| self.read_coords(little, dims)).collect()
+    }
+
+    fn read_members(&mut self, little: bool) -> Option<Vec<Geometry>> {
+        // Byte order plus type is the smallest possible member.
+        let count = self.read_count(little, 5)?;
+        (0..count)
+            .map(|_| self.read_geometry().map(|(_, _, geometry)| geometry))
+            .collect()
+    }
+
+    fn read_geometry(&mut self) -> Option<(Option<u32>, GeometryDims, Geometry)> {
+        let little = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+
+        let raw_type = self.read_u32(little)?;
+        let iso_type = raw_type & 0x0FFF_FFFF;
+        let iso_dims = iso_type / 1000;
+
+        let dims = GeometryDims {
+            z: raw_type & EWKB_Z != 0 || matches!(iso_dims, 1 | 3),
+            m: raw_type & EWKB_M != 0 || matches!(iso_dims, 2 | 3),
+        };
+
+        let srid = if raw_type & EWKB_SRID != 0 {
+            Some(self.read_u32(little)?)
+        } else {
+            None
+        };
+
+        let geometry = match iso_type % 1000 {
+            1 => {
+                let coord = self.read_coord(little, dims)?;
+                // WKB encodes `POINT EMPTY` as NaN coordinates.
+                Geometry::Point((!coord.x.is_nan() || !coord.y.is_nan()).then_some(coord))
+            }
+            2 => Geometry::LineString(self.read_coords(little, dims)?),
+            3 => Geometry::Polygon(self.read_rings(little, dims)?),
+            4 => Geometry::MultiPoint(
+                self.read_members(little)?
+                    .into_iter()
+                    .map(|member| match member {
+                        Geometry::Point(coord) => Some(coord),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            ),
+            5 => Geometry::MultiLineString(
+                self.read_members(little)?
+                    .into_iter()
+                    .map(|member| match member {
+                        Geometry::LineString(coords) => Some(coords),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            6 => Geometry::MultiPolygon(
+                self.read_members(little)?
+                    .into_iter()
+                    .map(|member| match member {
+                        Geometry::Polygon(rings) => Some(rings),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            7 => Geometry::GeometryCollection(self.read_members(little)?),
+            _ => return None,
+        };
+
+        Some((srid, dims, geometry))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum WktToken {
+    Word(String),
+    Number(f64),
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize_wkt(text: &str) -> Option<Vec<WktToken>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some(&(start, ch)) = chars.peek() {
+        match ch {
+            '(' => tokens.push(WktToken::Open),
+            ')' => tokens.push(WktToken::Close),
+            ',' => tokens.push(WktToken::Comma),
+            ch if ch.is_whitespace() => {}
+            ch if ch.is_ascii_alphabetic() => {
+                let mut end = start;
+                while let Some(&(ix, ch)) = chars.peek() {
+                    if !ch.is_ascii_alphabetic() {
+                        break;
+                    }
+                    end = ix + ch.len_utf8();
+                    chars.next();
+                }
+                tokens.push(WktToken::Word(text[start..end].to_ascii_uppercase()));
+                continue;
+            }
+            ch if ch.is_ascii_digit() || matches!(ch, '-' | '+' | '.') => {
+                let mut end = start;
+                let mut prev = None;
+                while let Some(&(ix, ch)) = chars.peek() {
+                    // Signs only lead the number or its exponent.
+                    let sign_allowed = matches!(prev, None | Some('e' | 'E'));
+                    let in_number = ch.is_ascii_digit()
+                        || matches!(ch, '.' | 'e' | 'E')
+                        || (matches!(ch, '-' | '+') && sign_allowed);
+                    if !in_number {
+                        break;
+                    }
+                    end = ix + 1;
+                    prev = Some(ch);
+                    chars.next();
+                }
+                tokens.push(WktToken::Number(text[start..end].parse().ok()?));
+                continue;
+            }
+            _ => return None,
+        }
+
+        chars.next();
+    }
+
+    Some(tokens)
+}
+
+struct WktParser {
+    tokens: Vec<WktToken>,
+    pos: usize,
+}
+
+impl WktParser {
+    fn next_token(&mut self) -> Option<WktToken> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&WktToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn expect(&mut self, token: WktToken) -> Option<()> {
+        (self.next_token()? == token).then_some(())
+    }
+
+    /// Consumes `EMPTY`, reporting whether it was there.
+    fn empty(&mut self) -> bool {
+        if self.peek() == Some(&WktToken::Word("EMPTY".to_string())) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Parses a parenthesised, comma-separated list of `item`s.
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Option<T>) -> Option<Vec<T>> {
+        if self.empty() {
+            return Some(Vec::new());
+        }
+
+        self.expect(WktToken::Open)?;
+        let mut items = vec![item(self)?];
+
+        loop {
+            match self.next_token()? {
+                WktToken::Comma => items.push(item(self)?),
+                WktToken::Close => return Some(items),
+                _ => return None,
+            }
+        }
+    }
+
+    fn coord(&mut self, dims: &mut Option<GeometryDims>) -> Option<Coord> {
+        let mut ordinates = Vec::with_capacity(4);
+
+        while let Some(&WktToken::Number(number)) = self.peek() {
+            ordinates.push(number);
+            self.pos += 1;
+        }
+
+        // Without a Z/M tag the first coordinate decides the dimensions.
+        let dims = *dims.get_or_insert(GeometryDims {
+            z: ordinates.len() >= 3,
+            m: ordinates.len() == 4,
+        });
+
+        (ordinates.len() == dims.ordinates()).then(|| Coord::from_ordinates(&ordinates, dims))
+    }
+
+    /// A coordinate list, also accepting MULTIPOINT's `((1 2), (3 4))` form.
+    fn coords(&mut self, dims: &mut Option<GeometryDims>) -> Option<Vec<Coord>> {
+        self.list(|parser| {
+            if parser.peek() == Some(&WktToken::Open) {
+                parser.pos += 1;
+                let coord = parser.coord(dims)?;
+                parser.expect(WktToken::Close)?;
+                Some(coord)
+            } else {
+                parser.coord(dims)
+            }
+        })
+    }
+
+    fn parse_geometry(
+        &mut self,
+        parent_dims: Option<GeometryDims>,
+    ) -> Option<(GeometryDims, Geometry)> {
+        let WktToken::Word(word) = self.next_token()? else {
+            return None;
+        };
+
+        // EWKT writes `POINTM(...)`; ISO WKT writes `POINT M (...)`.
+        let (name, mut dims) = match word.strip_suffix('M') {
+            Some(name) => (name.to_string(), Some(GeometryDims { z: false, m: true })),
+            None => (word, None),
+        };
+
+        if let Some(WktToken::Word(tag)) = self.peek() {
+            let tagged = match tag.as_str() {
+                "Z" => Some(GeometryDims { z: true, m: false }),
+                "M" => Some(GeometryDims { z: false, m: true }),
+                "ZM" => Some(GeometryDims { z: true, m: true }),
+                _ => None,
+            };
+            if tagged.is_some() {
+                dims = tagged;
+                self.pos += 1;
+            }
+        }
+
+        let mut dims = dims.or(parent_dims);
+
+        let geometry = match name.as_str() {
+            "POINT" => {
+                if self.empty() {
+                    Geometry::Point(None)
+                } else {
+                    self.expect(WktToken::Open)?;
+                    let coord = self.coord(&mut dims)?;
+                    self.expect(WktToken::Close)?;
+                    Geometry::Point(Some(coord))
+                }
+            }
+            "LINESTRING" => Geometry::LineString(self.coords(&mut dims)?),
+            "POLYGON" => Geometry::Polygon(self.list(|parser| parser.coords(&mut dims))?),
+            "MULTIPOINT" => Geometry::MultiPoint(self.coords(&mut dims)?),
+            "MULTILINESTRING" => {
+                Geometry::MultiLineString(self.list(|parser| parser.coords(&mut dims))?)
+            }
+            "MULTIPOLYGON" => Geometry::MultiPolygon(
+                self.list(|parser| parser.list(|parser| parser.coords(&mut dims)))?,
+            ),
+            "GEOMETRYCOLLECTION" => Geometry::GeometryCollection(self.list(|parser| {
+                let (member_dims, member) = parser.parse_geometry(dims)?;
+                dims.get_or_insert(member_dims);
+                Some(member)
+            })?),
+            _ => return None,
+        };
+
+        Some((dims.unwrap_or_default(), geometry))
+    }
+}
+
+fn write_coord(out: &mut String, coord: &Coord, dims: GeometryDims) {
+    let _ = write!(out, "{} {}", coord.x, coord.y);
+
+    for ordinate in [coord.z.filter(|_| dims.z), coord.m.filter(|_| dims.m)]
+        .into_iter()
+        .flatten()
+    {
+        let _ = write!(out, " {}", ordinate);
+    }
+}
+
+fn write_list<T>(out: &mut String, items: &[T], mut item: impl FnMut(&mut String, &T)) {
+    if items.is_empty() {
+        out.push_str(" EMPTY");
+        return;
+    }
+
+    out.push('(');
+    for (ix, value) in items.iter().enumerate() {
+        if ix > 0 {
+            out.push(',');
+        }
+        item(out, value);
+    }
+    out.push(')');
+}
+
+fn write_wkt(out: &mut String, geometry: &Geometry, dims: GeometryDims) {
+    out.push_str(geometry.wkt_name());
+    out.push_str(dims.wkt_tag());
+
+    // `POINT Z (1 2 3)` but `POINT(1 2)`, matching PostGIS output. The
+    // `EMPTY` writers add their own separator.
+    if !dims.wkt_tag().is_empty() && !geometry.is_empty() {
+        out.push(' ');
+    }
+
+    let coords = |out: &mut String, coords: &Vec<Coord>| {
+        write_list(out, coords, |out, coord| write_coord(out, coord, dims));
+    };
+
+    match geometry {
+        Geometry::Point(None) => out.push_str(" EMPTY"),
+        Geometry::Point(Some(coord)) => {
+            out.push('(');
+            write_coord(out, coord, dims);
+            out.push(')');
+        }
+        Geometry::LineString(line) | Geometry::MultiPoint(line) => coords(out, line),
+        Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+            write_list(out, rings, coords);
+        }
+        Geometry::MultiPolygon(polygons) => write_list(out, polygons, |out, rings| {
+            write_list(out, rings, coords);
+        }),
+        Geometry::GeometryCollection(members) => write_list(out, members, |out, member| {
+            write_wkt(out, member, dims);
+        }),
+    }
+}
+
+fn geojson_position(coord: &Coord) -> serde_json::Value {
+    let mut position = vec![coord.x, coord.y];
+    position.extend(coord.z);
+    serde_json::json!(position)
+}
+
+fn geojson_line(coords: &[Coord]) -> serde_json::Value {
+    coords.iter().map(geojson_position).collect()
+}
+
+fn geojson_rings(rings: &[Vec<Coord>]) -> serde_json::Value {
+    rings.iter().map(|ring| geojson_line(ring)).collect()
+}
+
+fn geojson(geometry: &Geometry) -> serde_json::Value {
+    let key_and_value = match geometry {
+        Geometry::Point(coord) => (
+            "coordinates",
+            coord
+                .as_ref()
+                .map_or_else(|| serde_json::json!([]), geojson_position),
+        ),
+        Geometry::LineString(coords) | Geometry::MultiPoint(coords) => {
+            ("coordinates", geojson_line(coords))
+        }
+        Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+            ("coordinates", geojson_rings(rings))
+        }
+        Geometry::MultiPolygon(polygons) => (
+            "coordinates",
+            polygons.iter().map(|rings| geojson_rings(rings)).collect(),
+        ),
+        Geometry::GeometryCollection(members) => {
+            ("geometries", members.iter().map(geojson).collect())
+        }
+    };
+
+    let mut object = serde_json::Map::new();
+    object.insert("type".to_string(), geometry.geojson_name().into());
+    object.insert(key_and_value.0.to_string(), key_and_value.1);
+    serde_json::Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(wkt: &str) -> String {
+        SpatialValue::from_wkt(wkt)
+            .unwrap_or_else(|| panic!("failed to parse {wkt}"))
+            .to_ewkt()
+    }
+
+    #[test]
+    fn wkt_round_trips_through_ewkt() {
+        assert_eq!(round_trip("point (1 2)"), "POINT(1 2)");
+        assert_eq!(
+            round_trip("SRID=4326;POINT(-71.06 42.35)"),
+            "SRID=4326;POINT(-71.06 42.35)"
+        );
+        assert_eq!(round_trip("POINT Z (1 2 3)"), "POINT Z (1 2 3)");
+        assert_eq!(round_trip("POINTM(1 2 5)"), "POINT M (1 2 5)");
+        assert_eq!(
+            round_trip("LINESTRING(0 0, 1 1.5e1)"),
+            "LINESTRING(0 0,1 15)"
+        );
+        assert_eq!(
+            round_trip("POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,1 2,1 1))"),
+            "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,1 2,1 1))"
+        );
+        assert_eq!(round_trip("MULTIPOINT((1 2),(3 4))"), "MULTIPOINT(1 2,3 4)");
+        assert_eq!(
+            round_trip("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY)"),
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY)"
+        );
+        assert_eq!(round_trip("POINT EMPTY"), "POINT EMPTY");
+    }
+
+    #[test]
+    fn invalid_wkt_is_rejected() {
+        assert!(SpatialValue::from_wkt("POINT(1)").is_none());
+        assert!(SpatialValue::from_wkt("POINT(1 2) trailing").is_none());
+        assert!(SpatialValue::from_wkt("CIRCLE(1 2)").is_none());
+        assert!(SpatialValue::from_wkt("SRID=x;POINT(1 2)").is_none());
+        assert!(SpatialValue::from_wkt("").is_none());
+    }
+
+    #[test]
+    fn ewkb_point_with_srid_decodes() {
+        // SRID=4326;POINT(1 2) as PostGIS sends it.
+        let mut ewkb = vec![1];
+        ewkb.extend_from_slice(&(1 | EWKB_SRID).to_le_bytes());
+        ewkb.extend_from_slice(&4326u32.to_le_bytes());
+        ewkb.extend_from_slice(&1.0f64.to_le_bytes());
+        ewkb.extend_from_slice(&2.0f64.to_le_bytes());
+
+        let value = SpatialValue::from_wkb(&ewkb).unwrap();
+        assert_eq!(value.to_ewkt(), "SRID=4326;POINT(1 2)");
+
+        let hex: String = ewkb.iter().map(|byte| format!("{byte:02X}")).collect();
+        assert_eq!(SpatialValue::from_value(&Value::Text(hex)), Some(value));
+    }
+
+    #[test]
+    fn big_endian_iso_wkb_and_mysql_layout_decode() {
+        // LINESTRING Z (0 0 1, 2 3 4) as big-endian ISO WKB.
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&1002u32.to_be_bytes());
+        wkb.extend_from_slice(&2u32.to_be_bytes());
+        for ordinate in [0.0f64, 0.0, 1.0, 2.0, 3.0, 4.0] {
+            wkb.extend_from_slice(&ordinate.to_be_bytes());
+        }
+        assert_eq!(
+            SpatialValue::from_wkb(&wkb).unwrap().to_ewkt(),
+            "LINESTRING Z (0 0 1,2 3 4)"
+        );
+
+        let mut mysql = 3857u32.to_le_bytes().to_vec();
+        mysql.push(1);
+        mysql.extend_from_slice(&1u32.to_le_bytes());
+        mysql.extend_from_slice(&5.0f64.to_le_bytes());
+        mysql.extend_from_slice(&6.0f64.to_le_bytes());
+        assert_eq!(
+            SpatialValue::from_value(&Value::Bytes(mysql))
+                .unwrap()
+                .to_ewkt(),
+            "SRID=3857;POINT(5 6)"
+        );
+
+        // A count larger than the input is rejected without allocating.
+        let mut corrupt = vec![1];
+        corrupt.extend_from_slice(&2u32.to_le_bytes());
+        corrupt.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(SpatialValue::from_wkb(&corrupt).is_none());
+    }
+
+    #[test]
+    fn geojson_and_bounds() {
+        let value =
+            SpatialValue::from_wkt("MULTIPOLYGON(((0 0,2 0,2 3,0 0)),((5 -1,6 -1,6 0,5 -1)))")
+                .unwrap();
+
+        assert_eq!(
+            value.to_geojson(),
+            serde_json::json!({
+                "type": "MultiPolygon",
+                "coordinates": [
+                    [[[0.0, 0.0], [2.0, 0.0], [2.0, 3.0], [0.0, 0.0]]],
+                    [[[5.0, -1.0], [6.0, -1.0], [6.0, 0.0], [5.0, -1.0]]]
+                ]
+            })
+        );
+        assert_eq!(value.geometry.bounds(), Some((0.0, -1.0, 6.0, 3.0)));
+        assert_eq!(Geometry::Point(None).bounds(), None);
+    }
+
+    #[test]
+    fn spatial_type_names() {
+        assert!(is_spatial_type("geometry"));
+        assert!(is_spatial_type("GEOMETRY"));
+        assert!(is_spatial_type("geography"));
+        assert!(!is_spatial_type("bytea"));
+    }
+}
@@ -0,0 +1,101 @@
+use super::user_admin::mysql_string_literal;
+use crate::DbKind;
+
+/// Per-driver statements for running a query as another role or user.
+///
+/// Impersonation is applied around each execution rather than left on the
+/// session, because a connection can be shared by several documents and one
+/// tab's role must not leak into another's queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoleImpersonation {
+    kind: DbKind,
+}
+
+impl RoleImpersonation {
+    /// Returns `None` for drivers without role switching (SQLite and the
+    /// non-SQL drivers).
+    pub fn for_kind(kind: DbKind) -> Option<Self> {
+        match kind {
+            DbKind::Postgres | DbKind::MySQL | DbKind::MariaDB | DbKind::SqlServer => {
+                Some(Self { kind })
+            }
+            DbKind::SQLite
+            | DbKind::MongoDB
+            | DbKind::Redis
+            | DbKind::DynamoDB
+            | DbKind::CloudWatchLogs
+            | DbKind::InfluxDB => None,
+        }
+    }
+
+    /// Statement that switches the session to `role`, quoted for the driver.
+    pub fn set_role_sql(&self, role: &str) -> String {
+        match self.kind {
+            DbKind::SqlServer => format!("EXECUTE AS USER = N'{}'", role.replace('\'', "''")),
+            DbKind::MySQL | DbKind::MariaDB => {
+                format!("SET ROLE {}", mysql_string_literal(role, true))
+            }
+            _ => format!("SET ROLE \"{}\"", role.replace('"', "\"\"")),
+        }
+    }
+
+    /// Statement that returns the session to the login role.
+    pub fn reset_sql(&self) -> &'static str {
+        match self.kind {
+            DbKind::SqlServer => "REVERT",
+            DbKind::MySQL => "SET ROLE DEFAULT",
+            DbKind::MariaDB => "SET ROLE NONE",
+            _ => "RESET ROLE",
+        }
+    }
+
+    /// What the driver switches: a database user on SQL Server, a role
+    /// elsewhere.
+    pub fn subject_label(&self) -> &'static str {
+        match self.kind {
+            DbKind::SqlServer => "user",
+            _ => "role",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postgres_quotes_the_role_as_an_identifier() {
+        let impersonation = RoleImpersonation::for_kind(DbKind::Postgres).unwrap();
+        assert_eq!(
+            impersonation.set_role_sql("app \"reader\""),
+            "SET ROLE \"app \"\"reader\"\"\""
+        );
+        assert_eq!(impersonation.reset_sql(), "RESET ROLE");
+    }
+
+    #[test]
+    fn sqlserver_executes_as_user_and_reverts() {
+        let impersonation = RoleImpersonation::for_kind(DbKind::SqlServer).unwrap();
+        assert_eq!(
+            impersonation.set_role_sql("o'brien"),
+            "EXECUTE AS USER = N'o''brien'"
+        );
+        assert_eq!(impersonation.reset_sql(), "REVERT");
+        assert_eq!(impersonation.subject_label(), "user");
+    }
+
+    #[test]
+    fn mysql_escapes_backslashes_and_quotes_in_the_role() {
+        let impersonation = RoleImpersonation::for_kind(DbKind::MySQL).unwrap();
+        assert_eq!(
+            impersonation.set_role_sql("a\\' OR '1"),
+            "SET ROLE 'a\\\\'' OR ''1'"
+        );
+    }
+
+    #[test]
+    fn drivers_without_roles_return_none() {
+        assert!(RoleImpersonation::for_kind(DbKind::SQLite).is_none());
+        assert!(RoleImpersonation::for_kind(DbKind::MongoDB).is_none());
+    }
+}
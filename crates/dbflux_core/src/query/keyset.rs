@@ -67,6 +67,52 @@ pub fn lower_keyset_predicate(
     }
 }
 
+/// Builds the literal seek predicate that starts a keyset page after `after`.
+///
+/// Same lexicographic expansion as [`lower_keyset_predicate`], with values
+/// inlined through the dialect and unqualified columns, for browse queries
+/// that are built as plain SQL text. `columns` and `after` must have the same
+/// length.
+pub(crate) fn seek_after_predicate(
+    columns: &[String],
+    after: &[Value],
+    dialect: &dyn SqlDialect,
+) -> String {
+    debug_assert_eq!(columns.len(), after.len());
+
+    let clauses: Vec<String> = (0..columns.len().min(after.len()))
+        .map(|prefix_len| {
+            let mut parts: Vec<String> = (0..prefix_len)
+                .map(|i| {
+                    format!(
+                        "{} = {}",
+                        dialect.quote_identifier(&columns[i]),
+                        dialect.value_to_literal(&after[i])
+                    )
+                })
+                .collect();
+
+            parts.push(format!(
+                "{} > {}",
+                dialect.quote_identifier(&columns[prefix_len]),
+                dialect.value_to_literal(&after[prefix_len])
+            ));
+
+            if parts.len() == 1 {
+                parts.remove(0)
+            } else {
+                format!("({})", parts.join(" AND "))
+            }
+        })
+        .collect();
+
+    if clauses.len() == 1 {
+        clauses.into_iter().next().unwrap_or_default()
+    } else {
+        format!("({})", clauses.join(" OR "))
+    }
+}
+
 fn qualified_col(dialect: &dyn SqlDialect, table_alias: &str, col: &str) -> String {
     format!(
         "{}.{}",
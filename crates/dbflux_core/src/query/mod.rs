@@ -1,23 +1,40 @@
 pub(crate) mod column_kind;
+pub(crate) mod data_search;
+pub(crate) mod explain_plan;
 pub(crate) mod generator;
+pub(crate) mod geometry;
+pub(crate) mod impersonation;
 pub(crate) mod keyset;
 pub(crate) mod language_service;
 pub mod relational_filter;
 pub(crate) mod safety;
+pub(crate) mod saved_query_params;
 pub(crate) mod semantic;
+pub(crate) mod session_scope;
 pub(crate) mod table_browser;
 pub(crate) mod time_macros;
 pub(crate) mod tx_vocab;
 pub(crate) mod types;
+pub(crate) mod user_admin;
+pub(crate) mod variables;
 pub(crate) mod visual_query;
 
 pub use column_kind::{infer_column_kind, project_aggregate_kinds};
+pub use data_search::{
+    DataSearchHit, DataSearchMode, collect_search_hits, is_searchable_text_type,
+    like_contains_pattern, search_columns, table_search_query,
+};
+pub use explain_plan::{
+    PlanChange, PlanDiffRow, PlanNode, diff_plans, explained_statement, plan_statement_key,
+};
 pub use generator::{
     CollectionTemplateRequest, GeneratedMutation, GeneratedQuery, GeneratorError, MutationCategory,
     MutationTemplateOperation, MutationTemplateRequest, QueryGenError, QueryGenerator,
     ReadTemplateOperation, ReadTemplateRequest, SelectQuery, SqlMutationGenerator, inline_params,
     render_filter_node_sql,
 };
+pub use geometry::{Coord, Geometry, GeometryDims, SpatialValue, is_spatial_type};
+pub use impersonation::RoleImpersonation;
 pub use keyset::lower_keyset_predicate;
 pub use language_service::{
     ClassifiedMutation, DangerousQueryKind, Diagnostic, DiagnosticSeverity, EditorDiagnostic,
@@ -26,11 +43,17 @@ pub use language_service::{
     classify_visual_mutation, detect_dangerous_query, detect_dangerous_sql, strip_leading_comments,
 };
 pub use safety::{classify_query_for_governance, classify_sql_execution, is_safe_read_query};
+pub use saved_query_params::{
+    SavedQueryParam, SavedQueryParamError, SavedQueryParamRef, SavedQueryParamType,
+    bind_saved_query_params, carry_saved_query_param_values, find_saved_query_params,
+    format_saved_query_params, parse_saved_query_params, references_saved_query_params,
+};
 pub use semantic::{
     AggregateFunction, AggregateRequest, AggregateSpec, PlannedQuery, SemanticFieldRef,
     SemanticFilter, SemanticPlan, SemanticPlanKind, SemanticPlanner, SemanticPredicate,
     SemanticRequest, SemanticRequestKind, parse_semantic_filter_json, render_semantic_filter_sql,
 };
+pub use session_scope::{SessionGate, SessionGuard, SessionScope};
 pub use table_browser::{
     CollectionBrowseRequest, CollectionCountRequest, CollectionRef, ColumnRef, DescribeRequest,
     ExplainRequest, OrderByColumn, Pagination, SortDirection, TableBrowseRequest,
@@ -39,9 +62,14 @@ pub use table_browser::{
 pub use time_macros::{contains_time_macros, substitute_time_macros};
 pub use tx_vocab::TransactionVocab;
 pub use types::{
-    ColumnKind, ColumnMeta, QueryHandle, QueryRequest, QueryResult, QueryResultShape,
+    ColumnKind, ColumnMeta, QueryHandle, QueryRequest, QueryResult, QueryResultShape, QueryTiming,
     ResolvedWindow, Row,
 };
+pub use user_admin::{GrantSpec, GrantTarget, UserAccount, UserAdminDialect, UserSpec};
+pub use variables::{
+    QueryVariableRef, QueryVariableScope, UndefinedQueryVariables, find_query_variables,
+    query_variable_names, substitute_query_variables,
+};
 pub use visual_query::AggregateSpec as VisualAggregateSpec;
 pub use visual_query::SortDirection as VisualSortDirection;
 pub use visual_query::{
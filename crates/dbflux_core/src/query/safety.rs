@@ -70,7 +70,7 @@ pub fn is_safe_read_query(sql: &str) -> bool {
     )
 }
 
-fn strip_comments(sql: &str) -> String {
+pub(crate) fn strip_comments(sql: &str) -> String {
     let chars: Vec<char> = sql.chars().collect();
     let mut result = String::with_capacity(sql.len());
     let mut index = 0;
@@ -0,0 +1,523 @@
+//! Named `:param` parameters declared on saved queries.
+//!
+//! A saved query declares each parameter with a name, a type and an optional
+//! default; the SQL references it as `:name`. Running the query collects a raw
+//! text value per parameter, converts it to a typed [`Value`] and binds it via
+//! the dialect's `value_to_literal`, the same binding `inline_params` applies to
+//! generated statements. Unlike `{{name}}` variables, text values are quoted
+//! and escaped by the dialect rather than pasted verbatim.
+//!
+//! Only declared names are bound. `::type` casts and placeholders inside string
+//! literals, quoted identifiers and comments are ignored.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::Value;
+use crate::sql::dialect::SqlDialect;
+
+/// Type a parameter's text input is converted to before binding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedQueryParamType {
+    #[default]
+    Text,
+    Integer,
+    Decimal,
+    Boolean,
+    Date,
+}
+
+impl SavedQueryParamType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Integer => "integer",
+            Self::Decimal => "decimal",
+            Self::Boolean => "boolean",
+            Self::Date => "date",
+        }
+    }
+
+    /// Parses a type name, accepting common aliases (`int`, `bool`, ...).
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "text" | "string" | "str" => Some(Self::Text),
+            "integer" | "int" | "bigint" => Some(Self::Integer),
+            "decimal" | "numeric" | "number" | "float" => Some(Self::Decimal),
+            "boolean" | "bool" => Some(Self::Boolean),
+            "date" => Some(Self::Date),
+            _ => None,
+        }
+    }
+
+    /// Converts a raw input to the value that is bound. An empty input binds
+    /// `NULL`.
+    pub fn to_value(self, raw: &str) -> Result<Value, String> {
+        let trimmed = raw.trim();
+
+        if trimmed.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        match self {
+            Self::Text => Ok(Value::Text(raw.to_string())),
+            Self::Integer => trimmed
+                .parse::<i64>()
+                .map(Value::Int)
+                .map_err(|_| format!("`{}` is not an integer", trimmed)),
+            Self::Decimal => trimmed
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .map(|_| Value::Decimal(trimmed.to_string()))
+                .ok_or_else(|| format!("`{}` is not a number", trimmed)),
+            Self::Boolean => match trimmed.to_lowercase().as_str() {
+                "true" | "t" | "yes" | "y" | "1" => Ok(Value::Bool(true)),
+                "false" | "f" | "no" | "n" | "0" => Ok(Value::Bool(false)),
+                _ => Err(format!("`{}` is not true or false", trimmed)),
+            },
+            Self::Date => NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+                .map(Value::Date)
+                .map_err(|_| format!("`{}` is not a YYYY-MM-DD date", trimmed)),
+        }
+    }
+}
+
+impl fmt::Display for SavedQueryParamType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A parameter declared on a saved query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedQueryParam {
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub param_type: SavedQueryParamType,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    /// Value entered the last time the query ran; pre-fills the next prompt.
+    #[serde(default)]
+    pub last_value: Option<String>,
+}
+
+impl SavedQueryParam {
+    pub fn new(name: impl Into<String>, param_type: SavedQueryParamType) -> Self {
+        Self {
+            name: name.into(),
+            param_type,
+            default_value: None,
+            last_value: None,
+        }
+    }
+
+    pub fn with_default(mut self, default_value: impl Into<String>) -> Self {
+        self.default_value = Some(default_value.into());
+        self
+    }
+
+    /// Value the run prompt starts with: the last-used value, else the default.
+    pub fn initial_value(&self) -> &str {
+        self.last_value
+            .as_deref()
+            .or(self.default_value.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// Problems found while declaring or binding saved-query parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SavedQueryParamError {
+    InvalidName(String),
+    UnknownType { name: String, type_name: String },
+    Duplicate(String),
+    InvalidValue { name: String, message: String },
+}
+
+impl fmt::Display for SavedQueryParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "Invalid parameter name `{}`", name),
+            Self::UnknownType { name, type_name } => write!(
+                f,
+                "Unknown type `{}` for parameter `{}` (expected text, integer, decimal, boolean or date)",
+                type_name, name
+            ),
+            Self::Duplicate(name) => write!(f, "Parameter `{}` is declared twice", name),
+            Self::InvalidValue { name, message } => {
+                write!(f, "Parameter `{}`: {}", name, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SavedQueryParamError {}
+
+/// One `:name` occurrence in a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedQueryParamRef {
+    pub name: String,
+    /// Byte range of the placeholder, colon included.
+    pub range: Range<usize>,
+}
+
+fn is_param_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// Index just past the closing `quote`, treating a doubled quote as an escape.
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut i = start + 1;
+
+    while i < bytes.len() {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+
+    bytes.len()
+}
+
+/// Returns every `:name` placeholder in `query`, in source order.
+///
+/// # Example
+///
+/// ```
+/// use dbflux_core::find_saved_query_params;
+///
+/// let refs = find_saved_query_params("SELECT id::text FROM t WHERE id = :id AND note <> ':x'");
+/// assert_eq!(refs.len(), 1);
+/// assert_eq!(refs[0].name, "id");
+/// ```
+pub fn find_saved_query_params(query: &str) -> Vec<SavedQueryParamRef> {
+    let bytes = query.as_bytes();
+    let mut refs = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => i = skip_quoted(bytes, i, quote),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = query[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = query[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+            }
+            b':' if bytes.get(i + 1) == Some(&b':') => i += 2,
+            b':' if i > 0 && is_name_byte(bytes[i - 1]) => i += 1,
+            b':' => {
+                let name_end = bytes[i + 1..]
+                    .iter()
+                    .position(|b| !is_name_byte(*b))
+                    .map_or(bytes.len(), |len| i + 1 + len);
+                let name = &query[i + 1..name_end];
+
+                if is_param_name(name) {
+                    refs.push(SavedQueryParamRef {
+                        name: name.to_string(),
+                        range: i..name_end,
+                    });
+                    i = name_end;
+                } else {
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    refs
+}
+
+/// Whether `query` references at least one of `params`.
+pub fn references_saved_query_params(query: &str, params: &[SavedQueryParam]) -> bool {
+    find_saved_query_params(query)
+        .iter()
+        .any(|reference| params.iter().any(|param| param.name == reference.name))
+}
+
+/// Parses a declaration list such as `id:integer=42, since:date, status=open`.
+///
+/// Entries are comma separated; the type defaults to `text` and the text
+/// after `=` is the default value.
+pub fn parse_saved_query_params(input: &str) -> Result<Vec<SavedQueryParam>, SavedQueryParamError> {
+    let mut params: Vec<SavedQueryParam> = Vec::new();
+
+    for entry in input.split(',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+
+        let (declaration, default_value) = match entry.split_once('=') {
+            Some((declaration, default_value)) => (declaration, Some(default_value.trim())),
+            None => (entry, None),
+        };
+
+        let (name, type_name) = match declaration.split_once(':') {
+            Some((name, type_name)) => (name.trim(), Some(type_name.trim())),
+            None => (declaration.trim(), None),
+        };
+        let name = name.trim_start_matches(':');
+
+        if !is_param_name(name) {
+            return Err(SavedQueryParamError::InvalidName(name.to_string()));
+        }
+
+        let param_type = match type_name {
+            Some(type_name) => SavedQueryParamType::parse(type_name).ok_or_else(|| {
+                SavedQueryParamError::UnknownType {
+                    name: name.to_string(),
+                    type_name: type_name.to_string(),
+                }
+            })?,
+            None => SavedQueryParamType::Text,
+        };
+
+        if params.iter().any(|param| param.name == name) {
+            return Err(SavedQueryParamError::Duplicate(name.to_string()));
+        }
+
+        let mut param = SavedQueryParam::new(name, param_type);
+        param.default_value = default_value
+            .filter(|value| !value.is_empty())
+            .map(String::from);
+        params.push(param);
+    }
+
+    Ok(params)
+}
+
+/// Formats declarations back into the list accepted by
+/// [`parse_saved_query_params`].
+pub fn format_saved_query_params(params: &[SavedQueryParam]) -> String {
+    params
+        .iter()
+        .map(|param| {
+            let mut entry = format!("{}:{}", param.name, param.param_type);
+            if let Some(default_value) = &param.default_value {
+                entry.push('=');
+                entry.push_str(default_value);
+            }
+            entry
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Carries last-used values from `previous` over to the matching declarations
+/// in `params`, so re-declaring a query keeps its prompt history.
+pub fn carry_saved_query_param_values(
+    mut params: Vec<SavedQueryParam>,
+    previous: &[SavedQueryParam],
+) -> Vec<SavedQueryParam> {
+    for param in &mut params {
+        param.last_value = previous
+            .iter()
+            .find(|old| old.name == param.name && old.param_type == param.param_type)
+            .and_then(|old| old.last_value.clone());
+    }
+
+    params
+}
+
+/// Converts `inputs` (raw text keyed by parameter name) to typed values and
+/// binds them into every declared `:name` placeholder of `query`.
+///
+/// Missing inputs fall back to the parameter's default, then to `NULL`.
+/// Placeholders that are not declared are left untouched.
+pub fn bind_saved_query_params(
+    query: &str,
+    params: &[SavedQueryParam],
+    inputs: &HashMap<String, String>,
+    dialect: &dyn SqlDialect,
+) -> Result<String, SavedQueryParamError> {
+    let mut literals: HashMap<&str, String> = HashMap::with_capacity(params.len());
+
+    for param in params {
+        let raw = inputs
+            .get(&param.name)
+            .map(String::as_str)
+            .or(param.default_value.as_deref())
+            .unwrap_or_default();
+
+        let value = param.param_type.to_value(raw).map_err(|message| {
+            SavedQueryParamError::InvalidValue {
+                name: param.name.clone(),
+                message,
+            }
+        })?;
+
+        literals.insert(param.name.as_str(), dialect.value_to_literal(&value));
+    }
+
+    let mut output = String::with_capacity(query.len());
+    let mut last_end = 0;
+
+    for reference in find_saved_query_params(query) {
+        let Some(literal) = literals.get(reference.name.as_str()) else {
+            continue;
+        };
+
+        output.push_str(&query[last_end..reference.range.start]);
+        output.push_str(literal);
+        last_end = reference.range.end;
+    }
+
+    output.push_str(&query[last_end..]);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::dialect::DefaultSqlDialect;
+
+    fn inputs(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn finds_placeholders_outside_literals_comments_and_casts() {
+        let query =
+            "SELECT a::int, ':skip', \"x:y\" -- :c\nFROM t /* :d */ WHERE a = :a AND b = :b_2";
+        let names: Vec<_> = find_saved_query_params(query)
+            .into_iter()
+            .map(|reference| reference.name)
+            .collect();
+
+        assert_eq!(names, vec!["a".to_string(), "b_2".to_string()]);
+    }
+
+    #[test]
+    fn ignores_colons_after_identifiers_and_digits() {
+        assert!(find_saved_query_params("SELECT '10:30', x:y, :1").is_empty());
+    }
+
+    #[test]
+    fn parses_and_formats_declarations() {
+        let params = parse_saved_query_params("id:int=42, since:date, status=open,").unwrap();
+
+        assert_eq!(params.len(), 3);
+        assert_eq!(params[0].param_type, SavedQueryParamType::Integer);
+        assert_eq!(params[0].default_value.as_deref(), Some("42"));
+        assert_eq!(params[1].param_type, SavedQueryParamType::Date);
+        assert_eq!(params[2].param_type, SavedQueryParamType::Text);
+
+        assert_eq!(
+            format_saved_query_params(&params),
+            "id:integer=42, since:date, status:text=open"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_declarations() {
+        assert_eq!(
+            parse_saved_query_params("9x"),
+            Err(SavedQueryParamError::InvalidName("9x".to_string()))
+        );
+        assert!(matches!(
+            parse_saved_query_params("a:uuid"),
+            Err(SavedQueryParamError::UnknownType { .. })
+        ));
+        assert_eq!(
+            parse_saved_query_params("a, a:int"),
+            Err(SavedQueryParamError::Duplicate("a".to_string()))
+        );
+    }
+
+    #[test]
+    fn binds_typed_literals_with_defaults() {
+        let params = vec![
+            SavedQueryParam::new("id", SavedQueryParamType::Integer),
+            SavedQueryParam::new("name", SavedQueryParamType::Text).with_default("o'brien"),
+        ];
+
+        let sql = bind_saved_query_params(
+            "SELECT * FROM t WHERE id = :id AND name = :name AND id <> :other",
+            &params,
+            &inputs(&[("id", "7")]),
+            &DefaultSqlDialect,
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM t WHERE id = 7 AND name = 'o''brien' AND id <> :other"
+        );
+    }
+
+    #[test]
+    fn binding_rejects_values_of_the_wrong_type() {
+        let params = vec![SavedQueryParam::new("id", SavedQueryParamType::Integer)];
+
+        let result = bind_saved_query_params(
+            "SELECT :id",
+            &params,
+            &inputs(&[("id", "seven")]),
+            &DefaultSqlDialect,
+        );
+
+        assert!(matches!(
+            result,
+            Err(SavedQueryParamError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn empty_input_binds_null() {
+        let params = vec![SavedQueryParam::new("d", SavedQueryParamType::Date)];
+
+        let sql = bind_saved_query_params(
+            "SELECT :d",
+            &params,
+            &inputs(&[("d", " ")]),
+            &DefaultSqlDialect,
+        )
+        .unwrap();
+
+        assert_eq!(sql, "SELECT NULL");
+    }
+
+    #[test]
+    fn carries_last_values_for_unchanged_declarations() {
+        let mut old = SavedQueryParam::new("id", SavedQueryParamType::Integer);
+        old.last_value = Some("5".to_string());
+
+        let params = carry_saved_query_param_values(
+            vec![
+                SavedQueryParam::new("id", SavedQueryParamType::Integer),
+                SavedQueryParam::new("other", SavedQueryParamType::Text),
+            ],
+            &[old],
+        );
+
+        assert_eq!(params[0].initial_value(), "5");
+        assert_eq!(params[1].initial_value(), "");
+    }
+}
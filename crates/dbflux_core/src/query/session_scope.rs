@@ -0,0 +1,386 @@
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::thread::{self, ThreadId};
+
+use crate::{DbError, QueryRequest, QueryResult};
+
+/// Session state a single execution is wrapped in, such as a read-only
+/// transaction or an impersonated role.
+///
+/// Each step pairs a statement that enters the state with one that leaves
+/// it. Steps are entered in order and left in reverse, all while the
+/// connection's [`SessionGate`] is held, so no other caller's statements run
+/// in between.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionScope {
+    steps: Vec<SessionStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SessionStep {
+    enter: String,
+    exit: String,
+}
+
+impl SessionScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a step entered after, and left before, the steps added so far.
+    pub fn with_step(mut self, enter: impl Into<String>, exit: impl Into<String>) -> Self {
+        self.steps.push(SessionStep {
+            enter: enter.into(),
+            exit: exit.into(),
+        });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Runs `request` inside the scope through `execute`.
+    ///
+    /// Steps that were entered are always left again. When leaving fails the
+    /// failed statements are queued on `gate`, replayed before the
+    /// connection's next statement, and reported as an error even if the
+    /// request itself succeeded.
+    pub fn run(
+        &self,
+        gate: &SessionGate,
+        request: &QueryRequest,
+        execute: impl Fn(&QueryRequest) -> Result<QueryResult, DbError>,
+    ) -> Result<QueryResult, DbError> {
+        let mut entered = 0;
+        let mut result = Ok(QueryResult::empty());
+
+        for step in &self.steps {
+            let enter = QueryRequest::new(step.enter.clone())
+                .with_database(request.database.clone())
+                .with_execution_context(request.execution_context.clone());
+
+            if let Err(error) = execute(&enter) {
+                result = Err(error);
+                break;
+            }
+
+            entered += 1;
+        }
+
+        if entered == self.steps.len() {
+            result = execute(request);
+        }
+
+        let mut unrestored = Vec::new();
+        let mut restore_error = None;
+
+        for step in self.steps[..entered].iter().rev() {
+            if let Err(error) = execute(&QueryRequest::new(step.exit.clone())) {
+                unrestored.push(step.exit.clone());
+                restore_error.get_or_insert(error);
+            }
+        }
+
+        let Some(restore_error) = restore_error else {
+            return result;
+        };
+
+        gate.defer_reset(unrestored);
+
+        let message = match result {
+            Ok(_) => format!(
+                "Restoring the session failed: {restore_error}. It will be reset before the next statement"
+            ),
+            Err(error) => {
+                format!("{error}. Restoring the session afterwards also failed: {restore_error}")
+            }
+        };
+
+        Err(DbError::query_failed(message))
+    }
+}
+
+/// Reentrant gate serializing the statements sent over one connection.
+///
+/// Drivers enter it at the top of `execute`; `execute_in_session` holds it
+/// across a whole [`SessionScope`], so the calls it makes on the same thread
+/// pass through while other threads wait.
+#[derive(Debug, Default)]
+pub struct SessionGate {
+    state: Mutex<GateState>,
+    released: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct GateState {
+    owner: Option<ThreadId>,
+    depth: usize,
+    pending_reset: Vec<String>,
+}
+
+/// Holds a [`SessionGate`] until dropped.
+#[derive(Debug)]
+pub struct SessionGuard<'a> {
+    gate: &'a SessionGate,
+}
+
+impl SessionGate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Blocks until the gate is free or already held by this thread.
+    ///
+    /// The outermost entry first replays any reset left over from a scope
+    /// that could not be left, through `restore`. If that fails again the
+    /// statements stay queued and the caller must not run its own.
+    pub fn enter(
+        &self,
+        restore: impl Fn(&QueryRequest) -> Result<QueryResult, DbError>,
+    ) -> Result<SessionGuard<'_>, DbError> {
+        let current = thread::current().id();
+        let mut state = self.lock_state();
+
+        while state.owner.is_some_and(|owner| owner != current) {
+            state = self
+                .released
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+
+        state.owner = Some(current);
+        state.depth += 1;
+
+        let pending = if state.depth == 1 {
+            std::mem::take(&mut state.pending_reset)
+        } else {
+            Vec::new()
+        };
+        drop(state);
+
+        let guard = SessionGuard { gate: self };
+
+        for (index, sql) in pending.iter().enumerate() {
+            if let Err(error) = restore(&QueryRequest::new(sql.clone())) {
+                self.defer_reset(pending[index..].to_vec());
+
+                return Err(DbError::query_failed(format!(
+                    "The connection is still in the state an earlier query left it in: {error}. Reconnect to continue"
+                )));
+            }
+        }
+
+        Ok(guard)
+    }
+
+    /// Whether statements are queued to restore the session.
+    pub fn needs_reset(&self) -> bool {
+        !self.lock_state().pending_reset.is_empty()
+    }
+
+    /// Drops queued reset statements, for drivers that replaced the session
+    /// they were meant for.
+    pub fn forget_reset(&self) {
+        self.lock_state().pending_reset.clear();
+    }
+
+    fn defer_reset(&self, statements: Vec<String>) {
+        self.lock_state().pending_reset.extend(statements);
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, GateState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for SessionGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.gate.lock_state();
+        state.depth -= 1;
+
+        if state.depth == 0 {
+            state.owner = None;
+            drop(state);
+            self.gate.released.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    struct Recorder {
+        log: RefCell<Vec<String>>,
+        failing: Vec<&'static str>,
+    }
+
+    impl Recorder {
+        fn new(failing: &[&'static str]) -> Self {
+            Self {
+                log: RefCell::new(Vec::new()),
+                failing: failing.to_vec(),
+            }
+        }
+
+        fn execute(&self, request: &QueryRequest) -> Result<QueryResult, DbError> {
+            self.log.borrow_mut().push(request.sql.clone());
+
+            if self.failing.contains(&request.sql.as_str()) {
+                return Err(DbError::query_failed(format!("{} failed", request.sql)));
+            }
+
+            Ok(QueryResult::empty())
+        }
+
+        fn log(&self) -> Vec<String> {
+            self.log.borrow().clone()
+        }
+    }
+
+    fn scope() -> SessionScope {
+        SessionScope::new()
+            .with_step("SET ROLE r", "RESET ROLE")
+            .with_step("BEGIN READ ONLY", "ROLLBACK")
+    }
+
+    #[test]
+    fn steps_wrap_the_request_in_nesting_order() {
+        let gate = SessionGate::new();
+        let recorder = Recorder::new(&[]);
+        let request = QueryRequest::new("SELECT 1").with_database(Some("app".into()));
+
+        scope()
+            .run(&gate, &request, |r| {
+                if r.sql == "BEGIN READ ONLY" {
+                    assert_eq!(r.database.as_deref(), Some("app"));
+                }
+                recorder.execute(r)
+            })
+            .unwrap();
+
+        assert_eq!(
+            recorder.log(),
+            [
+                "SET ROLE r",
+                "BEGIN READ ONLY",
+                "SELECT 1",
+                "ROLLBACK",
+                "RESET ROLE"
+            ]
+        );
+        assert!(!gate.needs_reset());
+    }
+
+    #[test]
+    fn failed_request_still_leaves_every_step() {
+        let gate = SessionGate::new();
+        let recorder = Recorder::new(&["SELECT 1"]);
+
+        let error = scope()
+            .run(&gate, &QueryRequest::new("SELECT 1"), |r| {
+                recorder.execute(r)
+            })
+            .unwrap_err();
+
+        assert!(error.to_string().contains("SELECT 1 failed"));
+        assert_eq!(
+            recorder.log(),
+            [
+                "SET ROLE r",
+                "BEGIN READ ONLY",
+                "SELECT 1",
+                "ROLLBACK",
+                "RESET ROLE"
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_enter_skips_the_request_and_leaves_entered_steps() {
+        let gate = SessionGate::new();
+        let recorder = Recorder::new(&["BEGIN READ ONLY"]);
+
+        let error = scope()
+            .run(&gate, &QueryRequest::new("SELECT 1"), |r| {
+                recorder.execute(r)
+            })
+            .unwrap_err();
+
+        assert!(error.to_string().contains("BEGIN READ ONLY failed"));
+        assert_eq!(
+            recorder.log(),
+            ["SET ROLE r", "BEGIN READ ONLY", "RESET ROLE"]
+        );
+    }
+
+    #[test]
+    fn failed_exit_is_an_error_and_is_replayed_on_next_entry() {
+        let gate = SessionGate::new();
+        let recorder = Recorder::new(&["RESET ROLE"]);
+
+        let error = scope()
+            .run(&gate, &QueryRequest::new("SELECT 1"), |r| {
+                recorder.execute(r)
+            })
+            .unwrap_err();
+
+        assert!(error.to_string().contains("Restoring the session failed"));
+        assert!(gate.needs_reset());
+
+        let replayed = Recorder::new(&[]);
+        drop(gate.enter(|r| replayed.execute(r)).unwrap());
+
+        assert_eq!(replayed.log(), ["RESET ROLE"]);
+        assert!(!gate.needs_reset());
+    }
+
+    #[test]
+    fn failed_replay_refuses_entry_and_keeps_the_reset_queued() {
+        let gate = SessionGate::new();
+        gate.defer_reset(vec!["RESET ROLE".into()]);
+
+        let recorder = Recorder::new(&["RESET ROLE"]);
+        assert!(gate.enter(|r| recorder.execute(r)).is_err());
+        assert!(gate.needs_reset());
+    }
+
+    #[test]
+    fn gate_is_reentrant_on_one_thread_and_replays_only_when_outermost() {
+        let gate = SessionGate::new();
+        let recorder = Recorder::new(&[]);
+
+        let outer = gate.enter(|r| recorder.execute(r)).unwrap();
+        gate.defer_reset(vec!["RESET ROLE".into()]);
+        drop(gate.enter(|r| recorder.execute(r)).unwrap());
+        drop(outer);
+
+        assert!(recorder.log().is_empty());
+        assert!(gate.needs_reset());
+    }
+
+    #[test]
+    fn gate_keeps_other_threads_out_until_released() {
+        let gate = std::sync::Arc::new(SessionGate::new());
+        let order = std::sync::Arc::new(Mutex::new(Vec::new()));
+
+        let guard = gate.enter(|_| Ok(QueryResult::empty())).unwrap();
+
+        let waiter = {
+            let gate = gate.clone();
+            let order = order.clone();
+            std::thread::spawn(move || {
+                let _guard = gate.enter(|_| Ok(QueryResult::empty())).unwrap();
+                order.lock().unwrap().push("other");
+            })
+        };
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        order.lock().unwrap().push("holder");
+        drop(guard);
+        waiter.join().unwrap();
+
+        assert_eq!(*order.lock().unwrap(), ["holder", "other"]);
+    }
+}
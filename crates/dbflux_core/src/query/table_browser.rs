@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{DefaultSqlDialect, SemanticFilter, SqlDialect};
+use crate::query::keyset::seek_after_predicate;
+use crate::{DefaultSqlDialect, SemanticFilter, SqlDialect, Value};
 
 /// Sort direction for ORDER BY clauses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -61,11 +62,23 @@ impl OrderByColumn {
 
 /// Pagination strategy for table browsing.
 ///
-/// Currently only supports OFFSET-based pagination.
-/// Keyset pagination can be added later for better performance on large tables.
+/// `Offset` skips rows with `OFFSET`, which gets slower the deeper the page.
+/// `Keyset` seeks past the primary key of the previous page's last row, so
+/// every page costs the same; it is only built by [`Pagination::seek_after`]
+/// and always orders by its key columns.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Pagination {
-    Offset { limit: u32, offset: u64 },
+    Offset {
+        limit: u32,
+        offset: u64,
+    },
+    Keyset {
+        limit: u32,
+        /// Primary-key columns the pages are ordered and seeked by.
+        key_columns: Vec<String>,
+        /// Key of the last row of every page before the current one.
+        after: Vec<Vec<Value>>,
+    },
 }
 
 impl Default for Pagination {
@@ -80,22 +93,54 @@ impl Default for Pagination {
 impl Pagination {
     pub fn limit(&self) -> u32 {
         match self {
-            Self::Offset { limit, .. } => *limit,
+            Self::Offset { limit, .. } | Self::Keyset { limit, .. } => *limit,
         }
     }
 
+    /// Rows before the current page. For keyset pages this is derived from
+    /// the page number, since every earlier page was full.
     pub fn offset(&self) -> u64 {
         match self {
             Self::Offset { offset, .. } => *offset,
+            Self::Keyset { limit, after, .. } => after.len() as u64 * *limit as u64,
         }
     }
 
+    /// The following page by offset. Keyset pages continue with
+    /// [`Self::seek_after`] instead, which needs the current page's last key.
     pub fn next_page(&self) -> Self {
-        match self {
-            Self::Offset { limit, offset } => Self::Offset {
-                limit: *limit,
-                offset: offset + *limit as u64,
-            },
+        Self::Offset {
+            limit: self.limit(),
+            offset: self.offset() + self.limit() as u64,
+        }
+    }
+
+    /// The page after the one ending at `last_key`, seeking on `key_columns`.
+    /// Continuing from an offset page starts a new keyset history.
+    pub fn seek_after(&self, key_columns: Vec<String>, last_key: Vec<Value>) -> Self {
+        let mut after = match self {
+            Self::Keyset {
+                key_columns: current,
+                after,
+                ..
+            } if *current == key_columns => after.clone(),
+            _ => Vec::new(),
+        };
+
+        // Boundaries of pages reached by offset are unknown; pad them so page
+        // numbers stay right. Going back onto a padded boundary falls back to
+        // offset pagination, see `prev_page`.
+        let earlier_pages = (self.offset() / self.limit().max(1) as u64) as usize;
+        if after.len() < earlier_pages {
+            after.resize(earlier_pages, Vec::new());
+        }
+
+        after.push(last_key);
+
+        Self::Keyset {
+            limit: self.limit(),
+            key_columns,
+            after,
         }
     }
 
@@ -111,42 +156,66 @@ impl Pagination {
                     })
                 }
             }
+            Self::Keyset {
+                limit,
+                key_columns,
+                after,
+            } => {
+                let mut after = after.clone();
+                after.pop()?;
+
+                if after.last().is_none_or(|key| key.is_empty()) {
+                    return Some(Self::Offset {
+                        limit: *limit,
+                        offset: after.len() as u64 * *limit as u64,
+                    });
+                }
+
+                Some(Self::Keyset {
+                    limit: *limit,
+                    key_columns: key_columns.clone(),
+                    after,
+                })
+            }
         }
     }
 
     pub fn current_page(&self) -> u64 {
-        match self {
-            Self::Offset { limit, offset } => {
-                if *limit == 0 {
-                    1
-                } else {
-                    offset / *limit as u64 + 1
-                }
-            }
+        let limit = self.limit();
+        if limit == 0 {
+            1
+        } else {
+            self.offset() / limit as u64 + 1
         }
     }
 
     pub fn is_first_page(&self) -> bool {
-        match self {
-            Self::Offset { offset, .. } => *offset == 0,
-        }
+        self.offset() == 0
+    }
+
+    pub fn is_keyset(&self) -> bool {
+        matches!(self, Self::Keyset { .. })
     }
 
+    /// Same position with a new page size. Keyset boundaries only line up
+    /// with the old size, so keyset pages restart from the first page.
     pub fn with_limit(&self, new_limit: u32) -> Self {
         match self {
             Self::Offset { offset, .. } => Self::Offset {
                 limit: new_limit,
                 offset: *offset,
             },
+            Self::Keyset { .. } => Self::Offset {
+                limit: new_limit,
+                offset: 0,
+            },
         }
     }
 
     pub fn reset_offset(&self) -> Self {
-        match self {
-            Self::Offset { limit, .. } => Self::Offset {
-                limit: *limit,
-                offset: 0,
-            },
+        Self::Offset {
+            limit: self.limit(),
+            offset: 0,
         }
     }
 }
@@ -406,37 +475,75 @@ impl TableBrowseRequest {
 
     /// Build the SQL query using a `SqlDialect` for identifier quoting.
     pub fn build_sql_with(&self, dialect: &dyn SqlDialect) -> String {
+        self.build_sql_with_where(dialect, self.filter.as_deref())
+    }
+
+    /// Build the SQL query with `where_clause` in place of the raw filter,
+    /// for drivers that render [`Self::semantic_filter`] themselves.
+    pub fn build_sql_with_where(
+        &self,
+        dialect: &dyn SqlDialect,
+        where_clause: Option<&str>,
+    ) -> String {
         let mut sql = format!("SELECT * FROM {}", self.table.quoted_with(dialect));
 
-        if let Some(ref filter) = self.filter {
-            let trimmed = filter.trim();
-            if !trimmed.is_empty() {
+        let filter = where_clause.map(str::trim).filter(|f| !f.is_empty());
+
+        let seek = match &self.pagination {
+            Pagination::Keyset {
+                key_columns, after, ..
+            } => after
+                .last()
+                .map(|key| seek_after_predicate(key_columns, key, dialect)),
+            Pagination::Offset { .. } => None,
+        };
+
+        match (filter, seek.as_deref()) {
+            (Some(filter), Some(seek)) => {
+                sql.push_str(&format!(" WHERE ({}) AND {}", filter, seek));
+            }
+            (Some(condition), None) | (None, Some(condition)) => {
                 sql.push_str(" WHERE ");
-                sql.push_str(trimmed);
+                sql.push_str(condition);
             }
+            (None, None) => {}
         }
 
-        if !self.order_by.is_empty() {
-            sql.push_str(" ORDER BY ");
-            let quoted_cols: Vec<String> = self
-                .order_by
-                .iter()
-                .map(|col| {
-                    let dir = match col.direction {
-                        SortDirection::Ascending => "ASC",
-                        SortDirection::Descending => "DESC",
-                    };
-                    format!("{} {}", col.column.quoted_with(dialect), dir)
-                })
-                .collect();
-            sql.push_str(&quoted_cols.join(", "));
+        match &self.pagination {
+            Pagination::Keyset { key_columns, .. } if !key_columns.is_empty() => {
+                let quoted_cols: Vec<String> = key_columns
+                    .iter()
+                    .map(|col| format!("{} ASC", dialect.quote_identifier(col)))
+                    .collect();
+                sql.push_str(" ORDER BY ");
+                sql.push_str(&quoted_cols.join(", "));
+            }
+            _ if !self.order_by.is_empty() => {
+                sql.push_str(" ORDER BY ");
+                let quoted_cols: Vec<String> = self
+                    .order_by
+                    .iter()
+                    .map(|col| {
+                        let dir = match col.direction {
+                            SortDirection::Ascending => "ASC",
+                            SortDirection::Descending => "DESC",
+                        };
+                        format!("{} {}", col.column.quoted_with(dialect), dir)
+                    })
+                    .collect();
+                sql.push_str(&quoted_cols.join(", "));
+            }
+            _ => {}
         }
 
-        sql.push_str(&format!(
-            " LIMIT {} OFFSET {}",
-            self.pagination.limit(),
-            self.pagination.offset()
-        ));
+        match &self.pagination {
+            Pagination::Offset { limit, offset } => {
+                sql.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+            }
+            Pagination::Keyset { limit, .. } => {
+                sql.push_str(&format!(" LIMIT {}", limit));
+            }
+        }
 
         sql
     }
@@ -603,6 +710,60 @@ mod tests {
         assert!(p.prev_page().is_none());
     }
 
+    #[test]
+    fn keyset_pages_seek_forward_and_step_back() {
+        let first = Pagination::Offset {
+            limit: 2,
+            offset: 0,
+        };
+        let key = vec!["id".to_string()];
+
+        let second = first.seek_after(key.clone(), vec![Value::Int(2)]);
+        let third = second.seek_after(key.clone(), vec![Value::Int(4)]);
+        assert!(third.is_keyset());
+        assert_eq!(third.offset(), 4);
+        assert_eq!(third.current_page(), 3);
+
+        assert_eq!(third.prev_page(), Some(second.clone()));
+        assert_eq!(second.prev_page(), Some(first));
+        assert_eq!(third.next_page().offset(), 6);
+    }
+
+    #[test]
+    fn keyset_from_a_deep_offset_page_steps_back_by_offset() {
+        let fifth = Pagination::Offset {
+            limit: 10,
+            offset: 30,
+        }
+        .seek_after(vec!["id".to_string()], vec![Value::Int(40)]);
+        assert_eq!(fifth.current_page(), 5);
+
+        assert_eq!(
+            fifth.prev_page(),
+            Some(Pagination::Offset {
+                limit: 10,
+                offset: 30,
+            })
+        );
+    }
+
+    #[test]
+    fn keyset_sql_seeks_past_the_last_key() {
+        let pagination = Pagination::default().seek_after(
+            vec!["tenant".to_string(), "id".to_string()],
+            vec![Value::Text("acme".into()), Value::Int(7)],
+        );
+        let req = TableBrowseRequest::new(TableRef::new("orders"))
+            .with_filter("status = 'open' OR id < 0")
+            .with_order_by(vec![OrderByColumn::desc("created_at")])
+            .with_pagination(pagination);
+
+        assert_eq!(
+            req.build_sql_with(&DefaultSqlDialect),
+            "SELECT * FROM \"orders\" WHERE (status = 'open' OR id < 0) AND (\"tenant\" > 'acme' OR (\"tenant\" = 'acme' AND \"id\" > 7)) ORDER BY \"tenant\" ASC, \"id\" ASC LIMIT 100"
+        );
+    }
+
     #[test]
     fn test_table_ref() {
         let t = TableRef::from_qualified("public.users");
@@ -1,4 +1,4 @@
-use crate::DbKind;
+use crate::{DbKind, QueryLanguage};
 
 /// Per-driver transaction SQL strings used by the mutation executor.
 ///
@@ -46,6 +46,22 @@ pub struct TransactionVocab {
     ///
     /// `None` when `autocommit_lock_timeout_template` is `None` (no SET was issued).
     pub autocommit_lock_timeout_reset_sql: Option<&'static str>,
+
+    /// Statement that opens a transaction for read-only SQL tabs.
+    ///
+    /// The transaction is always rolled back afterwards, so even drivers
+    /// without a read-only mode discard whatever the statement changed.
+    pub read_only_begin: &'static str,
+
+    /// When `true`, the server itself rejects writes inside the
+    /// `read_only_begin` transaction. When `false`, writes run and are only
+    /// undone by the rollback.
+    pub read_only_rejects_writes: bool,
+
+    /// When `true`, DDL and similar statements commit the open transaction
+    /// before they run (MySQL's implicit commit), so they escape
+    /// `read_only_begin` even where the server rejects writes.
+    pub ddl_commits_implicitly: bool,
 }
 
 impl TransactionVocab {
@@ -69,6 +85,9 @@ impl TransactionVocab {
                 // Outside a transaction, SET LOCAL has no effect. Use session-scoped SET instead.
                 autocommit_lock_timeout_template: Some("SET lock_timeout = '{ms}ms'"),
                 autocommit_lock_timeout_reset_sql: Some("SET lock_timeout = DEFAULT"),
+                read_only_begin: "BEGIN READ ONLY",
+                read_only_rejects_writes: true,
+                ddl_commits_implicitly: false,
             }),
             DbKind::MySQL | DbKind::MariaDB => Some(Self {
                 begin: "START TRANSACTION",
@@ -87,6 +106,9 @@ impl TransactionVocab {
                 autocommit_lock_timeout_reset_sql: Some(
                     "SET SESSION innodb_lock_wait_timeout = DEFAULT",
                 ),
+                read_only_begin: "START TRANSACTION READ ONLY",
+                read_only_rejects_writes: true,
+                ddl_commits_implicitly: true,
             }),
             DbKind::SQLite => Some(Self {
                 begin: "BEGIN IMMEDIATE",
@@ -97,6 +119,11 @@ impl TransactionVocab {
                 lock_timeout_reset_sql: None,
                 autocommit_lock_timeout_template: None,
                 autocommit_lock_timeout_reset_sql: None,
+                // No read-only transactions; DEFERRED at least avoids taking
+                // the write lock until a statement actually writes.
+                read_only_begin: "BEGIN DEFERRED",
+                read_only_rejects_writes: false,
+                ddl_commits_implicitly: false,
             }),
             DbKind::SqlServer => Some(Self {
                 begin: "BEGIN TRANSACTION",
@@ -110,6 +137,10 @@ impl TransactionVocab {
                 // Connection-scoped — same statement works in autocommit mode.
                 autocommit_lock_timeout_template: Some("SET LOCK_TIMEOUT {ms}"),
                 autocommit_lock_timeout_reset_sql: Some("SET LOCK_TIMEOUT -1"),
+                // No transaction-level read-only mode; rely on the rollback.
+                read_only_begin: "BEGIN TRANSACTION",
+                read_only_rejects_writes: false,
+                ddl_commits_implicitly: false,
             }),
             DbKind::MongoDB
             | DbKind::Redis
@@ -144,6 +175,111 @@ impl TransactionVocab {
                 .replace("{seconds}", &seconds.to_string())
         })
     }
+
+    /// Whether `sql` can run inside `read_only_begin` without escaping it.
+    ///
+    /// Only a single statement that does not end the transaction is
+    /// admitted: a script could commit and keep writing outside it, even
+    /// where the server rejects writes inside. Where DDL commits implicitly,
+    /// DDL is refused for the same reason.
+    pub fn read_only_admits(&self, sql: &str) -> bool {
+        let statements = QueryLanguage::Sql.split_statements(sql);
+        if statements.len() > 1 {
+            return false;
+        }
+
+        !statements.iter().any(|statement| {
+            let words = keywords(statement);
+            ends_transaction(&words) || (self.ddl_commits_implicitly && commits_implicitly(&words))
+        })
+    }
+}
+
+/// Whether the words contain `COMMIT` or `ROLLBACK`. T-SQL batches need no
+/// `;`, so a single statement can still end the transaction and carry on.
+fn ends_transaction(words: &[String]) -> bool {
+    words
+        .iter()
+        .any(|word| word.eq_ignore_ascii_case("COMMIT") || word.eq_ignore_ascii_case("ROLLBACK"))
+}
+
+/// Whether a statement starting with these words makes MySQL commit the
+/// open transaction before running it.
+fn commits_implicitly(words: &[String]) -> bool {
+    const IMPLICIT_COMMIT: &[&str] = &[
+        "ALTER",
+        "ANALYZE",
+        "BEGIN",
+        "CACHE",
+        "CREATE",
+        "DROP",
+        "FLUSH",
+        "GRANT",
+        "INSTALL",
+        "LOAD",
+        "LOCK",
+        "OPTIMIZE",
+        "RENAME",
+        "REPAIR",
+        "RESET",
+        "REVOKE",
+        "START",
+        "TRUNCATE",
+        "UNINSTALL",
+        "UNLOCK",
+    ];
+
+    let Some(first) = words.first() else {
+        return false;
+    };
+
+    IMPLICIT_COMMIT
+        .iter()
+        .any(|keyword| first.eq_ignore_ascii_case(keyword))
+        || (first.eq_ignore_ascii_case("SET")
+            && words
+                .get(1)
+                .is_some_and(|word| word.eq_ignore_ascii_case("PASSWORD")))
+}
+
+/// Words of `statement` outside quotes and comments, in order.
+fn keywords(statement: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut chars = statement.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+            continue;
+        }
+
+        words.push(std::mem::take(&mut word));
+
+        match c {
+            '\'' | '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                chars.by_ref().find(|&next| next == close);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                chars.by_ref().find(|&next| next == '\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                chars.by_ref().find(|&next| {
+                    let closed = previous == '*' && next == '/';
+                    previous = next;
+                    closed
+                });
+            }
+            _ => {}
+        }
+    }
+
+    words.push(word);
+    words.retain(|word| !word.is_empty());
+    words
 }
 
 #[cfg(test)]
@@ -170,6 +306,54 @@ mod tests {
         assert_eq!(vocab.begin, "START TRANSACTION");
     }
 
+    #[test]
+    fn read_only_begin_matches_driver_support() {
+        let postgres = TransactionVocab::for_kind(DbKind::Postgres).unwrap();
+        assert_eq!(postgres.read_only_begin, "BEGIN READ ONLY");
+        assert!(postgres.read_only_rejects_writes);
+
+        let mysql = TransactionVocab::for_kind(DbKind::MySQL).unwrap();
+        assert_eq!(mysql.read_only_begin, "START TRANSACTION READ ONLY");
+        assert!(mysql.read_only_rejects_writes);
+
+        let sqlite = TransactionVocab::for_kind(DbKind::SQLite).unwrap();
+        assert!(!sqlite.read_only_rejects_writes);
+    }
+
+    #[test]
+    fn read_only_refuses_scripts_on_every_backend() {
+        let postgres = TransactionVocab::for_kind(DbKind::Postgres).unwrap();
+        assert!(!postgres.read_only_admits("SELECT 1; COMMIT; DELETE FROM t"));
+        assert!(!postgres.read_only_admits("ROLLBACK"));
+        assert!(postgres.read_only_admits("CREATE TABLE t (a int)"));
+
+        let sqlite = TransactionVocab::for_kind(DbKind::SQLite).unwrap();
+        assert!(sqlite.read_only_admits("SELECT 'a;b' FROM t"));
+        assert!(!sqlite.read_only_admits("SELECT 1; DELETE FROM t"));
+        assert!(!sqlite.read_only_admits("COMMIT"));
+    }
+
+    #[test]
+    fn read_only_refuses_mysql_statements_that_commit_implicitly() {
+        let mysql = TransactionVocab::for_kind(DbKind::MySQL).unwrap();
+        assert!(!mysql.read_only_admits("DROP TABLE t"));
+        assert!(!mysql.read_only_admits("  /* x */ create table t (a int)"));
+        assert!(!mysql.read_only_admits("SET PASSWORD = 'x'"));
+        assert!(!mysql.read_only_admits("SELECT 1; DELETE FROM t"));
+        assert!(mysql.read_only_admits("SELECT `create` FROM t"));
+        assert!(mysql.read_only_admits("SET @a = 1"));
+    }
+
+    #[test]
+    fn read_only_refuses_tsql_batches_that_end_the_transaction() {
+        let mssql = TransactionVocab::for_kind(DbKind::SqlServer).unwrap();
+        assert!(!mssql.read_only_admits("COMMIT UPDATE t SET a = 1"));
+        assert!(!mssql.read_only_admits("rollback tran\nDELETE FROM t"));
+        assert!(mssql.read_only_admits(
+            "SELECT [commit], 'rollback' FROM t -- commit\n/* rollback */ WHERE a = 1"
+        ));
+    }
+
     #[test]
     fn sqlite_has_no_lock_timeout() {
         let vocab = TransactionVocab::for_kind(DbKind::SQLite).unwrap();
@@ -173,6 +173,37 @@ pub struct ResolvedWindow {
 
 // -- Query Result --
 
+/// Where the time of a query went. Phases that the driver or the view
+/// can't measure stay `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryTiming {
+    /// Wall time of the driver call as seen by the app, from sending the
+    /// query to holding the converted rows.
+    pub round_trip: Option<Duration>,
+
+    /// Execution time reported by the server itself.
+    pub server: Option<Duration>,
+
+    /// Time the driver spent converting wire rows into values.
+    pub deserialize: Option<Duration>,
+
+    /// Time from receiving the result to the first frame of the grid.
+    pub render: Option<Duration>,
+}
+
+impl QueryTiming {
+    /// The part of the round trip that is neither server execution nor
+    /// deserialization: network, protocol and driver overhead.
+    pub fn transfer(&self) -> Option<Duration> {
+        let round_trip = self.round_trip?;
+        Some(
+            round_trip
+                .saturating_sub(self.server.unwrap_or_default())
+                .saturating_sub(self.deserialize.unwrap_or_default()),
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct QueryResult {
     pub shape: QueryResultShape,
@@ -180,6 +211,9 @@ pub struct QueryResult {
     pub rows: Vec<Row>,
     pub affected_rows: Option<u64>,
     pub execution_time: Duration,
+    /// Per-phase breakdown of the query's time, filled by the driver and
+    /// the app as each phase completes.
+    pub timing: QueryTiming,
     pub text_body: Option<String>,
     pub raw_bytes: Option<Vec<u8>>,
     /// Pagination token for fetching the next page of results (used by PageToken-style pagination).
@@ -214,6 +248,7 @@ impl QueryResult {
             rows: Vec::new(),
             affected_rows: None,
             execution_time: Duration::ZERO,
+            timing: QueryTiming::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
@@ -229,6 +264,18 @@ impl QueryResult {
         self
     }
 
+    /// Records the wall time of the driver call that produced this result.
+    pub fn with_round_trip(mut self, elapsed: Duration) -> Self {
+        self.timing.round_trip = Some(elapsed);
+        self
+    }
+
+    /// Records how long the driver spent converting rows into values.
+    pub fn with_deserialize_time(mut self, elapsed: Duration) -> Self {
+        self.timing.deserialize = Some(elapsed);
+        self
+    }
+
     pub fn table(
         columns: Vec<ColumnMeta>,
         rows: Vec<Row>,
@@ -241,6 +288,7 @@ impl QueryResult {
             rows,
             affected_rows,
             execution_time,
+            timing: QueryTiming::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
@@ -257,6 +305,7 @@ impl QueryResult {
             rows,
             affected_rows: None,
             execution_time,
+            timing: QueryTiming::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
@@ -273,6 +322,7 @@ impl QueryResult {
             rows: Vec::new(),
             affected_rows: None,
             execution_time,
+            timing: QueryTiming::default(),
             text_body: Some(body),
             raw_bytes: None,
             next_page_token: None,
@@ -289,6 +339,7 @@ impl QueryResult {
             rows: Vec::new(),
             affected_rows: None,
             execution_time,
+            timing: QueryTiming::default(),
             text_body: None,
             raw_bytes: Some(data),
             next_page_token: None,
@@ -298,6 +349,25 @@ impl QueryResult {
         }
     }
 
+    /// A copy of this result holding `rows` instead of its own, for callers
+    /// that share the result and only need to swap the rows.
+    pub fn with_rows(&self, rows: Vec<Row>) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            columns: self.columns.clone(),
+            rows,
+            affected_rows: self.affected_rows,
+            execution_time: self.execution_time,
+            timing: self.timing,
+            text_body: self.text_body.clone(),
+            raw_bytes: self.raw_bytes.clone(),
+            next_page_token: self.next_page_token.clone(),
+            resolved_window: self.resolved_window.clone(),
+            metadata_extra: self.metadata_extra.clone(),
+            additional_results: self.additional_results.clone(),
+        }
+    }
+
     pub fn row_count(&self) -> usize {
         self.rows.len()
     }
@@ -408,6 +478,42 @@ mod tests {
         assert_eq!(result.resolved_window.as_ref(), Some(&window));
     }
 
+    #[test]
+    fn with_rows_keeps_metadata_and_replaces_rows() {
+        let result = QueryResult::table(
+            Vec::new(),
+            vec![vec![Value::Int(1)], vec![Value::Int(2)]],
+            Some(2),
+            Duration::from_millis(4),
+        );
+
+        let replaced = result.with_rows(vec![vec![Value::Int(2)]]);
+
+        assert_eq!(replaced.rows, vec![vec![Value::Int(2)]]);
+        assert_eq!(replaced.affected_rows, Some(2));
+        assert_eq!(replaced.execution_time, Duration::from_millis(4));
+        assert_eq!(result.rows.len(), 2);
+    }
+
+    #[test]
+    fn query_timing_transfer_is_the_unexplained_round_trip() {
+        let timing = QueryTiming {
+            round_trip: Some(Duration::from_millis(120)),
+            server: Some(Duration::from_millis(70)),
+            deserialize: Some(Duration::from_millis(20)),
+            render: Some(Duration::from_millis(5)),
+        };
+        assert_eq!(timing.transfer(), Some(Duration::from_millis(30)));
+
+        let unmeasured = QueryTiming {
+            round_trip: Some(Duration::from_millis(10)),
+            deserialize: Some(Duration::from_millis(15)),
+            ..QueryTiming::default()
+        };
+        assert_eq!(unmeasured.transfer(), Some(Duration::ZERO));
+        assert_eq!(QueryTiming::default().transfer(), None);
+    }
+
     fn make_set(label: &str) -> QueryResult {
         QueryResult::table(
             vec![ColumnMeta {
@@ -0,0 +1,544 @@
+use crate::{DbError, DbKind};
+
+/// A login or role as addressed by `CREATE USER`, `DROP USER` and `GRANT`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UserAccount {
+    pub name: String,
+    /// Host part of a MySQL/MariaDB account (`'name'@'host'`). `None` means
+    /// any host (`%`); ignored by PostgreSQL.
+    pub host: Option<String>,
+}
+
+impl UserAccount {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            host: None,
+        }
+    }
+
+    pub fn with_host(mut self, host: Option<String>) -> Self {
+        self.host = host;
+        self
+    }
+}
+
+/// Settings for `CREATE USER` / `ALTER USER`. `None` fields are left out of
+/// the generated statement.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UserSpec {
+    pub account: UserAccount,
+    pub password: Option<String>,
+    pub can_login: Option<bool>,
+    pub superuser: Option<bool>,
+    pub create_db: Option<bool>,
+    pub create_role: Option<bool>,
+    pub connection_limit: Option<i32>,
+}
+
+/// What a `GRANT` or `REVOKE` applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrantTarget {
+    /// Membership in another role; the privilege list is not used.
+    Role(String),
+    Database(String),
+    Schema(String),
+    Table {
+        schema: Option<String>,
+        name: String,
+    },
+    AllTablesInSchema(String),
+}
+
+/// A `GRANT` / `REVOKE` request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrantSpec {
+    /// Privilege keywords such as `SELECT` or `ALL PRIVILEGES`.
+    pub privileges: Vec<String>,
+    pub target: GrantTarget,
+    pub grantee: UserAccount,
+    /// Adds `WITH GRANT OPTION` (`WITH ADMIN OPTION` for role membership).
+    /// Only used by `GRANT`.
+    pub with_grant_option: bool,
+}
+
+/// Per-driver SQL for listing and administering users and roles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserAdminDialect {
+    kind: DbKind,
+    /// Whether backslashes escape in MySQL/MariaDB string literals, i.e. the
+    /// server's `sql_mode` lacks `NO_BACKSLASH_ESCAPES`.
+    backslash_escapes: bool,
+}
+
+impl UserAdminDialect {
+    /// Returns `None` for drivers without user administration support.
+    pub fn for_kind(kind: DbKind) -> Option<Self> {
+        match kind {
+            DbKind::Postgres | DbKind::MySQL | DbKind::MariaDB => Some(Self {
+                kind,
+                backslash_escapes: true,
+            }),
+            DbKind::SQLite
+            | DbKind::SqlServer
+            | DbKind::MongoDB
+            | DbKind::Redis
+            | DbKind::DynamoDB
+            | DbKind::CloudWatchLogs
+            | DbKind::InfluxDB => None,
+        }
+    }
+
+    /// Query returning the session's `sql_mode`, on drivers whose string
+    /// literals depend on it (MySQL, MariaDB). Pass its value to
+    /// [`with_sql_mode`](Self::with_sql_mode) before building statements
+    /// that carry passwords.
+    pub fn sql_mode_sql(&self) -> Option<&'static str> {
+        self.uses_hosts().then_some("SELECT @@SESSION.sql_mode")
+    }
+
+    /// This dialect quoting string literals for a server running with
+    /// `sql_mode`: under `NO_BACKSLASH_ESCAPES` a backslash is an ordinary
+    /// character and must not be doubled.
+    pub fn with_sql_mode(mut self, sql_mode: &str) -> Self {
+        self.backslash_escapes = !sql_mode
+            .split(',')
+            .any(|mode| mode.trim().eq_ignore_ascii_case("NO_BACKSLASH_ESCAPES"));
+        self
+    }
+
+    /// Whether accounts are `user@host` pairs (MySQL, MariaDB).
+    pub fn uses_hosts(&self) -> bool {
+        matches!(self.kind, DbKind::MySQL | DbKind::MariaDB)
+    }
+
+    /// Whether roles carry the login, superuser, create-db and create-role
+    /// attributes (PostgreSQL).
+    pub fn supports_role_attributes(&self) -> bool {
+        self.kind == DbKind::Postgres
+    }
+
+    /// Query listing users and roles with their attributes and the roles they
+    /// are members of. Always returns `name` first, and `host` second on
+    /// drivers that [use hosts](Self::uses_hosts).
+    pub fn list_users_sql(&self) -> &'static str {
+        match self.kind {
+            DbKind::MySQL => {
+                "SELECT u.User AS name, u.Host AS host, \
+                 u.account_locked = 'Y' AS locked, \
+                 u.password_expired = 'Y' AS password_expired, \
+                 u.max_user_connections AS connection_limit, \
+                 COALESCE((SELECT GROUP_CONCAT(CONCAT(e.FROM_USER, '@', e.FROM_HOST) \
+                 ORDER BY e.FROM_USER SEPARATOR ', ') \
+                 FROM mysql.role_edges e \
+                 WHERE e.TO_USER = u.User AND e.TO_HOST = u.Host), '') AS member_of \
+                 FROM mysql.user u ORDER BY u.User, u.Host"
+            }
+            DbKind::MariaDB => {
+                "SELECT u.User AS name, u.Host AS host, \
+                 u.is_role = 'Y' AS is_role, \
+                 u.password_expired = 'Y' AS password_expired, \
+                 u.max_user_connections AS connection_limit, \
+                 COALESCE((SELECT GROUP_CONCAT(m.Role ORDER BY m.Role SEPARATOR ', ') \
+                 FROM mysql.roles_mapping m \
+                 WHERE m.User = u.User AND m.Host = u.Host), '') AS member_of \
+                 FROM mysql.user u ORDER BY u.User, u.Host"
+            }
+            _ => {
+                "SELECT r.rolname AS name, r.rolcanlogin AS can_login, \
+                 r.rolsuper AS superuser, r.rolcreatedb AS create_db, \
+                 r.rolcreaterole AS create_role, r.rolreplication AS replication, \
+                 NULLIF(r.rolconnlimit, -1) AS connection_limit, \
+                 r.rolvaliduntil AS valid_until, \
+                 COALESCE((SELECT string_agg(m.rolname, ', ' ORDER BY m.rolname) \
+                 FROM pg_auth_members am JOIN pg_roles m ON m.oid = am.roleid \
+                 WHERE am.member = r.oid), '') AS member_of \
+                 FROM pg_roles r WHERE r.rolname !~ '^pg_' ORDER BY r.rolname"
+            }
+        }
+    }
+
+    pub fn create_user_sql(&self, spec: &UserSpec) -> Result<String, DbError> {
+        let account = self.account(&spec.account)?;
+        let options = self.user_options(spec);
+
+        Ok(match (self.uses_hosts(), options.is_empty()) {
+            (true, _) => format!("CREATE USER {account}{}", options.concat()),
+            (false, true) => format!("CREATE ROLE {account}"),
+            (false, false) => format!("CREATE ROLE {account} WITH{}", options.concat()),
+        })
+    }
+
+    pub fn alter_user_sql(&self, spec: &UserSpec) -> Result<String, DbError> {
+        let account = self.account(&spec.account)?;
+        let options = self.user_options(spec);
+
+        if options.is_empty() {
+            return Err(DbError::Parse("Nothing to change".to_string()));
+        }
+
+        Ok(if self.uses_hosts() {
+            format!("ALTER USER {account}{}", options.concat())
+        } else {
+            format!("ALTER ROLE {account} WITH{}", options.concat())
+        })
+    }
+
+    pub fn drop_user_sql(&self, account: &UserAccount) -> Result<String, DbError> {
+        let account_sql = self.account(account)?;
+
+        Ok(if self.uses_hosts() {
+            format!("DROP USER {account_sql}")
+        } else {
+            format!("DROP ROLE {account_sql}")
+        })
+    }
+
+    pub fn grant_sql(&self, grant: &GrantSpec) -> Result<String, DbError> {
+        let grantee = self.account(&grant.grantee)?;
+        let (what, object) = self.grant_parts(grant)?;
+
+        let option = match (&grant.target, grant.with_grant_option) {
+            (_, false) => "",
+            (GrantTarget::Role(_), true) => " WITH ADMIN OPTION",
+            (_, true) => " WITH GRANT OPTION",
+        };
+
+        Ok(format!("GRANT {what}{object} TO {grantee}{option}"))
+    }
+
+    pub fn revoke_sql(&self, grant: &GrantSpec) -> Result<String, DbError> {
+        let grantee = self.account(&grant.grantee)?;
+        let (what, object) = self.grant_parts(grant)?;
+
+        Ok(format!("REVOKE {what}{object} FROM {grantee}"))
+    }
+
+    /// Option clauses, each with a leading space. PostgreSQL clauses follow
+    /// `WITH`; MySQL ones are full `IDENTIFIED BY` / `WITH` clauses.
+    fn user_options(&self, spec: &UserSpec) -> Vec<String> {
+        let mut options = Vec::new();
+
+        if self.uses_hosts() {
+            if let Some(password) = &spec.password {
+                options.push(format!(" IDENTIFIED BY {}", self.string(password)));
+            }
+            if let Some(limit) = spec.connection_limit {
+                options.push(format!(" WITH MAX_USER_CONNECTIONS {}", limit.max(0)));
+            }
+            return options;
+        }
+
+        let flags = [
+            (spec.can_login, "LOGIN"),
+            (spec.superuser, "SUPERUSER"),
+            (spec.create_db, "CREATEDB"),
+            (spec.create_role, "CREATEROLE"),
+        ];
+        for (value, keyword) in flags {
+            match value {
+                Some(true) => options.push(format!(" {keyword}")),
+                Some(false) => options.push(format!(" NO{keyword}")),
+                None => {}
+            }
+        }
+
+        if let Some(limit) = spec.connection_limit {
+            options.push(format!(" CONNECTION LIMIT {}", limit.max(-1)));
+        }
+        if let Some(password) = &spec.password {
+            options.push(format!(" PASSWORD {}", self.string(password)));
+        }
+
+        options
+    }
+
+    /// The privilege list (or granted role) and the ` ON …` clause.
+    fn grant_parts(&self, grant: &GrantSpec) -> Result<(String, String), DbError> {
+        let object = match &grant.target {
+            GrantTarget::Role(role) => {
+                let role = required(role, "Role")?;
+                let role = if self.uses_hosts() {
+                    self.string(role)
+                } else {
+                    self.identifier(role)
+                };
+                return Ok((role, String::new()));
+            }
+            GrantTarget::Database(name) if self.uses_hosts() => {
+                format!("{}.*", self.identifier(required(name, "Database")?))
+            }
+            GrantTarget::Schema(name) | GrantTarget::AllTablesInSchema(name)
+                if self.uses_hosts() =>
+            {
+                format!("{}.*", self.identifier(required(name, "Schema")?))
+            }
+            GrantTarget::Database(name) => {
+                format!("DATABASE {}", self.identifier(required(name, "Database")?))
+            }
+            GrantTarget::Schema(name) => {
+                format!("SCHEMA {}", self.identifier(required(name, "Schema")?))
+            }
+            GrantTarget::AllTablesInSchema(name) => format!(
+                "ALL TABLES IN SCHEMA {}",
+                self.identifier(required(name, "Schema")?)
+            ),
+            GrantTarget::Table { schema, name } => {
+                let table = self.identifier(required(name, "Table")?);
+                let qualified = match schema.as_deref().map(str::trim) {
+                    Some(schema) if !schema.is_empty() => {
+                        format!("{}.{table}", self.identifier(schema))
+                    }
+                    _ => table,
+                };
+
+                if self.uses_hosts() {
+                    qualified
+                } else {
+                    format!("TABLE {qualified}")
+                }
+            }
+        };
+
+        Ok((privilege_list(&grant.privileges)?, format!(" ON {object}")))
+    }
+
+    fn account(&self, account: &UserAccount) -> Result<String, DbError> {
+        let name = required(&account.name, "User name")?;
+
+        if !self.uses_hosts() {
+            return Ok(self.identifier(name));
+        }
+
+        let host = account
+            .host
+            .as_deref()
+            .map(str::trim)
+            .filter(|host| !host.is_empty())
+            .unwrap_or("%");
+
+        Ok(format!("{}@{}", self.string(name), self.string(host)))
+    }
+
+    fn identifier(&self, name: &str) -> String {
+        if self.uses_hosts() {
+            format!("`{}`", name.replace('`', "``"))
+        } else {
+            format!("\"{}\"", name.replace('"', "\"\""))
+        }
+    }
+
+    fn string(&self, value: &str) -> String {
+        if self.uses_hosts() {
+            mysql_string_literal(value, self.backslash_escapes)
+        } else {
+            format!("'{}'", value.replace('\'', "''"))
+        }
+    }
+}
+
+/// `value` as a MySQL/MariaDB string literal. Backslashes are doubled when
+/// they are escapes, i.e. unless the server runs with `NO_BACKSLASH_ESCAPES`.
+pub(crate) fn mysql_string_literal(value: &str, backslash_escapes: bool) -> String {
+    let value = if backslash_escapes {
+        value.replace('\\', "\\\\")
+    } else {
+        value.to_string()
+    };
+
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn required<'a>(value: &'a str, what: &str) -> Result<&'a str, DbError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(DbError::Parse(format!("{what} is empty")));
+    }
+    Ok(value)
+}
+
+/// Upper-cases and joins privilege keywords, rejecting anything that is not
+/// made of letters and spaces so the list cannot smuggle in other SQL.
+fn privilege_list(privileges: &[String]) -> Result<String, DbError> {
+    let privileges: Vec<String> = privileges
+        .iter()
+        .map(|privilege| privilege.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|privilege| !privilege.is_empty())
+        .collect();
+
+    if privileges.is_empty() {
+        return Err(DbError::Parse("No privileges selected".to_string()));
+    }
+
+    if let Some(invalid) = privileges.iter().find(|privilege| {
+        !privilege
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == ' ')
+    }) {
+        return Err(DbError::Parse(format!("'{invalid}' is not a privilege")));
+    }
+
+    Ok(privileges
+        .iter()
+        .map(|privilege| privilege.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn postgres() -> UserAdminDialect {
+        UserAdminDialect::for_kind(DbKind::Postgres).unwrap()
+    }
+
+    fn mysql() -> UserAdminDialect {
+        UserAdminDialect::for_kind(DbKind::MySQL).unwrap()
+    }
+
+    #[test]
+    fn postgres_creates_roles_with_attributes() {
+        let spec = UserSpec {
+            account: UserAccount::new("report\"er"),
+            password: Some("it's secret".to_string()),
+            can_login: Some(true),
+            superuser: Some(false),
+            connection_limit: Some(5),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            postgres().create_user_sql(&spec).unwrap(),
+            "CREATE ROLE \"report\"\"er\" WITH LOGIN NOSUPERUSER CONNECTION LIMIT 5 \
+             PASSWORD 'it''s secret'"
+        );
+    }
+
+    #[test]
+    fn mysql_creates_host_qualified_users() {
+        let spec = UserSpec {
+            account: UserAccount::new("app").with_host(Some("10.0.%".to_string())),
+            password: Some("pa\\ss".to_string()),
+            // Role attributes do not exist on MySQL and are left out.
+            superuser: Some(true),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mysql().create_user_sql(&spec).unwrap(),
+            "CREATE USER 'app'@'10.0.%' IDENTIFIED BY 'pa\\\\ss'"
+        );
+        assert_eq!(
+            mysql().drop_user_sql(&UserAccount::new("app")).unwrap(),
+            "DROP USER 'app'@'%'"
+        );
+    }
+
+    #[test]
+    fn mysql_keeps_backslashes_under_no_backslash_escapes() {
+        let spec = UserSpec {
+            account: UserAccount::new("app"),
+            password: Some("pa\\ss'".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mysql()
+                .with_sql_mode("STRICT_TRANS_TABLES,NO_BACKSLASH_ESCAPES")
+                .alter_user_sql(&spec)
+                .unwrap(),
+            "ALTER USER 'app'@'%' IDENTIFIED BY 'pa\\ss'''"
+        );
+        assert_eq!(
+            mysql()
+                .with_sql_mode("STRICT_TRANS_TABLES")
+                .alter_user_sql(&spec)
+                .unwrap(),
+            "ALTER USER 'app'@'%' IDENTIFIED BY 'pa\\\\ss'''"
+        );
+        assert_eq!(mysql().sql_mode_sql(), Some("SELECT @@SESSION.sql_mode"));
+        assert_eq!(postgres().sql_mode_sql(), None);
+    }
+
+    #[test]
+    fn alter_without_changes_is_rejected() {
+        let spec = UserSpec {
+            account: UserAccount::new("app"),
+            ..Default::default()
+        };
+
+        assert!(postgres().alter_user_sql(&spec).is_err());
+        assert!(mysql().alter_user_sql(&spec).is_err());
+    }
+
+    #[test]
+    fn grants_privileges_on_each_target() {
+        let grant = |target| GrantSpec {
+            privileges: vec!["select".to_string(), " insert ".to_string()],
+            target,
+            grantee: UserAccount::new("app"),
+            with_grant_option: false,
+        };
+
+        assert_eq!(
+            postgres()
+                .grant_sql(&grant(GrantTarget::AllTablesInSchema("public".to_string())))
+                .unwrap(),
+            "GRANT SELECT, INSERT ON ALL TABLES IN SCHEMA \"public\" TO \"app\""
+        );
+        assert_eq!(
+            postgres()
+                .revoke_sql(&grant(GrantTarget::Table {
+                    schema: Some("sales".to_string()),
+                    name: "orders".to_string(),
+                }))
+                .unwrap(),
+            "REVOKE SELECT, INSERT ON TABLE \"sales\".\"orders\" FROM \"app\""
+        );
+        assert_eq!(
+            mysql()
+                .grant_sql(&grant(GrantTarget::Database("shop".to_string())))
+                .unwrap(),
+            "GRANT SELECT, INSERT ON `shop`.* TO 'app'@'%'"
+        );
+    }
+
+    #[test]
+    fn grants_role_membership_with_admin_option() {
+        let grant = GrantSpec {
+            privileges: Vec::new(),
+            target: GrantTarget::Role("readers".to_string()),
+            grantee: UserAccount::new("app"),
+            with_grant_option: true,
+        };
+
+        assert_eq!(
+            postgres().grant_sql(&grant).unwrap(),
+            "GRANT \"readers\" TO \"app\" WITH ADMIN OPTION"
+        );
+        assert_eq!(
+            mysql().revoke_sql(&grant).unwrap(),
+            "REVOKE 'readers' FROM 'app'@'%'"
+        );
+    }
+
+    #[test]
+    fn rejects_privileges_that_are_not_keywords() {
+        let grant = GrantSpec {
+            privileges: vec!["SELECT; DROP TABLE users".to_string()],
+            target: GrantTarget::Database("shop".to_string()),
+            grantee: UserAccount::new("app"),
+            with_grant_option: false,
+        };
+
+        assert!(postgres().grant_sql(&grant).is_err());
+    }
+
+    #[test]
+    fn only_postgres_and_mysql_family_are_supported() {
+        assert!(UserAdminDialect::for_kind(DbKind::MariaDB).is_some());
+        assert!(UserAdminDialect::for_kind(DbKind::SQLite).is_none());
+        assert!(UserAdminDialect::for_kind(DbKind::SqlServer).is_none());
+    }
+}
@@ -0,0 +1,286 @@
+//! Pure helpers for `{{name}}` query template variables.
+//!
+//! A variable is written as `{{name}}` (whitespace inside the braces is
+//! allowed) where `name` starts with an ASCII letter or underscore and
+//! continues with ASCII alphanumerics or underscores. Brace pairs that do not
+//! wrap a valid name — `{{ 1 + 2 }}`, nested JSON documents — are left alone,
+//! so MongoDB and other brace-heavy languages pass through unchanged.
+//!
+//! Substitution is textual: the stored value is inserted verbatim, so string
+//! values must carry their own quotes (`'2024-01-01'`). Placeholders inside
+//! comments and string literals are substituted too.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::ops::Range;
+use uuid::Uuid;
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+
+/// Storage key used for variables shared by every connection.
+const WORKSPACE_SCOPE_KEY: &str = "workspace";
+
+/// Where a variable value is stored.
+///
+/// Connection-scoped values take precedence over workspace-scoped values with
+/// the same name when a query runs against that connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryVariableScope {
+    Workspace,
+    Connection(Uuid),
+}
+
+impl QueryVariableScope {
+    /// Stable string form used as the storage key.
+    pub fn storage_key(&self) -> String {
+        match self {
+            Self::Workspace => WORKSPACE_SCOPE_KEY.to_string(),
+            Self::Connection(profile_id) => profile_id.to_string(),
+        }
+    }
+
+    /// Parses a key produced by [`QueryVariableScope::storage_key`].
+    pub fn from_storage_key(key: &str) -> Option<Self> {
+        if key == WORKSPACE_SCOPE_KEY {
+            return Some(Self::Workspace);
+        }
+
+        Uuid::parse_str(key).ok().map(Self::Connection)
+    }
+}
+
+/// One `{{name}}` occurrence in a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryVariableRef {
+    pub name: String,
+    /// Byte range of the whole placeholder, braces included.
+    pub range: Range<usize>,
+}
+
+/// Returned when a query references variables that have no value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndefinedQueryVariables(pub Vec<String>);
+
+impl fmt::Display for UndefinedQueryVariables {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let noun = if self.0.len() == 1 {
+            "variable"
+        } else {
+            "variables"
+        };
+
+        write!(f, "Undefined query {}: {}", noun, self.0.join(", "))
+    }
+}
+
+impl std::error::Error for UndefinedQueryVariables {}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns every placeholder in `query`, in source order.
+///
+/// # Example
+///
+/// ```
+/// use dbflux_core::find_query_variables;
+///
+/// let refs = find_query_variables("SELECT * FROM t WHERE id = {{ user_id }}");
+/// assert_eq!(refs.len(), 1);
+/// assert_eq!(refs[0].name, "user_id");
+/// ```
+pub fn find_query_variables(query: &str) -> Vec<QueryVariableRef> {
+    let mut refs = Vec::new();
+    let mut cursor = 0;
+
+    while let Some(found) = query[cursor..].find(OPEN) {
+        let start = cursor + found;
+        let name_start = start + OPEN.len();
+
+        let Some(close) = query[name_start..].find(CLOSE) else {
+            break;
+        };
+
+        let name = query[name_start..name_start + close].trim();
+
+        if is_variable_name(name) {
+            let end = name_start + close + CLOSE.len();
+            refs.push(QueryVariableRef {
+                name: name.to_string(),
+                range: start..end,
+            });
+            cursor = end;
+        } else {
+            // Step past one brace so `{{{name}}` still finds the inner pair.
+            cursor = start + 1;
+        }
+    }
+
+    refs
+}
+
+/// Distinct variable names referenced by `query`, sorted alphabetically.
+pub fn query_variable_names(query: &str) -> Vec<String> {
+    find_query_variables(query)
+        .into_iter()
+        .map(|reference| reference.name)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Replaces every placeholder with its value from `values`.
+///
+/// Fails without touching the query when any referenced name is missing, so
+/// a half-substituted statement never reaches the driver.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use dbflux_core::substitute_query_variables;
+///
+/// let values = HashMap::from([("limit".to_string(), "10".to_string())]);
+/// let sql = substitute_query_variables("SELECT 1 LIMIT {{limit}}", &values).unwrap();
+/// assert_eq!(sql, "SELECT 1 LIMIT 10");
+/// ```
+pub fn substitute_query_variables(
+    query: &str,
+    values: &HashMap<String, String>,
+) -> Result<String, UndefinedQueryVariables> {
+    let refs = find_query_variables(query);
+
+    let undefined: BTreeSet<&str> = refs
+        .iter()
+        .filter(|reference| !values.contains_key(&reference.name))
+        .map(|reference| reference.name.as_str())
+        .collect();
+
+    if !undefined.is_empty() {
+        return Err(UndefinedQueryVariables(
+            undefined.into_iter().map(String::from).collect(),
+        ));
+    }
+
+    let mut output = String::with_capacity(query.len());
+    let mut last_end = 0;
+
+    for reference in &refs {
+        output.push_str(&query[last_end..reference.range.start]);
+        output.push_str(&values[&reference.name]);
+        last_end = reference.range.end;
+    }
+
+    output.push_str(&query[last_end..]);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn finds_placeholders_with_optional_whitespace() {
+        let query = "SELECT {{a}}, {{ b_2 }} FROM t";
+        let refs = find_query_variables(query);
+
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].name, "a");
+        assert_eq!(&query[refs[0].range.clone()], "{{a}}");
+        assert_eq!(refs[1].name, "b_2");
+        assert_eq!(&query[refs[1].range.clone()], "{{ b_2 }}");
+    }
+
+    #[test]
+    fn ignores_braces_that_do_not_wrap_a_name() {
+        assert!(find_query_variables("{{ 1 + 2 }}").is_empty());
+        assert!(find_query_variables("{{9lives}}").is_empty());
+        assert!(find_query_variables(r#"{"a": {"$gt": 1}}"#).is_empty());
+        assert!(find_query_variables("{{unterminated").is_empty());
+    }
+
+    #[test]
+    fn triple_brace_still_finds_inner_placeholder() {
+        let refs = find_query_variables("{{{name}}}");
+
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].range, 1..9);
+    }
+
+    #[test]
+    fn names_are_distinct_and_sorted() {
+        let names = query_variable_names("{{b}} {{a}} {{b}}");
+        assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn substitutes_every_occurrence() {
+        let result = substitute_query_variables(
+            "SELECT * FROM {{table}} WHERE id = {{id}} OR parent = {{ id }}",
+            &values(&[("table", "users"), ("id", "42")]),
+        );
+
+        assert_eq!(
+            result.as_deref(),
+            Ok("SELECT * FROM users WHERE id = 42 OR parent = 42")
+        );
+    }
+
+    #[test]
+    fn substitution_reports_all_undefined_names() {
+        let result =
+            substitute_query_variables("{{b}} {{a}} {{known}} {{b}}", &values(&[("known", "1")]));
+
+        assert_eq!(
+            result,
+            Err(UndefinedQueryVariables(vec![
+                "a".to_string(),
+                "b".to_string()
+            ]))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Undefined query variables: a, b"
+        );
+    }
+
+    #[test]
+    fn query_without_placeholders_passes_through() {
+        let query = "SELECT '{{' || col || '}}' FROM t";
+        assert_eq!(
+            substitute_query_variables(query, &HashMap::new()).as_deref(),
+            Ok(query)
+        );
+    }
+
+    #[test]
+    fn scope_storage_key_round_trips() {
+        let profile_id = Uuid::new_v4();
+
+        for scope in [
+            QueryVariableScope::Workspace,
+            QueryVariableScope::Connection(profile_id),
+        ] {
+            assert_eq!(
+                QueryVariableScope::from_storage_key(&scope.storage_key()),
+                Some(scope)
+            );
+        }
+
+        assert_eq!(QueryVariableScope::from_storage_key("bogus"), None);
+    }
+}
@@ -186,6 +186,8 @@ mod tests {
             is_primary_key: is_pk,
             default_value: None,
             enum_values: None,
+            comment: None,
+            definition: None,
         }
     }
 
@@ -200,6 +202,7 @@ mod tests {
             sample_fields: None,
             presentation: Default::default(),
             child_items: None,
+            comment: None,
         }
     }
 
@@ -412,6 +415,7 @@ mod tests {
                 sample_fields: None,
                 presentation: Default::default(),
                 child_items: None,
+                comment: None,
             })
         }
     }
@@ -427,6 +431,7 @@ mod tests {
             sample_fields: None,
             presentation: Default::default(),
             child_items: None,
+            comment: None,
         }
     }
 
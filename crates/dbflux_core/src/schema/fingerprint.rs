@@ -69,6 +69,8 @@ mod tests {
                     is_primary_key: true,
                     default_value: None,
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 },
                 ColumnInfo {
                     name: "email".to_string(),
@@ -77,6 +79,8 @@ mod tests {
                     is_primary_key: false,
                     default_value: None,
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 },
             ]),
             indexes: None,
@@ -85,6 +89,7 @@ mod tests {
             sample_fields: None,
             presentation: Default::default(),
             child_items: None,
+            comment: None,
         }
     }
 
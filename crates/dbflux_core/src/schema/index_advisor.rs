@@ -0,0 +1,742 @@
+//! Index suggestions drawn from the slow queries run against a connection.
+//!
+//! The columns each query filters on, ranges over or joins by are read with
+//! the same lightweight tokenizer as
+//! [`extract_referenced_tables`](super::extract_referenced_tables), grouped per
+//! table into a candidate index, and dropped when an existing index already
+//! leads with those columns. Candidates are ranked by the execution time of
+//! the queries they would serve.
+
+use std::collections::HashMap;
+
+use super::query_parser::{
+    Token, is_reserved_keyword, read_table_binding, strip_comments, tokenize,
+};
+use crate::{HistoryEntry, IndexData, QueryTableRef, TableInfo};
+
+/// Columns a suggested index covers at most.
+const MAX_INDEX_COLUMNS: usize = 3;
+
+/// Longest index name most engines accept (PostgreSQL's limit).
+const MAX_INDEX_NAME_LEN: usize = 63;
+
+/// How a query compares a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateKind {
+    /// `=`, `IN` or `IS` against a value.
+    Equality,
+    /// `<`, `<=`, `>`, `>=` or `BETWEEN`.
+    Range,
+    /// `=` against a column of another table.
+    JoinKey,
+}
+
+/// A column compared in a `WHERE` or `ON` clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnPredicate {
+    /// Tables the column may belong to: just one when the column is qualified
+    /// or the statement reads a single table.
+    pub tables: Vec<QueryTableRef>,
+    pub column: String,
+    pub kind: PredicateKind,
+}
+
+/// A statement and the time the server spent running it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkloadQuery {
+    pub sql: String,
+    pub calls: u64,
+    pub total_time_ms: f64,
+}
+
+impl WorkloadQuery {
+    pub fn mean_time_ms(&self) -> f64 {
+        if self.calls == 0 {
+            0.0
+        } else {
+            self.total_time_ms / self.calls as f64
+        }
+    }
+
+    /// Successful history entries folded into one query per distinct
+    /// statement, counting every collapsed run.
+    pub fn from_history(entries: &[HistoryEntry]) -> Vec<Self> {
+        let mut queries: Vec<Self> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        for entry in entries.iter().filter(|entry| entry.succeeded) {
+            let key = entry.sql.split_whitespace().collect::<Vec<_>>().join(" ");
+            let runs = u64::from(entry.run_count.max(1));
+            let time_ms = entry.execution_time_ms as f64 * runs as f64;
+
+            match positions.get(&key) {
+                Some(&ix) => {
+                    queries[ix].calls += runs;
+                    queries[ix].total_time_ms += time_ms;
+                }
+                None => {
+                    positions.insert(key, queries.len());
+                    queries.push(Self {
+                        sql: entry.sql.clone(),
+                        calls: runs,
+                        total_time_ms: time_ms,
+                    });
+                }
+            }
+        }
+
+        queries
+    }
+}
+
+/// An index the advisor recommends creating.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexSuggestion {
+    /// The table as the schema lists it.
+    pub table: QueryTableRef,
+    /// Equality columns first, then at most one range column.
+    pub columns: Vec<String>,
+    /// Distinct statements the index would serve.
+    pub queries: usize,
+    /// Executions of those statements.
+    pub calls: u64,
+    /// Time spent running those statements, split evenly between the tables
+    /// each of them lacks an index on. An upper bound on what the index saves.
+    pub estimated_benefit_ms: f64,
+    /// The statement contributing the most time.
+    pub example_sql: String,
+}
+
+impl IndexSuggestion {
+    /// `idx_<table>_<columns>`, cut to a length every engine accepts.
+    pub fn index_name(&self) -> String {
+        let mut name = format!("idx_{}_{}", self.table.table, self.columns.join("_"))
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .to_lowercase();
+
+        if name.len() > MAX_INDEX_NAME_LEN {
+            let mut end = MAX_INDEX_NAME_LEN;
+            while !name.is_char_boundary(end) {
+                end -= 1;
+            }
+            name.truncate(end);
+        }
+
+        name
+    }
+}
+
+/// Columns compared in the `WHERE` and `ON` clauses of `query`.
+///
+/// Only bare column comparisons count; a column wrapped in a function call or
+/// compared with `<>`, `!=` or `LIKE` cannot use a plain index. A column only
+/// counts as a join key when the other side is a qualified column.
+pub fn extract_column_predicates(query: &str) -> Vec<ColumnPredicate> {
+    let cleaned = strip_comments(query);
+    let tokens: Vec<Token> = tokenize(&cleaned)
+        .into_iter()
+        .filter(|token| *token != Token::Other)
+        .collect();
+
+    let mut bindings: Vec<(QueryTableRef, Option<String>)> = Vec::new();
+    let mut comparisons: Vec<(ColumnPath, PredicateKind)> = Vec::new();
+
+    // Whether the current parenthesis level is inside a WHERE or ON clause.
+    let mut in_predicate = vec![false];
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::LParen => {
+                let inherited = in_predicate.last().copied().unwrap_or(false);
+                in_predicate.push(inherited);
+                i += 1;
+            }
+            Token::RParen => {
+                if in_predicate.len() > 1 {
+                    in_predicate.pop();
+                }
+                i += 1;
+            }
+            Token::Ident(word) if is_clause_keyword(word) => {
+                let upper = word.to_uppercase();
+                i += 1;
+
+                if let Some(current) = in_predicate.last_mut() {
+                    *current = matches!(upper.as_str(), "WHERE" | "ON");
+                }
+
+                if matches!(upper.as_str(), "FROM" | "JOIN" | "UPDATE" | "INTO") {
+                    while let Some(binding) = read_table_binding(&tokens, &mut i) {
+                        bindings.push(binding);
+                        if tokens.get(i) != Some(&Token::Symbol(',')) {
+                            break;
+                        }
+                        i += 1;
+                    }
+                }
+            }
+            Token::Ident(_) if in_predicate.last().copied().unwrap_or(false) => {
+                let Some((path, after_path)) = read_column_path(&tokens, i) else {
+                    i += 1;
+                    continue;
+                };
+                i = after_path;
+
+                let Some((kind, after_operator)) = read_operator(&tokens, i) else {
+                    continue;
+                };
+                i = after_operator;
+
+                let other_side = read_column_path(&tokens, i)
+                    .filter(|(other, _)| kind == PredicateKind::Equality && other.is_qualified());
+
+                match other_side {
+                    Some((other, after_other)) => {
+                        comparisons.push((path, PredicateKind::JoinKey));
+                        comparisons.push((other, PredicateKind::JoinKey));
+                        i = after_other;
+                    }
+                    None => comparisons.push((path, kind)),
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    comparisons
+        .into_iter()
+        .filter_map(|(path, kind)| {
+            let tables = path.candidate_tables(&bindings);
+            (!tables.is_empty()).then_some(ColumnPredicate {
+                tables,
+                column: path.column,
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Ranked index suggestions for `workload`.
+///
+/// `lookup` resolves a table the queries reference to its loaded details,
+/// whose columns decide which table an unqualified column belongs to and
+/// whose indexes rule out candidates that are already covered. Tables it
+/// cannot resolve are skipped.
+pub fn suggest_indexes<'a>(
+    workload: &[WorkloadQuery],
+    lookup: impl Fn(&QueryTableRef) -> Option<&'a TableInfo>,
+) -> Vec<IndexSuggestion> {
+    let mut suggestions: Vec<IndexSuggestion> = Vec::new();
+    let mut example_time: Vec<f64> = Vec::new();
+
+    for query in workload {
+        let candidates = candidate_indexes(&query.sql, &lookup);
+        if candidates.is_empty() {
+            continue;
+        }
+
+        let share_ms = query.total_time_ms / candidates.len() as f64;
+
+        for (table, columns) in candidates {
+            let existing = suggestions.iter().position(|suggestion| {
+                same_table(&suggestion.table, &table) && suggestion.columns == columns
+            });
+
+            match existing {
+                Some(ix) => {
+                    let suggestion = &mut suggestions[ix];
+                    suggestion.queries += 1;
+                    suggestion.calls += query.calls;
+                    suggestion.estimated_benefit_ms += share_ms;
+
+                    if query.total_time_ms > example_time[ix] {
+                        suggestion.example_sql = query.sql.clone();
+                        example_time[ix] = query.total_time_ms;
+                    }
+                }
+                None => {
+                    suggestions.push(IndexSuggestion {
+                        table,
+                        columns,
+                        queries: 1,
+                        calls: query.calls,
+                        estimated_benefit_ms: share_ms,
+                        example_sql: query.sql.clone(),
+                    });
+                    example_time.push(query.total_time_ms);
+                }
+            }
+        }
+    }
+
+    suggestions.sort_by(|a, b| {
+        b.estimated_benefit_ms
+            .total_cmp(&a.estimated_benefit_ms)
+            .then_with(|| a.table.table.cmp(&b.table.table))
+    });
+    suggestions
+}
+
+/// The uncovered index each table of `sql` would need, as the table and
+/// its column list.
+fn candidate_indexes<'a>(
+    sql: &str,
+    lookup: &impl Fn(&QueryTableRef) -> Option<&'a TableInfo>,
+) -> Vec<(QueryTableRef, Vec<String>)> {
+    let mut per_table: Vec<(&'a TableInfo, Vec<String>, Option<String>)> = Vec::new();
+
+    for predicate in extract_column_predicates(sql) {
+        let Some(table) = resolve_column_table(&predicate, lookup) else {
+            continue;
+        };
+
+        let ix = match per_table
+            .iter()
+            .position(|(known, _, _)| std::ptr::eq(*known, table))
+        {
+            Some(ix) => ix,
+            None => {
+                per_table.push((table, Vec::new(), None));
+                per_table.len() - 1
+            }
+        };
+
+        let (_, equality, range) = &mut per_table[ix];
+        let column = canonical_column(table, &predicate.column);
+
+        match predicate.kind {
+            PredicateKind::Equality | PredicateKind::JoinKey => {
+                if !equality.contains(&column) {
+                    equality.push(column);
+                }
+            }
+            PredicateKind::Range => {
+                if range.is_none() {
+                    *range = Some(column);
+                }
+            }
+        }
+    }
+
+    per_table
+        .into_iter()
+        .filter_map(|(table, mut columns, range)| {
+            if let Some(range) = range
+                && !columns.contains(&range)
+            {
+                columns.push(range);
+            }
+            columns.truncate(MAX_INDEX_COLUMNS);
+
+            if columns.is_empty() || is_covered(table, &columns) {
+                return None;
+            }
+
+            let table_ref = QueryTableRef {
+                database: None,
+                schema: table.schema.clone(),
+                table: table.name.clone(),
+            };
+            Some((table_ref, columns))
+        })
+        .collect()
+}
+
+/// The one candidate table of `predicate` that resolves and has its column.
+fn resolve_column_table<'a>(
+    predicate: &ColumnPredicate,
+    lookup: &impl Fn(&QueryTableRef) -> Option<&'a TableInfo>,
+) -> Option<&'a TableInfo> {
+    let mut matches =
+        predicate
+            .tables
+            .iter()
+            .filter_map(lookup)
+            .filter(|table| match &table.columns {
+                Some(columns) => columns
+                    .iter()
+                    .any(|column| column.name.eq_ignore_ascii_case(&predicate.column)),
+                // Without its columns, a table can only own a column it alone
+                // could have been meant by.
+                None => predicate.tables.len() == 1,
+            });
+
+    let table = matches.next()?;
+    matches.next().is_none().then_some(table)
+}
+
+/// The column's name as the table lists it.
+fn canonical_column(table: &TableInfo, column: &str) -> String {
+    table
+        .columns
+        .iter()
+        .flatten()
+        .find(|candidate| candidate.name.eq_ignore_ascii_case(column))
+        .map(|candidate| candidate.name.clone())
+        .unwrap_or_else(|| column.to_string())
+}
+
+/// Whether an existing index, or a single-column primary key, already leads
+/// with `columns` in some order.
+fn is_covered(table: &TableInfo, columns: &[String]) -> bool {
+    let leads_with = |index_columns: &[String]| {
+        index_columns.len() >= columns.len()
+            && columns.iter().all(|column| {
+                index_columns[..columns.len()]
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(column))
+            })
+    };
+
+    let primary_key: Vec<String> = table
+        .columns
+        .iter()
+        .flatten()
+        .filter(|column| column.is_primary_key)
+        .map(|column| column.name.clone())
+        .collect();
+
+    leads_with(&primary_key)
+        || matches!(
+            &table.indexes,
+            Some(IndexData::Relational(indexes))
+                if indexes.iter().any(|index| leads_with(&index.columns))
+        )
+}
+
+fn same_table(a: &QueryTableRef, b: &QueryTableRef) -> bool {
+    a.table == b.table && a.schema == b.schema
+}
+
+/// A column reference: `column`, `qualifier.column` or
+/// `schema.table.column`.
+#[derive(Debug)]
+struct ColumnPath {
+    qualifier: Vec<String>,
+    column: String,
+}
+
+impl ColumnPath {
+    fn is_qualified(&self) -> bool {
+        !self.qualifier.is_empty()
+    }
+
+    /// The statement's tables the column may belong to.
+    fn candidate_tables(&self, bindings: &[(QueryTableRef, Option<String>)]) -> Vec<QueryTableRef> {
+        let mut tables: Vec<QueryTableRef> = Vec::new();
+
+        for (table, alias) in bindings {
+            let matches = match self.qualifier.as_slice() {
+                [] => true,
+                [name] => match alias {
+                    Some(alias) => alias.eq_ignore_ascii_case(name),
+                    None => table.table.eq_ignore_ascii_case(name),
+                },
+                [.., schema, name] => {
+                    table.table.eq_ignore_ascii_case(name)
+                        && table
+                            .schema
+                            .as_deref()
+                            .is_none_or(|own| own.eq_ignore_ascii_case(schema))
+                }
+            };
+
+            if matches && !tables.contains(table) {
+                tables.push(table.clone());
+            }
+        }
+
+        tables
+    }
+}
+
+/// Reads a column reference at `start`, returning it and the position after
+/// it. Literals, placeholders, keywords and function calls are not columns.
+fn read_column_path(tokens: &[Token], start: usize) -> Option<(ColumnPath, usize)> {
+    let mut parts = Vec::new();
+    let mut i = start;
+
+    loop {
+        let Some(Token::Ident(word)) = tokens.get(i) else {
+            return None;
+        };
+        parts.push(word.clone());
+        i += 1;
+
+        if tokens.get(i) == Some(&Token::Dot) {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+
+    let column = parts.pop()?;
+    let first = parts.first().unwrap_or(&column);
+    let is_value = first.starts_with(|c: char| c.is_ascii_digit() || c == '$');
+
+    if is_value
+        || (parts.is_empty() && is_predicate_keyword(&column))
+        || tokens.get(i) == Some(&Token::LParen)
+    {
+        return None;
+    }
+
+    Some((
+        ColumnPath {
+            qualifier: parts,
+            column,
+        },
+        i,
+    ))
+}
+
+/// Reads a comparison operator at `start`, returning how it compares and the
+/// position after it. Operators a plain index cannot serve read as `None`.
+fn read_operator(tokens: &[Token], start: usize) -> Option<(PredicateKind, usize)> {
+    let mut symbols = String::new();
+    let mut i = start;
+
+    while let Some(Token::Symbol(c)) = tokens.get(i)
+        && *c != ','
+    {
+        symbols.push(*c);
+        i += 1;
+    }
+
+    if !symbols.is_empty() {
+        return match symbols.as_str() {
+            "=" | "==" => Some((PredicateKind::Equality, i)),
+            "<" | "<=" | ">" | ">=" => Some((PredicateKind::Range, i)),
+            _ => None,
+        };
+    }
+
+    match tokens.get(i) {
+        Some(Token::Ident(word)) => match word.to_uppercase().as_str() {
+            "IN" | "IS" => Some((PredicateKind::Equality, i + 1)),
+            "BETWEEN" => Some((PredicateKind::Range, i + 1)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Keywords that start or end a clause the extractor tracks.
+fn is_clause_keyword(word: &str) -> bool {
+    matches!(
+        word.to_uppercase().as_str(),
+        "SELECT"
+            | "FROM"
+            | "JOIN"
+            | "UPDATE"
+            | "INTO"
+            | "WHERE"
+            | "ON"
+            | "GROUP"
+            | "ORDER"
+            | "HAVING"
+            | "LIMIT"
+            | "OFFSET"
+            | "UNION"
+            | "EXCEPT"
+            | "INTERSECT"
+            | "SET"
+            | "RETURNING"
+            | "VALUES"
+    )
+}
+
+/// Keywords that can appear where a predicate's column would.
+fn is_predicate_keyword(word: &str) -> bool {
+    let upper = word.to_uppercase();
+    is_reserved_keyword(&upper)
+        || matches!(
+            upper.as_str(),
+            "NULL" | "TRUE" | "FALSE" | "EXISTS" | "CASE" | "WHEN" | "THEN" | "ELSE" | "END"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColumnInfo, IndexInfo};
+
+    fn table(schema: &str, name: &str, columns: &[&str], indexes: &[&[&str]]) -> TableInfo {
+        TableInfo {
+            name: name.to_string(),
+            schema: Some(schema.to_string()),
+            columns: Some(
+                columns
+                    .iter()
+                    .map(|column| ColumnInfo {
+                        name: column.to_string(),
+                        type_name: "integer".to_string(),
+                        nullable: true,
+                        is_primary_key: *column == "id",
+                        default_value: None,
+                        enum_values: None,
+                        comment: None,
+                        definition: None,
+                    })
+                    .collect(),
+            ),
+            indexes: Some(IndexData::Relational(
+                indexes
+                    .iter()
+                    .map(|columns| IndexInfo {
+                        name: format!("idx_{}", columns.join("_")),
+                        columns: columns.iter().map(|c| c.to_string()).collect(),
+                        is_unique: false,
+                        is_primary: false,
+                    })
+                    .collect(),
+            )),
+            foreign_keys: None,
+            constraints: None,
+            sample_fields: None,
+            presentation: Default::default(),
+            child_items: None,
+            comment: None,
+        }
+    }
+
+    fn query(sql: &str, calls: u64, total_time_ms: f64) -> WorkloadQuery {
+        WorkloadQuery {
+            sql: sql.to_string(),
+            calls,
+            total_time_ms,
+        }
+    }
+
+    fn summary(predicates: &[ColumnPredicate]) -> Vec<(String, &str, PredicateKind)> {
+        predicates
+            .iter()
+            .map(|p| {
+                let tables: Vec<&str> = p.tables.iter().map(|t| t.table.as_str()).collect();
+                (tables.join("|"), p.column.as_str(), p.kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn predicates_resolve_aliases_and_join_keys() {
+        let predicates = extract_column_predicates(
+            "SELECT * FROM orders AS o JOIN customers c ON c.id = o.customer_id \
+             WHERE o.status = 'paid' AND o.created_at >= $1 AND lower(c.email) = 'x'",
+        );
+
+        assert_eq!(
+            summary(&predicates),
+            vec![
+                ("customers".to_string(), "id", PredicateKind::JoinKey),
+                ("orders".to_string(), "customer_id", PredicateKind::JoinKey),
+                ("orders".to_string(), "status", PredicateKind::Equality),
+                ("orders".to_string(), "created_at", PredicateKind::Range),
+            ]
+        );
+    }
+
+    #[test]
+    fn unqualified_columns_keep_every_candidate_table() {
+        let predicates = extract_column_predicates(
+            "SELECT * FROM a, b WHERE tenant_id IN (1, 2) AND (x BETWEEN 1 AND 5 OR y <> 3)",
+        );
+
+        assert_eq!(
+            summary(&predicates),
+            vec![
+                ("a|b".to_string(), "tenant_id", PredicateKind::Equality),
+                ("a|b".to_string(), "x", PredicateKind::Range),
+            ]
+        );
+    }
+
+    #[test]
+    fn suggestions_skip_covered_columns_and_rank_by_time() {
+        let orders = table(
+            "public",
+            "orders",
+            &["id", "customer_id", "status", "created_at"],
+            &[&["customer_id"]],
+        );
+        let customers = table("public", "customers", &["id", "email"], &[]);
+        let lookup = |table: &QueryTableRef| {
+            [&orders, &customers]
+                .into_iter()
+                .find(|candidate| candidate.name.eq_ignore_ascii_case(&table.table))
+        };
+
+        let workload = [
+            query(
+                "SELECT * FROM orders o JOIN customers c ON c.id = o.customer_id \
+                 WHERE o.status = 'paid' AND o.created_at > now()",
+                10,
+                4_000.0,
+            ),
+            query(
+                "SELECT * FROM orders WHERE status = 'open' AND created_at < $1",
+                5,
+                1_000.0,
+            ),
+            query("SELECT * FROM customers WHERE email = $1", 100, 3_000.0),
+            query("SELECT * FROM customers WHERE id = 7", 500, 9_000.0),
+        ];
+
+        let suggestions = suggest_indexes(&workload, lookup);
+        let ranked: Vec<(&str, Vec<&str>, usize, f64)> = suggestions
+            .iter()
+            .map(|s| {
+                (
+                    s.table.table.as_str(),
+                    s.columns.iter().map(String::as_str).collect(),
+                    s.queries,
+                    s.estimated_benefit_ms,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            ranked,
+            vec![
+                (
+                    "orders",
+                    vec!["customer_id", "status", "created_at"],
+                    1,
+                    4_000.0
+                ),
+                ("customers", vec!["email"], 1, 3_000.0),
+                ("orders", vec!["status", "created_at"], 1, 1_000.0),
+            ]
+        );
+        assert_eq!(suggestions[1].index_name(), "idx_customers_email");
+    }
+
+    #[test]
+    fn history_entries_fold_into_one_query_per_statement() {
+        let entry = |sql: &str, ms: u64, runs: u32, succeeded: bool| HistoryEntry {
+            run_count: runs,
+            succeeded,
+            ..HistoryEntry::new(
+                sql.to_string(),
+                None,
+                None,
+                std::time::Duration::from_millis(ms),
+                None,
+            )
+        };
+
+        let workload = WorkloadQuery::from_history(&[
+            entry("SELECT * FROM t WHERE a = 1", 200, 3, true),
+            entry("SELECT *  FROM t\nWHERE a = 1", 100, 1, true),
+            entry("SELECT * FROM t WHERE b = 1", 900, 1, false),
+        ]);
+
+        assert_eq!(workload.len(), 1);
+        assert_eq!(workload[0].calls, 4);
+        assert_eq!(workload[0].total_time_ms, 700.0);
+        assert_eq!(workload[0].mean_time_ms(), 175.0);
+    }
+}
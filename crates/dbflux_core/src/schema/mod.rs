@@ -2,20 +2,34 @@ pub(crate) mod builder;
 pub mod dependents;
 pub mod drift_check;
 pub mod fingerprint;
+pub mod index_advisor;
 pub mod node_id;
+pub mod parallel;
 pub mod query_parser;
 pub mod schema_drift;
+pub mod snapshot_diff;
+pub mod system_objects;
 pub(crate) mod types;
 
 pub use builder::{ForeignKeyBuilder, IndexBuilder, SchemaForeignKeyBuilder, SchemaIndexBuilder};
 pub use dependents::{RelationKind, RelationRef};
 pub use drift_check::{DriftOutcome, check_drift_sync, check_schema_drift};
 pub use fingerprint::SchemaFingerprint;
+pub use index_advisor::{
+    ColumnPredicate, IndexSuggestion, PredicateKind, WorkloadQuery, extract_column_predicates,
+    suggest_indexes,
+};
 pub use node_id::{ParseSchemaNodeIdError, SchemaNodeId, SchemaNodeKind};
+pub use parallel::{
+    MAX_PARALLEL_INTROSPECTION, PREFETCH_DATABASE_LIMIT, databases_to_prefetch, map_bounded,
+    prefetch_database_schemas,
+};
 pub use query_parser::{QueryTableRef, extract_referenced_tables};
 pub use schema_drift::{
     ColumnDiff, ColumnSnapshot, SchemaChange, SchemaDiff, SchemaDriftDetected, diff_table_info,
 };
+pub use snapshot_diff::{SchemaObjectRef, SnapshotDiff, diff_snapshots};
+pub use system_objects::{is_internal_object, is_system_schema};
 pub use types::{
     CollectionChildInfo, CollectionChildrenCache, CollectionChildrenPage,
     CollectionChildrenRequest, CollectionIndexInfo, CollectionInfo, CollectionPresentation,
@@ -25,7 +39,7 @@ pub use types::{
     KeySpaceInfo, KeyValueSchema, MeasurementInfo, MultiModelCapabilities, MultiModelSchema,
     NodeLabelInfo, PropertyInfo, RelationalSchema, RelationshipTypeInfo, RetentionPolicyInfo,
     RoutineInfo, RoutineKind, SchemaForeignKeyInfo, SchemaIndexInfo, SchemaSnapshot,
-    SearchIndexInfo, SearchMappingInfo, SearchSchema, TableInfo, TimeSeriesFieldInfo,
-    TimeSeriesSchema, VectorCollectionInfo, VectorMetadataField, VectorMetric, VectorSchema,
-    ViewInfo, WideColumnInfo, WideColumnKeyspaceInfo, WideColumnSchema,
+    SearchIndexInfo, SearchMappingInfo, SearchSchema, TableInfo, TableStatistics,
+    TimeSeriesFieldInfo, TimeSeriesSchema, VectorCollectionInfo, VectorMetadataField, VectorMetric,
+    VectorSchema, ViewInfo, WideColumnInfo, WideColumnKeyspaceInfo, WideColumnSchema,
 };
@@ -144,6 +144,17 @@ pub enum SchemaNodeId {
         profile_id: Uuid,
         chart_id: Uuid,
     },
+    /// Root folder for the starred objects of a connection profile. Shown
+    /// above everything else while the profile has favorites.
+    FavoritesFolder {
+        profile_id: Uuid,
+    },
+    /// A single starred table, view, or saved query, keyed by the id of its
+    /// `FavoriteObject` in the connection tree.
+    FavoriteItem {
+        profile_id: Uuid,
+        favorite_id: Uuid,
+    },
 
     // Object variants
     Table {
@@ -368,6 +379,8 @@ pub enum SchemaNodeKind {
     RemoteDashboardItem,
     SavedChartsFolder,
     SavedChartItem,
+    FavoritesFolder,
+    FavoriteItem,
     Table,
     View,
     Collection,
@@ -435,6 +448,8 @@ impl SchemaNodeId {
             Self::RemoteDashboardItem { .. } => SchemaNodeKind::RemoteDashboardItem,
             Self::SavedChartsFolder { .. } => SchemaNodeKind::SavedChartsFolder,
             Self::SavedChartItem { .. } => SchemaNodeKind::SavedChartItem,
+            Self::FavoritesFolder { .. } => SchemaNodeKind::FavoritesFolder,
+            Self::FavoriteItem { .. } => SchemaNodeKind::FavoriteItem,
             Self::Table { .. } => SchemaNodeKind::Table,
             Self::View { .. } => SchemaNodeKind::View,
             Self::Collection { .. } => SchemaNodeKind::Collection,
@@ -529,6 +544,8 @@ impl SchemaNodeId {
             | Self::RemoteDashboardItem { profile_id, .. }
             | Self::SavedChartsFolder { profile_id, .. }
             | Self::SavedChartItem { profile_id, .. }
+            | Self::FavoritesFolder { profile_id, .. }
+            | Self::FavoriteItem { profile_id, .. }
             | Self::InstanceMetricsFolder { profile_id, .. }
             | Self::InstanceMetricLeaf { profile_id, .. }
             | Self::InstanceInspectorsFolder { profile_id, .. }
@@ -604,6 +621,8 @@ const P_REMOTE_DASHBOARDS_FOLDER: &str = "RDBF";
 const P_REMOTE_DASHBOARD_ITEM: &str = "RDBI";
 const P_SAVED_CHARTS_FOLDER: &str = "SCRF";
 const P_SAVED_CHART_ITEM: &str = "SCRI";
+const P_FAVORITES_FOLDER: &str = "FAVF";
+const P_FAVORITE_ITEM: &str = "FAVI";
 
 impl fmt::Display for SchemaNodeId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1029,6 +1048,15 @@ impl fmt::Display for SchemaNodeId {
             } => {
                 write!(f, "{}|{}|{}", P_SAVED_CHART_ITEM, profile_id, chart_id)
             }
+            Self::FavoritesFolder { profile_id } => {
+                write!(f, "{}|{}", P_FAVORITES_FOLDER, profile_id)
+            }
+            Self::FavoriteItem {
+                profile_id,
+                favorite_id,
+            } => {
+                write!(f, "{}|{}|{}", P_FAVORITE_ITEM, profile_id, favorite_id)
+            }
             Self::InstanceMetricsFolder { profile_id } => {
                 write!(f, "{}|{}", P_INST_METRICS_FOLDER, profile_id)
             }
@@ -1688,6 +1716,23 @@ impl FromStr for SchemaNodeId {
                 })
             }
 
+            P_FAVORITES_FOLDER => {
+                let profile_id =
+                    Uuid::parse_str(parts.get(1).ok_or_else(err)?).map_err(|_| err())?;
+                Ok(Self::FavoritesFolder { profile_id })
+            }
+
+            P_FAVORITE_ITEM => {
+                let profile_id =
+                    Uuid::parse_str(parts.get(1).ok_or_else(err)?).map_err(|_| err())?;
+                let favorite_id =
+                    Uuid::parse_str(parts.get(2).ok_or_else(err)?).map_err(|_| err())?;
+                Ok(Self::FavoriteItem {
+                    profile_id,
+                    favorite_id,
+                })
+            }
+
             P_INST_METRICS_FOLDER => {
                 let profile_id =
                     Uuid::parse_str(parts.get(1).ok_or_else(err)?).map_err(|_| err())?;
@@ -1771,6 +1816,8 @@ impl SchemaNodeKind {
                 | Self::RemoteDashboardItem
                 | Self::SavedChartsFolder
                 | Self::SavedChartItem
+                | Self::FavoritesFolder
+                | Self::FavoriteItem
                 | Self::InstanceMetricsFolder
                 | Self::InstanceMetricLeaf
                 | Self::InstanceInspectorsFolder
@@ -1806,6 +1853,7 @@ impl SchemaNodeKind {
                 | Self::DashboardsFolder
                 | Self::RemoteDashboardsFolder
                 | Self::SavedChartsFolder
+                | Self::FavoritesFolder
                 | Self::InstanceMetricsFolder
                 | Self::InstanceInspectorsFolder
         )
@@ -1825,6 +1873,7 @@ impl SchemaNodeKind {
                 | Self::DashboardItem
                 | Self::RemoteDashboardItem
                 | Self::SavedChartItem
+                | Self::FavoriteItem
                 | Self::InstanceMetricLeaf
                 | Self::InstanceInspectorLeaf
                 | Self::InstanceOverviewLeaf
@@ -2336,6 +2385,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn favorites_nodes_round_trip_via_display_and_from_str() {
+        let profile_id = Uuid::parse_str("12345678-1234-1234-1234-123456789abc").unwrap();
+        let favorite_id = Uuid::parse_str("aaaaaaaa-bbbb-cccc-dddd-eeeeeeeeeeee").unwrap();
+
+        roundtrip(SchemaNodeId::FavoritesFolder { profile_id });
+        roundtrip(SchemaNodeId::FavoriteItem {
+            profile_id,
+            favorite_id,
+        });
+
+        assert!(SchemaNodeKind::FavoritesFolder.is_expandable_folder());
+        assert!(SchemaNodeKind::FavoriteItem.shows_pointer_cursor());
+        assert!(SchemaNodeKind::FavoriteItem.needs_click_handler());
+    }
+
     #[test]
     fn instance_metrics_nodes_round_trip_via_display_and_from_str() {
         let uuid = Uuid::parse_str("12345678-1234-1234-1234-123456789abc").unwrap();
@@ -0,0 +1,174 @@
+//! Bounded parallel schema introspection.
+//!
+//! Connect-time introspection used to walk databases one after another, which
+//! dominated first-connect time on servers with many of them. These helpers
+//! spread the requests over a few worker threads, so a large server is not
+//! hit with one request per database at once, and keep results in input order.
+
+use std::sync::{Mutex, PoisonError};
+
+use crate::{Connection, DbSchemaInfo, SchemaLoadingStrategy, SchemaSnapshot, is_system_schema};
+
+/// Most introspection requests issued against one server at a time.
+pub const MAX_PARALLEL_INTROSPECTION: usize = 4;
+
+/// Databases whose tables are listed on connect for `LazyPerDatabase`
+/// drivers. Any others still load when first expanded.
+pub const PREFETCH_DATABASE_LIMIT: usize = 8;
+
+/// Applies `f` to every item on at most `max_parallel` threads and returns
+/// the results in the order of `items`.
+pub fn map_bounded<T, R, F>(items: impl IntoIterator<Item = T>, max_parallel: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let items: Vec<T> = items.into_iter().collect();
+    let workers = max_parallel.min(items.len());
+
+    if workers <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let total = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results: Mutex<Vec<Option<R>>> =
+        Mutex::new(std::iter::repeat_with(|| None).take(total).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let next = queue.lock().unwrap_or_else(PoisonError::into_inner).next();
+                    let Some((index, item)) = next else {
+                        break;
+                    };
+
+                    let result = f(item);
+                    results.lock().unwrap_or_else(PoisonError::into_inner)[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner)
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// The databases of `snapshot` to list on connect: the current one first,
+/// then user databases in listing order, at most `limit` in total.
+pub fn databases_to_prefetch(snapshot: &SchemaSnapshot, limit: usize) -> Vec<String> {
+    let databases = snapshot.databases();
+
+    let current = databases.iter().filter(|db| db.is_current);
+    let others = databases
+        .iter()
+        .filter(|db| !db.is_current && !is_system_schema(&db.name));
+
+    current
+        .chain(others)
+        .take(limit)
+        .map(|db| db.name.clone())
+        .collect()
+}
+
+/// Lists the tables and views of the first databases of `snapshot` for
+/// relational `LazyPerDatabase` connections, so expanding them right after
+/// connecting does not wait on a round-trip. Other connections already carry
+/// their objects in `snapshot` and get nothing. Failures are logged and
+/// leave the databases to load lazily.
+pub fn prefetch_database_schemas(
+    connection: &dyn Connection,
+    snapshot: &SchemaSnapshot,
+) -> Vec<DbSchemaInfo> {
+    if connection.schema_loading_strategy() != SchemaLoadingStrategy::LazyPerDatabase
+        || !snapshot.is_relational()
+    {
+        return Vec::new();
+    }
+
+    let databases = databases_to_prefetch(snapshot, PREFETCH_DATABASE_LIMIT);
+    if databases.is_empty() {
+        return Vec::new();
+    }
+
+    match connection.schemas_for_databases(&databases) {
+        Ok(schemas) => schemas,
+        Err(e) => {
+            log::warn!("Failed to prefetch database schemas: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DatabaseInfo, RelationalSchema};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn map_bounded_keeps_input_order() {
+        let doubled = map_bounded(0..20u64, 4, |n| {
+            // Later items finish first, so completion order differs from input order.
+            std::thread::sleep(Duration::from_millis(20 - n));
+            n * 2
+        });
+
+        assert_eq!(doubled, (0..20).map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn map_bounded_never_exceeds_the_limit() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        map_bounded(0..12, 3, |_| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(10));
+            running.fetch_sub(1, Ordering::SeqCst);
+        });
+
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn map_bounded_handles_empty_and_serial_input() {
+        assert!(map_bounded(Vec::<u8>::new(), 4, |n| n).is_empty());
+        assert_eq!(map_bounded(vec![1, 2, 3], 1, |n| n + 1), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn prefetch_puts_the_current_database_first_and_skips_system_ones() {
+        let database = |name: &str, is_current: bool| DatabaseInfo {
+            name: name.to_string(),
+            is_current,
+        };
+        let snapshot = SchemaSnapshot::relational(RelationalSchema {
+            databases: vec![
+                database("analytics", false),
+                database("information_schema", false),
+                database("mysql", false),
+                database("shop", true),
+                database("staging", false),
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            databases_to_prefetch(&snapshot, 8),
+            vec!["shop", "analytics", "staging"]
+        );
+        assert_eq!(
+            databases_to_prefetch(&snapshot, 2),
+            vec!["shop", "analytics"]
+        );
+    }
+}
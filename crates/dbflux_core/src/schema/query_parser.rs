@@ -30,7 +30,7 @@ pub fn extract_referenced_tables(query: &str) -> Vec<QueryTableRef> {
 }
 
 /// Strip SQL line comments (`--`) and block comments (`/* */`).
-fn strip_comments(input: &str) -> String {
+pub(super) fn strip_comments(input: &str) -> String {
     let bytes = input.as_bytes();
     let len = bytes.len();
     let mut out = String::with_capacity(len);
@@ -59,7 +59,7 @@ fn strip_comments(input: &str) -> String {
 
 /// A minimal SQL token for table-reference extraction.
 #[derive(Debug, Clone, PartialEq)]
-enum Token {
+pub(super) enum Token {
     /// A bare or quoted identifier.
     Ident(String),
     /// A dot separator between qualifier parts.
@@ -68,13 +68,17 @@ enum Token {
     LParen,
     /// A closing parenthesis — decrements subquery depth.
     RParen,
+    /// A comparison character (`=`, `<`, `>`, `!`) or a list comma.
+    Symbol(char),
+    /// A single-quoted string literal.
+    Literal,
     /// Other punctuation / whitespace that we do not need.
     Other,
 }
 
 /// Tokenize a comment-stripped SQL string into the minimal token stream needed
 /// for table-reference extraction.
-fn tokenize(sql: &str) -> Vec<Token> {
+pub(super) fn tokenize(sql: &str) -> Vec<Token> {
     let chars: Vec<char> = sql.chars().collect();
     let len = chars.len();
     let mut tokens = Vec::new();
@@ -155,7 +159,12 @@ fn tokenize(sql: &str) -> Vec<Token> {
                         i += 1;
                     }
                 }
-                tokens.push(Token::Other);
+                tokens.push(Token::Literal);
+            }
+
+            c @ ('=' | '<' | '>' | '!' | ',') => {
+                tokens.push(Token::Symbol(c));
+                i += 1;
             }
 
             c if c.is_alphanumeric() || c == '_' || c == '$' => {
@@ -199,7 +208,7 @@ fn parse_table_refs(tokens: &[Token]) -> Vec<QueryTableRef> {
                 i += 1;
             }
 
-            Token::Other | Token::Dot => {
+            Token::Other | Token::Dot | Token::Symbol(_) | Token::Literal => {
                 i += 1;
             }
 
@@ -273,6 +282,15 @@ fn parse_table_refs(tokens: &[Token]) -> Vec<QueryTableRef> {
 /// Advances `*pos` past the name (and an optional alias keyword).
 /// Returns `None` if the next token is not an identifier.
 fn read_qualified_name(tokens: &[Token], pos: &mut usize) -> Option<QueryTableRef> {
+    read_table_binding(tokens, pos).map(|(table_ref, _)| table_ref)
+}
+
+/// Like [`read_qualified_name`], but also returns the alias the table is
+/// given, if any.
+pub(super) fn read_table_binding(
+    tokens: &[Token],
+    pos: &mut usize,
+) -> Option<(QueryTableRef, Option<String>)> {
     // Skip any Other tokens (whitespace collapsed into Token::Other).
     while *pos < tokens.len() && tokens[*pos] == Token::Other {
         *pos += 1;
@@ -297,11 +315,12 @@ fn read_qualified_name(tokens: &[Token], pos: &mut usize) -> Option<QueryTableRe
             }
             _ => {
                 // Trailing dot without an identifier; treat first as table name.
-                return Some(QueryTableRef {
+                let table_ref = QueryTableRef {
                     database: None,
                     schema: None,
                     table: first,
-                });
+                };
+                return Some((table_ref, None));
             }
         };
 
@@ -314,65 +333,81 @@ fn read_qualified_name(tokens: &[Token], pos: &mut usize) -> Option<QueryTableRe
                     s.clone()
                 }
                 _ => {
-                    return Some(QueryTableRef {
+                    let table_ref = QueryTableRef {
                         database: None,
                         schema: Some(first),
                         table: second,
-                    });
+                    };
+                    return Some((table_ref, None));
                 }
             };
-            skip_alias(tokens, pos);
-            return Some(QueryTableRef {
+            let alias = read_alias(tokens, pos);
+            let table_ref = QueryTableRef {
                 database: Some(first),
                 schema: Some(second),
                 table: third,
-            });
+            };
+            return Some((table_ref, alias));
         }
 
-        skip_alias(tokens, pos);
-        return Some(QueryTableRef {
+        let alias = read_alias(tokens, pos);
+        let table_ref = QueryTableRef {
             database: None,
             schema: Some(first),
             table: second,
-        });
+        };
+        return Some((table_ref, alias));
     }
 
-    skip_alias(tokens, pos);
-    Some(QueryTableRef {
+    let alias = read_alias(tokens, pos);
+    let table_ref = QueryTableRef {
         database: None,
         schema: None,
         table: first,
-    })
+    };
+    Some((table_ref, alias))
 }
 
-/// Skip a potential alias token after a table name.
+/// Read a potential alias after a table name, with or without `AS`.
 ///
 /// An alias is a bare identifier that is NOT a SQL keyword. This advances
 /// `*pos` past it so subsequent keyword scans start at the right position.
-fn skip_alias(tokens: &[Token], pos: &mut usize) {
-    // Skip whitespace tokens.
+fn read_alias(tokens: &[Token], pos: &mut usize) -> Option<String> {
     let start = *pos;
-    while *pos < tokens.len() && tokens[*pos] == Token::Other {
+    let next_ident = |pos: &mut usize| {
+        // Skip whitespace tokens.
+        while *pos < tokens.len() && tokens[*pos] == Token::Other {
+            *pos += 1;
+        }
+        match tokens.get(*pos) {
+            Some(Token::Ident(word)) => Some(word.clone()),
+            _ => None,
+        }
+    };
+
+    let mut word = next_ident(pos);
+    if word
+        .as_deref()
+        .is_some_and(|w| w.eq_ignore_ascii_case("AS"))
+    {
         *pos += 1;
+        word = next_ident(pos);
     }
 
-    match tokens.get(*pos) {
-        Some(Token::Ident(word)) => {
-            let upper = word.to_uppercase();
-            // If the next token is a reserved keyword, don't consume it.
-            if is_reserved_keyword(&upper) {
-                *pos = start;
-            } else {
-                *pos += 1;
-            }
+    match word {
+        // If the next token is a reserved keyword, don't consume it.
+        Some(word) if !is_reserved_keyword(&word.to_uppercase()) => {
+            *pos += 1;
+            Some(word)
         }
         _ => {
             *pos = start;
+            None
         }
     }
 }
 
-fn is_reserved_keyword(word: &str) -> bool {
+pub(super) fn is_reserved_keyword(word: &str) -> bool {
     matches!(
         word,
         "WHERE"
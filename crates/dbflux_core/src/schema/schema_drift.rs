@@ -177,6 +177,7 @@ mod tests {
             sample_fields: None,
             presentation: Default::default(),
             child_items: None,
+            comment: None,
         }
     }
 
@@ -188,6 +189,8 @@ mod tests {
             is_primary_key: is_pk,
             default_value: None,
             enum_values: None,
+            comment: None,
+            definition: None,
         }
     }
 
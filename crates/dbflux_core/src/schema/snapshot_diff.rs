@@ -0,0 +1,250 @@
+//! Object-level diff between two schema snapshots of the same connection.
+//!
+//! Used by the periodic schema refresh to update only the sidebar nodes whose
+//! objects changed, instead of rebuilding the whole tree. A table only counts
+//! as altered when both snapshots carry its columns; most drivers load them
+//! lazily, so their details are compared with
+//! [`check_drift_sync`](super::check_drift_sync) instead.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{SchemaFingerprint, SchemaSnapshot, TableInfo, ViewInfo};
+
+/// A table or view, addressed the way the sidebar addresses it: by the schema
+/// it is listed under and its name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SchemaObjectRef {
+    pub schema: String,
+    pub name: String,
+}
+
+impl SchemaObjectRef {
+    pub fn new(schema: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            schema: schema.into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// What changed between two snapshots. Every list is sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    pub schemas_added: Vec<String>,
+    pub schemas_removed: Vec<String>,
+    pub objects_added: Vec<SchemaObjectRef>,
+    pub objects_removed: Vec<SchemaObjectRef>,
+    /// Tables in both snapshots whose columns or foreign keys differ.
+    pub objects_altered: Vec<SchemaObjectRef>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.schemas_added.is_empty()
+            && self.schemas_removed.is_empty()
+            && self.objects_added.is_empty()
+            && self.objects_removed.is_empty()
+            && self.objects_altered.is_empty()
+    }
+}
+
+/// Schemas, tables and views that exist in `after` but not in `before`, the
+/// other way around, and tables whose loaded structure differs. Only
+/// relational snapshots list such objects; any other paradigm diffs as empty.
+pub fn diff_snapshots(before: &SchemaSnapshot, after: &SchemaSnapshot) -> SnapshotDiff {
+    let before = SnapshotObjects::collect(before);
+    let after = SnapshotObjects::collect(after);
+
+    let objects_altered = after
+        .tables
+        .iter()
+        .filter(|(object, table)| {
+            before
+                .tables
+                .get(*object)
+                .is_some_and(|previous| is_altered(previous, table))
+        })
+        .map(|(object, _)| object.clone())
+        .collect();
+
+    SnapshotDiff {
+        schemas_added: after.schemas.difference(&before.schemas).cloned().collect(),
+        schemas_removed: before.schemas.difference(&after.schemas).cloned().collect(),
+        objects_added: after.objects.difference(&before.objects).cloned().collect(),
+        objects_removed: before.objects.difference(&after.objects).cloned().collect(),
+        objects_altered,
+    }
+}
+
+fn is_altered(before: &TableInfo, after: &TableInfo) -> bool {
+    before.columns.is_some()
+        && after.columns.is_some()
+        && SchemaFingerprint::from_table_info(before) != SchemaFingerprint::from_table_info(after)
+}
+
+#[derive(Default)]
+struct SnapshotObjects<'a> {
+    schemas: BTreeSet<String>,
+    objects: BTreeSet<SchemaObjectRef>,
+    tables: BTreeMap<SchemaObjectRef, &'a TableInfo>,
+}
+
+impl<'a> SnapshotObjects<'a> {
+    fn collect(snapshot: &'a SchemaSnapshot) -> Self {
+        let mut collected = Self::default();
+
+        for db_schema in snapshot.schemas() {
+            collected.schemas.insert(db_schema.name.clone());
+            collected.add(&db_schema.name, &db_schema.tables, &db_schema.views);
+        }
+        collected.add("", snapshot.tables(), snapshot.views());
+
+        collected
+    }
+
+    /// Adds `tables` and `views` listed under `listing_schema`; an object's own
+    /// schema wins over the one it is listed under.
+    fn add(&mut self, listing_schema: &str, tables: &'a [TableInfo], views: &[ViewInfo]) {
+        for table in tables {
+            let object = SchemaObjectRef::new(
+                table.schema.as_deref().unwrap_or(listing_schema),
+                table.name.clone(),
+            );
+            self.tables.insert(object.clone(), table);
+            self.objects.insert(object);
+        }
+
+        for view in views {
+            self.objects.insert(SchemaObjectRef::new(
+                view.schema.as_deref().unwrap_or(listing_schema),
+                view.name.clone(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColumnInfo, DbSchemaInfo, RelationalSchema};
+
+    fn table(name: &str) -> TableInfo {
+        TableInfo {
+            name: name.to_string(),
+            schema: None,
+            columns: None,
+            indexes: None,
+            foreign_keys: None,
+            constraints: None,
+            sample_fields: None,
+            presentation: Default::default(),
+            child_items: None,
+            comment: None,
+        }
+    }
+
+    fn view(name: &str) -> ViewInfo {
+        ViewInfo {
+            name: name.to_string(),
+            schema: None,
+        }
+    }
+
+    fn snapshot(schemas: Vec<(&str, Vec<TableInfo>, Vec<ViewInfo>)>) -> SchemaSnapshot {
+        SchemaSnapshot::relational(RelationalSchema {
+            schemas: schemas
+                .into_iter()
+                .map(|(name, tables, views)| DbSchemaInfo {
+                    name: name.to_string(),
+                    tables,
+                    views,
+                    custom_types: None,
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn identical_snapshots_diff_as_empty() {
+        let before = snapshot(vec![("public", vec![table("users")], vec![view("v")])]);
+        let after = snapshot(vec![("public", vec![table("users")], vec![view("v")])]);
+
+        assert!(diff_snapshots(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_objects_are_keyed_by_schema() {
+        let before = snapshot(vec![
+            ("public", vec![table("users"), table("orders")], vec![]),
+            ("audit", vec![table("log")], vec![]),
+        ]);
+        let after = snapshot(vec![
+            ("public", vec![table("users")], vec![view("active_users")]),
+            ("billing", vec![table("invoices")], vec![]),
+        ]);
+
+        let diff = diff_snapshots(&before, &after);
+
+        assert_eq!(diff.schemas_added, vec!["billing".to_string()]);
+        assert_eq!(diff.schemas_removed, vec!["audit".to_string()]);
+        assert_eq!(
+            diff.objects_added,
+            vec![
+                SchemaObjectRef::new("billing", "invoices"),
+                SchemaObjectRef::new("public", "active_users"),
+            ]
+        );
+        assert_eq!(
+            diff.objects_removed,
+            vec![
+                SchemaObjectRef::new("audit", "log"),
+                SchemaObjectRef::new("public", "orders"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tables_are_altered_only_when_both_sides_carry_columns() {
+        let column = |type_name: &str| ColumnInfo {
+            name: "id".to_string(),
+            type_name: type_name.to_string(),
+            nullable: false,
+            is_primary_key: true,
+            default_value: None,
+            enum_values: None,
+            comment: None,
+            definition: None,
+        };
+        let with_id = |type_name: &str| {
+            let mut users = table("users");
+            users.columns = Some(vec![column(type_name)]);
+            users
+        };
+
+        let before = snapshot(vec![("main", vec![with_id("integer")], vec![])]);
+        let retyped = snapshot(vec![("main", vec![with_id("bigint")], vec![])]);
+        let unloaded = snapshot(vec![("main", vec![table("users")], vec![])]);
+
+        assert_eq!(
+            diff_snapshots(&before, &retyped).objects_altered,
+            vec![SchemaObjectRef::new("main", "users")]
+        );
+        assert!(diff_snapshots(&before, &unloaded).is_empty());
+        assert!(diff_snapshots(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn explicit_table_schema_wins_over_the_listing_schema() {
+        let mut moved = table("users");
+        moved.schema = Some("archive".to_string());
+
+        let before = snapshot(vec![("main", vec![], vec![])]);
+        let after = snapshot(vec![("main", vec![moved], vec![])]);
+
+        assert_eq!(
+            diff_snapshots(&before, &after).objects_added,
+            vec![SchemaObjectRef::new("archive", "users")]
+        );
+    }
+}
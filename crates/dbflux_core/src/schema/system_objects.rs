@@ -0,0 +1,68 @@
+//! Names of the catalogs and bookkeeping objects engines create for
+//! themselves, which the sidebar and quick open can hide.
+
+/// Schemas (or MySQL/SQL Server databases) owned by the engine itself.
+const SYSTEM_SCHEMAS: &[&str] = &[
+    "information_schema",
+    "pg_catalog",
+    "pg_toast",
+    "mysql",
+    "performance_schema",
+    "sys",
+    "master",
+    "model",
+    "msdb",
+    "tempdb",
+];
+
+/// Prefixes of per-session schemas PostgreSQL creates on demand.
+const SYSTEM_SCHEMA_PREFIXES: &[&str] = &["pg_temp_", "pg_toast_temp_"];
+
+/// Whether `name` is a system schema or system database rather than one
+/// holding user objects.
+pub fn is_system_schema(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+
+    SYSTEM_SCHEMAS.contains(&name.as_str())
+        || SYSTEM_SCHEMA_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+/// Whether `name` is an engine-internal table, such as SQLite's
+/// `sqlite_sequence` or `sqlite_stat1`.
+pub fn is_internal_object(name: &str) -> bool {
+    name.to_ascii_lowercase().starts_with("sqlite_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_schemas_are_recognized() {
+        for name in [
+            "pg_catalog",
+            "INFORMATION_SCHEMA",
+            "pg_temp_3",
+            "pg_toast_temp_3",
+            "mysql",
+            "performance_schema",
+            "sys",
+            "tempdb",
+        ] {
+            assert!(is_system_schema(name), "{name}");
+        }
+
+        for name in ["public", "app", "pg_catalogue", "system"] {
+            assert!(!is_system_schema(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn internal_objects_are_recognized() {
+        assert!(is_internal_object("sqlite_sequence"));
+        assert!(is_internal_object("sqlite_stat1"));
+        assert!(!is_internal_object("users"));
+    }
+}
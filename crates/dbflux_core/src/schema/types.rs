@@ -611,6 +611,81 @@ pub struct TableInfo {
     /// Driver-provided child sources that should appear under this container.
     #[serde(default)]
     pub child_items: Option<Vec<CollectionChildInfo>>,
+
+    /// Comment attached to the table (`COMMENT ON TABLE`, MySQL `COMMENT=`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Approximate size of a table, as the engine's catalog reports it. Each
+/// figure is `None` when the driver cannot tell.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableStatistics {
+    /// Estimated row count; catalogs refresh it on `ANALYZE` or similar.
+    pub row_estimate: Option<u64>,
+
+    /// Bytes used by the table's rows, including TOAST or overflow pages.
+    pub data_bytes: Option<u64>,
+
+    /// Bytes used by the table's indexes.
+    pub index_bytes: Option<u64>,
+}
+
+impl TableStatistics {
+    pub fn is_empty(&self) -> bool {
+        self.row_estimate.is_none() && self.data_bytes.is_none() && self.index_bytes.is_none()
+    }
+
+    /// One-line summary such as `~12,400 rows · 3.1 MB data · 512 KB indexes`,
+    /// or `None` when nothing is known.
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.row_estimate
+                .map(|rows| format!("~{} rows", group_thousands(rows))),
+            self.data_bytes
+                .map(|bytes| format!("{} data", format_byte_size(bytes))),
+            self.index_bytes
+                .map(|bytes| format!("{} indexes", format_byte_size(bytes))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+}
+
+pub(crate) fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+pub(crate) fn format_byte_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else if size < 10.0 {
+        format!("{:.1} {}", size, UNITS[unit])
+    } else {
+        format!("{:.0} {}", size, UNITS[unit])
+    }
 }
 
 /// View metadata.
@@ -641,6 +716,16 @@ pub struct ColumnInfo {
     /// MySQL ENUM/SET).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enum_values: Option<Vec<String>>,
+
+    /// Comment attached to the column, if the database supports them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    /// Everything after the name in the column's DDL definition, for engines
+    /// that alter a column by restating it (MySQL `MODIFY COLUMN`). Keeps
+    /// attributes the other fields do not carry, such as `AUTO_INCREMENT`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definition: Option<String>,
 }
 
 /// Relational tables store [`IndexInfo`], document collections store
@@ -1030,6 +1115,38 @@ impl ContainerInfo {
 mod tests {
     use super::*;
 
+    #[test]
+    fn table_statistics_summary_formats_known_figures() {
+        let stats = TableStatistics {
+            row_estimate: Some(12_400),
+            data_bytes: Some(3_250_000),
+            index_bytes: Some(524_288),
+        };
+
+        assert_eq!(
+            stats.summary().as_deref(),
+            Some("~12,400 rows · 3.1 MB data · 512 KB indexes")
+        );
+
+        let rows_only = TableStatistics {
+            row_estimate: Some(999),
+            ..Default::default()
+        };
+        assert_eq!(rows_only.summary().as_deref(), Some("~999 rows"));
+
+        assert!(TableStatistics::default().is_empty());
+        assert_eq!(TableStatistics::default().summary(), None);
+    }
+
+    #[test]
+    fn byte_sizes_scale_through_units() {
+        assert_eq!(format_byte_size(0), "0 B");
+        assert_eq!(format_byte_size(1023), "1023 B");
+        assert_eq!(format_byte_size(1536), "1.5 KB");
+        assert_eq!(format_byte_size(50 * 1024 * 1024), "50 MB");
+        assert_eq!(group_thousands(1_234_567), "1,234,567");
+    }
+
     #[test]
     fn measurements_returns_slice_for_time_series_schema() {
         let schema = SchemaSnapshot::time_series(TimeSeriesSchema {
@@ -1,6 +1,8 @@
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 
+use crate::ColumnInfo;
+
 bitflags! {
     /// DDL operations supported by a driver.
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +32,12 @@ bitflags! {
         const DROP_TABLE = 1 << 13;
         const ALTER_TABLE = 1 << 14;
 
+        // Table and column comments
+        const COMMENTS = 1 << 15;
+
+        // Renaming tables and columns
+        const RENAME = 1 << 16;
+
         // Common combinations
         const INDEXES = Self::CREATE_INDEX.bits() | Self::DROP_INDEX.bits();
         const FOREIGN_KEYS = Self::ADD_FOREIGN_KEY.bits() | Self::DROP_FOREIGN_KEY.bits();
@@ -48,7 +56,9 @@ bitflags! {
         const POSTGRES_FULL = Self::SQL_FULL.bits()
             | Self::CREATE_TYPE.bits()
             | Self::DROP_TYPE.bits()
-            | Self::ALTER_TYPE.bits();
+            | Self::ALTER_TYPE.bits()
+            | Self::COMMENTS.bits()
+            | Self::RENAME.bits();
     }
 }
 
@@ -156,6 +166,26 @@ pub struct AddEnumValueRequest<'a> {
     pub new_value: &'a str,
 }
 
+#[derive(Debug, Clone)]
+pub struct SetCommentRequest<'a> {
+    pub table_name: &'a str,
+    pub schema_name: Option<&'a str>,
+    /// Column to comment on, or `None` for the table itself. Engines that
+    /// restate the column definition (MySQL) read its type and default here.
+    pub column: Option<&'a ColumnInfo>,
+    /// New comment; `None` removes the current one.
+    pub comment: Option<&'a str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenameRequest<'a> {
+    pub table_name: &'a str,
+    pub schema_name: Option<&'a str>,
+    /// Column to rename, or `None` to rename the table itself.
+    pub column: Option<&'a str>,
+    pub new_name: &'a str,
+}
+
 // =============================================================================
 // CodeGenerator Trait
 // =============================================================================
@@ -211,6 +241,22 @@ pub trait CodeGenerator: Send + Sync {
     fn generate_add_enum_value(&self, _request: &AddEnumValueRequest) -> Option<String> {
         None
     }
+
+    // =========================================================================
+    // Comment Operations
+    // =========================================================================
+
+    fn generate_set_comment(&self, _request: &SetCommentRequest) -> Option<String> {
+        None
+    }
+
+    // =========================================================================
+    // Rename Operations
+    // =========================================================================
+
+    fn generate_rename(&self, _request: &RenameRequest) -> Option<String> {
+        None
+    }
 }
 
 /// Code generator that returns `None` for all operations.
@@ -1,4 +1,6 @@
+use super::temp_table::TempColumnType;
 use crate::Value;
+use crate::core::is_plain_decimal;
 use serde::{Deserialize, Serialize};
 
 /// Placeholder style for parameterized queries.
@@ -103,6 +105,35 @@ pub trait SqlDialect: Send + Sync {
         format!("LIMIT {}", n)
     }
 
+    /// Clause appended to a SELECT to return `limit` rows after skipping
+    /// `offset`, used to page through a result incrementally.
+    fn page_clause(&self, limit: u32, offset: u64) -> String {
+        format!("LIMIT {} OFFSET {}", limit, offset)
+    }
+
+    /// Predicate matching rows whose text `column` contains `text`, used by
+    /// the cross-table data search. `%`, `_` and `!` in `text` match
+    /// literally.
+    ///
+    /// Case-insensitive matching lowercases both sides; dialects with an
+    /// `ILIKE` operator use it instead. Case-sensitive matching is a plain
+    /// `LIKE`, so it follows the collation where the engine's `LIKE` ignores
+    /// case.
+    fn text_contains_expr(&self, column: &str, text: &str, case_sensitive: bool) -> String {
+        let pattern = self.value_to_literal(&Value::Text(crate::like_contains_pattern(text)));
+        if case_sensitive {
+            format!("{column} LIKE {pattern} ESCAPE '!'")
+        } else {
+            format!("LOWER({column}) LIKE LOWER({pattern}) ESCAPE '!'")
+        }
+    }
+
+    /// Predicate matching rows whose text `column` matches the regular
+    /// expression `pattern`, or `None` when the engine has no regex operator.
+    fn regex_match_expr(&self, _column: &str, _pattern: &str) -> Option<String> {
+        None
+    }
+
     /// Whether this dialect requires HAVING clauses to repeat the full aggregate
     /// expression rather than referencing the column alias.
     ///
@@ -124,6 +155,33 @@ pub trait SqlDialect: Send + Sync {
     ) -> Option<String> {
         None
     }
+
+    /// Unquoted name under which a registered result's temporary table is
+    /// created and referenced. SQL Server prefixes session temp tables with
+    /// `#`; every other dialect uses the name as given.
+    fn temp_table_identifier(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    /// Leading keywords of the `CREATE` statement for a session temp table.
+    fn create_temp_table_prefix(&self) -> &'static str {
+        "CREATE TEMPORARY TABLE"
+    }
+
+    /// Statement that drops the temp table `identifier` if it exists, used to
+    /// replace an earlier registration under the same name.
+    ///
+    /// Must never be able to drop a permanent table of the same name, so the
+    /// default returns `None` (re-registering then fails with the engine's
+    /// "already exists" error).
+    fn drop_temp_table_statement(&self, _identifier: &str) -> Option<String> {
+        None
+    }
+
+    /// Concrete column type for a materialized result column.
+    fn temp_column_type(&self, ty: TempColumnType) -> &'static str {
+        ty.ansi_name()
+    }
 }
 
 /// Default SQL dialect using ANSI SQL conventions (double-quote identifiers).
@@ -170,10 +228,16 @@ impl SqlDialect for DefaultSqlDialect {
                 format!("X'{}'", hex)
             }
             Value::Json(s) => format!("'{}'", self.escape_string(s)),
-            Value::Decimal(s) => s.clone(),
+            // Non-finite and exponent forms go in as text, like `Float`'s.
+            Value::Decimal(s) if is_plain_decimal(s) => s.trim().to_string(),
+            Value::Decimal(s) => format!("'{}'", self.escape_string(s)),
             Value::DateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
+            Value::NaiveDateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
             Value::Date(d) => format!("'{}'", d.format("%Y-%m-%d")),
             Value::Time(t) => format!("'{}'", t.format("%H:%M:%S%.f")),
+            Value::Uuid(uuid) => format!("'{}'", uuid),
+            Value::Interval(interval) => format!("'{}'", interval),
+            Value::Enum(label) => format!("'{}'", self.escape_string(label)),
             Value::Array(arr) => {
                 let items: Vec<String> = arr.iter().map(|v| self.value_to_literal(v)).collect();
                 format!("ARRAY[{}]", items.join(", "))
@@ -293,6 +357,18 @@ mod tests {
         assert_eq!(dialect.normalize_identifier("email"), "email");
     }
 
+    #[test]
+    fn decimals_are_inlined_only_when_plain() {
+        let dialect = DefaultSqlDialect;
+        let literal = |s: &str| dialect.value_to_literal(&Value::Decimal(s.to_string()));
+
+        assert_eq!(literal("-10.50"), "-10.50");
+        assert_eq!(literal("NaN"), "'NaN'");
+        assert_eq!(literal("-Infinity"), "'-Infinity'");
+        assert_eq!(literal("1e3"), "'1e3'");
+        assert_eq!(literal("1; DROP TABLE t"), "'1; DROP TABLE t'");
+    }
+
     // F-R3-1: limit_clause default returns LIMIT n (used by Postgres, MySQL, SQLite)
     #[test]
     fn postgres_limit_clause_uses_limit() {
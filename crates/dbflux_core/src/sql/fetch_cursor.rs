@@ -0,0 +1,274 @@
+//! Incremental fetching of a `SELECT`'s rows in fixed-size pages.
+//!
+//! Exploratory queries on large tables only need the first rows to be useful.
+//! A [`FetchCursor`] appends the dialect's [`SqlDialect::page_clause`] to a
+//! single read statement, so the first page returns quickly and later pages
+//! are fetched on demand.
+//!
+//! Each page re-runs the statement with a larger offset (the LIMIT/OFFSET
+//! fallback), which needs no driver support beyond its dialect. Offsets only
+//! line up across runs when the rows come back in a fixed order, so only
+//! statements with a top-level `ORDER BY` are paged; without one, a scan may
+//! return rows in a different order each time and pages would repeat or skip
+//! rows. The statement itself is left untouched, so its column names and
+//! `ORDER BY` apply exactly as written. Drivers advertise that their SQL accepts the
+//! clause with [`DriverCapabilities::INCREMENTAL_FETCH`](crate::DriverCapabilities).
+
+use super::dialect::SqlDialect;
+use crate::Row;
+use crate::query::safety::{is_safe_read_query, strip_comments};
+
+/// Rows fetched per page.
+pub const FETCH_PAGE_ROWS: u32 = 10_000;
+
+/// Words that make a statement unsafe to page: row locking, `SELECT ... INTO`
+/// and data-modifying CTEs. `FOR` is matched on its own because its locking
+/// forms vary by engine (`FOR UPDATE`, `FOR SHARE`, `FOR NO KEY UPDATE`).
+const UNPAGEABLE_WORDS: &[&str] = &["INTO", "INSERT", "UPDATE", "DELETE", "MERGE", "FOR"];
+
+/// Top-level clauses a page clause cannot follow: the statement already
+/// limits its rows, or ends in a MySQL `LOCK IN SHARE MODE`.
+const TRAILING_CLAUSE_WORDS: &[&str] = &["LIMIT", "OFFSET", "FETCH", "LOCK"];
+
+/// Paging state of one statement's result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchCursor {
+    query: String,
+    page_rows: u32,
+    loaded_rows: u64,
+    has_more: bool,
+}
+
+impl FetchCursor {
+    /// A cursor over `sql`, or `None` when it is not a single plain `SELECT`
+    /// (or `WITH ... SELECT`) with a top-level `ORDER BY` that a page clause
+    /// can be appended to.
+    pub fn for_query(sql: &str, page_rows: u32) -> Option<Self> {
+        if page_rows == 0 || !is_safe_read_query(sql) {
+            return None;
+        }
+
+        let stripped = strip_comments(sql).to_ascii_uppercase();
+        let mut words = stripped
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .filter(|word| !word.is_empty());
+
+        if !matches!(words.next(), Some("SELECT" | "WITH"))
+            || words.any(|word| UNPAGEABLE_WORDS.contains(&word))
+            || top_level_words(&stripped).any(|word| TRAILING_CLAUSE_WORDS.contains(&word))
+            || !has_top_level_order_by(&stripped)
+        {
+            return None;
+        }
+
+        let query = sql.trim().trim_end_matches(';').trim_end();
+
+        // A terminator hidden behind a trailing comment would come before
+        // the page clause.
+        if strip_comments(query).trim_end().ends_with(';') {
+            return None;
+        }
+
+        Some(Self {
+            query: query.to_string(),
+            page_rows,
+            loaded_rows: 0,
+            has_more: true,
+        })
+    }
+
+    /// Statement that fetches the next page. It asks for one row more than
+    /// the page size so [`advance`](Self::advance) can tell whether another
+    /// page follows. The clause goes on its own line so a trailing `--`
+    /// comment cannot swallow it.
+    pub fn next_page_sql(&self, dialect: &dyn SqlDialect) -> String {
+        format!(
+            "{}\n{}",
+            self.query,
+            dialect.page_clause(self.page_rows + 1, self.loaded_rows)
+        )
+    }
+
+    /// Records a fetched page, trimming the look-ahead row from `rows`.
+    pub fn advance(&mut self, rows: &mut Vec<Row>) {
+        self.has_more = rows.len() > self.page_rows as usize;
+        rows.truncate(self.page_rows as usize);
+        self.loaded_rows += rows.len() as u64;
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn page_rows(&self) -> u32 {
+        self.page_rows
+    }
+
+    pub fn loaded_rows(&self) -> u64 {
+        self.loaded_rows
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// Whether `sql` (upper-cased) orders its final result, as opposed to only
+/// ordering inside a subquery or a window.
+fn has_top_level_order_by(sql: &str) -> bool {
+    let mut words = top_level_words(sql).peekable();
+
+    while let Some(word) = words.next() {
+        if word == "ORDER" && words.peek() == Some(&"BY") {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Words of `sql` outside parentheses and quoted text.
+fn top_level_words(sql: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    let mut quote = None;
+
+    sql.split(move |c: char| {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        quote.is_some() || depth > 0 || !(c.is_ascii_alphanumeric() || c == '_')
+    })
+    .filter(|word| !word.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DefaultSqlDialect, Value};
+
+    #[test]
+    fn only_plain_selects_get_a_cursor() {
+        assert!(FetchCursor::for_query("SELECT * FROM t ORDER BY id", 10).is_some());
+        assert!(
+            FetchCursor::for_query(
+                "-- recent\nWITH r AS (SELECT 1 AS a) SELECT * FROM r ORDER BY a;",
+                10
+            )
+            .is_some()
+        );
+
+        assert!(FetchCursor::for_query("SHOW TABLES", 10).is_none());
+        assert!(FetchCursor::for_query("SELECT 1; SELECT 2 ORDER BY 1", 10).is_none());
+        assert!(FetchCursor::for_query("SELECT * FROM t ORDER BY id FOR UPDATE", 10).is_none());
+        assert!(FetchCursor::for_query("SELECT * INTO copy FROM t ORDER BY id", 10).is_none());
+        assert!(
+            FetchCursor::for_query(
+                "WITH d AS (DELETE FROM t RETURNING *) SELECT * FROM d ORDER BY id",
+                10
+            )
+            .is_none()
+        );
+        assert!(FetchCursor::for_query("SELECT 1 ORDER BY 1", 0).is_none());
+    }
+
+    #[test]
+    fn statements_without_a_top_level_order_by_get_no_cursor() {
+        assert!(FetchCursor::for_query("SELECT * FROM t", 10).is_none());
+        assert!(
+            FetchCursor::for_query("SELECT * FROM (SELECT * FROM t ORDER BY id) s", 10).is_none()
+        );
+        assert!(
+            FetchCursor::for_query("SELECT row_number() OVER (ORDER BY id) FROM t", 10).is_none()
+        );
+        assert!(FetchCursor::for_query("SELECT 'order by' AS words FROM t", 10).is_none());
+
+        assert!(
+            FetchCursor::for_query("SELECT a FROM t UNION ALL SELECT a FROM u ORDER BY a", 10)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn statements_that_already_limit_their_rows_get_no_cursor() {
+        assert!(FetchCursor::for_query("SELECT * FROM t ORDER BY id LIMIT 5", 10).is_none());
+        assert!(FetchCursor::for_query("SELECT * FROM t ORDER BY id OFFSET 5", 10).is_none());
+        assert!(
+            FetchCursor::for_query("SELECT * FROM t ORDER BY id FETCH FIRST 5 ROWS ONLY", 10)
+                .is_none()
+        );
+        assert!(
+            FetchCursor::for_query("SELECT * FROM t ORDER BY id LOCK IN SHARE MODE", 10).is_none()
+        );
+
+        assert!(
+            FetchCursor::for_query(
+                "SELECT * FROM t WHERE id IN (SELECT id FROM u LIMIT 5) ORDER BY id",
+                10
+            )
+            .is_some()
+        );
+        assert!(FetchCursor::for_query("SELECT 'limit' AS word FROM t ORDER BY 1", 10).is_some());
+    }
+
+    #[test]
+    fn trailing_line_comment_does_not_swallow_the_page_clause() {
+        let cursor = FetchCursor::for_query("SELECT * FROM t ORDER BY id -- newest", 5).unwrap();
+
+        assert_eq!(
+            cursor.next_page_sql(&DefaultSqlDialect),
+            "SELECT * FROM t ORDER BY id -- newest\nLIMIT 6 OFFSET 0"
+        );
+    }
+
+    #[test]
+    fn page_clause_follows_the_statement_so_repeated_column_names_survive() {
+        let cursor = FetchCursor::for_query(
+            "SELECT a.id, b.id FROM a JOIN b ON b.a_id = a.id ORDER BY a.id, b.id",
+            5,
+        )
+        .unwrap();
+
+        assert_eq!(
+            cursor.next_page_sql(&DefaultSqlDialect),
+            "SELECT a.id, b.id FROM a JOIN b ON b.a_id = a.id ORDER BY a.id, b.id\nLIMIT 6 OFFSET 0"
+        );
+    }
+
+    #[test]
+    fn pages_keep_the_order_by_and_look_ahead_one_row() {
+        let mut cursor = FetchCursor::for_query("SELECT id FROM t ORDER BY id;  ", 2).unwrap();
+
+        assert_eq!(
+            cursor.next_page_sql(&DefaultSqlDialect),
+            "SELECT id FROM t ORDER BY id\nLIMIT 3 OFFSET 0"
+        );
+
+        let mut rows = vec![
+            vec![Value::Int(1)],
+            vec![Value::Int(2)],
+            vec![Value::Int(3)],
+        ];
+        cursor.advance(&mut rows);
+
+        assert_eq!(rows.len(), 2);
+        assert!(cursor.has_more());
+        assert_eq!(cursor.loaded_rows(), 2);
+        assert!(
+            cursor
+                .next_page_sql(&DefaultSqlDialect)
+                .ends_with("LIMIT 3 OFFSET 2")
+        );
+
+        let mut rows = vec![vec![Value::Int(3)]];
+        cursor.advance(&mut rows);
+
+        assert!(!cursor.has_more());
+        assert_eq!(cursor.loaded_rows(), 3);
+    }
+}
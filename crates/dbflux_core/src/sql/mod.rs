@@ -1,17 +1,24 @@
 pub(crate) mod code_generation;
 pub(crate) mod dialect;
+pub(crate) mod fetch_cursor;
 pub(crate) mod generation;
 pub(crate) mod query_builder;
+pub(crate) mod temp_table;
 
 pub use code_generation::{
     AddEnumValueRequest, AddForeignKeyRequest, CodeGenCapabilities, CodeGenerator,
     CreateIndexRequest, CreateTypeRequest, DropForeignKeyRequest, DropIndexRequest,
-    DropTypeRequest, NoOpCodeGenerator, ReindexRequest, TypeAttributeDefinition, TypeDefinition,
+    DropTypeRequest, NoOpCodeGenerator, ReindexRequest, RenameRequest, SetCommentRequest,
+    TypeAttributeDefinition, TypeDefinition,
 };
 pub use dialect::{DefaultSqlDialect, PlaceholderStyle, SqlDialect};
+pub use fetch_cursor::{FETCH_PAGE_ROWS, FetchCursor};
 pub use generation::{
     SqlGenerationOptions, SqlGenerationRequest, SqlOperation, SqlValueMode, generate_create_table,
     generate_delete_template, generate_drop_table, generate_insert_template, generate_select_star,
     generate_sql, generate_truncate, generate_update_template,
 };
 pub use query_builder::SqlQueryBuilder;
+pub use temp_table::{
+    TempColumnType, infer_temp_column_types, normalize_temp_table_name, temp_table_statements,
+};
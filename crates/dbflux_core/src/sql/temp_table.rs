@@ -0,0 +1,416 @@
+//! Materializes a query result as a session-scoped temporary table.
+//!
+//! Registering a result lets later statements on the same connection select
+//! from it by name instead of re-running the (possibly expensive) query that
+//! produced it. The rows already held by the client are written back with
+//! plain `CREATE` + multi-row `INSERT` statements built from the connection's
+//! [`SqlDialect`], so no driver-specific bulk path is needed.
+
+use super::dialect::SqlDialect;
+use crate::{DbError, QueryResult, Value};
+use std::collections::HashSet;
+
+/// Rows per generated `INSERT`. SQL Server caps a `VALUES` list at 1000 rows;
+/// 500 keeps every dialect well inside its statement size limits.
+const INSERT_BATCH_ROWS: usize = 500;
+
+const MAX_NAME_LEN: usize = 63;
+
+/// Portable column type inferred from the values of a result column.
+///
+/// Dialects map each variant to a concrete type name through
+/// [`SqlDialect::temp_column_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempColumnType {
+    Boolean,
+    Integer,
+    Float,
+    Decimal,
+    Text,
+    Date,
+    Time,
+    Timestamp,
+    Json,
+    Binary,
+}
+
+impl TempColumnType {
+    /// ANSI-style type name, used by dialects that do not override
+    /// [`SqlDialect::temp_column_type`].
+    pub fn ansi_name(self) -> &'static str {
+        match self {
+            Self::Boolean => "BOOLEAN",
+            Self::Integer => "BIGINT",
+            Self::Float => "DOUBLE PRECISION",
+            Self::Decimal => "NUMERIC",
+            Self::Text | Self::Json => "TEXT",
+            Self::Date => "DATE",
+            Self::Time => "TIME",
+            Self::Timestamp => "TIMESTAMP",
+            Self::Binary => "BLOB",
+        }
+    }
+
+    fn of_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Null | Value::Unsupported(_) => None,
+            Value::Bool(_) => Some(Self::Boolean),
+            Value::Int(_) => Some(Self::Integer),
+            Value::Float(_) => Some(Self::Float),
+            Value::Decimal(_) => Some(Self::Decimal),
+            Value::Text(_)
+            | Value::ObjectId(_)
+            | Value::Enum(_)
+            | Value::Uuid(_)
+            | Value::Interval(_) => Some(Self::Text),
+            Value::Date(_) => Some(Self::Date),
+            Value::Time(_) => Some(Self::Time),
+            Value::DateTime(_) | Value::NaiveDateTime(_) => Some(Self::Timestamp),
+            Value::Json(_) | Value::Array(_) | Value::Document(_) => Some(Self::Json),
+            Value::Bytes(_) => Some(Self::Binary),
+        }
+    }
+
+    /// Widest type that holds values of both `self` and `other`; anything
+    /// that does not widen numerically or temporally falls back to text.
+    fn widen(self, other: Self) -> Self {
+        use TempColumnType::*;
+
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Integer, Float) | (Float, Integer) => Float,
+            (Integer | Float, Decimal) | (Decimal, Integer | Float) => Decimal,
+            (Date, Timestamp) | (Timestamp, Date) => Timestamp,
+            _ => Text,
+        }
+    }
+}
+
+/// Validates a user-supplied temp table name and returns it lowercased.
+///
+/// Names are plain identifiers (`[a-z_][a-z0-9_]*`) so they can be referenced
+/// unquoted from later queries on every dialect.
+pub fn normalize_temp_table_name(name: &str) -> Result<String, DbError> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        return Err(DbError::Parse("Temp table name is empty".to_string()));
+    }
+
+    if name.len() > MAX_NAME_LEN {
+        return Err(DbError::Parse(format!(
+            "Temp table name is longer than {MAX_NAME_LEN} characters"
+        )));
+    }
+
+    let mut chars = name.chars();
+    let starts_well = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+
+    if !starts_well || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(DbError::Parse(format!(
+            "'{name}' is not a valid name: use letters, digits and '_', starting with a letter"
+        )));
+    }
+
+    Ok(name.to_ascii_lowercase())
+}
+
+/// Infers one [`TempColumnType`] per result column. Columns with only `NULL`s
+/// become text.
+pub fn infer_temp_column_types(result: &QueryResult) -> Vec<TempColumnType> {
+    (0..result.columns.len())
+        .map(|index| {
+            result
+                .rows
+                .iter()
+                .filter_map(|row| row.get(index).and_then(TempColumnType::of_value))
+                .reduce(TempColumnType::widen)
+                .unwrap_or(TempColumnType::Text)
+        })
+        .collect()
+}
+
+/// Builds the statements that materialize `result` as the temporary table
+/// `name`, in execution order: an optional drop of a previous registration,
+/// the `CREATE`, then batched `INSERT`s.
+///
+/// `name` must already be normalized with [`normalize_temp_table_name`]. Each
+/// statement is meant to be executed on its own, on the same session that
+/// will later query the table.
+pub fn temp_table_statements(
+    dialect: &dyn SqlDialect,
+    name: &str,
+    result: &QueryResult,
+) -> Result<Vec<String>, DbError> {
+    if result.columns.is_empty() {
+        return Err(DbError::NotSupported(
+            "Only results with columns can be registered".to_string(),
+        ));
+    }
+
+    let identifier = dialect.temp_table_identifier(name);
+    let table = dialect.quote_identifier(&identifier);
+    let types = infer_temp_column_types(result);
+    let columns = unique_column_names(result);
+
+    let mut statements = Vec::new();
+
+    if let Some(drop) = dialect.drop_temp_table_statement(&identifier) {
+        statements.push(drop);
+    }
+
+    let definitions = columns
+        .iter()
+        .zip(&types)
+        .map(|(column, ty)| {
+            format!(
+                "{} {}",
+                dialect.quote_identifier(column),
+                dialect.temp_column_type(*ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    statements.push(format!(
+        "{} {} ({})",
+        dialect.create_temp_table_prefix(),
+        table,
+        definitions
+    ));
+
+    let column_list = columns
+        .iter()
+        .map(|column| dialect.quote_identifier(column))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    for chunk in result.rows.chunks(INSERT_BATCH_ROWS) {
+        let values = chunk
+            .iter()
+            .map(|row| {
+                let literals = types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, ty)| {
+                        let value = row.get(index).unwrap_or(&Value::Null);
+                        dialect.value_to_literal(&coerce_value(value, *ty))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("({literals})")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        statements.push(format!(
+            "INSERT INTO {} ({}) VALUES {}",
+            table, column_list, values
+        ));
+    }
+
+    Ok(statements)
+}
+
+/// Column names made unique (`id`, `id_2`, ...) with blanks replaced by
+/// `column_<n>`, since joins commonly return repeated names.
+fn unique_column_names(result: &QueryResult) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let base = if column.name.trim().is_empty() {
+                format!("column_{}", index + 1)
+            } else {
+                column.name.clone()
+            };
+
+            let mut candidate = base.clone();
+            let mut suffix = 2;
+            while !seen.insert(candidate.to_lowercase()) {
+                candidate = format!("{base}_{suffix}");
+                suffix += 1;
+            }
+
+            candidate
+        })
+        .collect()
+}
+
+/// Converts a value into the representation its column type expects, so that
+/// mixed columns widened to text and nested values stored as JSON produce
+/// literals the target type accepts.
+fn coerce_value(value: &Value, ty: TempColumnType) -> Value {
+    match (value, ty) {
+        (Value::Null | Value::Unsupported(_), _) => Value::Null,
+        (Value::Array(_) | Value::Document(_), TempColumnType::Json) => {
+            Value::Json(Value::to_serde_json(value).to_string())
+        }
+        (Value::Date(date), TempColumnType::Timestamp) => date
+            .and_hms_opt(0, 0, 0)
+            .map(|naive| Value::DateTime(naive.and_utc()))
+            .unwrap_or(Value::Null),
+        (Value::Int(i), TempColumnType::Decimal) => Value::Decimal(i.to_string()),
+        (Value::Float(f), TempColumnType::Decimal) if f.is_finite() => {
+            Value::Decimal(f.to_string())
+        }
+        (Value::Int(i), TempColumnType::Float) => Value::Float(*i as f64),
+        (Value::Uuid(_) | Value::Interval(_) | Value::Enum(_), TempColumnType::Text) => {
+            Value::Text(value.as_raw_string())
+        }
+        (other, TempColumnType::Text) if TempColumnType::of_value(other) != Some(ty) => {
+            Value::Text(other.as_raw_string())
+        }
+        (other, _) => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColumnKind, ColumnMeta, DefaultSqlDialect};
+
+    fn column(name: &str) -> ColumnMeta {
+        ColumnMeta {
+            name: name.to_string(),
+            type_name: String::new(),
+            kind: ColumnKind::Unknown,
+            nullable: true,
+            is_primary_key: false,
+        }
+    }
+
+    fn result(columns: &[&str], rows: Vec<Vec<Value>>) -> QueryResult {
+        QueryResult::table(
+            columns.iter().map(|c| column(c)).collect(),
+            rows,
+            None,
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn names_are_validated_and_lowercased() {
+        assert_eq!(
+            normalize_temp_table_name(" Top_Customers ").unwrap(),
+            "top_customers"
+        );
+        assert_eq!(normalize_temp_table_name("_tmp1").unwrap(), "_tmp1");
+
+        assert!(normalize_temp_table_name("").is_err());
+        assert!(normalize_temp_table_name("1st").is_err());
+        assert!(normalize_temp_table_name("orders; DROP").is_err());
+        assert!(normalize_temp_table_name(&"a".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn column_types_widen_across_rows() {
+        let result = result(
+            &["id", "amount", "mixed", "empty"],
+            vec![
+                vec![Value::Int(1), Value::Int(5), Value::Int(1), Value::Null],
+                vec![
+                    Value::Int(2),
+                    Value::Float(2.5),
+                    Value::Text("x".into()),
+                    Value::Null,
+                ],
+            ],
+        );
+
+        assert_eq!(
+            infer_temp_column_types(&result),
+            vec![
+                TempColumnType::Integer,
+                TempColumnType::Float,
+                TempColumnType::Text,
+                TempColumnType::Text,
+            ]
+        );
+    }
+
+    #[test]
+    fn statements_create_then_insert_in_batches() {
+        let rows = (0..(INSERT_BATCH_ROWS as i64 + 1))
+            .map(|i| vec![Value::Int(i), Value::Text(format!("n{i}"))])
+            .collect();
+        let result = result(&["id", "name"], rows);
+
+        let statements = temp_table_statements(&DefaultSqlDialect, "recent", &result).unwrap();
+
+        assert_eq!(statements.len(), 3);
+        assert_eq!(
+            statements[0],
+            "CREATE TEMPORARY TABLE \"recent\" (\"id\" BIGINT, \"name\" TEXT)"
+        );
+        assert!(
+            statements[1].starts_with(
+                "INSERT INTO \"recent\" (\"id\", \"name\") VALUES (0, 'n0'), (1, 'n1')"
+            )
+        );
+        assert_eq!(
+            statements[2],
+            "INSERT INTO \"recent\" (\"id\", \"name\") VALUES (500, 'n500')"
+        );
+    }
+
+    #[test]
+    fn duplicate_and_blank_columns_get_unique_names() {
+        let result = result(&["id", "ID", ""], vec![]);
+
+        let statements = temp_table_statements(&DefaultSqlDialect, "t", &result).unwrap();
+
+        assert_eq!(
+            statements,
+            vec!["CREATE TEMPORARY TABLE \"t\" (\"id\" TEXT, \"ID_2\" TEXT, \"column_3\" TEXT)"]
+        );
+    }
+
+    #[test]
+    fn mixed_values_are_written_as_text() {
+        let result = result(&["v"], vec![vec![Value::Int(7)], vec![Value::Bool(true)]]);
+
+        let statements = temp_table_statements(&DefaultSqlDialect, "t", &result).unwrap();
+
+        assert_eq!(
+            statements[1],
+            "INSERT INTO \"t\" (\"v\") VALUES ('7'), ('true')"
+        );
+    }
+
+    #[test]
+    fn values_widened_to_text_keep_their_stored_form() {
+        let instant = chrono::NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|d| d.and_hms_opt(22, 15, 0))
+            .unwrap()
+            .and_utc();
+        let result = result(
+            &["v"],
+            vec![
+                vec![Value::Text("a".into())],
+                vec![Value::Bytes(vec![0xDE, 0xAD])],
+                vec![Value::DateTime(instant)],
+            ],
+        );
+
+        let statements = temp_table_statements(&DefaultSqlDialect, "t", &result).unwrap();
+
+        assert_eq!(
+            statements[1],
+            "INSERT INTO \"t\" (\"v\") VALUES ('a'), ('dead'), ('2026-03-01 22:15:00+00:00')"
+        );
+    }
+
+    #[test]
+    fn results_without_columns_are_rejected() {
+        let result = QueryResult::empty();
+
+        assert!(temp_table_statements(&DefaultSqlDialect, "t", &result).is_err());
+    }
+}
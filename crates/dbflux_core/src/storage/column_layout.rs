@@ -0,0 +1,166 @@
+use crate::{DbError, TableRef};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How a browsed table's columns are arranged in the data grid: their
+/// order, which ones are hidden and how many stay frozen on the left.
+///
+/// Columns are stored by name so the layout survives schema changes: names
+/// that no longer exist are ignored and new columns are shown at the end.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnLayout {
+    /// Shown columns in display order.
+    #[serde(default)]
+    pub order: Vec<String>,
+    #[serde(default)]
+    pub hidden: Vec<String>,
+    /// Number of leading columns of `order` that stay in place while the
+    /// grid scrolls horizontally.
+    #[serde(default)]
+    pub frozen: usize,
+}
+
+impl ColumnLayout {
+    /// Key of the layout of `table` in the UI state store.
+    pub fn storage_key(profile_id: Uuid, database: Option<&str>, table: &TableRef) -> String {
+        let table_name = match &table.schema {
+            Some(schema) => format!("{}.{}", schema, table.name),
+            None => table.name.clone(),
+        };
+
+        format!(
+            "column_layout:{}:{}:{}",
+            profile_id,
+            database.unwrap_or_default(),
+            table_name
+        )
+    }
+
+    /// Records an arrangement of `columns`, given the indices of the shown
+    /// columns in display order.
+    pub fn capture(columns: &[String], display: &[usize], frozen: usize) -> Self {
+        let order: Vec<String> = display
+            .iter()
+            .filter_map(|&ix| columns.get(ix).cloned())
+            .collect();
+
+        let hidden = columns
+            .iter()
+            .enumerate()
+            .filter(|(ix, _)| !display.contains(ix))
+            .map(|(_, name)| name.clone())
+            .collect();
+
+        Self {
+            frozen: frozen.min(order.len()),
+            order,
+            hidden,
+        }
+    }
+
+    /// Indices of the shown `columns` in display order, and how many of them
+    /// are frozen.
+    pub fn resolve(&self, columns: &[String]) -> (Vec<usize>, usize) {
+        let mut display: Vec<usize> = Vec::with_capacity(columns.len());
+        let mut frozen = 0;
+
+        for (position, name) in self.order.iter().enumerate() {
+            let Some(ix) = columns.iter().position(|column| column == name) else {
+                continue;
+            };
+
+            if display.contains(&ix) {
+                continue;
+            }
+
+            display.push(ix);
+
+            if position < self.frozen {
+                frozen += 1;
+            }
+        }
+
+        for (ix, name) in columns.iter().enumerate() {
+            if !display.contains(&ix) && !self.hidden.contains(name) {
+                display.push(ix);
+            }
+        }
+
+        // A grid with nothing to show cannot be recovered from, so a layout
+        // that hides every current column falls back to the natural order.
+        if display.is_empty() {
+            return ((0..columns.len()).collect(), 0);
+        }
+
+        (display, frozen)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, DbError> {
+        serde_json::from_str(content).map_err(|e| {
+            DbError::IoError(std::io::Error::other(format!("Invalid column layout: {e}")))
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, DbError> {
+        serde_json::to_string(self)
+            .map_err(|e| DbError::IoError(std::io::Error::other(e.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(columns: &[&str]) -> Vec<String> {
+        columns.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn capture_and_resolve_round_trip() {
+        let columns = names(&["id", "name", "email", "created_at"]);
+        let layout = ColumnLayout::capture(&columns, &[2, 0, 3], 1);
+
+        assert_eq!(layout.order, names(&["email", "id", "created_at"]));
+        assert_eq!(layout.hidden, names(&["name"]));
+        assert_eq!(layout.resolve(&columns), (vec![2, 0, 3], 1));
+    }
+
+    #[test]
+    fn resolve_follows_schema_changes() {
+        let layout = ColumnLayout {
+            order: names(&["email", "dropped", "id"]),
+            hidden: names(&["name"]),
+            frozen: 2,
+        };
+
+        // `dropped` is gone and `status` is new; the frozen prefix shrinks
+        // with the missing column and the new column is appended.
+        let columns = names(&["id", "name", "email", "status"]);
+        assert_eq!(layout.resolve(&columns), (vec![2, 0, 3], 1));
+    }
+
+    #[test]
+    fn resolve_never_hides_every_column() {
+        let layout = ColumnLayout {
+            order: Vec::new(),
+            hidden: names(&["id", "name"]),
+            frozen: 0,
+        };
+
+        assert_eq!(layout.resolve(&names(&["id", "name"])), (vec![0, 1], 0));
+    }
+
+    #[test]
+    fn storage_key_includes_database_and_schema() {
+        let profile_id = Uuid::nil();
+        let table = TableRef {
+            schema: Some("public".to_string()),
+            name: "users".to_string(),
+        };
+
+        assert_eq!(
+            ColumnLayout::storage_key(profile_id, Some("app"), &table),
+            format!("column_layout:{profile_id}:app:public.users")
+        );
+    }
+}
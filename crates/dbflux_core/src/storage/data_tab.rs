@@ -0,0 +1,103 @@
+use crate::{CollectionRef, DbError, OrderByColumn, Pagination, TableRef};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What a data tab browses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DataTabTarget {
+    Table {
+        table: TableRef,
+        #[serde(default)]
+        database: Option<String>,
+    },
+    Collection {
+        collection: CollectionRef,
+    },
+}
+
+/// Browse state of a table or collection tab, saved with the session so the
+/// tab reopens on the same filter, sort and page.
+///
+/// Rows are not stored; the restored tab loads them again the first time it
+/// is shown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataTabState {
+    pub profile_id: Uuid,
+    pub target: DataTabTarget,
+    /// Text of the filter bar: a WHERE clause for tables, a JSON filter for
+    /// collections.
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default)]
+    pub order_by: Vec<OrderByColumn>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl DataTabState {
+    pub fn title(&self) -> String {
+        match &self.target {
+            DataTabTarget::Table { table, .. } => table.qualified_name(),
+            DataTabTarget::Collection { collection } => collection.qualified_name(),
+        }
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, DbError> {
+        serde_json::from_str(content).map_err(|e| {
+            DbError::IoError(std::io::Error::other(format!(
+                "Invalid data tab state: {e}"
+            )))
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, DbError> {
+        serde_json::to_string(self)
+            .map_err(|e| DbError::IoError(std::io::Error::other(e.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Value;
+
+    #[test]
+    fn json_round_trip_keeps_filter_sort_and_page() {
+        let state = DataTabState {
+            profile_id: Uuid::new_v4(),
+            target: DataTabTarget::Table {
+                table: TableRef::with_schema("public", "orders"),
+                database: Some("shop".to_string()),
+            },
+            filter: "status = 'open'".to_string(),
+            order_by: vec![OrderByColumn::asc("created_at")],
+            pagination: Pagination::Keyset {
+                limit: 50,
+                key_columns: vec!["id".to_string()],
+                after: vec![vec![Value::Int(50)]],
+            },
+        };
+
+        let parsed = DataTabState::from_json(&state.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed, state);
+        assert_eq!(parsed.title(), "public.orders");
+    }
+
+    #[test]
+    fn missing_view_fields_fall_back_to_defaults() {
+        let profile_id = Uuid::new_v4();
+        let json = format!(
+            r#"{{"profile_id":"{profile_id}","target":{{"kind":"collection","collection":{{"database":"app","name":"events"}}}}}}"#
+        );
+
+        let parsed = DataTabState::from_json(&json).unwrap();
+
+        assert_eq!(parsed.title(), "app.events");
+        assert!(parsed.filter.is_empty());
+        assert!(parsed.order_by.is_empty());
+        assert_eq!(parsed.pagination, Pagination::default());
+        assert!(DataTabState::from_json("{ nope").is_err());
+    }
+}
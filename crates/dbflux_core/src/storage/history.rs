@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use uuid::Uuid;
 
+use crate::QueryTableRef;
+
 /// A single query history entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryEntry {
@@ -13,6 +15,31 @@ pub struct HistoryEntry {
     pub execution_time_ms: u64,
     pub row_count: Option<usize>,
     pub is_favorite: bool,
+    /// Profile the query ran against, used to filter history by connection.
+    #[serde(default)]
+    pub connection_profile_id: Option<Uuid>,
+    #[serde(default = "default_succeeded")]
+    pub succeeded: bool,
+    /// Driver error message for failed executions.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Pinned entries are exempt from the history retention limits.
+    #[serde(default)]
+    pub is_pinned: bool,
+    /// Consecutive identical executions collapsed into this entry.
+    #[serde(default = "default_run_count")]
+    pub run_count: u32,
+    /// Schema selected in the document when the query ran.
+    #[serde(default)]
+    pub schema: Option<String>,
+}
+
+fn default_succeeded() -> bool {
+    true
+}
+
+fn default_run_count() -> u32 {
+    1
 }
 
 impl HistoryEntry {
@@ -32,9 +59,32 @@ impl HistoryEntry {
             execution_time_ms: execution_time.as_millis() as u64,
             row_count,
             is_favorite: false,
+            connection_profile_id: None,
+            succeeded: true,
+            error: None,
+            is_pinned: false,
+            run_count: 1,
+            schema: None,
         }
     }
 
+    pub fn with_connection_profile(mut self, profile_id: Uuid) -> Self {
+        self.connection_profile_id = Some(profile_id);
+        self
+    }
+
+    pub fn with_schema(mut self, schema: String) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Marks the entry as a failed execution.
+    pub fn with_error(mut self, error: String) -> Self {
+        self.succeeded = false;
+        self.error = Some(error);
+        self
+    }
+
     pub fn formatted_timestamp(&self) -> String {
         use chrono::{DateTime, Local, TimeZone, Utc};
 
@@ -54,3 +104,110 @@ impl HistoryEntry {
         crate::truncate_string_safe(&single_line, max_len)
     }
 }
+
+/// Criteria for paging through query history. Unset fields match every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryFilter {
+    /// Whitespace-separated terms that must all appear in the SQL text.
+    pub text: String,
+    pub connection_profile_id: Option<Uuid>,
+    pub database: Option<String>,
+    /// Inclusive lower bound, as a Unix timestamp.
+    pub since: Option<i64>,
+    /// Exclusive upper bound, as a Unix timestamp.
+    pub until: Option<i64>,
+    pub succeeded: Option<bool>,
+}
+
+impl HistoryFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Tables the given history entries read or wrote, newest entry first and
+/// without duplicates, at most `limit` of them. Unqualified names take the
+/// database and schema the query ran against.
+pub fn recently_used_tables(entries: &[HistoryEntry], limit: usize) -> Vec<QueryTableRef> {
+    let mut tables: Vec<QueryTableRef> = Vec::new();
+
+    let referenced = entries.iter().flat_map(|entry| {
+        crate::extract_referenced_tables(&entry.sql)
+            .into_iter()
+            .map(move |table| QueryTableRef {
+                database: table.database.or_else(|| entry.database.clone()),
+                schema: table.schema.or_else(|| entry.schema.clone()),
+                table: table.table,
+            })
+    });
+
+    for table in referenced {
+        if tables.len() >= limit {
+            break;
+        }
+
+        if !tables.contains(&table) {
+            tables.push(table);
+        }
+    }
+
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(sql: &str, database: Option<&str>, schema: Option<&str>) -> HistoryEntry {
+        let mut entry = HistoryEntry::new(
+            sql.to_string(),
+            database.map(String::from),
+            None,
+            Duration::ZERO,
+            None,
+        );
+        entry.schema = schema.map(String::from);
+        entry
+    }
+
+    fn table(database: Option<&str>, schema: Option<&str>, name: &str) -> QueryTableRef {
+        QueryTableRef {
+            database: database.map(String::from),
+            schema: schema.map(String::from),
+            table: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn recently_used_tables_keep_the_newest_first_without_duplicates() {
+        let entries = [
+            entry(
+                "SELECT * FROM orders JOIN users ON true",
+                Some("shop"),
+                None,
+            ),
+            entry("UPDATE users SET name = 'a'", Some("shop"), None),
+            entry("SELECT * FROM audit.log", Some("shop"), None),
+        ];
+
+        assert_eq!(
+            recently_used_tables(&entries, 10),
+            vec![
+                table(Some("shop"), None, "orders"),
+                table(Some("shop"), None, "users"),
+                table(Some("shop"), Some("audit"), "log"),
+            ]
+        );
+        assert_eq!(recently_used_tables(&entries, 1).len(), 1);
+    }
+
+    #[test]
+    fn recently_used_tables_fall_back_to_the_entry_schema() {
+        let entries = [entry("SELECT * FROM users", Some("app"), Some("public"))];
+
+        assert_eq!(
+            recently_used_tables(&entries, 10),
+            vec![table(Some("app"), Some("public"), "users")]
+        );
+    }
+}
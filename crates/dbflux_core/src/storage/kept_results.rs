@@ -0,0 +1,162 @@
+use crate::{ColumnMeta, DbError, QueryResult, Row};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const KEPT_RESULTS_VERSION: u32 = 1;
+
+/// A result tab restored on next launch.
+///
+/// Kept tabs store the tab chrome and the tabular rows; the result is
+/// restored as a plain table and is not re-executed. Pinned tabs that are not
+/// kept store only their query (`rerun`) and run it again when first shown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeptResultTab {
+    pub title: String,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub query: String,
+    #[serde(default)]
+    pub columns: Vec<ColumnMeta>,
+    #[serde(default)]
+    pub rows: Vec<Row>,
+    /// Saved without rows; the query runs again when the tab is first shown.
+    #[serde(default)]
+    pub rerun: bool,
+}
+
+impl KeptResultTab {
+    pub fn from_result(title: String, pinned: bool, query: String, result: &QueryResult) -> Self {
+        Self {
+            title,
+            pinned,
+            query,
+            columns: result.columns.clone(),
+            rows: result.rows.clone(),
+            rerun: false,
+        }
+    }
+
+    /// A pinned tab saved without its rows.
+    pub fn from_query(title: String, query: String) -> Self {
+        Self {
+            title,
+            pinned: true,
+            query,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            rerun: true,
+        }
+    }
+
+    pub fn to_result(&self) -> QueryResult {
+        QueryResult::table(
+            self.columns.clone(),
+            self.rows.clone(),
+            None,
+            Duration::ZERO,
+        )
+    }
+}
+
+/// The kept result tabs of one document, in tab order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeptResults {
+    pub version: u32,
+    #[serde(default)]
+    pub tabs: Vec<KeptResultTab>,
+}
+
+impl Default for KeptResults {
+    fn default() -> Self {
+        Self {
+            version: KEPT_RESULTS_VERSION,
+            tabs: Vec::new(),
+        }
+    }
+}
+
+impl KeptResults {
+    pub fn new(tabs: Vec<KeptResultTab>) -> Self {
+        Self {
+            tabs,
+            ..Self::default()
+        }
+    }
+
+    /// Parses a kept results file. Empty content yields no tabs.
+    pub fn from_json(content: &str) -> Result<Self, DbError> {
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        serde_json::from_str(content).map_err(|e| {
+            DbError::IoError(std::io::Error::other(format!(
+                "Invalid kept results file: {e}"
+            )))
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, DbError> {
+        serde_json::to_string(self)
+            .map_err(|e| DbError::IoError(std::io::Error::other(e.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColumnKind, Value};
+
+    #[test]
+    fn json_round_trip_restores_table() {
+        let result = QueryResult::table(
+            vec![ColumnMeta {
+                name: "id".to_string(),
+                type_name: "int8".to_string(),
+                kind: ColumnKind::Unknown,
+                nullable: false,
+                is_primary_key: true,
+            }],
+            vec![vec![Value::Int(1)], vec![Value::Null]],
+            Some(2),
+            Duration::from_millis(12),
+        );
+
+        let kept = KeptResults::new(vec![KeptResultTab::from_result(
+            "Top ids".to_string(),
+            true,
+            "SELECT id FROM t".to_string(),
+            &result,
+        )]);
+
+        let parsed = KeptResults::from_json(&kept.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.tabs[0].title, "Top ids");
+        assert!(parsed.tabs[0].pinned);
+        assert_eq!(parsed.tabs[0].query, "SELECT id FROM t");
+
+        let restored = parsed.tabs[0].to_result();
+        assert_eq!(restored.columns[0].name, "id");
+        assert_eq!(restored.rows, result.rows);
+    }
+
+    #[test]
+    fn pinned_query_tab_round_trips_without_rows() {
+        let kept = KeptResults::new(vec![KeptResultTab::from_query(
+            "Open orders".to_string(),
+            "SELECT * FROM orders WHERE open".to_string(),
+        )]);
+
+        let parsed = KeptResults::from_json(&kept.to_json().unwrap()).unwrap();
+        let tab = &parsed.tabs[0];
+        assert!(tab.rerun && tab.pinned);
+        assert_eq!(tab.query, "SELECT * FROM orders WHERE open");
+        assert!(tab.to_result().rows.is_empty());
+    }
+
+    #[test]
+    fn empty_or_invalid_content() {
+        assert!(KeptResults::from_json("").unwrap().tabs.is_empty());
+        assert!(KeptResults::from_json("{ nope").is_err());
+    }
+}
@@ -1,14 +1,32 @@
+pub(crate) mod column_layout;
+pub(crate) mod data_tab;
 pub(crate) mod history;
+pub(crate) mod kept_results;
+pub(crate) mod notebook;
+pub(crate) mod plan_history;
 pub(crate) mod recent_files;
+pub(crate) mod recovery;
 pub(crate) mod saved_query;
+pub(crate) mod scheduled_export;
 pub mod secret_manager;
 pub(crate) mod secrets;
 pub(crate) mod session;
 pub(crate) mod ui_state;
 
-pub use history::HistoryEntry;
+pub use column_layout::ColumnLayout;
+pub use data_tab::{DataTabState, DataTabTarget};
+pub use history::{HistoryEntry, HistoryFilter, recently_used_tables};
+pub use kept_results::{KeptResultTab, KeptResults};
+pub use notebook::{
+    NOTEBOOK_EXTENSION, Notebook, NotebookCell, NotebookCellKind, is_notebook_path,
+};
+pub use plan_history::CapturedPlan;
 pub use recent_files::RecentFile;
-pub use saved_query::SavedQuery;
+pub use recovery::{DiffLine, diff_counts, line_diff};
+pub use saved_query::{
+    SavedQuery, SavedQueryFolder, SavedQuerySearch, normalize_tags, saved_query_folder_path,
+};
+pub use scheduled_export::{CronParseError, CronSchedule, ScheduledExport, ScheduledExportRun};
 pub use secret_manager::{HasSecretRef, SecretManager};
 pub use secrets::{
     KeyringSecretStore, NoopSecretStore, SecretStore, auth_field_secret_ref, connection_secret_ref,
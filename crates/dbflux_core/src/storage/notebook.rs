@@ -0,0 +1,234 @@
+use crate::DbError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use uuid::Uuid;
+
+/// File extension of notebook documents stored under the scripts directory.
+pub const NOTEBOOK_EXTENSION: &str = "dbnb";
+
+const NOTEBOOK_VERSION: u32 = 1;
+
+/// What a notebook cell holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotebookCellKind {
+    /// A statement in the connection's query language (SQL, Mongo, Redis...).
+    #[default]
+    Query,
+    /// Narrative text rendered as markdown.
+    Markdown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotebookCell {
+    pub id: Uuid,
+    #[serde(default)]
+    pub kind: NotebookCellKind,
+    #[serde(default)]
+    pub source: String,
+}
+
+impl NotebookCell {
+    pub fn new(kind: NotebookCellKind, source: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            source: source.into(),
+        }
+    }
+
+    pub fn query(source: impl Into<String>) -> Self {
+        Self::new(NotebookCellKind::Query, source)
+    }
+
+    pub fn markdown(source: impl Into<String>) -> Self {
+        Self::new(NotebookCellKind::Markdown, source)
+    }
+}
+
+/// An ordered list of query and markdown cells bound to one connection.
+///
+/// Results are never persisted; only the cell sources and the connection
+/// context the notebook was last run against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notebook {
+    pub version: u32,
+    #[serde(default)]
+    pub connection_id: Option<Uuid>,
+    #[serde(default)]
+    pub database: Option<String>,
+    #[serde(default)]
+    pub cells: Vec<NotebookCell>,
+}
+
+impl Default for Notebook {
+    fn default() -> Self {
+        Self {
+            version: NOTEBOOK_VERSION,
+            connection_id: None,
+            database: None,
+            cells: vec![NotebookCell::query("")],
+        }
+    }
+}
+
+impl Notebook {
+    /// Parses a notebook file. Empty content (a freshly created file) yields
+    /// a notebook with a single empty query cell.
+    pub fn from_json(content: &str) -> Result<Self, DbError> {
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        serde_json::from_str(content).map_err(|e| {
+            DbError::IoError(std::io::Error::other(format!("Invalid notebook file: {e}")))
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, DbError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| DbError::IoError(std::io::Error::other(e.to_string())))
+    }
+
+    pub fn position(&self, cell_id: Uuid) -> Option<usize> {
+        self.cells.iter().position(|cell| cell.id == cell_id)
+    }
+
+    /// Inserts `cell` at `index`, clamped to the end of the list.
+    pub fn insert_cell(&mut self, index: usize, cell: NotebookCell) {
+        let index = index.min(self.cells.len());
+        self.cells.insert(index, cell);
+    }
+
+    /// Moves a cell one slot up (`offset < 0`) or down. Returns `false` when
+    /// the cell is unknown or already at that edge.
+    pub fn move_cell(&mut self, cell_id: Uuid, offset: isize) -> bool {
+        let Some(from) = self.position(cell_id) else {
+            return false;
+        };
+
+        let Some(to) = from.checked_add_signed(offset) else {
+            return false;
+        };
+
+        if to >= self.cells.len() || to == from {
+            return false;
+        }
+
+        let cell = self.cells.remove(from);
+        self.cells.insert(to, cell);
+        true
+    }
+
+    pub fn remove_cell(&mut self, cell_id: Uuid) -> Option<NotebookCell> {
+        let index = self.position(cell_id)?;
+        Some(self.cells.remove(index))
+    }
+
+    /// Ids of the query cells with a non-blank source, in notebook order.
+    pub fn runnable_cells(&self) -> Vec<Uuid> {
+        self.cells
+            .iter()
+            .filter(|cell| cell.kind == NotebookCellKind::Query && !cell.source.trim().is_empty())
+            .map(|cell| cell.id)
+            .collect()
+    }
+}
+
+/// Returns `true` if `path` has the notebook file extension.
+pub fn is_notebook_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case(NOTEBOOK_EXTENSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Notebook {
+        Notebook {
+            cells: vec![
+                NotebookCell::markdown("# Orders"),
+                NotebookCell::query("SELECT 1"),
+                NotebookCell::query("  "),
+                NotebookCell::query("SELECT 2"),
+            ],
+            ..Notebook::default()
+        }
+    }
+
+    #[test]
+    fn empty_file_yields_single_query_cell() {
+        let notebook = Notebook::from_json("").unwrap();
+
+        assert_eq!(notebook.version, NOTEBOOK_VERSION);
+        assert_eq!(notebook.cells.len(), 1);
+        assert_eq!(notebook.cells[0].kind, NotebookCellKind::Query);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut notebook = sample();
+        notebook.connection_id = Some(Uuid::new_v4());
+        notebook.database = Some("shop".to_string());
+
+        let parsed = Notebook::from_json(&notebook.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed, notebook);
+    }
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        assert!(Notebook::from_json("{ not json").is_err());
+    }
+
+    #[test]
+    fn move_cell_stops_at_edges() {
+        let mut notebook = sample();
+        let first = notebook.cells[0].id;
+        let last = notebook.cells[3].id;
+
+        assert!(!notebook.move_cell(first, -1));
+        assert!(!notebook.move_cell(last, 1));
+
+        assert!(notebook.move_cell(first, 1));
+        assert_eq!(notebook.position(first), Some(1));
+
+        assert!(notebook.move_cell(last, -1));
+        assert_eq!(notebook.position(last), Some(2));
+    }
+
+    #[test]
+    fn insert_and_remove_cells() {
+        let mut notebook = sample();
+        let cell = NotebookCell::query("SELECT 3");
+        let id = cell.id;
+
+        notebook.insert_cell(usize::MAX, cell);
+        assert_eq!(notebook.position(id), Some(4));
+
+        assert_eq!(
+            notebook.remove_cell(id).map(|c| c.source),
+            Some("SELECT 3".into())
+        );
+        assert_eq!(notebook.position(id), None);
+    }
+
+    #[test]
+    fn runnable_cells_skip_markdown_and_blank_queries() {
+        let notebook = sample();
+
+        assert_eq!(
+            notebook.runnable_cells(),
+            vec![notebook.cells[1].id, notebook.cells[3].id]
+        );
+    }
+
+    #[test]
+    fn notebook_path_detection() {
+        assert!(is_notebook_path(Path::new("/tmp/Notebook 1.dbnb")));
+        assert!(is_notebook_path(Path::new("report.DBNB")));
+        assert!(!is_notebook_path(Path::new("query.sql")));
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{PlanNode, plan_statement_key};
+
+/// An execution plan captured by running EXPLAIN on a statement. Plans of
+/// the same statement and profile form its plan history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapturedPlan {
+    pub id: Uuid,
+    pub connection_profile_id: Option<Uuid>,
+    /// The explained statement, normalized by [`plan_statement_key`].
+    pub statement: String,
+    /// Unix timestamp, in seconds, of the capture.
+    pub captured_at: i64,
+    pub plan: PlanNode,
+}
+
+impl CapturedPlan {
+    pub fn new(connection_profile_id: Option<Uuid>, statement: &str, plan: PlanNode) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            connection_profile_id,
+            statement: plan_statement_key(statement),
+            captured_at: chrono::Utc::now().timestamp(),
+            plan,
+        }
+    }
+
+    pub fn formatted_timestamp(&self) -> String {
+        use chrono::{DateTime, Local, TimeZone, Utc};
+
+        match Utc.timestamp_opt(self.captured_at, 0).single() {
+            Some(dt) => {
+                let local: DateTime<Local> = dt.into();
+                local.format("%Y-%m-%d %H:%M:%S").to_string()
+            }
+            None => "Unknown".to_string(),
+        }
+    }
+}
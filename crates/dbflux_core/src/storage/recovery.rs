@@ -0,0 +1,190 @@
+//! Crash recovery: compare an autosaved editor buffer with the last saved
+//! version of its file, so the user can see what a restore brings back.
+
+/// One line of a line-based diff between a saved file and a recovered buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Unchanged(String),
+    /// Only in the recovered buffer.
+    Added(String),
+    /// Only in the saved file.
+    Removed(String),
+}
+
+/// Above this many line pairs the changed block is not aligned line by line;
+/// it is shown as removed followed by added.
+const MAX_ALIGNED_CELLS: usize = 4_000_000;
+
+/// Line diff from `saved` to `recovered`, in file order.
+pub fn line_diff(saved: &str, recovered: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = saved.lines().collect();
+    let new: Vec<&str> = recovered.lines().collect();
+
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(saved, recovered)| saved == recovered)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(saved, recovered)| saved == recovered)
+        .count();
+
+    let old_changed = &old[prefix..old.len() - suffix];
+    let new_changed = &new[prefix..new.len() - suffix];
+
+    let mut lines: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|line| DiffLine::Unchanged(line.to_string()))
+        .collect();
+
+    if old_changed.len().saturating_mul(new_changed.len()) > MAX_ALIGNED_CELLS {
+        lines.extend(
+            old_changed
+                .iter()
+                .map(|line| DiffLine::Removed(line.to_string())),
+        );
+        lines.extend(
+            new_changed
+                .iter()
+                .map(|line| DiffLine::Added(line.to_string())),
+        );
+    } else {
+        align(old_changed, new_changed, &mut lines);
+    }
+
+    lines.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::Unchanged(line.to_string())),
+    );
+
+    lines
+}
+
+/// `(added, removed)` line counts of a diff.
+pub fn diff_counts(lines: &[DiffLine]) -> (usize, usize) {
+    lines
+        .iter()
+        .fold((0, 0), |(added, removed), line| match line {
+            DiffLine::Added(_) => (added + 1, removed),
+            DiffLine::Removed(_) => (added, removed + 1),
+            DiffLine::Unchanged(_) => (added, removed),
+        })
+}
+
+/// Longest-common-subsequence alignment of two blocks of lines.
+fn align(old: &[&str], new: &[&str], out: &mut Vec<DiffLine>) {
+    let (n, m) = (old.len(), new.len());
+    let cell = move |i: usize, j: usize| i * (m + 1) + j;
+
+    // common[cell(i, j)] = length of the LCS of old[i..] and new[j..].
+    let mut common = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            common[cell(i, j)] = if old[i] == new[j] {
+                common[cell(i + 1, j + 1)] + 1
+            } else {
+                common[cell(i + 1, j)].max(common[cell(i, j + 1)])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            out.push(DiffLine::Unchanged(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if common[cell(i + 1, j)] >= common[cell(i, j + 1)] {
+            out.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            out.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+
+    out.extend(
+        old[i..]
+            .iter()
+            .map(|line| DiffLine::Removed(line.to_string())),
+    );
+    out.extend(
+        new[j..]
+            .iter()
+            .map(|line| DiffLine::Added(line.to_string())),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unchanged(line: &str) -> DiffLine {
+        DiffLine::Unchanged(line.to_string())
+    }
+
+    fn added(line: &str) -> DiffLine {
+        DiffLine::Added(line.to_string())
+    }
+
+    fn removed(line: &str) -> DiffLine {
+        DiffLine::Removed(line.to_string())
+    }
+
+    #[test]
+    fn identical_content_has_no_changes() {
+        let diff = line_diff("SELECT 1;\nSELECT 2;", "SELECT 1;\nSELECT 2;");
+
+        assert_eq!(diff, vec![unchanged("SELECT 1;"), unchanged("SELECT 2;")]);
+        assert_eq!(diff_counts(&diff), (0, 0));
+    }
+
+    #[test]
+    fn edited_and_inserted_lines_are_aligned() {
+        let saved = "SELECT *\nFROM users\nWHERE id = 1;\n";
+        let recovered = "SELECT *\nFROM users\nJOIN orders ON true\nWHERE id = 2;\n";
+
+        let diff = line_diff(saved, recovered);
+
+        assert_eq!(
+            diff,
+            vec![
+                unchanged("SELECT *"),
+                unchanged("FROM users"),
+                removed("WHERE id = 1;"),
+                added("JOIN orders ON true"),
+                added("WHERE id = 2;"),
+            ]
+        );
+        assert_eq!(diff_counts(&diff), (2, 1));
+    }
+
+    #[test]
+    fn common_lines_inside_the_changed_block_stay_unchanged() {
+        let diff = line_diff("a\nb\nc\nd", "x\nb\nc\ny");
+
+        assert_eq!(
+            diff,
+            vec![
+                removed("a"),
+                added("x"),
+                unchanged("b"),
+                unchanged("c"),
+                removed("d"),
+                added("y"),
+            ]
+        );
+    }
+
+    #[test]
+    fn buffer_without_saved_file_is_all_added() {
+        let diff = line_diff("", "SELECT 1;\nSELECT 2;");
+
+        assert_eq!(diff, vec![added("SELECT 1;"), added("SELECT 2;")]);
+        assert_eq!(diff_counts(&diff), (2, 0));
+    }
+}
@@ -2,6 +2,8 @@ use chrono::{DateTime, Local, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::SavedQueryParam;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SavedQuery {
     pub id: Uuid,
@@ -11,6 +13,14 @@ pub struct SavedQuery {
     pub connection_id: Option<Uuid>,
     pub created_at: i64,
     pub last_used_at: i64,
+    #[serde(default)]
+    pub folder_id: Option<Uuid>,
+    /// Normalized tags (lowercase, without the leading `#`), see [`normalize_tags`].
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// `:name` parameters prompted for when the query runs.
+    #[serde(default)]
+    pub params: Vec<SavedQueryParam>,
 }
 
 impl SavedQuery {
@@ -25,9 +35,31 @@ impl SavedQuery {
             connection_id,
             created_at: now,
             last_used_at: now,
+            folder_id: None,
+            tags: Vec::new(),
+            params: Vec::new(),
         }
     }
 
+    pub fn with_folder(mut self, folder_id: Option<Uuid>) -> Self {
+        self.folder_id = folder_id;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = normalize_tags(tags);
+        self
+    }
+
+    pub fn with_params(mut self, params: Vec<SavedQueryParam>) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
     pub fn formatted_created_at(&self) -> String {
         Self::format_timestamp(self.created_at)
     }
@@ -53,3 +85,179 @@ impl SavedQuery {
         }
     }
 }
+
+/// A folder in the saved-query tree. Folders nest through `parent_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedQueryFolder {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub position: usize,
+}
+
+impl SavedQueryFolder {
+    pub fn new(name: String, parent_id: Option<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            parent_id,
+            name,
+            position: 0,
+        }
+    }
+}
+
+/// Returns the `Parent / Child` path of `folder_id`, or `None` when the folder
+/// is unknown. A parent cycle stops the walk instead of looping forever.
+pub fn saved_query_folder_path(folders: &[SavedQueryFolder], folder_id: Uuid) -> Option<String> {
+    let mut names = Vec::new();
+    let mut current = Some(folder_id);
+
+    while let Some(id) = current {
+        if names.len() > folders.len() {
+            break;
+        }
+
+        let folder = folders.iter().find(|f| f.id == id)?;
+        names.push(folder.name.as_str());
+        current = folder.parent_id;
+    }
+
+    names.reverse();
+    Some(names.join(" / "))
+}
+
+/// Trims, lowercases and de-duplicates tags, dropping a leading `#` and
+/// empty entries. Order of first occurrence is kept.
+pub fn normalize_tags<I, S>(tags: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut normalized: Vec<String> = Vec::new();
+
+    for tag in tags {
+        let tag = tag.as_ref().trim().trim_start_matches('#').trim();
+        if tag.is_empty() {
+            continue;
+        }
+
+        let tag = tag.to_lowercase();
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+
+    normalized
+}
+
+/// Saved-query search input split into structured filters and free text.
+///
+/// `#tag` tokens require the tag, `driver:<name>` restricts the driver kind
+/// of the query's connection, and everything else is matched as text by the
+/// caller (substring or fuzzy).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SavedQuerySearch {
+    pub text: String,
+    pub tags: Vec<String>,
+    pub driver: Option<String>,
+}
+
+impl SavedQuerySearch {
+    pub fn parse(input: &str) -> Self {
+        let mut text = Vec::new();
+        let mut tags = Vec::new();
+        let mut driver = None;
+
+        for token in input.split_whitespace() {
+            if let Some(tag) = token.strip_prefix('#') {
+                tags.push(tag);
+            } else if let Some(name) = token.strip_prefix("driver:") {
+                let name = name.trim().to_lowercase();
+                if !name.is_empty() {
+                    driver = Some(name);
+                }
+            } else {
+                text.push(token);
+            }
+        }
+
+        Self {
+            text: text.join(" "),
+            tags: normalize_tags(tags),
+            driver,
+        }
+    }
+
+    /// Whether `query` carries every requested tag and, when a driver filter
+    /// is set, its connection's driver matches. `driver_names` are the names
+    /// the connection's driver is known by (driver id, kind display name);
+    /// queries without a connection never match a driver filter.
+    pub fn matches_filters(&self, query: &SavedQuery, driver_names: &[&str]) -> bool {
+        if !self.tags.iter().all(|tag| query.has_tag(tag)) {
+            return false;
+        }
+
+        match &self.driver {
+            Some(driver) => driver_names
+                .iter()
+                .any(|name| name.to_lowercase().contains(driver.as_str())),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_tags_trims_lowercases_and_dedupes() {
+        let tags = normalize_tags(["#Reports", " billing ", "reports", "", "#"]);
+        assert_eq!(tags, vec!["reports".to_string(), "billing".to_string()]);
+    }
+
+    #[test]
+    fn folder_path_walks_parents() {
+        let root = SavedQueryFolder::new("Reports".to_string(), None);
+        let child = SavedQueryFolder::new("Monthly".to_string(), Some(root.id));
+        let folders = vec![child.clone(), root.clone()];
+
+        assert_eq!(
+            saved_query_folder_path(&folders, child.id).as_deref(),
+            Some("Reports / Monthly")
+        );
+        assert_eq!(saved_query_folder_path(&folders, Uuid::new_v4()), None);
+    }
+
+    #[test]
+    fn folder_path_stops_on_cycles() {
+        let mut a = SavedQueryFolder::new("A".to_string(), None);
+        let b = SavedQueryFolder::new("B".to_string(), Some(a.id));
+        a.parent_id = Some(b.id);
+
+        assert!(saved_query_folder_path(&[a.clone(), b], a.id).is_some());
+    }
+
+    #[test]
+    fn search_parses_tags_driver_and_text() {
+        let search = SavedQuerySearch::parse("#Billing monthly driver:Postgres totals");
+
+        assert_eq!(search.text, "monthly totals");
+        assert_eq!(search.tags, vec!["billing".to_string()]);
+        assert_eq!(search.driver.as_deref(), Some("postgres"));
+    }
+
+    #[test]
+    fn search_filters_by_tags_and_driver() {
+        let query = SavedQuery::new("Totals".to_string(), "SELECT 1".to_string(), None)
+            .with_tags(vec!["billing".to_string(), "monthly".to_string()]);
+
+        let search = SavedQuerySearch::parse("#billing driver:postgres");
+        assert!(search.matches_filters(&query, &["postgres", "PostgreSQL"]));
+        assert!(!search.matches_filters(&query, &["mysql", "MySQL"]));
+        assert!(!search.matches_filters(&query, &[]));
+
+        let search = SavedQuerySearch::parse("#weekly");
+        assert!(!search.matches_filters(&query, &[]));
+    }
+}
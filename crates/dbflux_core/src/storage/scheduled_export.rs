@@ -0,0 +1,446 @@
+//! Scheduled exports: a saved query that runs on a cron-like schedule and
+//! writes its result to a file.
+//!
+//! Schedules only fire while the app is running; runs missed while it was
+//! closed are not caught up.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+    Utc,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Error returned when a cron expression cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{0}")]
+pub struct CronParseError(String);
+
+/// A five-field cron expression: `minute hour day-of-month month day-of-week`.
+///
+/// Fields accept `*`, numbers, ranges (`1-5`), steps (`*/15`, `0-30/10`) and
+/// comma-separated lists; months and weekdays also accept three-letter names
+/// (`jan`, `mon`). Sunday is both `0` and `7`. The `@hourly`, `@daily`,
+/// `@weekly`, `@monthly` and `@yearly` shorthands are supported.
+///
+/// As in classic cron, when both day fields are restricted a day matches if
+/// either of them does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Years searched for the next occurrence; `29 2` can be eight years away.
+const SEARCH_YEARS: i32 = 9;
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, CronParseError> {
+        let trimmed = expression.trim();
+
+        let expanded = match trimmed.to_ascii_lowercase().as_str() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            _ if trimmed.starts_with('@') => {
+                return Err(CronParseError(format!("unknown shorthand '{}'", trimmed)));
+            }
+            _ => trimmed,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(CronParseError(format!(
+                "expected 5 fields (minute hour day month weekday), found {}",
+                fields.len()
+            )));
+        };
+
+        let weekdays = parse_field(weekday, 0, 7, "weekday", &WEEKDAY_NAMES)?;
+        // Fold the Sunday alias `7` onto `0`.
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+
+        Ok(Self {
+            expression: trimmed.to_string(),
+            minutes: parse_field(minute, 0, 59, "minute", &[])?,
+            hours: parse_field(hour, 0, 23, "hour", &[])? as u32,
+            days: parse_field(day, 1, 31, "day", &[])? as u32,
+            months: parse_field(month, 1, 12, "month", &MONTH_NAMES)? as u16,
+            weekdays: weekdays as u8,
+            days_restricted: !day.starts_with('*'),
+            weekdays_restricted: !weekday.starts_with('*'),
+        })
+    }
+
+    /// The expression as entered.
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// The first matching minute strictly after `after`, or `None` when the
+    /// expression can never match (such as `0 0 31 2 *`).
+    ///
+    /// Local times skipped by a DST change never match; repeated ones match
+    /// once, at their first occurrence.
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let timezone = after.timezone();
+        let start = after.naive_local();
+        let limit = start.year() + SEARCH_YEARS;
+
+        let mut candidate =
+            start.date().and_hms_opt(start.hour(), start.minute(), 0)? + Duration::minutes(1);
+
+        loop {
+            candidate = self.next_naive(candidate, limit)?;
+
+            match timezone.from_local_datetime(&candidate) {
+                LocalResult::Single(time) | LocalResult::Ambiguous(time, _) => return Some(time),
+                LocalResult::None => candidate += Duration::minutes(1),
+            }
+        }
+    }
+
+    /// The first matching minute at or after `time`, searching until `limit`.
+    fn next_naive(&self, mut time: NaiveDateTime, limit: i32) -> Option<NaiveDateTime> {
+        loop {
+            if time.year() > limit {
+                return None;
+            }
+
+            if !bit(self.months as u64, time.month()) {
+                let (year, month) = match time.month() {
+                    12 => (time.year() + 1, 1),
+                    month => (time.year(), month + 1),
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+
+            if !self.matches_day(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+
+            if !bit(self.hours as u64, time.hour()) {
+                time = time.date().and_hms_opt(time.hour(), 0, 0)? + Duration::hours(1);
+                continue;
+            }
+
+            if !bit(self.minutes, time.minute()) {
+                time += Duration::minutes(1);
+                continue;
+            }
+
+            return Some(time);
+        }
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day = bit(self.days as u64, date.day());
+        let weekday = bit(self.weekdays as u64, date.weekday().num_days_from_sunday());
+
+        if self.days_restricted && self.weekdays_restricted {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// Parses one field into a bit mask of the values it allows.
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    label: &str,
+    names: &[&str],
+) -> Result<u64, CronParseError> {
+    let mut mask = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| CronParseError(format!("invalid {} step '{}'", label, step)))?;
+                (range, Some(step))
+            }
+            None => (part, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, min, names, label)?,
+                parse_value(end, min, names, label)?,
+            )
+        } else {
+            let value = parse_value(range, min, names, label)?;
+            // `5/15` means "from 5, every 15".
+            (value, if step.is_some() { max } else { value })
+        };
+
+        if start < min || end > max || start > end {
+            return Err(CronParseError(format!(
+                "{} '{}' is outside {}-{}",
+                label, range, min, max
+            )));
+        }
+
+        for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+/// A number, or a name from `names` (whose first entry stands for `first`).
+fn parse_value(text: &str, first: u32, names: &[&str], label: &str) -> Result<u32, CronParseError> {
+    if let Ok(value) = text.parse::<u32>() {
+        return Ok(value);
+    }
+
+    names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(text))
+        .map(|position| first + position as u32)
+        .ok_or_else(|| CronParseError(format!("invalid {} '{}'", label, text)))
+}
+
+/// A saved query exported to a file on a schedule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledExport {
+    pub id: Uuid,
+    pub name: String,
+    pub saved_query_id: Uuid,
+    /// Cron expression, see [`CronSchedule`].
+    pub schedule: String,
+    /// Export format key, such as `csv` or `json-pretty`.
+    pub format: String,
+    /// Folder the timestamped result files are written to.
+    pub output_dir: PathBuf,
+    pub enabled: bool,
+    pub created_at: i64,
+}
+
+impl ScheduledExport {
+    pub fn new(
+        name: String,
+        saved_query_id: Uuid,
+        schedule: String,
+        format: String,
+        output_dir: PathBuf,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            saved_query_id,
+            schedule,
+            format,
+            output_dir,
+            enabled: true,
+            created_at: Utc::now().timestamp(),
+        }
+    }
+
+    pub fn cron(&self) -> Result<CronSchedule, CronParseError> {
+        CronSchedule::parse(&self.schedule)
+    }
+
+    /// Path of the file a run started at `at` writes, such as
+    /// `daily_sales-20260314-060000.csv`.
+    pub fn output_path(&self, at: &DateTime<Local>, extension: &str) -> PathBuf {
+        let stem: String = self
+            .name
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let stem = if stem.trim_matches('_').is_empty() {
+            "export"
+        } else {
+            stem.as_str()
+        };
+
+        self.output_dir.join(format!(
+            "{}-{}.{}",
+            stem,
+            at.format("%Y%m%d-%H%M%S"),
+            extension
+        ))
+    }
+}
+
+/// One run of a scheduled export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledExportRun {
+    pub schedule_id: Uuid,
+    /// Unix timestamp, in seconds.
+    pub started_at: i64,
+    pub duration_ms: u64,
+    pub succeeded: bool,
+    /// What was written on success, the error otherwise.
+    pub detail: String,
+}
+
+impl ScheduledExportRun {
+    pub fn formatted_started_at(&self) -> String {
+        match Utc.timestamp_opt(self.started_at, 0).single() {
+            Some(time) => {
+                let local: DateTime<Local> = time.into();
+                local.format("%Y-%m-%d %H:%M:%S").to_string()
+            }
+            None => "Unknown".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn next(expression: &str, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        CronSchedule::parse(expression).unwrap().next_after(&after)
+    }
+
+    #[test]
+    fn steps_ranges_and_lists_pick_the_next_minute() {
+        assert_eq!(
+            next("*/15 * * * *", at(2026, 3, 14, 10, 7)),
+            Some(at(2026, 3, 14, 10, 15))
+        );
+        assert_eq!(
+            next("0 9-17/4 * * *", at(2026, 3, 14, 13, 0)),
+            Some(at(2026, 3, 14, 17, 0))
+        );
+        assert_eq!(
+            next("30 6,18 * * *", at(2026, 3, 14, 18, 30)),
+            Some(at(2026, 3, 15, 6, 30))
+        );
+    }
+
+    #[test]
+    fn names_and_shorthands_are_accepted() {
+        // 2026-03-14 is a Saturday.
+        assert_eq!(
+            next("0 8 * * mon-fri", at(2026, 3, 14, 9, 0)),
+            Some(at(2026, 3, 16, 8, 0))
+        );
+        assert_eq!(
+            next("@monthly", at(2026, 12, 5, 0, 0)),
+            Some(at(2027, 1, 1, 0, 0))
+        );
+        assert_eq!(
+            next("0 0 * * 7", at(2026, 3, 14, 0, 0)),
+            Some(at(2026, 3, 15, 0, 0))
+        );
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        // The 20th, or any Monday.
+        assert_eq!(
+            next("0 0 20 * 1", at(2026, 3, 14, 0, 0)),
+            Some(at(2026, 3, 16, 0, 0))
+        );
+        assert_eq!(
+            next("0 0 20 * 1", at(2026, 3, 16, 0, 0)),
+            Some(at(2026, 3, 20, 0, 0))
+        );
+    }
+
+    #[test]
+    fn impossible_dates_never_match_and_leap_days_do() {
+        assert_eq!(next("0 0 31 2 *", at(2026, 1, 1, 0, 0)), None);
+        assert_eq!(
+            next("0 0 29 2 *", at(2026, 1, 1, 0, 0)),
+            Some(at(2028, 2, 29, 0, 0))
+        );
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        for expression in [
+            "* * * *",
+            "60 * * * *",
+            "*/0 * * * *",
+            "0 0 * foo *",
+            "@often",
+        ] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "{} should not parse",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn output_path_is_timestamped_and_portable() {
+        let export = ScheduledExport::new(
+            "Daily sales / EU".to_string(),
+            Uuid::new_v4(),
+            "@daily".to_string(),
+            "csv".to_string(),
+            PathBuf::from("/exports"),
+        );
+        let time = Local.with_ymd_and_hms(2026, 3, 14, 6, 0, 5).unwrap();
+
+        assert_eq!(
+            export.output_path(&time, "csv"),
+            PathBuf::from("/exports/Daily_sales___EU-20260314-060005.csv")
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Time zone used to display timestamps.
+//!
+//! Timestamps with a time zone ([`Value::DateTime`](crate::Value::DateTime))
+//! are UTC instants and are shown in the display time zone picked in the
+//! settings. Timestamps without one
+//! ([`Value::NaiveDateTime`](crate::Value::NaiveDateTime)) are wall-clock
+//! values and are always shown as stored. Editing and copying a cell keep
+//! using the stored value, so the display time zone never changes what is
+//! written back to the database.
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+use std::sync::RwLock;
+
+/// Default format of timestamps in result grids, and the format of text
+/// exports.
+pub const DISPLAY_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Time zone timestamps are displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayTimezone {
+    #[default]
+    Utc,
+
+    /// The operating system's time zone, with its daylight saving rules.
+    Local,
+
+    /// A fixed offset from UTC.
+    Offset(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Storage code: `utc`, `local`, or an offset such as `+05:30`.
+    pub fn code(self) -> String {
+        match self {
+            Self::Utc => "utc".to_string(),
+            Self::Local => "local".to_string(),
+            Self::Offset(offset) => format_offset(offset),
+        }
+    }
+
+    /// Parses a [`Self::code`]. Offsets may also be written as `UTC+2`,
+    /// `-0330` or `+05`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_ascii_lowercase();
+
+        match code.as_str() {
+            "utc" | "z" => Some(Self::Utc),
+            "local" => Some(Self::Local),
+            other => parse_offset(other.strip_prefix("utc").unwrap_or(other)).map(Self::Offset),
+        }
+    }
+
+    /// Name shown in the settings, e.g. `UTC+05:30`.
+    pub fn label(self) -> String {
+        match self {
+            Self::Utc => "UTC".to_string(),
+            Self::Local => "Local time".to_string(),
+            Self::Offset(offset) => format!("UTC{}", format_offset(offset)),
+        }
+    }
+
+    /// `instant` as seen in this time zone.
+    pub fn convert(self, instant: &DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Self::Utc => instant.fixed_offset(),
+            Self::Local => instant.with_timezone(&Local).fixed_offset(),
+            Self::Offset(offset) => instant.with_timezone(&offset),
+        }
+    }
+}
+
+impl Display for DisplayTimezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label())
+    }
+}
+
+impl Serialize for DisplayTimezone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for DisplayTimezone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Self::from_code(&code)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown time zone \"{code}\"")))
+    }
+}
+
+fn format_offset(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.unsigned_abs() / 60;
+
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Parses `+HH:MM`, `+HHMM` or `+HH`.
+fn parse_offset(text: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match text.chars().next()? {
+        '+' => (1, &text[1..]),
+        '-' => (-1, &text[1..]),
+        _ => return None,
+    };
+
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) || !matches!(digits.len(), 1 | 2 | 4) {
+        return None;
+    }
+
+    let (hours, minutes) = digits.split_at(digits.len().min(2));
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = if minutes.is_empty() {
+        0
+    } else {
+        minutes.parse().ok()?
+    };
+
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+static DISPLAY_TIMEZONE: RwLock<DisplayTimezone> = RwLock::new(DisplayTimezone::Utc);
+
+pub fn set_display_timezone(timezone: DisplayTimezone) {
+    match DISPLAY_TIMEZONE.write() {
+        Ok(mut active) => *active = timezone,
+        Err(poisoned) => *poisoned.into_inner() = timezone,
+    }
+}
+
+pub fn display_timezone() -> DisplayTimezone {
+    match DISPLAY_TIMEZONE.read() {
+        Ok(active) => *active,
+        Err(poisoned) => *poisoned.into_inner(),
+    }
+}
+
+/// `instant` in the display time zone and timestamp format, as shown in
+/// result grids.
+pub fn format_timestamp(instant: &DateTime<Utc>) -> String {
+    crate::display_format::display_format().format_zoned(&display_timezone().convert(instant))
+}
+
+/// `instant` as RFC 3339 with the display time zone's offset, for exports.
+pub fn to_display_rfc3339(instant: &DateTime<Utc>) -> String {
+    display_timezone().convert(instant).to_rfc3339()
+}
+
+/// `instant` in the display time zone followed by the stored UTC value, for
+/// the cell viewer.
+pub fn describe_timestamp(instant: &DateTime<Utc>) -> String {
+    let timezone = display_timezone();
+    let shown = timezone.convert(instant).format("%Y-%m-%d %H:%M:%S%.f %:z");
+
+    if timezone == DisplayTimezone::Utc {
+        return shown.to_string();
+    }
+
+    format!(
+        "{shown} ({timezone})\nStored as UTC: {}",
+        instant.to_rfc3339()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn offset(hours: i32, minutes: i32) -> DisplayTimezone {
+        DisplayTimezone::Offset(
+            FixedOffset::east_opt(hours * 3600 + minutes * 60).expect("valid offset"),
+        )
+    }
+
+    #[test]
+    fn codes_round_trip_and_accept_loose_offsets() {
+        for timezone in [
+            DisplayTimezone::Utc,
+            DisplayTimezone::Local,
+            offset(5, 30),
+            offset(-3, -30),
+            offset(14, 0),
+        ] {
+            assert_eq!(DisplayTimezone::from_code(&timezone.code()), Some(timezone));
+        }
+
+        assert_eq!(DisplayTimezone::from_code("UTC+2"), Some(offset(2, 0)));
+        assert_eq!(DisplayTimezone::from_code("-0330"), Some(offset(-3, -30)));
+        assert_eq!(offset(-3, -30).code(), "-03:30");
+        assert_eq!(offset(5, 45).label(), "UTC+05:45");
+    }
+
+    #[test]
+    fn from_code_rejects_out_of_range_or_malformed_offsets() {
+        for code in [
+            "",
+            "+15:00",
+            "+05:60",
+            "0530",
+            "+5:3",
+            "Europe/Paris",
+            "+ab",
+        ] {
+            assert_eq!(DisplayTimezone::from_code(code), None, "{code}");
+        }
+    }
+
+    #[test]
+    fn convert_shifts_the_instant_into_the_offset() {
+        let instant = Utc.with_ymd_and_hms(2026, 3, 1, 22, 15, 0).unwrap();
+
+        let shifted = offset(5, 30).convert(&instant);
+        assert_eq!(shifted.to_rfc3339(), "2026-03-02T03:45:00+05:30");
+        assert_eq!(shifted.with_timezone(&Utc), instant);
+
+        assert_eq!(
+            DisplayTimezone::Utc.convert(&instant).to_rfc3339(),
+            "2026-03-01T22:15:00+00:00"
+        );
+    }
+
+    #[test]
+    fn serde_uses_the_storage_code() {
+        let json = serde_json::to_string(&offset(-5, 0)).expect("serialize");
+        assert_eq!(json, "\"-05:00\"");
+
+        let parsed: DisplayTimezone = serde_json::from_str("\"local\"").expect("deserialize");
+        assert_eq!(parsed, DisplayTimezone::Local);
+        assert!(serde_json::from_str::<DisplayTimezone>("\"mars\"").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use dbflux_core::{ColumnAssignment, Interval, Value};
+
+fn edited(type_name: &str, text: &str) -> ColumnAssignment {
+    ColumnAssignment::typed("col", Value::Text(text.to_string()), type_name)
+}
+
+#[test]
+fn uuid_and_interval_text_becomes_typed_values() {
+    let uuid = edited("uuid", " 67e55044-10b1-426f-9247-bb680e5fe0c8 ")
+        .validated()
+        .expect("valid uuid");
+    assert!(matches!(uuid.value, Value::Uuid(_)));
+
+    let guid = edited("UNIQUEIDENTIFIER", "67E55044-10B1-426F-9247-BB680E5FE0C8")
+        .validated()
+        .expect("valid uniqueidentifier");
+    assert_eq!(guid.value, uuid.value);
+
+    let interval = edited("interval", "1 day 02:00:00")
+        .validated()
+        .expect("valid interval");
+    assert_eq!(
+        interval.value,
+        Value::Interval(Interval::new(0, 1, 7_200_000_000))
+    );
+}
+
+#[test]
+fn malformed_values_are_rejected_with_the_column_name() {
+    let error = edited("uuid", "not-a-uuid")
+        .validated()
+        .expect_err("invalid uuid");
+    assert!(error.starts_with("col:"), "{error}");
+
+    assert!(edited("interval", "three days").validated().is_err());
+}
+
+#[test]
+fn other_columns_and_values_pass_through() {
+    let text = edited("text", "not-a-uuid").validated().expect("text");
+    assert_eq!(text.value, Value::Text("not-a-uuid".to_string()));
+
+    let untyped = ColumnAssignment::new("col", Value::Text("x".to_string()))
+        .validated()
+        .expect("untyped");
+    assert_eq!(untyped.value, Value::Text("x".to_string()));
+
+    let null = ColumnAssignment::typed("col", Value::Null, "uuid")
+        .validated()
+        .expect("null");
+    assert_eq!(null.value, Value::Null);
+}
@@ -40,7 +40,8 @@ pub static CLOUDWATCH_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| Driv
         .union(DriverCapabilities::METRIC_CATALOG)
         .union(DriverCapabilities::DASHBOARD_IMPORT)
         .union(DriverCapabilities::DASHBOARD_SYNC)
-        .union(DriverCapabilities::CHART_AUTHORING),
+        .union(DriverCapabilities::CHART_AUTHORING)
+        .union(DriverCapabilities::CONCURRENT_REQUESTS),
     default_port: None,
     uri_scheme: "cloudwatch".into(),
     icon: Icon::Logs,
@@ -654,6 +655,7 @@ impl Connection for CloudWatchConnection {
             sample_fields: None,
             presentation: CollectionPresentation::EventStream,
             child_items: None,
+            comment: None,
         })
     }
 
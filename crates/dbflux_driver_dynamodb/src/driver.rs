@@ -292,7 +292,8 @@ pub static DYNAMODB_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| Driver
             | DriverCapabilities::UPDATE.bits()
             | DriverCapabilities::DELETE.bits()
             | DriverCapabilities::NESTED_DOCUMENTS.bits()
-            | DriverCapabilities::ARRAYS.bits(),
+            | DriverCapabilities::ARRAYS.bits()
+            | DriverCapabilities::CONCURRENT_REQUESTS.bits(),
     ),
     default_port: None,
     uri_scheme: "dynamodb".into(),
@@ -737,6 +738,7 @@ impl Connection for DynamoConnection {
                 sample_fields: None,
                 presentation: dbflux_core::CollectionPresentation::DataGrid,
                 child_items: None,
+                comment: None,
             })
             .collect();
 
@@ -3822,6 +3824,7 @@ fn build_table_info_from_description(
         sample_fields,
         presentation: dbflux_core::CollectionPresentation::DataGrid,
         child_items: None,
+        comment: None,
     }
 }
 
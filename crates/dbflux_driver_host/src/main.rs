@@ -1,27 +1,91 @@
 mod session;
 
-use std::io;
+use std::collections::HashSet;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
 
 #[cfg(feature = "mysql")]
 use dbflux_core::DbKind;
 use dbflux_core::secrecy::SecretString;
-use dbflux_core::{ConnectionProfile, DbDriver};
+use dbflux_core::{ConnectionEvent, ConnectionProfile, DbDriver};
 use dbflux_ipc::driver_protocol::{
-    DriverHelloResponse, DriverRequestBody, DriverRequestEnvelope, DriverResponseBody,
-    DriverResponseEnvelope, DriverRpcError, DriverRpcErrorCode,
+    DriverCapability, DriverHelloResponse, DriverRequestBody, DriverRequestEnvelope,
+    DriverResponseBody, DriverResponseEnvelope, DriverRpcError, DriverRpcErrorCode,
 };
 use dbflux_ipc::{
-    DRIVER_RPC_AUTH_TOKEN_ENV, DRIVER_RPC_VERSION, ProtocolVersion, driver_rpc_supported_versions,
-    framing, negotiate_highest_mutual_version,
+    CompressionCodec, DRIVER_RPC_AUTH_TOKEN_ENV, DRIVER_RPC_VERSION, ProtocolVersion,
+    driver_rpc_supported_versions, framing, negotiate_compression,
+    negotiate_highest_mutual_version,
 };
 use interprocess::local_socket::{
-    GenericNamespaced, ListenerNonblockingMode::Neither, ListenerOptions, prelude::*,
+    GenericNamespaced, ListenerNonblockingMode::Neither, ListenerOptions, Stream as IpcStream,
+    prelude::*,
 };
+use native_tls::{Identity, TlsAcceptor, TlsStream};
 use session::SessionManager;
 use uuid::Uuid;
 
+/// Password of the PKCS#12 file given to `--tls-identity`.
+const TLS_IDENTITY_PASSWORD_ENV: &str = "DBFLUX_DRIVER_TLS_IDENTITY_PASSWORD";
+
+/// How long the host waits for connection events before checking for the
+/// next request again, while some session is subscribed to events.
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long a client has to finish the TLS handshake and send an authorized
+/// Hello before the host drops the connection.
+const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Pause after a failed accept, so a persistent error (e.g. out of file
+/// descriptors) does not spin the accept loop.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// A client connection. Nonblocking mode lets the host watch for requests
+/// while it forwards connection events.
+trait ClientStream: Read + Write {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()>;
+
+    /// Sets the read and write timeouts; `None` blocks indefinitely.
+    fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()>;
+}
+
+impl ClientStream for IpcStream {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        interprocess::local_socket::traits::Stream::set_nonblocking(self, nonblocking)
+    }
+
+    fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        interprocess::local_socket::traits::Stream::set_recv_timeout(self, timeout)?;
+        interprocess::local_socket::traits::Stream::set_send_timeout(self, timeout)
+    }
+}
+
+impl ClientStream for TcpStream {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        TcpStream::set_nonblocking(self, nonblocking)
+    }
+
+    fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.set_read_timeout(timeout)?;
+        self.set_write_timeout(timeout)
+    }
+}
+
+impl ClientStream for TlsStream<TcpStream> {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.get_ref().set_nonblocking(nonblocking)
+    }
+
+    fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.get_ref().set_timeout(timeout)
+    }
+}
+
 fn main() {
     use dbflux_core::observability::tracing_bridge::{BridgeConfig, FmtWriter, init_tracing};
     let _ = init_tracing(BridgeConfig {
@@ -47,36 +111,91 @@ fn main() {
     let driver = create_driver(&args.driver)
         .unwrap_or_else(|e| fatal(&format!("Failed to create driver '{}': {e}", args.driver)));
 
-    let socket_display = args.socket.clone();
-    let name = args
-        .socket
-        .to_ns_name::<GenericNamespaced>()
-        .unwrap_or_else(|e| fatal(&format!("Invalid socket name '{socket_display}': {e}")));
-
-    let listener = ListenerOptions::new()
-        .name(name)
-        .nonblocking(Neither)
-        .create_sync()
-        .unwrap_or_else(|e| fatal(&format!("Failed to bind socket '{socket_display}': {e}")));
-
-    log::info!(
-        "Driver host started: driver={}, socket={socket_display}",
-        args.driver,
-    );
-
-    // Accept loop — one connection at a time (the parent DBFlux process holds a
-    // single connection per driver-host instance).
-    loop {
-        match listener.accept() {
-            Ok(stream) => {
-                log::info!("Client connected");
-                handle_connection(stream, driver.as_ref(), auth_token.as_deref());
-                log::info!("Client disconnected");
-            }
-            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
-            Err(e) => {
-                log::error!("Accept failed: {e}");
-                break;
+    match args.endpoint {
+        Endpoint::Socket(socket) => {
+            let name = socket
+                .clone()
+                .to_ns_name::<GenericNamespaced>()
+                .unwrap_or_else(|e| fatal(&format!("Invalid socket name '{socket}': {e}")));
+
+            let listener = ListenerOptions::new()
+                .name(name)
+                .nonblocking(Neither)
+                .create_sync()
+                .unwrap_or_else(|e| fatal(&format!("Failed to bind socket '{socket}': {e}")));
+
+            log::info!(
+                "Driver host started: driver={}, socket={socket}",
+                args.driver,
+            );
+
+            serve(
+                || listener.accept(),
+                Ok,
+                driver.as_ref(),
+                auth_token.as_deref(),
+            );
+        }
+
+        Endpoint::Tcp {
+            address,
+            tls_identity,
+        } => {
+            let listener = TcpListener::bind(&address)
+                .unwrap_or_else(|e| fatal(&format!("Failed to listen on '{address}': {e}")));
+
+            log::info!(
+                "Driver host started: driver={}, address={address}, tls={}",
+                args.driver,
+                tls_identity.is_some(),
+            );
+
+            let accept_tcp = || listener.accept().map(|(stream, _)| stream);
+
+            // The timeout also bounds the TLS handshake, which runs before
+            // `handle_connection` sees the stream.
+            let prepare_tcp = |stream: TcpStream| {
+                stream.set_nodelay(true)?;
+                stream.set_timeout(Some(HELLO_TIMEOUT))?;
+                Ok::<_, io::Error>(stream)
+            };
+
+            match tls_identity {
+                Some(path) => {
+                    let acceptor = load_tls_acceptor(&path).unwrap_or_else(|e| fatal(&e));
+
+                    serve(
+                        accept_tcp,
+                        |stream| {
+                            acceptor.accept(prepare_tcp(stream)?).map_err(|e| {
+                                io::Error::new(
+                                    io::ErrorKind::ConnectionAborted,
+                                    format!("TLS handshake failed: {e}"),
+                                )
+                            })
+                        },
+                        driver.as_ref(),
+                        auth_token.as_deref(),
+                    );
+                }
+                None => {
+                    let loopback = listener
+                        .local_addr()
+                        .is_ok_and(|address| address.ip().is_loopback());
+                    if !loopback {
+                        log::warn!(
+                            "Listening on '{address}' without TLS: requests, results and \
+                             passwords cross the network in clear text"
+                        );
+                    }
+
+                    serve(
+                        accept_tcp,
+                        prepare_tcp,
+                        driver.as_ref(),
+                        auth_token.as_deref(),
+                    );
+                }
             }
         }
     }
@@ -84,29 +203,119 @@ fn main() {
     log::info!("Driver host shutting down");
 }
 
+/// Accept loop. Each connection runs `handshake` and is then served on its
+/// own thread, so a peer that stalls before its Hello cannot hold up the
+/// parent DBFlux process's connection. Failures of a single connection are
+/// logged and never stop the host.
+fn serve<R: Send, S: ClientStream>(
+    mut accept: impl FnMut() -> io::Result<R>,
+    handshake: impl Fn(R) -> io::Result<S> + Sync,
+    driver: &dyn DbDriver,
+    auth_token: Option<&str>,
+) {
+    let handshake = &handshake;
+
+    std::thread::scope(|scope| {
+        loop {
+            match accept() {
+                Ok(connection) => {
+                    scope.spawn(move || match handshake(connection) {
+                        Ok(stream) => {
+                            log::info!("Client connected");
+                            handle_connection(stream, driver, auth_token);
+                            log::info!("Client disconnected");
+                        }
+                        Err(e) => log::warn!("Rejected client: {e}"),
+                    });
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    log::warn!("Accept failed: {e}");
+                    std::thread::sleep(ACCEPT_RETRY_DELAY);
+                }
+            }
+        }
+    });
+}
+
+/// TLS acceptor for the PKCS#12 certificate and key at `path`.
+fn load_tls_acceptor(path: &Path) -> Result<TlsAcceptor, String> {
+    let pkcs12 = std::fs::read(path)
+        .map_err(|e| format!("Cannot read TLS identity '{}': {e}", path.display()))?;
+    let password = std::env::var(TLS_IDENTITY_PASSWORD_ENV).unwrap_or_default();
+
+    let identity = Identity::from_pkcs12(&pkcs12, &password)
+        .map_err(|e| format!("Invalid TLS identity '{}': {e}", path.display()))?;
+
+    TlsAcceptor::new(identity).map_err(|e| format!("Cannot set up TLS: {e}"))
+}
+
 /// Returns `true` when the client-supplied token matches the expected token.
 ///
 /// `expected_auth_token` must always be `Some` at runtime (the startup guard in
 /// `main` enforces this). The parameter is `Option` so the same helper can be
 /// exercised in tests that validate the reject path without a live socket.
 fn is_hello_authorized(client_token: Option<&str>, expected_auth_token: Option<&str>) -> bool {
-    match expected_auth_token {
-        Some(expected) => client_token == Some(expected),
-        None => false,
+    match (client_token, expected_auth_token) {
+        (Some(client), Some(expected)) => constant_time_eq(client.as_bytes(), expected.as_bytes()),
+        _ => false,
+    }
+}
+
+/// Compares `given` to `expected` in time that depends only on the length
+/// of `expected`, so response timing does not reveal how much of a guessed
+/// token was right.
+fn constant_time_eq(given: &[u8], expected: &[u8]) -> bool {
+    let mut difference = given.len() ^ expected.len();
+
+    for (index, byte) in expected.iter().enumerate() {
+        let given_byte = given.get(index).copied().unwrap_or(0);
+        difference |= usize::from(given_byte ^ byte);
     }
+
+    std::hint::black_box(difference) == 0
+}
+
+/// The requested capabilities the host grants: all of them, except that only
+/// the chosen compression codec is kept.
+fn accepted_capabilities(
+    requested: Vec<DriverCapability>,
+    compression: Option<CompressionCodec>,
+) -> Vec<DriverCapability> {
+    requested
+        .into_iter()
+        .filter(|capability| match capability {
+            DriverCapability::Compression(codec) => Some(*codec) == compression,
+            _ => true,
+        })
+        .collect()
 }
 
 /// Handles one client connection for its entire lifetime.
 fn handle_connection(
-    mut stream: interprocess::local_socket::Stream,
+    mut stream: impl ClientStream,
     driver: &dyn DbDriver,
     expected_auth_token: Option<&str>,
 ) {
+    if let Err(e) = stream.set_timeout(Some(HELLO_TIMEOUT)) {
+        log::warn!("Failed to set the Hello timeout: {e}");
+        return;
+    }
+
     let mut sessions = SessionManager::new();
     let mut negotiated_version = None;
+    let mut compression = None;
+    let (event_tx, event_rx) = mpsc::channel();
+    let mut subscribed = HashSet::new();
 
     loop {
-        let envelope: DriverRequestEnvelope = match framing::recv_msg(&mut stream) {
+        let request = recv_request(
+            &mut stream,
+            &event_rx,
+            &subscribed,
+            negotiated_version.unwrap_or(DRIVER_RPC_VERSION),
+        );
+        let envelope: DriverRequestEnvelope = match request {
             Ok(env) => env,
             Err(e) => {
                 if e.kind() == io::ErrorKind::UnexpectedEof {
@@ -121,6 +330,7 @@ fn handle_connection(
         let request_id = envelope.request_id;
         let session_id = envelope.session_id;
         let request_version = envelope.protocol_version;
+        let mut close_after_response = false;
 
         if !matches!(envelope.body, DriverRequestBody::Hello(_)) {
             let Some(selected_version) = negotiated_version else {
@@ -165,6 +375,11 @@ fn handle_connection(
         let response = match envelope.body {
             DriverRequestBody::Hello(hello_req) => {
                 if !is_hello_authorized(hello_req.auth_token.as_deref(), expected_auth_token) {
+                    // One guess per connection: the client has to reconnect,
+                    // TLS handshake included, to try another token.
+                    log::warn!("Rejected unauthorized Hello, closing the connection");
+                    close_after_response = true;
+
                     DriverResponseEnvelope::error(
                         request_version,
                         request_id,
@@ -176,7 +391,13 @@ fn handle_connection(
                 } else {
                     match negotiate_hello_version(&hello_req.supported_versions) {
                         Ok(selected_version) => {
+                            if let Err(e) = stream.set_timeout(None) {
+                                log::warn!("Failed to clear the Hello timeout: {e}");
+                                close_after_response = true;
+                            }
+
                             negotiated_version = Some(selected_version);
+                            compression = negotiate_compression(&hello_req.requested_capabilities);
 
                             DriverResponseEnvelope::ok(
                                 selected_version,
@@ -186,7 +407,10 @@ fn handle_connection(
                                     server_name: "dbflux-driver-host".to_string(),
                                     server_version: env!("CARGO_PKG_VERSION").to_string(),
                                     selected_version,
-                                    capabilities: hello_req.requested_capabilities,
+                                    capabilities: accepted_capabilities(
+                                        hello_req.requested_capabilities,
+                                        compression,
+                                    ),
                                     driver_kind: driver.kind(),
                                     driver_metadata: driver.metadata().clone(),
                                     form_definition: driver.form_definition().clone(),
@@ -219,6 +443,7 @@ fn handle_connection(
 
             DriverRequestBody::CloseSession => {
                 if let Some(sid) = session_id {
+                    subscribed.remove(&sid);
                     match sessions.remove(&sid) {
                         Some(mut conn) => match conn.close() {
                             Ok(()) => DriverResponseEnvelope::ok(
@@ -263,7 +488,27 @@ fn handle_connection(
             other => {
                 if let Some(sid) = session_id {
                     if let Some(conn) = sessions.get(&sid) {
-                        let body = session::dispatch(conn, other);
+                        let body = match other {
+                            DriverRequestBody::SubscribeEvents { kinds } => {
+                                let body = session::subscribe_events(conn, sid, &kinds, &event_tx);
+                                match &body {
+                                    DriverResponseBody::EventsSubscribed { kinds }
+                                        if !kinds.is_empty() =>
+                                    {
+                                        subscribed.insert(sid);
+                                    }
+                                    _ => {
+                                        subscribed.remove(&sid);
+                                    }
+                                }
+                                body
+                            }
+                            DriverRequestBody::UnsubscribeEvents => {
+                                subscribed.remove(&sid);
+                                session::unsubscribe_events(conn)
+                            }
+                            other => session::dispatch(conn, other),
+                        };
                         DriverResponseEnvelope::ok(
                             negotiated_version.expect("validated before dispatch"),
                             request_id,
@@ -293,15 +538,77 @@ fn handle_connection(
             }
         };
 
+        let response = match compression {
+            Some(codec) => response.compressed(codec),
+            None => response,
+        };
+
         if let Err(e) = framing::send_msg(&mut stream, &response) {
             log::warn!("Failed to send response: {e}");
             break;
         }
+
+        if close_after_response {
+            break;
+        }
     }
 
     sessions.close_all();
 }
 
+/// Reads the next request. While sessions are subscribed to events, waits for
+/// it in short nonblocking polls and forwards their events in between.
+fn recv_request(
+    stream: &mut impl ClientStream,
+    events: &Receiver<(Uuid, ConnectionEvent)>,
+    subscribed: &HashSet<Uuid>,
+    version: ProtocolVersion,
+) -> io::Result<DriverRequestEnvelope> {
+    if subscribed.is_empty() {
+        return framing::recv_msg(stream);
+    }
+
+    loop {
+        while let Ok((session_id, event)) = events.try_recv() {
+            forward_event(stream, subscribed, version, session_id, event)?;
+        }
+
+        stream.set_nonblocking(true)?;
+        let first = framing::poll_msg_start(&mut *stream);
+        stream.set_nonblocking(false)?;
+
+        if let Some(first) = first? {
+            return framing::recv_msg_after(first, stream);
+        }
+
+        match events.recv_timeout(EVENT_POLL_INTERVAL) {
+            Ok((session_id, event)) => {
+                forward_event(stream, subscribed, version, session_id, event)?
+            }
+            Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {}
+        }
+    }
+}
+
+/// Sends `event` as an event frame, unless its session has unsubscribed or
+/// closed since the driver reported it.
+fn forward_event(
+    stream: &mut impl ClientStream,
+    subscribed: &HashSet<Uuid>,
+    version: ProtocolVersion,
+    session_id: Uuid,
+    event: ConnectionEvent,
+) -> io::Result<()> {
+    if !subscribed.contains(&session_id) {
+        return Ok(());
+    }
+
+    framing::send_msg(
+        stream,
+        &DriverResponseEnvelope::event(version, session_id, event),
+    )
+}
+
 fn handle_open_session(
     protocol_version: ProtocolVersion,
     request_id: u64,
@@ -429,35 +736,70 @@ fn validate_negotiated_request_version(
 
 struct Args {
     driver: String,
-    socket: String,
+    endpoint: Endpoint,
+}
+
+/// Where the host waits for its client.
+enum Endpoint {
+    /// A local socket name, for hosts DBFlux launches itself.
+    Socket(String),
+    /// A TCP address, for hosts DBFlux reaches over the network.
+    Tcp {
+        address: String,
+        tls_identity: Option<PathBuf>,
+    },
 }
 
 fn parse_args() -> Args {
     let mut args = std::env::args().skip(1);
     let mut driver = None;
     let mut socket = None;
+    let mut listen = None;
+    let mut tls_identity = None;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
             "--driver" => driver = args.next(),
             "--socket" => socket = args.next(),
+            "--listen" => listen = args.next(),
+            "--tls-identity" => tls_identity = args.next().map(PathBuf::from),
             "--help" | "-h" => {
-                eprintln!("Usage: dbflux-driver-host --driver <name> --socket <name>");
+                eprintln!(
+                    "Usage: dbflux-driver-host --driver <name> (--socket <name> | --listen <host:port>)"
+                );
                 eprintln!();
                 eprintln!("Options:");
                 eprintln!(
-                    "  --driver <name>  Driver to host (sqlite, postgres, mysql, mariadb, mongodb, redis, dynamodb)"
+                    "  --driver <name>          Driver to host (sqlite, postgres, mysql, mariadb, mongodb, redis, dynamodb)"
+                );
+                eprintln!("  --socket <name>          Socket name to bind");
+                eprintln!(
+                    "  --listen <host:port>     TCP address to listen on, for remote clients"
+                );
+                eprintln!(
+                    "  --tls-identity <file>    PKCS#12 certificate and key to serve TLS with --listen"
                 );
-                eprintln!("  --socket <name>  Socket name to bind");
+                eprintln!("                           (password in {TLS_IDENTITY_PASSWORD_ENV})");
                 process::exit(0);
             }
             other => fatal(&format!("Unknown argument: {other}")),
         }
     }
 
+    let endpoint = match (socket, listen) {
+        (Some(_), Some(_)) => fatal("--socket and --listen cannot be used together"),
+        (Some(_), None) if tls_identity.is_some() => fatal("--tls-identity requires --listen"),
+        (Some(socket), None) => Endpoint::Socket(socket),
+        (None, Some(address)) => Endpoint::Tcp {
+            address,
+            tls_identity,
+        },
+        (None, None) => fatal("--socket or --listen is required"),
+    };
+
     Args {
         driver: driver.unwrap_or_else(|| fatal("--driver is required")),
-        socket: socket.unwrap_or_else(|| fatal("--socket is required")),
+        endpoint,
     }
 }
 
@@ -527,13 +869,134 @@ fn fatal(message: &str) -> ! {
 #[cfg(test)]
 mod tests {
     use super::{
-        choose_negotiated_driver_version, create_driver, hello_version_error_response,
-        is_hello_authorized, negotiate_hello_version, validate_negotiated_request_version,
+        ClientStream, accepted_capabilities, choose_negotiated_driver_version, create_driver,
+        hello_version_error_response, is_hello_authorized, negotiate_hello_version, recv_request,
+        validate_negotiated_request_version,
     };
+    use dbflux_core::ConnectionEvent;
+    #[cfg(feature = "sqlite")]
+    use dbflux_ipc::driver_protocol::DriverHelloRequest;
     use dbflux_ipc::{
-        ProtocolVersion,
-        driver_protocol::{DriverResponseBody, DriverRpcErrorCode},
+        CompressionCodec, DRIVER_RPC_VERSION, ProtocolVersion,
+        driver_protocol::{
+            DriverCapability, DriverRequestBody, DriverRequestEnvelope, DriverResponseBody,
+            DriverResponseEnvelope, DriverRpcErrorCode,
+        },
+        framing,
     };
+    use std::collections::HashSet;
+    use std::io::{self, Cursor, Read, Write};
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    /// Client stream whose request shows up only after a few polls.
+    struct ScriptedStream {
+        idle_polls: usize,
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl Read for ScriptedStream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.idle_polls > 0 {
+                self.idle_polls -= 1;
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for ScriptedStream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl ClientStream for ScriptedStream {
+        fn set_nonblocking(&self, _nonblocking: bool) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn set_timeout(&self, _timeout: Option<Duration>) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl ClientStream for &mut ScriptedStream {
+        fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+            (**self).set_nonblocking(nonblocking)
+        }
+
+        fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+            (**self).set_timeout(timeout)
+        }
+    }
+
+    #[test]
+    fn recv_request_forwards_events_of_subscribed_sessions_only() {
+        let subscribed_id = Uuid::new_v4();
+        let closed_id = Uuid::new_v4();
+
+        let mut input = Vec::new();
+        framing::send_msg(
+            &mut input,
+            &DriverRequestEnvelope::new(DRIVER_RPC_VERSION, 9, DriverRequestBody::Ping),
+        )
+        .unwrap();
+        let mut stream = ScriptedStream {
+            idle_polls: 2,
+            input: Cursor::new(input),
+            output: Vec::new(),
+        };
+
+        let (events_tx, events_rx) = mpsc::channel();
+        let schema_changed = ConnectionEvent::SchemaChanged {
+            database: Some("app".to_string()),
+        };
+        events_tx.send((closed_id, schema_changed.clone())).unwrap();
+        events_tx
+            .send((subscribed_id, schema_changed.clone()))
+            .unwrap();
+
+        let subscribed = HashSet::from([subscribed_id]);
+        let request =
+            recv_request(&mut stream, &events_rx, &subscribed, DRIVER_RPC_VERSION).unwrap();
+        assert_eq!(request.request_id, 9);
+
+        let mut output = Cursor::new(stream.output);
+        let frame: DriverResponseEnvelope = framing::recv_msg(&mut output).unwrap();
+        assert_eq!(frame.request_id, 0);
+        assert_eq!(frame.session_id, Some(subscribed_id));
+        assert!(!frame.done);
+        assert!(matches!(frame.body, DriverResponseBody::Event(event) if event == schema_changed));
+        assert_eq!(output.position() as usize, output.get_ref().len());
+    }
+
+    #[test]
+    fn accepted_capabilities_keep_only_the_chosen_codec() {
+        let requested = vec![
+            DriverCapability::Cancellation,
+            DriverCapability::Compression(CompressionCodec::Zstd),
+            DriverCapability::Compression(CompressionCodec::Lz4),
+        ];
+
+        assert_eq!(
+            accepted_capabilities(requested.clone(), Some(CompressionCodec::Zstd)),
+            vec![
+                DriverCapability::Cancellation,
+                DriverCapability::Compression(CompressionCodec::Zstd),
+            ]
+        );
+        assert_eq!(
+            accepted_capabilities(requested, None),
+            vec![DriverCapability::Cancellation]
+        );
+    }
 
     #[cfg(feature = "dynamodb")]
     #[test]
@@ -625,6 +1088,51 @@ mod tests {
         assert!(is_hello_authorized(Some("secret"), Some("secret")));
     }
 
+    #[test]
+    fn hello_auth_rejects_prefixes_and_extensions_of_the_token() {
+        assert!(!is_hello_authorized(Some("secre"), Some("secret")));
+        assert!(!is_hello_authorized(Some("secrets"), Some("secret")));
+        assert!(!is_hello_authorized(Some(""), Some("secret")));
+        assert!(!is_hello_authorized(Some("secreT"), Some("secret")));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn rejected_hello_closes_the_connection() {
+        let hello = DriverRequestBody::Hello(DriverHelloRequest {
+            client_name: "test".to_string(),
+            client_version: "0".to_string(),
+            supported_versions: vec![DRIVER_RPC_VERSION],
+            requested_capabilities: Vec::new(),
+            auth_token: Some("wrong".to_string()),
+        });
+
+        let mut input = Vec::new();
+        for body in [hello.clone(), hello, DriverRequestBody::Ping] {
+            framing::send_msg(
+                &mut input,
+                &DriverRequestEnvelope::new(DRIVER_RPC_VERSION, 1, body),
+            )
+            .unwrap();
+        }
+        let mut stream = ScriptedStream {
+            idle_polls: 0,
+            input: Cursor::new(input),
+            output: Vec::new(),
+        };
+        let driver = create_driver("sqlite").unwrap();
+
+        super::handle_connection(&mut stream, driver.as_ref(), Some("secret"));
+
+        let mut output = Cursor::new(stream.output);
+        let response: DriverResponseEnvelope = framing::recv_msg(&mut output).unwrap();
+        assert!(matches!(
+            response.body,
+            DriverResponseBody::Error(error) if error.code == DriverRpcErrorCode::InvalidRequest
+        ));
+        assert_eq!(output.position() as usize, output.get_ref().len());
+    }
+
     #[test]
     fn hello_version_error_response_preserves_request_metadata() {
         let response = hello_version_error_response(
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
 
-use dbflux_core::{Connection, DbError, KeyValueApi};
+use dbflux_core::{Connection, ConnectionEvent, ConnectionEventKind, DbError, KeyValueApi};
 use dbflux_ipc::driver_protocol::{
     DriverRequestBody, DriverResponseBody, DriverRpcErrorCode, QueryResultDto,
 };
@@ -364,16 +366,45 @@ pub fn dispatch(conn: &dyn Connection, body: DriverRequestBody) -> DriverRespons
             Err(e) => db_error_to_response(e),
         },
 
-        // Hello/OpenSession/CloseSession are handled by the main loop, not here.
+        // These are handled by the main loop, not here.
         DriverRequestBody::Hello(_)
         | DriverRequestBody::OpenSession { .. }
-        | DriverRequestBody::CloseSession => rpc_error(
+        | DriverRequestBody::CloseSession
+        | DriverRequestBody::SubscribeEvents { .. }
+        | DriverRequestBody::UnsubscribeEvents => rpc_error(
             DriverRpcErrorCode::InvalidRequest,
             "Request type handled at session level, not dispatch level",
         ),
     }
 }
 
+/// Subscribes session `session_id` to `kinds`, tagging each event with the
+/// session and sending it to `events` for the main loop to forward.
+pub fn subscribe_events(
+    conn: &dyn Connection,
+    session_id: Uuid,
+    kinds: &[ConnectionEventKind],
+    events: &Sender<(Uuid, ConnectionEvent)>,
+) -> DriverResponseBody {
+    let events = events.clone();
+    let sink = Arc::new(move |event| {
+        // The receiver only goes away when the client disconnects.
+        let _ = events.send((session_id, event));
+    });
+
+    match conn.subscribe_events(kinds, sink) {
+        Ok(kinds) => DriverResponseBody::EventsSubscribed { kinds },
+        Err(e) => db_error_to_response(e),
+    }
+}
+
+pub fn unsubscribe_events(conn: &dyn Connection) -> DriverResponseBody {
+    match conn.unsubscribe_events() {
+        Ok(()) => DriverResponseBody::EventsUnsubscribed,
+        Err(e) => db_error_to_response(e),
+    }
+}
+
 /// Helper to dispatch KV operations, returning NotSupported if no KV API.
 fn dispatch_kv(
     conn: &dyn Connection,
@@ -2,17 +2,17 @@ use std::sync::Arc;
 
 use dbflux_core::{
     CodeGenCapabilities, CodeGeneratorInfo, CollectionBrowseRequest, CollectionCountRequest,
-    Connection, CrudResult, CustomTypeInfo, DatabaseInfo, DbError, DbKind, DbSchemaInfo,
-    DescribeRequest, DocumentDelete, DocumentInsert, DocumentUpdate, DriverCapabilities,
-    DriverMetadata, ExplainRequest, HashDeleteRequest, HashSetRequest, KeyBulkGetRequest,
-    KeyDeleteRequest, KeyExistsRequest, KeyExpireRequest, KeyGetRequest, KeyGetResult,
-    KeyPersistRequest, KeyRenameRequest, KeyScanPage, KeyScanRequest, KeySetRequest, KeyTtlRequest,
-    KeyType, KeyTypeRequest, KeyValueApi, LanguageService, ListPushRequest, ListRemoveRequest,
-    ListSetRequest, QueryHandle, QueryRequest, QueryResult, RowDelete, RowInsert, RowPatch,
-    SchemaFeatures, SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaSnapshot,
-    SemanticPlan, SemanticRequest, SetAddRequest, SetRemoveRequest, SqlDialect, StreamAddRequest,
-    StreamDeleteRequest, TableBrowseRequest, TableCountRequest, TableInfo, ViewInfo,
-    ZSetAddRequest, ZSetRemoveRequest,
+    Connection, ConnectionEventKind, ConnectionEventSink, CrudResult, CustomTypeInfo, DatabaseInfo,
+    DbError, DbKind, DbSchemaInfo, DescribeRequest, DocumentDelete, DocumentInsert, DocumentUpdate,
+    DriverCapabilities, DriverMetadata, ExplainRequest, HashDeleteRequest, HashSetRequest,
+    KeyBulkGetRequest, KeyDeleteRequest, KeyExistsRequest, KeyExpireRequest, KeyGetRequest,
+    KeyGetResult, KeyPersistRequest, KeyRenameRequest, KeyScanPage, KeyScanRequest, KeySetRequest,
+    KeyTtlRequest, KeyType, KeyTypeRequest, KeyValueApi, LanguageService, ListPushRequest,
+    ListRemoveRequest, ListSetRequest, QueryHandle, QueryRequest, QueryResult, RowDelete,
+    RowInsert, RowPatch, SchemaFeatures, SchemaForeignKeyInfo, SchemaIndexInfo,
+    SchemaLoadingStrategy, SchemaSnapshot, SemanticPlan, SemanticRequest, SetAddRequest,
+    SetRemoveRequest, SqlDialect, StreamAddRequest, StreamDeleteRequest, TableBrowseRequest,
+    TableCountRequest, TableInfo, ViewInfo, ZSetAddRequest, ZSetRemoveRequest,
 };
 use dbflux_ipc::driver_protocol::{DriverRequestBody, DriverResponseBody};
 
@@ -343,6 +343,22 @@ impl Connection for IpcConnection {
             .generate_code(self.session_id, generator_id, table)
             .map_err(DbError::from)
     }
+
+    fn subscribe_events(
+        &self,
+        kinds: &[ConnectionEventKind],
+        sink: ConnectionEventSink,
+    ) -> Result<Vec<ConnectionEventKind>, DbError> {
+        self.client
+            .subscribe_events(self.session_id, kinds, sink)
+            .map_err(DbError::from)
+    }
+
+    fn unsubscribe_events(&self) -> Result<(), DbError> {
+        self.client
+            .unsubscribe_events(self.session_id)
+            .map_err(DbError::from)
+    }
 }
 
 impl KeyValueApi for IpcConnection {
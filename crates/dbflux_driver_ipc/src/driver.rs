@@ -9,13 +9,15 @@ use std::{process::Stdio, thread};
 use dbflux_core::secrecy::{ExposeSecret, SecretString};
 use dbflux_core::{
     ConnectionProfile, DbConfig, DbError, DbKind, DriverFormDef, DriverMetadata, FormValues,
+    RemoteServiceConfig,
 };
 use dbflux_ipc::ExternalAuditEmitter;
 use dbflux_ipc::driver_protocol::DriverResponseBody;
 use interprocess::local_socket::{GenericNamespaced, Name, Stream as IpcStream, prelude::*};
 
 use crate::connection::IpcConnection;
-use crate::transport::RpcClient;
+use crate::stream::DriverStream;
+use crate::transport::{Reconnect, RpcClient, RpcError};
 
 static MANAGED_HOSTS: OnceLock<Mutex<HashMap<String, Child>>> = OnceLock::new();
 
@@ -33,7 +35,7 @@ fn managed_hosts() -> &'static Mutex<HashMap<String, Child>> {
 ///
 /// Returns the number of processes that were terminated.
 pub fn shutdown_managed_hosts() -> usize {
-    let mut children = {
+    let children = {
         let Ok(mut hosts) = managed_hosts().lock() else {
             log::error!("Managed RPC host registry is poisoned");
             return 0;
@@ -42,47 +44,68 @@ pub fn shutdown_managed_hosts() -> usize {
         std::mem::take(&mut *hosts)
     };
 
-    let mut stopped = 0;
-    for (socket_id, mut child) in children.drain() {
-        match child.try_wait() {
-            Ok(Some(status)) => {
-                log::info!(
-                    "RPC host for '{}' already exited before shutdown ({})",
+    children
+        .into_iter()
+        .map(|(socket_id, mut child)| stop_child(&socket_id, &mut child))
+        .filter(|stopped| *stopped)
+        .count()
+}
+
+/// Stops the RPC host process DBFlux started for `socket_id`, if any.
+///
+/// Returns `true` when a running process was terminated.
+pub fn stop_managed_host(socket_id: &str) -> bool {
+    let child = {
+        let Ok(mut hosts) = managed_hosts().lock() else {
+            log::error!("Managed RPC host registry is poisoned");
+            return false;
+        };
+
+        hosts.remove(socket_id)
+    };
+
+    child.is_some_and(|mut child| stop_child(socket_id, &mut child))
+}
+
+fn stop_child(socket_id: &str, child: &mut Child) -> bool {
+    match child.try_wait() {
+        Ok(Some(status)) => {
+            log::info!(
+                "RPC host for '{}' already exited before shutdown ({})",
+                socket_id,
+                status
+            );
+            false
+        }
+        Ok(None) => {
+            if let Err(error) = child.kill() {
+                log::warn!(
+                    "Failed to kill managed RPC host for '{}': {}",
                     socket_id,
-                    status
+                    error
                 );
+                return false;
             }
-            Ok(None) => {
-                if let Err(error) = child.kill() {
-                    log::warn!(
-                        "Failed to kill managed RPC host for '{}': {}",
-                        socket_id,
-                        error
-                    );
-                    continue;
-                }
 
-                if let Err(error) = child.wait() {
-                    log::warn!(
-                        "Failed to wait for managed RPC host '{}' after kill: {}",
-                        socket_id,
-                        error
-                    );
-                }
-
-                stopped += 1;
-            }
-            Err(error) => {
+            if let Err(error) = child.wait() {
                 log::warn!(
-                    "Failed to inspect managed RPC host for '{}': {}",
+                    "Failed to wait for managed RPC host '{}' after kill: {}",
                     socket_id,
                     error
                 );
             }
+
+            true
+        }
+        Err(error) => {
+            log::warn!(
+                "Failed to inspect managed RPC host for '{}': {}",
+                socket_id,
+                error
+            );
+            false
         }
     }
-
-    stopped
 }
 
 /// An IPC-based driver that proxies all operations to a remote driver-host process.
@@ -101,6 +124,8 @@ pub struct IpcDriver {
     form_definition: DriverFormDef,
     settings_schema: Option<Arc<DriverFormDef>>,
     launch: Option<IpcDriverLaunchConfig>,
+    /// Set when the host runs on another machine and is reached over TCP.
+    remote: Option<RemoteServiceConfig>,
     /// Audit emitter passed to each `RpcClient` connection for intercepting
     /// `EmitAuditEvent` frames from the driver host.
     audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
@@ -136,6 +161,7 @@ impl IpcDriver {
             form_definition,
             settings_schema: settings_schema.map(Arc::new),
             launch: None,
+            remote: None,
             audit_emitter: None,
         }
     }
@@ -145,6 +171,12 @@ impl IpcDriver {
         self
     }
 
+    /// Reaches the driver host over TCP at `remote` instead of the local socket.
+    pub fn with_remote(mut self, remote: RemoteServiceConfig) -> Self {
+        self.remote = Some(remote);
+        self
+    }
+
     /// Attaches an audit emitter for routing `EmitAuditEvent` frames from the driver host.
     ///
     /// Each `RpcClient` built by this driver's connection methods will receive a clone
@@ -229,6 +261,37 @@ impl IpcDriver {
         Self::parse_socket_name(socket_id).map(|_| ())
     }
 
+    /// Checks the parts of a remote endpoint that can be checked offline.
+    #[allow(clippy::result_large_err)]
+    pub fn validate_remote(remote: &RemoteServiceConfig) -> Result<(), DbError> {
+        let port = remote
+            .address
+            .rsplit_once(':')
+            .and_then(|(host, port)| (!host.is_empty()).then_some(port));
+
+        if port.is_none_or(|port| port.parse::<u16>().is_err()) {
+            return Err(DbError::ConnectionFailed(
+                format!(
+                    "Invalid remote address '{}': expected host:port",
+                    remote.address
+                )
+                .into(),
+            ));
+        }
+
+        if remote.token_env.trim().is_empty() {
+            return Err(DbError::ConnectionFailed(
+                format!(
+                    "Remote driver host '{}' needs the environment variable holding its token",
+                    remote.address
+                )
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn probe_driver(
         socket_id: &str,
@@ -239,14 +302,31 @@ impl IpcDriver {
         let name = Self::parse_socket_name(socket_id)?;
 
         let client = RpcClient::connect(name).map_err(DbError::from)?;
+        Ok(Self::probe_result(&client))
+    }
+
+    /// [`Self::probe_driver`] for a host on another machine.
+    #[allow(clippy::result_large_err)]
+    pub fn probe_remote_driver(
+        socket_id: &str,
+        remote: &RemoteServiceConfig,
+    ) -> Result<(DbKind, DriverMetadata, DriverFormDef, Option<DriverFormDef>), DbError> {
+        let client = RpcClient::connect_remote(remote, socket_id.to_string(), None)
+            .map_err(DbError::from)?;
+        Ok(Self::probe_result(&client))
+    }
+
+    fn probe_result(
+        client: &RpcClient,
+    ) -> (DbKind, DriverMetadata, DriverFormDef, Option<DriverFormDef>) {
         let hello = client.hello_response();
 
-        Ok((
+        (
             hello.driver_kind,
             hello.driver_metadata.clone(),
             hello.form_definition.clone(),
             hello.settings_schema.clone(),
-        ))
+        )
     }
 
     #[allow(clippy::result_large_err)]
@@ -436,6 +516,53 @@ impl IpcDriver {
         Self::ensure_host_running_for(&self.socket_id, self.launch.as_ref())
     }
 
+    /// Connects a client to this driver's host, starting a managed local host
+    /// first if needed.
+    #[allow(clippy::result_large_err)]
+    fn connect_client(&self) -> Result<RpcClient, DbError> {
+        if let Some(remote) = &self.remote {
+            return RpcClient::connect_remote(
+                remote,
+                self.socket_id.clone(),
+                self.audit_emitter.clone(),
+            )
+            .map_err(DbError::from);
+        }
+
+        self.ensure_host_running()?;
+
+        let name = Self::parse_socket_name(&self.socket_id)?;
+
+        RpcClient::connect_with_audit(name, self.socket_id.clone(), self.audit_emitter.clone())
+            .map_err(DbError::from)
+    }
+
+    /// Restarts this driver's host, if it is one DBFlux launches, and connects
+    /// to it again. Used by connections to recover from a host crash; remote
+    /// hosts are only reconnected to.
+    fn reconnect(&self) -> Reconnect {
+        if let Some(remote) = self.remote.clone() {
+            return Box::new(move || {
+                DriverStream::connect_remote(&remote)
+                    .map_err(|e| RpcError::ConnectionFailed(e.to_string()))
+            });
+        }
+
+        let socket_id = self.socket_id.clone();
+        let launch = self.launch.clone();
+
+        Box::new(move || {
+            Self::ensure_host_running_for(&socket_id, launch.as_ref())
+                .map_err(|e| RpcError::ConnectionFailed(e.to_string()))?;
+            let name = Self::parse_socket_name(&socket_id)
+                .map_err(|e| RpcError::ConnectionFailed(e.to_string()))?;
+
+            IpcStream::connect(name)
+                .map(DriverStream::Local)
+                .map_err(|e| RpcError::ConnectionFailed(e.to_string()))
+        })
+    }
+
     fn missing_default_host_flag_value(flag: &str) -> DbError {
         DbError::ConnectionFailed(
             format!(
@@ -714,13 +841,7 @@ impl dbflux_core::DbDriver for IpcDriver {
         password: Option<&SecretString>,
         ssh_secret: Option<&SecretString>,
     ) -> Result<Box<dyn dbflux_core::Connection>, DbError> {
-        self.ensure_host_running()?;
-
-        let name = Self::parse_socket_name(&self.socket_id)?;
-
-        let client =
-            RpcClient::connect_with_audit(name, self.socket_id.clone(), self.audit_emitter.clone())
-                .map_err(DbError::from)?;
+        let client = self.connect_client()?.with_reconnect(self.reconnect());
 
         let profile_json = serde_json::to_string(profile)
             .map_err(|e| DbError::InvalidProfile(format!("JSON serialization failed: {e}")))?;
@@ -762,13 +883,7 @@ impl dbflux_core::DbDriver for IpcDriver {
     }
 
     fn test_connection(&self, profile: &ConnectionProfile) -> Result<(), DbError> {
-        self.ensure_host_running()?;
-
-        let name = Self::parse_socket_name(&self.socket_id)?;
-
-        let client =
-            RpcClient::connect_with_audit(name, self.socket_id.clone(), self.audit_emitter.clone())
-                .map_err(DbError::from)?;
+        let client = self.connect_client()?;
 
         let profile_json = serde_json::to_string(profile)
             .map_err(|e| DbError::InvalidProfile(format!("JSON serialization failed: {e}")))?;
@@ -972,6 +1087,22 @@ mod tests {
         assert!(error.to_string().contains("at least 1 ms"));
     }
 
+    #[test]
+    fn validate_remote_requires_host_port_and_token_env() {
+        let remote = |address: &str, token_env: &str| RemoteServiceConfig {
+            address: address.to_string(),
+            tls: false,
+            token_env: token_env.to_string(),
+        };
+
+        assert!(IpcDriver::validate_remote(&remote("db-jump:7400", "HOST_TOKEN")).is_ok());
+        assert!(IpcDriver::validate_remote(&remote("[::1]:7400", "HOST_TOKEN")).is_ok());
+        assert!(IpcDriver::validate_remote(&remote("db-jump", "HOST_TOKEN")).is_err());
+        assert!(IpcDriver::validate_remote(&remote(":7400", "HOST_TOKEN")).is_err());
+        assert!(IpcDriver::validate_remote(&remote("db-jump:port", "HOST_TOKEN")).is_err());
+        assert!(IpcDriver::validate_remote(&remote("db-jump:7400", " ")).is_err());
+    }
+
     #[test]
     fn startup_output_tail_keeps_recent_stdout_and_stderr_lines() {
         let stdout = (1..=8)
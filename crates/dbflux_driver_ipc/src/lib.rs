@@ -1,7 +1,9 @@
 pub mod connection;
 pub mod driver;
+pub mod stream;
 pub mod transport;
 
 pub use connection::IpcConnection;
 pub use driver::{IpcDriver, shutdown_managed_hosts};
-pub use transport::RpcClient;
+pub use stream::DriverStream;
+pub use transport::{RpcClient, RpcLimits};
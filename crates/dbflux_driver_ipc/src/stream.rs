@@ -0,0 +1,212 @@
+//! Byte streams the driver protocol runs over: the local socket for hosts on
+//! this machine, TCP (optionally TLS) for hosts elsewhere.
+
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use dbflux_core::RemoteServiceConfig;
+use interprocess::local_socket::Stream as IpcStream;
+use native_tls::{TlsConnector, TlsStream};
+
+/// How long to wait for a remote driver host to accept the TCP connection.
+const REMOTE_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub enum DriverStream {
+    Local(IpcStream),
+    Tcp(TcpStream),
+    Tls(Box<TlsStream<TcpStream>>),
+}
+
+impl DriverStream {
+    /// Connects to the driver host at `remote`, completing the TLS handshake
+    /// when it asks for one.
+    pub fn connect_remote(remote: &RemoteServiceConfig) -> io::Result<Self> {
+        let address = remote.address.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("'{}' did not resolve to an address", remote.address),
+            )
+        })?;
+
+        let stream = TcpStream::connect_timeout(&address, REMOTE_CONNECT_TIMEOUT)?;
+        stream.set_nodelay(true)?;
+
+        if !remote.tls {
+            return Ok(Self::Tcp(stream));
+        }
+
+        let connector = TlsConnector::new().map_err(io::Error::other)?;
+        let stream = connector
+            .connect(remote_host(&remote.address), stream)
+            .map_err(|e| io::Error::other(format!("TLS handshake failed: {e}")))?;
+
+        Ok(Self::Tls(Box::new(stream)))
+    }
+
+    /// Whether the stream crosses the network, where compressing large
+    /// responses pays off.
+    pub fn is_network(&self) -> bool {
+        !matches!(self, Self::Local(_))
+    }
+
+    /// Switches reads and writes between blocking and nonblocking, so the
+    /// client can check for event frames without waiting for one.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        match self {
+            Self::Local(stream) => {
+                interprocess::local_socket::traits::Stream::set_nonblocking(stream, nonblocking)
+            }
+            Self::Tcp(stream) => stream.set_nonblocking(nonblocking),
+            Self::Tls(stream) => stream.get_ref().set_nonblocking(nonblocking),
+        }
+    }
+
+    /// Limits how long a blocking read waits for data; `None` waits forever.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        match self {
+            Self::Local(stream) => {
+                interprocess::local_socket::traits::Stream::set_recv_timeout(stream, timeout)
+            }
+            Self::Tcp(stream) => stream.set_read_timeout(timeout),
+            Self::Tls(stream) => stream.get_ref().set_read_timeout(timeout),
+        }
+    }
+
+    /// Runs `read`, which reads one frame, waiting for the frame to start
+    /// only until `deadline`. A frame that started in time is read to its
+    /// end, as long as the host never pauses longer than `stall` within it.
+    ///
+    /// Missing the deadline fails with [`io::ErrorKind::TimedOut`] and leaves
+    /// the stream at a frame boundary. A stall partway through the frame
+    /// fails with [`io::ErrorKind::ConnectionAborted`]: the stream is then
+    /// out of sync and must not be read again.
+    pub(crate) fn read_before<T>(
+        &mut self,
+        deadline: Instant,
+        stall: Duration,
+        read: impl FnOnce(&mut DeadlineReader<'_>) -> io::Result<T>,
+    ) -> io::Result<T> {
+        let result = read(&mut DeadlineReader {
+            stream: self,
+            deadline,
+            stall,
+            started: false,
+        });
+        self.set_read_timeout(None)?;
+
+        result
+    }
+}
+
+/// A [`DriverStream`] whose reads time out at a deadline until data arrives,
+/// then after `stall` without data.
+pub(crate) struct DeadlineReader<'a> {
+    stream: &'a mut DriverStream,
+    deadline: Instant,
+    stall: Duration,
+    started: bool,
+}
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let wait = if self.started {
+            self.stall
+        } else {
+            self.deadline.saturating_duration_since(Instant::now())
+        };
+
+        // A zero read timeout is rejected rather than meaning "don't wait".
+        if wait.is_zero() {
+            return Err(timed_out());
+        }
+        self.stream.set_read_timeout(Some(wait))?;
+
+        loop {
+            match self.stream.read(buf) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                // Unix reports an expired read timeout as `WouldBlock`.
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    return Err(if self.started {
+                        io::Error::new(
+                            io::ErrorKind::ConnectionAborted,
+                            "driver host stopped partway through a response",
+                        )
+                    } else {
+                        timed_out()
+                    });
+                }
+                Ok(read) => {
+                    self.started |= read > 0;
+                    return Ok(read);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+fn timed_out() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::TimedOut,
+        "driver host did not respond in time",
+    )
+}
+
+impl Read for DriverStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Local(stream) => stream.read(buf),
+            Self::Tcp(stream) => stream.read(buf),
+            Self::Tls(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for DriverStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Local(stream) => stream.write(buf),
+            Self::Tcp(stream) => stream.write(buf),
+            Self::Tls(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Local(stream) => stream.flush(),
+            Self::Tcp(stream) => stream.flush(),
+            Self::Tls(stream) => stream.flush(),
+        }
+    }
+}
+
+/// Host part of a `host:port` address, the name the TLS certificate must
+/// carry. IPv6 literals lose their brackets.
+pub(crate) fn remote_host(address: &str) -> &str {
+    let host = address
+        .rsplit_once(':')
+        .map_or(address, |(host, _port)| host);
+
+    host.strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remote_host_strips_port_and_ipv6_brackets() {
+        assert_eq!(remote_host("db-jump.internal:7400"), "db-jump.internal");
+        assert_eq!(remote_host("10.0.0.5:7400"), "10.0.0.5");
+        assert_eq!(remote_host("[::1]:7400"), "::1");
+        assert_eq!(remote_host("db-jump"), "db-jump");
+    }
+}
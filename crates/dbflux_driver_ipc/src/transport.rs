@@ -1,19 +1,29 @@
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
-
-use dbflux_core::DbError;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Once, Weak};
+use std::time::{Duration, Instant};
+
+use dbflux_core::secrecy::{ExposeSecret, SecretString};
+use dbflux_core::{
+    ConnectionEvent, ConnectionEventKind, ConnectionEventSink, DbError, RemoteServiceConfig,
+};
 use dbflux_ipc::{
-    DRIVER_RPC_AUTH_TOKEN_ENV, DRIVER_RPC_VERSION, ExternalAuditEmitter, ExternalAuditSource,
-    ProtocolVersion, RpcApiFamily,
+    AuditEventEmitDto, DRIVER_RPC_AUTH_TOKEN_ENV, DRIVER_RPC_V1_3, DRIVER_RPC_VERSION,
+    EVENT_REQUEST_ID, ExternalAuditEmitter, ExternalAuditSource, ProtocolVersion, RpcApiFamily,
+    compression_capabilities,
     driver_protocol::{
         DriverCapability, DriverHelloRequest, DriverHelloResponse, DriverRequestBody,
-        DriverRequestEnvelope, DriverResponseBody, DriverResponseEnvelope,
+        DriverRequestEnvelope, DriverResponseBody, DriverResponseEnvelope, DriverRpcError,
+        DriverRpcErrorCode,
     },
     driver_rpc_supported_versions, framing,
 };
 use interprocess::local_socket::{Name, Stream as IpcStream, prelude::*};
 use uuid::Uuid;
 
+use crate::stream::DriverStream;
+
 /// Holds the mutable transport state protected by a single mutex.
 ///
 /// Both the stream and the request-ID counter live here so that ID assignment
@@ -27,8 +37,86 @@ use uuid::Uuid;
 /// `session_correlation_ids`. The reverse order (`session_correlation_ids →
 /// inner`) never occurs, so there is no lock-ordering cycle.
 struct RpcClientInner {
-    stream: IpcStream,
+    stream: DriverStream,
     next_id: u64,
+    /// Set when a read failed partway through a frame, so the stream is no
+    /// longer at a frame boundary. Nothing more is read from it until
+    /// [`RpcClient::recover`] replaces it.
+    broken: bool,
+}
+
+/// How often the event pump checks an idle connection for event frames.
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Extra time a query with a `statement_timeout` gets to report that it
+/// timed out before the client stops waiting.
+const STATEMENT_TIMEOUT_GRACE: Duration = Duration::from_secs(30);
+
+/// What the client tolerates from a driver host before giving up on a
+/// request, so a misbehaving host cannot hang its callers or exhaust memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpcLimits {
+    /// Longest wait for the answer to a metadata or control request (schema,
+    /// sessions, pings, cancellation), and the longest pause allowed inside
+    /// any response once it started arriving.
+    pub request_timeout: Duration,
+    /// Longest wait for a request that runs user work: queries, browsing,
+    /// edits and key-value commands. A query's `statement_timeout` extends it.
+    pub query_timeout: Duration,
+    /// Largest response accepted, measured after decompression.
+    pub max_response_bytes: usize,
+    /// How often a client with open sessions pings its host while idle.
+    /// `None` turns liveness checks off.
+    pub liveness_interval: Option<Duration>,
+}
+
+impl Default for RpcLimits {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(120),
+            query_timeout: Duration::from_secs(30 * 60),
+            max_response_bytes: 128 * 1024 * 1024,
+            liveness_interval: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+/// Event sinks by the id the current host knows their session by. Shared
+/// with the event pump thread; locked after `inner` and `sessions`.
+#[derive(Default)]
+struct EventRouter {
+    sinks: Mutex<HashMap<Uuid, ConnectionEventSink>>,
+}
+
+impl EventRouter {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Uuid, ConnectionEventSink>> {
+        self.sinks.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    fn deliver(&self, session_id: Option<Uuid>, event: ConnectionEvent) {
+        let sink = session_id.and_then(|id| self.lock().get(&id).cloned());
+
+        match sink {
+            Some(sink) => sink(event),
+            None => log::debug!("Dropping event for unsubscribed session {session_id:?}"),
+        }
+    }
+}
+
+/// Brings the driver host back after its socket broke — respawning a managed
+/// host if needed — and returns a fresh stream to it.
+pub type Reconnect = Box<dyn Fn() -> Result<DriverStream, RpcError> + Send + Sync>;
+
+/// What it takes to reopen a session on a restarted driver host.
+struct SessionReplay {
+    /// Id the current host knows the session by.
+    bound_id: Uuid,
+    profile_json: String,
+    password: Option<SecretString>,
+    ssh_secret: Option<SecretString>,
+    active_database: Option<String>,
+    /// Event kinds the session is subscribed to, if any.
+    event_kinds: Option<Vec<ConnectionEventKind>>,
 }
 
 pub struct RpcClient {
@@ -36,12 +124,31 @@ pub struct RpcClient {
     hello: DriverHelloResponse,
     /// Socket registry ID (`rpc:<socket_id>`) for correlation and logging.
     socket_id: String,
+    /// Environment variable holding the `Hello` auth token.
+    auth_token_env: String,
     /// Whether the driver advertised `DriverCapability::AuditEmit` in its hello.
     audit_emit_capability: bool,
     /// Sanitizing sink for audit frames emitted by this driver.
     audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
     /// Per-session correlation IDs, allocated lazily on first audit emit for a session.
     session_correlation_ids: Mutex<HashMap<Uuid, String>>,
+    /// Restarts the host when the socket breaks. Without it, a dead host
+    /// surfaces as an IO error.
+    reconnect: Option<Reconnect>,
+    /// Open sessions by the id they were first opened with, for replaying
+    /// them on a restarted host. Only kept when `reconnect` is set. Locked
+    /// after `inner`, like `session_correlation_ids`.
+    sessions: Mutex<HashMap<Uuid, SessionReplay>>,
+    /// Where event frames go, by session.
+    events: Arc<EventRouter>,
+    /// Starts the thread that reads event frames between requests.
+    event_pump: Once,
+    limits: RpcLimits,
+    /// Set while the host leaves liveness pings unanswered; requests fail
+    /// right away instead of queueing behind it.
+    unresponsive: Arc<AtomicBool>,
+    /// Starts the thread that pings the host while the client is idle.
+    liveness: Once,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -60,18 +167,83 @@ pub enum RpcError {
     UnsupportedMethod(String),
     #[error("timeout")]
     Timeout,
+    #[error("cancelled")]
+    Cancelled,
+    #[error("driver host restarted while the request was running")]
+    HostRestarted,
+    #[error("driver host did not respond within {} s", .0.as_secs())]
+    ResponseTimeout(Duration),
+    #[error("driver response exceeds the {limit} byte limit")]
+    ResponseTooLarge { limit: usize },
+    #[error("driver host is not responding")]
+    HostUnresponsive,
+}
+
+impl RpcError {
+    /// The protocol error code this error corresponds to.
+    pub fn code(&self) -> DriverRpcErrorCode {
+        match self {
+            RpcError::ConnectionFailed(_)
+            | RpcError::Io(_)
+            | RpcError::HostRestarted
+            | RpcError::ResponseTooLarge { .. } => DriverRpcErrorCode::Transport,
+            RpcError::Protocol(_) => DriverRpcErrorCode::InvalidRequest,
+            RpcError::SessionNotFound => DriverRpcErrorCode::SessionNotFound,
+            RpcError::Driver(_) => DriverRpcErrorCode::Driver,
+            RpcError::UnsupportedMethod(_) => DriverRpcErrorCode::UnsupportedMethod,
+            RpcError::Timeout | RpcError::ResponseTimeout(_) | RpcError::HostUnresponsive => {
+                DriverRpcErrorCode::Timeout
+            }
+            RpcError::Cancelled => DriverRpcErrorCode::Cancelled,
+        }
+    }
+}
+
+impl From<DriverRpcError> for RpcError {
+    fn from(error: DriverRpcError) -> Self {
+        match error.code {
+            DriverRpcErrorCode::UnsupportedMethod => RpcError::UnsupportedMethod(error.message),
+            DriverRpcErrorCode::SessionNotFound => RpcError::SessionNotFound,
+            DriverRpcErrorCode::Timeout => RpcError::Timeout,
+            DriverRpcErrorCode::Cancelled => RpcError::Cancelled,
+            DriverRpcErrorCode::Transport => RpcError::ConnectionFailed(error.message),
+            DriverRpcErrorCode::InvalidRequest | DriverRpcErrorCode::VersionMismatch => {
+                RpcError::Protocol(error.message)
+            }
+            DriverRpcErrorCode::Driver | DriverRpcErrorCode::Internal => {
+                RpcError::Driver(error.message)
+            }
+        }
+    }
 }
 
 impl From<RpcError> for DbError {
     fn from(err: RpcError) -> Self {
         match err {
             RpcError::SessionNotFound => DbError::QueryFailed("Session not found".into()),
-            RpcError::Timeout => DbError::Timeout,
+            RpcError::Timeout | RpcError::ResponseTimeout(_) => DbError::Timeout,
+            RpcError::Cancelled => DbError::Cancelled,
             RpcError::Driver(msg) => DbError::QueryFailed(msg.into()),
             RpcError::UnsupportedMethod(msg) => DbError::NotSupported(msg),
             RpcError::Protocol(msg) => DbError::QueryFailed(msg.into()),
             RpcError::ConnectionFailed(msg) => DbError::ConnectionFailed(msg.into()),
             RpcError::Io(e) => DbError::IoError(e),
+            RpcError::HostRestarted => DbError::QueryFailed(
+                "Driver restarted: the driver host exited while the request was running. \
+                 The connection was reopened; run the request again."
+                    .into(),
+            ),
+            RpcError::ResponseTooLarge { limit } => DbError::QueryFailed(
+                format!(
+                    "The driver response is larger than the {} MiB limit; \
+                     fetch fewer rows at a time.",
+                    limit / (1024 * 1024)
+                )
+                .into(),
+            ),
+            RpcError::HostUnresponsive => DbError::ConnectionFailed(
+                "The driver host is not responding. Requests resume once it answers again.".into(),
+            ),
         }
     }
 }
@@ -94,8 +266,48 @@ impl RpcClient {
         let stream =
             IpcStream::connect(name).map_err(|e| RpcError::ConnectionFailed(e.to_string()))?;
 
-        let mut inner = RpcClientInner { stream, next_id: 0 };
-        let hello = Self::perform_hello(&mut inner)?;
+        Self::handshake(
+            DriverStream::Local(stream),
+            socket_id,
+            DRIVER_RPC_AUTH_TOKEN_ENV.to_string(),
+            audit_emitter,
+        )
+    }
+
+    /// Connects to a driver host on another machine over TCP (or TLS), sending
+    /// the token from `remote.token_env` in the Hello handshake.
+    pub fn connect_remote(
+        remote: &RemoteServiceConfig,
+        socket_id: String,
+        audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
+    ) -> Result<Self, RpcError> {
+        if std::env::var(&remote.token_env).is_err() {
+            return Err(RpcError::ConnectionFailed(format!(
+                "{} is not set; it must hold the token of the driver host at {}",
+                remote.token_env, remote.address
+            )));
+        }
+
+        let stream = DriverStream::connect_remote(remote).map_err(|e| {
+            RpcError::ConnectionFailed(format!("cannot reach {}: {e}", remote.address))
+        })?;
+
+        Self::handshake(stream, socket_id, remote.token_env.clone(), audit_emitter)
+    }
+
+    fn handshake(
+        stream: DriverStream,
+        socket_id: String,
+        auth_token_env: String,
+        audit_emitter: Option<Arc<dyn ExternalAuditEmitter>>,
+    ) -> Result<Self, RpcError> {
+        let limits = RpcLimits::default();
+        let mut inner = RpcClientInner {
+            stream,
+            next_id: 0,
+            broken: false,
+        };
+        let hello = Self::perform_hello(&mut inner, &auth_token_env, &limits)?;
 
         let audit_emit_capability = hello.capabilities.contains(&DriverCapability::AuditEmit);
 
@@ -103,14 +315,46 @@ impl RpcClient {
             inner: Arc::new(Mutex::new(inner)),
             hello,
             socket_id,
+            auth_token_env,
             audit_emit_capability,
             audit_emitter,
             session_correlation_ids: Mutex::new(HashMap::new()),
+            reconnect: None,
+            sessions: Mutex::new(HashMap::new()),
+            events: Arc::new(EventRouter::default()),
+            event_pump: Once::new(),
+            limits,
+            unresponsive: Arc::new(AtomicBool::new(false)),
+            liveness: Once::new(),
         };
 
         Ok(client)
     }
 
+    /// Lets the client survive a driver host crash.
+    ///
+    /// When the socket breaks, `reconnect` brings a host back, the Hello
+    /// handshake and every open session are replayed, and later calls go to
+    /// the new sessions under their original ids. The request that was
+    /// running fails with [`RpcError::HostRestarted`]; a request that could
+    /// not be sent at all is sent again on the new host.
+    pub fn with_reconnect(mut self, reconnect: Reconnect) -> Self {
+        self.reconnect = Some(reconnect);
+        self
+    }
+
+    /// Replaces the default [`RpcLimits`].
+    pub fn with_limits(mut self, limits: RpcLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Whether the host answered its last liveness ping. Requests fail with
+    /// [`RpcError::HostUnresponsive`] while it does not.
+    pub fn is_responsive(&self) -> bool {
+        !self.unresponsive.load(Ordering::Relaxed)
+    }
+
     pub fn hello_response(&self) -> &DriverHelloResponse {
         &self.hello
     }
@@ -137,23 +381,32 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::SemanticPlan { plan } => Ok(plan),
-            DriverResponseBody::Error(error) => match error.code {
-                dbflux_ipc::driver_protocol::DriverRpcErrorCode::UnsupportedMethod => {
-                    Err(RpcError::UnsupportedMethod(error.message))
-                }
-                _ => Err(RpcError::Driver(error.message)),
-            },
+            DriverResponseBody::Error(error) => Err(error.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to PlanSemantic".into(),
             )),
         }
     }
 
-    fn perform_hello(inner: &mut RpcClientInner) -> Result<DriverHelloResponse, RpcError> {
-        let auth_token = std::env::var(DRIVER_RPC_AUTH_TOKEN_ENV)
+    fn perform_hello(
+        inner: &mut RpcClientInner,
+        auth_token_env: &str,
+        limits: &RpcLimits,
+    ) -> Result<DriverHelloResponse, RpcError> {
+        let auth_token = std::env::var(auth_token_env)
             .ok()
             .filter(|token| !token.is_empty());
 
+        let mut requested_capabilities = vec![
+            DriverCapability::Cancellation,
+            DriverCapability::ChunkedResults,
+            DriverCapability::SchemaIntrospection,
+            DriverCapability::MultiDatabase,
+        ];
+        if inner.stream.is_network() {
+            requested_capabilities.extend(compression_capabilities());
+        }
+
         let request = DriverRequestEnvelope::new(
             DRIVER_RPC_VERSION,
             0,
@@ -161,18 +414,15 @@ impl RpcClient {
                 client_name: "dbflux_driver_ipc".to_string(),
                 client_version: env!("CARGO_PKG_VERSION").to_string(),
                 supported_versions: driver_rpc_supported_versions().to_vec(),
-                requested_capabilities: vec![
-                    DriverCapability::Cancellation,
-                    DriverCapability::ChunkedResults,
-                    DriverCapability::SchemaIntrospection,
-                    DriverCapability::MultiDatabase,
-                ],
+                requested_capabilities,
                 auth_token,
             }),
         );
 
         framing::send_msg(&mut inner.stream, &request)?;
-        let response: DriverResponseEnvelope = framing::recv_msg(&mut inner.stream)?;
+        let deadline = Instant::now() + limits.request_timeout;
+        let response = recv_response(inner, deadline, limits)
+            .map_err(|e| read_error(e, limits, limits.request_timeout))?;
 
         if response.request_id != request.request_id {
             return Err(RpcError::Protocol(format!(
@@ -195,7 +445,7 @@ impl RpcClient {
                 );
                 Ok(hello)
             }
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol("Unexpected response to Hello".into())),
         }
     }
@@ -218,8 +468,24 @@ impl RpcClient {
         )?;
 
         match &body {
-            DriverResponseBody::SessionOpened { .. } => Ok(body),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message.clone())),
+            DriverResponseBody::SessionOpened { session_id, .. } => {
+                if self.reconnect.is_some() {
+                    self.lock_sessions().insert(
+                        *session_id,
+                        SessionReplay {
+                            bound_id: *session_id,
+                            profile_json: profile_json.to_string(),
+                            password: password.map(SecretString::from),
+                            ssh_secret: ssh_secret.map(SecretString::from),
+                            active_database: None,
+                            event_kinds: None,
+                        },
+                    );
+                }
+                self.start_liveness_watch();
+                Ok(body)
+            }
+            DriverResponseBody::Error(e) => Err(e.clone().into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to OpenSession".into(),
             )),
@@ -227,11 +493,91 @@ impl RpcClient {
     }
 
     pub fn close_session(&self, session_id: Uuid) -> Result<(), RpcError> {
-        self.expect_variant(
+        let result = self.expect_variant(
             Some(session_id),
             DriverRequestBody::CloseSession,
             |body| matches!(body, DriverResponseBody::SessionClosed),
             "CloseSession",
+        );
+
+        let bound_id = self.bound_session_id(session_id);
+        self.events.lock().remove(&bound_id);
+        self.lock_sessions().remove(&session_id);
+        result
+    }
+
+    /// Whether the host can forward connection events (driver RPC >= 1.3).
+    pub fn supports_server_events(&self) -> bool {
+        protocol_supports_server_events(self.selected_version())
+    }
+
+    /// Subscribes `session_id` to the events of `kinds`, replacing any
+    /// earlier subscription, and returns the kinds its driver reports.
+    ///
+    /// Events arriving during a call are delivered before it returns; between
+    /// calls a background thread picks them up. `sink` runs on whichever
+    /// thread reads the frame, with the transport locked, so it must not call
+    /// back into this client.
+    pub fn subscribe_events(
+        &self,
+        session_id: Uuid,
+        kinds: &[ConnectionEventKind],
+        sink: ConnectionEventSink,
+    ) -> Result<Vec<ConnectionEventKind>, RpcError> {
+        if !self.supports_server_events() {
+            return Ok(Vec::new());
+        }
+
+        // Registered before the request so events the host sends right after
+        // its response are not dropped.
+        let bound_id = self.bound_session_id(session_id);
+        self.events.lock().insert(bound_id, sink);
+
+        let result = self.call(
+            Some(session_id),
+            DriverRequestBody::SubscribeEvents {
+                kinds: kinds.to_vec(),
+            },
+        );
+
+        let granted = match result {
+            Ok(DriverResponseBody::EventsSubscribed { kinds }) => Ok(kinds),
+            Ok(DriverResponseBody::Error(e)) => Err(e.into()),
+            Ok(_) => Err(RpcError::Protocol(
+                "Unexpected response to SubscribeEvents".into(),
+            )),
+            Err(e) => Err(e),
+        };
+
+        let subscribed = granted.as_ref().ok().filter(|kinds| !kinds.is_empty());
+        if subscribed.is_none() {
+            self.events.lock().remove(&bound_id);
+        } else {
+            self.start_event_pump();
+        }
+        if let Some(replay) = self.lock_sessions().get_mut(&session_id) {
+            replay.event_kinds = subscribed.cloned();
+        }
+
+        granted
+    }
+
+    pub fn unsubscribe_events(&self, session_id: Uuid) -> Result<(), RpcError> {
+        let bound_id = self.bound_session_id(session_id);
+        self.events.lock().remove(&bound_id);
+        if let Some(replay) = self.lock_sessions().get_mut(&session_id) {
+            replay.event_kinds = None;
+        }
+
+        if !self.supports_server_events() {
+            return Ok(());
+        }
+
+        self.expect_variant(
+            Some(session_id),
+            DriverRequestBody::UnsubscribeEvents,
+            |body| matches!(body, DriverResponseBody::EventsUnsubscribed),
+            "UnsubscribeEvents",
         )
     }
 
@@ -248,7 +594,7 @@ impl RpcClient {
         let body = self.call(Some(session_id), DriverRequestBody::Schema)?;
         match body {
             DriverResponseBody::Schema { schema } => Ok(schema),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol("Unexpected response to Schema".into())),
         }
     }
@@ -266,7 +612,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::ExecuteResult { result } => Ok(result.into()),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol("Unexpected response to Execute".into())),
         }
     }
@@ -286,7 +632,7 @@ impl RpcClient {
             DriverResponseBody::ExecuteWithHandleResult { handle_id, result } => {
                 Ok((handle_id, result.into()))
             }
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to ExecuteWithHandle".into(),
             )),
@@ -327,7 +673,7 @@ impl RpcClient {
         let body = self.call(Some(session_id), DriverRequestBody::ListDatabases)?;
         match body {
             DriverResponseBody::Databases { databases } => Ok(databases),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to ListDatabases".into(),
             )),
@@ -348,7 +694,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::SchemaForDatabase { schema } => Ok(schema),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to SchemaForDatabase".into(),
             )),
@@ -373,7 +719,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::TableDetails { table } => Ok(table),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to TableDetails".into(),
             )),
@@ -398,7 +744,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::ViewDetails { view } => Ok(view),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to ViewDetails".into(),
             )),
@@ -417,14 +763,19 @@ impl RpcClient {
             },
             |body| matches!(body, DriverResponseBody::ActiveDatabaseSet),
             "SetActiveDatabase",
-        )
+        )?;
+
+        if let Some(replay) = self.lock_sessions().get_mut(&session_id) {
+            replay.active_database = database.map(|s| s.to_string());
+        }
+        Ok(())
     }
 
     pub fn active_database(&self, session_id: Uuid) -> Result<Option<String>, RpcError> {
         let body = self.call(Some(session_id), DriverRequestBody::ActiveDatabase)?;
         match body {
             DriverResponseBody::ActiveDatabaseResult { database } => Ok(database),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to ActiveDatabase".into(),
             )),
@@ -442,7 +793,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::BrowseResult { result } => Ok(result.into()),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to BrowseTable".into(),
             )),
@@ -458,7 +809,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CountResult { count } => Ok(count),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to CountTable".into(),
             )),
@@ -477,7 +828,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::BrowseResult { result } => Ok(result.into()),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to BrowseCollection".into(),
             )),
@@ -496,7 +847,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CountResult { count } => Ok(count),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to CountCollection".into(),
             )),
@@ -512,7 +863,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::BrowseResult { result } => Ok(result.into()),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol("Unexpected response to Explain".into())),
         }
     }
@@ -529,7 +880,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::BrowseResult { result } => Ok(result.into()),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to DescribeTable".into(),
             )),
@@ -547,7 +898,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CrudResult { result } => Ok(result),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to UpdateRow".into(),
             )),
@@ -563,7 +914,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CrudResult { result } => Ok(result),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to InsertRow".into(),
             )),
@@ -579,7 +930,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CrudResult { result } => Ok(result),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to DeleteRow".into(),
             )),
@@ -600,7 +951,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CrudResult { result } => Ok(result),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to UpdateDocument".into(),
             )),
@@ -619,7 +970,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CrudResult { result } => Ok(result),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to InsertDocument".into(),
             )),
@@ -638,7 +989,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::CrudResult { result } => Ok(result),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to DeleteDocument".into(),
             )),
@@ -663,7 +1014,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::SchemaTypes { types } => Ok(types),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to SchemaTypes".into(),
             )),
@@ -686,7 +1037,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::SchemaIndexes { indexes } => Ok(indexes),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to SchemaIndexes".into(),
             )),
@@ -709,7 +1060,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::SchemaForeignKeys { foreign_keys } => Ok(foreign_keys),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to SchemaForeignKeys".into(),
             )),
@@ -735,7 +1086,7 @@ impl RpcClient {
         let body = self.call(Some(session_id), DriverRequestBody::CodeGenerators)?;
         match body {
             DriverResponseBody::CodeGeneratorsResult { generators } => Ok(generators),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to CodeGenerators".into(),
             )),
@@ -758,7 +1109,7 @@ impl RpcClient {
 
         match body {
             DriverResponseBody::GenerateCodeResult { code } => Ok(code),
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(
                 "Unexpected response to GenerateCode".into(),
             )),
@@ -795,7 +1146,7 @@ impl RpcClient {
         }
 
         match response_body {
-            DriverResponseBody::Error(e) => Err(RpcError::Driver(e.message)),
+            DriverResponseBody::Error(e) => Err(e.into()),
             _ => Err(RpcError::Protocol(format!(
                 "Unexpected response to {label}"
             ))),
@@ -809,29 +1160,89 @@ impl RpcClient {
     /// lock is held across the entire send+receive transaction so that ID assignment
     /// and transport are atomic — no other caller can interleave on the stream.
     ///
-    /// `session_correlation_ids` is acquired inside this function while `inner`
-    /// is already held (`inner → session_correlation_ids`). The reverse order never
-    /// occurs anywhere, so the one-directional ordering is deadlock-free.
+    /// `session_correlation_ids` and `sessions` are acquired inside this function
+    /// while `inner` is already held (`inner → session_correlation_ids`,
+    /// `inner → sessions`). The reverse order never occurs anywhere, so the
+    /// one-directional ordering is deadlock-free.
+    ///
+    /// A broken socket, or a response cut off partway, triggers
+    /// [`Self::recover`]. If the request had not been sent yet it is sent
+    /// again on the new host; if it was waiting for its response it fails
+    /// with [`RpcError::HostRestarted`].
+    ///
+    /// The response must arrive within the request's timeout and fit in
+    /// [`RpcLimits::max_response_bytes`]. The answer to a request that gave up
+    /// waiting is skipped when it arrives late.
     fn send_raw(
         &self,
         mut request: DriverRequestEnvelope,
     ) -> Result<DriverResponseEnvelope, RpcError> {
+        if !self.is_responsive() {
+            return Err(RpcError::HostUnresponsive);
+        }
+
         let request_session_id = request.session_id;
+        let timeout = self.request_timeout(&request.body);
 
         let mut guard = self
             .inner
             .lock()
             .map_err(|_| RpcError::Protocol("RPC client mutex poisoned".into()))?;
 
+        if guard.broken {
+            self.recover(
+                &mut guard,
+                io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "an earlier response from the driver host was cut off",
+                ),
+            )?;
+        }
+
         guard.next_id += 1;
-        let expected_id = guard.next_id;
-        request.request_id = expected_id;
+        request.request_id = guard.next_id;
+        request.session_id = request_session_id.map(|id| self.bound_session_id(id));
+
+        if let Err(error) = framing::send_msg(&mut guard.stream, &request) {
+            self.recover(&mut guard, error)?;
+
+            guard.next_id += 1;
+            request.request_id = guard.next_id;
+            request.session_id = request_session_id.map(|id| self.bound_session_id(id));
+            framing::send_msg(&mut guard.stream, &request).map_err(RpcError::Io)?;
+        }
 
-        framing::send_msg(&mut guard.stream, &request).map_err(RpcError::Io)?;
+        let expected_id = request.request_id;
+        let deadline = Instant::now() + timeout;
 
         loop {
-            let response: DriverResponseEnvelope =
-                framing::recv_msg(&mut guard.stream).map_err(RpcError::Io)?;
+            let response = match recv_response(&mut guard, deadline, &self.limits) {
+                Ok(response) => response,
+                Err(error) if is_disconnect(&error) => {
+                    self.recover(&mut guard, error)?;
+                    return Err(RpcError::HostRestarted);
+                }
+                Err(error) => return Err(read_error(error, &self.limits, timeout)),
+            };
+            let response = response
+                .decompressed()
+                .map_err(|e| RpcError::Protocol(format!("Invalid compressed response: {e}")))?;
+
+            if response.request_id == EVENT_REQUEST_ID
+                && let DriverResponseBody::Event(event) = response.body
+            {
+                self.events.deliver(response.session_id, event);
+                continue;
+            }
+
+            if response.request_id < expected_id {
+                log::debug!(
+                    "Skipping late response to request {} from driver host '{}'",
+                    response.request_id,
+                    self.socket_id
+                );
+                continue;
+            }
 
             if response.request_id != expected_id {
                 return Err(RpcError::Protocol("Request ID mismatch".into()));
@@ -844,20 +1255,10 @@ impl RpcClient {
 
             match response.body {
                 DriverResponseBody::EmitAuditEvent(ref dto) if !response.done => {
-                    if self.audit_emit_capability
-                        && let Some(sink) = &self.audit_emitter
-                    {
-                        let session_id = response.session_id.or(request_session_id);
-                        let correlation_id = self.correlation_id_for_session(session_id);
-                        sink.emit(
-                            ExternalAuditSource::Driver {
-                                socket_id: self.socket_id.clone(),
-                                session_id,
-                                correlation_id,
-                            },
-                            dto.clone(),
-                        );
-                    }
+                    self.forward_audit_event(
+                        response.session_id.or(request_session_id),
+                        dto.clone(),
+                    );
                     // Loop to consume the next frame regardless of capability/emitter.
                     continue;
                 }
@@ -866,6 +1267,261 @@ impl RpcClient {
         }
     }
 
+    /// Replaces a broken stream with one to a restarted host, then replays the
+    /// Hello handshake and every open session on it.
+    ///
+    /// Returns `error` unchanged when the client cannot restart its host or the
+    /// error is not a broken connection.
+    fn recover(&self, inner: &mut RpcClientInner, error: std::io::Error) -> Result<(), RpcError> {
+        let Some(reconnect) = &self.reconnect else {
+            return Err(RpcError::Io(error));
+        };
+
+        if !is_disconnect(&error) {
+            return Err(RpcError::Io(error));
+        }
+
+        log::warn!(
+            "Driver host '{}' went away ({}); restarting it",
+            self.socket_id,
+            error
+        );
+
+        let stream = reconnect().map_err(|e| {
+            RpcError::ConnectionFailed(format!(
+                "driver host exited and could not be restarted: {e}"
+            ))
+        })?;
+
+        *inner = RpcClientInner {
+            stream,
+            next_id: 0,
+            broken: false,
+        };
+        self.unresponsive.store(false, Ordering::Relaxed);
+
+        let hello = Self::perform_hello(inner, &self.auth_token_env, &self.limits)?;
+        if hello.selected_version != self.selected_version() {
+            return Err(RpcError::Protocol(format!(
+                "restarted driver host negotiated protocol {}.{} instead of {}.{}",
+                hello.selected_version.major,
+                hello.selected_version.minor,
+                self.selected_version().major,
+                self.selected_version().minor
+            )));
+        }
+
+        let mut sessions = self.lock_sessions();
+        for (original_id, replay) in sessions.iter_mut() {
+            match self.replay_session(inner, replay) {
+                Ok(bound_id) => {
+                    let mut sinks = self.events.lock();
+                    if let Some(sink) = sinks.remove(&replay.bound_id) {
+                        sinks.insert(bound_id, sink);
+                    }
+                    replay.bound_id = bound_id;
+                }
+                Err(e) => log::warn!(
+                    "Could not reopen session {} on restarted driver host '{}': {}",
+                    original_id,
+                    self.socket_id,
+                    e
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Opens `replay`'s session on a freshly connected host and restores its
+    /// active database. Returns the new session id.
+    fn replay_session(
+        &self,
+        inner: &mut RpcClientInner,
+        replay: &SessionReplay,
+    ) -> Result<Uuid, RpcError> {
+        let body = self.exchange(
+            inner,
+            None,
+            DriverRequestBody::OpenSession {
+                profile_json: replay.profile_json.clone(),
+                password: replay
+                    .password
+                    .as_ref()
+                    .map(|value| value.expose_secret().to_string()),
+                ssh_secret: replay
+                    .ssh_secret
+                    .as_ref()
+                    .map(|value| value.expose_secret().to_string()),
+            },
+        )?;
+
+        let session_id = match body {
+            DriverResponseBody::SessionOpened { session_id, .. } => session_id,
+            DriverResponseBody::Error(e) => return Err(e.into()),
+            _ => {
+                return Err(RpcError::Protocol(
+                    "Unexpected response to OpenSession".into(),
+                ));
+            }
+        };
+
+        if replay.active_database.is_some() {
+            self.exchange(
+                inner,
+                Some(session_id),
+                DriverRequestBody::SetActiveDatabase {
+                    database: replay.active_database.clone(),
+                },
+            )?;
+        }
+
+        if let Some(kinds) = &replay.event_kinds {
+            self.exchange(
+                inner,
+                Some(session_id),
+                DriverRequestBody::SubscribeEvents {
+                    kinds: kinds.clone(),
+                },
+            )?;
+        }
+
+        Ok(session_id)
+    }
+
+    /// One request/response on `inner` for recovery, which already holds the
+    /// lock `send_raw` would take.
+    fn exchange(
+        &self,
+        inner: &mut RpcClientInner,
+        session_id: Option<Uuid>,
+        body: DriverRequestBody,
+    ) -> Result<DriverResponseBody, RpcError> {
+        inner.next_id += 1;
+        let request =
+            build_call_request_envelope(self.selected_version(), inner.next_id, body, session_id);
+
+        framing::send_msg(&mut inner.stream, &request)?;
+
+        let timeout = self.request_timeout(&request.body);
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let response = recv_response(inner, deadline, &self.limits)
+                .map_err(|e| read_error(e, &self.limits, timeout))?;
+            let response = response
+                .decompressed()
+                .map_err(|e| RpcError::Protocol(format!("Invalid compressed response: {e}")))?;
+
+            if response.request_id == EVENT_REQUEST_ID
+                && let DriverResponseBody::Event(event) = response.body
+            {
+                self.events.deliver(response.session_id, event);
+                continue;
+            }
+
+            if response.request_id != request.request_id {
+                return Err(RpcError::Protocol("Request ID mismatch".into()));
+            }
+
+            match response.body {
+                DriverResponseBody::EmitAuditEvent(dto) if !response.done => {
+                    self.forward_audit_event(response.session_id.or(session_id), dto);
+                }
+                body => return Ok(body),
+            }
+        }
+    }
+
+    fn forward_audit_event(&self, session_id: Option<Uuid>, dto: AuditEventEmitDto) {
+        if !self.audit_emit_capability {
+            return;
+        }
+
+        if let Some(sink) = &self.audit_emitter {
+            let correlation_id = self.correlation_id_for_session(session_id);
+            sink.emit(
+                ExternalAuditSource::Driver {
+                    socket_id: self.socket_id.clone(),
+                    session_id,
+                    correlation_id,
+                },
+                dto,
+            );
+        }
+    }
+
+    /// Spawns the event pump the first time a session subscribes. It holds
+    /// the transport weakly and exits once the client is dropped.
+    fn start_event_pump(&self) {
+        self.event_pump.call_once(|| {
+            let inner = Arc::downgrade(&self.inner);
+            let events = Arc::clone(&self.events);
+
+            let spawned = std::thread::Builder::new()
+                .name(format!("driver-events-{}", self.socket_id))
+                .spawn(move || pump_events(inner, events));
+
+            if let Err(e) = spawned {
+                log::warn!("Could not start the driver event pump: {e}");
+            }
+        });
+    }
+
+    /// Spawns the liveness watch the first time a session opens. Like the
+    /// event pump, it holds the transport weakly and exits with the client.
+    fn start_liveness_watch(&self) {
+        if self.limits.liveness_interval.is_none() {
+            return;
+        }
+
+        self.liveness.call_once(|| {
+            let watch = LivenessWatch {
+                inner: Arc::downgrade(&self.inner),
+                events: Arc::clone(&self.events),
+                unresponsive: Arc::clone(&self.unresponsive),
+                version: self.selected_version(),
+                limits: self.limits,
+                socket_id: self.socket_id.clone(),
+            };
+
+            let spawned = std::thread::Builder::new()
+                .name(format!("driver-liveness-{}", self.socket_id))
+                .spawn(move || watch.run());
+
+            if let Err(e) = spawned {
+                log::warn!("Could not start the driver liveness watch: {e}");
+            }
+        });
+    }
+
+    /// How long `body` may wait for its response.
+    fn request_timeout(&self, body: &DriverRequestBody) -> Duration {
+        match body {
+            DriverRequestBody::Execute { request }
+            | DriverRequestBody::ExecuteWithHandle { request } => request
+                .statement_timeout_ms
+                .map(|ms| Duration::from_millis(ms) + STATEMENT_TIMEOUT_GRACE)
+                .map_or(self.limits.query_timeout, |statement| {
+                    statement.max(self.limits.query_timeout)
+                }),
+            body if is_control_request(body) => self.limits.request_timeout,
+            _ => self.limits.query_timeout,
+        }
+    }
+
+    /// The id the current host knows `session_id` by.
+    fn bound_session_id(&self, session_id: Uuid) -> Uuid {
+        self.lock_sessions()
+            .get(&session_id)
+            .map(|replay| replay.bound_id)
+            .unwrap_or(session_id)
+    }
+
+    fn lock_sessions(&self) -> std::sync::MutexGuard<'_, HashMap<Uuid, SessionReplay>> {
+        self.sessions.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
     fn correlation_id_for_session(&self, session_id: Option<Uuid>) -> String {
         let Some(session_id) = session_id else {
             return Uuid::new_v4().to_string();
@@ -881,11 +1537,255 @@ impl RpcClient {
     }
 }
 
+/// Pings an idle client's host and flags it unresponsive while the pings go
+/// unanswered. A dead host is left to the next request, which restarts it.
+struct LivenessWatch {
+    inner: Weak<Mutex<RpcClientInner>>,
+    events: Arc<EventRouter>,
+    unresponsive: Arc<AtomicBool>,
+    version: ProtocolVersion,
+    limits: RpcLimits,
+    socket_id: String,
+}
+
+impl LivenessWatch {
+    fn run(self) {
+        let Some(interval) = self.limits.liveness_interval else {
+            return;
+        };
+
+        loop {
+            std::thread::sleep(interval);
+
+            let Some(inner) = self.inner.upgrade() else {
+                return;
+            };
+            // A request holding the transport is still being answered.
+            let Ok(mut guard) = inner.try_lock() else {
+                continue;
+            };
+
+            match self.ping(&mut guard) {
+                Ok(()) => {
+                    if self.unresponsive.swap(false, Ordering::Relaxed) {
+                        log::info!("Driver host '{}' is responding again", self.socket_id);
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                    if !self.unresponsive.swap(true, Ordering::Relaxed) {
+                        log::warn!(
+                            "Driver host '{}' did not answer a liveness ping within {} s; \
+                             failing its requests until it does",
+                            self.socket_id,
+                            self.limits.request_timeout.as_secs()
+                        );
+                    }
+                }
+                Err(e) => {
+                    // A host that went away is restarted by the next request,
+                    // so that request must not be failed up front.
+                    if is_disconnect(&e) {
+                        self.unresponsive.store(false, Ordering::Relaxed);
+                    }
+                    log::debug!(
+                        "Liveness ping to driver host '{}' failed: {e}",
+                        self.socket_id
+                    );
+                }
+            }
+        }
+    }
+
+    /// Sends a `Ping` without a session. Any answer shows the host is
+    /// alive; hosts answer it with a `SessionNotFound` error.
+    fn ping(&self, inner: &mut RpcClientInner) -> io::Result<()> {
+        // The next request recovers the stream.
+        if inner.broken {
+            return Err(io::ErrorKind::NotConnected.into());
+        }
+
+        inner.next_id += 1;
+        let request =
+            build_call_request_envelope(self.version, inner.next_id, DriverRequestBody::Ping, None);
+        framing::send_msg(&mut inner.stream, &request)?;
+
+        let deadline = Instant::now() + self.limits.request_timeout;
+        loop {
+            let response = recv_response(inner, deadline, &self.limits)?;
+            if response.request_id == request.request_id {
+                return Ok(());
+            }
+
+            if response.request_id == EVENT_REQUEST_ID
+                && let Ok(response) = response.decompressed()
+                && let DriverResponseBody::Event(event) = response.body
+            {
+                self.events.deliver(response.session_id, event);
+            }
+        }
+    }
+}
+
+/// Reads event frames that arrive while no request is running. Skips a round
+/// whenever a request holds the transport; that request delivers them.
+fn pump_events(inner: Weak<Mutex<RpcClientInner>>, events: Arc<EventRouter>) {
+    loop {
+        std::thread::sleep(EVENT_POLL_INTERVAL);
+
+        let Some(inner) = inner.upgrade() else {
+            return;
+        };
+        if events.lock().is_empty() {
+            continue;
+        }
+        let Ok(mut guard) = inner.try_lock() else {
+            continue;
+        };
+        if guard.broken {
+            continue;
+        }
+
+        loop {
+            match poll_frame(&mut guard.stream) {
+                Ok(Some(frame)) => match frame.body {
+                    DriverResponseBody::Event(event) if frame.request_id == EVENT_REQUEST_ID => {
+                        events.deliver(frame.session_id, event);
+                    }
+                    _ => log::warn!(
+                        "Ignoring driver host frame {} received outside a request",
+                        frame.request_id
+                    ),
+                },
+                Ok(None) => break,
+                Err(e) => {
+                    // The next request runs into the same error and recovers.
+                    log::debug!("Driver event pump could not read: {e}");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// The next frame if one has started to arrive, without waiting for one.
+fn poll_frame(stream: &mut DriverStream) -> std::io::Result<Option<DriverResponseEnvelope>> {
+    stream.set_nonblocking(true)?;
+    let first = framing::poll_msg_start(&mut *stream);
+    stream.set_nonblocking(false)?;
+
+    match first? {
+        Some(first) => framing::recv_msg_after(first, stream).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Reads the next frame, waiting for it to start until `deadline`; a frame
+/// that started in time may pause for up to the request timeout. A frame
+/// over the response limit, before or after decompression, is skipped and
+/// fails with [`io::ErrorKind::FileTooLarge`].
+///
+/// Any other failure than those two or a missed deadline leaves the stream
+/// partway through a frame, so `inner` is marked broken.
+fn recv_response(
+    inner: &mut RpcClientInner,
+    deadline: Instant,
+    limits: &RpcLimits,
+) -> io::Result<DriverResponseEnvelope> {
+    let max_bytes = limits.max_response_bytes;
+    let response: io::Result<DriverResponseEnvelope> =
+        inner
+            .stream
+            .read_before(deadline, limits.request_timeout, |reader| {
+                framing::recv_msg_limited(reader, max_bytes)
+            });
+
+    let response = response.inspect_err(|error| {
+        if !matches!(
+            error.kind(),
+            io::ErrorKind::TimedOut | io::ErrorKind::FileTooLarge
+        ) {
+            inner.broken = true;
+        }
+    })?;
+
+    if let DriverResponseBody::Compressed { original_len, .. } = &response.body
+        && *original_len as usize > max_bytes
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::FileTooLarge,
+            format!(
+                "compressed response of {original_len} bytes exceeds the {max_bytes} byte limit"
+            ),
+        ));
+    }
+
+    Ok(response)
+}
+
+/// The [`RpcError`] for a failed read of a response the client waited
+/// `timeout` for.
+fn read_error(error: io::Error, limits: &RpcLimits, timeout: Duration) -> RpcError {
+    match error.kind() {
+        io::ErrorKind::TimedOut => RpcError::ResponseTimeout(timeout),
+        io::ErrorKind::FileTooLarge => RpcError::ResponseTooLarge {
+            limit: limits.max_response_bytes,
+        },
+        _ => RpcError::Io(error),
+    }
+}
+
+/// Whether `body` only reads metadata or manages the session, as opposed to
+/// running user work that may legitimately take long.
+fn is_control_request(body: &DriverRequestBody) -> bool {
+    matches!(
+        body,
+        DriverRequestBody::Hello(_)
+            | DriverRequestBody::OpenSession { .. }
+            | DriverRequestBody::CloseSession
+            | DriverRequestBody::Ping
+            | DriverRequestBody::Cancel { .. }
+            | DriverRequestBody::CancelActive
+            | DriverRequestBody::CleanupAfterCancel
+            | DriverRequestBody::Schema
+            | DriverRequestBody::ListDatabases
+            | DriverRequestBody::SchemaForDatabase { .. }
+            | DriverRequestBody::TableDetails { .. }
+            | DriverRequestBody::ViewDetails { .. }
+            | DriverRequestBody::SetActiveDatabase { .. }
+            | DriverRequestBody::ActiveDatabase
+            | DriverRequestBody::SchemaTypes { .. }
+            | DriverRequestBody::SchemaIndexes { .. }
+            | DriverRequestBody::SchemaForeignKeys { .. }
+            | DriverRequestBody::PlanSemantic { .. }
+            | DriverRequestBody::CodeGenerators
+            | DriverRequestBody::GenerateCode { .. }
+            | DriverRequestBody::SubscribeEvents { .. }
+            | DriverRequestBody::UnsubscribeEvents
+    )
+}
+
+/// Whether `error` means the driver host is gone rather than a bad frame.
+fn is_disconnect(error: &std::io::Error) -> bool {
+    matches!(
+        error.kind(),
+        std::io::ErrorKind::UnexpectedEof
+            | std::io::ErrorKind::BrokenPipe
+            | std::io::ErrorKind::ConnectionReset
+            | std::io::ErrorKind::ConnectionAborted
+            | std::io::ErrorKind::NotConnected
+    )
+}
+
 fn protocol_supports_semantic_planning(version: ProtocolVersion) -> bool {
     version.major > DRIVER_RPC_VERSION.major
         || (version.major == DRIVER_RPC_VERSION.major && version.minor >= 1)
 }
 
+fn protocol_supports_server_events(version: ProtocolVersion) -> bool {
+    version.major > DRIVER_RPC_V1_3.major
+        || (version.major == DRIVER_RPC_V1_3.major && version.minor >= DRIVER_RPC_V1_3.minor)
+}
+
 fn build_call_request_envelope(
     selected_version: ProtocolVersion,
     request_id: u64,
@@ -942,16 +1842,18 @@ fn validate_response_protocol_version(
 #[cfg(test)]
 mod tests {
     use super::{
-        RpcClient, build_call_request_envelope, protocol_supports_semantic_planning,
-        validate_hello_selected_version, validate_response_protocol_version,
+        RpcClient, RpcError, RpcLimits, build_call_request_envelope,
+        protocol_supports_semantic_planning, validate_hello_selected_version,
+        validate_response_protocol_version,
     };
     use dbflux_ipc::audit::{
         AuditEventEmitDto, EventCategoryDto, EventOutcomeDto, EventSeverityDto,
         ExternalAuditEmitter, ExternalAuditSource,
     };
-    use dbflux_ipc::driver_protocol::DriverRequestBody;
+    use dbflux_ipc::driver_protocol::{DriverCapability, DriverRequestBody, DriverResponseBody};
     use dbflux_ipc::{ProtocolVersion, driver_rpc_supported_versions, driver_socket_name};
     use std::sync::{Arc, Mutex};
+    use std::time::Duration;
     use uuid::Uuid;
 
     struct RecordingEmitter {
@@ -1065,6 +1967,13 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn server_events_require_driver_rpc_v1_3_or_newer() {
+        assert!(!protocol_supports_server_events(ProtocolVersion::new(1, 2)));
+        assert!(protocol_supports_server_events(ProtocolVersion::new(1, 3)));
+        assert!(protocol_supports_server_events(ProtocolVersion::new(2, 0)));
+    }
+
     #[test]
     fn hello_selected_version_must_be_supported_by_both_peers() {
         let error = validate_hello_selected_version(
@@ -1229,4 +2138,461 @@ mod tests {
             "all 200 audit frames must be forwarded to the emitter by the transport loop"
         );
     }
+
+    // =========================================================================
+    // Driver host crash recovery
+    // =========================================================================
+
+    fn reconnect_to(socket_id: &str) -> super::Reconnect {
+        use interprocess::local_socket::{Stream, prelude::*};
+
+        let socket_id = socket_id.to_string();
+        Box::new(move || {
+            let stream = Stream::connect(driver_socket_name(&socket_id)?)?;
+            Ok(crate::stream::DriverStream::Local(stream))
+        })
+    }
+
+    #[test]
+    fn host_crash_fails_in_flight_request_and_rebinds_session() {
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let socket_id = format!("test-host-restart-{}", Uuid::new_v4());
+        let first_session = Uuid::new_v4();
+        let second_session = Uuid::new_v4();
+
+        let server = FakeDriverRpcServer::start(
+            FakeDriverRpcConfig::new(&socket_id)
+                .with_actions(vec![
+                    FakeDriverAction::OpenSession(first_session),
+                    FakeDriverAction::Hangup,
+                    FakeDriverAction::OpenSession(second_session),
+                    FakeDriverAction::Pong,
+                ])
+                .with_expected_connections(2),
+        )
+        .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow())
+            .expect("connect must succeed")
+            .with_reconnect(reconnect_to(&socket_id));
+
+        client
+            .open_session("{}", Some("secret"), None)
+            .expect("open session must succeed");
+
+        let error = client
+            .ping(first_session)
+            .expect_err("the request running when the host died must fail");
+        assert!(matches!(error, RpcError::HostRestarted));
+        assert_eq!(client.bound_session_id(first_session), second_session);
+
+        client
+            .ping(first_session)
+            .expect("the session must work again on the restarted host");
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn rpc_client_talks_to_remote_host_over_tcp() {
+        use dbflux_core::RemoteServiceConfig;
+        use dbflux_test_support::{FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let (server, address) = FakeDriverRpcServer::start_tcp(FakeDriverRpcConfig::new("remote"))
+            .expect("fake driver server must start");
+
+        let remote = RemoteServiceConfig {
+            address: address.to_string(),
+            tls: false,
+            token_env: format!("DBFLUX_TEST_REMOTE_TOKEN_{}", Uuid::new_v4().simple()),
+        };
+
+        let error = RpcClient::connect_remote(&remote, "remote".to_string(), None)
+            .err()
+            .expect("connecting without the token must fail");
+        assert!(error.to_string().contains(&remote.token_env));
+
+        unsafe { std::env::set_var(&remote.token_env, "remote-token") };
+
+        let client = RpcClient::connect_remote(&remote, "remote".to_string(), None)
+            .expect("connect must succeed");
+        client.ping(Uuid::nil()).expect("ping must succeed");
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn remote_clients_negotiate_compression_for_large_results() {
+        use dbflux_core::{QueryRequest, RemoteServiceConfig, Value};
+        use dbflux_ipc::CompressionCodec;
+        use dbflux_ipc::driver_protocol::{QueryResultDto, QueryResultShapeDto};
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let rows: Vec<Vec<Value>> = (0..5_000)
+            .map(|i| vec![Value::Int(i), Value::Text("repeated text".to_string())])
+            .collect();
+        let result = DriverResponseBody::ExecuteResult {
+            result: QueryResultDto {
+                shape: QueryResultShapeDto::Table,
+                columns: Vec::new(),
+                rows,
+                affected_rows: None,
+                execution_time_ms: 1,
+                text_body: None,
+                raw_bytes: None,
+                next_page_token: None,
+            },
+        };
+
+        let (server, address) = FakeDriverRpcServer::start_tcp(
+            FakeDriverRpcConfig::new("remote")
+                .with_actions(vec![FakeDriverAction::Respond(result)]),
+        )
+        .expect("fake driver server must start");
+
+        let remote = RemoteServiceConfig {
+            address: address.to_string(),
+            tls: false,
+            token_env: format!("DBFLUX_TEST_REMOTE_TOKEN_{}", Uuid::new_v4().simple()),
+        };
+        unsafe { std::env::set_var(&remote.token_env, "remote-token") };
+
+        let client = RpcClient::connect_remote(&remote, "remote".to_string(), None)
+            .expect("connect must succeed");
+        assert!(
+            client
+                .hello_response()
+                .capabilities
+                .contains(&DriverCapability::Compression(CompressionCodec::Zstd))
+        );
+
+        let result = client
+            .execute(Uuid::nil(), &QueryRequest::new("SELECT 1"))
+            .expect("execute must succeed");
+        assert_eq!(result.rows.len(), 5_000);
+        assert_eq!(result.rows[4_999][0], Value::Int(4_999));
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn subscribed_sessions_receive_events_between_requests() {
+        use dbflux_core::{ConnectionEvent, ConnectionEventKind};
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+        use std::sync::mpsc;
+
+        let notification = ConnectionEvent::Notification {
+            channel: "orders".to_string(),
+            payload: "42".to_string(),
+        };
+        let schema_changed = ConnectionEvent::SchemaChanged { database: None };
+
+        let socket_id = format!("test-server-events-{}", Uuid::new_v4());
+        let server =
+            FakeDriverRpcServer::start(FakeDriverRpcConfig::new(&socket_id).with_actions(vec![
+                FakeDriverAction::RespondThenEvents(
+                    DriverResponseBody::EventsSubscribed {
+                        kinds: vec![
+                            ConnectionEventKind::Notification,
+                            ConnectionEventKind::SchemaChanged,
+                        ],
+                    },
+                    vec![notification.clone(), schema_changed.clone()],
+                ),
+                FakeDriverAction::Pong,
+            ]))
+            .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow()).expect("connect must succeed");
+        assert!(client.supports_server_events());
+
+        let (tx, rx) = mpsc::channel();
+        let granted = client
+            .subscribe_events(
+                Uuid::nil(),
+                &ConnectionEventKind::ALL,
+                Arc::new(move |event| {
+                    let _ = tx.send(event);
+                }),
+            )
+            .expect("subscribe must succeed");
+        assert_eq!(granted.len(), 2);
+
+        // No request is running: the event pump has to pick these up.
+        let timeout = std::time::Duration::from_secs(5);
+        assert_eq!(rx.recv_timeout(timeout), Ok(notification));
+        assert_eq!(rx.recv_timeout(timeout), Ok(schema_changed));
+
+        client
+            .ping(Uuid::nil())
+            .expect("the stream must stay in sync after events");
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn host_crash_without_reconnect_surfaces_io_error() {
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let socket_id = format!("test-host-crash-{}", Uuid::new_v4());
+        let server = FakeDriverRpcServer::start(
+            FakeDriverRpcConfig::new(&socket_id).with_actions(vec![FakeDriverAction::Hangup]),
+        )
+        .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow()).expect("connect must succeed");
+
+        let error = client.ping(Uuid::nil()).expect_err("ping must fail");
+        assert!(matches!(error, RpcError::Io(_)));
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    fn test_limits() -> RpcLimits {
+        RpcLimits {
+            request_timeout: Duration::from_millis(100),
+            query_timeout: Duration::from_millis(100),
+            max_response_bytes: 1024,
+            liveness_interval: None,
+        }
+    }
+
+    #[test]
+    fn driver_error_codes_round_trip_through_rpc_errors() {
+        use dbflux_ipc::driver_protocol::{DriverRpcError, DriverRpcErrorCode};
+
+        for code in [
+            DriverRpcErrorCode::UnsupportedMethod,
+            DriverRpcErrorCode::SessionNotFound,
+            DriverRpcErrorCode::Timeout,
+            DriverRpcErrorCode::Cancelled,
+            DriverRpcErrorCode::Driver,
+        ] {
+            let error = RpcError::from(DriverRpcError {
+                code,
+                message: "boom".to_string(),
+                retriable: false,
+            });
+            assert_eq!(error.code(), code);
+        }
+
+        assert_eq!(
+            RpcError::ResponseTimeout(Duration::from_secs(1)).code(),
+            DriverRpcErrorCode::Timeout
+        );
+        assert!(matches!(
+            dbflux_core::DbError::from(RpcError::ResponseTimeout(Duration::from_secs(1))),
+            dbflux_core::DbError::Timeout
+        ));
+    }
+
+    #[test]
+    fn late_response_is_skipped_after_a_timeout() {
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let socket_id = format!("test-response-timeout-{}", Uuid::new_v4());
+        let server =
+            FakeDriverRpcServer::start(FakeDriverRpcConfig::new(&socket_id).with_actions(vec![
+                FakeDriverAction::DelayedPong(Duration::from_millis(300)),
+                FakeDriverAction::Pong,
+            ]))
+            .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow())
+            .expect("connect must succeed")
+            .with_limits(test_limits());
+
+        let error = client.ping(Uuid::nil()).expect_err("ping must time out");
+        assert!(matches!(error, RpcError::ResponseTimeout(_)));
+
+        client
+            .ping(Uuid::nil())
+            .expect("the late pong must not be taken for this one");
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn response_cut_off_midway_restarts_the_host_instead_of_reading_it_as_a_frame() {
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let socket_id = format!("test-response-cut-off-{}", Uuid::new_v4());
+        let server = FakeDriverRpcServer::start(
+            FakeDriverRpcConfig::new(&socket_id)
+                .with_actions(vec![
+                    FakeDriverAction::StallMidPong(Duration::from_millis(250)),
+                    FakeDriverAction::Pong,
+                ])
+                .with_expected_connections(2),
+        )
+        .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow())
+            .expect("connect must succeed")
+            .with_limits(RpcLimits {
+                request_timeout: Duration::from_millis(200),
+                ..test_limits()
+            })
+            .with_reconnect(reconnect_to(&socket_id));
+
+        let error = client
+            .ping(Uuid::nil())
+            .expect_err("the cut-off pong must fail");
+        assert!(matches!(error, RpcError::HostRestarted));
+
+        client
+            .ping(Uuid::nil())
+            .expect("the restarted host must answer in sync");
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn oversized_response_is_rejected_and_the_stream_stays_in_sync() {
+        use dbflux_core::{QueryRequest, Value};
+        use dbflux_ipc::driver_protocol::{QueryResultDto, QueryResultShapeDto};
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let result = DriverResponseBody::ExecuteResult {
+            result: QueryResultDto {
+                shape: QueryResultShapeDto::Table,
+                columns: Vec::new(),
+                rows: (0..500).map(|i| vec![Value::Int(i)]).collect(),
+                affected_rows: None,
+                execution_time_ms: 1,
+                text_body: None,
+                raw_bytes: None,
+                next_page_token: None,
+            },
+        };
+
+        let socket_id = format!("test-response-too-large-{}", Uuid::new_v4());
+        let server =
+            FakeDriverRpcServer::start(FakeDriverRpcConfig::new(&socket_id).with_actions(vec![
+                FakeDriverAction::Respond(result),
+                FakeDriverAction::Pong,
+            ]))
+            .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow())
+            .expect("connect must succeed")
+            .with_limits(test_limits());
+
+        let error = client
+            .execute(Uuid::nil(), &QueryRequest::new("SELECT 1"))
+            .expect_err("execute must fail");
+        assert!(matches!(error, RpcError::ResponseTooLarge { limit: 1024 }));
+
+        client
+            .ping(Uuid::nil())
+            .expect("the oversized response must have been skipped");
+
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn stalled_host_is_flagged_by_liveness_pings() {
+        use dbflux_test_support::{FakeDriverAction, FakeDriverRpcConfig, FakeDriverRpcServer};
+
+        let socket_id = format!("test-liveness-{}", Uuid::new_v4());
+        let session_id = Uuid::new_v4();
+        let server =
+            FakeDriverRpcServer::start(FakeDriverRpcConfig::new(&socket_id).with_actions(vec![
+                FakeDriverAction::OpenSession(session_id),
+                FakeDriverAction::DelayedPong(Duration::from_secs(1)),
+            ]))
+            .expect("fake driver server must start");
+
+        let socket_name = driver_socket_name(&socket_id).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow())
+            .expect("connect must succeed")
+            .with_limits(RpcLimits {
+                liveness_interval: Some(Duration::from_millis(50)),
+                ..test_limits()
+            });
+
+        client
+            .open_session("{}", None, None)
+            .expect("open session must succeed");
+        assert!(client.is_responsive());
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while client.is_responsive() && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(!client.is_responsive(), "the missed ping must be noticed");
+
+        let error = client
+            .ping(session_id)
+            .expect_err("requests must fail while the host is stalled");
+        assert!(matches!(error, RpcError::HostUnresponsive));
+
+        drop(client);
+        server.wait().expect("server must exit cleanly");
+    }
+
+    #[test]
+    fn recorded_conversation_replays_without_the_host() {
+        use dbflux_test_support::{
+            DriverRpcRecorder, DriverRpcRecording, DriverRpcReplayServer, FakeDriverAction,
+            FakeDriverRpcConfig, FakeDriverRpcServer,
+        };
+
+        let session_id = Uuid::new_v4();
+        let host_socket = format!("test-record-host-{}", Uuid::new_v4());
+        let recorder_socket = format!("test-recorder-{}", Uuid::new_v4());
+        let replay_socket = format!("test-replay-{}", Uuid::new_v4());
+        let path = std::env::temp_dir().join(format!("{recorder_socket}.jsonl"));
+
+        let host =
+            FakeDriverRpcServer::start(FakeDriverRpcConfig::new(&host_socket).with_actions(vec![
+                FakeDriverAction::OpenSession(session_id),
+                FakeDriverAction::Pong,
+            ]))
+            .expect("fake driver server must start");
+        let recorder = DriverRpcRecorder::start(&recorder_socket, &host_socket, &path, 1)
+            .expect("recorder must start");
+
+        let socket_name = driver_socket_name(&recorder_socket).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow()).expect("connect must succeed");
+        client
+            .open_session("{}", Some("secret"), None)
+            .expect("open session must succeed");
+        client.ping(session_id).expect("ping must succeed");
+        drop(client);
+
+        recorder.wait().expect("recorder must exit cleanly");
+        host.wait().expect("server must exit cleanly");
+
+        let recorded = std::fs::read_to_string(&path).expect("recording must exist");
+        assert!(!recorded.contains("secret"), "secrets must not be recorded");
+
+        let recording = DriverRpcRecording::load(&path).expect("recording must load");
+        assert_eq!(recording.frames.len(), 7);
+
+        let replay =
+            DriverRpcReplayServer::start(&replay_socket, recording).expect("replay must start");
+        let socket_name = driver_socket_name(&replay_socket).expect("socket name");
+        let client = RpcClient::connect(socket_name.borrow()).expect("connect must succeed");
+
+        let opened = client
+            .open_session("{}", Some("another secret"), None)
+            .expect("open session must replay");
+        assert!(matches!(
+            opened,
+            DriverResponseBody::SessionOpened { session_id: id, .. } if id == session_id
+        ));
+        client.ping(session_id).expect("ping must replay");
+        drop(client);
+
+        replay.wait().expect("replay must match the recording");
+        std::fs::remove_file(&path).expect("recording must be removed");
+    }
 }
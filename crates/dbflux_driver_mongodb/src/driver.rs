@@ -9,6 +9,7 @@ use std::time::Instant;
 
 use std::sync::Arc;
 
+use bson::spec::BinarySubtype;
 use bson::{Bson, Document, doc};
 use dbflux_core::secrecy::{ExposeSecret, SecretString};
 
@@ -112,7 +113,8 @@ pub static MONGODB_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverM
             | DriverCapabilities::INDEXES.bits()
             | DriverCapabilities::INSTANCE_METRICS.bits()
             | DriverCapabilities::INSTANCE_INSPECTOR.bits()
-            | DriverCapabilities::CHART_AUTHORING.bits(),
+            | DriverCapabilities::CHART_AUTHORING.bits()
+            | DriverCapabilities::CONCURRENT_REQUESTS.bits(),
     ),
     default_port: Some(27017),
     uri_scheme: "mongodb".into(),
@@ -1203,7 +1205,7 @@ impl ConnectionErrorFormatter for MongoErrorFormatter {
     ) -> FormattedError {
         let source = error.to_string();
         let message = Self::format_connection_message(&source, host, port);
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 
     fn format_uri_error(
@@ -1221,10 +1223,10 @@ impl ConnectionErrorFormatter for MongoErrorFormatter {
             } else if source.contains("timed out") {
                 "Connection timed out.".to_string()
             } else {
-                source
+                source.clone()
             };
 
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 }
 
@@ -2028,6 +2030,7 @@ impl Connection for MongoConnection {
                     sample_fields: None,
                     presentation: dbflux_core::CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 }
             })
             .collect();
@@ -2157,6 +2160,7 @@ impl Connection for MongoConnection {
             sample_fields: Some(sample_fields),
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment: None,
         })
     }
 
@@ -2575,11 +2579,16 @@ impl SqlDialect for MongoDialect {
             Value::Bool(b) => b.to_string(),
             Value::Int(i) => i.to_string(),
             Value::Float(f) => f.to_string(),
-            Value::Text(s) => format!("\"{}\"", s.replace('\"', "\\\"")),
+            Value::Text(s) | Value::Enum(s) => format!("\"{}\"", s.replace('\"', "\\\"")),
+            Value::Uuid(uuid) => format!("UUID(\"{}\")", uuid),
+            Value::Interval(interval) => format!("\"{}\"", interval),
             Value::Bytes(b) => format!("BinData(0, \"{}\")", base64_encode(b)),
             Value::Json(j) => j.clone(),
             Value::Decimal(d) => d.clone(),
             Value::DateTime(dt) => format!("ISODate(\"{}\")", dt.to_rfc3339()),
+            Value::NaiveDateTime(dt) => {
+                format!("ISODate(\"{}Z\")", dt.format("%Y-%m-%dT%H:%M:%S%.f"))
+            }
             Value::Date(d) => format!("ISODate(\"{}T00:00:00Z\")", d),
             Value::Time(t) => format!("\"{}\"", t),
             Value::Array(arr) => {
@@ -3407,6 +3416,11 @@ fn bson_to_value(bson: &Bson) -> Value {
                 Value::Text(dt.to_string())
             }
         }
+        Bson::Binary(bin) if bin.subtype == BinarySubtype::Uuid => {
+            uuid::Uuid::from_slice(&bin.bytes)
+                .map(Value::Uuid)
+                .unwrap_or_else(|_| Value::Bytes(bin.bytes.clone()))
+        }
         Bson::Binary(bin) => Value::Bytes(bin.bytes.clone()),
         Bson::Array(arr) => {
             let values: Vec<Value> = arr.iter().map(bson_to_value).collect();
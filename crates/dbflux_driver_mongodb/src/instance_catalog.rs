@@ -544,6 +544,7 @@ pub(crate) fn dispatch_metric_series(
                 rows: vec![row],
                 affected_rows: None,
                 execution_time: Duration::ZERO,
+                timing: Default::default(),
                 text_body: None,
                 raw_bytes: None,
                 next_page_token: None,
@@ -686,6 +687,7 @@ fn fetch_current_op(client: &Client) -> Result<QueryResult, DbError> {
         rows,
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -4,7 +4,7 @@ use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use dbflux_core::QueryGenerator;
 use dbflux_core::secrecy::{ExposeSecret, SecretString};
 use dbflux_core::{
@@ -12,20 +12,20 @@ use dbflux_core::{
     ConnectionProfile, ConstraintInfo, ConstraintKind, CrudResult, CustomTypeInfo, CustomTypeKind,
     DatabaseCategory, DatabaseInfo, DbConfig, DbDriver, DbError, DbKind, DbSchemaInfo,
     DdlCapabilities, DeploymentClass, DescribeRequest, DocumentConnection, DriverCapabilities,
-    DriverFormDef, DriverLimits, DriverMetadata, ExecutionSourceContext, ExplainRequest,
-    ForeignKeyBuilder, ForeignKeyInfo, FormFieldKind, FormSection, FormTab, FormValues,
-    FormattedError, Icon, IndexData, IndexInfo, InstanceCatalog, IsolationLevel,
+    DriverFormDef, DriverLimits, DriverMetadata, ErrorSeverity, ExecutionSourceContext,
+    ExplainRequest, ForeignKeyBuilder, ForeignKeyInfo, FormFieldKind, FormSection, FormTab,
+    FormValues, FormattedError, Icon, IndexData, IndexInfo, InstanceCatalog, IsolationLevel,
     KeyValueConnection, MutationCapabilities, OrderByColumn, PaginationStyle, PlaceholderStyle,
     QueryCancelHandle, QueryCapabilities, QueryErrorFormatter, QueryHandle, QueryLanguage,
     QueryRequest, QueryResult, RecordIdentity, RelationalConnection, RelationalSchema, RoutineInfo,
     RoutineKind, Row, RowDelete, RowInsert, RowPatch, SchemaFeatures, SchemaForeignKeyBuilder,
     SchemaForeignKeyInfo, SchemaIndexBuilder, SchemaIndexInfo, SchemaLoadingStrategy,
-    SchemaSnapshot, SortDirection, SqlDialect, SqlMutationGenerator, SshTunnelConfig, SyntaxInfo,
-    TableBrowseRequest, TableCountRequest, TableInfo, TransactionCapabilities, Value, ViewInfo,
-    WhereOperator, field, field_password, field_required, field_use_uri, generate_delete_template,
-    generate_drop_table, generate_insert_template, generate_select_star, generate_truncate,
-    generate_update_template, render_semantic_filter_sql, sanitize_uri, ssh_tab, when_checked,
-    when_unchecked, with_default,
+    SchemaSnapshot, SessionGate, SortDirection, SqlDialect, SqlMutationGenerator, SshTunnelConfig,
+    SyntaxInfo, TableBrowseRequest, TableCountRequest, TableInfo, TempColumnType,
+    TransactionCapabilities, Value, ViewInfo, WhereOperator, field, field_password, field_required,
+    field_use_uri, generate_delete_template, generate_drop_table, generate_insert_template,
+    generate_select_star, generate_truncate, generate_update_template, render_semantic_filter_sql,
+    sanitize_uri, ssh_tab, when_checked, when_unchecked, with_default,
 };
 use dbflux_ssh::SshTunnel;
 use tiberius::{AuthMethod, Client, Config, EncryptionLevel, SqlBrowser};
@@ -129,7 +129,8 @@ pub static METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMetadata
             | DriverCapabilities::MULTI_STATEMENT.bits()
             | DriverCapabilities::INSTANCE_METRICS.bits()
             | DriverCapabilities::INSTANCE_INSPECTOR.bits()
-            | DriverCapabilities::CHART_AUTHORING.bits(),
+            | DriverCapabilities::CHART_AUTHORING.bits()
+            | DriverCapabilities::TEMP_TABLES.bits(),
     ),
     default_port: Some(1433),
     uri_scheme: "sqlserver".into(),
@@ -343,6 +344,34 @@ impl SqlDialect for MssqlDialect {
         // an incorrect template. Use UPDATE/INSERT separately.
         None
     }
+
+    fn temp_table_identifier(&self, name: &str) -> String {
+        // Local temp tables live in tempdb and are private to the session.
+        format!("#{name}")
+    }
+
+    fn create_temp_table_prefix(&self) -> &'static str {
+        "CREATE TABLE"
+    }
+
+    fn drop_temp_table_statement(&self, identifier: &str) -> Option<String> {
+        Some(format!(
+            "DROP TABLE IF EXISTS {}",
+            self.quote_identifier(identifier)
+        ))
+    }
+
+    fn temp_column_type(&self, ty: TempColumnType) -> &'static str {
+        match ty {
+            TempColumnType::Boolean => "BIT",
+            TempColumnType::Float => "FLOAT",
+            TempColumnType::Decimal => "DECIMAL(38, 10)",
+            TempColumnType::Text | TempColumnType::Json => "NVARCHAR(MAX)",
+            TempColumnType::Timestamp => "DATETIME2",
+            TempColumnType::Binary => "VARBINARY(MAX)",
+            other => other.ansi_name(),
+        }
+    }
 }
 
 /// Returns `true` when `s` looks like a SQL Server-safe numeric literal:
@@ -395,7 +424,9 @@ fn value_to_mssql_literal(value: &Value) -> String {
                 f.to_string()
             }
         }
-        Value::Text(s) => format!("N'{}'", s.replace('\'', "''")),
+        Value::Text(s) | Value::Enum(s) => format!("N'{}'", s.replace('\'', "''")),
+        Value::Uuid(uuid) => format!("'{}'", uuid),
+        Value::Interval(interval) => format!("N'{}'", interval),
         Value::Bytes(b) => {
             let hex: String = b.iter().map(|byte| format!("{:02X}", byte)).collect();
             format!("0x{}", hex)
@@ -415,6 +446,7 @@ fn value_to_mssql_literal(value: &Value) -> String {
             }
         }
         Value::DateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
+        Value::NaiveDateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
         Value::Date(d) => format!("'{}'", d.format("%Y-%m-%d")),
         Value::Time(t) => format!("'{}'", t.format("%H:%M:%S%.f")),
         Value::Array(_) | Value::Document(_) | Value::ObjectId(_) => {
@@ -940,6 +972,7 @@ impl MssqlDriver {
         );
 
         Ok(Box::new(MssqlConnection {
+            session_gate: SessionGate::new(),
             inner: Arc::new(Mutex::new(MssqlConnectionInner {
                 client: Some(client),
                 runtime,
@@ -1109,6 +1142,7 @@ fn build_mssql_connection(
     ssh_tunnel: Option<SshTunnel>,
 ) -> MssqlConnection {
     MssqlConnection {
+        session_gate: SessionGate::new(),
         inner: Arc::new(Mutex::new(MssqlConnectionInner {
             client: Some(session.client),
             runtime: session.runtime,
@@ -1132,6 +1166,7 @@ pub(crate) struct MssqlConnectionInner {
 }
 
 pub struct MssqlConnection {
+    session_gate: SessionGate,
     inner: Arc<Mutex<MssqlConnectionInner>>,
     current_database: Mutex<Option<String>>,
     #[allow(dead_code)]
@@ -1414,10 +1449,7 @@ fn tiberius_value_to_value(row: &tiberius::Row, idx: usize) -> Value {
             .as_ref()
             .map(|s| Value::Text(s.to_string()))
             .unwrap_or(Value::Null),
-        ColumnData::Guid(v) => v
-            .as_ref()
-            .map(|g| Value::Text(g.to_string()))
-            .unwrap_or(Value::Null),
+        ColumnData::Guid(v) => v.as_ref().map(|g| Value::Uuid(*g)).unwrap_or(Value::Null),
         ColumnData::Binary(v) => v
             .as_ref()
             .map(|b| Value::Bytes(b.to_vec()))
@@ -1500,7 +1532,7 @@ fn extract_temporal(row: &tiberius::Row, idx: usize, _column_type: tiberius::Col
     }
 
     if let Ok(Some(v)) = row.try_get::<NaiveDateTime, _>(idx) {
-        return Value::DateTime(DateTime::<Utc>::from_naive_utc_and_offset(v, Utc));
+        return Value::NaiveDateTime(v);
     }
 
     if let Ok(Some(v)) = row.try_get::<NaiveDate, _>(idx) {
@@ -1553,7 +1585,13 @@ impl Connection for MssqlConnection {
         Ok(())
     }
 
+    fn session_gate(&self) -> Option<&SessionGate> {
+        Some(&self.session_gate)
+    }
+
     fn execute(&self, req: &QueryRequest) -> Result<QueryResult, DbError> {
+        let _session = self.session_gate.enter(|request| self.execute(request))?;
+
         // Do not blanket-reset `cancelled` here — that would race with a
         // `cancel()` that fires between two executions and silently drop the
         // signal. Instead, recover the connection if a previous cancel left
@@ -1706,6 +1744,9 @@ impl Connection for MssqlConnection {
             self.execute_simple(&format!("USE [{}]", escaped))?;
         }
 
+        // A role switch or transaction the killed session was left in died
+        // with it, so there is nothing left to undo.
+        self.session_gate.forget_reset();
         self.poisoned.store(false, Ordering::SeqCst);
         self.cancelled.store(false, Ordering::SeqCst);
 
@@ -1740,10 +1781,7 @@ impl Connection for MssqlConnection {
     }
 
     fn list_databases(&self) -> Result<Vec<DatabaseInfo>, DbError> {
-        // Hide built-in system databases.
-        let sql = "SELECT name FROM sys.databases \
-                   WHERE name NOT IN ('master','tempdb','model','msdb') \
-                   ORDER BY name";
+        let sql = "SELECT name FROM sys.databases ORDER BY name";
 
         let result = self.execute_simple(sql)?;
         let current = self
@@ -1808,6 +1846,7 @@ impl Connection for MssqlConnection {
                 sample_fields: None,
                 presentation: dbflux_core::CollectionPresentation::DataGrid,
                 child_items: None,
+                comment: None,
             });
         }
 
@@ -1878,6 +1917,7 @@ impl Connection for MssqlConnection {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment: None,
         })
     }
 
@@ -2466,6 +2506,8 @@ impl MssqlConnection {
                 default_value,
                 is_primary_key,
                 enum_values: None,
+                comment: None,
+                definition: None,
             });
         }
 
@@ -3435,6 +3477,16 @@ enum MssqlErrorClass {
     Syntax,
 }
 
+/// Maps a SQL Server severity class: 0-10 are informational, 11-19 are
+/// user-correctable errors and 20 and above terminate the connection.
+fn mssql_severity(class: u8) -> ErrorSeverity {
+    match class {
+        0..=10 => ErrorSeverity::Warning,
+        11..=19 => ErrorSeverity::Error,
+        _ => ErrorSeverity::Fatal,
+    }
+}
+
 fn classify_mssql_code(code: u32) -> Option<MssqlErrorClass> {
     match code {
         // Login / auth
@@ -3532,8 +3584,9 @@ impl MssqlErrorFormatter {
         match err {
             tiberius::error::Error::Server(token) => {
                 let message = token.message().to_string();
-                let mut fe =
-                    FormattedError::new(message.clone()).with_code(token.code().to_string());
+                let mut fe = FormattedError::new(message.clone())
+                    .with_code(token.code().to_string())
+                    .with_severity(mssql_severity(token.class()));
 
                 let state = token.state();
                 if state != 0 {
@@ -3626,7 +3679,7 @@ impl ConnectionErrorFormatter for MssqlErrorFormatter {
             format!("Connection error: {}", source)
         };
 
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 
     fn format_uri_error(
@@ -3634,10 +3687,12 @@ impl ConnectionErrorFormatter for MssqlErrorFormatter {
         error: &(dyn std::error::Error + 'static),
         sanitized_uri: &str,
     ) -> FormattedError {
+        let source = error.to_string();
         FormattedError::new(format!(
             "Connection error with URI {}: {}",
-            sanitized_uri, error
+            sanitized_uri, source
         ))
+        .with_connection_hint(&source)
     }
 }
 
@@ -3859,6 +3914,13 @@ mod tests {
         assert_eq!(loc.column.as_deref(), Some("id"));
     }
 
+    #[test]
+    fn mssql_severity_follows_the_class_ranges() {
+        assert_eq!(mssql_severity(10), ErrorSeverity::Warning);
+        assert_eq!(mssql_severity(16), ErrorSeverity::Error);
+        assert_eq!(mssql_severity(20), ErrorSeverity::Fatal);
+    }
+
     #[test]
     fn classify_mssql_code_routes_known_codes() {
         assert_eq!(classify_mssql_code(18456), Some(MssqlErrorClass::Auth));
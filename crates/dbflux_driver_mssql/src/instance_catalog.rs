@@ -473,6 +473,7 @@ fn fetch_performance_counter(
         rows: vec![row],
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -674,6 +675,7 @@ fn fetch_active_sessions(inner: &mut MssqlConnectionInner) -> Result<QueryResult
         rows,
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -12,16 +12,17 @@ use dbflux_core::{
     DatabaseCategory, DatabaseInfo, DbConfig, DbDriver, DbError, DbKind, DbSchemaInfo,
     DdlCapabilities, DeploymentClass, DescribeRequest, DocumentConnection, DriverCapabilities,
     DriverFormDef, DriverLimits, DriverMetadata, DropForeignKeyRequest, DropIndexRequest,
-    ExecutionSourceContext, ExplainRequest, FieldExportTransform, ForeignKeyBuilder,
+    ErrorSeverity, ExecutionSourceContext, ExplainRequest, FieldExportTransform, ForeignKeyBuilder,
     ForeignKeyInfo, FormFieldKind, FormSection, FormTab, FormValues, FormattedError, Icon,
     IndexData, IndexInfo, InstanceCatalog, IsolationLevel, KeyValueConnection,
     MutationCapabilities, OrderByColumn, PaginationStyle, PlaceholderStyle, QueryCancelHandle,
     QueryCapabilities, QueryErrorFormatter, QueryGenerator, QueryHandle, QueryLanguage,
-    QueryRequest, QueryResult, RecordIdentity, RelationalConnection, RelationalSchema, RoutineInfo,
-    RoutineKind, Row, RowDelete, RowInsert, RowPatch, SchemaFeatures, SchemaForeignKeyBuilder,
-    SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaSnapshot, SemanticPlan,
-    SemanticPlanKind, SemanticRequest, SortDirection, SqlDialect, SqlMutationGenerator,
-    SqlQueryBuilder, SshTunnelConfig, SyntaxInfo, TableInfo, TransactionCapabilities, Value,
+    QueryRequest, QueryResult, RecordIdentity, RelationalConnection, RelationalSchema,
+    RenameRequest, RoutineInfo, RoutineKind, Row, RowDelete, RowInsert, RowPatch, SchemaFeatures,
+    SchemaForeignKeyBuilder, SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy,
+    SchemaSnapshot, SemanticPlan, SemanticPlanKind, SemanticRequest, SessionGate,
+    SetCommentRequest, SortDirection, SqlDialect, SqlMutationGenerator, SqlQueryBuilder,
+    SshTunnelConfig, SyntaxInfo, TableInfo, TempColumnType, TransactionCapabilities, Value,
     ViewInfo, WhereOperator, field, field_password, field_required, field_use_uri,
     generate_delete_template, generate_drop_table, generate_insert_template, generate_select_star,
     generate_truncate, generate_update_template, render_semantic_filter_sql, sanitize_uri, ssh_tab,
@@ -51,7 +52,9 @@ pub static MYSQL_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMet
             | DriverCapabilities::MULTI_STATEMENT.bits()
             | DriverCapabilities::INSTANCE_METRICS.bits()
             | DriverCapabilities::INSTANCE_INSPECTOR.bits()
-            | DriverCapabilities::CHART_AUTHORING.bits(),
+            | DriverCapabilities::CHART_AUTHORING.bits()
+            | DriverCapabilities::TEMP_TABLES.bits()
+            | DriverCapabilities::INCREMENTAL_FETCH.bits(),
     ),
     default_port: Some(3306),
     uri_scheme: "mysql".into(),
@@ -65,7 +68,7 @@ pub static MYSQL_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMet
         case_sensitive_identifiers: false,
     }),
     query: Some(QueryCapabilities {
-        pagination: vec![PaginationStyle::Offset],
+        pagination: vec![PaginationStyle::Offset, PaginationStyle::Keyset],
         where_operators: vec![
             WhereOperator::Eq,
             WhereOperator::Ne,
@@ -208,7 +211,8 @@ pub static MARIADB_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverM
             | DriverCapabilities::ROUTINES.bits()
             | DriverCapabilities::MULTI_STATEMENT.bits()
             | DriverCapabilities::INSTANCE_METRICS.bits()
-            | DriverCapabilities::INSTANCE_INSPECTOR.bits(),
+            | DriverCapabilities::INSTANCE_INSPECTOR.bits()
+            | DriverCapabilities::TEMP_TABLES.bits(),
     ),
     default_port: Some(3306),
     uri_scheme: "mariadb".into(),
@@ -222,7 +226,7 @@ pub static MARIADB_METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverM
         case_sensitive_identifiers: false,
     }),
     query: Some(QueryCapabilities {
-        pagination: vec![PaginationStyle::Offset],
+        pagination: vec![PaginationStyle::Offset, PaginationStyle::Keyset],
         where_operators: vec![
             WhereOperator::Eq,
             WhereOperator::Ne,
@@ -381,6 +385,23 @@ impl SqlDialect for MysqlDialect {
         PlaceholderStyle::QuestionMark
     }
 
+    fn text_contains_expr(&self, column: &str, text: &str, case_sensitive: bool) -> String {
+        let pattern = self.value_to_literal(&Value::Text(dbflux_core::like_contains_pattern(text)));
+        // `LIKE` follows the column collation, which usually ignores case.
+        if case_sensitive {
+            format!("{column} LIKE BINARY {pattern} ESCAPE '!'")
+        } else {
+            format!("LOWER({column}) LIKE LOWER({pattern}) ESCAPE '!'")
+        }
+    }
+
+    fn regex_match_expr(&self, column: &str, pattern: &str) -> Option<String> {
+        Some(format!(
+            "{column} REGEXP {}",
+            self.value_to_literal(&Value::Text(pattern.to_string()))
+        ))
+    }
+
     fn build_upsert_statement(
         &self,
         schema: Option<&str>,
@@ -429,6 +450,26 @@ impl SqlDialect for MysqlDialect {
             table, columns, values, update_clause
         ))
     }
+
+    fn drop_temp_table_statement(&self, identifier: &str) -> Option<String> {
+        Some(format!(
+            "DROP TEMPORARY TABLE IF EXISTS {}",
+            mysql_quote_ident(identifier)
+        ))
+    }
+
+    fn temp_column_type(&self, ty: TempColumnType) -> &'static str {
+        match ty {
+            TempColumnType::Float => "DOUBLE",
+            TempColumnType::Decimal => "DECIMAL(65, 30)",
+            TempColumnType::Text => "LONGTEXT",
+            TempColumnType::Time => "TIME(6)",
+            TempColumnType::Timestamp => "DATETIME(6)",
+            TempColumnType::Json => "JSON",
+            TempColumnType::Binary => "LONGBLOB",
+            other => other.ansi_name(),
+        }
+    }
 }
 
 static MYSQL_DIALECT: MysqlDialect = MysqlDialect;
@@ -459,6 +500,8 @@ impl CodeGenerator for MysqlCodeGenerator {
             | CodeGenCapabilities::CREATE_TABLE
             | CodeGenCapabilities::DROP_TABLE
             | CodeGenCapabilities::ALTER_TABLE
+            | CodeGenCapabilities::COMMENTS
+            | CodeGenCapabilities::RENAME
     }
 
     fn generate_create_index(&self, req: &CreateIndexRequest) -> Option<String> {
@@ -537,6 +580,62 @@ impl CodeGenerator for MysqlCodeGenerator {
             self.quote(req.constraint_name)
         ))
     }
+
+    /// MySQL has no `COMMENT ON`: table comments are a table option, and
+    /// column comments require restating the column with `MODIFY COLUMN`,
+    /// from the definition `fetch_columns` recorded when there is one.
+    /// An empty comment removes it.
+    fn generate_set_comment(&self, req: &SetCommentRequest) -> Option<String> {
+        let table = self.qualified(req.schema_name, req.table_name);
+        let comment = format!(
+            "'{}'",
+            MYSQL_DIALECT.escape_string(req.comment.unwrap_or_default())
+        );
+
+        let Some(column) = req.column else {
+            return Some(format!("ALTER TABLE {} COMMENT = {};", table, comment));
+        };
+
+        let definition = match &column.definition {
+            Some(definition) => format!("{} {}", self.quote(&column.name), definition),
+            None => {
+                let spec = MysqlColumnSpec {
+                    type_name: &column.type_name,
+                    nullable: column.nullable,
+                    default: column.default_value.as_deref(),
+                    extra: "",
+                    charset: None,
+                    collation: None,
+                    generation_expression: None,
+                };
+                format!("{} {}", self.quote(&column.name), spec.definition())
+            }
+        };
+
+        Some(format!(
+            "-- MODIFY COLUMN restates the column definition; check it before running.\n\
+             ALTER TABLE {} MODIFY COLUMN {} COMMENT {};",
+            table, definition, comment
+        ))
+    }
+
+    fn generate_rename(&self, req: &RenameRequest) -> Option<String> {
+        let table = self.qualified(req.schema_name, req.table_name);
+
+        Some(match req.column {
+            Some(column) => format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                table,
+                self.quote(column),
+                self.quote(req.new_name)
+            ),
+            None => format!(
+                "RENAME TABLE {} TO {};",
+                table,
+                self.qualified(req.schema_name, req.new_name)
+            ),
+        })
+    }
 }
 
 // =============================================================================
@@ -1132,6 +1231,7 @@ impl MysqlDriver {
         );
 
         Ok(Box::new(MysqlConnection {
+            session_gate: SessionGate::new(),
             catalog_conn: Arc::new(Mutex::new(catalog_conn)),
             query_conn: Mutex::new(QueryConnState {
                 conn: query_conn,
@@ -1208,6 +1308,7 @@ impl MysqlDriver {
         );
 
         Ok(Box::new(MysqlConnection {
+            session_gate: SessionGate::new(),
             catalog_conn: Arc::new(Mutex::new(catalog_conn)),
             query_conn: Mutex::new(QueryConnState {
                 conn: query_conn,
@@ -1339,6 +1440,7 @@ impl MysqlDriver {
         );
 
         Ok(Box::new(MysqlConnection {
+            session_gate: SessionGate::new(),
             catalog_conn: Arc::new(Mutex::new(catalog_conn)),
             query_conn: Mutex::new(QueryConnState {
                 conn: query_conn,
@@ -1360,7 +1462,15 @@ impl MysqlErrorFormatter {
     fn format_mysql_error(e: &mysql::Error) -> FormattedError {
         match e {
             mysql::Error::MySqlError(mysql_err) => {
-                FormattedError::new(&mysql_err.message).with_code(mysql_err.code.to_string())
+                let formatted = FormattedError::new(&mysql_err.message)
+                    .with_code(mysql_err.code.to_string())
+                    .with_severity(ErrorSeverity::Error);
+
+                if mysql_err.state.is_empty() {
+                    formatted
+                } else {
+                    formatted.with_sqlstate(&mysql_err.state)
+                }
             }
             _ => FormattedError::new(e.to_string()),
         }
@@ -1410,7 +1520,7 @@ impl ConnectionErrorFormatter for MysqlErrorFormatter {
     ) -> FormattedError {
         let source = error.to_string();
         let message = Self::format_connection_message(&source, host, port);
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 
     fn format_uri_error(
@@ -1433,7 +1543,7 @@ impl ConnectionErrorFormatter for MysqlErrorFormatter {
             format!("Connection error with URI {}: {}", sanitized_uri, source)
         };
 
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 }
 
@@ -1552,6 +1662,9 @@ struct QueryConnState {
 }
 
 pub struct MysqlConnection {
+    /// Keeps other callers out while a scoped execution holds the session.
+    session_gate: SessionGate,
+
     /// Connection for catalog/schema operations (schema browsing, table details).
     catalog_conn: Arc<Mutex<Conn>>,
 
@@ -1648,41 +1761,8 @@ fn plan_mysql_table_browse(
     request: &dbflux_core::TableBrowseRequest,
 ) -> Result<SemanticPlan, DbError> {
     let sql = if let Some(filter) = request.semantic_filter.as_ref() {
-        let mut sql = format!(
-            "SELECT * FROM {}",
-            request.table.quoted_with(&MYSQL_DIALECT)
-        );
         let where_clause = render_semantic_filter_sql(filter, &MYSQL_DIALECT)?;
-        sql.push_str(" WHERE ");
-        sql.push_str(&where_clause);
-
-        if !request.order_by.is_empty() {
-            let order_by = request
-                .order_by
-                .iter()
-                .map(|column| {
-                    let direction = match column.direction {
-                        SortDirection::Ascending => "ASC",
-                        SortDirection::Descending => "DESC",
-                    };
-                    format!(
-                        "{} {}",
-                        column.column.quoted_with(&MYSQL_DIALECT),
-                        direction
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
-            sql.push_str(" ORDER BY ");
-            sql.push_str(&order_by);
-        }
-
-        sql.push_str(&format!(
-            " LIMIT {} OFFSET {}",
-            request.pagination.limit(),
-            request.pagination.offset()
-        ));
-        sql
+        request.build_sql_with_where(&MYSQL_DIALECT, Some(&where_clause))
     } else {
         request.build_sql_with(&MYSQL_DIALECT)
     };
@@ -1831,7 +1911,13 @@ impl Connection for MysqlConnection {
         ))
     }
 
+    fn session_gate(&self) -> Option<&SessionGate> {
+        Some(&self.session_gate)
+    }
+
     fn execute(&self, req: &QueryRequest) -> Result<QueryResult, DbError> {
+        let _session = self.session_gate.enter(|request| self.execute(request))?;
+
         self.cancelled.store(false, Ordering::SeqCst);
 
         if let Some(source) = req
@@ -1976,6 +2062,20 @@ impl Connection for MysqlConnection {
         })
     }
 
+    fn schemas_for_databases(&self, databases: &[String]) -> Result<Vec<DbSchemaInfo>, DbError> {
+        log::info!(
+            "[SCHEMA] Fetching schemas for {} databases",
+            databases.len()
+        );
+
+        let mut conn = self
+            .catalog_conn
+            .lock()
+            .map_err(|e| DbError::query_failed(format!("Lock error: {}", e)))?;
+
+        fetch_schemas_shallow(&mut conn, databases)
+    }
+
     fn table_details(
         &self,
         database: &str,
@@ -1997,6 +2097,7 @@ impl Connection for MysqlConnection {
         let indexes = fetch_indexes(&mut conn, database, table)?;
         let foreign_keys = fetch_foreign_keys(&mut conn, database, table)?;
         let constraints = fetch_constraints(&mut conn, database, table)?;
+        let comment = fetch_table_comment(&mut conn, database, table)?;
 
         log::info!(
             "[SCHEMA] Table {}.{}: {} columns, {} indexes, {} FKs, {} constraints",
@@ -2018,6 +2119,7 @@ impl Connection for MysqlConnection {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment,
         })
     }
 
@@ -2036,6 +2138,20 @@ impl Connection for MysqlConnection {
         })
     }
 
+    fn table_statistics(
+        &self,
+        database: &str,
+        _schema: Option<&str>,
+        table: &str,
+    ) -> Result<dbflux_core::TableStatistics, DbError> {
+        let mut conn = self
+            .catalog_conn
+            .lock()
+            .map_err(|e| DbError::query_failed(format!("Lock error: {}", e)))?;
+
+        fetch_table_statistics(&mut conn, database, table)
+    }
+
     fn list_databases(&self) -> Result<Vec<DatabaseInfo>, DbError> {
         let mut conn = self
             .catalog_conn
@@ -2048,12 +2164,6 @@ impl Connection for MysqlConnection {
 
         Ok(databases
             .into_iter()
-            .filter(|db| {
-                db != "information_schema"
-                    && db != "mysql"
-                    && db != "performance_schema"
-                    && db != "sys"
-            })
             .map(|name| DatabaseInfo {
                 name,
                 is_current: false,
@@ -2824,6 +2934,7 @@ fn mysql_execute_one_statement(
             }
 
             // Convert rows
+            let convert_start = Instant::now();
             let result_rows: Vec<Row> = rows
                 .iter()
                 .map(|row| {
@@ -2842,7 +2953,8 @@ fn mysql_execute_one_statement(
                 result_rows.len()
             );
 
-            Ok(QueryResult::table(columns, result_rows, None, query_time))
+            Ok(QueryResult::table(columns, result_rows, None, query_time)
+                .with_deserialize_time(convert_start.elapsed()))
         }
         Err(e) => {
             if cancelled.load(Ordering::SeqCst) {
@@ -2890,7 +3002,16 @@ fn mysql_value_to_value(row: &mysql::Row, idx: usize, col: &mysql::Column) -> Va
         }
     }
 
-    // Handle DATETIME and TIMESTAMP types using mysql's binary Date value
+    // Handle DATETIME and TIMESTAMP types using mysql's binary Date value.
+    // DATETIME has no time zone; TIMESTAMP is read as UTC.
+    let timestamp_value = |naive: chrono::NaiveDateTime| {
+        if col_type == ColumnType::MYSQL_TYPE_TIMESTAMP {
+            Value::DateTime(naive.and_utc())
+        } else {
+            Value::NaiveDateTime(naive)
+        }
+    };
+
     if matches!(
         col_type,
         ColumnType::MYSQL_TYPE_DATETIME | ColumnType::MYSQL_TYPE_TIMESTAMP
@@ -2907,12 +3028,7 @@ fn mysql_value_to_value(row: &mysql::Row, idx: usize, col: &mysql::Column) -> Va
                         *micro,
                     )
                 {
-                    let naive_dt = chrono::NaiveDateTime::new(naive_date, naive_time);
-                    let utc = chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
-                        naive_dt,
-                        chrono::Utc,
-                    );
-                    return Value::DateTime(utc);
+                    return timestamp_value(chrono::NaiveDateTime::new(naive_date, naive_time));
                 }
 
                 // Fallback: format as text
@@ -2927,11 +3043,7 @@ fn mysql_value_to_value(row: &mysql::Row, idx: usize, col: &mysql::Column) -> Va
                     if let Ok(naive) =
                         chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
                     {
-                        let utc = chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
-                            naive,
-                            chrono::Utc,
-                        );
-                        return Value::DateTime(utc);
+                        return timestamp_value(naive);
                     }
                     return Value::Text(s);
                 }
@@ -2995,6 +3107,54 @@ fn mysql_value_to_value(row: &mysql::Row, idx: usize, col: &mysql::Column) -> Va
         }
     }
 
+    // GEOMETRY is MySQL's internal SRID + WKB layout; keep it as bytes so it
+    // is never mistaken for text and round-trips as an `X'..'` literal.
+    if col_type == ColumnType::MYSQL_TYPE_GEOMETRY
+        && let Some(val) = row.get_opt::<Option<Vec<u8>>, _>(idx)
+    {
+        match val {
+            Ok(Some(v)) => return Value::Bytes(v),
+            Ok(None) => return Value::Null,
+            Err(_) => {}
+        }
+    }
+
+    // ENUM and SET arrive as strings with a flag on the column. Keep their
+    // labels apart from free text; a SET holds the labels it contains.
+    if col
+        .flags()
+        .intersects(ColumnFlags::ENUM_FLAG | ColumnFlags::SET_FLAG)
+        && let Some(val) = row.get_opt::<Option<String>, _>(idx)
+    {
+        match val {
+            Ok(Some(v)) if col.flags().contains(ColumnFlags::SET_FLAG) => {
+                return Value::Array(
+                    v.split(',')
+                        .filter(|label| !label.is_empty())
+                        .map(|label| Value::Enum(label.to_string()))
+                        .collect(),
+                );
+            }
+            Ok(Some(v)) => return Value::Enum(v),
+            Ok(None) => return Value::Null,
+            Err(_) => {}
+        }
+    }
+
+    // DECIMAL arrives as its exact decimal text in both protocols; keep it as
+    // a string so money columns are not rounded through `f64`.
+    if matches!(
+        col_type,
+        ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL
+    ) && let Some(val) = row.get_opt::<Option<String>, _>(idx)
+    {
+        match val {
+            Ok(Some(v)) => return Value::Decimal(v),
+            Ok(None) => return Value::Null,
+            Err(_) => {}
+        }
+    }
+
     // Try signed integer (covers most integer types)
     if let Some(val) = row.get_opt::<Option<i64>, _>(idx) {
         match val {
@@ -3060,14 +3220,22 @@ fn value_to_mysql_literal(value: &Value) -> String {
             }
         }
         Value::Decimal(s) => mysql_text_literal(s),
-        Value::Text(s) => mysql_text_literal(s),
+        Value::Text(s) | Value::Enum(s) => mysql_text_literal(s),
         Value::Json(s) => mysql_text_literal(s),
+        Value::Uuid(uuid) => mysql_text_literal(&uuid.to_string()),
+        Value::Interval(interval) => mysql_text_literal(&interval.to_string()),
         Value::Bytes(b) => format!("X'{}'", hex::encode(b)),
         Value::DateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S")),
+        Value::NaiveDateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
         Value::Date(d) => format!("'{}'", d.format("%Y-%m-%d")),
         Value::Time(t) => format!("'{}'", t.format("%H:%M:%S")),
         Value::ObjectId(id) => mysql_text_literal(id),
         Value::Unsupported(_) => "NULL".to_string(),
+        // A SET value is written back as its comma-separated labels.
+        Value::Array(arr) if !arr.is_empty() && arr.iter().all(|v| matches!(v, Value::Enum(_))) => {
+            let labels: Vec<String> = arr.iter().map(Value::as_display_string).collect();
+            mysql_text_literal(&labels.join(","))
+        }
         Value::Array(arr) => {
             let json = serde_json::to_string(arr).unwrap_or_else(|_| "[]".to_string());
             mysql_text_literal(&json)
@@ -3145,22 +3313,27 @@ fn mysql_text_literal(s: &str) -> String {
     }
 }
 
+/// MySQL reports a missing comment as an empty string.
+fn non_empty_comment(comment: Option<String>) -> Option<String> {
+    comment.filter(|comment| !comment.is_empty())
+}
+
 fn fetch_tables_shallow(conn: &mut Conn, database: &str) -> Result<Vec<TableInfo>, DbError> {
     let query = r"
-        SELECT table_name
+        SELECT table_name, table_comment
         FROM information_schema.tables
         WHERE table_schema = ?
           AND table_type = 'BASE TABLE'
         ORDER BY table_name
     ";
 
-    let table_names: Vec<String> = conn
+    let tables: Vec<(String, Option<String>)> = conn
         .exec(query, (database,))
         .map_err(|e| format_mysql_query_error(&e))?;
 
-    Ok(table_names
+    Ok(tables
         .into_iter()
-        .map(|name| TableInfo {
+        .map(|(name, comment)| TableInfo {
             name,
             schema: Some(database.to_string()),
             columns: None,
@@ -3170,10 +3343,81 @@ fn fetch_tables_shallow(conn: &mut Conn, database: &str) -> Result<Vec<TableInfo
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment: non_empty_comment(comment),
         })
         .collect())
 }
 
+/// Tables and views of several databases from a single catalog query, in the
+/// order of `databases`.
+fn fetch_schemas_shallow(
+    conn: &mut Conn,
+    databases: &[String],
+) -> Result<Vec<DbSchemaInfo>, DbError> {
+    if databases.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let placeholders = vec!["?"; databases.len()].join(", ");
+    let query = format!(
+        r"
+        SELECT table_schema, table_name, table_type, table_comment
+        FROM information_schema.tables
+        WHERE table_schema IN ({})
+          AND table_type IN ('BASE TABLE', 'VIEW')
+        ORDER BY table_schema, table_name
+        ",
+        placeholders
+    );
+
+    let params: Vec<mysql::Value> = databases
+        .iter()
+        .map(|database| mysql::Value::from(database.as_str()))
+        .collect();
+
+    let rows: Vec<(String, String, String, Option<String>)> = conn
+        .exec(query, params)
+        .map_err(|e| format_mysql_query_error(&e))?;
+
+    let mut schemas: Vec<DbSchemaInfo> = databases
+        .iter()
+        .map(|database| DbSchemaInfo {
+            name: database.clone(),
+            tables: Vec::new(),
+            views: Vec::new(),
+            custom_types: None,
+        })
+        .collect();
+
+    for (database, name, table_type, comment) in rows {
+        let Some(schema) = schemas.iter_mut().find(|s| s.name == database) else {
+            continue;
+        };
+
+        if table_type == "VIEW" {
+            schema.views.push(ViewInfo {
+                name,
+                schema: Some(database),
+            });
+        } else {
+            schema.tables.push(TableInfo {
+                name,
+                schema: Some(database),
+                columns: None,
+                indexes: None,
+                foreign_keys: None,
+                constraints: None,
+                sample_fields: None,
+                presentation: dbflux_core::CollectionPresentation::DataGrid,
+                child_items: None,
+                comment: non_empty_comment(comment),
+            });
+        }
+    }
+
+    Ok(schemas)
+}
+
 fn fetch_views(conn: &mut Conn, database: &str) -> Result<Vec<ViewInfo>, DbError> {
     let query = r"
         SELECT table_name
@@ -3196,8 +3440,35 @@ fn fetch_views(conn: &mut Conn, database: &str) -> Result<Vec<ViewInfo>, DbError
         .collect())
 }
 
+fn fetch_table_comment(
+    conn: &mut Conn,
+    database: &str,
+    table: &str,
+) -> Result<Option<String>, DbError> {
+    let comment: Option<Option<String>> = conn
+        .exec_first(
+            "SELECT table_comment FROM information_schema.tables
+             WHERE table_schema = ? AND table_name = ?",
+            (database, table),
+        )
+        .map_err(|e| format_mysql_query_error(&e))?;
+
+    Ok(non_empty_comment(comment.flatten()))
+}
+
 fn fetch_columns(conn: &mut Conn, database: &str, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
-    type ColumnMetadataRow = (String, String, String, Option<String>, Option<String>);
+    type ColumnMetadataRow = (
+        String,
+        String,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+    );
 
     let query = r"
         SELECT
@@ -3205,7 +3476,12 @@ fn fetch_columns(conn: &mut Conn, database: &str, table: &str) -> Result<Vec<Col
             column_type,
             is_nullable,
             column_default,
-            column_key
+            column_key,
+            column_comment,
+            extra,
+            character_set_name,
+            collation_name,
+            generation_expression
         FROM information_schema.columns
         WHERE table_schema = ?
           AND table_name = ?
@@ -3225,30 +3501,148 @@ fn fetch_columns(conn: &mut Conn, database: &str, table: &str) -> Result<Vec<Col
 
     Ok(rows
         .into_iter()
-        .map(|(name, type_name, nullable, default, key)| {
-            let is_pk = key.as_deref() == Some("PRI");
-            if is_pk {
-                log::info!(
-                    "[MYSQL] Column '{}' has Key='{:?}' -> is_primary_key={}",
-                    name,
-                    key,
-                    is_pk
-                );
-            }
-            let enum_values = parse_mysql_enum_or_set(&type_name);
-
-            ColumnInfo {
+        .map(
+            |(
                 name,
                 type_name,
-                nullable: nullable == "YES",
-                default_value: default,
-                is_primary_key: is_pk,
-                enum_values,
-            }
-        })
+                nullable,
+                default,
+                key,
+                comment,
+                extra,
+                charset,
+                collation,
+                generation_expression,
+            )| {
+                let is_pk = key.as_deref() == Some("PRI");
+                if is_pk {
+                    log::info!(
+                        "[MYSQL] Column '{}' has Key='{:?}' -> is_primary_key={}",
+                        name,
+                        key,
+                        is_pk
+                    );
+                }
+                let enum_values = parse_mysql_enum_or_set(&type_name);
+                let definition = MysqlColumnSpec {
+                    type_name: &type_name,
+                    nullable: nullable == "YES",
+                    default: default.as_deref(),
+                    extra: &extra,
+                    charset: charset.as_deref(),
+                    collation: collation.as_deref(),
+                    generation_expression: generation_expression.as_deref(),
+                }
+                .definition();
+
+                ColumnInfo {
+                    name,
+                    type_name,
+                    nullable: nullable == "YES",
+                    default_value: default,
+                    is_primary_key: is_pk,
+                    enum_values,
+                    comment: non_empty_comment(comment),
+                    definition: Some(definition),
+                }
+            },
+        )
         .collect())
 }
 
+/// A column as `information_schema.columns` describes it.
+struct MysqlColumnSpec<'a> {
+    type_name: &'a str,
+    nullable: bool,
+    default: Option<&'a str>,
+    /// `EXTRA`: `auto_increment`, `on update ...`, `DEFAULT_GENERATED`,
+    /// `VIRTUAL GENERATED`/`STORED GENERATED` and `INVISIBLE`.
+    extra: &'a str,
+    charset: Option<&'a str>,
+    collation: Option<&'a str>,
+    generation_expression: Option<&'a str>,
+}
+
+impl MysqlColumnSpec<'_> {
+    /// The column definition after its name, in the order `SHOW CREATE
+    /// TABLE` writes it.
+    fn definition(&self) -> String {
+        let extra = self.extra.to_ascii_lowercase();
+        let has = |flag: &str| extra.split_whitespace().any(|word| word == flag);
+
+        let mut definition = self.type_name.to_string();
+        if let Some(charset) = self.charset {
+            definition.push_str(&format!(" CHARACTER SET {}", charset));
+        }
+        if let Some(collation) = self.collation {
+            definition.push_str(&format!(" COLLATE {}", collation));
+        }
+
+        let generated = self
+            .generation_expression
+            .filter(|expression| !expression.is_empty() && has("generated"));
+        if let Some(expression) = generated {
+            let storage = if has("stored") || has("persistent") {
+                "STORED"
+            } else {
+                "VIRTUAL"
+            };
+            definition.push_str(&format!(
+                " GENERATED ALWAYS AS ({}) {}",
+                expression, storage
+            ));
+        }
+
+        definition.push_str(if self.nullable { " NULL" } else { " NOT NULL" });
+
+        if generated.is_none()
+            && let Some(default) = self.default.and_then(|d| mysql_default_sql(d, &extra))
+        {
+            definition.push_str(&format!(" DEFAULT {}", default));
+        }
+        if has("auto_increment") {
+            definition.push_str(" AUTO_INCREMENT");
+        }
+        if let Some(on_update) = extra.find("on update ") {
+            let expression = self.extra[on_update + "on update ".len()..]
+                .split_whitespace()
+                .next()
+                .unwrap_or_default();
+            definition.push_str(&format!(" ON UPDATE {}", expression));
+        }
+        if has("invisible") {
+            definition.push_str(" INVISIBLE");
+        }
+
+        definition
+    }
+}
+
+/// `column_default` as SQL, given the column's lowercased `EXTRA`. MySQL
+/// reports literal defaults unquoted and marks expression defaults
+/// `DEFAULT_GENERATED`; MariaDB quotes its literals and reports a missing
+/// default as `NULL`.
+fn mysql_default_sql(default: &str, extra: &str) -> Option<String> {
+    let lower = default.to_ascii_lowercase();
+    let is_timestamp = lower.starts_with("current_timestamp")
+        || lower.starts_with("now(")
+        || lower.starts_with("localtimestamp");
+
+    if default == "NULL" {
+        return None;
+    }
+
+    if is_timestamp || (default.len() > 1 && default.starts_with('\'') && default.ends_with('\'')) {
+        return Some(default.to_string());
+    }
+
+    if extra.contains("default_generated") {
+        return Some(format!("({})", default));
+    }
+
+    Some(mysql_text_literal(default))
+}
+
 fn fetch_indexes(conn: &mut Conn, database: &str, table: &str) -> Result<Vec<IndexInfo>, DbError> {
     let query = format!("SHOW INDEX FROM `{}`.`{}`", database, table);
 
@@ -3647,6 +4041,32 @@ fn mysql_routine_type_to_kind(routine_type: &str) -> RoutineKind {
     }
 }
 
+/// Row estimate and on-disk sizes of `database.table` as reported by
+/// `information_schema.TABLES`. InnoDB only samples `TABLE_ROWS`, so the count
+/// is approximate; views and tables of engines without statistics report NULL.
+fn fetch_table_statistics(
+    conn: &mut Conn,
+    database: &str,
+    table: &str,
+) -> Result<dbflux_core::TableStatistics, DbError> {
+    let row: Option<(Option<u64>, Option<u64>, Option<u64>)> = conn
+        .exec_first(
+            "SELECT TABLE_ROWS, DATA_LENGTH, INDEX_LENGTH
+             FROM information_schema.TABLES
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+            (database, table),
+        )
+        .map_err(|e| DbError::query_failed(format!("fetch_table_statistics: {}", e)))?;
+
+    let (row_estimate, data_bytes, index_bytes) = row.unwrap_or_default();
+
+    Ok(dbflux_core::TableStatistics {
+        row_estimate,
+        data_bytes,
+        index_bytes,
+    })
+}
+
 // =============================================================================
 // Dependents introspection (stub — not yet wired into ConnectedProfile cache)
 // =============================================================================
@@ -3727,15 +4147,173 @@ pub fn fetch_dependents(
 #[cfg(test)]
 mod tests {
     use super::{
-        MysqlDialect, MysqlDriver, inject_password_into_mysql_uri, mysql_routine_type_to_kind,
-        mysql_text_literal, normalize_mysql_tcp_host, plan_mysql_semantic_request,
+        MysqlCodeGenerator, MysqlColumnSpec, MysqlDialect, MysqlDriver,
+        inject_password_into_mysql_uri, mysql_routine_type_to_kind, mysql_text_literal,
+        normalize_mysql_tcp_host, plan_mysql_semantic_request,
     };
     use dbflux_core::{
-        DatabaseCategory, DbConfig, DbDriver, DbError, DbKind, FormValues, MutationRequest,
-        OrderByColumn, QueryLanguage, RoutineKind, RowInsert, SemanticRequest, SqlDialect,
-        TableBrowseRequest, TableRef, Value,
+        CodeGenerator, ColumnInfo, DatabaseCategory, DbConfig, DbDriver, DbError, DbKind,
+        FormValues, MutationRequest, OrderByColumn, QueryLanguage, RenameRequest, RoutineKind,
+        RowInsert, SemanticRequest, SetCommentRequest, SqlDialect, TableBrowseRequest, TableRef,
+        Value,
     };
 
+    #[test]
+    fn mysql_codegen_comments_on_tables_and_restates_columns() {
+        let generator = MysqlCodeGenerator;
+        let column = ColumnInfo {
+            name: "status".to_string(),
+            type_name: "varchar(16)".to_string(),
+            nullable: false,
+            is_primary_key: false,
+            default_value: Some("new".to_string()),
+            enum_values: None,
+            comment: Some("old".to_string()),
+            definition: None,
+        };
+
+        let table_sql = generator.generate_set_comment(&SetCommentRequest {
+            table_name: "orders",
+            schema_name: Some("shop"),
+            column: None,
+            comment: None,
+        });
+        let column_sql = generator.generate_set_comment(&SetCommentRequest {
+            table_name: "orders",
+            schema_name: Some("shop"),
+            column: Some(&column),
+            comment: Some("Order's state"),
+        });
+
+        assert_eq!(
+            table_sql.as_deref(),
+            Some("ALTER TABLE `shop`.`orders` COMMENT = '';")
+        );
+        assert!(column_sql.as_deref().is_some_and(|sql| sql.ends_with(
+            "ALTER TABLE `shop`.`orders` MODIFY COLUMN `status` varchar(16) NOT NULL \
+             DEFAULT 'new' COMMENT 'Order''s state';"
+        )));
+    }
+
+    #[test]
+    fn mysql_column_definition_keeps_what_information_schema_reports() {
+        let status = MysqlColumnSpec {
+            type_name: "varchar(16)",
+            nullable: false,
+            default: Some("it's new"),
+            extra: "",
+            charset: Some("utf8mb4"),
+            collation: Some("utf8mb4_bin"),
+            generation_expression: Some(""),
+        };
+        let id = MysqlColumnSpec {
+            type_name: "bigint unsigned",
+            nullable: false,
+            default: None,
+            extra: "auto_increment",
+            charset: None,
+            collation: None,
+            generation_expression: Some(""),
+        };
+        let updated_at = MysqlColumnSpec {
+            type_name: "timestamp",
+            nullable: true,
+            default: Some("CURRENT_TIMESTAMP"),
+            extra: "DEFAULT_GENERATED on update CURRENT_TIMESTAMP",
+            charset: None,
+            collation: None,
+            generation_expression: Some(""),
+        };
+        let total = MysqlColumnSpec {
+            type_name: "decimal(10,2)",
+            nullable: true,
+            default: None,
+            extra: "STORED GENERATED",
+            charset: None,
+            collation: None,
+            generation_expression: Some("(`price` * `quantity`)"),
+        };
+        let token = MysqlColumnSpec {
+            type_name: "char(36)",
+            nullable: false,
+            default: Some("uuid()"),
+            extra: "DEFAULT_GENERATED",
+            charset: None,
+            collation: None,
+            generation_expression: Some(""),
+        };
+
+        assert_eq!(
+            status.definition(),
+            "varchar(16) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL \
+             DEFAULT X'69742773206e6577'"
+        );
+        assert_eq!(id.definition(), "bigint unsigned NOT NULL AUTO_INCREMENT");
+        assert_eq!(
+            updated_at.definition(),
+            "timestamp NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP"
+        );
+        assert_eq!(
+            total.definition(),
+            "decimal(10,2) GENERATED ALWAYS AS ((`price` * `quantity`)) STORED NULL"
+        );
+        assert_eq!(token.definition(), "char(36) NOT NULL DEFAULT (uuid())");
+    }
+
+    #[test]
+    fn mysql_codegen_restates_auto_increment_columns_from_their_definition() {
+        let generator = MysqlCodeGenerator;
+        let column = ColumnInfo {
+            name: "id".to_string(),
+            type_name: "bigint unsigned".to_string(),
+            nullable: false,
+            is_primary_key: true,
+            default_value: None,
+            enum_values: None,
+            comment: None,
+            definition: Some("bigint unsigned NOT NULL AUTO_INCREMENT".to_string()),
+        };
+
+        let sql = generator.generate_set_comment(&SetCommentRequest {
+            table_name: "orders",
+            schema_name: Some("shop"),
+            column: Some(&column),
+            comment: Some("Order number"),
+        });
+
+        assert!(sql.as_deref().is_some_and(|sql| sql.ends_with(
+            "ALTER TABLE `shop`.`orders` MODIFY COLUMN `id` bigint unsigned NOT NULL \
+             AUTO_INCREMENT COMMENT 'Order number';"
+        )));
+    }
+
+    #[test]
+    fn mysql_codegen_renames_tables_within_their_database() {
+        let generator = MysqlCodeGenerator;
+
+        let table_sql = generator.generate_rename(&RenameRequest {
+            table_name: "orders",
+            schema_name: Some("shop"),
+            column: None,
+            new_name: "purchases",
+        });
+        let column_sql = generator.generate_rename(&RenameRequest {
+            table_name: "orders",
+            schema_name: Some("shop"),
+            column: Some("status"),
+            new_name: "state",
+        });
+
+        assert_eq!(
+            table_sql.as_deref(),
+            Some("RENAME TABLE `shop`.`orders` TO `shop`.`purchases`;")
+        );
+        assert_eq!(
+            column_sql.as_deref(),
+            Some("ALTER TABLE `shop`.`orders` RENAME COLUMN `status` TO `state`;")
+        );
+    }
+
     #[test]
     fn build_and_parse_uri_roundtrip_basics() {
         let driver = MysqlDriver::new(DbKind::MySQL);
@@ -330,6 +330,7 @@ fn single_sample_result(columns: Vec<ColumnMeta>, values: Vec<Value>) -> QueryRe
         rows: vec![row],
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -578,6 +579,7 @@ fn fetch_processlist(conn: &mut Conn) -> Result<QueryResult, DbError> {
         rows: result_rows,
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
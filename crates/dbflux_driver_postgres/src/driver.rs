@@ -15,20 +15,22 @@ use dbflux_core::{
     DbConfig, DbDriver, DbError, DbKind, DbSchemaInfo, DdlCapabilities, DeploymentClass,
     DescribeRequest, DocumentConnection, DriverCapabilities, DriverFormDef, DriverLimits,
     DriverMetadata, DropForeignKeyRequest, DropIndexRequest, DropTypeRequest, ErrorLocation,
-    ExecutionSourceContext, ExplainRequest, FieldExportTransform, ForeignKeyBuilder,
+    ErrorSeverity, ExecutionSourceContext, ExplainRequest, FieldExportTransform, ForeignKeyBuilder,
     ForeignKeyInfo, FormFieldKind, FormSection, FormTab, FormValues, FormattedError, Icon,
-    IndexData, IndexInfo, InstanceCatalog, IsolationLevel, KeyValueConnection,
+    IndexData, IndexInfo, InstanceCatalog, Interval, IsolationLevel, KeyValueConnection,
     MutationCapabilities, OrderByColumn, PaginationStyle, PlaceholderStyle, QueryCancelHandle,
     QueryCapabilities, QueryErrorFormatter, QueryGenerator, QueryHandle, QueryLanguage,
-    QueryRequest, QueryResult, ReindexRequest, RelationalConnection, RelationalSchema, RoutineInfo,
-    RoutineKind, Row, RowDelete, RowInsert, RowPatch, SchemaFeatures, SchemaForeignKeyBuilder,
-    SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaSnapshot, SemanticPlan,
-    SemanticPlanKind, SemanticRequest, SortDirection, SqlDialect, SqlMutationGenerator,
-    SqlQueryBuilder, SshTunnelConfig, SyntaxInfo, TableInfo, TransactionCapabilities,
-    TypeDefinition, Value, ViewInfo, WhereOperator, field_password, field_required, field_use_uri,
-    generate_create_table, generate_delete_template, generate_drop_table, generate_insert_template,
-    generate_select_star, generate_truncate, generate_update_template, render_semantic_filter_sql,
-    sanitize_uri, ssh_tab, when_checked, when_unchecked, with_default, with_help,
+    QueryRequest, QueryResult, ReindexRequest, RelationalConnection, RelationalSchema,
+    RenameRequest, RoutineInfo, RoutineKind, Row, RowDelete, RowInsert, RowPatch, SchemaFeatures,
+    SchemaForeignKeyBuilder, SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy,
+    SchemaSnapshot, SemanticPlan, SemanticPlanKind, SemanticRequest, SessionGate,
+    SetCommentRequest, SortDirection, SpatialValue, SqlDialect, SqlMutationGenerator,
+    SqlQueryBuilder, SshTunnelConfig, SyntaxInfo, TableInfo, TempColumnType,
+    TransactionCapabilities, TypeDefinition, Value, ViewInfo, WhereOperator, field_password,
+    field_required, field_use_uri, generate_create_table, generate_delete_template,
+    generate_drop_table, generate_insert_template, generate_select_star, generate_truncate,
+    generate_update_template, render_semantic_filter_sql, sanitize_uri, ssh_tab, when_checked,
+    when_unchecked, with_default, with_help,
 };
 use dbflux_ssh::SshTunnel;
 use native_tls::TlsConnector;
@@ -62,7 +64,9 @@ pub static METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMetadata
             | DriverCapabilities::MULTI_STATEMENT.bits()
             | DriverCapabilities::INSTANCE_METRICS.bits()
             | DriverCapabilities::INSTANCE_INSPECTOR.bits()
-            | DriverCapabilities::CHART_AUTHORING.bits(),
+            | DriverCapabilities::CHART_AUTHORING.bits()
+            | DriverCapabilities::TEMP_TABLES.bits()
+            | DriverCapabilities::INCREMENTAL_FETCH.bits(),
     ),
     default_port: Some(5432),
     uri_scheme: "postgresql".into(),
@@ -76,7 +80,7 @@ pub static METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMetadata
         case_sensitive_identifiers: true,
     }),
     query: Some(QueryCapabilities {
-        pagination: vec![PaginationStyle::Offset],
+        pagination: vec![PaginationStyle::Offset, PaginationStyle::Keyset],
         where_operators: vec![
             WhereOperator::Eq,
             WhereOperator::Ne,
@@ -237,6 +241,19 @@ impl SqlDialect for PostgresDialect {
         PlaceholderStyle::DollarNumber
     }
 
+    fn text_contains_expr(&self, column: &str, text: &str, case_sensitive: bool) -> String {
+        let pattern = self.value_to_literal(&Value::Text(dbflux_core::like_contains_pattern(text)));
+        let operator = if case_sensitive { "LIKE" } else { "ILIKE" };
+        format!("{column} {operator} {pattern} ESCAPE '!'")
+    }
+
+    fn regex_match_expr(&self, column: &str, pattern: &str) -> Option<String> {
+        Some(format!(
+            "{column} ~ {}",
+            self.value_to_literal(&Value::Text(pattern.to_string()))
+        ))
+    }
+
     fn supports_returning(&self) -> bool {
         true
     }
@@ -310,6 +327,24 @@ impl SqlDialect for PostgresDialect {
             table, columns, values, conflict_columns, update_clause
         ))
     }
+
+    fn drop_temp_table_statement(&self, identifier: &str) -> Option<String> {
+        // `pg_temp` resolves to this session's temp schema, so a permanent
+        // table with the same name is never touched.
+        Some(format!(
+            "DROP TABLE IF EXISTS pg_temp.{}",
+            pg_quote_ident(identifier)
+        ))
+    }
+
+    fn temp_column_type(&self, ty: TempColumnType) -> &'static str {
+        match ty {
+            TempColumnType::Timestamp => "TIMESTAMPTZ",
+            TempColumnType::Json => "JSONB",
+            TempColumnType::Binary => "BYTEA",
+            other => other.ansi_name(),
+        }
+    }
 }
 
 static POSTGRES_DIALECT: PostgresDialect = PostgresDialect;
@@ -475,6 +510,42 @@ impl CodeGenerator for PostgresCodeGenerator {
             type_name, req.new_value
         ))
     }
+
+    fn generate_set_comment(&self, req: &SetCommentRequest) -> Option<String> {
+        let table = self.qualified(req.schema_name, req.table_name);
+        let comment = req.comment.map_or_else(
+            || "NULL".to_string(),
+            |comment| format!("'{}'", POSTGRES_DIALECT.escape_string(comment)),
+        );
+
+        Some(match req.column {
+            Some(column) => format!(
+                "COMMENT ON COLUMN {}.{} IS {};",
+                table,
+                self.quote(&column.name),
+                comment
+            ),
+            None => format!("COMMENT ON TABLE {} IS {};", table, comment),
+        })
+    }
+
+    fn generate_rename(&self, req: &RenameRequest) -> Option<String> {
+        let table = self.qualified(req.schema_name, req.table_name);
+
+        Some(match req.column {
+            Some(column) => format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                table,
+                self.quote(column),
+                self.quote(req.new_name)
+            ),
+            None => format!(
+                "ALTER TABLE {} RENAME TO {};",
+                table,
+                self.quote(req.new_name)
+            ),
+        })
+    }
 }
 
 // =============================================================================
@@ -1006,6 +1077,7 @@ impl PostgresDriver {
             log::info!("[CONNECT] PostgreSQL connection established via URI");
 
             return Ok(Box::new(PostgresConnection {
+                session_gate: SessionGate::new(),
                 client: Arc::new(Mutex::new(client)),
                 ssh_tunnel: None,
                 cancel_token,
@@ -1035,6 +1107,7 @@ impl PostgresDriver {
         log::info!("[CONNECT] PostgreSQL connection established via URI");
 
         Ok(Box::new(PostgresConnection {
+            session_gate: SessionGate::new(),
             client: Arc::new(Mutex::new(client)),
             ssh_tunnel: None,
             cancel_token,
@@ -1073,6 +1146,7 @@ impl PostgresDriver {
         log::info!("Successfully connected to {}:{}", host, port);
 
         Ok(Box::new(PostgresConnection {
+            session_gate: SessionGate::new(),
             client: Arc::new(Mutex::new(client)),
             ssh_tunnel: None,
             cancel_token,
@@ -1151,6 +1225,7 @@ impl PostgresDriver {
         );
 
         Ok(Box::new(PostgresConnection {
+            session_gate: SessionGate::new(),
             client: Arc::new(Mutex::new(client)),
             ssh_tunnel: Some(tunnel),
             cancel_token,
@@ -1190,6 +1265,7 @@ fn parse_pg_uri_sslmode(uri: &str) -> PgUriSslMode {
 }
 
 pub struct PostgresConnection {
+    session_gate: SessionGate,
     client: Arc<Mutex<Client>>,
     #[allow(dead_code)]
     ssh_tunnel: Option<SshTunnel>,
@@ -1251,41 +1327,8 @@ fn plan_postgres_table_browse(
     request: &dbflux_core::TableBrowseRequest,
 ) -> Result<SemanticPlan, DbError> {
     let sql = if let Some(filter) = request.semantic_filter.as_ref() {
-        let mut sql = format!(
-            "SELECT * FROM {}",
-            request.table.quoted_with(&POSTGRES_DIALECT)
-        );
         let where_clause = render_semantic_filter_sql(filter, &POSTGRES_DIALECT)?;
-        sql.push_str(" WHERE ");
-        sql.push_str(&where_clause);
-
-        if !request.order_by.is_empty() {
-            let order_by = request
-                .order_by
-                .iter()
-                .map(|column| {
-                    let direction = match column.direction {
-                        SortDirection::Ascending => "ASC",
-                        SortDirection::Descending => "DESC",
-                    };
-                    format!(
-                        "{} {}",
-                        column.column.quoted_with(&POSTGRES_DIALECT),
-                        direction
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
-            sql.push_str(" ORDER BY ");
-            sql.push_str(&order_by);
-        }
-
-        sql.push_str(&format!(
-            " LIMIT {} OFFSET {}",
-            request.pagination.limit(),
-            request.pagination.offset()
-        ));
-        sql
+        request.build_sql_with_where(&POSTGRES_DIALECT, Some(&where_clause))
     } else {
         request.build_sql_with(&POSTGRES_DIALECT)
     };
@@ -1478,7 +1521,13 @@ impl Connection for PostgresConnection {
         ))
     }
 
+    fn session_gate(&self) -> Option<&SessionGate> {
+        Some(&self.session_gate)
+    }
+
     fn execute(&self, req: &QueryRequest) -> Result<QueryResult, DbError> {
+        let _session = self.session_gate.enter(|request| self.execute(request))?;
+
         self.cancelled.store(false, Ordering::SeqCst);
 
         if let Some(source) = req
@@ -1598,7 +1647,8 @@ impl Connection for PostgresConnection {
             columns.len()
         );
 
-        Ok(QueryResult::table(columns, result_rows, None, total_time))
+        Ok(QueryResult::table(columns, result_rows, None, total_time)
+            .with_deserialize_time(total_time - query_time))
     }
 
     fn cancel(&self, handle: &QueryHandle) -> Result<(), DbError> {
@@ -1779,6 +1829,7 @@ impl Connection for PostgresConnection {
         let indexes = get_indexes(&mut client, schema_name, table)?;
         let foreign_keys = get_foreign_keys(&mut client, schema_name, table)?;
         let constraints = get_constraints(&mut client, schema_name, table)?;
+        let comment = get_table_comment(&mut client, schema_name, table)?;
 
         log::info!(
             "[SCHEMA] Table {}.{}: {} columns, {} indexes, {} FKs, {} constraints",
@@ -1800,6 +1851,7 @@ impl Connection for PostgresConnection {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment,
         })
     }
 
@@ -1972,6 +2024,31 @@ impl Connection for PostgresConnection {
         fetch_dependents(&mut client, schema_name, table)
     }
 
+    fn table_statistics(
+        &self,
+        _database: &str,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Result<dbflux_core::TableStatistics, DbError> {
+        let schema_name = schema.unwrap_or("public");
+
+        let mut client = self
+            .client
+            .lock()
+            .map_err(|e| DbError::QueryFailed(format!("Lock error: {}", e).into()))?;
+
+        fetch_table_statistics(&mut client, schema_name, table)
+    }
+
+    fn query_statistics(&self, limit: usize) -> Result<Vec<dbflux_core::WorkloadQuery>, DbError> {
+        let mut client = self
+            .client
+            .lock()
+            .map_err(|e| DbError::QueryFailed(format!("Lock error: {}", e).into()))?;
+
+        fetch_query_statistics(&mut client, limit)
+    }
+
     fn fetch_row_by_pk(
         &self,
         _database: &str,
@@ -2487,7 +2564,6 @@ fn get_schemas(client: &mut Client) -> Result<Vec<DbSchemaInfo>, DbError> {
             r#"
             SELECT schema_name
             FROM information_schema.schemata
-            WHERE schema_name NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
             ORDER BY schema_name
             "#,
             &[],
@@ -2500,89 +2576,105 @@ fn get_schemas(client: &mut Client) -> Result<Vec<DbSchemaInfo>, DbError> {
         phase_start.elapsed().as_secs_f64() * 1000.0
     );
 
-    let mut schemas = Vec::new();
-
-    for row in schema_rows {
-        let schema_name: String = row.get(0);
-        let schema_start = Instant::now();
-
-        let tables = get_tables_for_schema(client, &schema_name)?;
-        let views = get_views_for_schema(client, &schema_name)?;
+    // Tables and views of every schema come back in one query each, rather
+    // than two round-trips per schema, which dominated connect time on
+    // servers with many schemas.
+    let phase_start = Instant::now();
+    let mut tables = get_tables_by_schema(client)?;
+    let mut views = get_views_by_schema(client)?;
 
-        log::info!(
-            "[SCHEMA] Schema '{}': {} tables, {} views in {:.2}ms",
-            schema_name,
-            tables.len(),
-            views.len(),
-            schema_start.elapsed().as_secs_f64() * 1000.0
-        );
+    let schemas: Vec<DbSchemaInfo> = schema_rows
+        .iter()
+        .map(|row| {
+            let schema_name: String = row.get(0);
+            DbSchemaInfo {
+                tables: tables.remove(&schema_name).unwrap_or_default(),
+                views: views.remove(&schema_name).unwrap_or_default(),
+                name: schema_name,
+                custom_types: None,
+            }
+        })
+        .collect();
 
-        schemas.push(DbSchemaInfo {
-            name: schema_name,
-            tables,
-            views,
-            custom_types: None,
-        });
-    }
+    log::info!(
+        "[SCHEMA] Listed {} tables and {} views in {:.2}ms",
+        schemas.iter().map(|s| s.tables.len()).sum::<usize>(),
+        schemas.iter().map(|s| s.views.len()).sum::<usize>(),
+        phase_start.elapsed().as_secs_f64() * 1000.0
+    );
 
     Ok(schemas)
 }
 
-fn get_tables_for_schema(client: &mut Client, schema: &str) -> Result<Vec<TableInfo>, DbError> {
+/// Base tables of every schema, keyed by schema name.
+fn get_tables_by_schema(client: &mut Client) -> Result<HashMap<String, Vec<TableInfo>>, DbError> {
     let rows = client
         .query(
             r#"
-            SELECT table_name
+            SELECT
+                table_schema,
+                table_name,
+                obj_description(
+                    (quote_ident(table_schema) || '.' || quote_ident(table_name))::regclass,
+                    'pg_class'
+                ) AS comment
             FROM information_schema.tables
             WHERE table_type = 'BASE TABLE'
-              AND table_schema = $1
-            ORDER BY table_name
+            ORDER BY table_schema, table_name
             "#,
-            &[&schema],
+            &[],
         )
         .map_err(|e| format_pg_query_error(&e))?;
 
-    let tables = rows
-        .iter()
-        .map(|row| {
-            let name: String = row.get(0);
-            TableInfo {
-                name,
-                schema: Some(schema.to_string()),
-                columns: None,
-                indexes: None,
-                foreign_keys: None,
-                constraints: None,
-                sample_fields: None,
-                presentation: dbflux_core::CollectionPresentation::DataGrid,
-                child_items: None,
-            }
-        })
-        .collect();
+    let mut tables: HashMap<String, Vec<TableInfo>> = HashMap::new();
+
+    for row in &rows {
+        let schema: String = row.get(0);
+        let table = TableInfo {
+            name: row.get(1),
+            schema: Some(schema.clone()),
+            columns: None,
+            indexes: None,
+            foreign_keys: None,
+            constraints: None,
+            sample_fields: None,
+            presentation: dbflux_core::CollectionPresentation::DataGrid,
+            child_items: None,
+            comment: row.get(2),
+        };
+
+        tables.entry(schema).or_default().push(table);
+    }
 
     Ok(tables)
 }
 
-fn get_views_for_schema(client: &mut Client, schema: &str) -> Result<Vec<ViewInfo>, DbError> {
+/// Views of every schema, keyed by schema name.
+fn get_views_by_schema(client: &mut Client) -> Result<HashMap<String, Vec<ViewInfo>>, DbError> {
     let rows = client
         .query(
             r#"
-            SELECT table_name
+            SELECT table_schema, table_name
             FROM information_schema.views
-            WHERE table_schema = $1
-            ORDER BY table_name
+            ORDER BY table_schema, table_name
             "#,
-            &[&schema],
+            &[],
         )
         .map_err(|e| format_pg_query_error(&e))?;
 
-    Ok(rows
-        .iter()
-        .map(|row| ViewInfo {
-            name: row.get(0),
-            schema: Some(schema.to_string()),
-        })
-        .collect())
+    let mut views: HashMap<String, Vec<ViewInfo>> = HashMap::new();
+
+    for row in &rows {
+        let schema: String = row.get(0);
+        let view = ViewInfo {
+            name: row.get(1),
+            schema: Some(schema.clone()),
+        };
+
+        views.entry(schema).or_default().push(view);
+    }
+
+    Ok(views)
 }
 
 #[allow(dead_code)]
@@ -2601,7 +2693,8 @@ fn get_columns(client: &mut Client, schema: &str, table: &str) -> Result<Vec<Col
                        AND ix.indisprimary
                        AND a.attnum = ANY(ix.indkey)),
                     false
-                ) AS is_pk
+                ) AS is_pk,
+                col_description(c.oid, a.attnum) AS comment
             FROM pg_attribute a
             JOIN pg_class c ON c.oid = a.attrelid
             JOIN pg_namespace n ON n.oid = c.relnamespace
@@ -2625,6 +2718,8 @@ fn get_columns(client: &mut Client, schema: &str, table: &str) -> Result<Vec<Col
             default_value: row.get(3),
             is_primary_key: row.get(4),
             enum_values: None,
+            comment: row.get(5),
+            definition: None,
         })
         .collect();
 
@@ -2638,6 +2733,27 @@ fn get_columns(client: &mut Client, schema: &str, table: &str) -> Result<Vec<Col
     Ok(columns)
 }
 
+fn get_table_comment(
+    client: &mut Client,
+    schema: &str,
+    table: &str,
+) -> Result<Option<String>, DbError> {
+    let row = client
+        .query_opt(
+            r#"
+            SELECT obj_description(c.oid, 'pg_class')
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1
+              AND c.relname = $2
+            "#,
+            &[&schema, &table],
+        )
+        .map_err(|e| format_pg_query_error(&e))?;
+
+    Ok(row.and_then(|row| row.get(0)))
+}
+
 /// Fetch enum values for all enum-typed columns in a table, keyed by type name.
 fn fetch_enum_values_for_columns(
     client: &mut Client,
@@ -2695,7 +2811,8 @@ fn get_all_columns_for_schema(
                        AND ix.indisprimary
                        AND a.attnum = ANY(ix.indkey)),
                     false
-                ) AS is_pk
+                ) AS is_pk,
+                col_description(c.oid, a.attnum) AS comment
             FROM pg_attribute a
             JOIN pg_class c ON c.oid = a.attrelid
             JOIN pg_namespace n ON n.oid = c.relnamespace
@@ -2721,6 +2838,8 @@ fn get_all_columns_for_schema(
             default_value: row.get(4),
             is_primary_key: row.get(5),
             enum_values: None,
+            comment: row.get(6),
+            definition: None,
         };
         result.entry(table_name).or_default().push(column);
     }
@@ -3153,9 +3272,16 @@ fn value_to_pg_literal(value: &Value) -> String {
         }
         Value::Decimal(s) => format!("'{}'::numeric", pg_escape_string(s)),
         Value::Text(s) => pg_quote_string(s),
+        // Left untyped so PostgreSQL resolves it to the column's enum type.
+        Value::Enum(label) => pg_quote_string(label),
+        Value::Uuid(uuid) => format!("'{}'::uuid", uuid),
+        Value::Interval(interval) => format!("'{}'::interval", interval),
         Value::Json(s) => format!("{}::jsonb", pg_quote_string(s)),
         Value::Bytes(b) => format!("'\\x{}'::bytea", hex::encode(b)),
         Value::DateTime(dt) => format!("'{}'::timestamptz", dt.to_rfc3339()),
+        Value::NaiveDateTime(dt) => {
+            format!("'{}'::timestamp", dt.format("%Y-%m-%d %H:%M:%S%.f"))
+        }
         Value::Date(d) => format!("'{}'::date", d.format("%Y-%m-%d")),
         Value::Time(t) => format!("'{}'::time", t.format("%H:%M:%S%.f")),
         Value::ObjectId(id) => pg_quote_string(id),
@@ -3212,6 +3338,143 @@ impl<'a> FromSql<'a> for PgText {
     }
 }
 
+/// A `numeric` value decoded from its binary wire format into an exact
+/// decimal string, so values wider than `f64` keep every digit.
+struct PgNumeric(String);
+
+const PG_NUMERIC_POS: u16 = 0x0000;
+const PG_NUMERIC_NEG: u16 = 0x4000;
+const PG_NUMERIC_NAN: u16 = 0xC000;
+const PG_NUMERIC_PINF: u16 = 0xD000;
+const PG_NUMERIC_NINF: u16 = 0xF000;
+
+/// Decodes `numeric`'s binary format: a header of digit count, weight of the
+/// first digit, sign and display scale, followed by base-10000 digits.
+fn decode_pg_numeric(raw: &[u8]) -> Result<String, Box<dyn std::error::Error + Sync + Send>> {
+    let header = |at: usize| -> Result<u16, Box<dyn std::error::Error + Sync + Send>> {
+        raw.get(at..at + 2)
+            .and_then(|bytes| <[u8; 2]>::try_from(bytes).ok())
+            .map(u16::from_be_bytes)
+            .ok_or_else(|| "numeric value is truncated".into())
+    };
+
+    let ndigits = header(0)? as usize;
+    let weight = header(2)? as i16 as i32;
+    let sign = header(4)?;
+    let dscale = header(6)? as usize;
+
+    let digits = raw
+        .get(8..)
+        .filter(|rest| rest.len() == ndigits * 2)
+        .ok_or("numeric value has the wrong length")?
+        .chunks_exact(2)
+        .filter_map(|pair| <[u8; 2]>::try_from(pair).ok())
+        .map(u16::from_be_bytes)
+        .collect::<Vec<_>>();
+
+    if digits.iter().any(|&digit| digit > 9999) {
+        return Err("numeric digit out of range".into());
+    }
+
+    let mut out = match sign {
+        PG_NUMERIC_NAN => return Ok("NaN".to_string()),
+        PG_NUMERIC_PINF => return Ok("Infinity".to_string()),
+        PG_NUMERIC_NINF => return Ok("-Infinity".to_string()),
+        PG_NUMERIC_NEG => "-".to_string(),
+        PG_NUMERIC_POS => String::new(),
+        other => return Err(format!("invalid numeric sign 0x{other:04x}").into()),
+    };
+
+    let digit_at = |position: i32| -> u16 {
+        usize::try_from(position)
+            .ok()
+            .and_then(|position| digits.get(position).copied())
+            .unwrap_or(0)
+    };
+
+    if weight < 0 {
+        out.push('0');
+    } else {
+        out.push_str(&digit_at(0).to_string());
+        for position in 1..=weight {
+            out.push_str(&format!("{:04}", digit_at(position)));
+        }
+    }
+
+    if dscale > 0 {
+        let mut fraction = String::with_capacity(dscale + 4);
+        let mut position = weight + 1;
+        while fraction.len() < dscale {
+            fraction.push_str(&format!("{:04}", digit_at(position)));
+            position += 1;
+        }
+        fraction.truncate(dscale);
+
+        out.push('.');
+        out.push_str(&fraction);
+    }
+
+    Ok(out)
+}
+
+impl<'a> FromSql<'a> for PgNumeric {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        decode_pg_numeric(raw).map(PgNumeric)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        ty.name() == "numeric"
+    }
+}
+
+/// An `interval` decoded from its binary wire format: microseconds, days and
+/// months, in that order.
+struct PgInterval(Interval);
+
+impl<'a> FromSql<'a> for PgInterval {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let (microseconds, rest) = raw
+            .split_at_checked(8)
+            .ok_or("interval value is too short")?;
+        let (days, months) = rest
+            .split_at_checked(4)
+            .ok_or("interval value is too short")?;
+
+        Ok(PgInterval(Interval::new(
+            i32::from_be_bytes(months.try_into()?),
+            i32::from_be_bytes(days.try_into()?),
+            i64::from_be_bytes(microseconds.try_into()?),
+        )))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        ty.name() == "interval"
+    }
+}
+
+/// Binary wire value of a PostGIS `geometry` or `geography`, which PostGIS
+/// sends as EWKB.
+struct PgSpatial(Vec<u8>);
+
+impl<'a> FromSql<'a> for PgSpatial {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(PgSpatial(raw.to_vec()))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.name(), "geometry" | "geography")
+    }
+}
+
 fn postgres_array_to_value(row: &postgres::Row, idx: usize, type_name: &str) -> Option<Value> {
     match type_name {
         "_bool" => match row.try_get::<_, Option<Vec<bool>>>(idx) {
@@ -3256,6 +3519,19 @@ fn postgres_array_to_value(row: &postgres::Row, idx: usize, type_name: &str) ->
             Err(_) => None,
         },
 
+        "_numeric" => match row.try_get::<_, Option<Vec<Option<PgNumeric>>>>(idx) {
+            Ok(Some(arr)) => Some(Value::Array(
+                arr.into_iter()
+                    .map(|v| {
+                        v.map(|PgNumeric(d)| Value::Decimal(d))
+                            .unwrap_or(Value::Null)
+                    })
+                    .collect(),
+            )),
+            Ok(None) => Some(Value::Null),
+            Err(_) => None,
+        },
+
         "_text" | "_varchar" | "_bpchar" | "_name" | "_citext" => {
             match row.try_get::<_, Option<Vec<String>>>(idx) {
                 Ok(Some(arr)) => Some(Value::Array(arr.into_iter().map(Value::Text).collect())),
@@ -3265,11 +3541,7 @@ fn postgres_array_to_value(row: &postgres::Row, idx: usize, type_name: &str) ->
         }
 
         "_uuid" => match row.try_get::<_, Option<Vec<Uuid>>>(idx) {
-            Ok(Some(arr)) => Some(Value::Array(
-                arr.into_iter()
-                    .map(|uuid| Value::Text(uuid.to_string()))
-                    .collect(),
-            )),
+            Ok(Some(arr)) => Some(Value::Array(arr.into_iter().map(Value::Uuid).collect())),
             Ok(None) => Some(Value::Null),
             Err(_) => None,
         },
@@ -3298,9 +3570,7 @@ fn postgres_array_to_value(row: &postgres::Row, idx: usize, type_name: &str) ->
 
         "_timestamp" => match row.try_get::<_, Option<Vec<NaiveDateTime>>>(idx) {
             Ok(Some(arr)) => Some(Value::Array(
-                arr.into_iter()
-                    .map(|ts| Value::DateTime(DateTime::<Utc>::from_naive_utc_and_offset(ts, Utc)))
-                    .collect(),
+                arr.into_iter().map(Value::NaiveDateTime).collect(),
             )),
             Ok(None) => Some(Value::Null),
             Err(_) => None,
@@ -3312,6 +3582,16 @@ fn postgres_array_to_value(row: &postgres::Row, idx: usize, type_name: &str) ->
             Err(_) => None,
         },
 
+        "_interval" => match row.try_get::<_, Option<Vec<PgInterval>>>(idx) {
+            Ok(Some(arr)) => Some(Value::Array(
+                arr.into_iter()
+                    .map(|PgInterval(interval)| Value::Interval(interval))
+                    .collect(),
+            )),
+            Ok(None) => Some(Value::Null),
+            Err(_) => None,
+        },
+
         "_inet" => match row.try_get::<_, Option<Vec<IpAddr>>>(idx) {
             Ok(Some(arr)) => Some(Value::Array(
                 arr.into_iter()
@@ -3366,11 +3646,20 @@ fn postgres_value_to_value(row: &postgres::Row, idx: usize) -> Value {
             })
             .unwrap_or(Value::Null),
 
-        "float8" | "numeric" => row
+        "float8" => row
             .try_get::<_, Option<f64>>(idx)
             .map(|value| value.map(Value::Float).unwrap_or(Value::Null))
             .unwrap_or(Value::Null),
 
+        "numeric" => row
+            .try_get::<_, Option<PgNumeric>>(idx)
+            .map(|value| {
+                value
+                    .map(|PgNumeric(decimal)| Value::Decimal(decimal))
+                    .unwrap_or(Value::Null)
+            })
+            .unwrap_or(Value::Null),
+
         "text" | "varchar" | "bpchar" | "name" | "citext" => row
             .try_get::<_, Option<String>>(idx)
             .map(|value| value.map(Value::Text).unwrap_or(Value::Null))
@@ -3387,9 +3676,14 @@ fn postgres_value_to_value(row: &postgres::Row, idx: usize) -> Value {
 
         "uuid" => row
             .try_get::<_, Option<Uuid>>(idx)
+            .map(|value| value.map(Value::Uuid).unwrap_or(Value::Null))
+            .unwrap_or(Value::Null),
+
+        "interval" => row
+            .try_get::<_, Option<PgInterval>>(idx)
             .map(|value| {
                 value
-                    .map(|uuid| Value::Text(uuid.to_string()))
+                    .map(|PgInterval(interval)| Value::Interval(interval))
                     .unwrap_or(Value::Null)
             })
             .unwrap_or(Value::Null),
@@ -3415,13 +3709,7 @@ fn postgres_value_to_value(row: &postgres::Row, idx: usize) -> Value {
 
         "timestamp" => row
             .try_get::<_, Option<NaiveDateTime>>(idx)
-            .map(|value| {
-                value
-                    .map(|timestamp| {
-                        Value::DateTime(DateTime::<Utc>::from_naive_utc_and_offset(timestamp, Utc))
-                    })
-                    .unwrap_or(Value::Null)
-            })
+            .map(|value| value.map(Value::NaiveDateTime).unwrap_or(Value::Null))
             .unwrap_or(Value::Null),
 
         "timestamptz" => row
@@ -3443,9 +3731,21 @@ fn postgres_value_to_value(row: &postgres::Row, idx: usize) -> Value {
             .map(|value| value.map(Value::Bytes).unwrap_or(Value::Null))
             .unwrap_or(Value::Null),
 
+        // Shown as EWKT, which PostGIS also accepts back when the cell is
+        // edited; bytes that do not decode are kept as they are.
+        "geometry" | "geography" => row
+            .try_get::<_, Option<PgSpatial>>(idx)
+            .map(|value| match value {
+                Some(PgSpatial(ewkb)) => SpatialValue::from_wkb(&ewkb)
+                    .map(|spatial| Value::Text(spatial.to_ewkt()))
+                    .unwrap_or(Value::Bytes(ewkb)),
+                None => Value::Null,
+            })
+            .unwrap_or(Value::Null),
+
         _ => match col_type.kind() {
             Kind::Enum(_) => match row.try_get::<_, Option<PgText>>(idx) {
-                Ok(Some(PgText(s))) => Value::Text(s),
+                Ok(Some(PgText(s))) => Value::Enum(s),
                 Ok(None) => Value::Null,
                 Err(e) => {
                     let col_name = row.columns()[idx].name();
@@ -3479,9 +3779,14 @@ fn postgres_value_to_value(row: &postgres::Row, idx: usize) -> Value {
             }
 
             Kind::Array(inner) if is_textual_pg_type(inner) => {
+                let element: fn(String) -> Value = match inner.kind() {
+                    Kind::Enum(_) => Value::Enum,
+                    _ => Value::Text,
+                };
+
                 match row.try_get::<_, Option<Vec<PgText>>>(idx) {
                     Ok(Some(arr)) => {
-                        Value::Array(arr.into_iter().map(|PgText(s)| Value::Text(s)).collect())
+                        Value::Array(arr.into_iter().map(|PgText(s)| element(s)).collect())
                     }
                     Ok(None) => Value::Null,
                     Err(e) => {
@@ -3513,6 +3818,16 @@ fn postgres_value_to_value(row: &postgres::Row, idx: usize) -> Value {
 
 pub struct PostgresErrorFormatter;
 
+fn postgres_severity(severity: postgres::error::Severity) -> ErrorSeverity {
+    use postgres::error::Severity;
+
+    match severity {
+        Severity::Panic | Severity::Fatal => ErrorSeverity::Fatal,
+        Severity::Error => ErrorSeverity::Error,
+        _ => ErrorSeverity::Warning,
+    }
+}
+
 impl PostgresErrorFormatter {
     fn format_postgres_error(e: &postgres::Error) -> FormattedError {
         if let Some(db_err) = e.as_db_error() {
@@ -3528,6 +3843,10 @@ impl PostgresErrorFormatter {
 
             formatted = formatted.with_code(db_err.code().code());
 
+            if let Some(severity) = db_err.parsed_severity() {
+                formatted = formatted.with_severity(postgres_severity(severity));
+            }
+
             let has_location = db_err.table().is_some()
                 || db_err.column().is_some()
                 || db_err.constraint().is_some()
@@ -3614,7 +3933,7 @@ impl ConnectionErrorFormatter for PostgresErrorFormatter {
     ) -> FormattedError {
         let source = error.to_string();
         let message = Self::format_connection_message(&source, host, port);
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 
     fn format_uri_error(
@@ -3634,7 +3953,7 @@ impl ConnectionErrorFormatter for PostgresErrorFormatter {
             format!("Connection error with URI {}: {}", sanitized_uri, source)
         };
 
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 }
 
@@ -4121,6 +4440,104 @@ fn collect_filter_values(filter: &Value, params: &mut Vec<Value>) {
 ///
 /// Returns an error if the query fails; returns an empty `Vec` when the table
 /// has no dependents.
+/// Planner row estimate and on-disk sizes of a table. `reltuples` is -1
+/// until the table has been vacuumed or analyzed, which maps to no estimate.
+pub fn fetch_table_statistics(
+    client: &mut Client,
+    schema: &str,
+    table: &str,
+) -> Result<dbflux_core::TableStatistics, DbError> {
+    let row = client
+        .query_opt(
+            "
+        SELECT
+            c.reltuples::float8 AS row_estimate,
+            pg_table_size(c.oid) AS data_bytes,
+            pg_indexes_size(c.oid) AS index_bytes
+        FROM pg_class c
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        WHERE n.nspname = $1
+          AND c.relname = $2
+        ",
+            &[&schema, &table],
+        )
+        .map_err(|e| DbError::QueryFailed(format!("fetch_table_statistics: {}", e).into()))?;
+
+    let Some(row) = row else {
+        return Ok(dbflux_core::TableStatistics::default());
+    };
+
+    let row_estimate: f64 = row.get("row_estimate");
+    let data_bytes: i64 = row.get("data_bytes");
+    let index_bytes: i64 = row.get("index_bytes");
+
+    Ok(dbflux_core::TableStatistics {
+        row_estimate: (row_estimate >= 0.0).then_some(row_estimate as u64),
+        data_bytes: u64::try_from(data_bytes).ok(),
+        index_bytes: u64::try_from(index_bytes).ok(),
+    })
+}
+
+/// The statements `pg_stat_statements` recorded for the current database,
+/// most total time first. Empty when the extension is not installed.
+pub fn fetch_query_statistics(
+    client: &mut Client,
+    limit: usize,
+) -> Result<Vec<dbflux_core::WorkloadQuery>, DbError> {
+    let installed = client
+        .query_opt(
+            "SELECT 1 FROM pg_extension WHERE extname = 'pg_stat_statements'",
+            &[],
+        )
+        .map_err(|e| DbError::QueryFailed(format!("fetch_query_statistics: {}", e).into()))?
+        .is_some();
+
+    if !installed {
+        return Ok(Vec::new());
+    }
+
+    // PostgreSQL 13 renamed `total_time` to `total_exec_time`.
+    let version: i32 = client
+        .query_one("SELECT current_setting('server_version_num')::int4", &[])
+        .map_err(|e| DbError::QueryFailed(format!("fetch_query_statistics: {}", e).into()))?
+        .get(0);
+    let time_column = if version >= 130_000 {
+        "total_exec_time"
+    } else {
+        "total_time"
+    };
+
+    let sql = format!(
+        "
+        SELECT s.query, s.calls::int8 AS calls, s.{time_column}::float8 AS total_ms
+        FROM pg_stat_statements s
+        JOIN pg_database d ON d.oid = s.dbid
+        WHERE d.datname = current_database()
+        ORDER BY s.{time_column} DESC
+        LIMIT $1
+        "
+    );
+
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    let rows = client
+        .query(&sql, &[&limit])
+        .map_err(|e| DbError::QueryFailed(format!("fetch_query_statistics: {}", e).into()))?;
+
+    // Statements of other roles read as NULL or `<insufficient privilege>`.
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let sql: Option<String> = row.get("query");
+            let calls: i64 = row.get("calls");
+            Some(dbflux_core::WorkloadQuery {
+                sql: sql.filter(|sql| !sql.starts_with('<'))?,
+                calls: u64::try_from(calls).unwrap_or_default(),
+                total_time_ms: row.get("total_ms"),
+            })
+        })
+        .collect())
+}
+
 pub fn fetch_dependents(
     client: &mut Client,
     schema: &str,
@@ -4314,10 +4731,10 @@ mod tests {
         prokind_to_routine_kind,
     };
     use dbflux_core::{
-        CodeGenerator, CreateTypeRequest, DatabaseCategory, DbConfig, DbDriver, DbError,
-        FormValues, MutationRequest, QueryLanguage, RowInsert, SemanticRequest, SqlDialect,
-        TableBrowseRequest, TableRef, TypeAttributeDefinition, TypeDefinition, Value,
-        WhereOperator,
+        CodeGenerator, ColumnInfo, CreateTypeRequest, DatabaseCategory, DbConfig, DbDriver,
+        DbError, FormValues, MutationRequest, QueryLanguage, RenameRequest, RowInsert,
+        SemanticRequest, SetCommentRequest, SqlDialect, TableBrowseRequest, TableRef,
+        TypeAttributeDefinition, TypeDefinition, Value, WhereOperator,
     };
 
     #[test]
@@ -4588,6 +5005,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn postgres_codegen_comments_on_tables_and_columns() {
+        let generator = PostgresCodeGenerator;
+        let column = ColumnInfo {
+            name: "email".to_string(),
+            type_name: "text".to_string(),
+            nullable: true,
+            is_primary_key: false,
+            default_value: None,
+            enum_values: None,
+            comment: None,
+            definition: None,
+        };
+
+        let table_sql = generator.generate_set_comment(&SetCommentRequest {
+            table_name: "users",
+            schema_name: Some("public"),
+            column: None,
+            comment: Some("People's accounts"),
+        });
+        let column_sql = generator.generate_set_comment(&SetCommentRequest {
+            table_name: "users",
+            schema_name: Some("public"),
+            column: Some(&column),
+            comment: None,
+        });
+
+        assert_eq!(
+            table_sql.as_deref(),
+            Some("COMMENT ON TABLE \"public\".\"users\" IS 'People''s accounts';")
+        );
+        assert_eq!(
+            column_sql.as_deref(),
+            Some("COMMENT ON COLUMN \"public\".\"users\".\"email\" IS NULL;")
+        );
+    }
+
+    #[test]
+    fn postgres_codegen_renames_tables_and_columns() {
+        let generator = PostgresCodeGenerator;
+
+        let table_sql = generator.generate_rename(&RenameRequest {
+            table_name: "users",
+            schema_name: Some("public"),
+            column: None,
+            new_name: "accounts",
+        });
+        let column_sql = generator.generate_rename(&RenameRequest {
+            table_name: "users",
+            schema_name: Some("public"),
+            column: Some("email"),
+            new_name: "Email Address",
+        });
+
+        assert_eq!(
+            table_sql.as_deref(),
+            Some("ALTER TABLE \"public\".\"users\" RENAME TO \"accounts\";")
+        );
+        assert_eq!(
+            column_sql.as_deref(),
+            Some("ALTER TABLE \"public\".\"users\" RENAME COLUMN \"email\" TO \"Email Address\";")
+        );
+    }
+
     #[test]
     fn postgres_codegen_skips_enum_types_without_real_values() {
         let generator = PostgresCodeGenerator;
@@ -4881,4 +5362,72 @@ mod tests {
             "non-URI mode must return None"
         );
     }
+
+    fn numeric_wire(weight: i16, sign: u16, dscale: u16, digits: &[u16]) -> Vec<u8> {
+        let mut raw = Vec::new();
+        raw.extend_from_slice(&(digits.len() as u16).to_be_bytes());
+        raw.extend_from_slice(&weight.to_be_bytes());
+        raw.extend_from_slice(&sign.to_be_bytes());
+        raw.extend_from_slice(&dscale.to_be_bytes());
+        for digit in digits {
+            raw.extend_from_slice(&digit.to_be_bytes());
+        }
+        raw
+    }
+
+    #[test]
+    fn decode_pg_numeric_keeps_every_digit() {
+        let cases = [
+            (numeric_wire(1, 0x0000, 3, &[1, 2345, 6780]), "12345.678"),
+            (numeric_wire(-1, 0x0000, 4, &[12]), "0.0012"),
+            (numeric_wire(0, 0x4000, 2, &[19, 9900]), "-19.99"),
+            (numeric_wire(5, 0x0000, 0, &[1]), "100000000000000000000"),
+            (
+                numeric_wire(4, 0x0000, 2, &[1, 9999, 9999, 9999, 9999, 9900]),
+                "19999999999999999.99",
+            ),
+            (numeric_wire(0, 0x0000, 2, &[]), "0.00"),
+            (numeric_wire(0, 0xC000, 0, &[]), "NaN"),
+            (numeric_wire(0, 0xF000, 0, &[]), "-Infinity"),
+        ];
+
+        for (raw, expected) in cases {
+            assert_eq!(
+                super::decode_pg_numeric(&raw).expect("valid numeric"),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn decode_pg_numeric_rejects_malformed_values() {
+        assert!(super::decode_pg_numeric(&[0, 1, 0, 0]).is_err());
+
+        let mut truncated = numeric_wire(0, 0x0000, 0, &[1, 2]);
+        truncated.pop();
+        assert!(super::decode_pg_numeric(&truncated).is_err());
+
+        assert!(super::decode_pg_numeric(&numeric_wire(0, 0x0000, 0, &[10_000])).is_err());
+        assert!(super::decode_pg_numeric(&numeric_wire(0, 0x1234, 0, &[1])).is_err());
+    }
+    #[test]
+    fn pg_interval_decodes_microseconds_days_and_months() {
+        use postgres::types::{FromSql, Type};
+
+        let mut raw = Vec::new();
+        raw.extend_from_slice(&14_706_000_000_i64.to_be_bytes());
+        raw.extend_from_slice(&3_i32.to_be_bytes());
+        raw.extend_from_slice(&14_i32.to_be_bytes());
+
+        let super::PgInterval(interval) =
+            super::PgInterval::from_sql(&Type::INTERVAL, &raw).expect("valid interval");
+        assert_eq!(interval, dbflux_core::Interval::new(14, 3, 14_706_000_000));
+        assert_eq!(
+            super::value_to_pg_literal_typed(&dbflux_core::Value::Interval(interval), None),
+            "'1 year 2 mons 3 days 04:05:06'::interval"
+        );
+
+        raw.truncate(12);
+        assert!(super::PgInterval::from_sql(&Type::INTERVAL, &raw).is_err());
+    }
 }
@@ -356,6 +356,7 @@ fn single_sample_result(columns: Vec<ColumnMeta>, values: Vec<Value>) -> QueryRe
         rows: vec![row],
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -628,6 +629,7 @@ fn fetch_activity_snapshot(client: &mut Client) -> Result<QueryResult, DbError>
         rows: result_rows,
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -679,6 +681,7 @@ fn fetch_locks_snapshot(client: &mut Client) -> Result<QueryResult, DbError> {
         rows: result_rows,
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -1746,7 +1746,7 @@ impl ConnectionErrorFormatter for RedisErrorFormatter {
     ) -> FormattedError {
         let source = error.to_string();
         let message = Self::format_connection_message(&source, host, port);
-        FormattedError::new(message)
+        FormattedError::new(message).with_connection_hint(&source)
     }
 
     fn format_uri_error(
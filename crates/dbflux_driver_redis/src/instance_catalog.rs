@@ -428,6 +428,7 @@ pub(crate) fn dispatch_metric_series(
                 rows: vec![row],
                 affected_rows: None,
                 execution_time: Duration::ZERO,
+                timing: Default::default(),
                 text_body: None,
                 raw_bytes: None,
                 next_page_token: None,
@@ -533,6 +534,7 @@ fn fetch_client_list(conn: &mut redis::Connection) -> Result<QueryResult, DbErro
         rows,
         affected_rows: None,
         execution_time: Duration::ZERO,
+        timing: Default::default(),
         text_body: None,
         raw_bytes: None,
         next_page_token: None,
@@ -16,13 +16,13 @@ use dbflux_core::{
     IndexData, IndexInfo, IsolationLevel, KeyValueConnection, MutationCapabilities, OrderByColumn,
     PaginationStyle, PlaceholderStyle, QueryCancelHandle, QueryCapabilities, QueryErrorFormatter,
     QueryGenerator, QueryHandle, QueryLanguage, QueryRequest, QueryResult, ReindexRequest,
-    RelationalConnection, RelationalSchema, Row, RowDelete, RowInsert, RowPatch,
+    RelationalConnection, RelationalSchema, RenameRequest, Row, RowDelete, RowInsert, RowPatch,
     SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaSnapshot, SemanticPlan,
-    SemanticPlanKind, SemanticRequest, SortDirection, SqlDialect, SqlMutationGenerator,
-    SqlQueryBuilder, SyntaxInfo, TableInfo, TransactionCapabilities, Value, ViewInfo,
-    WhereOperator, field_file_path, generate_delete_template, generate_drop_table,
-    generate_insert_template, generate_select_star, generate_update_template,
-    render_semantic_filter_sql,
+    SemanticPlanKind, SemanticRequest, SessionGate, SortDirection, SqlDialect,
+    SqlMutationGenerator, SqlQueryBuilder, SyntaxInfo, TableInfo, TempColumnType,
+    TransactionCapabilities, Value, ViewInfo, WhereOperator, field_file_path,
+    generate_delete_template, generate_drop_table, generate_insert_template, generate_select_star,
+    generate_update_template, render_semantic_filter_sql,
 };
 use rusqlite::{Connection as RusqliteConnection, InterruptHandle};
 
@@ -67,7 +67,9 @@ pub static METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMetadata
             | DriverCapabilities::EXPORT_JSON.bits()
             | DriverCapabilities::QUERY_CANCELLATION.bits()
             | DriverCapabilities::TRANSACTIONAL_DDL.bits()
-            | DriverCapabilities::MULTI_STATEMENT.bits(),
+            | DriverCapabilities::MULTI_STATEMENT.bits()
+            | DriverCapabilities::TEMP_TABLES.bits()
+            | DriverCapabilities::INCREMENTAL_FETCH.bits(),
     ),
     default_port: None,
     uri_scheme: "sqlite".into(),
@@ -81,7 +83,7 @@ pub static METADATA: LazyLock<DriverMetadata> = LazyLock::new(|| DriverMetadata
         case_sensitive_identifiers: true,
     }),
     query: Some(QueryCapabilities {
-        pagination: vec![PaginationStyle::Offset],
+        pagination: vec![PaginationStyle::Offset, PaginationStyle::Keyset],
         where_operators: vec![
             WhereOperator::Eq,
             WhereOperator::Ne,
@@ -202,6 +204,20 @@ impl SqlDialect for SqliteDialect {
         PlaceholderStyle::QuestionMark
     }
 
+    fn text_contains_expr(&self, column: &str, text: &str, case_sensitive: bool) -> String {
+        // SQLite's `LIKE` ignores ASCII case; `instr` compares exactly.
+        if case_sensitive {
+            format!(
+                "instr({column}, {}) > 0",
+                self.value_to_literal(&Value::Text(text.to_string()))
+            )
+        } else {
+            let pattern =
+                self.value_to_literal(&Value::Text(dbflux_core::like_contains_pattern(text)));
+            format!("{column} LIKE {pattern} ESCAPE '!'")
+        }
+    }
+
     fn build_upsert_statement(
         &self,
         schema: Option<&str>,
@@ -255,6 +271,24 @@ impl SqlDialect for SqliteDialect {
             table, columns, values, conflict_columns, update_clause
         ))
     }
+
+    fn drop_temp_table_statement(&self, identifier: &str) -> Option<String> {
+        Some(format!(
+            "DROP TABLE IF EXISTS temp.{}",
+            sqlite_quote_ident(identifier)
+        ))
+    }
+
+    fn temp_column_type(&self, ty: TempColumnType) -> &'static str {
+        // SQLite only has storage classes; dates and JSON stay as text.
+        match ty {
+            TempColumnType::Boolean | TempColumnType::Integer => "INTEGER",
+            TempColumnType::Float => "REAL",
+            TempColumnType::Decimal => "NUMERIC",
+            TempColumnType::Binary => "BLOB",
+            _ => "TEXT",
+        }
+    }
 }
 
 static SQLITE_DIALECT: SqliteDialect = SqliteDialect;
@@ -284,6 +318,7 @@ impl CodeGenerator for SqliteCodeGenerator {
             | CodeGenCapabilities::REINDEX
             | CodeGenCapabilities::CREATE_TABLE
             | CodeGenCapabilities::DROP_TABLE
+            | CodeGenCapabilities::RENAME
     }
 
     fn generate_create_index(&self, req: &CreateIndexRequest) -> Option<String> {
@@ -314,6 +349,24 @@ impl CodeGenerator for SqliteCodeGenerator {
         let index = self.qualified(req.schema_name, req.index_name);
         Some(format!("REINDEX {};", index))
     }
+
+    fn generate_rename(&self, req: &RenameRequest) -> Option<String> {
+        let table = self.qualified(req.schema_name, req.table_name);
+
+        Some(match req.column {
+            Some(column) => format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                table,
+                self.quote(column),
+                self.quote(req.new_name)
+            ),
+            None => format!(
+                "ALTER TABLE {} RENAME TO {};",
+                table,
+                self.quote(req.new_name)
+            ),
+        })
+    }
 }
 
 // =============================================================================
@@ -382,6 +435,7 @@ impl DbDriver for SqliteDriver {
                         .get_interrupt_handle();
                     drop(pool_key);
                     return Ok(Box::new(SqliteConnection {
+                        session_gate: SessionGate::new(),
                         conn,
                         interrupt_handle,
                         cancelled: Arc::new(AtomicBool::new(false)),
@@ -406,6 +460,7 @@ impl DbDriver for SqliteDriver {
                     .map_err(|_| DbError::connection_failed("connection pool mutex poisoned"))?
                     .insert(pool_key, pooled_conn.clone());
                 return Ok(Box::new(SqliteConnection {
+                    session_gate: SessionGate::new(),
                     conn: pooled_conn,
                     interrupt_handle,
                     cancelled: Arc::new(AtomicBool::new(false)),
@@ -415,6 +470,7 @@ impl DbDriver for SqliteDriver {
         }
 
         Ok(Box::new(SqliteConnection {
+            session_gate: SessionGate::new(),
             conn: Arc::new(Mutex::new(conn)),
             interrupt_handle,
             cancelled: Arc::new(AtomicBool::new(false)),
@@ -469,6 +525,7 @@ impl DbDriver for SqliteDriver {
 }
 
 pub struct SqliteConnection {
+    session_gate: SessionGate,
     conn: Arc<Mutex<RusqliteConnection>>,
     interrupt_handle: InterruptHandle,
     cancelled: Arc<AtomicBool>,
@@ -517,41 +574,8 @@ fn plan_sqlite_table_browse(
     request: &dbflux_core::TableBrowseRequest,
 ) -> Result<SemanticPlan, DbError> {
     let sql = if let Some(filter) = request.semantic_filter.as_ref() {
-        let mut sql = format!(
-            "SELECT * FROM {}",
-            request.table.quoted_with(&SQLITE_DIALECT)
-        );
         let where_clause = render_semantic_filter_sql(filter, &SQLITE_DIALECT)?;
-        sql.push_str(" WHERE ");
-        sql.push_str(&where_clause);
-
-        if !request.order_by.is_empty() {
-            let order_by = request
-                .order_by
-                .iter()
-                .map(|column| {
-                    let direction = match column.direction {
-                        SortDirection::Ascending => "ASC",
-                        SortDirection::Descending => "DESC",
-                    };
-                    format!(
-                        "{} {}",
-                        column.column.quoted_with(&SQLITE_DIALECT),
-                        direction
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
-            sql.push_str(" ORDER BY ");
-            sql.push_str(&order_by);
-        }
-
-        sql.push_str(&format!(
-            " LIMIT {} OFFSET {}",
-            request.pagination.limit(),
-            request.pagination.offset()
-        ));
-        sql
+        request.build_sql_with_where(&SQLITE_DIALECT, Some(&where_clause))
     } else {
         request.build_sql_with(&SQLITE_DIALECT)
     };
@@ -663,7 +687,13 @@ impl Connection for SqliteConnection {
         Ok(())
     }
 
+    fn session_gate(&self) -> Option<&SessionGate> {
+        Some(&self.session_gate)
+    }
+
     fn execute(&self, req: &QueryRequest) -> Result<QueryResult, DbError> {
+        let _session = self.session_gate.enter(|request| self.execute(request))?;
+
         self.cancelled.store(false, Ordering::SeqCst);
 
         let start = Instant::now();
@@ -795,6 +825,7 @@ impl Connection for SqliteConnection {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment: None,
         })
     }
 
@@ -838,6 +869,20 @@ impl Connection for SqliteConnection {
         fetch_dependents(&conn, table)
     }
 
+    fn table_statistics(
+        &self,
+        _database: &str,
+        _schema: Option<&str>,
+        table: &str,
+    ) -> Result<dbflux_core::TableStatistics, DbError> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| DbError::query_failed(format!("Lock error: {}", e)))?;
+
+        fetch_table_statistics(&conn, table)
+    }
+
     fn fetch_row_by_pk(
         &self,
         _database: &str,
@@ -1327,7 +1372,7 @@ impl ConnectionExt for SqliteConnection {
 impl SqliteConnection {
     fn get_tables(&self, conn: &RusqliteConnection) -> Result<Vec<TableInfo>, DbError> {
         let mut stmt = conn
-            .prepare("SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name")
+            .prepare("SELECT name FROM sqlite_master WHERE type='table' ORDER BY name")
             .map_err(|e| format_sqlite_query_error(&e))?;
 
         let table_names: Vec<String> = stmt
@@ -1348,6 +1393,7 @@ impl SqliteConnection {
                 sample_fields: None,
                 presentation: dbflux_core::CollectionPresentation::DataGrid,
                 child_items: None,
+                comment: None,
             })
             .collect();
 
@@ -1392,6 +1438,8 @@ impl SqliteConnection {
                     is_primary_key: pk > 0,
                     default_value: row.get::<_, Option<String>>(4).unwrap_or(None),
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 })
             })
             .map_err(|e| format_sqlite_query_error(&e))?
@@ -1928,9 +1976,12 @@ fn value_to_sqlite_literal(value: &Value) -> String {
         Value::Json(s) => format!("'{}'", sqlite_escape_string(s)),
         Value::Bytes(b) => format!("X'{}'", hex::encode(b)),
         Value::DateTime(dt) => format!("'{}'", dt.to_rfc3339()),
+        Value::NaiveDateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
         Value::Date(d) => format!("'{}'", d.format("%Y-%m-%d")),
         Value::Time(t) => format!("'{}'", t.format("%H:%M:%S%.f")),
-        Value::ObjectId(id) => format!("'{}'", sqlite_escape_string(id)),
+        Value::ObjectId(id) | Value::Enum(id) => format!("'{}'", sqlite_escape_string(id)),
+        Value::Uuid(uuid) => format!("'{}'", uuid),
+        Value::Interval(interval) => format!("'{}'", interval),
         Value::Unsupported(_) => "NULL".to_string(),
         Value::Array(arr) => {
             let json = serde_json::to_string(arr).unwrap_or_else(|_| "[]".to_string());
@@ -2090,6 +2141,78 @@ pub(crate) fn kind_from_decltype(decl: Option<&str>) -> ColumnKind {
     ColumnKind::Unknown
 }
 
+/// Row count and on-disk sizes of `table`. The count comes from
+/// `sqlite_stat1` once `ANALYZE` has run and from `COUNT(*)` otherwise. Sizes
+/// come from the `dbstat` virtual table and are left empty when the SQLite
+/// build does not provide it.
+fn fetch_table_statistics(
+    conn: &RusqliteConnection,
+    table: &str,
+) -> Result<dbflux_core::TableStatistics, DbError> {
+    use rusqlite::OptionalExtension;
+
+    // rusqlite only reads `u64` with `fallible_uint`; counts and sizes are
+    // read as `i64` and never negative.
+    let analyzed = conn
+        .query_row(
+            "SELECT CAST(stat AS INTEGER) FROM sqlite_stat1 WHERE tbl = ?1 AND idx IS NULL",
+            [table],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .ok()
+        .flatten()
+        .and_then(|rows| u64::try_from(rows).ok());
+
+    let row_estimate = match analyzed {
+        Some(rows) => rows,
+        None => conn
+            .query_row(
+                &format!(
+                    "SELECT COUNT(*) FROM {}",
+                    SQLITE_DIALECT.quote_identifier(table)
+                ),
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|rows| u64::try_from(rows).unwrap_or(0))
+            .map_err(|e| DbError::query_failed(format!("fetch_table_statistics: {}", e)))?,
+    };
+
+    let data_bytes = conn
+        .query_row(
+            "SELECT SUM(pgsize) FROM dbstat WHERE name = ?1",
+            [table],
+            |row| row.get::<_, Option<i64>>(0),
+        )
+        .ok()
+        .flatten()
+        .and_then(|bytes| u64::try_from(bytes).ok());
+
+    let index_bytes = conn
+        .query_row(
+            "SELECT SUM(d.pgsize)
+             FROM dbstat d
+             JOIN sqlite_master m ON m.name = d.name
+             WHERE m.type = 'index' AND m.tbl_name = ?1",
+            [table],
+            |row| row.get::<_, Option<i64>>(0),
+        )
+        .ok()
+        .flatten()
+        .and_then(|bytes| u64::try_from(bytes).ok());
+
+    // SUM over no index pages is NULL: with dbstat available that means the
+    // table simply has no indexes.
+    let index_bytes = data_bytes.map(|_| index_bytes.unwrap_or(0));
+
+    Ok(dbflux_core::TableStatistics {
+        row_estimate: Some(row_estimate),
+        data_bytes,
+        index_bytes,
+    })
+}
+
 // =============================================================================
 // Dependents introspection (stub — not yet wired into ConnectedProfile cache)
 // =============================================================================
@@ -2189,15 +2312,42 @@ pub fn fetch_dependents(
 #[cfg(test)]
 mod tests {
     use super::{
-        RusqliteConnection, SqliteDialect, SqliteDriver, kind_from_decltype,
+        RusqliteConnection, SqliteCodeGenerator, SqliteDialect, SqliteDriver, kind_from_decltype,
         plan_sqlite_semantic_request, sqlite_generate_create_table,
     };
     use dbflux_core::{
-        ColumnInfo, ColumnKind, DatabaseCategory, DbConfig, DbDriver, FormValues, MutationRequest,
-        QueryLanguage, RowInsert, SemanticRequest, SqlDialect, TableBrowseRequest, TableInfo,
-        TableRef, Value, WhereOperator,
+        CodeGenerator, ColumnInfo, ColumnKind, DatabaseCategory, DbConfig, DbDriver, FormValues,
+        MutationRequest, QueryLanguage, RenameRequest, RowInsert, SemanticRequest, SqlDialect,
+        TableBrowseRequest, TableInfo, TableRef, Value, WhereOperator,
     };
 
+    #[test]
+    fn sqlite_codegen_renames_tables_and_columns() {
+        let generator = SqliteCodeGenerator;
+
+        let table_sql = generator.generate_rename(&RenameRequest {
+            table_name: "orders",
+            schema_name: Some("main"),
+            column: None,
+            new_name: "purchases",
+        });
+        let column_sql = generator.generate_rename(&RenameRequest {
+            table_name: "orders",
+            schema_name: None,
+            column: Some("status"),
+            new_name: "state",
+        });
+
+        assert_eq!(
+            table_sql.as_deref(),
+            Some("ALTER TABLE \"orders\" RENAME TO \"purchases\";")
+        );
+        assert_eq!(
+            column_sql.as_deref(),
+            Some("ALTER TABLE \"orders\" RENAME COLUMN \"status\" TO \"state\";")
+        );
+    }
+
     // --- kind_from_decltype unit tests (TDD: RED → GREEN) ---
 
     #[test]
@@ -2287,6 +2437,8 @@ mod tests {
                 is_primary_key: true,
                 default_value: None,
                 enum_values: None,
+                comment: None,
+                definition: None,
             }]),
             indexes: None,
             foreign_keys: None,
@@ -2294,6 +2446,7 @@ mod tests {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment: None,
         };
 
         let composite_pk = TableInfo {
@@ -2307,6 +2460,8 @@ mod tests {
                     is_primary_key: true,
                     default_value: None,
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 },
                 ColumnInfo {
                     name: "role_id".to_string(),
@@ -2315,6 +2470,8 @@ mod tests {
                     is_primary_key: true,
                     default_value: None,
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 },
             ]),
             indexes: None,
@@ -2323,6 +2480,7 @@ mod tests {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::DataGrid,
             child_items: None,
+            comment: None,
         };
 
         let single_sql = sqlite_generate_create_table(&single_pk);
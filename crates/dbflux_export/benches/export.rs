@@ -0,0 +1,82 @@
+//! Benchmarks for serializing large results in each table export format.
+//!
+//! Run with `cargo bench -p dbflux_export`.
+
+use std::hint::black_box;
+use std::time::Duration;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use dbflux_core::{ColumnKind, ColumnMeta, QueryResult, Value};
+use dbflux_export::{ExportFormat, export};
+
+const ROW_COUNT: usize = 20_000;
+
+const FORMATS: [ExportFormat; 5] = [
+    ExportFormat::Csv,
+    ExportFormat::JsonCompact,
+    ExportFormat::JsonPretty,
+    ExportFormat::Markdown,
+    ExportFormat::Parquet,
+];
+
+fn large_result() -> QueryResult {
+    let columns = [
+        ("id", "int8", ColumnKind::Integer),
+        ("email", "text", ColumnKind::Text),
+        ("balance", "float8", ColumnKind::Float),
+        ("profile", "jsonb", ColumnKind::Unknown),
+        ("note", "text", ColumnKind::Text),
+    ]
+    .into_iter()
+    .map(|(name, type_name, kind)| ColumnMeta {
+        name: name.to_string(),
+        type_name: type_name.to_string(),
+        kind,
+        nullable: true,
+        is_primary_key: name == "id",
+    })
+    .collect();
+
+    let rows = (0..ROW_COUNT as i64)
+        .map(|i| {
+            vec![
+                Value::Int(i),
+                Value::Text(format!("customer-{}@example.com", i % 997)),
+                Value::Float(i as f64 * 1.25),
+                Value::Json(format!(r#"{{"tier":{},"tags":["a","b"]}}"#, i % 5)),
+                if i % 10 == 0 {
+                    Value::Null
+                } else {
+                    Value::Text("needs \"quoting\", and | pipes".to_string())
+                },
+            ]
+        })
+        .collect();
+
+    QueryResult::table(columns, rows, None, Duration::ZERO)
+}
+
+fn bench_export(c: &mut Criterion) {
+    let result = large_result();
+    let mut group = c.benchmark_group("export");
+    group.throughput(Throughput::Elements(ROW_COUNT as u64));
+
+    for format in FORMATS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format.name()),
+            &result,
+            |b, result| {
+                b.iter(|| {
+                    let mut out = Vec::new();
+                    export(result, format, &mut out).expect("export succeeds");
+                    black_box(out)
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_export);
+criterion_main!(benches);
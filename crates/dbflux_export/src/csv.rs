@@ -1,5 +1,5 @@
 use crate::ExportError;
-use csv::Writer;
+use csv::{QuoteStyle, WriterBuilder};
 use dbflux_core::{QueryResult, QueryResultShape, Value};
 use std::io::Write;
 
@@ -23,14 +23,25 @@ impl CsvExporter {
             QueryResultShape::Table | QueryResultShape::Json => {}
         }
 
-        let mut csv_writer = Writer::from_writer(writer);
-
-        let headers: Vec<&str> = result.columns.iter().map(|c| c.name.as_str()).collect();
+        // Fields are quoted here rather than by the writer so that an empty
+        // string (`""`) stays distinguishable from NULL (an empty field).
+        let mut csv_writer = WriterBuilder::new()
+            .quote_style(QuoteStyle::Never)
+            .from_writer(writer);
+
+        let headers: Vec<String> = result
+            .columns
+            .iter()
+            .map(|c| quote_field(&c.name))
+            .collect();
         csv_writer.write_record(&headers)?;
 
         for row in &result.rows {
             for value in row.iter() {
-                let field = value_to_csv_field(value);
+                let field = match value {
+                    Value::Null => String::new(),
+                    value => quote_field(&value_to_csv_field(value)),
+                };
                 csv_writer.write_field(&field)?;
             }
             csv_writer.write_record(None::<&[u8]>)?;
@@ -41,7 +52,17 @@ impl CsvExporter {
     }
 }
 
-fn value_to_csv_field(value: &Value) -> String {
+/// Quotes a non-NULL field when it is empty or holds a delimiter, quote or
+/// line break, doubling any embedded quotes.
+fn quote_field(field: &str) -> String {
+    if field.is_empty() || field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub(crate) fn value_to_csv_field(value: &Value) -> String {
     match value {
         Value::Null => String::new(),
         Value::Bool(b) => if *b { "true" } else { "false" }.to_string(),
@@ -59,11 +80,14 @@ fn value_to_csv_field(value: &Value) -> String {
                 f.to_string()
             }
         }
-        Value::Text(s) | Value::Json(s) | Value::Decimal(s) => s.clone(),
+        Value::Text(s) | Value::Json(s) | Value::Decimal(s) | Value::Enum(s) => s.clone(),
         Value::Bytes(b) => format!("\\x{}", hex::encode(b)),
-        Value::DateTime(dt) => dt.to_rfc3339(),
+        Value::DateTime(dt) => dbflux_core::timezone::to_display_rfc3339(dt),
+        Value::NaiveDateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
         Value::Date(d) => d.format("%Y-%m-%d").to_string(),
         Value::Time(t) => t.format("%H:%M:%S%.f").to_string(),
+        Value::Uuid(uuid) => uuid.to_string(),
+        Value::Interval(interval) => interval.to_string(),
         Value::ObjectId(id) => id.clone(),
         Value::Unsupported(type_name) => format!("UNSUPPORTED<{}>", type_name),
         Value::Array(arr) => serde_json::to_string(arr).unwrap_or_else(|_| "[]".to_string()),
@@ -163,7 +187,7 @@ mod tests {
     }
 
     #[test]
-    fn null_and_empty_string_share_representation() {
+    fn null_and_empty_string_stay_distinct() {
         let result = make_result(
             vec!["null_col", "empty_col"],
             vec![vec![Value::Null, Value::Text(String::new())]],
@@ -174,8 +198,7 @@ mod tests {
 
         let output = String::from_utf8(buf).unwrap();
         let lines: Vec<&str> = output.lines().collect();
-        assert_eq!(lines.len(), 2);
-        assert_eq!(lines[1], ",");
+        assert_eq!(lines, vec!["null_col,empty_col", ",\"\""]);
     }
 
     #[test]
@@ -202,6 +225,48 @@ mod tests {
         assert!(output.contains("\\xdeadbeef"));
     }
 
+    #[test]
+    fn decimals_export_every_digit() {
+        let result = make_result(
+            vec!["amount"],
+            vec![vec![Value::Decimal("19999999999999999.99".to_string())]],
+        );
+
+        let mut buf = Vec::new();
+        CsvExporter.export(&result, &mut buf).unwrap();
+
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("19999999999999999.99"));
+    }
+
+    #[test]
+    fn timestamps_export_in_the_display_time_zone() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+        use dbflux_core::timezone::{DisplayTimezone, set_display_timezone};
+
+        let naive = NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|d| d.and_hms_opt(22, 15, 0))
+            .unwrap();
+        let result = make_result(
+            vec!["created_at", "local_at"],
+            vec![vec![
+                Value::DateTime(Utc.from_utc_datetime(&naive)),
+                Value::NaiveDateTime(naive),
+            ]],
+        );
+
+        set_display_timezone(DisplayTimezone::Offset(
+            FixedOffset::east_opt(2 * 3600).unwrap(),
+        ));
+        let mut buf = Vec::new();
+        let exported = CsvExporter.export(&result, &mut buf);
+        set_display_timezone(DisplayTimezone::Utc);
+        exported.unwrap();
+
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("2026-03-02T00:15:00+02:00,2026-03-01 22:15:00"));
+    }
+
     #[test]
     fn nan_exports_as_nan_string() {
         let result = make_result(vec!["value"], vec![vec![Value::Float(f64::NAN)]]);
@@ -0,0 +1,76 @@
+use dbflux_core::display_format::DisplayFormat;
+use dbflux_core::{QueryResult, Value};
+
+/// A copy of `result` with numbers, dates and timestamps replaced by their
+/// displayed text, for exports that ask for formatted output.
+pub fn formatted_result(result: &QueryResult, format: &DisplayFormat) -> QueryResult {
+    let mut formatted = result.clone();
+
+    for row in &mut formatted.rows {
+        for value in row.iter_mut() {
+            if let Some(text) = format.format_value(value) {
+                *value = Value::Text(text);
+            }
+        }
+    }
+
+    formatted.additional_results = result
+        .additional_results
+        .iter()
+        .map(|additional| formatted_result(additional, format))
+        .collect();
+
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use dbflux_core::ColumnMeta;
+    use std::time::Duration;
+
+    #[test]
+    fn formats_numbers_and_dates_and_keeps_other_values() {
+        let result = QueryResult::table(
+            vec![ColumnMeta {
+                name: "value".to_string(),
+                type_name: "text".to_string(),
+                kind: dbflux_core::ColumnKind::Unknown,
+                nullable: true,
+                is_primary_key: false,
+            }],
+            vec![
+                vec![Value::Int(1_234_567)],
+                vec![Value::Decimal("1234.5".to_string())],
+                vec![Value::Date(NaiveDate::from_ymd_opt(2026, 3, 1).unwrap())],
+                vec![Value::Text("1234".to_string())],
+                vec![Value::Null],
+            ],
+            None,
+            Duration::from_millis(10),
+        );
+
+        let format = DisplayFormat {
+            thousands_separator: Some(','),
+            decimal_places: Some(2),
+            date_format: "%d/%m/%Y".to_string(),
+            formatted_output: true,
+            ..DisplayFormat::default()
+        };
+
+        let formatted = formatted_result(&result, &format);
+        let values: Vec<&Value> = formatted.rows.iter().map(|row| &row[0]).collect();
+
+        assert_eq!(
+            values,
+            [
+                &Value::Text("1,234,567".to_string()),
+                &Value::Text("1,234.50".to_string()),
+                &Value::Text("01/03/2026".to_string()),
+                &Value::Text("1234".to_string()),
+                &Value::Null,
+            ]
+        );
+    }
+}
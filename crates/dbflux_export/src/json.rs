@@ -50,7 +50,13 @@ fn row_to_json_object(columns: &[ColumnMeta], row: &Row) -> serde_json::Value {
     let mut map = serde_json::Map::new();
 
     for (col, value) in columns.iter().zip(row.iter()) {
-        map.insert(col.name.clone(), Value::to_serde_json(value));
+        let json = match value {
+            Value::DateTime(dt) => {
+                serde_json::json!({ "$date": dbflux_core::timezone::to_display_rfc3339(dt) })
+            }
+            other => Value::to_serde_json(other),
+        };
+        map.insert(col.name.clone(), json);
     }
 
     serde_json::Value::Object(map)
@@ -1,6 +1,9 @@
 mod binary;
 mod csv;
+mod formatted;
 mod json;
+mod markdown;
+mod parquet;
 mod text;
 
 use dbflux_core::{QueryResult, QueryResultShape};
@@ -9,7 +12,10 @@ use thiserror::Error;
 
 pub use binary::{BinaryExportMode, BinaryExporter};
 pub use csv::CsvExporter;
+pub use formatted::formatted_result;
 pub use json::JsonExporter;
+pub use markdown::MarkdownExporter;
+pub use parquet::ParquetExporter;
 pub use text::TextExporter;
 
 #[derive(Debug, Error)]
@@ -23,6 +29,9 @@ pub enum ExportError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+
     #[error("Export failed: {0}")]
     Failed(String),
 }
@@ -32,6 +41,8 @@ pub enum ExportFormat {
     Csv,
     JsonPretty,
     JsonCompact,
+    Markdown,
+    Parquet,
     Text,
     Binary,
     Hex,
@@ -44,6 +55,8 @@ impl ExportFormat {
             Self::Csv => "CSV",
             Self::JsonPretty => "JSON (pretty)",
             Self::JsonCompact => "JSON (compact)",
+            Self::Markdown => "Markdown",
+            Self::Parquet => "Parquet",
             Self::Text => "Text",
             Self::Binary => "Binary",
             Self::Hex => "Hex",
@@ -55,12 +68,45 @@ impl ExportFormat {
         match self {
             Self::Csv => "csv",
             Self::JsonPretty | Self::JsonCompact => "json",
+            Self::Markdown => "md",
+            Self::Parquet => "parquet",
             Self::Text => "txt",
             Self::Binary => "bin",
             Self::Hex => "hex",
             Self::Base64 => "b64",
         }
     }
+
+    /// Stable identifier used when a format is persisted.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::JsonPretty => "json-pretty",
+            Self::JsonCompact => "json-compact",
+            Self::Markdown => "markdown",
+            Self::Parquet => "parquet",
+            Self::Text => "text",
+            Self::Binary => "binary",
+            Self::Hex => "hex",
+            Self::Base64 => "base64",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        [
+            Self::Csv,
+            Self::JsonPretty,
+            Self::JsonCompact,
+            Self::Markdown,
+            Self::Parquet,
+            Self::Text,
+            Self::Binary,
+            Self::Hex,
+            Self::Base64,
+        ]
+        .into_iter()
+        .find(|format| format.key() == key)
+    }
 }
 
 pub fn available_formats(shape: &QueryResultShape) -> &'static [ExportFormat] {
@@ -69,6 +115,8 @@ pub fn available_formats(shape: &QueryResultShape) -> &'static [ExportFormat] {
             ExportFormat::Csv,
             ExportFormat::JsonPretty,
             ExportFormat::JsonCompact,
+            ExportFormat::Markdown,
+            ExportFormat::Parquet,
         ],
         QueryResultShape::Json => &[
             ExportFormat::JsonPretty,
@@ -93,6 +141,8 @@ pub fn export(
         ExportFormat::Csv => CsvExporter.export(result, writer),
         ExportFormat::JsonPretty => JsonExporter { pretty: true }.export(result, writer),
         ExportFormat::JsonCompact => JsonExporter { pretty: false }.export(result, writer),
+        ExportFormat::Markdown => MarkdownExporter.export(result, writer),
+        ExportFormat::Parquet => ParquetExporter.export(result, writer),
         ExportFormat::Text => TextExporter.export(result, writer),
         ExportFormat::Binary => BinaryExporter {
             mode: BinaryExportMode::Raw,
@@ -0,0 +1,112 @@
+use crate::ExportError;
+use dbflux_core::{ColumnKind, QueryResult, QueryResultShape, Value};
+use std::io::Write;
+
+/// Renders table results as a GitHub-flavored Markdown table, ready to paste
+/// into an issue or chat.
+pub struct MarkdownExporter;
+
+impl MarkdownExporter {
+    pub fn export(&self, result: &QueryResult, writer: &mut dyn Write) -> Result<(), ExportError> {
+        match &result.shape {
+            QueryResultShape::Text => {
+                if let Some(body) = &result.text_body {
+                    writer.write_all(body.as_bytes())?;
+                }
+                return Ok(());
+            }
+            QueryResultShape::Binary => {
+                return Err(ExportError::Failed(
+                    "binary results cannot be rendered as Markdown".to_string(),
+                ));
+            }
+            QueryResultShape::Table | QueryResultShape::Json => {}
+        }
+
+        let header: Vec<String> = result
+            .columns
+            .iter()
+            .map(|c| escape_markdown_cell(&c.name))
+            .collect();
+        writeln!(writer, "| {} |", header.join(" | "))?;
+
+        let rule: Vec<&str> = result
+            .columns
+            .iter()
+            .map(|c| match c.kind {
+                ColumnKind::Integer | ColumnKind::Float => "---:",
+                _ => "---",
+            })
+            .collect();
+        writeln!(writer, "| {} |", rule.join(" | "))?;
+
+        for row in &result.rows {
+            let cells: Vec<String> = row.iter().map(value_to_markdown_cell).collect();
+            writeln!(writer, "| {} |", cells.join(" | "))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn value_to_markdown_cell(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        value => escape_markdown_cell(&value.as_raw_string()),
+    }
+}
+
+/// Escape pipes and keep multi-line values on one table row.
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::ColumnMeta;
+    use std::time::Duration;
+
+    fn column(name: &str, kind: ColumnKind) -> ColumnMeta {
+        ColumnMeta {
+            name: name.to_string(),
+            type_name: "text".to_string(),
+            kind,
+            nullable: true,
+            is_primary_key: false,
+        }
+    }
+
+    #[test]
+    fn exports_table_with_aligned_numbers() {
+        let result = QueryResult::table(
+            vec![
+                column("id", ColumnKind::Integer),
+                column("name", ColumnKind::Text),
+            ],
+            vec![
+                vec![Value::Int(1), Value::Text("Alice".to_string())],
+                vec![Value::Int(2), Value::Null],
+            ],
+            None,
+            Duration::from_millis(10),
+        );
+
+        let mut buf = Vec::new();
+        MarkdownExporter.export(&result, &mut buf).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "| id | name |\n| ---: | --- |\n| 1 | Alice |\n| 2 | NULL |\n"
+        );
+    }
+
+    #[test]
+    fn escapes_pipes_and_newlines() {
+        assert_eq!(escape_markdown_cell("a|b"), "a\\|b");
+        assert_eq!(escape_markdown_cell("line1\nline2"), "line1<br>line2");
+    }
+}
@@ -0,0 +1,375 @@
+use crate::ExportError;
+use crate::csv::value_to_csv_field;
+use dbflux_core::{QueryResult, QueryResultShape, Row, Value};
+use parquet::basic::{Compression, LogicalType, Repetition, Type as PhysicalType};
+use parquet::data_type::{
+    BoolType, ByteArray, ByteArrayType, DoubleType, FixedLenByteArray, FixedLenByteArrayType,
+    Int64Type,
+};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::{SerializedColumnWriter, SerializedFileWriter};
+use parquet::schema::types::Type;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Rows per Parquet row group.
+const ROW_GROUP_SIZE: usize = 65_536;
+
+/// Writes table results as a Snappy-compressed Parquet file.
+///
+/// Every column is optional. Columns holding only booleans, integers, floats,
+/// bytes or UUIDs keep that type (integers mixed with floats become doubles);
+/// any other column is written as UTF-8 text, formatted as in CSV exports.
+pub struct ParquetExporter;
+
+/// Parquet type chosen for one result column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParquetColumn {
+    Boolean,
+    Int64,
+    Double,
+    Binary,
+    Uuid,
+    Utf8,
+}
+
+impl ParquetExporter {
+    pub fn export(&self, result: &QueryResult, writer: &mut dyn Write) -> Result<(), ExportError> {
+        match &result.shape {
+            QueryResultShape::Table | QueryResultShape::Json => {}
+            QueryResultShape::Text | QueryResultShape::Binary => {
+                return Err(ExportError::Failed(
+                    "only table results can be exported as Parquet".to_string(),
+                ));
+            }
+        }
+
+        if result.columns.is_empty() {
+            return Err(ExportError::Failed(
+                "a Parquet file needs at least one column".to_string(),
+            ));
+        }
+
+        let kinds: Vec<ParquetColumn> = (0..result.columns.len())
+            .map(|index| column_kind(&result.rows, index))
+            .collect();
+
+        let fields = result
+            .columns
+            .iter()
+            .zip(&kinds)
+            .map(|(column, kind)| {
+                let physical = match kind {
+                    ParquetColumn::Boolean => PhysicalType::BOOLEAN,
+                    ParquetColumn::Int64 => PhysicalType::INT64,
+                    ParquetColumn::Double => PhysicalType::DOUBLE,
+                    ParquetColumn::Binary | ParquetColumn::Utf8 => PhysicalType::BYTE_ARRAY,
+                    ParquetColumn::Uuid => PhysicalType::FIXED_LEN_BYTE_ARRAY,
+                };
+                let (logical, length) = match kind {
+                    ParquetColumn::Utf8 => (Some(LogicalType::String), -1),
+                    ParquetColumn::Uuid => (Some(LogicalType::Uuid), 16),
+                    _ => (None, -1),
+                };
+
+                Type::primitive_type_builder(&column.name, physical)
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_logical_type(logical)
+                    .with_length(length)
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<Result<Vec<_>, ParquetError>>()?;
+
+        let schema = Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()?;
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+
+        // The Parquet writer needs a `Send` sink, so the file is assembled in
+        // memory and copied out at the end.
+        let mut buffer = Vec::new();
+        let mut file_writer =
+            SerializedFileWriter::new(&mut buffer, Arc::new(schema), Arc::new(properties))?;
+
+        for rows in result.rows.chunks(ROW_GROUP_SIZE) {
+            let mut row_group = file_writer.next_row_group()?;
+            let mut index = 0;
+
+            while let Some(mut column) = row_group.next_column()? {
+                write_column(&mut column, kinds[index], rows, index)?;
+                column.close()?;
+                index += 1;
+            }
+
+            row_group.close()?;
+        }
+
+        file_writer.close()?;
+        writer.write_all(&buffer)?;
+        Ok(())
+    }
+}
+
+fn cell(row: &Row, index: usize) -> &Value {
+    row.get(index).unwrap_or(&Value::Null)
+}
+
+/// The narrowest Parquet type that holds every non-null value of a column.
+fn column_kind(rows: &[Row], index: usize) -> ParquetColumn {
+    let mut kind = None;
+
+    for value in rows.iter().map(|row| cell(row, index)) {
+        let value_kind = match value {
+            Value::Null => continue,
+            Value::Bool(_) => ParquetColumn::Boolean,
+            Value::Int(_) => ParquetColumn::Int64,
+            Value::Float(_) => ParquetColumn::Double,
+            Value::Bytes(_) => ParquetColumn::Binary,
+            Value::Uuid(_) => ParquetColumn::Uuid,
+            _ => return ParquetColumn::Utf8,
+        };
+
+        kind = Some(match (kind, value_kind) {
+            (None, value_kind) => value_kind,
+            (Some(current), value_kind) if current == value_kind => current,
+            (Some(ParquetColumn::Int64), ParquetColumn::Double)
+            | (Some(ParquetColumn::Double), ParquetColumn::Int64) => ParquetColumn::Double,
+            _ => return ParquetColumn::Utf8,
+        });
+    }
+
+    kind.unwrap_or(ParquetColumn::Utf8)
+}
+
+fn write_column(
+    column: &mut SerializedColumnWriter<'_>,
+    kind: ParquetColumn,
+    rows: &[Row],
+    index: usize,
+) -> Result<(), ParquetError> {
+    let def_levels: Vec<i16> = rows
+        .iter()
+        .map(|row| i16::from(!matches!(cell(row, index), Value::Null)))
+        .collect();
+    let present = rows
+        .iter()
+        .map(|row| cell(row, index))
+        .filter(|value| !matches!(value, Value::Null));
+
+    match kind {
+        ParquetColumn::Boolean => {
+            let values: Vec<bool> = present
+                .filter_map(|value| match value {
+                    Value::Bool(b) => Some(*b),
+                    _ => None,
+                })
+                .collect();
+            column
+                .typed::<BoolType>()
+                .write_batch(&values, Some(&def_levels), None)?;
+        }
+        ParquetColumn::Int64 => {
+            let values: Vec<i64> = present
+                .filter_map(|value| match value {
+                    Value::Int(i) => Some(*i),
+                    _ => None,
+                })
+                .collect();
+            column
+                .typed::<Int64Type>()
+                .write_batch(&values, Some(&def_levels), None)?;
+        }
+        ParquetColumn::Double => {
+            let values: Vec<f64> = present
+                .filter_map(|value| match value {
+                    Value::Float(f) => Some(*f),
+                    Value::Int(i) => Some(*i as f64),
+                    _ => None,
+                })
+                .collect();
+            column
+                .typed::<DoubleType>()
+                .write_batch(&values, Some(&def_levels), None)?;
+        }
+        ParquetColumn::Binary => {
+            let values: Vec<ByteArray> = present
+                .filter_map(|value| match value {
+                    Value::Bytes(bytes) => Some(ByteArray::from(bytes.clone())),
+                    _ => None,
+                })
+                .collect();
+            column
+                .typed::<ByteArrayType>()
+                .write_batch(&values, Some(&def_levels), None)?;
+        }
+        ParquetColumn::Uuid => {
+            let values: Vec<FixedLenByteArray> = present
+                .filter_map(|value| match value {
+                    Value::Uuid(uuid) => Some(FixedLenByteArray::from(uuid.as_bytes().to_vec())),
+                    _ => None,
+                })
+                .collect();
+            column.typed::<FixedLenByteArrayType>().write_batch(
+                &values,
+                Some(&def_levels),
+                None,
+            )?;
+        }
+        ParquetColumn::Utf8 => {
+            let values: Vec<ByteArray> = present
+                .map(|value| ByteArray::from(value_to_csv_field(value).into_bytes()))
+                .collect();
+            column
+                .typed::<ByteArrayType>()
+                .write_batch(&values, Some(&def_levels), None)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::ColumnMeta;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::RowAccessor;
+    use std::time::Duration;
+
+    fn make_result(columns: Vec<&str>, rows: Vec<Vec<Value>>) -> QueryResult {
+        QueryResult::table(
+            columns
+                .into_iter()
+                .map(|name| ColumnMeta {
+                    name: name.to_string(),
+                    type_name: "text".to_string(),
+                    kind: dbflux_core::ColumnKind::Unknown,
+                    nullable: true,
+                    is_primary_key: false,
+                })
+                .collect(),
+            rows,
+            None,
+            Duration::from_millis(10),
+        )
+    }
+
+    fn read_back(result: &QueryResult, name: &str) -> SerializedFileReader<std::fs::File> {
+        let mut buf = Vec::new();
+        ParquetExporter.export(result, &mut buf).unwrap();
+        assert!(buf.starts_with(b"PAR1"));
+
+        let path = std::env::temp_dir().join(format!(
+            "dbflux-export-{name}-{}.parquet",
+            std::process::id()
+        ));
+        std::fs::write(&path, &buf).unwrap();
+        let file = std::fs::File::open(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        SerializedFileReader::new(file).unwrap()
+    }
+
+    #[test]
+    fn round_trips_typed_columns_and_nulls() {
+        let result = make_result(
+            vec!["id", "active", "score", "name"],
+            vec![
+                vec![
+                    Value::Int(1),
+                    Value::Bool(true),
+                    Value::Float(2.5),
+                    Value::Text("Alice".to_string()),
+                ],
+                vec![Value::Int(2), Value::Null, Value::Int(3), Value::Null],
+            ],
+        );
+
+        let reader = read_back(&result, "typed");
+
+        let schema = reader.metadata().file_metadata().schema_descr();
+        assert_eq!(schema.column(0).physical_type(), PhysicalType::INT64);
+        assert_eq!(schema.column(1).physical_type(), PhysicalType::BOOLEAN);
+        assert_eq!(schema.column(2).physical_type(), PhysicalType::DOUBLE);
+        assert_eq!(schema.column(3).physical_type(), PhysicalType::BYTE_ARRAY);
+
+        let rows: Vec<_> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(rows[0].get_long(0).unwrap(), 1);
+        assert!(rows[0].get_bool(1).unwrap());
+        assert_eq!(rows[0].get_double(2).unwrap(), 2.5);
+        assert_eq!(rows[0].get_string(3).unwrap(), "Alice");
+
+        assert_eq!(rows[1].get_long(0).unwrap(), 2);
+        assert!(rows[1].get_bool(1).is_err());
+        assert_eq!(rows[1].get_double(2).unwrap(), 3.0);
+        assert!(rows[1].get_string(3).is_err());
+    }
+
+    #[test]
+    fn mixed_columns_fall_back_to_text() {
+        let result = make_result(
+            vec!["mixed"],
+            vec![vec![Value::Int(1)], vec![Value::Text("two".to_string())]],
+        );
+
+        let reader = read_back(&result, "mixed");
+        let rows: Vec<_> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows[0].get_string(0).unwrap(), "1");
+        assert_eq!(rows[1].get_string(0).unwrap(), "two");
+    }
+
+    #[test]
+    fn uuid_columns_are_fixed_length_uuids() {
+        let id = dbflux_core::uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let result = make_result(
+            vec!["id", "mixed"],
+            vec![
+                vec![Value::Uuid(id), Value::Uuid(id)],
+                vec![Value::Null, Value::Text("x".to_string())],
+            ],
+        );
+
+        let reader = read_back(&result, "uuid");
+
+        let schema = reader.metadata().file_metadata().schema_descr();
+        assert_eq!(
+            schema.column(0).physical_type(),
+            PhysicalType::FIXED_LEN_BYTE_ARRAY
+        );
+        assert_eq!(schema.column(0).type_length(), 16);
+        assert_eq!(schema.column(0).logical_type(), Some(LogicalType::Uuid));
+        assert_eq!(schema.column(1).physical_type(), PhysicalType::BYTE_ARRAY);
+
+        let rows: Vec<_> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get_string(1).unwrap(), &id.to_string());
+    }
+
+    #[test]
+    fn rejects_text_results() {
+        let result = QueryResult::text("hello".to_string(), Duration::from_millis(1));
+
+        let mut buf = Vec::new();
+        assert!(ParquetExporter.export(&result, &mut buf).is_err());
+        assert!(buf.is_empty());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::ExportError;
-use dbflux_core::{QueryResult, QueryResultShape};
+use dbflux_core::{QueryResult, QueryResultShape, Value};
 use std::io::Write;
 
 pub struct TextExporter;
@@ -35,17 +35,25 @@ fn write_rows_as_text(result: &QueryResult, writer: &mut dyn Write) -> Result<()
     }
 
     for row in &result.rows {
-        let fields: Vec<String> = row.iter().map(|v| v.as_display_string()).collect();
+        let fields: Vec<String> = row.iter().map(value_to_text_field).collect();
         writeln!(writer, "{}", fields.join("\t"))?;
     }
 
     Ok(())
 }
 
+/// The stored value, untruncated and independent of the display settings.
+fn value_to_text_field(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        value => value.as_raw_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use dbflux_core::{ColumnMeta, Value};
+    use dbflux_core::ColumnMeta;
     use std::time::Duration;
 
     #[test]
@@ -95,6 +103,40 @@ mod tests {
         assert_eq!(lines[2], "2\tNULL");
     }
 
+    #[test]
+    fn exports_values_as_stored_without_truncating() {
+        let long = "x".repeat(1500);
+        let instant = chrono::NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|d| d.and_hms_opt(22, 15, 0))
+            .unwrap()
+            .and_utc();
+        let result = QueryResult::table(
+            vec![ColumnMeta {
+                name: "v".to_string(),
+                type_name: "text".to_string(),
+                kind: dbflux_core::ColumnKind::Unknown,
+                nullable: true,
+                is_primary_key: false,
+            }],
+            vec![
+                vec![Value::Text(long.clone())],
+                vec![Value::Bytes(vec![0xDE, 0xAD])],
+                vec![Value::DateTime(instant)],
+            ],
+            None,
+            Duration::from_millis(1),
+        );
+
+        let mut buf = Vec::new();
+        TextExporter.export(&result, &mut buf).unwrap();
+
+        let output = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1], long);
+        assert_eq!(lines[2], "dead");
+        assert_eq!(lines[3], "2026-03-01 22:15:00+00:00");
+    }
+
     #[test]
     fn exports_binary_as_raw() {
         let result = QueryResult::binary(vec![0xDE, 0xAD], Duration::from_millis(1));
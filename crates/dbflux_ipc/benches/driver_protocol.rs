@@ -0,0 +1,116 @@
+//! Benchmarks for moving large results across the driver RPC protocol:
+//! DTO conversion and framed round trips with and without compression.
+//!
+//! Run with `cargo bench -p dbflux_ipc`.
+
+use std::hint::black_box;
+use std::time::Duration;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use dbflux_core::{ColumnKind, ColumnMeta, QueryResult, Value};
+use dbflux_ipc::{
+    CompressionCodec, DRIVER_RPC_VERSION, DriverResponseBody, DriverResponseEnvelope,
+    QueryResultDto, recv_msg, send_msg,
+};
+
+const ROW_COUNTS: [usize; 2] = [1_000, 20_000];
+
+fn large_result(count: usize) -> QueryResult {
+    let columns = [
+        ("id", "int8", ColumnKind::Integer),
+        ("email", "text", ColumnKind::Text),
+        ("balance", "float8", ColumnKind::Float),
+        ("profile", "jsonb", ColumnKind::Unknown),
+        ("avatar", "bytea", ColumnKind::Unknown),
+    ]
+    .into_iter()
+    .map(|(name, type_name, kind)| ColumnMeta {
+        name: name.to_string(),
+        type_name: type_name.to_string(),
+        kind,
+        nullable: true,
+        is_primary_key: name == "id",
+    })
+    .collect();
+
+    let rows = (0..count as i64)
+        .map(|i| {
+            vec![
+                Value::Int(i),
+                Value::Text(format!("customer-{}@example.com", i % 997)),
+                Value::Float(i as f64 * 1.25),
+                Value::Json(format!(r#"{{"tier":{},"tags":["a","b"]}}"#, i % 5)),
+                Value::Bytes(vec![(i % 256) as u8; 32]),
+            ]
+        })
+        .collect();
+
+    QueryResult::table(columns, rows, None, Duration::from_millis(3))
+}
+
+fn envelope(result: &QueryResult) -> DriverResponseEnvelope {
+    DriverResponseEnvelope::ok(
+        DRIVER_RPC_VERSION,
+        1,
+        None,
+        DriverResponseBody::ExecuteResult {
+            result: QueryResultDto::from(result),
+        },
+    )
+}
+
+fn bench_dto_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("driver_protocol/dto");
+
+    for count in ROW_COUNTS {
+        let result = large_result(count);
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::new("to_dto", count), &result, |b, result| {
+            b.iter(|| QueryResultDto::from(black_box(result)))
+        });
+
+        let dto = QueryResultDto::from(&result);
+        group.bench_with_input(BenchmarkId::new("from_dto", count), &dto, |b, dto| {
+            b.iter(|| QueryResult::from(black_box(dto.clone())))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_round_trip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("driver_protocol/round_trip");
+
+    for count in ROW_COUNTS {
+        let envelope = envelope(&large_result(count));
+        group.throughput(Throughput::Elements(count as u64));
+
+        for (label, codec) in [
+            ("plain", None),
+            ("zstd", Some(CompressionCodec::Zstd)),
+            ("lz4", Some(CompressionCodec::Lz4)),
+        ] {
+            group.bench_with_input(BenchmarkId::new(label, count), &envelope, |b, envelope| {
+                b.iter(|| {
+                    let outgoing = match codec {
+                        Some(codec) => envelope.clone().compressed(codec),
+                        None => envelope.clone(),
+                    };
+
+                    let mut frame = Vec::new();
+                    send_msg(&mut frame, &outgoing).expect("frame fits");
+
+                    let incoming: DriverResponseEnvelope =
+                        recv_msg(frame.as_slice()).expect("valid frame");
+                    black_box(incoming.decompressed().expect("valid body"))
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dto_conversion, bench_round_trip);
+criterion_main!(benches);
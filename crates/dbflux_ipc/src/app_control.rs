@@ -0,0 +1,42 @@
+//! Client side of the app-control channel, for code that runs inside the
+//! DBFlux process but outside the UI thread (e.g. desktop notification
+//! callbacks) and needs to reach the running app.
+
+use crate::envelope::APP_CONTROL_VERSION;
+use crate::protocol::{AppControlRequest, AppControlResponse, IpcMessage, IpcResponse};
+use crate::{framing, read_app_control_token, socket_name};
+use interprocess::local_socket::{Stream as IpcStream, prelude::*};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Sends `message` to the running app and returns its response. App-level
+/// errors (`IpcResponse::Error`) are returned as `Err`.
+pub fn send_app_control_message(message: IpcMessage) -> io::Result<IpcResponse> {
+    let mut stream = IpcStream::connect(socket_name()?)?;
+
+    let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    let request = AppControlRequest::new(request_id, Some(read_app_control_token()?), message);
+
+    framing::send_msg(&mut stream, &request)?;
+    let response: AppControlResponse = framing::recv_msg(&mut stream)?;
+
+    if !response
+        .protocol_version
+        .is_compatible_with(APP_CONTROL_VERSION)
+    {
+        return Err(io::Error::other(
+            "incompatible app-control protocol version",
+        ));
+    }
+
+    if response.request_id != request_id {
+        return Err(io::Error::other("mismatched app-control response id"));
+    }
+
+    match response.body {
+        IpcResponse::Error { message } => Err(io::Error::other(message)),
+        body => Ok(body),
+    }
+}
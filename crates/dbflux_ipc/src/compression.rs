@@ -0,0 +1,280 @@
+//! Optional compression of large driver RPC responses.
+//!
+//! A client lists the codecs it accepts as [`DriverCapability::Compression`]
+//! entries in its Hello request, most preferred first; the host answers with
+//! the first one it supports, or none. After that the host may replace a
+//! large result or schema body with [`DriverResponseBody::Compressed`], which
+//! holds the postcard encoding of the original body. Requests are never
+//! compressed.
+
+use crate::driver_protocol::{DriverCapability, DriverResponseBody, DriverResponseEnvelope};
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// A compression algorithm for driver RPC bodies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompressionCodec {
+    Zstd,
+    Lz4,
+}
+
+/// Codecs this build can read and write, most preferred first.
+pub const SUPPORTED_COMPRESSION_CODECS: [CompressionCodec; 2] =
+    [CompressionCodec::Zstd, CompressionCodec::Lz4];
+
+/// Encoded bodies smaller than this are sent as they are.
+pub const COMPRESSION_THRESHOLD: usize = 64 * 1024;
+
+/// Largest body a compressed frame may expand to. Bounds the allocation a
+/// corrupt or hostile `original_len` can cause.
+const MAX_DECOMPRESSED_SIZE: usize = 256 * 1024 * 1024;
+
+const ZSTD_LEVEL: i32 = 3;
+
+/// `Compression` capabilities for every supported codec, for a Hello request.
+pub fn compression_capabilities() -> impl Iterator<Item = DriverCapability> {
+    SUPPORTED_COMPRESSION_CODECS
+        .into_iter()
+        .map(DriverCapability::Compression)
+}
+
+/// The first codec in `requested` this build supports.
+pub fn negotiate_compression(requested: &[DriverCapability]) -> Option<CompressionCodec> {
+    requested.iter().find_map(|capability| match capability {
+        DriverCapability::Compression(codec) if SUPPORTED_COMPRESSION_CODECS.contains(codec) => {
+            Some(*codec)
+        }
+        _ => None,
+    })
+}
+
+impl DriverResponseBody {
+    /// Bodies that can grow large enough to be worth compressing.
+    fn is_compressible(&self) -> bool {
+        matches!(
+            self,
+            DriverResponseBody::ExecuteResult { .. }
+                | DriverResponseBody::ExecuteWithHandleResult { .. }
+                | DriverResponseBody::QueryChunk(_)
+                | DriverResponseBody::BrowseResult { .. }
+                | DriverResponseBody::Schema { .. }
+                | DriverResponseBody::SchemaForDatabase { .. }
+        )
+    }
+}
+
+impl DriverResponseEnvelope {
+    /// Compresses the body with `codec` when it is a large result or schema
+    /// and compression actually shrinks it.
+    pub fn compressed(mut self, codec: CompressionCodec) -> Self {
+        if !self.body.is_compressible() {
+            return self;
+        }
+
+        let Ok(encoded) = postcard::to_allocvec(&self.body) else {
+            return self;
+        };
+        if encoded.len() < COMPRESSION_THRESHOLD || encoded.len() > MAX_DECOMPRESSED_SIZE {
+            return self;
+        }
+
+        let data = match codec {
+            CompressionCodec::Zstd => match zstd::bulk::compress(&encoded, ZSTD_LEVEL) {
+                Ok(data) => data,
+                Err(error) => {
+                    log::warn!("zstd compression failed, sending uncompressed: {error}");
+                    return self;
+                }
+            },
+            CompressionCodec::Lz4 => lz4_flex::block::compress(&encoded),
+        };
+
+        if data.len() >= encoded.len() {
+            return self;
+        }
+
+        self.body = DriverResponseBody::Compressed {
+            codec,
+            original_len: encoded.len() as u32,
+            data,
+        };
+        self
+    }
+
+    /// Restores a [`DriverResponseBody::Compressed`] body; other bodies are
+    /// returned untouched.
+    pub fn decompressed(mut self) -> io::Result<Self> {
+        let DriverResponseBody::Compressed {
+            codec,
+            original_len,
+            data,
+        } = &self.body
+        else {
+            return Ok(self);
+        };
+
+        let original_len = *original_len as usize;
+        if original_len > MAX_DECOMPRESSED_SIZE {
+            return Err(io::Error::other("compressed message too large"));
+        }
+
+        let encoded = match codec {
+            CompressionCodec::Zstd => zstd::bulk::decompress(data, original_len)?,
+            CompressionCodec::Lz4 => lz4_flex::block::decompress(data, original_len)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        };
+
+        if encoded.len() != original_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "decompressed message length does not match its header",
+            ));
+        }
+
+        let body: DriverResponseBody = postcard::from_bytes(&encoded)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if matches!(body, DriverResponseBody::Compressed { .. }) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "nested compressed message",
+            ));
+        }
+
+        self.body = body;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DRIVER_RPC_VERSION;
+    use crate::driver_protocol::{QueryResultDto, QueryResultShapeDto};
+    use dbflux_core::Value;
+
+    fn large_result() -> DriverResponseEnvelope {
+        let rows = (0..5_000)
+            .map(|i| {
+                vec![
+                    Value::Int(i),
+                    Value::Text(format!("customer-{}@example.com", i % 50)),
+                ]
+            })
+            .collect();
+
+        DriverResponseEnvelope::ok(
+            DRIVER_RPC_VERSION,
+            7,
+            None,
+            DriverResponseBody::ExecuteResult {
+                result: QueryResultDto {
+                    shape: QueryResultShapeDto::Table,
+                    columns: Vec::new(),
+                    rows,
+                    affected_rows: None,
+                    execution_time_ms: 3,
+                    text_body: None,
+                    raw_bytes: None,
+                    next_page_token: None,
+                },
+            },
+        )
+    }
+
+    fn row_count(envelope: &DriverResponseEnvelope) -> usize {
+        match &envelope.body {
+            DriverResponseBody::ExecuteResult { result } => result.rows.len(),
+            other => panic!("expected an execute result, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn large_results_round_trip_through_every_codec() {
+        for codec in SUPPORTED_COMPRESSION_CODECS {
+            let compressed = large_result().compressed(codec);
+
+            let DriverResponseBody::Compressed {
+                codec: used,
+                original_len,
+                data,
+            } = &compressed.body
+            else {
+                panic!("{codec:?}: expected a compressed body");
+            };
+            assert_eq!(*used, codec);
+            assert!(data.len() < *original_len as usize);
+
+            let restored = compressed.decompressed().expect("valid frame");
+            assert_eq!(restored.request_id, 7);
+            assert_eq!(row_count(&restored), 5_000);
+        }
+    }
+
+    #[test]
+    fn small_and_non_result_bodies_stay_uncompressed() {
+        let pong =
+            DriverResponseEnvelope::ok(DRIVER_RPC_VERSION, 1, None, DriverResponseBody::Pong)
+                .compressed(CompressionCodec::Zstd);
+        assert!(matches!(pong.body, DriverResponseBody::Pong));
+
+        let mut small = large_result();
+        if let DriverResponseBody::ExecuteResult { result } = &mut small.body {
+            result.rows.truncate(3);
+        }
+        let small = small.compressed(CompressionCodec::Lz4);
+        assert_eq!(row_count(&small), 3);
+    }
+
+    #[test]
+    fn decompressed_rejects_corrupt_frames() {
+        let DriverResponseBody::Compressed {
+            codec,
+            original_len,
+            mut data,
+        } = large_result().compressed(CompressionCodec::Lz4).body
+        else {
+            panic!("expected a compressed body");
+        };
+        data.truncate(data.len() / 2);
+
+        let corrupt = DriverResponseEnvelope::ok(
+            DRIVER_RPC_VERSION,
+            1,
+            None,
+            DriverResponseBody::Compressed {
+                codec,
+                original_len,
+                data,
+            },
+        );
+        assert!(corrupt.decompressed().is_err());
+
+        let oversized = DriverResponseEnvelope::ok(
+            DRIVER_RPC_VERSION,
+            1,
+            None,
+            DriverResponseBody::Compressed {
+                codec: CompressionCodec::Zstd,
+                original_len: u32::MAX,
+                data: Vec::new(),
+            },
+        );
+        assert!(oversized.decompressed().is_err());
+    }
+
+    #[test]
+    fn negotiate_compression_picks_the_first_supported_codec() {
+        assert_eq!(
+            negotiate_compression(&[
+                DriverCapability::Cancellation,
+                DriverCapability::Compression(CompressionCodec::Lz4),
+                DriverCapability::Compression(CompressionCodec::Zstd),
+            ]),
+            Some(CompressionCodec::Lz4)
+        );
+        assert_eq!(
+            negotiate_compression(&[DriverCapability::ChunkedResults]),
+            None
+        );
+    }
+}
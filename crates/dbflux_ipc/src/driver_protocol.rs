@@ -1,11 +1,12 @@
 use crate::audit::AuditEventEmitDto;
+use crate::compression::CompressionCodec;
 use crate::envelope::ProtocolVersion;
 use dbflux_core::{
     CodeGenCapabilities, CodeGeneratorInfo, CollectionBrowseRequest, CollectionCountRequest,
-    ColumnMeta, CrudResult, CustomTypeInfo, DatabaseInfo, DbSchemaInfo, DescribeRequest,
-    DocumentDelete, DocumentInsert, DocumentUpdate, DriverFormDef, DriverMetadata,
-    ExecutionContext, ExplainRequest, QueryRequest, QueryResult, QueryResultShape, RowDelete,
-    RowInsert, RowPatch, SchemaFeatures, SchemaForeignKeyInfo, SchemaIndexInfo,
+    ColumnMeta, ConnectionEvent, ConnectionEventKind, CrudResult, CustomTypeInfo, DatabaseInfo,
+    DbSchemaInfo, DescribeRequest, DocumentDelete, DocumentInsert, DocumentUpdate, DriverFormDef,
+    DriverMetadata, ExecutionContext, ExplainRequest, QueryRequest, QueryResult, QueryResultShape,
+    RowDelete, RowInsert, RowPatch, SchemaFeatures, SchemaForeignKeyInfo, SchemaIndexInfo,
     SchemaLoadingStrategy, SchemaSnapshot, SemanticPlan, SemanticRequest, TableBrowseRequest,
     TableCountRequest, TableInfo, Value, ViewInfo,
 };
@@ -30,6 +31,9 @@ pub enum DriverCapability {
     /// Driver supports emitting audit events as intermediate response frames.
     /// Requires protocol version >= 1.2.
     AuditEmit,
+    /// Peer reads large responses compressed with the codec. Clients may
+    /// request several, most preferred first; hosts accept at most one.
+    Compression(CompressionCodec),
 }
 
 /// Well-known error categories for driver RPC responses.
@@ -166,9 +170,11 @@ impl From<QueryResultDto> for QueryResult {
             text_body: value.text_body,
             raw_bytes: value.raw_bytes,
             next_page_token: value.next_page_token,
-            // Resolved window and metadata_extra are not part of the IPC DTO; drivers set them locally.
+            // Resolved window, metadata_extra and the phase timing are not part of the IPC DTO;
+            // drivers set them locally.
             resolved_window: None,
             metadata_extra: None,
+            timing: Default::default(),
             // The driver RPC DTO does not currently propagate additional
             // result sets across the wire. External RPC drivers that need
             // multi-set support would have to extend QueryResultDto first;
@@ -380,6 +386,14 @@ pub enum DriverRequestBody {
         generator_id: String,
         table: TableInfo,
     },
+    // === Server events ===
+    /// Starts forwarding the session's events of `kinds` as
+    /// [`DriverResponseBody::Event`] frames, replacing any earlier
+    /// subscription. Requires protocol version >= 1.3.
+    SubscribeEvents {
+        kinds: Vec<ConnectionEventKind>,
+    },
+    UnsubscribeEvents,
 }
 
 /// Request envelope for driver RPC operations.
@@ -520,8 +534,30 @@ pub enum DriverResponseBody {
     EmitAuditEvent(AuditEventEmitDto),
     // === Error ===
     Error(DriverRpcError),
+    // === Compression ===
+    /// Another body, postcard-encoded and compressed with the codec
+    /// negotiated through `DriverCapability::Compression`. See
+    /// [`crate::compression`].
+    Compressed {
+        codec: CompressionCodec,
+        original_len: u32,
+        data: Vec<u8>,
+    },
+    // === Server events ===
+    /// The kinds the session's driver will actually report.
+    EventsSubscribed {
+        kinds: Vec<ConnectionEventKind>,
+    },
+    EventsUnsubscribed,
+    /// Unsolicited frame for a subscribed session. Sent with `request_id` 0
+    /// and `done=false`, between responses or before the response to the
+    /// request in flight.
+    Event(ConnectionEvent),
 }
 
+/// Request id of unsolicited event frames. Clients number requests from 1.
+pub const EVENT_REQUEST_ID: u64 = 0;
+
 /// Response envelope for driver RPC operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DriverResponseEnvelope {
@@ -563,6 +599,21 @@ impl DriverResponseEnvelope {
         }
     }
 
+    /// An unsolicited event frame for `session_id`.
+    pub fn event(
+        protocol_version: ProtocolVersion,
+        session_id: Uuid,
+        event: ConnectionEvent,
+    ) -> Self {
+        Self {
+            protocol_version,
+            request_id: EVENT_REQUEST_ID,
+            session_id: Some(session_id),
+            done: false,
+            body: DriverResponseBody::Event(event),
+        }
+    }
+
     pub fn error(
         protocol_version: ProtocolVersion,
         request_id: u64,
@@ -17,12 +17,18 @@ impl ProtocolVersion {
     }
 }
 
-pub const APP_CONTROL_VERSION: ProtocolVersion = ProtocolVersion::new(1, 0);
+/// App-control protocol version; see [`crate::protocol`] for what each minor
+/// version adds.
+pub const APP_CONTROL_VERSION: ProtocolVersion = ProtocolVersion::new(1, 4);
 pub const DRIVER_RPC_V1_0: ProtocolVersion = ProtocolVersion::new(1, 0);
 pub const DRIVER_RPC_V1_1: ProtocolVersion = ProtocolVersion::new(1, 1);
 pub const DRIVER_RPC_V1_2: ProtocolVersion = ProtocolVersion::new(1, 2);
+pub const DRIVER_RPC_V1_3: ProtocolVersion = ProtocolVersion::new(1, 3);
+/// Adds the `NaiveDateTime`, `Uuid`, `Interval` and `Enum` variants of
+/// `Value`.
+pub const DRIVER_RPC_V1_4: ProtocolVersion = ProtocolVersion::new(1, 4);
 /// Current highest driver protocol version.
-pub const DRIVER_RPC_VERSION: ProtocolVersion = DRIVER_RPC_V1_2;
+pub const DRIVER_RPC_VERSION: ProtocolVersion = DRIVER_RPC_V1_4;
 pub const AUTH_PROVIDER_RPC_V1_0: ProtocolVersion = ProtocolVersion::new(1, 0);
 pub const AUTH_PROVIDER_RPC_V1_1: ProtocolVersion = ProtocolVersion::new(1, 1);
 pub const AUTH_PROVIDER_RPC_V1_2: ProtocolVersion = ProtocolVersion::new(1, 2);
@@ -59,8 +65,13 @@ pub const DRIVER_RPC_API_CONTRACT: RpcApiContract =
 pub const AUTH_PROVIDER_RPC_API_CONTRACT: RpcApiContract =
     RpcApiContract::new(RpcApiFamily::AuthProviderRpc, AUTH_PROVIDER_RPC_VERSION);
 
-pub const DRIVER_RPC_SUPPORTED_VERSIONS: [ProtocolVersion; 3] =
-    [DRIVER_RPC_V1_0, DRIVER_RPC_V1_1, DRIVER_RPC_V1_2];
+pub const DRIVER_RPC_SUPPORTED_VERSIONS: [ProtocolVersion; 5] = [
+    DRIVER_RPC_V1_0,
+    DRIVER_RPC_V1_1,
+    DRIVER_RPC_V1_2,
+    DRIVER_RPC_V1_3,
+    DRIVER_RPC_V1_4,
+];
 
 pub const AUTH_PROVIDER_RPC_SUPPORTED_VERSIONS: [ProtocolVersion; 4] = [
     AUTH_PROVIDER_RPC_V1_3,
@@ -94,8 +105,9 @@ pub fn negotiate_highest_mutual_version(
 #[cfg(test)]
 mod tests {
     use super::{
-        AUTH_PROVIDER_RPC_V1_3, DRIVER_RPC_V1_1, DRIVER_RPC_V1_2, DRIVER_RPC_VERSION,
-        ProtocolVersion, RpcApiContract, RpcApiFamily, negotiate_highest_mutual_version,
+        AUTH_PROVIDER_RPC_V1_3, DRIVER_RPC_V1_1, DRIVER_RPC_V1_2, DRIVER_RPC_V1_3, DRIVER_RPC_V1_4,
+        DRIVER_RPC_VERSION, ProtocolVersion, RpcApiContract, RpcApiFamily,
+        negotiate_highest_mutual_version,
     };
 
     #[test]
@@ -142,9 +154,11 @@ mod tests {
 
     #[test]
     fn test_driver_rpc_version_constants() {
-        assert_eq!(DRIVER_RPC_VERSION, ProtocolVersion::new(1, 2));
+        assert_eq!(DRIVER_RPC_VERSION, ProtocolVersion::new(1, 4));
         assert_eq!(DRIVER_RPC_V1_1, ProtocolVersion::new(1, 1));
         assert_eq!(DRIVER_RPC_V1_2, ProtocolVersion::new(1, 2));
+        assert_eq!(DRIVER_RPC_V1_3, ProtocolVersion::new(1, 3));
+        assert_eq!(DRIVER_RPC_V1_4, ProtocolVersion::new(1, 4));
     }
 
     #[test]
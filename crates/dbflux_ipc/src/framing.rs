@@ -19,6 +19,35 @@ pub fn send_msg<W: Write, T: Serialize>(mut writer: W, msg: &T) -> io::Result<()
 }
 
 pub fn recv_msg<R: Read, T: DeserializeOwned>(mut reader: R) -> io::Result<T> {
+    let len = recv_len(&mut reader)?;
+    recv_body(reader, len)
+}
+
+/// [`recv_msg`] accepting messages of at most `max_len` bytes. A longer
+/// message is read and discarded, so the stream stays in sync, and fails
+/// with [`io::ErrorKind::FileTooLarge`].
+pub fn recv_msg_limited<R: Read, T: DeserializeOwned>(
+    mut reader: R,
+    max_len: usize,
+) -> io::Result<T> {
+    let len = recv_len(&mut reader)?;
+
+    if len > max_len {
+        let skipped = io::copy(&mut reader.take(len as u64), &mut io::sink())?;
+        if skipped != len as u64 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        return Err(io::Error::new(
+            io::ErrorKind::FileTooLarge,
+            format!("message of {len} bytes exceeds the {max_len} byte limit"),
+        ));
+    }
+
+    recv_body(reader, len)
+}
+
+fn recv_len<R: Read>(mut reader: R) -> io::Result<usize> {
     let mut len_bytes = [0u8; 4];
     reader.read_exact(&mut len_bytes)?;
     let len = u32::from_le_bytes(len_bytes) as usize;
@@ -27,6 +56,10 @@ pub fn recv_msg<R: Read, T: DeserializeOwned>(mut reader: R) -> io::Result<T> {
         return Err(io::Error::other("message too large"));
     }
 
+    Ok(len)
+}
+
+fn recv_body<R: Read, T: DeserializeOwned>(reader: R, len: usize) -> io::Result<T> {
     let mut buf = Vec::with_capacity(len.min(64 * 1024));
     reader.take(len as u64).read_to_end(&mut buf)?;
     if buf.len() != len {
@@ -39,6 +72,28 @@ pub fn recv_msg<R: Read, T: DeserializeOwned>(mut reader: R) -> io::Result<T> {
     postcard::from_bytes(&buf).map_err(io::Error::other)
 }
 
+/// Reads the first byte of the next message from a nonblocking `reader`, or
+/// `None` when no message has started to arrive.
+pub fn poll_msg_start<R: Read>(mut reader: R) -> io::Result<Option<u8>> {
+    let mut byte = [0u8; 1];
+
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(_) => return Ok(Some(byte[0])),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(None),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// [`recv_msg`] for a message whose first byte [`poll_msg_start`] already
+/// consumed.
+pub fn recv_msg_after<R: Read, T: DeserializeOwned>(first: u8, reader: R) -> io::Result<T> {
+    recv_msg([first].as_slice().chain(reader))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,6 +119,36 @@ mod tests {
         assert_eq!(decoded, payload);
     }
 
+    #[test]
+    fn recv_msg_after_resumes_a_polled_message() {
+        let mut buf = Vec::new();
+        send_msg(&mut buf, &"notify orders".to_string()).unwrap();
+
+        let mut reader = Cursor::new(buf);
+        let first = poll_msg_start(&mut reader)
+            .unwrap()
+            .expect("message pending");
+        let decoded: String = recv_msg_after(first, &mut reader).unwrap();
+        assert_eq!(decoded, "notify orders");
+
+        let err = poll_msg_start(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn recv_msg_limited_skips_oversized_messages() {
+        let mut buf = Vec::new();
+        send_msg(&mut buf, &vec![0xABu8; 4096]).unwrap();
+        send_msg(&mut buf, &"next".to_string()).unwrap();
+
+        let mut reader = Cursor::new(buf);
+        let err = recv_msg_limited::<_, Vec<u8>>(&mut reader, 1024).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::FileTooLarge);
+
+        let next: String = recv_msg_limited(&mut reader, 1024).unwrap();
+        assert_eq!(next, "next");
+    }
+
     #[test]
     fn recv_msg_rejects_oversized_header() {
         let oversized_len: u32 = MAX_MSG_SIZE + 1;
@@ -1,13 +1,16 @@
+pub mod app_control;
 pub mod audit;
 pub mod auth;
 pub mod auth_provider_client;
 pub mod auth_provider_protocol;
+pub mod compression;
 pub mod driver_protocol;
 pub mod envelope;
 pub mod framing;
 pub mod protocol;
 pub mod socket;
 
+pub use app_control::send_app_control_message;
 pub use audit::{
     AuditEventEmitDto, EventCategoryDto, EventOutcomeDto, EventSeverityDto, ExternalAuditEmitter,
     ExternalAuditSource,
@@ -30,19 +33,27 @@ pub use auth_provider_protocol::{
     LoginUrlProgress, ResolveCredentialsRequest, ResolvedCredentialsDto, ValidateSessionRequest,
     parse_auth_profile,
 };
+pub use compression::{
+    COMPRESSION_THRESHOLD, CompressionCodec, SUPPORTED_COMPRESSION_CODECS,
+    compression_capabilities, negotiate_compression,
+};
 pub use driver_protocol::{
     DriverCapability, DriverHelloRequest, DriverHelloResponse, DriverRequestBody,
     DriverRequestEnvelope, DriverResponseBody, DriverResponseEnvelope, DriverRpcError,
-    DriverRpcErrorCode, QueryRequestDto, QueryResultChunk, QueryResultDto, QueryResultShapeDto,
+    DriverRpcErrorCode, EVENT_REQUEST_ID, QueryRequestDto, QueryResultChunk, QueryResultDto,
+    QueryResultShapeDto,
 };
 pub use envelope::{
     APP_CONTROL_VERSION, AUTH_PROVIDER_RPC_API_CONTRACT, AUTH_PROVIDER_RPC_SUPPORTED_VERSIONS,
     AUTH_PROVIDER_RPC_V1_0, AUTH_PROVIDER_RPC_V1_1, AUTH_PROVIDER_RPC_V1_2, AUTH_PROVIDER_RPC_V1_3,
     AUTH_PROVIDER_RPC_VERSION, DRIVER_RPC_API_CONTRACT, DRIVER_RPC_SUPPORTED_VERSIONS,
-    DRIVER_RPC_V1_0, DRIVER_RPC_V1_1, DRIVER_RPC_V1_2, DRIVER_RPC_VERSION, ProtocolVersion,
-    RpcApiContract, RpcApiFamily, auth_provider_rpc_supported_versions,
-    driver_rpc_supported_versions, negotiate_highest_mutual_version,
+    DRIVER_RPC_V1_0, DRIVER_RPC_V1_1, DRIVER_RPC_V1_2, DRIVER_RPC_V1_3, DRIVER_RPC_V1_4,
+    DRIVER_RPC_VERSION, ProtocolVersion, RpcApiContract, RpcApiFamily,
+    auth_provider_rpc_supported_versions, driver_rpc_supported_versions,
+    negotiate_highest_mutual_version,
 };
 pub use framing::{recv_msg, send_msg};
-pub use protocol::{AppControlRequest, AppControlResponse, IpcMessage, IpcResponse};
+pub use protocol::{
+    ActiveConnection, AppControlRequest, AppControlResponse, IpcMessage, IpcResponse, TabSummary,
+};
 pub use socket::{auth_provider_socket_name, driver_socket_name, socket_name};
@@ -1,12 +1,94 @@
+//! App-control protocol: how other processes drive a running DBFlux.
+//!
+//! The app listens on the local socket from [`crate::socket_name`]. Every
+//! exchange opens a connection, sends one length-prefixed, postcard-encoded
+//! [`AppControlRequest`] (see [`crate::framing`]) and reads back one
+//! [`AppControlResponse`] with the same `request_id`. Requests must carry the
+//! token from [`crate::read_app_control_token`]; others get an
+//! [`IpcResponse::Error`].
+//!
+//! # Versioning
+//!
+//! Both envelopes carry [`APP_CONTROL_VERSION`]. Peers with the same major
+//! version are compatible; minor versions only append message and response
+//! variants. Postcard encodes variants by position, so new variants go at
+//! the end and existing ones never change shape; an older app cannot decode
+//! a newer variant and drops the connection.
+//!
+//! | Version | Adds |
+//! |---------|------|
+//! | 1.0 | `Ping`, `OpenScript`, `Focus` |
+//! | 1.1 | `FocusDocument` |
+//! | 1.2 | `OpenFile` |
+//! | 1.3 | `ListTabs`, `FocusTab`, `RunSavedQuery`, `GetActiveConnection` and the `Tabs` and `ActiveConnection` responses |
+//! | 1.4 | `ConnectProfile` |
+
 use crate::envelope::{APP_CONTROL_VERSION, ProtocolVersion};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IpcMessage {
     Ping,
-    OpenScript { path: PathBuf },
+    OpenScript {
+        path: PathBuf,
+    },
     Focus,
+    /// Raise the window and activate the tab of document `document_id`.
+    /// Sent when a desktop notification is clicked. Since 1.1.
+    FocusDocument {
+        document_id: Uuid,
+    },
+    /// Raise the window and open `path` as a document, run against the
+    /// connection profile named (or identified) by `profile` when given.
+    /// Sent by `dbflux <file>`. Since 1.2.
+    OpenFile {
+        path: PathBuf,
+        profile: Option<String>,
+    },
+    /// List the main window's open tabs, answered with
+    /// [`IpcResponse::Tabs`]. Since 1.3.
+    ListTabs,
+    /// Raise the window and activate the tab at `index` in the
+    /// [`IpcMessage::ListTabs`] order. Since 1.3.
+    FocusTab {
+        index: usize,
+    },
+    /// Open the saved query named (or identified) by `query` in a new tab
+    /// and run it against its connection, connecting it if needed. Since 1.3.
+    RunSavedQuery {
+        query: String,
+    },
+    /// Report the connection of the focused tab, or the app's active one,
+    /// answered with [`IpcResponse::ActiveConnection`]. Since 1.3.
+    GetActiveConnection,
+    /// Raise the window and connect the profile named (or identified) by
+    /// `profile`, focusing it in the sidebar. Sent by `dbflux connect`.
+    /// Since 1.4.
+    ConnectProfile {
+        profile: String,
+    },
+}
+
+/// One open tab, as listed by [`IpcMessage::ListTabs`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TabSummary {
+    pub document_id: Uuid,
+    pub title: String,
+    /// Document kind in snake case, such as `script`, `data` or `notebook`.
+    pub kind: String,
+    /// Whether this is the focused tab.
+    pub active: bool,
+    pub connection_id: Option<Uuid>,
+}
+
+/// The connection reported by [`IpcMessage::GetActiveConnection`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveConnection {
+    pub profile_id: Uuid,
+    pub profile_name: String,
+    pub database: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -19,6 +101,15 @@ pub enum IpcResponse {
     Error {
         message: String,
     },
+    /// Answer to [`IpcMessage::ListTabs`]. Since 1.3.
+    Tabs {
+        tabs: Vec<TabSummary>,
+    },
+    /// Answer to [`IpcMessage::GetActiveConnection`]; `None` when nothing is
+    /// connected. Since 1.3.
+    ActiveConnection {
+        connection: Option<ActiveConnection>,
+    },
 }
 
 /// Versioned request envelope for app-control IPC messages.
@@ -59,3 +150,85 @@ impl AppControlResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::{recv_msg, send_msg};
+
+    fn round_trip<T: Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+        let mut buf = Vec::new();
+        send_msg(&mut buf, value).unwrap();
+        recv_msg(buf.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn existing_variants_keep_their_encoding() {
+        // Older peers decode by variant position; appending variants must
+        // not renumber these.
+        let encode = |message: &IpcMessage| postcard::to_allocvec(message).unwrap()[0];
+
+        assert_eq!(encode(&IpcMessage::Ping), 0);
+        assert_eq!(encode(&IpcMessage::Focus), 2);
+        assert_eq!(encode(&IpcMessage::ListTabs), 5);
+        assert_eq!(encode(&IpcMessage::GetActiveConnection), 8);
+        assert_eq!(
+            encode(&IpcMessage::ConnectProfile {
+                profile: "prod".to_string()
+            }),
+            9
+        );
+    }
+
+    #[test]
+    fn connect_profile_round_trips() {
+        let message = IpcMessage::ConnectProfile {
+            profile: "prod".to_string(),
+        };
+
+        match round_trip(&message) {
+            IpcMessage::ConnectProfile { profile } => assert_eq!(profile, "prod"),
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn responses_round_trip() {
+        let tab = TabSummary {
+            document_id: Uuid::new_v4(),
+            title: "orders.sql".to_string(),
+            kind: "script".to_string(),
+            active: true,
+            connection_id: None,
+        };
+        let response = AppControlResponse::ok(
+            7,
+            IpcResponse::Tabs {
+                tabs: vec![tab.clone()],
+            },
+        );
+
+        let decoded = round_trip(&response);
+        assert_eq!(decoded.request_id, 7);
+        match decoded.body {
+            IpcResponse::Tabs { tabs } => assert_eq!(tabs, vec![tab]),
+            other => panic!("unexpected response: {other:?}"),
+        }
+
+        let connection = ActiveConnection {
+            profile_id: Uuid::new_v4(),
+            profile_name: "prod".to_string(),
+            database: Some("billing".to_string()),
+        };
+        match round_trip(&IpcResponse::ActiveConnection {
+            connection: Some(connection.clone()),
+        }) {
+            IpcResponse::ActiveConnection {
+                connection: decoded,
+            } => {
+                assert_eq!(decoded, Some(connection))
+            }
+            other => panic!("unexpected response: {other:?}"),
+        }
+    }
+}
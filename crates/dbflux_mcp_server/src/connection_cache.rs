@@ -10,7 +10,7 @@ use dbflux_core::{
     QueryRequest, QueryResult, RowDelete, RowInsert, RowPatch, SchemaFeatures,
     SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaSnapshot, SemanticPlan,
     SemanticPlanner, SemanticRequest, SqlDialect, SqlGenerationRequest, TableBrowseRequest,
-    TableCountRequest, TableInfo, Value, ViewInfo,
+    TableCountRequest, TableInfo, TableStatistics, Value, ViewInfo, WorkloadQuery,
 };
 
 pub struct CachedConnection {
@@ -62,6 +62,18 @@ impl Connection for CachedConnection {
         self.connection.execute_with_handle(req)
     }
 
+    fn session_gate(&self) -> Option<&dbflux_core::SessionGate> {
+        self.connection.session_gate()
+    }
+
+    fn execute_in_session(
+        &self,
+        scope: &dbflux_core::SessionScope,
+        req: &QueryRequest,
+    ) -> Result<QueryResult, DbError> {
+        self.connection.execute_in_session(scope, req)
+    }
+
     fn cancel(&self, handle: &QueryHandle) -> Result<(), DbError> {
         self.connection.cancel(handle)
     }
@@ -90,6 +102,10 @@ impl Connection for CachedConnection {
         self.connection.schema_for_database(database)
     }
 
+    fn schemas_for_databases(&self, databases: &[String]) -> Result<Vec<DbSchemaInfo>, DbError> {
+        self.connection.schemas_for_databases(databases)
+    }
+
     fn table_details(
         &self,
         database: &str,
@@ -108,6 +124,19 @@ impl Connection for CachedConnection {
         self.connection.view_details(database, schema, view)
     }
 
+    fn table_statistics(
+        &self,
+        database: &str,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Result<TableStatistics, DbError> {
+        self.connection.table_statistics(database, schema, table)
+    }
+
+    fn query_statistics(&self, limit: usize) -> Result<Vec<WorkloadQuery>, DbError> {
+        self.connection.query_statistics(limit)
+    }
+
     fn set_active_database(&self, database: Option<&str>) -> Result<(), DbError> {
         self.connection.set_active_database(database)
     }
@@ -163,9 +163,13 @@ pub fn value_to_json(value: &Value) -> serde_json::Value {
         | Value::Json(s)
         | Value::Decimal(s)
         | Value::ObjectId(s)
+        | Value::Enum(s)
         | Value::Unsupported(s) => serde_json::Value::String(s.clone()),
+        Value::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
+        Value::Interval(interval) => serde_json::Value::String(interval.to_string()),
         Value::Bytes(b) => serde_json::json!({ "_type": "bytes", "length": b.len() }),
         Value::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
+        Value::NaiveDateTime(dt) => serde_json::Value::String(dt.to_string()),
         Value::Date(d) => serde_json::Value::String(d.to_string()),
         Value::Time(t) => serde_json::Value::String(t.to_string()),
         Value::Array(arr) => serde_json::Value::Array(arr.iter().map(value_to_json).collect()),
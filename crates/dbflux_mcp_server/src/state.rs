@@ -993,6 +993,9 @@ mod tests {
             api_family: Some("auth_provider_rpc".to_string()),
             api_major: Some(1),
             api_minor: Some(0),
+            remote_address: None,
+            remote_tls: false,
+            remote_token_env: None,
             created_at: String::new(),
             updated_at: String::new(),
         })
@@ -1116,6 +1119,7 @@ mod tests {
             startup_timeout_ms: None,
             kind: RpcServiceKind::AuthProvider,
             api_contract: Some(dbflux_core::ServiceRpcApiContract::new("driver_rpc", 1, 1)),
+            remote: None,
         }];
 
         let registry = build_auth_provider_registry(&services);
@@ -19,10 +19,15 @@ use crate::paths;
 /// The session artifact subdirectory relative to the data directory.
 pub const SESSIONS_SUBDIR: &str = "sessions";
 
+/// Marker present while DBFlux runs; finding it at startup means the previous
+/// run did not shut down cleanly.
+const RUNNING_MARKER: &str = ".running";
+
 /// Manages filesystem artifact paths for DBFlux session content.
 ///
 /// Scratch files hold content for untitled tabs. Shadow files hold unsaved
-/// edits for file-backed tabs. Orphaned files (not referenced by any session
+/// edits for file-backed tabs. Results files hold result tabs the user chose
+/// to keep across restarts. Orphaned files (not referenced by any session
 /// tab) are cleaned up on restore.
 pub struct ArtifactStore {
     /// Root directory for session artifacts (e.g. `~/.local/share/dbflux/sessions/`).
@@ -68,6 +73,13 @@ impl ArtifactStore {
         self.root.join(format!("{}.shadow", id))
     }
 
+    /// Returns the path for the kept result tabs of the document with the given id.
+    ///
+    /// E.g. `results_path("tab-abc")` → `sessions/tab-abc.results.json`
+    pub fn results_path(&self, id: &str) -> PathBuf {
+        self.root.join(format!("{}.results.json", id))
+    }
+
     /// Checks whether a file exists at the given path.
     pub fn file_exists(&self, path: &Path) -> bool {
         path.exists()
@@ -110,10 +122,31 @@ impl ArtifactStore {
         std::fs::metadata(path).ok().and_then(|m| m.modified().ok())
     }
 
+    /// Records that DBFlux is running.
+    ///
+    /// Returns `true` if the marker was already there, i.e. the previous run
+    /// crashed or was killed before `clear_running_marker` was called.
+    pub fn mark_running(&self) -> bool {
+        let marker = self.root.join(RUNNING_MARKER);
+        let previous_run_crashed = marker.exists();
+
+        if let Err(error) = std::fs::write(&marker, std::process::id().to_string()) {
+            log::warn!("failed to write {}: {error}", marker.display());
+        }
+
+        previous_run_crashed
+    }
+
+    /// Removes the running marker on a clean shutdown.
+    pub fn clear_running_marker(&self) {
+        self.delete_file(&self.root.join(RUNNING_MARKER));
+    }
+
     /// Removes scratch/shadow files that are not in the given set of referenced paths.
     ///
     /// This is the orphan cleanup mechanism called during session restore.
-    /// Files are matched by absolute path; `session.json` is always excluded.
+    /// Files are matched by absolute path; `session.json` and the running
+    /// marker are always excluded.
     pub fn cleanup_orphans(&self, referenced_paths: &[PathBuf]) {
         let referenced: std::collections::HashSet<PathBuf> =
             referenced_paths.iter().cloned().collect();
@@ -129,7 +162,7 @@ impl ArtifactStore {
             // Always skip session.json (the old manifest — may linger after migration)
             if path
                 .file_name()
-                .map(|n| n == "session.json")
+                .map(|n| n == "session.json" || n == RUNNING_MARKER)
                 .unwrap_or(false)
             {
                 continue;
@@ -231,6 +264,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn running_marker_reports_an_unclean_exit() {
+        let (_dir, store) = temp_store();
+
+        assert!(!store.mark_running());
+        store.cleanup_orphans(&[]);
+        assert!(store.list_artifacts().is_empty());
+
+        // Not cleared: the next launch sees the previous run crashed.
+        assert!(store.mark_running());
+
+        store.clear_running_marker();
+        assert!(!store.mark_running());
+    }
+
     #[test]
     fn list_artifacts() {
         let (_dir, store) = temp_store();
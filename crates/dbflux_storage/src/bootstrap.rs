@@ -20,13 +20,15 @@ use crate::repositories::general_settings::GeneralSettingsRepository;
 use crate::repositories::governance_settings::GovernanceSettingsRepository;
 use crate::repositories::hook_definitions::HookDefinitionRepository;
 use crate::repositories::proxy_profiles::ProxyProfileRepository;
+use crate::repositories::query_variables::QueryVariablesRepository;
 use crate::repositories::saved_filters::SavedFiltersRepository;
+use crate::repositories::scheduled_exports::ScheduledExportsRepository;
 use crate::repositories::services::ServiceRepository;
 use crate::repositories::ssh_tunnel_profiles::SshTunnelProfileRepository;
 use crate::repositories::state::{
-    query_history::QueryHistoryRepository, recent_items::RecentItemsRepository,
-    saved_queries::SavedQueriesRepository, sessions::SessionRepository,
-    ui_state::UiStateRepository,
+    explain_plans::ExplainPlansRepository, query_history::QueryHistoryRepository,
+    recent_items::RecentItemsRepository, saved_queries::SavedQueriesRepository,
+    sessions::SessionRepository, ui_state::UiStateRepository,
 };
 use crate::repositories::viz_dashboard_panels::DashboardPanelsRepository;
 use crate::repositories::viz_dashboards::DashboardsRepository;
@@ -212,6 +214,16 @@ impl StorageRuntime {
         DriverSettingValuesRepository::new(self.dbflux_db())
     }
 
+    /// Creates a query variables repository.
+    pub fn query_variables(&self) -> QueryVariablesRepository {
+        QueryVariablesRepository::new(self.dbflux_db())
+    }
+
+    /// Creates a scheduled exports repository.
+    pub fn scheduled_exports(&self) -> ScheduledExportsRepository {
+        ScheduledExportsRepository::new(self.dbflux_db())
+    }
+
     // --- State repositories ---
 
     /// Creates a UI state repository.
@@ -229,6 +241,11 @@ impl StorageRuntime {
         QueryHistoryRepository::new(self.dbflux_db())
     }
 
+    /// Creates an execution plan history repository.
+    pub fn explain_plans(&self) -> ExplainPlansRepository {
+        ExplainPlansRepository::new(self.dbflux_db())
+    }
+
     /// Creates a saved queries repository.
     pub fn saved_queries(&self) -> SavedQueriesRepository {
         SavedQueriesRepository::new(self.dbflux_db())
@@ -330,6 +347,11 @@ impl StorageRuntime {
     pub fn shadow_path(&self, doc_id: &str) -> std::path::PathBuf {
         self.artifacts.shadow_path(doc_id)
     }
+
+    /// Returns the kept result tabs file path for a document ID.
+    pub fn results_path(&self, doc_id: &str) -> std::path::PathBuf {
+        self.artifacts.results_path(doc_id)
+    }
 }
 
 /// Bootstraps the internal storage layer.
@@ -18,14 +18,15 @@ pub use repositories::audit::{
 };
 pub use repositories::qry_saved_queries::{SavedQueryRepo, SavedQuerySummary};
 pub use repositories::state::{
-    query_history::QueryHistoryRepository, recent_items::RecentItemsRepository,
-    saved_queries::SavedQueriesRepository, sessions::SessionRepository,
-    ui_state::UiStateRepository,
+    explain_plans::ExplainPlansRepository, query_history::QueryHistoryRepository,
+    recent_items::RecentItemsRepository, saved_queries::SavedQueriesRepository,
+    sessions::SessionRepository, ui_state::UiStateRepository,
 };
 pub use repositories::{
     auth_profiles::AuthProfileRepository, connection_profiles::ConnectionProfileRepository,
     driver_settings::DriverSettingsRepository, hook_definitions::HookDefinitionRepository,
-    proxy_profiles::ProxyProfileRepository, services::ServiceRepository,
-    settings::SettingsRepository, ssh_tunnel_profiles::SshTunnelProfileRepository,
+    proxy_profiles::ProxyProfileRepository, query_variables::QueryVariablesRepository,
+    services::ServiceRepository, settings::SettingsRepository,
+    ssh_tunnel_profiles::SshTunnelProfileRepository,
 };
 pub use service_configs::load_service_configs;
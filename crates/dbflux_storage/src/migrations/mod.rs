@@ -161,6 +161,29 @@ impl MigrationRegistry {
         registry.register(mod_017_qry_saved_queries::MigrationImpl);
         registry.register(mod_018_app_pending_executions::MigrationImpl);
         registry.register(mod_019_hook_env_denylist::MigrationImpl);
+        registry.register(mod_020_general_settings_editor_vim_mode::MigrationImpl);
+        registry.register(mod_021_cfg_query_variables::MigrationImpl);
+        registry.register(mod_022_query_history_fts::MigrationImpl);
+        registry.register(mod_023_history_retention::MigrationImpl);
+        registry.register(mod_024_query_history_schema::MigrationImpl);
+        registry.register(mod_025_saved_query_tags::MigrationImpl);
+        registry.register(mod_026_saved_query_params::MigrationImpl);
+        registry.register(mod_027_session_tab_results::MigrationImpl);
+        registry.register(mod_028_cfg_connection_favorites::MigrationImpl);
+        registry.register(mod_029_general_settings_hide_system_objects::MigrationImpl);
+        registry.register(mod_030_general_settings_schema_refresh::MigrationImpl);
+        registry.register(mod_031_cfg_scheduled_exports::MigrationImpl);
+        registry.register(mod_032_general_settings_query_notifications::MigrationImpl);
+        registry.register(mod_033_session_tab_data_state::MigrationImpl);
+        registry.register(mod_034_session_tab_group::MigrationImpl);
+        registry.register(mod_035_general_settings_font_scale::MigrationImpl);
+        registry.register(mod_036_general_settings_language::MigrationImpl);
+        registry.register(mod_037_rpc_service_remote::MigrationImpl);
+        registry.register(mod_038_general_settings_display_timezone::MigrationImpl);
+        registry.register(mod_039_general_settings_null_display::MigrationImpl);
+        registry.register(mod_040_general_settings_display_format::MigrationImpl);
+        registry.register(mod_041_st_explain_plans::MigrationImpl);
+        registry.register(mod_042_query_history_retention_index::MigrationImpl);
         registry
     }
 
@@ -363,6 +386,29 @@ mod mod_016_viz_divider_saved_chart_id_constraint;
 mod mod_017_qry_saved_queries;
 mod mod_018_app_pending_executions;
 mod mod_019_hook_env_denylist;
+mod mod_020_general_settings_editor_vim_mode;
+mod mod_021_cfg_query_variables;
+mod mod_022_query_history_fts;
+mod mod_023_history_retention;
+mod mod_024_query_history_schema;
+mod mod_025_saved_query_tags;
+mod mod_026_saved_query_params;
+mod mod_027_session_tab_results;
+mod mod_028_cfg_connection_favorites;
+mod mod_029_general_settings_hide_system_objects;
+mod mod_030_general_settings_schema_refresh;
+mod mod_031_cfg_scheduled_exports;
+mod mod_032_general_settings_query_notifications;
+mod mod_033_session_tab_data_state;
+mod mod_034_session_tab_group;
+mod mod_035_general_settings_font_scale;
+mod mod_036_general_settings_language;
+mod mod_037_rpc_service_remote;
+mod mod_038_general_settings_display_timezone;
+mod mod_039_general_settings_null_display;
+mod mod_040_general_settings_display_format;
+mod mod_041_st_explain_plans;
+mod mod_042_query_history_retention_index;
 
 pub use mod_001_initial::MigrationImpl;
 pub use mod_002_audit_extended::MigrationImpl as MigrationImplAuditExtended;
@@ -961,6 +1007,29 @@ mod tests {
             "017_qry_saved_queries",
             "018_app_pending_executions",
             "019_hook_env_denylist",
+            "020_general_settings_editor_vim_mode",
+            "021_cfg_query_variables",
+            "022_query_history_fts",
+            "023_history_retention",
+            "024_query_history_schema",
+            "025_saved_query_tags",
+            "026_saved_query_params",
+            "027_session_tab_results",
+            "028_cfg_connection_favorites",
+            "029_general_settings_hide_system_objects",
+            "030_general_settings_schema_refresh",
+            "031_cfg_scheduled_exports",
+            "032_general_settings_query_notifications",
+            "033_session_tab_data_state",
+            "034_session_tab_group",
+            "035_general_settings_font_scale",
+            "036_general_settings_language",
+            "037_rpc_service_remote",
+            "038_general_settings_display_timezone",
+            "039_general_settings_null_display",
+            "040_general_settings_display_format",
+            "041_st_explain_plans",
+            "042_query_history_retention_index",
         ];
 
         let pending = registry.get_pending(&conn).unwrap();
@@ -0,0 +1,61 @@
+//! Migration 020: Add `editor_vim_mode` column to `cfg_general_settings`.
+//!
+//! Adds `editor_vim_mode INTEGER NOT NULL DEFAULT 0` so the opt-in modal
+//! editing layer survives restarts. Existing rows default to off.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+/// Adds the `editor_vim_mode` column to `cfg_general_settings`.
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "020_general_settings_editor_vim_mode"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<unknown>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = 'editor_vim_mode'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<unknown>"),
+                source,
+            })?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE cfg_general_settings ADD COLUMN editor_vim_mode INTEGER NOT NULL DEFAULT 0;",
+            )
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<unknown>"),
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+//! Migration 021: `cfg_query_variables` table for `{{name}}` query variables.
+//!
+//! Each row holds one variable value for a scope. `scope` is either the
+//! literal `workspace` (shared by every connection) or a connection profile
+//! id; connection-scoped rows shadow workspace rows with the same name.
+
+use rusqlite::Transaction;
+
+use super::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "021_cfg_query_variables"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        tx.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS cfg_query_variables (
+                scope      TEXT NOT NULL,
+                name       TEXT NOT NULL,
+                value      TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (scope, name)
+            );
+            ",
+        )
+        .map_err(|source| MigrationError::Sqlite {
+            path: std::path::PathBuf::from("<021_cfg_query_variables>"),
+            source,
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! Migration 022: full-text index and filter indexes for `st_query_history`.
+//!
+//! `st_query_history_fts` is an external-content FTS5 table over
+//! `query_text` using the trigram tokenizer, so any substring of three or more
+//! characters matches case-insensitively — the same semantics the history
+//! search had with `LIKE`, without scanning every row. Triggers keep the index
+//! in step with the history table; existing rows are indexed by the final
+//! `rebuild`.
+//!
+//! The index is keyed by the history table's implicit rowid. dbflux never runs
+//! `VACUUM` on `dbflux.db`; if that changes, follow it with a `rebuild`.
+
+use rusqlite::Transaction;
+
+use super::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "022_query_history_fts"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_query_history'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<022_query_history_fts>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        tx.execute_batch(
+            "
+            CREATE VIRTUAL TABLE IF NOT EXISTS st_query_history_fts USING fts5(
+                query_text,
+                content = 'st_query_history',
+                content_rowid = 'rowid',
+                tokenize = 'trigram'
+            );
+
+            CREATE TRIGGER IF NOT EXISTS trg_st_query_history_fts_insert
+            AFTER INSERT ON st_query_history BEGIN
+                INSERT INTO st_query_history_fts (rowid, query_text)
+                VALUES (new.rowid, new.query_text);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS trg_st_query_history_fts_delete
+            AFTER DELETE ON st_query_history BEGIN
+                INSERT INTO st_query_history_fts (st_query_history_fts, rowid, query_text)
+                VALUES ('delete', old.rowid, old.query_text);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS trg_st_query_history_fts_update
+            AFTER UPDATE OF query_text ON st_query_history BEGIN
+                INSERT INTO st_query_history_fts (st_query_history_fts, rowid, query_text)
+                VALUES ('delete', old.rowid, old.query_text);
+                INSERT INTO st_query_history_fts (rowid, query_text)
+                VALUES (new.rowid, new.query_text);
+            END;
+
+            INSERT INTO st_query_history_fts (st_query_history_fts) VALUES ('rebuild');
+
+            CREATE INDEX IF NOT EXISTS idx_st_query_history_profile_executed
+                ON st_query_history(connection_profile_id, executed_at DESC);
+            CREATE INDEX IF NOT EXISTS idx_st_query_history_database
+                ON st_query_history(database_name);
+            ",
+        )
+        .map_err(|source| MigrationError::Sqlite {
+            path: std::path::PathBuf::from("<022_query_history_fts>"),
+            source,
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Migration 023: query history retention, pinning and de-duplication.
+//!
+//! - `cfg_general_settings` gains `history_max_age_days` (0 keeps entries
+//!   forever) and `history_collapse_duplicates` (on by default).
+//! - `st_query_history` gains `is_pinned`, which exempts an entry from
+//!   pruning, and `run_count`, which counts consecutive identical executions
+//!   collapsed into one entry.
+//!
+//! Favorites were the only entries exempt from trimming before this
+//! migration, so existing favorites start out pinned.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn table_exists(tx: &Transaction, table: &str) -> Result<bool, MigrationError> {
+    tx.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name = ?1",
+        [table],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+    .map_err(|source| MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<023_history_retention>"),
+        source,
+    })
+}
+
+fn column_exists(tx: &Transaction, table: &str, column: &str) -> Result<bool, MigrationError> {
+    tx.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+        [table, column],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+    .map_err(|source| MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<023_history_retention>"),
+        source,
+    })
+}
+
+/// Adds `column` to `table` unless the table is absent (tests that pre-seed
+/// `sys_migrations` create only a subset of tables) or the column is already
+/// there. Returns whether the column was added.
+fn add_column(
+    tx: &Transaction,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<bool, MigrationError> {
+    if !table_exists(tx, table)? || column_exists(tx, table, column)? {
+        return Ok(false);
+    }
+
+    tx.execute_batch(&format!(
+        "ALTER TABLE {} ADD COLUMN {} {};",
+        table, column, definition
+    ))
+    .map_err(|source| MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<023_history_retention>"),
+        source,
+    })?;
+
+    Ok(true)
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "023_history_retention"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        add_column(
+            tx,
+            "cfg_general_settings",
+            "history_max_age_days",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+        add_column(
+            tx,
+            "cfg_general_settings",
+            "history_collapse_duplicates",
+            "INTEGER NOT NULL DEFAULT 1",
+        )?;
+
+        let pinned_added = add_column(
+            tx,
+            "st_query_history",
+            "is_pinned",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+        add_column(
+            tx,
+            "st_query_history",
+            "run_count",
+            "INTEGER NOT NULL DEFAULT 1",
+        )?;
+
+        if pinned_added {
+            tx.execute_batch(
+                "
+                UPDATE st_query_history SET is_pinned = 1 WHERE is_favorite = 1;
+                CREATE INDEX IF NOT EXISTS idx_st_query_history_pinned
+                    ON st_query_history(is_pinned);
+                ",
+            )
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<023_history_retention>"),
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+//! Migration 024: Add `schema_name` column to `st_query_history`.
+//!
+//! History entries already record the connection profile and database they
+//! ran against; the schema completes the execution context so "Re-run" can
+//! restore it. Existing rows have no recorded schema.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+/// Adds the `schema_name` column to `st_query_history`.
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "024_query_history_schema"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_query_history'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<024_query_history_schema>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('st_query_history') WHERE name = 'schema_name'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<024_query_history_schema>"),
+                source,
+            })?;
+
+        if !column_exists {
+            tx.execute_batch("ALTER TABLE st_query_history ADD COLUMN schema_name TEXT;")
+                .map_err(|source| MigrationError::Sqlite {
+                    path: std::path::PathBuf::from("<024_query_history_schema>"),
+                    source,
+                })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Migration 025: Add `tags_json` column to `st_saved_queries`.
+//!
+//! Saved queries could already live in folders (`st_saved_query_folders`)
+//! but had no tags. Tags are stored as a JSON array of normalized names;
+//! existing rows start with no tags.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+/// Adds the `tags_json` column to `st_saved_queries`.
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "025_saved_query_tags"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_saved_queries'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<025_saved_query_tags>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('st_saved_queries') WHERE name = 'tags_json'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<025_saved_query_tags>"),
+                source,
+            })?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE st_saved_queries ADD COLUMN tags_json TEXT NOT NULL DEFAULT '[]';",
+            )
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<025_saved_query_tags>"),
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Migration 026: Add `params_json` column to `st_saved_queries`.
+//!
+//! Saved queries can declare `:name` parameters with a type, a default and
+//! the last value entered when the query ran. Declarations are stored as a
+//! JSON array; existing rows start with no parameters.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+/// Adds the `params_json` column to `st_saved_queries`.
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "026_saved_query_params"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_saved_queries'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<026_saved_query_params>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('st_saved_queries') WHERE name = 'params_json'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<026_saved_query_params>"),
+                source,
+            })?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE st_saved_queries ADD COLUMN params_json TEXT NOT NULL DEFAULT '[]';",
+            )
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<026_saved_query_params>"),
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
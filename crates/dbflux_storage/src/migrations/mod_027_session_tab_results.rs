@@ -0,0 +1,61 @@
+//! Migration 027: Add `results_file_path` column to `st_session_tabs`.
+//!
+//! Result tabs marked "keep results" are written to a JSON artifact next to
+//! the tab's scratch/shadow file. The column records that artifact so it is
+//! restored with the session and spared by orphan cleanup; existing rows
+//! start with no kept results.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+/// Adds the `results_file_path` column to `st_session_tabs`.
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "027_session_tab_results"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_session_tabs'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<027_session_tab_results>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('st_session_tabs') WHERE name = 'results_file_path'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<027_session_tab_results>"),
+                source,
+            })?;
+
+        if !column_exists {
+            tx.execute_batch("ALTER TABLE st_session_tabs ADD COLUMN results_file_path TEXT;")
+                .map_err(|source| MigrationError::Sqlite {
+                    path: std::path::PathBuf::from("<027_session_tab_results>"),
+                    source,
+                })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+//! Migration 028: `cfg_connection_favorites` table for sidebar favorites.
+//!
+//! Each row stars one table, view or saved query of a connection profile.
+//! `kind` is `table`, `view` or `saved_query`; objects fill the
+//! `database_name`/`schema_name`/`object_name` columns and saved queries fill
+//! `saved_query_id`. `position` orders the favorites of a profile.
+
+use rusqlite::Transaction;
+
+use super::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "028_cfg_connection_favorites"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        tx.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS cfg_connection_favorites (
+                id             TEXT PRIMARY KEY,
+                profile_id     TEXT NOT NULL,
+                kind           TEXT NOT NULL,
+                database_name  TEXT,
+                schema_name    TEXT,
+                object_name    TEXT,
+                saved_query_id TEXT,
+                position       INTEGER NOT NULL DEFAULT 0,
+                FOREIGN KEY (profile_id) REFERENCES cfg_connection_profiles(id) ON DELETE CASCADE
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_cfg_connection_favorites_profile
+                ON cfg_connection_favorites(profile_id);
+            ",
+        )
+        .map_err(|source| MigrationError::Sqlite {
+            path: std::path::PathBuf::from("<028_cfg_connection_favorites>"),
+            source,
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Migration 029: Add `hide_system_objects` column to `cfg_general_settings`.
+//!
+//! Adds `hide_system_objects INTEGER NOT NULL DEFAULT 1`, the default for
+//! hiding system schemas and internal objects from the sidebar and quick
+//! open. Existing rows keep them hidden, as drivers used to.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+/// Adds the `hide_system_objects` column to `cfg_general_settings`.
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "029_general_settings_hide_system_objects"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<unknown>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = 'hide_system_objects'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<unknown>"),
+                source,
+            })?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE cfg_general_settings ADD COLUMN hide_system_objects INTEGER NOT NULL DEFAULT 1;",
+            )
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<unknown>"),
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+//! Migration 030: sidebar schema auto-refresh settings.
+//!
+//! `cfg_general_settings` gains `schema_refresh_interval_secs` (0 keeps schema
+//! refreshes manual) and `schema_refresh_on_focus`. Both default to off, so
+//! existing installs only refresh schemas when asked to.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<030_general_settings_schema_refresh>"),
+        source,
+    }
+}
+
+fn column_exists(tx: &Transaction, column: &str) -> Result<bool, MigrationError> {
+    tx.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = ?1",
+        [column],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+    .map_err(sqlite_error)
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "030_general_settings_schema_refresh"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        for (column, definition) in [
+            ("schema_refresh_interval_secs", "INTEGER NOT NULL DEFAULT 0"),
+            ("schema_refresh_on_focus", "INTEGER NOT NULL DEFAULT 0"),
+        ] {
+            if column_exists(tx, column)? {
+                continue;
+            }
+
+            tx.execute_batch(&format!(
+                "ALTER TABLE cfg_general_settings ADD COLUMN {} {};",
+                column, definition
+            ))
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+//! Migration 031: `cfg_scheduled_exports` and `cfg_scheduled_export_runs`.
+//!
+//! A scheduled export runs a saved query on a cron expression and writes the
+//! result to `output_dir` in `format` (an export format key such as `csv`).
+//! Each run is recorded in `cfg_scheduled_export_runs`; `detail` holds the
+//! written file on success and the error otherwise.
+
+use rusqlite::Transaction;
+
+use super::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "031_cfg_scheduled_exports"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        tx.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS cfg_scheduled_exports (
+                id             TEXT PRIMARY KEY,
+                name           TEXT NOT NULL,
+                saved_query_id TEXT NOT NULL,
+                schedule       TEXT NOT NULL,
+                format         TEXT NOT NULL,
+                output_dir     TEXT NOT NULL,
+                enabled        INTEGER NOT NULL DEFAULT 1,
+                created_at     INTEGER NOT NULL,
+                updated_at     TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS cfg_scheduled_export_runs (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                schedule_id TEXT NOT NULL,
+                started_at  INTEGER NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                succeeded   INTEGER NOT NULL,
+                detail      TEXT NOT NULL,
+                FOREIGN KEY (schedule_id) REFERENCES cfg_scheduled_exports(id) ON DELETE CASCADE
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_cfg_scheduled_export_runs_schedule
+                ON cfg_scheduled_export_runs(schedule_id, started_at);
+            ",
+        )
+        .map_err(|source| MigrationError::Sqlite {
+            path: std::path::PathBuf::from("<031_cfg_scheduled_exports>"),
+            source,
+        })?;
+
+        Ok(())
+    }
+}
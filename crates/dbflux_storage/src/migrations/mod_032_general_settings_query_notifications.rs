@@ -0,0 +1,59 @@
+//! Migration 032: long-running query notification threshold.
+//!
+//! `cfg_general_settings` gains `notify_long_query_secs`: queries that run at
+//! least this long raise a desktop notification when their tab is not
+//! focused. 0 turns the notification off; existing installs get 30 seconds.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<032_general_settings_query_notifications>"),
+        source,
+    }
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "032_general_settings_query_notifications"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = 'notify_long_query_secs'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE cfg_general_settings ADD COLUMN notify_long_query_secs INTEGER NOT NULL DEFAULT 30;",
+            )
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+//! Migration 033: Add `data_state_json` column to `st_session_tabs`.
+//!
+//! Table and collection tabs are restored with the session. The column holds
+//! the tab's browse state (target, filter, sort, page) as a serialized
+//! `DataTabState`; editor tabs and existing rows leave it empty.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<033_session_tab_data_state>"),
+        source,
+    }
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "033_session_tab_data_state"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_session_tabs'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('st_session_tabs') WHERE name = 'data_state_json'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !column_exists {
+            tx.execute_batch("ALTER TABLE st_session_tabs ADD COLUMN data_state_json TEXT;")
+                .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
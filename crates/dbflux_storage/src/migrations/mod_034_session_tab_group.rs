@@ -0,0 +1,58 @@
+//! Migration 034: Add `tab_group` column to `st_session_tabs`.
+//!
+//! Tabs can be split into groups within the tab bar. The column holds the
+//! 0-based group of each tab; existing rows fall into the first group.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<034_session_tab_group>"),
+        source,
+    }
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "034_session_tab_group"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_session_tabs'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('st_session_tabs') WHERE name = 'tab_group'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE st_session_tabs ADD COLUMN tab_group INTEGER NOT NULL DEFAULT 0;",
+            )
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
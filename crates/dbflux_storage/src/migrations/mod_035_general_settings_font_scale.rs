@@ -0,0 +1,69 @@
+//! Migration 035: UI zoom and font size settings.
+//!
+//! `cfg_general_settings` gains `ui_zoom_percent`, `editor_font_size` and
+//! `grid_font_size`. Existing installs get the sizes they rendered with
+//! before: 100% zoom, 14px editors and 13px grid cells.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<035_general_settings_font_scale>"),
+        source,
+    }
+}
+
+fn column_exists(tx: &Transaction, column: &str) -> Result<bool, MigrationError> {
+    tx.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = ?1",
+        [column],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+    .map_err(sqlite_error)
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "035_general_settings_font_scale"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        for (column, definition) in [
+            ("ui_zoom_percent", "INTEGER NOT NULL DEFAULT 100"),
+            ("editor_font_size", "INTEGER NOT NULL DEFAULT 14"),
+            ("grid_font_size", "INTEGER NOT NULL DEFAULT 13"),
+        ] {
+            if column_exists(tx, column)? {
+                continue;
+            }
+
+            tx.execute_batch(&format!(
+                "ALTER TABLE cfg_general_settings ADD COLUMN {} {};",
+                column, definition
+            ))
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+//! Migration 036: interface language.
+//!
+//! `cfg_general_settings` gains `language`, the ISO 639-1 code of the UI
+//! translation. Existing installs keep English (`en`).
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<036_general_settings_language>"),
+        source,
+    }
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "036_general_settings_language"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        let column_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = 'language'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !column_exists {
+            tx.execute_batch(
+                "ALTER TABLE cfg_general_settings ADD COLUMN language TEXT NOT NULL DEFAULT 'en';",
+            )
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
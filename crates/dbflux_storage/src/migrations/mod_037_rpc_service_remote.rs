@@ -0,0 +1,66 @@
+//! Migration 037: remote RPC services.
+//!
+//! `cfg_services` gains the TCP endpoint of services that run on another
+//! machine: `remote_address` (`host:port`, NULL for local services),
+//! `remote_tls` and `remote_token_env`, the environment variable holding the
+//! service's `Hello` token.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<037_rpc_service_remote>"),
+        source,
+    }
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "037_rpc_service_remote"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_services'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        for (column, definition) in [
+            ("remote_address", "TEXT"),
+            ("remote_tls", "INTEGER NOT NULL DEFAULT 0"),
+            ("remote_token_env", "TEXT"),
+        ] {
+            let column_exists: bool = tx
+                .query_row(
+                    "SELECT COUNT(*) FROM pragma_table_info('cfg_services') WHERE name = ?1",
+                    [column],
+                    |row| row.get::<_, i64>(0),
+                )
+                .map(|n| n > 0)
+                .map_err(sqlite_error)?;
+
+            if !column_exists {
+                tx.execute_batch(&format!(
+                    "ALTER TABLE cfg_services ADD COLUMN {column} {definition};"
+                ))
+                .map_err(sqlite_error)?;
+            }
+        }
+
+        Ok(())
+    }
+}
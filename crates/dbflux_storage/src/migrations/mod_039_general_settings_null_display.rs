@@ -0,0 +1,68 @@
+//! Migration 039: NULL display settings.
+//!
+//! `cfg_general_settings` gains `null_marker`, the text shown for NULL cells,
+//! and `null_style` (`muted`, `badge` or `plain`). Existing installs keep the
+//! muted italic `NULL` they rendered with before.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<039_general_settings_null_display>"),
+        source,
+    }
+}
+
+fn column_exists(tx: &Transaction, column: &str) -> Result<bool, MigrationError> {
+    tx.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = ?1",
+        [column],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+    .map_err(sqlite_error)
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "039_general_settings_null_display"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        for (column, definition) in [
+            ("null_marker", "TEXT NOT NULL DEFAULT 'NULL'"),
+            ("null_style", "TEXT NOT NULL DEFAULT 'muted'"),
+        ] {
+            if column_exists(tx, column)? {
+                continue;
+            }
+
+            tx.execute_batch(&format!(
+                "ALTER TABLE cfg_general_settings ADD COLUMN {} {};",
+                column, definition
+            ))
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
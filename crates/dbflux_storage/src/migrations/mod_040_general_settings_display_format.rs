@@ -0,0 +1,78 @@
+//! Migration 040: number and date display formats.
+//!
+//! `cfg_general_settings` gains the digit grouping, decimal places,
+//! date/time/timestamp patterns, scientific-notation threshold and
+//! formatted-output switch of result grids. `-1` stores "as stored" / "never"
+//! for the integer settings. Existing installs keep the formats they rendered
+//! with before.
+
+use rusqlite::Transaction;
+
+use crate::migrations::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+fn sqlite_error(source: rusqlite::Error) -> MigrationError {
+    MigrationError::Sqlite {
+        path: std::path::PathBuf::from("<040_general_settings_display_format>"),
+        source,
+    }
+}
+
+fn column_exists(tx: &Transaction, column: &str) -> Result<bool, MigrationError> {
+    tx.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('cfg_general_settings') WHERE name = ?1",
+        [column],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+    .map_err(sqlite_error)
+}
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "040_general_settings_display_format"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='cfg_general_settings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sqlite_error)?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        for (column, definition) in [
+            ("thousands_separator", "TEXT NOT NULL DEFAULT ''"),
+            ("decimal_places", "INTEGER NOT NULL DEFAULT -1"),
+            ("date_format", "TEXT NOT NULL DEFAULT '%Y-%m-%d'"),
+            ("time_format", "TEXT NOT NULL DEFAULT '%H:%M:%S'"),
+            (
+                "timestamp_format",
+                "TEXT NOT NULL DEFAULT '%Y-%m-%d %H:%M:%S'",
+            ),
+            ("scientific_threshold", "INTEGER NOT NULL DEFAULT -1"),
+            ("formatted_output", "INTEGER NOT NULL DEFAULT 0"),
+        ] {
+            if column_exists(tx, column)? {
+                continue;
+            }
+
+            tx.execute_batch(&format!(
+                "ALTER TABLE cfg_general_settings ADD COLUMN {} {};",
+                column, definition
+            ))
+            .map_err(sqlite_error)?;
+        }
+
+        Ok(())
+    }
+}
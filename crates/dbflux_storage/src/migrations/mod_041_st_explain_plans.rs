@@ -0,0 +1,42 @@
+//! Migration 041: `st_explain_plans` table for execution plan history.
+//!
+//! Each row is one plan captured by running EXPLAIN on a statement.
+//! `statement` is the whitespace-normalized statement text, so plans of the
+//! same query group together per profile; `plan_json` holds the parsed plan
+//! tree.
+
+use rusqlite::Transaction;
+
+use super::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "041_st_explain_plans"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        tx.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS st_explain_plans (
+                id                    TEXT PRIMARY KEY,
+                connection_profile_id TEXT,
+                statement             TEXT NOT NULL,
+                captured_at           TEXT NOT NULL DEFAULT (datetime('now')),
+                plan_json             TEXT NOT NULL,
+                FOREIGN KEY (connection_profile_id) REFERENCES cfg_connection_profiles(id) ON DELETE CASCADE
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_st_explain_plans_statement
+                ON st_explain_plans(connection_profile_id, statement, captured_at DESC);
+            ",
+        )
+        .map_err(|source| MigrationError::Sqlite {
+            path: std::path::PathBuf::from("<041_st_explain_plans>"),
+            source,
+        })?;
+
+        Ok(())
+    }
+}
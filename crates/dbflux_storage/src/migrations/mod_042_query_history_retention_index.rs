@@ -0,0 +1,49 @@
+//! Migration 042: index for pruning `st_query_history`.
+//!
+//! Retention deletes entries that are neither pinned nor favorited, oldest
+//! first. The index lets those deletes find them without scanning the table.
+
+use rusqlite::Transaction;
+
+use super::{Migration, MigrationError};
+
+pub struct MigrationImpl;
+
+impl Migration for MigrationImpl {
+    fn name(&self) -> &str {
+        "042_query_history_retention_index"
+    }
+
+    fn run(&self, tx: &Transaction) -> Result<(), MigrationError> {
+        // Skip entirely when the base table is absent (tests that pre-seed
+        // sys_migrations and create only a subset of tables).
+        let table_exists: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='st_query_history'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(|source| MigrationError::Sqlite {
+                path: std::path::PathBuf::from("<042_query_history_retention_index>"),
+                source,
+            })?;
+
+        if !table_exists {
+            return Ok(());
+        }
+
+        tx.execute_batch(
+            "
+            CREATE INDEX IF NOT EXISTS idx_st_query_history_retention
+                ON st_query_history(is_pinned, is_favorite, executed_at);
+            ",
+        )
+        .map_err(|source| MigrationError::Sqlite {
+            path: std::path::PathBuf::from("<042_query_history_retention_index>"),
+            source,
+        })?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 //! Repository for connection folders in dbflux.db.
 //!
 //! Connection folders store the hierarchical folder structure for organizing
-//! connection profiles in the connection tree.
+//! connection profiles in the connection tree, and the favorites starred
+//! under each profile.
 
 use log::{info, warn};
 use rusqlite::{Connection, params};
@@ -35,6 +36,20 @@ pub struct ConnectionFolderItemDto {
     pub position: i32,
 }
 
+/// Data transfer object for a sidebar favorite of a connection profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionFavoriteDto {
+    pub id: String,
+    pub profile_id: String,
+    /// `table`, `view` or `saved_query`.
+    pub kind: String,
+    pub database_name: Option<String>,
+    pub schema_name: Option<String>,
+    pub object_name: Option<String>,
+    pub saved_query_id: Option<String>,
+    pub position: i32,
+}
+
 /// Repository for managing connection folders and their items.
 pub struct ConnectionFoldersRepository {
     conn: OwnedConnection,
@@ -562,8 +577,190 @@ impl ConnectionFoldersRepository {
         Ok(())
     }
 
-    /// Clears all folders and items.
+    /// Fetches all favorites, in display order.
+    pub fn all_favorites(&self) -> Result<Vec<ConnectionFavoriteDto>, StorageError> {
+        let mut stmt = self
+            .conn()
+            .prepare(
+                r#"
+                SELECT id, profile_id, kind, database_name, schema_name, object_name,
+                       saved_query_id, position
+                FROM cfg_connection_favorites
+                ORDER BY position ASC
+                "#,
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        let favorites = stmt
+            .query_map([], |row| {
+                Ok(ConnectionFavoriteDto {
+                    id: row.get(0)?,
+                    profile_id: row.get(1)?,
+                    kind: row.get(2)?,
+                    database_name: row.get(3)?,
+                    schema_name: row.get(4)?,
+                    object_name: row.get(5)?,
+                    saved_query_id: row.get(6)?,
+                    position: row.get(7)?,
+                })
+            })
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        favorites
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })
+    }
+
+    /// Inserts a favorite.
+    pub fn insert_favorite(&self, dto: &ConnectionFavoriteDto) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                r#"
+                INSERT OR REPLACE INTO cfg_connection_favorites (
+                    id, profile_id, kind, database_name, schema_name, object_name,
+                    saved_query_id, position
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                "#,
+                params![
+                    dto.id,
+                    dto.profile_id,
+                    dto.kind,
+                    dto.database_name,
+                    dto.schema_name,
+                    dto.object_name,
+                    dto.saved_query_id,
+                    dto.position
+                ],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        Ok(())
+    }
+
+    fn favorite_to_dto(
+        favorite: &dbflux_core::FavoriteObject,
+        position: i32,
+    ) -> ConnectionFavoriteDto {
+        use dbflux_core::FavoriteTarget;
+
+        let (kind, database_name, schema_name, object_name, saved_query_id) = match &favorite.target
+        {
+            FavoriteTarget::Table {
+                database,
+                schema,
+                name,
+            } => (
+                "table",
+                database.clone(),
+                Some(schema.clone()),
+                Some(name.clone()),
+                None,
+            ),
+            FavoriteTarget::View {
+                database,
+                schema,
+                name,
+            } => (
+                "view",
+                database.clone(),
+                Some(schema.clone()),
+                Some(name.clone()),
+                None,
+            ),
+            FavoriteTarget::SavedQuery { query_id } => {
+                ("saved_query", None, None, None, Some(query_id.to_string()))
+            }
+        };
+
+        ConnectionFavoriteDto {
+            id: favorite.id.to_string(),
+            profile_id: favorite.profile_id.to_string(),
+            kind: kind.to_string(),
+            database_name,
+            schema_name,
+            object_name,
+            saved_query_id,
+            position,
+        }
+    }
+
+    fn favorite_from_dto(
+        dto: ConnectionFavoriteDto,
+    ) -> Result<dbflux_core::FavoriteObject, StorageError> {
+        use dbflux_core::FavoriteTarget;
+
+        let object = |dto: &ConnectionFavoriteDto| {
+            let name = dto.object_name.clone().ok_or_else(|| {
+                StorageError::Data(format!("favorite '{}' has no object name", dto.id))
+            })?;
+            Ok::<_, StorageError>((
+                dto.database_name.clone(),
+                dto.schema_name.clone().unwrap_or_default(),
+                name,
+            ))
+        };
+
+        let target = match dto.kind.as_str() {
+            "table" => {
+                let (database, schema, name) = object(&dto)?;
+                FavoriteTarget::Table {
+                    database,
+                    schema,
+                    name,
+                }
+            }
+            "view" => {
+                let (database, schema, name) = object(&dto)?;
+                FavoriteTarget::View {
+                    database,
+                    schema,
+                    name,
+                }
+            }
+            "saved_query" => {
+                let query_id = dto.saved_query_id.as_deref().ok_or_else(|| {
+                    StorageError::Data(format!("favorite '{}' has no saved query", dto.id))
+                })?;
+                FavoriteTarget::SavedQuery {
+                    query_id: Self::parse_uuid(query_id, "favorite.saved_query_id")?,
+                }
+            }
+            other => {
+                return Err(StorageError::Data(format!(
+                    "favorite '{}' has unknown kind '{}'",
+                    dto.id, other
+                )));
+            }
+        };
+
+        Ok(dbflux_core::FavoriteObject {
+            id: Self::parse_uuid(&dto.id, "favorite.id")?,
+            profile_id: Self::parse_uuid(&dto.profile_id, "favorite.profile_id")?,
+            target,
+        })
+    }
+
+    /// Clears all folders, items and favorites.
     pub fn clear_all(&self) -> Result<(), StorageError> {
+        self.conn()
+            .execute("DELETE FROM cfg_connection_favorites", [])
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
         self.conn()
             .execute("DELETE FROM cfg_connection_folder_items", [])
             .map_err(|source| StorageError::Sqlite {
@@ -655,6 +852,20 @@ impl ConnectionFoldersRepository {
             }
         }
 
+        for (position, favorite) in tree.favorites.iter().enumerate() {
+            let dto = Self::favorite_to_dto(favorite, position as i32);
+
+            if !self.profile_exists(&dto.profile_id) {
+                info!(
+                    "Skipping favorite for non-existent profile '{}'",
+                    dto.profile_id
+                );
+                continue;
+            }
+
+            self.insert_favorite(&dto)?;
+        }
+
         info!(
             "Saved connection tree with {} folders and {} items",
             tree.folders().len(),
@@ -756,6 +967,17 @@ impl ConnectionFoldersRepository {
             tree.add_node(node);
         }
 
+        for dto in self.all_favorites()? {
+            let id = dto.id.clone();
+            match Self::favorite_from_dto(dto) {
+                Ok(favorite) => tree.favorites.push(favorite),
+                Err(error) => {
+                    recovered_from_error = true;
+                    warn!("Skipping invalid connection favorite '{}': {}", id, error);
+                }
+            }
+        }
+
         let repaired_orphans = tree.repair_orphans();
         if repaired_orphans > 0 {
             recovered_from_error = true;
@@ -927,4 +1149,54 @@ mod tests {
         assert_eq!(folders.len(), 2);
         assert!(repaired_child.parent_id.is_none());
     }
+
+    #[test]
+    fn save_tree_roundtrips_favorites_in_order() {
+        use dbflux_core::{ConnectionTree, FavoriteTarget};
+
+        let (_temp_dir, repo) = temp_repo();
+        let profile_id = Uuid::new_v4();
+
+        repo.conn()
+            .execute(
+                r#"
+                INSERT INTO cfg_connection_profiles (
+                    id, name, driver_id, kind, created_at, updated_at
+                ) VALUES (?1, 'Profile', 'postgres', 'postgres', datetime('now'), datetime('now'))
+                "#,
+                params![profile_id.to_string()],
+            )
+            .expect("insert profile");
+
+        let mut tree = ConnectionTree::new();
+        tree.toggle_favorite(
+            profile_id,
+            FavoriteTarget::View {
+                database: Some("app".to_string()),
+                schema: "public".to_string(),
+                name: "active_users".to_string(),
+            },
+        );
+        tree.toggle_favorite(
+            profile_id,
+            FavoriteTarget::SavedQuery {
+                query_id: Uuid::new_v4(),
+            },
+        );
+        // Favorites of profiles that no longer exist are not persisted.
+        tree.toggle_favorite(
+            Uuid::new_v4(),
+            FavoriteTarget::Table {
+                database: None,
+                schema: "public".to_string(),
+                name: "users".to_string(),
+            },
+        );
+
+        repo.save_tree(&tree).expect("save tree");
+        let loaded = repo.load_tree().expect("load tree");
+
+        assert!(!loaded.recovered_from_error);
+        assert_eq!(loaded.tree.favorites, tree.favorites[..2].to_vec());
+    }
 }
@@ -37,7 +37,14 @@ impl GeneralSettingsRepository {
                        max_concurrent_background_tasks, auto_refresh_pause_on_error,
                        auto_refresh_only_if_visible, confirm_dangerous_queries,
                        dangerous_requires_where, dangerous_requires_preview,
-                       style, updated_at
+                       style, editor_vim_mode, history_max_age_days,
+                       history_collapse_duplicates, hide_system_objects,
+                       schema_refresh_interval_secs, schema_refresh_on_focus,
+                       notify_long_query_secs, ui_zoom_percent, editor_font_size,
+                       grid_font_size, language, display_timezone, null_marker,
+                       null_style, thousands_separator, decimal_places, date_format,
+                       time_format, timestamp_format, scientific_threshold,
+                       formatted_output, updated_at
                 FROM cfg_general_settings WHERE id = 1
                 "#,
             )
@@ -64,7 +71,28 @@ impl GeneralSettingsRepository {
                 dangerous_requires_where: row.get(13)?,
                 dangerous_requires_preview: row.get(14)?,
                 style: row.get(15)?,
-                updated_at: row.get(16)?,
+                editor_vim_mode: row.get(16)?,
+                history_max_age_days: row.get(17)?,
+                history_collapse_duplicates: row.get(18)?,
+                hide_system_objects: row.get(19)?,
+                schema_refresh_interval_secs: row.get(20)?,
+                schema_refresh_on_focus: row.get(21)?,
+                notify_long_query_secs: row.get(22)?,
+                ui_zoom_percent: row.get(23)?,
+                editor_font_size: row.get(24)?,
+                grid_font_size: row.get(25)?,
+                language: row.get(26)?,
+                display_timezone: row.get(27)?,
+                null_marker: row.get(28)?,
+                null_style: row.get(29)?,
+                thousands_separator: row.get(30)?,
+                decimal_places: row.get(31)?,
+                date_format: row.get(32)?,
+                time_format: row.get(33)?,
+                timestamp_format: row.get(34)?,
+                scientific_threshold: row.get(35)?,
+                formatted_output: row.get(36)?,
+                updated_at: row.get(37)?,
             })
         });
 
@@ -90,8 +118,15 @@ impl GeneralSettingsRepository {
                     max_concurrent_background_tasks, auto_refresh_pause_on_error,
                     auto_refresh_only_if_visible, confirm_dangerous_queries,
                     dangerous_requires_where, dangerous_requires_preview,
-                    style, updated_at
-                ) VALUES (1, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, datetime('now'))
+                    style, editor_vim_mode, history_max_age_days,
+                    history_collapse_duplicates, hide_system_objects,
+                    schema_refresh_interval_secs, schema_refresh_on_focus,
+                    notify_long_query_secs, ui_zoom_percent, editor_font_size,
+                    grid_font_size, language, display_timezone, null_marker,
+                    null_style, thousands_separator, decimal_places, date_format,
+                    time_format, timestamp_format, scientific_threshold,
+                    formatted_output, updated_at
+                ) VALUES (1, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34, ?35, ?36, datetime('now'))
                 ON CONFLICT(id) DO UPDATE SET
                     theme = excluded.theme,
                     restore_session_on_startup = excluded.restore_session_on_startup,
@@ -108,6 +143,27 @@ impl GeneralSettingsRepository {
                     dangerous_requires_where = excluded.dangerous_requires_where,
                     dangerous_requires_preview = excluded.dangerous_requires_preview,
                     style = excluded.style,
+                    editor_vim_mode = excluded.editor_vim_mode,
+                    history_max_age_days = excluded.history_max_age_days,
+                    history_collapse_duplicates = excluded.history_collapse_duplicates,
+                    hide_system_objects = excluded.hide_system_objects,
+                    schema_refresh_interval_secs = excluded.schema_refresh_interval_secs,
+                    schema_refresh_on_focus = excluded.schema_refresh_on_focus,
+                    notify_long_query_secs = excluded.notify_long_query_secs,
+                    ui_zoom_percent = excluded.ui_zoom_percent,
+                    editor_font_size = excluded.editor_font_size,
+                    grid_font_size = excluded.grid_font_size,
+                    language = excluded.language,
+                    display_timezone = excluded.display_timezone,
+                    null_marker = excluded.null_marker,
+                    null_style = excluded.null_style,
+                    thousands_separator = excluded.thousands_separator,
+                    decimal_places = excluded.decimal_places,
+                    date_format = excluded.date_format,
+                    time_format = excluded.time_format,
+                    timestamp_format = excluded.timestamp_format,
+                    scientific_threshold = excluded.scientific_threshold,
+                    formatted_output = excluded.formatted_output,
                     updated_at = datetime('now')
                 "#,
                 params![
@@ -126,6 +182,27 @@ impl GeneralSettingsRepository {
                     settings.dangerous_requires_where,
                     settings.dangerous_requires_preview,
                     settings.style,
+                    settings.editor_vim_mode,
+                    settings.history_max_age_days,
+                    settings.history_collapse_duplicates,
+                    settings.hide_system_objects,
+                    settings.schema_refresh_interval_secs,
+                    settings.schema_refresh_on_focus,
+                    settings.notify_long_query_secs,
+                    settings.ui_zoom_percent,
+                    settings.editor_font_size,
+                    settings.grid_font_size,
+                    settings.language,
+                    settings.display_timezone,
+                    settings.null_marker,
+                    settings.null_style,
+                    settings.thousands_separator,
+                    settings.decimal_places,
+                    settings.date_format,
+                    settings.time_format,
+                    settings.timestamp_format,
+                    settings.scientific_threshold,
+                    settings.formatted_output,
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -159,6 +236,46 @@ pub struct GeneralSettingsDto {
     /// Serialized `AppStyle` value: `"default"` or `"compact"`.
     /// Unknown values fall back to `"default"` at the loader layer.
     pub style: String,
+    pub editor_vim_mode: i32,
+    /// Days to keep unpinned history entries; 0 keeps them forever.
+    pub history_max_age_days: i64,
+    pub history_collapse_duplicates: i32,
+    pub hide_system_objects: i32,
+    /// Seconds between sidebar schema refreshes; 0 keeps them manual.
+    pub schema_refresh_interval_secs: i64,
+    pub schema_refresh_on_focus: i32,
+    /// Seconds before a finished query raises a notification; 0 disables it.
+    pub notify_long_query_secs: i64,
+    /// Application-wide zoom in percent.
+    pub ui_zoom_percent: i64,
+    /// Editor and grid font sizes in pixels at 100% zoom.
+    pub editor_font_size: i64,
+    pub grid_font_size: i64,
+    /// Serialized `Language` code, e.g. `"en"`. Unknown codes fall back to
+    /// English at the loader layer.
+    pub language: String,
+    /// Serialized `DisplayTimezone` code: `"utc"`, `"local"` or an offset
+    /// such as `"+05:30"`. Unknown codes fall back to UTC at the loader layer.
+    pub display_timezone: String,
+    /// Text shown for NULL cells. A blank marker falls back to `"NULL"` at
+    /// the loader layer.
+    pub null_marker: String,
+    /// Serialized `NullStyle` code: `"muted"`, `"badge"` or `"plain"`.
+    /// Unknown codes fall back to muted at the loader layer.
+    pub null_style: String,
+    /// Digit group separator; empty when digits are not grouped.
+    pub thousands_separator: String,
+    /// Fraction digits shown for floats and decimals; `-1` shows them as
+    /// stored.
+    pub decimal_places: i64,
+    /// strftime patterns. Invalid patterns fall back to the defaults at the
+    /// loader layer.
+    pub date_format: String,
+    pub time_format: String,
+    pub timestamp_format: String,
+    /// Exponent from which floats use scientific notation; `-1` never does.
+    pub scientific_threshold: i64,
+    pub formatted_output: i64,
     pub updated_at: String,
 }
 
@@ -209,6 +326,27 @@ mod tests {
             dangerous_requires_where: 0,
             dangerous_requires_preview: 1,
             style: "compact".to_string(),
+            editor_vim_mode: 1,
+            history_max_age_days: 30,
+            history_collapse_duplicates: 0,
+            hide_system_objects: 0,
+            schema_refresh_interval_secs: 300,
+            schema_refresh_on_focus: 1,
+            notify_long_query_secs: 120,
+            ui_zoom_percent: 150,
+            editor_font_size: 18,
+            grid_font_size: 12,
+            language: "es".to_string(),
+            display_timezone: "+05:30".to_string(),
+            null_marker: "∅".to_string(),
+            null_style: "badge".to_string(),
+            thousands_separator: ".".to_string(),
+            decimal_places: 2,
+            date_format: "%d/%m/%Y".to_string(),
+            time_format: "%H:%M".to_string(),
+            timestamp_format: "%d/%m/%Y %H:%M".to_string(),
+            scientific_threshold: 9,
+            formatted_output: 1,
             updated_at: String::new(),
         };
 
@@ -219,6 +357,24 @@ mod tests {
         assert_eq!(fetched.restore_session_on_startup, 0);
         assert_eq!(fetched.max_history_entries, 500);
         assert_eq!(fetched.style, "compact");
+        assert_eq!(fetched.editor_vim_mode, 1);
+        assert_eq!(fetched.history_max_age_days, 30);
+        assert_eq!(fetched.history_collapse_duplicates, 0);
+        assert_eq!(fetched.hide_system_objects, 0);
+        assert_eq!(fetched.schema_refresh_interval_secs, 300);
+        assert_eq!(fetched.schema_refresh_on_focus, 1);
+        assert_eq!(fetched.notify_long_query_secs, 120);
+        assert_eq!(fetched.ui_zoom_percent, 150);
+        assert_eq!(fetched.editor_font_size, 18);
+        assert_eq!(fetched.grid_font_size, 12);
+        assert_eq!(fetched.display_timezone, "+05:30");
+        assert_eq!(fetched.null_marker, "∅");
+        assert_eq!(fetched.null_style, "badge");
+        assert_eq!(fetched.thousands_separator, ".");
+        assert_eq!(fetched.decimal_places, 2);
+        assert_eq!(fetched.timestamp_format, "%d/%m/%Y %H:%M");
+        assert_eq!(fetched.scientific_threshold, 9);
+        assert_eq!(fetched.formatted_output, 1);
 
         let _ = std::fs::remove_file(&path);
     }
@@ -252,6 +408,27 @@ mod tests {
                 dangerous_requires_where: 1,
                 dangerous_requires_preview: 0,
                 style: style_str.to_string(),
+                editor_vim_mode: 0,
+                history_max_age_days: 0,
+                history_collapse_duplicates: 1,
+                hide_system_objects: 1,
+                schema_refresh_interval_secs: 0,
+                schema_refresh_on_focus: 0,
+                notify_long_query_secs: 30,
+                ui_zoom_percent: 100,
+                editor_font_size: 14,
+                grid_font_size: 13,
+                language: "en".to_string(),
+                display_timezone: "utc".to_string(),
+                null_marker: "NULL".to_string(),
+                null_style: "muted".to_string(),
+                thousands_separator: String::new(),
+                decimal_places: -1,
+                date_format: "%Y-%m-%d".to_string(),
+                time_format: "%H:%M:%S".to_string(),
+                timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+                scientific_threshold: -1,
+                formatted_output: 0,
                 updated_at: String::new(),
             };
 
@@ -286,6 +463,9 @@ mod tests {
             fetched.style, "default",
             "style column default should be 'default'"
         );
+        assert_eq!(fetched.ui_zoom_percent, 100);
+        assert_eq!(fetched.editor_font_size, 14);
+        assert_eq!(fetched.grid_font_size, 13);
 
         let _ = std::fs::remove_file(&path);
     }
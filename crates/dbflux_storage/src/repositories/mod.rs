@@ -36,6 +36,8 @@ pub mod hook_definitions;
 pub mod hook_environment;
 pub mod proxy_auth;
 pub mod proxy_profiles;
+pub mod query_variables;
+pub mod scheduled_exports;
 pub mod service_args;
 pub mod service_env;
 pub mod services;
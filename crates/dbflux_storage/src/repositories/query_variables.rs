@@ -0,0 +1,215 @@
+//! Repository for the `cfg_query_variables` table in dbflux.db.
+//!
+//! Stores `{{name}}` query variable values keyed by `(scope, name)`, where
+//! `scope` is `workspace` or a connection profile id.
+
+use log::info;
+use rusqlite::{Connection, params};
+
+use crate::bootstrap::OwnedConnection;
+use crate::error::StorageError;
+
+/// Repository for query variable values.
+pub struct QueryVariablesRepository {
+    conn: OwnedConnection,
+}
+
+impl QueryVariablesRepository {
+    /// Creates a new repository instance.
+    pub fn new(conn: OwnedConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Borrows the underlying connection.
+    fn conn(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Returns every stored variable, ordered by scope then name.
+    pub fn all(&self) -> Result<Vec<QueryVariableDto>, StorageError> {
+        let mut stmt = self
+            .conn()
+            .prepare(
+                r#"
+                SELECT scope, name, value
+                FROM cfg_query_variables
+                ORDER BY scope ASC, name ASC
+                "#,
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(QueryVariableDto {
+                    scope: row.get(0)?,
+                    name: row.get(1)?,
+                    value: row.get(2)?,
+                })
+            })
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            result.push(row?);
+        }
+        Ok(result)
+    }
+
+    /// Inserts or updates a single variable value.
+    pub fn upsert(&self, variable: &QueryVariableDto) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                r#"
+                INSERT INTO cfg_query_variables (scope, name, value, updated_at)
+                VALUES (?1, ?2, ?3, datetime('now'))
+                ON CONFLICT(scope, name) DO UPDATE SET
+                    value = excluded.value,
+                    updated_at = excluded.updated_at
+                "#,
+                params![variable.scope, variable.name, variable.value],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        info!(
+            "Upserted query variable: {} in scope {}",
+            variable.name, variable.scope
+        );
+        Ok(())
+    }
+
+    /// Deletes a single variable value.
+    pub fn delete(&self, scope: &str, name: &str) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                "DELETE FROM cfg_query_variables WHERE scope = ?1 AND name = ?2",
+                params![scope, name],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        info!("Deleted query variable: {} in scope {}", name, scope);
+        Ok(())
+    }
+
+    /// Deletes every variable stored for `scope`.
+    pub fn delete_scope(&self, scope: &str) -> Result<(), StorageError> {
+        self.conn()
+            .execute("DELETE FROM cfg_query_variables WHERE scope = ?1", [scope])
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        info!("Deleted all query variables in scope {}", scope);
+        Ok(())
+    }
+}
+
+/// DTO for the `cfg_query_variables` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryVariableDto {
+    pub scope: String,
+    pub name: String,
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations::MigrationRegistry;
+    use crate::sqlite::open_database;
+    use std::sync::Arc;
+
+    fn temp_db(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "dbflux_repo_cfg_query_variables_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("sqlite-wal"));
+        let _ = std::fs::remove_file(path.with_extension("sqlite-shm"));
+        path
+    }
+
+    fn variable(scope: &str, name: &str, value: &str) -> QueryVariableDto {
+        QueryVariableDto {
+            scope: scope.to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn upsert_overwrites_and_delete_removes() {
+        let path = temp_db("upsert");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryVariablesRepository::new(Arc::new(conn));
+
+        repo.upsert(&variable("workspace", "limit", "10"))
+            .expect("should insert");
+        repo.upsert(&variable("workspace", "limit", "50"))
+            .expect("should update");
+        repo.upsert(&variable("workspace", "tenant", "'acme'"))
+            .expect("should insert");
+
+        assert_eq!(
+            repo.all().expect("should list"),
+            vec![
+                variable("workspace", "limit", "50"),
+                variable("workspace", "tenant", "'acme'"),
+            ]
+        );
+
+        repo.delete("workspace", "limit").expect("should delete");
+        assert_eq!(
+            repo.all().expect("should list"),
+            vec![variable("workspace", "tenant", "'acme'")]
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn scopes_are_independent() {
+        let path = temp_db("scopes");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryVariablesRepository::new(Arc::new(conn));
+        let profile_id = uuid::Uuid::new_v4().to_string();
+
+        repo.upsert(&variable("workspace", "env", "'prod'"))
+            .expect("should insert");
+        repo.upsert(&variable(&profile_id, "env", "'staging'"))
+            .expect("should insert");
+
+        repo.delete_scope(&profile_id).expect("should delete scope");
+
+        assert_eq!(
+            repo.all().expect("should list"),
+            vec![variable("workspace", "env", "'prod'")]
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,321 @@
+//! Repository for the `cfg_scheduled_exports` and `cfg_scheduled_export_runs`
+//! tables in dbflux.db.
+//!
+//! Run history is capped per schedule: recording a run drops the oldest ones
+//! beyond [`RUNS_KEPT_PER_SCHEDULE`].
+
+use log::info;
+use rusqlite::{Connection, params};
+
+use crate::bootstrap::OwnedConnection;
+use crate::error::StorageError;
+
+/// Runs kept for each scheduled export.
+pub const RUNS_KEPT_PER_SCHEDULE: usize = 50;
+
+/// Repository for scheduled exports and their run history.
+pub struct ScheduledExportsRepository {
+    conn: OwnedConnection,
+}
+
+impl ScheduledExportsRepository {
+    /// Creates a new repository instance.
+    pub fn new(conn: OwnedConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Borrows the underlying connection.
+    fn conn(&self) -> &Connection {
+        &self.conn
+    }
+
+    fn sqlite_error(source: rusqlite::Error) -> StorageError {
+        StorageError::Sqlite {
+            path: "dbflux.db".into(),
+            source,
+        }
+    }
+
+    /// Returns every scheduled export, oldest first.
+    pub fn all(&self) -> Result<Vec<ScheduledExportDto>, StorageError> {
+        let mut stmt = self
+            .conn()
+            .prepare(
+                r#"
+                SELECT id, name, saved_query_id, schedule, format, output_dir, enabled, created_at
+                FROM cfg_scheduled_exports
+                ORDER BY created_at ASC, name ASC
+                "#,
+            )
+            .map_err(Self::sqlite_error)?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(ScheduledExportDto {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    saved_query_id: row.get(2)?,
+                    schedule: row.get(3)?,
+                    format: row.get(4)?,
+                    output_dir: row.get(5)?,
+                    enabled: row.get::<_, i64>(6)? != 0,
+                    created_at: row.get(7)?,
+                })
+            })
+            .map_err(Self::sqlite_error)?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            result.push(row?);
+        }
+        Ok(result)
+    }
+
+    /// Inserts or updates a scheduled export.
+    pub fn upsert(&self, export: &ScheduledExportDto) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                r#"
+                INSERT INTO cfg_scheduled_exports
+                    (id, name, saved_query_id, schedule, format, output_dir, enabled,
+                     created_at, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'))
+                ON CONFLICT(id) DO UPDATE SET
+                    name = excluded.name,
+                    saved_query_id = excluded.saved_query_id,
+                    schedule = excluded.schedule,
+                    format = excluded.format,
+                    output_dir = excluded.output_dir,
+                    enabled = excluded.enabled,
+                    updated_at = excluded.updated_at
+                "#,
+                params![
+                    export.id,
+                    export.name,
+                    export.saved_query_id,
+                    export.schedule,
+                    export.format,
+                    export.output_dir,
+                    export.enabled as i64,
+                    export.created_at,
+                ],
+            )
+            .map_err(Self::sqlite_error)?;
+
+        info!("Upserted scheduled export: {}", export.name);
+        Ok(())
+    }
+
+    /// Deletes a scheduled export and its run history.
+    pub fn delete(&self, id: &str) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                "DELETE FROM cfg_scheduled_export_runs WHERE schedule_id = ?1",
+                [id],
+            )
+            .map_err(Self::sqlite_error)?;
+        self.conn()
+            .execute("DELETE FROM cfg_scheduled_exports WHERE id = ?1", [id])
+            .map_err(Self::sqlite_error)?;
+
+        info!("Deleted scheduled export: {}", id);
+        Ok(())
+    }
+
+    /// Records a run and trims the schedule's history.
+    pub fn record_run(&self, run: &ScheduledExportRunDto) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                r#"
+                INSERT INTO cfg_scheduled_export_runs
+                    (schedule_id, started_at, duration_ms, succeeded, detail)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+                params![
+                    run.schedule_id,
+                    run.started_at,
+                    run.duration_ms,
+                    run.succeeded as i64,
+                    run.detail,
+                ],
+            )
+            .map_err(Self::sqlite_error)?;
+
+        self.conn()
+            .execute(
+                r#"
+                DELETE FROM cfg_scheduled_export_runs
+                WHERE schedule_id = ?1
+                  AND id NOT IN (
+                      SELECT id FROM cfg_scheduled_export_runs
+                      WHERE schedule_id = ?1
+                      ORDER BY started_at DESC, id DESC
+                      LIMIT ?2
+                  )
+                "#,
+                params![run.schedule_id, RUNS_KEPT_PER_SCHEDULE as i64],
+            )
+            .map_err(Self::sqlite_error)?;
+
+        Ok(())
+    }
+
+    /// The most recent runs of a schedule, newest first.
+    pub fn recent_runs(
+        &self,
+        schedule_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ScheduledExportRunDto>, StorageError> {
+        let mut stmt = self
+            .conn()
+            .prepare(
+                r#"
+                SELECT schedule_id, started_at, duration_ms, succeeded, detail
+                FROM cfg_scheduled_export_runs
+                WHERE schedule_id = ?1
+                ORDER BY started_at DESC, id DESC
+                LIMIT ?2
+                "#,
+            )
+            .map_err(Self::sqlite_error)?;
+
+        let rows = stmt
+            .query_map(params![schedule_id, limit as i64], |row| {
+                Ok(ScheduledExportRunDto {
+                    schedule_id: row.get(0)?,
+                    started_at: row.get(1)?,
+                    duration_ms: row.get(2)?,
+                    succeeded: row.get::<_, i64>(3)? != 0,
+                    detail: row.get(4)?,
+                })
+            })
+            .map_err(Self::sqlite_error)?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            result.push(row?);
+        }
+        Ok(result)
+    }
+}
+
+/// DTO for the `cfg_scheduled_exports` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledExportDto {
+    pub id: String,
+    pub name: String,
+    pub saved_query_id: String,
+    pub schedule: String,
+    pub format: String,
+    pub output_dir: String,
+    pub enabled: bool,
+    pub created_at: i64,
+}
+
+/// DTO for the `cfg_scheduled_export_runs` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledExportRunDto {
+    pub schedule_id: String,
+    pub started_at: i64,
+    pub duration_ms: i64,
+    pub succeeded: bool,
+    pub detail: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations::MigrationRegistry;
+    use crate::sqlite::open_database;
+    use std::sync::Arc;
+
+    fn temp_db(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "dbflux_repo_cfg_scheduled_exports_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("sqlite-wal"));
+        let _ = std::fs::remove_file(path.with_extension("sqlite-shm"));
+        path
+    }
+
+    fn export(id: &str, name: &str) -> ScheduledExportDto {
+        ScheduledExportDto {
+            id: id.to_string(),
+            name: name.to_string(),
+            saved_query_id: uuid::Uuid::new_v4().to_string(),
+            schedule: "@daily".to_string(),
+            format: "csv".to_string(),
+            output_dir: "/tmp/exports".to_string(),
+            enabled: true,
+            created_at: 1_700_000_000,
+        }
+    }
+
+    fn run(schedule_id: &str, started_at: i64) -> ScheduledExportRunDto {
+        ScheduledExportRunDto {
+            schedule_id: schedule_id.to_string(),
+            started_at,
+            duration_ms: 12,
+            succeeded: true,
+            detail: format!("run at {}", started_at),
+        }
+    }
+
+    #[test]
+    fn upsert_updates_and_delete_removes_runs() {
+        let path = temp_db("upsert");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = ScheduledExportsRepository::new(Arc::new(conn));
+
+        let mut daily = export("a", "Daily");
+        repo.upsert(&daily).expect("should insert");
+        daily.enabled = false;
+        daily.schedule = "0 6 * * *".to_string();
+        repo.upsert(&daily).expect("should update");
+
+        assert_eq!(repo.all().expect("should list"), vec![daily]);
+
+        repo.record_run(&run("a", 10)).expect("should record");
+        repo.delete("a").expect("should delete");
+
+        assert!(repo.all().expect("should list").is_empty());
+        assert!(repo.recent_runs("a", 10).expect("should list").is_empty());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn run_history_is_newest_first_and_capped() {
+        let path = temp_db("runs");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = ScheduledExportsRepository::new(Arc::new(conn));
+        repo.upsert(&export("a", "Daily")).expect("should insert");
+
+        for started_at in 0..(RUNS_KEPT_PER_SCHEDULE as i64 + 5) {
+            repo.record_run(&run("a", started_at))
+                .expect("should record");
+        }
+
+        let runs = repo
+            .recent_runs("a", RUNS_KEPT_PER_SCHEDULE * 2)
+            .expect("should list");
+        assert_eq!(runs.len(), RUNS_KEPT_PER_SCHEDULE);
+        assert_eq!(runs[0].started_at, RUNS_KEPT_PER_SCHEDULE as i64 + 4);
+        assert_eq!(runs.last().unwrap().started_at, 5);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -83,7 +83,7 @@ impl ServiceRepository {
             .conn()
             .prepare(
                 r#"
-                SELECT socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, created_at, updated_at
+                SELECT socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, remote_address, remote_tls, remote_token_env, created_at, updated_at
                 FROM cfg_services
                 ORDER BY socket_id ASC
                 "#,
@@ -104,8 +104,11 @@ impl ServiceRepository {
                     api_family: row.get(5)?,
                     api_major: row.get(6)?,
                     api_minor: row.get(7)?,
-                    created_at: row.get(8)?,
-                    updated_at: row.get(9)?,
+                    remote_address: row.get(8)?,
+                    remote_tls: row.get::<_, i32>(9)? != 0,
+                    remote_token_env: row.get(10)?,
+                    created_at: row.get(11)?,
+                    updated_at: row.get(12)?,
                 })
             })
             .map_err(|source| StorageError::Sqlite {
@@ -138,7 +141,7 @@ impl ServiceRepository {
             .conn()
             .prepare(
                 r#"
-                SELECT socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, created_at, updated_at
+                SELECT socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, remote_address, remote_tls, remote_token_env, created_at, updated_at
                 FROM cfg_services
                 WHERE socket_id = ?1
                 "#,
@@ -158,8 +161,11 @@ impl ServiceRepository {
                 api_family: row.get(5)?,
                 api_major: row.get(6)?,
                 api_minor: row.get(7)?,
-                created_at: row.get(8)?,
-                updated_at: row.get(9)?,
+                remote_address: row.get(8)?,
+                remote_tls: row.get::<_, i32>(9)? != 0,
+                remote_token_env: row.get(10)?,
+                created_at: row.get(11)?,
+                updated_at: row.get(12)?,
             })
         });
 
@@ -187,9 +193,9 @@ impl ServiceRepository {
         tx.execute(
             r#"
                 INSERT INTO cfg_services (
-                    socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, created_at, updated_at
+                    socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, remote_address, remote_tls, remote_token_env, created_at, updated_at
                 ) VALUES (
-                    ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'), datetime('now')
+                    ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, datetime('now'), datetime('now')
                 )
                 "#,
             params![
@@ -201,6 +207,9 @@ impl ServiceRepository {
                 service.api_family,
                 service.api_major,
                 service.api_minor,
+                service.remote_address,
+                service.remote_tls as i32,
+                service.remote_token_env,
             ],
         )
         .map_err(|source| StorageError::Sqlite {
@@ -239,6 +248,9 @@ impl ServiceRepository {
                     api_family = ?6,
                     api_major = ?7,
                     api_minor = ?8,
+                    remote_address = ?9,
+                    remote_tls = ?10,
+                    remote_token_env = ?11,
                     updated_at = datetime('now')
                 WHERE socket_id = ?1
                 "#,
@@ -251,6 +263,9 @@ impl ServiceRepository {
                     service.api_family,
                     service.api_major,
                     service.api_minor,
+                    service.remote_address,
+                    service.remote_tls as i32,
+                    service.remote_token_env,
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -287,8 +302,8 @@ impl ServiceRepository {
         tx.execute(
             r#"
                 INSERT INTO cfg_services (
-                    socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, created_at, updated_at
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'), datetime('now'))
+                    socket_id, enabled, command, startup_timeout_ms, service_kind, api_family, api_major, api_minor, remote_address, remote_tls, remote_token_env, created_at, updated_at
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, datetime('now'), datetime('now'))
                 ON CONFLICT(socket_id) DO UPDATE SET
                     enabled = excluded.enabled,
                     command = excluded.command,
@@ -297,6 +312,9 @@ impl ServiceRepository {
                     api_family = excluded.api_family,
                     api_major = excluded.api_major,
                     api_minor = excluded.api_minor,
+                    remote_address = excluded.remote_address,
+                    remote_tls = excluded.remote_tls,
+                    remote_token_env = excluded.remote_token_env,
                     updated_at = datetime('now')
                 "#,
             params![
@@ -308,6 +326,9 @@ impl ServiceRepository {
                 service.api_family,
                 service.api_major,
                 service.api_minor,
+                service.remote_address,
+                service.remote_tls as i32,
+                service.remote_token_env,
             ],
         )
         .map_err(|source| StorageError::Sqlite {
@@ -364,6 +385,10 @@ pub struct ServiceDto {
     pub api_family: Option<String>,
     pub api_major: Option<i64>,
     pub api_minor: Option<i64>,
+    /// `host:port` of a service running on another machine.
+    pub remote_address: Option<String>,
+    pub remote_tls: bool,
+    pub remote_token_env: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -380,6 +405,9 @@ impl ServiceDto {
             api_family: None,
             api_major: None,
             api_minor: None,
+            remote_address: None,
+            remote_tls: false,
+            remote_token_env: None,
             created_at: String::new(),
             updated_at: String::new(),
         }
@@ -448,6 +476,9 @@ mod tests {
             api_family: Some("auth_provider_rpc".to_string()),
             api_major: Some(1),
             api_minor: Some(0),
+            remote_address: None,
+            remote_tls: false,
+            remote_token_env: None,
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -469,6 +500,39 @@ mod tests {
         let _ = std::fs::remove_file(path.with_extension("sqlite-shm"));
     }
 
+    #[test]
+    fn service_upsert_persists_remote_endpoint() {
+        let path = temp_db("remote");
+        let _ = std::fs::remove_file(&path);
+
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = ServiceRepository::new(Arc::new(conn));
+        let mut dto = ServiceDto::new("remote-postgres".to_string());
+        dto.remote_address = Some("db-jump:7400".to_string());
+        dto.remote_tls = true;
+        dto.remote_token_env = Some("PG_HOST_TOKEN".to_string());
+
+        repo.upsert(&dto).expect("should upsert");
+
+        let fetched = repo
+            .get("remote-postgres")
+            .expect("should load")
+            .expect("service row");
+
+        assert_eq!(fetched.remote_address.as_deref(), Some("db-jump:7400"));
+        assert!(fetched.remote_tls);
+        assert_eq!(fetched.remote_token_env.as_deref(), Some("PG_HOST_TOKEN"));
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("sqlite-wal"));
+        let _ = std::fs::remove_file(path.with_extension("sqlite-shm"));
+    }
+
     #[test]
     fn service_insert_defaults_missing_api_contract_columns_to_null() {
         let path = temp_db("service_api_contract_defaults");
@@ -0,0 +1,207 @@
+//! Repository for execution plan history in dbflux.db.
+//!
+//! Stores the plans captured by EXPLAIN for each statement so later
+//! captures can be compared against earlier ones.
+
+use rusqlite::{Connection, params};
+use serde::{Deserialize, Serialize};
+
+use crate::bootstrap::OwnedConnection;
+use crate::error::StorageError;
+
+/// Plans kept per statement and profile; older captures are dropped.
+const MAX_PLANS_PER_STATEMENT: usize = 20;
+
+/// Repository for captured execution plans.
+pub struct ExplainPlansRepository {
+    conn: OwnedConnection,
+}
+
+impl ExplainPlansRepository {
+    pub fn new(conn: OwnedConnection) -> Self {
+        Self { conn }
+    }
+
+    fn conn(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Stores a captured plan and drops the statement's oldest captures
+    /// beyond `MAX_PLANS_PER_STATEMENT`.
+    pub fn add(&self, dto: &ExplainPlanDto) -> Result<(), StorageError> {
+        self.conn()
+            .execute(
+                r#"
+                INSERT INTO st_explain_plans (
+                    id, connection_profile_id, statement, captured_at, plan_json
+                ) VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+                params![
+                    dto.id,
+                    dto.connection_profile_id,
+                    dto.statement,
+                    dto.captured_at,
+                    dto.plan_json,
+                ],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        self.conn()
+            .execute(
+                r#"
+                DELETE FROM st_explain_plans
+                WHERE connection_profile_id IS ?1
+                  AND statement = ?2
+                  AND id NOT IN (
+                      SELECT id FROM st_explain_plans
+                      WHERE connection_profile_id IS ?1 AND statement = ?2
+                      ORDER BY captured_at DESC, rowid DESC
+                      LIMIT ?3
+                  )
+                "#,
+                params![
+                    dto.connection_profile_id,
+                    dto.statement,
+                    MAX_PLANS_PER_STATEMENT as i64
+                ],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        Ok(())
+    }
+
+    /// Returns the plans captured for a statement, newest first.
+    pub fn for_statement(
+        &self,
+        connection_profile_id: Option<&str>,
+        statement: &str,
+    ) -> Result<Vec<ExplainPlanDto>, StorageError> {
+        let mut stmt = self
+            .conn()
+            .prepare(
+                r#"
+                SELECT id, connection_profile_id, statement, captured_at, plan_json
+                FROM st_explain_plans
+                WHERE connection_profile_id IS ?1 AND statement = ?2
+                ORDER BY captured_at DESC, rowid DESC
+                "#,
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        let rows = stmt
+            .query_map(params![connection_profile_id, statement], |row| {
+                Ok(ExplainPlanDto {
+                    id: row.get(0)?,
+                    connection_profile_id: row.get(1)?,
+                    statement: row.get(2)?,
+                    captured_at: row.get(3)?,
+                    plan_json: row.get(4)?,
+                })
+            })
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })
+    }
+}
+
+/// DTO for a captured execution plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainPlanDto {
+    pub id: String,
+    pub connection_profile_id: Option<String>,
+    pub statement: String,
+    /// RFC 3339 UTC timestamp of the capture.
+    pub captured_at: String,
+    pub plan_json: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations::MigrationRegistry;
+    use crate::sqlite::open_database;
+    use std::sync::Arc;
+
+    fn temp_db(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "dbflux_repo_explain_plans_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("sqlite-wal"));
+        let _ = std::fs::remove_file(path.with_extension("sqlite-shm"));
+        path
+    }
+
+    fn plan(statement: &str, captured_at: &str) -> ExplainPlanDto {
+        ExplainPlanDto {
+            id: uuid::Uuid::new_v4().to_string(),
+            connection_profile_id: None,
+            statement: statement.to_string(),
+            captured_at: captured_at.to_string(),
+            plan_json: "{}".to_string(),
+        }
+    }
+
+    #[test]
+    fn lists_plans_of_a_statement_newest_first() {
+        let path = temp_db("list");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = ExplainPlansRepository::new(Arc::new(conn));
+
+        repo.add(&plan("SELECT 1", "2026-01-01T00:00:00+00:00"))
+            .expect("should add");
+        repo.add(&plan("SELECT 1", "2026-02-01T00:00:00+00:00"))
+            .expect("should add");
+        repo.add(&plan("SELECT 2", "2026-03-01T00:00:00+00:00"))
+            .expect("should add");
+
+        let plans = repo.for_statement(None, "SELECT 1").expect("should list");
+        assert_eq!(plans.len(), 2);
+        assert_eq!(plans[0].captured_at, "2026-02-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn keeps_a_bounded_history_per_statement() {
+        let path = temp_db("trim");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = ExplainPlansRepository::new(Arc::new(conn));
+
+        for day in 1..=MAX_PLANS_PER_STATEMENT + 5 {
+            repo.add(&plan(
+                "SELECT 1",
+                &format!("2026-01-{:02}T00:00:00+00:00", day),
+            ))
+            .expect("should add");
+        }
+
+        let plans = repo.for_statement(None, "SELECT 1").expect("should list");
+        assert_eq!(plans.len(), MAX_PLANS_PER_STATEMENT);
+        assert_eq!(plans[0].captured_at, "2026-01-25T00:00:00+00:00");
+    }
+}
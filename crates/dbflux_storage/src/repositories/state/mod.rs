@@ -1,6 +1,7 @@
 //! Repository modules for runtime state domains in dbflux.db.
 
 pub mod event_log;
+pub mod explain_plans;
 pub mod query_history;
 pub mod recent_items;
 pub mod saved_queries;
@@ -3,7 +3,8 @@
 //! Stores individual query executions with timing, results, and favorites.
 
 use log::info;
-use rusqlite::{Connection, params};
+use rusqlite::types::Value;
+use rusqlite::{Connection, Row, params, params_from_iter};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -28,6 +29,11 @@ impl QueryHistoryRepository {
         Self { conn, max_entries }
     }
 
+    /// Changes the retention limit applied on the next `add`.
+    pub fn set_max_entries(&mut self, max_entries: usize) {
+        self.max_entries = max_entries;
+    }
+
     fn conn(&self) -> &Connection {
         &self.conn
     }
@@ -40,8 +46,9 @@ impl QueryHistoryRepository {
                 INSERT INTO st_query_history (
                     id, connection_profile_id, driver_id, database_name,
                     query_text, query_kind, executed_at, duration_ms,
-                    succeeded, error_summary, row_count, is_favorite
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+                    succeeded, error_summary, row_count, is_favorite,
+                    is_pinned, run_count, schema_name
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
                 "#,
                 params![
                     dto.id,
@@ -56,6 +63,9 @@ impl QueryHistoryRepository {
                     dto.error_summary,
                     dto.row_count,
                     dto.is_favorite as i32,
+                    dto.is_pinned as i32,
+                    dto.run_count,
+                    dto.schema_name,
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -63,7 +73,7 @@ impl QueryHistoryRepository {
                 source,
             })?;
 
-        // Trim to max entries, preserving favorites
+        // Trim to max entries, preserving pinned and favorite entries
         self.trim_to_max().map_err(|source| StorageError::Sqlite {
             path: "dbflux.db".into(),
             source,
@@ -73,6 +83,76 @@ impl QueryHistoryRepository {
         Ok(())
     }
 
+    /// Folds `dto` into the newest entry when that entry ran the same text
+    /// against the same profile, database and schema: its execution fields are
+    /// replaced and `run_count` is incremented. Returns `false` (and writes
+    /// nothing) when the newest entry differs, so the caller can `add` instead.
+    pub fn collapse_into_latest(&self, dto: &QueryHistoryDto) -> Result<bool, StorageError> {
+        let rows = self
+            .conn()
+            .execute(
+                r#"
+                UPDATE st_query_history SET
+                    executed_at = ?1,
+                    duration_ms = ?2,
+                    succeeded = ?3,
+                    error_summary = ?4,
+                    row_count = ?5,
+                    run_count = run_count + 1
+                WHERE rowid = (
+                    SELECT rowid FROM st_query_history
+                    ORDER BY executed_at DESC, rowid DESC
+                    LIMIT 1
+                )
+                  AND query_text = ?6
+                  AND connection_profile_id IS ?7
+                  AND database_name IS ?8
+                  AND schema_name IS ?9
+                "#,
+                params![
+                    dto.executed_at,
+                    dto.duration_ms,
+                    dto.succeeded as i32,
+                    dto.error_summary,
+                    dto.row_count,
+                    dto.query_text,
+                    dto.connection_profile_id,
+                    dto.database_name,
+                    dto.schema_name,
+                ],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        Ok(rows > 0)
+    }
+
+    /// Deletes entries executed before `cutoff` (RFC 3339) that are neither
+    /// pinned nor favorited. Returns the number of deleted entries.
+    pub fn prune_older_than(&self, cutoff: &str) -> Result<usize, StorageError> {
+        let deleted = self
+            .conn()
+            .execute(
+                "DELETE FROM st_query_history
+                 WHERE is_pinned = 0 AND is_favorite = 0 AND executed_at < ?1",
+                [cutoff],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        if deleted > 0 {
+            info!(
+                "Pruned {} query history entries older than {}",
+                deleted, cutoff
+            );
+        }
+        Ok(deleted)
+    }
+
     /// Returns recent history entries.
     pub fn recent(&self, limit: usize) -> Result<Vec<QueryHistoryDto>, StorageError> {
         let mut stmt = self
@@ -80,7 +160,7 @@ impl QueryHistoryRepository {
             .prepare(
                 "SELECT id, connection_profile_id, driver_id, database_name, query_text,
                         query_kind, executed_at, duration_ms, succeeded, error_summary,
-                        row_count, is_favorite
+                        row_count, is_favorite, is_pinned, run_count, schema_name
                  FROM st_query_history ORDER BY executed_at DESC LIMIT ?1",
             )
             .map_err(|source| StorageError::Sqlite {
@@ -89,22 +169,7 @@ impl QueryHistoryRepository {
             })?;
 
         let rows = stmt
-            .query_map([limit as i64], |row| {
-                Ok(QueryHistoryDto {
-                    id: row.get(0)?,
-                    connection_profile_id: row.get(1)?,
-                    driver_id: row.get(2)?,
-                    database_name: row.get(3)?,
-                    query_text: row.get(4)?,
-                    query_kind: row.get(5)?,
-                    executed_at: row.get(6)?,
-                    duration_ms: row.get(7)?,
-                    succeeded: row.get::<_, i32>(8)? != 0,
-                    error_summary: row.get(9)?,
-                    row_count: row.get(10)?,
-                    is_favorite: row.get::<_, i32>(11)? != 0,
-                })
-            })
+            .query_map([limit as i64], history_dto_from_row)
             .map_err(|source| StorageError::Sqlite {
                 path: "dbflux.db".into(),
                 source,
@@ -170,6 +235,36 @@ impl QueryHistoryRepository {
         }
     }
 
+    /// Toggles the pinned flag on an entry.
+    pub fn toggle_pinned(&self, id: &str) -> Result<bool, StorageError> {
+        let rows = self
+            .conn()
+            .execute(
+                "UPDATE st_query_history SET is_pinned = NOT is_pinned WHERE id = ?1",
+                [id],
+            )
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })?;
+
+        if rows == 0 {
+            return Ok(false);
+        }
+
+        self.conn()
+            .query_row(
+                "SELECT is_pinned FROM st_query_history WHERE id = ?1",
+                [id],
+                |row| row.get::<_, i32>(0),
+            )
+            .map(|pinned| pinned != 0)
+            .map_err(|source| StorageError::Sqlite {
+                path: "dbflux.db".into(),
+                source,
+            })
+    }
+
     /// Removes a history entry by ID.
     pub fn remove(&self, id: &str) -> Result<(), StorageError> {
         self.conn()
@@ -205,61 +300,72 @@ impl QueryHistoryRepository {
 
     /// Searches entries by query text.
     pub fn search(&self, query: &str, limit: usize) -> Result<Vec<QueryHistoryDto>, StorageError> {
-        let pattern = format!("%{}%", query.to_lowercase());
+        let filter = QueryHistoryFilter {
+            text: Some(query.to_string()),
+            ..Default::default()
+        };
+
+        self.page(&filter, 0, limit)
+    }
+
+    /// Returns one page of entries matching `filter`, newest first.
+    pub fn page(
+        &self,
+        filter: &QueryHistoryFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<QueryHistoryDto>, StorageError> {
+        let (where_clause, mut values) = filter.where_clause();
+        values.push(Value::Integer(limit as i64));
+        values.push(Value::Integer(offset as i64));
+
+        let sql = format!(
+            "SELECT id, connection_profile_id, driver_id, database_name, query_text,
+                    query_kind, executed_at, duration_ms, succeeded, error_summary,
+                    row_count, is_favorite, is_pinned, run_count, schema_name
+             FROM st_query_history
+             {}
+             ORDER BY executed_at DESC, rowid DESC
+             LIMIT ? OFFSET ?",
+            where_clause
+        );
+
         let mut stmt = self
             .conn()
-            .prepare(
-                "SELECT id, connection_profile_id, driver_id, database_name, query_text,
-                        query_kind, executed_at, duration_ms, succeeded, error_summary,
-                        row_count, is_favorite
-                 FROM st_query_history
-                 WHERE LOWER(query_text) LIKE ?1
-                 ORDER BY executed_at DESC LIMIT ?2",
-            )
+            .prepare(&sql)
             .map_err(|source| StorageError::Sqlite {
                 path: "dbflux.db".into(),
                 source,
             })?;
 
         let rows = stmt
-            .query_map(params![pattern, limit as i64], |row| {
-                Ok(QueryHistoryDto {
-                    id: row.get(0)?,
-                    connection_profile_id: row.get(1)?,
-                    driver_id: row.get(2)?,
-                    database_name: row.get(3)?,
-                    query_text: row.get(4)?,
-                    query_kind: row.get(5)?,
-                    executed_at: row.get(6)?,
-                    duration_ms: row.get(7)?,
-                    succeeded: row.get::<_, i32>(8)? != 0,
-                    error_summary: row.get(9)?,
-                    row_count: row.get(10)?,
-                    is_favorite: row.get::<_, i32>(11)? != 0,
-                })
-            })
+            .query_map(params_from_iter(values), history_dto_from_row)
             .map_err(|source| StorageError::Sqlite {
                 path: "dbflux.db".into(),
                 source,
             })?;
 
         let mut result = Vec::new();
-        let mut last_err = None;
         for row in rows {
-            match row {
-                Ok(r) => result.push(r),
-                Err(e) => last_err = Some(e),
-            }
+            result.push(row?);
         }
+        Ok(result)
+    }
 
-        if let Some(e) = last_err {
-            return Err(StorageError::Sqlite {
+    /// Counts entries matching `filter`.
+    pub fn count(&self, filter: &QueryHistoryFilter) -> Result<usize, StorageError> {
+        let (where_clause, values) = filter.where_clause();
+        let sql = format!("SELECT COUNT(*) FROM st_query_history {}", where_clause);
+
+        let count: i64 = self
+            .conn()
+            .query_row(&sql, params_from_iter(values), |row| row.get(0))
+            .map_err(|source| StorageError::Sqlite {
                 path: "dbflux.db".into(),
-                source: e,
-            });
-        }
+                source,
+            })?;
 
-        Ok(result)
+        Ok(count as usize)
     }
 
     /// Returns only favorite entries.
@@ -269,7 +375,7 @@ impl QueryHistoryRepository {
             .prepare(
                 "SELECT id, connection_profile_id, driver_id, database_name, query_text,
                         query_kind, executed_at, duration_ms, succeeded, error_summary,
-                        row_count, is_favorite
+                        row_count, is_favorite, is_pinned, run_count, schema_name
                  FROM st_query_history WHERE is_favorite = 1 ORDER BY executed_at DESC",
             )
             .map_err(|source| StorageError::Sqlite {
@@ -277,27 +383,12 @@ impl QueryHistoryRepository {
                 source,
             })?;
 
-        let rows = stmt
-            .query_map([], |row| {
-                Ok(QueryHistoryDto {
-                    id: row.get(0)?,
-                    connection_profile_id: row.get(1)?,
-                    driver_id: row.get(2)?,
-                    database_name: row.get(3)?,
-                    query_text: row.get(4)?,
-                    query_kind: row.get(5)?,
-                    executed_at: row.get(6)?,
-                    duration_ms: row.get(7)?,
-                    succeeded: row.get::<_, i32>(8)? != 0,
-                    error_summary: row.get(9)?,
-                    row_count: row.get(10)?,
-                    is_favorite: row.get::<_, i32>(11)? != 0,
-                })
-            })
-            .map_err(|source| StorageError::Sqlite {
-                path: "dbflux.db".into(),
-                source,
-            })?;
+        let rows =
+            stmt.query_map([], history_dto_from_row)
+                .map_err(|source| StorageError::Sqlite {
+                    path: "dbflux.db".into(),
+                    source,
+                })?;
 
         let mut result = Vec::new();
         let mut last_err = None;
@@ -330,33 +421,144 @@ impl QueryHistoryRepository {
             return Ok(());
         }
 
-        // Keep all favorites plus enough non-favorites to reach max_entries
-        let non_fav_keep = self
+        // Keep all pinned and favorite entries plus enough of the others to
+        // reach max_entries
+        let unpinned_keep = self
             .max_entries
             .saturating_sub(self.conn().query_row::<i64, _, _>(
-                "SELECT COUNT(*) FROM st_query_history WHERE is_favorite = 1",
+                "SELECT COUNT(*) FROM st_query_history WHERE is_pinned = 1 OR is_favorite = 1",
                 [],
                 |row| row.get(0),
             )? as usize);
 
-        // Delete old non-favorites beyond the keep limit
+        // Delete the oldest of the others beyond the keep limit
         self.conn().execute(
             r#"
             DELETE FROM st_query_history
-            WHERE is_favorite = 0
+            WHERE is_pinned = 0 AND is_favorite = 0
               AND id NOT IN (
                   SELECT id FROM st_query_history
-                  WHERE is_favorite = 0
+                  WHERE is_pinned = 0 AND is_favorite = 0
                   ORDER BY executed_at DESC
                   LIMIT ?1
               )
             "#,
-            [non_fav_keep as i64],
+            [unpinned_keep as i64],
         )?;
         Ok(())
     }
 }
 
+/// Filters for [`QueryHistoryRepository::page`] and
+/// [`QueryHistoryRepository::count`]. Unset fields match every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryHistoryFilter {
+    /// Whitespace-separated terms that must all appear in the query text,
+    /// case-insensitively.
+    pub text: Option<String>,
+    pub connection_profile_id: Option<String>,
+    pub database_name: Option<String>,
+    /// Inclusive lower bound on `executed_at` (RFC 3339).
+    pub executed_after: Option<String>,
+    /// Exclusive upper bound on `executed_at` (RFC 3339).
+    pub executed_before: Option<String>,
+    pub succeeded: Option<bool>,
+}
+
+impl QueryHistoryFilter {
+    /// Builds the `WHERE` clause (empty when nothing is filtered) and its
+    /// positional values.
+    ///
+    /// Terms of three or more characters go through the trigram index; shorter
+    /// terms cannot be matched by it and fall back to `LIKE`.
+    fn where_clause(&self) -> (String, Vec<Value>) {
+        let mut conditions = Vec::new();
+        let mut values = Vec::new();
+
+        if let Some(text) = &self.text {
+            let mut fts_terms = Vec::new();
+
+            for term in text.split_whitespace() {
+                if term.chars().count() >= 3 {
+                    fts_terms.push(format!("\"{}\"", term.replace('"', "\"\"")));
+                } else {
+                    conditions.push("LOWER(query_text) LIKE ? ESCAPE '\\'".to_string());
+                    values.push(Value::Text(format!(
+                        "%{}%",
+                        escape_like(&term.to_lowercase())
+                    )));
+                }
+            }
+
+            if !fts_terms.is_empty() {
+                conditions.push(
+                    "rowid IN (SELECT rowid FROM st_query_history_fts \
+                     WHERE st_query_history_fts MATCH ?)"
+                        .to_string(),
+                );
+                values.push(Value::Text(fts_terms.join(" AND ")));
+            }
+        }
+
+        if let Some(profile_id) = &self.connection_profile_id {
+            conditions.push("connection_profile_id = ?".to_string());
+            values.push(Value::Text(profile_id.clone()));
+        }
+
+        if let Some(database) = &self.database_name {
+            conditions.push("database_name = ?".to_string());
+            values.push(Value::Text(database.clone()));
+        }
+
+        if let Some(after) = &self.executed_after {
+            conditions.push("executed_at >= ?".to_string());
+            values.push(Value::Text(after.clone()));
+        }
+
+        if let Some(before) = &self.executed_before {
+            conditions.push("executed_at < ?".to_string());
+            values.push(Value::Text(before.clone()));
+        }
+
+        if let Some(succeeded) = self.succeeded {
+            conditions.push("succeeded = ?".to_string());
+            values.push(Value::Integer(succeeded as i64));
+        }
+
+        if conditions.is_empty() {
+            (String::new(), values)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), values)
+        }
+    }
+}
+
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn history_dto_from_row(row: &Row<'_>) -> rusqlite::Result<QueryHistoryDto> {
+    Ok(QueryHistoryDto {
+        id: row.get(0)?,
+        connection_profile_id: row.get(1)?,
+        driver_id: row.get(2)?,
+        database_name: row.get(3)?,
+        query_text: row.get(4)?,
+        query_kind: row.get(5)?,
+        executed_at: row.get(6)?,
+        duration_ms: row.get(7)?,
+        succeeded: row.get::<_, i32>(8)? != 0,
+        error_summary: row.get(9)?,
+        row_count: row.get(10)?,
+        is_favorite: row.get::<_, i32>(11)? != 0,
+        is_pinned: row.get::<_, i32>(12)? != 0,
+        run_count: row.get(13)?,
+        schema_name: row.get(14)?,
+    })
+}
+
 /// DTO for query history entries.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryHistoryDto {
@@ -372,6 +574,11 @@ pub struct QueryHistoryDto {
     pub error_summary: Option<String>,
     pub row_count: Option<i64>,
     pub is_favorite: bool,
+    /// Pinned entries are never pruned by the retention limits.
+    pub is_pinned: bool,
+    /// Consecutive identical executions collapsed into this entry.
+    pub run_count: i64,
+    pub schema_name: Option<String>,
 }
 
 impl QueryHistoryDto {
@@ -400,6 +607,9 @@ impl QueryHistoryDto {
             error_summary,
             row_count,
             is_favorite: false,
+            is_pinned: false,
+            run_count: 1,
+            schema_name: None,
         }
     }
 }
@@ -433,7 +643,7 @@ mod tests {
         #[allow(clippy::arc_with_non_send_sync)]
         let repo = QueryHistoryRepository::new(Arc::new(conn));
 
-        let dto = QueryHistoryDto::new(
+        let mut dto = QueryHistoryDto::new(
             "SELECT * FROM users".to_string(),
             None,
             Some("postgres".to_string()),
@@ -444,11 +654,13 @@ mod tests {
             None,
             Some(100),
         );
+        dto.schema_name = Some("public".to_string());
         repo.add(&dto).expect("should add");
 
         let all = repo.all().expect("should list");
         assert_eq!(all.len(), 1);
         assert_eq!(all[0].query_text, "SELECT * FROM users");
+        assert_eq!(all[0].schema_name.as_deref(), Some("public"));
     }
 
     #[test]
@@ -549,4 +761,200 @@ mod tests {
         repo.clear_non_favorites().expect("clear non-fav");
         assert_eq!(repo.all().expect("should list").len(), 1);
     }
+
+    fn executed(query: &str, executed_at: &str, succeeded: bool) -> QueryHistoryDto {
+        let mut dto = QueryHistoryDto::new(
+            query.to_string(),
+            None,
+            None,
+            None,
+            "select".to_string(),
+            None,
+            succeeded,
+            None,
+            None,
+        );
+        dto.executed_at = executed_at.to_string();
+        dto
+    }
+
+    #[test]
+    fn page_applies_filters_and_offsets() {
+        let path = temp_db("page");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryHistoryRepository::new(Arc::new(conn));
+
+        for day in 1..=5 {
+            let mut dto = executed(
+                &format!("SELECT {} FROM Orders", day),
+                &format!("2026-01-0{}T00:00:00+00:00", day),
+                day != 3,
+            );
+            dto.database_name = Some(if day % 2 == 0 { "even" } else { "odd" }.to_string());
+            repo.add(&dto).expect("add");
+        }
+        repo.add(&executed(
+            "DELETE FROM users",
+            "2026-01-06T00:00:00+00:00",
+            true,
+        ))
+        .expect("add");
+
+        let orders = QueryHistoryFilter {
+            text: Some("orders".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(repo.count(&orders).expect("count"), 5);
+
+        let second_page = repo.page(&orders, 2, 2).expect("page");
+        let texts: Vec<&str> = second_page.iter().map(|e| e.query_text.as_str()).collect();
+        assert_eq!(texts, vec!["SELECT 3 FROM Orders", "SELECT 2 FROM Orders"]);
+
+        let filtered = QueryHistoryFilter {
+            database_name: Some("odd".to_string()),
+            executed_after: Some("2026-01-02T00:00:00+00:00".to_string()),
+            executed_before: Some("2026-01-06T00:00:00+00:00".to_string()),
+            succeeded: Some(true),
+            ..Default::default()
+        };
+        let entries = repo.page(&filtered, 0, 10).expect("page");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].query_text, "SELECT 5 FROM Orders");
+    }
+
+    #[test]
+    fn text_search_follows_inserts_and_deletes() {
+        let path = temp_db("fts");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryHistoryRepository::new(Arc::new(conn));
+
+        let first = executed("select id from user_accounts", "2026-01-01T00:00:00Z", true);
+        repo.add(&first).expect("add");
+        repo.add(&executed("SELECT 50% FROM t", "2026-01-02T00:00:00Z", true))
+            .expect("add");
+
+        // Trigram terms match substrings regardless of case; short terms
+        // fall back to LIKE with wildcards escaped.
+        assert_eq!(repo.search("ACCOUNT ID", 10).expect("search").len(), 1);
+        assert_eq!(repo.search("0%", 10).expect("search").len(), 1);
+        assert_eq!(repo.search("_", 10).expect("search").len(), 1);
+
+        repo.remove(&first.id).expect("remove");
+        assert!(repo.search("accounts", 10).expect("search").is_empty());
+    }
+
+    #[test]
+    fn collapse_into_latest_only_folds_the_newest_matching_entry() {
+        let path = temp_db("collapse");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryHistoryRepository::new(Arc::new(conn));
+
+        repo.add(&executed("SELECT 1", "2026-01-01T00:00:00Z", true))
+            .expect("add");
+
+        let rerun = executed("SELECT 1", "2026-01-02T00:00:00Z", false);
+        assert!(repo.collapse_into_latest(&rerun).expect("collapse"));
+
+        repo.add(&executed("SELECT 2", "2026-01-03T00:00:00Z", true))
+            .expect("add");
+        let stale = executed("SELECT 1", "2026-01-04T00:00:00Z", true);
+        assert!(!repo.collapse_into_latest(&stale).expect("collapse"));
+
+        let all = repo.all().expect("should list");
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].query_text, "SELECT 1");
+        assert_eq!(all[1].run_count, 2);
+        assert_eq!(all[1].executed_at, "2026-01-02T00:00:00Z");
+        assert!(!all[1].succeeded);
+    }
+
+    #[test]
+    fn collapse_into_latest_keeps_runs_in_other_schemas_apart() {
+        let path = temp_db("collapse_schema");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryHistoryRepository::new(Arc::new(conn));
+
+        let mut first = executed("SELECT 1", "2026-01-01T00:00:00Z", true);
+        first.schema_name = Some("public".to_string());
+        repo.add(&first).expect("add");
+
+        let mut other_schema = executed("SELECT 1", "2026-01-02T00:00:00Z", true);
+        other_schema.schema_name = Some("audit".to_string());
+        assert!(!repo.collapse_into_latest(&other_schema).expect("collapse"));
+
+        let mut same_schema = executed("SELECT 1", "2026-01-03T00:00:00Z", true);
+        same_schema.schema_name = Some("public".to_string());
+        assert!(repo.collapse_into_latest(&same_schema).expect("collapse"));
+
+        let all = repo.all().expect("should list");
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].schema_name.as_deref(), Some("public"));
+        assert_eq!(all[0].run_count, 2);
+    }
+
+    #[test]
+    fn retention_limits_skip_pinned_and_favorite_entries() {
+        let path = temp_db("retention");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = QueryHistoryRepository::with_max_entries(Arc::new(conn), 3);
+
+        let pinned = executed("SELECT pinned", "2026-01-01T00:00:00Z", true);
+        repo.add(&pinned).expect("add");
+        assert!(repo.toggle_pinned(&pinned.id).expect("pin"));
+
+        let favorite = executed("SELECT favorite", "2026-01-01T12:00:00Z", true);
+        repo.add(&favorite).expect("add");
+        assert!(repo.toggle_favorite(&favorite.id).expect("favorite"));
+
+        for day in 2..=5 {
+            repo.add(&executed(
+                &format!("SELECT {}", day),
+                &format!("2026-01-0{}T00:00:00Z", day),
+                true,
+            ))
+            .expect("add");
+        }
+
+        let texts: Vec<String> = repo
+            .all()
+            .expect("should list")
+            .into_iter()
+            .map(|e| e.query_text)
+            .collect();
+        assert_eq!(texts, vec!["SELECT 5", "SELECT favorite", "SELECT pinned"]);
+
+        let pruned = repo
+            .prune_older_than("2026-01-06T00:00:00Z")
+            .expect("prune");
+        assert_eq!(pruned, 1);
+
+        let remaining = repo.all().expect("should list");
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.iter().any(|e| e.id == pinned.id && e.is_pinned));
+        assert!(
+            remaining
+                .iter()
+                .any(|e| e.id == favorite.id && e.is_favorite)
+        );
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Stores named query definitions with folder organization.
 
+use dbflux_core::SavedQueryParam;
 use log::info;
 use rusqlite::{Connection, params};
 use serde::{Deserialize, Serialize};
@@ -107,8 +108,9 @@ impl SavedQueriesRepository {
         self.conn()
             .execute(
                 r#"
-                INSERT INTO st_saved_queries (id, folder_id, name, sql, is_favorite, connection_id)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                INSERT INTO st_saved_queries
+                    (id, folder_id, name, sql, is_favorite, connection_id, tags_json, params_json)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
                 "#,
                 params![
                     dto.id,
@@ -117,6 +119,8 @@ impl SavedQueriesRepository {
                     dto.sql,
                     dto.is_favorite as i32,
                     dto.connection_id,
+                    tags_to_json(&dto.tags),
+                    params_to_json(&dto.params),
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -135,7 +139,8 @@ impl SavedQueriesRepository {
                 r#"
                 UPDATE st_saved_queries SET
                     folder_id = ?2, name = ?3, sql = ?4, is_favorite = ?5,
-                    connection_id = ?6, last_used_at = datetime('now')
+                    connection_id = ?6, tags_json = ?7, params_json = ?8,
+                    last_used_at = datetime('now')
                 WHERE id = ?1
                 "#,
                 params![
@@ -145,6 +150,8 @@ impl SavedQueriesRepository {
                     dto.sql,
                     dto.is_favorite as i32,
                     dto.connection_id,
+                    tags_to_json(&dto.tags),
+                    params_to_json(&dto.params),
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -166,7 +173,8 @@ impl SavedQueriesRepository {
         let mut stmt = self
             .conn()
             .prepare(
-                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at
+                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at,
+                        tags_json, params_json
                  FROM st_saved_queries ORDER BY last_used_at DESC",
             )
             .map_err(|source| StorageError::Sqlite {
@@ -185,6 +193,8 @@ impl SavedQueriesRepository {
                     connection_id: row.get(5)?,
                     created_at: row.get(6)?,
                     last_used_at: row.get(7)?,
+                    tags: tags_from_json(&row.get::<_, String>(8)?),
+                    params: params_from_json(&row.get::<_, String>(9)?),
                 })
             })
             .map_err(|source| StorageError::Sqlite {
@@ -216,7 +226,8 @@ impl SavedQueriesRepository {
         let mut stmt = self
             .conn()
             .prepare(
-                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at
+                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at,
+                        tags_json, params_json
                  FROM st_saved_queries WHERE id = ?1",
             )
             .map_err(|source| StorageError::Sqlite {
@@ -234,6 +245,8 @@ impl SavedQueriesRepository {
                 connection_id: row.get(5)?,
                 created_at: row.get(6)?,
                 last_used_at: row.get(7)?,
+                tags: tags_from_json(&row.get::<_, String>(8)?),
+                params: params_from_json(&row.get::<_, String>(9)?),
             })
         }) {
             Ok(dto) => Ok(Some(dto)),
@@ -306,7 +319,8 @@ impl SavedQueriesRepository {
         let mut stmt = self
             .conn()
             .prepare(
-                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at
+                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at,
+                        tags_json, params_json
                  FROM st_saved_queries
                  WHERE LOWER(name) LIKE ?1 OR LOWER(sql) LIKE ?1
                  ORDER BY last_used_at DESC LIMIT ?2",
@@ -327,6 +341,8 @@ impl SavedQueriesRepository {
                     connection_id: row.get(5)?,
                     created_at: row.get(6)?,
                     last_used_at: row.get(7)?,
+                    tags: tags_from_json(&row.get::<_, String>(8)?),
+                    params: params_from_json(&row.get::<_, String>(9)?),
                 })
             })
             .map_err(|source| StorageError::Sqlite {
@@ -358,7 +374,8 @@ impl SavedQueriesRepository {
         let mut stmt = self
             .conn()
             .prepare(
-                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at
+                "SELECT id, folder_id, name, sql, is_favorite, connection_id, created_at, last_used_at,
+                        tags_json, params_json
                  FROM st_saved_queries WHERE is_favorite = 1 ORDER BY last_used_at DESC",
             )
             .map_err(|source| StorageError::Sqlite {
@@ -377,6 +394,8 @@ impl SavedQueriesRepository {
                     connection_id: row.get(5)?,
                     created_at: row.get(6)?,
                     last_used_at: row.get(7)?,
+                    tags: tags_from_json(&row.get::<_, String>(8)?),
+                    params: params_from_json(&row.get::<_, String>(9)?),
                 })
             })
             .map_err(|source| StorageError::Sqlite {
@@ -443,6 +462,8 @@ pub struct SavedQueryDto {
     pub connection_id: Option<String>,
     pub created_at: String,
     pub last_used_at: String,
+    pub tags: Vec<String>,
+    pub params: Vec<SavedQueryParam>,
 }
 
 impl SavedQueryDto {
@@ -457,10 +478,28 @@ impl SavedQueryDto {
             connection_id: connection_id.map(|u| u.to_string()),
             created_at: now.clone(),
             last_used_at: now,
+            tags: Vec::new(),
+            params: Vec::new(),
         }
     }
 }
 
+fn tags_to_json(tags: &[String]) -> String {
+    serde_json::to_string(tags).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn tags_from_json(json: &str) -> Vec<String> {
+    serde_json::from_str(json).unwrap_or_default()
+}
+
+fn params_to_json(params: &[SavedQueryParam]) -> String {
+    serde_json::to_string(params).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn params_from_json(json: &str) -> Vec<SavedQueryParam> {
+    serde_json::from_str(json).unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -532,6 +571,72 @@ mod tests {
         assert_eq!(repo.folders().expect("should list").len(), 0);
     }
 
+    #[test]
+    fn tags_and_folder_round_trip() {
+        let path = temp_db("sq_tags");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = SavedQueriesRepository::new(Arc::new(conn));
+
+        let folder = SavedQueryFolderDto {
+            id: Uuid::new_v4().to_string(),
+            parent_id: None,
+            name: "Reports".to_string(),
+            position: 0,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        repo.create_folder(&folder).expect("create folder");
+
+        let mut dto = SavedQueryDto::new("Totals".to_string(), "SELECT 1".to_string(), None);
+        dto.tags = vec!["billing".to_string(), "monthly".to_string()];
+        repo.insert(&dto).expect("should insert");
+
+        let fetched = repo.get(&dto.id).expect("get").expect("exists");
+        assert_eq!(fetched.tags, dto.tags);
+        assert_eq!(fetched.folder_id, None);
+
+        let mut moved = fetched.clone();
+        moved.folder_id = Some(folder.id.clone());
+        moved.tags = vec!["billing".to_string()];
+        repo.update(&moved).expect("should update");
+
+        let all = repo.all().expect("should list");
+        assert_eq!(all[0].folder_id.as_deref(), Some(folder.id.as_str()));
+        assert_eq!(all[0].tags, vec!["billing".to_string()]);
+    }
+
+    #[test]
+    fn params_round_trip() {
+        let path = temp_db("sq_params");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = SavedQueriesRepository::new(Arc::new(conn));
+
+        let mut dto = SavedQueryDto::new(
+            "By id".to_string(),
+            "SELECT * FROM t WHERE id = :id".to_string(),
+            None,
+        );
+        dto.params = dbflux_core::parse_saved_query_params("id:integer=1").expect("valid");
+        repo.insert(&dto).expect("should insert");
+
+        let mut fetched = repo.get(&dto.id).expect("get").expect("exists");
+        assert_eq!(fetched.params, dto.params);
+
+        fetched.params[0].last_value = Some("42".to_string());
+        repo.update(&fetched).expect("should update");
+
+        let all = repo.all().expect("should list");
+        assert_eq!(all[0].params[0].last_value.as_deref(), Some("42"));
+    }
+
     #[test]
     fn search_and_favorites() {
         let path = temp_db("sq_search");
@@ -63,6 +63,9 @@ pub(crate) struct FullTab {
     pub exec_ctx_database: Option<String>,
     pub exec_ctx_schema: Option<String>,
     pub exec_ctx_container: Option<String>,
+    pub results_file_path: Option<String>,
+    pub data_state_json: Option<String>,
+    pub tab_group: usize,
 }
 
 /// Session repository — manages session and tab metadata in dbflux.db.
@@ -200,7 +203,8 @@ impl SessionRepository {
                 "SELECT id, tab_kind, title, position, is_pinned,
                         scratch_file_path, shadow_file_path, language, file_path,
                         exec_ctx_json, exec_ctx_connection_id, exec_ctx_database, exec_ctx_schema,
-                        exec_ctx_container, created_at, updated_at
+                        exec_ctx_container, created_at, updated_at, results_file_path,
+                        data_state_json, tab_group
                  FROM st_session_tabs WHERE session_id = ?1 ORDER BY position ASC",
             )
             .map_err(|source| StorageError::Sqlite {
@@ -227,6 +231,9 @@ impl SessionRepository {
                     row.get::<_, Option<String>>(13)?,
                     row.get::<_, String>(14)?,
                     row.get::<_, String>(15)?,
+                    row.get::<_, Option<String>>(16)?,
+                    row.get::<_, Option<String>>(17)?,
+                    row.get::<_, i64>(18)?,
                 ))
             })
             .map_err(|source| StorageError::Sqlite {
@@ -256,6 +263,9 @@ impl SessionRepository {
                     exec_ctx_container,
                     _tab_created,
                     _tab_updated,
+                    results_file_path,
+                    data_state_json,
+                    tab_group,
                 )) => {
                     // Native columns hold the data previously extracted from JSON.
                     tabs.push(FullTab {
@@ -273,6 +283,9 @@ impl SessionRepository {
                         exec_ctx_database,
                         exec_ctx_schema,
                         exec_ctx_container,
+                        results_file_path,
+                        data_state_json,
+                        tab_group: usize::try_from(tab_group).unwrap_or(0),
                     });
                 }
                 Err(e) => last_err = Some(e),
@@ -350,9 +363,10 @@ impl SessionRepository {
                                          scratch_file_path, shadow_file_path,
                                          language, file_path, exec_ctx_json, exec_ctx_connection_id,
                                          exec_ctx_database, exec_ctx_schema, exec_ctx_container,
+                                         results_file_path, data_state_json, tab_group,
                                          created_at, updated_at)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15,
-                        datetime('now'), datetime('now'))
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                        ?17, ?18, datetime('now'), datetime('now'))
                 ON CONFLICT(id) DO UPDATE SET
                     tab_kind = excluded.tab_kind,
                     title = excluded.title,
@@ -367,6 +381,9 @@ impl SessionRepository {
                     exec_ctx_database = excluded.exec_ctx_database,
                     exec_ctx_schema = excluded.exec_ctx_schema,
                     exec_ctx_container = excluded.exec_ctx_container,
+                    results_file_path = excluded.results_file_path,
+                    data_state_json = excluded.data_state_json,
+                    tab_group = excluded.tab_group,
                     updated_at = datetime('now')
                 "#,
                 params![
@@ -385,6 +402,9 @@ impl SessionRepository {
                     dto.exec_ctx_database,
                     dto.exec_ctx_schema,
                     dto.exec_ctx_container,
+                    dto.results_file_path,
+                    dto.data_state_json,
+                    dto.tab_group as i64,
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -467,14 +487,18 @@ impl SessionRepository {
         let referenced_paths: Vec<std::path::PathBuf> = session
             .tabs
             .iter()
-            .filter_map(|tab| {
+            .flat_map(|tab| {
                 let scratch = tab.scratch_file_path.as_ref();
                 let shadow = tab.shadow_file_path.as_ref();
-                if scratch.is_some() {
+                let content = if scratch.is_some() {
                     scratch.map(PathBuf::from)
                 } else {
                     shadow.map(PathBuf::from)
-                }
+                };
+
+                content
+                    .into_iter()
+                    .chain(tab.results_file_path.as_ref().map(PathBuf::from))
             })
             .collect();
 
@@ -514,6 +538,12 @@ impl SessionRepository {
                             serde_json::to_string(&exec_ctx).unwrap_or_else(|_| "{}".to_string())
                         });
 
+                    let data_state = tab.data_state_json.as_deref().and_then(|json| {
+                        dbflux_core::DataTabState::from_json(json)
+                            .map_err(|e| log::warn!("Ignoring data tab '{}': {}", tab.title, e))
+                            .ok()
+                    });
+
                     RestoredTab {
                         id: tab.id,
                         title: tab.title,
@@ -525,6 +555,9 @@ impl SessionRepository {
                         exec_ctx_json,
                         position: tab.position,
                         is_pinned: tab.is_pinned,
+                        results_path: tab.results_file_path.map(PathBuf::from),
+                        data_state,
+                        tab_group: tab.tab_group,
                     }
                 })
                 .collect(),
@@ -642,6 +675,19 @@ impl SessionRepository {
                 .file_path
                 .as_ref()
                 .map(|p| p.to_string_lossy().to_string());
+            let results_path_str: Option<String> = tab
+                .results_path
+                .as_ref()
+                .map(|p| p.to_string_lossy().to_string());
+            let data_state_json = tab
+                .data_state
+                .as_ref()
+                .map(|state| {
+                    state
+                        .to_json()
+                        .map_err(|error| StorageError::Data(error.to_string()))
+                })
+                .transpose()?;
 
             // Extract exec_ctx fields for native columns
             let exec_ctx_connection_id = tab.exec_ctx.connection_id.map(|u| u.to_string());
@@ -683,9 +729,10 @@ impl SessionRepository {
                                          scratch_file_path, shadow_file_path,
                                          language, file_path, exec_ctx_json, exec_ctx_connection_id,
                                          exec_ctx_database, exec_ctx_schema, exec_ctx_container,
+                                         results_file_path, data_state_json, tab_group,
                                          created_at, updated_at)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15,
-                        datetime('now'), datetime('now'))
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                        ?17, ?18, datetime('now'), datetime('now'))
                 "#,
                 params![
                     tab.id,
@@ -703,6 +750,9 @@ impl SessionRepository {
                     exec_ctx_database,
                     exec_ctx_schema,
                     exec_ctx_container,
+                    results_path_str,
+                    data_state_json,
+                    tab.tab_group as i64,
                 ],
             )
             .map_err(|source| StorageError::Sqlite {
@@ -770,6 +820,12 @@ pub struct SessionTabDto {
     pub exec_ctx_schema: Option<String>,
     pub exec_ctx_container: Option<String>,
     pub file_path: Option<String>,
+    /// Kept result tabs artifact, see `ArtifactStore::results_path`.
+    pub results_file_path: Option<String>,
+    /// Serialized `DataTabState` of table and collection tabs.
+    pub data_state_json: Option<String>,
+    /// Tab group in the tab bar (0-based).
+    pub tab_group: usize,
 }
 
 /// A session manifest restored from dbflux.db.
@@ -798,6 +854,13 @@ pub struct RestoredTab {
     pub exec_ctx_json: String,
     pub position: i32,
     pub is_pinned: bool,
+    pub results_path: Option<std::path::PathBuf>,
+    /// Browse state of `"Data"` tabs; `None` for editor tabs or when the
+    /// stored state no longer parses.
+    pub data_state: Option<dbflux_core::DataTabState>,
+    /// Tab group in the tab bar (0-based).
+    #[serde(default)]
+    pub tab_group: usize,
 }
 
 impl Default for TabRestorePayload {
@@ -840,6 +903,13 @@ pub struct WorkspaceTab {
     pub title: String,
     pub position: usize,
     pub is_pinned: bool,
+    /// Kept result tabs artifact of the document; the file may not exist.
+    pub results_path: Option<std::path::PathBuf>,
+    /// Browse state of table and collection tabs (`tab_kind` `"Data"`).
+    pub data_state: Option<dbflux_core::DataTabState>,
+    /// Tab group in the tab bar (0-based).
+    #[serde(default)]
+    pub tab_group: usize,
 }
 
 #[cfg(test)]
@@ -972,6 +1042,9 @@ mod tests {
             exec_ctx_schema: None,
             exec_ctx_container: None,
             file_path: None,
+            results_file_path: None,
+            data_state_json: None,
+            tab_group: 0,
         })
         .expect("upsert tab");
 
@@ -1007,6 +1080,9 @@ mod tests {
                 title: "Query 1".to_string(),
                 position: 0,
                 is_pinned: false,
+                results_path: None,
+                data_state: None,
+                tab_group: 0,
             }],
         };
 
@@ -1110,6 +1186,9 @@ mod tests {
             exec_ctx_schema: None,
             exec_ctx_container: None,
             file_path: None,
+            results_file_path: None,
+            data_state_json: None,
+            tab_group: 0,
         })
         .expect("upsert tab");
 
@@ -1149,6 +1228,181 @@ mod tests {
         let _ = std::fs::remove_dir_all(&artifact_root);
     }
 
+    #[test]
+    fn restore_session_keeps_results_artifact() {
+        let path = temp_db("restore_results");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = SessionRepository::new(Arc::new(conn));
+
+        let artifact_root = std::env::temp_dir().join(format!(
+            "dbflux_test_artifacts_{}_{}",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        let store = ArtifactStore::for_root(artifact_root.clone()).expect("temp store");
+
+        let scratch_file = store.scratch_path("tab-results", "sql");
+        let results_file = store.results_path("tab-results");
+        store
+            .write_content(&scratch_file, "SELECT 1")
+            .expect("write");
+        store
+            .write_content(&results_file, "[]")
+            .expect("write results");
+
+        let manifest = WorkspaceSessionManifest {
+            version: 1,
+            active_index: Some(0),
+            tabs: vec![WorkspaceTab {
+                id: "tab-results".to_string(),
+                tab_kind: "Scratch".to_string(),
+                language: "sql".to_string(),
+                exec_ctx: dbflux_core::ExecutionContext::default(),
+                scratch_path: Some(scratch_file.clone()),
+                shadow_path: None,
+                file_path: None,
+                title: "Query 1".to_string(),
+                position: 0,
+                is_pinned: false,
+                results_path: Some(results_file.clone()),
+                data_state: None,
+                tab_group: 0,
+            }],
+        };
+
+        repo.save_workspace_session(&manifest).expect("save");
+
+        let restored = repo
+            .restore_session(&store)
+            .expect("restore should succeed")
+            .expect("should return a session");
+
+        assert_eq!(restored.tabs[0].results_path.as_ref(), Some(&results_file));
+        assert!(
+            results_file.exists(),
+            "kept results should survive orphan cleanup"
+        );
+
+        let _ = std::fs::remove_dir_all(&artifact_root);
+    }
+
+    #[test]
+    fn data_tab_state_roundtrips() {
+        let path = temp_db("data_tab");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = SessionRepository::new(Arc::new(conn));
+
+        let artifact_root = std::env::temp_dir().join(format!(
+            "dbflux_test_artifacts_{}_{}",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        let store = ArtifactStore::for_root(artifact_root.clone()).expect("temp store");
+
+        let state = dbflux_core::DataTabState {
+            profile_id: Uuid::new_v4(),
+            target: dbflux_core::DataTabTarget::Table {
+                table: dbflux_core::TableRef::with_schema("public", "orders"),
+                database: None,
+            },
+            filter: "total > 100".to_string(),
+            order_by: vec![dbflux_core::OrderByColumn::asc("created_at")],
+            pagination: dbflux_core::Pagination::default().next_page(),
+        };
+
+        let manifest = WorkspaceSessionManifest {
+            version: 1,
+            active_index: Some(0),
+            tabs: vec![WorkspaceTab {
+                id: "tab-data".to_string(),
+                tab_kind: "Data".to_string(),
+                language: "sql".to_string(),
+                exec_ctx: dbflux_core::ExecutionContext::default(),
+                scratch_path: None,
+                shadow_path: None,
+                file_path: None,
+                title: "public.orders".to_string(),
+                position: 0,
+                is_pinned: false,
+                results_path: None,
+                data_state: Some(state.clone()),
+                tab_group: 0,
+            }],
+        };
+
+        repo.save_workspace_session(&manifest).expect("save");
+
+        let restored = repo
+            .restore_session(&store)
+            .expect("restore should succeed")
+            .expect("should return a session");
+
+        assert_eq!(restored.tabs[0].tab_kind, "Data");
+        assert_eq!(restored.tabs[0].data_state.as_ref(), Some(&state));
+
+        let _ = std::fs::remove_dir_all(&artifact_root);
+    }
+
+    #[test]
+    fn tab_group_roundtrips() {
+        let path = temp_db("tab_group");
+        let conn = open_database(&path).expect("should open");
+        MigrationRegistry::new()
+            .run_all(&conn)
+            .expect("migration should run");
+        #[allow(clippy::arc_with_non_send_sync)]
+        let repo = SessionRepository::new(Arc::new(conn));
+
+        let artifact_root = std::env::temp_dir().join(format!(
+            "dbflux_test_artifacts_{}_{}",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        let store = ArtifactStore::for_root(artifact_root.clone()).expect("temp store");
+
+        let tab = |id: &str, position: usize, tab_group: usize| WorkspaceTab {
+            id: id.to_string(),
+            tab_kind: "Scratch".to_string(),
+            language: "sql".to_string(),
+            exec_ctx: dbflux_core::ExecutionContext::default(),
+            scratch_path: None,
+            shadow_path: None,
+            file_path: None,
+            title: id.to_string(),
+            position,
+            is_pinned: false,
+            results_path: None,
+            data_state: None,
+            tab_group,
+        };
+
+        let manifest = WorkspaceSessionManifest {
+            version: 1,
+            active_index: Some(0),
+            tabs: vec![tab("tab-a", 0, 0), tab("tab-b", 1, 2)],
+        };
+
+        repo.save_workspace_session(&manifest).expect("save");
+
+        let restored = repo
+            .restore_session(&store)
+            .expect("restore should succeed")
+            .expect("should return a session");
+
+        let groups: Vec<usize> = restored.tabs.iter().map(|tab| tab.tab_group).collect();
+        assert_eq!(groups, vec![0, 2]);
+
+        let _ = std::fs::remove_dir_all(&artifact_root);
+    }
+
     #[test]
     fn save_and_restore_file_backed_tab() {
         // Verifies that file_path round-trips correctly through save and restore.
@@ -1199,6 +1453,9 @@ mod tests {
                 title: "my-script.sql".to_string(),
                 position: 0,
                 is_pinned: false,
+                results_path: None,
+                data_state: None,
+                tab_group: 0,
             }],
         };
 
@@ -1266,6 +1523,9 @@ mod tests {
                 title: "Query with context".to_string(),
                 position: 0,
                 is_pinned: false,
+                results_path: None,
+                data_state: None,
+                tab_group: 0,
             }],
         };
 
@@ -1340,6 +1600,9 @@ mod tests {
                     title: "CloudWatch One".to_string(),
                     position: 0,
                     is_pinned: false,
+                    results_path: None,
+                    data_state: None,
+                    tab_group: 0,
                 },
                 WorkspaceTab {
                     id: "cw-2".to_string(),
@@ -1363,6 +1626,9 @@ mod tests {
                     title: "CloudWatch Two".to_string(),
                     position: 1,
                     is_pinned: false,
+                    results_path: None,
+                    data_state: None,
+                    tab_group: 0,
                 },
             ],
         };
@@ -1552,6 +1818,9 @@ mod tests {
                     title: "Query 0".to_string(),
                     position: 0,
                     is_pinned: false,
+                    results_path: None,
+                    data_state: None,
+                    tab_group: 0,
                 },
                 WorkspaceTab {
                     id: "tab-1".to_string(),
@@ -1564,6 +1833,9 @@ mod tests {
                     title: "Query 1".to_string(),
                     position: 1,
                     is_pinned: false,
+                    results_path: None,
+                    data_state: None,
+                    tab_group: 0,
                 },
                 WorkspaceTab {
                     id: "tab-2".to_string(),
@@ -1576,6 +1848,9 @@ mod tests {
                     title: "Query 2 — active".to_string(),
                     position: 2,
                     is_pinned: false,
+                    results_path: None,
+                    data_state: None,
+                    tab_group: 0,
                 },
             ],
         };
@@ -1629,6 +1904,9 @@ mod tests {
                     title: format!("Save {}", i),
                     position: 0,
                     is_pinned: false,
+                    results_path: None,
+                    data_state: None,
+                    tab_group: 0,
                 }],
             };
 
@@ -1,4 +1,4 @@
-use dbflux_core::{RpcServiceKind, ServiceConfig, ServiceRpcApiContract};
+use dbflux_core::{RemoteServiceConfig, RpcServiceKind, ServiceConfig, ServiceRpcApiContract};
 
 use crate::bootstrap::StorageRuntime;
 
@@ -12,6 +12,7 @@ pub fn load_service_configs(runtime: &StorageRuntime) -> Vec<ServiceConfig> {
                 let args = repo.get_args(&dto.socket_id).unwrap_or_default();
                 let env = repo.get_env(&dto.socket_id).unwrap_or_default();
                 let api_contract = service_api_contract_from_dto(&dto);
+                let remote = service_remote_from_dto(&dto);
 
                 ServiceConfig {
                     socket_id: dto.socket_id,
@@ -22,6 +23,7 @@ pub fn load_service_configs(runtime: &StorageRuntime) -> Vec<ServiceConfig> {
                     startup_timeout_ms: dto.startup_timeout_ms.map(|value| value as u64),
                     kind: rpc_service_kind_from_storage(&dto.service_kind),
                     api_contract,
+                    remote,
                 }
             })
             .collect()
@@ -78,3 +80,15 @@ fn service_api_contract_from_dto(
         _ => None,
     }
 }
+
+fn service_remote_from_dto(
+    dto: &crate::repositories::services::ServiceDto,
+) -> Option<RemoteServiceConfig> {
+    let address = dto.remote_address.clone()?;
+
+    Some(RemoteServiceConfig {
+        address,
+        tls: dto.remote_tls,
+        token_env: dto.remote_token_env.clone().unwrap_or_default(),
+    })
+}
@@ -0,0 +1,328 @@
+//! Record and replay driver RPC traffic.
+//!
+//! [`DriverRpcRecorder`] sits between a client and a real driver host and
+//! appends every envelope that crosses it to a JSON Lines file.
+//! [`DriverRpcReplayServer`] then plays that file back as a fake driver host,
+//! so an integration test or a bug report can run against the recorded
+//! conversation without the database behind it.
+//!
+//! Passwords, SSH secrets and auth tokens are left out of recordings.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use dbflux_ipc::{
+    EVENT_REQUEST_ID,
+    driver_protocol::{DriverRequestBody, DriverRequestEnvelope, DriverResponseEnvelope},
+    driver_socket_name, framing,
+};
+use interprocess::local_socket::traits::{Listener as _, Stream as _};
+use interprocess::local_socket::{
+    ListenerNonblockingMode::Neither, ListenerOptions, Stream as IpcStream,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// How often the recorder checks both ends of an idle connection.
+const RECORDER_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// One line of a recording.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "frame", rename_all = "snake_case")]
+pub enum RecordedFrame {
+    /// A client connected; the frames after it belong to that connection.
+    Connected,
+    Request {
+        envelope: DriverRequestEnvelope,
+    },
+    Response {
+        envelope: DriverResponseEnvelope,
+    },
+}
+
+/// The frames of a recording, in the order they crossed the recorder.
+#[derive(Debug, Clone, Default)]
+pub struct DriverRpcRecording {
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl DriverRpcRecording {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut frames = Vec::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let frame = serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: {e}", path.display(), index + 1),
+                )
+            })?;
+            frames.push(frame);
+        }
+
+        Ok(Self { frames })
+    }
+
+    /// The recorded frames split by connection.
+    fn connections(&self) -> Vec<&[RecordedFrame]> {
+        self.frames
+            .split(|frame| matches!(frame, RecordedFrame::Connected))
+            .skip_while(|frames| frames.is_empty())
+            .collect()
+    }
+}
+
+/// A proxy that forwards `connections` client connections to a driver host
+/// and records their traffic.
+pub struct DriverRpcRecorder {
+    join_handle: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl DriverRpcRecorder {
+    /// Listens on `socket_id` and forwards each connection to the host
+    /// listening on `upstream_socket_id`, writing the traffic to `path`.
+    pub fn start(
+        socket_id: &str,
+        upstream_socket_id: &str,
+        path: impl Into<PathBuf>,
+        connections: usize,
+    ) -> io::Result<Self> {
+        let socket_name = driver_socket_name(socket_id)?;
+        let listener = ListenerOptions::new()
+            .name(socket_name.borrow())
+            .nonblocking(Neither)
+            .create_sync()?;
+
+        let upstream_socket_id = upstream_socket_id.to_string();
+        let mut writer = BufWriter::new(File::create(path.into())?);
+
+        let join_handle = thread::spawn(move || {
+            for _ in 0..connections {
+                let mut client = listener.accept()?;
+                let upstream_name = driver_socket_name(&upstream_socket_id)?;
+                let mut upstream = IpcStream::connect(upstream_name)?;
+
+                write_frame(&mut writer, &RecordedFrame::Connected)?;
+                record_connection(&mut client, &mut upstream, &mut writer)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(Self {
+            join_handle: Some(join_handle),
+        })
+    }
+
+    /// Waits until every connection closed and the recording is complete.
+    pub fn wait(mut self) -> io::Result<()> {
+        join(self.join_handle.take())
+    }
+}
+
+impl Drop for DriverRpcRecorder {
+    fn drop(&mut self) {
+        let _ = join(self.join_handle.take());
+    }
+}
+
+/// A fake driver host answering with a recording.
+///
+/// Each incoming request must match the next recorded one; `Hello` is
+/// accepted as is, and secrets are ignored. The recorded responses are sent
+/// back with the live request id. A mismatch closes the connection and
+/// [`Self::wait`] reports it.
+pub struct DriverRpcReplayServer {
+    join_handle: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl DriverRpcReplayServer {
+    pub fn start(socket_id: &str, recording: DriverRpcRecording) -> io::Result<Self> {
+        let socket_name = driver_socket_name(socket_id)?;
+        let listener = ListenerOptions::new()
+            .name(socket_name.borrow())
+            .nonblocking(Neither)
+            .create_sync()?;
+
+        let join_handle = thread::spawn(move || {
+            for frames in recording.connections() {
+                let mut stream = listener.accept()?;
+                replay_connection(&mut stream, frames)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(Self {
+            join_handle: Some(join_handle),
+        })
+    }
+
+    /// Waits until the whole recording was replayed.
+    pub fn wait(mut self) -> io::Result<()> {
+        join(self.join_handle.take())
+    }
+}
+
+impl Drop for DriverRpcReplayServer {
+    fn drop(&mut self) {
+        let _ = join(self.join_handle.take());
+    }
+}
+
+fn join(handle: Option<thread::JoinHandle<io::Result<()>>>) -> io::Result<()> {
+    let Some(handle) = handle else {
+        return Ok(());
+    };
+
+    handle
+        .join()
+        .map_err(|_| io::Error::other("driver RPC harness thread panicked"))?
+}
+
+/// Forwards frames both ways until either side hangs up.
+fn record_connection(
+    client: &mut IpcStream,
+    upstream: &mut IpcStream,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    loop {
+        let mut idle = true;
+
+        match poll_frame::<DriverRequestEnvelope>(client) {
+            Ok(Some(request)) => {
+                let recorded = RecordedFrame::Request {
+                    envelope: redacted(request.clone()),
+                };
+                write_frame(writer, &recorded)?;
+                framing::send_msg(&mut *upstream, &request)?;
+                idle = false;
+            }
+            Ok(None) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(e) => return Err(e),
+        }
+
+        match poll_frame::<DriverResponseEnvelope>(upstream) {
+            Ok(Some(response)) => {
+                let recorded = RecordedFrame::Response {
+                    envelope: response.clone(),
+                };
+                write_frame(writer, &recorded)?;
+                framing::send_msg(&mut *client, &response)?;
+                idle = false;
+            }
+            Ok(None) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(e) => return Err(e),
+        }
+
+        if idle {
+            thread::sleep(RECORDER_POLL_INTERVAL);
+        }
+    }
+}
+
+fn replay_connection(stream: &mut IpcStream, frames: &[RecordedFrame]) -> io::Result<()> {
+    let mut live_request_id = None;
+    let mut recorded_request_id = None;
+
+    for frame in frames {
+        match frame {
+            RecordedFrame::Connected => {}
+
+            RecordedFrame::Request { envelope } => {
+                let request: DriverRequestEnvelope = framing::recv_msg(&mut *stream)?;
+                live_request_id = Some(request.request_id);
+                recorded_request_id = Some(envelope.request_id);
+
+                check_request(envelope, request)?;
+            }
+
+            RecordedFrame::Response { envelope } => {
+                let mut response = envelope.clone();
+                if response.request_id != EVENT_REQUEST_ID
+                    && Some(response.request_id) == recorded_request_id
+                    && let Some(request_id) = live_request_id
+                {
+                    response.request_id = request_id;
+                }
+
+                framing::send_msg(&mut *stream, &response)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_request(
+    expected: &DriverRequestEnvelope,
+    actual: DriverRequestEnvelope,
+) -> io::Result<()> {
+    if matches!(expected.body, DriverRequestBody::Hello(_))
+        && matches!(actual.body, DriverRequestBody::Hello(_))
+    {
+        return Ok(());
+    }
+
+    let expected_body = serde_json::to_value(&expected.body).map_err(io::Error::other)?;
+    let actual_body = serde_json::to_value(redacted(actual).body).map_err(io::Error::other)?;
+
+    if expected_body != actual_body {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "request {} does not match the recording: expected {expected_body}, got \
+                 {actual_body}",
+                expected.request_id
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// `request` without the secrets it carries.
+fn redacted(mut request: DriverRequestEnvelope) -> DriverRequestEnvelope {
+    match &mut request.body {
+        DriverRequestBody::Hello(hello) => hello.auth_token = None,
+        DriverRequestBody::OpenSession {
+            password,
+            ssh_secret,
+            ..
+        } => {
+            *password = None;
+            *ssh_secret = None;
+        }
+        _ => {}
+    }
+
+    request
+}
+
+fn poll_frame<T: DeserializeOwned>(stream: &mut IpcStream) -> io::Result<Option<T>> {
+    stream.set_nonblocking(true)?;
+    let first = framing::poll_msg_start(&mut *stream);
+    stream.set_nonblocking(false)?;
+
+    match first? {
+        Some(first) => framing::recv_msg_after(first, stream).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn write_frame(writer: &mut impl Write, frame: &RecordedFrame) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, frame).map_err(io::Error::other)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
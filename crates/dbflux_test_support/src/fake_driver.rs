@@ -2,9 +2,10 @@ use dbflux_core::secrecy::SecretString;
 use dbflux_core::{
     Connection, ConnectionProfile, CrudResult, DatabaseCategory, DbConfig, DbDriver, DbError,
     DbKind, DdlCapabilities, DriverCapabilities, DriverFormDef, DriverLimits, DriverMetadata,
-    FormValues, Icon, MutationCapabilities, QueryCapabilities, QueryHandle, QueryLanguage,
-    QueryRequest, QueryResult, RowDelete, RowInsert, RowPatch, SchemaLoadingStrategy,
-    SchemaSnapshot, SqlDialect, SqlLanguageService, SyntaxInfo, TransactionCapabilities,
+    FormValues, Icon, MutationCapabilities, QueryCancelHandle, QueryCapabilities, QueryHandle,
+    QueryLanguage, QueryRequest, QueryResult, RowDelete, RowInsert, RowPatch,
+    SchemaLoadingStrategy, SchemaSnapshot, SessionGate, SqlDialect, SqlLanguageService, SyntaxInfo,
+    TransactionCapabilities,
 };
 use dbflux_core::{DatabaseInfo, DefaultSqlDialect};
 use dbflux_driver_cloudwatch::CLOUDWATCH_FORM;
@@ -18,8 +19,12 @@ use dbflux_driver_redis::{REDIS_FORM, RedisLanguageService};
 use dbflux_driver_sqlite::SQLITE_FORM;
 use std::collections::HashMap;
 use std::sync::LazyLock;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration, Instant};
+
+/// How often a delayed query checks whether it was cancelled.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(5);
 
 #[derive(Debug, Clone)]
 pub enum FakeQueryOutcome {
@@ -28,6 +33,26 @@ pub enum FakeQueryOutcome {
     Error(String),
     Timeout,
     Cancelled,
+    /// Waits, then resolves to the inner outcome. Cancelling the query ends
+    /// the wait with `Cancelled`; a shorter `statement_timeout` ends it with
+    /// `Timeout`.
+    Delayed(Duration, Box<FakeQueryOutcome>),
+    /// Fails with `message` on every `every`th execution of the query and
+    /// resolves to `outcome` otherwise.
+    Intermittent {
+        every: usize,
+        message: String,
+        outcome: Box<FakeQueryOutcome>,
+    },
+    /// Serves `result` one page of at most `rows_per_chunk` rows at a time,
+    /// each after `delay`. The request's `offset` selects the page and a
+    /// smaller `limit` shrinks it; `next_page_token` holds the next offset
+    /// while rows remain.
+    Chunked {
+        result: Box<QueryResult>,
+        rows_per_chunk: usize,
+        delay: Duration,
+    },
 }
 
 impl FakeQueryOutcome {
@@ -35,16 +60,45 @@ impl FakeQueryOutcome {
         Self::Success(Box::new(result))
     }
 
-    fn to_result(&self) -> Result<QueryResult, Box<DbError>> {
-        match self {
-            Self::Success(result) => Ok(*result.clone()),
-            Self::Error(message) => Err(Box::new(DbError::query_failed(message.clone()))),
-            Self::Timeout => Err(Box::new(DbError::Timeout)),
-            Self::Cancelled => Err(Box::new(DbError::Cancelled)),
+    pub fn delayed(latency: Duration, outcome: FakeQueryOutcome) -> Self {
+        Self::Delayed(latency, Box::new(outcome))
+    }
+
+    pub fn intermittent(
+        every: usize,
+        message: impl Into<String>,
+        outcome: FakeQueryOutcome,
+    ) -> Self {
+        Self::Intermittent {
+            every,
+            message: message.into(),
+            outcome: Box::new(outcome),
+        }
+    }
+
+    pub fn chunked(result: QueryResult, rows_per_chunk: usize, delay: Duration) -> Self {
+        Self::Chunked {
+            result: Box::new(result),
+            rows_per_chunk,
+            delay,
         }
     }
 }
 
+/// Cancels whatever query its connection is running.
+struct FakeCancelHandle(Arc<AtomicBool>);
+
+impl QueryCancelHandle for FakeCancelHandle {
+    fn cancel(&self) -> Result<(), DbError> {
+        self.0.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
 /// A single recorded CRUD call made through `FakeConnection`.
 #[derive(Debug, Clone)]
 pub enum CrudOp {
@@ -67,6 +121,8 @@ struct FakeDriverState {
     schema: RwLock<SchemaSnapshot>,
     query_outcomes: RwLock<HashMap<String, FakeQueryOutcome>>,
     default_outcome: RwLock<Option<FakeQueryOutcome>>,
+    latency: RwLock<Duration>,
+    execution_counts: Mutex<HashMap<String, usize>>,
     executed_requests: Mutex<Vec<QueryRequest>>,
     crud_ops: Mutex<Vec<CrudOp>>,
     cancelled_handles: Mutex<Vec<QueryHandle>>,
@@ -120,6 +176,12 @@ impl FakeDriver {
         self
     }
 
+    /// Delays every query by `latency` before its outcome applies.
+    pub fn with_latency(self, latency: Duration) -> Self {
+        *rwlock_write(&self.state.latency) = latency;
+        self
+    }
+
     pub fn with_ping_error(self, message: impl Into<String>) -> Self {
         *rwlock_write(&self.state.ping_error) = Some(message.into());
         self
@@ -440,6 +502,8 @@ struct FakeConnection {
     kind: DbKind,
     state: Arc<FakeDriverState>,
     active_database: RwLock<Option<String>>,
+    cancelled: Arc<AtomicBool>,
+    session_gate: SessionGate,
 }
 
 impl FakeConnection {
@@ -448,29 +512,139 @@ impl FakeConnection {
             kind,
             state,
             active_database: RwLock::new(active_database_from_profile(profile)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            session_gate: SessionGate::new(),
         }
     }
 
     fn execute_internal(&self, req: &QueryRequest) -> Result<QueryResult, Box<DbError>> {
+        let _session = self
+            .session_gate
+            .enter(|request| self.execute_internal(request).map_err(|error| *error))?;
+
         mutex_lock(&self.state.executed_requests).push(req.clone());
+        self.cancelled.store(false, Ordering::Relaxed);
+
+        let execution = {
+            let mut counts = mutex_lock(&self.state.execution_counts);
+            let count = counts.entry(req.sql.clone()).or_default();
+            *count += 1;
+            *count
+        };
 
         if let Some(database) = req.database.clone() {
             *rwlock_write(&self.active_database) = Some(database);
         }
 
+        let latency = *rwlock_read(&self.state.latency);
+        self.wait(latency, req)?;
+
         if let Some(outcome) = rwlock_read(&self.state.query_outcomes)
             .get(&req.sql)
             .cloned()
         {
-            return outcome.to_result();
+            return self.resolve(&outcome, req, execution);
         }
 
         if let Some(outcome) = rwlock_read(&self.state.default_outcome).clone() {
-            return outcome.to_result();
+            return self.resolve(&outcome, req, execution);
         }
 
         Ok(QueryResult::empty())
     }
+
+    /// Applies `outcome` to the `execution`th run of `req`.
+    fn resolve(
+        &self,
+        outcome: &FakeQueryOutcome,
+        req: &QueryRequest,
+        execution: usize,
+    ) -> Result<QueryResult, Box<DbError>> {
+        match outcome {
+            FakeQueryOutcome::Success(result) => Ok(*result.clone()),
+            FakeQueryOutcome::Error(message) => {
+                Err(Box::new(DbError::query_failed(message.clone())))
+            }
+            FakeQueryOutcome::Timeout => Err(Box::new(DbError::Timeout)),
+            FakeQueryOutcome::Cancelled => Err(Box::new(DbError::Cancelled)),
+            FakeQueryOutcome::Delayed(latency, outcome) => {
+                self.wait(*latency, req)?;
+                self.resolve(outcome, req, execution)
+            }
+            FakeQueryOutcome::Intermittent {
+                every,
+                message,
+                outcome,
+            } => {
+                if *every > 0 && execution.is_multiple_of(*every) {
+                    return Err(Box::new(DbError::query_failed(message.clone())));
+                }
+
+                self.resolve(outcome, req, execution)
+            }
+            FakeQueryOutcome::Chunked {
+                result,
+                rows_per_chunk,
+                delay,
+            } => {
+                self.wait(*delay, req)?;
+                Ok(result_chunk(result, req, *rows_per_chunk))
+            }
+        }
+    }
+
+    /// Sleeps for `duration` unless the query is cancelled or its
+    /// `statement_timeout` runs out first.
+    fn wait(&self, duration: Duration, req: &QueryRequest) -> Result<(), Box<DbError>> {
+        if duration.is_zero() {
+            return Ok(());
+        }
+
+        let limit = req
+            .statement_timeout
+            .map_or(duration, |timeout| timeout.min(duration));
+        let started = Instant::now();
+
+        loop {
+            if self.cancelled.load(Ordering::Relaxed) {
+                return Err(Box::new(DbError::Cancelled));
+            }
+
+            let elapsed = started.elapsed();
+            if elapsed >= limit {
+                break;
+            }
+
+            std::thread::sleep(CANCEL_POLL_INTERVAL.min(limit - elapsed));
+        }
+
+        if limit < duration {
+            return Err(Box::new(DbError::Timeout));
+        }
+
+        Ok(())
+    }
+}
+
+/// The page of `result` that `req` asks for.
+fn result_chunk(result: &QueryResult, req: &QueryRequest, rows_per_chunk: usize) -> QueryResult {
+    let rows_per_chunk = rows_per_chunk.max(1);
+    let size = req
+        .limit
+        .map_or(rows_per_chunk, |limit| (limit as usize).min(rows_per_chunk));
+    let start = (req.offset.unwrap_or(0) as usize).min(result.rows.len());
+    let end = (start + size).min(result.rows.len());
+
+    let mut chunk = result.clone();
+    chunk.rows = result
+        .rows
+        .iter()
+        .skip(start)
+        .take(end - start)
+        .cloned()
+        .collect();
+    chunk.next_page_token = (end < result.rows.len()).then(|| end.to_string());
+    chunk
 }
 
 impl Connection for FakeConnection {
@@ -491,6 +665,10 @@ impl Connection for FakeConnection {
         Ok(())
     }
 
+    fn session_gate(&self) -> Option<&SessionGate> {
+        Some(&self.session_gate)
+    }
+
     fn execute(&self, req: &QueryRequest) -> Result<QueryResult, DbError> {
         self.execute_internal(req).map_err(|error| *error)
     }
@@ -506,6 +684,7 @@ impl Connection for FakeConnection {
 
     fn cancel(&self, handle: &QueryHandle) -> Result<(), DbError> {
         mutex_lock(&self.state.cancelled_handles).push(handle.clone());
+        self.cancelled.store(true, Ordering::Relaxed);
         Ok(())
     }
 
@@ -513,9 +692,14 @@ impl Connection for FakeConnection {
         self.state
             .cancel_active_calls
             .fetch_add(1, Ordering::Relaxed);
+        self.cancelled.store(true, Ordering::Relaxed);
         Ok(())
     }
 
+    fn cancel_handle(&self) -> Arc<dyn QueryCancelHandle> {
+        Arc::new(FakeCancelHandle(self.cancelled.clone()))
+    }
+
     fn schema(&self) -> Result<SchemaSnapshot, DbError> {
         Ok(rwlock_read(&self.state.schema).clone())
     }
@@ -1081,8 +1265,11 @@ mod tests {
     use super::{FakeDriver, FakeQueryOutcome};
     use crate::fixtures;
     use dbflux_core::{
-        ConnectionProfile, DbConfig, DbDriver, DbError, DbKind, QueryRequest, SchemaLoadingStrategy,
+        Connection, ConnectionProfile, DbConfig, DbDriver, DbError, DbKind, QueryRequest,
+        QueryResult, SchemaLoadingStrategy, Value,
     };
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn sqlite_build_config_requires_path() {
@@ -1261,4 +1448,87 @@ mod tests {
             assert_eq!(connection.schema_loading_strategy(), expected_strategy);
         }
     }
+
+    #[test]
+    fn delayed_queries_end_early_on_cancel_and_statement_timeout() {
+        let driver = FakeDriver::new(DbKind::Postgres).with_latency(Duration::from_secs(5));
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection: Arc<dyn Connection> = Arc::from(
+            driver
+                .connect(&profile)
+                .expect("fake connection should work"),
+        );
+
+        let started = Instant::now();
+        let mut request = QueryRequest::new("SELECT pg_sleep(5)");
+        request.statement_timeout = Some(Duration::from_millis(20));
+        let timed_out = connection.execute(&request);
+        assert!(matches!(timed_out, Err(DbError::Timeout)));
+
+        let cancel_handle = connection.cancel_handle();
+        let running = {
+            let connection = connection.clone();
+            std::thread::spawn(move || connection.execute(&QueryRequest::new("SELECT 1")))
+        };
+        std::thread::sleep(Duration::from_millis(20));
+        cancel_handle.cancel().expect("cancel should succeed");
+
+        let cancelled = running.join().expect("query thread should not panic");
+        assert!(matches!(cancelled, Err(DbError::Cancelled)));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn intermittent_outcome_fails_every_nth_execution() {
+        let driver = FakeDriver::new(DbKind::Postgres);
+        driver.set_query_outcome(
+            "SELECT 1",
+            FakeQueryOutcome::intermittent(
+                3,
+                "connection reset",
+                FakeQueryOutcome::success(QueryResult::empty()),
+            ),
+        );
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver
+            .connect(&profile)
+            .expect("fake connection should work");
+
+        let failures: Vec<bool> = (0..6)
+            .map(|_| connection.execute(&QueryRequest::new("SELECT 1")).is_err())
+            .collect();
+        assert_eq!(failures, [false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn chunked_outcome_serves_large_results_page_by_page() {
+        let driver = FakeDriver::new(DbKind::Postgres);
+        driver.set_query_outcome(
+            "SELECT * FROM big",
+            FakeQueryOutcome::chunked(
+                fixtures::large_table_result(250, 3),
+                100,
+                Duration::from_millis(1),
+            ),
+        );
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver
+            .connect(&profile)
+            .expect("fake connection should work");
+
+        let first = connection
+            .execute(&QueryRequest::new("SELECT * FROM big"))
+            .expect("first chunk should load");
+        assert_eq!(first.rows.len(), 100);
+        assert_eq!(first.next_page_token.as_deref(), Some("100"));
+
+        let mut request = QueryRequest::new("SELECT * FROM big");
+        request.offset = Some(200);
+        let last = connection
+            .execute(&request)
+            .expect("last chunk should load");
+        assert_eq!(last.rows.len(), 50);
+        assert_eq!(last.rows[0][0], Value::Text("r200c0".to_string()));
+        assert_eq!(last.next_page_token, None);
+    }
 }
@@ -1,28 +1,53 @@
-use std::io;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener};
 use std::thread;
+use std::time::Duration;
 
-use dbflux_core::{DatabaseCategory, DbKind, DriverFormDef, DriverMetadataBuilder, QueryLanguage};
+use dbflux_core::{
+    CodeGenCapabilities, ConnectionEvent, DatabaseCategory, DbKind, DriverFormDef, DriverMetadata,
+    DriverMetadataBuilder, QueryLanguage, SchemaFeatures, SchemaLoadingStrategy,
+};
 use dbflux_ipc::audit::AuditEventEmitDto;
 use dbflux_ipc::{
-    DRIVER_RPC_VERSION,
+    CompressionCodec, DRIVER_RPC_VERSION,
     driver_protocol::{
-        DriverCapability, DriverHelloResponse, DriverRequestEnvelope, DriverResponseBody,
-        DriverResponseEnvelope,
+        DriverCapability, DriverHelloResponse, DriverRequestBody, DriverRequestEnvelope,
+        DriverResponseBody, DriverResponseEnvelope,
     },
-    driver_rpc_supported_versions, driver_socket_name, framing,
+    driver_rpc_supported_versions, driver_socket_name, framing, negotiate_compression,
 };
+use interprocess::local_socket::traits::Listener as _;
 use interprocess::local_socket::{ListenerNonblockingMode::Neither, ListenerOptions};
+use uuid::Uuid;
 
 /// Script of actions for the fake driver server to perform on each connection.
 #[derive(Clone, Debug)]
 pub enum FakeDriverAction {
     /// Reply with a pong.
     Pong,
+    /// Wait, then reply with a pong, as a stalled host would.
+    DelayedPong(Duration),
     /// Emit an audit event (intermediate, `done=false`) then pong.
     EmitAuditThenPong(AuditEventEmitDto),
     /// Emit N audit frames (all `done=false`) then a pong.
     /// Used to exercise the rate-limit drop path while verifying session continuity.
     EmitNAuditThenPong(u32, AuditEventEmitDto),
+    /// Reply to an `OpenSession` with the given session id.
+    OpenSession(Uuid),
+    /// Reply with the given body, compressed when the client negotiated a
+    /// codec and the body is large enough.
+    Respond(DriverResponseBody),
+    /// Reply with the given body, then push the events to the request's
+    /// session as unsolicited event frames.
+    RespondThenEvents(DriverResponseBody, Vec<ConnectionEvent>),
+    /// Read the request, then drop the connection without replying, as a
+    /// crashed host would. The actions after it are served on the next
+    /// connection.
+    Hangup,
+    /// Send the first half of a pong, wait, then drop the connection, as a
+    /// host that hangs partway through a response would. The actions after
+    /// it are served on the next connection.
+    StallMidPong(Duration),
 }
 
 #[derive(Clone, Debug)]
@@ -74,13 +99,31 @@ impl FakeDriverRpcServer {
             .nonblocking(Neither)
             .create_sync()?;
 
-        let join_handle = thread::spawn(move || run_server(listener, config));
+        let join_handle = thread::spawn(move || run_server(|| listener.accept(), config));
 
         Ok(Self {
             join_handle: Some(join_handle),
         })
     }
 
+    /// Like [`Self::start`], but listens on a loopback TCP port, as a remote
+    /// driver host would. `config.socket_id` is unused.
+    pub fn start_tcp(config: FakeDriverRpcConfig) -> io::Result<(Self, SocketAddr)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+
+        let join_handle = thread::spawn(move || {
+            run_server(|| listener.accept().map(|(stream, _)| stream), config)
+        });
+
+        Ok((
+            Self {
+                join_handle: Some(join_handle),
+            },
+            address,
+        ))
+    }
+
     pub fn wait(mut self) -> io::Result<()> {
         let Some(join_handle) = self.join_handle.take() else {
             return Ok(());
@@ -100,22 +143,109 @@ impl Drop for FakeDriverRpcServer {
     }
 }
 
-fn run_server(
-    listener: impl interprocess::local_socket::traits::Listener,
+fn run_server<S: Read + Write>(
+    mut accept: impl FnMut() -> io::Result<S>,
     config: FakeDriverRpcConfig,
 ) -> io::Result<()> {
+    // Index of the first action for the next connection: past the last
+    // `Hangup`, or back at the start once a connection ran the whole script.
+    let mut first_action = 0;
+
     for _ in 0..config.expected_connections {
-        let mut stream = listener.accept()?;
+        let mut stream = accept()?;
 
         let hello_req: DriverRequestEnvelope = framing::recv_msg(&mut stream)?;
-        let hello_response = build_hello_response(&config, hello_req.request_id);
+        let compression = match &hello_req.body {
+            DriverRequestBody::Hello(hello) => negotiate_compression(&hello.requested_capabilities),
+            _ => None,
+        };
+        let hello_response = build_hello_response(&config, hello_req.request_id, compression);
         framing::send_msg(&mut stream, &hello_response)?;
 
-        for action in &config.actions {
+        let start = std::mem::take(&mut first_action);
+        for (index, action) in config.actions.iter().enumerate().skip(start) {
             let request: DriverRequestEnvelope = framing::recv_msg(&mut stream)?;
 
             match action {
-                FakeDriverAction::Pong => {
+                FakeDriverAction::Hangup => {
+                    first_action = index + 1;
+                    break;
+                }
+
+                FakeDriverAction::StallMidPong(delay) => {
+                    let pong = DriverResponseEnvelope::ok(
+                        DRIVER_RPC_VERSION,
+                        request.request_id,
+                        request.session_id,
+                        DriverResponseBody::Pong,
+                    );
+                    let mut framed = Vec::new();
+                    framing::send_msg(&mut framed, &pong)?;
+
+                    stream.write_all(&framed[..framed.len() / 2])?;
+                    stream.flush()?;
+                    thread::sleep(*delay);
+
+                    first_action = index + 1;
+                    break;
+                }
+
+                FakeDriverAction::OpenSession(session_id) => {
+                    let opened = DriverResponseEnvelope::ok(
+                        DRIVER_RPC_VERSION,
+                        request.request_id,
+                        Some(*session_id),
+                        DriverResponseBody::SessionOpened {
+                            session_id: *session_id,
+                            kind: DbKind::SQLite,
+                            metadata: fake_metadata(),
+                            schema_loading_strategy: SchemaLoadingStrategy::SingleDatabase,
+                            schema_features: SchemaFeatures::empty(),
+                            code_gen_capabilities: CodeGenCapabilities::empty(),
+                        },
+                    );
+                    framing::send_msg(&mut stream, &opened)?;
+                }
+
+                FakeDriverAction::Respond(body) => {
+                    let response = DriverResponseEnvelope::ok(
+                        DRIVER_RPC_VERSION,
+                        request.request_id,
+                        request.session_id,
+                        body.clone(),
+                    );
+                    let response = match compression {
+                        Some(codec) => response.compressed(codec),
+                        None => response,
+                    };
+                    framing::send_msg(&mut stream, &response)?;
+                }
+
+                FakeDriverAction::RespondThenEvents(body, events) => {
+                    let response = DriverResponseEnvelope::ok(
+                        DRIVER_RPC_VERSION,
+                        request.request_id,
+                        request.session_id,
+                        body.clone(),
+                    );
+                    framing::send_msg(&mut stream, &response)?;
+
+                    let session_id = request.session_id.unwrap_or_default();
+                    for event in events {
+                        let frame = DriverResponseEnvelope::event(
+                            DRIVER_RPC_VERSION,
+                            session_id,
+                            event.clone(),
+                        );
+                        framing::send_msg(&mut stream, &frame)?;
+                    }
+                }
+
+                FakeDriverAction::Pong | FakeDriverAction::DelayedPong(_) => {
+                    if let FakeDriverAction::DelayedPong(delay) = action {
+                        thread::sleep(*delay);
+                    }
+
                     let pong = DriverResponseEnvelope::ok(
                         DRIVER_RPC_VERSION,
                         request.request_id,
@@ -171,19 +301,26 @@ fn run_server(
     Ok(())
 }
 
-fn build_hello_response(config: &FakeDriverRpcConfig, request_id: u64) -> DriverResponseEnvelope {
-    let metadata = DriverMetadataBuilder::new(
+fn fake_metadata() -> DriverMetadata {
+    DriverMetadataBuilder::new(
         "fake-rpc",
         "Fake RPC Driver",
         DatabaseCategory::Relational,
         QueryLanguage::Sql,
     )
-    .build();
+    .build()
+}
 
+fn build_hello_response(
+    config: &FakeDriverRpcConfig,
+    request_id: u64,
+    compression: Option<CompressionCodec>,
+) -> DriverResponseEnvelope {
     let mut capabilities = vec![DriverCapability::Cancellation];
     if config.audit_emit_capability {
         capabilities.push(DriverCapability::AuditEmit);
     }
+    capabilities.extend(compression.map(DriverCapability::Compression));
 
     let hello = DriverHelloResponse {
         server_name: "fake-rpc-host".to_string(),
@@ -191,7 +328,7 @@ fn build_hello_response(config: &FakeDriverRpcConfig, request_id: u64) -> Driver
         selected_version: negotiate_version(),
         capabilities,
         driver_kind: DbKind::SQLite,
-        driver_metadata: metadata,
+        driver_metadata: fake_metadata(),
         form_definition: DriverFormDef { tabs: vec![] },
         settings_schema: None,
     };
@@ -8,6 +8,23 @@ pub fn table_result(columns: Vec<ColumnMeta>, rows: Vec<Row>) -> QueryResult {
     QueryResult::table(columns, rows, None, Duration::ZERO)
 }
 
+/// A `rows` x `columns` text table (`c0`, `c1`, ... holding `r<row>c<col>`),
+/// for exercising large results.
+pub fn large_table_result(rows: usize, columns: usize) -> QueryResult {
+    let column_meta = (0..columns)
+        .map(|col| column(format!("c{col}"), "text", false))
+        .collect();
+    let rows = (0..rows)
+        .map(|row| {
+            (0..columns)
+                .map(|col| Value::Text(format!("r{row}c{col}")))
+                .collect()
+        })
+        .collect();
+
+    table_result(column_meta, rows)
+}
+
 pub fn json_result(rows: Vec<Row>) -> QueryResult {
     QueryResult::json(Vec::new(), rows, Duration::ZERO)
 }
@@ -77,6 +94,8 @@ pub fn relational_schema_with_table(
             is_primary_key: true,
             default_value: None,
             enum_values: None,
+            comment: None,
+            definition: None,
         }]),
         indexes: None,
         foreign_keys: None,
@@ -84,6 +103,7 @@ pub fn relational_schema_with_table(
         sample_fields: None,
         presentation: dbflux_core::CollectionPresentation::DataGrid,
         child_items: None,
+        comment: None,
     };
 
     let schema = DbSchemaInfo {
@@ -2,12 +2,16 @@
 
 pub mod containers;
 pub mod ddl_fixtures;
+pub mod driver_rpc_recording;
 pub mod fake_auth_provider_rpc;
 pub mod fake_driver;
 pub mod fake_driver_rpc;
 pub mod fixtures;
 pub mod seed;
 
+pub use driver_rpc_recording::{
+    DriverRpcRecorder, DriverRpcRecording, DriverRpcReplayServer, RecordedFrame,
+};
 pub use fake_auth_provider_rpc::{
     FakeAuthProviderRpcConfig, FakeAuthProviderRpcServer, FakeAuthRpcResult,
 };
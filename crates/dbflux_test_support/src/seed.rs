@@ -52,6 +52,14 @@ pub fn row(fields: Vec<(&str, Value)>) -> HashMap<String, Value> {
         .collect()
 }
 
+/// `(id, username, email)` rows the seeded containers load into their
+/// `users` table, index or collection.
+pub const USERS_FIXTURE: [(i64, &str, &str); 3] = [
+    (1, "alice", "alice@example.com"),
+    (2, "bob", "bob@example.com"),
+    (3, "charlie", "charlie@example.com"),
+];
+
 pub fn users_seed_data() -> SeedData {
     SeedData::new("users")
         .add_row(row(vec![
@@ -86,3 +94,19 @@ pub fn products_seed_data() -> SeedData {
             ("stock", Value::Int(25)),
         ]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn users_fixture_matches_the_users_seed_data() {
+        let seed = users_seed_data();
+
+        assert_eq!(seed.rows.len(), USERS_FIXTURE.len());
+        for (row, (_, username, email)) in seed.rows.iter().zip(USERS_FIXTURE) {
+            assert_eq!(row["username"], Value::Text(username.to_string()));
+            assert_eq!(row["email"], Value::Text(email.to_string()));
+        }
+    }
+}
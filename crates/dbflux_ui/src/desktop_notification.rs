@@ -0,0 +1,73 @@
+//! OS-level (desktop) notifications.
+//!
+//! Notifications are shown from a background thread so the UI never waits on
+//! the notification daemon. On Linux and the BSDs the notification carries a
+//! default action: clicking it sends `IpcMessage::FocusDocument` over the
+//! app-control channel, which raises the window and activates the tab the
+//! notification is about. Other platforms show the notification without a
+//! click action.
+
+use uuid::Uuid;
+
+/// Shows a desktop notification. `document_id` is focused when the
+/// notification is clicked, where the platform supports it.
+pub fn show(summary: String, body: String, document_id: Option<Uuid>) {
+    let spawned = std::thread::Builder::new()
+        .name("desktop-notification".into())
+        .spawn(move || show_blocking(&summary, &body, document_id));
+
+    if let Err(error) = spawned {
+        log::warn!("Failed to spawn desktop notification thread: {}", error);
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn show_blocking(summary: &str, body: &str, document_id: Option<Uuid>) {
+    let mut notification = notification(summary, body);
+    if document_id.is_some() {
+        notification.action("default", "Show result");
+    }
+
+    let handle = match notification.show() {
+        Ok(handle) => handle,
+        Err(error) => {
+            log::warn!("Failed to show desktop notification: {}", error);
+            return;
+        }
+    };
+
+    let Some(document_id) = document_id else {
+        return;
+    };
+
+    handle.wait_for_action(|action| {
+        if action == "default" {
+            focus_document(document_id);
+        }
+    });
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn show_blocking(summary: &str, body: &str, _document_id: Option<Uuid>) {
+    if let Err(error) = notification(summary, body).show() {
+        log::warn!("Failed to show desktop notification: {}", error);
+    }
+}
+
+fn notification(summary: &str, body: &str) -> notify_rust::Notification {
+    let mut notification = notify_rust::Notification::new();
+    notification
+        .appname(dbflux_core::ReleaseChannel::current().display_name())
+        .summary(summary)
+        .body(body);
+    notification
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn focus_document(document_id: Uuid) {
+    use dbflux_ipc::{IpcMessage, send_app_control_message};
+
+    if let Err(error) = send_app_control_message(IpcMessage::FocusDocument { document_id }) {
+        log::warn!("Failed to focus document from notification: {}", error);
+    }
+}
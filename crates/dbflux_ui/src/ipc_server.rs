@@ -12,18 +12,50 @@ use std::path::PathBuf;
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
 use std::time::Duration;
+use uuid::Uuid;
 
 pub struct IpcServer;
 
 enum IpcCommand {
-    OpenScript { path: PathBuf },
+    OpenScript {
+        path: PathBuf,
+    },
     Focus,
+    FocusDocument {
+        document_id: Uuid,
+    },
+    OpenFile {
+        path: PathBuf,
+        profile: Option<String>,
+    },
+    ListTabs {
+        reply: Sender<IpcResponse>,
+    },
+    FocusTab {
+        index: usize,
+        reply: Sender<IpcResponse>,
+    },
+    RunSavedQuery {
+        query: String,
+        reply: Sender<IpcResponse>,
+    },
+    GetActiveConnection {
+        reply: Sender<IpcResponse>,
+    },
+    ConnectProfile {
+        profile: String,
+        reply: Sender<IpcResponse>,
+    },
 }
 
+/// How long a request that needs an answer from the UI thread waits for it.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
 impl IpcServer {
     pub fn start_with_listener(
         listener: IpcListener,
         workspace: Entity<Workspace>,
+        window: AnyWindowHandle,
         auth_token: String,
         cx: &mut App,
     ) {
@@ -34,7 +66,7 @@ impl IpcServer {
         });
 
         cx.spawn(async move |cx| {
-            process_commands(cmd_rx, workspace, cx.clone()).await;
+            process_commands(cmd_rx, workspace, window, cx.clone()).await;
         })
         .detach();
     }
@@ -115,6 +147,42 @@ fn handle_connection(
                 }
             }
         }
+        IpcMessage::FocusDocument { document_id } => {
+            if cmd_tx
+                .send(IpcCommand::FocusDocument { document_id })
+                .is_ok()
+            {
+                IpcResponse::Ok
+            } else {
+                IpcResponse::Error {
+                    message: "failed to send command".into(),
+                }
+            }
+        }
+        IpcMessage::OpenFile { path, profile } => {
+            let path = path.canonicalize().unwrap_or(path);
+            if cmd_tx.send(IpcCommand::OpenFile { path, profile }).is_ok() {
+                IpcResponse::Ok
+            } else {
+                IpcResponse::Error {
+                    message: "failed to send command".into(),
+                }
+            }
+        }
+        IpcMessage::ListTabs => ask(cmd_tx, |reply| IpcCommand::ListTabs { reply }),
+        IpcMessage::FocusTab { index } => {
+            ask(cmd_tx, |reply| IpcCommand::FocusTab { index, reply })
+        }
+        IpcMessage::RunSavedQuery { query } => {
+            ask(cmd_tx, |reply| IpcCommand::RunSavedQuery { query, reply })
+        }
+        IpcMessage::GetActiveConnection => {
+            ask(cmd_tx, |reply| IpcCommand::GetActiveConnection { reply })
+        }
+        IpcMessage::ConnectProfile { profile } => ask(cmd_tx, |reply| IpcCommand::ConnectProfile {
+            profile,
+            reply,
+        }),
     };
 
     let response = AppControlResponse::ok(request_id, response_body);
@@ -123,23 +191,102 @@ fn handle_connection(
     Ok(())
 }
 
+/// Sends a command that answers through `reply` and waits for the answer.
+fn ask(
+    cmd_tx: &Sender<IpcCommand>,
+    command: impl FnOnce(Sender<IpcResponse>) -> IpcCommand,
+) -> IpcResponse {
+    let (reply_tx, reply_rx) = mpsc::channel();
+
+    if cmd_tx.send(command(reply_tx)).is_err() {
+        return IpcResponse::Error {
+            message: "failed to send command".into(),
+        };
+    }
+
+    reply_rx
+        .recv_timeout(REPLY_TIMEOUT)
+        .unwrap_or_else(|_| IpcResponse::Error {
+            message: "the app did not answer in time".into(),
+        })
+}
+
+fn result_response(result: Result<(), String>) -> IpcResponse {
+    match result {
+        Ok(()) => IpcResponse::Ok,
+        Err(message) => IpcResponse::Error { message },
+    }
+}
+
 async fn process_commands(
     cmd_rx: Receiver<IpcCommand>,
     workspace: Entity<Workspace>,
+    window: AnyWindowHandle,
     cx: AsyncApp,
 ) {
     loop {
         match cmd_rx.try_recv() {
             Ok(cmd) => {
-                let _ = cx.update(|cx| {
-                    workspace.update(cx, |ws, cx| match cmd {
-                        IpcCommand::OpenScript { path } => {
-                            ws.open_script_from_path(path, cx);
-                        }
-                        IpcCommand::Focus => {
-                            // TODO: implement window focus
-                        }
-                    });
+                let _ = cx.update(|cx| match cmd {
+                    IpcCommand::OpenScript { path } => {
+                        workspace.update(cx, |ws, cx| ws.open_script_from_path(path, cx));
+                    }
+                    IpcCommand::Focus => {
+                        let _ = window.update(cx, |_, window, _| window.activate_window());
+                    }
+                    IpcCommand::FocusDocument { document_id } => {
+                        let _ = window.update(cx, |_, window, cx| {
+                            window.activate_window();
+                            workspace.update(cx, |ws, cx| {
+                                ws.focus_document(document_id, window, cx);
+                            });
+                        });
+                    }
+                    IpcCommand::OpenFile { path, profile } => {
+                        let _ = window.update(cx, |_, window, cx| {
+                            window.activate_window();
+                            workspace.update(cx, |ws, cx| {
+                                ws.open_file_from_cli(path, profile, window, cx);
+                            });
+                        });
+                    }
+                    IpcCommand::ListTabs { reply } => {
+                        let tabs = workspace.read(cx).tab_summaries(cx);
+                        let _ = reply.send(IpcResponse::Tabs { tabs });
+                    }
+                    IpcCommand::FocusTab { index, reply } => {
+                        let result = window
+                            .update(cx, |_, window, cx| {
+                                window.activate_window();
+                                workspace.update(cx, |ws, cx| ws.focus_tab_at(index, window, cx))
+                            })
+                            .unwrap_or_else(|e| Err(e.to_string()));
+                        let _ = reply.send(result_response(result));
+                    }
+                    IpcCommand::RunSavedQuery { query, reply } => {
+                        let result = window
+                            .update(cx, |_, window, cx| {
+                                workspace.update(cx, |ws, cx| {
+                                    ws.run_saved_query_by_key(&query, window, cx)
+                                })
+                            })
+                            .unwrap_or_else(|e| Err(e.to_string()));
+                        let _ = reply.send(result_response(result));
+                    }
+                    IpcCommand::GetActiveConnection { reply } => {
+                        let connection = workspace.read(cx).active_connection(cx);
+                        let _ = reply.send(IpcResponse::ActiveConnection { connection });
+                    }
+                    IpcCommand::ConnectProfile { profile, reply } => {
+                        let result = window
+                            .update(cx, |_, window, cx| {
+                                window.activate_window();
+                                workspace
+                                    .update(cx, |ws, cx| ws.connect_profile_by_key(&profile, cx))
+                            })
+                            .unwrap_or_else(|e| Err(e.to_string()));
+                        let _ = reply.send(result_response(result));
+                    }
                 });
             }
             Err(mpsc::TryRecvError::Empty) => {
@@ -11,7 +11,8 @@ mod dispatcher;
 
 // Re-export pure keymap types from dbflux_app
 pub use dbflux_app::keymap::{
-    Command, ContextId, FocusTarget, KeyChord, KeymapLayer, KeymapStack, Modifiers,
+    Command, ContextId, FocusTarget, KeyChord, KeymapLayer, KeymapStack, Modifiers, SequenceMatch,
+    format_sequence,
 };
 
 #[allow(unused_imports)]
@@ -4,11 +4,12 @@
 //! This crate contains all GPUI-dependent code:
 //! - UI components, views, overlays, and windows
 //! - The `AppStateEntity` wrapper
-//! - IPC server and platform utilities
+//! - IPC server, desktop notifications and platform utilities
 //! - Keymap actions and dispatcher
 
 pub mod app;
 pub mod assets;
+pub mod desktop_notification;
 pub mod ipc_server;
 pub mod keymap;
 pub mod ui;
@@ -16,6 +17,8 @@ pub mod ui;
 // Re-exports for external consumers that previously used dbflux_ui::{platform, ui::theme}
 pub use dbflux_components::theme;
 pub use dbflux_ui_base::platform;
+pub use dbflux_ui_base::rpc_driver_watch;
+pub use dbflux_ui_base::user_themes;
 
 // Re-exports for convenience
 #[cfg(feature = "mcp")]
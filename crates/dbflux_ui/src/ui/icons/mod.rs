@@ -6,6 +6,7 @@ pub const ALL_ICONS: &[AppIcon] = &[
     AppIcon::ChevronRight,
     AppIcon::ChevronUp,
     AppIcon::Play,
+    AppIcon::Pause,
     AppIcon::SquarePlay,
     AppIcon::Plus,
     AppIcon::Power,
@@ -31,6 +32,7 @@ pub const ALL_ICONS: &[AppIcon] = &[
     AppIcon::CircleCheck,
     AppIcon::CircleX,
     AppIcon::TriangleAlert,
+    AppIcon::Bell,
     AppIcon::ExternalLink,
     AppIcon::Globe,
     AppIcon::Code,
@@ -44,11 +46,14 @@ pub const ALL_ICONS: &[AppIcon] = &[
     AppIcon::Zap,
     AppIcon::Hash,
     AppIcon::Lock,
+    AppIcon::Pin,
     AppIcon::Layers,
     AppIcon::Keyboard,
     AppIcon::FingerprintPattern,
     AppIcon::Maximize2,
     AppIcon::Minimize2,
+    AppIcon::ZoomIn,
+    AppIcon::ZoomOut,
     AppIcon::PanelBottomClose,
     AppIcon::PanelBottomOpen,
     AppIcon::FileSpreadsheet,
@@ -112,6 +117,7 @@ pub(crate) fn embedded_bytes(icon: AppIcon) -> &'static [u8] {
         }
         AppIcon::ChevronUp => include_bytes!("../../../../../resources/icons/ui/chevron-up.svg"),
         AppIcon::Play => include_bytes!("../../../../../resources/icons/ui/play.svg"),
+        AppIcon::Pause => include_bytes!("../../../../../resources/icons/ui/pause.svg"),
         AppIcon::SquarePlay => include_bytes!("../../../../../resources/icons/ui/square-play.svg"),
         AppIcon::Plus => include_bytes!("../../../../../resources/icons/ui/plus.svg"),
         AppIcon::Power => include_bytes!("../../../../../resources/icons/ui/power.svg"),
@@ -147,6 +153,7 @@ pub(crate) fn embedded_bytes(icon: AppIcon) -> &'static [u8] {
         AppIcon::TriangleAlert => {
             include_bytes!("../../../../../resources/icons/ui/triangle-alert.svg")
         }
+        AppIcon::Bell => include_bytes!("../../../../../resources/icons/ui/bell.svg"),
         AppIcon::Code => include_bytes!("../../../../../resources/icons/ui/code.svg"),
         AppIcon::Table => include_bytes!("../../../../../resources/icons/ui/table.svg"),
         AppIcon::Columns => include_bytes!("../../../../../resources/icons/ui/columns.svg"),
@@ -158,6 +165,7 @@ pub(crate) fn embedded_bytes(icon: AppIcon) -> &'static [u8] {
         AppIcon::Zap => include_bytes!("../../../../../resources/icons/ui/zap.svg"),
         AppIcon::Hash => include_bytes!("../../../../../resources/icons/ui/hash.svg"),
         AppIcon::Lock => include_bytes!("../../../../../resources/icons/ui/lock.svg"),
+        AppIcon::Pin => include_bytes!("../../../../../resources/icons/ui/pin.svg"),
         AppIcon::Layers => include_bytes!("../../../../../resources/icons/ui/layers.svg"),
         AppIcon::Keyboard => include_bytes!("../../../../../resources/icons/ui/keyboard.svg"),
         AppIcon::FingerprintPattern => {
@@ -165,6 +173,8 @@ pub(crate) fn embedded_bytes(icon: AppIcon) -> &'static [u8] {
         }
         AppIcon::Maximize2 => include_bytes!("../../../../../resources/icons/ui/maximize-2.svg"),
         AppIcon::Minimize2 => include_bytes!("../../../../../resources/icons/ui/minimize-2.svg"),
+        AppIcon::ZoomIn => include_bytes!("../../../../../resources/icons/ui/zoom-in.svg"),
+        AppIcon::ZoomOut => include_bytes!("../../../../../resources/icons/ui/zoom-out.svg"),
         AppIcon::PanelBottomClose => {
             include_bytes!("../../../../../resources/icons/ui/panel-bottom-close.svg")
         }
@@ -7,7 +7,8 @@ use dbflux_components::primitives::{Chord, Icon, overlay_bg, surface_modal_conta
 use dbflux_components::semantic::BannerColors as SemBannerColors;
 use dbflux_components::tokens::{Radii, Spacing};
 use dbflux_components::typography::{Body, MonoCaption, MonoLabel};
-use dbflux_core::{CollectionRef, TableRef};
+use dbflux_core::i18n;
+use dbflux_core::{CollectionRef, SavedQuery, SavedQuerySearch, TableRef};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use gpui::prelude::FluentBuilder;
@@ -16,7 +17,10 @@ use gpui_component::{ActiveTheme, Sizable};
 use std::path::PathBuf;
 use uuid::Uuid;
 
-actions!(command_palette, [SelectNext, SelectPrev, Close, Execute]);
+actions!(
+    command_palette,
+    [SelectNext, SelectPrev, Close, Execute, ViewDefinition]
+);
 
 pub fn command_palette_keybindings() -> Vec<KeyBinding> {
     let ctx = Some(ContextId::CommandPalette.as_gpui_context());
@@ -27,6 +31,7 @@ pub fn command_palette_keybindings() -> Vec<KeyBinding> {
         KeyBinding::new("ctrl-j", SelectNext, ctx),
         KeyBinding::new("escape", Close, ctx),
         KeyBinding::new("enter", Execute, ctx),
+        KeyBinding::new("alt-enter", ViewDefinition, ctx),
     ]
 }
 
@@ -37,13 +42,24 @@ pub enum PaletteItem {
         id: &'static str,
         name: &'static str,
         category: &'static str,
-        shortcut: Option<&'static str>,
+        /// Bound keys in the kebab-case form `palette_shortcut_parts` reads,
+        /// chords of a sequence separated by spaces.
+        shortcut: Option<SharedString>,
     },
     Connection {
         profile_id: Uuid,
         name: String,
         is_connected: bool,
     },
+    /// A profile, or one of its databases, the focused document can run
+    /// against. Listed by "Switch Connection or Database...".
+    ExecutionTarget {
+        profile_id: Uuid,
+        profile_name: String,
+        /// `None` targets the profile's default database.
+        database: Option<String>,
+        is_connected: bool,
+    },
     Resource(ResourceItem),
     Script {
         /// Absolute filesystem path (used to open the script).
@@ -67,6 +83,16 @@ pub enum PaletteItem {
     /// "Import Dashboard from JSON" action (shown only when the active connection
     /// has the `DASHBOARD_IMPORT` capability).
     ImportDashboard,
+    /// A saved SQL query surfaced by the "Find Saved Query..." command.
+    SavedQuery {
+        query: SavedQuery,
+        /// `Parent / Child` path of the query's folder.
+        folder_path: Option<String>,
+        /// Name of the query's connection profile, if it still exists.
+        connection_name: Option<String>,
+        /// Names the connection's driver is known by, for `driver:` filters.
+        driver_names: Vec<String>,
+    },
 }
 
 /// Schema resource variants surfaced by connected profiles.
@@ -97,14 +123,45 @@ pub enum ResourceItem {
         profile_name: String,
         database: String,
     },
+    /// A function or procedure from a schema's cached routine list.
+    Routine {
+        profile_id: Uuid,
+        profile_name: String,
+        database: Option<String>,
+        schema: String,
+        name: String,
+        specific_name: String,
+    },
 }
 
 impl PaletteItem {
     /// Text searched by `SkimMatcherV2`.
     pub fn search_text(&self) -> String {
         match self {
-            Self::Action { category, name, .. } => format!("{} {}", category, name),
+            Self::Action {
+                id, category, name, ..
+            } => {
+                let translated_category = command_category_label(category);
+                let translated_name = command_label(id, name);
+                if translated_name == *name && translated_category == *category {
+                    format!("{} {}", category, name)
+                } else {
+                    // Keep the English words searchable in every language.
+                    format!(
+                        "{} {} {} {}",
+                        translated_category, translated_name, category, name
+                    )
+                }
+            }
             Self::Connection { name, .. } => format!("Connection {}", name),
+            Self::ExecutionTarget {
+                profile_name,
+                database,
+                ..
+            } => match database {
+                Some(database) => format!("Database {} {}", profile_name, database),
+                None => format!("Connection {}", profile_name),
+            },
             Self::SavedChart {
                 name, profile_name, ..
             } => format!("Chart {} {}", name, profile_name),
@@ -152,6 +209,12 @@ impl PaletteItem {
                     database,
                     ..
                 } => format!("Keyspace {} {}", profile_name, database),
+                ResourceItem::Routine {
+                    profile_name,
+                    schema,
+                    name,
+                    ..
+                } => format!("Routine {} {} {}", profile_name, name, schema),
             },
             Self::Script {
                 name,
@@ -161,14 +224,39 @@ impl PaletteItem {
                 format!("Script {} {}", name, relative_path)
             }
             Self::ImportDashboard => "Charts Import Dashboard from JSON".to_string(),
+            Self::SavedQuery {
+                query,
+                folder_path,
+                connection_name,
+                ..
+            } => {
+                let mut parts = format!("Query {}", query.name);
+                for part in [folder_path, connection_name].into_iter().flatten() {
+                    parts.push_str(&format!(" {}", part));
+                }
+                parts
+            }
         }
     }
 
     /// Returns `(category_label, display_name)`.
     pub fn display_label(&self) -> (String, String) {
         match self {
-            Self::Action { category, name, .. } => (category.to_string(), name.to_string()),
+            Self::Action {
+                id, category, name, ..
+            } => (
+                command_category_label(category).to_string(),
+                command_label(id, name).to_string(),
+            ),
             Self::Connection { name, .. } => ("Connection".to_string(), name.clone()),
+            Self::ExecutionTarget {
+                profile_name,
+                database,
+                ..
+            } => match database {
+                Some(database) => ("Database".to_string(), database.clone()),
+                None => ("Connection".to_string(), profile_name.clone()),
+            },
             Self::SavedChart {
                 name,
                 is_collection_source,
@@ -188,12 +276,14 @@ impl PaletteItem {
                 ResourceItem::KeyValueDb { database, .. } => {
                     ("Keyspace".to_string(), database.clone())
                 }
+                ResourceItem::Routine { name, .. } => ("Routine".to_string(), name.clone()),
             },
             Self::Script { name, .. } => ("Script".to_string(), name.clone()),
             Self::ImportDashboard => (
                 "Charts".to_string(),
                 "Import Dashboard from JSON...".to_string(),
             ),
+            Self::SavedQuery { query, .. } => ("Query".to_string(), query.name.clone()),
         }
     }
 
@@ -201,19 +291,29 @@ impl PaletteItem {
     pub fn type_priority(&self) -> u8 {
         match self {
             Self::Action { .. } => 0,
-            Self::Connection { .. } => 1,
+            Self::Connection { .. } | Self::ExecutionTarget { .. } => 1,
             Self::SavedChart { .. } => 2,
             Self::ImportDashboard => 2,
             Self::Resource(_) => 3,
-            Self::Script { .. } => 4,
+            Self::Script { .. } | Self::SavedQuery { .. } => 4,
         }
     }
 
     /// Optional qualifier text shown after the item name.
     pub fn qualifier(&self) -> Option<String> {
         match self {
-            Self::Action { shortcut, .. } => shortcut.map(|s| s.to_string()),
+            Self::Action { shortcut, .. } => shortcut.as_ref().map(|s| s.to_string()),
             Self::SavedChart { profile_name, .. } => Some(profile_name.clone()),
+            Self::ExecutionTarget {
+                profile_name,
+                database,
+                is_connected,
+                ..
+            } => match database {
+                Some(_) => Some(profile_name.clone()),
+                None if !is_connected => Some("not connected".to_string()),
+                None => None,
+            },
             Self::Resource(r) => match r {
                 ResourceItem::Table {
                     profile_name,
@@ -242,6 +342,19 @@ impl PaletteItem {
                     ..
                 } => Some(format!("{} / {}", profile_name, database)),
                 ResourceItem::KeyValueDb { profile_name, .. } => Some(profile_name.clone()),
+                ResourceItem::Routine {
+                    profile_name,
+                    database,
+                    schema,
+                    ..
+                } => {
+                    let mut parts = profile_name.clone();
+                    if let Some(db) = database {
+                        parts.push_str(&format!(" / {}", db));
+                    }
+                    parts.push_str(&format!(" / {}", schema));
+                    Some(parts)
+                }
             },
             Self::Script { relative_path, .. } => {
                 if relative_path.contains('/') {
@@ -250,11 +363,40 @@ impl PaletteItem {
                     None
                 }
             }
+            Self::SavedQuery {
+                query,
+                folder_path,
+                connection_name,
+                ..
+            } => {
+                let tags = query.tags.iter().map(|tag| format!("#{}", tag));
+                let parts: Vec<String> = folder_path
+                    .iter()
+                    .cloned()
+                    .chain(tags)
+                    .chain(connection_name.iter().cloned())
+                    .collect();
+
+                (!parts.is_empty()).then(|| parts.join("  "))
+            }
             _ => None,
         }
     }
 }
 
+/// The palette name of command `id` in the active language; `name` is the
+/// English one.
+fn command_label(id: &str, name: &'static str) -> &'static str {
+    i18n::tr_or(&format!("command-{}", id.replace('_', "-")), name)
+}
+
+fn command_category_label(category: &'static str) -> &'static str {
+    i18n::tr_or(
+        &format!("command-category-{}", category.to_lowercase()),
+        category,
+    )
+}
+
 /// Legacy static command descriptor kept for `default_commands()` backwards compat.
 #[derive(Clone)]
 pub struct PaletteCommand {
@@ -286,7 +428,7 @@ impl From<PaletteCommand> for PaletteItem {
             id: cmd.id,
             name: cmd.name,
             category: cmd.category,
-            shortcut: cmd.shortcut,
+            shortcut: cmd.shortcut.map(SharedString::from),
         }
     }
 }
@@ -305,31 +447,38 @@ const VISIBLE_ITEMS: usize = 8;
 /// themselves are not selectable — they are a render-only concern.
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum PaletteSection {
+    Recent,
     Connections,
     Commands,
     Charts,
     Tables,
     Scripts,
+    SavedQueries,
 }
 
 impl PaletteSection {
     fn label(self) -> &'static str {
         match self {
-            Self::Connections => "Connections",
-            Self::Commands => "Commands",
-            Self::Charts => "Charts",
-            Self::Tables => "Tables",
-            Self::Scripts => "Scripts",
+            Self::Recent => i18n::tr("palette-section-recent"),
+            Self::Connections => i18n::tr("palette-section-connections"),
+            Self::Commands => i18n::tr("palette-section-commands"),
+            Self::Charts => i18n::tr("palette-section-charts"),
+            Self::Tables => i18n::tr("palette-section-tables"),
+            Self::Scripts => i18n::tr("palette-section-scripts"),
+            Self::SavedQueries => i18n::tr("palette-section-saved-queries"),
         }
     }
 
     fn for_item(item: &PaletteItem) -> Self {
         match item {
-            PaletteItem::Connection { .. } => Self::Connections,
+            PaletteItem::Connection { .. } | PaletteItem::ExecutionTarget { .. } => {
+                Self::Connections
+            }
             PaletteItem::Action { .. } => Self::Commands,
             PaletteItem::SavedChart { .. } | PaletteItem::ImportDashboard => Self::Charts,
             PaletteItem::Resource(_) => Self::Tables,
             PaletteItem::Script { .. } => Self::Scripts,
+            PaletteItem::SavedQuery { .. } => Self::SavedQueries,
         }
     }
 
@@ -337,11 +486,13 @@ impl PaletteSection {
     /// keyboard navigation walks the list in the same order the user sees it.
     fn sort_order(self) -> u8 {
         match self {
-            Self::Connections => 0,
-            Self::Commands => 1,
-            Self::Charts => 2,
-            Self::Tables => 3,
-            Self::Scripts => 4,
+            Self::Recent => 0,
+            Self::Connections => 1,
+            Self::Commands => 2,
+            Self::Charts => 3,
+            Self::Tables => 4,
+            Self::Scripts => 5,
+            Self::SavedQueries => 6,
         }
     }
 }
@@ -375,9 +526,10 @@ fn palette_item_name(
     };
 
     match item {
-        PaletteItem::Resource(_) | PaletteItem::Script { .. } | PaletteItem::SavedChart { .. } => {
-            MonoLabel::new(name).color(color).into_any_element()
-        }
+        PaletteItem::Resource(_)
+        | PaletteItem::Script { .. }
+        | PaletteItem::SavedChart { .. }
+        | PaletteItem::SavedQuery { .. } => MonoLabel::new(name).color(color).into_any_element(),
         _ => Body::new(name).color(color).into_any_element(),
     }
 }
@@ -462,6 +614,14 @@ pub struct CommandPalette {
     scroll_offset: usize,
     input_state: Entity<InputState>,
     matcher: SkimMatcherV2,
+    /// Quick open over schema objects: the selected hit also offers
+    /// "View DDL" and "Insert name".
+    quick_open: bool,
+    /// Ids of the commands last run from the palette, most recent first.
+    recent_commands: Vec<String>,
+    /// Whether the search input has text; recent commands only get their
+    /// own section while it is empty.
+    searching: bool,
 }
 
 /// Event emitted when the user selects a palette item.
@@ -488,6 +648,12 @@ pub enum PaletteSelection {
     FocusConnection {
         profile_id: Uuid,
     },
+    /// Run the focused document against this profile and database,
+    /// connecting first when needed.
+    SwitchExecutionContext {
+        profile_id: Uuid,
+        database: Option<String>,
+    },
     OpenScript {
         path: PathBuf,
     },
@@ -496,16 +662,26 @@ pub enum PaletteSelection {
     },
     /// The user selected the "Import Dashboard from JSON" entry.
     ImportDashboard,
+    OpenSavedQuery {
+        query_id: Uuid,
+    },
+    OpenRoutine {
+        profile_id: Uuid,
+        schema: String,
+        specific_name: String,
+    },
+    /// Quick open: show the DDL of a schema object.
+    ViewDefinition(ResourceItem),
+    /// Quick open: insert a schema object's name into the active editor.
+    InsertName(ResourceItem),
 }
 
 pub struct CommandPaletteClosed;
 
 impl CommandPalette {
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
-        let input_state = cx.new(|cx| {
-            InputState::new(window, cx)
-                .placeholder("Search commands, connections, tables, scripts...")
-        });
+        let input_state =
+            cx.new(|cx| InputState::new(window, cx).placeholder(i18n::tr("palette-placeholder")));
 
         cx.subscribe_in(
             &input_state,
@@ -515,6 +691,9 @@ impl CommandPalette {
                     let query = this.input_state.read(cx).value().to_string();
                     this.update_filter(&query, cx);
                 }
+                InputEvent::PressEnter { secondary: true } if this.quick_open => {
+                    this.emit_object_action(PaletteSelection::InsertName, cx);
+                }
                 InputEvent::PressEnter { .. } => {
                     this.execute_selected(window, cx);
                 }
@@ -531,6 +710,9 @@ impl CommandPalette {
             scroll_offset: 0,
             input_state,
             matcher: SkimMatcherV2::default(),
+            quick_open: false,
+            recent_commands: Vec::new(),
+            searching: false,
         }
     }
 
@@ -550,8 +732,11 @@ impl CommandPalette {
             .collect();
 
         self.visible = true;
+        self.quick_open = false;
+        self.searching = false;
         self.selected_index = 0;
         self.scroll_offset = 0;
+        self.sort_filtered_by_section();
 
         self.input_state.update(cx, |state, cx| {
             state.set_value("", window, cx);
@@ -561,6 +746,23 @@ impl CommandPalette {
         cx.notify();
     }
 
+    /// Opens the palette over schema objects only, with per-hit actions.
+    pub fn open_quick_open(
+        &mut self,
+        items: Vec<PaletteItem>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_with_items(items, window, cx);
+        self.quick_open = true;
+    }
+
+    /// Commands last run from the palette, most recent first. They are
+    /// listed first the next time the palette opens.
+    pub fn set_recent_commands(&mut self, ids: Vec<String>) {
+        self.recent_commands = ids;
+    }
+
     pub fn register_commands(&mut self, _commands: Vec<PaletteCommand>) {
         // No-op; items are now set via open_with_items.
         // Kept to avoid breaking the call site during migration.
@@ -576,6 +778,7 @@ impl CommandPalette {
             });
             self.selected_index = 0;
             self.scroll_offset = 0;
+            self.searching = false;
             self.filtered = self
                 .items
                 .iter()
@@ -600,6 +803,8 @@ impl CommandPalette {
     }
 
     fn update_filter(&mut self, query: &str, cx: &mut Context<Self>) {
+        self.searching = !query.is_empty();
+
         if query.is_empty() {
             self.filtered = self
                 .items
@@ -608,12 +813,36 @@ impl CommandPalette {
                 .map(|(index, _)| FilteredItem { index, score: 0 })
                 .collect();
         } else {
+            // Saved queries understand `#tag` and `driver:<name>` filters; the
+            // rest of the input is fuzzy-matched as usual.
+            let saved_search = SavedQuerySearch::parse(query);
+
             self.filtered = self
                 .items
                 .iter()
                 .enumerate()
                 .filter_map(|(index, item)| {
                     let search_text = item.search_text();
+
+                    if let PaletteItem::SavedQuery {
+                        query: saved,
+                        driver_names,
+                        ..
+                    } = item
+                    {
+                        let names: Vec<&str> = driver_names.iter().map(String::as_str).collect();
+                        if !saved_search.matches_filters(saved, &names) {
+                            return None;
+                        }
+                        if saved_search.text.is_empty() {
+                            return Some(FilteredItem { index, score: 0 });
+                        }
+                        return self
+                            .matcher
+                            .fuzzy_match(&search_text, &saved_search.text)
+                            .map(|score| FilteredItem { index, score });
+                    }
+
                     self.matcher
                         .fuzzy_match(&search_text, query)
                         .map(|score| FilteredItem { index, score })
@@ -630,22 +859,46 @@ impl CommandPalette {
 
     /// Sort `self.filtered` so its index order matches the visual section
     /// order produced by the renderer. Within a section, items are ordered by
-    /// fuzzy-match score (desc) and then by `type_priority` as a tiebreaker.
-    /// Keeping these in sync ensures up/down keyboard navigation walks the
-    /// list in the order the user sees it instead of jumping across sections.
+    /// fuzzy-match score (desc), then by how recently they were run and by
+    /// `type_priority` as tiebreakers. Keeping these in sync ensures up/down
+    /// keyboard navigation walks the list in the order the user sees it
+    /// instead of jumping across sections.
     fn sort_filtered_by_section(&mut self) {
         self.filtered.sort_by(|a, b| {
             let item_a = &self.items[a.index];
             let item_b = &self.items[b.index];
-            let sec_a = PaletteSection::for_item(item_a).sort_order();
-            let sec_b = PaletteSection::for_item(item_b).sort_order();
+            let sec_a = self.section_for(item_a).sort_order();
+            let sec_b = self.section_for(item_b).sort_order();
             sec_a
                 .cmp(&sec_b)
                 .then_with(|| b.score.cmp(&a.score))
+                .then_with(|| {
+                    let rank_a = self.recent_rank(item_a).unwrap_or(usize::MAX);
+                    let rank_b = self.recent_rank(item_b).unwrap_or(usize::MAX);
+                    rank_a.cmp(&rank_b)
+                })
                 .then_with(|| item_a.type_priority().cmp(&item_b.type_priority()))
         });
     }
 
+    fn section_for(&self, item: &PaletteItem) -> PaletteSection {
+        if !self.searching && self.recent_rank(item).is_some() {
+            return PaletteSection::Recent;
+        }
+
+        PaletteSection::for_item(item)
+    }
+
+    /// Position of a command among the recently run ones, most recent first.
+    fn recent_rank(&self, item: &PaletteItem) -> Option<usize> {
+        match item {
+            PaletteItem::Action { id, .. } => {
+                self.recent_commands.iter().position(|recent| recent == id)
+            }
+            _ => None,
+        }
+    }
+
     pub fn select_next(&mut self, cx: &mut Context<Self>) {
         if !self.filtered.is_empty() {
             self.selected_index = (self.selected_index + 1) % self.filtered.len();
@@ -712,6 +965,14 @@ impl CommandPalette {
                         }
                     }
                 }
+                PaletteItem::ExecutionTarget {
+                    profile_id,
+                    database,
+                    ..
+                } => PaletteSelection::SwitchExecutionContext {
+                    profile_id: *profile_id,
+                    database: database.clone(),
+                },
                 PaletteItem::Resource(r) => match r {
                     ResourceItem::Table {
                         profile_id,
@@ -754,6 +1015,16 @@ impl CommandPalette {
                         profile_id: *profile_id,
                         database: database.clone(),
                     },
+                    ResourceItem::Routine {
+                        profile_id,
+                        schema,
+                        specific_name,
+                        ..
+                    } => PaletteSelection::OpenRoutine {
+                        profile_id: *profile_id,
+                        schema: schema.clone(),
+                        specific_name: specific_name.clone(),
+                    },
                 },
                 PaletteItem::Script { path, .. } => {
                     PaletteSelection::OpenScript { path: path.clone() }
@@ -762,6 +1033,9 @@ impl CommandPalette {
                     PaletteSelection::OpenSavedChart { chart_id: *id }
                 }
                 PaletteItem::ImportDashboard => PaletteSelection::ImportDashboard,
+                PaletteItem::SavedQuery { query, .. } => {
+                    PaletteSelection::OpenSavedQuery { query_id: query.id }
+                }
             };
 
             self.visible = false;
@@ -770,6 +1044,26 @@ impl CommandPalette {
         }
     }
 
+    /// Emits `action` for the selected schema object, if any.
+    fn emit_object_action(
+        &mut self,
+        action: fn(ResourceItem) -> PaletteSelection,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(PaletteItem::Resource(resource)) = self
+            .filtered
+            .get(self.selected_index)
+            .and_then(|filtered| self.items.get(filtered.index))
+        else {
+            return;
+        };
+
+        let selection = action(resource.clone());
+        self.visible = false;
+        cx.emit(selection);
+        cx.notify();
+    }
+
     fn render_palette_item(
         idx: usize,
         item: &PaletteItem,
@@ -783,14 +1077,23 @@ impl CommandPalette {
         // connections use their qualifier text. Selected rows additionally
         // surface an `Enter` glyph to reinforce the run affordance.
         let right_el: Option<AnyElement> = match item {
-            PaletteItem::Action { shortcut, .. } => shortcut.map(|s| {
-                let parts = palette_shortcut_parts(s);
-                Chord::new(parts).into_any_element()
+            PaletteItem::Action { shortcut, .. } => shortcut.as_ref().map(|s| {
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::XS)
+                    .children(
+                        s.split(' ')
+                            .map(|chord| Chord::new(palette_shortcut_parts(chord))),
+                    )
+                    .into_any_element()
             }),
             PaletteItem::Connection { .. }
+            | PaletteItem::ExecutionTarget { .. }
             | PaletteItem::Resource(_)
             | PaletteItem::Script { .. }
             | PaletteItem::SavedChart { .. }
+            | PaletteItem::SavedQuery { .. }
             | PaletteItem::ImportDashboard => item
                 .qualifier()
                 .map(|q| palette_qualifier_text(q, is_selected, theme).into_any_element()),
@@ -862,18 +1165,20 @@ impl Render for CommandPalette {
             .collect();
 
         let section_order = [
+            PaletteSection::Recent,
             PaletteSection::Connections,
             PaletteSection::Commands,
             PaletteSection::Charts,
             PaletteSection::Tables,
             PaletteSection::Scripts,
+            PaletteSection::SavedQueries,
         ];
 
         let mut rows: Vec<PaletteRow> = Vec::with_capacity(windowed.len() + section_order.len());
         for section in section_order {
             let mut header_pushed = false;
             for (display_idx, item) in windowed.iter() {
-                if PaletteSection::for_item(item) != section {
+                if self.section_for(item) != section {
                     continue;
                 }
                 if !header_pushed {
@@ -894,6 +1199,7 @@ impl Render for CommandPalette {
 
         let total_count = self.items.len();
         let filtered_count = self.filtered.len();
+        let quick_open = self.quick_open;
 
         div()
             .id("command-palette-overlay")
@@ -922,6 +1228,11 @@ impl Render for CommandPalette {
             .on_action(cx.listener(|this, _: &Execute, window, cx| {
                 this.execute_selected(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ViewDefinition, _window, cx| {
+                if this.quick_open {
+                    this.emit_object_action(PaletteSelection::ViewDefinition, cx);
+                }
+            }))
             .child(
                 surface_modal_container(cx)
                     .id("command-palette-container")
@@ -1018,7 +1329,7 @@ impl Render for CommandPalette {
                                         .py(Spacing::LG)
                                         .flex()
                                         .justify_center()
-                                        .child(Body::new("No matching items").muted(cx)),
+                                        .child(Body::new(i18n::tr("palette-no-matches")).muted(cx)),
                                 )
                             }),
                     )
@@ -1056,8 +1367,12 @@ impl Render for CommandPalette {
                                             .gap(Spacing::XS)
                                             .child(Chord::new(vec![SharedString::from("\u{21B5}")]))
                                             .child(
-                                                MonoCaption::new("run")
-                                                    .color(theme.muted_foreground),
+                                                MonoCaption::new(if quick_open {
+                                                    "open"
+                                                } else {
+                                                    "run"
+                                                })
+                                                .color(theme.muted_foreground),
                                             ),
                                     )
                                     // Aspirational: "open in new tab" has no
@@ -1065,21 +1380,71 @@ impl Render for CommandPalette {
                                     // yet. Rendered at half opacity to flag
                                     // it as a forthcoming affordance rather
                                     // than a live shortcut.
-                                    .child(
-                                        div()
-                                            .flex()
-                                            .items_center()
-                                            .gap(Spacing::XS)
-                                            .opacity(0.5)
-                                            .child(Chord::new(vec![
-                                                SharedString::from("\u{21E5}"),
-                                                SharedString::from("\u{21B5}"),
-                                            ]))
-                                            .child(
-                                                MonoCaption::new("open in new tab")
-                                                    .color(theme.muted_foreground),
-                                            ),
-                                    ),
+                                    .when(!quick_open, |d| {
+                                        d.child(
+                                            div()
+                                                .flex()
+                                                .items_center()
+                                                .gap(Spacing::XS)
+                                                .opacity(0.5)
+                                                .child(Chord::new(vec![
+                                                    SharedString::from("\u{21E5}"),
+                                                    SharedString::from("\u{21B5}"),
+                                                ]))
+                                                .child(
+                                                    MonoCaption::new("open in new tab")
+                                                        .color(theme.muted_foreground),
+                                                ),
+                                        )
+                                    })
+                                    // Quick open: the per-hit actions, also
+                                    // clickable for the selected hit.
+                                    .when(quick_open, |d| {
+                                        d.child(
+                                            div()
+                                                .id("quick-open-view-ddl")
+                                                .flex()
+                                                .items_center()
+                                                .gap(Spacing::XS)
+                                                .cursor_pointer()
+                                                .child(Chord::new(vec![
+                                                    SharedString::from("Alt"),
+                                                    SharedString::from("\u{21B5}"),
+                                                ]))
+                                                .child(
+                                                    MonoCaption::new("view DDL")
+                                                        .color(theme.muted_foreground),
+                                                )
+                                                .on_click(cx.listener(|this, _, _, cx| {
+                                                    this.emit_object_action(
+                                                        PaletteSelection::ViewDefinition,
+                                                        cx,
+                                                    );
+                                                })),
+                                        )
+                                        .child(
+                                            div()
+                                                .id("quick-open-insert-name")
+                                                .flex()
+                                                .items_center()
+                                                .gap(Spacing::XS)
+                                                .cursor_pointer()
+                                                .child(Chord::new(vec![
+                                                    SharedString::from("Ctrl"),
+                                                    SharedString::from("\u{21B5}"),
+                                                ]))
+                                                .child(
+                                                    MonoCaption::new("insert name")
+                                                        .color(theme.muted_foreground),
+                                                )
+                                                .on_click(cx.listener(|this, _, _, cx| {
+                                                    this.emit_object_action(
+                                                        PaletteSelection::InsertName,
+                                                        cx,
+                                                    );
+                                                })),
+                                        )
+                                    }),
                             ),
                     ),
             )
@@ -1257,6 +1622,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn every_palette_command_has_an_english_message() {
+        use super::super::super::views::workspace::Workspace;
+        use super::{command_category_label, command_label};
+
+        // Tests run in English: a missing message falls back to the name
+        // itself, so compare against a sentinel lookup instead.
+        for cmd in Workspace::palette_commands_for_test() {
+            let key = format!("command-{}", cmd.id.replace('_', "-"));
+            assert_eq!(
+                dbflux_core::i18n::tr_or(&key, "<missing>"),
+                cmd.name,
+                "en.ftl `{key}` must match the command name"
+            );
+            assert_eq!(command_label(cmd.id, cmd.name), cmd.name);
+
+            let category_key = format!("command-category-{}", cmd.category.to_lowercase());
+            assert_eq!(
+                dbflux_core::i18n::tr_or(&category_key, "<missing>"),
+                cmd.category,
+                "en.ftl `{category_key}` must match the category"
+            );
+            assert_eq!(command_category_label(cmd.category), cmd.category);
+        }
+    }
+
     #[test]
     fn command_palette_includes_new_dashboard_entry() {
         use super::super::super::views::workspace::Workspace;
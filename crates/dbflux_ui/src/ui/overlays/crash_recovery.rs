@@ -0,0 +1,307 @@
+//! Crash recovery prompt: after a run that did not shut down cleanly, lists
+//! the documents whose autosaved buffer differs from the saved file and shows
+//! what restoring each one brings back.
+
+use dbflux_components::controls::Button;
+use dbflux_components::modals::shell::ModalShell;
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{FontSizes, Radii, Spacing};
+use dbflux_core::{DiffLine, diff_counts, line_diff};
+use dbflux_storage::repositories::state::sessions::RestoredTab;
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use std::path::PathBuf;
+
+/// Diff lines rendered in the preview; the rest is summarized.
+const MAX_PREVIEW_LINES: usize = 500;
+
+/// An unsaved document left behind by the previous run.
+#[derive(Clone, Debug)]
+pub struct RecoveredDocument {
+    pub tab: RestoredTab,
+    pub title: String,
+    /// File the buffer belongs to; `None` for untitled scripts.
+    pub file_path: Option<PathBuf>,
+    /// From the saved file (empty for untitled scripts) to the buffer.
+    pub diff: Vec<DiffLine>,
+}
+
+impl RecoveredDocument {
+    /// The recoverable buffer of a session tab, or `None` when the tab has no
+    /// autosaved content or it matches the saved file.
+    pub fn from_session_tab(tab: &RestoredTab) -> Option<Self> {
+        let (buffer, saved, file_path) = match tab.tab_kind.as_str() {
+            "FileBacked" => {
+                let buffer = std::fs::read_to_string(tab.shadow_path.as_ref()?).ok()?;
+                let saved = tab
+                    .file_path
+                    .as_ref()
+                    .and_then(|path| std::fs::read_to_string(path).ok())
+                    .unwrap_or_default();
+                (buffer, saved, tab.file_path.clone())
+            }
+            "Scratch" => {
+                let buffer = std::fs::read_to_string(tab.scratch_path.as_ref()?).ok()?;
+                (buffer, String::new(), None)
+            }
+            _ => return None,
+        };
+
+        if buffer.trim().is_empty() || buffer == saved {
+            return None;
+        }
+
+        let title = file_path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| tab.title.clone());
+
+        Some(Self {
+            tab: tab.clone(),
+            title,
+            file_path,
+            diff: line_diff(&saved, &buffer),
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        let (added, removed) = diff_counts(&self.diff);
+
+        match &self.file_path {
+            Some(path) => format!("+{}/−{} lines · {}", added, removed, path.display()),
+            None => format!("Untitled · {} lines", added),
+        }
+    }
+}
+
+/// Emitted when the user decides; every document is in exactly one list.
+#[derive(Clone, Debug)]
+pub struct CrashRecoveryResolved {
+    pub restore: Vec<RecoveredDocument>,
+    pub discard: Vec<RecoveredDocument>,
+}
+
+pub struct CrashRecoveryModal {
+    documents: Vec<RecoveredDocument>,
+    selected: Vec<bool>,
+    /// Document whose diff is shown.
+    previewed: usize,
+    visible: bool,
+}
+
+impl CrashRecoveryModal {
+    pub fn new(_cx: &mut Context<Self>) -> Self {
+        Self {
+            documents: Vec::new(),
+            selected: Vec::new(),
+            previewed: 0,
+            visible: false,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self, documents: Vec<RecoveredDocument>, cx: &mut Context<Self>) {
+        self.selected = vec![true; documents.len()];
+        self.documents = documents;
+        self.previewed = 0;
+        self.visible = !self.documents.is_empty();
+        cx.notify();
+    }
+
+    fn toggle(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if let Some(selected) = self.selected.get_mut(ix) {
+            *selected = !*selected;
+        }
+        cx.notify();
+    }
+
+    fn preview(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.previewed = ix;
+        cx.notify();
+    }
+
+    fn resolve(&mut self, restore_selected: bool, cx: &mut Context<Self>) {
+        let mut restore = Vec::new();
+        let mut discard = Vec::new();
+
+        for (document, selected) in self.documents.drain(..).zip(&self.selected) {
+            if restore_selected && *selected {
+                restore.push(document);
+            } else {
+                discard.push(document);
+            }
+        }
+
+        self.selected.clear();
+        self.visible = false;
+        cx.emit(CrashRecoveryResolved { restore, discard });
+        cx.notify();
+    }
+
+    fn render_row(&self, ix: usize, cx: &mut Context<Self>) -> AnyElement {
+        let theme = cx.theme();
+        let document = &self.documents[ix];
+        let checked = self.selected[ix];
+        let check_color = if checked { theme.primary } else { theme.border };
+        let row_bg = if ix == self.previewed {
+            theme.list_active
+        } else {
+            theme.transparent
+        };
+
+        div()
+            .id(("recovery-row", ix))
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .rounded(Radii::SM)
+            .bg(row_bg)
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _, _, cx| this.preview(ix, cx)))
+            .child(
+                div()
+                    .id(("recovery-check", ix))
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        cx.stop_propagation();
+                        this.toggle(ix, cx);
+                    }))
+                    .w(px(14.0))
+                    .h(px(14.0))
+                    .flex_shrink_0()
+                    .rounded(px(2.0))
+                    .border_1()
+                    .border_color(check_color)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .when(checked, |el| {
+                        el.bg(theme.primary).child(
+                            div()
+                                .text_size(FontSizes::XS)
+                                .text_color(theme.background)
+                                .child("✓"),
+                        )
+                    }),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .child(Text::label_sm(document.title.clone()))
+                    .child(Text::caption(document.summary()).muted_foreground()),
+            )
+            .into_any_element()
+    }
+
+    fn render_preview(&self, cx: &mut Context<Self>) -> AnyElement {
+        let theme = cx.theme();
+        let Some(document) = self.documents.get(self.previewed) else {
+            return div().into_any_element();
+        };
+
+        let lines = document.diff.iter().take(MAX_PREVIEW_LINES).map(|line| {
+            let (marker, text, color) = match line {
+                DiffLine::Unchanged(text) => (" ", text, theme.muted_foreground),
+                DiffLine::Added(text) => ("+", text, theme.success),
+                DiffLine::Removed(text) => ("-", text, theme.danger),
+            };
+
+            div().child(Text::code(format!("{} {}", marker, text)).color(color))
+        });
+
+        let hidden = document.diff.len().saturating_sub(MAX_PREVIEW_LINES);
+
+        div()
+            .id("recovery-preview")
+            .flex_1()
+            .min_w_0()
+            .h(px(320.0))
+            .overflow_y_scroll()
+            .p(Spacing::SM)
+            .rounded(Radii::SM)
+            .border_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .children(lines)
+            .when(hidden > 0, |el| {
+                el.child(Text::caption(format!("… {} more lines", hidden)).muted_foreground())
+            })
+            .into_any_element()
+    }
+}
+
+impl EventEmitter<CrashRecoveryResolved> for CrashRecoveryModal {}
+
+impl Render for CrashRecoveryModal {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let rows: Vec<AnyElement> = (0..self.documents.len())
+            .map(|ix| self.render_row(ix, cx))
+            .collect();
+        let selected_count = self.selected.iter().filter(|selected| **selected).count();
+
+        let body = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::MD)
+            .child(Text::body(
+                "DBFlux did not shut down cleanly last time. These documents had \
+                 unsaved changes; the preview compares each one with its saved file.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .gap(Spacing::MD)
+                    .child(
+                        div()
+                            .id("recovery-rows")
+                            .w(px(260.0))
+                            .flex_shrink_0()
+                            .max_h(px(320.0))
+                            .overflow_y_scroll()
+                            .flex()
+                            .flex_col()
+                            .gap(Spacing::XS)
+                            .children(rows),
+                    )
+                    .child(self.render_preview(cx)),
+            );
+
+        let footer = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(
+                Button::new("recovery-discard", "Discard All")
+                    .on_click(cx.listener(|this, _, _, cx| this.resolve(false, cx))),
+            )
+            .child(
+                Button::new(
+                    "recovery-restore",
+                    format!("Restore Selected ({})", selected_count),
+                )
+                .primary()
+                .on_click(cx.listener(|this, _, _, cx| this.resolve(true, cx))),
+            );
+
+        ModalShell::new(
+            "Recover Unsaved Documents",
+            body.into_any_element(),
+            footer.into_any_element(),
+        )
+        .width(px(820.0))
+        .into_any_element()
+    }
+}
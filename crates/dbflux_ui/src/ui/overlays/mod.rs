@@ -1,8 +1,10 @@
 pub mod cell_editor_modal;
 pub mod command_palette;
+pub mod crash_recovery;
 pub mod document_preview_modal;
 pub mod login_modal;
 pub mod modals;
+pub mod notification_center;
 pub mod shutdown_overlay;
 pub mod sql_preview_modal;
 pub mod sso_wizard;
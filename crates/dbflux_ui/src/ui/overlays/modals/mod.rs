@@ -2,6 +2,7 @@ pub mod active_query;
 pub mod delete_connection;
 pub mod drop_table;
 pub mod import_dashboard;
+pub mod rename_object;
 pub mod schema_drift;
 pub mod shell;
 pub mod tunnel_auth;
@@ -17,6 +18,7 @@ pub use drop_table::{DropTableOutcome, DropTableRequest, ModalDropTable};
 pub use import_dashboard::{
     ImportDashboardCancelled, ImportDashboardConfirmed, ModalImportDashboard,
 };
+pub use rename_object::{ModalRenameObject, RenameObjectOutcome, RenameObjectRequest};
 pub use schema_drift::ModalSchemaDrift;
 pub use shell::{ModalShell, ModalVariant};
 pub use tunnel_auth::{ModalTunnelAuth, TunnelAuthOutcome, TunnelAuthRequest};
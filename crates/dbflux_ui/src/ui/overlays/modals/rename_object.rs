@@ -0,0 +1,3 @@
+pub use dbflux_components::modals::rename_object::{
+    ModalRenameObject, RenameObjectOutcome, RenameObjectRequest,
+};
@@ -0,0 +1,339 @@
+//! Notifications center: the toasts and background events of this session,
+//! newest first, filterable by severity. Clicking a notification about a
+//! document focuses its tab.
+//!
+//! Besides showing the log, the center feeds it with finished background
+//! tasks (connects, exports, hooks, ...), which do not raise a toast.
+
+use crate::app::{AppStateChanged, AppStateEntity};
+use dbflux_components::controls::Button;
+use dbflux_components::modals::shell::ModalShell;
+use dbflux_components::primitives::{Icon, SegmentedControl, SegmentedItem, Text};
+use dbflux_components::tokens::{Radii, Spacing};
+use dbflux_core::TaskId;
+use dbflux_ui_base::notifications::{AppNotification, task_notification};
+use dbflux_ui_base::toast::ToastKind;
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Recent tasks inspected for new completions on every state change.
+const TASK_SCAN_LIMIT: usize = 50;
+
+/// Severities offered by the filter, in display order.
+const SEVERITY_FILTERS: [(ToastKind, &str, &str); 4] = [
+    (ToastKind::Error, "error", "Errors"),
+    (ToastKind::Warning, "warning", "Warnings"),
+    (ToastKind::Success, "success", "Success"),
+    (ToastKind::Info, "info", "Info"),
+];
+
+fn filter_id(filter: Option<ToastKind>) -> &'static str {
+    SEVERITY_FILTERS
+        .iter()
+        .find(|(kind, _, _)| Some(*kind) == filter)
+        .map(|(_, id, _)| *id)
+        .unwrap_or("all")
+}
+
+fn filter_from_id(id: &str) -> Option<ToastKind> {
+    SEVERITY_FILTERS
+        .iter()
+        .find(|(_, filter_id, _)| *filter_id == id)
+        .map(|(kind, _, _)| *kind)
+}
+
+/// Emitted when a notification about a document is clicked.
+#[derive(Clone, Debug)]
+pub enum NotificationCenterEvent {
+    FocusDocument(Uuid),
+}
+
+pub struct NotificationCenter {
+    app_state: Entity<AppStateEntity>,
+    visible: bool,
+    /// Only notifications of this severity are listed; `None` lists all.
+    filter: Option<ToastKind>,
+    /// Finished tasks already turned into a notification (or skipped).
+    seen_tasks: HashSet<TaskId>,
+    _subscription: Subscription,
+}
+
+impl NotificationCenter {
+    pub fn new(app_state: Entity<AppStateEntity>, cx: &mut Context<Self>) -> Self {
+        let subscription = cx.subscribe(&app_state, |this, _, _: &AppStateChanged, cx| {
+            this.collect_finished_tasks(cx);
+
+            // Notifications that arrive while the center is open are read.
+            if this.visible {
+                this.app_state
+                    .update(cx, |state, cx| state.mark_notifications_read(cx));
+                cx.notify();
+            }
+        });
+
+        Self {
+            app_state,
+            visible: false,
+            filter: None,
+            seen_tasks: HashSet::new(),
+            _subscription: subscription,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self, cx: &mut Context<Self>) {
+        self.visible = true;
+        self.app_state
+            .update(cx, |state, cx| state.mark_notifications_read(cx));
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.visible = false;
+        cx.notify();
+    }
+
+    pub fn toggle(&mut self, cx: &mut Context<Self>) {
+        if self.visible {
+            self.close(cx);
+        } else {
+            self.open(cx);
+        }
+    }
+
+    /// Adds a notification for every task that finished since the last scan.
+    fn collect_finished_tasks(&mut self, cx: &mut Context<Self>) {
+        let recent = self
+            .app_state
+            .read(cx)
+            .tasks()
+            .recent_tasks(TASK_SCAN_LIMIT);
+
+        let mut finished = Vec::new();
+        for task in &recent {
+            if task.status.is_terminal() && self.seen_tasks.insert(task.id) {
+                finished.extend(task_notification(task));
+            }
+        }
+
+        // Forget tasks that dropped out of the task manager.
+        self.seen_tasks
+            .retain(|id| recent.iter().any(|task| task.id == *id));
+
+        if finished.is_empty() {
+            return;
+        }
+
+        self.app_state.update(cx, |state, cx| {
+            // Oldest first, so the log ends up newest first.
+            for (title, body, severity) in finished.into_iter().rev() {
+                state.push_notification(title, body, None, severity, cx);
+            }
+        });
+    }
+
+    fn set_filter(&mut self, filter: Option<ToastKind>, cx: &mut Context<Self>) {
+        self.filter = filter;
+        cx.notify();
+    }
+
+    fn dismiss(&mut self, id: u64, cx: &mut Context<Self>) {
+        self.app_state.update(cx, |state, cx| {
+            state.notifications.remove(id);
+            cx.emit(AppStateChanged);
+        });
+    }
+
+    fn clear(&mut self, cx: &mut Context<Self>) {
+        self.app_state.update(cx, |state, cx| {
+            state.notifications.clear();
+            cx.emit(AppStateChanged);
+        });
+    }
+
+    fn render_filter(&self, cx: &mut Context<Self>) -> AnyElement {
+        let notifications = &self.app_state.read(cx).notifications;
+
+        let mut items = vec![SegmentedItem::new(
+            "all",
+            format!("All ({})", notifications.entries().count()),
+        )];
+        items.extend(SEVERITY_FILTERS.iter().map(|(kind, id, label)| {
+            SegmentedItem::new(*id, format!("{} ({})", label, notifications.count(*kind)))
+        }));
+
+        let entity = cx.entity().clone();
+        SegmentedControl::new(items, filter_id(self.filter), move |selected, _, cx| {
+            let filter = filter_from_id(selected.as_ref());
+            entity.update(cx, |this, cx| this.set_filter(filter, cx));
+        })
+        .into_any_element()
+    }
+
+    fn render_entry(
+        &self,
+        ix: usize,
+        entry: &AppNotification,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let id = entry.id;
+        let document_id = entry.document_id;
+        let color = entry.severity.accent(cx);
+        let hover_bg = cx.theme().secondary;
+
+        div()
+            .id(("notification-row", ix))
+            .flex()
+            .items_start()
+            .gap(Spacing::SM)
+            .p(Spacing::XS)
+            .rounded(Radii::SM)
+            .hover(move |d| d.bg(hover_bg))
+            .when(document_id.is_some(), |el| el.cursor_pointer())
+            .on_click(cx.listener(move |this, _, _, cx| {
+                if let Some(document_id) = document_id {
+                    this.close(cx);
+                    cx.emit(NotificationCenterEvent::FocusDocument(document_id));
+                }
+            }))
+            .child(
+                div()
+                    .pt(px(2.0))
+                    .child(Icon::new(entry.severity.icon()).small().color(color)),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .gap(px(2.0))
+                    .child(
+                        div()
+                            .flex()
+                            .gap(Spacing::SM)
+                            .child(Text::label_sm(entry.title.clone()))
+                            .child(div().flex_1())
+                            .child(
+                                Text::caption(entry.created_at.format("%H:%M:%S").to_string())
+                                    .muted_foreground(),
+                            ),
+                    )
+                    .when(!entry.body.is_empty(), |el| {
+                        el.child(Text::caption(entry.body.clone()).muted_foreground())
+                    }),
+            )
+            .child(
+                Button::new(("notification-dismiss", ix), "Dismiss")
+                    .small()
+                    .ghost()
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        cx.stop_propagation();
+                        this.dismiss(id, cx);
+                    })),
+            )
+            .into_any_element()
+    }
+}
+
+impl EventEmitter<NotificationCenterEvent> for NotificationCenter {}
+
+impl Render for NotificationCenter {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let filter = self.filter;
+        let entries: Vec<AppNotification> = self
+            .app_state
+            .read(cx)
+            .notifications
+            .entries()
+            .filter(|entry| filter.is_none_or(|severity| entry.severity == severity))
+            .cloned()
+            .collect();
+        let log_is_empty = self.app_state.read(cx).notifications.is_empty();
+
+        let rows: Vec<AnyElement> = entries
+            .iter()
+            .enumerate()
+            .map(|(ix, entry)| self.render_entry(ix, entry, cx))
+            .collect();
+
+        let empty_message = if log_is_empty {
+            "No notifications"
+        } else {
+            "No notifications of this severity"
+        };
+
+        let body = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::SM)
+            .child(self.render_filter(cx))
+            .child(
+                div()
+                    .id("notification-rows")
+                    .max_h(px(420.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::XS)
+                    .when(entries.is_empty(), |el| {
+                        el.child(Text::caption(empty_message).muted_foreground())
+                    })
+                    .children(rows),
+            );
+
+        let footer = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(
+                Button::new("notifications-clear", "Clear All")
+                    .disabled(log_is_empty)
+                    .on_click(cx.listener(|this, _, _, cx| this.clear(cx))),
+            )
+            .child(
+                Button::new("notifications-close", "Close")
+                    .primary()
+                    .on_click(cx.listener(|this, _, _, cx| this.close(cx))),
+            );
+
+        let close_for_x = cx.entity().clone();
+
+        ModalShell::new(
+            "Notifications",
+            body.into_any_element(),
+            footer.into_any_element(),
+        )
+        .width(px(560.0))
+        .on_close(move |_window, cx| {
+            close_for_x.update(cx, |this, cx| this.close(cx));
+        })
+        .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_filter_ids_round_trip() {
+        assert_eq!(filter_id(None), "all");
+        assert_eq!(filter_from_id("all"), None);
+
+        for (kind, id, _) in SEVERITY_FILTERS {
+            assert_eq!(filter_id(Some(kind)), id);
+            assert_eq!(filter_from_id(id), Some(kind));
+        }
+    }
+}
@@ -11,6 +11,8 @@ use std::time::Duration;
 
 pub struct ToggleTasksPanel;
 
+pub struct OpenNotificationCenter;
+
 pub struct StatusBar {
     app_state: Entity<AppStateEntity>,
     /// Periodic notify task that drives the 100 ms busy-pulse animation.
@@ -23,6 +25,7 @@ pub struct StatusBar {
 }
 
 impl EventEmitter<ToggleTasksPanel> for StatusBar {}
+impl EventEmitter<OpenNotificationCenter> for StatusBar {}
 
 impl StatusBar {
     pub fn new(
@@ -157,6 +160,7 @@ impl StatusBar {
             dbflux_core::TaskStatus::Failed(_) => "✗",
             dbflux_core::TaskStatus::Cancelled => "⊘",
             dbflux_core::TaskStatus::Running => "⋯",
+            dbflux_core::TaskStatus::Queued => "◷",
         };
 
         format!(
@@ -214,6 +218,7 @@ impl Render for StatusBar {
 
         let divider_color = ChromeColors::ghost_border();
         let unread = app_state.unread_error_count;
+        let unread_notifications = app_state.notifications.unread_count();
 
         div()
             .flex()
@@ -279,7 +284,7 @@ impl Render for StatusBar {
                         )
                     }),
             )
-            // Right section: error badge (when present) + tasks toggle
+            // Right section: error badge (when present) + notifications bell + tasks toggle
             .child(
                 div()
                     .flex()
@@ -311,6 +316,30 @@ impl Render for StatusBar {
                         )
                     })
                     .child(Self::vertical_divider(divider_color))
+                    .child(
+                        div()
+                            .id("notifications-bell")
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .px(px(10.0))
+                            .h(px(22.0))
+                            .cursor_pointer()
+                            .hover(|s| s.bg(cx.theme().secondary))
+                            .child(
+                                Icon::new(crate::ui::icons::AppIcon::Bell)
+                                    .size(Heights::ICON_SM)
+                                    .when(unread_notifications > 0, |icon| icon.primary())
+                                    .when(unread_notifications == 0, |icon| icon.muted()),
+                            )
+                            .when(unread_notifications > 0, |this| {
+                                this.child(Self::metadata_text(unread_notifications.to_string()))
+                            })
+                            .on_click(cx.listener(|_this, _, _, cx| {
+                                cx.emit(OpenNotificationCenter);
+                            })),
+                    )
+                    .child(Self::vertical_divider(divider_color))
                     .child(
                         div()
                             .id("tasks-toggle")
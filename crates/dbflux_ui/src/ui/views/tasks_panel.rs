@@ -1,5 +1,6 @@
 use crate::app::{AppStateChanged, AppStateEntity};
 use crate::ui::icons::AppIcon;
+use dbflux_components::controls::Button;
 use dbflux_components::primitives::{Icon, Text};
 use dbflux_core::{TaskId, TaskKind, TaskSnapshot, TaskStatus};
 use gpui::prelude::FluentBuilder;
@@ -147,6 +148,39 @@ impl TasksPanel {
         });
     }
 
+    /// Cancels every running and queued task of one connection.
+    fn cancel_profile_tasks(&mut self, profile_id: Uuid, cx: &mut Context<Self>) {
+        self.app_state.update(cx, |state, cx| {
+            if state.cancel_tasks_for_profile(profile_id) > 0 {
+                cx.emit(AppStateChanged);
+            }
+        });
+    }
+
+    /// Moves a queued task one place earlier or later among the tasks
+    /// waiting on its connection.
+    fn move_queued_task(&mut self, task_id: TaskId, earlier: bool, cx: &mut Context<Self>) {
+        self.app_state.update(cx, |state, cx| {
+            if state.move_queued_task(task_id, earlier) {
+                cx.emit(AppStateChanged);
+            }
+        });
+    }
+
+    /// Small square icon button at the end of a task row.
+    fn row_button(id: String, icon: AppIcon, hover_bg: Hsla) -> Stateful<Div> {
+        div()
+            .id(SharedString::from(id))
+            .flex()
+            .items_center()
+            .justify_center()
+            .size_5()
+            .rounded(px(2.0))
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .child(Icon::new(icon).size(px(12.0)).muted())
+    }
+
     fn format_elapsed(secs: f64) -> String {
         if secs < 1.0 {
             format!("{:.0}ms", secs * 1000.0)
@@ -159,12 +193,84 @@ impl TasksPanel {
         }
     }
 
+    /// `ETA 6s`, `ETA 2m 05s`.
+    fn format_eta(secs: f64) -> String {
+        let secs = secs.ceil() as u64;
+        if secs < 60 {
+            format!("ETA {}s", secs)
+        } else {
+            format!("ETA {}m {:02}s", secs / 60, secs % 60)
+        }
+    }
+
+    /// Units summary, throughput and ETA of a task, e.g.
+    /// `3 / 12 tables · 1.5 tables/s · ETA 6s`; a bare percentage for tasks
+    /// that only report a fraction.
+    fn progress_text(task: &TaskSnapshot) -> Option<String> {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(summary) = task.units_summary() {
+            parts.push(summary);
+        } else if let Some(progress) = task.progress {
+            parts.push(format!("{:.0}%", progress * 100.0));
+        }
+
+        if let Some(eta) = task.eta_secs() {
+            parts.push(Self::format_eta(eta));
+        }
+
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+
+    /// Bar above the task list for a connection with several active tasks,
+    /// offering to cancel all of them at once.
+    fn render_profile_bar(
+        &self,
+        profile_id: Uuid,
+        name: String,
+        active: usize,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let theme = cx.theme();
+
+        div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .w_full()
+            .px_3()
+            .py_1()
+            .border_b_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .child(
+                div()
+                    .flex_1()
+                    .text_ellipsis()
+                    .child(Text::caption(format!("{} · {} active tasks", name, active))),
+            )
+            .child(
+                Button::new(
+                    SharedString::from(format!("cancel-profile-tasks-{}", profile_id)),
+                    "Cancel all",
+                )
+                .small()
+                .danger()
+                .on_click(cx.listener(move |this, _, _, cx| {
+                    this.cancel_profile_tasks(profile_id, cx);
+                })),
+            )
+    }
+
     fn render_task_row(&mut self, task: &TaskSnapshot, cx: &mut Context<Self>) -> Div {
         let theme = cx.theme();
         let task_id = task.id;
         let task_kind = task.kind;
         let task_profile_id = task.profile_id;
         let is_running = matches!(task.status, TaskStatus::Running);
+        let is_queued = matches!(task.status, TaskStatus::Queued);
+        let is_cancellable = task.is_cancellable;
         let details_text = task.details.clone().or_else(|| match &task.status {
             TaskStatus::Failed(error) => Some(error.clone()),
             _ => None,
@@ -173,8 +279,11 @@ impl TasksPanel {
             .as_ref()
             .is_some_and(|details| !details.trim().is_empty());
         let is_expanded = self.expanded_task_ids.contains(&task_id);
+        let progress_bar = task.progress.filter(|_| is_running);
+        let progress_text = Self::progress_text(task);
 
         let status_icon = match &task.status {
+            TaskStatus::Queued => "◷",
             TaskStatus::Running => "⋯",
             TaskStatus::Completed => "✓",
             TaskStatus::Failed(_) => "✗",
@@ -182,6 +291,7 @@ impl TasksPanel {
         };
 
         let status_color = match &task.status {
+            TaskStatus::Queued => theme.muted_foreground,
             TaskStatus::Running => theme.accent,
             TaskStatus::Completed => theme.success,
             TaskStatus::Failed(_) => theme.danger,
@@ -234,12 +344,40 @@ impl TasksPanel {
                                     .text_ellipsis()
                                     .child(Text::body(task.description.clone())),
                             )
-                            .child(Text::caption(format!(
-                                "({})",
-                                Self::format_elapsed(task.elapsed_secs)
-                            ))),
+                            .child(Text::caption(if is_queued {
+                                format!("(waiting {})", Self::format_elapsed(task.elapsed_secs))
+                            } else {
+                                format!("({})", Self::format_elapsed(task.elapsed_secs))
+                            })),
                     )
-                    .when(is_running, |el| {
+                    .when(is_queued, |el| {
+                        let hover_bg = theme.secondary_hover;
+                        el.child(
+                            Self::row_button(
+                                format!("queue-earlier-{}", task_id),
+                                AppIcon::ChevronUp,
+                                hover_bg,
+                            )
+                            .on_click(cx.listener(
+                                move |this, _, _, cx| {
+                                    this.move_queued_task(task_id, true, cx);
+                                },
+                            )),
+                        )
+                        .child(
+                            Self::row_button(
+                                format!("queue-later-{}", task_id),
+                                AppIcon::ChevronDown,
+                                hover_bg,
+                            )
+                            .on_click(cx.listener(
+                                move |this, _, _, cx| {
+                                    this.move_queued_task(task_id, false, cx);
+                                },
+                            )),
+                        )
+                    })
+                    .when(is_cancellable, |el| {
                         let danger_bg = theme.danger.opacity(0.1);
                         el.child(
                             div()
@@ -258,6 +396,33 @@ impl TasksPanel {
                         )
                     }),
             )
+            .when_some(progress_text, |el, text| {
+                el.child(
+                    div()
+                        .px_3()
+                        .pb_1()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .when_some(progress_bar, |el, fraction| {
+                            el.child(
+                                div()
+                                    .w_full()
+                                    .h(px(3.0))
+                                    .rounded(px(2.0))
+                                    .bg(theme.secondary)
+                                    .child(
+                                        div()
+                                            .h_full()
+                                            .rounded(px(2.0))
+                                            .w(relative(fraction))
+                                            .bg(theme.accent),
+                                    ),
+                            )
+                        })
+                        .child(Text::caption(text).muted_foreground()),
+                )
+            })
             .when(has_details && is_expanded, |el| {
                 let mut lines: Vec<String> = details_text
                     .unwrap_or_default()
@@ -289,22 +454,60 @@ impl Render for TasksPanel {
         let state = self.app_state.read(cx);
 
         let running_tasks = state.tasks().running_tasks();
-        let running_ids: HashSet<TaskId> = running_tasks.iter().map(|t| t.id).collect();
+        let queued_tasks = state.tasks().queued_tasks();
+        let active_ids: HashSet<TaskId> = running_tasks
+            .iter()
+            .chain(&queued_tasks)
+            .map(|t| t.id)
+            .collect();
+
+        // Connections with more than one active task get a "Cancel all" bar.
+        let mut busy_profiles: Vec<(Uuid, usize)> = Vec::new();
+        for profile_id in running_tasks
+            .iter()
+            .chain(&queued_tasks)
+            .filter_map(|t| t.profile_id)
+        {
+            match busy_profiles.iter_mut().find(|(id, _)| *id == profile_id) {
+                Some((_, count)) => *count += 1,
+                None => busy_profiles.push((profile_id, 1)),
+            }
+        }
+        let busy_profiles: Vec<(Uuid, String, usize)> = busy_profiles
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(profile_id, count)| {
+                let name = state
+                    .profiles()
+                    .iter()
+                    .find(|profile| profile.id == profile_id)
+                    .map(|profile| profile.name.clone())
+                    .unwrap_or_else(|| "Connection".to_string());
+                (profile_id, name, count)
+            })
+            .collect();
 
         let recent_tasks: Vec<TaskSnapshot> = state
             .tasks()
-            .recent_tasks(10)
+            .recent_tasks(10 + queued_tasks.len())
             .into_iter()
-            .filter(|t| !running_ids.contains(&t.id))
+            .filter(|t| !active_ids.contains(&t.id))
             .take(5)
             .collect();
 
-        let all_tasks: Vec<TaskSnapshot> = running_tasks.into_iter().chain(recent_tasks).collect();
+        let all_tasks: Vec<TaskSnapshot> = running_tasks
+            .into_iter()
+            .chain(queued_tasks)
+            .chain(recent_tasks)
+            .collect();
         let visible_task_ids: HashSet<TaskId> = all_tasks.iter().map(|task| task.id).collect();
         self.expanded_task_ids
             .retain(|task_id| visible_task_ids.contains(task_id));
 
         let mut task_rows: Vec<Div> = Vec::new();
+        for (profile_id, name, active) in busy_profiles {
+            task_rows.push(self.render_profile_bar(profile_id, name, active, cx));
+        }
         for task in &all_tasks {
             task_rows.push(self.render_task_row(task, cx));
         }
@@ -0,0 +1,136 @@
+use super::*;
+use crate::ui::document::DocumentId;
+use dbflux_ipc::{ActiveConnection, TabSummary};
+
+impl Workspace {
+    /// The docked tabs in tab bar order, for app-control `ListTabs`.
+    pub fn tab_summaries(&self, cx: &App) -> Vec<TabSummary> {
+        let manager = self.tab_manager.read(cx);
+        let focused = manager.focused_id();
+
+        manager
+            .docked_ids()
+            .into_iter()
+            .filter_map(|id| manager.document(id))
+            .map(|tab| TabSummary {
+                document_id: tab.id().0,
+                title: tab.tab_title(cx),
+                kind: document_kind_key(tab.kind()).to_string(),
+                active: Some(tab.id()) == focused,
+                connection_id: tab.connection_id(cx),
+            })
+            .collect()
+    }
+
+    /// Activates the tab at `index` in [`Self::tab_summaries`] order.
+    pub fn focus_tab_at(
+        &mut self,
+        index: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<(), String> {
+        let docked = self.tab_manager.read(cx).docked_ids();
+        let Some(&id) = docked.get(index) else {
+            return Err(format!(
+                "no tab at index {index} ({} tabs open)",
+                docked.len()
+            ));
+        };
+
+        self.tab_manager.update(cx, |mgr, cx| mgr.activate(id, cx));
+        self.set_focus(FocusTarget::Document, window, cx);
+        Ok(())
+    }
+
+    /// The focused tab's connection, or the app's active one.
+    pub fn active_connection(&self, cx: &App) -> Option<ActiveConnection> {
+        let state = self.app_state.read(cx);
+        let profile_id = self
+            .tab_manager
+            .read(cx)
+            .focused_tab()
+            .and_then(|tab| tab.connection_id(cx))
+            .or_else(|| state.active_connection_id())?;
+        let connected = state.connections().get(&profile_id)?;
+
+        Some(ActiveConnection {
+            profile_id,
+            profile_name: connected.profile.name.clone(),
+            database: connected.active_database.clone(),
+        })
+    }
+
+    /// Opens the saved query named (or identified) by `key` in a new tab and
+    /// runs it against its connection, connecting that first if needed.
+    pub fn run_saved_query_by_key(
+        &mut self,
+        key: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<(), String> {
+        let query = {
+            let queries = self.app_state.read(cx).saved_queries();
+            key.parse::<uuid::Uuid>()
+                .ok()
+                .and_then(|id| queries.iter().find(|query| query.id == id))
+                .or_else(|| queries.iter().find(|query| query.name == key))
+                .map(|query| (query.id, query.connection_id))
+        };
+        let Some((query_id, connection_id)) = query else {
+            return Err(format!("no saved query named \"{key}\""));
+        };
+
+        self.open_saved_query(query_id, window, cx);
+
+        let Some(document_id) = self.tab_manager.read(cx).focused_id() else {
+            return Err("the saved query could not be opened".to_string());
+        };
+
+        if let Some(profile_id) = connection_id {
+            self.switch_execution_context(profile_id, None, window, cx);
+
+            if let Some(pending) = self.pending_context_switch.as_mut()
+                && pending.document_id == document_id
+            {
+                pending.run_after = true;
+                return Ok(());
+            }
+        }
+
+        self.run_document(document_id, window, cx);
+        Ok(())
+    }
+
+    /// Runs the query of the tab `document_id`.
+    pub(in crate::ui::views::workspace) fn run_document(
+        &mut self,
+        document_id: DocumentId,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.tab_manager.update(cx, |mgr, cx| {
+            if let Some(tab) = mgr.document(document_id) {
+                tab.dispatch_command(Command::RunQuery, window, cx);
+            }
+        });
+    }
+}
+
+/// Stable snake-case name of a document kind, as reported over app-control.
+fn document_kind_key(kind: DocumentKind) -> &'static str {
+    match kind {
+        DocumentKind::Script => "script",
+        DocumentKind::Data => "data",
+        DocumentKind::SqlQuery => "sql_query",
+        DocumentKind::TableView => "table_view",
+        DocumentKind::RedisKeyBrowser => "redis_key_browser",
+        DocumentKind::RedisKey => "redis_key",
+        DocumentKind::RedisConsole => "redis_console",
+        DocumentKind::MongoCollection => "mongo_collection",
+        DocumentKind::Audit => "audit",
+        DocumentKind::Chart => "chart",
+        DocumentKind::Dashboard => "dashboard",
+        DocumentKind::Notebook => "notebook",
+        DocumentKind::UserAdmin => "user_admin",
+    }
+}
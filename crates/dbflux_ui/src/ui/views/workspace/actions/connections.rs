@@ -1,3 +1,4 @@
+use super::scripts::find_profile_id;
 use super::*;
 
 impl Workspace {
@@ -39,6 +40,7 @@ impl Workspace {
     pub(in crate::ui::views::workspace) fn open_connection_manager_for_edit(
         &self,
         profile_id: uuid::Uuid,
+        fix: Option<dbflux_core::ConnectionFixTarget>,
         cx: &mut Context<Self>,
     ) {
         let app_state = self.app_state.clone();
@@ -72,8 +74,14 @@ impl Workspace {
         platform::apply_window_options(&mut options, 600.0, 500.0);
 
         if let Err(error) = cx.open_window(options, |window, cx| {
-            let manager =
-                cx.new(|cx| ConnectionManagerWindow::new_for_edit(app_state, &profile, window, cx));
+            let manager = cx.new(|cx| {
+                let mut manager =
+                    ConnectionManagerWindow::new_for_edit(app_state, &profile, window, cx);
+                if let Some(target) = fix {
+                    manager.focus_connection_fix(target, window, cx);
+                }
+                manager
+            });
             cx.new(|cx| Root::new(manager, window, cx))
         }) {
             log::warn!("Failed to open connection editor window: {:?}", error);
@@ -205,4 +213,170 @@ impl Workspace {
             .meta_right(now_hms())
             .push(cx);
     }
+
+    /// Makes a connected profile the active connection and focuses the
+    /// sidebar, as selecting it there would.
+    pub(in crate::ui::views::workspace) fn focus_connection(
+        &mut self,
+        profile_id: uuid::Uuid,
+        cx: &mut Context<Self>,
+    ) {
+        self.app_state.update(cx, |state, cx| {
+            state.set_active_connection(profile_id);
+            cx.emit(AppStateChanged);
+        });
+        if self.is_sidebar_collapsed(cx) {
+            self.toggle_sidebar(cx);
+        }
+        self.pending_focus = Some(FocusTarget::Sidebar);
+        cx.notify();
+    }
+
+    /// Connects the profile named (or identified) by `key` and focuses the
+    /// sidebar, for app-control `ConnectProfile`. A connected profile is only
+    /// focused.
+    pub fn connect_profile_by_key(
+        &mut self,
+        key: &str,
+        cx: &mut Context<Self>,
+    ) -> Result<(), String> {
+        let Some(profile_id) = find_profile_id(self.app_state.read(cx).profiles(), key) else {
+            return Err(format!("no connection profile named \"{key}\""));
+        };
+
+        if self
+            .app_state
+            .read(cx)
+            .connections()
+            .contains_key(&profile_id)
+        {
+            self.focus_connection(profile_id, cx);
+            return Ok(());
+        }
+
+        self.sidebar.update(cx, |sidebar, cx| {
+            sidebar.connect_to_profile(profile_id, cx);
+        });
+        if self.is_sidebar_collapsed(cx) {
+            self.toggle_sidebar(cx);
+        }
+        self.pending_focus = Some(FocusTarget::Sidebar);
+        cx.notify();
+        Ok(())
+    }
+
+    /// Opens the palette over every profile and the databases of the
+    /// connected ones.
+    pub(in crate::ui::views::workspace) fn open_connection_switcher(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let state = self.app_state.read(cx);
+        let items = build_execution_target_items(state.profiles(), state.connections());
+
+        if items.is_empty() {
+            Toast::warning("No connections configured")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        self.command_palette.update(cx, |palette, cx| {
+            palette.open_with_items(items, window, cx);
+        });
+    }
+
+    /// Runs the focused document against `profile_id`, and `database` when
+    /// given, connecting the profile first if needed.
+    pub(in crate::ui::views::workspace) fn switch_execution_context(
+        &mut self,
+        profile_id: uuid::Uuid,
+        database: Option<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let document_id = self
+            .tab_manager
+            .read(cx)
+            .focused_tab()
+            .filter(|tab| tab.as_pane().switch_execution_context.is_some())
+            .map(|tab| tab.id());
+
+        let Some(document_id) = document_id else {
+            Toast::warning("Focus a query tab to switch its connection")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        if self
+            .app_state
+            .read(cx)
+            .connections()
+            .contains_key(&profile_id)
+        {
+            self.apply_execution_context(document_id, profile_id, database, window, cx);
+            return;
+        }
+
+        self.pending_context_switch = Some(PendingContextSwitch {
+            document_id,
+            profile_id,
+            database,
+            run_after: false,
+        });
+        self.sidebar.update(cx, |sidebar, cx| {
+            sidebar.connect_to_profile(profile_id, cx);
+        });
+    }
+
+    /// Finishes a switch once its profile is connected, or drops it when the
+    /// connection attempt ended without one.
+    pub(in crate::ui::views::workspace) fn resume_context_switch(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(pending) = self.pending_context_switch.take() else {
+            return;
+        };
+
+        let state = self.app_state.read(cx);
+        let connected = state.connections().contains_key(&pending.profile_id);
+        let connecting = state.is_operation_pending(pending.profile_id, None);
+
+        if connected {
+            self.apply_execution_context(
+                pending.document_id,
+                pending.profile_id,
+                pending.database,
+                window,
+                cx,
+            );
+            if pending.run_after {
+                self.run_document(pending.document_id, window, cx);
+            }
+        } else if connecting {
+            self.pending_context_switch = Some(pending);
+        }
+    }
+
+    fn apply_execution_context(
+        &mut self,
+        document_id: crate::ui::document::DocumentId,
+        profile_id: uuid::Uuid,
+        database: Option<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.tab_manager.update(cx, |mgr, cx| {
+            if let Some(switch) = mgr
+                .document(document_id)
+                .and_then(|tab| tab.as_pane().switch_execution_context.as_ref())
+            {
+                switch(profile_id, database, window, cx);
+            }
+        });
+    }
 }
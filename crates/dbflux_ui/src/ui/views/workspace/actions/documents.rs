@@ -71,6 +71,237 @@ impl Workspace {
         log::info!("Opened table document: {:?}.{:?}", table.schema, table.name);
     }
 
+    /// Opens (or focuses) a table document and replaces its WHERE filter with
+    /// `filter`, so it shows only the rows a foreign key points at.
+    pub(in crate::ui::views::workspace) fn open_filtered_table_document(
+        &mut self,
+        profile_id: uuid::Uuid,
+        table: dbflux_core::TableRef,
+        database: Option<String>,
+        filter: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let key = crate::ui::document::DocumentKey::Table {
+            profile_id,
+            database: database.clone(),
+            table: table.clone(),
+        };
+
+        self.open_table_document(profile_id, table, database, window, cx);
+
+        let Some(id) = self.tab_manager.read(cx).find_by_key(&key, cx) else {
+            return;
+        };
+
+        self.tab_manager.update(cx, |mgr, cx| {
+            if let Some(f) = mgr
+                .document(id)
+                .and_then(|tab| tab.as_pane().set_table_filter.as_ref())
+            {
+                f(filter, window, cx);
+            }
+        });
+    }
+
+    /// The active connection and its current database, if connected.
+    fn active_database_target(&self, cx: &App) -> Option<(uuid::Uuid, Option<String>)> {
+        let state = self.app_state.read(cx);
+        let profile_id = state.active_connection_id()?;
+        let connected = state.connections().get(&profile_id)?;
+        let database = connected.active_database.clone().or_else(|| {
+            connected
+                .schema
+                .as_ref()
+                .and_then(|schema| schema.as_relational())
+                .and_then(|relational| relational.current_database.clone())
+        });
+        Some((profile_id, database))
+    }
+
+    /// Opens the "Search data" modal for the active connection's current
+    /// database.
+    pub(in crate::ui::views::workspace) fn open_data_search(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((profile_id, database)) = self.active_database_target(cx) else {
+            Toast::warning("Connect to a database to search its data")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        self.data_search_modal.update(cx, |modal, cx| {
+            modal.open(profile_id, database, window, cx);
+        });
+    }
+
+    /// Opens the index advisor for the active connection's current database.
+    pub(in crate::ui::views::workspace) fn open_index_advisor(&mut self, cx: &mut Context<Self>) {
+        let Some((profile_id, database)) = self.active_database_target(cx) else {
+            Toast::warning("Connect to a database to analyze its queries")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        self.index_advisor_modal.update(cx, |modal, cx| {
+            modal.open(profile_id, database, cx);
+        });
+    }
+
+    /// Opens the DDL of a quick-open hit: the routine's definition, or a
+    /// `CREATE TABLE` generated from the table's columns.
+    pub(in crate::ui::views::workspace) fn view_object_definition(
+        &mut self,
+        item: ResourceItem,
+        cx: &mut Context<Self>,
+    ) {
+        match item {
+            ResourceItem::Routine {
+                profile_id,
+                schema,
+                specific_name,
+                ..
+            } => {
+                self.open_routine_definition(
+                    profile_id,
+                    schema,
+                    specific_name.clone(),
+                    specific_name,
+                    cx,
+                );
+            }
+            ResourceItem::Table {
+                profile_id,
+                database,
+                schema,
+                name,
+                ..
+            } => self.open_table_ddl(profile_id, database, schema, name, cx),
+            _ => {
+                Toast::warning("No DDL is available for this object")
+                    .meta_right(now_hms())
+                    .push(cx);
+            }
+        }
+    }
+
+    /// Generates `CREATE TABLE` for a table, loading its columns first when
+    /// they are not cached, and opens it in a new query tab.
+    fn open_table_ddl(
+        &mut self,
+        profile_id: uuid::Uuid,
+        database: Option<String>,
+        schema: Option<String>,
+        name: String,
+        cx: &mut Context<Self>,
+    ) {
+        let (connection, cached, database) = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(&profile_id) else {
+                return;
+            };
+
+            let cached = connected
+                .table_details
+                .iter()
+                .find(|((_, table), info)| {
+                    *table == name && info.schema == schema && info.columns.is_some()
+                })
+                .map(|(_, info)| info.clone());
+            let database = database
+                .or_else(|| connected.active_database.clone())
+                .unwrap_or_else(|| "default".to_string());
+
+            (
+                connected.connection_for_database(&database),
+                cached,
+                database,
+            )
+        };
+
+        cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let table = match cached {
+                        Some(table) => table,
+                        None => connection.table_details(&database, schema.as_deref(), &name)?,
+                    };
+                    connection.generate_code("create_table", &table)
+                })
+                .await;
+
+            cx.update(|cx| match result {
+                Ok(sql) => {
+                    this.update(cx, |ws, cx| {
+                        ws.pending_sql = Some(sql);
+                        cx.notify();
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    Toast::error(format!("Could not generate DDL: {}", e))
+                        .meta_right(now_hms())
+                        .push(cx);
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    /// Inserts the qualified name of a quick-open hit at the cursor of the
+    /// active editor, or into a new query tab when no editor is active.
+    pub(in crate::ui::views::workspace) fn insert_object_name(
+        &mut self,
+        item: ResourceItem,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let text = {
+            let state = self.app_state.read(cx);
+            let profile_id = match &item {
+                ResourceItem::Table { profile_id, .. }
+                | ResourceItem::Collection { profile_id, .. }
+                | ResourceItem::View { profile_id, .. }
+                | ResourceItem::KeyValueDb { profile_id, .. }
+                | ResourceItem::Routine { profile_id, .. } => *profile_id,
+            };
+            let Some(connected) = state.connections().get(&profile_id) else {
+                return;
+            };
+            let dialect = connected.connection.dialect();
+
+            match &item {
+                ResourceItem::Table { schema, name, .. }
+                | ResourceItem::View { schema, name, .. } => {
+                    dialect.qualified_table(schema.as_deref(), name)
+                }
+                ResourceItem::Routine { schema, name, .. } => format!(
+                    "{}.{}",
+                    dialect.quote_identifier(schema),
+                    dialect.quote_identifier(name)
+                ),
+                ResourceItem::Collection { name, .. } => name.clone(),
+                ResourceItem::KeyValueDb { database, .. } => database.clone(),
+            }
+        };
+
+        let inserted = self.tab_manager.update(cx, |mgr, cx| {
+            mgr.focused_tab()
+                .and_then(|tab| tab.as_pane().insert_text.as_ref())
+                .is_some_and(|insert| insert(&text, window, cx))
+        });
+
+        if !inserted {
+            self.new_query_tab_with_content(text, window, cx);
+        }
+    }
+
     pub(in crate::ui::views::workspace) fn open_collection_document(
         &mut self,
         profile_id: uuid::Uuid,
@@ -317,7 +548,14 @@ impl Workspace {
         ) -> Vec<crate::ui::document::DocumentId>,
         reference_id: crate::ui::document::DocumentId,
     ) {
-        let ids = selector(self.tab_manager.read(cx).documents(), reference_id);
+        // Detached documents are not in the tab bar, so tab bar batches skip them.
+        let ids: Vec<_> = {
+            let mgr = self.tab_manager.read(cx);
+            selector(mgr.documents(), reference_id)
+                .into_iter()
+                .filter(|&id| !mgr.is_detached(id))
+                .collect()
+        };
 
         for doc_id in ids {
             self.close_tab(doc_id, window, cx);
@@ -337,7 +575,8 @@ impl Workspace {
         });
     }
 
-    /// Closes the active tab.
+    /// Closes the focused tab: the active one, or the split pane's document
+    /// while that pane has focus.
     ///
     /// If the tab has unsaved changes, opens `ModalUnsavedChanges` instead of
     /// closing immediately. The modal's subscription in `Workspace::new` handles
@@ -347,7 +586,7 @@ impl Workspace {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let Some(doc_id) = self.tab_manager.read(cx).active_id() else {
+        let Some(doc_id) = self.tab_manager.read(cx).focused_id() else {
             return;
         };
 
@@ -0,0 +1,128 @@
+use super::*;
+use crate::keymap::{KeyChord, SequenceMatch};
+use dbflux_components::primitives::{Chord, Text};
+use dbflux_components::typography::MonoMeta;
+
+impl Workspace {
+    /// Feeds a keystroke to the multi-key bindings of the user keymap.
+    ///
+    /// Returns true when the key was consumed: it started, extended or
+    /// completed a sequence, or broke off one in progress. Keys that start
+    /// nothing are left to the single-chord bindings.
+    pub(in crate::ui::views::workspace) fn handle_key_sequence(
+        &mut self,
+        context: ContextId,
+        chord: &KeyChord,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if self.pending_keys.is_empty() {
+            // Plain keys are text while typing; only modified chords may
+            // start a sequence there.
+            let typing = context == ContextId::Editor || context.is_modal();
+            let modified = chord.modifiers.ctrl || chord.modifiers.alt || chord.modifiers.platform;
+
+            if (typing && !modified)
+                || self
+                    .keymap
+                    .resolve_sequence(context, std::slice::from_ref(chord))
+                    != SequenceMatch::Prefix
+            {
+                return false;
+            }
+
+            self.pending_keys.push(chord.clone());
+            cx.notify();
+            return true;
+        }
+
+        if chord.key == "escape" && !chord.modifiers.has_any() {
+            self.cancel_key_sequence(cx);
+            return true;
+        }
+
+        let mut keys = std::mem::take(&mut self.pending_keys);
+        keys.push(chord.clone());
+        cx.notify();
+
+        match self.keymap.resolve_sequence(context, &keys) {
+            SequenceMatch::Prefix => self.pending_keys = keys,
+            SequenceMatch::Command(command) => {
+                self.dispatch(command, window, cx);
+            }
+            SequenceMatch::None => {}
+        }
+
+        true
+    }
+
+    fn cancel_key_sequence(&mut self, cx: &mut Context<Self>) {
+        if !self.pending_keys.is_empty() {
+            self.pending_keys.clear();
+            cx.notify();
+        }
+    }
+
+    /// Popup listing the keys that can follow the ones typed so far.
+    pub(in crate::ui::views::workspace) fn render_which_key(
+        &self,
+        cx: &Context<Self>,
+    ) -> Option<AnyElement> {
+        if self.pending_keys.is_empty() {
+            return None;
+        }
+
+        let continuations = self
+            .keymap
+            .continuations(self.active_context(cx), &self.pending_keys);
+
+        let theme = cx.theme();
+        let typed = crate::keymap::format_sequence(&self.pending_keys);
+
+        let rows = continuations.into_iter().map(|(rest, command)| {
+            div()
+                .flex()
+                .items_center()
+                .gap(Spacing::SM)
+                .child(
+                    div()
+                        .flex()
+                        .gap(Spacing::XS)
+                        .children(rest.iter().map(chord_badge)),
+                )
+                .child(Text::dim_secondary(command.display_name()))
+        });
+
+        Some(
+            div()
+                .absolute()
+                .bottom(px(36.0))
+                .right(Spacing::MD)
+                .min_w(px(220.0))
+                .max_h(px(360.0))
+                .overflow_hidden()
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .p(Spacing::SM)
+                .bg(theme.popover)
+                .border_1()
+                .border_color(theme.border)
+                .rounded(Radii::MD)
+                .shadow_lg()
+                .child(MonoMeta::new(format!("{typed} …")).color(theme.muted_foreground))
+                .children(rows)
+                .into_any_element(),
+        )
+    }
+}
+
+fn chord_badge(chord: &KeyChord) -> Chord {
+    Chord::new(
+        chord
+            .to_string()
+            .split('+')
+            .map(|part| SharedString::from(part.to_string()))
+            .collect::<Vec<_>>(),
+    )
+}
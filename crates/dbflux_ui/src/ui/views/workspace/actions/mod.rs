@@ -78,14 +78,21 @@ fn collection_document_presentation_for_connection(
     }
 }
 
+mod app_control;
 mod audit;
 mod charts_dashboards;
 mod connections;
 mod documents;
+mod key_sequences;
 mod metrics;
+mod notifications;
 mod query;
+mod quit;
 mod scripts;
 mod settings;
+mod split;
+mod user_admin;
+mod windows;
 
 impl Workspace {
     pub(super) fn handle_command(
@@ -215,7 +222,10 @@ mod tests {
     // --- PaletteItem model tests ---
 
     use crate::ui::overlays::command_palette::{PaletteItem, PaletteSelection, ResourceItem};
-    use crate::ui::views::workspace::{build_resource_items_from_schema, map_item_to_selection};
+    use crate::ui::views::workspace::{
+        build_execution_target_items, build_resource_items_from_schema, is_system_resource,
+        map_item_to_selection,
+    };
     use dbflux_core::{
         CollectionInfo, DataStructure, DbSchemaInfo, DocumentSchema, KeySpaceInfo, KeyValueSchema,
         RelationalSchema, ScriptEntry, TableInfo, ViewInfo,
@@ -229,7 +239,7 @@ mod tests {
             id: "new_query_tab",
             name: "New Query Tab",
             category: "Editor",
-            shortcut: Some("Ctrl+N"),
+            shortcut: Some("ctrl-n".into()),
         }
     }
 
@@ -456,6 +466,7 @@ mod tests {
                     sample_fields: None,
                     presentation: dbflux_core::CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 },
                 TableInfo {
                     name: "orders".to_string(),
@@ -467,6 +478,7 @@ mod tests {
                     sample_fields: None,
                     presentation: dbflux_core::CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 },
             ],
             views: vec![ViewInfo {
@@ -518,6 +530,7 @@ mod tests {
                     sample_fields: None,
                     presentation: dbflux_core::CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 }],
                 views: vec![],
                 custom_types: None,
@@ -543,6 +556,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn system_resources_are_recognized() {
+        let table = |database: &str, schema: &str, name: &str| {
+            PaletteItem::Resource(ResourceItem::Table {
+                profile_id: Uuid::new_v4(),
+                profile_name: "pg-prod".to_string(),
+                database: Some(database.to_string()),
+                schema: Some(schema.to_string()),
+                name: name.to_string(),
+            })
+        };
+
+        assert!(is_system_resource(&table("app", "pg_catalog", "pg_class")));
+        assert!(is_system_resource(&table("mysql", "mysql", "user")));
+        assert!(is_system_resource(&table(
+            "main",
+            "main",
+            "sqlite_sequence"
+        )));
+        assert!(!is_system_resource(&table("app", "public", "users")));
+        assert!(!is_system_resource(&sample_action()));
+    }
+
     #[test]
     fn build_resources_from_document_schema() {
         let pid = Uuid::new_v4();
@@ -775,6 +811,60 @@ mod tests {
         }
     }
 
+    // --- Context-aware palette commands ---
+
+    #[test]
+    fn palette_hides_commands_the_focused_document_cannot_run() {
+        use crate::keymap::ContextId;
+        use crate::ui::document::DocumentKind;
+        use crate::ui::views::workspace::{PaletteScope, palette_commands_for};
+
+        let ids = |scope: PaletteScope| -> Vec<&'static str> {
+            palette_commands_for(&scope, Workspace::palette_commands_for_test())
+                .iter()
+                .map(|cmd| cmd.id)
+                .collect()
+        };
+
+        let sidebar = ids(PaletteScope {
+            context: ContextId::Sidebar,
+            document: None,
+        });
+        assert!(!sidebar.contains(&"run_query"));
+        assert!(!sidebar.contains(&"export_results"));
+        assert!(sidebar.contains(&"new_query_tab"));
+        assert_eq!(sidebar[0], "open_connection_manager");
+
+        let grid = ids(PaletteScope {
+            context: ContextId::Results,
+            document: Some(DocumentKind::Data),
+        });
+        assert!(!grid.contains(&"run_query"));
+        assert_eq!(&grid[..2], &["export_results", "toggle_row_inspector"]);
+
+        let editor = ids(PaletteScope {
+            context: ContextId::Editor,
+            document: Some(DocumentKind::Script),
+        });
+        assert_eq!(editor[0], "run_query");
+        assert!(editor.contains(&"export_results"));
+    }
+
+    #[test]
+    fn palette_shortcut_label_uses_kebab_chords() {
+        use crate::keymap::{KeyChord, Modifiers};
+        use crate::ui::views::workspace::palette_shortcut_label;
+
+        let chord = KeyChord::new("p", Modifiers::ctrl_shift());
+        assert_eq!(palette_shortcut_label(&[chord]), "ctrl-shift-p");
+
+        let sequence = [
+            KeyChord::new("space", Modifiers::none()),
+            KeyChord::new("left", Modifiers::alt()),
+        ];
+        assert_eq!(palette_shortcut_label(&sequence), "space alt-left");
+    }
+
     // --- Selection routing (map_item_to_selection) ---
 
     #[test]
@@ -783,7 +873,7 @@ mod tests {
             id: "new_query_tab",
             name: "New Query Tab",
             category: "Editor",
-            shortcut: Some("Ctrl+N"),
+            shortcut: Some("ctrl-n".into()),
         };
 
         let sel = map_item_to_selection(&item).unwrap();
@@ -809,6 +899,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn selection_routing_execution_target_switches_context() {
+        let pid = Uuid::new_v4();
+        let item = PaletteItem::ExecutionTarget {
+            profile_id: pid,
+            profile_name: "prod-pg".to_string(),
+            database: Some("billing".to_string()),
+            is_connected: true,
+        };
+
+        match map_item_to_selection(&item).unwrap() {
+            PaletteSelection::SwitchExecutionContext {
+                profile_id,
+                database,
+            } => {
+                assert_eq!(profile_id, pid);
+                assert_eq!(database.as_deref(), Some("billing"));
+            }
+            _ => panic!("Expected SwitchExecutionContext selection"),
+        }
+    }
+
+    #[test]
+    fn connection_switcher_lists_disconnected_profiles_without_databases() {
+        let profile = dbflux_core::ConnectionProfile::new(
+            "analytics",
+            dbflux_core::DbConfig::default_postgres(),
+        );
+        let items = build_execution_target_items(
+            std::slice::from_ref(&profile),
+            &std::collections::HashMap::new(),
+        );
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            items[0].display_label(),
+            ("Connection".to_string(), "analytics".to_string())
+        );
+        assert_eq!(items[0].qualifier().as_deref(), Some("not connected"));
+    }
+
     #[test]
     fn selection_routing_connected_profile_produces_focus_connection() {
         let pid = Uuid::new_v4();
@@ -918,6 +1049,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn selection_routing_routine_produces_open_routine() {
+        let pid = Uuid::new_v4();
+        let item = PaletteItem::Resource(ResourceItem::Routine {
+            profile_id: pid,
+            profile_name: "pg-prod".to_string(),
+            database: Some("app".to_string()),
+            schema: "public".to_string(),
+            name: "refresh_totals".to_string(),
+            specific_name: "refresh_totals_16402".to_string(),
+        });
+
+        let sel = map_item_to_selection(&item).unwrap();
+        match sel {
+            PaletteSelection::OpenRoutine {
+                profile_id,
+                schema,
+                specific_name,
+            } => {
+                assert_eq!(profile_id, pid);
+                assert_eq!(schema, "public");
+                assert_eq!(specific_name, "refresh_totals_16402");
+            }
+            _ => panic!("Expected OpenRoutine selection"),
+        }
+    }
+
     #[test]
     fn selection_routing_script_produces_open_script() {
         let path = PathBuf::from("/scripts/health-check.sql");
@@ -1318,6 +1476,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn saved_query_palette_item_maps_to_open_saved_query() {
+        let query =
+            dbflux_core::SavedQuery::new("Revenue".to_string(), "SELECT 1".to_string(), None)
+                .with_tags(vec!["billing".to_string()]);
+        let query_id = query.id;
+        let item = PaletteItem::SavedQuery {
+            query,
+            folder_path: Some("Reports / Monthly".to_string()),
+            connection_name: None,
+            driver_names: Vec::new(),
+        };
+
+        assert_eq!(item.display_label().1, "Revenue");
+        assert_eq!(
+            item.qualifier().as_deref(),
+            Some("Reports / Monthly  #billing")
+        );
+        assert!(matches!(
+            map_item_to_selection(&item),
+            Some(PaletteSelection::OpenSavedQuery { query_id: id }) if id == query_id
+        ));
+    }
+
     /// T19.1: Verify `DocumentKey::MetricChart` variant exists and carries the
     /// expected fields — compile-time contract for the dedup path.
     #[test]
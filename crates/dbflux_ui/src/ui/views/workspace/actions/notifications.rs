@@ -0,0 +1,78 @@
+use super::*;
+use crate::ui::document::{DocumentId, QueryCompletion};
+use dbflux_ui_base::notifications::query_finished_body;
+use dbflux_ui_base::toast::ToastKind;
+use uuid::Uuid;
+
+impl Workspace {
+    /// Activates the tab of `document_id` and focuses it. Used when a
+    /// notification about that document is clicked.
+    pub fn focus_document(
+        &mut self,
+        document_id: Uuid,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let id = DocumentId(document_id);
+
+        if self.tab_manager.read(cx).document(id).is_none() {
+            return;
+        }
+
+        self.tab_manager.update(cx, |mgr, cx| mgr.activate(id, cx));
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+
+    /// Raises an in-app and a desktop notification when a query that ran
+    /// past the configured threshold finishes while the user is looking
+    /// elsewhere (another tab, or another application).
+    pub(in crate::ui::views::workspace) fn notify_query_completed(
+        &mut self,
+        id: DocumentId,
+        completion: &QueryCompletion,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) {
+        let threshold = self
+            .app_state
+            .read(cx)
+            .general_settings()
+            .notify_long_query_secs;
+
+        if threshold == 0 || completion.elapsed.as_secs() < u64::from(threshold) {
+            return;
+        }
+
+        let tab_manager = self.tab_manager.read(cx);
+        if window.is_window_active() && tab_manager.active_id() == Some(id) {
+            return;
+        }
+
+        let Some(tab) = tab_manager.document(id) else {
+            return;
+        };
+
+        let (title, severity) = if completion.error.is_some() {
+            (
+                format!("Query failed: {}", tab.tab_title(cx)),
+                ToastKind::Error,
+            )
+        } else {
+            (
+                format!("Query finished: {}", tab.tab_title(cx)),
+                ToastKind::Success,
+            )
+        };
+        let body = query_finished_body(
+            completion.elapsed,
+            completion.row_count,
+            completion.error.as_deref(),
+        );
+
+        self.app_state.update(cx, |state, cx| {
+            state.push_notification(title.clone(), body.clone(), Some(id.0), severity, cx);
+        });
+
+        crate::desktop_notification::show(title, body, Some(id.0));
+    }
+}
@@ -1,4 +1,7 @@
 use super::*;
+use crate::ui::overlays::crash_recovery::CrashRecoveryResolved;
+use dbflux_storage::repositories::state::sessions::{RestoredSession, RestoredTab};
+use std::collections::HashSet;
 
 impl Workspace {
     /// Creates a new SQL query tab backed by a script file.
@@ -58,6 +61,18 @@ impl Workspace {
         sql: String,
         window: &mut Window,
         cx: &mut Context<Self>,
+    ) {
+        self.open_query_tab(sql, None, window, cx);
+    }
+
+    /// Opens `sql` in a new query tab, optionally linked to the saved query it
+    /// came from so runs prompt for that query's parameters.
+    fn open_query_tab(
+        &mut self,
+        sql: String,
+        saved_query_id: Option<uuid::Uuid>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
     ) {
         let query_language = self
             .app_state
@@ -89,6 +104,9 @@ impl Workspace {
                     .to_string();
                 doc = doc.with_title(title).with_path(path.clone());
             }
+            if let Some(saved_query_id) = saved_query_id {
+                doc = doc.with_saved_query(saved_query_id);
+            }
             doc.set_content(&sql, window, cx);
             doc
         });
@@ -120,10 +138,79 @@ impl Workspace {
         self.set_focus(FocusTarget::Document, window, cx);
     }
 
+    /// Builds palette items for every saved query, for the "Find Saved
+    /// Query..." command.
+    pub(in crate::ui::views::workspace) fn build_saved_query_palette_items(
+        &self,
+        cx: &Context<Self>,
+    ) -> Vec<PaletteItem> {
+        let app_state = self.app_state.read(cx);
+        let folders = app_state.saved_query_folders();
+        let profiles = app_state.profiles();
+
+        app_state
+            .saved_queries()
+            .iter()
+            .map(|query| {
+                let profile = query
+                    .connection_id
+                    .and_then(|id| profiles.iter().find(|p| p.id == id));
+
+                PaletteItem::SavedQuery {
+                    query: query.clone(),
+                    folder_path: query
+                        .folder_id
+                        .and_then(|id| dbflux_core::saved_query_folder_path(folders, id)),
+                    connection_name: profile.map(|p| p.name.clone()),
+                    driver_names: profile
+                        .map(|p| vec![p.driver_id(), p.kind().display_name().to_string()])
+                        .unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+
+    /// Opens a saved query in a new query tab linked to it, and records it as
+    /// used.
+    pub(in crate::ui::views::workspace) fn open_saved_query(
+        &mut self,
+        query_id: uuid::Uuid,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let sql = self
+            .app_state
+            .read(cx)
+            .saved_queries()
+            .iter()
+            .find(|query| query.id == query_id)
+            .map(|query| query.sql.clone());
+
+        let Some(sql) = sql else {
+            Toast::error("Saved query not found")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        self.app_state.update(cx, |state, _| {
+            state.update_saved_query_last_used(query_id);
+        });
+        self.open_query_tab(sql, Some(query_id), window, cx);
+    }
+
     /// Write the current tab state to the session manifest (dbflux.db-backed).
-    pub(in crate::ui::views::workspace) fn write_session_manifest(&self, cx: &mut App) {
+    ///
+    /// Skipped when nothing changed since the last write, in secondary
+    /// windows (their tabs are not part of the session), and while the crash
+    /// recovery prompt is open so the documents it offers stay referenced.
+    pub(in crate::ui::views::workspace) fn write_session_manifest(&mut self, cx: &mut App) {
         use dbflux_core::SessionTab;
 
+        if !self.is_main_window || self.crash_recovery.read(cx).is_visible() {
+            return;
+        }
+
         let runtime = self.app_state.read(cx).storage_runtime();
 
         let repo = runtime.sessions();
@@ -147,6 +234,9 @@ impl Workspace {
                     title: snap.title,
                     position: tabs.len(),
                     is_pinned: false,
+                    results_path: snap.results_path,
+                    data_state: snap.data_state,
+                    tab_group: manager.group_of(doc_tab.id()),
                 },
             );
         }
@@ -162,234 +252,267 @@ impl Workspace {
             tabs,
         };
 
+        let snapshot = serde_json::to_string(&manifest).ok();
+        if snapshot.is_some() && snapshot == self.last_session_snapshot {
+            return;
+        }
+
         if let Err(e) = repo.save_workspace_session(&manifest) {
             report_error(
                 UserFacingError::new(ErrorKind::Storage, "Failed to save session manifest")
                     .with_cause(format!("{e}")),
                 cx,
             );
+            return;
         }
+
+        self.last_session_snapshot = snapshot;
     }
 
-    /// Restore tabs from the session manifest on startup (dbflux.db-backed).
-    pub(in crate::ui::views::workspace) fn restore_session(
+    /// Reopens the documents picked in the crash recovery prompt with their
+    /// autosaved buffers, and deletes the buffers of the others.
+    pub(in crate::ui::views::workspace) fn apply_crash_recovery(
         &mut self,
+        resolved: &CrashRecoveryResolved,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let manifest = {
-            let app = self.app_state.read(cx);
-            let runtime = app.storage_runtime();
-            let repo = runtime.sessions();
-            let artifacts = runtime.artifacts();
-
-            match repo.restore_session(artifacts) {
-                Ok(Some(session)) => session,
-                Ok(None) => return,
-                Err(e) => {
-                    log::warn!("Failed to restore session from dbflux.db: {}", e);
-                    return;
-                }
-            }
-        };
-
-        if manifest.tabs.is_empty() {
-            return;
+        for document in &resolved.restore {
+            let open_tabs = self.tab_manager.read(cx).len();
+            self.restore_session_tab(&document.tab, true, window, cx);
+            self.restore_tab_group(open_tabs, document.tab.tab_group, cx);
         }
 
-        for tab in &manifest.tabs {
-            let manifest_language = match tab.language.as_str() {
-                "sql" => dbflux_core::QueryLanguage::Sql,
-                "mongo" => dbflux_core::QueryLanguage::MongoQuery,
-                "redis" => dbflux_core::QueryLanguage::RedisCommands,
-                "cypher" => dbflux_core::QueryLanguage::Cypher,
-                "lua" => dbflux_core::QueryLanguage::Lua,
-                "python" => dbflux_core::QueryLanguage::Python,
-                "bash" => dbflux_core::QueryLanguage::Bash,
-                _ => dbflux_core::QueryLanguage::Sql,
-            };
+        let artifacts = self.app_state.read(cx).storage_runtime().artifacts();
+        for document in &resolved.discard {
+            let tab = &document.tab;
+            for path in tab.shadow_path.iter().chain(&tab.scratch_path) {
+                artifacts.delete_file(path);
+            }
+        }
 
-            let language = match &tab.tab_kind[..] {
-                "FileBacked" => {
-                    if let Some(ref fp) = tab.file_path {
-                        dbflux_core::QueryLanguage::from_path(fp).unwrap_or(manifest_language)
-                    } else {
-                        manifest_language
-                    }
-                }
-                "Scratch" => {
-                    let title_path = std::path::Path::new(&tab.title);
-                    dbflux_core::QueryLanguage::from_path(title_path).unwrap_or(manifest_language)
-                }
-                _ => manifest_language,
-            };
+        self.write_session_manifest(cx);
+    }
 
-            // Routine tabs are persisted with their descriptor encoded in exec_ctx:
-            // connection_id=profile_id, schema=schema, container=specific_name.
-            // Reconstruct as a read-only document; the definition is re-fetched when the
-            // connection becomes available (handled by AppStateChanged in CodeDocument).
-            if tab.tab_kind == "Routine" {
-                let exec_ctx_json = tab.exec_ctx_json.as_str();
-                let exec_ctx: dbflux_core::ExecutionContext = serde_json::from_str(exec_ctx_json)
-                    .unwrap_or_else(|_| dbflux_core::ExecutionContext::default());
-
-                let Some(profile_id) = exec_ctx.connection_id else {
-                    log::warn!(
-                        "Routine tab '{}' has no profile_id in exec_ctx — skipping",
-                        tab.title
-                    );
-                    continue;
-                };
+    /// Loads the last session from dbflux.db. Session artifacts the session
+    /// no longer references are removed on the way.
+    pub(in crate::ui::views::workspace) fn load_last_session(
+        &self,
+        cx: &App,
+    ) -> Option<RestoredSession> {
+        let runtime = self.app_state.read(cx).storage_runtime();
 
-                let Some(schema) = exec_ctx.schema.clone() else {
-                    log::warn!(
-                        "Routine tab '{}' has no schema in exec_ctx — skipping",
-                        tab.title
-                    );
-                    continue;
-                };
+        match runtime.sessions().restore_session(runtime.artifacts()) {
+            Ok(session) => session,
+            Err(e) => {
+                log::warn!("Failed to restore session from dbflux.db: {}", e);
+                None
+            }
+        }
+    }
 
-                let Some(specific_name) = exec_ctx.container.clone() else {
-                    log::warn!(
-                        "Routine tab '{}' has no specific_name (container) in exec_ctx — skipping",
-                        tab.title
-                    );
-                    continue;
-                };
+    /// Restore tabs from the session manifest on startup (dbflux.db-backed).
+    ///
+    /// Tabs in `skip` are held back for the crash recovery prompt.
+    pub(in crate::ui::views::workspace) fn restore_session(
+        &mut self,
+        manifest: &RestoredSession,
+        skip: &HashSet<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for tab in manifest.tabs.iter().filter(|tab| !skip.contains(&tab.id)) {
+            let open_tabs = self.tab_manager.read(cx).len();
+            self.restore_session_tab(tab, false, window, cx);
+            self.restore_tab_group(open_tabs, tab.tab_group, cx);
+        }
 
-                let title = tab.title.clone();
-
-                let doc = cx.new(|cx| {
-                    // Pass Some(profile_id) as connection_id so the exec context
-                    // is pre-seeded; the connection might not be active yet.
-                    CodeDocument::new_with_language(
-                        self.app_state.clone(),
-                        Some(profile_id),
-                        language,
-                        window,
-                        cx,
-                    )
-                    .with_title(title)
-                    .with_read_only(cx)
-                    .with_routine_dedup(profile_id, schema, specific_name)
-                    .with_routine_definition_pending()
-                });
+        // Restore active tab, counting only the tabs that were reopened.
+        let active_idx = manifest.active_index.and_then(|active_idx| {
+            let active = manifest.tabs.get(active_idx)?;
+            if skip.contains(&active.id) {
+                return None;
+            }
 
-                // If the connection is already active at restore time, trigger
-                // the definition fetch immediately via the same path used by
-                // the AppStateChanged handler.
-                doc.update(cx, |d, cx| {
-                    d.try_fetch_pending_routine_definition(cx);
-                });
+            Some(
+                manifest.tabs[..active_idx]
+                    .iter()
+                    .filter(|tab| !skip.contains(&tab.id))
+                    .count(),
+            )
+        });
 
-                let pane = CodeDocument::into_pane(doc, cx);
+        if let Some(active_idx) = active_idx {
+            let docs: Vec<_> = self
+                .tab_manager
+                .read(cx)
+                .documents()
+                .iter()
+                .map(|d| d.id())
+                .collect();
 
+            if let Some(id) = docs.get(active_idx) {
                 self.tab_manager.update(cx, |mgr, cx| {
-                    mgr.open(Tab::Pane(Box::new(pane)), cx);
+                    mgr.activate(*id, cx);
                 });
+            }
+        }
+    }
 
-                continue;
+    /// Opens the most recently closed tab again, with its content,
+    /// connection and tab group. Tabs whose backing is gone are skipped.
+    pub(in crate::ui::views::workspace) fn reopen_closed_tab(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        use dbflux_core::SessionTab;
+
+        loop {
+            let Some(closed) = self
+                .tab_manager
+                .update(cx, |mgr, _| mgr.pop_recently_closed())
+            else {
+                Toast::info("No closed tabs to reopen").push(cx);
+                return;
+            };
+
+            let snap = closed.snapshot;
+            let tab = RestoredTab {
+                id: snap.id.0.to_string(),
+                title: snap.title,
+                tab_kind: snap.kind.to_string(),
+                language: SessionTab::language_key(snap.language),
+                scratch_path: snap.scratch_path,
+                shadow_path: snap.shadow_path,
+                file_path: snap.file_path,
+                exec_ctx_json: serde_json::to_string(&snap.exec_ctx)
+                    .unwrap_or_else(|_| "{}".to_string()),
+                position: 0,
+                is_pinned: false,
+                results_path: snap.results_path,
+                data_state: snap.data_state,
+                tab_group: closed.group,
+            };
+
+            let open_tabs = self.tab_manager.read(cx).len();
+            self.restore_session_tab(&tab, false, window, cx);
+            self.restore_tab_group(open_tabs, tab.tab_group, cx);
+
+            if self.tab_manager.read(cx).len() > open_tabs {
+                self.tab_manager
+                    .update(cx, |mgr, cx| mgr.focus_active(window, cx));
+                return;
             }
+        }
+    }
 
-            let (content, path, scratch_path, shadow_path) = match tab.tab_kind.as_str() {
-                "Scratch" => {
-                    let sp = match tab.scratch_path.as_ref() {
-                        Some(p) => p.clone(),
-                        None => {
-                            log::warn!(
-                                "Scratch tab '{}' has no scratch_path in restored session — skipping",
-                                tab.title
-                            );
-                            continue;
-                        }
-                    };
-                    let content = std::fs::read_to_string(&sp).unwrap_or_default();
-                    (content, None, Some(sp), None)
-                }
-                "FileBacked" => {
-                    let fp = match tab.file_path.as_ref() {
-                        Some(p) => p.clone(),
-                        None => {
-                            log::warn!(
-                                "FileBacked tab '{}' has no file_path in restored session — skipping",
-                                tab.title
-                            );
-                            continue;
-                        }
-                    };
-                    let content = if let Some(ref sh) = tab.shadow_path {
-                        let shadow_content = std::fs::read_to_string(sh).unwrap_or_default();
-                        let original_modified =
-                            std::fs::metadata(&fp).ok().and_then(|m| m.modified().ok());
-                        let shadow_modified =
-                            std::fs::metadata(sh).ok().and_then(|m| m.modified().ok());
-
-                        if let (Some(orig_t), Some(shad_t)) = (original_modified, shadow_modified) {
-                            if orig_t > shad_t {
-                                log::warn!(
-                                    "External edit detected for {}: using original file",
-                                    fp.display()
-                                );
-                                std::fs::read_to_string(&fp).unwrap_or(shadow_content)
-                            } else {
-                                shadow_content
-                            }
-                        } else {
-                            shadow_content
-                        }
-                    } else {
-                        std::fs::read_to_string(&fp).unwrap_or_default()
-                    };
+    /// Moves the tab just reopened by [`Self::restore_session_tab`] back into
+    /// its saved tab group. `open_tabs` is the tab count before reopening it;
+    /// nothing happens when the tab was skipped.
+    fn restore_tab_group(&mut self, open_tabs: usize, group: usize, cx: &mut Context<Self>) {
+        self.tab_manager.update(cx, |mgr, cx| {
+            if mgr.len() > open_tabs
+                && let Some(id) = mgr.active_id()
+            {
+                mgr.move_tab_to_group(id, group, cx);
+            }
+        });
+    }
 
-                    (content, Some(fp), None, tab.shadow_path.clone())
+    /// Reopens one tab of a restored session.
+    ///
+    /// When `recovering`, a file-backed tab always takes its autosaved buffer,
+    /// even if the file was changed on disk after the buffer was written.
+    pub(in crate::ui::views::workspace) fn restore_session_tab(
+        &mut self,
+        tab: &RestoredTab,
+        recovering: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let manifest_language = match tab.language.as_str() {
+            "sql" => dbflux_core::QueryLanguage::Sql,
+            "mongo" => dbflux_core::QueryLanguage::MongoQuery,
+            "redis" => dbflux_core::QueryLanguage::RedisCommands,
+            "cypher" => dbflux_core::QueryLanguage::Cypher,
+            "lua" => dbflux_core::QueryLanguage::Lua,
+            "python" => dbflux_core::QueryLanguage::Python,
+            "bash" => dbflux_core::QueryLanguage::Bash,
+            _ => dbflux_core::QueryLanguage::Sql,
+        };
+
+        let language = match &tab.tab_kind[..] {
+            "FileBacked" => {
+                if let Some(ref fp) = tab.file_path {
+                    dbflux_core::QueryLanguage::from_path(fp).unwrap_or(manifest_language)
+                } else {
+                    manifest_language
                 }
-                _ => continue,
-            };
+            }
+            "Scratch" => {
+                let title_path = std::path::Path::new(&tab.title);
+                dbflux_core::QueryLanguage::from_path(title_path).unwrap_or(manifest_language)
+            }
+            _ => manifest_language,
+        };
 
+        // Routine tabs are persisted with their descriptor encoded in exec_ctx:
+        // connection_id=profile_id, schema=schema, container=specific_name.
+        // Reconstruct as a read-only document; the definition is re-fetched when the
+        // connection becomes available (handled by AppStateChanged in CodeDocument).
+        if tab.tab_kind == "Routine" {
             let exec_ctx_json = tab.exec_ctx_json.as_str();
             let exec_ctx: dbflux_core::ExecutionContext = serde_json::from_str(exec_ctx_json)
                 .unwrap_or_else(|_| dbflux_core::ExecutionContext::default());
 
-            let connection_id = exec_ctx
-                .connection_id
-                .filter(|id| self.app_state.read(cx).connections().contains_key(id));
+            let Some(profile_id) = exec_ctx.connection_id else {
+                log::warn!(
+                    "Routine tab '{}' has no profile_id in exec_ctx — skipping",
+                    tab.title
+                );
+                return;
+            };
 
-            let body = Self::strip_annotation_header(&content, &language);
+            let Some(schema) = exec_ctx.schema.clone() else {
+                log::warn!(
+                    "Routine tab '{}' has no schema in exec_ctx — skipping",
+                    tab.title
+                );
+                return;
+            };
 
-            let title = if tab.tab_kind == "Scratch" {
-                tab.title.clone()
-            } else {
-                tab.file_path
-                    .as_ref()
-                    .and_then(|p| p.file_name())
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("Untitled")
-                    .to_string()
+            let Some(specific_name) = exec_ctx.container.clone() else {
+                log::warn!(
+                    "Routine tab '{}' has no specific_name (container) in exec_ctx — skipping",
+                    tab.title
+                );
+                return;
             };
 
+            let title = tab.title.clone();
+
             let doc = cx.new(|cx| {
-                let mut doc = CodeDocument::new_with_language(
+                // Pass Some(profile_id) as connection_id so the exec context
+                // is pre-seeded; the connection might not be active yet.
+                CodeDocument::new_with_language(
                     self.app_state.clone(),
-                    connection_id,
+                    Some(profile_id),
                     language,
                     window,
                     cx,
-                );
-
-                doc.set_session_paths(scratch_path.clone(), shadow_path.clone());
-
-                if let Some(p) = path {
-                    doc = doc.with_path(p);
-                }
-
-                doc = doc.with_title(title).with_exec_ctx(exec_ctx, cx);
-                doc.set_content(body, window, cx);
-
-                if tab.tab_kind == "FileBacked" && tab.shadow_path.is_some() {
-                    doc.restore_dirty(cx);
-                }
+                )
+                .with_title(title)
+                .with_read_only(cx)
+                .with_routine_dedup(profile_id, schema, specific_name)
+                .with_routine_definition_pending()
+            });
 
-                doc
+            // If the connection is already active at restore time, trigger
+            // the definition fetch immediately via the same path used by
+            // the AppStateChanged handler.
+            doc.update(cx, |d, cx| {
+                d.try_fetch_pending_routine_definition(cx);
             });
 
             let pane = CodeDocument::into_pane(doc, cx);
@@ -397,23 +520,157 @@ impl Workspace {
             self.tab_manager.update(cx, |mgr, cx| {
                 mgr.open(Tab::Pane(Box::new(pane)), cx);
             });
+
+            return;
         }
 
-        // Restore active tab
-        if let Some(active_idx) = manifest.active_index {
-            let docs: Vec<_> = self
-                .tab_manager
+        // Table and collection tabs: reopened on their saved filter, sort and
+        // page; the rows are fetched when the tab is first shown.
+        if tab.tab_kind == "Data" {
+            let Some(state) = tab.data_state.as_ref() else {
+                log::warn!(
+                    "Data tab '{}' has no browse state in restored session — skipping",
+                    tab.title
+                );
+                return;
+            };
+
+            let known_profile = self
+                .app_state
                 .read(cx)
-                .documents()
+                .profiles()
                 .iter()
-                .map(|d| d.id())
-                .collect();
+                .any(|profile| profile.id == state.profile_id);
 
-            if let Some(id) = docs.get(active_idx) {
-                self.tab_manager.update(cx, |mgr, cx| {
-                    mgr.activate(*id, cx);
-                });
+            if !known_profile {
+                log::warn!(
+                    "Data tab '{}' belongs to a deleted connection — skipping",
+                    tab.title
+                );
+                return;
             }
+
+            let doc = cx.new(|cx| DataDocument::restore(state, self.app_state.clone(), window, cx));
+            let pane = DataDocument::into_pane(doc, cx);
+
+            self.tab_manager.update(cx, |mgr, cx| {
+                mgr.open(Tab::Pane(Box::new(pane)), cx);
+            });
+
+            return;
         }
+
+        let (content, path, scratch_path, shadow_path) = match tab.tab_kind.as_str() {
+            "Scratch" => {
+                let sp = match tab.scratch_path.as_ref() {
+                    Some(p) => p.clone(),
+                    None => {
+                        log::warn!(
+                            "Scratch tab '{}' has no scratch_path in restored session — skipping",
+                            tab.title
+                        );
+                        return;
+                    }
+                };
+                let content = std::fs::read_to_string(&sp).unwrap_or_default();
+                (content, None, Some(sp), None)
+            }
+            "FileBacked" => {
+                let fp = match tab.file_path.as_ref() {
+                    Some(p) => p.clone(),
+                    None => {
+                        log::warn!(
+                            "FileBacked tab '{}' has no file_path in restored session — skipping",
+                            tab.title
+                        );
+                        return;
+                    }
+                };
+                let content = if let Some(ref sh) = tab.shadow_path {
+                    let shadow_content = std::fs::read_to_string(sh).unwrap_or_default();
+                    let original_modified =
+                        std::fs::metadata(&fp).ok().and_then(|m| m.modified().ok());
+                    let shadow_modified =
+                        std::fs::metadata(sh).ok().and_then(|m| m.modified().ok());
+
+                    if let (Some(orig_t), Some(shad_t)) = (original_modified, shadow_modified) {
+                        if orig_t > shad_t && !recovering {
+                            log::warn!(
+                                "External edit detected for {}: using original file",
+                                fp.display()
+                            );
+                            std::fs::read_to_string(&fp).unwrap_or(shadow_content)
+                        } else {
+                            shadow_content
+                        }
+                    } else {
+                        shadow_content
+                    }
+                } else {
+                    std::fs::read_to_string(&fp).unwrap_or_default()
+                };
+
+                (content, Some(fp), None, tab.shadow_path.clone())
+            }
+            _ => return,
+        };
+
+        let exec_ctx_json = tab.exec_ctx_json.as_str();
+        let exec_ctx: dbflux_core::ExecutionContext = serde_json::from_str(exec_ctx_json)
+            .unwrap_or_else(|_| dbflux_core::ExecutionContext::default());
+
+        let connection_id = exec_ctx
+            .connection_id
+            .filter(|id| self.app_state.read(cx).connections().contains_key(id));
+
+        let body = Self::strip_annotation_header(&content, &language);
+
+        let title = if tab.tab_kind == "Scratch" {
+            tab.title.clone()
+        } else {
+            tab.file_path
+                .as_ref()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .unwrap_or("Untitled")
+                .to_string()
+        };
+
+        let doc = cx.new(|cx| {
+            let mut doc = CodeDocument::new_with_language(
+                self.app_state.clone(),
+                connection_id,
+                language,
+                window,
+                cx,
+            );
+
+            doc.set_session_paths(scratch_path.clone(), shadow_path.clone());
+
+            if let Some(results_path) = tab.results_path.clone() {
+                doc.set_results_path(results_path);
+            }
+
+            if let Some(p) = path {
+                doc = doc.with_path(p);
+            }
+
+            doc = doc.with_title(title).with_exec_ctx(exec_ctx, cx);
+            doc.set_content(body, window, cx);
+
+            if tab.tab_kind == "FileBacked" && tab.shadow_path.is_some() {
+                doc.restore_dirty(cx);
+            }
+
+            doc.restore_kept_results(window, cx);
+
+            doc
+        });
+
+        let pane = CodeDocument::into_pane(doc, cx);
+
+        self.tab_manager.update(cx, |mgr, cx| {
+            mgr.open(Tab::Pane(Box::new(pane)), cx);
+        });
     }
 }
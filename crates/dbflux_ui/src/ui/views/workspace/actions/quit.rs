@@ -0,0 +1,172 @@
+use super::*;
+use crate::ui::document::DocumentId;
+use crate::ui::overlays::modals::{
+    DirtySummaryEntry, UnsavedChangesOutcome, UnsavedChangesRequest,
+};
+use std::time::{Duration, Instant};
+
+/// How often a save-before-quit checks whether the documents are clean.
+const QUIT_SAVE_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Quitting is called off when the documents picked for saving are still
+/// dirty after this long (Save As dialog dismissed, failed commit, ...).
+const QUIT_SAVE_TIMEOUT: Duration = Duration::from_secs(60);
+
+impl Workspace {
+    /// Called when the window is asked to close.
+    ///
+    /// Returns `true` when nothing is unsaved and the main window can start
+    /// shutdown (or a secondary window can close) right away. Otherwise opens
+    /// the unsaved-changes prompt and returns `false`; `QuitConfirmed` is
+    /// emitted once the user has saved or discarded the changes, and nothing
+    /// happens if they cancel.
+    ///
+    /// Quitting from the main window first brings up the prompt of any other
+    /// window with unsaved changes.
+    pub fn confirm_quit(&mut self, cx: &mut Context<Self>) -> bool {
+        if self.is_main_window
+            && let Some(secondary) = Self::secondary_with_unsaved_changes(cx)
+        {
+            secondary.update(cx, |workspace, cx| {
+                workspace.confirm_quit(cx);
+                workspace.activate_own_window(cx);
+            });
+            return false;
+        }
+
+        let dirty = self.tab_manager.read(cx).dirty_summaries(cx);
+        if dirty.is_empty() {
+            return true;
+        }
+
+        // A prompt (or a save started from it) is already in progress.
+        if !self.begin_close_prompt(cx) {
+            return false;
+        }
+
+        let entries = {
+            let mgr = self.tab_manager.read(cx);
+            dirty
+                .into_iter()
+                .map(|(id, summary)| DirtySummaryEntry {
+                    id,
+                    name: mgr
+                        .document(id)
+                        .map(|doc| doc.tab_title(cx))
+                        .unwrap_or_else(|| "Untitled".to_string()),
+                    summary,
+                })
+                .collect()
+        };
+
+        self.modal_unsaved_changes.update(cx, |modal, cx| {
+            modal.open(UnsavedChangesRequest { entries }, cx);
+        });
+
+        false
+    }
+
+    /// Whether the unsaved-changes modal was opened by `confirm_quit` and is
+    /// still waiting for an answer.
+    pub(in crate::ui::views::workspace) fn is_quit_prompt_pending(&self, cx: &App) -> bool {
+        let confirming = if self.is_main_window {
+            self.app_state.read(cx).is_confirming_shutdown()
+        } else {
+            self.close_prompt_pending
+        };
+
+        self.quit_save_task.is_none() && confirming
+    }
+
+    /// Marks the close prompt as open; `false` when it already is. The main
+    /// window's prompt is app-wide, as it guards the shutdown.
+    fn begin_close_prompt(&mut self, cx: &App) -> bool {
+        if self.is_main_window {
+            return self.app_state.read(cx).begin_shutdown_confirmation();
+        }
+
+        !std::mem::replace(&mut self.close_prompt_pending, true)
+    }
+
+    fn cancel_close_prompt(&mut self, cx: &App) {
+        if self.is_main_window {
+            self.app_state.read(cx).cancel_shutdown_confirmation();
+        } else {
+            self.close_prompt_pending = false;
+        }
+    }
+
+    /// Applies the answer to the quit prompt. "Don't save" quits with the
+    /// tabs left as they are, so the session can still be restored.
+    pub(in crate::ui::views::workspace) fn resolve_quit_prompt(
+        &mut self,
+        outcome: &UnsavedChangesOutcome,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match outcome {
+            UnsavedChangesOutcome::DiscardAll => cx.emit(QuitConfirmed),
+            UnsavedChangesOutcome::Cancelled => {
+                self.cancel_close_prompt(cx);
+            }
+            UnsavedChangesOutcome::SaveSelected(ids) => {
+                self.save_before_quit(ids.clone(), window, cx);
+            }
+        }
+    }
+
+    /// Saves the given documents (scripts in place or through Save As, grid
+    /// edits through the usual commit path) and quits once all of them are
+    /// clean.
+    fn save_before_quit(
+        &mut self,
+        ids: Vec<DocumentId>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for id in &ids {
+            self.tab_manager.update(cx, |mgr, cx| {
+                if let Some(tab) = mgr.document(*id) {
+                    tab.dispatch_command(crate::keymap::Command::SaveQuery, window, cx);
+                }
+            });
+        }
+
+        let started = Instant::now();
+
+        self.quit_save_task = Some(cx.spawn(async move |this, cx| {
+            loop {
+                let saved = this.update(cx, |this, cx| {
+                    let dirty = this.tab_manager.read(cx).dirty_summaries(cx);
+                    !dirty.iter().any(|(id, _)| ids.contains(id))
+                });
+
+                match saved {
+                    Ok(true) => break,
+                    Ok(false) => {}
+                    Err(_) => return,
+                }
+
+                if started.elapsed() > QUIT_SAVE_TIMEOUT {
+                    let _ = this.update(cx, |this, cx| {
+                        this.quit_save_task = None;
+                        this.cancel_close_prompt(cx);
+                        Toast::warning("Quit cancelled: some documents were not saved")
+                            .meta_right(now_hms())
+                            .push(cx);
+                    });
+                    return;
+                }
+
+                cx.background_executor()
+                    .timer(QUIT_SAVE_POLL_INTERVAL)
+                    .await;
+            }
+
+            let _ = this.update(cx, |this, cx| {
+                this.quit_save_task = None;
+                cx.emit(QuitConfirmed);
+            });
+        }));
+    }
+}
@@ -15,6 +15,7 @@ impl Workspace {
                 .add_filter("SQL Files", &["sql"])
                 .add_filter("JavaScript (MongoDB)", &["js", "mongodb"])
                 .add_filter("Redis", &["redis", "red"])
+                .add_filter("Notebooks", &[dbflux_core::NOTEBOOK_EXTENSION])
                 .add_filter("All Files", &["*"])
                 .pick_file()
                 .await;
@@ -76,7 +77,7 @@ impl Workspace {
 
             if let Err(error) = cx.update(|cx| {
                 this.update(cx, |ws, cx| {
-                    ws.open_script_with_content(path, content, cx);
+                    ws.open_script_with_content(path, content, None, cx);
                 })
                 .unwrap_or_else(|inner_error| {
                     log::warn!(
@@ -96,6 +97,48 @@ impl Workspace {
 
     /// Opens a script file from a known path (e.g., from sidebar recent files).
     pub fn open_script_from_path(&mut self, path: std::path::PathBuf, cx: &mut Context<Self>) {
+        self.open_script_from_path_with_profile(path, None, cx);
+    }
+
+    /// Opens a file passed on the command line, running it against
+    /// `profile` (a profile name or id) when given.
+    pub fn open_file_from_cli(
+        &mut self,
+        path: std::path::PathBuf,
+        profile: Option<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let profile_id = profile.and_then(|key| {
+            let found = find_profile_id(self.app_state.read(cx).profiles(), &key);
+            if found.is_none() {
+                Toast::warning(format!("No connection profile named \"{key}\""))
+                    .meta_right(now_hms())
+                    .push(cx);
+            }
+            found
+        });
+
+        let already_open = self.tab_manager.read(cx).find_by_key(
+            &crate::ui::document::DocumentKey::File { path: path.clone() },
+            cx,
+        );
+
+        match (already_open, profile_id) {
+            (Some(id), Some(profile_id)) => {
+                self.tab_manager.update(cx, |mgr, cx| mgr.activate(id, cx));
+                self.switch_execution_context(profile_id, None, window, cx);
+            }
+            _ => self.open_script_from_path_with_profile(path, profile_id, cx),
+        }
+    }
+
+    fn open_script_from_path_with_profile(
+        &mut self,
+        path: std::path::PathBuf,
+        profile_id: Option<uuid::Uuid>,
+        cx: &mut Context<Self>,
+    ) {
         let tab_manager = self.tab_manager.clone();
 
         // Check if already open
@@ -134,7 +177,7 @@ impl Workspace {
 
             if let Err(error) = cx.update(|cx| {
                 this.update(cx, |ws, cx| {
-                    ws.open_script_with_content(path, content, cx);
+                    ws.open_script_with_content(path, content, profile_id, cx);
                 })
                 .unwrap_or_else(|inner_error| {
                     log::warn!(
@@ -274,10 +317,16 @@ impl Workspace {
         &mut self,
         path: std::path::PathBuf,
         content: String,
+        profile_id: Option<uuid::Uuid>,
         cx: &mut Context<Self>,
     ) {
         use dbflux_core::{ExecutionContext, QueryLanguage};
 
+        if dbflux_core::is_notebook_path(&path) {
+            self.open_notebook_with_content(path, &content, cx);
+            return;
+        }
+
         let language = QueryLanguage::from_path(&path).unwrap_or(QueryLanguage::Sql);
         let uses_connection_context = language.supports_connection_context();
 
@@ -320,6 +369,7 @@ impl Workspace {
             language,
             connection_id,
             exec_ctx,
+            switch_to_profile: profile_id.filter(|_| uses_connection_context),
         });
         cx.notify();
     }
@@ -356,5 +406,154 @@ impl Workspace {
         });
 
         self.set_focus(FocusTarget::Document, window, cx);
+
+        if let Some(profile_id) = pending.switch_to_profile {
+            self.switch_execution_context(profile_id, None, window, cx);
+        }
+    }
+
+    /// Creates a new notebook file in the scripts directory and opens it.
+    pub(in crate::ui::views::workspace) fn new_notebook(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let extension = dbflux_core::NOTEBOOK_EXTENSION;
+
+        let notebook_path = self.app_state.update(cx, |state, cx| {
+            let dir = state.scripts_directory_mut()?;
+            let name = dir.next_available_name("Notebook", extension);
+            let path = dir.create_file(None, &name, extension).ok();
+            if path.is_some() {
+                cx.emit(AppStateChanged);
+            }
+            path
+        });
+
+        let Some(path) = notebook_path else {
+            Toast::warning("Notebooks need a scripts directory to be saved in.")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        self.finalize_open_notebook(
+            PendingOpenNotebook {
+                path,
+                notebook: dbflux_core::Notebook::default(),
+            },
+            window,
+            cx,
+        );
+    }
+
+    fn open_notebook_with_content(
+        &mut self,
+        path: std::path::PathBuf,
+        content: &str,
+        cx: &mut Context<Self>,
+    ) {
+        let notebook = match dbflux_core::Notebook::from_json(content) {
+            Ok(notebook) => notebook,
+            Err(e) => {
+                report_error(
+                    UserFacingError::new(
+                        ErrorKind::Storage,
+                        format!("Failed to open notebook {}: {e}", path.display()),
+                    ),
+                    cx,
+                );
+                return;
+            }
+        };
+
+        self.app_state.update(cx, |state, cx| {
+            state.record_recent_file(path.clone());
+            cx.emit(AppStateChanged);
+        });
+
+        self.pending_open_notebook = Some(PendingOpenNotebook { path, notebook });
+        cx.notify();
+    }
+
+    pub(in crate::ui::views::workspace) fn finalize_open_notebook(
+        &mut self,
+        pending: PendingOpenNotebook,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let doc = cx.new(|cx| {
+            NotebookDocument::new(
+                self.app_state.clone(),
+                pending.path,
+                pending.notebook,
+                window,
+                cx,
+            )
+        });
+
+        let pane = NotebookDocument::into_pane(doc, cx);
+
+        self.tab_manager.update(cx, |mgr, cx| {
+            mgr.open(Tab::Pane(Box::new(pane)), cx);
+        });
+
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+}
+
+/// Finds a profile by id or, failing that, by exact name.
+pub(super) fn find_profile_id(
+    profiles: &[dbflux_core::ConnectionProfile],
+    key: &str,
+) -> Option<uuid::Uuid> {
+    let by_id = key
+        .parse::<uuid::Uuid>()
+        .ok()
+        .filter(|id| profiles.iter().any(|profile| profile.id == *id));
+
+    by_id.or_else(|| {
+        profiles
+            .iter()
+            .find(|profile| profile.name == key)
+            .map(|profile| profile.id)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_profile_id;
+    use dbflux_core::{ConnectionProfile, DbConfig};
+
+    fn profiles() -> Vec<ConnectionProfile> {
+        vec![
+            ConnectionProfile::new("prod", DbConfig::default_postgres()),
+            ConnectionProfile::new("dev", DbConfig::default_postgres()),
+        ]
+    }
+
+    #[test]
+    fn profile_is_found_by_id_or_exact_name() {
+        let profiles = profiles();
+
+        assert_eq!(
+            find_profile_id(&profiles, &profiles[1].id.to_string()),
+            Some(profiles[1].id)
+        );
+        assert_eq!(find_profile_id(&profiles, "prod"), Some(profiles[0].id));
+        assert_eq!(find_profile_id(&profiles, "Prod"), None);
+    }
+
+    #[test]
+    fn unknown_id_falls_back_to_a_profile_with_that_name() {
+        let mut profiles = profiles();
+        let unknown = uuid::Uuid::new_v4().to_string();
+        profiles[1].name = unknown.clone();
+
+        assert_eq!(find_profile_id(&profiles, &unknown), Some(profiles[1].id));
+        assert_eq!(
+            find_profile_id(&profiles, &uuid::Uuid::new_v4().to_string()),
+            None
+        );
     }
 }
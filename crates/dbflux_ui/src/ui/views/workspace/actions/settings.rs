@@ -73,4 +73,52 @@ impl Workspace {
             wizard.open(window, cx);
         });
     }
+
+    /// Zooms in or out by `steps` zoom steps; 0 resets to 100%.
+    pub(in crate::ui::views::workspace) fn step_ui_zoom(
+        &mut self,
+        steps: i32,
+        cx: &mut Context<Self>,
+    ) {
+        let current = self.app_state.read(cx).general_settings().ui_zoom_percent;
+        let percent = if steps == 0 {
+            dbflux_core::DEFAULT_UI_ZOOM_PERCENT
+        } else {
+            let step = i32::from(dbflux_core::UI_ZOOM_STEP_PERCENT);
+            (i32::from(current) + steps * step).clamp(
+                i32::from(dbflux_core::MIN_UI_ZOOM_PERCENT),
+                i32::from(dbflux_core::MAX_UI_ZOOM_PERCENT),
+            ) as u16
+        };
+
+        if percent == current {
+            return;
+        }
+
+        let runtime = self.app_state.read(cx).storage_runtime();
+        let mut settings = self.app_state.read(cx).general_settings().clone();
+        settings.ui_zoom_percent = percent;
+
+        if let Err(e) = dbflux_app::config_loader::save_general_settings(runtime, &settings) {
+            report_error(
+                UserFacingError::new(ErrorKind::Storage, format!("Failed to save zoom: {e}")),
+                cx,
+            );
+        }
+
+        dbflux_components::density::set_font_scale(
+            cx,
+            dbflux_components::density::FontScale::from_settings(&settings),
+        );
+        dbflux_components::theme::apply_ui_zoom(cx);
+
+        self.app_state.update(cx, |state, _cx| {
+            state.update_general_settings(settings);
+        });
+
+        cx.refresh_windows();
+        Toast::info(format!("Zoom {percent}%"))
+            .meta_right(now_hms())
+            .push(cx);
+    }
 }
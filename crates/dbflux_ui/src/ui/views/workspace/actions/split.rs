@@ -0,0 +1,151 @@
+use super::*;
+use crate::ui::document::DocumentId;
+
+impl Workspace {
+    /// Moves the active document into a split pane placed `direction` of the
+    /// main pane; the main pane falls back to the previous tab. With a split
+    /// already open, only its placement changes.
+    pub(in crate::ui::views::workspace) fn split_active_document(
+        &mut self,
+        direction: SplitDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(split) = &mut self.split {
+            split.direction = direction;
+            cx.notify();
+            return;
+        }
+
+        let (active_id, docked) = {
+            let mgr = self.tab_manager.read(cx);
+            (mgr.active_id(), mgr.docked_ids().len())
+        };
+
+        let Some(id) = active_id else {
+            return;
+        };
+
+        if docked < 2 {
+            Toast::info("Open another tab to split")
+                .body("The split pane shows a document next to the active tab.")
+                .push(cx);
+            return;
+        }
+
+        self.open_split(id, direction, window, cx);
+    }
+
+    fn open_split(
+        &mut self,
+        id: DocumentId,
+        direction: SplitDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.tab_manager.update(cx, |mgr, cx| {
+            mgr.detach(id, cx);
+            mgr.set_split_focus(Some(id), cx);
+        });
+
+        self.split = Some(SplitPane {
+            document_id: id,
+            direction,
+        });
+
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+
+    /// Moves keyboard focus between the main pane and the split pane.
+    pub(in crate::ui::views::workspace) fn focus_other_pane(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(split) = self.split else {
+            return;
+        };
+
+        let target = match self.tab_manager.read(cx).split_focus() {
+            Some(_) => None,
+            None => Some(split.document_id),
+        };
+
+        self.tab_manager
+            .update(cx, |mgr, cx| mgr.set_split_focus(target, cx));
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+
+    /// Gives focus to the split pane's document.
+    pub(in crate::ui::views::workspace) fn focus_split_pane(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(split) = self.split else {
+            return;
+        };
+
+        self.tab_manager.update(cx, |mgr, cx| {
+            mgr.set_split_focus(Some(split.document_id), cx)
+        });
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+
+    /// Moves the focused document to the other pane. From the split pane the
+    /// document goes back to the tab bar and the split closes; from the main
+    /// pane the active document swaps places with the split pane's one, or a
+    /// split to the right is opened for it.
+    pub(in crate::ui::views::workspace) fn move_to_other_pane(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(split) = self.split else {
+            self.split_active_document(SplitDirection::Right, window, cx);
+            return;
+        };
+
+        if self.tab_manager.read(cx).split_focus().is_some() {
+            self.close_split(window, cx);
+            return;
+        }
+
+        let Some(active_id) = self.tab_manager.read(cx).active_id() else {
+            return;
+        };
+
+        self.split = None;
+        self.tab_manager
+            .update(cx, |mgr, cx| mgr.redock(split.document_id, cx));
+        self.open_split(active_id, split.direction, window, cx);
+    }
+
+    /// Closes the split pane; its document goes back to the tab bar as the
+    /// active tab.
+    pub(in crate::ui::views::workspace) fn close_split(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(split) = self.split.take() else {
+            return;
+        };
+
+        self.tab_manager
+            .update(cx, |mgr, cx| mgr.redock(split.document_id, cx));
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+
+    /// Drops the split once its document was closed or docked elsewhere.
+    pub(in crate::ui::views::workspace) fn forget_split_document(
+        &mut self,
+        id: DocumentId,
+        cx: &mut Context<Self>,
+    ) {
+        if self.split.is_some_and(|split| split.document_id == id) {
+            self.split = None;
+            cx.notify();
+        }
+    }
+}
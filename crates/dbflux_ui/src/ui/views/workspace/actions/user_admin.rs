@@ -0,0 +1,54 @@
+use super::*;
+
+impl Workspace {
+    /// Open the users and roles administration document for a connected profile.
+    ///
+    /// Deduplicates by `profile_id` using `DocumentKey::UserAdmin`, so asking
+    /// again focuses the existing tab.
+    pub(in crate::ui::views::workspace) fn open_user_admin(
+        &mut self,
+        profile_id: uuid::Uuid,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        use crate::ui::document::{DocumentKey, UserAdminDocument};
+        use dbflux_core::UserAdminDialect;
+
+        let key = DocumentKey::UserAdmin { profile_id };
+
+        if let Some(existing_id) = self.tab_manager.read(cx).find_by_key(&key, cx) {
+            self.tab_manager.update(cx, |mgr, cx| {
+                mgr.activate(existing_id, cx);
+            });
+            self.set_focus(FocusTarget::Document, window, cx);
+            return;
+        }
+
+        let dialect = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(&profile_id)
+            .and_then(|connected| UserAdminDialect::for_kind(connected.profile.kind()));
+
+        let Some(dialect) = dialect else {
+            Toast::warning("This connection does not support user management")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        let doc =
+            cx.new(|cx| UserAdminDocument::new(profile_id, dialect, self.app_state.clone(), cx));
+
+        doc.update(cx, |doc, cx| {
+            doc.refresh(cx);
+        });
+
+        let pane = UserAdminDocument::into_pane(doc, cx);
+        self.tab_manager.update(cx, |mgr, cx| {
+            mgr.open(Tab::Pane(Box::new(pane)), cx);
+        });
+        self.set_focus(FocusTarget::Document, window, cx);
+    }
+}
@@ -0,0 +1,301 @@
+use super::*;
+use crate::ui::document::DocumentId;
+use crate::ui::views::workspace::detached::DetachedDocumentWindow;
+
+/// Workspaces of the windows opened with "New Window", so the main window can
+/// check them for unsaved changes before quitting.
+#[derive(Default)]
+struct SecondaryWorkspaces(Vec<WeakEntity<Workspace>>);
+
+impl Global for SecondaryWorkspaces {}
+
+impl Workspace {
+    /// Opens another top-level window with its own tabs over the same
+    /// connections. With `profile_id` its sidebar only shows that connection,
+    /// which is connected if it is not already.
+    pub(in crate::ui::views::workspace) fn open_workspace_window(
+        &self,
+        profile_id: Option<uuid::Uuid>,
+        cx: &mut Context<Self>,
+    ) {
+        let app_state = self.app_state.clone();
+        let channel = dbflux_core::ReleaseChannel::current();
+
+        let profile_name = profile_id.and_then(|id| {
+            app_state
+                .read(cx)
+                .profiles()
+                .iter()
+                .find(|profile| profile.id == id)
+                .map(|profile| profile.name.clone())
+        });
+
+        let title = match profile_name {
+            Some(name) => format!("{} — {}", name, channel.display_name()),
+            None => channel.display_name().to_string(),
+        };
+
+        let secondary = SecondaryWindow {
+            export_scheduler: self.export_scheduler.clone(),
+            profile_scope: profile_id.map(|id| std::collections::HashSet::from([id])),
+        };
+
+        let mut options = WindowOptions {
+            app_id: Some(channel.app_id().into()),
+            titlebar: Some(TitlebarOptions {
+                title: Some(title.into()),
+                ..Default::default()
+            }),
+            window_decorations: platform::main_window_decoration_request(),
+            focus: true,
+            ..Default::default()
+        };
+        platform::apply_window_options(&mut options, 800.0, 600.0);
+
+        let result = cx.open_window(options, move |window, cx| {
+            let workspace = cx.new(|cx| Workspace::new_secondary(app_state, secondary, window, cx));
+
+            cx.default_global::<SecondaryWorkspaces>()
+                .0
+                .push(workspace.downgrade());
+
+            // Unsaved changes are resolved in the workspace; the window closes
+            // once they have been saved or discarded.
+            let handle = window.window_handle();
+            cx.subscribe(&workspace, move |workspace, _: &QuitConfirmed, cx| {
+                workspace.update(cx, |workspace, cx| workspace.close_detached_windows(cx));
+                let _ = handle.update(cx, |_, window, _| window.remove_window());
+            })
+            .detach();
+
+            let closing = workspace.clone();
+            window.on_window_should_close(cx, move |_window, cx| {
+                closing.update(cx, |workspace, cx| workspace.confirm_quit(cx))
+            });
+
+            cx.new(|cx| Root::new(workspace, window, cx))
+        });
+
+        match result {
+            Ok(handle) => {
+                // Explicitly activate the window and force initial render (X11 fix)
+                if let Err(error) = handle.update(cx, |_root, window, cx| {
+                    window.activate_window();
+                    cx.notify();
+                }) {
+                    log::warn!("Failed to activate new workspace window: {:?}", error);
+                }
+            }
+            Err(error) => {
+                log::warn!("Failed to open new workspace window: {:?}", error);
+            }
+        }
+    }
+
+    /// Setup of a window opened with "New Window": scope the sidebar, bring
+    /// its connection up and keep it the active one while the window has
+    /// focus, so new tabs bind to it.
+    pub(in crate::ui::views::workspace) fn init_secondary_window(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let scope = self.profile_scope.clone();
+        self.sidebar
+            .update(cx, |sidebar, cx| sidebar.set_profile_scope(scope, cx));
+
+        let unconnected: Vec<uuid::Uuid> = {
+            let state = self.app_state.read(cx);
+            self.profile_scope
+                .iter()
+                .flatten()
+                .filter(|id| !state.connections().contains_key(id))
+                .copied()
+                .collect()
+        };
+
+        for profile_id in unconnected {
+            self.sidebar.update(cx, |sidebar, cx| {
+                sidebar.connect_to_profile(profile_id, cx);
+            });
+        }
+
+        self.activate_scoped_connection(cx);
+
+        cx.observe_window_activation(window, |this, window, cx| {
+            if window.is_window_active() {
+                this.activate_scoped_connection(cx);
+            }
+        })
+        .detach();
+
+        self.pending_focus = Some(FocusTarget::Sidebar);
+    }
+
+    /// Makes a connection of this window's scope the active one, unless the
+    /// active connection is already in scope.
+    fn activate_scoped_connection(&mut self, cx: &mut Context<Self>) {
+        let Some(scope) = &self.profile_scope else {
+            return;
+        };
+
+        let state = self.app_state.read(cx);
+        if state
+            .active_connection_id()
+            .is_some_and(|id| scope.contains(&id))
+        {
+            return;
+        }
+
+        let Some(profile_id) = scope
+            .iter()
+            .copied()
+            .find(|id| state.connections().contains_key(id))
+        else {
+            return;
+        };
+
+        self.app_state.update(cx, |state, cx| {
+            state.set_active_connection(profile_id);
+            cx.emit(AppStateChanged);
+        });
+    }
+
+    /// An open secondary window with unsaved changes, if any.
+    pub(in crate::ui::views::workspace) fn secondary_with_unsaved_changes(
+        cx: &mut App,
+    ) -> Option<Entity<Workspace>> {
+        let secondaries = cx.default_global::<SecondaryWorkspaces>();
+        secondaries
+            .0
+            .retain(|workspace| workspace.upgrade().is_some());
+        let secondaries: Vec<Entity<Workspace>> = secondaries
+            .0
+            .iter()
+            .filter_map(|workspace| workspace.upgrade())
+            .collect();
+
+        secondaries.into_iter().find(|workspace| {
+            !workspace
+                .read(cx)
+                .tab_manager
+                .read(cx)
+                .dirty_summaries(cx)
+                .is_empty()
+        })
+    }
+
+    /// Brings this workspace's window to the front.
+    pub(in crate::ui::views::workspace) fn activate_own_window(&self, cx: &mut App) {
+        let handle = self.window_handle;
+        cx.defer(move |cx| {
+            let _ = handle.update(cx, |_, window, _| window.activate_window());
+        });
+    }
+
+    /// Moves a document out of the tab bar into a window of its own. The
+    /// document keeps running in this workspace; closing the window docks it
+    /// again.
+    pub(in crate::ui::views::workspace) fn detach_document(
+        &mut self,
+        id: DocumentId,
+        cx: &mut Context<Self>,
+    ) {
+        if self.detached_windows.contains_key(&id) {
+            self.activate_detached_window(id, cx);
+            return;
+        }
+
+        let Some(title) = self
+            .tab_manager
+            .read(cx)
+            .document(id)
+            .map(|tab| tab.tab_title(cx))
+        else {
+            return;
+        };
+
+        self.tab_manager.update(cx, |mgr, cx| mgr.detach(id, cx));
+
+        let channel = dbflux_core::ReleaseChannel::current();
+        let mut options = WindowOptions {
+            app_id: Some(channel.app_id().into()),
+            titlebar: Some(TitlebarOptions {
+                title: Some(title.into()),
+                ..Default::default()
+            }),
+            window_decorations: platform::main_window_decoration_request(),
+            focus: true,
+            ..Default::default()
+        };
+        platform::apply_window_options(&mut options, 640.0, 480.0);
+
+        let tab_manager = self.tab_manager.clone();
+        let workspace = cx.entity().downgrade();
+
+        let result = cx.open_window(options, move |window, cx| {
+            let view = cx.new(|cx| DetachedDocumentWindow::new(tab_manager, id, window, cx));
+
+            window.on_window_should_close(cx, move |_window, cx| {
+                let _ = workspace.update(cx, |workspace, cx| workspace.redock_document(id, cx));
+                true
+            });
+
+            cx.new(|cx| Root::new(view, window, cx))
+        });
+
+        match result {
+            Ok(handle) => {
+                self.detached_windows.insert(id, handle.into());
+                self.activate_detached_window(id, cx);
+            }
+            Err(error) => {
+                log::warn!("Failed to open window for detached document: {:?}", error);
+                self.tab_manager.update(cx, |mgr, cx| mgr.redock(id, cx));
+            }
+        }
+    }
+
+    /// Docks a detached document whose window is closing.
+    fn redock_document(&mut self, id: DocumentId, cx: &mut Context<Self>) {
+        self.detached_windows.remove(&id);
+        self.tab_manager.update(cx, |mgr, cx| mgr.redock(id, cx));
+    }
+
+    /// Closes the window of a document that was docked again or closed.
+    pub(in crate::ui::views::workspace) fn close_detached_window(
+        &mut self,
+        id: DocumentId,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(handle) = self.detached_windows.remove(&id) else {
+            return;
+        };
+
+        // The request may come from inside that window's own update.
+        cx.defer(move |cx| {
+            let _ = handle.update(cx, |_, window, _| window.remove_window());
+        });
+    }
+
+    fn close_detached_windows(&mut self, cx: &mut Context<Self>) {
+        let ids: Vec<DocumentId> = self.detached_windows.keys().copied().collect();
+        for id in ids {
+            self.close_detached_window(id, cx);
+        }
+    }
+
+    pub(in crate::ui::views::workspace) fn activate_detached_window(
+        &self,
+        id: DocumentId,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(&handle) = self.detached_windows.get(&id) else {
+            return;
+        };
+
+        cx.defer(move |cx| {
+            let _ = handle.update(cx, |_, window, _| window.activate_window());
+        });
+    }
+}
@@ -0,0 +1,155 @@
+//! Window for a document detached from the tab bar.
+//!
+//! The document stays owned by the workspace's `TabManager`, so its
+//! connection, background tasks, session entry and unsaved-changes checks are
+//! unchanged; this view only renders it and routes keys to it.
+
+use crate::keymap::{ContextId, default_keymap, key_chord_from_gpui};
+use crate::ui::document::{DocumentId, TabManager};
+use dbflux_components::controls::Button;
+use dbflux_components::tokens::{Heights, Spacing};
+use dbflux_components::typography::MonoMeta;
+use dbflux_ui_base::platform;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+pub(super) struct DetachedDocumentWindow {
+    tab_manager: Entity<TabManager>,
+    document_id: DocumentId,
+    focus_handle: FocusHandle,
+    /// Last title pushed to the OS window.
+    window_title: String,
+}
+
+impl DetachedDocumentWindow {
+    pub(super) fn new(
+        tab_manager: Entity<TabManager>,
+        document_id: DocumentId,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        cx.observe(&tab_manager, |_, _, cx| cx.notify()).detach();
+
+        if let Some(tab) = tab_manager.read(cx).document(document_id) {
+            tab.focus(window, cx);
+        }
+
+        Self {
+            tab_manager,
+            document_id,
+            focus_handle: cx.focus_handle(),
+            window_title: String::new(),
+        }
+    }
+
+    fn redock(&mut self, cx: &mut Context<Self>) {
+        let id = self.document_id;
+        self.tab_manager.update(cx, |mgr, cx| mgr.redock(id, cx));
+    }
+
+    fn handle_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let id = self.document_id;
+        let chord = key_chord_from_gpui(&event.keystroke);
+
+        let handled = self.tab_manager.update(cx, |mgr, cx| {
+            let Some(tab) = mgr.document(id) else {
+                return false;
+            };
+
+            let context = tab.active_context(cx);
+            if let Some(cmd) = default_keymap().resolve(context, &chord)
+                && tab.dispatch_command(cmd, window, cx)
+            {
+                return true;
+            }
+
+            context == ContextId::Editor
+                && tab.handle_unbound_keystroke(&event.keystroke, window, cx)
+        });
+
+        if handled {
+            cx.stop_propagation();
+        }
+    }
+}
+
+impl Focusable for DetachedDocumentWindow {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DetachedDocumentWindow {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let title = self
+            .tab_manager
+            .read(cx)
+            .document(self.document_id)
+            .map(|tab| tab.tab_title(cx))
+            .unwrap_or_default();
+
+        if title != self.window_title {
+            window.set_window_title(&title);
+            self.window_title = title.clone();
+        }
+
+        let content = self.tab_manager.update(cx, |mgr, cx| {
+            mgr.render_document(self.document_id, window, cx)
+        });
+
+        let crumbs = [platform::TitleCrumb {
+            icon: None,
+            label: title.clone().into(),
+        }];
+        let title_bar = platform::render_csd_title_bar_with_crumbs(window, cx, "DBFlux", &crumbs);
+
+        let theme = cx.theme();
+        let header = div()
+            .h(Heights::TAB)
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .px(Spacing::MD)
+            .bg(theme.tab_bar)
+            .border_b_1()
+            .border_color(theme.border)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .child(MonoMeta::new(title).color(theme.foreground)),
+            )
+            .child(
+                Button::new("detached-dock", "Dock")
+                    .small()
+                    .ghost()
+                    .on_click(cx.listener(|this, _, _, cx| this.redock(cx))),
+            );
+
+        div()
+            .id("detached-document")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(cx.theme().background)
+            .text_color(cx.theme().foreground)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .when_some(title_bar, |el, title_bar| el.child(title_bar))
+            .child(header)
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .when_some(content, |el, content| el.child(content)),
+            )
+    }
+}
@@ -102,6 +102,31 @@ impl CommandDispatcher for Workspace {
                 self.new_query_tab(window, cx);
                 true
             }
+            Command::NewNotebook => {
+                self.new_notebook(window, cx);
+                true
+            }
+            Command::SearchData => {
+                self.open_data_search(window, cx);
+                true
+            }
+            Command::IndexAdvisor => {
+                self.open_index_advisor(cx);
+                true
+            }
+            Command::ScheduledExports => {
+                self.scheduled_exports_modal
+                    .update(cx, |modal, cx| modal.open(window, cx));
+                true
+            }
+            Command::QuickOpen => {
+                self.open_quick_open(window, cx);
+                true
+            }
+            Command::SwitchConnection => {
+                self.open_connection_switcher(window, cx);
+                true
+            }
             Command::OpenScriptFile => {
                 self.open_script_file(window, cx);
                 true
@@ -128,6 +153,10 @@ impl CommandDispatcher for Workspace {
                 self.open_connection_manager(cx);
                 true
             }
+            Command::NewWindow => {
+                self.open_workspace_window(None, cx);
+                true
+            }
             Command::ExportConnections => {
                 // Export is now per-connection: it is initiated from a
                 // connection's three-dots menu, which carries the profile id.
@@ -148,6 +177,23 @@ impl CommandDispatcher for Workspace {
                 self.open_audit_viewer(window, cx);
                 true
             }
+            Command::OpenNotifications => {
+                self.notification_center
+                    .update(cx, |center, cx| center.open(cx));
+                true
+            }
+            Command::ZoomIn => {
+                self.step_ui_zoom(1, cx);
+                true
+            }
+            Command::ZoomOut => {
+                self.step_ui_zoom(-1, cx);
+                true
+            }
+            Command::ResetZoom => {
+                self.step_ui_zoom(0, cx);
+                true
+            }
             #[cfg(feature = "mcp")]
             Command::OpenMcpApprovals => {
                 self.open_mcp_approvals(window, cx);
@@ -184,6 +230,26 @@ impl CommandDispatcher for Workspace {
                 self.toggle_tasks_panel(cx);
                 true
             }
+            Command::SplitRight => {
+                self.split_active_document(SplitDirection::Right, window, cx);
+                true
+            }
+            Command::SplitDown => {
+                self.split_active_document(SplitDirection::Down, window, cx);
+                true
+            }
+            Command::FocusOtherPane => {
+                self.focus_other_pane(window, cx);
+                true
+            }
+            Command::MoveToOtherPane => {
+                self.move_to_other_pane(window, cx);
+                true
+            }
+            Command::CloseSplit => {
+                self.close_split(window, cx);
+                true
+            }
             Command::ToggleSidebar => {
                 self.toggle_sidebar(cx);
                 true
@@ -247,6 +313,31 @@ impl CommandDispatcher for Workspace {
                     .update(cx, |mgr, cx| mgr.focus_active(window, cx));
                 true
             }
+            Command::MoveTabToGroup(n) => {
+                self.tab_manager.update(cx, |mgr, cx| {
+                    if let Some(id) = mgr.active_id() {
+                        mgr.move_tab_to_group(id, n.saturating_sub(1), cx);
+                    }
+                });
+                true
+            }
+            Command::ReopenClosedTab => {
+                self.reopen_closed_tab(window, cx);
+                true
+            }
+            Command::NavigateBack => {
+                self.tab_manager.update(cx, |mgr, cx| mgr.navigate_back(cx));
+                self.tab_manager
+                    .update(cx, |mgr, cx| mgr.focus_active(window, cx));
+                true
+            }
+            Command::NavigateForward => {
+                self.tab_manager
+                    .update(cx, |mgr, cx| mgr.navigate_forward(cx));
+                self.tab_manager
+                    .update(cx, |mgr, cx| mgr.focus_active(window, cx));
+                true
+            }
             Command::CloseCurrentTab => {
                 self.close_active_tab(window, cx);
                 // Focus the newly active document if any
@@ -617,6 +708,15 @@ impl CommandDispatcher for Workspace {
                 true
             }
 
+            Command::FindInEditor
+            | Command::ReplaceInEditor
+            | Command::FindNext
+            | Command::FindPrevious
+            | Command::ToggleQueryVariables
+            | Command::OrganizeSavedQuery => self
+                .tab_manager
+                .update(cx, |mgr, cx| mgr.dispatch_active(cmd, window, cx)),
+
             Command::FocusBackgroundTasks => {
                 self.set_focus(FocusTarget::BackgroundTasks, window, cx);
                 true
@@ -778,7 +878,10 @@ impl CommandDispatcher for Workspace {
                 false
             }
 
-            Command::ResultsAddRow | Command::ResultsCopyRow | Command::ResultsCopyCell => {
+            Command::ResultsAddRow
+            | Command::ResultsCopyRow
+            | Command::ResultsCopyCell
+            | Command::ToggleRowInspector => {
                 self.tab_manager.update(cx, |mgr, cx| {
                     mgr.dispatch_active(cmd, window, cx);
                 });
@@ -812,6 +915,22 @@ impl CommandDispatcher for Workspace {
                 true
             }
 
+            Command::FindSavedQuery => {
+                // Same palette-mode pattern as OpenSavedChart: open the palette
+                // with only saved queries so `#tag` / `driver:` filters apply.
+                let query_items = self.build_saved_query_palette_items(cx);
+                if query_items.is_empty() {
+                    Toast::warning("No saved queries yet")
+                        .meta_right(now_hms())
+                        .push(cx);
+                } else {
+                    self.command_palette.update(cx, |palette, cx| {
+                        palette.open_with_items(query_items, window, cx);
+                    });
+                }
+                true
+            }
+
             Command::ImportDashboard => {
                 // Only available when the active connection has DASHBOARD_IMPORT.
                 let has_capability = self
@@ -1,4 +1,5 @@
 mod actions;
+mod detached;
 mod dispatch;
 pub mod inspector;
 pub mod pipeline;
@@ -22,11 +23,15 @@ use dbflux_ui_base::{AppStateGlobal, OpenAuditRequested};
 use crate::app::McpRuntimeEventRaised;
 
 use crate::keymap::{
-    self, Command, CommandDispatcher, ContextId, FocusTarget, KeymapStack, default_keymap,
-    key_chord_from_gpui,
+    self, Command, CommandDispatcher, ContextId, FocusTarget, KeyChord, KeymapStack,
+    default_keymap, key_chord_from_gpui,
 };
 use crate::ui::dock::{SidebarDock, SidebarDockEvent};
-use crate::ui::document::{CodeDocument, DataDocument, Tab, TabBar, TabBarEvent, TabManager};
+use crate::ui::document::{
+    CodeDocument, DataDocument, DataSearchEvent, DataSearchModal, DocumentKind, ExportScheduler,
+    IndexAdvisorEvent, IndexAdvisorModal, NotebookDocument, ScheduledExportsModal, Tab, TabBar,
+    TabBarEvent, TabManager,
+};
 
 #[cfg(feature = "mcp")]
 use crate::ui::document::McpApprovalsView;
@@ -35,11 +40,15 @@ use crate::ui::overlays::command_palette::{
     CommandPalette, CommandPaletteClosed, PaletteCommand, PaletteItem, PaletteSelection,
     ResourceItem,
 };
+use crate::ui::overlays::crash_recovery::{
+    CrashRecoveryModal, CrashRecoveryResolved, RecoveredDocument,
+};
 use crate::ui::overlays::login_modal::{LoginModal, LoginModalEvent};
+use crate::ui::overlays::notification_center::{NotificationCenter, NotificationCenterEvent};
 use crate::ui::overlays::shutdown_overlay::ShutdownOverlay;
 use crate::ui::overlays::sql_preview_modal::SqlPreviewModal;
 use crate::ui::overlays::sso_wizard::{SsoWizard, SsoWizardEvent};
-use crate::ui::views::status_bar::{StatusBar, ToggleTasksPanel};
+use crate::ui::views::status_bar::{OpenNotificationCenter, StatusBar, ToggleTasksPanel};
 use crate::ui::views::tasks_panel::TasksPanel;
 use dbflux_components::tokens::{Heights, Radii, Spacing};
 #[cfg(test)]
@@ -52,7 +61,7 @@ use gpui::prelude::FluentBuilder;
 use gpui::*;
 use gpui_component::ActiveTheme;
 use gpui_component::Root;
-use gpui_component::resizable::{resizable_panel, v_resizable};
+use gpui_component::resizable::{h_resizable, resizable_panel, v_resizable};
 use std::path::PathBuf;
 
 /// Extract resource items from a schema snapshot into a palette item list.
@@ -138,6 +147,256 @@ pub(super) fn build_resource_items_from_schema(
     }
 }
 
+/// Collect every schema object cached for a connected profile — the primary
+/// schema, per-database schemas and routine lists — for quick open.
+///
+/// Objects reachable through more than one cache are listed once, and
+/// system objects are left out when `hide_system_objects` is set.
+pub(super) fn build_quick_open_items(
+    profile_id: uuid::Uuid,
+    connected: &dbflux_core::ConnectedProfile,
+    hide_system_objects: bool,
+    items: &mut Vec<PaletteItem>,
+) {
+    let profile_name = connected.profile.name.as_str();
+    let start = items.len();
+
+    if let Some(schema) = &connected.schema {
+        build_resource_items_from_schema(profile_id, profile_name, &schema.structure, items);
+    }
+
+    for database in connected.database_connections.values() {
+        if let Some(schema) = &database.schema {
+            build_resource_items_from_schema(profile_id, profile_name, &schema.structure, items);
+        }
+    }
+
+    for (database, db_schema) in &connected.database_schemas {
+        for table in &db_schema.tables {
+            items.push(PaletteItem::Resource(ResourceItem::Table {
+                profile_id,
+                profile_name: profile_name.to_string(),
+                database: Some(database.clone()),
+                schema: table.schema.clone(),
+                name: table.name.clone(),
+            }));
+        }
+        for view in &db_schema.views {
+            items.push(PaletteItem::Resource(ResourceItem::View {
+                profile_id,
+                profile_name: profile_name.to_string(),
+                database: Some(database.clone()),
+                schema: view.schema.clone(),
+                name: view.name.clone(),
+            }));
+        }
+    }
+
+    for (key, routines) in &connected.schema_routines {
+        // Engines without schemas keep routines at the database level.
+        let schema = key.schema.as_ref().unwrap_or(&key.database);
+        for routine in routines {
+            items.push(PaletteItem::Resource(ResourceItem::Routine {
+                profile_id,
+                profile_name: profile_name.to_string(),
+                database: Some(key.database.clone()),
+                schema: schema.clone(),
+                name: routine.name.clone(),
+                specific_name: routine.specific_name.clone(),
+            }));
+        }
+    }
+
+    let mut added = items.split_off(start);
+    let mut seen = std::collections::HashSet::new();
+    added.retain(|item| {
+        if hide_system_objects && is_system_resource(item) {
+            return false;
+        }
+        let (category, name) = item.display_label();
+        seen.insert((category, name, item.qualifier()))
+    });
+    items.extend(added);
+}
+
+/// Items of the connection switcher: every profile, followed by the
+/// databases of the connected ones.
+pub(super) fn build_execution_target_items(
+    profiles: &[dbflux_core::ConnectionProfile],
+    connections: &std::collections::HashMap<uuid::Uuid, dbflux_core::ConnectedProfile>,
+) -> Vec<PaletteItem> {
+    let mut items = Vec::new();
+
+    for profile in profiles {
+        let connected = connections.get(&profile.id);
+
+        items.push(PaletteItem::ExecutionTarget {
+            profile_id: profile.id,
+            profile_name: profile.name.clone(),
+            database: None,
+            is_connected: connected.is_some(),
+        });
+
+        let Some(schema) = connected.and_then(|connected| connected.schema.as_ref()) else {
+            continue;
+        };
+
+        for database in schema.databases() {
+            items.push(PaletteItem::ExecutionTarget {
+                profile_id: profile.id,
+                profile_name: profile.name.clone(),
+                database: Some(database.name.clone()),
+                is_connected: true,
+            });
+        }
+    }
+
+    items
+}
+
+/// Whether a quick-open item lives in a system schema or database, or is an
+/// engine-internal table.
+pub(super) fn is_system_resource(item: &PaletteItem) -> bool {
+    let PaletteItem::Resource(resource) = item else {
+        return false;
+    };
+
+    let (database, schema, name) = match resource {
+        ResourceItem::Table {
+            database,
+            schema,
+            name,
+            ..
+        }
+        | ResourceItem::View {
+            database,
+            schema,
+            name,
+            ..
+        } => (database.as_deref(), schema.as_deref(), Some(name.as_str())),
+        ResourceItem::Collection { database, .. } | ResourceItem::KeyValueDb { database, .. } => {
+            (Some(database.as_str()), None, None)
+        }
+        ResourceItem::Routine {
+            database, schema, ..
+        } => (database.as_deref(), Some(schema.as_str()), None),
+    };
+
+    database
+        .into_iter()
+        .chain(schema)
+        .any(dbflux_core::is_system_schema)
+        || name.is_some_and(dbflux_core::is_internal_object)
+}
+
+/// Kind of document a palette command acts on. Commands without one work
+/// from anywhere.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum PaletteTarget {
+    QueryEditor,
+    Results,
+}
+
+fn palette_command_target(id: &str) -> Option<PaletteTarget> {
+    match id {
+        "run_query"
+        | "run_query_in_new_tab"
+        | "save_query"
+        | "save_file_as"
+        | "find_in_editor"
+        | "replace_in_editor"
+        | "toggle_query_variables"
+        | "switch_connection" => Some(PaletteTarget::QueryEditor),
+        "export_results" | "toggle_row_inspector" => Some(PaletteTarget::Results),
+        _ => None,
+    }
+}
+
+/// Where the command palette was opened from: the focused area and the kind
+/// of the focused document.
+#[derive(Clone, Copy, Debug)]
+struct PaletteScope {
+    context: ContextId,
+    document: Option<DocumentKind>,
+}
+
+impl PaletteScope {
+    fn offers(&self, target: PaletteTarget) -> bool {
+        let Some(kind) = self.document else {
+            return false;
+        };
+
+        let editor = matches!(
+            kind,
+            DocumentKind::Script | DocumentKind::SqlQuery | DocumentKind::Notebook
+        );
+
+        match target {
+            PaletteTarget::QueryEditor => editor,
+            PaletteTarget::Results => {
+                editor
+                    || matches!(
+                        kind,
+                        DocumentKind::Data
+                            | DocumentKind::TableView
+                            | DocumentKind::MongoCollection
+                    )
+            }
+        }
+    }
+
+    /// Whether a command belongs to the focused area.
+    fn is_focused_on(&self, cmd: &PaletteCommand) -> bool {
+        match self.context {
+            ContextId::Editor => palette_command_target(cmd.id) == Some(PaletteTarget::QueryEditor),
+            ContextId::Results => palette_command_target(cmd.id) == Some(PaletteTarget::Results),
+            ContextId::Sidebar => cmd.category == "Connections",
+            _ => false,
+        }
+    }
+}
+
+/// Drops the commands the focused document cannot run and lists those of
+/// the focused area first.
+fn palette_commands_for(
+    scope: &PaletteScope,
+    commands: Vec<PaletteCommand>,
+) -> Vec<PaletteCommand> {
+    let mut commands: Vec<PaletteCommand> = commands
+        .into_iter()
+        .filter(|cmd| palette_command_target(cmd.id).is_none_or(|target| scope.offers(target)))
+        .collect();
+
+    commands.sort_by_key(|cmd| !scope.is_focused_on(cmd));
+    commands
+}
+
+/// Formats keys as a palette shortcut label: `ctrl-shift-p`, with the chords
+/// of a sequence separated by spaces.
+fn palette_shortcut_label(keys: &[KeyChord]) -> String {
+    keys.iter()
+        .map(|chord| {
+            let mods = &chord.modifiers;
+            let mut parts: Vec<&str> = Vec::new();
+            if mods.platform {
+                parts.push("cmd");
+            }
+            if mods.ctrl {
+                parts.push("ctrl");
+            }
+            if mods.alt {
+                parts.push("alt");
+            }
+            if mods.shift {
+                parts.push("shift");
+            }
+            parts.push(&chord.key);
+            parts.join("-")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Map a `PaletteItem` to its corresponding `PaletteSelection`.
 ///
 /// Separated from `CommandPalette` for testability — pure data transformation.
@@ -160,6 +419,14 @@ pub(super) fn map_item_to_selection(item: &PaletteItem) -> Option<PaletteSelecti
                 })
             }
         }
+        PaletteItem::ExecutionTarget {
+            profile_id,
+            database,
+            ..
+        } => Some(PaletteSelection::SwitchExecutionContext {
+            profile_id: *profile_id,
+            database: database.clone(),
+        }),
         PaletteItem::Resource(r) => match r {
             ResourceItem::Table {
                 profile_id,
@@ -202,6 +469,16 @@ pub(super) fn map_item_to_selection(item: &PaletteItem) -> Option<PaletteSelecti
                 profile_id: *profile_id,
                 database: database.clone(),
             }),
+            ResourceItem::Routine {
+                profile_id,
+                schema,
+                specific_name,
+                ..
+            } => Some(PaletteSelection::OpenRoutine {
+                profile_id: *profile_id,
+                schema: schema.clone(),
+                specific_name: specific_name.clone(),
+            }),
         },
         PaletteItem::Script { path, .. } => {
             Some(PaletteSelection::OpenScript { path: path.clone() })
@@ -210,6 +487,9 @@ pub(super) fn map_item_to_selection(item: &PaletteItem) -> Option<PaletteSelecti
             Some(PaletteSelection::OpenSavedChart { chart_id: *id })
         }
         PaletteItem::ImportDashboard => Some(PaletteSelection::ImportDashboard),
+        PaletteItem::SavedQuery { query, .. } => {
+            Some(PaletteSelection::OpenSavedQuery { query_id: query.id })
+        }
     }
 }
 
@@ -241,6 +521,15 @@ pub(super) struct PendingOpenScript {
     pub language: QueryLanguage,
     pub connection_id: Option<uuid::Uuid>,
     pub exec_ctx: ExecutionContext,
+    /// Profile to run the new tab against once it is open, connecting it
+    /// first if needed.
+    pub switch_to_profile: Option<uuid::Uuid>,
+}
+
+/// Deferred notebook open (needs `Window` access for the cell editors).
+pub(super) struct PendingOpenNotebook {
+    pub path: PathBuf,
+    pub notebook: dbflux_core::Notebook,
 }
 
 /// Deferred routine-definition open (needs `Window` access for CodeDocument creation).
@@ -252,6 +541,15 @@ pub(super) struct PendingOpenRoutine {
     pub body: String,
 }
 
+/// Connection switch waiting for its profile to finish connecting.
+pub(super) struct PendingContextSwitch {
+    pub document_id: crate::ui::document::DocumentId,
+    pub profile_id: uuid::Uuid,
+    pub database: Option<String>,
+    /// Run the document's query once the switch is applied.
+    pub run_after: bool,
+}
+
 pub struct Workspace {
     app_state: Entity<AppStateEntity>,
     sidebar: Entity<Sidebar>,
@@ -280,6 +578,9 @@ pub struct Workspace {
     modal_drop_table: Entity<crate::ui::overlays::modals::ModalDropTable>,
     /// Item ID of the drop-table pending delete, consumed when modal confirms.
     pending_drop_table_item_id: Option<String>,
+    modal_rename_object: Entity<crate::ui::overlays::modals::ModalRenameObject>,
+    /// Item ID of the table or column being renamed, consumed when modal confirms.
+    pending_rename_item_id: Option<String>,
     /// SSH tunnel passphrase modal.
     modal_tunnel_auth: Entity<crate::ui::overlays::modals::ModalTunnelAuth>,
     /// Import Dashboard from JSON modal.
@@ -292,6 +593,22 @@ pub struct Workspace {
     modal_delete_saved_chart: Entity<ModalDeleteSavedChartConfirm>,
     modal_add_panel: Entity<ModalAddPanelPicker>,
 
+    /// "Search data" modal for finding a text across a database's tables.
+    data_search_modal: Entity<DataSearchModal>,
+
+    /// Index suggestions for the slow queries of a database.
+    index_advisor_modal: Entity<IndexAdvisorModal>,
+
+    /// Runs saved queries on their export schedule while the app is open.
+    /// Created by the main window and shared with the windows it opens.
+    export_scheduler: Entity<ExportScheduler>,
+
+    /// Lists and edits the scheduled exports.
+    scheduled_exports_modal: Entity<ScheduledExportsModal>,
+
+    /// Notifications raised during this session (long queries, ...).
+    notification_center: Entity<NotificationCenter>,
+
     /// In-app single-connection export modal (overlay, not an OS window).
     export_modal: Entity<dbflux_ui_windows::connection_manager::ExportBundleModal>,
 
@@ -300,7 +617,9 @@ pub struct Workspace {
     pending_sql: Option<String>,
     pending_focus: Option<FocusTarget>,
     pending_open_script: Option<PendingOpenScript>,
+    pending_open_notebook: Option<PendingOpenNotebook>,
     pending_open_routine: Option<PendingOpenRoutine>,
+    pending_context_switch: Option<PendingContextSwitch>,
     needs_focus_restore: bool,
 
     /// Active pipeline progress watcher for pipeline-enabled connects.
@@ -309,6 +628,8 @@ pub struct Workspace {
 
     focus_target: FocusTarget,
     keymap: &'static KeymapStack,
+    /// Chords typed so far of a multi-key binding, see `handle_key_sequence`.
+    pending_keys: Vec<KeyChord>,
     focus_handle: FocusHandle,
 
     #[cfg(feature = "mcp")]
@@ -323,8 +644,65 @@ pub struct Workspace {
     ///
     /// Fields: `(provider_name, profile_name, url)`.
     pending_login_modal_open: Option<(String, String, Option<String>)>,
+
+    /// Waits for the documents picked in the quit prompt to finish saving.
+    quit_save_task: Option<Task<()>>,
+
+    /// Offers the unsaved documents of a run that did not shut down cleanly.
+    crash_recovery: Entity<CrashRecoveryModal>,
+    /// Last session manifest written, to skip identical writes.
+    last_session_snapshot: Option<String>,
+    /// Rewrites the session manifest periodically so dirty documents and
+    /// their connection bindings survive a crash.
+    _session_snapshot_task: Option<Task<()>>,
+
+    /// Only the main window restores and records the session, offers crash
+    /// recovery and runs the app-wide background work.
+    is_main_window: bool,
+    /// Connections a secondary window is limited to; `None` for all of them.
+    profile_scope: Option<std::collections::HashSet<uuid::Uuid>>,
+    /// A secondary window's close prompt is open or its saves are running.
+    close_prompt_pending: bool,
+    window_handle: AnyWindowHandle,
+    /// Windows of the documents detached from this workspace's tab bar.
+    detached_windows: std::collections::HashMap<crate::ui::document::DocumentId, AnyWindowHandle>,
+    /// Second document pane, when the document area is split.
+    split: Option<SplitPane>,
+}
+
+/// Where the split pane sits relative to the tab bar's pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SplitDirection {
+    Right,
+    Down,
+}
+
+/// The document shown in the split pane. It is detached from the tab bar, so
+/// it keeps its connection, tasks and session entry while it is shown there.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SplitPane {
+    pub document_id: crate::ui::document::DocumentId,
+    pub direction: SplitDirection,
+}
+
+/// What a window opened with "New Window" shares with the window it was
+/// opened from.
+pub struct SecondaryWindow {
+    pub export_scheduler: Entity<ExportScheduler>,
+    /// Connections shown in the window's sidebar; `None` shows all of them.
+    pub profile_scope: Option<std::collections::HashSet<uuid::Uuid>>,
 }
 
+/// How often the session manifest is refreshed in the background.
+const SESSION_SNAPSHOT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Emitted once unsaved changes have been saved or discarded after the window
+/// was asked to close: the main window starts graceful shutdown, a secondary
+/// window closes.
+pub struct QuitConfirmed;
+
+impl EventEmitter<QuitConfirmed> for Workspace {}
+
 #[cfg(feature = "mcp")]
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum GovernancePanel {
@@ -337,13 +715,42 @@ impl Workspace {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Self {
+        Self::build(app_state, None, window, cx)
+    }
+
+    /// Workspace of an extra window: its own tabs over the connections of
+    /// the main window, without the session and app-wide background work.
+    pub fn new_secondary(
+        app_state: Entity<AppStateEntity>,
+        secondary: SecondaryWindow,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        Self::build(app_state, Some(secondary), window, cx)
+    }
+
+    fn build(
+        app_state: Entity<AppStateEntity>,
+        secondary: Option<SecondaryWindow>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let is_main_window = secondary.is_none();
+        let (shared_export_scheduler, profile_scope) = match secondary {
+            Some(secondary) => (Some(secondary.export_scheduler), secondary.profile_scope),
+            None => (None, None),
+        };
+
+        // Toasts and the app-state global belong to the main window.
         let toast_host = cx.new(|_cx| ToastHost::new());
-        cx.set_global(ToastGlobal {
-            host: toast_host.clone(),
-        });
-        cx.set_global(AppStateGlobal {
-            entity: app_state.clone(),
-        });
+        if is_main_window {
+            cx.set_global(ToastGlobal {
+                host: toast_host.clone(),
+            });
+            cx.set_global(AppStateGlobal {
+                entity: app_state.clone(),
+            });
+        }
 
         let sidebar = cx.new(|cx| Sidebar::new(app_state.clone(), window, cx));
         let sidebar_dock = cx.new(|cx| SidebarDock::new(sidebar.clone(), cx));
@@ -368,6 +775,8 @@ impl Workspace {
         let modal_unsaved_changes = cx.new(crate::ui::overlays::modals::ModalUnsavedChanges::new);
         let modal_drop_table =
             cx.new(|cx| crate::ui::overlays::modals::ModalDropTable::new(window, cx));
+        let modal_rename_object =
+            cx.new(|cx| crate::ui::overlays::modals::ModalRenameObject::new(window, cx));
         let modal_tunnel_auth =
             cx.new(|cx| crate::ui::overlays::modals::ModalTunnelAuth::new(window, cx));
         let modal_import_dashboard =
@@ -378,6 +787,15 @@ impl Workspace {
         let modal_delete_dashboard = cx.new(ModalDeleteDashboardConfirm::new);
         let modal_delete_saved_chart = cx.new(ModalDeleteSavedChartConfirm::new);
         let modal_add_panel = cx.new(|cx| ModalAddPanelPicker::new(window, cx));
+        let data_search_modal = cx.new(|cx| DataSearchModal::new(app_state.clone(), window, cx));
+        let index_advisor_modal = cx.new(|_| IndexAdvisorModal::new(app_state.clone()));
+        let export_scheduler = shared_export_scheduler
+            .unwrap_or_else(|| cx.new(|cx| ExportScheduler::new(app_state.clone(), cx)));
+        let scheduled_exports_modal = cx.new(|cx| {
+            ScheduledExportsModal::new(app_state.clone(), export_scheduler.clone(), window, cx)
+        });
+        let notification_center = cx.new(|cx| NotificationCenter::new(app_state.clone(), cx));
+        let crash_recovery = cx.new(CrashRecoveryModal::new);
 
         let export_modal = cx.new(|cx| {
             dbflux_ui_windows::connection_manager::ExportBundleModal::new(
@@ -425,6 +843,23 @@ impl Workspace {
         )
         .detach();
 
+        // Subscribe: ModalRenameObject — on Confirmed, run the rename.
+        cx.subscribe(
+            &modal_rename_object,
+            |this, _, outcome: &crate::ui::overlays::modals::RenameObjectOutcome, cx| {
+                use crate::ui::overlays::modals::RenameObjectOutcome;
+                let item_id = this.pending_rename_item_id.take();
+                if let (RenameObjectOutcome::Confirmed { new_name }, Some(item_id)) =
+                    (outcome, item_id)
+                {
+                    this.sidebar.update(cx, |sidebar, cx| {
+                        sidebar.execute_rename(&item_id, new_name, cx);
+                    });
+                }
+            },
+        )
+        .detach();
+
         // Subscribe: ModalTunnelAuth — handle passphrase provided or cancelled.
         cx.subscribe_in(
             &modal_tunnel_auth,
@@ -479,6 +914,12 @@ impl Workspace {
             window,
             |this, _, outcome: &crate::ui::overlays::modals::UnsavedChangesOutcome, window, cx| {
                 use crate::ui::overlays::modals::UnsavedChangesOutcome;
+
+                if this.is_quit_prompt_pending(cx) {
+                    this.resolve_quit_prompt(outcome, window, cx);
+                    return;
+                }
+
                 match outcome {
                     UnsavedChangesOutcome::DiscardAll => {
                         // Close all tabs without saving.
@@ -518,6 +959,32 @@ impl Workspace {
         })
         .detach();
 
+        cx.subscribe(&status_bar, |this, _, _: &OpenNotificationCenter, cx| {
+            this.notification_center
+                .update(cx, |center, cx| center.toggle(cx));
+        })
+        .detach();
+
+        cx.subscribe_in(
+            &crash_recovery,
+            window,
+            |this, _, resolved: &CrashRecoveryResolved, window, cx| {
+                this.apply_crash_recovery(resolved, window, cx);
+            },
+        )
+        .detach();
+
+        cx.subscribe_in(
+            &notification_center,
+            window,
+            |this, _, event: &NotificationCenterEvent, window, cx| match event {
+                NotificationCenterEvent::FocusDocument(document_id) => {
+                    this.focus_document(*document_id, window, cx);
+                }
+            },
+        )
+        .detach();
+
         cx.subscribe_in(
             &app_state,
             window,
@@ -532,6 +999,8 @@ impl Workspace {
             window,
             |this, _, event: &PaletteSelection, window, cx| match event {
                 PaletteSelection::Command { id } => {
+                    this.app_state
+                        .update(cx, |state, _| state.record_palette_command(id));
                     this.pending_command = Some(id);
                     cx.notify();
                 }
@@ -541,17 +1010,14 @@ impl Workspace {
                     });
                 }
                 PaletteSelection::FocusConnection { profile_id } => {
-                    // Mirror sidebar's execute_item for connected profiles:
-                    // set the connection as active in AppState, then focus the sidebar.
-                    this.app_state.update(cx, |state, cx| {
-                        state.set_active_connection(*profile_id);
-                        cx.emit(AppStateChanged);
-                    });
-                    if this.is_sidebar_collapsed(cx) {
-                        this.toggle_sidebar(cx);
-                    }
-                    this.pending_focus = Some(FocusTarget::Sidebar);
-                    cx.notify();
+                    this.focus_connection(*profile_id, cx);
+                }
+                PaletteSelection::SwitchExecutionContext {
+                    profile_id,
+                    database,
+                } => {
+                    this.switch_execution_context(*profile_id, database.clone(), window, cx);
+                    this.set_focus(FocusTarget::Document, window, cx);
                 }
                 PaletteSelection::OpenTable {
                     profile_id,
@@ -589,6 +1055,28 @@ impl Workspace {
                         modal.open(window, cx);
                     });
                 }
+                PaletteSelection::OpenSavedQuery { query_id } => {
+                    this.open_saved_query(*query_id, window, cx);
+                }
+                PaletteSelection::OpenRoutine {
+                    profile_id,
+                    schema,
+                    specific_name,
+                } => {
+                    this.open_routine_definition(
+                        *profile_id,
+                        schema.clone(),
+                        specific_name.clone(),
+                        specific_name.clone(),
+                        cx,
+                    );
+                }
+                PaletteSelection::ViewDefinition(item) => {
+                    this.view_object_definition(item.clone(), cx);
+                }
+                PaletteSelection::InsertName(item) => {
+                    this.insert_object_name(item.clone(), window, cx);
+                }
             },
         )
         .detach();
@@ -633,6 +1121,40 @@ impl Workspace {
         )
         .detach();
 
+        // Subscribe: IndexAdvisorModal — open a suggested statement in a new tab.
+        cx.subscribe_in(
+            &index_advisor_modal,
+            window,
+            |this, _, event: &IndexAdvisorEvent, window, cx| {
+                let IndexAdvisorEvent::OpenStatement { sql } = event;
+                this.new_query_tab_with_content(sql.clone(), window, cx);
+            },
+        )
+        .detach();
+
+        // Subscribe: DataSearchModal — on a hit, open its table filtered to the row.
+        cx.subscribe_in(
+            &data_search_modal,
+            window,
+            |this, _, event: &DataSearchEvent, window, cx| {
+                let DataSearchEvent::OpenRow {
+                    profile_id,
+                    database,
+                    table,
+                    filter,
+                } = event;
+                this.open_filtered_table_document(
+                    *profile_id,
+                    table.clone(),
+                    database.clone(),
+                    filter,
+                    window,
+                    cx,
+                );
+            },
+        )
+        .detach();
+
         // Subscribe: ModalDeleteDashboardConfirm — on Confirmed, delete the dashboard.
         cx.subscribe_in(
             &modal_delete_dashboard,
@@ -900,6 +1422,12 @@ impl Workspace {
                         modal.open(req, window, cx);
                     });
                 }
+                SidebarEvent::RequestRenameObject { item_id, request } => {
+                    this.pending_rename_item_id = Some(item_id.clone());
+                    this.modal_rename_object.update(cx, |modal, cx| {
+                        modal.open(request.clone(), window, cx);
+                    });
+                }
                 SidebarEvent::OpenRoutineDefinition {
                     profile_id,
                     schema,
@@ -936,6 +1464,9 @@ impl Workspace {
                 SidebarEvent::OpenSavedChart { chart_id } => {
                     this.open_saved_chart(*chart_id, window, cx);
                 }
+                SidebarEvent::OpenSavedQuery { query_id } => {
+                    this.open_saved_query(*query_id, window, cx);
+                }
                 SidebarEvent::RequestCreateDashboard { profile_id } => {
                     this.create_dashboard_from_sidebar(*profile_id, window, cx);
                 }
@@ -975,6 +1506,9 @@ impl Workspace {
                 SidebarEvent::OpenInstanceOverview { profile_id } => {
                     this.open_instance_overview(*profile_id, window, cx);
                 }
+                SidebarEvent::OpenUserAdmin { profile_id } => {
+                    this.open_user_admin(*profile_id, window, cx);
+                }
                 SidebarEvent::RequestTunnelAuth {
                     tunnel_id,
                     tunnel_name,
@@ -1000,18 +1534,34 @@ impl Workspace {
                 SidebarEvent::RequestExportConnection { profile_id } => {
                     this.open_export_connection_modal(*profile_id, window, cx);
                 }
+                SidebarEvent::RequestExportTables {
+                    profile_id,
+                    database,
+                    tables,
+                } => {
+                    crate::ui::document::table_export::export_tables(
+                        this.app_state.clone(),
+                        *profile_id,
+                        database.clone(),
+                        tables.clone(),
+                        cx,
+                    );
+                }
                 SidebarEvent::RequestOpenSettings => {
                     this.open_settings(cx);
                 }
                 SidebarEvent::RequestOpenConnectionManager => {
                     this.open_connection_manager(cx);
                 }
-                SidebarEvent::RequestEditConnection { profile_id } => {
-                    this.open_connection_manager_for_edit(*profile_id, cx);
+                SidebarEvent::RequestEditConnection { profile_id, fix } => {
+                    this.open_connection_manager_for_edit(*profile_id, *fix, cx);
                 }
                 SidebarEvent::RequestOpenConnectionManagerInFolder { folder_id } => {
                     this.open_connection_manager_in_folder(*folder_id, cx);
                 }
+                SidebarEvent::RequestOpenInNewWindow { profile_id } => {
+                    this.open_workspace_window(Some(*profile_id), cx);
+                }
             },
         )
         .detach();
@@ -1050,6 +1600,15 @@ impl Workspace {
         )
         .detach();
 
+        cx.subscribe_in(
+            &app_state,
+            window,
+            |this, _, _: &AppStateChanged, window, cx| {
+                this.resume_context_switch(window, cx);
+            },
+        )
+        .detach();
+
         #[cfg(feature = "mcp")]
         cx.subscribe(&app_state, |this, _, _event: &McpRuntimeEventRaised, cx| {
             this.app_state.update(cx, |_state, cx| {
@@ -1083,13 +1642,7 @@ impl Workspace {
                     );
                 }
                 TabBarEvent::CloseAllTabs => {
-                    let ids: Vec<_> = this
-                        .tab_manager
-                        .read(cx)
-                        .documents()
-                        .iter()
-                        .map(|d| d.id())
-                        .collect();
+                    let ids = this.tab_manager.read(cx).docked_ids();
                     for doc_id in ids {
                         this.close_tab(doc_id, window, cx);
                     }
@@ -1119,6 +1672,9 @@ impl Workspace {
                         *id,
                     );
                 }
+                TabBarEvent::OpenInNewWindow(id) => {
+                    this.detach_document(*id, cx);
+                }
             },
         )
         .detach();
@@ -1185,13 +1741,14 @@ impl Workspace {
                         });
                     }
                     TabManagerEvent::Activated(new_id) => {
-                        let doc_ids: Vec<_> = this
-                            .tab_manager
-                            .read(cx)
-                            .documents()
-                            .iter()
-                            .map(|d| (d.id(), d.id() == *new_id))
-                            .collect();
+                        // Detached documents stay active: their window shows them.
+                        let doc_ids: Vec<_> = {
+                            let mgr = this.tab_manager.read(cx);
+                            mgr.documents()
+                                .iter()
+                                .map(|d| (d.id(), d.id() == *new_id || mgr.is_detached(d.id())))
+                                .collect()
+                        };
 
                         for (id, is_active) in doc_ids {
                             this.tab_manager.update(cx, |mgr, cx| {
@@ -1226,9 +1783,48 @@ impl Workspace {
                     TabManagerEvent::OpenEditorWithContent { sql, .. } => {
                         this.new_query_tab_with_content(sql.clone(), window, cx);
                     }
-                    TabManagerEvent::Opened(_)
-                    | TabManagerEvent::Closed(_)
-                    | TabManagerEvent::Reordered => {
+                    TabManagerEvent::OpenTableFiltered {
+                        profile_id,
+                        database,
+                        table,
+                        filter,
+                    } => {
+                        this.open_filtered_table_document(
+                            *profile_id,
+                            table.clone(),
+                            database.clone(),
+                            filter,
+                            window,
+                            cx,
+                        );
+                    }
+                    TabManagerEvent::RequestConnect { profile_id } => {
+                        let profile_id = *profile_id;
+                        this.sidebar.update(cx, |sidebar, cx| {
+                            sidebar.connect_to_profile(profile_id, cx);
+                        });
+                    }
+                    TabManagerEvent::QueryCompleted { id, completion } => {
+                        this.notify_query_completed(*id, completion, window, cx);
+                    }
+                    TabManagerEvent::Redocked(id) => {
+                        this.close_detached_window(*id, cx);
+                        this.forget_split_document(*id, cx);
+                    }
+                    TabManagerEvent::DetachedActivated(id) => {
+                        if this.split.is_some_and(|split| split.document_id == *id) {
+                            this.focus_split_pane(window, cx);
+                        } else {
+                            this.activate_detached_window(*id, cx);
+                        }
+                    }
+                    TabManagerEvent::Detached(_) => {}
+                    TabManagerEvent::Closed(id) => {
+                        this.close_detached_window(*id, cx);
+                        this.forget_split_document(*id, cx);
+                        this.write_session_manifest(cx);
+                    }
+                    TabManagerEvent::Opened(_) | TabManagerEvent::Reordered => {
                         this.write_session_manifest(cx);
                     }
                 }
@@ -1261,6 +1857,8 @@ impl Workspace {
             modal_unsaved_changes,
             modal_drop_table,
             pending_drop_table_item_id: None,
+            modal_rename_object,
+            pending_rename_item_id: None,
             modal_tunnel_auth,
             modal_import_dashboard,
             modal_create_dashboard,
@@ -1268,36 +1866,99 @@ impl Workspace {
             modal_delete_dashboard,
             modal_delete_saved_chart,
             modal_add_panel,
+            data_search_modal,
+            index_advisor_modal,
+            export_scheduler,
+            scheduled_exports_modal,
+            notification_center,
             export_modal,
             tasks_state: PanelState::Collapsed,
             pending_command: None,
             pending_sql: None,
             pending_focus: None,
             pending_open_script: None,
+            pending_open_notebook: None,
             pending_open_routine: None,
+            pending_context_switch: None,
             needs_focus_restore: false,
             pipeline_progress: None,
             _pipeline_subscription: None,
             focus_target: FocusTarget::default(),
             keymap: default_keymap(),
+            pending_keys: Vec::new(),
             focus_handle,
             #[cfg(feature = "mcp")]
             active_governance_panel: None,
             _background_purge_task: None,
             pending_login_modal_open: None,
+            quit_save_task: None,
+            crash_recovery,
+            last_session_snapshot: None,
+            _session_snapshot_task: None,
+            is_main_window,
+            profile_scope,
+            close_prompt_pending: false,
+            window_handle: window.window_handle(),
+            detached_windows: std::collections::HashMap::new(),
+            split: None,
         };
 
-        {
+        if !is_main_window {
+            workspace.init_secondary_window(window, cx);
+        } else {
             let settings = workspace.app_state.read(cx).general_settings().clone();
 
+            let previous_run_crashed = workspace.app_state.read(cx).mark_session_running();
+            let session = if settings.restore_session_on_startup || previous_run_crashed {
+                workspace.load_last_session(cx)
+            } else {
+                None
+            };
+
+            // After a crash, documents with unsaved changes are offered in the
+            // recovery prompt instead of being reopened silently.
+            let recovered: Vec<RecoveredDocument> = match &session {
+                Some(session) if previous_run_crashed => session
+                    .tabs
+                    .iter()
+                    .filter_map(RecoveredDocument::from_session_tab)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let held_back: std::collections::HashSet<String> =
+                recovered.iter().map(|doc| doc.tab.id.clone()).collect();
+
+            if !recovered.is_empty() {
+                workspace
+                    .crash_recovery
+                    .update(cx, |modal, cx| modal.open(recovered, cx));
+            }
+
             if settings.restore_session_on_startup {
-                workspace.restore_session(window, cx);
+                if let Some(session) = &session {
+                    workspace.restore_session(session, &held_back, window, cx);
+                }
 
                 if settings.reopen_last_connections {
                     workspace.reopen_last_connections(cx);
                 }
             }
 
+            workspace._session_snapshot_task = Some(cx.spawn(async move |this, cx| {
+                loop {
+                    cx.background_executor()
+                        .timer(SESSION_SNAPSHOT_INTERVAL)
+                        .await;
+
+                    if this
+                        .update(cx, |this, cx| this.write_session_manifest(cx))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }));
+
             let has_tabs = !workspace.tab_manager.read(cx).is_empty();
             match settings.default_focus_on_startup {
                 dbflux_core::StartupFocus::Sidebar => {
@@ -1324,7 +1985,7 @@ impl Workspace {
                     .unwrap_or(0)
             };
 
-            if interval_minutes > 0 {
+            if is_main_window && interval_minutes > 0 {
                 let task = cx.spawn(async move |_workspace, cx| {
                     let interval_duration =
                         std::time::Duration::from_secs((interval_minutes as u64) * 60);
@@ -1438,11 +2099,16 @@ impl Workspace {
             save_query: &'static str,
             save_file_as: &'static str,
             open_script_file: &'static str,
+            find_in_editor: &'static str,
+            replace_in_editor: &'static str,
             open_history: &'static str,
             close_tab: &'static str,
             export_results: &'static str,
             toggle_sidebar: &'static str,
             open_audit_viewer: &'static str,
+            quick_open: &'static str,
+            switch_connection: &'static str,
+            reopen_closed_tab: &'static str,
         }
 
         #[cfg(target_os = "macos")]
@@ -1453,11 +2119,16 @@ impl Workspace {
             save_query: "cmd-s",
             save_file_as: "cmd-shift-s",
             open_script_file: "cmd-o",
+            find_in_editor: "cmd-f",
+            replace_in_editor: "cmd-shift-h",
             open_history: "cmd-p",
             close_tab: "cmd-w",
             export_results: "cmd-e",
             toggle_sidebar: "cmd-b",
             open_audit_viewer: "cmd-shift-a",
+            quick_open: "cmd-p",
+            switch_connection: "cmd-shift-d",
+            reopen_closed_tab: "cmd-shift-t",
         };
         #[cfg(not(target_os = "macos"))]
         const SC: ShortcutLabels = ShortcutLabels {
@@ -1467,17 +2138,23 @@ impl Workspace {
             save_query: "ctrl-s",
             save_file_as: "ctrl-shift-s",
             open_script_file: "ctrl-o",
+            find_in_editor: "ctrl-f",
+            replace_in_editor: "ctrl-shift-h",
             open_history: "ctrl-p",
             close_tab: "ctrl-w",
             export_results: "ctrl-e",
             toggle_sidebar: "ctrl-b",
             open_audit_viewer: "ctrl-shift-a",
+            quick_open: "ctrl-p",
+            switch_connection: "ctrl-shift-d",
+            reopen_closed_tab: "ctrl-shift-t",
         };
 
         vec![
             // Editor
             PaletteCommand::new("new_query_tab", "New Query Tab", "Editor")
                 .with_shortcut(SC.new_query_tab),
+            PaletteCommand::new("new_notebook", "New Notebook", "Editor"),
             PaletteCommand::new("run_query", "Run Query", "Editor").with_shortcut(SC.run_query),
             PaletteCommand::new("run_query_in_new_tab", "Run Query in New Tab", "Editor")
                 .with_shortcut(SC.run_query_in_new_tab),
@@ -1486,6 +2163,12 @@ impl Workspace {
                 .with_shortcut(SC.save_file_as),
             PaletteCommand::new("open_script_file", "Open Script File", "Editor")
                 .with_shortcut(SC.open_script_file),
+            PaletteCommand::new("find_in_editor", "Find in Editor", "Editor")
+                .with_shortcut(SC.find_in_editor),
+            PaletteCommand::new("replace_in_editor", "Find and Replace in Editor", "Editor")
+                .with_shortcut(SC.replace_in_editor),
+            PaletteCommand::new("toggle_query_variables", "Toggle Query Variables", "Editor"),
+            PaletteCommand::new("find_saved_query", "Find Saved Query...", "Editor"),
             PaletteCommand::new("open_history", "Open Query History", "Editor")
                 .with_shortcut(SC.open_history),
             PaletteCommand::new("cancel_query", "Cancel Running Query", "Editor")
@@ -1496,9 +2179,21 @@ impl Workspace {
                 .with_shortcut(SC.close_tab),
             PaletteCommand::new("next_tab", "Next Tab", "Tabs").with_shortcut("ctrl-tab"),
             PaletteCommand::new("prev_tab", "Previous Tab", "Tabs").with_shortcut("ctrl-shift-tab"),
+            PaletteCommand::new("move_tab_to_group_1", "Move Tab to Group 1", "Tabs"),
+            PaletteCommand::new("move_tab_to_group_2", "Move Tab to Group 2", "Tabs"),
+            PaletteCommand::new("move_tab_to_group_3", "Move Tab to Group 3", "Tabs"),
+            PaletteCommand::new("move_tab_to_group_4", "Move Tab to Group 4", "Tabs"),
+            PaletteCommand::new("reopen_closed_tab", "Reopen Closed Tab", "Tabs")
+                .with_shortcut(SC.reopen_closed_tab),
+            PaletteCommand::new("navigate_back", "Go Back", "Tabs").with_shortcut("alt-left"),
+            PaletteCommand::new("navigate_forward", "Go Forward", "Tabs")
+                .with_shortcut("alt-right"),
             // Results
             PaletteCommand::new("export_results", "Export Results", "Results")
                 .with_shortcut(SC.export_results),
+            PaletteCommand::new("scheduled_exports", "Scheduled Exports...", "Results"),
+            PaletteCommand::new("toggle_row_inspector", "Toggle Row Inspector", "Results")
+                .with_shortcut("i"),
             // Connections
             PaletteCommand::new(
                 "open_connection_manager",
@@ -1507,6 +2202,16 @@ impl Workspace {
             ),
             PaletteCommand::new("disconnect", "Disconnect Current", "Connections"),
             PaletteCommand::new("refresh_schema", "Refresh Schema", "Connections"),
+            PaletteCommand::new("search_data", "Search Data...", "Connections"),
+            PaletteCommand::new("index_advisor", "Index Advisor...", "Connections"),
+            PaletteCommand::new("quick_open", "Quick Open Object...", "Connections")
+                .with_shortcut(SC.quick_open),
+            PaletteCommand::new(
+                "switch_connection",
+                "Switch Connection or Database...",
+                "Connections",
+            )
+            .with_shortcut(SC.switch_connection),
             // Focus — Ctrl+Shift+1..4 stay literal Ctrl on every platform
             // (Cmd+Shift+3/4 are macOS screenshot shortcuts).
             PaletteCommand::new("focus_sidebar", "Focus Sidebar", "Focus")
@@ -1523,6 +2228,12 @@ impl Workspace {
             PaletteCommand::new("toggle_editor", "Toggle Editor Panel", "View"),
             PaletteCommand::new("toggle_results", "Toggle Results Panel", "View"),
             PaletteCommand::new("toggle_tasks", "Toggle Tasks Panel", "View"),
+            PaletteCommand::new("new_window", "New Window", "View"),
+            PaletteCommand::new("split_right", "Split Right", "View"),
+            PaletteCommand::new("split_down", "Split Down", "View"),
+            PaletteCommand::new("focus_other_pane", "Focus Other Pane", "View"),
+            PaletteCommand::new("move_to_other_pane", "Move Document to Other Pane", "View"),
+            PaletteCommand::new("close_split", "Close Split", "View"),
             PaletteCommand::new("open_settings", "Open Settings", "View"),
             PaletteCommand::new("open_login_modal", "Open Auth Profile Login", "View"),
             PaletteCommand::new("open_sso_wizard", "Open AWS SSO Wizard", "View"),
@@ -1532,12 +2243,30 @@ impl Workspace {
             PaletteCommand::new("refresh_mcp_governance", "Refresh MCP Governance", "View"),
             PaletteCommand::new("open_audit_viewer", "Open Audit Viewer", "View")
                 .with_shortcut(SC.open_audit_viewer),
+            PaletteCommand::new("open_notifications", "Open Notifications...", "View"),
+            PaletteCommand::new("zoom_in", "Zoom In", "View"),
+            PaletteCommand::new("zoom_out", "Zoom Out", "View"),
+            PaletteCommand::new("reset_zoom", "Reset Zoom", "View"),
             // Charts / Dashboards
             PaletteCommand::new("open_saved_chart", "Open Chart...", "Charts"),
             PaletteCommand::new("new_dashboard", "New Dashboard...", "Dashboards"),
         ]
     }
 
+    /// Keys bound to a palette command in the active keymap, nearest to
+    /// `context` first, as a palette shortcut label.
+    fn palette_shortcut(&self, context: ContextId, cmd: &PaletteCommand) -> Option<String> {
+        let command = Command::from_palette_id(cmd.id)?;
+
+        let keys = self.keymap.keys_for_command(context, command).or_else(|| {
+            ContextId::all_variants()
+                .iter()
+                .find_map(|ctx| self.keymap.keys_for_command(*ctx, command))
+        })?;
+
+        Some(palette_shortcut_label(&keys))
+    }
+
     /// Test-only accessor to the list of default palette commands.
     ///
     /// Used by command_palette tests to verify command labels without
@@ -1573,7 +2302,10 @@ impl Workspace {
             || self.modal_create_dashboard.read(cx).is_visible()
             || self.modal_rename_item.read(cx).is_visible()
             || self.modal_add_panel.read(cx).is_visible()
+            || self.data_search_modal.read(cx).is_visible()
+            || self.scheduled_exports_modal.read(cx).is_visible()
             || self.modal_drop_table.read(cx).is_visible()
+            || self.modal_rename_object.read(cx).is_visible()
             || self.modal_tunnel_auth.read(cx).is_visible()
         {
             return ContextId::TextInput;
@@ -1604,9 +2336,9 @@ impl Workspace {
             return ContextId::TextInput;
         }
 
-        // When focused on document area, delegate context to the active document
+        // When focused on document area, delegate context to the focused document
         if self.focus_target == FocusTarget::Document
-            && let Some(tab) = self.tab_manager.read(cx).active_tab()
+            && let Some(tab) = self.tab_manager.read(cx).focused_tab()
         {
             return tab.active_context(cx);
         }
@@ -1645,7 +2377,9 @@ impl Workspace {
 
         if !was_visible {
             let items = self.build_palette_items(cx);
+            let recent = self.app_state.read(cx).recent_palette_commands();
             self.command_palette.update(cx, |palette, cx| {
+                palette.set_recent_commands(recent);
                 palette.open_with_items(items, window, cx);
             });
         } else {
@@ -1656,11 +2390,54 @@ impl Workspace {
         }
     }
 
+    /// Opens the palette in quick-open mode over the cached schema objects
+    /// of every connected profile.
+    pub(super) fn open_quick_open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let mut items = Vec::new();
+        let state = self.app_state.read(cx);
+        for (&profile_id, connected) in state.connections().iter() {
+            let hide_system_objects = state
+                .effective_settings_for_connection(Some(profile_id))
+                .hide_system_objects;
+            build_quick_open_items(profile_id, connected, hide_system_objects, &mut items);
+        }
+
+        if items.is_empty() {
+            Toast::warning("Connect to a database to quick-open its objects")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        self.command_palette.update(cx, |palette, cx| {
+            palette.open_quick_open(items, window, cx);
+        });
+    }
+
     /// Build the palette item list from current app state.
     fn build_palette_items(&self, cx: &Context<Self>) -> Vec<PaletteItem> {
-        let mut items: Vec<PaletteItem> = Self::default_commands()
+        let scope = PaletteScope {
+            context: self.active_context(cx),
+            document: self
+                .tab_manager
+                .read(cx)
+                .focused_tab()
+                .map(|tab| tab.kind()),
+        };
+
+        let mut items: Vec<PaletteItem> = palette_commands_for(&scope, Self::default_commands())
             .into_iter()
-            .map(|cmd| cmd.into())
+            .map(|cmd| PaletteItem::Action {
+                id: cmd.id,
+                name: cmd.name,
+                category: cmd.category,
+                // Labels from the keymap follow the user's own bindings;
+                // the built-in label covers keys handled outside of it.
+                shortcut: self
+                    .palette_shortcut(scope.context, &cmd)
+                    .map(SharedString::from)
+                    .or(cmd.shortcut.map(SharedString::from)),
+            })
             .collect();
 
         let app_state = self.app_state.read(cx);
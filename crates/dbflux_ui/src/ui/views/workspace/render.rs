@@ -4,7 +4,7 @@ use dbflux_components::composites::{PanelHeaderVariant, panel_header_collapsible
 use dbflux_components::controls::Button;
 use dbflux_components::modals::shell::{ModalShell, ModalVariant};
 use dbflux_components::primitives::{Chord, Icon, Text};
-use dbflux_components::typography::Body;
+use dbflux_components::typography::{Body, MonoMeta};
 use dbflux_ui_base::modal_frame::ModalFrame;
 use dbflux_ui_base::platform;
 use gpui_component::IconName;
@@ -24,6 +24,100 @@ impl Workspace {
         self.tab_manager
             .update(cx, |mgr, cx| mgr.render_active(window, cx))
     }
+
+    /// Renders the split pane: a header with the document's title and a
+    /// close button above the document itself.
+    fn render_split_pane(
+        &mut self,
+        split: SplitPane,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        let id = split.document_id;
+        let (title, content) = self.tab_manager.update(cx, |mgr, cx| {
+            let title = mgr.document(id)?.tab_title(cx);
+            Some((title, mgr.render_document(id, window, cx)?))
+        })?;
+
+        let focused = self.focus_target == FocusTarget::Document
+            && self.tab_manager.read(cx).split_focus() == Some(id);
+        let theme = cx.theme();
+
+        let header = div()
+            .h(Heights::TAB)
+            .flex_shrink_0()
+            .relative()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .px(Spacing::MD)
+            .bg(theme.tab_bar)
+            .border_b_1()
+            .border_color(theme.border)
+            .when(focused, |el| {
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom_0()
+                        .left_0()
+                        .right_0()
+                        .h(Heights::TAB_STRIPE)
+                        .bg(theme.primary),
+                )
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .child(MonoMeta::new(title).color(if focused {
+                        theme.foreground
+                    } else {
+                        theme.muted_foreground
+                    })),
+            )
+            .child(
+                div()
+                    .id("split-pane-close")
+                    .w(Heights::ICON_SM)
+                    .h(Heights::ICON_SM)
+                    .rounded(Radii::SM)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .cursor_pointer()
+                    .hover(|el| el.bg(theme.secondary))
+                    .child(Icon::new(AppIcon::X).size(px(12.0)).muted()) // guardrail-allow: 12px icon size, no ICON_XS token
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        cx.stop_propagation();
+                        this.close_split(window, cx);
+                    })),
+            );
+
+        Some(
+            div()
+                .id("split-pane")
+                .flex()
+                .flex_col()
+                .size_full()
+                .border_color(theme.border)
+                .map(|el| match split.direction {
+                    SplitDirection::Right => el.border_l_1(),
+                    SplitDirection::Down => el.border_t_1(),
+                })
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _, _, cx| {
+                        this.tab_manager
+                            .update(cx, |mgr, cx| mgr.set_split_focus(Some(id), cx));
+                        cx.notify();
+                    }),
+                )
+                .child(header)
+                .child(div().flex_1().min_h_0().overflow_hidden().child(content))
+                .into_any_element(),
+        )
+    }
 }
 
 /// One row of the empty-workspace placeholder: a `Chord` followed by a
@@ -61,6 +155,10 @@ impl Render for Workspace {
             self.finalize_open_script(pending, window, cx);
         }
 
+        if let Some(pending) = self.pending_open_notebook.take() {
+            self.finalize_open_notebook(pending, window, cx);
+        }
+
         if let Some(pending) = self.pending_open_routine.take() {
             self.finalize_open_routine(pending, window, cx);
         }
@@ -86,8 +184,11 @@ impl Render for Workspace {
         let sso_wizard = self.sso_wizard.clone();
 
         let tab_bar = self.tab_bar.clone();
-        let has_tabs = !self.tab_manager.read(cx).is_empty();
+        let split = self.split;
+        let has_tabs = !self.tab_manager.read(cx).docked_ids().is_empty() || split.is_some();
         let active_doc_element = self.render_active_document(window, cx);
+        let split_pane_element =
+            split.and_then(|split| Some((split, self.render_split_pane(split, window, cx)?)));
         let inspector_open = self.workspace_inspector.read(cx).is_open();
         let inspector_resizing = self.workspace_inspector.read(cx).is_resizing();
         let inspector_entity = self.workspace_inspector.clone();
@@ -141,6 +242,62 @@ impl Render for Workspace {
                 cx,
             );
 
+            // Tab bar and active document (with the inspector) form the main
+            // pane; the split pane, when open, sits beside or below it.
+            let main_pane = div()
+                .id("main-pane")
+                .flex()
+                .flex_col()
+                .size_full()
+                .when(split_pane_element.is_some(), |el| {
+                    el.on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, _, _, cx| {
+                            this.tab_manager
+                                .update(cx, |mgr, cx| mgr.set_split_focus(None, cx));
+                            cx.notify();
+                        }),
+                    )
+                })
+                .child(tab_bar)
+                // doc + inspector live in a flex_row under the tab bar.
+                .child(
+                    div()
+                        .id("document-content-row")
+                        .flex()
+                        .flex_row()
+                        .flex_1()
+                        .min_h_0()
+                        .overflow_hidden()
+                        .when_some(active_doc_element, |el, doc| {
+                            el.child(
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .flex_1()
+                                    .min_h_0()
+                                    .overflow_hidden()
+                                    .child(doc),
+                            )
+                        })
+                        .when(inspector_open, |el| el.child(inspector_entity.clone())),
+                );
+
+            let document_panes = match split_pane_element {
+                Some((split, split_pane)) => {
+                    let group = match split.direction {
+                        SplitDirection::Right => h_resizable("document-split-right"),
+                        SplitDirection::Down => v_resizable("document-split-down"),
+                    };
+
+                    group
+                        .child(resizable_panel().child(main_pane))
+                        .child(resizable_panel().child(split_pane))
+                        .into_any_element()
+                }
+                None => main_pane.into_any_element(),
+            };
+
             v_resizable("main-panels")
                 .child(
                     resizable_panel()
@@ -168,31 +325,7 @@ impl Render for Workspace {
                                         }
                                     }),
                                 )
-                                .child(tab_bar)
-                                // doc + inspector live in a flex_row under the tab bar.
-                                .child(
-                                    div()
-                                        .id("document-content-row")
-                                        .flex()
-                                        .flex_row()
-                                        .flex_1()
-                                        .min_h_0()
-                                        .overflow_hidden()
-                                        .when_some(active_doc_element, |el, doc| {
-                                            el.child(
-                                                div()
-                                                    .flex()
-                                                    .flex_col()
-                                                    .flex_1()
-                                                    .min_h_0()
-                                                    .overflow_hidden()
-                                                    .child(doc),
-                                            )
-                                        })
-                                        .when(inspector_open, |el| {
-                                            el.child(inspector_entity.clone())
-                                        }),
-                                ),
+                                .child(document_panes),
                         ),
                 )
                 .child(
@@ -564,8 +697,25 @@ impl Render for Workspace {
                 let chord = key_chord_from_gpui(&event.keystroke);
                 let context = this.active_context(cx);
 
+                if this.handle_key_sequence(context, &chord, window, cx) {
+                    cx.stop_propagation();
+                    return;
+                }
+
                 if let Some(cmd) = this.keymap.resolve(context, &chord)
                     && this.dispatch(cmd, window, cx)
+                {
+                    cx.stop_propagation();
+                    return;
+                }
+
+                // Keys no binding claimed go to the editor's Vim layer (when
+                // enabled) so global shortcuts always take precedence over it.
+                if context == ContextId::Editor
+                    && this.focus_target == FocusTarget::Document
+                    && this.tab_manager.update(cx, |mgr, cx| {
+                        mgr.handle_unbound_keystroke_active(&event.keystroke, window, cx)
+                    })
                 {
                     cx.stop_propagation();
                 }
@@ -616,6 +766,9 @@ impl Render for Workspace {
             .when(self.modal_drop_table.read(cx).is_visible(), |root| {
                 root.child(self.modal_drop_table.clone())
             })
+            .when(self.modal_rename_object.read(cx).is_visible(), |root| {
+                root.child(self.modal_rename_object.clone())
+            })
             .when(self.modal_tunnel_auth.read(cx).is_visible(), |root| {
                 root.child(self.modal_tunnel_auth.clone())
             })
@@ -638,9 +791,27 @@ impl Render for Workspace {
             .when(self.modal_add_panel.read(cx).is_visible(), |root| {
                 root.child(self.modal_add_panel.clone())
             })
+            .when(self.data_search_modal.read(cx).is_visible(), |root| {
+                root.child(self.data_search_modal.clone())
+            })
+            .when(self.index_advisor_modal.read(cx).is_visible(), |root| {
+                root.child(self.index_advisor_modal.clone())
+            })
+            .when(self.scheduled_exports_modal.read(cx).is_visible(), |root| {
+                root.child(self.scheduled_exports_modal.clone())
+            })
+            .when(self.notification_center.read(cx).is_visible(), |root| {
+                root.child(self.notification_center.clone())
+            })
             .when(self.export_modal.read(cx).is_visible(), |root| {
                 root.child(self.export_modal.clone())
             })
+            .when(self.crash_recovery.read(cx).is_visible(), |root| {
+                root.child(self.crash_recovery.clone())
+            })
+            .when_some(self.render_which_key(cx), |root, which_key| {
+                root.child(which_key)
+            })
             .child(
                 div()
                     .absolute()
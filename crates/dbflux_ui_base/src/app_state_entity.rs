@@ -13,8 +13,10 @@ use gpui_component::Root;
 use uuid::Uuid;
 
 use crate::dashboard_manager::DashboardManager;
+use crate::notifications::NotificationLog;
 use crate::saved_chart_manager::SavedChartManager;
 use crate::saved_query_manager::SavedQueryManager;
+use crate::toast::ToastKind;
 
 // ============================================================================
 // GPUI-coupled event types
@@ -105,6 +107,9 @@ pub struct AppStateEntity {
     /// call. Ephemeral — resets to 0 on every app start. The audit log is the
     /// durable record; this counter only drives the status-bar badge.
     pub unread_error_count: u32,
+
+    /// Notifications center log. Ephemeral, like `unread_error_count`.
+    pub notifications: NotificationLog,
 }
 
 impl AppStateEntity {
@@ -132,6 +137,7 @@ impl AppStateEntity {
             pending_edit_reconnect_prompt: None,
             pending_reconnect_request: None,
             unread_error_count: 0,
+            notifications: NotificationLog::default(),
         })
     }
 
@@ -161,6 +167,7 @@ impl AppStateEntity {
             pending_edit_reconnect_prompt: None,
             pending_reconnect_request: None,
             unread_error_count: 0,
+            notifications: NotificationLog::default(),
         })
     }
 
@@ -193,6 +200,28 @@ impl AppStateEntity {
         cx.emit(OpenAuditRequested(correlation_id));
     }
 
+    /// Adds an entry to the notifications center.
+    pub fn push_notification(
+        &mut self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        document_id: Option<Uuid>,
+        severity: ToastKind,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.notifications.push(title, body, document_id, severity);
+        cx.emit(AppStateChanged);
+        cx.notify();
+    }
+
+    /// Marks every notification as read, e.g. when the center is opened.
+    pub fn mark_notifications_read(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.notifications.mark_all_read() {
+            cx.emit(AppStateChanged);
+            cx.notify();
+        }
+    }
+
     /// Resets the unread-error counter to zero.
     ///
     /// Called when the user opens the audit panel via the badge click,
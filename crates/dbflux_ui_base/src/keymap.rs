@@ -5,7 +5,7 @@
 //! intentionally does not depend on.
 
 use dbflux_app::keymap::{Command, ContextId, KeymapLayer};
-use dbflux_app::keymap::{KeyChord, KeymapStack, Modifiers};
+use dbflux_app::keymap::{KeyChord, KeymapStack, Modifiers, UserKeymapReport, load_user_keymap};
 use gpui::Keystroke;
 use std::sync::LazyLock;
 
@@ -37,7 +37,35 @@ pub fn modifiers_from_gpui(mods: &gpui::Modifiers) -> Modifiers {
 // Default keymap
 // ============================================================================
 
-static DEFAULT_KEYMAP: LazyLock<KeymapStack> = LazyLock::new(|| {
+/// Built-in bindings with the user keymap file applied on top, and the
+/// report of loading that file.
+static DEFAULT_KEYMAP: LazyLock<(KeymapStack, UserKeymapReport)> = LazyLock::new(|| {
+    let mut stack = builtin_keymap();
+
+    // Tests run against the built-in bindings only.
+    let report = if cfg!(test) {
+        UserKeymapReport::default()
+    } else {
+        match dbflux_storage::paths::data_dir() {
+            Ok(dir) => load_user_keymap(&dir.join(USER_KEYMAP_FILE), &mut stack),
+            Err(e) => UserKeymapReport {
+                errors: vec![format!("Failed to locate the keymap file: {e}")],
+                ..Default::default()
+            },
+        }
+    };
+
+    for error in &report.errors {
+        log::warn!("Keymap: {error}");
+    }
+
+    (stack, report)
+});
+
+/// Name of the user keymap file in the data directory.
+pub const USER_KEYMAP_FILE: &str = "keymap.json";
+
+fn builtin_keymap() -> KeymapStack {
     let mut stack = KeymapStack::new();
 
     stack.add_layer(global_layer());
@@ -57,11 +85,16 @@ static DEFAULT_KEYMAP: LazyLock<KeymapStack> = LazyLock::new(|| {
     stack.add_layer(event_streams_picker_layer());
 
     stack
-});
+}
 
 /// Returns a reference to the default [`KeymapStack`] with all default keybindings.
 pub fn default_keymap() -> &'static KeymapStack {
-    &DEFAULT_KEYMAP
+    &DEFAULT_KEYMAP.0
+}
+
+/// How loading the user keymap file went. The file is read once at startup.
+pub fn user_keymap_report() -> &'static UserKeymapReport {
+    &DEFAULT_KEYMAP.1
 }
 
 fn global_layer() -> KeymapLayer {
@@ -73,6 +106,14 @@ fn global_layer() -> KeymapLayer {
         Command::ToggleCommandPalette,
     );
 
+    // Quick open over schema objects. The editor keeps Ctrl+P for saved
+    // queries, so there it is reached through the command palette.
+    layer.bind(KeyChord::new("p", Modifiers::primary()), Command::QuickOpen);
+    layer.bind(
+        KeyChord::new("d", Modifiers::primary_shift()),
+        Command::SwitchConnection,
+    );
+
     // Tab management — primary modifier (Cmd on macOS, Ctrl elsewhere).
     layer.bind(
         KeyChord::new("n", Modifiers::primary()),
@@ -96,6 +137,37 @@ fn global_layer() -> KeymapLayer {
             Command::SwitchToTab(i),
         );
     }
+    for i in 1..=4 {
+        layer.bind(
+            KeyChord::new(i.to_string(), Modifiers::primary_alt()),
+            Command::MoveTabToGroup(i),
+        );
+    }
+    layer.bind(
+        KeyChord::new("t", Modifiers::primary_shift()),
+        Command::ReopenClosedTab,
+    );
+    layer.bind(
+        KeyChord::new("left", Modifiers::alt()),
+        Command::NavigateBack,
+    );
+    layer.bind(
+        KeyChord::new("right", Modifiers::alt()),
+        Command::NavigateForward,
+    );
+
+    // UI zoom. Zoom in answers to both `=` and `+` so it works with and
+    // without Shift on layouts that put `+` above `=`.
+    for chord in [
+        KeyChord::new("=", Modifiers::primary()),
+        KeyChord::new("=", Modifiers::primary_shift()),
+        KeyChord::new("+", Modifiers::primary()),
+        KeyChord::new("+", Modifiers::primary_shift()),
+    ] {
+        layer.bind(chord, Command::ZoomIn);
+    }
+    layer.bind(KeyChord::new("-", Modifiers::primary()), Command::ZoomOut);
+    layer.bind(KeyChord::new("0", Modifiers::primary()), Command::ResetZoom);
 
     // File operations
     layer.bind(
@@ -176,6 +248,17 @@ fn global_layer() -> KeymapLayer {
     // "minimize window" shortcut on macOS.
     layer.bind(KeyChord::new("m", Modifiers::ctrl()), Command::OpenTabMenu);
 
+    // Split panes. Ctrl+` stays literal Ctrl everywhere: Cmd+` cycles the
+    // app's windows on macOS.
+    layer.bind(
+        KeyChord::new("\\", Modifiers::primary()),
+        Command::SplitRight,
+    );
+    layer.bind(
+        KeyChord::new("`", Modifiers::ctrl()),
+        Command::FocusOtherPane,
+    );
+
     layer
 }
 
@@ -316,6 +399,22 @@ fn editor_layer() -> KeymapLayer {
         Command::SaveFileAs,
     );
 
+    // Find / replace. Ctrl+H is taken by panel navigation, so replace uses
+    // the shifted chord instead of the common Ctrl+H.
+    layer.bind(
+        KeyChord::new("f", Modifiers::primary()),
+        Command::FindInEditor,
+    );
+    layer.bind(
+        KeyChord::new("h", Modifiers::primary_shift()),
+        Command::ReplaceInEditor,
+    );
+    layer.bind(KeyChord::new("f3", Modifiers::none()), Command::FindNext);
+    layer.bind(
+        KeyChord::new("f3", Modifiers::shift()),
+        Command::FindPrevious,
+    );
+
     layer
 }
 
@@ -369,6 +468,10 @@ fn history_modal_layer() -> KeymapLayer {
         Command::ToggleFavorite,
     );
     layer.bind(KeyChord::new("r", Modifiers::ctrl()), Command::Rename);
+    layer.bind(
+        KeyChord::new("e", Modifiers::ctrl()),
+        Command::OrganizeSavedQuery,
+    );
     layer.bind(KeyChord::new("/", Modifiers::none()), Command::FocusSearch);
     layer.bind(KeyChord::new("s", Modifiers::primary()), Command::SaveQuery);
 
@@ -471,6 +574,12 @@ fn results_layer() -> KeymapLayer {
     // Toggle panel collapse
     layer.bind(KeyChord::new("z", Modifiers::none()), Command::TogglePanel);
 
+    // Row inspector rail
+    layer.bind(
+        KeyChord::new("i", Modifiers::none()),
+        Command::ToggleRowInspector,
+    );
+
     // Context menu
     layer.bind(
         KeyChord::new("m", Modifiers::none()),
@@ -831,6 +940,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_quick_open_is_global_but_editor_keeps_saved_queries() {
+        let keymap = default_keymap();
+
+        let chord = KeyChord::new("p", Modifiers::primary());
+        assert_eq!(
+            keymap.resolve(ContextId::Sidebar, &chord),
+            Some(Command::QuickOpen)
+        );
+        assert_eq!(
+            keymap.resolve(ContextId::Editor, &chord),
+            Some(Command::OpenSavedQueries)
+        );
+    }
+
+    #[test]
+    fn test_switch_connection_is_reachable_from_the_editor() {
+        let keymap = default_keymap();
+
+        let chord = KeyChord::new("d", Modifiers::primary_shift());
+        assert_eq!(
+            keymap.resolve(ContextId::Editor, &chord),
+            Some(Command::SwitchConnection)
+        );
+    }
+
+    #[test]
+    fn test_zoom_bindings_reach_every_context() {
+        let keymap = default_keymap();
+
+        let zoom_in = KeyChord::new("=", Modifiers::primary());
+        let zoom_out = KeyChord::new("-", Modifiers::primary());
+        let reset = KeyChord::new("0", Modifiers::primary());
+
+        for context in [ContextId::Sidebar, ContextId::Editor, ContextId::Results] {
+            assert_eq!(keymap.resolve(context, &zoom_in), Some(Command::ZoomIn));
+            assert_eq!(keymap.resolve(context, &zoom_out), Some(Command::ZoomOut));
+            assert_eq!(keymap.resolve(context, &reset), Some(Command::ResetZoom));
+        }
+    }
+
+    #[test]
+    fn test_split_pane_bindings_reach_editor_and_results() {
+        let keymap = default_keymap();
+
+        let split = KeyChord::new("\\", Modifiers::primary());
+        let other_pane = KeyChord::new("`", Modifiers::ctrl());
+
+        for context in [ContextId::Editor, ContextId::Results] {
+            assert_eq!(keymap.resolve(context, &split), Some(Command::SplitRight));
+            assert_eq!(
+                keymap.resolve(context, &other_pane),
+                Some(Command::FocusOtherPane)
+            );
+        }
+    }
+
+    #[test]
+    fn test_move_tab_to_group_bindings() {
+        let keymap = default_keymap();
+
+        for i in 1..=4 {
+            let chord = KeyChord::new(i.to_string(), Modifiers::primary_alt());
+            assert_eq!(
+                keymap.resolve(ContextId::Editor, &chord),
+                Some(Command::MoveTabToGroup(i))
+            );
+        }
+    }
+
+    #[test]
+    fn test_reopen_and_tab_history_bindings() {
+        let keymap = default_keymap();
+
+        let reopen = KeyChord::new("t", Modifiers::primary_shift());
+        let back = KeyChord::new("left", Modifiers::alt());
+        let forward = KeyChord::new("right", Modifiers::alt());
+
+        for context in [ContextId::Editor, ContextId::Results] {
+            assert_eq!(
+                keymap.resolve(context, &reopen),
+                Some(Command::ReopenClosedTab)
+            );
+            assert_eq!(keymap.resolve(context, &back), Some(Command::NavigateBack));
+            assert_eq!(
+                keymap.resolve(context, &forward),
+                Some(Command::NavigateForward)
+            );
+        }
+    }
+
     #[test]
     fn test_sidebar_vim_navigation() {
         let keymap = default_keymap();
@@ -870,6 +1070,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_history_modal_organize_binding() {
+        let keymap = default_keymap();
+
+        assert_eq!(
+            keymap.resolve(
+                ContextId::HistoryModal,
+                &KeyChord::new("e", Modifiers::ctrl())
+            ),
+            Some(Command::OrganizeSavedQuery)
+        );
+    }
+
+    #[test]
+    fn test_editor_find_bindings() {
+        let keymap = default_keymap();
+
+        let primary_f = KeyChord::new("f", Modifiers::primary());
+        let primary_shift_h = KeyChord::new("h", Modifiers::primary_shift());
+        let f3 = KeyChord::new("f3", Modifiers::none());
+        let shift_f3 = KeyChord::new("f3", Modifiers::shift());
+
+        assert_eq!(
+            keymap.resolve(ContextId::Editor, &primary_f),
+            Some(Command::FindInEditor)
+        );
+        assert_eq!(
+            keymap.resolve(ContextId::Editor, &primary_shift_h),
+            Some(Command::ReplaceInEditor)
+        );
+        assert_eq!(
+            keymap.resolve(ContextId::Editor, &f3),
+            Some(Command::FindNext)
+        );
+        assert_eq!(
+            keymap.resolve(ContextId::Editor, &shift_f3),
+            Some(Command::FindPrevious)
+        );
+    }
+
     #[test]
     fn test_global_fallback_from_sidebar() {
         let keymap = default_keymap();
@@ -966,6 +1206,7 @@ mod tests {
             ('r', Command::Rename),
             ('o', Command::ResultsAddRow),
             ('x', Command::Delete),
+            ('i', Command::ToggleRowInspector),
         ];
         for (letter, expected) in expectations {
             let chord = KeyChord::new(letter.to_string(), Modifiers::none());
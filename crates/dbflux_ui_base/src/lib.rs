@@ -13,13 +13,17 @@ pub mod file_dialog;
 pub mod keymap;
 pub mod modal_frame;
 pub mod modals;
+pub mod notifications;
 pub mod platform;
+pub mod rpc_driver_watch;
 pub mod saved_chart_manager;
 pub mod saved_query_manager;
+pub mod schema_drag;
 pub mod sql_preview_modal;
 pub mod sso_wizard;
 pub mod toast;
 pub mod user_error;
+pub mod user_themes;
 
 mod style_guardrails;
 
@@ -34,7 +38,8 @@ pub use dashboard_manager::{
     Dashboard, DashboardManager, DashboardPanel, DashboardPanelDraft, DashboardPanelKind,
     DraftGridLayout,
 };
-pub use keymap::{default_keymap, key_chord_from_gpui};
+pub use keymap::{default_keymap, key_chord_from_gpui, user_keymap_report};
 pub use saved_chart_manager::SavedChartManager;
 pub use saved_query_manager::{ConnectionTableProbe, SavedQueryManager, TableProbe};
+pub use schema_drag::{DraggedSchemaObject, SchemaObjectDrag};
 pub use user_error::{ErrorKind, UserFacingError, report_error, report_error_async};
@@ -0,0 +1,296 @@
+//! In-app notifications center.
+//!
+//! Keeps the most recent notifications raised while the app is running (every
+//! toast, finished background tasks, a long query that finished in a
+//! background tab) so they can be reviewed after the toast or desktop
+//! notification is gone. Ephemeral: the log starts empty on every launch.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use dbflux_core::chrono::{DateTime, Local};
+use dbflux_core::{TaskKind, TaskSnapshot, TaskStatus};
+use uuid::Uuid;
+
+use crate::toast::ToastKind;
+
+/// Notifications kept in the log; older ones are dropped.
+pub const MAX_NOTIFICATIONS: usize = 50;
+
+/// One entry of the notifications center.
+#[derive(Clone, Debug)]
+pub struct AppNotification {
+    pub id: u64,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Local>,
+    /// Document the notification is about; clicking it focuses that tab.
+    pub document_id: Option<Uuid>,
+    pub severity: ToastKind,
+}
+
+/// Bounded, newest-first list of notifications with an unread counter.
+#[derive(Default)]
+pub struct NotificationLog {
+    entries: VecDeque<AppNotification>,
+    next_id: u64,
+    unread: usize,
+}
+
+impl NotificationLog {
+    /// Records a notification and returns its id.
+    pub fn push(
+        &mut self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        document_id: Option<Uuid>,
+        severity: ToastKind,
+    ) -> u64 {
+        self.next_id += 1;
+        let id = self.next_id;
+
+        self.entries.push_front(AppNotification {
+            id,
+            title: title.into(),
+            body: body.into(),
+            created_at: Local::now(),
+            document_id,
+            severity,
+        });
+        self.entries.truncate(MAX_NOTIFICATIONS);
+        self.unread = (self.unread + 1).min(self.entries.len());
+
+        id
+    }
+
+    /// Notifications, newest first.
+    pub fn entries(&self) -> impl Iterator<Item = &AppNotification> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of notifications of the given severity.
+    pub fn count(&self, severity: ToastKind) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.severity == severity)
+            .count()
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.unread
+    }
+
+    /// Returns `true` if anything was unread.
+    pub fn mark_all_read(&mut self) -> bool {
+        std::mem::take(&mut self.unread) > 0
+    }
+
+    pub fn remove(&mut self, id: u64) {
+        self.entries.retain(|entry| entry.id != id);
+        self.unread = self.unread.min(self.entries.len());
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.unread = 0;
+    }
+}
+
+/// Body of a "query finished" notification: run time plus the row count or
+/// the first line of the error.
+pub fn query_finished_body(
+    elapsed: Duration,
+    row_count: Option<u64>,
+    error: Option<&str>,
+) -> String {
+    let runtime = format_runtime(elapsed);
+
+    if let Some(error) = error {
+        let first_line = error.lines().next().unwrap_or_default().trim();
+        return format!("Failed after {}: {}", runtime, first_line);
+    }
+
+    match row_count {
+        Some(1) => format!("Finished in {} · 1 row", runtime),
+        Some(rows) => format!("Finished in {} · {} rows", runtime, rows),
+        None => format!("Finished in {}", runtime),
+    }
+}
+
+/// Title, body and severity of the notification for a finished background
+/// task, or `None` for tasks that are too frequent or too routine to be worth
+/// one (queries, key browsing, lazy schema loads, schema auto-refresh).
+pub fn task_notification(task: &TaskSnapshot) -> Option<(String, String, ToastKind)> {
+    match task.kind {
+        TaskKind::Query
+        | TaskKind::KeyScan
+        | TaskKind::KeyGet
+        | TaskKind::KeyMutation
+        | TaskKind::LoadSchema
+        | TaskKind::SwitchDatabase
+        | TaskKind::SchemaRefresh => return None,
+        TaskKind::Connect
+        | TaskKind::Disconnect
+        | TaskKind::Hook { .. }
+        | TaskKind::SchemaDrop
+        | TaskKind::SchemaRename
+        | TaskKind::Export
+        | TaskKind::DataSearch
+        | TaskKind::IndexAdvice => {}
+    }
+
+    let runtime = format_runtime(Duration::from_secs_f64(task.elapsed_secs.max(0.0)));
+
+    let (body, severity) = match &task.status {
+        TaskStatus::Queued | TaskStatus::Running => return None,
+        TaskStatus::Completed => {
+            let body = match task.details.as_deref().and_then(|d| d.lines().next()) {
+                Some(detail) if !detail.trim().is_empty() => {
+                    format!("Completed in {} · {}", runtime, detail.trim())
+                }
+                _ => format!("Completed in {}", runtime),
+            };
+            (body, ToastKind::Success)
+        }
+        TaskStatus::Failed(error) => {
+            let first_line = error.lines().next().unwrap_or_default().trim();
+            (
+                format!("Failed after {}: {}", runtime, first_line),
+                ToastKind::Error,
+            )
+        }
+        TaskStatus::Cancelled => (format!("Cancelled after {}", runtime), ToastKind::Info),
+    };
+
+    Some((task.description.clone(), body, severity))
+}
+
+/// `42s`, `3m 05s` or `1h 02m`.
+fn format_runtime(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_is_newest_first_bounded_and_counts_unread() {
+        let mut log = NotificationLog::default();
+
+        for n in 0..(MAX_NOTIFICATIONS + 5) {
+            log.push(format!("n{}", n), "", None, ToastKind::Info);
+        }
+
+        let titles: Vec<_> = log.entries().map(|entry| entry.title.clone()).collect();
+        assert_eq!(titles.len(), MAX_NOTIFICATIONS);
+        assert_eq!(titles[0], format!("n{}", MAX_NOTIFICATIONS + 4));
+        assert_eq!(log.unread_count(), MAX_NOTIFICATIONS);
+
+        assert!(log.mark_all_read());
+        assert!(!log.mark_all_read());
+
+        let newest = log.push("latest", "", None, ToastKind::Error);
+        assert_eq!(log.count(ToastKind::Error), 1);
+        assert_eq!(log.unread_count(), 1);
+
+        log.remove(newest);
+        assert_eq!(log.unread_count(), 0);
+        assert_eq!(log.entries().count(), MAX_NOTIFICATIONS);
+
+        log.clear();
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn query_finished_body_reports_runtime_and_outcome() {
+        assert_eq!(
+            query_finished_body(Duration::from_secs(42), Some(1200), None),
+            "Finished in 42s · 1200 rows"
+        );
+        assert_eq!(
+            query_finished_body(Duration::from_secs(185), Some(1), None),
+            "Finished in 3m 05s · 1 row"
+        );
+        assert_eq!(
+            query_finished_body(Duration::from_secs(3720), None, None),
+            "Finished in 1h 02m"
+        );
+        assert_eq!(
+            query_finished_body(
+                Duration::from_secs(31),
+                None,
+                Some("canceling statement due to statement timeout\nCONTEXT: ...")
+            ),
+            "Failed after 31s: canceling statement due to statement timeout"
+        );
+    }
+
+    fn task(kind: TaskKind, status: TaskStatus, details: Option<&str>) -> TaskSnapshot {
+        TaskSnapshot {
+            id: Uuid::new_v4(),
+            kind,
+            description: "Scheduled export: nightly".to_string(),
+            status,
+            elapsed_secs: 12.4,
+            progress: None,
+            units: None,
+            details: details.map(str::to_string),
+            is_cancellable: false,
+            profile_id: None,
+            target: None,
+        }
+    }
+
+    #[test]
+    fn task_notification_covers_finished_notable_tasks_only() {
+        assert_eq!(
+            task_notification(&task(
+                TaskKind::Export,
+                TaskStatus::Completed,
+                Some("120 rows written to /tmp/nightly.csv")
+            )),
+            Some((
+                "Scheduled export: nightly".to_string(),
+                "Completed in 12s · 120 rows written to /tmp/nightly.csv".to_string(),
+                ToastKind::Success
+            ))
+        );
+        assert_eq!(
+            task_notification(&task(
+                TaskKind::Connect,
+                TaskStatus::Failed("connection refused\nis the server running?".into()),
+                None
+            ))
+            .map(|(_, body, severity)| (body, severity)),
+            Some((
+                "Failed after 12s: connection refused".to_string(),
+                ToastKind::Error
+            ))
+        );
+        assert_eq!(
+            task_notification(&task(TaskKind::DataSearch, TaskStatus::Cancelled, None))
+                .map(|(_, _, severity)| severity),
+            Some(ToastKind::Info)
+        );
+
+        assert!(task_notification(&task(TaskKind::Export, TaskStatus::Running, None)).is_none());
+        assert!(task_notification(&task(TaskKind::Query, TaskStatus::Completed, None)).is_none());
+        assert!(
+            task_notification(&task(TaskKind::SchemaRefresh, TaskStatus::Completed, None))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Runtime discovery of external driver services.
+//!
+//! The configured RPC services are polled: a driver service added, edited,
+//! enabled or removed in the settings is handshaked with and registered — or
+//! unregistered — without a restart. Listeners see the new driver list on the
+//! next `AppStateChanged`.
+
+use crate::toast::ToastKind;
+use crate::{AppStateChanged, AppStateEntity};
+use gpui::{App, Entity};
+use std::time::Duration;
+
+const RELOAD_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Starts watching the configured driver services.
+///
+/// Call once at startup, after the app state is created.
+pub fn init(app_state: Entity<AppStateEntity>, cx: &mut App) {
+    cx.spawn(async move |cx| {
+        loop {
+            cx.background_executor().timer(RELOAD_POLL_INTERVAL).await;
+
+            let Ok(reload) = cx.update(|cx| app_state.read(cx).pending_rpc_driver_reload()) else {
+                break;
+            };
+            let Some(reload) = reload else {
+                continue;
+            };
+
+            let probed = cx.background_spawn(async move { reload.probe() }).await;
+
+            let applied = app_state.update(cx, |state, cx| {
+                let registered = state.apply_rpc_driver_reload(probed);
+                if registered.is_empty() {
+                    cx.emit(AppStateChanged);
+                    cx.notify();
+                    return;
+                }
+
+                let names: Vec<String> = registered
+                    .iter()
+                    .filter_map(|id| state.drivers().get(id))
+                    .map(|driver| driver.metadata().display_name.clone())
+                    .collect();
+                state.push_notification(
+                    "External driver available",
+                    names.join(", "),
+                    None,
+                    ToastKind::Info,
+                    cx,
+                );
+            });
+
+            if applied.is_err() {
+                break;
+            }
+        }
+    })
+    .detach();
+}
@@ -0,0 +1,157 @@
+//! Drag payload for schema objects dragged out of the sidebar and dropped on
+//! a SQL document.
+
+use dbflux_app::AppState;
+use dbflux_core::{ColumnInfo, SqlDialect};
+use uuid::Uuid;
+
+/// The table, view or column being dragged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DraggedSchemaObject {
+    Table {
+        database: Option<String>,
+        schema: String,
+        name: String,
+    },
+    Column {
+        name: String,
+    },
+}
+
+impl DraggedSchemaObject {
+    /// The quoted name of the object in `dialect`, or for a table with
+    /// `as_select` set a `SELECT` of its `columns` (`*` when they are not
+    /// loaded yet).
+    pub fn sql_text(
+        &self,
+        dialect: &dyn SqlDialect,
+        columns: Option<&[ColumnInfo]>,
+        as_select: bool,
+    ) -> String {
+        match self {
+            Self::Column { name } => dialect.quote_identifier(name),
+            Self::Table { schema, name, .. } => {
+                let schema = Some(schema.as_str()).filter(|schema| !schema.is_empty());
+                let table = dialect.qualified_table(schema, name);
+                if !as_select {
+                    return table;
+                }
+
+                let select_list = match columns {
+                    Some(columns) if !columns.is_empty() => columns
+                        .iter()
+                        .map(|column| dialect.quote_identifier(&column.name))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    _ => "*".to_string(),
+                };
+
+                format!("SELECT {select_list}\nFROM {table};")
+            }
+        }
+    }
+}
+
+/// A schema object in flight from the sidebar. Drop targets turn it into
+/// SQL with [`SchemaObjectDrag::resolve`].
+#[derive(Debug, Clone)]
+pub struct SchemaObjectDrag {
+    pub profile_id: Uuid,
+    pub object: DraggedSchemaObject,
+    pub label: String,
+}
+
+impl SchemaObjectDrag {
+    /// Text to insert for the drop, quoted in the dialect of the connection
+    /// the object came from. `None` once that connection is closed.
+    pub fn resolve(&self, state: &AppState, as_select: bool) -> Option<String> {
+        let connected = state.connections().get(&self.profile_id)?;
+
+        let columns = match &self.object {
+            DraggedSchemaObject::Table {
+                database,
+                schema,
+                name,
+            } => {
+                let cache_db = database.as_deref().unwrap_or(schema);
+                connected
+                    .table_details
+                    .get(&(cache_db.to_string(), name.clone()))
+                    .and_then(|table| table.columns.as_deref())
+            }
+            DraggedSchemaObject::Column { .. } => None,
+        };
+
+        Some(
+            self.object
+                .sql_text(connected.connection.dialect(), columns, as_select),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::DefaultSqlDialect;
+
+    fn column(name: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            type_name: "text".to_string(),
+            nullable: true,
+            is_primary_key: false,
+            default_value: None,
+            enum_values: None,
+            comment: None,
+            definition: None,
+        }
+    }
+
+    fn users() -> DraggedSchemaObject {
+        DraggedSchemaObject::Table {
+            database: None,
+            schema: "public".to_string(),
+            name: "users".to_string(),
+        }
+    }
+
+    #[test]
+    fn tables_insert_their_qualified_name() {
+        assert_eq!(
+            users().sql_text(&DefaultSqlDialect, None, false),
+            "\"public\".\"users\""
+        );
+
+        let unqualified = DraggedSchemaObject::Table {
+            database: None,
+            schema: String::new(),
+            name: "users".to_string(),
+        };
+        assert_eq!(
+            unqualified.sql_text(&DefaultSqlDialect, None, false),
+            "\"users\""
+        );
+
+        let column = DraggedSchemaObject::Column {
+            name: "user id".to_string(),
+        };
+        assert_eq!(
+            column.sql_text(&DefaultSqlDialect, None, true),
+            "\"user id\""
+        );
+    }
+
+    #[test]
+    fn select_template_lists_loaded_columns() {
+        let columns = [column("id"), column("email")];
+
+        assert_eq!(
+            users().sql_text(&DefaultSqlDialect, Some(&columns), true),
+            "SELECT \"id\", \"email\"\nFROM \"public\".\"users\";"
+        );
+        assert_eq!(
+            users().sql_text(&DefaultSqlDialect, None, true),
+            "SELECT *\nFROM \"public\".\"users\";"
+        );
+    }
+}
@@ -208,6 +208,8 @@ impl SqlPreviewModal {
                 is_primary_key: pk_indices.contains(&idx),
                 default_value: None,
                 enum_values: None,
+                comment: None,
+                definition: None,
             })
             .collect();
 
@@ -378,6 +380,8 @@ impl SqlPreviewModal {
                     is_primary_key: true,
                     default_value: None,
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 },
                 ColumnInfo {
                     name: "column2".to_string(),
@@ -386,6 +390,8 @@ impl SqlPreviewModal {
                     is_primary_key: false,
                     default_value: None,
                     enum_values: None,
+                    comment: None,
+                    definition: None,
                 },
             ]
         });
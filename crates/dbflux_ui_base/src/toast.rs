@@ -14,6 +14,7 @@ use gpui::{App, Context, Entity, Global, Hsla, SharedString, Window, px, rems};
 use gpui_component::ActiveTheme;
 
 use crate::AsyncUpdateResultExt;
+use crate::app_state_entity::AppStateGlobal;
 use dbflux_components::icons::AppIcon;
 use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
 
@@ -46,7 +47,7 @@ pub enum ToastKind {
 }
 
 impl ToastKind {
-    fn icon(self) -> AppIcon {
+    pub fn icon(self) -> AppIcon {
         match self {
             Self::Success => AppIcon::CircleCheck,
             Self::Info => AppIcon::Info,
@@ -60,7 +61,7 @@ impl ToastKind {
     }
 
     /// Foreground / accent fill color (icon, stripe, progress fill where appropriate).
-    fn accent(self, cx: &App) -> Hsla {
+    pub fn accent(self, cx: &App) -> Hsla {
         let b = SemBannerColors::for_current(cx);
         match self {
             Self::Success => b.success_fg,
@@ -318,6 +319,11 @@ impl ToastHost {
 
         let auto_dismiss = toast.effective_auto_dismiss();
 
+        // Progress toasts are transient status, not events worth keeping.
+        if toast.progress.is_none() {
+            Self::record_in_notifications(&toast, cx);
+        }
+
         // Initially-collapsed when the toast opts in AND there's something to hide.
         let stored = StoredToast {
             id,
@@ -345,6 +351,33 @@ impl ToastHost {
         }
     }
 
+    /// Copies the toast into the notifications center so it can be reviewed
+    /// after it is dismissed. Deferred: toasts are often pushed from inside an
+    /// `AppStateEntity` update.
+    fn record_in_notifications(toast: &Toast, cx: &mut Context<Self>) {
+        let Some(app_state) = cx
+            .try_global::<AppStateGlobal>()
+            .map(|global| global.entity.clone())
+        else {
+            return;
+        };
+
+        let severity = toast.kind;
+        let title = toast.title.to_string();
+        let body = [&toast.subtitle, &toast.body, &toast.code_block]
+            .into_iter()
+            .flatten()
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        cx.defer(move |cx| {
+            app_state.update(cx, |state, cx| {
+                state.push_notification(title, body, None, severity, cx);
+            });
+        });
+    }
+
     fn dismiss(&mut self, id: u64, cx: &mut Context<Self>) {
         self.toasts.retain(|t| t.id != id);
         self.collapsed.remove(&id);
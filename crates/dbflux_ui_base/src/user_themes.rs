@@ -0,0 +1,257 @@
+//! User themes from the `themes` folder of the data directory.
+//!
+//! Every `*.json` file there is parsed into a [`UserTheme`] at startup. The
+//! folder is then polled: edits, new and removed files reload the catalog
+//! and re-apply the active theme, and an OS switch between light and dark
+//! re-applies it when the settings follow the system.
+
+use crate::AppStateEntity;
+use dbflux_components::theme::apply_theme;
+use dbflux_components::user_theme::{UserTheme, apply_user_theme};
+use dbflux_core::{GeneralSettings, ThemeSetting};
+use gpui::{App, Entity, Global, Window, WindowAppearance};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Name of the user themes folder in the data directory.
+pub const USER_THEMES_DIR: &str = "themes";
+
+const RELOAD_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Theme files with their modification times, used to notice edits.
+type DirStamp = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// The user themes that loaded, and what went wrong with the rest.
+#[derive(Debug, Clone, Default)]
+pub struct UserThemeCatalog {
+    pub themes: Vec<UserTheme>,
+    pub errors: Vec<String>,
+    stamp: DirStamp,
+}
+
+impl Global for UserThemeCatalog {}
+
+impl UserThemeCatalog {
+    /// Loads every `*.json` file in `dir`, sorted by theme name. A missing
+    /// folder is an empty catalog.
+    pub fn load(dir: &Path) -> Self {
+        let stamp = scan_dir(dir);
+        let mut themes: Vec<UserTheme> = Vec::new();
+        let mut errors = Vec::new();
+
+        for (path, _) in &stamp {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+            let parsed = std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| UserTheme::parse(&stem, &json));
+
+            match parsed {
+                Ok(theme) if themes.iter().any(|t| t.name == theme.name) => {
+                    errors.push(format!(
+                        "{file_name}: a theme named \"{}\" is already loaded",
+                        theme.name
+                    ));
+                }
+                Ok(theme) => themes.push(theme),
+                Err(e) => errors.push(format!("{file_name}: {e}")),
+            }
+        }
+
+        themes.sort_by_key(|theme| theme.name.to_lowercase());
+
+        Self {
+            themes,
+            errors,
+            stamp,
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<&UserTheme> {
+        self.themes.iter().find(|theme| theme.name == name)
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.themes.iter().map(|theme| theme.name.clone()).collect()
+    }
+}
+
+/// The user themes folder, or `None` when the data directory is unavailable.
+pub fn user_themes_dir() -> Option<PathBuf> {
+    dbflux_storage::paths::data_dir()
+        .ok()
+        .map(|dir| dir.join(USER_THEMES_DIR))
+}
+
+/// The loaded user themes; empty before [`init`] runs.
+pub fn user_theme_catalog(cx: &App) -> UserThemeCatalog {
+    cx.try_global::<UserThemeCatalog>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Applies the theme `settings` selects: its user theme while that is
+/// loaded, the built-in theme otherwise.
+pub fn apply_configured_theme(
+    settings: &GeneralSettings,
+    window: Option<&mut Window>,
+    cx: &mut App,
+) {
+    let user_theme = settings
+        .custom_theme
+        .as_deref()
+        .and_then(|name| cx.try_global::<UserThemeCatalog>()?.find(name).cloned());
+
+    match user_theme {
+        Some(user_theme) => apply_user_theme(&user_theme, settings.style, window, cx),
+        None => apply_theme(settings.theme, settings.style, window, cx),
+    }
+}
+
+/// Whether the active theme changes with the OS appearance.
+fn follows_system(settings: &GeneralSettings) -> bool {
+    settings.custom_theme.is_none() && settings.theme == ThemeSetting::System
+}
+
+/// Loads the user themes, applies the configured theme and starts watching
+/// the themes folder and the OS appearance.
+///
+/// Call once at startup, after the built-in theme is initialized.
+pub fn init(app_state: Entity<AppStateEntity>, cx: &mut App) {
+    let dir = user_themes_dir();
+    let catalog = dir
+        .as_deref()
+        .map(UserThemeCatalog::load)
+        .unwrap_or_default();
+
+    for error in &catalog.errors {
+        log::warn!("User theme: {error}");
+    }
+
+    cx.set_global(catalog);
+
+    let settings = app_state.read(cx).general_settings().clone();
+    if settings.custom_theme.is_some() {
+        apply_configured_theme(&settings, None, cx);
+    }
+
+    let mut appearance = cx.window_appearance();
+
+    cx.spawn(async move |cx| {
+        loop {
+            cx.background_executor().timer(RELOAD_POLL_INTERVAL).await;
+
+            let stamp = match dir.clone() {
+                Some(dir) => cx.background_spawn(async move { scan_dir(&dir) }).await,
+                None => Vec::new(),
+            };
+
+            let updated = cx.update(|cx| {
+                let settings = app_state.read(cx).general_settings().clone();
+
+                let files_changed = cx
+                    .try_global::<UserThemeCatalog>()
+                    .is_none_or(|catalog| catalog.stamp != stamp);
+                if files_changed {
+                    let catalog = dir
+                        .as_deref()
+                        .map(UserThemeCatalog::load)
+                        .unwrap_or_default();
+                    for error in &catalog.errors {
+                        log::warn!("User theme: {error}");
+                    }
+                    cx.set_global(catalog);
+                }
+
+                let current_appearance = cx.window_appearance();
+                let appearance_changed = !same_appearance(appearance, current_appearance);
+                appearance = current_appearance;
+
+                if files_changed || (appearance_changed && follows_system(&settings)) {
+                    apply_configured_theme(&settings, None, cx);
+                    cx.refresh_windows();
+                }
+            });
+
+            if updated.is_err() {
+                break;
+            }
+        }
+    })
+    .detach();
+}
+
+/// Whether two appearances resolve to the same built-in theme.
+fn same_appearance(a: WindowAppearance, b: WindowAppearance) -> bool {
+    let is_light = |appearance| {
+        matches!(
+            appearance,
+            WindowAppearance::Light | WindowAppearance::VibrantLight
+        )
+    };
+    is_light(a) == is_light(b)
+}
+
+/// The `*.json` files in `dir` with their modification times, sorted by path.
+fn scan_dir(dir: &Path) -> DirStamp {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut stamp: DirStamp = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+        .map(|path| {
+            let modified = std::fs::metadata(&path)
+                .and_then(|meta| meta.modified())
+                .ok();
+            (path, modified)
+        })
+        .collect();
+
+    stamp.sort();
+    stamp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("dbflux-user-themes-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        dir
+    }
+
+    #[test]
+    fn catalog_loads_valid_themes_and_reports_broken_files() {
+        let dir = temp_dir("catalog");
+        std::fs::write(dir.join("b.json"), r#"{ "name": "Zenburn" }"#).unwrap();
+        std::fs::write(dir.join("a.json"), r#"{ "base": "light" }"#).unwrap();
+        std::fs::write(dir.join("broken.json"), "{ not json").unwrap();
+        std::fs::write(dir.join("dup.json"), r#"{ "name": "Zenburn" }"#).unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let catalog = UserThemeCatalog::load(&dir);
+
+        assert_eq!(catalog.names(), vec!["a", "Zenburn"]);
+        assert!(catalog.find("a").is_some_and(UserTheme::is_light));
+        assert_eq!(catalog.errors.len(), 2);
+        assert!(catalog.errors[0].starts_with("broken.json: "));
+        assert!(catalog.errors[1].contains("already loaded"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn missing_folder_is_an_empty_catalog() {
+        let catalog = UserThemeCatalog::load(Path::new("/nonexistent/dbflux-themes"));
+
+        assert!(catalog.themes.is_empty());
+        assert!(catalog.errors.is_empty());
+    }
+}
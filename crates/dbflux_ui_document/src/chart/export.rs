@@ -0,0 +1,113 @@
+//! Saves the chart of a `ChartShell` as a PNG or SVG image.
+
+use super::shell::ChartShell;
+use dbflux_components::chart::{ChartExportOption, encode_chart_image};
+use dbflux_ui_base::async_ext::AsyncUpdateResultExt;
+use dbflux_ui_base::file_dialog::{
+    fallback_export_dir, is_native_file_dialog_available, unique_path_in,
+};
+use dbflux_ui_base::toast::{Toast, now_hms};
+use gpui::*;
+
+/// Renders the shell's current chart with `title`, asks where to save it, and
+/// writes it in `option`'s format and size.
+///
+/// The SVG is drawn on the UI thread from the chart as currently shown
+/// (hidden series stay hidden); rasterizing and writing happen in the
+/// background.
+pub fn export_chart(
+    shell: &Entity<ChartShell>,
+    title: &str,
+    option: ChartExportOption,
+    cx: &mut App,
+) {
+    let Some(chart_view) = shell.read(cx).chart_view().cloned() else {
+        Toast::error("Nothing to export: the chart has no data yet")
+            .meta_right(now_hms())
+            .push(cx);
+        return;
+    };
+
+    let svg = chart_view
+        .read(cx)
+        .render_svg(title, option.width, option.height, cx);
+
+    let format = option.format;
+    let suggested_name = format!("{}.{}", file_stem(title), format.extension());
+    let dialog_available = is_native_file_dialog_available();
+
+    cx.spawn(async move |cx| {
+        let path = if dialog_available {
+            let handle = rfd::AsyncFileDialog::new()
+                .set_title(format!("Export chart as {}", format.name()))
+                .set_file_name(&suggested_name)
+                .add_filter(format.name(), &[format.extension()])
+                .save_file()
+                .await;
+
+            // A cancelled dialog is not a failure.
+            let Some(handle) = handle else {
+                return;
+            };
+            handle.path().to_path_buf()
+        } else {
+            match fallback_export_dir() {
+                Ok(dir) => unique_path_in(&dir, &suggested_name),
+                Err(error) => {
+                    cx.update(|cx| {
+                        Toast::error(format!("Chart export failed: {}", error))
+                            .meta_right(now_hms())
+                            .push(cx);
+                    })
+                    .log_if_dropped();
+                    return;
+                }
+            }
+        };
+
+        let result = cx
+            .background_executor()
+            .spawn({
+                let path = path.clone();
+                async move {
+                    let bytes = encode_chart_image(&svg, &option).map_err(|e| e.to_string())?;
+                    std::fs::write(&path, bytes).map_err(|e| e.to_string())
+                }
+            })
+            .await;
+
+        cx.update(|cx| {
+            let toast = match result {
+                Ok(()) => Toast::success(format!("Chart exported to {}", path.display())),
+                Err(error) => {
+                    log::warn!("Chart export to {} failed: {}", path.display(), error);
+                    Toast::error(format!("Chart export failed: {}", error))
+                }
+            };
+            toast.meta_right(now_hms()).push(cx);
+        })
+        .log_if_dropped();
+    })
+    .detach();
+}
+
+/// File name stem derived from the chart title, keeping it portable.
+fn file_stem(title: &str) -> String {
+    let stem: String = title
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if stem.trim_matches('_').is_empty() {
+        "chart".to_string()
+    } else {
+        stem
+    }
+}
@@ -5,13 +5,18 @@
 //! Any surface that can mount a chart implements `ChartHost`; the shell owns
 //! `ChartView`, hidden-series state, the rail, and toolbar rendering.
 
+pub mod export;
 pub mod host;
 pub mod metric_picker;
 pub(crate) mod metric_picker_render;
 pub mod shell;
 pub mod toolbar;
 
+pub use export::export_chart;
 pub use host::{ChartHost, HostAdapter};
 pub use metric_picker::MetricPickerState;
 pub use shell::{ChartRailTab, ChartShell, ChartShellEvent};
-pub use toolbar::{ActionHandler, ChartToolbarContext, ChartToolbarHandlers, render_chart_toolbar};
+pub use toolbar::{
+    ActionHandler, ChartExportHandler, ChartToolbarContext, ChartToolbarHandlers,
+    render_chart_toolbar,
+};
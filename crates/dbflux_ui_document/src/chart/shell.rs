@@ -43,13 +43,13 @@ impl Clone for ChartShellEvent {
 use super::host::{ChartHost, HostAdapter};
 use super::metric_picker::MetricPickerState;
 use dbflux_components::chart::{
-    AxisPill, BindingSpec, ChartDetection, ChartKind, ChartSpec, ChartView, DataPointRef,
-    ManualChartSelection, SourceRowRef, YScale, detect_chart_columns,
+    AggKind, AxisPill, BindingSpec, ChartDetection, ChartKind, ChartSpec, ChartView, DataPointRef,
+    ManualChartSelection, PALETTE_SLOTS, SourceRowRef, YScale, detect_chart_columns,
 };
 use dbflux_core::{ColumnKind, ColumnMeta, QueryResult};
 use gpui::prelude::*;
 use gpui::{Context, Entity, EventEmitter, Subscription, Window};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Reusable entity that owns all chart state for a single mounted host.
@@ -79,6 +79,13 @@ pub struct ChartShell {
     /// detection result.
     pub(crate) chart_manual_selection: Option<ManualChartSelection>,
 
+    /// Column whose distinct values split each Y column into one series per
+    /// group. `None` = no grouping.
+    chart_group_by: Option<usize>,
+
+    /// Aggregation merging rows that share an X value (and group).
+    chart_aggregation: AggKind,
+
     // ---- interaction state ----
     /// Series indices hidden by the user via the legend.
     pub(crate) chart_hidden_series: HashSet<usize>,
@@ -90,6 +97,10 @@ pub struct ChartShell {
     /// own rule (hide when series count ≤ 1) is applied on top of this.
     chart_legend_visible: bool,
 
+    /// Palette slots picked by the user from the legend, keyed by series
+    /// label so they survive rebuilds and re-runs.
+    series_color_overrides: HashMap<String, u8>,
+
     // ---- overlay picker state ----
     /// Whether the manual column-picker overlay is visible in the degraded card.
     pub(crate) chart_picker_overlay_open: bool,
@@ -119,6 +130,10 @@ pub struct ChartShell {
     /// Which AxisBar pill picker is currently open.
     pub(crate) axis_open_pill: Option<AxisPill>,
 
+    // ---- export menu ----
+    /// Whether the toolbar's image export menu is open.
+    pub(crate) export_menu_open: bool,
+
     // ---- Y scale ----
     /// Y-axis scale mode. Persists across rebuilds (set_result / apply_bindings).
     pub(crate) y_scale: YScale,
@@ -156,9 +171,12 @@ impl ChartShell {
             chart_view_observer: None,
             chart_detection: None,
             chart_manual_selection: None,
+            chart_group_by: None,
+            chart_aggregation: AggKind::None,
             chart_hidden_series: HashSet::new(),
             chart_focused_series_idx: 0,
             chart_legend_visible: true,
+            series_color_overrides: HashMap::new(),
             chart_picker_overlay_open: false,
             chart_picker_x_col: 0,
             chart_picker_y_checked: Vec::new(),
@@ -167,6 +185,7 @@ impl ChartShell {
             chart_rail_picker_x_col: 0,
             chart_rail_picker_y_checked: Vec::new(),
             axis_open_pill: None,
+            export_menu_open: false,
             y_scale: YScale::Linear,
             chart_kind: ChartKind::default(),
             metric_picker: None,
@@ -223,6 +242,8 @@ impl ChartShell {
         } else {
             self.chart_hidden_series = HashSet::new();
             self.chart_manual_selection = None;
+            self.chart_group_by = None;
+            self.chart_aggregation = AggKind::None;
             self.chart_focused_series_idx = 0;
             self.chart_rail_open = false;
             self.chart_rail_tab = ChartRailTab::Configure;
@@ -264,12 +285,14 @@ impl ChartShell {
             }
         }?;
 
-        spec.legend_visible = self.chart_legend_visible && spec.series.len() > 1;
+        spec.legend_visible =
+            self.chart_legend_visible && (spec.series.len() > 1 || self.chart_group_by.is_some());
         spec.y_scale = self.y_scale;
-        spec.kind = self.chart_kind;
+        self.decorate_spec(&mut spec);
 
         match ChartView::build(result, spec) {
             Ok(chart_view) => {
+                let chart_view = chart_view.with_series_colors(&self.series_color_overrides);
                 let entity = cx.new(|_cx| chart_view);
                 let observer = cx.observe(&entity, |_this, _chart, cx| cx.notify());
                 self.chart_view = Some(entity.clone());
@@ -303,6 +326,44 @@ impl ChartShell {
         cx.notify();
     }
 
+    /// Move the series at `idx` to the next palette colour.
+    ///
+    /// The choice is remembered by series label, so it is kept when the chart
+    /// is rebuilt or the query re-runs.
+    pub fn cycle_series_color(&mut self, idx: usize, cx: &mut Context<Self>) {
+        let Some(chart_entity) = self.chart_view.clone() else {
+            return;
+        };
+
+        let Some((label, slot)) = chart_entity
+            .read(cx)
+            .spec_series()
+            .get(idx)
+            .map(|series| (series.label.clone(), series.color_slot))
+        else {
+            return;
+        };
+
+        let next = (slot + 1) % PALETTE_SLOTS;
+        self.series_color_overrides.insert(label, next);
+        chart_entity.update(cx, |view, cx| view.set_series_color(idx, next, cx));
+
+        cx.notify();
+    }
+
+    /// Apply the shell-owned settings every spec it produces shares.
+    fn decorate_spec(&self, spec: &mut ChartSpec) {
+        spec.kind = self.chart_kind;
+        spec.binding.group_by = self.chart_group_by;
+        spec.binding.aggregation = self.chart_aggregation;
+
+        for series in &mut spec.series {
+            if let Some(&slot) = self.series_color_overrides.get(&series.label) {
+                series.color_slot = slot;
+            }
+        }
+    }
+
     /// Returns the current `ChartView` entity without triggering a build.
     pub fn chart_view(&self) -> Option<&Entity<ChartView>> {
         self.chart_view.as_ref()
@@ -356,6 +417,8 @@ impl ChartShell {
             x_col: bindings.x,
             y_cols: bindings.y,
         });
+        self.chart_group_by = bindings.group_by;
+        self.chart_aggregation = bindings.aggregation;
 
         self.chart_view = None;
         self.chart_view_observer = None;
@@ -410,6 +473,14 @@ impl ChartShell {
         }
     }
 
+    /// Open or close the toolbar's image export menu.
+    pub fn set_export_menu_open(&mut self, open: bool, cx: &mut Context<Self>) {
+        if self.export_menu_open != open {
+            self.export_menu_open = open;
+            cx.notify();
+        }
+    }
+
     /// Update the Y-axis scale mode and rebuild the chart view.
     ///
     /// The new scale is applied to the next `ensure_chart_view` call.
@@ -439,7 +510,7 @@ impl ChartShell {
         if let Some(manual) = &self.chart_manual_selection
             && let Some(mut spec) = ChartSpec::from_manual_selection(manual, columns, 10_000)
         {
-            spec.kind = self.chart_kind;
+            self.decorate_spec(&mut spec);
             return spec;
         }
 
@@ -451,7 +522,7 @@ impl ChartShell {
             && let Some(mut spec) =
                 ChartSpec::from_detection(*time_col, numeric_cols.clone(), columns, 10_000)
         {
-            spec.kind = self.chart_kind;
+            self.decorate_spec(&mut spec);
             return spec;
         }
 
@@ -483,9 +554,9 @@ impl ChartShell {
             BindingSpec {
                 x: manual.x_col,
                 y: manual.y_cols.clone(),
-                group_by: None,
+                group_by: self.chart_group_by,
                 filter: None,
-                aggregation: dbflux_components::chart::AggKind::None,
+                aggregation: self.chart_aggregation,
             }
         } else if let Some(ChartDetection::Ok {
             time_col,
@@ -495,9 +566,9 @@ impl ChartShell {
             BindingSpec {
                 x: *time_col,
                 y: numeric_cols.clone(),
-                group_by: None,
+                group_by: self.chart_group_by,
                 filter: None,
-                aggregation: dbflux_components::chart::AggKind::None,
+                aggregation: self.chart_aggregation,
             }
         } else {
             BindingSpec::default()
@@ -8,30 +8,35 @@
 //! - Spacer
 //! - Points · resolution display
 //! - Stats toggle button
-//! - PNG export button (stub)
+//! - Export button with a menu of image formats and sizes
 //! - Save chart button (gated on `source_supports_save`)
 //!
 //! The AxisBar row is NOT part of this toolbar — it lives below and is
 //! assembled separately in each caller.
 
 use super::shell::{ChartRailTab, ChartShell};
-use dbflux_components::chart::{ChartKind, format_resolution, format_x_value};
+use dbflux_components::chart::{
+    CHART_EXPORT_OPTIONS, ChartExportOption, ChartKind, format_resolution, format_x_value,
+};
 use dbflux_components::composites::refresh_split_button;
 use dbflux_components::controls::Dropdown;
 use dbflux_components::icons::AppIcon;
-use dbflux_components::primitives::Icon;
-use dbflux_components::tokens::{FontSizes, Radii, Spacing};
+use dbflux_components::primitives::{Icon, Text, surface_raised};
+use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
 use dbflux_core::RefreshPolicy;
 use gpui::prelude::*;
 use gpui::*;
 use gpui_component::theme::Theme;
 use std::sync::Arc;
 
-/// Handler called when the Stats button, PNG button, Save button, or Refresh
-/// button is clicked.
+/// Handler called when the Stats button, Save button, or Refresh button is
+/// clicked.
 pub type ActionHandler = Arc<dyn Fn(&mut Window, &mut App)>;
 /// Handler called when a chart-kind chip is clicked; receives the chosen kind.
 pub type ChartKindHandler = Arc<dyn Fn(ChartKind, &mut Window, &mut App)>;
+/// Handler called when an export menu entry is picked; receives the chosen
+/// format and size.
+pub type ChartExportHandler = Arc<dyn Fn(ChartExportOption, &mut Window, &mut App)>;
 
 /// All read-only state the toolbar needs to render itself.
 ///
@@ -71,8 +76,8 @@ pub struct ChartToolbarHandlers {
     pub on_refresh: ActionHandler,
     /// Called when the Stats button is clicked.
     pub on_toggle_stats_rail: ActionHandler,
-    /// Called when the PNG button is clicked.
-    pub on_png_export: ActionHandler,
+    /// Called when an entry of the Export menu is picked.
+    pub on_export: ChartExportHandler,
     /// Called when the "Save chart" button is clicked.
     pub on_save_chart: ActionHandler,
     /// Called when a chart-kind chip (Line / Bar) is clicked.
@@ -97,13 +102,14 @@ pub fn render_chart_toolbar(
     let primary_fg = theme.primary_foreground;
 
     // --- Read rail state from the shell ---
-    let (chart_view_entity, rail_open, rail_tab, current_kind) = {
+    let (chart_view_entity, rail_open, rail_tab, current_kind, export_menu_open) = {
         let shell = ctx.chart_shell.read(cx);
         (
             shell.chart_view().cloned(),
             shell.chart_rail_open,
             shell.chart_rail_tab,
             shell.chart_kind(),
+            shell.export_menu_open,
         )
     };
 
@@ -243,7 +249,6 @@ pub fn render_chart_toolbar(
 
     let is_stats_active = rail_open && rail_tab == ChartRailTab::Stats;
     let on_stats = handlers.on_toggle_stats_rail.clone();
-    let on_png = handlers.on_png_export.clone();
     let on_save = handlers.on_save_chart.clone();
 
     let stats_btn = toolbar_btn(
@@ -256,12 +261,30 @@ pub fn render_chart_toolbar(
         on_stats(window, cx);
     });
 
-    let png_btn = toolbar_btn("chart-toolbar-png", AppIcon::Download, "PNG", false).on_mouse_down(
-        MouseButton::Left,
-        move |_, window, cx| {
-            on_png(window, cx);
-        },
-    );
+    let export_btn = {
+        let shell = ctx.chart_shell.clone();
+
+        toolbar_btn(
+            "chart-toolbar-export",
+            AppIcon::Download,
+            "Export",
+            export_menu_open,
+        )
+        .relative()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            shell.update(cx, |shell, cx| {
+                shell.set_export_menu_open(!shell.export_menu_open, cx)
+            });
+        })
+        .when(export_menu_open, |btn| {
+            btn.child(render_export_menu(
+                ctx.chart_shell.clone(),
+                handlers.on_export.clone(),
+                theme,
+                cx,
+            ))
+        })
+    };
 
     let save_btn = toolbar_btn("chart-toolbar-save", AppIcon::Save, "Save chart", false)
         .on_mouse_down(MouseButton::Left, move |_, window, cx| {
@@ -337,13 +360,72 @@ pub fn render_chart_toolbar(
         )
         .child(vdivider(border))
         .child(stats_btn)
-        .child(png_btn)
+        .child(export_btn)
         .when(ctx.source_supports_save, |el| {
             el.child(vdivider(border)).child(save_btn)
         })
         .into_any_element()
 }
 
+/// Dropdown listing `CHART_EXPORT_OPTIONS` below the Export button.
+fn render_export_menu(
+    shell: Entity<ChartShell>,
+    on_export: ChartExportHandler,
+    theme: &Theme,
+    cx: &App,
+) -> impl IntoElement {
+    let secondary = theme.secondary;
+
+    let items = CHART_EXPORT_OPTIONS
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let option = *option;
+            let shell = shell.clone();
+            let on_export = on_export.clone();
+
+            div()
+                .id(SharedString::from(format!("chart-export-{}", index)))
+                .flex()
+                .items_center()
+                .h(Heights::ROW_COMPACT)
+                .px(Spacing::SM)
+                .mx(Spacing::XS)
+                .rounded(Radii::SM)
+                .cursor_pointer()
+                .hover(move |d| d.bg(secondary))
+                .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                    cx.stop_propagation();
+                    shell.update(cx, |shell, cx| shell.set_export_menu_open(false, cx));
+                    on_export(option, window, cx);
+                })
+                .child(Text::body(option.label))
+        })
+        .collect::<Vec<_>>();
+
+    let close_shell = shell.clone();
+
+    deferred(
+        surface_raised(cx)
+            .absolute()
+            .top_full()
+            .right_0()
+            .mt(Spacing::XS)
+            .w(px(160.0))
+            .shadow_lg()
+            .py(Spacing::XS)
+            .occlude()
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .on_mouse_down_out(move |_, _, cx| {
+                close_shell.update(cx, |shell, cx| shell.set_export_menu_open(false, cx));
+            })
+            .children(items),
+    )
+    .with_priority(1)
+}
+
 fn vdivider(border: gpui::Hsla) -> impl IntoElement {
     div().w(px(1.0)).h(px(12.0)).mx(px(4.0)).bg(border)
 }
@@ -10,14 +10,14 @@ pub mod pane;
 mod render;
 
 use super::chart::shell::ChartShellEvent;
-use super::chart::{ChartHost, ChartShell, HostAdapter};
+use super::chart::{ChartHost, ChartShell, HostAdapter, export_chart};
 use super::handle::DocumentEvent;
 use super::task_runner::DocumentTaskRunner;
 use super::types::{DocumentId, DocumentState};
 use dbflux_app::keymap::{Command, ContextId};
 use dbflux_components::chart::{
-    ChartDataSource, ChartDetection, ChartSourceError, TimeWindow, detect_chart_columns,
-    resolve_source,
+    ChartDataSource, ChartDetection, ChartExportOption, ChartSourceError, TimeWindow,
+    detect_chart_columns, resolve_source,
 };
 use dbflux_components::common::time_range::state::TimeRange;
 use dbflux_components::common::time_range::view::TimeRangePanel;
@@ -1269,14 +1269,9 @@ impl ChartDocument {
         });
     }
 
-    /// Schedule a "PNG export coming soon" toast. The host document's render
-    /// loop drains `pending_toast` and surfaces it through the global toast host.
-    pub fn schedule_png_export_toast(&mut self, cx: &mut Context<Self>) {
-        self.pending_toast = Some(PendingToast {
-            message: "PNG export coming in v0.7".to_string(),
-            is_error: false,
-        });
-        cx.notify();
+    /// Save the current chart as an image, titled with the document title.
+    pub fn export_image(&mut self, option: ChartExportOption, cx: &mut Context<Self>) {
+        export_chart(&self.chart_shell, &self.title, option, cx);
     }
 
     /// Persist the current `chart_spec` + bindings back to `SavedChart` storage.
@@ -1562,6 +1557,7 @@ impl InstantSeriesBuffer {
             rows: self.rows.clone(),
             affected_rows: None,
             execution_time: std::time::Duration::ZERO,
+            timing: Default::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
@@ -2444,6 +2440,7 @@ mod tests {
             rows: vec![vec![Value::Int(timestamp_ms), Value::Float(value)]],
             affected_rows: None,
             execution_time: Duration::ZERO,
+            timing: Default::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
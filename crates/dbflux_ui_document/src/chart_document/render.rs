@@ -34,7 +34,7 @@ use dbflux_components::primitives::{Icon, Text};
 use dbflux_components::result_panel::ResultPanel;
 use dbflux_components::semantic::ChartColors;
 use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
-use dbflux_ui_base::toast::{PendingToast, flush_pending_toast, now_hms};
+use dbflux_ui_base::toast::flush_pending_toast;
 use gpui::prelude::*;
 use gpui::*;
 use gpui_component::button::{Button, ButtonVariant, ButtonVariants};
@@ -297,7 +297,7 @@ impl ChartDocument {
         // and axis rows entirely so the chart canvas fills the panel card.
         let embedded = self.embedded;
 
-        // -- Chart toolbar row: RANGE / REFRESH / window / points / Stats / PNG / Save --
+        // -- Chart toolbar row: RANGE / REFRESH / window / points / Stats / Export / Save --
         let chart_toolbar_row = {
             let resolved_window = self
                 .last_result
@@ -312,7 +312,7 @@ impl ChartDocument {
 
             let shell_for_stats = self.chart_shell.clone();
             let shell_for_kind = self.chart_shell.clone();
-            let weak_self_for_png = cx.weak_entity();
+            let weak_self_for_export = cx.weak_entity();
             let weak_self_for_save = cx.weak_entity();
             let weak_self_for_refresh = cx.weak_entity();
 
@@ -345,14 +345,9 @@ impl ChartDocument {
                         cx.notify();
                     });
                 }),
-                on_png_export: Arc::new(move |_window, cx| {
-                    if let Some(doc) = weak_self_for_png.upgrade() {
-                        doc.update(cx, |this, _cx| {
-                            this.pending_toast = Some(PendingToast {
-                                message: format!("PNG export coming in v0.7 — {}", now_hms()),
-                                is_error: false,
-                            });
-                        });
+                on_export: Arc::new(move |option, _window, cx| {
+                    if let Some(doc) = weak_self_for_export.upgrade() {
+                        doc.update(cx, |this, cx| this.export_image(option, cx));
                     }
                 }),
                 on_save_chart: Arc::new(move |window, cx| {
@@ -641,11 +636,15 @@ impl ChartDocument {
         let hidden = shell.read(cx).chart_hidden_series.clone();
         let chart_colors = ChartColors::for_current(cx);
 
+        let shell_for_color = shell.clone();
         let on_toggle = move |idx: usize, _window: &mut Window, cx: &mut App| {
             shell.update(cx, |s, cx| {
                 s.toggle_chart_series_hidden(idx, cx);
             });
         };
+        let on_cycle_color = move |idx: usize, _window: &mut Window, cx: &mut App| {
+            shell_for_color.update(cx, |s, cx| s.cycle_series_color(idx, cx));
+        };
 
         let legend = legend_element(
             &series,
@@ -655,6 +654,7 @@ impl ChartDocument {
             focused_idx,
             &chart_colors,
             Some(on_toggle),
+            Some(on_cycle_color),
         );
 
         Some(
@@ -1300,6 +1300,8 @@ mod tests {
             is_primary_key: false,
             default_value: None,
             enum_values: None,
+            comment: None,
+            definition: None,
         }
     }
 
@@ -1354,6 +1356,7 @@ mod tests {
             sample_fields: Some(vec![field("pk"), field("sk")]),
             presentation: dbflux_core::CollectionPresentation::default(),
             child_items: None,
+            comment: None,
         }
     }
 
@@ -1483,6 +1486,7 @@ mod tests {
             sample_fields: None,
             presentation: dbflux_core::CollectionPresentation::default(),
             child_items: None,
+            comment: None,
         };
 
         let mut metadata = SqlCompletionMetadata::default();
@@ -105,7 +105,7 @@ impl CodeDocument {
         items
     }
 
-    fn default_database_for_connection(
+    pub(super) fn default_database_for_connection(
         app_state: &Entity<AppStateEntity>,
         connection_id: Uuid,
         cx: &App,
@@ -717,6 +717,11 @@ impl CodeDocument {
             return;
         };
 
+        // A role belongs to the connection it was picked for.
+        if self.connection_id != Some(new_conn_id) {
+            self.impersonated_role = None;
+        }
+
         self.source.exec_ctx.connection_id = Some(new_conn_id);
         self.connection_id = Some(new_conn_id);
         self.source.exec_ctx.database =
@@ -725,6 +730,8 @@ impl CodeDocument {
         self.source.exec_ctx.container = None;
 
         self.sync_context_dropdowns(cx);
+        self.reload_variable_inputs(window, cx);
+        self.refresh_editor_diagnostics(window, cx);
 
         // Re-validate context bar index since dropdown visibility may have changed
         if self.focus_mode == SqlQueryFocus::ContextBar {
@@ -732,6 +739,60 @@ impl CodeDocument {
         }
     }
 
+    /// Points the document at `profile_id`, and at `database` when given
+    /// (the connection's default otherwise), as if both were picked in the
+    /// context bar. The profile must already be connected.
+    pub fn switch_execution_context(
+        &mut self,
+        profile_id: Uuid,
+        database: Option<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let default_database =
+            Self::default_database_for_connection(&self.app_state, profile_id, cx);
+
+        // What a failed database connection falls back to.
+        let (prev_database, prev_schema) = if self.connection_id == Some(profile_id) {
+            (
+                self.source.exec_ctx.database.clone(),
+                self.source.exec_ctx.schema.clone(),
+            )
+        } else {
+            (default_database.clone(), None)
+        };
+
+        if self.connection_id != Some(profile_id) {
+            self.impersonated_role = None;
+        }
+
+        self.source.exec_ctx.connection_id = Some(profile_id);
+        self.connection_id = Some(profile_id);
+        self.source.exec_ctx.database = database.clone().or(default_database);
+        if self.source.exec_ctx.database != prev_database {
+            self.source.exec_ctx.schema = None;
+            self.source.exec_ctx.container = None;
+        }
+
+        self.sync_context_dropdowns(cx);
+
+        if let Some(database) = database
+            && self.database_needs_connection(profile_id, &database, cx)
+        {
+            self.connect_to_database(profile_id, database, prev_database, prev_schema, cx);
+        }
+
+        self.reload_variable_inputs(window, cx);
+        self.refresh_editor_diagnostics(window, cx);
+
+        if self.focus_mode == SqlQueryFocus::ContextBar {
+            self.revalidate_context_bar_index(window, cx);
+        }
+
+        cx.emit(DocumentEvent::MetaChanged);
+        cx.notify();
+    }
+
     fn on_database_changed(&mut self, item: &DropdownItem, cx: &mut Context<Self>) {
         let db_name = item.value.to_string();
 
@@ -742,25 +803,10 @@ impl CodeDocument {
         self.source.exec_ctx.database = Some(db_name.clone());
         self.source.exec_ctx.schema = None;
 
-        if let Some(conn_id) = self.source.exec_ctx.connection_id {
-            let needs_connection = self
-                .app_state
-                .read(cx)
-                .connections()
-                .get(&conn_id)
-                .is_some_and(|c| {
-                    let strategy = c.connection.schema_loading_strategy();
-                    strategy == SchemaLoadingStrategy::ConnectionPerDatabase
-                        && c.database_connection(&db_name).is_none()
-                        && c.schema
-                            .as_ref()
-                            .and_then(|s| s.current_database())
-                            .is_none_or(|current| current != db_name)
-                });
-
-            if needs_connection {
-                self.connect_to_database(conn_id, db_name.clone(), prev_database, prev_schema, cx);
-            }
+        if let Some(conn_id) = self.source.exec_ctx.connection_id
+            && self.database_needs_connection(conn_id, &db_name, cx)
+        {
+            self.connect_to_database(conn_id, db_name.clone(), prev_database, prev_schema, cx);
         }
 
         self.refresh_schema_dropdown_with_default(cx);
@@ -811,8 +857,31 @@ impl CodeDocument {
         });
     }
 
+    /// Whether running against `database` first needs a dedicated connection
+    /// (connection-per-database drivers such as PostgreSQL).
+    pub(super) fn database_needs_connection(
+        &self,
+        profile_id: Uuid,
+        database: &str,
+        cx: &App,
+    ) -> bool {
+        self.app_state
+            .read(cx)
+            .connections()
+            .get(&profile_id)
+            .is_some_and(|c| {
+                let strategy = c.connection.schema_loading_strategy();
+                strategy == SchemaLoadingStrategy::ConnectionPerDatabase
+                    && c.database_connection(database).is_none()
+                    && c.schema
+                        .as_ref()
+                        .and_then(|s| s.current_database())
+                        .is_none_or(|current| current != database)
+            })
+    }
+
     /// Connect to a specific database. Reverts `exec_ctx` on failure.
-    fn connect_to_database(
+    pub(super) fn connect_to_database(
         &mut self,
         profile_id: Uuid,
         database: String,
@@ -1263,6 +1332,7 @@ impl CodeDocument {
             return div().id("exec-context-bar").into_any_element();
         }
 
+        let impersonation_indicator = self.render_impersonation_indicator(cx);
         let theme = cx.theme();
 
         let show_source_controls = self.should_show_source_controls(cx);
@@ -1480,6 +1550,7 @@ impl CodeDocument {
                             )),
                     )
             })
+            .when_some(impersonation_indicator, |el, indicator| el.child(indicator))
             .child(div().flex_1())
             .when_some(self.editor.path.as_ref(), |el, path| {
                 el.child(
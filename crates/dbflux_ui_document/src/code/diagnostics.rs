@@ -40,6 +40,10 @@ impl CodeDocument {
     fn run_diagnostics(&mut self, cx: &mut Context<Self>) {
         let query_text = self.editor.input_state.read(cx).value().to_string();
 
+        self.recompute_find_matches(&query_text, cx);
+        let mut highlights = self.vim_selection_diagnostics(&query_text, cx);
+        highlights.extend(self.undefined_variable_diagnostics(&query_text, cx));
+
         // Live diagnostics are driven by the connected driver's `LanguageService`
         // rather than the editor mode. Each driver's service validates its own
         // dialect (SQL drivers use the SQL grammar; Mongo and DynamoDB validate
@@ -72,6 +76,10 @@ impl CodeDocument {
                 diagnostic_set.push(Self::to_input_diagnostic(diagnostic));
             }
 
+            for highlight in highlights {
+                diagnostic_set.push(highlight);
+            }
+
             cx.notify();
         });
     }
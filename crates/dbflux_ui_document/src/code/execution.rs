@@ -28,7 +28,7 @@ fn resolve_source_context(
     fallback
 }
 
-fn evaluate_dangerous_with_effective_settings(
+pub(crate) fn evaluate_dangerous_with_effective_settings(
     kind: dbflux_core::DangerousQueryKind,
     is_suppressed: bool,
     effective: &dbflux_core::EffectiveSettings,
@@ -61,7 +61,7 @@ fn evaluate_dangerous_with_effective_settings(
     dbflux_core::DangerousAction::Confirm(kind)
 }
 
-fn task_target_for_execution(
+pub(crate) fn task_target_for_execution(
     profile_id: Uuid,
     connected: &dbflux_core::ConnectedProfile,
     target_db: Option<&str>,
@@ -85,7 +85,11 @@ fn task_target_for_execution(
 
 impl CodeDocument {
     /// Returns selected text when a non-empty selection exists.
-    fn selected_query(&self, window: &mut Window, cx: &mut Context<Self>) -> Option<String> {
+    pub(super) fn selected_query(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<String> {
         self.editor.input_state.update(cx, |state, cx| {
             let sel = state.selected_text_range(false, window, cx)?;
 
@@ -102,7 +106,11 @@ impl CodeDocument {
     }
 
     /// Returns the selected text if a selection exists, otherwise the full editor content.
-    fn selected_or_full_query(&self, window: &mut Window, cx: &mut Context<Self>) -> String {
+    pub(super) fn selected_or_full_query(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> String {
         self.selected_query(window, cx)
             .unwrap_or_else(|| self.editor.input_state.read(cx).value().to_string())
     }
@@ -238,11 +246,11 @@ impl CodeDocument {
         cx.notify();
     }
 
-    fn run_query_text(
+    pub(super) fn run_query_text(
         &mut self,
         query: String,
         in_new_tab: bool,
-        _window: &mut Window,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) {
         if query.trim().is_empty() {
@@ -252,6 +260,28 @@ impl CodeDocument {
             return;
         }
 
+        // Saved-query parameters are prompted for first; the prompt re-enters
+        // here with the bound statement.
+        if self.prompt_saved_query_params(&query, in_new_tab, window, cx) {
+            return;
+        }
+
+        // Substitute `{{name}}` variables before the dangerous-query check so
+        // it, validation and drift preflight all see the statement that will
+        // run.
+        let query = match self.resolve_query_variables(&query, cx) {
+            Ok(query) => query,
+            Err(undefined) => {
+                let toast_msg = undefined.to_string();
+                Toast::error(toast_msg.clone())
+                    .meta_right(now_hms())
+                    .action(copy_action(toast_msg))
+                    .push(cx);
+                self.open_variables_panel(window, cx);
+                return;
+            }
+        };
+
         let dangerous_kind = self.connection_id.and_then(|conn_id| {
             self.app_state
                 .read(cx)
@@ -582,11 +612,13 @@ impl CodeDocument {
             finished_at: None,
             result: None,
             error: None,
+            error_details: None,
             rows_affected: None,
             is_script: false,
         };
         self.execution.execution_history.push(record);
         self.execution.active_execution_index = Some(self.execution.execution_history.len() - 1);
+        self.execution.error_details_expanded = false;
         self.execution.active_query_task = Some(ActiveQueryTask {
             task_id,
             target: task_target.clone(),
@@ -596,13 +628,26 @@ impl CodeDocument {
         cx.emit(DocumentEvent::ExecutionStarted);
         cx.notify();
 
-        let request = query_request_for_execution(
+        let mut request = query_request_for_execution(
             query.clone(),
             active_database,
             &self.source.exec_ctx,
             self.editor.query_language.clone(),
         );
 
+        // A single ordered SELECT on a driver that can page it fetches only
+        // its first page; the result grid loads the rest on demand.
+        let fetch_cursor = connection
+            .metadata()
+            .capabilities
+            .contains(DriverCapabilities::INCREMENTAL_FETCH)
+            .then(|| FetchCursor::for_query(&request.sql, FETCH_PAGE_ROWS))
+            .flatten();
+
+        if let Some(cursor) = &fetch_cursor {
+            request.sql = cursor.next_page_sql(connection.dialect());
+        }
+
         // Capture audit_service, task_target, and started_at before spawning so we can emit
         // audit events even if the document is closed before the deferred task runs.
         let audit_service = self.app_state.read(cx).audit_service().clone();
@@ -619,9 +664,16 @@ impl CodeDocument {
             .map(|c| c.profile.driver_id())
             .unwrap_or_default();
 
+        let session = self.tab_session(connection.kind());
+
         let task = cx.background_executor().spawn({
             let connection = connection.clone();
-            async move { connection.execute(&request) }
+            async move {
+                let sent_at = Instant::now();
+                session
+                    .execute(&*connection, &request)
+                    .map(|result| result.with_round_trip(sent_at.elapsed()))
+            }
         });
 
         cx.spawn(async move |this, cx| {
@@ -730,6 +782,18 @@ impl CodeDocument {
             // use it in the fallback event if needed.
             let query_text = query.clone();
 
+            // The look-ahead row of a paged fetch is not part of the result.
+            let completion = QueryCompletion {
+                elapsed: started_at.elapsed(),
+                row_count: result.as_ref().ok().map(|qr| {
+                    qr.affected_rows.unwrap_or_else(|| match &fetch_cursor {
+                        Some(cursor) => qr.rows.len().min(cursor.page_rows() as usize) as u64,
+                        None => qr.rows.len() as u64,
+                    })
+                }),
+                error: result.as_ref().err().map(|error| error.to_string()),
+            };
+
             let inner_result = this.update(cx, |doc, cx| {
                 doc.pending.result = Some(PendingQueryResult {
                     task_id,
@@ -737,7 +801,9 @@ impl CodeDocument {
                     query,
                     result,
                     is_script: false,
+                    fetch_cursor,
                 });
+                cx.emit(DocumentEvent::QueryCompleted(completion));
                 cx.notify();
             });
 
@@ -921,6 +987,7 @@ impl CodeDocument {
         }
 
         self.editor.saved_query_id = selected.saved_query_id;
+        self.saved_params = SavedParamsPrompt::default();
 
         self.focus_mode = SqlQueryFocus::Editor;
 
@@ -928,6 +995,124 @@ impl CodeDocument {
         cx.notify();
     }
 
+    /// Loads a history entry and switches the document to the connection,
+    /// database and schema it originally ran against. The run itself is
+    /// deferred to [`Self::process_pending_rerun`] so it can wait for the
+    /// connection; entries whose connection is unknown are only loaded.
+    pub(super) fn process_pending_rerun_request(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(entry) = self.pending.rerun_request.take() else {
+            return;
+        };
+
+        self.pending.rerun = None;
+        self.editor
+            .input_state
+            .update(cx, |state, cx| state.set_value(&entry.sql, window, cx));
+        self.editor.saved_query_id = None;
+        self.focus_mode = SqlQueryFocus::Editor;
+
+        let profile_id = entry.connection_profile_id.filter(|profile_id| {
+            self.app_state
+                .read(cx)
+                .profiles()
+                .iter()
+                .any(|profile| profile.id == *profile_id)
+        });
+
+        let Some(profile_id) = profile_id else {
+            Toast::warning(
+                "The connection this query ran on is unknown; loaded it without running",
+            )
+            .meta_right(now_hms())
+            .push(cx);
+            cx.emit(DocumentEvent::MetaChanged);
+            cx.notify();
+            return;
+        };
+
+        self.connection_id = Some(profile_id);
+        self.source.exec_ctx.connection_id = Some(profile_id);
+        self.source.exec_ctx.database = entry.database.clone();
+        self.source.exec_ctx.schema = entry.schema.clone();
+        self.source.exec_ctx.container = None;
+
+        self.sync_context_dropdowns(cx);
+        self.reload_variable_inputs(window, cx);
+        self.refresh_editor_diagnostics(window, cx);
+
+        if !self
+            .app_state
+            .read(cx)
+            .connections()
+            .contains_key(&profile_id)
+        {
+            cx.emit(DocumentEvent::RequestConnect { profile_id });
+        }
+
+        self.pending.rerun = Some(PendingRerun {
+            profile_id,
+            database: entry.database,
+            connect_started: false,
+            database_requested: false,
+        });
+
+        cx.emit(DocumentEvent::MetaChanged);
+        cx.notify();
+    }
+
+    /// Runs a pending history re-run once its connection is ready. Dropped
+    /// when the user switches the document elsewhere or the connect fails.
+    pub(super) fn process_pending_rerun(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(mut rerun) = self.pending.rerun.take() else {
+            return;
+        };
+
+        let profile_id = rerun.profile_id;
+        let (connected, connecting) = {
+            let state = self.app_state.read(cx);
+            (
+                state.connections().contains_key(&profile_id),
+                state.is_operation_pending(profile_id, None),
+            )
+        };
+        let database_needs_connection = connected
+            && rerun
+                .database
+                .as_deref()
+                .is_some_and(|database| self.database_needs_connection(profile_id, database, cx));
+
+        let step = rerun.step(
+            self.connection_id,
+            RerunReadiness {
+                connected,
+                connecting,
+                database: self.source.exec_ctx.database.as_deref(),
+                database_needs_connection,
+            },
+        );
+
+        match step {
+            RerunStep::Wait => self.pending.rerun = Some(rerun),
+            RerunStep::Cancel => {}
+            RerunStep::DatabaseUnavailable(database) => {
+                let msg = format!("Database '{}' is not available; re-run cancelled", database);
+                Toast::warning(msg).meta_right(now_hms()).push(cx);
+            }
+            RerunStep::ConnectDatabase(database) => {
+                let fallback =
+                    Self::default_database_for_connection(&self.app_state, profile_id, cx);
+                let schema = self.source.exec_ctx.schema.clone();
+                self.connect_to_database(profile_id, database, fallback, schema, cx);
+                self.pending.rerun = Some(rerun);
+            }
+            RerunStep::Run => self.run_query(window, cx),
+        }
+    }
+
     pub(super) fn process_pending_auto_refresh(
         &mut self,
         window: &mut Window,
@@ -942,6 +1127,9 @@ impl CodeDocument {
         if !self.can_auto_refresh(cx) {
             self.refresh.refresh_policy = dbflux_core::RefreshPolicy::Manual;
             self.refresh._refresh_timer = None;
+            self.refresh
+                .countdown
+                .reset(dbflux_core::RefreshPolicy::Manual);
             self.refresh.refresh_dropdown.update(cx, |dd, cx| {
                 dd.set_selected_index(Some(dbflux_core::RefreshPolicy::Manual.index()), cx);
             });
@@ -981,11 +1169,17 @@ impl CodeDocument {
             .map(|d| d.as_millis() as i64);
 
         let is_script = pending.is_script;
+        let mut fetch_cursor = pending.fetch_cursor;
 
         match pending.result {
-            Ok(qr) => {
+            Ok(mut qr) => {
                 self.runner.complete_primary(pending.task_id, cx);
 
+                // Drop the look-ahead row before anything counts the page.
+                if let Some(cursor) = &mut fetch_cursor {
+                    cursor.advance(&mut qr.rows);
+                }
+
                 // Use affected_rows when available (INSERT/UPDATE/DELETE), otherwise rows.len() (SELECT)
                 let affected_rows = qr.affected_rows;
                 let row_count = affected_rows.unwrap_or(qr.rows.len() as u64);
@@ -998,33 +1192,24 @@ impl CodeDocument {
                 let arc_result = Arc::new(qr);
                 record.result = Some(arc_result.clone());
 
-                let (database, connection_name) = self
-                    .connection_id
-                    .and_then(|id| self.app_state.read(cx).connections().get(&id))
-                    .map(|c| {
-                        let db = self
-                            .source
-                            .exec_ctx
-                            .database
-                            .clone()
-                            .or(c.active_database.clone());
-                        (db, Some(c.profile.name.clone()))
-                    })
-                    .unwrap_or((None, None));
-
-                let history_entry = HistoryEntry::new(
-                    pending.query.clone(),
-                    database,
-                    connection_name,
+                self.record_history_entry(
+                    &pending.query,
                     execution_time,
                     Some(row_count as usize),
+                    None,
+                    cx,
                 );
-                self.app_state.update(cx, |state, _| {
-                    state.add_history_entry(history_entry);
-                });
+
+                if !is_script {
+                    self.capture_explain_plan(&pending.query, &arc_result, cx);
+                }
 
                 self.setup_data_grid(arc_result, pending.query.clone(), window, cx);
 
+                if let Some(grid) = self.active_result_grid() {
+                    grid.update(cx, |grid, cx| grid.set_fetch_cursor(fetch_cursor, cx));
+                }
+
                 if self.layout == SqlQueryLayout::EditorOnly {
                     self.layout = SqlQueryLayout::Split;
                 }
@@ -1089,11 +1274,21 @@ impl CodeDocument {
             }
             Err(e) => {
                 self.runner.fail_primary(pending.task_id, e.to_string(), cx);
+                self.result_tabs.rerun_target = None;
 
                 let error_msg = e.to_string();
                 record.error = Some(error_msg.clone());
+                record.error_details = e.formatted().cloned();
                 self.state = DocumentState::Error;
 
+                self.record_history_entry(
+                    &pending.query,
+                    std::time::Duration::from_millis(duration_ms.unwrap_or(0) as u64),
+                    None,
+                    Some(error_msg.clone()),
+                    cx,
+                );
+
                 let title: SharedString = if is_script {
                     "Script failed".into()
                 } else {
@@ -1188,6 +1383,52 @@ impl CodeDocument {
         cx.emit(DocumentEvent::MetaChanged);
     }
 
+    /// Persists an execution to the query history. Failed executions are
+    /// recorded too so the history panel can filter by status.
+    fn record_history_entry(
+        &self,
+        query: &str,
+        execution_time: std::time::Duration,
+        row_count: Option<usize>,
+        error: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        let (database, connection_name) = self
+            .connection_id
+            .and_then(|id| self.app_state.read(cx).connections().get(&id))
+            .map(|c| {
+                let db = self
+                    .source
+                    .exec_ctx
+                    .database
+                    .clone()
+                    .or(c.active_database.clone());
+                (db, Some(c.profile.name.clone()))
+            })
+            .unwrap_or((None, None));
+
+        let mut history_entry = HistoryEntry::new(
+            query.to_string(),
+            database,
+            connection_name,
+            execution_time,
+            row_count,
+        );
+        if let Some(profile_id) = self.connection_id {
+            history_entry = history_entry.with_connection_profile(profile_id);
+        }
+        if let Some(schema) = self.source.exec_ctx.schema.clone() {
+            history_entry = history_entry.with_schema(schema);
+        }
+        if let Some(error) = error {
+            history_entry = history_entry.with_error(error);
+        }
+
+        self.app_state.update(cx, |state, _| {
+            state.add_history_entry(history_entry);
+        });
+    }
+
     fn setup_data_grid(
         &mut self,
         result: Arc<QueryResult>,
@@ -1195,6 +1436,9 @@ impl CodeDocument {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        // A restored pinned tab re-running its query takes the result itself.
+        let rerun_target = self.result_tabs.rerun_target.take();
+
         // A multi-statement batch yields one result set per statement. Give
         // each its own tab so every statement's output is visible, rather than
         // surfacing only the primary set.
@@ -1203,7 +1447,28 @@ impl CodeDocument {
             return;
         }
 
+        if let Some(tab_id) = rerun_target
+            && let Some(tab) = self
+                .result_tabs
+                .result_tabs
+                .iter_mut()
+                .find(|tab| tab.id == tab_id)
+        {
+            let profile_id = self.connection_id;
+            tab.grid.update(cx, |g, cx| {
+                g.set_query_result(result, query, profile_id, cx)
+            });
+            return;
+        }
+
+        let active_is_pinned = self
+            .result_tabs
+            .active_result_index
+            .and_then(|index| self.result_tabs.result_tabs.get(index))
+            .is_some_and(|tab| tab.pinned);
+
         let should_create_new_tab = self.result_tabs.run_in_new_tab
+            || active_is_pinned
             || self.result_tabs.result_tabs.is_empty()
             || self.result_tabs.active_result_index.is_none();
 
@@ -1215,9 +1480,16 @@ impl CodeDocument {
             && let Some(tab) = self.result_tabs.result_tabs.get_mut(index)
         {
             let profile_id = self.connection_id;
+            // The temp table keeps the old rows; the tab no longer shows them.
+            tab.registered_as = None;
+            let kept = tab.keep;
             tab.grid.update(cx, |g, cx| {
                 g.set_query_result(result, query.clone(), profile_id, cx)
             });
+
+            if kept {
+                self.persist_kept_results(cx);
+            }
         }
     }
 
@@ -1248,7 +1520,7 @@ impl CodeDocument {
         }
     }
 
-    fn create_result_tab(
+    pub(super) fn create_result_tab(
         &mut self,
         result: Arc<QueryResult>,
         query: String,
@@ -1279,7 +1551,10 @@ impl CodeDocument {
 
         let subscription = cx.subscribe(
             &grid,
-            |this, _grid, event: &DataGridEvent, cx| match event {
+            |this, panel, event: &DataGridEvent, cx| match event {
+                DataGridEvent::LoadMoreRequested => {
+                    this.load_more_result_rows(panel, cx);
+                }
                 DataGridEvent::RequestHide => {
                     this.hide_results(cx);
                 }
@@ -1330,6 +1605,10 @@ impl CodeDocument {
                     // Builder events are only emitted from table-browsing grids.
                     // CodeDocument result grids never have a builder panel.
                 }
+                DataGridEvent::OpenTableFiltered { .. } => {
+                    // Foreign-key navigation needs a table source; result
+                    // grids never offer it.
+                }
             },
         );
 
@@ -1339,6 +1618,10 @@ impl CodeDocument {
         let tab = ResultTab {
             id: tab_id,
             title,
+            registered_as: None,
+            pinned: false,
+            keep: false,
+            rerun_pending: false,
             grid,
             result_panel,
             _subscription: subscription,
@@ -1450,7 +1733,19 @@ impl CodeDocument {
             return;
         };
 
-        self.result_tabs.result_tabs.remove(index);
+        let removed = self.result_tabs.result_tabs.remove(index);
+
+        if self.register_result.tab_id == Some(tab_id) {
+            self.register_result = RegisterResultPrompt::default();
+        }
+
+        if self.rename_result.tab_id == Some(tab_id) {
+            self.rename_result = ResultTabRename::default();
+        }
+
+        if removed.keep || removed.pinned {
+            self.persist_kept_results(cx);
+        }
 
         if self.result_tabs.result_tabs.is_empty() {
             self.result_tabs.active_result_index = None;
@@ -1567,11 +1862,13 @@ impl CodeDocument {
             finished_at: None,
             result: None,
             error: None,
+            error_details: None,
             rows_affected: None,
             is_script: true,
         };
         self.execution.execution_history.push(record);
         self.execution.active_execution_index = Some(self.execution.execution_history.len() - 1);
+        self.execution.error_details_expanded = false;
 
         self.clear_live_output();
         self.start_live_output(output_receiver, cx);
@@ -1645,6 +1942,7 @@ impl CodeDocument {
                         rows: Vec::new(),
                         affected_rows: None,
                         execution_time: elapsed,
+                        timing: Default::default(),
                         text_body: Some(output),
                         raw_bytes: None,
                         next_page_token: None,
@@ -1748,6 +2046,12 @@ impl CodeDocument {
             // use it in the fallback event if needed.
             let script_content = content.clone();
 
+            let completion = QueryCompletion {
+                elapsed: script_started_at.elapsed(),
+                row_count: None,
+                error: result.as_ref().err().map(|error| error.to_string()),
+            };
+
             let inner_result = this.update(cx, |doc, cx| {
                 doc.pending.result = Some(PendingQueryResult {
                     task_id,
@@ -1755,7 +2059,9 @@ impl CodeDocument {
                     query: content,
                     result,
                     is_script: true,
+                    fetch_cursor: None,
                 });
+                cx.emit(DocumentEvent::QueryCompleted(completion));
                 cx.notify();
             });
 
@@ -1890,9 +2196,70 @@ impl CodeDocument {
     }
 }
 
+/// State of the document's connection that a pending re-run waits on.
+struct RerunReadiness<'a> {
+    connected: bool,
+    connecting: bool,
+    /// Database the document currently targets.
+    database: Option<&'a str>,
+    /// The entry's database still needs its own connection.
+    database_needs_connection: bool,
+}
+
+/// What a pending history re-run does next.
+#[derive(Debug, PartialEq, Eq)]
+enum RerunStep {
+    Wait,
+    /// Dropped silently: the document moved elsewhere, or the connect failed
+    /// and was already reported by whoever ran it.
+    Cancel,
+    DatabaseUnavailable(String),
+    ConnectDatabase(String),
+    Run,
+}
+
+impl PendingRerun {
+    /// Advances the re-run for a document now on `connection_id`.
+    fn step(&mut self, connection_id: Option<Uuid>, ready: RerunReadiness<'_>) -> RerunStep {
+        if connection_id != Some(self.profile_id) {
+            return RerunStep::Cancel;
+        }
+
+        if !ready.connected {
+            if ready.connecting || !self.connect_started {
+                self.connect_started |= ready.connecting;
+                return RerunStep::Wait;
+            }
+            return RerunStep::Cancel;
+        }
+
+        let Some(database) = self.database.clone() else {
+            return RerunStep::Run;
+        };
+
+        if ready.database != Some(database.as_str()) {
+            if self.database_requested {
+                return RerunStep::Cancel;
+            }
+            return RerunStep::DatabaseUnavailable(database);
+        }
+
+        if ready.database_needs_connection {
+            if self.database_requested {
+                return RerunStep::Wait;
+            }
+            self.database_requested = true;
+            return RerunStep::ConnectDatabase(database);
+        }
+
+        RerunStep::Run
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{query_request_for_execution, resolve_source_context};
+    use super::{RerunReadiness, RerunStep, query_request_for_execution, resolve_source_context};
+    use crate::code::PendingRerun;
     use crate::code::build_source_window_context;
     use dbflux_core::{ExecutionContext, ExecutionSourceContext, QueryLanguage};
     use uuid::Uuid;
@@ -2286,4 +2653,118 @@ mod tests {
             "stored query must be preserved across the put-back"
         );
     }
+
+    fn rerun(database: Option<&str>) -> PendingRerun {
+        PendingRerun {
+            profile_id: Uuid::nil(),
+            database: database.map(str::to_string),
+            connect_started: false,
+            database_requested: false,
+        }
+    }
+
+    fn ready(connected: bool, connecting: bool, database: Option<&str>) -> RerunReadiness<'_> {
+        RerunReadiness {
+            connected,
+            connecting,
+            database,
+            database_needs_connection: false,
+        }
+    }
+
+    /// A re-run waits for the connect it asked for and gives up once that
+    /// connect is no longer pending without having connected.
+    #[test]
+    fn rerun_waits_for_its_connection_and_drops_after_a_failed_connect() {
+        let mut pending = rerun(None);
+        let profile = Some(Uuid::nil());
+
+        assert_eq!(
+            pending.step(profile, ready(false, false, None)),
+            RerunStep::Wait
+        );
+        assert_eq!(
+            pending.step(profile, ready(false, true, None)),
+            RerunStep::Wait
+        );
+        assert_eq!(
+            pending.step(profile, ready(false, false, None)),
+            RerunStep::Cancel
+        );
+
+        let mut pending = rerun(None);
+        assert_eq!(
+            pending.step(profile, ready(false, true, None)),
+            RerunStep::Wait
+        );
+        assert_eq!(
+            pending.step(profile, ready(true, false, None)),
+            RerunStep::Run
+        );
+    }
+
+    #[test]
+    fn rerun_is_dropped_when_the_document_switches_connection() {
+        let mut pending = rerun(None);
+
+        assert_eq!(
+            pending.step(Some(Uuid::new_v4()), ready(true, false, None)),
+            RerunStep::Cancel
+        );
+        assert_eq!(
+            pending.step(None, ready(true, false, None)),
+            RerunStep::Cancel
+        );
+    }
+
+    /// The entry's database is connected once before the query runs in it.
+    #[test]
+    fn rerun_connects_its_database_once_then_runs_there() {
+        let mut pending = rerun(Some("billing"));
+        let profile = Some(Uuid::nil());
+        let needs_connection = RerunReadiness {
+            database_needs_connection: true,
+            ..ready(true, false, Some("billing"))
+        };
+
+        assert_eq!(
+            pending.step(profile, needs_connection),
+            RerunStep::ConnectDatabase("billing".to_string())
+        );
+        assert_eq!(
+            pending.step(
+                profile,
+                RerunReadiness {
+                    database_needs_connection: true,
+                    ..ready(true, false, Some("billing"))
+                }
+            ),
+            RerunStep::Wait
+        );
+        assert_eq!(
+            pending.step(profile, ready(true, false, Some("billing"))),
+            RerunStep::Run
+        );
+    }
+
+    /// A database missing from the connection is reported, unless the re-run
+    /// already asked for it and the user moved on.
+    #[test]
+    fn rerun_reports_a_database_the_connection_does_not_have() {
+        let profile = Some(Uuid::nil());
+
+        assert_eq!(
+            rerun(Some("billing")).step(profile, ready(true, false, Some("postgres"))),
+            RerunStep::DatabaseUnavailable("billing".to_string())
+        );
+
+        let mut pending = PendingRerun {
+            database_requested: true,
+            ..rerun(Some("billing"))
+        };
+        assert_eq!(
+            pending.step(profile, ready(true, false, Some("postgres"))),
+            RerunStep::Cancel
+        );
+    }
 }
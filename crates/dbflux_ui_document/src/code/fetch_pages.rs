@@ -0,0 +1,93 @@
+use super::*;
+
+impl CodeDocument {
+    /// Fetches the next page of `grid`'s result and appends it to the grid.
+    ///
+    /// The page re-runs the paged statement on the document's connection, so
+    /// it sees the data as of this fetch rather than as of the first page.
+    pub(super) fn load_more_result_rows(
+        &mut self,
+        grid: Entity<DataGridPanel>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(cursor) = grid.read(cx).fetch_cursor().cloned() else {
+            return;
+        };
+
+        let resolved = self.connection_id.and_then(|conn_id| {
+            let connections = self.app_state.read(cx).connections();
+            connections.get(&conn_id).map(|connected| {
+                let database = self
+                    .source
+                    .exec_ctx
+                    .database
+                    .clone()
+                    .or_else(|| connected.active_database.clone());
+
+                connected
+                    .resolve_connection_for_execution(database.as_deref())
+                    .map(|connection| (connection, database))
+            })
+        });
+
+        let (connection, database) = match resolved {
+            Some(Ok(resolved)) => resolved,
+            Some(Err(dbflux_core::ConnectionResolutionError::PendingDatabaseConnection {
+                database,
+            })) => {
+                grid.update(cx, |grid, cx| grid.cancel_load_more(cx));
+                Toast::error(format!(
+                    "Connecting to database '{}', please wait...",
+                    database
+                ))
+                .meta_right(now_hms())
+                .push(cx);
+                return;
+            }
+            None => {
+                grid.update(cx, |grid, cx| grid.cancel_load_more(cx));
+                Toast::error("Connection not found")
+                    .meta_right(now_hms())
+                    .push(cx);
+                return;
+            }
+        };
+
+        // Time macros were already substituted into the cursor's statement,
+        // so only the database and schema context carry over from page 1.
+        let request = QueryRequest::new(cursor.next_page_sql(connection.dialect()))
+            .with_database(database)
+            .with_execution_context(Some(self.source.exec_ctx.clone()));
+        let session = self.tab_session(connection.kind());
+
+        let task = cx
+            .background_executor()
+            .spawn(async move { session.execute(&*connection, &request) });
+
+        cx.spawn(async move |_this, cx| {
+            let outcome = task.await;
+
+            grid.update(cx, |grid, cx| {
+                // A re-run replaced the result while the page was in flight.
+                if grid.fetch_cursor() != Some(&cursor) {
+                    return;
+                }
+
+                match outcome {
+                    Ok(page) => grid.append_fetched_page(page.rows, cx),
+                    Err(error) => {
+                        grid.cancel_load_more(cx);
+
+                        let message = format!("Failed to load more rows: {error}");
+                        Toast::error(message.clone())
+                            .meta_right(now_hms())
+                            .action(copy_action(message))
+                            .push(cx);
+                    }
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,818 @@
+use super::*;
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant};
+use dbflux_components::primitives::Text;
+use regex::{Regex, RegexBuilder};
+use std::ops::Range;
+
+/// Upper bound on highlighted matches. Past this the editor would spend more
+/// time painting highlights than the user gains from seeing them.
+const MAX_FIND_MATCHES: usize = 10_000;
+
+/// Search toggles shown in the find bar.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct FindOptions {
+    pub(super) case_sensitive: bool,
+    pub(super) regex: bool,
+}
+
+/// Find/replace bar state owned by the code document.
+///
+/// Matches are byte ranges into the editor text and are recomputed on every
+/// diagnostic pass so highlights stay in sync with edits. The highlights are
+/// painted over the editor rather than added to its diagnostics, so they
+/// neither hover like lint results nor mix with them.
+pub(super) struct FindState {
+    pub(super) visible: bool,
+    pub(super) replace_visible: bool,
+    pub(super) query_input: PromptInput,
+    pub(super) replace_input: PromptInput,
+    pub(super) options: FindOptions,
+    pub(super) matches: Vec<Range<usize>>,
+    /// `matches` split into single-line UTF-16 ranges, each tagged with the
+    /// index of its match.
+    pub(super) highlights: Vec<(usize, Range<usize>)>,
+    pub(super) active_match: Option<usize>,
+    pub(super) error: Option<String>,
+}
+
+impl FindState {
+    pub(super) fn is_focused(&self) -> bool {
+        self.query_input.is_focused() || self.replace_input.is_focused()
+    }
+}
+
+fn build_pattern(query: &str, options: FindOptions) -> Result<Regex, String> {
+    let pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .multi_line(true)
+        .build()
+        .map_err(|error| error.to_string())
+}
+
+/// Returns the byte ranges of every non-empty match of `query` in `text`.
+///
+/// Zero-width regex matches (`^`, `\b`, `a*` on a non-`a`) are skipped: they
+/// cannot be highlighted and replacing them would insert text at every position.
+pub(super) fn find_matches(
+    text: &str,
+    query: &str,
+    options: FindOptions,
+) -> Result<Vec<Range<usize>>, String> {
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pattern = build_pattern(query, options)?;
+
+    Ok(pattern
+        .find_iter(text)
+        .filter(|found| !found.range().is_empty())
+        .take(MAX_FIND_MATCHES)
+        .map(|found| found.range())
+        .collect())
+}
+
+/// Expands the replacement for the match starting at `range.start`.
+///
+/// In regex mode `$1`/`${name}` refer to capture groups; literal mode inserts
+/// the replacement verbatim.
+pub(super) fn replacement_for_match(
+    text: &str,
+    range: &Range<usize>,
+    query: &str,
+    replacement: &str,
+    options: FindOptions,
+) -> Result<String, String> {
+    if !options.regex {
+        return Ok(replacement.to_string());
+    }
+
+    let pattern = build_pattern(query, options)?;
+
+    let captures = pattern
+        .captures_at(text, range.start)
+        .filter(|captures| captures.get(0).is_some_and(|m| m.range() == *range))
+        .ok_or_else(|| "Match is no longer present".to_string())?;
+
+    let mut expanded = String::new();
+    captures.expand(replacement, &mut expanded);
+    Ok(expanded)
+}
+
+/// Replaces every non-empty match and returns the new text plus the count.
+pub(super) fn replace_all_matches(
+    text: &str,
+    query: &str,
+    replacement: &str,
+    options: FindOptions,
+) -> Result<(String, usize), String> {
+    if query.is_empty() {
+        return Ok((text.to_string(), 0));
+    }
+
+    let pattern = build_pattern(query, options)?;
+
+    let mut output = String::with_capacity(text.len());
+    let mut last_end = 0;
+    let mut count = 0;
+
+    for captures in pattern.captures_iter(text) {
+        let Some(whole) = captures.get(0) else {
+            continue;
+        };
+
+        if whole.range().is_empty() {
+            continue;
+        }
+
+        output.push_str(&text[last_end..whole.start()]);
+
+        if options.regex {
+            captures.expand(replacement, &mut output);
+        } else {
+            output.push_str(replacement);
+        }
+
+        last_end = whole.end();
+        count += 1;
+    }
+
+    output.push_str(&text[last_end..]);
+
+    Ok((output, count))
+}
+
+/// Converts a byte offset into the UTF-16 offset used by the input handler.
+pub(super) fn utf16_offset(text: &str, byte_offset: usize) -> usize {
+    text[..byte_offset.min(text.len())]
+        .chars()
+        .map(char::len_utf16)
+        .sum()
+}
+
+/// Converts a byte offset into a zero-based `(line, character)` position with
+/// UTF-16 character units, matching the editor's LSP-style positions.
+pub(super) fn position_for_offset(text: &str, byte_offset: usize) -> (u32, u32) {
+    let prefix = &text[..byte_offset.min(text.len())];
+    let line = prefix.matches('\n').count();
+    let line_start = prefix.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    let character = utf16_offset(&prefix[line_start..], prefix.len() - line_start);
+
+    (line as u32, character as u32)
+}
+
+/// Splits `matches` (sorted, non-overlapping byte ranges) into single-line
+/// UTF-16 ranges tagged with the index of their match, the form the editor
+/// measures text in.
+pub(super) fn highlight_segments(
+    text: &str,
+    matches: &[Range<usize>],
+) -> Vec<(usize, Range<usize>)> {
+    let mut segments = Vec::with_capacity(matches.len());
+    let mut byte = 0;
+    let mut utf16 = 0;
+
+    for (index, range) in matches.iter().enumerate() {
+        utf16 += utf16_offset(&text[byte..], range.start - byte);
+
+        let mut start = utf16;
+        for ch in text[range.clone()].chars() {
+            if ch == '\n' {
+                if utf16 > start {
+                    segments.push((index, start..utf16));
+                }
+                start = utf16 + 1;
+            }
+            utf16 += ch.len_utf16();
+        }
+
+        if utf16 > start {
+            segments.push((index, start..utf16));
+        }
+        byte = range.end;
+    }
+
+    segments
+}
+
+/// Index of the first match starting at or after `offset`, wrapping to the
+/// first match when none does.
+pub(super) fn first_match_from(matches: &[Range<usize>], offset: usize) -> Option<usize> {
+    matches
+        .iter()
+        .position(|range| range.start >= offset)
+        .or((!matches.is_empty()).then_some(0))
+}
+
+/// Steps the active match index, wrapping at both ends.
+pub(super) fn step_match_index(
+    current: Option<usize>,
+    count: usize,
+    forward: bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => count - 1,
+        (Some(index), true) => (index + 1) % count,
+        (Some(index), false) => (index + count - 1) % count,
+    })
+}
+
+impl CodeDocument {
+    pub(super) fn create_find_state(window: &mut Window, cx: &mut Context<Self>) -> FindState {
+        let query_input = PromptInput::new(
+            window,
+            cx,
+            |state| state.placeholder("Find"),
+            |this, event, window, cx| match event {
+                InputEvent::Change => {
+                    this.find.active_match = None;
+                    this.refresh_find_matches(cx);
+                    this.reveal_active_match(window, cx);
+                }
+                InputEvent::PressEnter { .. } => this.find_step(true, window, cx),
+                InputEvent::Focus | InputEvent::Blur => {}
+            },
+        );
+
+        let replace_input = PromptInput::new(
+            window,
+            cx,
+            |state| state.placeholder("Replace"),
+            |this, event, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.replace_active_match(window, cx);
+                }
+            },
+        );
+
+        FindState {
+            visible: false,
+            replace_visible: false,
+            query_input,
+            replace_input,
+            options: FindOptions::default(),
+            matches: Vec::new(),
+            highlights: Vec::new(),
+            active_match: None,
+            error: None,
+        }
+    }
+
+    /// Opens the find bar, seeding the query from a single-line selection.
+    pub(super) fn open_find(
+        &mut self,
+        with_replace: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let seed = self
+            .selected_query(window, cx)
+            .filter(|selection| !selection.contains('\n'));
+
+        self.find.visible = true;
+        self.find.replace_visible = with_replace && !self.read_only;
+
+        if let Some(seed) = seed {
+            self.find
+                .query_input
+                .state
+                .update(cx, |state, cx| state.set_value(&seed, window, cx));
+        }
+
+        let target = if with_replace && !self.find.query_input.state.read(cx).value().is_empty() {
+            &self.find.replace_input
+        } else {
+            &self.find.query_input
+        };
+        target.focus(window, cx);
+
+        self.refresh_find_matches(cx);
+    }
+
+    pub(super) fn close_find(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.find.visible = false;
+        self.find.matches.clear();
+        self.find.highlights.clear();
+        self.find.active_match = None;
+        self.find.error = None;
+
+        self.editor
+            .input_state
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    pub(super) fn toggle_find_option(
+        &mut self,
+        toggle: impl FnOnce(&mut FindOptions),
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        toggle(&mut self.find.options);
+        self.find.active_match = None;
+        self.refresh_find_matches(cx);
+        self.reveal_active_match(window, cx);
+    }
+
+    /// Recomputes matches against the current editor text and repaints them.
+    fn refresh_find_matches(&mut self, cx: &mut Context<Self>) {
+        let text = self.editor.input_state.read(cx).value().to_string();
+        self.recompute_find_matches(&text, cx);
+        cx.notify();
+    }
+
+    /// Recomputes matches against `text`. Called from the diagnostic pass so
+    /// edits and query changes share one refresh path.
+    pub(super) fn recompute_find_matches(&mut self, text: &str, cx: &App) {
+        if !self.find.visible {
+            return;
+        }
+
+        let query = self.find.query_input.state.read(cx).value().to_string();
+
+        match find_matches(text, &query, self.find.options) {
+            Ok(matches) => {
+                self.find.error = None;
+                self.find.active_match = self
+                    .find
+                    .active_match
+                    .filter(|index| *index < matches.len())
+                    .or((!matches.is_empty()).then_some(0));
+                self.find.highlights = highlight_segments(text, &matches);
+                self.find.matches = matches;
+            }
+            Err(error) => {
+                self.find.error = Some(error);
+                self.find.matches.clear();
+                self.find.highlights.clear();
+                self.find.active_match = None;
+            }
+        }
+    }
+
+    /// Paints the current matches over the editor, the active one stronger.
+    /// Only lines in view are measured.
+    pub(super) fn render_find_highlights(&self, cx: &App) -> impl IntoElement {
+        let input = self.editor.input_state.clone();
+        let highlights = self.find.highlights.clone();
+        let active = self.find.active_match;
+        let match_color = cx.theme().warning.opacity(0.25);
+        let active_color = cx.theme().warning.opacity(0.55);
+
+        canvas(
+            |_, _, _| (),
+            move |bounds, _, window, cx| {
+                input.update(cx, |state, cx| {
+                    let first = state
+                        .character_index_for_point(bounds.origin, window, cx)
+                        .unwrap_or(0);
+                    let last = state
+                        .character_index_for_point(bounds.bottom_right(), window, cx)
+                        .unwrap_or(usize::MAX);
+                    let from = highlights.partition_point(|(_, range)| range.end < first);
+
+                    for (index, range) in &highlights[from..] {
+                        if range.start > last {
+                            break;
+                        }
+
+                        let Some(rect) = state.bounds_for_range(range.clone(), bounds, window, cx)
+                        else {
+                            continue;
+                        };
+
+                        let color = if active == Some(*index) {
+                            active_color
+                        } else {
+                            match_color
+                        };
+                        window.paint_quad(fill(rect, color));
+                    }
+                });
+            },
+        )
+        .absolute()
+        .size_full()
+    }
+
+    pub(super) fn find_step(&mut self, forward: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.find.visible {
+            self.open_find(false, window, cx);
+            return;
+        }
+
+        self.find.active_match =
+            step_match_index(self.find.active_match, self.find.matches.len(), forward);
+
+        self.reveal_active_match(window, cx);
+        cx.notify();
+    }
+
+    fn reveal_active_match(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(range) = self
+            .find
+            .active_match
+            .and_then(|index| self.find.matches.get(index))
+            .cloned()
+        else {
+            return;
+        };
+
+        self.editor.input_state.update(cx, |state, cx| {
+            let text = state.value().to_string();
+            let (line, character) = position_for_offset(&text, range.start);
+            state.set_cursor_position(InputPosition::new(line, character), window, cx);
+        });
+    }
+
+    pub(super) fn replace_active_match(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.read_only {
+            return;
+        }
+
+        let Some(range) = self
+            .find
+            .active_match
+            .and_then(|index| self.find.matches.get(index))
+            .cloned()
+        else {
+            return;
+        };
+
+        let text = self.editor.input_state.read(cx).value().to_string();
+        let query = self.find.query_input.state.read(cx).value().to_string();
+        let replacement = self.find.replace_input.state.read(cx).value().to_string();
+
+        let replacement =
+            match replacement_for_match(&text, &range, &query, &replacement, self.find.options) {
+                Ok(replacement) => replacement,
+                Err(error) => {
+                    self.find.error = Some(error);
+                    cx.notify();
+                    return;
+                }
+            };
+
+        let utf16_range = utf16_offset(&text, range.start)..utf16_offset(&text, range.end);
+
+        // Routed through the input handler (not `set_value`) so the edit lands
+        // on the editor's undo stack and emits a regular `Change`.
+        self.editor.input_state.update(cx, |state, cx| {
+            state.replace_text_in_range(Some(utf16_range), &replacement, window, cx);
+        });
+
+        // Continue after the inserted text, which may itself contain the query.
+        self.refresh_find_matches(cx);
+        self.find.active_match =
+            first_match_from(&self.find.matches, range.start + replacement.len());
+        self.reveal_active_match(window, cx);
+    }
+
+    pub(super) fn replace_all_find_matches(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.read_only {
+            return;
+        }
+
+        let text = self.editor.input_state.read(cx).value().to_string();
+        let query = self.find.query_input.state.read(cx).value().to_string();
+        let replacement = self.find.replace_input.state.read(cx).value().to_string();
+
+        let (replaced, count) =
+            match replace_all_matches(&text, &query, &replacement, self.find.options) {
+                Ok(result) => result,
+                Err(error) => {
+                    self.find.error = Some(error);
+                    cx.notify();
+                    return;
+                }
+            };
+
+        if count == 0 {
+            Toast::info("No matches to replace")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        let whole_range = 0..utf16_offset(&text, text.len());
+
+        self.editor.input_state.update(cx, |state, cx| {
+            state.replace_text_in_range(Some(whole_range), &replaced, window, cx);
+        });
+
+        self.find.active_match = None;
+        self.refresh_find_matches(cx);
+
+        let noun = if count == 1 {
+            "occurrence"
+        } else {
+            "occurrences"
+        };
+        Toast::success(format!("Replaced {} {}", count, noun))
+            .meta_right(now_hms())
+            .push(cx);
+        cx.notify();
+    }
+
+    pub(super) fn render_find_bar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let options = self.find.options;
+        let has_matches = !self.find.matches.is_empty();
+        let can_replace = has_matches && !self.read_only;
+
+        let status = if let Some(error) = &self.find.error {
+            error.clone()
+        } else if self.find.query_input.state.read(cx).value().is_empty() {
+            String::new()
+        } else if let Some(index) = self.find.active_match {
+            format!("{} of {}", index + 1, self.find.matches.len())
+        } else {
+            "No results".to_string()
+        };
+
+        let option_variant = |enabled: bool| {
+            if enabled {
+                ToolbarButtonVariant::Default
+            } else {
+                ToolbarButtonVariant::Ghost
+            }
+        };
+
+        div()
+            .id("sql-find-bar")
+            .flex()
+            .flex_col()
+            .flex_shrink_0()
+            .gap(Spacing::XS)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .border_b_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::SM)
+                    .child(
+                        div()
+                            .w(px(260.0)) // guardrail-allow: find input width, not a height token
+                            .child(Input::new(&self.find.query_input.state).small()),
+                    )
+                    .child(
+                        ToolbarButton::new("find-case-toggle")
+                            .icon(AppIcon::CaseSensitive)
+                            .tooltip("Match case")
+                            .variant(option_variant(options.case_sensitive))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.toggle_find_option(
+                                    |options| options.case_sensitive = !options.case_sensitive,
+                                    window,
+                                    cx,
+                                );
+                            })),
+                    )
+                    .child(
+                        ToolbarButton::new("find-regex-toggle")
+                            .label(".*")
+                            .tooltip("Use regular expression")
+                            .variant(option_variant(options.regex))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.toggle_find_option(
+                                    |options| options.regex = !options.regex,
+                                    window,
+                                    cx,
+                                );
+                            })),
+                    )
+                    .child(
+                        ToolbarButton::new("find-prev-btn")
+                            .icon(AppIcon::ArrowUp)
+                            .tooltip("Previous match (Shift+F3)")
+                            .variant(ToolbarButtonVariant::Ghost)
+                            .disabled(!has_matches)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.find_step(false, window, cx);
+                            })),
+                    )
+                    .child(
+                        ToolbarButton::new("find-next-btn")
+                            .icon(AppIcon::ArrowDown)
+                            .tooltip("Next match (F3)")
+                            .variant(ToolbarButtonVariant::Ghost)
+                            .disabled(!has_matches)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.find_step(true, window, cx);
+                            })),
+                    )
+                    .when(!self.read_only, |el| {
+                        el.child(
+                            ToolbarButton::new("find-toggle-replace-btn")
+                                .icon(if self.find.replace_visible {
+                                    AppIcon::ChevronUp
+                                } else {
+                                    AppIcon::ChevronDown
+                                })
+                                .tooltip("Toggle replace")
+                                .variant(ToolbarButtonVariant::Ghost)
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.find.replace_visible = !this.find.replace_visible;
+                                    cx.notify();
+                                })),
+                        )
+                    })
+                    .child(
+                        Text::caption(status)
+                            .when(self.find.error.is_some(), |text| text.color(theme.danger)),
+                    )
+                    .child(div().flex_1())
+                    .child(
+                        ToolbarButton::new("find-close-btn")
+                            .icon(AppIcon::X)
+                            .tooltip("Close (Esc)")
+                            .variant(ToolbarButtonVariant::Ghost)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.close_find(window, cx);
+                            })),
+                    ),
+            )
+            .when(self.find.replace_visible && !self.read_only, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::SM)
+                        .child(
+                            div()
+                                .w(px(260.0)) // guardrail-allow: find input width, not a height token
+                                .child(Input::new(&self.find.replace_input.state).small()),
+                        )
+                        .child(
+                            ToolbarButton::new("replace-one-btn")
+                                .label("Replace")
+                                .disabled(!can_replace)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.replace_active_match(window, cx);
+                                })),
+                        )
+                        .child(
+                            ToolbarButton::new("replace-all-btn")
+                                .label("Replace all")
+                                .disabled(!can_replace)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.replace_all_find_matches(window, cx);
+                                })),
+                        ),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FindOptions, find_matches, first_match_from, highlight_segments, position_for_offset,
+        replace_all_matches, replacement_for_match, step_match_index, utf16_offset,
+    };
+
+    const LITERAL: FindOptions = FindOptions {
+        case_sensitive: false,
+        regex: false,
+    };
+
+    const REGEX: FindOptions = FindOptions {
+        case_sensitive: true,
+        regex: true,
+    };
+
+    #[test]
+    fn test_literal_search_is_case_insensitive_by_default() {
+        let matches = find_matches("SELECT 1; select 2", "select", LITERAL).unwrap();
+        assert_eq!(matches, vec![0..6, 10..16]);
+    }
+
+    #[test]
+    fn test_case_sensitive_search() {
+        let options = FindOptions {
+            case_sensitive: true,
+            regex: false,
+        };
+        let matches = find_matches("SELECT 1; select 2", "select", options).unwrap();
+        assert_eq!(matches, vec![10..16]);
+    }
+
+    #[test]
+    fn test_literal_search_escapes_regex_metacharacters() {
+        let matches = find_matches("a.b axb", "a.b", LITERAL).unwrap();
+        assert_eq!(matches, vec![0..3]);
+    }
+
+    #[test]
+    fn test_regex_search_skips_empty_matches() {
+        let matches = find_matches("abc", "x*", REGEX).unwrap();
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_regex_reports_error() {
+        assert!(find_matches("abc", "(", REGEX).is_err());
+    }
+
+    #[test]
+    fn test_empty_query_has_no_matches() {
+        assert!(find_matches("abc", "", LITERAL).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_replace_all_literal() {
+        let (text, count) =
+            replace_all_matches("id = 1 AND ID = 2", "id", "user_id", LITERAL).unwrap();
+        assert_eq!(text, "user_id = 1 AND user_id = 2");
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_literal_replacement_does_not_expand_captures() {
+        let (text, _) = replace_all_matches("a", "a", "$1", LITERAL).unwrap();
+        assert_eq!(text, "$1");
+    }
+
+    #[test]
+    fn test_replace_all_regex_expands_captures() {
+        let (text, count) =
+            replace_all_matches("t1.a, t2.b", r"(t\d)\.(\w)", "$2@$1", REGEX).unwrap();
+        assert_eq!(text, "a@t1, b@t2");
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_replacement_for_single_match_uses_its_captures() {
+        let text = "x1 x2";
+        let matches = find_matches(text, r"x(\d)", REGEX).unwrap();
+        let replacement = replacement_for_match(text, &matches[1], r"x(\d)", "y$1", REGEX);
+        assert_eq!(replacement.unwrap(), "y2");
+    }
+
+    #[test]
+    fn test_position_for_offset_tracks_lines() {
+        let text = "SELECT\n  name\nFROM users";
+        assert_eq!(position_for_offset(text, 0), (0, 0));
+        assert_eq!(position_for_offset(text, 9), (1, 2));
+        assert_eq!(position_for_offset(text, 19), (2, 5));
+    }
+
+    #[test]
+    fn test_utf16_offset_counts_surrogate_pairs() {
+        let text = "é😀x";
+        assert_eq!(utf16_offset(text, text.len()), 4);
+        assert_eq!(position_for_offset(text, text.len()), (0, 4));
+    }
+
+    #[test]
+    fn test_step_match_index_wraps() {
+        assert_eq!(step_match_index(None, 3, true), Some(0));
+        assert_eq!(step_match_index(None, 3, false), Some(2));
+        assert_eq!(step_match_index(Some(2), 3, true), Some(0));
+        assert_eq!(step_match_index(Some(0), 3, false), Some(2));
+        assert_eq!(step_match_index(Some(0), 0, true), None);
+    }
+
+    #[test]
+    fn test_highlight_segments_split_lines_in_utf16_units() {
+        let text = "é ab\ncd ab";
+        let matches = find_matches(text, "ab\ncd", LITERAL).unwrap();
+        assert_eq!(
+            highlight_segments(text, &matches),
+            vec![(0, 2..4), (0, 5..7)]
+        );
+
+        let matches = find_matches(text, "ab", LITERAL).unwrap();
+        assert_eq!(
+            highlight_segments(text, &matches),
+            vec![(0, 2..4), (1, 8..10)]
+        );
+    }
+
+    #[test]
+    fn test_replacing_continues_after_the_inserted_text() {
+        // "id" replaced by "user_id" at 0: the next search must skip the
+        // "id" inside the replacement.
+        let text = "user_id = 1 AND id = 2";
+        let matches = find_matches(text, "id", LITERAL).unwrap();
+        assert_eq!(matches, vec![5..7, 16..18]);
+        assert_eq!(first_match_from(&matches, "user_id".len()), Some(1));
+        assert_eq!(first_match_from(&matches, text.len()), Some(0));
+        assert_eq!(first_match_from(&[], 0), None);
+    }
+}
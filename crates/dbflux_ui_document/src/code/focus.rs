@@ -28,6 +28,32 @@ impl CodeDocument {
             return ContextId::HistoryModal;
         }
 
+        if self.find.visible && self.find.is_focused() {
+            return ContextId::TextInput;
+        }
+
+        if self.variables.visible && self.variables.is_focused() {
+            return ContextId::TextInput;
+        }
+
+        if self.saved_params.is_visible() && self.saved_params.is_focused() {
+            return ContextId::TextInput;
+        }
+
+        let inline_prompts = [
+            &self.impersonation_prompt.input,
+            &self.register_result.input,
+            &self.rename_result.input,
+        ];
+
+        if inline_prompts
+            .into_iter()
+            .flatten()
+            .any(PromptInput::is_focused)
+        {
+            return ContextId::TextInput;
+        }
+
         // Check if the active result tab's grid has a modal, context menu, or inline edit open
         if self.focus_mode == SqlQueryFocus::Results
             && let Some(index) = self.result_tabs.active_result_index
@@ -0,0 +1,177 @@
+use super::*;
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant};
+use dbflux_components::primitives::{Icon, Text};
+use dbflux_core::RoleImpersonation;
+
+/// Prompt for the role (or SQL Server user) this tab's queries run as.
+#[derive(Default)]
+pub(super) struct ImpersonationPrompt {
+    pub(super) input: Option<PromptInput>,
+}
+
+impl ImpersonationPrompt {
+    pub(super) fn is_visible(&self) -> bool {
+        self.input.is_some()
+    }
+}
+
+impl CodeDocument {
+    /// Role switching statements for the document's connection, or `None`
+    /// when its driver cannot impersonate.
+    pub(super) fn role_impersonation(&self, cx: &App) -> Option<RoleImpersonation> {
+        let conn_id = self.connection_id?;
+        let connections = self.app_state.read(cx).connections();
+        let connected = connections.get(&conn_id)?;
+
+        RoleImpersonation::for_kind(connected.profile.kind())
+    }
+
+    pub fn open_impersonation_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(impersonation) = self.role_impersonation(cx) else {
+            Toast::warning("This connection does not support switching roles")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        let initial = self.impersonated_role.clone().unwrap_or_default();
+        let input = PromptInput::new(
+            window,
+            cx,
+            |state| {
+                state
+                    .placeholder(format!("{} name", impersonation.subject_label()))
+                    .default_value(initial)
+            },
+            |this, event, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.confirm_impersonation(window, cx);
+                }
+            },
+        );
+        input.focus(window, cx);
+
+        self.impersonation_prompt = ImpersonationPrompt { input: Some(input) };
+
+        cx.notify();
+    }
+
+    fn confirm_impersonation(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(input) = self.impersonation_prompt.input.as_ref() else {
+            return;
+        };
+
+        let role = input.state.read(cx).value().trim().to_string();
+        if role.is_empty() {
+            Toast::warning("Enter a role name")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        self.impersonated_role = Some(role);
+        self.close_impersonation_prompt(window, cx);
+    }
+
+    /// Drops the impersonated role; later queries run as the login role.
+    pub fn revert_impersonation(&mut self, cx: &mut Context<Self>) {
+        if self.impersonated_role.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    pub(super) fn close_impersonation_prompt(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.impersonation_prompt = ImpersonationPrompt::default();
+        self.editor
+            .input_state
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    pub(super) fn render_impersonation_prompt(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+
+        div()
+            .id("impersonation-prompt")
+            .flex()
+            .items_center()
+            .flex_shrink_0()
+            .gap(Spacing::SM)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .border_b_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .child(Text::label("Run queries as"))
+            .when_some(self.impersonation_prompt.input.as_ref(), |el, input| {
+                el.child(
+                    div()
+                        .w(px(260.0)) // guardrail-allow: name input width, not a height token
+                        .child(Input::new(&input.state).small()),
+                )
+            })
+            .child(Text::caption("Applies to this tab only").muted_foreground())
+            .child(div().flex_1())
+            .child(
+                ToolbarButton::new("impersonation-confirm-btn")
+                    .icon(AppIcon::KeyRound)
+                    .label("Switch")
+                    .variant(ToolbarButtonVariant::Primary)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.confirm_impersonation(window, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("impersonation-close-btn")
+                    .icon(AppIcon::X)
+                    .tooltip("Cancel")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.close_impersonation_prompt(window, cx);
+                    })),
+            )
+    }
+
+    /// Context bar badge naming the impersonated role, with a revert button.
+    pub(super) fn render_impersonation_indicator(
+        &self,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        let role = self.impersonated_role.as_ref()?;
+        let theme = cx.theme();
+
+        let element = div()
+            .id("impersonation-indicator")
+            .flex()
+            .flex_none()
+            .items_center()
+            .gap_1()
+            .pl(Spacing::SM)
+            .rounded(Radii::SM)
+            .border_1()
+            .border_color(theme.warning)
+            .bg(theme.warning.opacity(0.12))
+            .child(
+                Icon::new(AppIcon::KeyRound)
+                    .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                    .color(theme.warning),
+            )
+            .child(Text::caption(format!("As {role}")))
+            .child(
+                ToolbarButton::new("impersonation-revert-btn")
+                    .icon(AppIcon::X)
+                    .tooltip("Revert to the login role")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.revert_impersonation(cx);
+                    })),
+            )
+            .into_any_element();
+
+        Some(element)
+    }
+}
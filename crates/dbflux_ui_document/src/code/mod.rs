@@ -1,9 +1,14 @@
 use super::data_grid_panel::{DataGridEvent, DataGridPanel};
-use super::handle::DocumentEvent;
+use super::handle::{DocumentEvent, QueryCompletion};
 use super::task_runner::DocumentTaskRunner;
 use super::types::{DocumentId, DocumentState};
 use crate::history_modal::{
     HistoryModal, HistoryModalCallbacks, HistoryModalClosed, HistoryQuerySelected,
+    HistoryRerunRequested,
+};
+use crate::refresh::{
+    CUSTOM_REFRESH_INDEX, RefreshCountdown, auto_refresh_dropdown_index,
+    auto_refresh_dropdown_items, parse_refresh_interval,
 };
 use dbflux_app::keymap::{Command, ContextId};
 use dbflux_components::common::time_range::state::TimeRange;
@@ -27,12 +32,13 @@ use dbflux_core::observability::{
 use dbflux_core::{
     DangerousAction, DangerousQueryKind, DbError, DiagnosticSeverity as CoreDiagnosticSeverity,
     DriftOutcome, DriverCapabilities, EditorDiagnostic as CoreEditorDiagnostic,
-    EditorLanguageProfile, ExecutionContext, ExecutionSourceContext, HistoryEntry, OutputReceiver,
-    QueryLanguage, QueryRequest, QueryResult, RefreshPolicy, SchemaDriftDetected,
-    SchemaLoadingStrategy, TaskTarget, ValidationResult, check_schema_drift,
+    EditorLanguageProfile, ExecutionContext, ExecutionSourceContext, FETCH_PAGE_ROWS, FetchCursor,
+    FormattedError, HistoryEntry, OutputReceiver, QueryLanguage, QueryRequest, QueryResult,
+    RefreshPolicy, SchemaDriftDetected, SchemaLoadingStrategy, TaskTarget, ValidationResult,
+    check_schema_drift,
 };
 use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
-use dbflux_ui_base::{AppStateChanged, AppStateEntity};
+use dbflux_ui_base::{AppStateChanged, AppStateEntity, SchemaObjectDrag};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
 use gpui_component::ActiveTheme;
@@ -57,14 +63,35 @@ mod completion;
 mod context_bar;
 mod diagnostics;
 mod execution;
+mod fetch_pages;
 mod file_ops;
+mod find;
 mod focus;
+mod impersonation;
 mod live_output;
 pub mod pane;
+mod plan_history;
+mod prompt_input;
+mod read_only_tx;
+mod register_result;
 mod render;
+mod result_tabs;
+mod saved_params;
+mod session;
+mod variables;
+mod vim;
 
 use completion::QueryCompletionProvider;
+pub(crate) use execution::{evaluate_dangerous_with_effective_settings, task_target_for_execution};
+use find::FindState;
+use impersonation::ImpersonationPrompt;
 use live_output::LiveOutputState;
+use prompt_input::PromptInput;
+use register_result::RegisterResultPrompt;
+use result_tabs::ResultTabRename;
+use saved_params::SavedParamsPrompt;
+use variables::VariablesState;
+use vim::VimState;
 
 /// A single result tab within the CodeDocument.
 ///
@@ -75,6 +102,14 @@ use live_output::LiveOutputState;
 pub(super) struct ResultTab {
     id: Uuid,
     title: String,
+    /// Temp table name this tab's rows were registered under, if any.
+    registered_as: Option<String>,
+    /// Excluded from replacement: the next run opens a new tab instead.
+    pinned: bool,
+    /// Written to the session's results artifact and restored on next launch.
+    keep: bool,
+    /// Pinned tab restored without rows; its query runs when first shown.
+    rerun_pending: bool,
     grid: Entity<DataGridPanel>,
     result_panel: Entity<ResultPanel>,
     _subscription: Subscription,
@@ -258,6 +293,8 @@ pub(super) struct EditorState {
 pub(super) struct SessionPersistence {
     pub(super) scratch_path: Option<PathBuf>,
     pub(super) shadow_path: Option<PathBuf>,
+    /// Artifact holding the result tabs marked "keep results".
+    pub(super) results_path: Option<PathBuf>,
     pub(super) _auto_save_debounce: Option<Task<()>>,
     pub(super) show_saved_label: bool,
     pub(super) _saved_label_timer: Option<Task<()>>,
@@ -269,10 +306,13 @@ pub(super) struct HistoryState {
     pub(super) _history_subscriptions: Vec<Subscription>,
 }
 
-/// Auto-refresh policy, timer, and dropdown control.
+/// Auto-refresh policy, timer, countdown, and dropdown control.
 pub(super) struct RefreshState {
     pub(super) refresh_policy: RefreshPolicy,
     pub(super) refresh_dropdown: Entity<Dropdown>,
+    pub(super) countdown: RefreshCountdown,
+    /// Interval input shown after picking "Custom…", with its subscription.
+    pub(super) custom_input: Option<(Entity<InputState>, Subscription)>,
     pub(super) _refresh_timer: Option<Task<()>>,
     pub(super) _refresh_subscriptions: Vec<Subscription>,
 }
@@ -291,6 +331,8 @@ pub(super) struct Execution {
     pub(super) live_output: Option<LiveOutputState>,
     pub(super) _live_output_drain: Option<Task<()>>,
     pub(super) active_query_task: Option<ActiveQueryTask>,
+    /// Whether the error view shows the detail and hint of the failed run.
+    pub(super) error_details_expanded: bool,
 }
 
 /// The result-tab collection and its selection cursor.
@@ -299,6 +341,8 @@ pub(super) struct ResultTabs {
     pub(super) active_result_index: Option<usize>,
     pub(super) result_tab_counter: usize,
     pub(super) run_in_new_tab: bool,
+    /// Tab the next result goes to while a restored pinned tab re-runs.
+    pub(super) rerun_target: Option<Uuid>,
 }
 
 /// All deferred action slots drained at the top of each render cycle.
@@ -312,6 +356,8 @@ pub(super) struct ResultTabs {
 pub(super) struct PendingActions {
     result: Option<PendingQueryResult>,
     set_query: Option<HistoryQuerySelected>,
+    rerun_request: Option<HistoryEntry>,
+    rerun: Option<PendingRerun>,
     auto_refresh: bool,
     history_focus_restore: bool,
     drift_query: Option<PendingDriftQuery>,
@@ -324,6 +370,8 @@ pub(super) struct PendingActions {
     script_confirm: Option<PendingScriptConfirm>,
     routine_definition: Option<String>,
     error: Option<String>,
+    /// Statement whose two latest plans should open in a comparison tab.
+    plan_comparison: Option<String>,
 }
 
 pub struct CodeDocument {
@@ -334,6 +382,11 @@ pub struct CodeDocument {
     connection_id: Option<Uuid>,
     /// When true, the editor content must not be modified and query execution is blocked.
     read_only: bool,
+    /// When true, executions run inside a read-only transaction that is
+    /// always rolled back.
+    read_only_transactions: bool,
+    /// Role (or SQL Server user) this tab's executions switch to.
+    impersonated_role: Option<String>,
     /// Deduplication key for routine definition documents. `None` for regular code documents.
     routine_dedup: Option<(Uuid, String, String)>,
     /// True when this is a routine document restored from a session without an active connection.
@@ -346,6 +399,27 @@ pub struct CodeDocument {
     // Editor: text input, file-backing, language mode, and diagnostics.
     editor: EditorState,
 
+    // In-editor find/replace bar.
+    find: FindState,
+
+    // Modal editing layer; inert unless enabled in settings.
+    vim: VimState,
+
+    // `{{name}}` query variables panel.
+    variables: VariablesState,
+
+    // Run prompt for the linked saved query's `:name` parameters.
+    saved_params: SavedParamsPrompt,
+
+    // Name prompt for the role to impersonate.
+    impersonation_prompt: ImpersonationPrompt,
+
+    // Name prompt for registering a result tab as a temp table.
+    register_result: RegisterResultPrompt,
+
+    // Inline title editor for a result tab.
+    rename_result: ResultTabRename,
+
     // Execution context and associated source-control widgets.
     source: SourceContext,
 
@@ -388,6 +462,8 @@ struct PendingQueryResult {
     /// Whether this execution is a script (vs a database query).
     /// Determines the audit event category and whether connection context is required.
     is_script: bool,
+    /// Paging state when only the first page of a `SELECT` was fetched.
+    fetch_cursor: Option<FetchCursor>,
 }
 
 pub(super) struct ActiveQueryTask {
@@ -402,6 +478,17 @@ struct PendingDangerousQuery {
     in_new_tab: bool,
 }
 
+/// A history entry re-run waiting for its original connection (and, for
+/// connection-per-database drivers, its database) to become ready.
+struct PendingRerun {
+    profile_id: Uuid,
+    database: Option<String>,
+    /// Set once a connect attempt for the profile has been seen, so a failed
+    /// attempt drops the re-run instead of waiting forever.
+    connect_started: bool,
+    database_requested: bool,
+}
+
 /// Pending confirmation for running a whole multi-statement script.
 ///
 /// Raised when the user runs without a selection, the buffer holds more than
@@ -442,6 +529,9 @@ pub struct ExecutionRecord {
     pub finished_at: Option<Instant>,
     pub result: Option<Arc<QueryResult>>,
     pub error: Option<String>,
+    /// Code, severity, detail and hint behind `error`, when the driver
+    /// reported them.
+    pub error_details: Option<FormattedError>,
     pub rows_affected: Option<u64>,
     /// Whether this execution is a script (vs a database query).
     /// Used to determine audit event category on cancellation.
@@ -563,6 +653,7 @@ impl CodeDocument {
                         this.mark_dirty(cx);
                         this.schedule_auto_save(cx);
                         this.schedule_diagnostic_refresh(cx);
+                        this.sync_variable_rows(_window, cx);
                     }
                 }
                 InputEvent::Focus => {
@@ -579,9 +670,15 @@ impl CodeDocument {
             let app = app_state.clone();
             HistoryModal::new(
                 HistoryModalCallbacks {
-                    history_provider: {
+                    history_page: {
                         let a = app.clone();
-                        Box::new(move |cx: &App| a.read(cx).history_entries().to_vec())
+                        Box::new(move |filter, offset, limit, cx: &App| {
+                            a.read(cx).history_page(filter, offset, limit)
+                        })
+                    },
+                    history_count: {
+                        let a = app.clone();
+                        Box::new(move |filter, cx: &App| a.read(cx).history_count(filter))
                     },
                     saved_provider: {
                         let a = app.clone();
@@ -606,16 +703,18 @@ impl CodeDocument {
                     on_delete: {
                         let a = app.clone();
                         Box::new(move |id, cx| {
-                            a.update(cx, |s, _| {
+                            a.update(cx, |s, cx| {
                                 s.remove_saved_query(id);
+                                cx.emit(AppStateChanged);
                             });
                         })
                     },
                     on_toggle_favorite: {
                         let a = app.clone();
                         Box::new(move |id, cx| {
-                            a.update(cx, |s, _| {
+                            a.update(cx, |s, cx| {
                                 s.toggle_saved_query_favorite(id);
+                                cx.emit(AppStateChanged);
                             });
                         })
                     },
@@ -627,6 +726,45 @@ impl CodeDocument {
                             });
                         })
                     },
+                    on_toggle_history_pin: {
+                        let a = app.clone();
+                        Box::new(move |id, cx| a.update(cx, |s, _| s.toggle_history_pinned(id)))
+                    },
+                    folders_provider: {
+                        let a = app.clone();
+                        Box::new(move |cx: &App| a.read(cx).saved_query_folders().to_vec())
+                    },
+                    resolve_folder: {
+                        let a = app.clone();
+                        Box::new(move |path, cx| {
+                            a.update(cx, |s, _| s.ensure_saved_query_folder_path(path))
+                        })
+                    },
+                    on_organize: {
+                        let a = app.clone();
+                        Box::new(move |id, folder_id, tags, params, cx| {
+                            a.update(cx, |s, _| {
+                                s.update_saved_query_organization(id, folder_id, tags);
+                                s.update_saved_query_params(id, params);
+                            });
+                        })
+                    },
+                    driver_names: {
+                        let a = app.clone();
+                        Box::new(move |profile_id, cx: &App| {
+                            a.read(cx)
+                                .profiles()
+                                .iter()
+                                .find(|profile| profile.id == profile_id)
+                                .map(|profile| {
+                                    vec![
+                                        profile.driver_id(),
+                                        profile.kind().display_name().to_string(),
+                                    ]
+                                })
+                                .unwrap_or_default()
+                        })
+                    },
                 },
                 window,
                 cx,
@@ -642,6 +780,14 @@ impl CodeDocument {
             },
         );
 
+        let rerun_sub = cx.subscribe(
+            &history_modal,
+            |this, _, event: &HistoryRerunRequested, cx| {
+                this.pending.rerun_request = Some(event.entry.clone());
+                cx.notify();
+            },
+        );
+
         let history_closed_sub =
             cx.subscribe(&history_modal, |this, _, _: &HistoryModalClosed, cx| {
                 this.pending.history_focus_restore = true;
@@ -687,21 +833,21 @@ impl CodeDocument {
             .resolve_refresh_policy();
 
         let refresh_dropdown = cx.new(|_cx| {
-            let items = RefreshPolicy::ALL
-                .iter()
-                .map(|policy| DropdownItem::new(policy.label()))
-                .collect();
-
             Dropdown::new("sql-auto-refresh")
-                .items(items)
-                .selected_index(Some(default_refresh.index()))
+                .items(auto_refresh_dropdown_items())
+                .selected_index(Some(auto_refresh_dropdown_index(default_refresh)))
                 .compact_trigger(true)
         });
 
         let refresh_policy_sub = cx.subscribe_in(
             &refresh_dropdown,
             window,
-            |this, _, event: &DropdownSelectionChanged, _window, cx| {
+            |this, _, event: &DropdownSelectionChanged, window, cx| {
+                if event.index == CUSTOM_REFRESH_INDEX {
+                    this.open_custom_refresh_input(window, cx);
+                    return;
+                }
+
                 let policy = RefreshPolicy::from_index(event.index);
 
                 if policy.is_auto() && !this.can_auto_refresh(cx) {
@@ -726,6 +872,8 @@ impl CodeDocument {
                 .scratch_path(&doc_id.0.to_string(), query_language.default_extension()),
         );
 
+        let results_path = Some(app_state.read(cx).results_path(&doc_id.0.to_string()));
+
         let initial_database = connection_id.and_then(|id| {
             let connections = app_state.read(cx).connections();
             let connected = connections.get(&id)?;
@@ -820,12 +968,16 @@ impl CodeDocument {
 
         let refresh_policy = default_refresh;
 
+        let find = Self::create_find_state(window, cx);
+
         let mut document = Self {
             id: doc_id,
             title: "Query 1".to_string(),
             state: DocumentState::Clean,
             connection_id,
             read_only: false,
+            read_only_transactions: false,
+            impersonated_role: None,
             routine_dedup: None,
             routine_definition_pending: false,
             app_state,
@@ -844,6 +996,13 @@ impl CodeDocument {
                 suppress_dirty: false,
                 query_language,
             },
+            find,
+            vim: VimState::default(),
+            variables: VariablesState::default(),
+            saved_params: SavedParamsPrompt::default(),
+            impersonation_prompt: ImpersonationPrompt::default(),
+            register_result: RegisterResultPrompt::default(),
+            rename_result: ResultTabRename::default(),
             source: SourceContext {
                 exec_ctx,
                 connection_dropdown,
@@ -873,16 +1032,18 @@ impl CodeDocument {
                 live_output: None,
                 _live_output_drain: None,
                 active_query_task: None,
+                error_details_expanded: false,
             },
             result_tabs: ResultTabs {
                 result_tabs: Vec::new(),
                 active_result_index: None,
                 result_tab_counter: 0,
                 run_in_new_tab: false,
+                rerun_target: None,
             },
             history: HistoryState {
                 history_modal,
-                _history_subscriptions: vec![query_selected_sub, history_closed_sub],
+                _history_subscriptions: vec![query_selected_sub, rerun_sub, history_closed_sub],
             },
             layout: SqlQueryLayout::EditorOnly,
             focus_handle: cx.focus_handle(),
@@ -893,6 +1054,8 @@ impl CodeDocument {
             refresh: RefreshState {
                 refresh_policy,
                 refresh_dropdown,
+                countdown: RefreshCountdown::default(),
+                custom_input: None,
                 _refresh_timer: None,
                 _refresh_subscriptions: vec![refresh_policy_sub],
             },
@@ -910,6 +1073,7 @@ impl CodeDocument {
             session: SessionPersistence {
                 scratch_path,
                 shadow_path: None,
+                results_path,
                 _auto_save_debounce: None,
                 show_saved_label: false,
                 _saved_label_timer: None,
@@ -965,16 +1129,85 @@ impl CodeDocument {
         cx.notify();
     }
 
+    /// Pause or resume the auto-refresh countdown without changing the policy.
+    pub fn toggle_refresh_paused(&mut self, cx: &mut Context<Self>) {
+        let paused = self.refresh.countdown.is_paused();
+        self.refresh.countdown.set_paused(!paused);
+        cx.notify();
+    }
+
+    fn open_custom_refresh_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder("45s, 2m, 1h"));
+        input.update(cx, |state, cx| state.focus(window, cx));
+
+        let subscription = cx.subscribe_in(
+            &input,
+            window,
+            |this, _, event: &InputEvent, _window, cx| match event {
+                InputEvent::PressEnter { .. } => this.apply_custom_refresh_input(cx),
+                InputEvent::Blur => this.close_custom_refresh_input(cx),
+                _ => {}
+            },
+        );
+
+        self.refresh.custom_input = Some((input, subscription));
+        cx.notify();
+    }
+
+    fn apply_custom_refresh_input(&mut self, cx: &mut Context<Self>) {
+        let Some((input, _)) = &self.refresh.custom_input else {
+            return;
+        };
+
+        let Some(every_secs) = parse_refresh_interval(&input.read(cx).value()) else {
+            Toast::warning("Enter an interval such as 45s, 2m or 1h")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        if !self.can_auto_refresh(cx) {
+            Toast::warning("Auto-refresh blocked: query modifies data")
+                .meta_right(now_hms())
+                .push(cx);
+            self.close_custom_refresh_input(cx);
+            return;
+        }
+
+        self.set_refresh_policy(RefreshPolicy::Interval { every_secs }, cx);
+        self.close_custom_refresh_input(cx);
+    }
+
+    /// Drop the custom interval input and point the dropdown back at the
+    /// active policy.
+    fn close_custom_refresh_input(&mut self, cx: &mut Context<Self>) {
+        if self.refresh.custom_input.take().is_none() {
+            return;
+        }
+
+        let index = auto_refresh_dropdown_index(self.refresh.refresh_policy);
+        self.refresh
+            .refresh_dropdown
+            .update(cx, |dd, cx| dd.set_selected_index(Some(index), cx));
+        cx.notify();
+    }
+
+    /// Restarts the countdown and ticks it once per second; the query runs
+    /// when it reaches zero.
     fn update_refresh_timer(&mut self, cx: &mut Context<Self>) {
         self.refresh._refresh_timer = None;
+        self.refresh.countdown.set_paused(false);
+        self.refresh.countdown.reset(self.refresh.refresh_policy);
 
-        let Some(duration) = self.refresh.refresh_policy.duration() else {
+        if !self.refresh.refresh_policy.is_auto() {
             return;
-        };
+        }
 
         self.refresh._refresh_timer = Some(cx.spawn(async move |this, cx| {
             loop {
-                cx.background_executor().timer(duration).await;
+                cx.background_executor()
+                    .timer(std::time::Duration::from_secs(1))
+                    .await;
 
                 let _ = cx.update(|cx| {
                     let Some(entity) = this.upgrade() else {
@@ -982,7 +1215,15 @@ impl CodeDocument {
                     };
 
                     entity.update(cx, |doc, cx| {
-                        if !doc.refresh.refresh_policy.is_auto() || doc.runner.is_primary_active() {
+                        // Hold the countdown while a run is in flight.
+                        if doc.runner.is_primary_active() {
+                            return;
+                        }
+
+                        let due = doc.refresh.countdown.tick(doc.refresh.refresh_policy);
+                        cx.notify();
+
+                        if !due {
                             return;
                         }
 
@@ -998,7 +1239,6 @@ impl CodeDocument {
                         }
 
                         doc.pending.auto_refresh = true;
-                        cx.notify();
                     });
                 });
             }
@@ -1017,6 +1257,64 @@ impl CodeDocument {
         self.refresh_editor_diagnostics(window, cx);
     }
 
+    /// Inserts `text` at the cursor, replacing the selection, and focuses the
+    /// editor. Returns `false` on read-only documents.
+    pub fn insert_at_cursor(
+        &mut self,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if self.read_only {
+            return false;
+        }
+
+        // Routed through the input handler so the edit lands on the undo
+        // stack and marks the document dirty like typing does.
+        self.editor.input_state.update(cx, |state, cx| {
+            state.replace_text_in_range(None, text, window, cx);
+            state.focus(window, cx);
+        });
+        true
+    }
+
+    /// Inserts a schema object dropped from the sidebar where the pointer
+    /// released it, or at the cursor when that is outside the text. Holding
+    /// Alt turns a dropped table into a `SELECT` template.
+    fn drop_schema_object(
+        &mut self,
+        drag: &SchemaObjectDrag,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.read_only {
+            return;
+        }
+
+        let as_select = window.modifiers().alt;
+        let Some(text) = drag.resolve(self.app_state.read(cx), as_select) else {
+            return;
+        };
+
+        let position = window.mouse_position();
+        self.editor.input_state.update(cx, |state, cx| {
+            let range = state
+                .character_index_for_point(position, window, cx)
+                .map(|offset| offset..offset);
+            state.replace_text_in_range(range, &text, window, cx);
+            state.focus(window, cx);
+        });
+        self.enter_editor_mode(cx);
+        cx.emit(DocumentEvent::RequestFocus);
+    }
+
+    /// Links the document to the saved query its content came from, so runs
+    /// prompt for that query's parameters.
+    pub fn with_saved_query(mut self, saved_query_id: Uuid) -> Self {
+        self.editor.saved_query_id = Some(saved_query_id);
+        self
+    }
+
     /// Creates document with specific title.
     pub fn with_title(mut self, title: String) -> Self {
         self.title = title;
@@ -1245,6 +1543,15 @@ impl CodeDocument {
         self.session.shadow_path.as_ref()
     }
 
+    pub fn results_path(&self) -> Option<&PathBuf> {
+        self.session.results_path.as_ref()
+    }
+
+    /// Override the kept results artifact (used during session restore).
+    pub fn set_results_path(&mut self, path: PathBuf) {
+        self.session.results_path = Some(path);
+    }
+
     /// Override session paths (used during session restore).
     pub fn set_session_paths(&mut self, scratch: Option<PathBuf>, shadow: Option<PathBuf>) {
         self.session.scratch_path = scratch;
@@ -1293,6 +1600,22 @@ impl CodeDocument {
         }
     }
 
+    /// Opens the history modal scoped to this document's connection and
+    /// database.
+    fn open_history_modal(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let database = self.source.exec_ctx.database.clone().or_else(|| {
+            self.connection_id
+                .and_then(|id| self.app_state.read(cx).connections().get(&id))
+                .and_then(|connected| connected.active_database.clone())
+        });
+        let connection_id = self.connection_id;
+
+        self.history.history_modal.update(cx, |modal, cx| {
+            modal.set_connection_context(connection_id, database);
+            modal.open(window, cx);
+        });
+    }
+
     // === Command Dispatch ===
 
     /// Route commands to the history modal when it's visible.
@@ -1342,7 +1665,13 @@ impl CodeDocument {
             Command::Rename => {
                 self.history
                     .history_modal
-                    .update(cx, |modal, cx| modal.start_rename_selected(window, cx));
+                    .update(cx, |modal, cx| modal.rename_or_rerun_selected(window, cx));
+                true
+            }
+            Command::OrganizeSavedQuery => {
+                self.history
+                    .history_modal
+                    .update(cx, |modal, cx| modal.start_organize_selected(window, cx));
                 true
             }
             Command::FocusSearch => {
@@ -1426,6 +1755,32 @@ impl CodeDocument {
                 self.run_query_in_new_tab(window, cx);
                 true
             }
+            Command::Cancel if self.find.visible => {
+                self.close_find(window, cx);
+                true
+            }
+            Command::Cancel if self.saved_params.is_visible() => {
+                self.close_saved_params_prompt(window, cx);
+                true
+            }
+            Command::Cancel if self.impersonation_prompt.is_visible() => {
+                self.close_impersonation_prompt(window, cx);
+                true
+            }
+            Command::Cancel if self.register_result.is_visible() => {
+                self.close_register_result_prompt(window, cx);
+                true
+            }
+            Command::Cancel if self.rename_result.is_visible() => {
+                self.close_rename_result_tab(window, cx);
+                true
+            }
+            Command::Cancel
+                if self.focus_mode == SqlQueryFocus::Editor && self.vim_leaves_on_escape(cx) =>
+            {
+                self.vim_escape(window, cx);
+                true
+            }
             Command::Cancel | Command::CancelQuery if self.runner.is_primary_active() => {
                 self.cancel_query(cx);
                 true
@@ -1476,9 +1831,7 @@ impl CodeDocument {
                         .history_modal
                         .update(cx, |modal, cx| modal.close(cx));
                 } else {
-                    self.history
-                        .history_modal
-                        .update(cx, |modal, cx| modal.open(window, cx));
+                    self.open_history_modal(window, cx);
                 }
                 true
             }
@@ -1502,6 +1855,33 @@ impl CodeDocument {
                 true
             }
 
+            Command::FindInEditor | Command::ReplaceInEditor => {
+                self.enter_editor_mode(cx);
+                self.open_find(cmd == Command::ReplaceInEditor, window, cx);
+                true
+            }
+            Command::FindNext => {
+                self.find_step(true, window, cx);
+                true
+            }
+            Command::FindPrevious => {
+                self.find_step(false, window, cx);
+                true
+            }
+            Command::ToggleQueryVariables => {
+                self.toggle_variables_panel(window, cx);
+                true
+            }
+            // From the palette while the editor has focus: act on the
+            // visible result grid.
+            Command::ToggleRowInspector => match self.active_result_grid() {
+                Some(grid) => {
+                    grid.update(cx, |grid, cx| grid.toggle_row_inspector(cx));
+                    true
+                }
+                None => false,
+            },
+
             _ => false,
         }
     }
@@ -181,6 +181,8 @@ impl CodeDocument {
                         file_path: None,
                         scratch_path: None,
                         shadow_path: None,
+                        results_path: None,
+                        data_state: None,
                     });
                 }
 
@@ -202,10 +204,36 @@ impl CodeDocument {
                     file_path: d.path().cloned(),
                     scratch_path: d.scratch_path().cloned(),
                     shadow_path: d.shadow_path().cloned(),
+                    results_path: d.results_path().cloned(),
+                    data_state: None,
                 })
             })
         });
 
+        // Populate optional helper: text insertion at the editor cursor.
+        handle.insert_text = Some({
+            let e = entity.clone();
+            Box::new(move |text, w, cx| e.update(cx, |d, cx| d.insert_at_cursor(text, w, cx)))
+        });
+
+        // Populate optional helper: connection/database switching.
+        handle.switch_execution_context = Some({
+            let e = entity.clone();
+            Box::new(move |profile_id, database, w, cx| {
+                e.update(cx, |d, cx| {
+                    d.switch_execution_context(profile_id, database, w, cx)
+                })
+            })
+        });
+
+        // Populate optional helper: Vim layer for keys the keymap left unbound.
+        handle.handle_unbound_keystroke = Some({
+            let e = entity.clone();
+            Box::new(move |keystroke, w, cx| {
+                e.update(cx, |d, cx| d.handle_vim_keystroke(keystroke, w, cx))
+            })
+        });
+
         handle
     }
 }
@@ -0,0 +1,210 @@
+use super::*;
+use dbflux_core::{
+    CapturedPlan, ColumnKind, ColumnMeta, PlanChange, PlanDiffRow, PlanNode, Value, diff_plans,
+    explained_statement,
+};
+use dbflux_ui_base::toast::ToastAction;
+
+impl CodeDocument {
+    /// Adds the plan returned by an EXPLAIN to the explained statement's
+    /// plan history, and warns when its shape differs from the previous
+    /// capture.
+    pub(super) fn capture_explain_plan(
+        &self,
+        query: &str,
+        result: &QueryResult,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(statement) = explained_statement(query) else {
+            return;
+        };
+        let Some(plan) = PlanNode::from_explain_result(result) else {
+            return;
+        };
+
+        let captured = CapturedPlan::new(self.connection_id, statement, plan);
+        let state = self.app_state.read(cx);
+        let previous = state
+            .captured_plans(self.connection_id, statement)
+            .into_iter()
+            .next();
+        state.add_captured_plan(&captured);
+
+        let Some(previous) = previous else {
+            return;
+        };
+
+        let shape_changed = diff_plans(&previous.plan, &captured.plan)
+            .iter()
+            .any(|row| row.change.is_structural());
+        if !shape_changed {
+            return;
+        }
+
+        let document = cx.entity().downgrade();
+        let statement = statement.to_string();
+
+        Toast::warning("Execution plan changed")
+            .meta_right(now_hms())
+            .body(format!(
+                "The plan differs from the one captured {}.",
+                previous.formatted_timestamp()
+            ))
+            .action(
+                ToastAction::new("compare-plans", "Compare")
+                    .primary()
+                    .on_click(move |cx: &mut App| {
+                        let _ = document.update(cx, |document, cx| {
+                            document.pending.plan_comparison = Some(statement.clone());
+                            cx.notify();
+                        });
+                    }),
+            )
+            .push(cx);
+    }
+
+    /// Compares the plan history of the selected (or whole) query, which
+    /// may itself be an EXPLAIN.
+    pub fn compare_plans(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let query = self.selected_or_full_query(window, cx);
+        let statement = explained_statement(&query).unwrap_or(&query).to_string();
+
+        self.open_plan_comparison(&statement, window, cx);
+    }
+
+    /// Opens a result tab comparing the two latest plans captured for
+    /// `statement`, node by node.
+    pub(super) fn open_plan_comparison(
+        &mut self,
+        statement: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let plans = self
+            .app_state
+            .read(cx)
+            .captured_plans(self.connection_id, statement);
+
+        let [latest, previous, ..] = plans.as_slice() else {
+            Toast::info("Explain this query at least twice to compare its plans")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        let result = plan_comparison_result(&diff_plans(&previous.plan, &latest.plan));
+        self.create_result_tab(Arc::new(result), statement.to_string(), window, cx);
+
+        if let Some(tab) = self.result_tabs.result_tabs.last_mut() {
+            tab.title = format!(
+                "Plan {} vs {}",
+                previous.formatted_timestamp(),
+                latest.formatted_timestamp()
+            );
+        }
+
+        if self.layout == SqlQueryLayout::EditorOnly {
+            self.layout = SqlQueryLayout::Split;
+        }
+        cx.notify();
+    }
+}
+
+/// One row per plan node, indented by depth, with the change and the
+/// estimates of both captures side by side.
+fn plan_comparison_result(rows: &[PlanDiffRow<'_>]) -> QueryResult {
+    let column = |name: &str, kind: ColumnKind| ColumnMeta {
+        name: name.to_string(),
+        type_name: match kind {
+            ColumnKind::Float => "float8".to_string(),
+            _ => "text".to_string(),
+        },
+        kind,
+        nullable: true,
+        is_primary_key: false,
+    };
+
+    let columns = vec![
+        column("node", ColumnKind::Text),
+        column("change", ColumnKind::Text),
+        column("cost_before", ColumnKind::Float),
+        column("cost_after", ColumnKind::Float),
+        column("cost_change", ColumnKind::Text),
+        column("rows_before", ColumnKind::Float),
+        column("rows_after", ColumnKind::Float),
+    ];
+
+    let estimate = |value: Option<f64>| value.map(Value::Float).unwrap_or(Value::Null);
+
+    let result_rows = rows
+        .iter()
+        .map(|row| {
+            let before_cost = row.before.and_then(|node| node.cost);
+            let after_cost = row.after.and_then(|node| node.cost);
+
+            vec![
+                Value::Text(plan_comparison_label(row)),
+                Value::Text(row.change.label().to_string()),
+                estimate(before_cost),
+                estimate(after_cost),
+                relative_change(before_cost, after_cost)
+                    .map(Value::Text)
+                    .unwrap_or(Value::Null),
+                estimate(row.before.and_then(|node| node.rows)),
+                estimate(row.after.and_then(|node| node.rows)),
+            ]
+        })
+        .collect();
+
+    QueryResult::table(columns, result_rows, None, std::time::Duration::ZERO)
+}
+
+fn plan_comparison_label(row: &PlanDiffRow<'_>) -> String {
+    let indent = "  ".repeat(row.depth);
+
+    let label = match (row.before, row.after) {
+        (Some(before), Some(after)) if row.change == PlanChange::Replaced => {
+            format!("{} → {}", before.label, after.label)
+        }
+        (_, Some(node)) | (Some(node), None) => node.label.clone(),
+        (None, None) => String::new(),
+    };
+
+    format!("{indent}{label}")
+}
+
+/// `+35%` / `-80%` between two costs.
+fn relative_change(before: Option<f64>, after: Option<f64>) -> Option<String> {
+    let (before, after) = (before?, after?);
+    if before == 0.0 {
+        return None;
+    }
+
+    Some(format!("{:+.0}%", (after - before) / before * 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparison_rows_show_replacements_and_cost_changes() {
+        let before = PlanNode::new("Sort")
+            .with_estimates(Some(100.0), Some(50.0))
+            .with_child(PlanNode::new("Seq Scan on users").with_estimates(Some(90.0), None));
+        let after = PlanNode::new("Sort")
+            .with_estimates(Some(25.0), Some(50.0))
+            .with_child(PlanNode::new("Index Scan on users").with_estimates(Some(8.0), None));
+
+        let result = plan_comparison_result(&diff_plans(&before, &after));
+
+        assert_eq!(result.rows.len(), 2);
+        assert_eq!(result.rows[0][0], Value::Text("Sort".to_string()));
+        assert_eq!(result.rows[0][4], Value::Text("-75%".to_string()));
+        assert_eq!(
+            result.rows[1][0],
+            Value::Text("  Seq Scan on users → Index Scan on users".to_string())
+        );
+        assert_eq!(result.rows[1][1], Value::Text("node changed".to_string()));
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+use std::cell::Cell;
+
+/// Text input inside one of the editor's inline prompts and panels.
+///
+/// Focus is tracked from the input's focus/blur events so `active_context`
+/// can hand typed keys to the text-input keymap instead of editor shortcuts.
+pub(super) struct PromptInput {
+    pub(super) state: Entity<InputState>,
+    focused: Rc<Cell<bool>>,
+    _subscription: Subscription,
+}
+
+impl PromptInput {
+    /// Creates the input from `build` and forwards its events to `on_event`
+    /// once the focus flag reflects them.
+    pub(super) fn new(
+        window: &mut Window,
+        cx: &mut Context<CodeDocument>,
+        build: impl FnOnce(InputState) -> InputState,
+        on_event: impl Fn(&mut CodeDocument, &InputEvent, &mut Window, &mut Context<CodeDocument>)
+        + 'static,
+    ) -> Self {
+        let state = cx.new(|cx| build(InputState::new(window, cx)));
+        let focused = Rc::new(Cell::new(false));
+
+        let subscription = cx.subscribe_in(&state, window, {
+            let focused = focused.clone();
+            move |this, _input, event: &InputEvent, window, cx| {
+                match event {
+                    InputEvent::Focus => focused.set(true),
+                    InputEvent::Blur => focused.set(false),
+                    InputEvent::Change | InputEvent::PressEnter { .. } => {}
+                }
+
+                on_event(this, event, window, cx);
+            }
+        });
+
+        Self {
+            state,
+            focused,
+            _subscription: subscription,
+        }
+    }
+
+    pub(super) fn is_focused(&self) -> bool {
+        self.focused.get()
+    }
+
+    /// Moves focus into the input, counting it as focused right away rather
+    /// than once the focus event arrives.
+    pub(super) fn focus(&self, window: &mut Window, cx: &mut App) {
+        self.state.update(cx, |state, cx| state.focus(window, cx));
+        self.focused.set(true);
+    }
+}
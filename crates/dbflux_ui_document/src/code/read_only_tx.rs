@@ -0,0 +1,33 @@
+use super::*;
+use dbflux_core::TransactionVocab;
+
+impl CodeDocument {
+    /// Transaction vocabulary of the document's connection, or `None` when
+    /// the driver does not speak SQL transactions.
+    pub(super) fn read_only_vocab(&self, cx: &App) -> Option<TransactionVocab> {
+        let conn_id = self.connection_id?;
+        let connections = self.app_state.read(cx).connections();
+        let connected = connections.get(&conn_id)?;
+
+        TransactionVocab::for_kind(connected.profile.kind())
+    }
+
+    /// Flips whether this tab's executions run inside a rolled-back
+    /// read-only transaction.
+    pub fn toggle_read_only_transactions(&mut self, cx: &mut Context<Self>) {
+        self.read_only_transactions = !self.read_only_transactions;
+        cx.notify();
+    }
+
+    pub(super) fn read_only_transactions_tooltip(&self, cx: &App) -> &'static str {
+        let rejects_writes = self
+            .read_only_vocab(cx)
+            .is_some_and(|vocab| vocab.read_only_rejects_writes);
+
+        match (self.read_only_transactions, rejects_writes) {
+            (true, true) => "Read-only transactions on: writes are rejected",
+            (true, false) => "Read-only transactions on: changes are rolled back",
+            (false, _) => "Run queries in a read-only transaction",
+        }
+    }
+}
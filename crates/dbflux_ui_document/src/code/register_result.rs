@@ -0,0 +1,318 @@
+use super::*;
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant};
+use dbflux_components::primitives::Text;
+use dbflux_core::{normalize_temp_table_name, temp_table_statements};
+
+/// Name prompt that registers a result tab as a session temp table.
+///
+/// Confirming writes the tab's rows into a temporary table on the document's
+/// connection so later queries can select from it by name without re-running
+/// the statement that produced it.
+#[derive(Default)]
+pub(super) struct RegisterResultPrompt {
+    pub(super) tab_id: Option<Uuid>,
+    pub(super) input: Option<PromptInput>,
+    pub(super) registering: bool,
+}
+
+impl RegisterResultPrompt {
+    pub(super) fn is_visible(&self) -> bool {
+        self.tab_id.is_some()
+    }
+}
+
+impl CodeDocument {
+    /// Whether the document's connection can hold registered results.
+    pub(super) fn supports_result_registration(&self, cx: &App) -> bool {
+        self.connection_id
+            .and_then(|id| self.app_state.read(cx).connections().get(&id))
+            .is_some_and(|connected| {
+                connected
+                    .connection
+                    .metadata()
+                    .capabilities
+                    .contains(DriverCapabilities::TEMP_TABLES)
+            })
+    }
+
+    /// Opens the name prompt for the active result tab, pre-filled with its
+    /// current registration or a name derived from the tab title.
+    pub(super) fn open_register_result_prompt(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(tab) = self
+            .result_tabs
+            .active_result_index
+            .and_then(|index| self.result_tabs.result_tabs.get(index))
+        else {
+            return;
+        };
+
+        if !self.supports_result_registration(cx) {
+            Toast::warning("This connection does not support temp tables")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        let tab_id = tab.id;
+        let initial = tab
+            .registered_as
+            .clone()
+            .unwrap_or_else(|| tab.title.to_lowercase().replace(' ', "_"));
+
+        let input = PromptInput::new(
+            window,
+            cx,
+            |state| state.placeholder("temp_table_name").default_value(initial),
+            |this, event, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.confirm_register_result(window, cx);
+                }
+            },
+        );
+        input.focus(window, cx);
+
+        self.register_result = RegisterResultPrompt {
+            tab_id: Some(tab_id),
+            input: Some(input),
+            registering: false,
+        };
+
+        cx.notify();
+    }
+
+    /// Materializes the prompted tab's rows as a temp table, replacing an
+    /// earlier registration under the same name.
+    pub(super) fn confirm_register_result(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.register_result.registering {
+            return;
+        }
+
+        let Some(tab_id) = self.register_result.tab_id else {
+            return;
+        };
+
+        let Some(input) = self.register_result.input.as_ref() else {
+            return;
+        };
+
+        let name = match normalize_temp_table_name(&input.state.read(cx).value()) {
+            Ok(name) => name,
+            Err(error) => {
+                Toast::warning(error.to_string())
+                    .meta_right(now_hms())
+                    .push(cx);
+                return;
+            }
+        };
+
+        let Some(tab) = self
+            .result_tabs
+            .result_tabs
+            .iter()
+            .find(|tab| tab.id == tab_id)
+        else {
+            self.close_register_result_prompt(window, cx);
+            return;
+        };
+
+        let result = tab.grid.read(cx).result().clone();
+
+        let Some(conn_id) = self.connection_id else {
+            Toast::error("No active connection")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        let resolved = {
+            let connections = self.app_state.read(cx).connections();
+            connections.get(&conn_id).map(|connected| {
+                let database = self
+                    .source
+                    .exec_ctx
+                    .database
+                    .clone()
+                    .or_else(|| connected.active_database.clone());
+
+                connected
+                    .resolve_connection_for_execution(database.as_deref())
+                    .map(|connection| (connection, database))
+            })
+        };
+
+        let (connection, database) = match resolved {
+            Some(Ok(resolved)) => resolved,
+            Some(Err(dbflux_core::ConnectionResolutionError::PendingDatabaseConnection {
+                database,
+            })) => {
+                Toast::error(format!(
+                    "Connecting to database '{}', please wait...",
+                    database
+                ))
+                .meta_right(now_hms())
+                .push(cx);
+                return;
+            }
+            None => {
+                Toast::error("Connection not found")
+                    .meta_right(now_hms())
+                    .push(cx);
+                return;
+            }
+        };
+
+        let session = self.tab_session(connection.kind());
+
+        // The temp table would only ever exist inside a rolled-back transaction.
+        if session.is_read_only() {
+            Toast::warning("Turn off read-only transactions to register a result")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        let statements = match temp_table_statements(connection.dialect(), &name, &result) {
+            Ok(statements) => statements,
+            Err(error) => {
+                Toast::warning(error.to_string())
+                    .meta_right(now_hms())
+                    .push(cx);
+                return;
+            }
+        };
+
+        let identifier = connection.dialect().temp_table_identifier(&name);
+        let row_count = result.row_count();
+
+        self.register_result.registering = true;
+        cx.notify();
+
+        let task = cx.background_executor().spawn(async move {
+            for sql in statements {
+                session.execute(
+                    &*connection,
+                    &QueryRequest::new(sql).with_database(database.clone()),
+                )?;
+            }
+            Ok::<(), DbError>(())
+        });
+
+        cx.spawn_in(window, async move |this, cx| {
+            let outcome = task.await;
+
+            this.update_in(cx, |doc, window, cx| {
+                doc.register_result.registering = false;
+
+                match outcome {
+                    Ok(()) => {
+                        doc.mark_result_registered(tab_id, &identifier);
+                        doc.close_register_result_prompt(window, cx);
+
+                        Toast::info(format!(
+                            "Registered {} row{} as {}",
+                            row_count,
+                            if row_count == 1 { "" } else { "s" },
+                            identifier
+                        ))
+                        .meta_right(now_hms())
+                        .push(cx);
+                    }
+                    Err(error) => {
+                        let message = format!("Failed to register result: {error}");
+                        Toast::error(message.clone())
+                            .meta_right(now_hms())
+                            .action(copy_action(message))
+                            .push(cx);
+                        cx.notify();
+                    }
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    /// Records `identifier` on the tab and clears it from any other tab,
+    /// since re-registering a name replaces the earlier temp table.
+    fn mark_result_registered(&mut self, tab_id: Uuid, identifier: &str) {
+        for tab in &mut self.result_tabs.result_tabs {
+            if tab.id == tab_id {
+                tab.registered_as = Some(identifier.to_string());
+            } else if tab.registered_as.as_deref() == Some(identifier) {
+                tab.registered_as = None;
+            }
+        }
+    }
+
+    pub(super) fn close_register_result_prompt(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.register_result = RegisterResultPrompt::default();
+        self.editor
+            .input_state
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    pub(super) fn render_register_result_prompt(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let registering = self.register_result.registering;
+
+        div()
+            .id("register-result-prompt")
+            .flex()
+            .items_center()
+            .flex_shrink_0()
+            .gap(Spacing::SM)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .border_b_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .child(Text::label("Register as temp table"))
+            .when_some(self.register_result.input.as_ref(), |el, input| {
+                el.child(
+                    div()
+                        .w(px(260.0)) // guardrail-allow: name input width, not a height token
+                        .child(Input::new(&input.state).small()),
+                )
+            })
+            .child(
+                Text::caption(if registering {
+                    "Writing rows…"
+                } else {
+                    "Later queries on this connection can select from it"
+                })
+                .muted_foreground(),
+            )
+            .child(div().flex_1())
+            .child(
+                ToolbarButton::new("register-result-confirm-btn")
+                    .icon(if registering {
+                        AppIcon::Loader
+                    } else {
+                        AppIcon::Link2
+                    })
+                    .label("Register")
+                    .variant(ToolbarButtonVariant::Primary)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.confirm_register_result(window, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("register-result-close-btn")
+                    .icon(AppIcon::X)
+                    .tooltip("Cancel")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.close_register_result_prompt(window, cx);
+                    })),
+            )
+    }
+}
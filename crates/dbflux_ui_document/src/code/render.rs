@@ -2,18 +2,50 @@ use super::*;
 use crate::chrome::{ToolbarButton, ToolbarButtonVariant, compact_top_bar};
 use dbflux_components::composites::split_toolbar_action;
 use dbflux_components::controls::Button;
+use dbflux_components::density;
 use dbflux_components::helpers::text_color_for_active;
 use dbflux_components::modals::shell::{ModalShell, ModalVariant};
 use dbflux_components::primitives::{
     Badge, BadgeVariant, BannerBlock, BannerVariant, Icon, Text, focus_frame,
 };
+use dbflux_core::ErrorSeverity;
 use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use gpui_component::scroll::ScrollableElement;
+use gpui_component::tooltip::Tooltip;
 
 fn code_pane_is_focused(focus_mode: SqlQueryFocus, pane: SqlQueryFocus) -> bool {
     focus_mode == pane
 }
 
+/// Labelled lines shown when the error view is expanded: the driver's
+/// detail and hint, then where the error happened.
+fn error_detail_rows(details: &FormattedError) -> Vec<(&'static str, String)> {
+    let mut rows = Vec::new();
+
+    if let Some(detail) = &details.detail {
+        rows.push(("Detail", detail.clone()));
+    }
+    if let Some(hint) = &details.hint {
+        rows.push(("Hint", hint.clone()));
+    }
+
+    if let Some(location) = &details.location {
+        let fields = [
+            ("Schema", &location.schema),
+            ("Table", &location.table),
+            ("Column", &location.column),
+            ("Constraint", &location.constraint),
+        ];
+        for (label, value) in fields {
+            if let Some(value) = value {
+                rows.push((label, value.clone()));
+            }
+        }
+    }
+
+    rows
+}
+
 impl CodeDocument {
     fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = cx.theme().clone();
@@ -23,11 +55,9 @@ impl CodeDocument {
         let is_read_only = self.read_only;
 
         let auto_refresh_enabled = self.refresh.refresh_policy.is_auto();
-        let refresh_label = if auto_refresh_enabled {
-            self.refresh.refresh_policy.label()
-        } else {
-            "Refresh"
-        };
+        let refresh_countdown = &self.refresh.countdown;
+        let refresh_paused = refresh_countdown.is_paused();
+        let refresh_label = refresh_countdown.label(self.refresh.refresh_policy);
         let refresh_icon = if is_executing {
             AppIcon::Loader
         } else if auto_refresh_enabled {
@@ -144,6 +174,40 @@ impl CodeDocument {
                     cx,
                 ))
             })
+            .when(
+                !is_read_only && is_db_language && refresh_countdown.is_running(),
+                |el| {
+                    el.child(
+                        ToolbarButton::new("sql-refresh-pause-btn")
+                            .icon(if refresh_paused {
+                                AppIcon::Play
+                            } else {
+                                AppIcon::Pause
+                            })
+                            .tooltip(if refresh_paused {
+                                "Resume auto-refresh"
+                            } else {
+                                "Pause auto-refresh"
+                            })
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.toggle_refresh_paused(cx);
+                            })),
+                    )
+                },
+            )
+            .when_some(
+                self.refresh
+                    .custom_input
+                    .as_ref()
+                    .map(|(input, _)| input.clone()),
+                |el, input| {
+                    el.child(
+                        div()
+                            .w(px(96.0)) // guardrail-allow: fits a short interval such as "90s"
+                            .child(Input::new(&input).small()),
+                    )
+                },
+            )
             .child(div().flex_1())
             .when_some(execution_time, |el, duration| {
                 el.child(Text::caption(format!("{:.2}s", duration.as_secs_f64())))
@@ -153,7 +217,8 @@ impl CodeDocument {
             })
     }
 
-    /// Renders the secondary action buttons: Save, Format, History, Explain, Chart.
+    /// Renders the secondary action buttons: Save, Format, History, Variables,
+    /// Explain, Compare plans, Chart.
     ///
     /// All mutating or execution buttons are hidden when `is_read_only` is true.
     fn render_secondary_actions(
@@ -204,13 +269,22 @@ impl CodeDocument {
                                     .history_modal
                                     .update(cx, |modal, cx| modal.close(cx));
                             } else {
-                                this.history
-                                    .history_modal
-                                    .update(cx, |modal, cx| modal.open(window, cx));
+                                this.open_history_modal(window, cx);
                             }
                         })),
                 )
             })
+            // Variables button — hidden for read-only documents
+            .when(!is_read_only && is_db_language, |el| {
+                el.child(
+                    ToolbarButton::new("toolbar-variables-btn")
+                        .icon(AppIcon::Braces)
+                        .tooltip("Query variables")
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.toggle_variables_panel(window, cx);
+                        })),
+                )
+            })
             // Explain button — hidden for read-only documents
             .when(!is_read_only && is_db_language, |el| {
                 el.child(
@@ -222,6 +296,50 @@ impl CodeDocument {
                         })),
                 )
             })
+            // Plan comparison button — hidden for read-only documents
+            .when(!is_read_only && is_db_language, |el| {
+                el.child(
+                    ToolbarButton::new("toolbar-compare-plans-btn")
+                        .icon(AppIcon::Layers)
+                        .tooltip("Compare with previous plan")
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.compare_plans(window, cx);
+                        })),
+                )
+            })
+            // Read-only transaction toggle — only for drivers with SQL transactions
+            .when(
+                !is_read_only && is_db_language && self.read_only_vocab(cx).is_some(),
+                |el| {
+                    el.child(
+                        ToolbarButton::new("toolbar-read-only-tx-btn")
+                            .icon(AppIcon::Lock)
+                            .variant(if self.read_only_transactions {
+                                ToolbarButtonVariant::Default
+                            } else {
+                                ToolbarButtonVariant::Ghost
+                            })
+                            .tooltip(self.read_only_transactions_tooltip(cx))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_read_only_transactions(cx);
+                            })),
+                    )
+                },
+            )
+            // Impersonation button — only for drivers that can switch roles
+            .when(
+                !is_read_only && is_db_language && self.role_impersonation(cx).is_some(),
+                |el| {
+                    el.child(
+                        ToolbarButton::new("toolbar-impersonate-btn")
+                            .icon(AppIcon::KeyRound)
+                            .tooltip("Run queries as another role")
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.open_impersonation_prompt(window, cx);
+                            })),
+                    )
+                },
+            )
             // Chart button — hidden for read-only documents
             .when(!is_read_only, |el| {
                 el.child(
@@ -287,20 +405,51 @@ impl CodeDocument {
                     })
                     .detach();
                 }))
+                .when(self.find.visible, |el| el.child(self.render_find_bar(cx)))
+                .when(self.variables.visible, |el| {
+                    el.child(self.render_variables_panel(cx))
+                })
+                .when(self.saved_params.is_visible(), |el| {
+                    el.child(self.render_saved_params_prompt(cx))
+                })
+                .when(self.impersonation_prompt.is_visible(), |el| {
+                    el.child(self.render_impersonation_prompt(cx))
+                })
                 .child(
-                    div().flex_1().min_h_0().overflow_hidden().child(
-                        Input::new(&self.editor.input_state)
-                            .appearance(false)
-                            .w_full()
-                            .h_full()
-                            // Propagate read-only into the Input component so the
-                            // underlying InputState has its `disabled` flag set
-                            // during render. This prevents all text-mutating actions
-                            // (backspace, delete, paste, undo, redo) from being
-                            // bound while keeping selection and copy fully functional.
-                            .disabled(self.read_only),
-                    ),
-                ),
+                    div()
+                        .flex_1()
+                        .min_h_0()
+                        .overflow_hidden()
+                        .when(!self.read_only, |el| {
+                            el.drag_over::<SchemaObjectDrag>(move |style, _, _, _| {
+                                style.bg(accent.opacity(0.08))
+                            })
+                            .on_drop(cx.listener(
+                                |this, drag: &SchemaObjectDrag, window, cx| {
+                                    this.drop_schema_object(drag, window, cx);
+                                },
+                            ))
+                        })
+                        .child(
+                            Input::new(&self.editor.input_state)
+                                .appearance(false)
+                                .w_full()
+                                .h_full()
+                                .text_size(density::zoomed(cx, density::editor_font_size(cx)))
+                                // Propagate read-only into the Input component so the
+                                // underlying InputState has its `disabled` flag set
+                                // during render. This prevents all text-mutating actions
+                                // (backspace, delete, paste, undo, redo) from being
+                                // bound while keeping selection and copy fully functional.
+                                .disabled(self.read_only),
+                        )
+                        .when(self.find.visible, |el| {
+                            el.child(self.render_find_highlights(cx))
+                        }),
+                )
+                .when(self.vim_enabled(cx), |el| {
+                    el.child(self.render_vim_status(cx))
+                }),
             cx,
         )
         .size_full()
@@ -316,7 +465,7 @@ impl CodeDocument {
             .execution
             .active_execution_index
             .and_then(|i| self.execution.execution_history.get(i))
-            .and_then(|r| r.error.clone());
+            .and_then(|r| r.error.clone().map(|e| (e, r.error_details.clone())));
 
         let has_error = error.is_some();
         let has_live_output = self.execution.live_output.is_some() && !has_error;
@@ -334,12 +483,17 @@ impl CodeDocument {
                 .min_h_0()
                 .bg(bg)
                 .when(has_tabs, |el| el.child(self.render_results_header(cx)))
+                .when(has_tabs && self.register_result.is_visible(), |el| {
+                    el.child(self.render_register_result_prompt(cx))
+                })
                 .child(
                     div()
                         .flex_1()
                         .min_h_0()
                         .overflow_hidden()
-                        .when_some(error, |el, err| el.child(self.render_error_state(&err, cx)))
+                        .when_some(error, |el, (err, details)| {
+                            el.child(self.render_error_state(&err, details.as_ref(), cx))
+                        })
                         .when(has_live_output, |el| el.child(self.render_live_output(cx)))
                         .when(!has_live_output, |el| {
                             el.when_some(active_panel, |el, panel| el.child(panel))
@@ -443,6 +597,7 @@ impl CodeDocument {
                             .map(|(i, tab)| {
                                 let is_active = active_index == Some(i);
                                 let tab_id = tab.id;
+                                let is_renaming = self.rename_result.tab_id == Some(tab_id);
 
                                 div()
                                     .id(ElementId::Name(format!("result-tab-{}", tab.id).into()))
@@ -457,13 +612,38 @@ impl CodeDocument {
                                     .when(!is_active, |el| {
                                         el.hover(|d| d.bg(theme.secondary.opacity(0.5)))
                                     })
-                                    .on_click(cx.listener(move |this, _, _, cx| {
-                                        this.activate_result_tab(i, cx);
-                                    }))
-                                    .child(
-                                        Text::caption(tab.title.clone())
-                                            .color(text_color_for_active(is_active, theme)),
-                                    )
+                                    .on_click(cx.listener(
+                                        move |this, event: &ClickEvent, window, cx| {
+                                            this.activate_result_tab(i, cx);
+
+                                            if event.click_count() == 2 {
+                                                this.start_rename_result_tab(tab_id, window, cx);
+                                            }
+                                        },
+                                    ))
+                                    .when(tab.pinned, |el| {
+                                        el.child(
+                                            Icon::new(AppIcon::Pin).size(px(12.0)).muted(), // guardrail-allow: 12px icon size, no ICON_XS token
+                                        )
+                                    })
+                                    .map(|el| match self.render_result_tab_rename_input() {
+                                        Some(input) if is_renaming => el.child(input),
+                                        _ => el.child(
+                                            Text::caption(tab.title.clone())
+                                                .color(text_color_for_active(is_active, theme)),
+                                        ),
+                                    })
+                                    .when(tab.keep, |el| {
+                                        el.child(
+                                            Icon::new(AppIcon::Save).size(px(12.0)).muted(), // guardrail-allow: 12px icon size, no ICON_XS token
+                                        )
+                                    })
+                                    .when_some(tab.registered_as.clone(), |el, name| {
+                                        el.child(
+                                            Icon::new(AppIcon::Link2).size(px(12.0)).muted(), // guardrail-allow: 12px icon size, no ICON_XS token
+                                        )
+                                        .child(Text::caption(name).muted_foreground())
+                                    })
                                     .child(
                                         div()
                                             .id(ElementId::Name(
@@ -489,13 +669,105 @@ impl CodeDocument {
     }
 
     fn render_results_controls(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let can_register = self.supports_result_registration(cx);
         let theme = cx.theme();
         let is_maximized = self.results_maximized;
+        let active_tab = self
+            .result_tabs
+            .active_result_index
+            .and_then(|index| self.result_tabs.result_tabs.get(index))
+            .map(|tab| (tab.id, tab.pinned, tab.keep));
 
         div()
             .flex()
             .items_center()
             .gap_1()
+            .when_some(active_tab, |el, (tab_id, pinned, keep)| {
+                el.child(
+                    div()
+                        .id("pin-result-tab")
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .size_6()
+                        .rounded(Radii::SM)
+                        .cursor_pointer()
+                        .when(pinned, |el| el.bg(theme.secondary))
+                        .hover(|d| d.bg(theme.secondary))
+                        .tooltip(move |window, cx| {
+                            Tooltip::new(if pinned {
+                                "Unpin result"
+                            } else {
+                                "Pin result (next run opens a new tab)"
+                            })
+                            .build(window, cx)
+                        })
+                        .on_click(cx.listener(move |this, _, _, cx| {
+                            this.toggle_result_tab_pinned(tab_id, cx);
+                        }))
+                        .child(Icon::new(AppIcon::Pin).size(px(14.0)).muted()),
+                )
+                .child(
+                    div()
+                        .id("rename-result-tab")
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .size_6()
+                        .rounded(Radii::SM)
+                        .cursor_pointer()
+                        .hover(|d| d.bg(theme.secondary))
+                        .tooltip(|window, cx| Tooltip::new("Rename result").build(window, cx))
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            this.start_rename_result_tab(tab_id, window, cx);
+                        }))
+                        .child(Icon::new(AppIcon::Pencil).size(px(14.0)).muted()),
+                )
+                .child(
+                    div()
+                        .id("keep-result-tab")
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .size_6()
+                        .rounded(Radii::SM)
+                        .cursor_pointer()
+                        .when(keep, |el| el.bg(theme.secondary))
+                        .hover(|d| d.bg(theme.secondary))
+                        .tooltip(move |window, cx| {
+                            Tooltip::new(if keep {
+                                "Stop keeping results"
+                            } else {
+                                "Keep results across restarts"
+                            })
+                            .build(window, cx)
+                        })
+                        .on_click(cx.listener(move |this, _, _, cx| {
+                            this.toggle_result_tab_kept(tab_id, cx);
+                        }))
+                        .child(Icon::new(AppIcon::Save).size(px(14.0)).muted()),
+                )
+            })
+            .when(can_register, |el| {
+                el.child(
+                    div()
+                        .id("register-result-as-temp-table")
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .size_6()
+                        .rounded(Radii::SM)
+                        .cursor_pointer()
+                        .hover(|d| d.bg(theme.secondary))
+                        .tooltip(|window, cx| {
+                            Tooltip::new("Register as temp table").build(window, cx)
+                        })
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.open_register_result_prompt(window, cx);
+                        }))
+                        .child(Icon::new(AppIcon::Link2).size(px(14.0)).muted()),
+                )
+            })
             .child(
                 div()
                     .id("toggle-maximize-results")
@@ -588,13 +860,110 @@ impl CodeDocument {
         )
     }
 
-    fn render_error_state(&self, error: &str, _cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_error_state(
+        &self,
+        error: &str,
+        details: Option<&FormattedError>,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
         let icon = Icon::new(AppIcon::CircleX).size(Heights::ICON_SM);
-        div().p(Spacing::MD).size_full().overflow_y_hidden().child(
-            BannerBlock::new(BannerVariant::Danger, "Query Error")
-                .with_icon(icon)
-                .with_pre(error.to_string()),
-        )
+        let container = div()
+            .p(Spacing::MD)
+            .size_full()
+            .flex()
+            .flex_col()
+            .gap(Spacing::SM)
+            .overflow_y_hidden();
+
+        // Errors without structured fields (scripts, IPC drivers) keep the
+        // flattened message.
+        let Some(details) = details else {
+            return container.child(
+                BannerBlock::new(BannerVariant::Danger, "Query Error")
+                    .with_icon(icon)
+                    .with_pre(error.to_string()),
+            );
+        };
+
+        let badges = div()
+            .flex()
+            .flex_wrap()
+            .gap(Spacing::XS)
+            .when_some(details.severity, |el, severity| {
+                let variant = match severity {
+                    ErrorSeverity::Warning => BadgeVariant::Warning,
+                    ErrorSeverity::Error | ErrorSeverity::Fatal => BadgeVariant::Danger,
+                };
+                el.child(Badge::new(severity.label(), variant))
+            })
+            .when_some(details.code.clone(), |el, code| {
+                el.child(Badge::new(code, BadgeVariant::Neutral))
+            })
+            .when_some(details.sqlstate.clone(), |el, sqlstate| {
+                el.child(Badge::new(
+                    format!("SQLSTATE {}", sqlstate),
+                    BadgeVariant::Neutral,
+                ))
+            });
+
+        let rows = error_detail_rows(details);
+        let expanded = self.execution.error_details_expanded;
+
+        container
+            .child(
+                BannerBlock::new(BannerVariant::Danger, "Query Error")
+                    .with_icon(icon)
+                    .with_body(details.message.clone())
+                    .with_actions(badges),
+            )
+            .when(!rows.is_empty(), |el| {
+                el.child(
+                    div()
+                        .id("query-error-details-toggle")
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::XS)
+                        .cursor_pointer()
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.execution.error_details_expanded =
+                                !this.execution.error_details_expanded;
+                            cx.notify();
+                        }))
+                        .child(
+                            Icon::new(if expanded {
+                                AppIcon::ChevronDown
+                            } else {
+                                AppIcon::ChevronRight
+                            })
+                            .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                            .muted(),
+                        )
+                        .child(
+                            Text::caption(if expanded {
+                                "Hide details"
+                            } else {
+                                "Show details"
+                            })
+                            .muted_foreground(),
+                        ),
+                )
+                .when(expanded, |el| {
+                    el.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap(Spacing::XS)
+                            .pl(Spacing::MD)
+                            .children(rows.into_iter().map(|(label, value)| {
+                                div()
+                                    .flex()
+                                    .gap(Spacing::SM)
+                                    .child(Text::caption(label).muted_foreground())
+                                    .child(Text::body_sm(value))
+                            })),
+                    )
+                })
+            })
     }
 
     fn render_empty_results(&self, _cx: &mut Context<Self>) -> impl IntoElement {
@@ -769,6 +1138,12 @@ impl Render for CodeDocument {
 
         self.process_pending_set_query(window, cx);
 
+        self.process_pending_rerun_request(window, cx);
+
+        self.process_pending_rerun(window, cx);
+
+        self.process_restored_result_rerun(window, cx);
+
         self.process_pending_auto_refresh(window, cx);
 
         if std::mem::take(&mut self.pending.history_focus_restore) {
@@ -777,6 +1152,10 @@ impl Render for CodeDocument {
 
         self.process_pending_drift_continue(window, cx);
 
+        if let Some(statement) = self.pending.plan_comparison.take() {
+            self.open_plan_comparison(&statement, window, cx);
+        }
+
         if let Some((start_value, end_value)) = self.pending.source_input_values.take() {
             // Each `set_value` emits an `InputEvent::Change`; mark both as
             // seed-originated so the subscription handler skips them.
@@ -922,8 +1301,9 @@ impl Render for CodeDocument {
 
 #[cfg(test)]
 mod tests {
-    use super::code_pane_is_focused;
+    use super::{code_pane_is_focused, error_detail_rows};
     use crate::code::SqlQueryFocus;
+    use dbflux_core::{ErrorLocation, FormattedError};
 
     #[test]
     fn editor_focus_shell_tracks_editor_mode_only() {
@@ -948,4 +1328,24 @@ mod tests {
             SqlQueryFocus::Results,
         ));
     }
+
+    #[test]
+    fn error_detail_rows_list_detail_hint_and_location() {
+        let details = FormattedError::new("duplicate key value")
+            .with_detail("Key (id)=(1) already exists.")
+            .with_hint("Use ON CONFLICT")
+            .with_location(
+                ErrorLocation::new()
+                    .with_table("users")
+                    .with_constraint("users_pkey"),
+            );
+
+        let labels: Vec<_> = error_detail_rows(&details)
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect();
+        assert_eq!(labels, ["Detail", "Hint", "Table", "Constraint"]);
+
+        assert!(error_detail_rows(&FormattedError::new("boom")).is_empty());
+    }
 }
@@ -0,0 +1,307 @@
+use super::*;
+use crate::DataSource;
+use dbflux_core::{KeptResultTab, KeptResults};
+
+/// Inline title editor for a result tab.
+#[derive(Default)]
+pub(super) struct ResultTabRename {
+    pub(super) tab_id: Option<Uuid>,
+    pub(super) input: Option<PromptInput>,
+}
+
+impl ResultTabRename {
+    pub(super) fn is_visible(&self) -> bool {
+        self.tab_id.is_some()
+    }
+}
+
+impl CodeDocument {
+    fn result_tab_mut(&mut self, tab_id: Uuid) -> Option<&mut ResultTab> {
+        self.result_tabs
+            .result_tabs
+            .iter_mut()
+            .find(|tab| tab.id == tab_id)
+    }
+
+    /// Pinned tabs are never reused by the next run; it opens a new tab instead.
+    pub(super) fn toggle_result_tab_pinned(&mut self, tab_id: Uuid, cx: &mut Context<Self>) {
+        let Some(tab) = self.result_tab_mut(tab_id) else {
+            return;
+        };
+
+        tab.pinned = !tab.pinned;
+        self.persist_kept_results(cx);
+
+        cx.notify();
+    }
+
+    /// Kept tabs are written to the session and restored on next launch.
+    pub(super) fn toggle_result_tab_kept(&mut self, tab_id: Uuid, cx: &mut Context<Self>) {
+        let Some(tab) = self.result_tab_mut(tab_id) else {
+            return;
+        };
+
+        tab.keep = !tab.keep;
+        let kept = tab.keep;
+        let row_count = tab.grid.read(cx).result().row_count();
+
+        self.persist_kept_results(cx);
+
+        if kept {
+            Toast::info(format!(
+                "Keeping {} row{} with the session",
+                row_count,
+                if row_count == 1 { "" } else { "s" }
+            ))
+            .meta_right(now_hms())
+            .push(cx);
+        }
+
+        cx.notify();
+    }
+
+    pub(super) fn start_rename_result_tab(
+        &mut self,
+        tab_id: Uuid,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(title) = self
+            .result_tabs
+            .result_tabs
+            .iter()
+            .find(|tab| tab.id == tab_id)
+            .map(|tab| tab.title.clone())
+        else {
+            return;
+        };
+
+        let input = PromptInput::new(
+            window,
+            cx,
+            |state| state.placeholder("Result name").default_value(title),
+            |this, event, window, cx| match event {
+                InputEvent::PressEnter { .. } | InputEvent::Blur => {
+                    this.confirm_rename_result_tab(window, cx);
+                }
+                InputEvent::Focus | InputEvent::Change => {}
+            },
+        );
+        input.focus(window, cx);
+
+        self.rename_result = ResultTabRename {
+            tab_id: Some(tab_id),
+            input: Some(input),
+        };
+
+        cx.notify();
+    }
+
+    /// Applies the edited title; a blank title keeps the previous one.
+    pub(super) fn confirm_rename_result_tab(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(tab_id) = self.rename_result.tab_id else {
+            return;
+        };
+
+        let title = self
+            .rename_result
+            .input
+            .as_ref()
+            .map(|input| input.state.read(cx).value().trim().to_string())
+            .unwrap_or_default();
+
+        if !title.is_empty()
+            && let Some(tab) = self.result_tab_mut(tab_id)
+        {
+            tab.title = title;
+            let persisted = tab.keep || tab.pinned;
+
+            if persisted {
+                self.persist_kept_results(cx);
+            }
+        }
+
+        self.close_rename_result_tab(window, cx);
+    }
+
+    pub(super) fn close_rename_result_tab(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let had_focus = self
+            .rename_result
+            .input
+            .as_ref()
+            .is_some_and(PromptInput::is_focused);
+        self.rename_result = ResultTabRename::default();
+
+        if had_focus {
+            self.editor
+                .input_state
+                .update(cx, |state, cx| state.focus(window, cx));
+        }
+
+        cx.notify();
+    }
+
+    /// Writes the kept and pinned tabs to the document's results artifact, or
+    /// removes the artifact once there are none. Pinned tabs that are not kept
+    /// are written without their rows.
+    pub(super) fn persist_kept_results(&self, cx: &mut Context<Self>) {
+        let Some(target) = self.session.results_path.clone() else {
+            return;
+        };
+
+        let tabs: Vec<KeptResultTab> = self
+            .result_tabs
+            .result_tabs
+            .iter()
+            .filter(|tab| tab.keep || tab.pinned)
+            .filter_map(|tab| {
+                let grid = tab.grid.read(cx);
+                let query = match grid.source() {
+                    DataSource::QueryResult { original_query, .. } => original_query.clone(),
+                    _ => String::new(),
+                };
+
+                if tab.keep {
+                    Some(KeptResultTab::from_result(
+                        tab.title.clone(),
+                        tab.pinned,
+                        query,
+                        grid.result(),
+                    ))
+                } else if !query.trim().is_empty() {
+                    Some(KeptResultTab::from_query(tab.title.clone(), query))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let content = if tabs.is_empty() {
+            None
+        } else {
+            match KeptResults::new(tabs).to_json() {
+                Ok(json) => Some(json),
+                Err(error) => {
+                    log::error!("Failed to serialize kept results: {error}");
+                    return;
+                }
+            }
+        };
+
+        cx.background_executor()
+            .spawn(async move {
+                let outcome = match content {
+                    Some(json) => std::fs::write(&target, json),
+                    None => match std::fs::remove_file(&target) {
+                        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                        other => other,
+                    },
+                };
+
+                if let Err(error) = outcome {
+                    log::error!("Failed to write kept results {}: {error}", target.display());
+                }
+            })
+            .detach();
+    }
+
+    /// Recreates the result tabs kept or pinned in a previous session. Called
+    /// once during session restore, before the document has run anything.
+    pub fn restore_kept_results(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(path) = self.session.results_path.as_ref() else {
+            return;
+        };
+
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return;
+        };
+
+        let kept = match KeptResults::from_json(&content) {
+            Ok(kept) => kept,
+            Err(error) => {
+                log::warn!("Ignoring kept results {}: {error}", path.display());
+                return;
+            }
+        };
+
+        if kept.tabs.is_empty() {
+            return;
+        }
+
+        for kept_tab in kept.tabs {
+            let result = Arc::new(kept_tab.to_result());
+            self.create_result_tab(result, kept_tab.query, window, cx);
+
+            if let Some(tab) = self.result_tabs.result_tabs.last_mut() {
+                tab.title = kept_tab.title;
+                tab.pinned = kept_tab.pinned;
+                tab.keep = !kept_tab.rerun;
+                tab.rerun_pending = kept_tab.rerun;
+            }
+        }
+
+        self.layout = SqlQueryLayout::Split;
+        cx.notify();
+    }
+
+    /// Re-runs a restored pinned tab the first time it is shown, once the
+    /// document's connection is up. Its result replaces the empty tab.
+    pub(super) fn process_restored_result_rerun(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.state == DocumentState::Executing || self.layout == SqlQueryLayout::EditorOnly {
+            return;
+        }
+
+        let Some(index) = self.result_tabs.active_result_index else {
+            return;
+        };
+
+        let Some(tab) = self.result_tabs.result_tabs.get(index) else {
+            return;
+        };
+
+        if !tab.rerun_pending {
+            return;
+        }
+
+        let connected = self
+            .connection_id
+            .is_some_and(|id| self.app_state.read(cx).connections().contains_key(&id));
+
+        if !connected {
+            return;
+        }
+
+        let tab_id = tab.id;
+        let query = match tab.grid.read(cx).source() {
+            DataSource::QueryResult { original_query, .. } => original_query.clone(),
+            _ => String::new(),
+        };
+
+        self.result_tabs.result_tabs[index].rerun_pending = false;
+
+        if query.trim().is_empty() {
+            return;
+        }
+
+        self.result_tabs.rerun_target = Some(tab_id);
+        self.run_query_text(query, false, window, cx);
+    }
+
+    pub(super) fn render_result_tab_rename_input(&self) -> Option<impl IntoElement> {
+        let input = self.rename_result.input.as_ref()?;
+
+        Some(
+            div()
+                .w(px(140.0)) // guardrail-allow: inline tab title editor width, not a height token
+                .child(Input::new(&input.state).small()),
+        )
+    }
+}
@@ -0,0 +1,252 @@
+use super::*;
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant};
+use dbflux_components::primitives::Text;
+use dbflux_core::{
+    DefaultSqlDialect, SavedQueryParam, bind_saved_query_params, references_saved_query_params,
+};
+
+/// Run prompt for the `:name` parameters of the saved query this document was
+/// opened from.
+///
+/// Running a statement that references a declared parameter opens the prompt
+/// instead; confirming it binds the values and runs the bound statement.
+#[derive(Default)]
+pub(super) struct SavedParamsPrompt {
+    pub(super) pending: Option<PendingSavedParamsRun>,
+    pub(super) rows: Vec<SavedParamRow>,
+}
+
+pub(super) struct PendingSavedParamsRun {
+    query_id: Uuid,
+    query: String,
+    in_new_tab: bool,
+    params: Vec<SavedQueryParam>,
+}
+
+pub(super) struct SavedParamRow {
+    param: SavedQueryParam,
+    input: PromptInput,
+}
+
+impl SavedParamsPrompt {
+    pub(super) fn is_visible(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub(super) fn is_focused(&self) -> bool {
+        self.rows.iter().any(|row| row.input.is_focused())
+    }
+}
+
+impl CodeDocument {
+    /// Opens the parameter prompt when `query` references parameters declared
+    /// on the linked saved query. Returns whether the run was deferred to the
+    /// prompt.
+    pub(super) fn prompt_saved_query_params(
+        &mut self,
+        query: &str,
+        in_new_tab: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(query_id) = self.editor.saved_query_id else {
+            return false;
+        };
+
+        let Some(params) = self
+            .app_state
+            .read(cx)
+            .get_saved_query(query_id)
+            .map(|saved| saved.params.clone())
+        else {
+            return false;
+        };
+
+        if !references_saved_query_params(query, &params) {
+            return false;
+        }
+
+        self.saved_params.rows = params
+            .iter()
+            .map(|param| self.create_saved_param_row(param.clone(), window, cx))
+            .collect();
+
+        if let Some(row) = self.saved_params.rows.first() {
+            row.input.focus(window, cx);
+        }
+
+        self.saved_params.pending = Some(PendingSavedParamsRun {
+            query_id,
+            query: query.to_string(),
+            in_new_tab,
+            params,
+        });
+
+        cx.notify();
+        true
+    }
+
+    fn create_saved_param_row(
+        &self,
+        param: SavedQueryParam,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> SavedParamRow {
+        let initial = param.initial_value().to_string();
+        let input = PromptInput::new(
+            window,
+            cx,
+            |state| state.placeholder("NULL").default_value(initial),
+            |this, event, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.confirm_saved_params(window, cx);
+                }
+            },
+        );
+
+        SavedParamRow { param, input }
+    }
+
+    /// Binds the entered values, remembers them for the next run and runs the
+    /// bound statement. Invalid values keep the prompt open.
+    pub(super) fn confirm_saved_params(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(pending) = self.saved_params.pending.as_ref() else {
+            return;
+        };
+
+        let values: HashMap<String, String> = self
+            .saved_params
+            .rows
+            .iter()
+            .map(|row| {
+                (
+                    row.param.name.clone(),
+                    row.input.state.read(cx).value().to_string(),
+                )
+            })
+            .collect();
+
+        let bound = {
+            let app_state = self.app_state.read(cx);
+            let connected = self
+                .connection_id
+                .and_then(|conn_id| app_state.connections().get(&conn_id));
+
+            match connected {
+                Some(connected) => bind_saved_query_params(
+                    &pending.query,
+                    &pending.params,
+                    &values,
+                    connected.connection.dialect(),
+                ),
+                None => bind_saved_query_params(
+                    &pending.query,
+                    &pending.params,
+                    &values,
+                    &DefaultSqlDialect,
+                ),
+            }
+        };
+
+        let query = match bound {
+            Ok(query) => query,
+            Err(error) => {
+                Toast::warning(error.to_string())
+                    .meta_right(now_hms())
+                    .push(cx);
+                return;
+            }
+        };
+
+        let Some(pending) = self.saved_params.pending.take() else {
+            return;
+        };
+
+        self.app_state.update(cx, |state, _cx| {
+            state.record_saved_query_param_values(pending.query_id, &values);
+        });
+
+        self.close_saved_params_prompt(window, cx);
+        self.run_query_text(query, pending.in_new_tab, window, cx);
+    }
+
+    pub(super) fn close_saved_params_prompt(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.saved_params = SavedParamsPrompt::default();
+        self.editor
+            .input_state
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    pub(super) fn render_saved_params_prompt(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+
+        let header = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(Text::label("Parameters"))
+            .child(Text::caption("Enter to run, empty binds NULL").muted_foreground())
+            .child(div().flex_1())
+            .child(
+                ToolbarButton::new("saved-params-run-btn")
+                    .icon(AppIcon::Play)
+                    .label("Run")
+                    .variant(ToolbarButtonVariant::Primary)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.confirm_saved_params(window, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("saved-params-close-btn")
+                    .icon(AppIcon::X)
+                    .tooltip("Cancel")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.close_saved_params_prompt(window, cx);
+                    })),
+            );
+
+        let body =
+            div()
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .children(self.saved_params.rows.iter().map(|row| {
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::SM)
+                        .child(
+                            div()
+                                .w(px(160.0)) // guardrail-allow: parameter name column width, not a height token
+                                .overflow_hidden()
+                                .child(Text::caption(format!(":{}", row.param.name))),
+                        )
+                        .child(
+                            div()
+                                .w(px(260.0)) // guardrail-allow: value input width, not a height token
+                                .child(Input::new(&row.input.state).small()),
+                        )
+                        .child(Text::caption(row.param.param_type.as_str()).muted_foreground())
+                }));
+
+        div()
+            .id("saved-params-prompt")
+            .flex()
+            .flex_col()
+            .flex_shrink_0()
+            .gap(Spacing::XS)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .border_b_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .child(header)
+            .child(body)
+    }
+}
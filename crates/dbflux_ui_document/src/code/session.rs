@@ -0,0 +1,220 @@
+use super::*;
+use dbflux_core::{Connection, DbKind, RoleImpersonation, SessionScope, TransactionVocab};
+
+/// Session state this tab's statements run in, captured on the UI thread so
+/// background tasks can apply it.
+#[derive(Debug, Clone, Default)]
+pub(super) struct TabSession {
+    role: Option<(RoleImpersonation, String)>,
+    read_only: Option<TransactionVocab>,
+}
+
+impl TabSession {
+    /// Runs `request` on `connection` inside this tab's session state,
+    /// without other tabs' statements running in between.
+    pub(super) fn execute(
+        &self,
+        connection: &dyn Connection,
+        request: &QueryRequest,
+    ) -> Result<QueryResult, DbError> {
+        let scope = self.scope_for(&request.sql)?;
+        connection.execute_in_session(&scope, request)
+    }
+
+    pub(super) fn is_read_only(&self) -> bool {
+        self.read_only.is_some()
+    }
+
+    fn scope_for(&self, sql: &str) -> Result<SessionScope, DbError> {
+        let mut scope = SessionScope::new();
+
+        if let Some((impersonation, role)) = &self.role {
+            scope = scope.with_step(impersonation.set_role_sql(role), impersonation.reset_sql());
+        }
+
+        if let Some(vocab) = &self.read_only {
+            if !vocab.read_only_admits(sql) {
+                return Err(DbError::NotSupported(
+                    "Read-only transactions run one statement at a time, without COMMIT, \
+                     ROLLBACK or statements that commit implicitly"
+                        .to_string(),
+                ));
+            }
+
+            scope = scope.with_step(vocab.read_only_begin, vocab.rollback);
+        }
+
+        Ok(scope)
+    }
+}
+
+impl CodeDocument {
+    /// This tab's session state for a connection of `kind`.
+    pub(super) fn tab_session(&self, kind: DbKind) -> TabSession {
+        TabSession {
+            role: self.impersonated_role.clone().and_then(|role| {
+                RoleImpersonation::for_kind(kind).map(|impersonation| (impersonation, role))
+            }),
+            read_only: self
+                .read_only_transactions
+                .then(|| TransactionVocab::for_kind(kind))
+                .flatten(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TabSession;
+    use dbflux_core::{
+        ConnectionProfile, DbConfig, DbDriver, DbError, DbKind, QueryRequest, RoleImpersonation,
+        TransactionVocab,
+    };
+    use dbflux_test_support::FakeDriver;
+
+    fn read_only(kind: DbKind) -> TabSession {
+        TabSession {
+            read_only: TransactionVocab::for_kind(kind),
+            ..TabSession::default()
+        }
+    }
+
+    fn executed_sql(driver: &FakeDriver) -> Vec<String> {
+        driver
+            .stats()
+            .executed_requests
+            .into_iter()
+            .map(|request| request.sql)
+            .collect()
+    }
+
+    #[test]
+    fn read_only_wraps_the_request_in_a_rolled_back_transaction() {
+        let driver = FakeDriver::new(DbKind::Postgres);
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+
+        read_only(DbKind::Postgres)
+            .execute(&*connection, &QueryRequest::new("SELECT 1"))
+            .unwrap();
+
+        assert_eq!(
+            executed_sql(&driver),
+            ["BEGIN READ ONLY", "SELECT 1", "ROLLBACK"]
+        );
+    }
+
+    #[test]
+    fn failed_query_is_still_rolled_back() {
+        let driver =
+            FakeDriver::new(DbKind::Postgres).with_query_error("DELETE FROM t", "read-only");
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+
+        let result =
+            read_only(DbKind::Postgres).execute(&*connection, &QueryRequest::new("DELETE FROM t"));
+
+        assert!(matches!(result, Err(DbError::QueryFailed(_))));
+        assert_eq!(
+            executed_sql(&driver),
+            ["BEGIN READ ONLY", "DELETE FROM t", "ROLLBACK"]
+        );
+    }
+
+    #[test]
+    fn failed_rollback_is_an_error_and_is_retried_before_the_next_statement() {
+        let driver = FakeDriver::new(DbKind::Postgres).with_query_error("ROLLBACK", "lost");
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+
+        let result =
+            read_only(DbKind::Postgres).execute(&*connection, &QueryRequest::new("SELECT 1"));
+        assert!(result.is_err());
+
+        let next = connection.execute(&QueryRequest::new("SELECT 2"));
+        assert!(next.is_err());
+        assert_eq!(
+            executed_sql(&driver),
+            ["BEGIN READ ONLY", "SELECT 1", "ROLLBACK", "ROLLBACK"]
+        );
+    }
+
+    #[test]
+    fn role_is_switched_outside_the_read_only_transaction() {
+        let driver = FakeDriver::new(DbKind::Postgres);
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+        let session = TabSession {
+            role: RoleImpersonation::for_kind(DbKind::Postgres)
+                .map(|impersonation| (impersonation, "reader".to_string())),
+            ..read_only(DbKind::Postgres)
+        };
+
+        session
+            .execute(&*connection, &QueryRequest::new("SELECT 1"))
+            .unwrap();
+
+        assert_eq!(
+            executed_sql(&driver),
+            [
+                "SET ROLE \"reader\"",
+                "BEGIN READ ONLY",
+                "SELECT 1",
+                "ROLLBACK",
+                "RESET ROLE"
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_role_revert_is_an_error_and_blocks_the_connection_until_it_succeeds() {
+        let driver = FakeDriver::new(DbKind::Postgres).with_query_error("RESET ROLE", "lost");
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+        let session = TabSession {
+            role: RoleImpersonation::for_kind(DbKind::Postgres)
+                .map(|impersonation| (impersonation, "reader".to_string())),
+            ..TabSession::default()
+        };
+
+        let result = session.execute(&*connection, &QueryRequest::new("SELECT 1"));
+        assert!(result.is_err());
+
+        assert!(connection.execute(&QueryRequest::new("SELECT 2")).is_err());
+        assert_eq!(
+            executed_sql(&driver),
+            [
+                "SET ROLE \"reader\"",
+                "SELECT 1",
+                "RESET ROLE",
+                "RESET ROLE"
+            ]
+        );
+    }
+
+    #[test]
+    fn rollback_only_backends_refuse_scripts_before_sending_anything() {
+        let driver = FakeDriver::new(DbKind::SQLite);
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+
+        let result = read_only(DbKind::SQLite)
+            .execute(&*connection, &QueryRequest::new("COMMIT; DELETE FROM t"));
+
+        assert!(matches!(result, Err(DbError::NotSupported(_))));
+        assert!(executed_sql(&driver).is_empty());
+    }
+
+    #[test]
+    fn no_session_state_runs_the_request_as_is() {
+        let driver = FakeDriver::new(DbKind::SQLite);
+        let profile = ConnectionProfile::new("fake", DbConfig::default_postgres());
+        let connection = driver.connect(&profile).unwrap();
+
+        TabSession::default()
+            .execute(&*connection, &QueryRequest::new("SELECT 1; SELECT 2"))
+            .unwrap();
+
+        assert_eq!(executed_sql(&driver), ["SELECT 1; SELECT 2"]);
+    }
+}
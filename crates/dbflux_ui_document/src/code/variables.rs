@@ -0,0 +1,353 @@
+use super::find::position_for_offset;
+use super::*;
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant};
+use dbflux_components::primitives::Text;
+use dbflux_core::{
+    QueryVariableScope, UndefinedQueryVariables, find_query_variables, query_variable_names,
+    substitute_query_variables,
+};
+
+/// `{{name}}` variables panel state owned by the code document.
+///
+/// Rows mirror the distinct variable names in the editor text; values live in
+/// `AppState` and are written back when a row's input is confirmed or blurred.
+pub(super) struct VariablesState {
+    pub(super) visible: bool,
+    /// Edit the document connection's values instead of the workspace values.
+    /// Ignored while the document has no connection.
+    pub(super) connection_scope: bool,
+    pub(super) rows: Vec<VariableRow>,
+}
+
+pub(super) struct VariableRow {
+    pub(super) name: String,
+    pub(super) input: PromptInput,
+}
+
+impl VariablesState {
+    pub(super) fn is_focused(&self) -> bool {
+        self.rows.iter().any(|row| row.input.is_focused())
+    }
+}
+
+impl Default for VariablesState {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            connection_scope: true,
+            rows: Vec::new(),
+        }
+    }
+}
+
+impl CodeDocument {
+    /// Scope the panel currently reads from and writes to.
+    fn variable_edit_scope(&self) -> QueryVariableScope {
+        match self.connection_id {
+            Some(profile_id) if self.variables.connection_scope => {
+                QueryVariableScope::Connection(profile_id)
+            }
+            _ => QueryVariableScope::Workspace,
+        }
+    }
+
+    /// Substitutes `{{name}}` placeholders with the values visible to this
+    /// document's connection.
+    pub(super) fn resolve_query_variables(
+        &self,
+        query: &str,
+        cx: &App,
+    ) -> Result<String, UndefinedQueryVariables> {
+        let values = self
+            .app_state
+            .read(cx)
+            .resolved_query_variables(self.connection_id);
+
+        substitute_query_variables(query, &values)
+    }
+
+    pub(super) fn toggle_variables_panel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.variables.visible {
+            self.variables.visible = false;
+            self.editor
+                .input_state
+                .update(cx, |state, cx| state.focus(window, cx));
+        } else {
+            self.open_variables_panel(window, cx);
+        }
+
+        cx.notify();
+    }
+
+    /// Opens the panel and focuses the first variable that has no value.
+    pub(super) fn open_variables_panel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.variables.visible = true;
+        self.sync_variable_rows(window, cx);
+
+        let values = self
+            .app_state
+            .read(cx)
+            .resolved_query_variables(self.connection_id);
+
+        if let Some(row) = self
+            .variables
+            .rows
+            .iter()
+            .find(|row| !values.contains_key(&row.name))
+        {
+            row.input.focus(window, cx);
+        }
+
+        cx.notify();
+    }
+
+    /// Adds rows for new variable names and drops rows whose name no longer
+    /// appears in the editor. Existing rows keep their input (and any
+    /// unconfirmed text) untouched.
+    pub(super) fn sync_variable_rows(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.variables.visible {
+            return;
+        }
+
+        let text = self.editor.input_state.read(cx).value().to_string();
+        let names = query_variable_names(&text);
+
+        let mut existing: HashMap<String, VariableRow> = self
+            .variables
+            .rows
+            .drain(..)
+            .map(|row| (row.name.clone(), row))
+            .collect();
+
+        let mut rows = Vec::with_capacity(names.len());
+
+        for name in names {
+            match existing.remove(&name) {
+                Some(row) => rows.push(row),
+                None => rows.push(self.create_variable_row(name, window, cx)),
+            }
+        }
+
+        self.variables.rows = rows;
+    }
+
+    fn create_variable_row(
+        &self,
+        name: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> VariableRow {
+        let value = self.stored_variable_value(&name, cx);
+        let row_name = name.clone();
+        let input = PromptInput::new(
+            window,
+            cx,
+            |state| {
+                state
+                    .placeholder("Undefined")
+                    .default_value(value.unwrap_or_default())
+            },
+            move |this, event, window, cx| match event {
+                InputEvent::PressEnter { .. } | InputEvent::Blur => {
+                    this.commit_variable_value(&row_name, window, cx);
+                }
+                InputEvent::Focus | InputEvent::Change => {}
+            },
+        );
+
+        VariableRow { name, input }
+    }
+
+    fn stored_variable_value(&self, name: &str, cx: &App) -> Option<String> {
+        self.app_state
+            .read(cx)
+            .query_variables_in_scope(self.variable_edit_scope())
+            .into_iter()
+            .find(|(stored_name, _)| stored_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Persists the row's value into the edit scope. An empty input removes
+    /// the value from that scope.
+    fn commit_variable_value(&mut self, name: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(row) = self.variables.rows.iter().find(|row| row.name == name) else {
+            return;
+        };
+
+        let value = row.input.state.read(cx).value().to_string();
+        if self.stored_variable_value(name, cx).unwrap_or_default() == value {
+            return;
+        }
+
+        let scope = self.variable_edit_scope();
+        self.app_state.update(cx, |state, _cx| {
+            if value.is_empty() {
+                state.remove_query_variable(scope, name);
+            } else {
+                state.set_query_variable(scope, name, &value);
+            }
+        });
+
+        self.refresh_editor_diagnostics(window, cx);
+        cx.notify();
+    }
+
+    /// Switches between connection and workspace values and reloads every row.
+    fn toggle_variable_scope(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.variables.connection_scope = !self.variables.connection_scope;
+        self.reload_variable_inputs(window, cx);
+    }
+
+    /// Re-seeds every row from the current edit scope, discarding unconfirmed
+    /// text. Called when the scope or the document's connection changes.
+    pub(super) fn reload_variable_inputs(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        for row in &self.variables.rows {
+            let value = self
+                .stored_variable_value(&row.name, cx)
+                .unwrap_or_default();
+            row.input
+                .state
+                .update(cx, |state, cx| state.set_value(&value, window, cx));
+        }
+
+        cx.notify();
+    }
+
+    /// Warning diagnostics for placeholders that have no value in any scope
+    /// visible to this document.
+    pub(super) fn undefined_variable_diagnostics(
+        &self,
+        text: &str,
+        cx: &App,
+    ) -> Vec<InputDiagnostic> {
+        let refs = find_query_variables(text);
+        if refs.is_empty() {
+            return Vec::new();
+        }
+
+        let values = self
+            .app_state
+            .read(cx)
+            .resolved_query_variables(self.connection_id);
+
+        refs.into_iter()
+            .filter(|reference| !values.contains_key(&reference.name))
+            .map(|reference| {
+                let (start_line, start_character) =
+                    position_for_offset(text, reference.range.start);
+                let (end_line, end_character) = position_for_offset(text, reference.range.end);
+
+                InputDiagnostic::new(
+                    InputPosition::new(start_line, start_character)
+                        ..InputPosition::new(end_line, end_character),
+                    format!("Undefined query variable `{}`", reference.name),
+                )
+                .with_severity(InputDiagnosticSeverity::Warning)
+            })
+            .collect()
+    }
+
+    pub(super) fn render_variables_panel(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let has_connection = self.connection_id.is_some();
+        let scope = self.variable_edit_scope();
+        let values = self
+            .app_state
+            .read(cx)
+            .resolved_query_variables(self.connection_id);
+
+        let scope_label = match scope {
+            QueryVariableScope::Connection(_) => "Connection",
+            QueryVariableScope::Workspace => "Workspace",
+        };
+
+        let header = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(Text::label("Variables"))
+            .when(has_connection, |el| {
+                el.child(
+                    ToolbarButton::new("variables-scope-toggle")
+                        .label(scope_label)
+                        .tooltip("Switch between connection and workspace values")
+                        .variant(ToolbarButtonVariant::Ghost)
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.toggle_variable_scope(window, cx);
+                        })),
+                )
+            })
+            .when(!has_connection, |el| {
+                el.child(Text::caption(scope_label).muted_foreground())
+            })
+            .child(div().flex_1())
+            .child(
+                ToolbarButton::new("variables-close-btn")
+                    .icon(AppIcon::X)
+                    .tooltip("Close")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.toggle_variables_panel(window, cx);
+                    })),
+            );
+
+        let body = if self.variables.rows.is_empty() {
+            div()
+                .child(Text::caption("No {{variables}} in this document").muted_foreground())
+                .into_any_element()
+        } else {
+            div()
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .children(self.variables.rows.iter().map(|row| {
+                    let inherited = self.stored_variable_value(&row.name, cx).is_none()
+                        && values.contains_key(&row.name);
+                    let undefined = !values.contains_key(&row.name);
+
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::SM)
+                        .child(
+                            div()
+                                .w(px(160.0)) // guardrail-allow: variable name column width, not a height token
+                                .overflow_hidden()
+                                .child(Text::caption(format!("{{{{{}}}}}", row.name))),
+                        )
+                        .child(
+                            div()
+                                .w(px(260.0)) // guardrail-allow: value input width, not a height token
+                                .child(Input::new(&row.input.state).small()),
+                        )
+                        .when(inherited, |el| {
+                            el.child(
+                                Text::caption(format!(
+                                    "Inherits {}",
+                                    values.get(&row.name).cloned().unwrap_or_default()
+                                ))
+                                .muted_foreground(),
+                            )
+                        })
+                        .when(undefined, |el| {
+                            el.child(Text::caption("Undefined").color(theme.warning))
+                        })
+                }))
+                .into_any_element()
+        };
+
+        div()
+            .id("sql-variables-panel")
+            .flex()
+            .flex_col()
+            .flex_shrink_0()
+            .gap(Spacing::XS)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .border_b_1()
+            .border_color(theme.border)
+            .bg(theme.secondary)
+            .child(header)
+            .child(body)
+    }
+}
@@ -0,0 +1,1508 @@
+use super::find::{position_for_offset, utf16_offset};
+use super::*;
+use dbflux_components::controls::{InputRedo, InputUndo};
+use std::ops::Range;
+
+/// Modal editing state shown in the editor status strip.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) enum VimMode {
+    #[default]
+    Normal,
+    Insert,
+    Visual,
+    VisualLine,
+}
+
+impl VimMode {
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            VimMode::Normal => "NORMAL",
+            VimMode::Insert => "INSERT",
+            VimMode::Visual => "VISUAL",
+            VimMode::VisualLine => "VISUAL LINE",
+        }
+    }
+
+    fn is_visual(self) -> bool {
+        matches!(self, VimMode::Visual | VimMode::VisualLine)
+    }
+}
+
+/// A keystroke as seen by the Vim layer.
+///
+/// Chords carrying Alt/Cmd never reach the layer; Ctrl is only forwarded for
+/// the handful of Vim bindings that use it so global shortcuts keep working.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum VimKey {
+    Char(char),
+    Escape,
+    Enter,
+    Backspace,
+    Ctrl(char),
+}
+
+impl VimKey {
+    pub(super) fn from_keystroke(keystroke: &Keystroke) -> Option<Self> {
+        let modifiers = &keystroke.modifiers;
+
+        if modifiers.alt || modifiers.platform || modifiers.function {
+            return None;
+        }
+
+        if modifiers.control {
+            return match keystroke.key.as_str() {
+                "r" => Some(VimKey::Ctrl('r')),
+                _ => None,
+            };
+        }
+
+        match keystroke.key.as_str() {
+            "escape" => Some(VimKey::Escape),
+            "enter" => Some(VimKey::Enter),
+            "backspace" => Some(VimKey::Backspace),
+            "space" => Some(VimKey::Char(' ')),
+            _ => {
+                let typed = keystroke.key_char.as_deref()?;
+                let mut chars = typed.chars();
+                let character = chars.next()?;
+                chars.next().is_none().then_some(VimKey::Char(character))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+impl Operator {
+    fn from_char(character: char) -> Option<Self> {
+        match character {
+            'd' => Some(Operator::Delete),
+            'c' => Some(Operator::Change),
+            'y' => Some(Operator::Yank),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    Down,
+    Up,
+    WordForward,
+    WordBackward,
+    WordEnd,
+    LineStart,
+    FirstNonBlank,
+    LineEnd,
+    FileStart,
+    /// `G` — last line, or line `n` when a count was given.
+    FileEnd,
+}
+
+impl Motion {
+    fn from_char(character: char) -> Option<Self> {
+        match character {
+            'h' => Some(Motion::Left),
+            'l' | ' ' => Some(Motion::Right),
+            'j' => Some(Motion::Down),
+            'k' => Some(Motion::Up),
+            'w' => Some(Motion::WordForward),
+            'b' => Some(Motion::WordBackward),
+            'e' => Some(Motion::WordEnd),
+            '0' => Some(Motion::LineStart),
+            '^' => Some(Motion::FirstNonBlank),
+            '$' => Some(Motion::LineEnd),
+            'G' => Some(Motion::FileEnd),
+            _ => None,
+        }
+    }
+
+    fn is_linewise(self) -> bool {
+        matches!(
+            self,
+            Motion::Down | Motion::Up | Motion::FileStart | Motion::FileEnd
+        )
+    }
+
+    fn is_inclusive(self) -> bool {
+        matches!(self, Motion::WordEnd | Motion::LineEnd)
+    }
+}
+
+/// Keys that need one more character before they can run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Awaiting {
+    Register,
+    ReplaceChar,
+    GPrefix,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct Register {
+    pub(super) text: String,
+    pub(super) linewise: bool,
+}
+
+/// Requests the layer cannot satisfy with a text edit alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum VimRequest {
+    Undo,
+    Redo,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct VimEdit {
+    pub(super) range: Range<usize>,
+    pub(super) text: String,
+}
+
+/// Effect of one keystroke. Offsets are bytes; `cursor` refers to the text
+/// after `edit` has been applied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct VimOutcome {
+    pub(super) edit: Option<VimEdit>,
+    pub(super) cursor: Option<usize>,
+    pub(super) request: Option<VimRequest>,
+}
+
+impl VimOutcome {
+    fn consumed() -> Self {
+        Self::default()
+    }
+
+    fn move_to(cursor: usize) -> Self {
+        Self {
+            cursor: Some(cursor),
+            ..Self::default()
+        }
+    }
+
+    fn edit(range: Range<usize>, text: impl Into<String>, cursor: usize) -> Self {
+        Self {
+            edit: Some(VimEdit {
+                range,
+                text: text.into(),
+            }),
+            cursor: Some(cursor),
+            request: None,
+        }
+    }
+}
+
+/// Modal editing engine. Pure over `(text, cursor)` so it can be driven and
+/// tested without an editor; the document applies the returned outcome.
+#[derive(Debug, Default)]
+pub(super) struct VimState {
+    pub(super) mode: VimMode,
+    count: Option<usize>,
+    /// Pending operator with the count typed before it (`2d3w` deletes six words).
+    operator: Option<(Operator, usize)>,
+    awaiting: Option<Awaiting>,
+    register: Option<char>,
+    registers: HashMap<char, Register>,
+    visual_anchor: usize,
+    /// Last cursor seen, used to render the visual selection between keys.
+    cursor: usize,
+    pending_keys: String,
+}
+
+impl VimState {
+    /// Keys typed towards an unfinished command, e.g. `"a2d`.
+    pub(super) fn pending_keys(&self) -> &str {
+        &self.pending_keys
+    }
+
+    pub(super) fn register(&self, name: char) -> Option<&Register> {
+        self.registers.get(&name.to_ascii_lowercase())
+    }
+
+    pub(super) fn visual_selection(&self, text: &str) -> Option<Range<usize>> {
+        if !self.mode.is_visual() {
+            return None;
+        }
+
+        Some(self.selection_range(text, self.cursor))
+    }
+
+    pub(super) fn enter_normal(&mut self) {
+        self.mode = VimMode::Normal;
+        self.reset_pending();
+    }
+
+    /// Handles one key. `None` means the key belongs to the editor (insert
+    /// mode typing, or a chord the layer does not own).
+    pub(super) fn handle_key(
+        &mut self,
+        key: VimKey,
+        text: &str,
+        cursor: usize,
+    ) -> Option<VimOutcome> {
+        let cursor = clamp_to_boundary(text, cursor);
+
+        if self.mode == VimMode::Insert {
+            if key != VimKey::Escape {
+                return None;
+            }
+
+            self.enter_normal();
+            let target = if cursor > line_start(text, cursor) {
+                prev_char(text, cursor)
+            } else {
+                cursor
+            };
+            self.cursor = target;
+            return Some(VimOutcome::move_to(target));
+        }
+
+        let outcome = match key {
+            VimKey::Escape => {
+                self.enter_normal();
+                let target = clamp_normal(text, cursor);
+                self.cursor = target;
+                return Some(VimOutcome::move_to(target));
+            }
+            VimKey::Ctrl('r') => {
+                self.reset_pending();
+                VimOutcome {
+                    request: Some(VimRequest::Redo),
+                    ..VimOutcome::default()
+                }
+            }
+            VimKey::Ctrl(_) => return None,
+            VimKey::Enter => self.handle_char('j', text, cursor).map(|mut outcome| {
+                if let Some(target) = outcome.cursor.filter(|_| outcome.edit.is_none()) {
+                    outcome.cursor = Some(first_non_blank(text, target));
+                }
+                outcome
+            })?,
+            VimKey::Backspace => self.handle_char('h', text, cursor)?,
+            VimKey::Char(character) => self.handle_char(character, text, cursor)?,
+        };
+
+        if let Some(target) = outcome.cursor {
+            self.cursor = target;
+        }
+
+        Some(outcome)
+    }
+
+    fn reset_pending(&mut self) {
+        self.count = None;
+        self.operator = None;
+        self.awaiting = None;
+        self.register = None;
+        self.pending_keys.clear();
+    }
+
+    fn take_count(&mut self) -> usize {
+        self.count.take().unwrap_or(1)
+    }
+
+    fn handle_char(&mut self, character: char, text: &str, cursor: usize) -> Option<VimOutcome> {
+        if let Some(awaiting) = self.awaiting.take() {
+            return Some(self.handle_awaiting(awaiting, character, text, cursor));
+        }
+
+        // `0` is a motion unless a count is already being typed.
+        if character.is_ascii_digit() && (character != '0' || self.count.is_some()) {
+            let digit = character.to_digit(10).unwrap_or(0) as usize;
+            self.count = Some(
+                self.count
+                    .unwrap_or(0)
+                    .saturating_mul(10)
+                    .saturating_add(digit),
+            );
+            self.pending_keys.push(character);
+            return Some(VimOutcome::consumed());
+        }
+
+        if let Some(motion) = Motion::from_char(character) {
+            return Some(self.run_motion(motion, text, cursor));
+        }
+
+        if let Some(operator) = Operator::from_char(character) {
+            if self.mode.is_visual() {
+                return Some(self.apply_visual_operator(operator, text, cursor));
+            }
+
+            if let Some((pending, count)) = self.operator {
+                if pending == operator {
+                    let total = count * self.take_count();
+                    let outcome = self.apply_linewise_operator(operator, total, text, cursor);
+                    self.reset_pending();
+                    return Some(outcome);
+                }
+
+                self.reset_pending();
+                return Some(VimOutcome::consumed());
+            }
+
+            let count = self.take_count();
+            self.operator = Some((operator, count));
+            self.pending_keys.push(character);
+            return Some(VimOutcome::consumed());
+        }
+
+        match character {
+            '"' => self.await_next(Awaiting::Register, character),
+            'g' => self.await_next(Awaiting::GPrefix, character),
+            'r' if self.mode == VimMode::Normal => {
+                self.await_next(Awaiting::ReplaceChar, character)
+            }
+            _ => {
+                let outcome = self.handle_command(character, text, cursor);
+                self.reset_pending();
+                Some(outcome)
+            }
+        }
+    }
+
+    fn await_next(&mut self, awaiting: Awaiting, character: char) -> Option<VimOutcome> {
+        self.awaiting = Some(awaiting);
+        self.pending_keys.push(character);
+        Some(VimOutcome::consumed())
+    }
+
+    fn handle_awaiting(
+        &mut self,
+        awaiting: Awaiting,
+        character: char,
+        text: &str,
+        cursor: usize,
+    ) -> VimOutcome {
+        match awaiting {
+            Awaiting::Register => {
+                if character.is_ascii_alphanumeric() || character == '"' || character == '_' {
+                    self.register = Some(character);
+                    self.pending_keys.push(character);
+                } else {
+                    self.reset_pending();
+                }
+                VimOutcome::consumed()
+            }
+            Awaiting::GPrefix => {
+                if character == 'g' {
+                    self.run_motion(Motion::FileStart, text, cursor)
+                } else {
+                    self.reset_pending();
+                    VimOutcome::consumed()
+                }
+            }
+            Awaiting::ReplaceChar => {
+                let count = self.take_count();
+                self.reset_pending();
+
+                let end = advance_chars(text, cursor, count);
+                let available = text[cursor..end].chars().count();
+                if available < count || end > line_end(text, cursor) {
+                    return VimOutcome::consumed();
+                }
+
+                let replacement: String = std::iter::repeat_n(character, count).collect();
+                let new_cursor = cursor + replacement.len() - character.len_utf8();
+                VimOutcome::edit(cursor..end, replacement, new_cursor)
+            }
+        }
+    }
+
+    fn run_motion(&mut self, motion: Motion, text: &str, cursor: usize) -> VimOutcome {
+        let explicit_count = self.count;
+        let count = self.take_count();
+
+        if let Some((operator, operator_count)) = self.operator.take() {
+            let total = count * operator_count;
+            // `cw` behaves like `ce`: changing a word keeps the trailing space.
+            let motion = if operator == Operator::Change && motion == Motion::WordForward {
+                Motion::WordEnd
+            } else {
+                motion
+            };
+            let explicit = explicit_count.map(|value| value * operator_count);
+            let target = apply_motion(text, cursor, motion, total, explicit);
+            let outcome = self.apply_operator(operator, motion, cursor, target, text);
+            self.reset_pending();
+            return outcome;
+        }
+
+        let target = apply_motion(text, cursor, motion, count, explicit_count);
+        self.reset_pending();
+
+        if self.mode.is_visual() {
+            VimOutcome::move_to(target)
+        } else {
+            VimOutcome::move_to(clamp_normal(text, target))
+        }
+    }
+
+    fn apply_operator(
+        &mut self,
+        operator: Operator,
+        motion: Motion,
+        from: usize,
+        to: usize,
+        text: &str,
+    ) -> VimOutcome {
+        if motion.is_linewise() {
+            let first = from.min(to);
+            let last = from.max(to);
+            let lines = line_index(text, last) - line_index(text, first) + 1;
+            return self.apply_linewise_operator(operator, lines, text, first);
+        }
+
+        let start = from.min(to);
+        let mut end = from.max(to);
+        if motion.is_inclusive() && end < text.len() {
+            end = next_char(text, end);
+        }
+
+        self.apply_charwise(operator, start..end, text)
+    }
+
+    fn apply_charwise(
+        &mut self,
+        operator: Operator,
+        range: Range<usize>,
+        text: &str,
+    ) -> VimOutcome {
+        if range.is_empty() && operator != Operator::Change {
+            return VimOutcome::consumed();
+        }
+
+        let removed = text[range.clone()].to_string();
+        self.store_register(removed, false, operator == Operator::Yank);
+
+        match operator {
+            Operator::Yank => {
+                self.mode = VimMode::Normal;
+                VimOutcome::move_to(clamp_normal(text, range.start))
+            }
+            Operator::Delete => {
+                self.mode = VimMode::Normal;
+                let after = format!("{}{}", &text[..range.start], &text[range.end..]);
+                let target = clamp_normal(&after, range.start);
+                VimOutcome::edit(range, "", target)
+            }
+            Operator::Change => {
+                self.mode = VimMode::Insert;
+                let start = range.start;
+                VimOutcome::edit(range, "", start)
+            }
+        }
+    }
+
+    fn apply_linewise_operator(
+        &mut self,
+        operator: Operator,
+        lines: usize,
+        text: &str,
+        cursor: usize,
+    ) -> VimOutcome {
+        let start = line_start(text, cursor);
+        let last_line_end = line_end(text, advance_lines(text, cursor, lines.saturating_sub(1)));
+
+        let mut yanked = text[start..last_line_end].to_string();
+        yanked.push('\n');
+        self.store_register(yanked, true, operator == Operator::Yank);
+
+        match operator {
+            Operator::Yank => {
+                self.mode = VimMode::Normal;
+                VimOutcome::move_to(clamp_normal(text, cursor))
+            }
+            Operator::Change => {
+                self.mode = VimMode::Insert;
+                let indent_end = first_non_blank(text, start);
+                let indent = text[start..indent_end].to_string();
+                let target = start + indent.len();
+                VimOutcome::edit(start..last_line_end, indent, target)
+            }
+            Operator::Delete => {
+                self.mode = VimMode::Normal;
+                // Take the newline after the block, or the one before it when
+                // the block runs to the end of the buffer.
+                let range = if last_line_end < text.len() {
+                    start..last_line_end + 1
+                } else if start > 0 {
+                    start - 1..last_line_end
+                } else {
+                    start..last_line_end
+                };
+
+                let after = format!("{}{}", &text[..range.start], &text[range.end..]);
+                let landing = range.start.min(after.len());
+                let target = first_non_blank(&after, line_start(&after, landing));
+                VimOutcome::edit(range, "", target)
+            }
+        }
+    }
+
+    fn apply_visual_operator(
+        &mut self,
+        operator: Operator,
+        text: &str,
+        cursor: usize,
+    ) -> VimOutcome {
+        let linewise = self.mode == VimMode::VisualLine;
+        let range = self.selection_range(text, cursor);
+
+        let outcome = if linewise {
+            let lines = line_index(text, range.end.saturating_sub(1).max(range.start))
+                - line_index(text, range.start)
+                + 1;
+            self.apply_linewise_operator(operator, lines, text, range.start)
+        } else {
+            self.apply_charwise(operator, range, text)
+        };
+
+        self.reset_pending();
+        outcome
+    }
+
+    fn selection_range(&self, text: &str, cursor: usize) -> Range<usize> {
+        let first = self.visual_anchor.min(cursor).min(text.len());
+        let last = self.visual_anchor.max(cursor).min(text.len());
+
+        if self.mode == VimMode::VisualLine {
+            let end = line_end(text, last);
+            return line_start(text, first)..(end + 1).min(text.len());
+        }
+
+        let end = if last < text.len() {
+            next_char(text, last)
+        } else {
+            last
+        };
+        first..end
+    }
+
+    fn store_register(&mut self, text: String, linewise: bool, is_yank: bool) {
+        let target = self.register.take();
+
+        if target == Some('_') {
+            return;
+        }
+
+        if let Some(name) = target.filter(|name| name.is_ascii_alphabetic()) {
+            let key = name.to_ascii_lowercase();
+            if name.is_ascii_uppercase()
+                && let Some(existing) = self.registers.get_mut(&key)
+            {
+                existing.text.push_str(&text);
+                existing.linewise |= linewise;
+            } else {
+                self.registers.insert(
+                    key,
+                    Register {
+                        text: text.clone(),
+                        linewise,
+                    },
+                );
+            }
+        }
+
+        if is_yank {
+            self.registers.insert(
+                '0',
+                Register {
+                    text: text.clone(),
+                    linewise,
+                },
+            );
+        }
+
+        self.registers.insert('"', Register { text, linewise });
+    }
+
+    fn handle_command(&mut self, character: char, text: &str, cursor: usize) -> VimOutcome {
+        let count = self.take_count();
+        let line_begin = line_start(text, cursor);
+        let line_finish = line_end(text, cursor);
+
+        if self.mode.is_visual() {
+            return match character {
+                'v' | 'V' => {
+                    let requested = if character == 'v' {
+                        VimMode::Visual
+                    } else {
+                        VimMode::VisualLine
+                    };
+                    self.mode = if self.mode == requested {
+                        VimMode::Normal
+                    } else {
+                        requested
+                    };
+                    VimOutcome::move_to(clamp_normal(text, cursor))
+                }
+                'x' => self.apply_visual_operator(Operator::Delete, text, cursor),
+                's' => self.apply_visual_operator(Operator::Change, text, cursor),
+                'o' => {
+                    let anchor = self.visual_anchor;
+                    self.visual_anchor = cursor;
+                    VimOutcome::move_to(anchor)
+                }
+                _ => VimOutcome::consumed(),
+            };
+        }
+
+        match character {
+            'i' => self.insert_at(cursor),
+            'a' => {
+                let target = if cursor < line_finish {
+                    next_char(text, cursor)
+                } else {
+                    cursor
+                };
+                self.insert_at(target)
+            }
+            'I' => self.insert_at(first_non_blank(text, line_begin)),
+            'A' => self.insert_at(line_finish),
+            'o' => {
+                self.mode = VimMode::Insert;
+                VimOutcome::edit(line_finish..line_finish, "\n", line_finish + 1)
+            }
+            'O' => {
+                self.mode = VimMode::Insert;
+                VimOutcome::edit(line_begin..line_begin, "\n", line_begin)
+            }
+            'v' | 'V' => {
+                self.mode = if character == 'v' {
+                    VimMode::Visual
+                } else {
+                    VimMode::VisualLine
+                };
+                self.visual_anchor = cursor;
+                VimOutcome::move_to(cursor)
+            }
+            'x' => {
+                let end = advance_chars(text, cursor, count).min(line_finish);
+                self.apply_charwise(Operator::Delete, cursor..end, text)
+            }
+            'X' => {
+                let mut start = cursor;
+                for _ in 0..count {
+                    if start <= line_begin {
+                        break;
+                    }
+                    start = prev_char(text, start);
+                }
+                self.apply_charwise(Operator::Delete, start..cursor, text)
+            }
+            's' => {
+                let end = advance_chars(text, cursor, count).min(line_finish);
+                self.apply_charwise(Operator::Change, cursor..end, text)
+            }
+            'S' => self.apply_linewise_operator(Operator::Change, count, text, cursor),
+            'D' => self.apply_charwise(Operator::Delete, cursor..line_finish, text),
+            'C' => self.apply_charwise(Operator::Change, cursor..line_finish, text),
+            'Y' => self.apply_linewise_operator(Operator::Yank, count, text, cursor),
+            'p' | 'P' => self.paste(character == 'p', count, text, cursor),
+            'J' => join_lines(text, cursor, count.max(2) - 1),
+            '~' => toggle_case(text, cursor, count),
+            'u' => VimOutcome {
+                request: Some(VimRequest::Undo),
+                ..VimOutcome::default()
+            },
+            _ => VimOutcome::consumed(),
+        }
+    }
+
+    fn insert_at(&mut self, target: usize) -> VimOutcome {
+        self.mode = VimMode::Insert;
+        VimOutcome::move_to(target)
+    }
+
+    fn paste(&mut self, after: bool, count: usize, text: &str, cursor: usize) -> VimOutcome {
+        let name = self.register.take().unwrap_or('"');
+        let Some(register) = self.register(name).cloned() else {
+            return VimOutcome::consumed();
+        };
+
+        let payload = register.text.repeat(count);
+
+        if register.linewise {
+            if !after {
+                let start = line_start(text, cursor);
+                let target = first_non_blank(&payload, 0) + start;
+                return VimOutcome::edit(start..start, payload, target);
+            }
+
+            let end = line_end(text, cursor);
+            if end < text.len() {
+                let insert_at = end + 1;
+                let target = insert_at + first_non_blank(&payload, 0);
+                return VimOutcome::edit(insert_at..insert_at, payload, target);
+            }
+
+            // Last line has no newline to insert after: lead with one instead.
+            let body = payload.strip_suffix('\n').unwrap_or(&payload);
+            let inserted = format!("\n{}", body);
+            let target = end + 1 + first_non_blank(body, 0);
+            return VimOutcome::edit(end..end, inserted, target);
+        }
+
+        let insert_at = if after && cursor < line_end(text, cursor) {
+            next_char(text, cursor)
+        } else {
+            cursor
+        };
+        let target = insert_at + payload.len() - payload.chars().last().map_or(0, char::len_utf8);
+        VimOutcome::edit(insert_at..insert_at, payload, target)
+    }
+}
+
+fn join_lines(text: &str, cursor: usize, joins: usize) -> VimOutcome {
+    let mut edited = text.to_string();
+    let mut landing = cursor;
+
+    for _ in 0..joins {
+        let end = line_end(&edited, landing);
+        if end >= edited.len() {
+            break;
+        }
+
+        let next_start = end + 1;
+        let next_content = first_non_blank(&edited, next_start);
+        let separator = if next_content == line_end(&edited, next_start) {
+            ""
+        } else {
+            " "
+        };
+
+        edited.replace_range(end..next_content, separator);
+        landing = end;
+    }
+
+    if edited == text {
+        return VimOutcome::consumed();
+    }
+
+    let line_begin = line_start(text, cursor);
+    let original_end = line_end(text, advance_lines(text, cursor, joins));
+    let replaced_end = original_end + edited.len() - text.len();
+    let replacement = edited[line_begin..replaced_end].to_string();
+
+    VimOutcome::edit(line_begin..original_end, replacement, landing)
+}
+
+fn toggle_case(text: &str, cursor: usize, count: usize) -> VimOutcome {
+    let end = advance_chars(text, cursor, count).min(line_end(text, cursor));
+    if end == cursor {
+        return VimOutcome::consumed();
+    }
+
+    let toggled: String = text[cursor..end]
+        .chars()
+        .flat_map(|character| {
+            if character.is_uppercase() {
+                character.to_lowercase().collect::<Vec<_>>()
+            } else {
+                character.to_uppercase().collect::<Vec<_>>()
+            }
+        })
+        .collect();
+
+    let after = format!("{}{}{}", &text[..cursor], toggled, &text[end..]);
+    let target = clamp_normal(&after, cursor + toggled.len());
+    VimOutcome::edit(cursor..end, toggled, target)
+}
+
+// ---------------------------------------------------------------------------
+// Text navigation helpers (byte offsets, always on char boundaries)
+// ---------------------------------------------------------------------------
+
+fn clamp_to_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn next_char(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .chars()
+        .next()
+        .map_or(offset, |character| offset + character.len_utf8())
+}
+
+fn prev_char(text: &str, offset: usize) -> usize {
+    text[..offset]
+        .chars()
+        .next_back()
+        .map_or(offset, |character| offset - character.len_utf8())
+}
+
+fn advance_chars(text: &str, offset: usize, count: usize) -> usize {
+    (0..count).fold(offset, |position, _| next_char(text, position))
+}
+
+fn line_start(text: &str, offset: usize) -> usize {
+    text[..offset].rfind('\n').map_or(0, |index| index + 1)
+}
+
+fn line_end(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .find('\n')
+        .map_or(text.len(), |index| offset + index)
+}
+
+fn line_index(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count()
+}
+
+fn first_non_blank(text: &str, offset: usize) -> usize {
+    let start = line_start(text, offset);
+    let end = line_end(text, start);
+    text[start..end]
+        .char_indices()
+        .find(|(_, character)| !matches!(character, ' ' | '\t'))
+        .map_or(end, |(index, _)| start + index)
+}
+
+/// Normal mode keeps the cursor on a character, never past the last one.
+fn clamp_normal(text: &str, offset: usize) -> usize {
+    let offset = clamp_to_boundary(text, offset);
+    let start = line_start(text, offset);
+    let end = line_end(text, offset);
+
+    if offset >= end && end > start {
+        prev_char(text, end)
+    } else {
+        offset
+    }
+}
+
+/// Moves `lines` lines down (clamped at the last line), keeping the column.
+fn advance_lines(text: &str, offset: usize, lines: usize) -> usize {
+    move_vertically(text, offset, lines as isize)
+}
+
+fn move_vertically(text: &str, offset: usize, delta: isize) -> usize {
+    let start = line_start(text, offset);
+    let column = text[start..offset].chars().count();
+    let mut target_start = start;
+
+    if delta > 0 {
+        for _ in 0..delta {
+            let end = line_end(text, target_start);
+            if end >= text.len() {
+                break;
+            }
+            target_start = end + 1;
+        }
+    } else {
+        for _ in 0..delta.unsigned_abs() {
+            if target_start == 0 {
+                break;
+            }
+            target_start = line_start(text, target_start - 1);
+        }
+    }
+
+    let target_end = line_end(text, target_start);
+    advance_chars(text, target_start, column).min(target_end)
+}
+
+fn line_offset(text: &str, line_number: usize) -> usize {
+    let mut offset = 0;
+    for _ in 1..line_number {
+        let end = line_end(text, offset);
+        if end >= text.len() {
+            break;
+        }
+        offset = end + 1;
+    }
+    offset
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Blank,
+    Word,
+    Punctuation,
+}
+
+fn char_class(character: char) -> CharClass {
+    if character.is_whitespace() {
+        CharClass::Blank
+    } else if character.is_alphanumeric() || character == '_' {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+fn class_at(text: &str, offset: usize) -> Option<CharClass> {
+    text[offset..].chars().next().map(char_class)
+}
+
+fn word_forward(text: &str, offset: usize) -> usize {
+    let mut position = offset;
+
+    if let Some(class) = class_at(text, position).filter(|class| *class != CharClass::Blank) {
+        while class_at(text, position) == Some(class) {
+            position = next_char(text, position);
+        }
+    }
+
+    while class_at(text, position) == Some(CharClass::Blank) {
+        position = next_char(text, position);
+    }
+
+    position
+}
+
+fn word_backward(text: &str, offset: usize) -> usize {
+    let mut position = offset;
+
+    while position > 0 && class_at(text, prev_char(text, position)) == Some(CharClass::Blank) {
+        position = prev_char(text, position);
+    }
+
+    let Some(class) = (position > 0)
+        .then(|| class_at(text, prev_char(text, position)))
+        .flatten()
+    else {
+        return position;
+    };
+
+    while position > 0 && class_at(text, prev_char(text, position)) == Some(class) {
+        position = prev_char(text, position);
+    }
+
+    position
+}
+
+fn word_end(text: &str, offset: usize) -> usize {
+    let mut position = next_char(text, offset);
+
+    while class_at(text, position) == Some(CharClass::Blank) {
+        position = next_char(text, position);
+    }
+
+    let Some(class) = class_at(text, position) else {
+        return prev_char(text, text.len());
+    };
+
+    while next_char(text, position) < text.len()
+        && class_at(text, next_char(text, position)) == Some(class)
+    {
+        position = next_char(text, position);
+    }
+
+    position
+}
+
+fn apply_motion(
+    text: &str,
+    offset: usize,
+    motion: Motion,
+    count: usize,
+    explicit_count: Option<usize>,
+) -> usize {
+    let repeat = |step: fn(&str, usize) -> usize| {
+        (0..count).fold(offset, |position, _| step(text, position))
+    };
+
+    match motion {
+        Motion::Left => {
+            let start = line_start(text, offset);
+            (0..count).fold(offset, |position, _| {
+                if position > start {
+                    prev_char(text, position)
+                } else {
+                    position
+                }
+            })
+        }
+        Motion::Right => advance_chars(text, offset, count).min(line_end(text, offset)),
+        Motion::Down => move_vertically(text, offset, count as isize),
+        Motion::Up => move_vertically(text, offset, -(count as isize)),
+        Motion::WordForward => repeat(word_forward),
+        Motion::WordBackward => repeat(word_backward),
+        Motion::WordEnd => repeat(word_end),
+        Motion::LineStart => line_start(text, offset),
+        Motion::FirstNonBlank => first_non_blank(text, offset),
+        Motion::LineEnd => {
+            let target_line = move_vertically(text, offset, count as isize - 1);
+            let end = line_end(text, target_line);
+            if end > line_start(text, target_line) {
+                prev_char(text, end)
+            } else {
+                end
+            }
+        }
+        Motion::FileStart => first_non_blank(text, line_offset(text, explicit_count.unwrap_or(1))),
+        Motion::FileEnd => {
+            let line_number = explicit_count.unwrap_or_else(|| line_index(text, text.len()) + 1);
+            first_non_blank(text, line_offset(text, line_number))
+        }
+    }
+}
+
+impl CodeDocument {
+    pub(super) fn vim_enabled(&self, cx: &App) -> bool {
+        self.app_state.read(cx).general_settings().editor_vim_mode
+    }
+
+    /// Feeds a keystroke the workspace keymap did not claim to the Vim layer.
+    ///
+    /// Returns `true` when the key was consumed so the editor never sees it.
+    pub fn handle_vim_keystroke(
+        &mut self,
+        keystroke: &Keystroke,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if !self.vim_enabled(cx)
+            || self.focus_mode != SqlQueryFocus::Editor
+            || (self.find.visible && self.find.is_focused())
+            || self.history.history_modal.read(cx).is_visible()
+        {
+            return false;
+        }
+
+        let Some(key) = VimKey::from_keystroke(keystroke) else {
+            return false;
+        };
+
+        let text = self.editor.input_state.read(cx).value().to_string();
+        let cursor = self.editor_cursor_offset(&text, window, cx);
+        let was_visual = self.vim.mode.is_visual();
+
+        let Some(outcome) = self.vim.handle_key(key, &text, cursor) else {
+            return false;
+        };
+
+        self.apply_vim_outcome(outcome, &text, window, cx);
+
+        if was_visual || self.vim.mode.is_visual() {
+            self.refresh_editor_diagnostics(window, cx);
+        }
+
+        cx.notify();
+        true
+    }
+
+    /// True while Esc should drop back to normal mode instead of cancelling.
+    pub(super) fn vim_leaves_on_escape(&self, cx: &App) -> bool {
+        self.vim_enabled(cx) && self.vim.mode != VimMode::Normal
+    }
+
+    /// Leaves insert/visual mode. Used for Esc, which the keymap resolves to
+    /// `Cancel` before the Vim layer sees it.
+    pub(super) fn vim_escape(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.editor.input_state.read(cx).value().to_string();
+        let cursor = self.editor_cursor_offset(&text, window, cx);
+        let was_visual = self.vim.mode.is_visual();
+
+        if let Some(outcome) = self.vim.handle_key(VimKey::Escape, &text, cursor) {
+            self.apply_vim_outcome(outcome, &text, window, cx);
+        }
+
+        if was_visual {
+            self.refresh_editor_diagnostics(window, cx);
+        }
+
+        cx.notify();
+    }
+
+    fn editor_cursor_offset(
+        &self,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> usize {
+        let selection = self
+            .editor
+            .input_state
+            .update(cx, |state, cx| state.selected_text_range(false, window, cx));
+
+        let Some(selection) = selection else {
+            return 0;
+        };
+
+        let utf16_cursor = if selection.reversed {
+            selection.range.start
+        } else {
+            selection.range.end
+        };
+
+        byte_offset_for_utf16(text, utf16_cursor)
+    }
+
+    fn apply_vim_outcome(
+        &mut self,
+        outcome: VimOutcome,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(request) = outcome.request {
+            if !self.read_only {
+                match request {
+                    VimRequest::Undo => window.dispatch_action(Box::new(InputUndo), cx),
+                    VimRequest::Redo => window.dispatch_action(Box::new(InputRedo), cx),
+                }
+            }
+            return;
+        }
+
+        let mut current = text.to_string();
+
+        if let Some(edit) = outcome.edit {
+            if self.read_only {
+                // Motions still work in read-only editors; edits are dropped
+                // and the layer falls back to normal mode.
+                self.vim.enter_normal();
+                return;
+            }
+
+            let utf16_range =
+                utf16_offset(text, edit.range.start)..utf16_offset(text, edit.range.end);
+
+            // Same path as find/replace so the edit is undoable.
+            self.editor.input_state.update(cx, |state, cx| {
+                state.replace_text_in_range(Some(utf16_range), &edit.text, window, cx);
+            });
+
+            current.replace_range(edit.range, &edit.text);
+        }
+
+        if let Some(cursor) = outcome.cursor {
+            let (line, character) = position_for_offset(&current, cursor);
+            self.editor.input_state.update(cx, |state, cx| {
+                state.set_cursor_position(InputPosition::new(line, character), window, cx);
+            });
+        }
+    }
+
+    /// Visual-mode selection rendered as a diagnostic highlight, like find matches.
+    pub(super) fn vim_selection_diagnostics(&self, text: &str, cx: &App) -> Vec<InputDiagnostic> {
+        if !self.vim_enabled(cx) {
+            return Vec::new();
+        }
+
+        let Some(range) = self
+            .vim
+            .visual_selection(text)
+            .filter(|range| !range.is_empty())
+        else {
+            return Vec::new();
+        };
+
+        let (start_line, start_character) = position_for_offset(text, range.start);
+        let (end_line, end_character) = position_for_offset(text, range.end);
+
+        vec![
+            InputDiagnostic::new(
+                InputPosition::new(start_line, start_character)
+                    ..InputPosition::new(end_line, end_character),
+                self.vim.mode.label(),
+            )
+            .with_severity(InputDiagnosticSeverity::Info),
+        ]
+    }
+
+    pub(super) fn render_vim_status(&self, cx: &App) -> impl IntoElement {
+        let theme = cx.theme();
+        let color = match self.vim.mode {
+            VimMode::Normal => theme.muted_foreground,
+            VimMode::Insert => theme.success,
+            VimMode::Visual | VimMode::VisualLine => theme.accent_foreground,
+        };
+
+        div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .px(Spacing::SM)
+            .h(Heights::ROW_COMPACT)
+            .border_t_1()
+            .border_color(theme.border)
+            .text_size(FontSizes::XS)
+            .child(
+                div()
+                    .text_color(color)
+                    .child(format!("-- {} --", self.vim.mode.label())),
+            )
+            .child(
+                div()
+                    .text_color(theme.muted_foreground)
+                    .child(self.vim.pending_keys().to_string()),
+            )
+    }
+}
+
+fn byte_offset_for_utf16(text: &str, utf16_offset: usize) -> usize {
+    let mut consumed = 0;
+
+    for (index, character) in text.char_indices() {
+        if consumed >= utf16_offset {
+            return index;
+        }
+        consumed += character.len_utf16();
+    }
+
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(state: &mut VimState, keys: &str, text: &mut String, cursor: &mut usize) {
+        for character in keys.chars() {
+            let key = match character {
+                '\u{1b}' => VimKey::Escape,
+                _ => VimKey::Char(character),
+            };
+
+            if let Some(outcome) = state.handle_key(key, text, *cursor) {
+                if let Some(edit) = outcome.edit {
+                    text.replace_range(edit.range, &edit.text);
+                }
+                if let Some(target) = outcome.cursor {
+                    *cursor = target;
+                }
+            } else {
+                // Insert mode: the editor would type the character.
+                text.insert(*cursor, character);
+                *cursor += character.len_utf8();
+            }
+        }
+    }
+
+    fn run(keys: &str, initial: &str, cursor: usize) -> (String, usize, VimState) {
+        let mut state = VimState::default();
+        let mut text = initial.to_string();
+        let mut position = cursor;
+        press(&mut state, keys, &mut text, &mut position);
+        (text, position, state)
+    }
+
+    #[test]
+    fn basic_motions_move_within_lines() {
+        let (_, cursor, _) = run("ll", "select 1", 0);
+        assert_eq!(cursor, 2);
+
+        let (_, cursor, _) = run("$", "select 1", 0);
+        assert_eq!(cursor, 7);
+
+        let (_, cursor, _) = run("$0", "select 1", 0);
+        assert_eq!(cursor, 0);
+
+        let (_, cursor, _) = run("^", "   from t", 7);
+        assert_eq!(cursor, 3);
+    }
+
+    #[test]
+    fn vertical_motion_keeps_column_and_clamps() {
+        let text = "select a\nfrom t\nwhere x = 1";
+        let (_, cursor, _) = run("j", text, 7);
+        assert_eq!(
+            cursor, 14,
+            "column clamps to the last char of a shorter line"
+        );
+
+        let (_, cursor, _) = run("2j", text, 2);
+        assert_eq!(cursor, 18);
+
+        let (_, cursor, _) = run("G", text, 0);
+        assert_eq!(cursor, 16);
+
+        let (_, cursor, _) = run("gg", text, 18);
+        assert_eq!(cursor, 0);
+    }
+
+    #[test]
+    fn word_motions_respect_punctuation() {
+        let text = "select a.b, c from t";
+        let (_, cursor, _) = run("w", text, 0);
+        assert_eq!(cursor, 7);
+
+        let (_, cursor, _) = run("ww", text, 0);
+        assert_eq!(cursor, 8);
+
+        let (_, cursor, _) = run("e", text, 0);
+        assert_eq!(cursor, 5);
+
+        let (_, cursor, _) = run("b", text, 12);
+        assert_eq!(cursor, 10);
+    }
+
+    #[test]
+    fn counts_multiply_with_operators() {
+        let (text, _, _) = run("2dw", "one two three four", 0);
+        assert_eq!(text, "three four");
+
+        let (text, _, _) = run("d2w", "one two three four", 0);
+        assert_eq!(text, "three four");
+    }
+
+    #[test]
+    fn dd_deletes_lines_and_fills_register() {
+        let (text, cursor, state) = run("dd", "a\nb\nc", 2);
+        assert_eq!(text, "a\nc");
+        assert_eq!(cursor, 2);
+        let register = state.register('"').expect("unnamed register");
+        assert_eq!(register.text, "b\n");
+        assert!(register.linewise);
+
+        let (text, _, _) = run("dd", "a\nb\nc", 4);
+        assert_eq!(
+            text, "a\nb",
+            "deleting the last line takes the preceding newline"
+        );
+
+        let (text, _, _) = run("2dd", "a\nb\nc", 0);
+        assert_eq!(text, "c");
+    }
+
+    #[test]
+    fn yank_and_paste_linewise() {
+        let (text, cursor, _) = run("yyp", "a\nb", 0);
+        assert_eq!(text, "a\na\nb");
+        assert_eq!(cursor, 2);
+
+        let (text, _, _) = run("yyjp", "a\nb", 0);
+        assert_eq!(text, "a\nb\na");
+
+        let (text, _, _) = run("yyP", "a\nb", 2);
+        assert_eq!(text, "a\nb\nb");
+    }
+
+    #[test]
+    fn named_registers_are_independent_and_append() {
+        let (text, _, state) = run("\"ayww\"Aye0\"ap", "one two", 0);
+        assert_eq!(state.register('a').expect("register a").text, "one two");
+        assert_eq!(text, "oone twone two");
+    }
+
+    #[test]
+    fn black_hole_register_keeps_unnamed() {
+        let (_, _, state) = run("yw\"_dw", "one two", 0);
+        assert_eq!(state.register('"').expect("unnamed").text, "one ");
+    }
+
+    #[test]
+    fn change_word_enters_insert_and_keeps_space() {
+        let (text, _, state) = run("cwfoo", "one two", 0);
+        assert_eq!(text, "foo two");
+        assert_eq!(state.mode, VimMode::Insert);
+    }
+
+    #[test]
+    fn insert_escape_steps_back_one_char() {
+        let (text, cursor, state) = run("Ax\u{1b}", "abc", 0);
+        assert_eq!(text, "abcx");
+        assert_eq!(cursor, 3);
+        assert_eq!(state.mode, VimMode::Normal);
+    }
+
+    #[test]
+    fn open_line_below_and_above() {
+        let (text, _, _) = run("ox\u{1b}", "a\nb", 0);
+        assert_eq!(text, "a\nx\nb");
+
+        let (text, _, _) = run("Ox\u{1b}", "a\nb", 2);
+        assert_eq!(text, "a\nx\nb");
+    }
+
+    #[test]
+    fn x_and_replace_char_stay_on_the_line() {
+        let (text, _, _) = run("3x", "ab\ncd", 0);
+        assert_eq!(text, "\ncd");
+
+        let (text, _, _) = run("rz", "abc", 1);
+        assert_eq!(text, "azc");
+
+        let (text, _, _) = run("5rz", "abc", 1);
+        assert_eq!(text, "abc", "replace past the line end is a no-op");
+    }
+
+    #[test]
+    fn visual_mode_deletes_inclusive_selection() {
+        let (text, _, state) = run("vld", "abcdef", 1);
+        assert_eq!(text, "adef");
+        assert_eq!(state.mode, VimMode::Normal);
+
+        let (text, _, _) = run("Vjd", "a\nb\nc", 0);
+        assert_eq!(text, "c");
+    }
+
+    #[test]
+    fn visual_yank_honours_register() {
+        let (_, _, state) = run("v$\"by", "abc", 0);
+        assert_eq!(state.register('b').expect("register b").text, "abc");
+    }
+
+    #[test]
+    fn visual_selection_tracks_cursor() {
+        let mut state = VimState::default();
+        let mut text = "select 1".to_string();
+        let mut cursor = 0;
+        press(&mut state, "vw", &mut text, &mut cursor);
+        assert_eq!(state.visual_selection(&text), Some(0..8));
+
+        press(&mut state, "\u{1b}", &mut text, &mut cursor);
+        assert_eq!(state.visual_selection(&text), None);
+    }
+
+    #[test]
+    fn join_lines_collapses_indent() {
+        let (text, cursor, _) = run("J", "select a\n    from t", 0);
+        assert_eq!(text, "select a from t");
+        assert_eq!(cursor, 8);
+    }
+
+    #[test]
+    fn undo_and_redo_are_requests() {
+        let mut state = VimState::default();
+        let outcome = state
+            .handle_key(VimKey::Char('u'), "a", 0)
+            .expect("handled");
+        assert_eq!(outcome.request, Some(VimRequest::Undo));
+
+        let outcome = state
+            .handle_key(VimKey::Ctrl('r'), "a", 0)
+            .expect("handled");
+        assert_eq!(outcome.request, Some(VimRequest::Redo));
+    }
+
+    #[test]
+    fn normal_mode_swallows_unknown_keys_and_insert_passes_through() {
+        let mut state = VimState::default();
+        assert!(state.handle_key(VimKey::Char('!'), "a", 0).is_some());
+
+        state.mode = VimMode::Insert;
+        assert!(state.handle_key(VimKey::Char('!'), "a", 0).is_none());
+        assert!(state.handle_key(VimKey::Ctrl('s'), "a", 0).is_none());
+    }
+
+    #[test]
+    fn multibyte_text_stays_on_char_boundaries() {
+        let (text, cursor, _) = run("lx", "ñandú", 0);
+        assert_eq!(text, "ñndú");
+        assert_eq!(cursor, 2);
+
+        let (_, cursor, _) = run("$", "ñandú", 0);
+        assert_eq!(cursor, "ñand".len());
+    }
+
+    #[test]
+    fn utf16_to_byte_offset_round_trips() {
+        let text = "a😀b";
+        assert_eq!(byte_offset_for_utf16(text, 0), 0);
+        assert_eq!(byte_offset_for_utf16(text, 1), 1);
+        assert_eq!(byte_offset_for_utf16(text, 3), 5);
+        assert_eq!(byte_offset_for_utf16(text, 4), 6);
+    }
+}
@@ -1,17 +1,19 @@
 //! Per-panel Configure popover for the dashboard.
 //!
-//! Surfaces three sections behind a modal shell so per-panel configuration
-//! (chart kind, axis bindings, stats/PNG actions) is reachable from the kebab
-//! menu without polluting the chrome of every embedded chart panel.
+//! Surfaces four sections behind a modal shell so per-panel configuration
+//! (chart kind, axis bindings, refresh interval, stats/PNG actions) is
+//! reachable from the kebab menu without polluting the chrome of every
+//! embedded chart panel.
 //!
 //! All operations route through `ChartDocument` public accessors so the
 //! popover never reaches into `chart_shell` directly.
 
-use super::{DashboardDocument, DashboardPanelSlot};
+use super::{DashboardDocument, DashboardPanelSlot, REFRESH_POLICY_OPTIONS};
 use dbflux_components::chart::{AggKind, AxisPill, BindingSpec, ChartKind, axis_bar_element};
 use dbflux_components::controls::Button;
 use dbflux_components::modals::ModalShell;
 use dbflux_components::primitives::Text;
+use dbflux_components::saved_chart::SavedChartRefreshPolicy;
 use dbflux_components::semantic::ChartColors;
 use dbflux_components::tokens::Spacing;
 use dbflux_core::ColumnMeta;
@@ -54,6 +56,7 @@ pub(super) fn render_configure_popover(
         .last_result_columns()
         .unwrap_or_default();
     let axis_open_pill = panel_entity.read(cx).axis_open_pill(cx);
+    let refresh_policy = dashboard.panel_refresh_policy(panel_index, cx);
 
     let chart_kind_row = render_chart_kind_row(panel_index, chart_kind, cx);
     let bindings_row = render_bindings_row(
@@ -64,6 +67,7 @@ pub(super) fn render_configure_popover(
         axis_open_pill,
         cx,
     );
+    let refresh_row = render_refresh_row(panel_index, refresh_policy, cx);
     let actions_row = render_actions_row(panel_index, cx);
 
     let body = div()
@@ -72,6 +76,7 @@ pub(super) fn render_configure_popover(
         .gap(Spacing::LG)
         .child(section("Chart type", chart_kind_row))
         .child(section("Axis bindings", bindings_row))
+        .child(section("Refresh", refresh_row))
         .child(section("Actions", actions_row))
         .into_any_element();
 
@@ -237,6 +242,48 @@ fn render_bindings_row(
     .into_any_element()
 }
 
+/// Interval buttons for the panel's own refresh. "With dashboard" stores
+/// `Off`, leaving the panel on the dashboard's shared policy only.
+fn render_refresh_row(
+    panel_index: usize,
+    current: SavedChartRefreshPolicy,
+    cx: &mut Context<DashboardDocument>,
+) -> AnyElement {
+    let current = match current {
+        SavedChartRefreshPolicy::Interval { .. } => current,
+        _ => SavedChartRefreshPolicy::Off,
+    };
+
+    let buttons: Vec<AnyElement> = REFRESH_POLICY_OPTIONS
+        .iter()
+        .enumerate()
+        .map(|(idx, (policy, label))| {
+            let policy = *policy;
+            let label = if policy == SavedChartRefreshPolicy::Off {
+                "With dashboard"
+            } else {
+                *label
+            };
+            let on_click = cx.listener(move |this, _: &gpui::ClickEvent, _, cx| {
+                this.set_panel_refresh_policy(panel_index, policy, cx);
+            });
+            let btn = Button::new(("configure-refresh", idx), label).on_click(on_click);
+            if policy == current {
+                btn.primary().into_any_element()
+            } else {
+                btn.into_any_element()
+            }
+        })
+        .collect();
+
+    div()
+        .flex()
+        .flex_row()
+        .gap(Spacing::XS)
+        .children(buttons)
+        .into_any_element()
+}
+
 fn render_actions_row(panel_index: usize, cx: &mut Context<DashboardDocument>) -> AnyElement {
     let on_stats = cx.listener(move |this, _: &gpui::ClickEvent, _, cx| {
         this.configure_toggle_stats(panel_index, cx);
@@ -18,6 +18,7 @@ use super::instance_inspector::InspectorPanel;
 use super::types::{DocumentId, DocumentState};
 use builder::{DragReorderState, DragResizeState, PanelContextMenu, ResizeAxis};
 use dbflux_app::keymap::{Command, ContextId};
+use dbflux_components::chart::ChartExportOption;
 use dbflux_components::common::time_range::view::{TimeRangeChanged, TimeRangePanel};
 use dbflux_components::controls::{Dropdown, DropdownItem, DropdownSelectionChanged, InputState};
 use dbflux_components::saved_chart::{SavedChartRefreshPolicy, TimeRangePreset};
@@ -54,6 +55,17 @@ pub(crate) fn refresh_policy_from_index(index: usize) -> SavedChartRefreshPolicy
         .unwrap_or(SavedChartRefreshPolicy::Off)
 }
 
+/// How often a panel with its own `policy` refreshes, clamped to the refresh
+/// floor. `None` leaves the panel on the dashboard's shared policy.
+pub(crate) fn panel_refresh_interval(policy: SavedChartRefreshPolicy) -> Option<Duration> {
+    match policy {
+        SavedChartRefreshPolicy::Interval { every_secs } if every_secs > 0 => Some(
+            Duration::from_secs(u64::from(every_secs).max(crate::refresh::MIN_REFRESH_FLOOR_SECS)),
+        ),
+        _ => None,
+    }
+}
+
 /// Grid column count for every dashboard. The persisted `grid_columns` field
 /// is preserved for forward compatibility but ignored by the UI.
 pub const DASHBOARD_GRID_COLUMNS: u32 = 12;
@@ -334,6 +346,12 @@ pub struct DashboardDocument {
     /// when the policy is `Off` / `OnOpen`.
     _refresh_timer: Option<Task<()>>,
 
+    /// One timer per chart panel whose saved chart has its own refresh
+    /// interval. These run next to `_refresh_timer`, so a panel can refresh
+    /// more often than the rest of the dashboard. Rebuilt by
+    /// `update_panel_refresh_timers`.
+    _panel_refresh_timers: Vec<Task<()>>,
+
     /// Refresh-policy `Dropdown` entity rendered in the toolbar. Wired through
     /// `set_shared_refresh_policy` on `DropdownSelectionChanged`.
     pub(crate) refresh_dropdown: Entity<Dropdown>,
@@ -546,6 +564,7 @@ impl DashboardDocument {
             pending_panels_sync: false,
             pending_refresh_timer_init: true,
             _refresh_timer: None,
+            _panel_refresh_timers: Vec::new(),
             refresh_dropdown,
             pending_configure_panel_index: None,
             collapsed_divider_indices: HashSet::new(),
@@ -616,12 +635,12 @@ impl DashboardDocument {
         }
     }
 
-    /// Schedule a "PNG export coming soon" toast on the panel.
+    /// Save the panel's chart as a full-HD PNG.
     pub fn configure_export_png(&mut self, panel_index: usize, cx: &mut Context<Self>) {
         if let Some(DashboardPanelSlot::Loaded { panel, .. }) = self.panel_slots.get(panel_index) {
             let panel = panel.clone();
             panel.update(cx, |doc, cx| {
-                doc.schedule_png_export_toast(cx);
+                doc.export_image(ChartExportOption::DEFAULT_PNG, cx);
             });
         }
     }
@@ -1249,6 +1268,138 @@ impl DashboardDocument {
         }));
     }
 
+    /// Own refresh policy of the saved chart behind the panel at
+    /// `panel_index`. Anything but `Interval` means the panel only refreshes
+    /// with the dashboard.
+    pub fn panel_refresh_policy(&self, panel_index: usize, cx: &App) -> SavedChartRefreshPolicy {
+        let Some(DashboardPanelSlot::Loaded { panel, .. }) = self.panel_slots.get(panel_index)
+        else {
+            return SavedChartRefreshPolicy::Off;
+        };
+
+        panel
+            .read(cx)
+            .saved_chart_id()
+            .and_then(|id| self.app_state.read(cx).saved_charts.chart_by_id(id))
+            .map(|chart| chart.refresh_policy)
+            .unwrap_or_default()
+    }
+
+    /// Give the panel at `panel_index` its own refresh interval, stored on its
+    /// saved chart, and reinstall the per-panel timers.
+    pub fn set_panel_refresh_policy(
+        &mut self,
+        panel_index: usize,
+        policy: SavedChartRefreshPolicy,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(DashboardPanelSlot::Loaded { panel, .. }) = self.panel_slots.get(panel_index)
+        else {
+            return;
+        };
+        let Some(chart_id) = panel.read(cx).saved_chart_id() else {
+            return;
+        };
+        let Some(mut saved) = self
+            .app_state
+            .read(cx)
+            .saved_charts
+            .chart_by_id(chart_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        if saved.refresh_policy == policy {
+            return;
+        }
+
+        saved.refresh_policy = policy;
+        let title = saved.name.clone();
+        let result = self.app_state.update(cx, |state, _cx| {
+            state.saved_charts.upsert(saved).inspect_err(|e| {
+                state.record_storage_failure(
+                    dbflux_core::observability::actions::CONFIG_UPDATE,
+                    "saved_chart",
+                    chart_id.to_string(),
+                    format!("Failed to save chart '{title}'"),
+                    e.to_string(),
+                );
+            })
+        });
+
+        match result {
+            Ok(_) => self.update_panel_refresh_timers(cx),
+            Err(e) => Toast::error(format!("Failed to save panel refresh: {e}")).push(cx),
+        }
+
+        cx.notify();
+    }
+
+    /// Drop the per-panel timers and spawn one for every chart panel whose
+    /// saved chart has an `Interval` policy.
+    ///
+    /// Each timer follows its panel entity rather than a slot index, so
+    /// reordering panels keeps it attached; a removed panel stops its timer
+    /// on the next tick.
+    pub(crate) fn update_panel_refresh_timers(&mut self, cx: &mut Context<Self>) {
+        let intervals: Vec<(gpui::EntityId, Duration)> = (0..self.panel_slots.len())
+            .filter_map(|idx| {
+                let DashboardPanelSlot::Loaded { panel, .. } = &self.panel_slots[idx] else {
+                    return None;
+                };
+                panel_refresh_interval(self.panel_refresh_policy(idx, cx))
+                    .map(|duration| (panel.entity_id(), duration))
+            })
+            .collect();
+
+        self._panel_refresh_timers = intervals
+            .into_iter()
+            .map(|(panel_id, duration)| {
+                cx.spawn(async move |this, cx| {
+                    loop {
+                        cx.background_executor().timer(duration).await;
+
+                        let still_alive = cx
+                            .update(|cx| {
+                                let Some(entity) = this.upgrade() else {
+                                    return false;
+                                };
+                                entity.update(cx, |doc, cx| {
+                                    let Some(slot_idx) = doc.panel_slots.iter().position(|slot| {
+                                        matches!(
+                                            slot,
+                                            DashboardPanelSlot::Loaded { panel, .. }
+                                                if panel.entity_id() == panel_id
+                                        )
+                                    }) else {
+                                        return false;
+                                    };
+                                    if let Some(profile_id) = doc.profile_id
+                                        && !doc
+                                            .app_state
+                                            .read(cx)
+                                            .connections()
+                                            .contains_key(&profile_id)
+                                    {
+                                        return true;
+                                    }
+                                    doc.request_reexec_for_slot(slot_idx, cx);
+                                    true
+                                })
+                            })
+                            .ok()
+                            .unwrap_or(false);
+
+                        if !still_alive {
+                            break;
+                        }
+                    }
+                })
+            })
+            .collect();
+    }
+
     /// Returns the shared refresh policy mapped to the canonical
     /// `dbflux_core::RefreshPolicy` used by `refresh_split_button`.
     ///
@@ -3045,6 +3196,96 @@ mod tests {
         );
     }
 
+    /// Only an `Interval` policy gives a panel its own timer, and never one
+    /// faster than the refresh floor.
+    #[test]
+    fn panel_refresh_interval_needs_an_interval_above_the_floor() {
+        assert_eq!(
+            panel_refresh_interval(SavedChartRefreshPolicy::Interval { every_secs: 30 }),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            panel_refresh_interval(SavedChartRefreshPolicy::Interval { every_secs: 2 }),
+            Some(Duration::from_secs(crate::refresh::MIN_REFRESH_FLOOR_SECS))
+        );
+        assert_eq!(
+            panel_refresh_interval(SavedChartRefreshPolicy::Interval { every_secs: 0 }),
+            None
+        );
+        assert_eq!(panel_refresh_interval(SavedChartRefreshPolicy::Off), None);
+        assert_eq!(
+            panel_refresh_interval(SavedChartRefreshPolicy::OnOpen),
+            None
+        );
+    }
+
+    /// A slot without a saved chart behind it reports `Off` and ignores a new
+    /// per-panel policy instead of panicking.
+    #[gpui::test]
+    fn orphan_slot_has_no_panel_refresh_policy(cx: &mut gpui::TestAppContext) {
+        init_test_runtime(cx);
+        let app_state = isolated_test_app_state(cx);
+
+        let dashboard_holder = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let dashboard_ref = dashboard_holder.clone();
+
+        let (_, window) = cx.add_window_view(|window, cx| {
+            let shared_time_range = cx.new(|cx| TimeRangePanel::new("24h", None, window, cx));
+
+            let orphan_slot = DashboardPanelSlot::Orphan {
+                saved_chart_id: Uuid::new_v4(),
+                grid_pos: PanelGridPos {
+                    grid_row: 0,
+                    grid_column: 0,
+                    grid_width: 1,
+                    grid_height: 1,
+                },
+            };
+
+            let dashboard = cx.new(|cx| {
+                DashboardDocument::new(
+                    Uuid::nil(),
+                    "Orphan Dashboard".to_string(),
+                    vec![orphan_slot],
+                    shared_time_range,
+                    None,
+                    SavedChartRefreshPolicy::Off,
+                    false,
+                    app_state,
+                    cx,
+                )
+            });
+
+            dashboard_ref.replace(Some(dashboard.clone()));
+            gpui_component::Root::new(dashboard, window, cx)
+        });
+
+        let dashboard = dashboard_holder
+            .borrow()
+            .clone()
+            .expect("dashboard entity must be created");
+
+        window.update(|_, cx| {
+            dashboard.update(cx, |doc, cx| {
+                doc.set_panel_refresh_policy(
+                    0,
+                    SavedChartRefreshPolicy::Interval { every_secs: 30 },
+                    cx,
+                );
+            });
+        });
+
+        let (policy, timers) = window.update(|_, cx| {
+            let doc = dashboard.read(cx);
+            (
+                doc.panel_refresh_policy(0, cx),
+                doc._panel_refresh_timers.len(),
+            )
+        });
+        assert_eq!(policy, SavedChartRefreshPolicy::Off);
+        assert_eq!(timers, 0, "an orphan slot must not get a refresh timer");
+    }
+
     /// Q.9 — `start_panel_title_edit` with an `Orphan` slot at index 0 must
     /// leave `editing_title_panel_index` and `panel_title_input` unchanged
     /// because the match arm for non-Loaded slots returns early.
@@ -50,8 +50,10 @@ impl Render for DashboardDocument {
         // signalled a possible mutation (panel added through the workspace
         // Add-Panel flow). This is the bridge that makes new panels visible
         // without forcing the user to close and re-open the dashboard.
-        if std::mem::take(&mut self.pending_panels_sync) {
-            let _ = self.reconcile_panels_from_manager(window, cx);
+        if std::mem::take(&mut self.pending_panels_sync)
+            && self.reconcile_panels_from_manager(window, cx) > 0
+        {
+            self.update_panel_refresh_timers(cx);
         }
 
         // First render after construction: install the auto-refresh timer
@@ -59,6 +61,7 @@ impl Render for DashboardDocument {
         // `Self` is still being built.
         if std::mem::take(&mut self.pending_refresh_timer_init) {
             self.update_refresh_timer(cx);
+            self.update_panel_refresh_timers(cx);
         }
 
         // Drain pending menu action — must run inside `render` because the
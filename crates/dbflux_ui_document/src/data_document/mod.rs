@@ -5,7 +5,9 @@ use super::handle::DocumentEvent;
 use super::types::{DataSourceKind, DocumentId, DocumentState};
 use dbflux_app::keymap::{Command, ContextId};
 use dbflux_components::result_panel::{ResultPanel, ResultPanelEvent};
-use dbflux_core::{CollectionRef, QueryResult, RefreshPolicy, TableRef};
+use dbflux_core::{
+    CollectionRef, DataTabState, DataTabTarget, QueryResult, RefreshPolicy, TableRef,
+};
 use dbflux_ui_base::AppStateEntity;
 use gpui::*;
 use std::sync::Arc;
@@ -57,6 +59,23 @@ impl DataDocument {
         Self::new_with_grid(title, DataSourceKind::Collection, data_grid, window, cx)
     }
 
+    /// Reopens a table or collection tab saved with the session. Its rows are
+    /// loaded the first time the tab is shown.
+    pub fn restore(
+        state: &DataTabState,
+        app_state: Entity<AppStateEntity>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let source_kind = match state.target {
+            DataTabTarget::Table { .. } => DataSourceKind::Table,
+            DataTabTarget::Collection { .. } => DataSourceKind::Collection,
+        };
+        let data_grid = cx.new(|cx| DataGridPanel::restore(state, app_state, window, cx));
+
+        Self::new_with_grid(state.title(), source_kind, data_grid, window, cx)
+    }
+
     #[allow(dead_code)]
     pub fn new_for_result(
         result: Arc<QueryResult>,
@@ -165,6 +184,19 @@ impl DataDocument {
                     sql: sql.clone(),
                 });
             }
+            DataGridEvent::OpenTableFiltered {
+                profile_id,
+                database,
+                table,
+                filter,
+            } => {
+                cx.emit(DocumentEvent::OpenTableFiltered {
+                    profile_id: *profile_id,
+                    database: database.clone(),
+                    table: table.clone(),
+                    filter: filter.clone(),
+                });
+            }
             _ => {}
         }
     }
@@ -249,6 +281,12 @@ impl DataDocument {
         self.data_grid.read(cx).source().table_ref().cloned()
     }
 
+    /// Replaces the WHERE filter of a table document and reloads it.
+    pub fn set_table_filter(&mut self, filter: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.data_grid
+            .update(cx, |grid, cx| grid.set_table_filter(filter, window, cx));
+    }
+
     /// Returns the database name if this is a table document.
     pub fn database(&self, cx: &App) -> Option<String> {
         self.data_grid
@@ -258,6 +296,12 @@ impl DataDocument {
             .map(|s| s.to_string())
     }
 
+    /// Filter, sort and page of a table or collection document, for the
+    /// session manifest.
+    pub fn session_state(&self, cx: &App) -> Option<DataTabState> {
+        self.data_grid.read(cx).session_state(cx)
+    }
+
     pub fn collection_ref(&self, cx: &App) -> Option<CollectionRef> {
         self.data_grid.read(cx).source().collection_ref().cloned()
     }
@@ -7,7 +7,7 @@
 use super::DataDocument;
 use crate::dedup::DocumentKey;
 use crate::handle::DocumentEvent;
-use crate::pane::{BoxedDocEventCallback, PaneHandle};
+use crate::pane::{BoxedDocEventCallback, CodeSessionTabSnapshot, PaneHandle};
 use crate::types::{DataSourceKind, DocumentIcon, DocumentKind, DocumentMetaSnapshot};
 use gpui::{App, Entity, IntoElement};
 
@@ -140,6 +140,39 @@ impl DataDocument {
             },
         );
 
+        handle.set_table_filter = Some({
+            let e = entity.clone();
+            Box::new(move |filter, window, cx| {
+                e.update(cx, |d, cx| d.set_table_filter(filter, window, cx));
+            })
+        });
+
+        // Table and collection documents are restored with the session; their
+        // browse state is persisted, the rows are fetched again.
+        handle.session_tab_snapshot = Some({
+            let e = entity.clone();
+            Box::new(move |cx| {
+                let d = e.read(cx);
+                let state = d.session_state(cx)?;
+
+                Some(CodeSessionTabSnapshot {
+                    kind: "Data",
+                    id: d.id(),
+                    title: d.title(),
+                    language: dbflux_core::QueryLanguage::Sql,
+                    exec_ctx: dbflux_core::ExecutionContext {
+                        connection_id: Some(state.profile_id),
+                        ..Default::default()
+                    },
+                    file_path: None,
+                    scratch_path: None,
+                    shadow_path: None,
+                    results_path: None,
+                    data_state: Some(state),
+                })
+            })
+        });
+
         handle.mark_inspector_closed = Some({
             let e = entity.clone();
             Box::new(move |cx| {
@@ -0,0 +1,995 @@
+//! Saving every pending edit of a browsed table at once: the deletes, inserts
+//! and updates are rendered to SQL for review, then applied in a single
+//! transaction so a failing row leaves the table untouched.
+//!
+//! Each save also keeps the inverse statements, built from the rows as they
+//! were loaded, so the last saved change set can be reviewed and undone the
+//! same way.
+
+use super::{DataGridPanel, DataSource, PendingToast};
+use dbflux_components::components::data_table::DataTableState;
+use dbflux_components::icons::AppIcon;
+use dbflux_components::primitives::{Icon, Text, overlay_bg, surface_panel};
+use dbflux_components::tokens::{Radii, Spacing};
+use dbflux_core::{
+    ColumnAssignment, ColumnMeta, Connection, DbError, MutationRequest, QueryRequest, RowDelete,
+    RowIdentity, RowInsert, RowPatch, TableRef, TaskKind, TransactionVocab, Value,
+};
+use dbflux_ui_base::AsyncUpdateResultExt;
+use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::sync::Arc;
+
+/// Buffered edit a statement was generated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ChangeTarget {
+    /// Model row marked for deletion.
+    Delete(usize),
+    /// Index into the edit buffer's pending inserts.
+    Insert(usize),
+    /// Model row with edited cells.
+    Update(usize),
+    /// Undo of `Delete`: the saved row inserted again.
+    Reinsert(usize),
+    /// Undo of `Insert`: the new row deleted again.
+    RemoveInsert(usize),
+    /// Undo of `Update`: the edited cells set back to their saved values.
+    Restore(usize),
+}
+
+impl ChangeTarget {
+    fn label(self) -> String {
+        match self {
+            Self::Delete(row) => format!("Delete row {}", row + 1),
+            Self::Insert(idx) => format!("Insert new row {}", idx + 1),
+            Self::Update(row) => format!("Update row {}", row + 1),
+            Self::Reinsert(row) => format!("Re-insert deleted row {}", row + 1),
+            Self::RemoveInsert(idx) => format!("Remove inserted row {}", idx + 1),
+            Self::Restore(row) => format!("Restore row {}", row + 1),
+        }
+    }
+
+    /// Target of the statement undoing this change.
+    fn inverse(self) -> Self {
+        match self {
+            Self::Delete(row) => Self::Reinsert(row),
+            Self::Insert(idx) => Self::RemoveInsert(idx),
+            Self::Update(row) => Self::Restore(row),
+            Self::Reinsert(row) => Self::Delete(row),
+            Self::RemoveInsert(idx) => Self::Insert(idx),
+            Self::Restore(row) => Self::Update(row),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct PendingChange {
+    target: ChangeTarget,
+    sql: String,
+}
+
+/// Why applying the reviewed changes failed. Either way nothing was
+/// committed and the edits stay pending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum CommitFailure {
+    /// The statement at `index` was rejected.
+    Statement { index: usize, error: String },
+    /// Opening or committing the transaction failed.
+    Transaction(String),
+}
+
+/// Review modal state, kept while the generated statements are shown and
+/// while they are being applied. An undo is the same review over the
+/// inverse statements of the last save.
+#[derive(Clone)]
+pub(super) struct PendingCommit {
+    changes: Vec<PendingChange>,
+    connection: Arc<dyn Connection>,
+    vocab: TransactionVocab,
+    applying: bool,
+    failure: Option<CommitFailure>,
+    is_undo: bool,
+    /// Changes of the last save that have no inverse statement.
+    irreversible: Vec<ChangeTarget>,
+    /// Undo of these changes, kept once they are applied.
+    undo: Option<Box<PendingCommit>>,
+}
+
+/// Runs `statements` between the vocabulary's BEGIN and COMMIT, stopping at
+/// the first rejected statement and rolling everything back.
+pub(super) fn apply_in_transaction(
+    vocab: &TransactionVocab,
+    statements: &[String],
+    mut execute: impl FnMut(&str) -> Result<(), DbError>,
+) -> Result<(), CommitFailure> {
+    execute(vocab.begin).map_err(|e| CommitFailure::Transaction(e.to_string()))?;
+
+    for (index, sql) in statements.iter().enumerate() {
+        if let Err(e) = execute(sql) {
+            if let Err(rb_err) = execute(vocab.rollback) {
+                log::warn!("ROLLBACK failed after rejected statement: {}", rb_err);
+            }
+            return Err(CommitFailure::Statement {
+                index,
+                error: e.to_string(),
+            });
+        }
+    }
+
+    if let Err(e) = execute(vocab.commit) {
+        if let Err(rb_err) = execute(vocab.rollback) {
+            log::warn!("ROLLBACK failed after COMMIT error: {}", rb_err);
+        }
+        return Err(CommitFailure::Transaction(e.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Primary key identity of a model row.
+fn row_identity(state: &DataTableState, row_idx: usize) -> Result<RowIdentity, String> {
+    let pk_indices = state.pk_columns();
+    let model = state.model();
+
+    if pk_indices.is_empty() {
+        return Err("no primary key defined for this table".to_string());
+    }
+
+    let mut pk_columns = Vec::with_capacity(pk_indices.len());
+    let mut pk_values = Vec::with_capacity(pk_indices.len());
+
+    for &col_idx in pk_indices {
+        if let Some(col_spec) = model.columns.get(col_idx) {
+            pk_columns.push(col_spec.title.to_string());
+        }
+        if let Some(cell) = model.cell(row_idx, col_idx) {
+            pk_values.push(cell.to_value());
+        }
+    }
+
+    if pk_columns.len() != pk_indices.len() || pk_values.len() != pk_indices.len() {
+        return Err("failed to build row identity from primary key columns".to_string());
+    }
+
+    if pk_values
+        .iter()
+        .any(|value| matches!(value, Value::Unsupported(_)))
+    {
+        return Err("primary key uses an unsupported value type".to_string());
+    }
+
+    Ok(RowIdentity::new(pk_columns, pk_values))
+}
+
+/// Mutations for every buffered edit of `table`, in save order: deletes,
+/// then inserts, then updates.
+fn collect_mutations(
+    state: &DataTableState,
+    table: &TableRef,
+    mut pending_deletes: Vec<usize>,
+    pending_inserts: &[usize],
+    mut dirty_rows: Vec<usize>,
+) -> Result<Vec<(ChangeTarget, MutationRequest)>, (ChangeTarget, String)> {
+    let model = state.model();
+    let buffer = state.edit_buffer();
+    let mut mutations = Vec::new();
+
+    pending_deletes.sort_unstable();
+    dirty_rows.sort_unstable();
+
+    for row_idx in pending_deletes {
+        let target = ChangeTarget::Delete(row_idx);
+        let identity = row_identity(state, row_idx).map_err(|e| (target, e))?;
+
+        mutations.push((
+            target,
+            MutationRequest::SqlDelete(RowDelete::new(
+                identity,
+                table.name.clone(),
+                table.schema.clone(),
+            )),
+        ));
+    }
+
+    for &insert_idx in pending_inserts {
+        let target = ChangeTarget::Insert(insert_idx);
+        let Some(cells) = buffer.get_pending_insert_by_idx(insert_idx) else {
+            continue;
+        };
+
+        let assignments: Vec<ColumnAssignment> = cells
+            .iter()
+            .enumerate()
+            .filter_map(|(col_idx, cell)| {
+                let value = cell.to_value();
+                let col = model.columns.get(col_idx)?;
+
+                (!matches!(value, Value::Null)).then(|| ColumnAssignment {
+                    name: col.title.to_string(),
+                    value,
+                    type_name: Some(col.type_name.to_string()),
+                })
+            })
+            .collect();
+
+        if assignments.is_empty() {
+            return Err((target, "no values provided".to_string()));
+        }
+
+        mutations.push((
+            target,
+            MutationRequest::SqlInsert(RowInsert::with_typed_assignments(
+                table.name.clone(),
+                table.schema.clone(),
+                assignments,
+            )),
+        ));
+    }
+
+    for row_idx in dirty_rows {
+        let target = ChangeTarget::Update(row_idx);
+        let changes: Vec<ColumnAssignment> = buffer
+            .row_changes(row_idx)
+            .into_iter()
+            .filter_map(|(col_idx, cell)| {
+                model.columns.get(col_idx).map(|col| ColumnAssignment {
+                    name: col.title.to_string(),
+                    value: cell.to_value(),
+                    type_name: Some(col.type_name.to_string()),
+                })
+            })
+            .collect();
+
+        if changes.is_empty() {
+            continue;
+        }
+
+        if changes
+            .iter()
+            .any(|a| matches!(a.value, Value::Unsupported(_)))
+        {
+            return Err((target, "unsupported values are read-only".to_string()));
+        }
+
+        let identity = row_identity(state, row_idx).map_err(|e| (target, e))?;
+
+        mutations.push((
+            target,
+            MutationRequest::SqlUpdate(RowPatch::with_typed_changes(
+                identity,
+                table.name.clone(),
+                table.schema.clone(),
+                changes,
+            )),
+        ));
+    }
+
+    Ok(mutations)
+}
+
+/// Statement reversing `mutation` once it has been committed, built from
+/// `original`, the row as it was loaded. `None` when the change cannot be
+/// reversed: an insert whose key was left to the database, or a row holding
+/// values that cannot be written back.
+fn inverse_mutation(
+    mutation: &MutationRequest,
+    original: Option<&[Value]>,
+    columns: &[ColumnMeta],
+    pk_columns: &[String],
+) -> Option<MutationRequest> {
+    let saved_value = |name: &str| -> Option<Value> {
+        let ix = columns.iter().position(|column| column.name == name)?;
+        original?
+            .get(ix)
+            .filter(|value| !matches!(value, Value::Unsupported(_)))
+            .cloned()
+    };
+
+    match mutation {
+        MutationRequest::SqlDelete(delete) => {
+            let assignments = columns
+                .iter()
+                .map(|column| {
+                    Some(ColumnAssignment {
+                        name: column.name.clone(),
+                        value: saved_value(&column.name)?,
+                        type_name: Some(column.type_name.clone()),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(MutationRequest::SqlInsert(
+                RowInsert::with_typed_assignments(
+                    delete.table.clone(),
+                    delete.schema.clone(),
+                    assignments,
+                ),
+            ))
+        }
+        MutationRequest::SqlInsert(insert) => {
+            if pk_columns.is_empty() {
+                return None;
+            }
+
+            let values = pk_columns
+                .iter()
+                .map(|pk| {
+                    insert
+                        .assignments
+                        .iter()
+                        .find(|assignment| &assignment.name == pk)
+                        .map(|assignment| assignment.value.clone())
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(MutationRequest::SqlDelete(RowDelete::new(
+                RowIdentity::new(pk_columns.to_vec(), values),
+                insert.table.clone(),
+                insert.schema.clone(),
+            )))
+        }
+        MutationRequest::SqlUpdate(patch) => {
+            let changes = patch
+                .changes
+                .iter()
+                .map(|change| {
+                    Some(ColumnAssignment {
+                        name: change.name.clone(),
+                        value: saved_value(&change.name)?,
+                        type_name: change.type_name.clone(),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            // The row is found by its key after the update, which differs
+            // from the saved one when a key column was edited.
+            let key_columns = patch.identity.columns().to_vec();
+            let key_values = key_columns
+                .iter()
+                .zip(patch.identity.values())
+                .map(|(column, saved)| {
+                    patch
+                        .changes
+                        .iter()
+                        .find(|change| &change.name == column)
+                        .map_or_else(|| saved.clone(), |change| change.value.clone())
+                })
+                .collect();
+
+            Some(MutationRequest::SqlUpdate(RowPatch::with_typed_changes(
+                RowIdentity::new(key_columns, key_values),
+                patch.table.clone(),
+                patch.schema.clone(),
+                changes,
+            )))
+        }
+        _ => None,
+    }
+}
+
+impl DataGridPanel {
+    /// Connection and transaction vocabulary for saving a browsed table in
+    /// one transaction, when its driver generates SQL and supports
+    /// transactions.
+    fn transactional_save_target(
+        &self,
+        cx: &App,
+    ) -> Option<(Arc<dyn Connection>, TransactionVocab)> {
+        let DataSource::Table {
+            profile_id,
+            database,
+            ..
+        } = &self.source
+        else {
+            return None;
+        };
+
+        let connection = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(profile_id)?
+            .resolve_connection_for_execution(database.as_deref())
+            .ok()?;
+
+        connection.query_generator()?;
+        let vocab = TransactionVocab::for_kind(connection.kind())?;
+
+        Some((connection, vocab))
+    }
+
+    /// Opens the review modal for every buffered edit. Returns `false` when
+    /// the table cannot be saved in one transaction, leaving the caller to
+    /// save row by row.
+    pub(super) fn open_commit_review(
+        &mut self,
+        pending_deletes: &[usize],
+        pending_inserts: &[usize],
+        dirty_rows: &[usize],
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let (Some(table_state), Some(table)) =
+            (&self.grid_table.table_state, self.source.table_ref())
+        else {
+            return false;
+        };
+
+        let Some((connection, vocab)) = self.transactional_save_target(cx) else {
+            return false;
+        };
+
+        let mutations = collect_mutations(
+            table_state.read(cx),
+            table,
+            pending_deletes.to_vec(),
+            pending_inserts,
+            dirty_rows.to_vec(),
+        );
+
+        let mutations = match mutations {
+            Ok(mutations) => mutations,
+            Err((target, message)) => {
+                report_error(
+                    UserFacingError::new(
+                        ErrorKind::User,
+                        format!("Cannot save: {}: {}", target.label(), message),
+                    ),
+                    cx,
+                );
+                return true;
+            }
+        };
+
+        let Some(generator) = connection.query_generator() else {
+            return false;
+        };
+
+        let mut changes = Vec::with_capacity(mutations.len());
+        for (target, mutation) in &mutations {
+            let Some(query) = generator.generate_mutation(mutation) else {
+                report_error(
+                    UserFacingError::new(
+                        ErrorKind::Driver,
+                        format!("Cannot save: failed to generate SQL for {}", target.label()),
+                    ),
+                    cx,
+                );
+                return true;
+            };
+
+            changes.push(PendingChange {
+                target: *target,
+                sql: query.text,
+            });
+        }
+
+        if changes.is_empty() {
+            return true;
+        }
+
+        // Undone in reverse order: restore updates, remove the new rows,
+        // then bring the deleted rows back.
+        let mut undo_changes = Vec::new();
+        let mut irreversible = Vec::new();
+        for (target, mutation) in mutations.iter().rev() {
+            let original = match target {
+                ChangeTarget::Delete(row) | ChangeTarget::Update(row) => {
+                    self.result.rows.get(*row).map(Vec::as_slice)
+                }
+                _ => None,
+            };
+
+            match inverse_mutation(mutation, original, &self.result.columns, &self.pk_columns)
+                .and_then(|inverse| generator.generate_mutation(&inverse))
+            {
+                Some(query) => undo_changes.push(PendingChange {
+                    target: target.inverse(),
+                    sql: query.text,
+                }),
+                None => irreversible.push(*target),
+            }
+        }
+
+        let undo = (!undo_changes.is_empty()).then(|| {
+            Box::new(PendingCommit {
+                changes: undo_changes,
+                connection: connection.clone(),
+                vocab: vocab.clone(),
+                applying: false,
+                failure: None,
+                is_undo: true,
+                irreversible,
+                undo: None,
+            })
+        });
+
+        self.pending_commit = Some(PendingCommit {
+            changes,
+            connection,
+            vocab,
+            applying: false,
+            failure: None,
+            is_undo: false,
+            irreversible: Vec::new(),
+            undo,
+        });
+        cx.notify();
+        true
+    }
+
+    /// Opens the review of the statements undoing the last saved change
+    /// set. Refused while the grid has unsaved edits, which the undo would
+    /// otherwise be mixed with.
+    pub fn open_undo_review(&mut self, cx: &mut Context<Self>) {
+        if self.pending_commit.is_some() {
+            return;
+        }
+
+        let has_edits = self
+            .grid_table
+            .table_state
+            .as_ref()
+            .is_some_and(|state| state.read(cx).has_pending_changes());
+        if has_edits {
+            report_error(
+                UserFacingError::new(
+                    ErrorKind::User,
+                    "Save or revert the current edits before undoing the last save",
+                ),
+                cx,
+            );
+            return;
+        }
+
+        if let Some(undo) = self.last_change_set.clone() {
+            self.pending_commit = Some(undo);
+            cx.notify();
+        }
+    }
+
+    /// Applies the reviewed statements in one transaction. On success the
+    /// edit buffer is cleared and the table reloaded; on failure the modal
+    /// stays open with the rejected change marked.
+    pub fn apply_commit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(commit) = self.pending_commit.as_mut() else {
+            return;
+        };
+
+        if commit.applying {
+            return;
+        }
+
+        commit.applying = true;
+        commit.failure = None;
+
+        let statements: Vec<String> = commit.changes.iter().map(|c| c.sql.clone()).collect();
+        let count = statements.len();
+        let connection = commit.connection.clone();
+        let vocab = commit.vocab.clone();
+        let verb = if commit.is_undo { "Undo" } else { "Save" };
+
+        let (task_id, _cancel_token) =
+            self.runner
+                .start_mutation(TaskKind::Query, format!("{verb} {count} change(s)"), cx);
+
+        let entity = cx.entity().clone();
+
+        cx.spawn(async move |_this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    apply_in_transaction(&vocab, &statements, |sql| {
+                        connection.execute(&QueryRequest::new(sql)).map(|_| ())
+                    })
+                })
+                .await;
+
+            cx.update(|cx| {
+                entity.update(cx, |panel, cx| {
+                    panel.handle_commit_result(task_id, count, result, cx);
+                });
+            })
+            .log_if_dropped();
+        })
+        .detach();
+
+        window.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    fn handle_commit_result(
+        &mut self,
+        task_id: dbflux_core::TaskId,
+        count: usize,
+        result: Result<(), CommitFailure>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(()) => {
+                self.runner.complete_mutation(task_id, cx);
+                let commit = self.pending_commit.take();
+                let is_undo = commit.as_ref().is_some_and(|commit| commit.is_undo);
+                self.last_change_set = commit.and_then(|commit| commit.undo).map(|undo| *undo);
+
+                if let Some(table_state) = &self.grid_table.table_state {
+                    table_state.update(cx, |state, cx| {
+                        state.edit_buffer_mut().clear_all();
+                        cx.notify();
+                    });
+                }
+
+                self.pending.toast = Some(PendingToast {
+                    message: format!(
+                        "{} {} change{}",
+                        if is_undo { "Undid" } else { "Saved" },
+                        count,
+                        if count == 1 { "" } else { "s" }
+                    ),
+                    is_error: false,
+                });
+                self.pending.refresh = true;
+            }
+            Err(failure) => {
+                let message = match (&failure, self.pending_commit.as_ref()) {
+                    (CommitFailure::Statement { index, error }, Some(commit)) => commit
+                        .changes
+                        .get(*index)
+                        .map(|change| format!("{}: {}", change.target.label(), error))
+                        .unwrap_or_else(|| error.clone()),
+                    (CommitFailure::Statement { error, .. }, None) => error.clone(),
+                    (CommitFailure::Transaction(error), _) => error.clone(),
+                };
+
+                self.runner.fail_mutation(task_id, message.clone(), cx);
+
+                if let Some(commit) = self.pending_commit.as_mut() {
+                    commit.applying = false;
+                    commit.failure = Some(failure);
+                }
+
+                let verb = if self.pending_commit.as_ref().is_some_and(|c| c.is_undo) {
+                    "Undo"
+                } else {
+                    "Save"
+                };
+
+                report_error(
+                    UserFacingError::new(
+                        ErrorKind::Driver,
+                        format!("{verb} failed, no changes were applied: {message}"),
+                    ),
+                    cx,
+                );
+            }
+        }
+
+        cx.notify();
+    }
+
+    pub fn cancel_commit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self
+            .pending_commit
+            .as_ref()
+            .is_some_and(|commit| !commit.applying)
+        {
+            self.pending_commit = None;
+            self.focus_active_view(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn render_commit_review_modal(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let Some(commit) = self.pending_commit.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let count = commit.changes.len();
+        let applying = commit.applying;
+        let irreversible = (!commit.irreversible.is_empty()).then(|| {
+            let labels: Vec<String> = commit.irreversible.iter().map(|t| t.label()).collect();
+            format!("Not undone: {}.", labels.join(", "))
+        });
+        let failed_index = match &commit.failure {
+            Some(CommitFailure::Statement { index, .. }) => Some(*index),
+            _ => None,
+        };
+
+        let changes = commit.changes.iter().enumerate().map(|(index, change)| {
+            let error = match &commit.failure {
+                Some(CommitFailure::Statement {
+                    index: failed,
+                    error,
+                }) if *failed == index => Some(error.clone()),
+                _ => None,
+            };
+
+            div()
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .p(Spacing::SM)
+                .rounded(Radii::SM)
+                .border_1()
+                .border_color(if failed_index == Some(index) {
+                    theme.danger
+                } else {
+                    theme.border
+                })
+                .child(Text::caption(change.target.label()).color(theme.muted_foreground))
+                .child(Text::code(change.sql.clone()))
+                .when_some(error, |d, error| d.child(Text::caption(error).danger()))
+        });
+
+        let transaction_error = match &commit.failure {
+            Some(CommitFailure::Transaction(error)) => Some(error.clone()),
+            _ => None,
+        };
+
+        div()
+            .id("commit-review-overlay")
+            .absolute()
+            .inset_0()
+            .bg(overlay_bg(theme))
+            .flex()
+            .items_center()
+            .justify_center()
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                surface_panel(cx)
+                    .rounded(Radii::MD)
+                    .w(px(560.0))
+                    .max_h(relative(0.8))
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::MD)
+                    .p(Spacing::MD)
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(Icon::new(AppIcon::Code).medium().color(theme.primary))
+                            .child(Text::heading(format!(
+                                "{} {} change{}",
+                                if commit.is_undo { "Undo" } else { "Review" },
+                                count,
+                                if count == 1 { "" } else { "s" }
+                            ))),
+                    )
+                    .child(Text::muted(
+                        "These statements run in one transaction. If any of them fails, \
+                         none are applied.",
+                    ))
+                    .when_some(irreversible, |d, note| {
+                        d.child(Text::caption(note).color(theme.warning))
+                    })
+                    .child(
+                        div()
+                            .id("commit-review-statements")
+                            .flex()
+                            .flex_col()
+                            .min_h_0()
+                            .gap(Spacing::SM)
+                            .overflow_y_scroll()
+                            .children(changes),
+                    )
+                    .when_some(transaction_error, |d, error| {
+                        d.child(Text::caption(error).danger())
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .justify_end()
+                            .gap(Spacing::SM)
+                            .child(
+                                div()
+                                    .id("commit-cancel-btn")
+                                    .flex()
+                                    .items_center()
+                                    .gap_1()
+                                    .px(Spacing::SM)
+                                    .py(Spacing::XS)
+                                    .rounded(Radii::SM)
+                                    .bg(theme.secondary)
+                                    .when(!applying, |d| {
+                                        d.cursor_pointer().hover(|d| d.bg(theme.muted)).on_click(
+                                            cx.listener(|this, _, window, cx| {
+                                                this.cancel_commit(window, cx);
+                                            }),
+                                        )
+                                    })
+                                    .child(
+                                        Icon::new(AppIcon::X).small().color(theme.muted_foreground),
+                                    )
+                                    .child(Text::caption("Cancel")),
+                            )
+                            .child(
+                                div()
+                                    .id("commit-apply-btn")
+                                    .flex()
+                                    .items_center()
+                                    .gap_1()
+                                    .px(Spacing::SM)
+                                    .py(Spacing::XS)
+                                    .rounded(Radii::SM)
+                                    .bg(theme.primary)
+                                    .when(applying, |d| d.opacity(0.6))
+                                    .when(!applying, |d| {
+                                        d.cursor_pointer().hover(|d| d.opacity(0.9)).on_click(
+                                            cx.listener(|this, _, window, cx| {
+                                                this.apply_commit(window, cx);
+                                            }),
+                                        )
+                                    })
+                                    .child(
+                                        Icon::new(AppIcon::Check)
+                                            .small()
+                                            .color(theme.primary_foreground),
+                                    )
+                                    .child(
+                                        Text::caption(if applying {
+                                            "Applying…"
+                                        } else {
+                                            "Apply"
+                                        })
+                                        .color(theme.primary_foreground),
+                                    ),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::DbKind;
+
+    fn run(fail_on: Option<&str>, fail_commit: bool) -> (Vec<String>, Result<(), CommitFailure>) {
+        let vocab = TransactionVocab::for_kind(DbKind::Postgres).unwrap();
+        let statements = vec![
+            "DELETE FROM t WHERE id = 1".to_string(),
+            "UPDATE t SET a = 2 WHERE id = 2".to_string(),
+        ];
+        let mut executed = Vec::new();
+
+        let result = apply_in_transaction(&vocab, &statements, |sql| {
+            executed.push(sql.to_string());
+            if Some(sql) == fail_on || (fail_commit && sql == "COMMIT") {
+                return Err(DbError::query_failed("rejected"));
+            }
+            Ok(())
+        });
+
+        (executed, result)
+    }
+
+    #[test]
+    fn statements_run_between_begin_and_commit() {
+        let (executed, result) = run(None, false);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            executed,
+            vec![
+                "BEGIN",
+                "DELETE FROM t WHERE id = 1",
+                "UPDATE t SET a = 2 WHERE id = 2",
+                "COMMIT",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejected_statement_rolls_back_and_names_its_index() {
+        let (executed, result) = run(Some("UPDATE t SET a = 2 WHERE id = 2"), false);
+
+        assert!(matches!(
+            result,
+            Err(CommitFailure::Statement { index: 1, .. })
+        ));
+        assert_eq!(executed.last().map(String::as_str), Some("ROLLBACK"));
+        assert!(!executed.iter().any(|sql| sql == "COMMIT"));
+    }
+
+    fn columns() -> Vec<ColumnMeta> {
+        ["id", "name"]
+            .iter()
+            .map(|name| ColumnMeta {
+                name: name.to_string(),
+                type_name: "text".to_string(),
+                kind: dbflux_core::ColumnKind::Text,
+                nullable: true,
+                is_primary_key: *name == "id",
+            })
+            .collect()
+    }
+
+    fn pk() -> Vec<String> {
+        vec!["id".to_string()]
+    }
+
+    fn identity(id: i64) -> RowIdentity {
+        RowIdentity::new(pk(), vec![Value::Int(id)])
+    }
+
+    #[test]
+    fn deleted_row_is_reinserted_from_its_saved_values() {
+        let delete = MutationRequest::SqlDelete(RowDelete::new(identity(1), "t".into(), None));
+        let saved = [Value::Int(1), Value::Null];
+
+        let Some(MutationRequest::SqlInsert(insert)) =
+            inverse_mutation(&delete, Some(&saved), &columns(), &pk())
+        else {
+            panic!("expected an INSERT");
+        };
+
+        let values: Vec<Value> = insert.assignments.into_iter().map(|a| a.value).collect();
+        assert_eq!(values, saved);
+    }
+
+    #[test]
+    fn update_is_restored_under_the_edited_key() {
+        let update = MutationRequest::SqlUpdate(RowPatch::with_typed_changes(
+            identity(1),
+            "t".into(),
+            None,
+            vec![
+                ColumnAssignment::new("id", Value::Int(5)),
+                ColumnAssignment::new("name", Value::Text("new".into())),
+            ],
+        ));
+        let saved = [Value::Int(1), Value::Text("old".into())];
+
+        let Some(MutationRequest::SqlUpdate(patch)) =
+            inverse_mutation(&update, Some(&saved), &columns(), &pk())
+        else {
+            panic!("expected an UPDATE");
+        };
+
+        assert_eq!(patch.identity.values(), &[Value::Int(5)]);
+        let values: Vec<Value> = patch.changes.into_iter().map(|c| c.value).collect();
+        assert_eq!(values, saved);
+    }
+
+    #[test]
+    fn insert_is_reversible_only_with_an_explicit_key() {
+        let insert = |assignments| {
+            MutationRequest::SqlInsert(RowInsert::with_typed_assignments(
+                "t".into(),
+                None,
+                assignments,
+            ))
+        };
+
+        let keyed = insert(vec![
+            ColumnAssignment::new("id", Value::Int(9)),
+            ColumnAssignment::new("name", Value::Text("a".into())),
+        ]);
+        let Some(MutationRequest::SqlDelete(delete)) =
+            inverse_mutation(&keyed, None, &columns(), &pk())
+        else {
+            panic!("expected a DELETE");
+        };
+        assert_eq!(delete.identity.values(), &[Value::Int(9)]);
+
+        let generated_key = insert(vec![ColumnAssignment::new("name", Value::Text("a".into()))]);
+        assert!(inverse_mutation(&generated_key, None, &columns(), &pk()).is_none());
+    }
+
+    #[test]
+    fn failed_commit_is_a_transaction_failure() {
+        let (executed, result) = run(None, true);
+
+        assert!(matches!(result, Err(CommitFailure::Transaction(_))));
+        assert_eq!(executed.last().map(String::as_str), Some("ROLLBACK"));
+    }
+}
@@ -0,0 +1,409 @@
+//! "Column Stats": count, NULLs, distinct values, min/max and the most common
+//! values of one column, computed over the whole table with two generated
+//! aggregate queries and shown in a small panel over the grid.
+
+use super::{DataGridPanel, DataSource};
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{Heights, Spacing};
+use dbflux_core::{ColumnKind, QueryRequest, QueryResult, SqlDialect, TableRef, Value};
+use gpui::*;
+use gpui_component::Sizable;
+use gpui_component::button::Button;
+
+/// How many of the most common values the histogram shows.
+const TOP_VALUES: u32 = 10;
+
+/// Statistics panel for the column picked from the context menu.
+pub(super) struct ColumnStatsView {
+    column: String,
+    state: ColumnStatsState,
+}
+
+enum ColumnStatsState {
+    Loading,
+    Ready(ColumnStats),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ColumnStats {
+    pub(super) total: u64,
+    pub(super) nulls: u64,
+    pub(super) distinct: u64,
+    /// `None` for column types without an ordering (booleans, JSON, ...).
+    pub(super) min: Option<String>,
+    pub(super) max: Option<String>,
+    /// Most common values first, with NULL shown as `NULL`.
+    pub(super) top_values: Vec<(String, u64)>,
+}
+
+/// MIN/MAX are only asked for kinds every SQL database can order; others
+/// (booleans in PostgreSQL, JSON, arrays) would fail the whole query.
+fn is_orderable(kind: ColumnKind) -> bool {
+    matches!(
+        kind,
+        ColumnKind::Integer | ColumnKind::Float | ColumnKind::Timestamp | ColumnKind::Text
+    )
+}
+
+/// The summary and top-values queries for `column` of `table`.
+pub(super) fn column_stats_queries(
+    dialect: &dyn SqlDialect,
+    table: &TableRef,
+    column: &str,
+    kind: ColumnKind,
+) -> (String, String) {
+    let from = dialect.qualified_table(table.schema.as_deref(), &table.name);
+    let col = dialect.quote_identifier(column);
+
+    let min_max = if is_orderable(kind) {
+        format!(", MIN({col}), MAX({col})")
+    } else {
+        String::new()
+    };
+
+    let summary =
+        format!("SELECT COUNT(*), COUNT({col}), COUNT(DISTINCT {col}){min_max} FROM {from}");
+    let top_values = format!(
+        "SELECT {col}, COUNT(*) FROM {from} GROUP BY {col} ORDER BY 2 DESC {}",
+        dialect.limit_clause(TOP_VALUES)
+    );
+
+    (summary, top_values)
+}
+
+/// Counts arrive as integers from most drivers; decimals and text are
+/// accepted for the rest.
+fn count_value(value: &Value) -> Option<u64> {
+    match value {
+        Value::Int(n) => u64::try_from(*n).ok(),
+        Value::Float(f) if *f >= 0.0 => Some(*f as u64),
+        Value::Decimal(s) | Value::Text(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        value => value.as_shown_string_truncated(80),
+    }
+}
+
+/// Statistics from the rows of the two queries.
+pub(super) fn column_stats_from_results(
+    summary: &QueryResult,
+    top_values: &QueryResult,
+) -> Result<ColumnStats, String> {
+    let row = summary
+        .rows
+        .first()
+        .ok_or_else(|| "the summary query returned no rows".to_string())?;
+
+    let count = |ix: usize| {
+        row.get(ix)
+            .and_then(count_value)
+            .ok_or_else(|| "the summary query returned an unexpected row".to_string())
+    };
+
+    let total = count(0)?;
+    let non_null = count(1)?;
+
+    let top_values = top_values
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let count = row.get(1).and_then(count_value)?;
+            Some((display_value(row.first()?), count))
+        })
+        .collect();
+
+    Ok(ColumnStats {
+        total,
+        nulls: total.saturating_sub(non_null),
+        distinct: count(2)?,
+        min: row.get(3).map(display_value),
+        max: row.get(4).map(display_value),
+        top_values,
+    })
+}
+
+impl DataGridPanel {
+    /// Whether the clicked column can be profiled: the grid browses a table
+    /// on a SQL connection.
+    pub(super) fn can_show_column_stats(&self, cx: &App) -> bool {
+        let DataSource::Table { profile_id, .. } = &self.source else {
+            return false;
+        };
+
+        self.app_state
+            .read(cx)
+            .connections()
+            .get(profile_id)
+            .is_some_and(|connected| {
+                connected.connection.metadata().query_language == dbflux_core::QueryLanguage::Sql
+            })
+    }
+
+    pub(super) fn open_column_stats(&mut self, col: usize, cx: &mut Context<Self>) {
+        let DataSource::Table {
+            profile_id,
+            database,
+            table,
+            ..
+        } = &self.source
+        else {
+            return;
+        };
+        let Some(column) = self.result.columns.get(col) else {
+            return;
+        };
+
+        let (conn, summary_sql, top_values_sql) = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(profile_id) else {
+                return;
+            };
+            let Ok(conn) = connected.resolve_connection_for_execution(database.as_deref()) else {
+                return;
+            };
+
+            let (summary, top_values) = column_stats_queries(
+                connected.connection.dialect(),
+                table,
+                &column.name,
+                column.kind,
+            );
+            (conn, summary, top_values)
+        };
+
+        let request = |sql: String| {
+            let mut request = QueryRequest::new(sql);
+            request.database = database.clone();
+            request
+        };
+        let summary_request = request(summary_sql);
+        let top_values_request = request(top_values_sql);
+        let column = column.name.clone();
+
+        self.column_stats = Some(ColumnStatsView {
+            column: column.clone(),
+            state: ColumnStatsState::Loading,
+        });
+        cx.notify();
+
+        let task = cx.background_executor().spawn(async move {
+            let summary = conn.execute(&summary_request).map_err(|e| e.to_string())?;
+            let top_values = conn
+                .execute(&top_values_request)
+                .map_err(|e| e.to_string())?;
+            column_stats_from_results(&summary, &top_values)
+        });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+
+            if let Err(e) = this.update(cx, |panel, cx| {
+                // The panel was closed or reopened on another column meanwhile.
+                let Some(view) = panel.column_stats.as_mut() else {
+                    return;
+                };
+                if view.column != column {
+                    return;
+                }
+
+                view.state = match result {
+                    Ok(stats) => ColumnStatsState::Ready(stats),
+                    Err(message) => ColumnStatsState::Failed(message),
+                };
+                cx.notify();
+            }) {
+                log::warn!("Failed to apply column stats: {:?}", e);
+            }
+        })
+        .detach();
+    }
+
+    pub(super) fn close_column_stats(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.column_stats.take().is_some() {
+            self.focus_active_view(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn render_column_stats(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let Some(view) = self.column_stats.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let stat_row = |label: &'static str, value: String| {
+            div()
+                .flex()
+                .justify_between()
+                .gap(Spacing::SM)
+                .child(Text::caption(label).muted_foreground())
+                .child(Text::code(value))
+        };
+
+        let body =
+            match &view.state {
+                ColumnStatsState::Loading => div()
+                    .child(Text::muted("Computing statistics…"))
+                    .into_any_element(),
+                ColumnStatsState::Failed(message) => div()
+                    .child(Text::caption(format!("Cannot compute statistics: {message}")).danger())
+                    .into_any_element(),
+                ColumnStatsState::Ready(stats) => {
+                    let max_count = stats
+                        .top_values
+                        .iter()
+                        .map(|(_, count)| *count)
+                        .max()
+                        .unwrap_or(0)
+                        .max(1);
+
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(Spacing::XS)
+                        .child(stat_row("Rows", stats.total.to_string()))
+                        .child(stat_row("NULLs", stats.nulls.to_string()))
+                        .child(stat_row("Distinct", stats.distinct.to_string()))
+                        .when_some(stats.min.clone(), |d, min| d.child(stat_row("Min", min)))
+                        .when_some(stats.max.clone(), |d, max| d.child(stat_row("Max", max)))
+                        .when(!stats.top_values.is_empty(), |d| {
+                            d.child(div().pt(Spacing::SM).child(Text::label("Top values")))
+                                .children(stats.top_values.iter().map(|(value, count)| {
+                                    let share = *count as f32 / max_count as f32;
+
+                                    div()
+                                        .flex()
+                                        .items_center()
+                                        .gap(Spacing::SM)
+                                        .h(Heights::ROW_COMPACT)
+                                        .child(
+                                            div()
+                                                .w(px(120.0))
+                                                .overflow_hidden()
+                                                .whitespace_nowrap()
+                                                .child(Text::caption(value.clone())),
+                                        )
+                                        .child(div().flex_1().h(px(10.0)).bg(theme.muted).child(
+                                            div().h_full().w(relative(share)).bg(theme.primary),
+                                        ))
+                                        .child(Text::caption(count.to_string()).muted_foreground())
+                                }))
+                        })
+                        .into_any_element()
+                }
+            };
+
+        div()
+            .id("column-stats-overlay")
+            .absolute()
+            .inset_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(theme.background.opacity(0.6))
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .bg(theme.secondary)
+                    .border_1()
+                    .border_color(theme.border)
+                    .p(Spacing::LG)
+                    .w(px(380.0))
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::SM)
+                    .child(Text::label(format!("Column stats: {}", view.column)))
+                    .child(body)
+                    .child(
+                        div().flex().flex_row().justify_end().child(
+                            Button::new("close-column-stats")
+                                .label("Close")
+                                .small()
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.close_column_stats(window, cx);
+                                })),
+                        ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::{ColumnMeta, DefaultSqlDialect};
+    use std::time::Duration;
+
+    fn result(rows: Vec<Vec<Value>>) -> QueryResult {
+        let columns = (0..rows.first().map_or(0, Vec::len))
+            .map(|ix| ColumnMeta {
+                name: format!("c{ix}"),
+                type_name: String::new(),
+                kind: ColumnKind::Unknown,
+                nullable: true,
+                is_primary_key: false,
+            })
+            .collect();
+        QueryResult::table(columns, rows, None, Duration::ZERO)
+    }
+
+    #[test]
+    fn queries_skip_min_max_for_unordered_kinds() {
+        let table = TableRef::with_schema("public", "users");
+
+        let (summary, top_values) =
+            column_stats_queries(&DefaultSqlDialect, &table, "age", ColumnKind::Integer);
+        assert_eq!(
+            summary,
+            "SELECT COUNT(*), COUNT(\"age\"), COUNT(DISTINCT \"age\"), MIN(\"age\"), MAX(\"age\") \
+             FROM \"public\".\"users\""
+        );
+        assert_eq!(
+            top_values,
+            "SELECT \"age\", COUNT(*) FROM \"public\".\"users\" GROUP BY \"age\" ORDER BY 2 DESC LIMIT 10"
+        );
+
+        let (summary, _) =
+            column_stats_queries(&DefaultSqlDialect, &table, "active", ColumnKind::Unknown);
+        assert!(!summary.contains("MIN("));
+    }
+
+    #[test]
+    fn stats_are_read_from_both_results() {
+        let summary = result(vec![vec![
+            Value::Int(10),
+            Value::Decimal("7".to_string()),
+            Value::Int(3),
+            Value::Int(1),
+            Value::Int(42),
+        ]]);
+        let top_values = result(vec![
+            vec![Value::Int(1), Value::Int(5)],
+            vec![Value::Null, Value::Int(3)],
+        ]);
+
+        let stats = column_stats_from_results(&summary, &top_values).unwrap();
+
+        assert_eq!(stats.total, 10);
+        assert_eq!(stats.nulls, 3);
+        assert_eq!(stats.distinct, 3);
+        assert_eq!(stats.min.as_deref(), Some("1"));
+        assert_eq!(stats.max.as_deref(), Some("42"));
+        assert_eq!(
+            stats.top_values,
+            vec![("1".to_string(), 5), ("NULL".to_string(), 3)]
+        );
+    }
+}
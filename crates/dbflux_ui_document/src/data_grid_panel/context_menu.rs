@@ -1,18 +1,21 @@
 use super::utils::{extract_pk_columns, value_to_json};
 use super::{
-    ContextMenuItem, DataGridEvent, DataGridPanel, DataSource, EditState, PendingDeleteConfirm,
-    PendingDocumentPreview, PendingModalOpen, PendingToast, SqlGenerateKind, TableContextMenu,
+    ColumnMenuState, ContextMenuItem, DataGridEvent, DataGridPanel, DataSource, EditState,
+    ForeignKeyMenuState, PendingDeleteConfirm, PendingDocumentPreview, PendingModalOpen,
+    PendingToast, SqlGenerateKind, TableContextMenu,
 };
+use crate::chart::export_chart;
 use dbflux_app::keymap::{Command, ContextId};
-use dbflux_components::chart::detect_chart_columns;
+use dbflux_components::chart::{ChartExportOption, detect_chart_columns};
+use dbflux_components::components::data_table::HEADER_HEIGHT;
 use dbflux_components::components::data_table::{ContextMenuAction, FilterOperator};
-use dbflux_components::components::data_table::{HEADER_HEIGHT, ROW_HEIGHT};
+use dbflux_components::density;
 use dbflux_components::icons::AppIcon;
 use dbflux_components::primitives::{Icon, Text, overlay_bg, surface_panel, surface_raised};
 use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
 use dbflux_core::{
-    DocumentDelete, DocumentFilter, DocumentInsert, DocumentUpdate, MutationRequest, RowDelete,
-    RowIdentity, RowInsert, RowPatch, Value,
+    DocumentDelete, DocumentFilter, DocumentInsert, DocumentUpdate, MutationRequest, QueryResult,
+    RowDelete, RowIdentity, RowInsert, RowPatch, Value,
 };
 use dbflux_export::ExportFormat;
 use dbflux_ui_base::AsyncUpdateResultExt;
@@ -20,8 +23,19 @@ use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use gpui::prelude::FluentBuilder;
 use gpui::{deferred, *};
 use gpui_component::ActiveTheme;
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::BufWriter;
+use std::time::Duration;
+
+/// Which text a single-cell copy puts on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CellCopy {
+    /// Exactly as read from the server.
+    Raw,
+    /// As the grid displays it.
+    Display,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum FilterBackend {
@@ -81,11 +95,15 @@ impl DataGridPanel {
 
         // Calculate position in window coordinates:
         // x: panel_origin.x + cell_x - horizontal_scroll + some padding
-        // y: panel_origin.y + HEADER_HEIGHT + (row * ROW_HEIGHT) + some padding for toolbar
+        // y: panel_origin.y + header height + (row * row height) + some padding for toolbar
         let toolbar_height = px(36.0); // Approximate toolbar height
+        let header_height = density::zoomed(cx, HEADER_HEIGHT);
         let position = Point {
             x: self.panel_origin.x + px(cell_x) - horizontal_offset + px(20.0),
-            y: self.panel_origin.y + toolbar_height + HEADER_HEIGHT + ROW_HEIGHT * row,
+            y: self.panel_origin.y
+                + toolbar_height
+                + header_height
+                + density::grid_row_height(cx) * row,
         };
 
         self.context_menu = Some(TableContextMenu {
@@ -94,6 +112,7 @@ impl DataGridPanel {
             position,
             sql_submenu_open: false,
             copy_query_submenu_open: false,
+            copy_selection_submenu_open: false,
             filter_submenu_open: false,
             order_submenu_open: false,
             selected_index: 0,
@@ -125,6 +144,7 @@ impl DataGridPanel {
             position,
             sql_submenu_open: false,
             copy_query_submenu_open: false,
+            copy_selection_submenu_open: false,
             filter_submenu_open: false,
             order_submenu_open: false,
             selected_index: 0,
@@ -182,6 +202,7 @@ impl DataGridPanel {
             position,
             sql_submenu_open: false,
             copy_query_submenu_open: false,
+            copy_selection_submenu_open: false,
             filter_submenu_open: false,
             order_submenu_open: false,
             selected_index: 0,
@@ -314,6 +335,7 @@ impl DataGridPanel {
                 | Value::Float(_)
                 | Value::Decimal(_)
                 | Value::DateTime(_)
+                | Value::NaiveDateTime(_)
                 | Value::Date(_)
                 | Value::Time(_)
         )
@@ -334,8 +356,12 @@ impl DataGridPanel {
                 | Value::Float(_)
                 | Value::Decimal(_)
                 | Value::DateTime(_)
+                | Value::NaiveDateTime(_)
                 | Value::Date(_)
                 | Value::Time(_)
+                | Value::Interval(_)
+                | Value::Uuid(_)
+                | Value::Enum(_)
         ) {
             return true;
         }
@@ -413,8 +439,12 @@ impl DataGridPanel {
                 format!("ObjectId(\"{}\")", Self::truncate_for_label(oid, 12))
             }
             Value::DateTime(dt) => format!("\"{}\"", dt.to_rfc3339()),
+            Value::NaiveDateTime(dt) => format!("\"{}\"", dt),
             Value::Date(d) => format!("\"{}\"", d),
             Value::Time(t) => format!("\"{}\"", t),
+            Value::Uuid(uuid) => format!("\"{}\"", uuid),
+            Value::Interval(interval) => format!("\"{}\"", interval),
+            Value::Enum(label) => format!("\"{}\"", Self::sanitize_for_label(label)),
             Value::Unsupported(type_name) => {
                 format!("<unsupported:{}>", Self::truncate_for_label(type_name, 20))
             }
@@ -536,6 +566,7 @@ impl DataGridPanel {
     /// Returns the active context for keyboard handling.
     pub fn active_context(&self, cx: &App) -> ContextId {
         if self.document_view.cell_editor.read(cx).is_visible()
+            || self.document_view.cell_viewer.read(cx).is_visible()
             || self
                 .document_view
                 .document_preview_modal
@@ -578,6 +609,8 @@ impl DataGridPanel {
         let has_order = matches!(backend, Some(FilterBackend::Sql)) && !is_document_view;
         let has_generate_sql = !is_document_view;
         let has_copy_query = self.has_copy_query_support();
+        let has_copy_selection = !is_document_view;
+        let copy_selection_formats = self.selection_copy_formats(cx);
         let can_chart = self.can_chart_from_context_menu(cx);
 
         // Layout:
@@ -586,15 +619,25 @@ impl DataGridPanel {
         //   [Order trigger]?          (if has_order, shares separator with filter)
         //   [sep + GenSQL trigger]?   (if has_generate_sql)
         //   [sep + CopyQuery trigger]?(if has_copy_query)
+        //   [sep + CopySelection]?    (if has_copy_selection)
         //   [sep + row_action...]?    (if row_actions non-empty)
         let inspect_row_enabled = !self.is_grouped_result();
 
+        let columns = self.column_menu_state(is_document_view, cx);
+        let foreign_keys = self
+            .context_menu
+            .as_ref()
+            .map(|m| self.foreign_key_menu_state(m.row, m.col, is_document_view, cx))
+            .unwrap_or_default();
+
         let base_items = Self::build_context_menu_items(
             is_editable,
             is_document_view,
             has_row_target,
             can_chart,
             inspect_row_enabled,
+            columns,
+            foreign_keys,
         );
         let base_count = base_items.len();
 
@@ -611,6 +654,10 @@ impl DataGridPanel {
         let copy_query_slots = if has_copy_query { 2 } else { 0 };
         let after_copy_query = after_gen_sql + copy_query_slots;
 
+        // CopySelection: sep(1) + trigger(1) = 2
+        let copy_selection_slots = if has_copy_selection { 2 } else { 0 };
+        let after_copy_selection = after_copy_query + copy_selection_slots;
+
         // RowActions: sep(1) + N action items
         let row_action_count = self
             .context_menu
@@ -622,8 +669,8 @@ impl DataGridPanel {
         } else {
             0
         };
-        let row_actions_start = after_copy_query; // index of the separator
-        let total_count = after_copy_query + row_actions_slots;
+        let row_actions_start = after_copy_selection; // index of the separator
+        let total_count = after_copy_selection + row_actions_slots;
 
         let filter_trigger_idx = if has_filter {
             Some(base_count + 1) // after separator
@@ -649,12 +696,19 @@ impl DataGridPanel {
             None
         };
 
+        let copy_selection_trigger_idx = if has_copy_selection {
+            Some(after_copy_query + 1) // after separator
+        } else {
+            None
+        };
+
         let any_submenu_open = self
             .context_menu
             .as_ref()
             .map(|m| {
                 m.sql_submenu_open
                     || m.copy_query_submenu_open
+                    || m.copy_selection_submenu_open
                     || m.filter_submenu_open
                     || m.order_submenu_open
             })
@@ -680,6 +734,8 @@ impl DataGridPanel {
                 4 // SELECT WHERE, INSERT, UPDATE, DELETE
             } else if menu.copy_query_submenu_open {
                 3 // INSERT, UPDATE, DELETE
+            } else if menu.copy_selection_submenu_open {
+                copy_selection_formats.len()
             } else {
                 0
             }
@@ -707,6 +763,11 @@ impl DataGridPanel {
                 return true;
             }
 
+            // CopySelection separator
+            if has_copy_selection && idx == after_copy_query {
+                return true;
+            }
+
             // Row actions separator
             if row_action_count > 0 && idx == row_actions_start {
                 return true;
@@ -820,11 +881,22 @@ impl DataGridPanel {
                             _ => ContextMenuAction::CopyAsDelete,
                         };
                         self.handle_context_menu_action(action, window, cx);
+                    } else if menu.copy_selection_submenu_open {
+                        if let Some(&(_, format)) =
+                            copy_selection_formats.get(menu.submenu_selected_index)
+                        {
+                            self.handle_context_menu_action(
+                                ContextMenuAction::CopySelectionAs(format),
+                                window,
+                                cx,
+                            );
+                        }
                     } else if filter_trigger_idx == Some(menu.selected_index) {
                         menu.filter_submenu_open = true;
                         menu.order_submenu_open = false;
                         menu.sql_submenu_open = false;
                         menu.copy_query_submenu_open = false;
+                        menu.copy_selection_submenu_open = false;
                         menu.submenu_selected_index = 0;
                         cx.notify();
                     } else if order_trigger_idx == Some(menu.selected_index) {
@@ -832,6 +904,7 @@ impl DataGridPanel {
                         menu.filter_submenu_open = false;
                         menu.sql_submenu_open = false;
                         menu.copy_query_submenu_open = false;
+                        menu.copy_selection_submenu_open = false;
                         menu.submenu_selected_index = 0;
                         cx.notify();
                     } else if gen_sql_trigger_idx == Some(menu.selected_index) {
@@ -839,10 +912,20 @@ impl DataGridPanel {
                         menu.filter_submenu_open = false;
                         menu.order_submenu_open = false;
                         menu.copy_query_submenu_open = false;
+                        menu.copy_selection_submenu_open = false;
                         menu.submenu_selected_index = 0;
                         cx.notify();
                     } else if copy_query_trigger_idx == Some(menu.selected_index) {
                         menu.copy_query_submenu_open = true;
+                        menu.copy_selection_submenu_open = false;
+                        menu.filter_submenu_open = false;
+                        menu.order_submenu_open = false;
+                        menu.sql_submenu_open = false;
+                        menu.submenu_selected_index = 0;
+                        cx.notify();
+                    } else if copy_selection_trigger_idx == Some(menu.selected_index) {
+                        menu.copy_selection_submenu_open = true;
+                        menu.copy_query_submenu_open = false;
                         menu.filter_submenu_open = false;
                         menu.order_submenu_open = false;
                         menu.sql_submenu_open = false;
@@ -861,11 +944,13 @@ impl DataGridPanel {
                 if let Some(ref mut menu) = self.context_menu {
                     if menu.sql_submenu_open
                         || menu.copy_query_submenu_open
+                        || menu.copy_selection_submenu_open
                         || menu.filter_submenu_open
                         || menu.order_submenu_open
                     {
                         menu.sql_submenu_open = false;
                         menu.copy_query_submenu_open = false;
+                        menu.copy_selection_submenu_open = false;
                         menu.filter_submenu_open = false;
                         menu.order_submenu_open = false;
                         cx.notify();
@@ -899,13 +984,36 @@ impl DataGridPanel {
             .is_some()
     }
 
+    /// Column items of the table view's menu; `None` in the document view.
+    fn column_menu_state(&self, is_document_view: bool, cx: &App) -> Option<ColumnMenuState> {
+        if is_document_view {
+            return None;
+        }
+
+        let state = self.grid_table.table_state.as_ref()?.read(cx);
+
+        Some(ColumnMenuState {
+            has_frozen: state.frozen_column_count() > 0,
+            has_hidden: state.hidden_column_count() > 0,
+            can_hide: state.display_columns().len() > 1,
+            can_show_stats: self.can_show_column_stats(cx),
+        })
+    }
+
     fn context_menu_action_requires_row_target(action: ContextMenuAction) -> bool {
         matches!(
             action,
             ContextMenuAction::Edit
                 | ContextMenuAction::EditInModal
+                | ContextMenuAction::ViewValue
+                | ContextMenuAction::CopyRawValue
+                | ContextMenuAction::CopyDisplayValue
+                | ContextMenuAction::GoToReferencedRow
+                | ContextMenuAction::ShowReferencingRows
                 | ContextMenuAction::SetDefault
                 | ContextMenuAction::SetNull
+                | ContextMenuAction::SetEmpty
+                | ContextMenuAction::SetValueInSelection
                 | ContextMenuAction::DuplicateRow
                 | ContextMenuAction::DeleteRow
                 | ContextMenuAction::GenerateSelectWhere
@@ -915,6 +1023,7 @@ impl DataGridPanel {
                 | ContextMenuAction::CopyAsInsert
                 | ContextMenuAction::CopyAsUpdate
                 | ContextMenuAction::CopyAsDelete
+                | ContextMenuAction::CopySelectionAs(_)
                 | ContextMenuAction::FilterByValue(_)
         )
     }
@@ -948,7 +1057,7 @@ impl DataGridPanel {
     ) {
         self.chrome.export_menu_open = false;
 
-        let result = self.result.clone();
+        let result = output_result(&self.result).into_owned();
         let base_name = self.export_base_name();
         let extension = format.extension();
         let suggested_name = format!("{}.{}", base_name, extension);
@@ -1045,6 +1154,108 @@ impl DataGridPanel {
         .detach();
     }
 
+    /// Writes a binary value from the cell viewer to a file, either raw or
+    /// hex-encoded, through the binary exporters. Raw images keep their own
+    /// extension so the file opens in an image viewer.
+    pub(super) fn save_cell_bytes(
+        &mut self,
+        column: &str,
+        bytes: &[u8],
+        as_hex: bool,
+        image_extension: Option<&'static str>,
+        cx: &mut Context<Self>,
+    ) {
+        let format = if as_hex {
+            ExportFormat::Hex
+        } else {
+            ExportFormat::Binary
+        };
+
+        let result = QueryResult::binary(bytes.to_vec(), Duration::ZERO);
+        let mut contents: Vec<u8> = Vec::new();
+
+        if let Err(e) = dbflux_export::export(&result, format, &mut contents) {
+            self.pending.toast = Some(PendingToast {
+                message: format!("Save failed: {}", e),
+                is_error: true,
+            });
+            cx.notify();
+            return;
+        }
+
+        let extension = image_extension.unwrap_or(format.extension());
+        self.save_cell_value(column, contents, extension, cx);
+    }
+
+    /// Writes a value from the cell viewer to a file, named after the
+    /// source and column.
+    pub(super) fn save_cell_value(
+        &mut self,
+        column: &str,
+        contents: Vec<u8>,
+        extension: &'static str,
+        cx: &mut Context<Self>,
+    ) {
+        let suggested_name = format!("{}_{}.{}", self.export_base_name(), column, extension);
+        let entity = cx.entity().clone();
+        let dialog_available = dbflux_ui_base::file_dialog::is_native_file_dialog_available();
+
+        cx.spawn(async move |_this, cx| {
+            let target: Result<Option<(std::path::PathBuf, bool)>, String> = if dialog_available {
+                let file_handle = rfd::AsyncFileDialog::new()
+                    .set_title("Save Value")
+                    .set_file_name(&suggested_name)
+                    .add_filter(extension.to_uppercase(), &[extension])
+                    .save_file()
+                    .await;
+
+                Ok(file_handle.map(|handle| (handle.path().to_path_buf(), false)))
+            } else {
+                dbflux_ui_base::file_dialog::fallback_export_dir()
+                    .map(|dir| {
+                        Some((
+                            dbflux_ui_base::file_dialog::unique_path_in(&dir, &suggested_name),
+                            true,
+                        ))
+                    })
+            };
+
+            let (message, is_error) = match target {
+                // The user cancelled the native dialog.
+                Ok(None) => return,
+                Ok(Some((target_path, used_fallback))) => {
+                    match std::fs::write(&target_path, contents) {
+                        Ok(()) if used_fallback => (
+                            format!(
+                                "Native file picker unavailable — saved to {} instead",
+                                target_path.display()
+                            ),
+                            false,
+                        ),
+                        Ok(()) => (format!("Saved to {}", target_path.display()), false),
+                        Err(e) => (format!("Save failed: {}", e), true),
+                    }
+                }
+                Err(err) => (
+                    format!(
+                        "Save failed — file dialog unavailable and fallback directory could not be created: {}",
+                        err
+                    ),
+                    true,
+                ),
+            };
+
+            cx.update(|cx| {
+                entity.update(cx, |panel, cx| {
+                    panel.pending.toast = Some(PendingToast { message, is_error });
+                    cx.notify();
+                });
+            })
+            .log_if_dropped();
+        })
+        .detach();
+    }
+
     pub fn copy_to_clipboard_with_format(
         &mut self,
         format: ExportFormat,
@@ -1064,14 +1275,35 @@ impl DataGridPanel {
             return;
         }
 
+        if matches!(format, ExportFormat::Parquet) {
+            self.pending.toast = Some(PendingToast {
+                message: "Parquet cannot be copied to the clipboard — save it to a file instead."
+                    .to_string(),
+                is_error: true,
+            });
+            cx.notify();
+            return;
+        }
+
         let mut buffer: Vec<u8> = Vec::new();
-        let export_result = dbflux_export::export(&self.result, format, &mut buffer);
+        let output = dbflux_export::export(&output_result(&self.result), format, &mut buffer)
+            .map(|()| buffer);
 
-        let format_name = format.name();
+        self.write_export_to_clipboard(format.name(), output, cx);
+    }
+
+    /// Puts exported text on the clipboard, records the copy in the audit
+    /// log and reports the outcome with a toast.
+    pub(super) fn write_export_to_clipboard(
+        &mut self,
+        format_name: &str,
+        output: Result<Vec<u8>, dbflux_export::ExportError>,
+        cx: &mut Context<Self>,
+    ) {
         let audit_service = self.app_state.read(cx).audit_service().clone();
 
-        match export_result {
-            Ok(()) => match String::from_utf8(buffer) {
+        match output {
+            Ok(buffer) => match String::from_utf8(buffer) {
                 Ok(text) => {
                     let byte_len = text.len();
                     cx.write_to_clipboard(ClipboardItem::new_string(text));
@@ -1107,6 +1339,14 @@ impl DataGridPanel {
         }
     }
 
+    /// Save the chart shown in Chart mode as an image named after the source.
+    pub(super) fn export_chart_image(&mut self, option: ChartExportOption, cx: &mut Context<Self>) {
+        let Some(shell) = self.chart.chart_shell.clone() else {
+            return;
+        };
+        export_chart(&shell, &self.export_base_name(), option, cx);
+    }
+
     fn export_base_name(&self) -> String {
         match &self.source {
             DataSource::Table { table, .. } => table.name.clone(),
@@ -1127,6 +1367,8 @@ impl DataGridPanel {
         has_row_target: bool,
         can_chart: bool,
         inspect_row_enabled: bool,
+        columns: Option<ColumnMenuState>,
+        foreign_keys: ForeignKeyMenuState,
     ) -> Vec<ContextMenuItem> {
         if is_document_view {
             // Document view menu: Copy, View/Edit Document, CRUD operations
@@ -1202,6 +1444,53 @@ impl DataGridPanel {
             is_danger: false,
         }];
 
+        if has_row_target {
+            items.push(ContextMenuItem {
+                label: "View Value",
+                action: Some(ContextMenuAction::ViewValue),
+                icon: Some(AppIcon::Eye),
+                is_separator: false,
+                is_danger: false,
+            });
+
+            if foreign_keys.references {
+                items.push(ContextMenuItem {
+                    label: "Go to Referenced Row",
+                    action: Some(ContextMenuAction::GoToReferencedRow),
+                    icon: Some(AppIcon::Link2),
+                    is_separator: false,
+                    is_danger: false,
+                });
+            }
+
+            if foreign_keys.referenced_by {
+                items.push(ContextMenuItem {
+                    label: "Show Referencing Rows",
+                    action: Some(ContextMenuAction::ShowReferencingRows),
+                    icon: Some(AppIcon::Rows3),
+                    is_separator: false,
+                    is_danger: false,
+                });
+            }
+
+            items.extend([
+                ContextMenuItem {
+                    label: "Copy Raw Value",
+                    action: Some(ContextMenuAction::CopyRawValue),
+                    icon: Some(AppIcon::Copy),
+                    is_separator: false,
+                    is_danger: false,
+                },
+                ContextMenuItem {
+                    label: "Copy Display Value",
+                    action: Some(ContextMenuAction::CopyDisplayValue),
+                    icon: Some(AppIcon::Copy),
+                    is_separator: false,
+                    is_danger: false,
+                },
+            ]);
+        }
+
         if is_editable {
             if has_row_target {
                 items.extend([
@@ -1247,6 +1536,20 @@ impl DataGridPanel {
                         is_separator: false,
                         is_danger: false,
                     },
+                    ContextMenuItem {
+                        label: "Set to Empty String",
+                        action: Some(ContextMenuAction::SetEmpty),
+                        icon: Some(AppIcon::CaseSensitive),
+                        is_separator: false,
+                        is_danger: false,
+                    },
+                    ContextMenuItem {
+                        label: "Set Value in Selection…",
+                        action: Some(ContextMenuAction::SetValueInSelection),
+                        icon: Some(AppIcon::Pencil),
+                        is_separator: false,
+                        is_danger: false,
+                    },
                     ContextMenuItem {
                         label: "",
                         action: None,
@@ -1257,13 +1560,22 @@ impl DataGridPanel {
                 ]);
             }
 
-            items.push(ContextMenuItem {
-                label: "Add Row",
-                action: Some(ContextMenuAction::AddRow),
-                icon: Some(AppIcon::Plus),
-                is_separator: false,
-                is_danger: false,
-            });
+            items.extend([
+                ContextMenuItem {
+                    label: "Add Row",
+                    action: Some(ContextMenuAction::AddRow),
+                    icon: Some(AppIcon::Plus),
+                    is_separator: false,
+                    is_danger: false,
+                },
+                ContextMenuItem {
+                    label: "Paste as New Rows",
+                    action: Some(ContextMenuAction::PasteAsRows),
+                    icon: Some(AppIcon::Download),
+                    is_separator: false,
+                    is_danger: false,
+                },
+            ]);
 
             if has_row_target {
                 if inspect_row_enabled {
@@ -1294,6 +1606,65 @@ impl DataGridPanel {
             }
         }
 
+        if let Some(columns) = columns {
+            items.extend([
+                ContextMenuItem {
+                    label: "",
+                    action: None,
+                    icon: None,
+                    is_separator: true,
+                    is_danger: false,
+                },
+                ContextMenuItem {
+                    label: "Freeze Up To Column",
+                    action: Some(ContextMenuAction::FreezeColumns),
+                    icon: Some(AppIcon::Pin),
+                    is_separator: false,
+                    is_danger: false,
+                },
+            ]);
+
+            if columns.has_frozen {
+                items.push(ContextMenuItem {
+                    label: "Unfreeze Columns",
+                    action: Some(ContextMenuAction::UnfreezeColumns),
+                    icon: Some(AppIcon::X),
+                    is_separator: false,
+                    is_danger: false,
+                });
+            }
+
+            if columns.can_hide {
+                items.push(ContextMenuItem {
+                    label: "Hide Column",
+                    action: Some(ContextMenuAction::HideColumn),
+                    icon: Some(AppIcon::EyeOff),
+                    is_separator: false,
+                    is_danger: false,
+                });
+            }
+
+            if columns.has_hidden {
+                items.push(ContextMenuItem {
+                    label: "Show All Columns",
+                    action: Some(ContextMenuAction::ShowAllColumns),
+                    icon: Some(AppIcon::Eye),
+                    is_separator: false,
+                    is_danger: false,
+                });
+            }
+
+            if columns.can_show_stats {
+                items.push(ContextMenuItem {
+                    label: "Column Stats",
+                    action: Some(ContextMenuAction::ColumnStats),
+                    icon: Some(AppIcon::ChartBar),
+                    is_separator: false,
+                    is_danger: false,
+                });
+            }
+        }
+
         if can_chart {
             items.push(ContextMenuItem {
                 label: "",
@@ -1318,8 +1689,15 @@ impl DataGridPanel {
     /// This includes all visible items plus the Generate SQL trigger (for table view).
     #[allow(dead_code)]
     pub(super) fn context_menu_item_count(is_editable: bool, is_document_view: bool) -> usize {
-        let base_items =
-            Self::build_context_menu_items(is_editable, is_document_view, true, false, true);
+        let base_items = Self::build_context_menu_items(
+            is_editable,
+            is_document_view,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         let base_count = base_items.iter().filter(|i| !i.is_separator).count();
         // Add 1 for Generate SQL only in table view
         if is_document_view {
@@ -1456,12 +1834,17 @@ impl DataGridPanel {
         let has_row_target = self.has_context_menu_row_target(menu.row, menu.is_document_view, cx);
         let can_chart = self.can_chart_from_context_menu(cx);
         let inspect_row_enabled = !self.is_grouped_result();
+        let columns = self.column_menu_state(menu.is_document_view, cx);
+        let foreign_keys =
+            self.foreign_key_menu_state(menu.row, menu.col, menu.is_document_view, cx);
         let visible_items = Self::build_context_menu_items(
             is_editable,
             menu.is_document_view,
             has_row_target,
             can_chart,
             inspect_row_enabled,
+            columns,
+            foreign_keys,
         );
         let selected_index = menu.selected_index;
         let is_document_view = menu.is_document_view;
@@ -1634,6 +2017,7 @@ impl DataGridPanel {
                             menu.order_submenu_open = false;
                             menu.sql_submenu_open = false;
                             menu.copy_query_submenu_open = false;
+                            menu.copy_selection_submenu_open = false;
                             menu.submenu_selected_index = 0;
                             cx.notify();
                         }
@@ -1845,6 +2229,7 @@ impl DataGridPanel {
                             menu.filter_submenu_open = false;
                             menu.sql_submenu_open = false;
                             menu.copy_query_submenu_open = false;
+                            menu.copy_selection_submenu_open = false;
                             menu.submenu_selected_index = 0;
                             cx.notify();
                         }
@@ -2066,6 +2451,7 @@ impl DataGridPanel {
                         if let Some(ref mut menu) = this.context_menu {
                             menu.sql_submenu_open = !menu.sql_submenu_open;
                             menu.copy_query_submenu_open = false;
+                            menu.copy_selection_submenu_open = false;
                             menu.submenu_selected_index = 0;
                             cx.notify();
                         }
@@ -2163,6 +2549,7 @@ impl DataGridPanel {
                     })
                     .into_any_element(),
             );
+            visual_index += 1;
         }
 
         // -- Copy as Query submenu --
@@ -2219,6 +2606,7 @@ impl DataGridPanel {
                     .on_click(cx.listener(|this, _, _, cx| {
                         if let Some(ref mut menu) = this.context_menu {
                             menu.copy_query_submenu_open = !menu.copy_query_submenu_open;
+                            menu.copy_selection_submenu_open = false;
                             menu.sql_submenu_open = false;
                             menu.submenu_selected_index = 0;
                             cx.notify();
@@ -2318,6 +2706,171 @@ impl DataGridPanel {
                     })
                     .into_any_element(),
             );
+            visual_index += 1;
+        }
+
+        // -- Copy Selection As submenu (table view only) --
+        if !is_document_view {
+            menu_items.push(
+                div()
+                    .h(px(1.0))
+                    .mx(Spacing::SM)
+                    .my(Spacing::XS)
+                    .bg(theme.border)
+                    .into_any_element(),
+            );
+            visual_index += 1;
+
+            let copy_selection_open = menu.copy_selection_submenu_open;
+            let submenu_bg = theme.popover;
+            let submenu_border = theme.border;
+            let submenu_fg = theme.foreground;
+            let submenu_hover = theme.secondary;
+            let copy_selection_index = visual_index;
+            let copy_selection_selected = selected_index == copy_selection_index;
+            let submenu_selected_index = menu.submenu_selected_index;
+            let formats = self.selection_copy_formats(cx);
+
+            menu_items.push(
+                div()
+                    .id("copy-selection-trigger")
+                    .relative()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .h(Heights::ROW_COMPACT)
+                    .px(Spacing::SM)
+                    .mx(Spacing::XS)
+                    .rounded(Radii::SM)
+                    .cursor_pointer()
+                    .text_size(FontSizes::SM)
+                    .when(copy_selection_open, |d| d.bg(submenu_hover))
+                    .when(copy_selection_selected && !copy_selection_open, |d| {
+                        d.bg(theme.accent)
+                    })
+                    .when(!copy_selection_selected && !copy_selection_open, |d| {
+                        d.hover(|d| d.bg(submenu_hover))
+                    })
+                    .on_mouse_move(cx.listener(move |this, _, _, cx| {
+                        if let Some(ref mut menu) = this.context_menu
+                            && menu.selected_index != copy_selection_index
+                            && !menu.copy_selection_submenu_open
+                        {
+                            menu.selected_index = copy_selection_index;
+                            cx.notify();
+                        }
+                    }))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        if let Some(ref mut menu) = this.context_menu {
+                            menu.copy_selection_submenu_open = !menu.copy_selection_submenu_open;
+                            menu.copy_query_submenu_open = false;
+                            menu.sql_submenu_open = false;
+                            menu.submenu_selected_index = 0;
+                            cx.notify();
+                        }
+                    }))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap(Spacing::SM)
+                            .child(Icon::new(AppIcon::Layers).small().color(
+                                if copy_selection_selected && !copy_selection_open {
+                                    theme.accent_foreground
+                                } else {
+                                    submenu_fg
+                                },
+                            ))
+                            .child("Copy Selection As"),
+                    )
+                    .child(Icon::new(AppIcon::ChevronRight).small().color(
+                        if copy_selection_selected && !copy_selection_open {
+                            theme.accent_foreground
+                        } else {
+                            theme.muted_foreground
+                        },
+                    ))
+                    .when(copy_selection_open, |d: Stateful<Div>| {
+                        d.child(
+                            div()
+                                .absolute()
+                                .left(px(172.0))
+                                .top(px(-4.0))
+                                .w(px(140.0))
+                                .bg(submenu_bg)
+                                .border_1()
+                                .border_color(submenu_border)
+                                .rounded(Radii::MD)
+                                .shadow_lg()
+                                .py(Spacing::XS)
+                                .occlude()
+                                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                                    cx.stop_propagation();
+                                })
+                                .children(
+                                    formats
+                                        .into_iter()
+                                        .enumerate()
+                                        .map(|(idx, (label, format))| {
+                                            let is_submenu_selected = idx == submenu_selected_index;
+                                            let item_color = if is_submenu_selected {
+                                                theme.accent_foreground
+                                            } else {
+                                                submenu_fg
+                                            };
+                                            let action = ContextMenuAction::CopySelectionAs(format);
+                                            div()
+                                                .id(SharedString::from(format!(
+                                                    "copy-selection-{}",
+                                                    label
+                                                )))
+                                                .flex()
+                                                .items_center()
+                                                .gap(Spacing::SM)
+                                                .h(Heights::ROW_COMPACT)
+                                                .px(Spacing::SM)
+                                                .mx(Spacing::XS)
+                                                .rounded(Radii::SM)
+                                                .cursor_pointer()
+                                                .text_size(FontSizes::SM)
+                                                .when(is_submenu_selected, |d| d.bg(theme.accent))
+                                                .when(!is_submenu_selected, |d| {
+                                                    d.hover(|d| d.bg(submenu_hover))
+                                                })
+                                                .on_mouse_move(cx.listener(
+                                                    move |this, _, _, cx| {
+                                                        if let Some(ref mut menu) =
+                                                            this.context_menu
+                                                            && menu.submenu_selected_index != idx
+                                                        {
+                                                            menu.submenu_selected_index = idx;
+                                                            cx.notify();
+                                                        }
+                                                    },
+                                                ))
+                                                .on_click(cx.listener(
+                                                    move |this, _, window, cx| {
+                                                        this.handle_context_menu_action(
+                                                            action, window, cx,
+                                                        );
+                                                    },
+                                                ))
+                                                .child(Icon::new(AppIcon::Layers).small().color(
+                                                    if is_submenu_selected {
+                                                        theme.accent_foreground
+                                                    } else {
+                                                        theme.muted_foreground
+                                                    },
+                                                ))
+                                                .child(Text::caption(label).color(item_color))
+                                        })
+                                        .collect::<Vec<_>>(),
+                                ),
+                        )
+                    })
+                    .into_any_element(),
+            );
+            visual_index += 1;
         }
 
         // -- Row actions (driver-supplied, e.g. Kill / Cancel) --
@@ -2533,6 +3086,12 @@ impl DataGridPanel {
                     self.handle_copy(window, cx);
                 }
             }
+            ContextMenuAction::CopyRawValue => {
+                self.handle_copy_value(menu.row, menu.col, CellCopy::Raw, cx)
+            }
+            ContextMenuAction::CopyDisplayValue => {
+                self.handle_copy_value(menu.row, menu.col, CellCopy::Display, cx)
+            }
             ContextMenuAction::Paste => self.handle_paste(window, cx),
             ContextMenuAction::Edit => self.handle_edit(menu.row, menu.col, window, cx),
             ContextMenuAction::EditInModal => {
@@ -2542,9 +3101,15 @@ impl DataGridPanel {
                     self.handle_edit_in_modal(menu.row, menu.col, cx);
                 }
             }
+            ContextMenuAction::ViewValue => self.handle_view_value(menu.row, menu.col, cx),
             ContextMenuAction::SetDefault => self.handle_set_default(menu.row, menu.col, cx),
             ContextMenuAction::SetNull => self.handle_set_null(menu.row, menu.col, cx),
+            ContextMenuAction::SetEmpty => self.handle_set_empty(menu.row, menu.col, cx),
+            ContextMenuAction::SetValueInSelection => {
+                self.open_set_value_prompt(menu.row, menu.col, window, cx)
+            }
             ContextMenuAction::AddRow => self.handle_add_row(menu.row, is_document_view, cx),
+            ContextMenuAction::PasteAsRows => self.handle_paste_as_rows(menu.row, cx),
             ContextMenuAction::DuplicateRow => {
                 self.handle_duplicate_row(menu.row, is_document_view, cx)
             }
@@ -2576,6 +3141,9 @@ impl DataGridPanel {
             | ContextMenuAction::CopyAsDelete => {
                 self.handle_copy_as_query(menu.row, action, cx);
             }
+            ContextMenuAction::CopySelectionAs(format) => {
+                self.handle_copy_selection_as(menu.row, format, cx);
+            }
             ContextMenuAction::FilterByValue(op) => match backend {
                 Some(FilterBackend::Mongo) => {
                     self.handle_mongo_filter_by_value(
@@ -2618,6 +3186,28 @@ impl DataGridPanel {
             ContextMenuAction::InspectRow => {
                 self.open_row_inspector(menu.row, menu.col, cx);
             }
+            ContextMenuAction::GoToReferencedRow => {
+                self.handle_go_to_referenced_row(menu.row, menu.col, cx);
+            }
+            ContextMenuAction::ShowReferencingRows => {
+                self.handle_show_referencing_rows(menu.row, menu.col, cx);
+            }
+            ContextMenuAction::FreezeColumns
+            | ContextMenuAction::UnfreezeColumns
+            | ContextMenuAction::HideColumn
+            | ContextMenuAction::ShowAllColumns => {
+                if let Some(table_state) = &self.grid_table.table_state {
+                    table_state.update(cx, |state, cx| match action {
+                        ContextMenuAction::FreezeColumns => {
+                            state.freeze_columns_through(menu.col, cx)
+                        }
+                        ContextMenuAction::UnfreezeColumns => state.unfreeze_columns(cx),
+                        ContextMenuAction::HideColumn => state.hide_column(menu.col, cx),
+                        _ => state.show_all_columns(cx),
+                    });
+                }
+            }
+            ContextMenuAction::ColumnStats => self.open_column_stats(menu.col, cx),
             ContextMenuAction::ChartThisQuery => {
                 let query = self.chart_host_current_query(cx);
                 let connection_id = self.chart_host_connection_id(cx);
@@ -2636,6 +3226,36 @@ impl DataGridPanel {
         cx.notify();
     }
 
+    /// Shows the selected row in the inspector rail, or hides the rail when
+    /// it already shows a row.
+    pub fn toggle_row_inspector(&mut self, cx: &mut Context<Self>) {
+        use crate::DataViewMode;
+
+        if self.inspector.inspector_row.is_some() {
+            self.clear_inspector_state(cx);
+            cx.emit(DataGridEvent::CloseInspector);
+            cx.notify();
+            return;
+        }
+
+        if self.view_config.mode == DataViewMode::Document || self.is_grouped_result() {
+            return;
+        }
+
+        let Some(table_state) = &self.grid_table.table_state else {
+            return;
+        };
+
+        let (row, col) = table_state
+            .read(cx)
+            .selection()
+            .active
+            .map(|active| (active.row, active.col))
+            .unwrap_or((0, 0));
+
+        self.open_row_inspector(row, col, cx);
+    }
+
     /// Build an `InspectorSnapshot` from the given row/col and emit
     /// `DataGridEvent::OpenInspector` so the workspace mounts the content.
     pub(super) fn open_row_inspector(&mut self, row: usize, col: usize, cx: &mut Context<Self>) {
@@ -2671,7 +3291,7 @@ impl DataGridPanel {
             .map(|(ix, spec)| {
                 let value = model
                     .cell(row, ix)
-                    .map(|c| self.cell_to_value(c))
+                    .map(|c| self.cell_to_value(&c))
                     .unwrap_or(dbflux_core::Value::Null);
 
                 let type_label = match spec.kind {
@@ -2939,15 +3559,85 @@ impl DataGridPanel {
         }
     }
 
+    /// Copies the selection. A single cell is copied whole, raw or as
+    /// displayed depending on the formatted-output setting; larger
+    /// selections are copied as TSV.
     pub(super) fn handle_copy(&self, _window: &mut Window, cx: &mut Context<Self>) {
-        if let Some(table_state) = &self.grid_table.table_state {
-            let text = table_state.read(cx).copy_selection();
-            if let Some(text) = text {
-                cx.write_to_clipboard(ClipboardItem::new_string(text));
+        let Some(table_state) = &self.grid_table.table_state else {
+            return;
+        };
+
+        let single_cell = table_state
+            .read(cx)
+            .selection()
+            .selected_range()
+            .filter(|range| range.row_count() == 1 && range.col_count() == 1);
+
+        let text = match single_cell {
+            Some(range) => {
+                let mode = if dbflux_core::display_format::display_format().formatted_output {
+                    CellCopy::Display
+                } else {
+                    CellCopy::Raw
+                };
+                self.cell_copy_text(range.start.row, range.start.col, mode, cx)
             }
+            None => table_state.read(cx).copy_selection(),
+        };
+
+        if let Some(text) = text {
+            cx.write_to_clipboard(ClipboardItem::new_string(text));
+        }
+    }
+
+    pub(super) fn handle_copy_value(
+        &self,
+        row: usize,
+        col: usize,
+        mode: CellCopy,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(text) = self.cell_copy_text(row, col, mode, cx) {
+            cx.write_to_clipboard(ClipboardItem::new_string(text));
         }
     }
 
+    /// Text of the cell at visual `row`. Raw copies of unedited cells come
+    /// from the query result, so nothing the grid truncates or formats is
+    /// lost; edited and new cells copy what was typed.
+    fn cell_copy_text(&self, row: usize, col: usize, mode: CellCopy, cx: &App) -> Option<String> {
+        use dbflux_components::components::data_table::clipboard::display_value;
+        use dbflux_components::components::data_table::model::VisualRowSource;
+
+        let state = self.grid_table.table_state.as_ref()?.read(cx);
+        let buffer = state.edit_buffer();
+
+        let cell = match buffer.visual_row_source(row)? {
+            VisualRowSource::Base(base_idx) => {
+                if mode == CellCopy::Raw && !buffer.is_cell_dirty(base_idx, col) {
+                    return self
+                        .result
+                        .rows
+                        .get(base_idx)?
+                        .get(col)
+                        .map(Value::as_raw_string);
+                }
+
+                let base = state.model().cell(base_idx, col)?;
+                buffer.get_cell(base_idx, col, &base).clone()
+            }
+            VisualRowSource::Insert(insert_idx) => buffer
+                .get_pending_insert_by_idx(insert_idx)?
+                .get(col)?
+                .clone(),
+        };
+
+        Some(match mode {
+            CellCopy::Raw => cell.edit_text(),
+            CellCopy::Display => display_value(&cell),
+        })
+    }
+
     /// Copy entire document as JSON (for document view).
     pub(super) fn handle_copy_document(&self, doc_index: usize, cx: &mut Context<Self>) {
         let Some(tree_state) = &self.document_view.document_tree_state else {
@@ -2994,6 +3684,8 @@ impl DataGridPanel {
         let visual_order = buffer.compute_visual_order();
 
         // Get row data based on visual row source
+        let formatted_output = dbflux_core::display_format::display_format().formatted_output;
+
         let row_values: Vec<String> = match visual_order.get(row).copied() {
             Some(VisualRowSource::Base(base_idx)) => self
                 .result
@@ -3002,10 +3694,11 @@ impl DataGridPanel {
                 .map(|r| {
                     r.iter()
                         .map(|val| {
-                            dbflux_components::components::data_table::clipboard::format_cell(
+                            dbflux_components::components::data_table::clipboard::format_cell_as(
                                 &dbflux_components::components::data_table::model::CellValue::from(
                                     val,
                                 ),
+                                formatted_output,
                             )
                         })
                         .collect()
@@ -3016,7 +3709,12 @@ impl DataGridPanel {
                 .map(|cells| {
                     cells
                         .iter()
-                        .map(dbflux_components::components::data_table::clipboard::format_cell)
+                        .map(|cell| {
+                            dbflux_components::components::data_table::clipboard::format_cell_as(
+                                cell,
+                                formatted_output,
+                            )
+                        })
                         .collect()
                 })
                 .unwrap_or_default(),
@@ -3070,6 +3768,16 @@ impl DataGridPanel {
         }
     }
 
+    pub(super) fn handle_view_value(&mut self, row: usize, col: usize, cx: &mut Context<Self>) {
+        if let Some(table_state) = &self.grid_table.table_state {
+            table_state.update(cx, |state, cx| {
+                let coord =
+                    dbflux_components::components::data_table::selection::CellCoord::new(row, col);
+                state.request_cell_view(coord, cx);
+            });
+        }
+    }
+
     pub(super) fn handle_edit_in_modal(&mut self, row: usize, col: usize, cx: &mut Context<Self>) {
         use dbflux_components::components::data_table::model::{ColumnKind, VisualRowSource};
 
@@ -3090,13 +3798,13 @@ impl DataGridPanel {
             .unwrap_or(false);
 
         let visual_order = state.edit_buffer().compute_visual_order();
-        let null_cell = dbflux_components::components::data_table::model::CellValue::null();
 
         let value = match visual_order.get(row).copied() {
             Some(VisualRowSource::Base(base_idx)) => {
-                let base_cell = state.model().cell(base_idx, col);
-                let base = base_cell.unwrap_or(&null_cell);
-                let cell = state.edit_buffer().get_cell(base_idx, col, base);
+                let base = state.model().cell(base_idx, col).unwrap_or_else(
+                    dbflux_components::components::data_table::model::CellValue::null,
+                );
+                let cell = state.edit_buffer().get_cell(base_idx, col, &base);
                 cell.edit_text()
             }
             Some(VisualRowSource::Insert(insert_idx)) => {
@@ -3158,6 +3866,24 @@ impl DataGridPanel {
     }
 
     pub(super) fn handle_set_null(&mut self, row: usize, col: usize, cx: &mut Context<Self>) {
+        let cell_value = dbflux_components::components::data_table::model::CellValue::null();
+        self.set_cell_value(row, col, cell_value, cx);
+    }
+
+    /// Stores an empty string, which the grid shows as a blank cell rather
+    /// than the NULL marker.
+    pub(super) fn handle_set_empty(&mut self, row: usize, col: usize, cx: &mut Context<Self>) {
+        let cell_value = dbflux_components::components::data_table::model::CellValue::text("");
+        self.set_cell_value(row, col, cell_value, cx);
+    }
+
+    fn set_cell_value(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell_value: dbflux_components::components::data_table::model::CellValue,
+        cx: &mut Context<Self>,
+    ) {
         use dbflux_components::components::data_table::model::VisualRowSource;
 
         let Some(table_state) = &self.grid_table.table_state else {
@@ -3167,7 +3893,6 @@ impl DataGridPanel {
         table_state.update(cx, |state, cx| {
             let buffer = state.edit_buffer_mut();
             let visual_order = buffer.compute_visual_order();
-            let cell_value = dbflux_components::components::data_table::model::CellValue::null();
 
             match visual_order.get(row).copied() {
                 Some(VisualRowSource::Base(base_idx)) => {
@@ -3431,13 +4156,37 @@ impl DataGridPanel {
         .detach();
     }
 
+    /// Base row that rows added at `after_visual_row` are inserted after. A
+    /// pending insert resolves to the base row it follows; anything else
+    /// appends at the end.
+    pub(super) fn insert_position(&self, after_visual_row: usize, cx: &App) -> usize {
+        use dbflux_components::components::data_table::model::VisualRowSource;
+
+        let Some(table_state) = &self.grid_table.table_state else {
+            return self.result.rows.len().saturating_sub(1);
+        };
+
+        let state = table_state.read(cx);
+        let buffer = state.edit_buffer();
+        let visual_order = buffer.compute_visual_order();
+
+        match visual_order.get(after_visual_row).copied() {
+            Some(VisualRowSource::Base(base_idx)) => base_idx,
+            Some(VisualRowSource::Insert(insert_idx)) => buffer
+                .pending_inserts()
+                .get(insert_idx)
+                .and_then(|pi| pi.insert_after)
+                .unwrap_or(self.result.rows.len().saturating_sub(1)),
+            None => self.result.rows.len().saturating_sub(1),
+        }
+    }
+
     pub(super) fn handle_add_row(
         &mut self,
         after_visual_row: usize,
         is_document_view: bool,
         cx: &mut Context<Self>,
     ) {
-        use dbflux_components::components::data_table::model::VisualRowSource;
         use dbflux_components::modals::document_preview::DOC_INDEX_NEW;
 
         let is_table = matches!(self.source, DataSource::Table { .. });
@@ -3464,21 +4213,7 @@ impl DataGridPanel {
             return;
         };
 
-        let insert_after_base = {
-            let state = table_state.read(cx);
-            let buffer = state.edit_buffer();
-            let visual_order = buffer.compute_visual_order();
-
-            match visual_order.get(after_visual_row).copied() {
-                Some(VisualRowSource::Base(base_idx)) => base_idx,
-                Some(VisualRowSource::Insert(insert_idx)) => buffer
-                    .pending_inserts()
-                    .get(insert_idx)
-                    .and_then(|pi| pi.insert_after)
-                    .unwrap_or(self.result.rows.len().saturating_sub(1)),
-                None => self.result.rows.len().saturating_sub(1),
-            }
-        };
+        let insert_after_base = self.insert_position(after_visual_row, cx);
 
         let new_row: Vec<dbflux_components::components::data_table::model::CellValue> =
             if is_collection {
@@ -4057,8 +4792,12 @@ impl DataGridPanel {
             Value::Json(s) => format!("'{}'", Self::sanitize_for_label(s)),
             Value::ObjectId(id) => format!("'{}'", id),
             Value::DateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S")),
+            Value::NaiveDateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S")),
             Value::Date(d) => format!("'{}'", d.format("%Y-%m-%d")),
             Value::Time(t) => format!("'{}'", t.format("%H:%M:%S")),
+            Value::Uuid(uuid) => format!("'{}'", uuid),
+            Value::Interval(interval) => format!("'{}'", interval),
+            Value::Enum(label) => format!("'{}'", Self::sanitize_for_label(label)),
             Value::Unsupported(type_name) => format!("UNSUPPORTED<{}>", type_name),
             Value::Bytes(b) => format!("[{} bytes]", b.len()),
             Value::Array(_) | Value::Document(_) => "'...'".to_string(),
@@ -4623,6 +5362,18 @@ impl DataGridPanel {
     }
 }
 
+/// The result as exports and copies write it: as stored, or with numbers and
+/// dates in the display format when the settings ask for formatted output.
+pub(super) fn output_result(result: &QueryResult) -> Cow<'_, QueryResult> {
+    let format = dbflux_core::display_format::display_format();
+
+    if format.formatted_output {
+        Cow::Owned(dbflux_export::formatted_result(result, &format))
+    } else {
+        Cow::Borrowed(result)
+    }
+}
+
 fn record_export_audit(
     audit_service: &dbflux_audit::AuditService,
     format_name: &str,
@@ -4732,7 +5483,7 @@ fn record_clipboard_audit(
 
 #[cfg(test)]
 mod tests {
-    use super::DataGridPanel;
+    use super::{ColumnMenuState, DataGridPanel, ForeignKeyMenuState};
 
     fn labels(items: &[super::ContextMenuItem]) -> Vec<&'static str> {
         items
@@ -4744,7 +5495,15 @@ mod tests {
 
     #[test]
     fn empty_table_menu_keeps_insert_actions_but_hides_row_actions() {
-        let items = DataGridPanel::build_context_menu_items(true, false, false, false, true);
+        let items = DataGridPanel::build_context_menu_items(
+            true,
+            false,
+            false,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         let labels = labels(&items);
 
         assert!(labels.contains(&"Add Row"));
@@ -4756,19 +5515,55 @@ mod tests {
 
     #[test]
     fn non_editable_table_menu_stays_unchanged_without_row_target() {
-        let items = DataGridPanel::build_context_menu_items(false, false, false, false, true);
+        let items = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            false,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
 
         assert_eq!(labels(&items), vec!["Copy"]);
     }
 
+    #[test]
+    fn read_only_table_menu_offers_value_viewer_for_row_target() {
+        let items = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
+
+        assert_eq!(&labels(&items)[..2], ["Copy", "View Value"]);
+        assert!(labels(&items).contains(&"Copy Raw Value"));
+        assert!(labels(&items).contains(&"Copy Display Value"));
+        assert!(!labels(&items).contains(&"Edit in Modal"));
+    }
+
     #[test]
     fn editable_table_menu_with_row_target_keeps_row_actions() {
-        let items = DataGridPanel::build_context_menu_items(true, false, true, false, true);
+        let items = DataGridPanel::build_context_menu_items(
+            true,
+            false,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         let labels = labels(&items);
 
         assert!(labels.contains(&"Edit"));
         assert!(labels.contains(&"Edit in Modal"));
+        assert!(labels.contains(&"Set Value in Selection…"));
         assert!(labels.contains(&"Add Row"));
+        assert!(labels.contains(&"Paste as New Rows"));
         assert!(labels.contains(&"Duplicate Row"));
         assert!(labels.contains(&"Delete Row"));
     }
@@ -4776,18 +5571,41 @@ mod tests {
     #[test]
     fn chart_this_query_absent_when_can_chart_false() {
         // can_chart = false: item must NOT appear regardless of other flags.
-        let table_items = DataGridPanel::build_context_menu_items(false, false, false, false, true);
+        let table_items = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            false,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         assert!(!labels(&table_items).contains(&"Chart this query"));
 
-        let editable_items =
-            DataGridPanel::build_context_menu_items(true, false, true, false, true);
+        let editable_items = DataGridPanel::build_context_menu_items(
+            true,
+            false,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         assert!(!labels(&editable_items).contains(&"Chart this query"));
     }
 
     #[test]
     fn chart_this_query_present_only_when_can_chart_true() {
         // can_chart = true: item must appear.
-        let items = DataGridPanel::build_context_menu_items(false, false, false, true, true);
+        let items = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            false,
+            true,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         assert!(labels(&items).contains(&"Chart this query"));
     }
 
@@ -4795,14 +5613,77 @@ mod tests {
     fn chart_this_query_absent_in_document_view_regardless_of_can_chart() {
         // Document-view menu never shows Chart this query because the source is never
         // a QueryResult when is_document_view is true.
-        let doc_items = DataGridPanel::build_context_menu_items(false, true, false, true, true);
+        let doc_items = DataGridPanel::build_context_menu_items(
+            false,
+            true,
+            false,
+            true,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         assert!(!labels(&doc_items).contains(&"Chart this query"));
     }
 
+    #[test]
+    fn column_items_follow_the_column_arrangement() {
+        let natural = ColumnMenuState {
+            has_frozen: false,
+            has_hidden: false,
+            can_hide: true,
+            can_show_stats: false,
+        };
+        let items = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            true,
+            false,
+            true,
+            Some(natural),
+            ForeignKeyMenuState::default(),
+        );
+        let labels_natural = labels(&items);
+
+        assert!(labels_natural.contains(&"Freeze Up To Column"));
+        assert!(labels_natural.contains(&"Hide Column"));
+        assert!(!labels_natural.contains(&"Unfreeze Columns"));
+        assert!(!labels_natural.contains(&"Show All Columns"));
+        assert!(!labels_natural.contains(&"Column Stats"));
+
+        let arranged = ColumnMenuState {
+            has_frozen: true,
+            has_hidden: true,
+            can_hide: false,
+            can_show_stats: true,
+        };
+        let items = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            true,
+            false,
+            true,
+            Some(arranged),
+            ForeignKeyMenuState::default(),
+        );
+        let labels_arranged = labels(&items);
+
+        assert!(labels_arranged.contains(&"Unfreeze Columns"));
+        assert!(labels_arranged.contains(&"Show All Columns"));
+        assert!(!labels_arranged.contains(&"Hide Column"));
+        assert!(labels_arranged.contains(&"Column Stats"));
+    }
+
     #[test]
     fn inspect_row_hidden_when_inspect_row_disabled() {
-        let items_with_target =
-            DataGridPanel::build_context_menu_items(true, false, true, false, false);
+        let items_with_target = DataGridPanel::build_context_menu_items(
+            true,
+            false,
+            true,
+            false,
+            false,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         assert!(
             !labels(&items_with_target).contains(&"Inspect Row"),
             "Inspect Row must not appear when inspect_row_enabled=false"
@@ -4811,10 +5692,55 @@ mod tests {
 
     #[test]
     fn inspect_row_present_when_enabled_and_has_target() {
-        let items = DataGridPanel::build_context_menu_items(true, false, true, false, true);
+        let items = DataGridPanel::build_context_menu_items(
+            true,
+            false,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
         assert!(
             labels(&items).contains(&"Inspect Row"),
             "Inspect Row must appear when inspect_row_enabled=true and has_row_target=true"
         );
     }
+
+    #[test]
+    fn foreign_key_items_follow_menu_state() {
+        let none = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState::default(),
+        );
+        assert!(!labels(&none).contains(&"Go to Referenced Row"));
+        assert!(!labels(&none).contains(&"Show Referencing Rows"));
+
+        let both = DataGridPanel::build_context_menu_items(
+            false,
+            false,
+            true,
+            false,
+            true,
+            None,
+            ForeignKeyMenuState {
+                references: true,
+                referenced_by: true,
+            },
+        );
+        assert_eq!(
+            &labels(&both)[..4],
+            [
+                "Copy",
+                "View Value",
+                "Go to Referenced Row",
+                "Show Referencing Rows"
+            ]
+        );
+    }
 }
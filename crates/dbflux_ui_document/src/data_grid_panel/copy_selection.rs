@@ -0,0 +1,231 @@
+//! "Copy Selection As": the selected rows copied to the clipboard as CSV,
+//! JSON, Markdown or INSERT statements. The text formats go through the
+//! same exporters as a file export, so a pasted snippet matches the file.
+
+use super::context_menu::output_result;
+use super::{DataGridPanel, DataSource};
+use dbflux_components::components::data_table::SelectionCopyFormat;
+use dbflux_components::components::data_table::model::VisualRowSource;
+use dbflux_components::components::data_table::selection::CellRange;
+use dbflux_core::{ColumnAssignment, MutationRequest, QueryResult, RowInsert, Value};
+use dbflux_export::{ExportError, ExportFormat};
+use gpui::*;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// Visual rows and model columns copied for a menu opened on
+/// `clicked_row`: the selected range when it covers that row and spans more
+/// than one cell, otherwise the whole clicked row.
+fn copy_extent(
+    range: Option<CellRange>,
+    clicked_row: usize,
+    column_count: usize,
+) -> (RangeInclusive<usize>, Vec<usize>) {
+    match range {
+        Some(range) if range.contains_row(clicked_row) && range.start != range.end => (
+            range.start.row..=range.end.row,
+            (range.start.col..=range.end.col)
+                .filter(|&col| col < column_count)
+                .collect(),
+        ),
+        _ => (clicked_row..=clicked_row, (0..column_count).collect()),
+    }
+}
+
+impl DataGridPanel {
+    /// The copied rows as a result of their own, with only the copied
+    /// columns. Pending inserts are included with their buffered values.
+    fn selection_result(&self, clicked_row: usize, cx: &App) -> Option<QueryResult> {
+        let table_state = self.grid_table.table_state.as_ref()?;
+        let state = table_state.read(cx);
+        let buffer = state.edit_buffer();
+        let visual_order = buffer.compute_visual_order();
+
+        let (rows, columns) = copy_extent(
+            state.selection().selected_range(),
+            clicked_row,
+            self.result.columns.len(),
+        );
+
+        let rows: Vec<Vec<Value>> = rows
+            .filter_map(|visual_row| {
+                let full_row: Vec<Value> = match visual_order.get(visual_row).copied()? {
+                    VisualRowSource::Base(idx) => self.result.rows.get(idx)?.clone(),
+                    VisualRowSource::Insert(idx) => buffer
+                        .get_pending_insert_by_idx(idx)?
+                        .iter()
+                        .map(|cell| self.cell_value_to_value(cell))
+                        .collect(),
+                };
+
+                Some(
+                    columns
+                        .iter()
+                        .map(|&col| full_row.get(col).cloned().unwrap_or(Value::Null))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return None;
+        }
+
+        Some(QueryResult::table(
+            columns
+                .iter()
+                .map(|&col| self.result.columns[col].clone())
+                .collect(),
+            rows,
+            None,
+            Duration::ZERO,
+        ))
+    }
+
+    /// Whether INSERT statements can be generated for the copied rows: the
+    /// grid browses a table whose driver renders SQL mutations.
+    fn can_copy_selection_as_insert(&self, cx: &App) -> bool {
+        let DataSource::Table { profile_id, .. } = &self.source else {
+            return false;
+        };
+
+        self.app_state
+            .read(cx)
+            .connections()
+            .get(profile_id)
+            .is_some_and(|connected| {
+                connected.connection.metadata().query_language == dbflux_core::QueryLanguage::Sql
+                    && connected.connection.query_generator().is_some()
+            })
+    }
+
+    /// Entries of the "Copy Selection As" submenu, in menu order.
+    pub(super) fn selection_copy_formats(
+        &self,
+        cx: &App,
+    ) -> Vec<(&'static str, SelectionCopyFormat)> {
+        let mut formats = vec![
+            ("CSV", SelectionCopyFormat::Csv),
+            ("JSON", SelectionCopyFormat::Json),
+            ("Markdown", SelectionCopyFormat::Markdown),
+        ];
+        if self.can_copy_selection_as_insert(cx) {
+            formats.push(("INSERT", SelectionCopyFormat::Insert));
+        }
+        formats
+    }
+
+    /// One INSERT per copied row, skipping values that cannot be written
+    /// back.
+    fn selection_inserts(&self, result: &QueryResult, cx: &App) -> Result<Vec<u8>, ExportError> {
+        let failed = || ExportError::Failed("INSERT statements are not available here".into());
+
+        let DataSource::Table {
+            profile_id, table, ..
+        } = &self.source
+        else {
+            return Err(failed());
+        };
+
+        let connection = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(profile_id)
+            .map(|connected| connected.connection.clone())
+            .ok_or_else(failed)?;
+        let generator = connection.query_generator().ok_or_else(failed)?;
+
+        let statements = result
+            .rows
+            .iter()
+            .map(|row| {
+                let assignments = result
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .filter(|(_, value)| !matches!(value, Value::Unsupported(_)))
+                    .map(|(column, value)| ColumnAssignment {
+                        name: column.name.clone(),
+                        value: value.clone(),
+                        type_name: Some(column.type_name.clone()),
+                    })
+                    .collect();
+
+                let insert = MutationRequest::SqlInsert(RowInsert::with_typed_assignments(
+                    table.name.clone(),
+                    table.schema.clone(),
+                    assignments,
+                ));
+
+                generator
+                    .generate_mutation(&insert)
+                    .map(|query| format!("{};", query.text.trim_end_matches(';')))
+                    .ok_or_else(failed)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(statements.join("\n").into_bytes())
+    }
+
+    pub(super) fn handle_copy_selection_as(
+        &mut self,
+        clicked_row: usize,
+        format: SelectionCopyFormat,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(result) = self.selection_result(clicked_row, cx) else {
+            return;
+        };
+
+        let (format_name, output) = match format {
+            SelectionCopyFormat::Insert => ("INSERT", self.selection_inserts(&result, cx)),
+            SelectionCopyFormat::Csv => export_rows(&result, ExportFormat::Csv),
+            SelectionCopyFormat::Json => export_rows(&result, ExportFormat::JsonPretty),
+            SelectionCopyFormat::Markdown => export_rows(&result, ExportFormat::Markdown),
+        };
+
+        self.write_export_to_clipboard(format_name, output, cx);
+    }
+}
+
+fn export_rows(
+    result: &QueryResult,
+    format: ExportFormat,
+) -> (&'static str, Result<Vec<u8>, ExportError>) {
+    let mut buffer = Vec::new();
+    let output =
+        dbflux_export::export(&output_result(result), format, &mut buffer).map(|()| buffer);
+    (format.name(), output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_components::components::data_table::selection::CellCoord;
+
+    fn range(start: (usize, usize), end: (usize, usize)) -> Option<CellRange> {
+        Some(CellRange::new(
+            CellCoord::new(start.0, start.1),
+            CellCoord::new(end.0, end.1),
+        ))
+    }
+
+    #[test]
+    fn multi_cell_selection_copies_its_rows_and_columns() {
+        let (rows, columns) = copy_extent(range((1, 1), (3, 2)), 2, 4);
+
+        assert_eq!(rows, 1..=3);
+        assert_eq!(columns, vec![1, 2]);
+    }
+
+    #[test]
+    fn single_cell_or_outside_click_copies_the_whole_row() {
+        let (rows, columns) = copy_extent(range((1, 1), (1, 1)), 1, 3);
+        assert_eq!(rows, 1..=1);
+        assert_eq!(columns, vec![0, 1, 2]);
+
+        let (rows, _) = copy_extent(range((1, 0), (3, 2)), 5, 3);
+        assert_eq!(rows, 5..=5);
+    }
+}
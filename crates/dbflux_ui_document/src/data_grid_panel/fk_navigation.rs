@@ -0,0 +1,428 @@
+//! Following foreign keys from a grid cell: open the row a key points at, or
+//! the rows of other tables that point at the clicked row.
+
+use super::{DataGridEvent, DataGridPanel, DataSource, FkLoadState, ForeignKeyMenuState};
+use dbflux_core::{ForeignKeyInfo, SchemaForeignKeyInfo, TableRef, Value};
+use gpui::*;
+
+/// Rows of `table` picked out by key values. Each entry of `keys` is a set of
+/// `column = value` pairs; a row matches when it equals any of them.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct KeyTarget {
+    pub(super) table: TableRef,
+    pub(super) keys: Vec<Vec<(String, Value)>>,
+}
+
+impl KeyTarget {
+    /// WHERE filter selecting the target rows, with identifiers and values
+    /// rendered by the connection's dialect.
+    pub(super) fn filter(
+        &self,
+        quote: impl Fn(&str) -> String,
+        literal: impl Fn(&Value) -> String,
+    ) -> String {
+        let clauses: Vec<String> = self
+            .keys
+            .iter()
+            .map(|key| {
+                key.iter()
+                    .map(|(column, value)| format!("{} = {}", quote(column), literal(value)))
+                    .collect::<Vec<_>>()
+                    .join(" AND ")
+            })
+            .collect();
+
+        if clauses.len() == 1 {
+            return clauses.into_iter().next().unwrap_or_default();
+        }
+
+        clauses
+            .iter()
+            .map(|clause| format!("({})", clause))
+            .collect::<Vec<_>>()
+            .join(" OR ")
+    }
+}
+
+/// Pairs each `target` column with the row value of the matching `local`
+/// column. `None` when the columns do not line up, a local column is not in
+/// the result or any value is NULL (a NULL key references nothing).
+fn match_key(
+    local: &[String],
+    target: &[String],
+    names: &[String],
+    row: &[Value],
+) -> Option<Vec<(String, Value)>> {
+    if local.is_empty() || local.len() != target.len() {
+        return None;
+    }
+
+    local
+        .iter()
+        .zip(target)
+        .map(|(local, target)| {
+            let ix = names.iter().position(|name| name == local)?;
+            let value = row.get(ix).filter(|value| !value.is_null())?;
+            Some((target.clone(), value.clone()))
+        })
+        .collect()
+}
+
+/// The row referenced by the first foreign key of `table` that includes
+/// `column`.
+pub(super) fn referenced_row(
+    foreign_keys: &[ForeignKeyInfo],
+    table: &TableRef,
+    column: &str,
+    names: &[String],
+    row: &[Value],
+) -> Option<KeyTarget> {
+    foreign_keys
+        .iter()
+        .filter(|fk| fk.columns.iter().any(|local| local == column))
+        .find_map(|fk| {
+            let key = match_key(&fk.columns, &fk.referenced_columns, names, row)?;
+
+            Some(KeyTarget {
+                table: TableRef {
+                    schema: fk
+                        .referenced_schema
+                        .clone()
+                        .or_else(|| table.schema.clone()),
+                    name: fk.referenced_table.clone(),
+                },
+                keys: vec![key],
+            })
+        })
+}
+
+/// Rows of other tables whose foreign keys point at `column` of `table`,
+/// one target per referencing table. A table with several such keys (say
+/// `created_by` and `updated_by`) matches rows using any of them.
+pub(super) fn referencing_rows(
+    foreign_keys: &[SchemaForeignKeyInfo],
+    table: &TableRef,
+    column: &str,
+    names: &[String],
+    row: &[Value],
+) -> Vec<KeyTarget> {
+    let mut targets: Vec<KeyTarget> = Vec::new();
+
+    for fk in foreign_keys {
+        if fk.referenced_table != table.name
+            || !fk.referenced_columns.iter().any(|target| target == column)
+        {
+            continue;
+        }
+
+        if let (Some(referenced), Some(schema)) = (&fk.referenced_schema, &table.schema)
+            && referenced != schema
+        {
+            continue;
+        }
+
+        let Some(key) = match_key(&fk.referenced_columns, &fk.columns, names, row) else {
+            continue;
+        };
+
+        match targets
+            .iter_mut()
+            .find(|target| target.table.name == fk.table_name)
+        {
+            Some(target) => target.keys.push(key),
+            None => targets.push(KeyTarget {
+                table: TableRef {
+                    schema: table.schema.clone(),
+                    name: fk.table_name.clone(),
+                },
+                keys: vec![key],
+            }),
+        }
+    }
+
+    targets
+}
+
+impl DataGridPanel {
+    /// Foreign keys of the browsed table, from the table details cache or,
+    /// when those are not loaded, from the schema-wide foreign key list.
+    fn table_foreign_keys(&self, cx: &App) -> Vec<ForeignKeyInfo> {
+        let DataSource::Table {
+            profile_id,
+            database,
+            table,
+            ..
+        } = &self.source
+        else {
+            return Vec::new();
+        };
+
+        let cached = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(profile_id)
+            .and_then(|connected| {
+                let database = database
+                    .clone()
+                    .or_else(|| connected.active_database.clone())
+                    .or_else(|| table.schema.clone())
+                    .unwrap_or_else(|| "default".to_string());
+
+                connected
+                    .table_details
+                    .get(&(database, table.name.clone()))
+                    .and_then(|info| info.foreign_keys.clone())
+            });
+
+        if let Some(foreign_keys) = cached {
+            return foreign_keys;
+        }
+
+        let FkLoadState::Ready(schema_keys) = &self.builder.fk_cache else {
+            return Vec::new();
+        };
+
+        schema_keys
+            .iter()
+            .filter(|fk| fk.table_name == table.name)
+            .map(|fk| ForeignKeyInfo {
+                name: fk.name.clone(),
+                columns: fk.columns.clone(),
+                referenced_table: fk.referenced_table.clone(),
+                referenced_schema: fk.referenced_schema.clone(),
+                referenced_columns: fk.referenced_columns.clone(),
+                on_delete: fk.on_delete.clone(),
+                on_update: fk.on_update.clone(),
+            })
+            .collect()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.result
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect()
+    }
+
+    fn referenced_row_target(&self, row: usize, col: usize, cx: &App) -> Option<KeyTarget> {
+        let table = self.source.table_ref()?;
+        let column = self.result.columns.get(col)?;
+
+        referenced_row(
+            &self.table_foreign_keys(cx),
+            table,
+            &column.name,
+            &self.column_names(),
+            &self.collect_row_values(row, cx),
+        )
+    }
+
+    fn referencing_row_targets(&self, row: usize, col: usize, cx: &App) -> Vec<KeyTarget> {
+        let (Some(table), Some(column)) = (self.source.table_ref(), self.result.columns.get(col))
+        else {
+            return Vec::new();
+        };
+
+        let FkLoadState::Ready(schema_keys) = &self.builder.fk_cache else {
+            return Vec::new();
+        };
+
+        referencing_rows(
+            schema_keys,
+            table,
+            &column.name,
+            &self.column_names(),
+            &self.collect_row_values(row, cx),
+        )
+    }
+
+    /// Foreign-key items of the table view's menu for the clicked cell.
+    pub(super) fn foreign_key_menu_state(
+        &self,
+        row: usize,
+        col: usize,
+        is_document_view: bool,
+        cx: &App,
+    ) -> ForeignKeyMenuState {
+        if is_document_view || self.source.table_ref().is_none() {
+            return ForeignKeyMenuState::default();
+        }
+
+        ForeignKeyMenuState {
+            references: self.referenced_row_target(row, col, cx).is_some(),
+            referenced_by: !self.referencing_row_targets(row, col, cx).is_empty(),
+        }
+    }
+
+    pub(super) fn handle_go_to_referenced_row(
+        &mut self,
+        row: usize,
+        col: usize,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(target) = self.referenced_row_target(row, col, cx) {
+            self.open_key_target(&target, cx);
+        }
+    }
+
+    /// Opens one filtered tab per table that references the clicked row.
+    pub(super) fn handle_show_referencing_rows(
+        &mut self,
+        row: usize,
+        col: usize,
+        cx: &mut Context<Self>,
+    ) {
+        for target in self.referencing_row_targets(row, col, cx) {
+            self.open_key_target(&target, cx);
+        }
+    }
+
+    fn open_key_target(&self, target: &KeyTarget, cx: &mut Context<Self>) {
+        let DataSource::Table {
+            profile_id,
+            database,
+            ..
+        } = &self.source
+        else {
+            return;
+        };
+
+        let Some(conn) = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(profile_id)
+            .map(|c| c.connection.clone())
+        else {
+            return;
+        };
+
+        let dialect = conn.dialect();
+        let filter = target.filter(
+            |column| dialect.quote_identifier(column),
+            |value| dialect.value_to_literal(value),
+        );
+
+        cx.emit(DataGridEvent::OpenTableFiltered {
+            profile_id: *profile_id,
+            database: database.clone(),
+            table: target.table.clone(),
+            filter,
+        });
+    }
+
+    /// Replaces the WHERE filter of a browsed table and reloads it from the
+    /// first page.
+    pub fn set_table_filter(&mut self, filter: &str, window: &mut Window, cx: &mut Context<Self>) {
+        if let DataSource::Table { pagination, .. } = &mut self.source {
+            *pagination = pagination.reset_offset();
+        }
+
+        self.filter_bar
+            .filter_input
+            .update(cx, |state, cx| state.set_value(filter, window, cx));
+        self.refresh(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(columns: &[&str]) -> Vec<String> {
+        columns.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn schema_key(table: &str, column: &str) -> SchemaForeignKeyInfo {
+        SchemaForeignKeyInfo {
+            name: format!("{table}_{column}_fkey"),
+            table_name: table.to_string(),
+            columns: names(&[column]),
+            referenced_schema: Some("public".to_string()),
+            referenced_table: "users".to_string(),
+            referenced_columns: names(&["id"]),
+            on_delete: None,
+            on_update: None,
+        }
+    }
+
+    fn render(target: &KeyTarget) -> String {
+        target.filter(|column| format!("\"{column}\""), |value| value.to_string())
+    }
+
+    #[test]
+    fn referenced_row_follows_the_clicked_column() {
+        let orders = TableRef::with_schema("public", "orders");
+        let foreign_keys = vec![ForeignKeyInfo {
+            name: "orders_user_id_fkey".to_string(),
+            columns: names(&["user_id"]),
+            referenced_table: "users".to_string(),
+            referenced_schema: None,
+            referenced_columns: names(&["id"]),
+            on_delete: None,
+            on_update: None,
+        }];
+        let columns = names(&["id", "user_id"]);
+
+        let target = referenced_row(
+            &foreign_keys,
+            &orders,
+            "user_id",
+            &columns,
+            &[Value::Int(1), Value::Int(7)],
+        )
+        .unwrap();
+
+        assert_eq!(target.table, TableRef::with_schema("public", "users"));
+        assert_eq!(render(&target), "\"id\" = 7");
+
+        assert!(
+            referenced_row(
+                &foreign_keys,
+                &orders,
+                "id",
+                &columns,
+                &[Value::Int(1), Value::Int(7)]
+            )
+            .is_none()
+        );
+        assert!(
+            referenced_row(
+                &foreign_keys,
+                &orders,
+                "user_id",
+                &columns,
+                &[Value::Int(1), Value::Null]
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn referencing_rows_group_keys_by_table() {
+        let users = TableRef::with_schema("public", "users");
+        let foreign_keys = vec![
+            schema_key("orders", "user_id"),
+            schema_key("posts", "created_by"),
+            schema_key("posts", "updated_by"),
+        ];
+
+        let targets = referencing_rows(
+            &foreign_keys,
+            &users,
+            "id",
+            &names(&["id", "name"]),
+            &[Value::Int(7), Value::Text("ann".to_string())],
+        );
+
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].table, TableRef::with_schema("public", "orders"));
+        assert_eq!(render(&targets[0]), "\"user_id\" = 7");
+        assert_eq!(
+            render(&targets[1]),
+            "(\"created_by\" = 7) OR (\"updated_by\" = 7)"
+        );
+    }
+}
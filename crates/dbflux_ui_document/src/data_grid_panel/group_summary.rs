@@ -0,0 +1,835 @@
+//! "Summarize": the table grouped by chosen columns with aggregate
+//! expressions such as `COUNT(*)` or `SUM(amount)`, re-queried on the server
+//! with GROUP BY. The raw filter keeps applying, so switching between the
+//! summary and the rows compares the same slice of the table.
+
+use super::{DataGridPanel, DataSource, GridState, PendingToast};
+use dbflux_components::controls::{Checkbox, Input, InputEvent, InputState};
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::Spacing;
+use dbflux_core::{
+    AggFn, GroupByEntry, OrderByColumn, Pagination, Projection, QueryRequest, SortDirection,
+    SourceTable, SqlDialect, TableRef, TaskKind, TaskTarget, VisualAggregateSpec, VisualQuerySpec,
+    project_aggregate_kinds,
+};
+use gpui::*;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariant, ButtonVariants};
+use uuid::Uuid;
+
+/// Aggregate list the prompt starts with.
+const DEFAULT_AGGREGATES: &str = "COUNT(*)";
+
+/// Grouping picked for the table. Kept after switching back to rows so the
+/// prompt reopens with the same choice.
+pub(super) struct GroupSummary {
+    /// The table's own columns, captured while the grid showed rows.
+    columns: Vec<String>,
+    group_by: Vec<String>,
+    aggregates: Vec<VisualAggregateSpec>,
+    active: bool,
+}
+
+/// Group-by and aggregate prompt opened from the toolbar.
+pub(super) struct SummaryPrompt {
+    columns: Vec<String>,
+    group_by: Vec<bool>,
+    aggregates_input: Entity<InputState>,
+    error: Option<String>,
+    _subscription: Subscription,
+}
+
+fn agg_fn_name(function: AggFn) -> &'static str {
+    match function {
+        AggFn::Count | AggFn::CountStar | AggFn::CountDistinct => "COUNT",
+        AggFn::Sum => "SUM",
+        AggFn::Avg => "AVG",
+        AggFn::Min => "MIN",
+        AggFn::Max => "MAX",
+    }
+}
+
+/// Alias given to an aggregate written without `AS`, following the query
+/// builder's `count_star` / `sum_amount` convention.
+fn default_alias(function: AggFn, column: Option<&str>) -> String {
+    let prefix = match function {
+        AggFn::CountStar => return "count_star".to_string(),
+        AggFn::CountDistinct => "count_distinct",
+        AggFn::Count => "count",
+        AggFn::Sum => "sum",
+        AggFn::Avg => "avg",
+        AggFn::Min => "min",
+        AggFn::Max => "max",
+    };
+
+    let column: String = column
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{prefix}_{column}")
+}
+
+/// Splits on commas outside parentheses.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (ix, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(&text[start..ix]);
+                start = ix + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&text[start..]);
+    items
+}
+
+fn unquote(name: &str) -> &str {
+    let name = name.trim();
+    for (open, close) in [('"', '"'), ('`', '`'), ('[', ']')] {
+        if let Some(inner) = name
+            .strip_prefix(open)
+            .and_then(|rest| rest.strip_suffix(close))
+        {
+            return inner;
+        }
+    }
+    name
+}
+
+fn parse_aggregate(item: &str, columns: &[String]) -> Result<VisualAggregateSpec, String> {
+    let (Some(open), Some(close)) = (item.find('('), item.rfind(')')) else {
+        return Err(format!("'{item}' is not an aggregate like SUM(column)"));
+    };
+    if close < open {
+        return Err(format!("'{item}' is not an aggregate like SUM(column)"));
+    }
+
+    let name = item[..open].trim().to_ascii_uppercase();
+    let argument = item[open + 1..close].trim();
+
+    let rest = item[close + 1..].trim();
+    let alias = match rest.get(..3) {
+        _ if rest.is_empty() => None,
+        Some(keyword) if keyword.eq_ignore_ascii_case("as ") => Some(unquote(&rest[3..])),
+        _ => Some(unquote(rest)),
+    };
+
+    let (distinct, argument) = match argument.get(..9) {
+        Some(keyword) if keyword.eq_ignore_ascii_case("distinct ") => (true, argument[9..].trim()),
+        _ => (false, argument),
+    };
+
+    let function = match (name.as_str(), distinct, argument) {
+        ("COUNT", false, "*") => AggFn::CountStar,
+        (_, _, "*") => return Err(format!("{name}(*) is not supported, only COUNT(*)")),
+        ("COUNT", true, _) => AggFn::CountDistinct,
+        (_, true, _) => return Err("DISTINCT is only supported in COUNT".to_string()),
+        ("COUNT", false, _) => AggFn::Count,
+        ("SUM", false, _) => AggFn::Sum,
+        ("AVG", false, _) => AggFn::Avg,
+        ("MIN", false, _) => AggFn::Min,
+        ("MAX", false, _) => AggFn::Max,
+        _ => return Err(format!("unknown aggregate function {name}")),
+    };
+
+    let column = if function == AggFn::CountStar {
+        None
+    } else {
+        let argument = unquote(argument);
+        let column = columns
+            .iter()
+            .find(|column| column.eq_ignore_ascii_case(argument))
+            .ok_or_else(|| format!("unknown column {argument}"))?;
+        Some(column.clone())
+    };
+
+    let alias = match alias {
+        Some(alias) if !alias.is_empty() => alias.to_string(),
+        _ => default_alias(function, column.as_deref()),
+    };
+
+    Ok(VisualAggregateSpec {
+        function,
+        source_alias: None,
+        column,
+        alias,
+    })
+}
+
+/// Parses a comma-separated aggregate list such as
+/// `COUNT(*), SUM(amount) AS total, COUNT(DISTINCT customer_id)`. Column
+/// names are matched against `columns` ignoring case; aliases default to the
+/// query builder's `sum_amount` style.
+pub(super) fn parse_aggregates(
+    text: &str,
+    columns: &[String],
+    group_by: &[String],
+) -> Result<Vec<VisualAggregateSpec>, String> {
+    let mut aggregates: Vec<VisualAggregateSpec> = Vec::new();
+
+    for item in split_top_level(text) {
+        let item = item.trim();
+        if item.is_empty() {
+            continue;
+        }
+
+        let aggregate = parse_aggregate(item, columns)?;
+        let taken = group_by
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(&aggregate.alias))
+            || aggregates
+                .iter()
+                .any(|a| a.alias.eq_ignore_ascii_case(&aggregate.alias));
+        if taken {
+            return Err(format!("the name {} is used twice", aggregate.alias));
+        }
+        aggregates.push(aggregate);
+    }
+
+    Ok(aggregates)
+}
+
+/// Inverse of [`parse_aggregates`], used to prefill the prompt.
+fn format_aggregates(aggregates: &[VisualAggregateSpec]) -> String {
+    aggregates
+        .iter()
+        .map(|aggregate| {
+            let argument = match aggregate.function {
+                AggFn::CountStar => "*".to_string(),
+                AggFn::CountDistinct => {
+                    format!(
+                        "DISTINCT {}",
+                        aggregate.column.as_deref().unwrap_or_default()
+                    )
+                }
+                _ => aggregate.column.clone().unwrap_or_default(),
+            };
+            let call = format!("{}({argument})", agg_fn_name(aggregate.function));
+
+            if aggregate.alias == default_alias(aggregate.function, aggregate.column.as_deref()) {
+                call
+            } else {
+                format!("{call} AS {}", aggregate.alias)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn aggregate_sql(dialect: &dyn SqlDialect, aggregate: &VisualAggregateSpec) -> String {
+    let column = aggregate
+        .column
+        .as_deref()
+        .map(|column| dialect.quote_identifier(column))
+        .unwrap_or_default();
+
+    let argument = match aggregate.function {
+        AggFn::CountStar => "*".to_string(),
+        AggFn::CountDistinct => format!("DISTINCT {column}"),
+        _ => column,
+    };
+
+    format!(
+        "{}({argument}) AS {}",
+        agg_fn_name(aggregate.function),
+        dialect.quote_identifier(&aggregate.alias)
+    )
+}
+
+/// The page of groups to show, and the query counting every group (`None`
+/// without group columns, which always yields one row).
+///
+/// The grid's sort is kept when it names output columns; otherwise groups
+/// are ordered by the group columns so paging is stable.
+pub(super) fn summary_queries(
+    dialect: &dyn SqlDialect,
+    table: &TableRef,
+    filter: Option<&str>,
+    group_by: &[String],
+    aggregates: &[VisualAggregateSpec],
+    order_by: &[OrderByColumn],
+    pagination: &Pagination,
+) -> (String, Option<String>) {
+    let group_columns: Vec<String> = group_by
+        .iter()
+        .map(|column| dialect.quote_identifier(column))
+        .collect();
+
+    let select_list: Vec<String> = group_columns
+        .iter()
+        .cloned()
+        .chain(aggregates.iter().map(|a| aggregate_sql(dialect, a)))
+        .collect();
+
+    let mut base = format!(
+        "SELECT {} FROM {}",
+        select_list.join(", "),
+        table.quoted_with(dialect)
+    );
+    if let Some(filter) = filter.map(str::trim).filter(|f| !f.is_empty()) {
+        base.push_str(&format!(" WHERE {filter}"));
+    }
+
+    if group_columns.is_empty() {
+        return (base, None);
+    }
+    base.push_str(&format!(" GROUP BY {}", group_columns.join(", ")));
+
+    let is_output = |name: &str| {
+        group_by.iter().any(|c| c == name) || aggregates.iter().any(|a| a.alias == name)
+    };
+
+    let sort: Vec<String> = if !order_by.is_empty()
+        && order_by
+            .iter()
+            .all(|o| o.column.table.is_none() && is_output(&o.column.name))
+    {
+        order_by
+            .iter()
+            .map(|o| {
+                let direction = match o.direction {
+                    SortDirection::Ascending => "ASC",
+                    SortDirection::Descending => "DESC",
+                };
+                format!("{} {direction}", dialect.quote_identifier(&o.column.name))
+            })
+            .collect()
+    } else {
+        group_columns.clone()
+    };
+
+    let select = format!(
+        "{base} ORDER BY {} {}",
+        sort.join(", "),
+        dialect.page_clause(pagination.limit(), pagination.offset())
+    );
+    let count = format!(
+        "SELECT COUNT(*) FROM ({base}) AS {}",
+        dialect.quote_identifier("_dbflux_count_subq")
+    );
+
+    (select, Some(count))
+}
+
+impl DataGridPanel {
+    /// The summary is offered for SQL tables browsed without a builder query.
+    pub(super) fn can_summarize(&self, cx: &App) -> bool {
+        let DataSource::Table { profile_id, .. } = &self.source else {
+            return false;
+        };
+
+        self.builder.visual_select.is_none()
+            && self
+                .app_state
+                .read(cx)
+                .connections()
+                .get(profile_id)
+                .is_some_and(|connected| {
+                    connected.connection.metadata().query_language
+                        == dbflux_core::QueryLanguage::Sql
+                })
+    }
+
+    pub(super) fn is_summary_active(&self) -> bool {
+        self.group_summary.as_ref().is_some_and(|s| s.active)
+    }
+
+    pub(super) fn open_summary_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let (columns, group_by, aggregates) = match &self.group_summary {
+            Some(summary) => (
+                summary.columns.clone(),
+                summary.group_by.clone(),
+                format_aggregates(&summary.aggregates),
+            ),
+            None => (
+                self.result
+                    .columns
+                    .iter()
+                    .map(|column| column.name.clone())
+                    .collect(),
+                Vec::new(),
+                DEFAULT_AGGREGATES.to_string(),
+            ),
+        };
+        if columns.is_empty() {
+            return;
+        }
+
+        let aggregates_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("COUNT(*), SUM(amount) AS total")
+                .default_value(aggregates)
+        });
+        aggregates_input.update(cx, |input, cx| input.focus(window, cx));
+
+        let subscription = cx.subscribe_in(
+            &aggregates_input,
+            window,
+            |this: &mut Self, _input, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.apply_summary_prompt(window, cx);
+                }
+            },
+        );
+
+        self.summary_prompt = Some(SummaryPrompt {
+            group_by: columns.iter().map(|c| group_by.contains(c)).collect(),
+            columns,
+            aggregates_input,
+            error: None,
+            _subscription: subscription,
+        });
+        cx.notify();
+    }
+
+    pub(super) fn cancel_summary_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.summary_prompt.take().is_some() {
+            self.focus_active_view(window, cx);
+            cx.notify();
+        }
+    }
+
+    /// Switches the grid to the summary described by the prompt, starting
+    /// from the first page of groups.
+    fn apply_summary_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(prompt) = self.summary_prompt.as_mut() else {
+            return;
+        };
+
+        let group_by: Vec<String> = prompt
+            .columns
+            .iter()
+            .zip(&prompt.group_by)
+            .filter(|(_, checked)| **checked)
+            .map(|(column, _)| column.clone())
+            .collect();
+
+        let text = prompt.aggregates_input.read(cx).value().to_string();
+        let aggregates = match parse_aggregates(&text, &prompt.columns, &group_by) {
+            Ok(aggregates) if aggregates.is_empty() && group_by.is_empty() => {
+                prompt.error = Some("Pick a group column or add an aggregate".to_string());
+                cx.notify();
+                return;
+            }
+            Ok(aggregates) => aggregates,
+            Err(message) => {
+                prompt.error = Some(message);
+                cx.notify();
+                return;
+            }
+        };
+
+        let Some(prompt) = self.summary_prompt.take() else {
+            return;
+        };
+        self.group_summary = Some(GroupSummary {
+            columns: prompt.columns,
+            group_by,
+            aggregates,
+            active: true,
+        });
+
+        self.restart_table_paging(|_| true);
+        self.focus_active_view(window, cx);
+        self.refresh(window, cx);
+    }
+
+    /// Back to browsing rows with the same filter. Sorting on summary-only
+    /// columns is dropped.
+    pub(super) fn show_summary_rows(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(summary) = self.group_summary.as_mut() else {
+            return;
+        };
+        if !summary.active {
+            return;
+        }
+        summary.active = false;
+        let columns = summary.columns.clone();
+
+        self.builder.current_visual_spec = None;
+        self.restart_table_paging(|column| {
+            column.column.table.is_some() || columns.contains(&column.column.name)
+        });
+        self.refresh(window, cx);
+    }
+
+    /// First page, unknown total, and only the sort columns `keep` accepts.
+    fn restart_table_paging(&mut self, keep: impl Fn(&OrderByColumn) -> bool) {
+        if let DataSource::Table {
+            pagination,
+            order_by,
+            total_rows,
+            ..
+        } = &mut self.source
+        {
+            *pagination = pagination.reset_offset();
+            order_by.retain(keep);
+            *total_rows = None;
+        }
+    }
+
+    /// Runs the summary in place of the table browse. Called from
+    /// `run_table_query` so paging, sorting and refresh all go through it.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn run_summary_query(
+        &mut self,
+        profile_id: Uuid,
+        database: Option<String>,
+        table: TableRef,
+        pagination: Pagination,
+        order_by: Vec<OrderByColumn>,
+        total_rows: Option<u64>,
+        filter: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(summary) = self.group_summary.as_ref() else {
+            return;
+        };
+
+        let (conn, sql, count_sql) = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(&profile_id) else {
+                return;
+            };
+            let Ok(conn) = connected.resolve_connection_for_execution(database.as_deref()) else {
+                return;
+            };
+
+            let (sql, count_sql) = summary_queries(
+                connected.connection.dialect(),
+                &table,
+                filter.as_deref(),
+                &summary.group_by,
+                &summary.aggregates,
+                &order_by,
+                &pagination,
+            );
+            (conn, sql, count_sql)
+        };
+
+        let spec = VisualQuerySpec {
+            source: SourceTable {
+                schema: table.schema.clone(),
+                table: table.name.clone(),
+                alias: table.name.clone(),
+            },
+            projection: Projection::All,
+            joins: Vec::new(),
+            filter: None,
+            group_by: summary
+                .group_by
+                .iter()
+                .map(|column| GroupByEntry {
+                    source_alias: table.name.clone(),
+                    column: column.clone(),
+                })
+                .collect(),
+            aggregates: summary
+                .aggregates
+                .iter()
+                .map(|aggregate| VisualAggregateSpec {
+                    source_alias: aggregate.column.as_ref().map(|_| table.name.clone()),
+                    ..aggregate.clone()
+                })
+                .collect(),
+            having: None,
+            sort: Vec::new(),
+            limit: Some(pagination.limit() as u64),
+            offset: pagination.offset(),
+        };
+
+        let request = |sql: String| {
+            let mut request = QueryRequest::new(sql);
+            request.database = database.clone();
+            request
+        };
+
+        let (task_id, cancel_token) = self.runner.start_primary_for_target(
+            TaskKind::Query,
+            sql.clone(),
+            Some(TaskTarget {
+                profile_id,
+                database: database.clone(),
+            }),
+            cx,
+        );
+
+        self.refresh.state = GridState::Loading;
+        cx.notify();
+
+        let total_rows = match count_sql {
+            None => Some(1),
+            Some(count_sql) => {
+                if total_rows.is_none() {
+                    self.spawn_grouped_count(
+                        conn.clone(),
+                        request(count_sql),
+                        table.qualified_name(),
+                        cx,
+                    );
+                }
+                total_rows
+            }
+        };
+
+        let select_request = request(sql);
+        let conn_for_cleanup = conn.clone();
+        let task = cx
+            .background_executor()
+            .spawn(async move { conn.execute(&select_request) });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+
+            if let Err(e) = this.update(cx, |panel, cx| {
+                if cancel_token.is_cancelled() {
+                    if let Err(e) = conn_for_cleanup.cleanup_after_cancel() {
+                        log::warn!("Cleanup after cancel failed: {}", e);
+                    }
+                    return;
+                }
+
+                match result {
+                    Ok(mut query_result) => {
+                        project_aggregate_kinds(&spec, &mut query_result.columns);
+
+                        panel.runner.complete_primary(task_id, cx);
+                        panel.builder.current_visual_spec = Some(spec);
+                        panel.builder.builder_editable_binding = None;
+                        panel.apply_table_result(
+                            profile_id,
+                            table,
+                            pagination,
+                            order_by,
+                            total_rows,
+                            query_result,
+                            cx,
+                        );
+                    }
+                    Err(e) => {
+                        log::error!("Summary query failed: {}", e);
+                        panel.runner.fail_primary(task_id, e.to_string(), cx);
+                        panel.refresh.state = GridState::Error;
+                        panel.pending.toast = Some(PendingToast {
+                            message: format!("Summary failed: {}", e),
+                            is_error: true,
+                        });
+                        cx.notify();
+                    }
+                }
+            }) {
+                log::warn!("Failed to apply summary result: {:?}", e);
+            }
+        })
+        .detach();
+    }
+
+    pub(super) fn render_summary_prompt(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let Some(prompt) = self.summary_prompt.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let group_checks = prompt.columns.iter().zip(&prompt.group_by).enumerate().map(
+            |(ix, (column, &checked))| {
+                Checkbox::new(("summary-group-by", ix))
+                    .checked(checked)
+                    .label(column.clone())
+                    .on_click(cx.listener(move |this, &checked, _, cx| {
+                        if let Some(prompt) = this.summary_prompt.as_mut()
+                            && let Some(slot) = prompt.group_by.get_mut(ix)
+                        {
+                            *slot = checked;
+                            cx.notify();
+                        }
+                    }))
+            },
+        );
+
+        div()
+            .id("summary-overlay")
+            .absolute()
+            .inset_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(theme.background.opacity(0.6))
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .bg(theme.secondary)
+                    .border_1()
+                    .border_color(theme.border)
+                    .p(Spacing::LG)
+                    .w(px(360.0))
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::SM)
+                    .child(Text::label("Summarize"))
+                    .child(Text::caption("Group by"))
+                    .child(
+                        div()
+                            .id("summary-group-columns")
+                            .max_h(px(200.0))
+                            .overflow_y_scroll()
+                            .flex()
+                            .flex_col()
+                            .gap(Spacing::XS)
+                            .children(group_checks),
+                    )
+                    .child(Text::caption("Aggregates"))
+                    .child(Input::new(&prompt.aggregates_input))
+                    .when_some(prompt.error.clone(), |d, error| {
+                        d.child(Text::caption(error).danger())
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .gap(Spacing::XXS)
+                            .justify_end()
+                            .child(
+                                Button::new("cancel-summary")
+                                    .label("Cancel")
+                                    .small()
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.cancel_summary_prompt(window, cx);
+                                    })),
+                            )
+                            .child(
+                                Button::new("apply-summary")
+                                    .label("Summarize")
+                                    .small()
+                                    .with_variant(ButtonVariant::Primary)
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.apply_summary_prompt(window, cx);
+                                    })),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::DefaultSqlDialect;
+
+    fn names(columns: &[&str]) -> Vec<String> {
+        columns.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn aggregates_parse_with_default_and_explicit_aliases() {
+        let columns = names(&["status", "Amount", "customer_id"]);
+        let aggregates = parse_aggregates(
+            "count(*), SUM(amount) AS total, COUNT(DISTINCT \"customer_id\")",
+            &columns,
+            &[],
+        )
+        .unwrap();
+
+        let parsed: Vec<(AggFn, Option<&str>, &str)> = aggregates
+            .iter()
+            .map(|a| (a.function, a.column.as_deref(), a.alias.as_str()))
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                (AggFn::CountStar, None, "count_star"),
+                (AggFn::Sum, Some("Amount"), "total"),
+                (
+                    AggFn::CountDistinct,
+                    Some("customer_id"),
+                    "count_distinct_customer_id"
+                ),
+            ]
+        );
+        assert_eq!(
+            format_aggregates(&aggregates),
+            "COUNT(*), SUM(Amount) AS total, COUNT(DISTINCT customer_id)"
+        );
+    }
+
+    #[test]
+    fn invalid_aggregates_are_rejected() {
+        let columns = names(&["status", "amount"]);
+
+        assert_eq!(
+            parse_aggregates("SUM(price)", &columns, &[]).unwrap_err(),
+            "unknown column price"
+        );
+        assert_eq!(
+            parse_aggregates("MEDIAN(amount)", &columns, &[]).unwrap_err(),
+            "unknown aggregate function MEDIAN"
+        );
+        assert_eq!(
+            parse_aggregates("COUNT(*) AS status", &columns, &names(&["status"])).unwrap_err(),
+            "the name status is used twice"
+        );
+    }
+
+    #[test]
+    fn summary_query_groups_filters_and_pages() {
+        let aggregates =
+            parse_aggregates("COUNT(*), SUM(amount)", &names(&["amount"]), &[]).unwrap();
+        let pagination = Pagination::Offset {
+            limit: 50,
+            offset: 100,
+        };
+
+        let (select, count) = summary_queries(
+            &DefaultSqlDialect,
+            &TableRef::with_schema("public", "orders"),
+            Some("amount > 10"),
+            &names(&["status"]),
+            &aggregates,
+            &[OrderByColumn::desc("sum_amount")],
+            &pagination,
+        );
+
+        let base = "SELECT \"status\", COUNT(*) AS \"count_star\", SUM(\"amount\") AS \"sum_amount\" \
+                    FROM \"public\".\"orders\" WHERE amount > 10 GROUP BY \"status\"";
+        assert_eq!(
+            select,
+            format!("{base} ORDER BY \"sum_amount\" DESC LIMIT 50 OFFSET 100")
+        );
+        assert_eq!(
+            count.as_deref(),
+            Some(format!("SELECT COUNT(*) FROM ({base}) AS \"_dbflux_count_subq\"").as_str())
+        );
+
+        // A sort on a column the summary does not return falls back to the
+        // group columns.
+        let (select, _) = summary_queries(
+            &DefaultSqlDialect,
+            &TableRef::new("orders"),
+            None,
+            &names(&["status"]),
+            &aggregates,
+            &[OrderByColumn::asc("id")],
+            &pagination,
+        );
+        assert!(select.ends_with("GROUP BY \"status\" ORDER BY \"status\" LIMIT 50 OFFSET 100"));
+    }
+}
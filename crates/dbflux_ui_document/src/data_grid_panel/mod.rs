@@ -1,12 +1,22 @@
+mod change_review;
+mod column_stats;
 mod context_menu;
+mod copy_selection;
 pub(crate) mod filter_bar;
+mod fk_navigation;
+mod group_summary;
 pub(crate) mod mutation_confirm;
 pub(crate) mod mutation_executor;
 mod mutations;
 mod navigation;
+mod paste_rows;
+mod pivot;
 mod query;
 mod render;
 pub mod row_inspector;
+mod session;
+mod set_value;
+mod transpose;
 mod utils;
 
 use super::query_builder::completion::{
@@ -17,6 +27,10 @@ use super::result_view::{
     ResultViewMode, default_bindings_for_time_series, should_auto_select_chart_for_time_series,
 };
 use super::task_runner::DocumentTaskRunner;
+use crate::refresh::{
+    CUSTOM_REFRESH_INDEX, RefreshCountdown, auto_refresh_dropdown_index,
+    auto_refresh_dropdown_items, parse_refresh_interval,
+};
 use dbflux_components::SqlPreviewContext;
 use dbflux_components::chart::{
     ChartDetection, ChartView, DataPointRef, SourceRowRef, detect_chart_columns,
@@ -29,11 +43,14 @@ use dbflux_components::components::document_tree::{
     DocumentTree, DocumentTreeEvent, DocumentTreeState,
 };
 use dbflux_components::controls::CompletionProvider;
-use dbflux_components::controls::{Dropdown, DropdownItem, DropdownSelectionChanged};
+use dbflux_components::controls::{Dropdown, DropdownSelectionChanged};
 use dbflux_components::controls::{InputEvent, InputState};
 use dbflux_components::modals::cell_editor::{
     CellEditorClosedEvent, CellEditorModal, CellEditorSaveEvent,
 };
+use dbflux_components::modals::cell_viewer::{
+    CellViewerClosedEvent, CellViewerModal, CellViewerSaveBytesEvent, CellViewerSaveEvent,
+};
 use dbflux_components::modals::document_preview::{
     DocumentPreviewClosedEvent, DocumentPreviewModal, DocumentPreviewSaveEvent,
 };
@@ -41,8 +58,9 @@ use dbflux_components::modals::{
     ModalMutationConfirm, ModalMutationConfirmHard, MutationConfirmOutcome,
 };
 use dbflux_core::{
-    CollectionRef, ColumnMeta, DatabaseCategory, OrderByColumn, Pagination, QueryResult,
-    RefreshPolicy, SelectQuery, SortDirection, TableRef, Value, VisualQuerySpec, WhereOperator,
+    CollectionRef, ColumnLayout, ColumnMeta, DatabaseCategory, FetchCursor, OrderByColumn,
+    Pagination, QueryResult, RefreshPolicy, SelectQuery, SortDirection, SpatialValue, TableRef,
+    Value, VisualQuerySpec, WhereOperator, is_spatial_type,
 };
 use dbflux_ui_base::AppStateEntity;
 use dbflux_ui_base::AsyncUpdateResultExt;
@@ -53,6 +71,7 @@ use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 /// Source of data for the grid panel.
@@ -181,6 +200,10 @@ pub enum DataGridEvent {
         row_values: Vec<Value>,
         position: Point<Pixels>,
     },
+    /// The user asked for the next page of a result loaded through a
+    /// [`FetchCursor`]. The owner fetches it and hands the rows to
+    /// [`DataGridPanel::append_fetched_page`].
+    LoadMoreRequested,
     /// Request to hide the results panel.
     RequestHide,
     /// Request to maximize/restore the results panel.
@@ -225,6 +248,15 @@ pub enum DataGridEvent {
     /// Carries the profile the query should run against and the fully
     /// materialized SQL (literals inlined, no placeholders).
     OpenEditorWithContent { profile_id: Uuid, sql: String },
+
+    /// A foreign-key action asked for `table` to be opened (or focused) with
+    /// its WHERE filter replaced by `filter`.
+    OpenTableFiltered {
+        profile_id: Uuid,
+        database: Option<String>,
+        table: TableRef,
+        filter: String,
+    },
 }
 
 // Re-export the rail tab enum from the chart module so DataGridPanel's render
@@ -283,7 +315,7 @@ enum EditState {
     Editing,
 }
 
-/// Sort state for in-memory sorting (QueryResult source only).
+/// One key of the in-memory sort (QueryResult and Collection sources).
 #[derive(Clone, Copy)]
 struct LocalSortState {
     column_ix: usize,
@@ -314,6 +346,18 @@ struct PendingModalOpen {
     is_json: bool,
 }
 
+struct PendingCellView {
+    column: String,
+    value: String,
+    is_json: bool,
+    /// NULL cells show the NULL marker instead of `value`.
+    is_null: bool,
+    /// Raw bytes of a binary cell, shown as a hex dump instead of `value`.
+    bytes: Option<Arc<[u8]>>,
+    /// Decoded value of a geometry/geography cell.
+    geometry: Option<SpatialValue>,
+}
+
 struct PendingDeleteConfirm {
     row_indices: Vec<usize>,
     is_table: bool,
@@ -344,6 +388,8 @@ struct TableContextMenu {
     sql_submenu_open: bool,
     /// Whether the "Copy as Query" submenu is open.
     copy_query_submenu_open: bool,
+    /// Whether the "Copy Selection As" submenu is open.
+    copy_selection_submenu_open: bool,
     /// Whether the "Filter" submenu is open.
     filter_submenu_open: bool,
     /// Whether the "Order" submenu is open.
@@ -371,6 +417,27 @@ struct ContextMenuItem {
     is_danger: bool,
 }
 
+/// Which column arrangement items the table view's context menu offers.
+#[derive(Clone, Copy)]
+struct ColumnMenuState {
+    has_frozen: bool,
+    has_hidden: bool,
+    /// False when only one column is still shown.
+    can_hide: bool,
+    /// The grid browses a SQL table, so column statistics can be queried.
+    can_show_stats: bool,
+}
+
+/// Which foreign-key items the table view's context menu offers for the
+/// clicked cell.
+#[derive(Clone, Copy, Default)]
+struct ForeignKeyMenuState {
+    /// The cell's column is part of a foreign key with a non-NULL value.
+    references: bool,
+    /// Other tables have foreign keys pointing at the cell's column.
+    referenced_by: bool,
+}
+
 /// Kind of SQL statement to generate from row data.
 #[derive(Debug, Clone, Copy)]
 enum SqlGenerateKind {
@@ -387,7 +454,7 @@ type RowActionProvider = Arc<dyn Fn(&str) -> Vec<dbflux_core::InspectorRowAction
 ///
 /// Each field is set by a producer and consumed exactly once per cycle.
 /// Fields that are mid-flow state machines (`pending_delete_confirm`,
-/// `pending_batch_remaining`, `pending_mutation_exec`,
+/// `pending_commit`, `pending_batch_remaining`, `pending_mutation_exec`,
 /// `pending_collection_chart_save`) are not included here; they are read
 /// mid-render and remain as direct fields on `DataGridPanel`.
 #[derive(Default)]
@@ -398,27 +465,41 @@ struct PendingActions {
     refresh: bool,
     toast: Option<PendingToast>,
     modal_open: Option<PendingModalOpen>,
+    cell_view: Option<PendingCellView>,
     document_preview: Option<PendingDocumentPreview>,
     context_menu_focus: bool,
     mutation_modal: Option<crate::data_grid_panel::mutation_confirm::PendingMutationModal>,
+    /// Restored from the session and not loaded yet.
+    deferred_load: bool,
 }
 
-/// The rendered table widget and its in-memory sort state.
+/// The rendered table widget and its in-memory view state.
 ///
-/// All five fields describe the current `DataTable` entity instance and the
-/// `QueryResult`-source local sort; they are created and destroyed together in
-/// `rebuild_table`.
+/// The widget fields describe the current `DataTable` entity instance and are
+/// recreated in `rebuild_table`. The remaining fields describe the
+/// client-side search and sort of query results and collections, applied by
+/// `apply_local_view` without re-running the query.
 struct GridTableState {
     data_table: Option<Entity<DataTable>>,
     table_state: Option<Entity<DataTableState>>,
     table_subscription: Option<Subscription>,
-    local_sort_state: Option<LocalSortState>,
+    /// Sort keys, highest priority first.
+    local_sort: Vec<LocalSortState>,
+    /// Lowercased search text; rows without a matching cell are hidden.
+    local_search: String,
+    /// Rows as the query returned them, kept while a local view is applied.
+    base_rows: Option<Vec<Vec<Value>>>,
+    /// Maps each displayed row to its index in `base_rows`.
     original_row_order: Option<Vec<usize>>,
+    /// Column arrangement of a query result, re-applied when the table is
+    /// rebuilt. Browsed tables keep theirs in the UI state store instead.
+    column_layout: Option<ColumnLayout>,
 }
 
-/// The WHERE/LIMIT inputs and refresh-policy dropdown.
+/// The WHERE/LIMIT inputs, the client-side search input and the
+/// refresh-policy dropdown.
 ///
-/// All four fields are consumed by `render_toolbar` /
+/// The fields are consumed by `render_toolbar`, `render_search_bar` and
 /// `render_filter_bar_as_segment`; they are created together at construction
 /// time and are never individually swapped out.
 struct FilterBarState {
@@ -427,18 +508,32 @@ struct FilterBarState {
     /// `source` is `DataSource::Table` and a completion provider was wired.
     filter_completion_cache: Option<Rc<RefCell<SchemaCache>>>,
     limit_input: Entity<InputState>,
+    /// Narrows the displayed rows of a query result without re-running it.
+    search_input: Entity<InputState>,
     /// Refresh-policy dropdown; rendered both in the embedded toolbar and the
     /// chart toolbar. Change events are handled via a subscription wired in
     /// `new_internal`.
     refresh_dropdown: Entity<Dropdown>,
 }
 
-/// Auto-refresh policy, timer, and grid load state.
+/// Page-by-page loading of a query result fetched through a [`FetchCursor`].
+#[derive(Default)]
+struct FetchState {
+    /// Paging state of the statement; `None` when the result was fetched whole.
+    cursor: Option<FetchCursor>,
+    /// A "Load more" request is in flight.
+    loading: bool,
+}
+
+/// Auto-refresh policy, timer, countdown, and grid load state.
 ///
-/// The four fields are mutated together in `set_refresh_policy` /
-/// `update_refresh_timer` and are logically inseparable.
+/// The policy, timer and countdown are mutated together in
+/// `set_refresh_policy` / `update_refresh_timer` and are logically inseparable.
 struct RefreshState {
     refresh_policy: RefreshPolicy,
+    countdown: RefreshCountdown,
+    /// Interval input shown after picking "Custom…", with its subscription.
+    custom_input: Option<(Entity<InputState>, Subscription)>,
     _refresh_timer: Option<Task<()>>,
     _refresh_subscriptions: Vec<Subscription>,
     state: GridState,
@@ -462,6 +557,7 @@ struct DocumentViewState {
 
     document_preview_modal: Entity<DocumentPreviewModal>,
     cell_editor: Entity<CellEditorModal>,
+    cell_viewer: Entity<CellViewerModal>,
 }
 
 /// Chart shell and source time-range panel.
@@ -571,7 +667,8 @@ pub(crate) struct BuilderState {
 pub struct DataGridPanel {
     source: DataSource,
     app_state: gpui::Entity<AppStateEntity>,
-    result: QueryResult,
+    /// Shared with the table model, which builds cells from it on demand.
+    result: Arc<QueryResult>,
     grid_table: GridTableState,
     filter_bar: FilterBarState,
     refresh: RefreshState,
@@ -590,11 +687,28 @@ pub struct DataGridPanel {
     context_menu: Option<TableContextMenu>,
     is_active_tab: bool,
     pending: PendingActions,
+    fetch: FetchState,
     pending_delete_confirm: Option<PendingDeleteConfirm>,
+    pending_commit: Option<change_review::PendingCommit>,
+    /// Undo review for the last saved change set, while one is available.
+    last_change_set: Option<change_review::PendingCommit>,
+    set_value_prompt: Option<set_value::SetValuePrompt>,
+    column_stats: Option<column_stats::ColumnStatsView>,
+    /// Group-by summary picked for a table, shown while it is active.
+    group_summary: Option<group_summary::GroupSummary>,
+    summary_prompt: Option<group_summary::SummaryPrompt>,
+    /// Pivot of the loaded rows, shown in their place while set.
+    pivot: Option<pivot::PivotView>,
+    pivot_prompt: Option<pivot::PivotPrompt>,
     pending_batch_remaining: Option<PendingBatchRemaining>,
     /// Pending "Save chart from collection" state.
     pub(super) pending_collection_chart_save: Option<CollectionChartSaveState>,
     pub(crate) pending_mutation_exec: Option<PendingMutationExec>,
+    /// When the current result arrived, until its first frame is drawn and
+    /// the grid render time is recorded.
+    result_received_at: Option<Instant>,
+    /// Time from the current result's arrival to its first drawn frame.
+    result_render_time: Option<Duration>,
 }
 
 /// Pending mutation execution — holds the spec and options while the
@@ -827,7 +941,7 @@ impl DataGridPanel {
 
         // Query results are not editable (no PK info)
         let mut panel = Self::new_internal(source, app_state, Vec::new(), window, cx);
-        panel.set_result((*result).clone(), cx);
+        panel.set_shared_result(result, cx);
         panel
     }
 
@@ -941,6 +1055,35 @@ impl DataGridPanel {
         )
         .detach();
 
+        let search_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("Search results..."));
+
+        cx.subscribe_in(
+            &search_input,
+            window,
+            |this, input, event: &InputEvent, window, cx| match event {
+                InputEvent::Change => {
+                    let text = input.read(cx).value().to_string();
+                    this.set_local_search(&text, cx);
+                }
+                InputEvent::PressEnter { .. } => {
+                    this.focus_table(window, cx);
+                }
+                // Editing state only routes typed keys to the input; focus is
+                // left where the user moved it.
+                InputEvent::Focus => {
+                    this.focus.edit_state = EditState::Editing;
+                    cx.emit(DataGridEvent::Focused);
+                    cx.notify();
+                }
+                InputEvent::Blur => {
+                    this.focus.edit_state = EditState::Navigating;
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
+
         let focus_handle = cx.focus_handle();
         let context_menu_focus = cx.focus_handle();
 
@@ -964,6 +1107,46 @@ impl DataGridPanel {
         )
         .detach();
 
+        let cell_viewer = cx.new(|cx| CellViewerModal::new(window, cx));
+
+        cx.subscribe_in(
+            &cell_viewer,
+            window,
+            |this, _, event: &CellViewerSaveEvent, _window, cx| {
+                this.save_cell_value(
+                    &event.column,
+                    event.contents.clone().into_bytes(),
+                    event.extension,
+                    cx,
+                );
+            },
+        )
+        .detach();
+
+        cx.subscribe_in(
+            &cell_viewer,
+            window,
+            |this, _, event: &CellViewerSaveBytesEvent, _window, cx| {
+                this.save_cell_bytes(
+                    &event.column,
+                    &event.bytes,
+                    event.as_hex,
+                    event.image_extension,
+                    cx,
+                );
+            },
+        )
+        .detach();
+
+        cx.subscribe_in(
+            &cell_viewer,
+            window,
+            |this, _, _: &CellViewerClosedEvent, window, cx| {
+                this.focus_active_view(window, cx);
+            },
+        )
+        .detach();
+
         let document_preview_modal = cx.new(|cx| DocumentPreviewModal::new(window, cx));
 
         cx.subscribe_in(
@@ -1032,14 +1215,9 @@ impl DataGridPanel {
         );
 
         let refresh_dropdown = cx.new(|_cx| {
-            let items: Vec<DropdownItem> = RefreshPolicy::ALL
-                .iter()
-                .map(|policy| DropdownItem::new(policy.label()))
-                .collect();
-
             Dropdown::new("data-grid-auto-refresh")
-                .items(items)
-                .selected_index(Some(default_refresh.index()))
+                .items(auto_refresh_dropdown_items())
+                .selected_index(Some(auto_refresh_dropdown_index(default_refresh)))
                 .disabled(!supports_auto_refresh)
                 .compact_trigger(true)
         });
@@ -1047,7 +1225,12 @@ impl DataGridPanel {
         let refresh_policy_sub = cx.subscribe_in(
             &refresh_dropdown,
             window,
-            |this, _, event: &DropdownSelectionChanged, _window, cx| {
+            |this, _, event: &DropdownSelectionChanged, window, cx| {
+                if event.index == CUSTOM_REFRESH_INDEX {
+                    this.open_custom_refresh_input(window, cx);
+                    return;
+                }
+
                 let policy = RefreshPolicy::from_index(event.index);
 
                 if policy.is_auto() && !this.supports_auto_refresh() {
@@ -1085,22 +1268,28 @@ impl DataGridPanel {
         Self {
             source,
             app_state,
-            result: QueryResult::empty(),
+            result: Arc::new(QueryResult::empty()),
             grid_table: GridTableState {
                 data_table: None,
                 table_state: None,
                 table_subscription: None,
-                local_sort_state: None,
+                local_sort: Vec::new(),
+                local_search: String::new(),
+                base_rows: None,
                 original_row_order: None,
+                column_layout: None,
             },
             filter_bar: FilterBarState {
                 filter_input,
                 filter_completion_cache,
                 limit_input,
+                search_input,
                 refresh_dropdown,
             },
             refresh: RefreshState {
                 refresh_policy: default_refresh,
+                countdown: RefreshCountdown::default(),
+                custom_input: None,
                 _refresh_timer: None,
                 _refresh_subscriptions: vec![refresh_policy_sub],
                 state: GridState::Ready,
@@ -1112,6 +1301,7 @@ impl DataGridPanel {
                 document_card_list: None,
                 document_preview_modal,
                 cell_editor,
+                cell_viewer,
             },
             chart: ChartState {
                 chart_shell: None,
@@ -1161,10 +1351,21 @@ impl DataGridPanel {
             context_menu: None,
             is_active_tab: true,
             pending: PendingActions::default(),
+            fetch: FetchState::default(),
             pending_delete_confirm: None,
+            pending_commit: None,
+            last_change_set: None,
+            set_value_prompt: None,
+            column_stats: None,
+            group_summary: None,
+            summary_prompt: None,
+            pivot: None,
+            pivot_prompt: None,
             pending_batch_remaining: None,
             pending_collection_chart_save: None,
             pending_mutation_exec: None,
+            result_received_at: None,
+            result_render_time: None,
         }
     }
 
@@ -1654,20 +1855,79 @@ impl DataGridPanel {
         cx.notify();
     }
 
+    /// Pause or resume the auto-refresh countdown without changing the policy.
+    pub fn toggle_refresh_paused(&mut self, cx: &mut Context<Self>) {
+        let paused = self.refresh.countdown.is_paused();
+        self.refresh.countdown.set_paused(!paused);
+        cx.notify();
+    }
+
+    fn open_custom_refresh_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder("45s, 2m, 1h"));
+        input.update(cx, |state, cx| state.focus(window, cx));
+
+        let subscription = cx.subscribe_in(
+            &input,
+            window,
+            |this, _, event: &InputEvent, _window, cx| match event {
+                InputEvent::PressEnter { .. } => this.apply_custom_refresh_input(cx),
+                InputEvent::Blur => this.close_custom_refresh_input(cx),
+                _ => {}
+            },
+        );
+
+        self.refresh.custom_input = Some((input, subscription));
+        cx.notify();
+    }
+
+    fn apply_custom_refresh_input(&mut self, cx: &mut Context<Self>) {
+        let Some((input, _)) = &self.refresh.custom_input else {
+            return;
+        };
+
+        let Some(every_secs) = parse_refresh_interval(&input.read(cx).value()) else {
+            dbflux_ui_base::toast::Toast::warning("Enter an interval such as 45s, 2m or 1h")
+                .meta_right(dbflux_ui_base::toast::now_hms())
+                .push(cx);
+            return;
+        };
+
+        self.set_refresh_policy(RefreshPolicy::Interval { every_secs }, cx);
+        self.close_custom_refresh_input(cx);
+    }
+
+    /// Drop the custom interval input and point the dropdown back at the
+    /// active policy.
+    fn close_custom_refresh_input(&mut self, cx: &mut Context<Self>) {
+        if self.refresh.custom_input.take().is_none() {
+            return;
+        }
+
+        let index = auto_refresh_dropdown_index(self.refresh.refresh_policy);
+        self.filter_bar
+            .refresh_dropdown
+            .update(cx, |dd, cx| dd.set_selected_index(Some(index), cx));
+        cx.notify();
+    }
+
+    /// Restarts the countdown and ticks it once per second; the grid reloads
+    /// when it reaches zero.
     fn update_refresh_timer(&mut self, cx: &mut Context<Self>) {
         self.refresh._refresh_timer = None;
+        self.refresh.countdown.set_paused(false);
+        self.refresh.countdown.reset(RefreshPolicy::Manual);
 
-        if !self.supports_auto_refresh() {
+        if !self.supports_auto_refresh() || !self.refresh.refresh_policy.is_auto() {
             return;
         }
 
-        let Some(duration) = self.refresh.refresh_policy.duration() else {
-            return;
-        };
+        self.refresh.countdown.reset(self.refresh.refresh_policy);
 
         self.refresh._refresh_timer = Some(cx.spawn(async move |this, cx| {
             loop {
-                cx.background_executor().timer(duration).await;
+                cx.background_executor()
+                    .timer(std::time::Duration::from_secs(1))
+                    .await;
 
                 let _ = cx.update(|cx| {
                     let Some(entity) = this.upgrade() else {
@@ -1675,10 +1935,15 @@ impl DataGridPanel {
                     };
 
                     entity.update(cx, |panel, cx| {
-                        if !panel.refresh.refresh_policy.is_auto()
-                            || !panel.supports_auto_refresh()
-                            || panel.runner.is_primary_active()
-                        {
+                        // Hold the countdown while a load is in flight.
+                        if !panel.supports_auto_refresh() || panel.runner.is_primary_active() {
+                            return;
+                        }
+
+                        let due = panel.refresh.countdown.tick(panel.refresh.refresh_policy);
+                        cx.notify();
+
+                        if !due {
                             return;
                         }
 
@@ -1710,6 +1975,12 @@ impl DataGridPanel {
 
     /// Update the result data (for QueryResult source or after table fetch).
     pub fn set_result(&mut self, result: QueryResult, cx: &mut Context<Self>) {
+        self.set_shared_result(Arc::new(result), cx);
+    }
+
+    /// [`Self::set_result`] for a result that is also held elsewhere, such as
+    /// by a `QueryResult` source; the rows are shared rather than copied.
+    fn set_shared_result(&mut self, result: Arc<QueryResult>, cx: &mut Context<Self>) {
         let was_chart_mode = matches!(self.chrome.result_view_mode, ResultViewMode::Chart);
 
         self.view_config = super::data_view::DataViewConfig::for_source(&self.source);
@@ -1771,9 +2042,9 @@ impl DataGridPanel {
             }
         }
 
-        self.result = result;
-        self.rebuild_table(None, cx);
         self.refresh.state = GridState::Ready;
+        self.result = self.pivot_incoming(result, cx);
+        self.rebuild_table(Vec::new(), cx);
 
         // Re-snapshot the row inspector against the fresh data so the rail
         // keeps following the same row position across refreshes.
@@ -1794,6 +2065,7 @@ impl DataGridPanel {
     ) {
         self.refresh.refresh_policy = RefreshPolicy::Manual;
         self.refresh._refresh_timer = None;
+        self.refresh.countdown.reset(RefreshPolicy::Manual);
 
         self.filter_bar.refresh_dropdown.update(cx, |dd, cx| {
             dd.set_selected_index(Some(RefreshPolicy::Manual.index()), cx);
@@ -1806,9 +2078,14 @@ impl DataGridPanel {
             original_query: query,
             profile_id,
         };
-        self.grid_table.local_sort_state = None;
-        self.grid_table.original_row_order = None;
-        self.set_result((*result).clone(), cx);
+        self.fetch = FetchState::default();
+        self.reset_local_view();
+        self.set_shared_result(result, cx);
+
+        // A search typed before the re-run keeps narrowing the new rows.
+        if !self.grid_table.local_search.is_empty() {
+            self.apply_local_view(cx);
+        }
     }
 
     pub(super) fn focus_active_view(&mut self, window: &mut Window, cx: &mut Context<Self>) {
@@ -1829,7 +2106,7 @@ impl DataGridPanel {
         cx.notify();
     }
 
-    fn rebuild_table(&mut self, initial_sort: Option<TableSortState>, cx: &mut Context<Self>) {
+    fn rebuild_table(&mut self, initial_sort: Vec<TableSortState>, cx: &mut Context<Self>) {
         // For collections, update pk_columns from result metadata (is_primary_key flag)
         // This allows DynamoDB and other drivers to use their actual primary keys
         // instead of hardcoded "_id"
@@ -1916,15 +2193,25 @@ impl DataGridPanel {
                 std::collections::HashSet::new()
             };
 
-        let table_model = Arc::new(TableModel::from(&self.result));
+        let column_layout = match self.column_layout_key() {
+            Some(key) => self.app_state.read(cx).table_column_layout(&key),
+            None => self.grid_table.column_layout.clone(),
+        }
+        .map(|layout| layout.resolve(&self.result_column_names()));
+
+        let table_model = Arc::new(TableModel::from(self.result.clone()));
+        let search = self.grid_table.local_search.clone();
         let table_state = cx.new(|cx| {
             let mut state = DataTableState::new(table_model, cx);
-            if let Some(sort) = initial_sort {
-                state.set_sort_without_emit(sort);
-            }
+            state.set_sort_without_emit(initial_sort);
+            state.set_highlight(&search, cx);
             state.set_pk_columns(pk_indices.clone());
             state.set_insertable(is_insertable);
 
+            if let Some((display, frozen)) = column_layout {
+                state.set_column_layout_without_emit(display, frozen);
+            }
+
             if !fk_indices.is_empty() {
                 state.set_fk_columns(fk_indices);
             }
@@ -1954,21 +2241,19 @@ impl DataGridPanel {
         let subscription =
             cx.subscribe(&table_state, |this, _state, event: &DataTableEvent, cx| {
                 match event {
-                    DataTableEvent::SortChanged(sort) => match sort {
-                        Some(sort_state) => {
-                            this.handle_sort_request(
-                                sort_state.column_ix,
-                                sort_state.direction,
-                                cx,
-                            );
-                        }
-                        None => {
+                    DataTableEvent::SortChanged(sort) => {
+                        if sort.is_empty() {
                             this.handle_sort_clear(cx);
+                        } else {
+                            this.handle_sort_request(sort, cx);
                         }
-                    },
+                    }
                     DataTableEvent::Focused => {
                         cx.emit(DataGridEvent::Focused);
                     }
+                    DataTableEvent::ColumnLayoutChanged => {
+                        this.handle_column_layout_changed(cx);
+                    }
                     DataTableEvent::SelectionChanged(selection) => {
                         // When the row inspector is active, follow the user's
                         // cursor so click / arrow-key navigation updates the
@@ -1978,6 +2263,8 @@ impl DataGridPanel {
                         {
                             this.open_row_inspector(active.row, active.col, cx);
                         }
+                        // The status bar describes the active cell.
+                        cx.notify();
                     }
                     DataTableEvent::SaveRowRequested(row_idx) => {
                         this.handle_save_row(*row_idx, cx);
@@ -2000,6 +2287,7 @@ impl DataGridPanel {
                             position: *position,
                             sql_submenu_open: false,
                             copy_query_submenu_open: false,
+                            copy_selection_submenu_open: false,
                             filter_submenu_open: false,
                             order_submenu_open: false,
                             selected_index: 0,
@@ -2043,6 +2331,49 @@ impl DataGridPanel {
                         });
                         cx.notify();
                     }
+                    DataTableEvent::ViewCellRequested {
+                        row,
+                        col,
+                        value,
+                        is_json,
+                        is_null,
+                    } => {
+                        let (column, is_spatial) = this
+                            .result
+                            .columns
+                            .get(*col)
+                            .map(|column| (column.name.clone(), is_spatial_type(&column.type_name)))
+                            .unwrap_or_default();
+
+                        // The grid only keeps the length of binary values, so
+                        // the bytes come from the result row.
+                        let row_value = this.collect_row_values(*row, cx).get(*col).cloned();
+                        let bytes = match &row_value {
+                            Some(Value::Bytes(bytes)) => Some(Arc::from(bytes.as_slice())),
+                            _ => None,
+                        };
+                        // Timestamps show the display time zone next to the
+                        // stored UTC value.
+                        let value = match &row_value {
+                            Some(Value::DateTime(dt)) => {
+                                dbflux_core::timezone::describe_timestamp(dt)
+                            }
+                            _ => value.clone(),
+                        };
+                        let geometry = row_value
+                            .filter(|_| is_spatial)
+                            .and_then(|value| SpatialValue::from_value(&value));
+
+                        this.pending.cell_view = Some(PendingCellView {
+                            column,
+                            value,
+                            is_json: *is_json,
+                            is_null: *is_null,
+                            bytes,
+                            geometry,
+                        });
+                        cx.notify();
+                    }
                     DataTableEvent::CommitInsertRequested(insert_idx) => {
                         this.handle_commit_insert(*insert_idx, cx);
                     }
@@ -2086,6 +2417,61 @@ impl DataGridPanel {
         ));
     }
 
+    /// UI state key of the browsed table's column layout, if the grid shows a
+    /// table.
+    fn column_layout_key(&self) -> Option<String> {
+        // Summary and pivot columns are not the table's; leave its layout alone.
+        if self.is_summary_active() || self.is_pivot_active() {
+            return None;
+        }
+
+        match &self.source {
+            DataSource::Table {
+                profile_id,
+                database,
+                table,
+                ..
+            } => Some(ColumnLayout::storage_key(
+                *profile_id,
+                database.as_deref(),
+                table,
+            )),
+            _ => None,
+        }
+    }
+
+    fn result_column_names(&self) -> Vec<String> {
+        self.result
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect()
+    }
+
+    /// Remembers the arrangement the user just made so rebuilding the table
+    /// (re-running, paging, sorting) keeps it.
+    fn handle_column_layout_changed(&mut self, cx: &mut Context<Self>) {
+        let Some(table_state) = &self.grid_table.table_state else {
+            return;
+        };
+
+        let state = table_state.read(cx);
+        let layout = ColumnLayout::capture(
+            &self.result_column_names(),
+            state.display_columns(),
+            state.frozen_column_count(),
+        );
+        let is_default = state.has_default_column_layout();
+
+        if let Some(key) = self.column_layout_key() {
+            self.app_state.update(cx, |app, _cx| {
+                app.set_table_column_layout(&key, (!is_default).then_some(&layout));
+            });
+        }
+
+        self.grid_table.column_layout = (!is_default).then_some(layout);
+    }
+
     fn rebuild_document_tree(&mut self, cx: &mut Context<Self>) {
         let tree_state = cx.new(|cx| {
             let mut state = DocumentTreeState::new(cx);
@@ -2137,6 +2523,7 @@ impl DataGridPanel {
                         position: *position,
                         sql_submenu_open: false,
                         copy_query_submenu_open: false,
+                        copy_selection_submenu_open: false,
                         filter_submenu_open: false,
                         order_submenu_open: false,
                         selected_index: 0,
@@ -2242,19 +2629,31 @@ impl DataGridPanel {
         Vec::new()
     }
 
+    /// Sorted column names joined in priority order, the direction of the
+    /// first key, and whether the sort runs on the server.
     fn current_sort_info(&self) -> Option<(String, SortDirection, bool)> {
-        match &self.source {
-            DataSource::Table { order_by, .. } => order_by
-                .first()
-                .map(|col| (col.column.name.clone(), col.direction, true)),
-            DataSource::Collection { .. } => None,
-            DataSource::QueryResult { .. } => self.grid_table.local_sort_state.and_then(|state| {
-                self.result
-                    .columns
-                    .get(state.column_ix)
-                    .map(|col| (col.name.clone(), state.direction, false))
-            }),
-        }
+        let (names, direction, is_server): (Vec<String>, _, _) = match &self.source {
+            DataSource::Table { order_by, .. } if self.pivot.is_none() => (
+                order_by.iter().map(|col| col.column.name.clone()).collect(),
+                order_by.first()?.direction,
+                true,
+            ),
+            DataSource::Collection { .. } if self.pivot.is_none() => return None,
+            DataSource::Table { .. }
+            | DataSource::Collection { .. }
+            | DataSource::QueryResult { .. } => (
+                self.grid_table
+                    .local_sort
+                    .iter()
+                    .filter_map(|key| self.result.columns.get(key.column_ix))
+                    .map(|col| col.name.clone())
+                    .collect(),
+                self.grid_table.local_sort.first()?.direction,
+                false,
+            ),
+        };
+
+        Some((names.join(", "), direction, is_server))
     }
 
     #[allow(dead_code)]
@@ -2424,14 +2823,12 @@ impl DataGridPanel {
     /// Returns the current result as a shared `Arc<QueryResult>`.
     ///
     /// For `QueryResult` sources the result is already `Arc`-wrapped in the
-    /// source; for other sources we wrap the live `result` field in a new
-    /// `Arc` (shallow clone, no data copy).
+    /// source; for other sources the live `result` field is shared (no data
+    /// copy).
     pub(crate) fn chart_host_current_result(&self, _cx: &App) -> Option<Arc<QueryResult>> {
         match &self.source {
             DataSource::QueryResult { result, .. } => Some(result.clone()),
-            DataSource::Table { .. } | DataSource::Collection { .. } => {
-                Some(Arc::new(self.result.clone()))
-            }
+            DataSource::Table { .. } | DataSource::Collection { .. } => Some(self.result.clone()),
         }
     }
 
@@ -2651,12 +3048,15 @@ impl DataGridPanel {
     /// `current_visual_spec` is updated only on successful query completion so
     /// it always describes the rows visible in the grid. On query failure the
     /// previous successful spec is retained, which keeps this method consistent
-    /// with what the user can actually see and interact with.
+    /// with what the user can actually see and interact with. A pivot or a
+    /// transposed result counts too: its cells are not rows of the source.
     pub fn is_grouped_result(&self) -> bool {
-        self.builder
-            .current_visual_spec
-            .as_ref()
-            .is_some_and(|s| s.is_grouped())
+        self.pivot.is_some()
+            || self
+                .builder
+                .current_visual_spec
+                .as_ref()
+                .is_some_and(|s| s.is_grouped())
     }
 
     /// Returns `true` when row mutations (add, edit, delete) are permitted on
@@ -4004,6 +4404,8 @@ impl DataGridPanel {
                         cx.update(|cx| {
                             this.update(cx, |grid, cx| {
                                 grid.runner.complete_mutation(task_id, cx);
+                                grid.pending.refresh = true;
+                                cx.notify();
                             })
                             .ok();
                             dbflux_ui_base::toast::Toast::success(format!(
@@ -4094,6 +4496,8 @@ impl DataGridPanel {
                         cx.update(|cx| {
                             this.update(cx, |grid, cx| {
                                 grid.runner.complete_mutation(task_id, cx);
+                                grid.pending.refresh = true;
+                                cx.notify();
                             })
                             .ok();
                             dbflux_ui_base::toast::Toast::success(format!(
@@ -4181,6 +4585,8 @@ impl DataGridPanel {
                         cx.update(|cx| {
                             this.update(cx, |grid, cx| {
                                 grid.runner.complete_mutation(task_id, cx);
+                                grid.pending.refresh = true;
+                                cx.notify();
                             })
                             .ok();
                             dbflux_ui_base::toast::Toast::success(format!(
@@ -5168,6 +5574,7 @@ mod tests {
                     schema_foreign_keys: Default::default(),
                     schema_routines: Default::default(),
                     dependents_cache: Default::default(),
+                    table_statistics: Default::default(),
                     active_database: None,
                     redis_key_cache: Default::default(),
                     database_connections: Default::default(),
@@ -5944,6 +6351,7 @@ mod tests {
                     schema_foreign_keys: Default::default(),
                     schema_routines: Default::default(),
                     dependents_cache: Default::default(),
+                    table_statistics: Default::default(),
                     active_database: None,
                     redis_key_cache: Default::default(),
                     database_connections: Default::default(),
@@ -6100,7 +6508,12 @@ mod tests {
                         is_primary_key: false,
                     },
                 ];
-                panel.result = QueryResult::table(columns, Vec::new(), None, Duration::ZERO);
+                panel.result = Arc::new(QueryResult::table(
+                    columns,
+                    Vec::new(),
+                    None,
+                    Duration::ZERO,
+                ));
                 panel.pk_columns = vec!["id".to_string()];
 
                 // Install the binding: id is Source/PK, amount is Joined, not insertable.
@@ -6134,7 +6547,7 @@ mod tests {
         // Trigger rebuild_table.
         window.update(|_, app| {
             panel.update(app, |panel, cx| {
-                panel.rebuild_table(None, cx);
+                panel.rebuild_table(Vec::new(), cx);
             });
         });
 
@@ -6202,7 +6615,12 @@ mod tests {
                     nullable: false,
                     is_primary_key: false,
                 }];
-                panel.result = QueryResult::table(columns, Vec::new(), None, Duration::ZERO);
+                panel.result = Arc::new(QueryResult::table(
+                    columns,
+                    Vec::new(),
+                    None,
+                    Duration::ZERO,
+                ));
                 panel.builder.current_visual_spec = Some(make_grouped_spec());
                 panel.builder.builder_editable_binding = None;
 
@@ -6220,7 +6638,7 @@ mod tests {
 
         window.update(|_, app| {
             panel.update(app, |panel, cx| {
-                panel.rebuild_table(None, cx);
+                panel.rebuild_table(Vec::new(), cx);
             });
         });
 
@@ -6354,6 +6772,7 @@ mod tests {
                     schema_foreign_keys: Default::default(),
                     schema_routines: Default::default(),
                     dependents_cache: Default::default(),
+                    table_statistics: Default::default(),
                     active_database: Some("app".to_string()),
                     redis_key_cache: Default::default(),
                     database_connections: Default::default(),
@@ -6422,6 +6841,8 @@ mod tests {
                                 is_primary_key: true,
                                 default_value: None,
                                 enum_values: None,
+                                comment: None,
+                                definition: None,
                             },
                             ColumnInfo {
                                 name: "name".to_string(),
@@ -6430,6 +6851,8 @@ mod tests {
                                 is_primary_key: false,
                                 default_value: None,
                                 enum_values: None,
+                                comment: None,
+                                definition: None,
                             },
                         ]),
                         indexes: None,
@@ -6438,6 +6861,7 @@ mod tests {
                         sample_fields: None,
                         presentation: Default::default(),
                         child_items: None,
+                        comment: None,
                     },
                 );
             });
@@ -6653,6 +7077,7 @@ mod tests {
                     schema_foreign_keys: Default::default(),
                     schema_routines: Default::default(),
                     dependents_cache: Default::default(),
+                    table_statistics: Default::default(),
                     active_database: None,
                     redis_key_cache: Default::default(),
                     database_connections: Default::default(),
@@ -6700,7 +7125,12 @@ mod tests {
                     dbflux_core::Value::Int(1),
                     dbflux_core::Value::Text("alice".to_string()),
                 ]];
-                panel.result = QueryResult::table(columns, rows, None, std::time::Duration::ZERO);
+                panel.result = Arc::new(QueryResult::table(
+                    columns,
+                    rows,
+                    None,
+                    std::time::Duration::ZERO,
+                ));
                 panel.pk_columns = vec!["id".to_string()];
 
                 panel
@@ -6718,7 +7148,7 @@ mod tests {
         // Rebuild the table so table_state is populated and pk_indices are set.
         window.update(|_, app| {
             panel.update(app, |panel, cx| {
-                panel.rebuild_table(None, cx);
+                panel.rebuild_table(Vec::new(), cx);
             });
         });
 
@@ -10,6 +10,7 @@ use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error, report_error_async};
 use gpui::*;
 use std::collections::BTreeMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
 fn parse_inline_document_value(input: &str) -> serde_json::Value {
@@ -153,17 +154,14 @@ impl DataGridPanel {
             return;
         };
 
-        let Some(row) = self.result.rows.get_mut(doc_index) else {
+        let result = Arc::make_mut(&mut self.result);
+        let Some(row) = result.rows.get_mut(doc_index) else {
             return;
         };
 
         let mut updated = false;
 
-        if let Some(doc_col_idx) = self
-            .result
-            .columns
-            .iter()
-            .position(|c| c.name == "_document")
+        if let Some(doc_col_idx) = result.columns.iter().position(|c| c.name == "_document")
             && let Some(doc_cell) = row.get_mut(doc_col_idx)
         {
             updated |= set_value_at_path(doc_cell, &node_id.path[1..], new_value.clone());
@@ -171,11 +169,7 @@ impl DataGridPanel {
 
         if node_id.path.len() == 2 {
             let field_name = &node_id.path[1];
-            if let Some(col_idx) = self
-                .result
-                .columns
-                .iter()
-                .position(|c| c.name == *field_name)
+            if let Some(col_idx) = result.columns.iter().position(|c| c.name == *field_name)
                 && let Some(cell) = row.get_mut(col_idx)
             {
                 *cell = new_value.clone();
@@ -494,6 +488,27 @@ impl DataGridPanel {
             return;
         }
 
+        let change_values = match change_values
+            .into_iter()
+            .map(ColumnAssignment::validated)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(change_values) => change_values,
+            Err(error) => {
+                let message = format!("Cannot save row: {}", error);
+
+                table_state.update(cx, |state, cx| {
+                    state
+                        .edit_buffer_mut()
+                        .set_row_state(row_idx, RowState::Error(message.clone()));
+                    cx.notify();
+                });
+
+                report_error(UserFacingError::new(ErrorKind::User, message), cx);
+                return;
+            }
+        };
+
         let patch = RowPatch::with_typed_changes(
             identity,
             table_ref.name.clone(),
@@ -939,6 +954,21 @@ impl DataGridPanel {
             return;
         }
 
+        let assignments = match assignments
+            .into_iter()
+            .map(ColumnAssignment::validated)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(assignments) => assignments,
+            Err(error) => {
+                report_error(
+                    UserFacingError::new(ErrorKind::User, format!("Cannot insert: {}", error)),
+                    cx,
+                );
+                return;
+            }
+        };
+
         let insert = RowInsert::with_typed_assignments(
             table_ref.name.clone(),
             table_ref.schema.clone(),
@@ -1374,6 +1404,14 @@ impl DataGridPanel {
         dirty_rows: Vec<usize>,
         cx: &mut Context<Self>,
     ) {
+        // Tables whose driver supports transactions are reviewed and saved
+        // atomically; everything else goes through the row-by-row pipeline.
+        if matches!(self.source, DataSource::Table { .. })
+            && self.open_commit_review(&pending_deletes, &pending_inserts, &dirty_rows, cx)
+        {
+            return;
+        }
+
         let has_remaining = !pending_inserts.is_empty() || !dirty_rows.is_empty();
 
         // Handle all pending deletes as a batch
@@ -1,31 +1,27 @@
 use super::{
-    DataGridPanel, DataSource, EditState, GridFocusMode, LocalSortState, PendingRequery,
-    ToolbarFocus,
+    DataGridEvent, DataGridPanel, DataSource, EditState, FetchState, GridFocusMode, LocalSortState,
+    PendingRequery, ToolbarFocus,
 };
 use dbflux_app::keymap::Command;
-use dbflux_components::components::data_table::{Direction, Edge, SortState as TableSortState};
-use dbflux_core::{OrderByColumn, Pagination, SortDirection};
+use dbflux_components::components::data_table::{
+    Direction, Edge, SortState as TableSortState, TableModel,
+};
+use dbflux_core::{
+    FetchCursor, OrderByColumn, Pagination, PaginationStyle, Row, SortDirection, Value,
+};
 use gpui::*;
 use std::cmp::Ordering;
+use std::sync::Arc;
+use uuid::Uuid;
 
 impl DataGridPanel {
     // === Sorting ===
 
-    pub(super) fn handle_sort_request(
-        &mut self,
-        col_ix: usize,
-        direction: SortDirection,
-        cx: &mut Context<Self>,
-    ) {
-        let col_name = self
-            .result
-            .columns
-            .get(col_ix)
-            .map(|c| c.name.clone())
-            .unwrap_or_default();
-
+    pub(super) fn handle_sort_request(&mut self, sort: &[TableSortState], cx: &mut Context<Self>) {
         // Extract values before mutating self.source
         let table_info = match &self.source {
+            // A pivot is computed locally, so it also sorts locally.
+            DataSource::Table { .. } if self.pivot.is_some() => None,
             DataSource::Table {
                 profile_id,
                 database,
@@ -46,7 +42,15 @@ impl DataGridPanel {
 
         if let Some((profile_id, database, table, new_pagination, total_rows)) = table_info {
             // Server-side sort: update source and queue re-query
-            let new_order_by = vec![OrderByColumn::from_name(&col_name, direction)];
+            let new_order_by: Vec<OrderByColumn> = sort
+                .iter()
+                .filter_map(|key| {
+                    self.result
+                        .columns
+                        .get(key.column_ix)
+                        .map(|c| OrderByColumn::from_name(&c.name, key.direction))
+                })
+                .collect();
 
             let filter_value = self.filter_bar.filter_input.read(cx).value();
             let filter = if filter_value.trim().is_empty() {
@@ -79,13 +83,22 @@ impl DataGridPanel {
             cx.notify();
         } else {
             // Client-side sort: sort in memory
-            self.apply_local_sort(col_ix, direction, cx);
+            self.grid_table.local_sort = sort
+                .iter()
+                .map(|key| LocalSortState {
+                    column_ix: key.column_ix,
+                    direction: key.direction,
+                })
+                .collect();
+            self.apply_local_view(cx);
         }
     }
 
     pub(super) fn handle_sort_clear(&mut self, cx: &mut Context<Self>) {
         // Extract values before mutating self.source
         let table_info = match &self.source {
+            // A pivot is computed locally, so it also sorts locally.
+            DataSource::Table { .. } if self.pivot.is_some() => None,
             DataSource::Table {
                 profile_id,
                 database,
@@ -140,75 +153,151 @@ impl DataGridPanel {
 
             cx.notify();
         } else {
-            // Restore original row order
-            if let Some(original_order) = self.grid_table.original_row_order.take() {
-                let mut restore_indices: Vec<(usize, usize)> = original_order
-                    .iter()
-                    .enumerate()
-                    .map(|(current, &original)| (original, current))
-                    .collect();
-                restore_indices.sort_by_key(|(orig, _)| *orig);
+            self.grid_table.local_sort.clear();
+            self.apply_local_view(cx);
+        }
+    }
 
-                let rows = std::mem::take(&mut self.result.rows);
-                self.result.rows = restore_indices
-                    .into_iter()
-                    .map(|(_, current)| rows[current].clone())
-                    .collect();
-            }
+    /// Updates the client-side search text and re-applies the local view.
+    pub(super) fn set_local_search(&mut self, text: &str, cx: &mut Context<Self>) {
+        let search = text.trim().to_lowercase();
 
-            self.grid_table.local_sort_state = None;
+        if search == self.grid_table.local_search {
+            return;
+        }
+
+        self.grid_table.local_search = search;
+        self.apply_local_view(cx);
+    }
+
+    /// Forgets the local search base and sort keys when a new result arrives.
+    /// The search text itself is kept so it applies to the new rows.
+    pub(super) fn reset_local_view(&mut self) {
+        self.grid_table.local_sort.clear();
+        self.grid_table.base_rows = None;
+        self.grid_table.original_row_order = None;
+    }
+
+    /// Rebuilds the displayed rows from the query's rows: drops rows with no
+    /// cell matching the search text, then orders them by the sort keys.
+    /// Table sources filter and sort on the server instead.
+    pub(super) fn apply_local_view(&mut self, cx: &mut Context<Self>) {
+        if matches!(self.source, DataSource::Table { .. }) && self.pivot.is_none() {
             self.pending.rebuild = true;
             cx.notify();
+            return;
         }
+
+        // Query results are read-only, but collection edits are saved into the
+        // displayed rows; fold those back so re-filtering does not revert them.
+        if self.source.is_collection()
+            && let (Some(base), Some(order)) = (
+                self.grid_table.base_rows.as_mut(),
+                self.grid_table.original_row_order.as_ref(),
+            )
+            && order.len() == self.result.rows.len()
+        {
+            for (row, &base_ix) in self.result.rows.iter().zip(order) {
+                base[base_ix] = row.clone();
+            }
+        }
+
+        let base = self
+            .grid_table
+            .base_rows
+            .get_or_insert_with(|| self.result.rows.clone());
+
+        let order = local_view_order(
+            base,
+            &self.grid_table.local_search,
+            &self.grid_table.local_sort,
+        );
+        // The table model shares the current result; swap in a new one
+        // rather than copying the rows just to overwrite them.
+        self.result = Arc::new(
+            self.result
+                .with_rows(order.iter().map(|&ix| base[ix].clone()).collect()),
+        );
+        self.grid_table.original_row_order = Some(order);
+
+        self.pending.rebuild = true;
+        cx.notify();
     }
 
-    pub(super) fn apply_local_sort(
-        &mut self,
-        col_ix: usize,
-        direction: SortDirection,
-        cx: &mut Context<Self>,
-    ) {
-        // Save original order if this is the first sort
-        if self.grid_table.original_row_order.is_none() {
-            self.grid_table.original_row_order = Some((0..self.result.rows.len()).collect());
-        }
-
-        // Sort using indices for tracking
-        let mut indices: Vec<usize> = (0..self.result.rows.len()).collect();
-        indices.sort_by(|&a, &b| {
-            let val_a = self.result.rows[a].get(col_ix);
-            let val_b = self.result.rows[b].get(col_ix);
-
-            let cmp = match (val_a, val_b) {
-                (Some(a), Some(b)) => a.cmp(b),
-                (None, Some(_)) => Ordering::Greater,
-                (Some(_), None) => Ordering::Less,
-                (None, None) => Ordering::Equal,
-            };
+    // === Incremental fetch ===
 
-            match direction {
-                SortDirection::Ascending => cmp,
-                SortDirection::Descending => cmp.reverse(),
-            }
-        });
+    /// Attaches the paging state of the statement that produced the current
+    /// result, enabling the "Load more" control while pages remain.
+    pub fn set_fetch_cursor(&mut self, cursor: Option<FetchCursor>, cx: &mut Context<Self>) {
+        self.fetch = FetchState {
+            cursor,
+            loading: false,
+        };
+        cx.notify();
+    }
 
-        // Reorder rows according to sorted indices
-        let sorted_rows: Vec<_> = indices
-            .iter()
-            .map(|&i| self.result.rows[i].clone())
-            .collect();
-        self.result.rows = sorted_rows;
+    pub fn fetch_cursor(&self) -> Option<&FetchCursor> {
+        self.fetch.cursor.as_ref()
+    }
 
-        // Update original_row_order to map new order -> original
-        if let Some(ref mut orig) = self.grid_table.original_row_order {
-            *orig = indices.iter().map(|&i| orig[i]).collect();
+    pub(super) fn can_load_more(&self) -> bool {
+        !self.fetch.loading
+            && self
+                .fetch
+                .cursor
+                .as_ref()
+                .is_some_and(FetchCursor::has_more)
+    }
+
+    pub(super) fn request_load_more(&mut self, cx: &mut Context<Self>) {
+        if !self.can_load_more() {
+            return;
+        }
+
+        self.fetch.loading = true;
+        cx.emit(DataGridEvent::LoadMoreRequested);
+        cx.notify();
+    }
+
+    /// Appends a page fetched for [`DataGridEvent::LoadMoreRequested`] below
+    /// the loaded rows. An active search or sort is re-applied over the grown
+    /// result.
+    pub fn append_fetched_page(&mut self, mut rows: Vec<Row>, cx: &mut Context<Self>) {
+        self.fetch.loading = false;
+
+        let Some(cursor) = self.fetch.cursor.as_mut() else {
+            cx.notify();
+            return;
+        };
+
+        cursor.advance(&mut rows);
+
+        if self.pivot.is_some() {
+            self.append_pivot_rows(rows, cx);
+            return;
+        }
+
+        if let Some(base) = self.grid_table.base_rows.as_mut() {
+            base.extend(rows);
+            self.apply_local_view(cx);
+            return;
+        }
+
+        Arc::make_mut(&mut self.result).rows.extend(rows);
+
+        // Swapping the model in place keeps scroll position, column widths
+        // and selection, unlike a full table rebuild.
+        let model = Arc::new(TableModel::from(self.result.clone()));
+        if let Some(table_state) = &self.grid_table.table_state {
+            table_state.update(cx, |state, cx| state.set_model(model, cx));
         }
 
-        self.grid_table.local_sort_state = Some(LocalSortState {
-            column_ix: col_ix,
-            direction,
-        });
-        self.pending.rebuild = true;
+        cx.notify();
+    }
+
+    /// Clears the in-flight flag after a failed page fetch so it can be retried.
+    pub fn cancel_load_more(&mut self, cx: &mut Context<Self>) {
+        self.fetch.loading = false;
         cx.notify();
     }
 
@@ -228,7 +317,7 @@ impl DataGridPanel {
                     *profile_id,
                     database.clone(),
                     table.clone(),
-                    pagination.next_page(),
+                    self.next_table_page(*profile_id, pagination, order_by, cx),
                     order_by.clone(),
                     *total_rows,
                     window,
@@ -298,6 +387,52 @@ impl DataGridPanel {
         }
     }
 
+    /// Next page of a table browse. Seeks past the last row's primary key
+    /// when the driver supports keyset pagination and the rows are ordered by
+    /// that key; otherwise falls back to the next offset.
+    fn next_table_page(
+        &self,
+        profile_id: Uuid,
+        pagination: &Pagination,
+        order_by: &[OrderByColumn],
+        cx: &App,
+    ) -> Pagination {
+        let ordered_by_key = !self.pk_columns.is_empty()
+            && order_by.len() == self.pk_columns.len()
+            && order_by.iter().zip(&self.pk_columns).all(|(column, pk)| {
+                column.direction == SortDirection::Ascending
+                    && column.column.table.is_none()
+                    && column.column.name == *pk
+            });
+
+        let supports_keyset = ordered_by_key
+            && self
+                .app_state
+                .read(cx)
+                .connections()
+                .get(&profile_id)
+                .and_then(|connected| connected.connection.metadata().query.as_ref())
+                .is_some_and(|query| query.pagination.contains(&PaginationStyle::Keyset));
+
+        let last_key = supports_keyset
+            .then(|| self.result.rows.last())
+            .flatten()
+            .and_then(|row| {
+                self.pk_columns
+                    .iter()
+                    .map(|pk| {
+                        let index = self.result.columns.iter().position(|c| c.name == *pk)?;
+                        row.get(index).filter(|value| !value.is_null()).cloned()
+                    })
+                    .collect::<Option<Vec<_>>>()
+            });
+
+        match last_key {
+            Some(key) => pagination.seek_after(self.pk_columns.clone(), key),
+            None => pagination.next_page(),
+        }
+    }
+
     pub(super) fn can_go_prev(&self) -> bool {
         self.source
             .pagination()
@@ -352,6 +487,30 @@ impl DataGridPanel {
         }
     }
 
+    /// Moves the active cell one screen of rows down.
+    pub fn page_down(&mut self, cx: &mut Context<Self>) {
+        if self.result.rows.is_empty() {
+            return;
+        }
+        if let Some(table_state) = &self.grid_table.table_state {
+            table_state.update(cx, |state, cx| {
+                state.move_active(Direction::PageDown, false, cx);
+            });
+        }
+    }
+
+    /// Moves the active cell one screen of rows up.
+    pub fn page_up(&mut self, cx: &mut Context<Self>) {
+        if self.result.rows.is_empty() {
+            return;
+        }
+        if let Some(table_state) = &self.grid_table.table_state {
+            table_state.update(cx, |state, cx| {
+                state.move_active(Direction::PageUp, false, cx);
+            });
+        }
+    }
+
     pub fn select_first(&mut self, cx: &mut Context<Self>) {
         if self.result.rows.is_empty() {
             return;
@@ -420,6 +579,17 @@ impl DataGridPanel {
         cx.notify();
     }
 
+    /// Focuses the client-side search input of a query result.
+    pub fn focus_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !matches!(self.source, DataSource::QueryResult { .. }) {
+            return;
+        }
+
+        self.filter_bar.search_input.update(cx, |input, cx| {
+            input.focus(window, cx);
+        });
+    }
+
     pub fn toolbar_left(&mut self, cx: &mut Context<Self>) {
         if self.focus.focus_mode != GridFocusMode::Toolbar {
             return;
@@ -499,6 +669,43 @@ impl DataGridPanel {
             }
         }
 
+        if self.set_value_prompt.is_some() {
+            if cmd == Command::Cancel {
+                self.cancel_set_value(window, cx);
+            }
+            return true;
+        }
+
+        if self.summary_prompt.is_some() {
+            if cmd == Command::Cancel {
+                self.cancel_summary_prompt(window, cx);
+            }
+            return true;
+        }
+
+        if self.pivot_prompt.is_some() {
+            if cmd == Command::Cancel {
+                self.cancel_pivot_prompt(window, cx);
+            }
+            return true;
+        }
+
+        if self.column_stats.is_some() {
+            if cmd == Command::Cancel {
+                self.close_column_stats(window, cx);
+            }
+            return true;
+        }
+
+        if self.pending_commit.is_some() {
+            match cmd {
+                Command::Cancel => self.cancel_commit(window, cx),
+                Command::Execute => self.apply_commit(window, cx),
+                _ => {}
+            }
+            return true;
+        }
+
         // Handle context menu commands when menu is open
         if self.context_menu.is_some() {
             return self.dispatch_menu_command(cmd, window, cx);
@@ -563,13 +770,26 @@ impl DataGridPanel {
                 self.focus_toolbar(cx);
                 true
             }
+            Command::FocusSearch if matches!(self.source, DataSource::QueryResult { .. }) => {
+                self.focus_search(window, cx);
+                true
+            }
+            Command::SaveQuery if self.change_summary(cx).is_some() => {
+                if let Some(table_state) = &self.grid_table.table_state {
+                    table_state.update(cx, |state, cx| state.request_save_all(cx));
+                }
+                true
+            }
             Command::Execute => {
                 if let Some(table_state) = &self.grid_table.table_state {
                     table_state.update(cx, |state, cx| {
                         if state.is_editing() {
                             state.stop_editing(true, cx);
-                        } else if let Some(coord) = state.selection().active {
-                            state.start_editing(coord, window, cx);
+                        } else if let Some(coord) = state.selection().active
+                            && !state.start_editing(coord, window, cx)
+                        {
+                            // Read-only cells open in the viewer instead.
+                            state.request_cell_view(coord, cx);
                         }
                     });
                 }
@@ -615,11 +835,19 @@ impl DataGridPanel {
                 self.column_right(cx);
                 true
             }
-            Command::ResultsNextPage | Command::PageDown => {
+            Command::PageDown => {
+                self.page_down(cx);
+                true
+            }
+            Command::PageUp => {
+                self.page_up(cx);
+                true
+            }
+            Command::ResultsNextPage => {
                 self.go_to_next_page(window, cx);
                 true
             }
-            Command::ResultsPrevPage | Command::PageUp => {
+            Command::ResultsPrevPage => {
                 self.go_to_prev_page(window, cx);
                 true
             }
@@ -644,7 +872,87 @@ impl DataGridPanel {
                 self.handle_copy(window, cx);
                 true
             }
+            Command::ToggleRowInspector => {
+                self.toggle_row_inspector(cx);
+                true
+            }
             _ => false,
         }
     }
 }
+
+/// Indices of the rows to display, in display order: rows with a non-null cell
+/// containing `search` (already lowercased; empty keeps every row), stably
+/// ordered by `sort` with the first key taking priority.
+fn local_view_order(rows: &[Vec<Value>], search: &str, sort: &[LocalSortState]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len())
+        .filter(|&ix| {
+            search.is_empty()
+                || rows[ix].iter().any(|value| {
+                    !value.is_null() && value.as_shown_string().to_lowercase().contains(search)
+                })
+        })
+        .collect();
+
+    if sort.is_empty() {
+        return order;
+    }
+
+    order.sort_by(|&a, &b| {
+        sort.iter()
+            .map(|key| {
+                let cmp = match (rows[a].get(key.column_ix), rows[b].get(key.column_ix)) {
+                    (Some(a), Some(b)) => a.cmp(b),
+                    (None, Some(_)) => Ordering::Greater,
+                    (Some(_), None) => Ordering::Less,
+                    (None, None) => Ordering::Equal,
+                };
+
+                match key.direction {
+                    SortDirection::Ascending => cmp,
+                    SortDirection::Descending => cmp.reverse(),
+                }
+            })
+            .find(|cmp| cmp.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Vec<Value>> {
+        vec![
+            vec![Value::Text("berlin".into()), Value::Int(2)],
+            vec![Value::Text("Bern".into()), Value::Int(1)],
+            vec![Value::Text("oslo".into()), Value::Null],
+            vec![Value::Text("bergen".into()), Value::Int(2)],
+        ]
+    }
+
+    #[test]
+    fn search_keeps_rows_with_a_matching_cell() {
+        assert_eq!(local_view_order(&rows(), "ber", &[]), vec![0, 1, 3]);
+        assert_eq!(local_view_order(&rows(), "", &[]), vec![0, 1, 2, 3]);
+        assert!(local_view_order(&rows(), "null", &[]).is_empty());
+    }
+
+    #[test]
+    fn sort_keys_apply_in_priority_order() {
+        let sort = [
+            LocalSortState {
+                column_ix: 1,
+                direction: SortDirection::Descending,
+            },
+            LocalSortState {
+                column_ix: 0,
+                direction: SortDirection::Ascending,
+            },
+        ];
+
+        assert_eq!(local_view_order(&rows(), "ber", &sort), vec![3, 0, 1]);
+    }
+}
@@ -0,0 +1,267 @@
+//! "Paste as New Rows": tabular clipboard text (TSV from a spreadsheet or a
+//! CSV file) turned into pending inserts. Nothing is written until the grid
+//! is saved, where the rows go through the change review as one batch of
+//! INSERTs.
+
+use super::{DataGridPanel, DataSource, PendingToast};
+use dbflux_components::components::data_table::clipboard::parse_tabular;
+use dbflux_components::components::data_table::model::CellValue;
+use dbflux_core::ColumnKind;
+use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
+use gpui::*;
+
+/// What pasting needs to know about a target column.
+#[derive(Debug, Clone)]
+pub(super) struct PasteColumn {
+    pub(super) name: String,
+    pub(super) type_name: String,
+    pub(super) kind: ColumnKind,
+    pub(super) default: Option<String>,
+}
+
+impl PasteColumn {
+    /// Value for a column the paste leaves empty: the column default when
+    /// there is one, NULL otherwise.
+    fn empty_value(&self) -> CellValue {
+        match &self.default {
+            Some(expr) => CellValue::auto_generated(expr),
+            None => CellValue::null(),
+        }
+    }
+
+    fn is_bool(&self) -> bool {
+        matches!(
+            self.type_name.to_ascii_lowercase().as_str(),
+            "bool" | "boolean"
+        )
+    }
+}
+
+/// Target column of each header field when every non-empty field of
+/// `header` names a column (ignoring case). `None` when the first row is
+/// data rather than a header.
+fn header_mapping(header: &[String], columns: &[PasteColumn]) -> Option<Vec<Option<usize>>> {
+    let mut any = false;
+
+    let mapping = header
+        .iter()
+        .map(|field| {
+            let field = field.trim();
+            if field.is_empty() {
+                return Some(None);
+            }
+            any = true;
+            columns
+                .iter()
+                .position(|column| column.name.eq_ignore_ascii_case(field))
+                .map(Some)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    any.then_some(mapping)
+}
+
+/// Cell for one pasted field, checked against the column type. Empty fields
+/// fall back to the column default or NULL.
+fn parse_field(text: &str, column: &PasteColumn) -> Result<CellValue, String> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Ok(column.empty_value());
+    }
+
+    if column.is_bool() {
+        return match trimmed.to_ascii_lowercase().as_str() {
+            "true" | "t" | "yes" | "1" => Ok(CellValue::bool(true)),
+            "false" | "f" | "no" | "0" => Ok(CellValue::bool(false)),
+            _ => Err(format!("'{trimmed}' is not a boolean")),
+        };
+    }
+
+    match column.kind {
+        ColumnKind::Integer => trimmed
+            .parse::<i64>()
+            .map(CellValue::int)
+            .map_err(|_| format!("'{trimmed}' is not an integer")),
+        // Kept as text so decimal columns keep their exact digits.
+        ColumnKind::Float => trimmed
+            .parse::<f64>()
+            .map(|_| CellValue::text(trimmed))
+            .map_err(|_| format!("'{trimmed}' is not a number")),
+        ColumnKind::Timestamp | ColumnKind::Text | ColumnKind::Unknown => Ok(CellValue::text(text)),
+    }
+}
+
+/// Full rows, one per pasted line, ready to become pending inserts. A first
+/// line naming columns is used as a header and maps fields by name;
+/// otherwise fields map by position. Columns the paste does not cover get
+/// their default or NULL.
+pub(super) fn rows_from_tabular(
+    lines: &[Vec<String>],
+    columns: &[PasteColumn],
+) -> Result<Vec<Vec<CellValue>>, String> {
+    let (mapping, data, first_line) = match lines.split_first() {
+        Some((header, data)) => match header_mapping(header, columns) {
+            Some(mapping) => (Some(mapping), data, 2),
+            None => (None, lines, 1),
+        },
+        None => return Ok(Vec::new()),
+    };
+
+    data.iter()
+        .enumerate()
+        .map(|(ix, fields)| {
+            let line = first_line + ix;
+            let mut row: Vec<CellValue> = columns.iter().map(PasteColumn::empty_value).collect();
+
+            for (field_ix, field) in fields.iter().enumerate() {
+                let target = match &mapping {
+                    Some(mapping) => mapping.get(field_ix).copied().flatten(),
+                    None => (field_ix < columns.len()).then_some(field_ix),
+                };
+
+                let Some(col) = target else {
+                    if field.trim().is_empty() {
+                        continue;
+                    }
+                    return Err(match mapping {
+                        Some(_) => format!("line {line} has a value under an empty header"),
+                        None => format!("line {line} has more fields than the table has columns"),
+                    });
+                };
+
+                row[col] = parse_field(field, &columns[col]).map_err(|error| {
+                    format!("line {line}, column {}: {error}", columns[col].name)
+                })?;
+            }
+
+            Ok(row)
+        })
+        .collect()
+}
+
+impl DataGridPanel {
+    /// Adds the clipboard's rows as pending inserts after the clicked row.
+    /// A value that does not fit its column rejects the whole paste.
+    pub(super) fn handle_paste_as_rows(&mut self, after_visual_row: usize, cx: &mut Context<Self>) {
+        if !matches!(self.source, DataSource::Table { .. }) {
+            return;
+        }
+        let Some(table_state) = self.grid_table.table_state.clone() else {
+            return;
+        };
+
+        let Some(text) = cx
+            .read_from_clipboard()
+            .and_then(|item| item.text().map(|s| s.to_string()))
+        else {
+            return;
+        };
+
+        let defaults = self.get_all_column_defaults(cx);
+        let columns: Vec<PasteColumn> = self
+            .result
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| PasteColumn {
+                name: column.name.clone(),
+                type_name: column.type_name.clone(),
+                kind: column.kind,
+                default: defaults.get(idx).cloned().flatten(),
+            })
+            .collect();
+
+        let rows = match rows_from_tabular(&parse_tabular(&text), &columns) {
+            Ok(rows) if !rows.is_empty() => rows,
+            Ok(_) => return,
+            Err(message) => {
+                report_error(
+                    UserFacingError::new(ErrorKind::User, format!("Cannot paste rows: {message}")),
+                    cx,
+                );
+                return;
+            }
+        };
+
+        let insert_after_base = self.insert_position(after_visual_row, cx);
+        let count = rows.len();
+
+        table_state.update(cx, |state, cx| {
+            let buffer = state.edit_buffer_mut();
+            buffer.set_base_row_count(self.result.rows.len());
+            for row in rows {
+                buffer.add_pending_insert_after(insert_after_base, row);
+            }
+            cx.notify();
+        });
+
+        self.pending.toast = Some(PendingToast {
+            message: format!("Pasted {} row{}", count, if count == 1 { "" } else { "s" }),
+            is_error: false,
+        });
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, type_name: &str, kind: ColumnKind) -> PasteColumn {
+        PasteColumn {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            kind,
+            default: None,
+        }
+    }
+
+    fn columns() -> Vec<PasteColumn> {
+        vec![
+            PasteColumn {
+                default: Some("nextval('users_id_seq')".to_string()),
+                ..column("id", "int4", ColumnKind::Integer)
+            },
+            column("name", "text", ColumnKind::Text),
+            column("active", "bool", ColumnKind::Unknown),
+        ]
+    }
+
+    fn lines(text: &[&[&str]]) -> Vec<Vec<String>> {
+        text.iter()
+            .map(|line| line.iter().map(|field| field.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn header_maps_fields_by_name() {
+        let rows =
+            rows_from_tabular(&lines(&[&["Active", "NAME"], &["yes", "Ada"]]), &columns()).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0][0].is_auto_generated());
+        assert_eq!(rows[0][1].edit_text(), "Ada");
+        assert_eq!(rows[0][2].edit_text(), "true");
+    }
+
+    #[test]
+    fn fields_map_by_position_without_header() {
+        let rows = rows_from_tabular(&lines(&[&["7", "Ada"], &["", "Grace"]]), &columns()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0][0].edit_text(), "7");
+        assert!(rows[0][2].is_null());
+        assert!(rows[1][0].is_auto_generated());
+    }
+
+    #[test]
+    fn invalid_values_name_line_and_column() {
+        let error =
+            rows_from_tabular(&lines(&[&["1", "Ada"], &["x", "Grace"]]), &columns()).unwrap_err();
+        assert_eq!(error, "line 2, column id: 'x' is not an integer");
+
+        let error =
+            rows_from_tabular(&lines(&[&["1", "Ada", "t", "extra"]]), &columns()).unwrap_err();
+        assert_eq!(error, "line 1 has more fields than the table has columns");
+    }
+}
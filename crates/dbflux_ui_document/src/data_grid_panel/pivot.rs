@@ -0,0 +1,949 @@
+//! "Pivot": a table-shaped result reshaped into a cross-tab. Row dimensions
+//! become the leading columns, each distinct value of the column dimension
+//! becomes a column, and every cell aggregates the value column over the
+//! rows it covers. The pivot is computed from the loaded rows; on a SQL table
+//! it can instead be pushed down as a GROUP BY over the whole table, leaving
+//! only the reshaping to the client.
+//!
+//! A transposed result (see [`super::transpose`]) is shown through the same
+//! view, so both share the way back to rows.
+
+use super::{DataGridPanel, DataSource, GridState, PendingToast};
+use dbflux_components::components::data_table::SortState as TableSortState;
+use dbflux_components::controls::Checkbox;
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{FontSizes, Radii, Spacing};
+use dbflux_core::{ColumnKind, ColumnMeta, QueryRequest, QueryResult, SqlDialect, TableRef, Value};
+use gpui::*;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariant, ButtonVariants};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Distinct column-dimension values a pivot may spread into columns.
+const MAX_PIVOT_COLUMNS: usize = 200;
+
+/// Groups fetched when the pivot is computed on the server.
+const MAX_PUSHDOWN_GROUPS: u32 = 10_000;
+
+/// Name of the aggregated column in the pushed-down GROUP BY.
+const PUSHDOWN_VALUE: &str = "pivot_value";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PivotAgg {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl PivotAgg {
+    const ALL: [PivotAgg; 5] = [Self::Count, Self::Sum, Self::Avg, Self::Min, Self::Max];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Count => "COUNT",
+            Self::Sum => "SUM",
+            Self::Avg => "AVG",
+            Self::Min => "MIN",
+            Self::Max => "MAX",
+        }
+    }
+}
+
+/// Dimensions and aggregation of a pivot, by column name so it survives a
+/// refresh of the underlying result.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct PivotConfig {
+    pub(super) rows: Vec<String>,
+    pub(super) column: Option<String>,
+    /// Aggregated column; `None` counts rows.
+    pub(super) value: Option<String>,
+    pub(super) agg: PivotAgg,
+    pub(super) push_down: bool,
+}
+
+impl PivotConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.agg != PivotAgg::Count && self.value.is_none() {
+            return Err(format!("{} needs a value column", self.agg.label()));
+        }
+        Ok(())
+    }
+
+    /// Header of the value column when there is no column dimension.
+    fn value_label(&self) -> String {
+        match &self.value {
+            Some(value) => format!("{}_{}", self.agg.label().to_lowercase(), value),
+            None => "count".to_string(),
+        }
+    }
+}
+
+/// How a [`PivotView`] reshapes the rows it was computed from.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Reshape {
+    Pivot(PivotConfig),
+    /// Columns become rows; see [`super::transpose`].
+    Transpose,
+}
+
+/// The active reshape and the rows it was computed from, restored when the
+/// grid goes back to rows.
+pub(super) struct PivotView {
+    pub(super) reshape: Reshape,
+    pub(super) rows: Arc<QueryResult>,
+}
+
+/// Dimension picker opened from the status bar.
+pub(super) struct PivotPrompt {
+    columns: Vec<String>,
+    rows: Vec<bool>,
+    column: Option<usize>,
+    value: Option<usize>,
+    agg: PivotAgg,
+    push_down: bool,
+    error: Option<String>,
+}
+
+fn numeric(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int(n) => Some(*n as f64),
+        Value::Float(f) => Some(*f),
+        Value::Decimal(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Running aggregate of one pivot cell.
+#[derive(Default)]
+struct Cell {
+    count: i64,
+    numbers: usize,
+    sum: f64,
+    /// Exact sum while every value is an integer and it has not overflowed.
+    int_sum: Option<i64>,
+    min: Option<Value>,
+    max: Option<Value>,
+}
+
+impl Cell {
+    fn add(&mut self, value: Option<&Value>) {
+        let Some(value) = value else {
+            self.count += 1;
+            return;
+        };
+        if value.is_null() {
+            return;
+        }
+
+        self.count += 1;
+        if let Some(n) = numeric(value) {
+            self.int_sum = match (self.numbers, value) {
+                (0, Value::Int(i)) => Some(*i),
+                (_, Value::Int(i)) => self.int_sum.and_then(|sum| sum.checked_add(*i)),
+                _ => None,
+            };
+            self.numbers += 1;
+            self.sum += n;
+        }
+        if self.min.as_ref().is_none_or(|min| value < min) {
+            self.min = Some(value.clone());
+        }
+        if self.max.as_ref().is_none_or(|max| value > max) {
+            self.max = Some(value.clone());
+        }
+    }
+
+    fn finish(self, agg: PivotAgg) -> Value {
+        match agg {
+            PivotAgg::Count => Value::Int(self.count),
+            PivotAgg::Sum if self.numbers == 0 => Value::Null,
+            PivotAgg::Sum => self
+                .int_sum
+                .map(Value::Int)
+                .unwrap_or(Value::Float(self.sum)),
+            PivotAgg::Avg if self.numbers == 0 => Value::Null,
+            PivotAgg::Avg => Value::Float(self.sum / self.numbers as f64),
+            PivotAgg::Min => self.min.unwrap_or(Value::Null),
+            PivotAgg::Max => self.max.unwrap_or(Value::Null),
+        }
+    }
+}
+
+fn position(result: &QueryResult, name: &str) -> Result<usize, String> {
+    result
+        .columns
+        .iter()
+        .position(|column| column.name == name)
+        .ok_or_else(|| format!("column {name} is not in the result"))
+}
+
+/// Cross-tab of `result` for `config`. Rows keep the order in which their
+/// dimension values first appear; value columns are sorted by their value.
+///
+/// `pre_aggregated` marks a result that already holds one aggregated value
+/// per cell (the pushed-down GROUP BY), which is then placed as is.
+pub(super) fn pivot_result(
+    result: &QueryResult,
+    config: &PivotConfig,
+    pre_aggregated: bool,
+) -> Result<QueryResult, String> {
+    config.validate()?;
+
+    let row_ixs = config
+        .rows
+        .iter()
+        .map(|name| position(result, name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let column_ix = config
+        .column
+        .as_deref()
+        .map(|name| position(result, name))
+        .transpose()?;
+    let value_ix = if pre_aggregated {
+        Some(position(result, PUSHDOWN_VALUE)?)
+    } else {
+        config
+            .value
+            .as_deref()
+            .map(|name| position(result, name))
+            .transpose()?
+    };
+
+    let mut row_keys: Vec<Vec<Value>> = Vec::new();
+    let mut row_lookup: BTreeMap<Vec<Value>, usize> = BTreeMap::new();
+    let mut column_keys: BTreeMap<Value, usize> = BTreeMap::new();
+    let mut cells: BTreeMap<(usize, Value), Cell> = BTreeMap::new();
+
+    for row in &result.rows {
+        let cell_value = |ix: usize| row.get(ix).cloned().unwrap_or(Value::Null);
+
+        let key: Vec<Value> = row_ixs.iter().map(|&ix| cell_value(ix)).collect();
+        let row_slot = *row_lookup.entry(key.clone()).or_insert_with(|| {
+            row_keys.push(key);
+            row_keys.len() - 1
+        });
+
+        let column_key = column_ix.map(cell_value).unwrap_or(Value::Null);
+        if !column_keys.contains_key(&column_key) {
+            if column_keys.len() == MAX_PIVOT_COLUMNS {
+                return Err(format!(
+                    "{} has more than {} distinct values",
+                    config.column.as_deref().unwrap_or_default(),
+                    MAX_PIVOT_COLUMNS
+                ));
+            }
+            column_keys.insert(column_key.clone(), 0);
+        }
+
+        cells
+            .entry((row_slot, column_key))
+            .or_default()
+            .add(value_ix.map(|ix| row.get(ix).unwrap_or(&Value::Null)));
+    }
+
+    // Pushed-down cells hold one finished value; MAX passes it through.
+    let agg = if pre_aggregated {
+        PivotAgg::Max
+    } else {
+        config.agg
+    };
+
+    let value_meta = value_ix.and_then(|ix| result.columns.get(ix));
+    let (kind, type_name) = match (config.agg, value_meta) {
+        (PivotAgg::Count, _) => (ColumnKind::Integer, "bigint".to_string()),
+        (PivotAgg::Avg, _) => (ColumnKind::Float, "double".to_string()),
+        (PivotAgg::Sum, Some(meta)) if meta.kind == ColumnKind::Integer => {
+            (ColumnKind::Integer, meta.type_name.clone())
+        }
+        (PivotAgg::Sum, _) => (ColumnKind::Float, "numeric".to_string()),
+        (PivotAgg::Min | PivotAgg::Max, Some(meta)) => (meta.kind, meta.type_name.clone()),
+        (PivotAgg::Min | PivotAgg::Max, None) => (ColumnKind::Unknown, String::new()),
+    };
+    let value_column = |name: String| ColumnMeta {
+        name,
+        type_name: type_name.clone(),
+        kind,
+        nullable: true,
+        is_primary_key: false,
+    };
+
+    let mut columns: Vec<ColumnMeta> = row_ixs
+        .iter()
+        .map(|&ix| ColumnMeta {
+            is_primary_key: false,
+            ..result.columns[ix].clone()
+        })
+        .collect();
+    match column_ix {
+        Some(_) => columns.extend(column_keys.keys().map(|key| {
+            value_column(match key {
+                Value::Null => "NULL".to_string(),
+                key => key.as_shown_string(),
+            })
+        })),
+        None => columns.push(value_column(config.value_label())),
+    }
+
+    let rows = row_keys
+        .into_iter()
+        .enumerate()
+        .map(|(row_slot, mut row)| {
+            for column_key in column_keys.keys() {
+                row.push(
+                    cells
+                        .remove(&(row_slot, column_key.clone()))
+                        .map(|cell| cell.finish(agg))
+                        .unwrap_or(Value::Null),
+                );
+            }
+            row
+        })
+        .collect();
+
+    Ok(QueryResult {
+        timing: result.timing,
+        ..QueryResult::table(columns, rows, None, result.execution_time)
+    })
+}
+
+/// GROUP BY computing every pivot cell on the server: the dimensions plus
+/// one aggregated `pivot_value` column, capped at [`MAX_PUSHDOWN_GROUPS`].
+pub(super) fn pivot_pushdown_query(
+    dialect: &dyn SqlDialect,
+    table: &TableRef,
+    filter: Option<&str>,
+    config: &PivotConfig,
+) -> String {
+    let dimensions: Vec<String> = config
+        .rows
+        .iter()
+        .chain(config.column.as_ref())
+        .map(|column| dialect.quote_identifier(column))
+        .collect();
+
+    let aggregate = match &config.value {
+        Some(value) => format!(
+            "{}({})",
+            config.agg.label(),
+            dialect.quote_identifier(value)
+        ),
+        None => "COUNT(*)".to_string(),
+    };
+
+    let select_list: Vec<String> = dimensions
+        .iter()
+        .cloned()
+        .chain(std::iter::once(format!(
+            "{aggregate} AS {}",
+            dialect.quote_identifier(PUSHDOWN_VALUE)
+        )))
+        .collect();
+
+    let mut sql = format!(
+        "SELECT {} FROM {}",
+        select_list.join(", "),
+        table.quoted_with(dialect)
+    );
+    if let Some(filter) = filter.map(str::trim).filter(|f| !f.is_empty()) {
+        sql.push_str(&format!(" WHERE {filter}"));
+    }
+    if !dimensions.is_empty() {
+        sql.push_str(&format!(
+            " GROUP BY {dims} ORDER BY {dims} {}",
+            dialect.limit_clause(MAX_PUSHDOWN_GROUPS),
+            dims = dimensions.join(", ")
+        ));
+    }
+    sql
+}
+
+impl DataGridPanel {
+    pub(super) fn is_pivot_active(&self) -> bool {
+        self.pivot.is_some()
+    }
+
+    pub(super) fn is_transposed(&self) -> bool {
+        self.pivot
+            .as_ref()
+            .is_some_and(|pivot| pivot.reshape == Reshape::Transpose)
+    }
+
+    /// Pivoting needs a table-shaped result with columns.
+    pub(super) fn can_pivot(&self) -> bool {
+        self.pivot.is_some() || (self.result.shape.is_table() && !self.result.columns.is_empty())
+    }
+
+    /// Pushing down needs a SQL table browsed with its raw filter.
+    fn can_push_down_pivot(&self, cx: &App) -> bool {
+        let DataSource::Table { profile_id, .. } = &self.source else {
+            return false;
+        };
+
+        self.builder.visual_select.is_none()
+            && !self.is_summary_active()
+            && self
+                .app_state
+                .read(cx)
+                .connections()
+                .get(profile_id)
+                .is_some_and(|connected| {
+                    connected.connection.metadata().query_language
+                        == dbflux_core::QueryLanguage::Sql
+                })
+    }
+
+    pub(super) fn open_pivot_prompt(&mut self, cx: &mut Context<Self>) {
+        let (columns, config) = match &self.pivot {
+            Some(pivot) => (
+                &pivot.rows.columns,
+                match &pivot.reshape {
+                    Reshape::Pivot(config) => Some(config),
+                    Reshape::Transpose => None,
+                },
+            ),
+            None => (&self.result.columns, None),
+        };
+        let columns: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+        if columns.is_empty() {
+            return;
+        }
+
+        let index_of =
+            |name: Option<&String>| name.and_then(|n| columns.iter().position(|c| c == n));
+
+        self.pivot_prompt = Some(PivotPrompt {
+            rows: columns
+                .iter()
+                .map(|c| config.is_some_and(|config| config.rows.contains(c)))
+                .collect(),
+            column: index_of(config.and_then(|c| c.column.as_ref())),
+            value: index_of(config.and_then(|c| c.value.as_ref())),
+            agg: config.map_or(PivotAgg::Count, |c| c.agg),
+            push_down: config.is_some_and(|c| c.push_down),
+            error: None,
+            columns,
+        });
+        cx.notify();
+    }
+
+    pub(super) fn cancel_pivot_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.pivot_prompt.take().is_some() {
+            self.focus_active_view(window, cx);
+            cx.notify();
+        }
+    }
+
+    fn apply_pivot_prompt(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let can_push_down = self.can_push_down_pivot(cx);
+        let Some(prompt) = self.pivot_prompt.as_mut() else {
+            return;
+        };
+
+        let name = |ix: Option<usize>| ix.and_then(|ix| prompt.columns.get(ix)).cloned();
+        let config = PivotConfig {
+            rows: prompt
+                .columns
+                .iter()
+                .zip(&prompt.rows)
+                .filter(|(_, checked)| **checked)
+                .map(|(column, _)| column.clone())
+                .collect(),
+            column: name(prompt.column),
+            value: name(prompt.value),
+            agg: prompt.agg,
+            push_down: prompt.push_down && can_push_down,
+        };
+
+        // A server pivot reports its errors when the query returns; a local
+        // one is checked against the loaded rows before the prompt closes.
+        let checked = if config.push_down {
+            config.validate()
+        } else {
+            let rows = self.pivot.as_ref().map_or(&self.result, |p| &p.rows);
+            pivot_result(rows, &config, false).map(|_| ())
+        };
+        if let Err(message) = checked {
+            prompt.error = Some(message);
+            cx.notify();
+            return;
+        }
+
+        let rows = match self.pivot.take() {
+            Some(pivot) => pivot.rows,
+            None => std::mem::replace(&mut self.result, Arc::new(QueryResult::empty())),
+        };
+
+        self.pivot_prompt = None;
+        self.pivot = Some(PivotView {
+            reshape: Reshape::Pivot(config),
+            rows: Arc::new(QueryResult::empty()),
+        });
+        self.result = self.pivot_incoming(rows, cx);
+        self.reset_local_view();
+        self.rebuild_table(Vec::new(), cx);
+        self.focus_active_view(window, cx);
+        cx.notify();
+    }
+
+    /// What the grid shows for a freshly loaded result: the result itself,
+    /// or its pivot or transposition while one is active. A pushed-down
+    /// pivot keeps showing the previous pivot until its query returns.
+    pub(super) fn pivot_incoming(
+        &mut self,
+        result: Arc<QueryResult>,
+        cx: &mut Context<Self>,
+    ) -> Arc<QueryResult> {
+        self.result_render_time = None;
+        self.result_received_at = Some(Instant::now());
+
+        let push_down = self.can_push_down_pivot(cx);
+        let Some(pivot) = self.pivot.as_mut() else {
+            return result;
+        };
+
+        let config = match &pivot.reshape {
+            Reshape::Pivot(config) => config,
+            Reshape::Transpose => {
+                let transposed = super::transpose::transpose_result(&result);
+                pivot.rows = result;
+                return Arc::new(transposed);
+            }
+        };
+
+        if config.push_down && push_down {
+            pivot.rows = result;
+            self.run_pivot_pushdown(cx);
+            return std::mem::replace(&mut self.result, Arc::new(QueryResult::empty()));
+        }
+
+        match pivot_result(&result, config, false) {
+            Ok(pivoted) => {
+                pivot.rows = result;
+                Arc::new(pivoted)
+            }
+            Err(message) => {
+                self.pivot = None;
+                self.pending.toast = Some(PendingToast {
+                    message: format!("Pivot closed: {message}"),
+                    is_error: true,
+                });
+                result
+            }
+        }
+    }
+
+    /// Keeps the pivot current when more rows are fetched into the result
+    /// it was computed from.
+    pub(super) fn append_pivot_rows(
+        &mut self,
+        rows: Vec<dbflux_core::Row>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(pivot) = self.pivot.as_mut() else {
+            return;
+        };
+        Arc::make_mut(&mut pivot.rows).rows.extend(rows);
+
+        let rows = std::mem::replace(&mut pivot.rows, Arc::new(QueryResult::empty()));
+        self.result = self.pivot_incoming(rows, cx);
+        self.grid_table.base_rows = None;
+        self.grid_table.original_row_order = None;
+        self.apply_local_view(cx);
+    }
+
+    fn run_pivot_pushdown(&mut self, cx: &mut Context<Self>) {
+        let (
+            Some(PivotView {
+                reshape: Reshape::Pivot(config),
+                ..
+            }),
+            DataSource::Table {
+                profile_id,
+                database,
+                table,
+                ..
+            },
+        ) = (self.pivot.as_ref(), &self.source)
+        else {
+            return;
+        };
+
+        let filter = self.filter_bar.filter_input.read(cx).value().to_string();
+        let config = config.clone();
+
+        let (conn, sql) = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(profile_id) else {
+                return;
+            };
+            let Ok(conn) = connected.resolve_connection_for_execution(database.as_deref()) else {
+                return;
+            };
+            let sql = pivot_pushdown_query(
+                connected.connection.dialect(),
+                table,
+                Some(&filter),
+                &config,
+            );
+            (conn, sql)
+        };
+
+        let mut request = QueryRequest::new(sql);
+        request.database = database.clone();
+
+        self.refresh.state = GridState::Loading;
+        cx.notify();
+
+        let task = cx
+            .background_executor()
+            .spawn(async move { conn.execute(&request) });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+
+            if let Err(e) = this.update(cx, |panel, cx| {
+                panel.refresh.state = GridState::Ready;
+
+                // The pivot was closed or changed while the query ran.
+                if panel.pivot.as_ref().map(|p| &p.reshape) != Some(&Reshape::Pivot(config.clone()))
+                {
+                    cx.notify();
+                    return;
+                }
+
+                let pivoted = result.map_err(|e| e.to_string()).and_then(|grouped| {
+                    let truncated = grouped.rows.len() as u32 >= MAX_PUSHDOWN_GROUPS;
+                    pivot_result(&grouped, &config, true).map(|p| (p, truncated))
+                });
+
+                match pivoted {
+                    Ok((pivoted, truncated)) => {
+                        if truncated {
+                            panel.pending.toast = Some(PendingToast {
+                                message: format!(
+                                    "Pivot limited to the first {} groups",
+                                    MAX_PUSHDOWN_GROUPS
+                                ),
+                                is_error: false,
+                            });
+                        }
+                        panel.result = Arc::new(pivoted);
+                        panel.reset_local_view();
+                        panel.rebuild_table(Vec::new(), cx);
+                    }
+                    Err(message) => {
+                        panel.pending.toast = Some(PendingToast {
+                            message: format!("Pivot failed: {message}"),
+                            is_error: true,
+                        });
+                        panel.show_pivot_rows(cx);
+                    }
+                }
+                cx.notify();
+            }) {
+                log::warn!("Failed to apply pivot result: {:?}", e);
+            }
+        })
+        .detach();
+    }
+
+    /// Back to the rows the pivot was computed from.
+    pub(super) fn show_pivot_rows(&mut self, cx: &mut Context<Self>) {
+        let Some(pivot) = self.pivot.take() else {
+            return;
+        };
+
+        self.result = pivot.rows;
+        self.reset_local_view();
+
+        let initial_sort = match &self.source {
+            DataSource::Table { order_by, .. } => order_by
+                .iter()
+                .filter_map(|col| {
+                    self.result
+                        .columns
+                        .iter()
+                        .position(|c| c.name == col.column.name)
+                        .map(|ix| TableSortState::new(ix, col.direction))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.rebuild_table(initial_sort, cx);
+        cx.notify();
+    }
+
+    pub(super) fn render_pivot_prompt(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let Some(prompt) = self.pivot_prompt.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let chip = |id: (&'static str, usize), label: &'static str, active: bool| {
+            div()
+                .id(id)
+                .px(Spacing::XS)
+                .rounded(Radii::SM)
+                .text_size(FontSizes::XS)
+                .cursor_pointer()
+                .when(active, |d| {
+                    d.bg(theme.accent.opacity(0.15))
+                        .text_color(theme.foreground)
+                })
+                .when(!active, |d| {
+                    d.text_color(theme.muted_foreground)
+                        .hover(|d| d.bg(theme.secondary))
+                })
+                .child(label)
+        };
+
+        let column_rows = prompt.columns.iter().enumerate().map(|(ix, column)| {
+            div()
+                .flex()
+                .items_center()
+                .gap(Spacing::XS)
+                .child(
+                    div()
+                        .flex_1()
+                        .overflow_hidden()
+                        .child(Text::caption(column.clone())),
+                )
+                .child(
+                    chip(("pivot-row", ix), "Row", prompt.rows[ix]).on_click(cx.listener(
+                        move |this, _, _, cx| {
+                            if let Some(prompt) = this.pivot_prompt.as_mut() {
+                                prompt.rows[ix] = !prompt.rows[ix];
+                                if prompt.rows[ix] && prompt.column == Some(ix) {
+                                    prompt.column = None;
+                                }
+                                cx.notify();
+                            }
+                        },
+                    )),
+                )
+                .child(
+                    chip(("pivot-column", ix), "Column", prompt.column == Some(ix)).on_click(
+                        cx.listener(move |this, _, _, cx| {
+                            if let Some(prompt) = this.pivot_prompt.as_mut() {
+                                prompt.column = (prompt.column != Some(ix)).then_some(ix);
+                                prompt.rows[ix] = false;
+                                cx.notify();
+                            }
+                        }),
+                    ),
+                )
+                .child(
+                    chip(("pivot-value", ix), "Value", prompt.value == Some(ix)).on_click(
+                        cx.listener(move |this, _, _, cx| {
+                            if let Some(prompt) = this.pivot_prompt.as_mut() {
+                                prompt.value = (prompt.value != Some(ix)).then_some(ix);
+                                cx.notify();
+                            }
+                        }),
+                    ),
+                )
+        });
+
+        let agg_chips = PivotAgg::ALL.iter().enumerate().map(|(ix, &agg)| {
+            chip(("pivot-agg", ix), agg.label(), prompt.agg == agg).on_click(cx.listener(
+                move |this, _, _, cx| {
+                    if let Some(prompt) = this.pivot_prompt.as_mut() {
+                        prompt.agg = agg;
+                        cx.notify();
+                    }
+                },
+            ))
+        });
+
+        div()
+            .id("pivot-overlay")
+            .absolute()
+            .inset_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(theme.background.opacity(0.6))
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .bg(theme.secondary)
+                    .border_1()
+                    .border_color(theme.border)
+                    .p(Spacing::LG)
+                    .w(px(360.0))
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::SM)
+                    .child(Text::label("Pivot"))
+                    .child(
+                        div()
+                            .id("pivot-columns")
+                            .max_h(px(240.0))
+                            .overflow_y_scroll()
+                            .flex()
+                            .flex_col()
+                            .gap(Spacing::XS)
+                            .children(column_rows),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap(Spacing::XS)
+                            .child(Text::caption("Aggregate"))
+                            .children(agg_chips),
+                    )
+                    .when(self.can_push_down_pivot(cx), |d| {
+                        d.child(
+                            Checkbox::new("pivot-push-down")
+                                .checked(prompt.push_down)
+                                .label("Compute on the server (whole table)")
+                                .on_click(cx.listener(|this, &checked, _, cx| {
+                                    if let Some(prompt) = this.pivot_prompt.as_mut() {
+                                        prompt.push_down = checked;
+                                        cx.notify();
+                                    }
+                                })),
+                        )
+                    })
+                    .when_some(prompt.error.clone(), |d, error| {
+                        d.child(Text::caption(error).danger())
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .gap(Spacing::XXS)
+                            .justify_end()
+                            .child(
+                                Button::new("cancel-pivot")
+                                    .label("Cancel")
+                                    .small()
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.cancel_pivot_prompt(window, cx);
+                                    })),
+                            )
+                            .child(
+                                Button::new("apply-pivot")
+                                    .label("Pivot")
+                                    .small()
+                                    .with_variant(ButtonVariant::Primary)
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.apply_pivot_prompt(window, cx);
+                                    })),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbflux_core::DefaultSqlDialect;
+    use std::time::Duration;
+
+    fn column(name: &str, kind: ColumnKind) -> ColumnMeta {
+        ColumnMeta {
+            name: name.to_string(),
+            type_name: String::new(),
+            kind,
+            nullable: true,
+            is_primary_key: false,
+        }
+    }
+
+    fn sales() -> QueryResult {
+        let row = |region: &str, year: i64, amount: i64| {
+            vec![
+                Value::Text(region.to_string()),
+                Value::Int(year),
+                Value::Int(amount),
+            ]
+        };
+        QueryResult::table(
+            vec![
+                column("region", ColumnKind::Text),
+                column("year", ColumnKind::Integer),
+                column("amount", ColumnKind::Integer),
+            ],
+            vec![
+                row("north", 2024, 10),
+                row("south", 2023, 5),
+                row("north", 2023, 7),
+                row("north", 2024, 3),
+            ],
+            None,
+            Duration::ZERO,
+        )
+    }
+
+    fn config(agg: PivotAgg, value: Option<&str>) -> PivotConfig {
+        PivotConfig {
+            rows: vec!["region".to_string()],
+            column: Some("year".to_string()),
+            value: value.map(str::to_string),
+            agg,
+            push_down: false,
+        }
+    }
+
+    #[test]
+    fn pivot_spreads_column_values_and_aggregates_cells() {
+        let pivoted =
+            pivot_result(&sales(), &config(PivotAgg::Sum, Some("amount")), false).unwrap();
+
+        let names: Vec<&str> = pivoted.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["region", "2023", "2024"]);
+        assert_eq!(pivoted.columns[1].kind, ColumnKind::Integer);
+        assert_eq!(
+            pivoted.rows,
+            vec![
+                vec![Value::Text("north".into()), Value::Int(7), Value::Int(13)],
+                vec![Value::Text("south".into()), Value::Int(5), Value::Null],
+            ]
+        );
+    }
+
+    #[test]
+    fn pivot_without_column_dimension_has_one_value_column() {
+        let totals = PivotConfig {
+            column: None,
+            ..config(PivotAgg::Count, None)
+        };
+        let pivoted = pivot_result(&sales(), &totals, false).unwrap();
+
+        assert_eq!(pivoted.columns[1].name, "count");
+        assert_eq!(
+            pivoted.rows[0],
+            vec![Value::Text("north".into()), Value::Int(3)]
+        );
+        assert!(pivot_result(&sales(), &config(PivotAgg::Avg, None), false).is_err());
+    }
+
+    #[test]
+    fn pushdown_groups_by_every_dimension() {
+        let sql = pivot_pushdown_query(
+            &DefaultSqlDialect,
+            &TableRef::new("sales"),
+            Some("amount > 0"),
+            &config(PivotAgg::Sum, Some("amount")),
+        );
+
+        assert_eq!(
+            sql,
+            "SELECT \"region\", \"year\", SUM(\"amount\") AS \"pivot_value\" FROM \"sales\" \
+             WHERE amount > 0 GROUP BY \"region\", \"year\" ORDER BY \"region\", \"year\" LIMIT 10000"
+        );
+    }
+}
@@ -13,6 +13,7 @@ use dbflux_core::{
 use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use gpui::*;
 use log::info;
+use std::sync::Arc;
 use uuid::Uuid;
 
 impl DataGridPanel {
@@ -112,6 +113,13 @@ impl DataGridPanel {
             Err(_) => pagination,
         };
 
+        if self.is_summary_active() {
+            self.run_summary_query(
+                profile_id, database, table, pagination, order_by, total_rows, filter, cx,
+            );
+            return;
+        }
+
         // --- Relational filter gate (FR-GATE-1 to FR-GATE-3) ---
         //
         // Only attempt FK resolution when: the input has an unquoted `.`,
@@ -230,9 +238,11 @@ impl DataGridPanel {
         let pagination_for_spawn = pagination.clone();
         let order_by_for_spawn = order_by.clone();
 
-        let task = cx
-            .background_executor()
-            .spawn(async move { conn.browse_table(&browse_request) });
+        let task = cx.background_executor().spawn(async move {
+            let sent_at = std::time::Instant::now();
+            conn.browse_table(&browse_request)
+                .map(|result| result.with_round_trip(sent_at.elapsed()))
+        });
 
         cx.spawn(async move |_this, cx| {
             let result = task.await;
@@ -367,9 +377,11 @@ impl DataGridPanel {
 
         let committed_spec: Option<VisualQuerySpec> = self.builder.builder_draft_spec.clone();
 
-        let task = cx
-            .background_executor()
-            .spawn(async move { conn.execute(&request) });
+        let task = cx.background_executor().spawn(async move {
+            let sent_at = std::time::Instant::now();
+            conn.execute(&request)
+                .map(|result| result.with_round_trip(sent_at.elapsed()))
+        });
 
         cx.spawn(async move |_this, cx| {
             let result = task.await;
@@ -398,8 +410,8 @@ impl DataGridPanel {
                         entity.update(cx, |panel, cx| {
                             panel.runner.complete_primary(task_id, cx);
                             panel.builder.current_visual_spec = committed_spec.clone();
-                            panel.result = query_result;
                             panel.refresh.state = GridState::Ready;
+                            panel.result = panel.pivot_incoming(Arc::new(query_result), cx);
 
                             let binding = panel.compute_builder_binding(
                                 committed_spec.as_ref(),
@@ -544,9 +556,11 @@ impl DataGridPanel {
         let collection_for_spawn = collection.clone();
         let pagination_for_spawn = pagination.clone();
 
-        let task = cx
-            .background_executor()
-            .spawn(async move { conn.browse_collection(&browse_request) });
+        let task = cx.background_executor().spawn(async move {
+            let sent_at = std::time::Instant::now();
+            conn.browse_collection(&browse_request)
+                .map(|result| result.with_round_trip(sent_at.elapsed()))
+        });
 
         cx.spawn(async move |_this, cx| {
             let result = task.await;
@@ -631,11 +645,10 @@ impl DataGridPanel {
             total_docs: total_docs.or(existing_total),
         };
 
-        self.result = result;
-        self.grid_table.local_sort_state = None;
-        self.grid_table.original_row_order = None;
-        self.rebuild_table(None, cx);
         self.refresh.state = GridState::Ready;
+        self.result = self.pivot_incoming(Arc::new(result), cx);
+        self.reset_local_view();
+        self.rebuild_table(Vec::new(), cx);
         cx.notify();
     }
 
@@ -650,14 +663,19 @@ impl DataGridPanel {
         result: QueryResult,
         cx: &mut Context<Self>,
     ) {
-        // Determine sort state from order_by for visual indicator
-        let initial_sort = order_by.first().and_then(|col| {
-            let pos = result
-                .columns
-                .iter()
-                .position(|c| c.name == col.column.name);
-            pos.map(|column_ix| TableSortState::new(column_ix, col.direction))
-        });
+        // Determine sort state from order_by for visual indicator. A pivot
+        // has its own columns and sorts locally.
+        let initial_sort = order_by
+            .iter()
+            .filter(|_| self.pivot.is_none())
+            .filter_map(|col| {
+                let pos = result
+                    .columns
+                    .iter()
+                    .position(|c| c.name == col.column.name);
+                pos.map(|column_ix| TableSortState::new(column_ix, col.direction))
+            })
+            .collect();
 
         // Preserve existing total_rows and database if not provided
         let (existing_total, existing_database) = match &self.source {
@@ -678,11 +696,10 @@ impl DataGridPanel {
             total_rows: total_rows.or(existing_total),
         };
 
-        self.result = result;
-        self.grid_table.local_sort_state = None;
-        self.grid_table.original_row_order = None;
-        self.rebuild_table(initial_sort, cx);
         self.refresh.state = GridState::Ready;
+        self.result = self.pivot_incoming(Arc::new(result), cx);
+        self.reset_local_view();
+        self.rebuild_table(initial_sort, cx);
         cx.notify();
     }
 
@@ -1015,15 +1032,26 @@ impl DataGridPanel {
             (conn, count_query)
         };
 
-        let table_name = spec.source.table.clone();
-        let entity = cx.entity().clone();
-
         let mut request = dbflux_core::QueryRequest::new(count_query.sql.clone());
         request.params = count_query.params.clone();
         if let Some(ref db) = database {
             request.database = Some(db.clone());
         }
 
+        self.spawn_grouped_count(conn, request, spec.source.table.clone(), cx);
+    }
+
+    /// Runs a `SELECT COUNT(*)` over a grouped query and stores the group
+    /// count as the total for `source_qualified`.
+    pub(super) fn spawn_grouped_count(
+        &mut self,
+        conn: std::sync::Arc<dyn dbflux_core::Connection>,
+        request: QueryRequest,
+        source_qualified: String,
+        cx: &mut Context<Self>,
+    ) {
+        let entity = cx.entity().clone();
+
         let task = cx
             .background_executor()
             .spawn(async move { conn.execute(&request) });
@@ -1046,7 +1074,7 @@ impl DataGridPanel {
                     if let Some(total) = count_opt {
                         entity.update(cx, |panel, cx| {
                             panel.pending.total_count = Some(PendingTotalCount {
-                                source_qualified: table_name,
+                                source_qualified,
                                 total,
                             });
                             cx.notify();
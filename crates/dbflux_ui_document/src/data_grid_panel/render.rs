@@ -21,11 +21,13 @@ use dbflux_components::icons::AppIcon;
 use dbflux_components::primitives::{BannerBlock, BannerVariant, Icon, Text, surface_raised};
 use dbflux_components::semantic::ChartColors;
 use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
-use dbflux_core::{ColumnKind, Pagination, QueryResultShape, SortDirection, Value};
+use dbflux_core::{ColumnKind, Pagination, QueryResultShape, QueryTiming, SortDirection, Value};
 use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use gpui::prelude::*;
 use gpui::*;
 use gpui_component::ActiveTheme;
+use gpui_component::tooltip::Tooltip;
+use std::time::Duration;
 
 /// Snapshot of derived render state computed once per frame from `&self`.
 ///
@@ -36,6 +38,7 @@ struct RenderState {
     row_count: usize,
     exec_time: String,
     show_data_toolbar: bool,
+    show_search_bar: bool,
     is_paginated: bool,
     source_name: String,
     source_query_prefix: &'static str,
@@ -107,6 +110,13 @@ impl Render for DataGridPanel {
         self.process_pending_actions(window, cx);
         let st = self.derive_render_state(cx);
 
+        if let Some(received_at) = self.result_received_at.take() {
+            cx.on_next_frame(window, move |this, _, cx| {
+                this.result_render_time = Some(received_at.elapsed());
+                cx.notify();
+            });
+        }
+
         div()
             .track_focus(&st.focus_handle)
             .flex()
@@ -127,6 +137,9 @@ impl Render for DataGridPanel {
                     cx,
                 ))
             })
+            .when(st.show_search_bar, |d| {
+                d.child(self.render_search_bar(&st.theme, cx))
+            })
             .child(self.render_warning_banners(&st))
             .when(st.show_edit_toolbar, |d| {
                 d.child(self.render_edit_toolbar(
@@ -163,9 +176,27 @@ impl Render for DataGridPanel {
             .when(self.pending_delete_confirm.is_some(), |d| {
                 d.child(self.render_delete_confirm_modal(&st.theme, cx))
             })
+            .when(self.pending_commit.is_some(), |d| {
+                d.child(self.render_commit_review_modal(&st.theme, cx))
+            })
+            .when(self.set_value_prompt.is_some(), |d| {
+                d.child(self.render_set_value_prompt(&st.theme, cx))
+            })
+            .when(self.column_stats.is_some(), |d| {
+                d.child(self.render_column_stats(&st.theme, cx))
+            })
+            .when(self.summary_prompt.is_some(), |d| {
+                d.child(self.render_summary_prompt(&st.theme, cx))
+            })
+            .when(self.pivot_prompt.is_some(), |d| {
+                d.child(self.render_pivot_prompt(&st.theme, cx))
+            })
             .when(self.document_view.cell_editor.read(cx).is_visible(), |d| {
                 d.child(self.document_view.cell_editor.clone())
             })
+            .when(self.document_view.cell_viewer.read(cx).is_visible(), |d| {
+                d.child(self.document_view.cell_viewer.clone())
+            })
             .when(
                 self.document_view
                     .document_preview_modal
@@ -217,8 +248,10 @@ impl DataGridPanel {
         if std::mem::take(&mut self.pending.rebuild) {
             let sort = self
                 .grid_table
-                .local_sort_state
-                .map(|s| TableSortState::new(s.column_ix, s.direction));
+                .local_sort
+                .iter()
+                .map(|s| TableSortState::new(s.column_ix, s.direction))
+                .collect();
             self.rebuild_table(sort, cx);
         }
 
@@ -226,6 +259,8 @@ impl DataGridPanel {
             self.refresh(window, cx);
         }
 
+        self.process_deferred_load(window, cx);
+
         if self.context_menu.is_none() {
             self.pending.context_menu_focus = false;
         } else if std::mem::take(&mut self.pending.context_menu_focus) {
@@ -238,6 +273,17 @@ impl DataGridPanel {
             });
         }
 
+        if let Some(view) = self.pending.cell_view.take() {
+            self.document_view.cell_viewer.update(cx, |viewer, cx| {
+                match (view.geometry, view.bytes) {
+                    _ if view.is_null => viewer.open_null(view.column, window, cx),
+                    (Some(geometry), _) => viewer.open_geometry(view.column, geometry, window, cx),
+                    (None, Some(bytes)) => viewer.open_binary(view.column, bytes, window, cx),
+                    (None, None) => viewer.open(view.column, view.value, view.is_json, window, cx),
+                }
+            });
+        }
+
         if let Some(preview) = self.pending.document_preview.take() {
             self.document_view
                 .document_preview_modal
@@ -323,6 +369,8 @@ impl DataGridPanel {
         let content_mode =
             content_mode_for_result(uses_result_view, view_mode, has_columns, has_data);
         let shows_table_content = matches!(content_mode, DataGridContentMode::Table);
+        let show_search_bar =
+            shows_table_content && matches!(self.source, DataSource::QueryResult { .. });
         let shows_content_controls = has_data || shows_table_content;
 
         let (is_editable, has_pending_changes, dirty_count, can_undo, can_redo) = self
@@ -370,6 +418,7 @@ impl DataGridPanel {
             row_count,
             exec_time,
             show_data_toolbar,
+            show_search_bar,
             is_paginated,
             source_name,
             source_query_prefix,
@@ -443,8 +492,12 @@ impl DataGridPanel {
                                 .color(st.theme.muted_foreground),
                         )
                         .child(
-                            Text::caption("Aggregated results cannot be edited")
-                                .color(st.theme.muted_foreground),
+                            Text::caption(if self.is_transposed() {
+                                "Transposed results cannot be edited"
+                            } else {
+                                "Aggregated results cannot be edited"
+                            })
+                            .color(st.theme.muted_foreground),
                         ),
                 )
             })
@@ -678,13 +731,12 @@ pub(super) fn render_filter_bar_as_segment(
 
     let theme = cx.theme().clone();
 
-    let refresh_label = if refresh_policy.is_auto() {
-        refresh_policy.label()
-    } else {
-        "Refresh"
-    };
+    let refresh_label = g.refresh.countdown.label(refresh_policy);
+    let refresh_extras = render_refresh_extras(g, grid.clone(), &theme);
 
     let can_open_builder = g.can_open_builder(cx);
+    let can_summarize = g.can_summarize(cx);
+    let is_summary_active = g.is_summary_active();
     let relational_filter_state = g.builder.relational_filter_state.clone();
     let has_filter_error = filter_input_has_error(&relational_filter_state);
 
@@ -693,6 +745,8 @@ pub(super) fn render_filter_bar_as_segment(
     let grid_for_clear = grid.clone();
     let grid_for_refresh = grid.clone();
     let grid_for_builder = grid.clone();
+    let grid_for_summary = grid.clone();
+    let grid_for_rows = grid.clone();
     let grid_for_chip = grid.clone();
     let grid_for_error = grid.clone();
 
@@ -916,6 +970,58 @@ pub(super) fn render_filter_bar_as_segment(
                 )
             }
         })
+        .when(can_summarize, {
+            let theme_btn = theme.clone();
+            let icon_color = theme.muted_foreground;
+            move |d| {
+                d.child(
+                    div()
+                        .id("summarize-btn")
+                        .h(Heights::ROW_COMPACT)
+                        .px(Spacing::SM)
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::XS)
+                        .rounded(Radii::SM)
+                        .text_color(theme_btn.muted_foreground)
+                        .cursor_pointer()
+                        .hover(move |d| d.bg(theme_btn.secondary).text_color(theme_btn.foreground))
+                        .on_click(move |_, window, cx| {
+                            grid_for_summary.update(cx, |this, cx| {
+                                this.open_summary_prompt(window, cx);
+                            });
+                        })
+                        .child(Icon::new(AppIcon::Sigma).small().color(icon_color))
+                        .child(Text::muted("Summarize")),
+                )
+            }
+        })
+        .when(can_summarize && is_summary_active, {
+            let theme_btn = theme.clone();
+            let icon_color = theme.muted_foreground;
+            move |d| {
+                d.child(
+                    div()
+                        .id("summary-rows-btn")
+                        .h(Heights::ROW_COMPACT)
+                        .px(Spacing::SM)
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::XS)
+                        .rounded(Radii::SM)
+                        .text_color(theme_btn.muted_foreground)
+                        .cursor_pointer()
+                        .hover(move |d| d.bg(theme_btn.secondary).text_color(theme_btn.foreground))
+                        .on_click(move |_, window, cx| {
+                            grid_for_rows.update(cx, |this, cx| {
+                                this.show_summary_rows(window, cx);
+                            });
+                        })
+                        .child(Icon::new(AppIcon::Rows3).small().color(icon_color))
+                        .child(Text::muted("Rows")),
+                )
+            }
+        })
         .child(
             div()
                 .id("refresh-action-btn")
@@ -970,10 +1076,125 @@ pub(super) fn render_filter_bar_as_segment(
                 .child(div().w(px(1.0)).h_full().bg(theme.input)) // guardrail-allow: vertical separator div, not a border-width token
                 .child(div().w(px(28.0)).h_full().child(refresh_dropdown)), // guardrail-allow: dropdown control width, not a height token
         )
+        .child(refresh_extras)
         .into_any()
 }
 
+/// Pause/resume button for a running auto-refresh countdown and the custom
+/// interval input, shown after the refresh split button.
+fn render_refresh_extras(
+    g: &DataGridPanel,
+    grid: Entity<DataGridPanel>,
+    theme: &gpui_component::theme::Theme,
+) -> AnyElement {
+    let running = g.refresh.countdown.is_running();
+    let paused = g.refresh.countdown.is_paused();
+    let custom_input = g
+        .refresh
+        .custom_input
+        .as_ref()
+        .map(|(input, _)| input.clone());
+    let secondary = theme.secondary;
+    let icon_color = theme.muted_foreground;
+
+    div()
+        .flex()
+        .items_center()
+        .gap(Spacing::XS)
+        .when(running, |d| {
+            d.child(
+                div()
+                    .id("refresh-pause-btn")
+                    .h(Heights::ROW_COMPACT)
+                    .px(Spacing::XS)
+                    .flex()
+                    .items_center()
+                    .rounded(Radii::SM)
+                    .cursor_pointer()
+                    .hover(move |d| d.bg(secondary))
+                    .tooltip(move |window, cx| {
+                        Tooltip::new(if paused {
+                            "Resume auto-refresh"
+                        } else {
+                            "Pause auto-refresh"
+                        })
+                        .build(window, cx)
+                    })
+                    .on_click(move |_, _, cx| {
+                        grid.update(cx, |this, cx| this.toggle_refresh_paused(cx));
+                    })
+                    .child(
+                        Icon::new(if paused {
+                            AppIcon::Play
+                        } else {
+                            AppIcon::Pause
+                        })
+                        .small()
+                        .color(icon_color),
+                    ),
+            )
+        })
+        .when_some(custom_input, |d, input| {
+            d.child(
+                div()
+                    .w(px(96.0)) // guardrail-allow: fits a short interval such as "90s"
+                    .child(Input::new(&input).small()),
+            )
+        })
+        .into_any_element()
+}
+
 impl DataGridPanel {
+    /// Search row for query results. Narrows and highlights the rows already
+    /// loaded; the query is not re-run.
+    fn render_search_bar(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let search_input = &self.filter_bar.search_input;
+        let searching = !self.grid_table.local_search.is_empty();
+        let shown = self.result.rows.len();
+        let total = self
+            .grid_table
+            .base_rows
+            .as_ref()
+            .map_or(shown, |rows| rows.len());
+
+        compact_top_bar(theme, std::iter::empty::<AnyElement>())
+            .child(
+                Icon::new(AppIcon::Search)
+                    .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                    .color(theme.muted_foreground),
+            )
+            .child(div().flex_1().child(Input::new(search_input).small()))
+            .when(searching, |d| {
+                d.child(Text::caption(format!("{} of {} rows", shown, total)).muted_foreground())
+                    .child(
+                        div()
+                            .id("clear-result-search")
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .size(Heights::ICON_SM)
+                            .rounded(Radii::SM)
+                            .cursor_pointer()
+                            .hover(|d| d.bg(theme.secondary_hover))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.filter_bar.search_input.update(cx, |input, cx| {
+                                    input.set_value("", window, cx);
+                                });
+                                this.set_local_search("", cx);
+                            }))
+                            .child(
+                                Icon::new(AppIcon::X)
+                                    .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                                    .color(theme.muted_foreground),
+                            ),
+                    )
+            })
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(super) fn render_toolbar(
         &self,
@@ -988,11 +1209,8 @@ impl DataGridPanel {
         theme: &gpui_component::theme::Theme,
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
-        let refresh_label = if self.refresh.refresh_policy.is_auto() {
-            self.refresh.refresh_policy.label()
-        } else {
-            "Refresh"
-        };
+        let refresh_label = self.refresh.countdown.label(self.refresh.refresh_policy);
+        let refresh_extras = render_refresh_extras(self, cx.entity(), theme);
 
         let toolbar_has_filter_error =
             filter_input_has_error(&self.builder.relational_filter_state);
@@ -1194,6 +1412,54 @@ impl DataGridPanel {
                         .child(Text::muted("Builder")),
                 )
             })
+            .when(self.can_summarize(cx), |d| {
+                d.child(
+                    div()
+                        .id("summarize-btn")
+                        .h_full()
+                        .px(Spacing::SM)
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::XS)
+                        .rounded(Radii::SM)
+                        .text_color(theme.muted_foreground)
+                        .cursor_pointer()
+                        .hover(|d| d.bg(theme.secondary).text_color(theme.foreground))
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.open_summary_prompt(window, cx);
+                        }))
+                        .child(
+                            Icon::new(AppIcon::Sigma)
+                                .small()
+                                .color(theme.muted_foreground),
+                        )
+                        .child(Text::muted("Summarize")),
+                )
+                .when(self.is_summary_active(), |d| {
+                    d.child(
+                        div()
+                            .id("summary-rows-btn")
+                            .h_full()
+                            .px(Spacing::SM)
+                            .flex()
+                            .items_center()
+                            .gap(Spacing::XS)
+                            .rounded(Radii::SM)
+                            .text_color(theme.muted_foreground)
+                            .cursor_pointer()
+                            .hover(|d| d.bg(theme.secondary).text_color(theme.foreground))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.show_summary_rows(window, cx);
+                            }))
+                            .child(
+                                Icon::new(AppIcon::Rows3)
+                                    .small()
+                                    .color(theme.muted_foreground),
+                            )
+                            .child(Text::muted("Rows")),
+                    )
+                })
+            })
             .child(
                 div()
                     .id("refresh-action-btn")
@@ -1251,6 +1517,7 @@ impl DataGridPanel {
                             .child(self.filter_bar.refresh_dropdown.clone()),
                     ),
             )
+            .child(refresh_extras)
     }
 
     pub(super) fn render_edit_toolbar(
@@ -1448,7 +1715,39 @@ impl DataGridPanel {
                             } else {
                                 theme.muted_foreground
                             })),
-                    ),
+                    )
+                    // Undo-last-save button, while the last save can be reversed
+                    .when(self.last_change_set.is_some(), |d| {
+                        d.child(
+                            div()
+                                .id("undo-save-btn")
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .px(Spacing::MD)
+                                .h(Heights::BUTTON)
+                                .rounded(Radii::MD)
+                                .border_1()
+                                .border_color(theme.border)
+                                .when(!has_changes, |d| {
+                                    d.cursor_pointer()
+                                        .hover(|d| d.bg(theme.secondary))
+                                        .on_click(cx.listener(|this, _, _, cx| {
+                                            this.open_undo_review(cx);
+                                        }))
+                                })
+                                .child(Icon::new(AppIcon::History).small().color(if has_changes {
+                                    theme.muted_foreground
+                                } else {
+                                    theme.foreground
+                                }))
+                                .child(Text::caption("Undo Last Save").color(if has_changes {
+                                    theme.muted_foreground
+                                } else {
+                                    theme.foreground
+                                })),
+                        )
+                    }),
             )
     }
 
@@ -1581,7 +1880,7 @@ impl DataGridPanel {
                 .color(text_color)
                 .into_any_element(),
 
-            Value::DateTime(dt) => Text::caption(dt.to_rfc3339())
+            Value::DateTime(dt) => Text::caption(dbflux_core::timezone::to_display_rfc3339(dt))
                 .color(text_color)
                 .into_any_element(),
 
@@ -1707,17 +2006,9 @@ impl DataGridPanel {
                     cx.notify();
                 });
             }),
-            on_png_export: Arc::new(move |_window, cx| {
+            on_export: Arc::new(move |option, _window, cx| {
                 if let Some(panel) = weak_panel.upgrade() {
-                    panel.update(cx, |this, _cx| {
-                        this.pending.toast = Some(dbflux_ui_base::toast::PendingToast {
-                            message: format!(
-                                "PNG export coming in v0.7 — {}",
-                                dbflux_ui_base::toast::now_hms()
-                            ),
-                            is_error: false,
-                        });
-                    });
+                    panel.update(cx, |this, cx| this.export_chart_image(option, cx));
                 }
             }),
             on_save_chart: Arc::new(move |window, cx| {
@@ -2048,7 +2339,8 @@ impl DataGridPanel {
                         V::Float(f) => format!("{:.3}", f),
                         V::Text(s) | V::Json(s) | V::Decimal(s) | V::ObjectId(s) => s.clone(),
                         V::Bytes(b) => format!("<{} bytes>", b.len()),
-                        V::DateTime(dt) => dt.to_rfc3339(),
+                        V::DateTime(dt) => dbflux_core::timezone::to_display_rfc3339(dt),
+                        V::NaiveDateTime(dt) => dt.to_string(),
                         V::Date(d) => d.to_string(),
                         V::Time(t) => t.to_string(),
                         V::Array(a) => format!("[{} items]", a.len()),
@@ -2158,12 +2450,16 @@ impl DataGridPanel {
 
         let hidden = shell.chart_hidden_series.clone();
         let panel_entity = cx.entity().clone();
+        let shell_for_color = shell_entity.clone();
 
         let on_toggle = move |idx: usize, _window: &mut Window, cx: &mut App| {
             panel_entity.update(cx, |this, cx| {
                 this.toggle_chart_series_hidden(idx, cx);
             });
         };
+        let on_cycle_color = move |idx: usize, _window: &mut Window, cx: &mut App| {
+            shell_for_color.update(cx, |s, cx| s.cycle_series_color(idx, cx));
+        };
 
         let chart_colors = ChartColors::for_current(cx);
 
@@ -2175,6 +2471,7 @@ impl DataGridPanel {
             focused_idx,
             &chart_colors,
             Some(on_toggle),
+            Some(on_cycle_color),
         )
         .into_any_element()
     }
@@ -3339,6 +3636,47 @@ impl DataGridPanel {
             .child(div().whitespace_nowrap().child(Text::code(hex_dump)))
     }
 
+    /// "Load next 10k" control for a result fetched in pages.
+    fn render_load_more_rows(
+        &self,
+        page_rows: u32,
+        loading: bool,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        div()
+            .id("load-more-rows")
+            .flex()
+            .items_center()
+            .gap_1()
+            .px(Spacing::SM)
+            .rounded(Radii::SM)
+            .text_size(FontSizes::XS)
+            .when(!loading, |d| {
+                d.cursor_pointer()
+                    .text_color(theme.foreground)
+                    .hover(|d| d.bg(theme.secondary))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.request_load_more(cx);
+                    }))
+            })
+            .when(loading, |d| d.text_color(theme.muted_foreground))
+            .child(
+                Icon::new(if loading {
+                    AppIcon::Loader
+                } else {
+                    AppIcon::Download
+                })
+                .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                .color(theme.muted_foreground),
+            )
+            .child(if loading {
+                "Loading…".to_string()
+            } else {
+                format!("Load next {}", compact_row_count(page_rows))
+            })
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(super) fn render_status_bar(
         &self,
@@ -3380,6 +3718,24 @@ impl DataGridPanel {
         };
         let current_result_mode = self.chrome.result_view_mode;
 
+        // Pages still to fetch for a result loaded through a fetch cursor.
+        let fetch_page_rows = self
+            .fetch
+            .cursor
+            .as_ref()
+            .filter(|cursor| cursor.has_more())
+            .map(|cursor| cursor.page_rows());
+        let loading_more = self.fetch.loading;
+
+        // Row, column and value of the active cell, so keyboard users can
+        // tell where they are without reading the highlighted cell.
+        let active_cell = self
+            .grid_table
+            .table_state
+            .as_ref()
+            .filter(|_| current_result_mode == ResultViewMode::Table)
+            .and_then(|table_state| table_state.read(cx).active_cell_description());
+
         div()
             .flex()
             .items_center()
@@ -3462,7 +3818,15 @@ impl DataGridPanel {
                                     .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
                                     .color(theme.muted_foreground),
                             )
-                            .child(Text::caption(format!("{} rows", row_count))),
+                            .child(Text::caption({
+                                let rows = dbflux_core::display_format::display_format()
+                                    .format_int(row_count as i64);
+                                if fetch_page_rows.is_some() {
+                                    format!("{}+ rows", rows)
+                                } else {
+                                    format!("{} rows", rows)
+                                }
+                            })),
                     )
                     .when_some(sort_info, |d, (col_name, direction, is_server)| {
                         let arrow_icon = match direction {
@@ -3482,6 +3846,12 @@ impl DataGridPanel {
                                 )
                                 .child(Text::caption(format!("{} ({})", col_name, mode))),
                         )
+                    })
+                    .when_some(fetch_page_rows, |d, page_rows| {
+                        d.child(self.render_load_more_rows(page_rows, loading_more, theme, cx))
+                    })
+                    .when_some(active_cell, |d, description| {
+                        d.child(Text::caption(description))
                     }),
             )
             // Center: pagination (for Table and Collection sources).
@@ -3550,17 +3920,32 @@ impl DataGridPanel {
                     )
                 },
             ))
-            // Right: export and execution time
+            // Right: pivot, export and execution time
             .child(
                 div()
                     .flex()
                     .items_center()
                     .gap(Spacing::SM)
+                    .when(self.can_pivot(), |d| {
+                        d.child(self.render_reshape_buttons(theme, cx))
+                    })
                     .when(has_data, |d| d.child(self.render_export_button(theme, cx)))
                     .child({
                         let mut muted = theme.muted_foreground;
                         muted.a = 0.5;
-                        Text::caption(exec_time.to_string()).color(muted)
+                        let breakdown = timing_breakdown(&QueryTiming {
+                            render: self.result_render_time,
+                            ..self.result.timing
+                        });
+
+                        div()
+                            .id("exec-time")
+                            .child(Text::caption(exec_time.to_string()).color(muted))
+                            .when_some(breakdown, |d, text| {
+                                d.tooltip(move |window, cx| {
+                                    Tooltip::new(text.clone()).build(window, cx)
+                                })
+                            })
                     }),
             )
     }
@@ -3584,6 +3969,68 @@ impl DataGridPanel {
         }
     }
 
+    /// "Pivot" opens the dimension picker and "Transpose" swaps rows and
+    /// columns; while either is shown, "Rows" goes back to the rows.
+    fn render_reshape_buttons(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let active = self.is_pivot_active();
+        let transposed = self.is_transposed();
+        let chip = |id: &'static str, icon: AppIcon, label: &'static str, highlight: bool| {
+            div()
+                .id(id)
+                .flex()
+                .items_center()
+                .gap_1()
+                .px(Spacing::XS)
+                .rounded(Radii::SM)
+                .text_size(FontSizes::XS)
+                .cursor_pointer()
+                .when(highlight, |d| d.bg(theme.accent.opacity(0.15)))
+                .hover(|d| d.bg(theme.secondary))
+                .child(Icon::new(icon).small().color(theme.muted_foreground))
+                .child(Text::caption(label).muted_foreground())
+        };
+
+        div()
+            .flex()
+            .items_center()
+            .gap(Spacing::XXS)
+            .child(
+                chip(
+                    "pivot-trigger",
+                    AppIcon::Columns,
+                    "Pivot",
+                    active && !transposed,
+                )
+                .on_click(cx.listener(|this, _, _, cx| {
+                    this.open_pivot_prompt(cx);
+                })),
+            )
+            .child(
+                chip(
+                    "transpose-trigger",
+                    AppIcon::ArrowUpDown,
+                    "Transpose",
+                    transposed,
+                )
+                .on_click(cx.listener(|this, _, _, cx| {
+                    this.toggle_transpose(cx);
+                })),
+            )
+            .when(active, |d| {
+                d.child(
+                    chip("pivot-rows", AppIcon::Rows3, "Rows", false).on_click(cx.listener(
+                        |this, _, _, cx| {
+                            this.show_pivot_rows(cx);
+                        },
+                    )),
+                )
+            })
+    }
+
     fn render_export_button(
         &self,
         theme: &gpui_component::theme::Theme,
@@ -3685,7 +4132,10 @@ impl DataGridPanel {
         items.push(section_header("Copy to clipboard"));
 
         for (idx, &format) in formats.iter().enumerate() {
-            let copyable = !matches!(format, dbflux_export::ExportFormat::Binary);
+            let copyable = !matches!(
+                format,
+                dbflux_export::ExportFormat::Binary | dbflux_export::ExportFormat::Parquet
+            );
             let row = div()
                 .id(SharedString::from(format!("export-copy-{}", idx)))
                 .flex()
@@ -3737,6 +4187,51 @@ impl DataGridPanel {
     }
 }
 
+/// Row count for the "Load next" label: `500`, `10k`, `2.5k`.
+fn compact_row_count(rows: u32) -> String {
+    if rows < 1_000 {
+        rows.to_string()
+    } else if rows % 1_000 == 0 {
+        format!("{}k", rows / 1_000)
+    } else {
+        format!("{:.1}k", rows as f64 / 1_000.0)
+    }
+}
+
+/// Per-phase lines for the execution-time tooltip, or `None` when the
+/// result carries no timing (e.g. it was not produced by a driver call).
+fn timing_breakdown(timing: &QueryTiming) -> Option<String> {
+    let round_trip = timing.round_trip?;
+    let transfer = timing.transfer().unwrap_or(round_trip);
+
+    let phase = |value: Option<Duration>, missing: &str| {
+        value
+            .map(format_phase)
+            .unwrap_or_else(|| missing.to_string())
+    };
+
+    Some(format!(
+        "Driver round-trip: {}\n\
+         Server execution: {}\n\
+         Deserialization: {}\n\
+         Network & driver: {}\n\
+         Grid render: {}",
+        format_phase(round_trip),
+        phase(timing.server, "not reported by driver"),
+        phase(timing.deserialize, "not measured"),
+        format_phase(transfer),
+        phase(timing.render, "pending"),
+    ))
+}
+
+fn format_phase(duration: Duration) -> String {
+    if duration < Duration::from_millis(10) {
+        format!("{:.1}ms", duration.as_secs_f64() * 1_000.0)
+    } else {
+        format!("{}ms", duration.as_millis())
+    }
+}
+
 fn format_hex_dump(data: &[u8]) -> String {
     const BYTES_PER_LINE: usize = 16;
 
@@ -3809,6 +4304,38 @@ mod tests {
         assert_eq!(mode, DataGridContentMode::EmptyFallback);
     }
 
+    #[test]
+    fn load_more_label_uses_compact_counts() {
+        assert_eq!(super::compact_row_count(500), "500");
+        assert_eq!(super::compact_row_count(10_000), "10k");
+        assert_eq!(super::compact_row_count(2_500), "2.5k");
+    }
+
+    #[test]
+    fn timing_breakdown_lists_each_phase() {
+        use dbflux_core::QueryTiming;
+        use std::time::Duration;
+
+        assert_eq!(super::timing_breakdown(&QueryTiming::default()), None);
+
+        let timing = QueryTiming {
+            round_trip: Some(Duration::from_millis(120)),
+            deserialize: Some(Duration::from_micros(4_500)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            super::timing_breakdown(&timing).as_deref(),
+            Some(
+                "Driver round-trip: 120ms\n\
+                 Server execution: not reported by driver\n\
+                 Deserialization: 4.5ms\n\
+                 Network & driver: 115ms\n\
+                 Grid render: pending"
+            )
+        );
+    }
+
     #[test]
     fn document_mode_with_columns_and_zero_rows_keeps_empty_fallback() {
         let mode = super::content_mode_for_result(false, DataViewMode::Document, true, false);
@@ -15,13 +15,14 @@
 //!
 //! # Sections
 //!
-//! - **ROW** — all column name / value pairs for the selected row.
+//! - **ROW** — all column name / value pairs for the selected row, with full
+//!   (untruncated, wrapped) values and a copy button per field.
 //! - **COLUMN** — metadata for the focused column (type, nullable, PK/FK flags).
 //! - **REFERENCES** — FK-resolved values; each FK resolves asynchronously.
 
 use dbflux_components::icons::AppIcon;
-use dbflux_components::primitives::{Icon, LoadingState, Text};
-use dbflux_components::tokens::Spacing;
+use dbflux_components::primitives::{Icon, IconButton, LoadingState, Text};
+use dbflux_components::tokens::{Heights, Spacing};
 use dbflux_core::Value;
 use gpui::prelude::FluentBuilder;
 use gpui::*;
@@ -95,12 +96,31 @@ fn render_section_header(
         )
 }
 
+/// What a field's copy button puts on the clipboard. Binary values, arrays
+/// and documents only show a summary, so copies take the raw value unless
+/// `formatted_output` asks for what is displayed. NULL has no text worth
+/// copying.
+fn copy_text_for(value: &Value, formatted_output: bool) -> Option<String> {
+    match value {
+        Value::Null => None,
+        value if formatted_output => Some(value.as_shown_string_truncated(usize::MAX)),
+        value => Some(value.as_raw_string()),
+    }
+}
+
 fn render_row_entry(
+    ix: usize,
     cell: &InspectorCell,
     theme: &gpui_component::theme::Theme,
 ) -> impl IntoElement {
-    let value_text = cell.value.as_display_string_truncated(200);
+    // The grid truncates long values; the inspector is where they are read
+    // in full, so nothing is cut here and the text wraps instead.
+    let value_text = cell.value.as_shown_string_truncated(usize::MAX);
     let is_null = cell.value.is_null();
+    let copy_text = copy_text_for(
+        &cell.value,
+        dbflux_core::display_format::display_format().formatted_output,
+    );
 
     div()
         .flex()
@@ -154,8 +174,6 @@ fn render_row_entry(
                 .flex_basis(px(220.0))
                 .min_w(px(60.0))
                 .overflow_hidden()
-                .whitespace_nowrap()
-                .text_ellipsis()
                 .when(is_null, |d| d.italic())
                 .child(Text::caption(value_text).color(if is_null {
                     theme.muted_foreground
@@ -163,6 +181,20 @@ fn render_row_entry(
                     theme.foreground
                 })),
         )
+        .child(
+            div()
+                .flex_shrink_0()
+                .size(Heights::BUTTON)
+                .when_some(copy_text, |d, copy_text| {
+                    d.child(
+                        IconButton::new(("row-inspector-copy", ix), AppIcon::Copy.into())
+                            .icon_size(Heights::ICON_SM)
+                            .on_click(move |_, _, cx| {
+                                cx.write_to_clipboard(ClipboardItem::new_string(copy_text.clone()));
+                            }),
+                    )
+                }),
+        )
 }
 
 fn render_references_section(
@@ -218,7 +250,7 @@ fn render_fk_reference_entry(
         fk_ref.column,
         qualified_table,
         fk_ref.target_pk,
-        fk_ref.value.as_display_string_truncated(40),
+        fk_ref.value.as_shown_string_truncated(40),
     );
 
     div()
@@ -298,7 +330,7 @@ pub fn summarize_row(map: &HashMap<String, Value>) -> String {
         if let Some(val) = map.get(*key)
             && !val.is_null()
         {
-            parts.push(val.as_display_string_truncated(60));
+            parts.push(val.as_shown_string_truncated(60));
             if parts.len() >= 2 {
                 break;
             }
@@ -312,7 +344,7 @@ pub fn summarize_row(map: &HashMap<String, Value>) -> String {
                 continue;
             }
             if matches!(val, Value::Text(_)) {
-                parts.push(val.as_display_string_truncated(60));
+                parts.push(val.as_shown_string_truncated(60));
                 break;
             }
         }
@@ -491,7 +523,8 @@ impl Render for RowInspectorContent {
                 snapshot
                     .cells
                     .iter()
-                    .map(|cell| render_row_entry(cell, theme)),
+                    .enumerate()
+                    .map(|(ix, cell)| render_row_entry(ix, cell, theme)),
             )
             .when(has_fk, |d| {
                 d.child(render_section_header("REFERENCES", theme)).child(
@@ -517,7 +550,8 @@ impl Render for RowInspectorContent {
 #[cfg(test)]
 mod tests {
     use super::{
-        FkReference, InspectorCell, InspectorSnapshot, RowInspectorContent, summarize_row,
+        FkReference, InspectorCell, InspectorSnapshot, RowInspectorContent, copy_text_for,
+        summarize_row,
     };
     use dbflux_components::primitives::LoadingState;
     use dbflux_core::Value;
@@ -707,4 +741,28 @@ mod tests {
         let summary = summarize_row(&map);
         assert_eq!(summary, "0 fields");
     }
+
+    #[test]
+    fn copy_takes_the_full_raw_value() {
+        let long = "x".repeat(5000);
+
+        assert_eq!(
+            copy_text_for(&Value::Text(long.clone()), false).as_deref(),
+            Some(long.as_str())
+        );
+        assert_eq!(
+            copy_text_for(&Value::Bytes(vec![0xCA, 0xFE]), false).as_deref(),
+            Some("cafe")
+        );
+        assert_eq!(copy_text_for(&Value::Null, false), None);
+    }
+
+    #[test]
+    fn formatted_output_copies_the_shown_text() {
+        assert_eq!(
+            copy_text_for(&Value::Bytes(vec![0xCA, 0xFE]), true).as_deref(),
+            Some("<2 bytes>")
+        );
+        assert_eq!(copy_text_for(&Value::Null, true), None);
+    }
 }
@@ -0,0 +1,176 @@
+use super::*;
+use dbflux_core::{DataTabState, DataTabTarget};
+use dbflux_ui_base::AppStateChanged;
+
+impl DataGridPanel {
+    /// Recreates a table or collection grid from its session state.
+    ///
+    /// Nothing is fetched yet: the first load runs when the grid is first
+    /// rendered with its connection up, see [`Self::process_deferred_load`].
+    pub fn restore(
+        state: &DataTabState,
+        app_state: gpui::Entity<AppStateEntity>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let (source, pk_columns) = match &state.target {
+            DataTabTarget::Table { table, database } => {
+                let primary_key =
+                    Self::get_primary_key_columns(&app_state, state.profile_id, table, cx);
+                let pk_columns = primary_key.iter().map(|c| c.column.name.clone()).collect();
+                let order_by = if state.order_by.is_empty() {
+                    primary_key
+                } else {
+                    state.order_by.clone()
+                };
+
+                let source = DataSource::Table {
+                    profile_id: state.profile_id,
+                    database: database.clone(),
+                    table: table.clone(),
+                    pagination: state.pagination.clone(),
+                    order_by,
+                    total_rows: None,
+                };
+
+                (source, pk_columns)
+            }
+            DataTabTarget::Collection { collection } => {
+                let source = DataSource::Collection {
+                    profile_id: state.profile_id,
+                    collection: collection.clone(),
+                    pagination: state.pagination.clone(),
+                    total_docs: None,
+                };
+
+                (source, vec!["_id".to_string()])
+            }
+        };
+
+        let mut panel = Self::new_internal(source, app_state.clone(), pk_columns, window, cx);
+
+        panel.filter_bar.filter_input.update(cx, |input, cx| {
+            input.set_value(state.filter.as_str(), window, cx);
+        });
+
+        let limit = state.pagination.limit().to_string();
+        panel.filter_bar.limit_input.update(cx, |input, cx| {
+            input.set_value(limit.as_str(), window, cx);
+        });
+
+        panel.pending.deferred_load = true;
+
+        // Re-render once the connection comes up so the deferred load can run.
+        let connection_sub = cx.subscribe(&app_state, |this, _, _: &AppStateChanged, cx| {
+            if this.pending.deferred_load {
+                cx.notify();
+            }
+        });
+        panel.refresh._refresh_subscriptions.push(connection_sub);
+
+        panel
+    }
+
+    /// Session state of table and collection grids; `None` for query results.
+    pub fn session_state(&self, cx: &App) -> Option<DataTabState> {
+        let filter = self.filter_bar.filter_input.read(cx).value().to_string();
+
+        match &self.source {
+            DataSource::Table {
+                profile_id,
+                database,
+                table,
+                pagination,
+                order_by,
+                ..
+            } => Some(DataTabState {
+                profile_id: *profile_id,
+                target: DataTabTarget::Table {
+                    table: table.clone(),
+                    database: database.clone(),
+                },
+                filter,
+                order_by: order_by.clone(),
+                pagination: pagination.clone(),
+            }),
+            DataSource::Collection {
+                profile_id,
+                collection,
+                pagination,
+                ..
+            } => Some(DataTabState {
+                profile_id: *profile_id,
+                target: DataTabTarget::Collection {
+                    collection: collection.clone(),
+                },
+                filter,
+                order_by: Vec::new(),
+                pagination: pagination.clone(),
+            }),
+            DataSource::QueryResult { .. } => None,
+        }
+    }
+
+    /// Runs the first load of a restored grid once its connection is up.
+    pub(super) fn process_deferred_load(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.pending.deferred_load {
+            return;
+        }
+
+        let profile_id = match &self.source {
+            DataSource::Table { profile_id, .. } | DataSource::Collection { profile_id, .. } => {
+                *profile_id
+            }
+            DataSource::QueryResult { .. } => {
+                self.pending.deferred_load = false;
+                return;
+            }
+        };
+
+        if !self
+            .app_state
+            .read(cx)
+            .connections()
+            .contains_key(&profile_id)
+        {
+            return;
+        }
+
+        self.pending.deferred_load = false;
+
+        // The schema was not loaded at restore time; pick up the primary key
+        // now, falling back to fetching the table details.
+        let mut missing_pk = None;
+        if let DataSource::Table {
+            table, order_by, ..
+        } = &mut self.source
+            && self.pk_columns.is_empty()
+        {
+            let primary_key = Self::get_primary_key_columns(&self.app_state, profile_id, table, cx);
+            self.pk_columns = primary_key.iter().map(|c| c.column.name.clone()).collect();
+
+            if order_by.is_empty() {
+                *order_by = primary_key;
+            }
+
+            if self.pk_columns.is_empty() {
+                missing_pk = Some(table.clone());
+            }
+        }
+
+        // Restoring the filter text may already have given up on the foreign
+        // keys while the connection was down.
+        if matches!(self.builder.fk_cache, FkLoadState::Unavailable) {
+            self.builder.fk_cache = FkLoadState::Loading;
+        }
+
+        self.refresh(window, cx);
+
+        if let Some(table) = missing_pk {
+            self.fetch_table_details_for_pk(profile_id, &table, cx);
+        }
+
+        self.ensure_filter_source_columns_loaded(cx);
+        self.ensure_fk_cache_loaded(cx);
+    }
+}
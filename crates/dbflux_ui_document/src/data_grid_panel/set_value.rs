@@ -0,0 +1,393 @@
+//! "Set Value in Selection": one value or SQL expression written to a column
+//! of every selected row with a single UPDATE keyed on the primary key. The
+//! statement goes through the visual mutation confirmation, which previews it
+//! before anything runs.
+
+use super::DataGridPanel;
+use super::mutation_executor::MutationExecOptions;
+use dbflux_components::components::data_table::model::VisualRowSource;
+use dbflux_components::controls::{Checkbox, Input, InputEvent, InputState};
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::Spacing;
+use dbflux_core::{
+    Assignment, AssignmentValue, BoolOp, ColumnKind, Comparator, FilterNode, LiteralValue,
+    MutationKind, Predicate, PredicateValue, ScalarLiteral, Value, VisualMutationSpec,
+};
+use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
+use gpui::*;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariant, ButtonVariants};
+
+/// Value prompt shown after picking "Set Value in Selection". The keys are
+/// captured when the prompt opens so a reload underneath does not change
+/// which rows are updated.
+pub(super) struct SetValuePrompt {
+    column: String,
+    kind: ColumnKind,
+    keys: Vec<Vec<Value>>,
+    value_input: Entity<InputState>,
+    is_expression: bool,
+    _subscription: Subscription,
+}
+
+/// Filter literal for a key value, or `None` for values that cannot identify
+/// a row in a WHERE clause.
+fn key_literal(value: &Value) -> Option<LiteralValue> {
+    match value {
+        Value::Bool(b) => Some(LiteralValue::Bool(*b)),
+        Value::Int(i) => Some(LiteralValue::Integer(*i)),
+        Value::Float(f) => Some(LiteralValue::Float(*f)),
+        Value::Text(s) | Value::Decimal(s) | Value::Enum(s) => Some(LiteralValue::Text(s.clone())),
+        Value::Uuid(uuid) => Some(LiteralValue::Text(uuid.to_string())),
+        Value::Interval(interval) => Some(LiteralValue::Text(interval.to_string())),
+        Value::DateTime(dt) => Some(LiteralValue::Timestamp(dt.to_rfc3339())),
+        Value::NaiveDateTime(dt) => Some(LiteralValue::Timestamp(dt.to_string())),
+        Value::Date(date) => Some(LiteralValue::Text(date.to_string())),
+        Value::Time(time) => Some(LiteralValue::Text(time.to_string())),
+        _ => None,
+    }
+}
+
+fn key_predicate(column: &str, comparator: Comparator, value: PredicateValue) -> FilterNode {
+    FilterNode::Predicate(Predicate {
+        source_alias: String::new(),
+        column: column.to_string(),
+        comparator,
+        value,
+        node_id: 0,
+    })
+}
+
+/// WHERE filter matching exactly the rows with the given primary keys: an
+/// `IN` list for a single-column key, otherwise one `AND` group per row
+/// joined with `OR`.
+pub(super) fn key_filter(pk_columns: &[String], keys: &[Vec<Value>]) -> Option<FilterNode> {
+    if pk_columns.is_empty() || keys.is_empty() {
+        return None;
+    }
+
+    let rows: Vec<Vec<LiteralValue>> = keys
+        .iter()
+        .map(|key| {
+            if key.len() != pk_columns.len() {
+                return None;
+            }
+            key.iter().map(key_literal).collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<_>>()?;
+
+    if let [column] = pk_columns {
+        let values = rows.into_iter().flatten().collect();
+        return Some(key_predicate(
+            column,
+            Comparator::In,
+            PredicateValue::List(values),
+        ));
+    }
+
+    let groups = rows
+        .into_iter()
+        .map(|row| FilterNode::Group {
+            op: BoolOp::And,
+            children: pk_columns
+                .iter()
+                .zip(row)
+                .map(|(column, value)| {
+                    key_predicate(column, Comparator::Eq, PredicateValue::Single(value))
+                })
+                .collect(),
+        })
+        .collect();
+
+    Some(FilterNode::Group {
+        op: BoolOp::Or,
+        children: groups,
+    })
+}
+
+impl DataGridPanel {
+    /// Primary keys of the selected saved rows, or of the clicked row when it
+    /// is outside the selection. Pending inserts are skipped.
+    fn selected_row_keys(&self, clicked_row: usize, cx: &App) -> Result<Vec<Vec<Value>>, String> {
+        let Some(table_state) = &self.grid_table.table_state else {
+            return Ok(Vec::new());
+        };
+
+        if self.pk_columns.is_empty() {
+            return Err("no primary key defined for this table".to_string());
+        }
+
+        let state = table_state.read(cx);
+        let visual_order = state.edit_buffer().compute_visual_order();
+
+        let visual_rows = match state.selection().selected_range() {
+            Some(range) if range.contains_row(clicked_row) => range.start.row..range.end.row + 1,
+            _ => clicked_row..clicked_row + 1,
+        };
+
+        let pk_positions: Vec<usize> = self
+            .pk_columns
+            .iter()
+            .map(|name| {
+                self.result
+                    .columns
+                    .iter()
+                    .position(|column| &column.name == name)
+                    .ok_or_else(|| format!("primary key column {name} is not in the result"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(visual_rows
+            .filter_map(|visual_row| match visual_order.get(visual_row) {
+                Some(VisualRowSource::Base(idx)) => self.result.rows.get(*idx),
+                _ => None,
+            })
+            .map(|row| {
+                pk_positions
+                    .iter()
+                    .map(|&ix| row.get(ix).cloned().unwrap_or(Value::Null))
+                    .collect()
+            })
+            .collect())
+    }
+
+    pub(super) fn open_set_value_prompt(
+        &mut self,
+        row: usize,
+        col: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(column) = self.result.columns.get(col) else {
+            return;
+        };
+        let (column, kind) = (column.name.clone(), column.kind);
+
+        let keys = match self.selected_row_keys(row, cx) {
+            Ok(keys) if !keys.is_empty() => keys,
+            Ok(_) => return,
+            Err(message) => {
+                report_error(
+                    UserFacingError::new(ErrorKind::User, format!("Cannot set value: {message}")),
+                    cx,
+                );
+                return;
+            }
+        };
+
+        let value_input = cx.new(|cx| InputState::new(window, cx).placeholder("New value"));
+        value_input.update(cx, |input, cx| input.focus(window, cx));
+
+        let subscription = cx.subscribe_in(
+            &value_input,
+            window,
+            |this: &mut Self, _input, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.confirm_set_value(window, cx);
+                }
+            },
+        );
+
+        self.set_value_prompt = Some(SetValuePrompt {
+            column,
+            kind,
+            keys,
+            value_input,
+            is_expression: false,
+            _subscription: subscription,
+        });
+        cx.notify();
+    }
+
+    /// Builds the UPDATE for the prompt's rows and hands it to the mutation
+    /// confirmation, which shows the SQL before running it.
+    pub(super) fn confirm_set_value(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(prompt) = self.set_value_prompt.take() else {
+            return;
+        };
+        let Some(table) = self.source.table_ref().cloned() else {
+            return;
+        };
+
+        let text = prompt.value_input.read(cx).value().to_string();
+        let value = if prompt.is_expression {
+            if text.trim().is_empty() {
+                self.set_value_prompt = Some(prompt);
+                return;
+            }
+            AssignmentValue::Expression(text.trim().to_string())
+        } else {
+            AssignmentValue::Literal(ScalarLiteral::from_input_for_kind(&text, prompt.kind))
+        };
+
+        let Some(filter) = key_filter(&self.pk_columns, &prompt.keys) else {
+            report_error(
+                UserFacingError::new(
+                    ErrorKind::User,
+                    "Cannot set value: primary key uses an unsupported value type",
+                ),
+                cx,
+            );
+            return;
+        };
+
+        let spec = VisualMutationSpec {
+            from: table,
+            filter: Some(filter),
+            kind: MutationKind::Update {
+                assignments: vec![Assignment {
+                    column: prompt.column,
+                    value,
+                }],
+            },
+        };
+
+        let row_count = prompt.keys.len() as u64;
+        self.on_mutation_run_requested(
+            spec,
+            MutationExecOptions::single_transaction(),
+            Some(row_count),
+            window,
+            cx,
+        );
+        cx.notify();
+    }
+
+    pub(super) fn cancel_set_value(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.set_value_prompt.take().is_some() {
+            self.focus_active_view(window, cx);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn render_set_value_prompt(
+        &self,
+        theme: &gpui_component::theme::Theme,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let Some(prompt) = self.set_value_prompt.as_ref() else {
+            return div().into_any_element();
+        };
+
+        let rows = prompt.keys.len();
+        let title = format!(
+            "Set {} in {} row{}",
+            prompt.column,
+            rows,
+            if rows == 1 { "" } else { "s" }
+        );
+
+        div()
+            .id("set-value-overlay")
+            .absolute()
+            .inset_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(theme.background.opacity(0.6))
+            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                cx.stop_propagation();
+            })
+            .child(
+                div()
+                    .bg(theme.secondary)
+                    .border_1()
+                    .border_color(theme.border)
+                    .p(Spacing::LG)
+                    .w(px(360.0))
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::SM)
+                    .child(Text::label(title))
+                    .child(Input::new(&prompt.value_input))
+                    .child(
+                        Checkbox::new("set-value-expression")
+                            .checked(prompt.is_expression)
+                            .label("SQL expression")
+                            .on_click(cx.listener(|this, &checked, _, cx| {
+                                if let Some(prompt) = this.set_value_prompt.as_mut() {
+                                    prompt.is_expression = checked;
+                                    cx.notify();
+                                }
+                            })),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .gap(Spacing::XXS)
+                            .justify_end()
+                            .child(
+                                Button::new("cancel-set-value")
+                                    .label("Cancel")
+                                    .small()
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.cancel_set_value(window, cx);
+                                    })),
+                            )
+                            .child(
+                                Button::new("confirm-set-value")
+                                    .label("Preview")
+                                    .small()
+                                    .with_variant(ButtonVariant::Primary)
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.confirm_set_value(window, cx);
+                                    })),
+                            ),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(columns: &[&str]) -> Vec<String> {
+        columns.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn single_column_key_uses_in_list() {
+        let filter = key_filter(&names(&["id"]), &[vec![Value::Int(1)], vec![Value::Int(4)]]);
+
+        assert_eq!(
+            filter,
+            Some(key_predicate(
+                "id",
+                Comparator::In,
+                PredicateValue::List(vec![LiteralValue::Integer(1), LiteralValue::Integer(4)]),
+            ))
+        );
+    }
+
+    #[test]
+    fn composite_key_matches_each_row_exactly() {
+        let filter = key_filter(
+            &names(&["order_id", "line"]),
+            &[
+                vec![Value::Int(7), Value::Int(1)],
+                vec![Value::Int(7), Value::Int(2)],
+            ],
+        )
+        .unwrap();
+
+        let FilterNode::Group { op, children } = filter else {
+            panic!("expected an OR group");
+        };
+        assert_eq!(op, BoolOp::Or);
+        assert_eq!(children.len(), 2);
+        assert!(
+            children
+                .iter()
+                .all(|child| matches!(child, FilterNode::Group { op: BoolOp::And, children } if children.len() == 2))
+        );
+    }
+
+    #[test]
+    fn unsupported_key_values_have_no_filter() {
+        assert!(key_filter(&names(&["id"]), &[vec![Value::Bytes(vec![1])]]).is_none());
+        assert!(key_filter(&names(&["id"]), &[]).is_none());
+    }
+}
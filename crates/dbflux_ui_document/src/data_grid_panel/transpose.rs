@@ -0,0 +1,132 @@
+//! "Transpose": the result turned on its side, one row per column and one
+//! column per row. Single-row `SHOW`/settings results and very wide rows read
+//! top to bottom instead of scrolling sideways.
+
+use super::pivot::{PivotView, Reshape};
+use super::{DataGridPanel, PendingToast};
+use dbflux_core::{ColumnKind, ColumnMeta, QueryResult, Value};
+use gpui::*;
+use std::sync::Arc;
+
+/// Rows that become columns; the rest are left out of the transposition.
+const MAX_TRANSPOSED_ROWS: usize = 200;
+
+/// `result` with rows and columns swapped. The first column names the source
+/// column; the others are the source rows, headed by their 1-based number.
+pub(super) fn transpose_result(result: &QueryResult) -> QueryResult {
+    let rows = &result.rows[..result.rows.len().min(MAX_TRANSPOSED_ROWS)];
+
+    let mut columns = vec![ColumnMeta {
+        name: "column".to_string(),
+        type_name: "text".to_string(),
+        kind: ColumnKind::Text,
+        nullable: false,
+        is_primary_key: false,
+    }];
+    columns.extend((1..=rows.len()).map(|n| ColumnMeta {
+        name: n.to_string(),
+        type_name: String::new(),
+        kind: ColumnKind::Unknown,
+        nullable: true,
+        is_primary_key: false,
+    }));
+
+    let transposed = result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(ix, column)| {
+            std::iter::once(Value::Text(column.name.clone()))
+                .chain(
+                    rows.iter()
+                        .map(|row| row.get(ix).cloned().unwrap_or(Value::Null)),
+                )
+                .collect()
+        })
+        .collect();
+
+    QueryResult {
+        timing: result.timing,
+        ..QueryResult::table(columns, transposed, None, result.execution_time)
+    }
+}
+
+impl DataGridPanel {
+    /// Transposes the result, or goes back to its rows when it already is.
+    pub(super) fn toggle_transpose(&mut self, cx: &mut Context<Self>) {
+        if self.is_transposed() {
+            self.show_pivot_rows(cx);
+            return;
+        }
+
+        let rows = match self.pivot.take() {
+            Some(pivot) => pivot.rows,
+            None => std::mem::replace(&mut self.result, Arc::new(QueryResult::empty())),
+        };
+
+        if rows.rows.len() > MAX_TRANSPOSED_ROWS {
+            self.pending.toast = Some(PendingToast {
+                message: format!(
+                    "Transposed the first {} of {} rows",
+                    MAX_TRANSPOSED_ROWS,
+                    rows.rows.len()
+                ),
+                is_error: false,
+            });
+        }
+
+        self.pivot = Some(PivotView {
+            reshape: Reshape::Transpose,
+            rows: Arc::new(QueryResult::empty()),
+        });
+        self.result = self.pivot_incoming(rows, cx);
+        self.reset_local_view();
+        self.rebuild_table(Vec::new(), cx);
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn transpose_turns_columns_into_rows() {
+        let column = |name: &str, kind| ColumnMeta {
+            name: name.to_string(),
+            type_name: String::new(),
+            kind,
+            nullable: true,
+            is_primary_key: false,
+        };
+        let result = QueryResult::table(
+            vec![
+                column("name", ColumnKind::Text),
+                column("setting", ColumnKind::Integer),
+            ],
+            vec![
+                vec![Value::Text("work_mem".into()), Value::Int(4096)],
+                vec![Value::Text("port".into())],
+            ],
+            None,
+            Duration::ZERO,
+        );
+
+        let transposed = transpose_result(&result);
+
+        let names: Vec<&str> = transposed.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["column", "1", "2"]);
+        assert_eq!(
+            transposed.rows,
+            vec![
+                vec![
+                    Value::Text("name".into()),
+                    Value::Text("work_mem".into()),
+                    Value::Text("port".into()),
+                ],
+                vec![Value::Text("setting".into()), Value::Int(4096), Value::Null],
+            ]
+        );
+    }
+}
@@ -20,7 +20,7 @@ pub(super) fn value_to_json(value: &Value) -> serde_json::Value {
         Value::Bool(b) => serde_json::Value::Bool(*b),
         Value::Int(i) => serde_json::json!(*i),
         Value::Float(f) => serde_json::json!(*f),
-        Value::Text(s) => serde_json::Value::String(s.clone()),
+        Value::Text(s) | Value::Enum(s) => serde_json::Value::String(s.clone()),
         Value::Bytes(b) => {
             let hex: String = b.iter().map(|byte| format!("{:02x}", byte)).collect();
             serde_json::json!({"$binary": {"hex": hex}})
@@ -28,8 +28,11 @@ pub(super) fn value_to_json(value: &Value) -> serde_json::Value {
         Value::Json(j) => serde_json::from_str(j).unwrap_or(serde_json::Value::String(j.clone())),
         Value::Decimal(d) => serde_json::Value::String(d.clone()),
         Value::DateTime(dt) => serde_json::json!({"$date": dt.to_rfc3339()}),
+        Value::NaiveDateTime(dt) => serde_json::Value::String(dt.to_string()),
         Value::Date(d) => serde_json::Value::String(d.to_string()),
         Value::Time(t) => serde_json::Value::String(t.to_string()),
+        Value::Uuid(uuid) => serde_json::Value::String(uuid.to_string()),
+        Value::Interval(interval) => serde_json::Value::String(interval.to_string()),
         Value::Array(arr) => serde_json::Value::Array(arr.iter().map(value_to_json).collect()),
         Value::Document(doc) => {
             let map: serde_json::Map<String, serde_json::Value> = doc
@@ -0,0 +1,563 @@
+//! "Search data": finds a text in the rows of every table of a database.
+//!
+//! Tables are searched one at a time in the background as a cancellable
+//! task, and the matching rows stream into the modal as they are found.
+//! Clicking a hit opens the table filtered down to that row.
+
+use dbflux_components::controls::{Button, GpuiInput as Input, InputEvent, InputState};
+use dbflux_components::modals::shell::ModalShell;
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{FontSizes, Radii, Spacing};
+use dbflux_core::{
+    CancelToken, ConnectedProfile, Connection, DataSearchHit, DataSearchMode, DbError,
+    ProgressUnit, QueryRequest, TableInfo, TableRef, TaskId, TaskKind, TaskTarget,
+    collect_search_hits, search_columns, table_search_query,
+};
+use dbflux_ui_base::{AppStateChanged, AppStateEntity};
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use uuid::Uuid;
+
+/// Matching rows fetched from each table.
+const ROWS_PER_TABLE: u32 = 50;
+
+/// Hits after which the search stops early.
+const MAX_HITS: usize = 1_000;
+
+/// Emitted when a hit is clicked.
+#[derive(Clone, Debug)]
+pub enum DataSearchEvent {
+    OpenRow {
+        profile_id: Uuid,
+        database: Option<String>,
+        table: TableRef,
+        filter: String,
+    },
+}
+
+/// Picks the column a row matched on, mirroring the comparison the database
+/// made. A pattern the `regex` crate cannot parse matches nothing, so those
+/// hits fall back to the first searched column.
+#[derive(Clone)]
+enum TextMatcher {
+    IgnoreCase(String),
+    MatchCase(String),
+    Regex(Option<regex::Regex>),
+}
+
+impl TextMatcher {
+    fn new(text: &str, mode: DataSearchMode) -> Self {
+        match mode {
+            DataSearchMode::Contains => Self::IgnoreCase(text.to_lowercase()),
+            DataSearchMode::MatchCase => Self::MatchCase(text.to_string()),
+            DataSearchMode::Regex => Self::Regex(regex::Regex::new(text).ok()),
+        }
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match self {
+            Self::IgnoreCase(text) => value.to_lowercase().contains(text.as_str()),
+            Self::MatchCase(text) => value.contains(text.as_str()),
+            Self::Regex(regex) => regex.as_ref().is_some_and(|r| r.is_match(value)),
+        }
+    }
+}
+
+/// The search in flight, or the last one once it has finished.
+struct SearchRun {
+    task_id: TaskId,
+    cancel_token: CancelToken,
+    total: usize,
+    searched: usize,
+    failed: usize,
+    finished: bool,
+}
+
+pub struct DataSearchModal {
+    app_state: Entity<AppStateEntity>,
+    visible: bool,
+    input: Entity<InputState>,
+    mode: DataSearchMode,
+    target: Option<(Uuid, Option<String>)>,
+    run: Option<SearchRun>,
+    hits: Vec<DataSearchHit>,
+    message: Option<String>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl DataSearchModal {
+    pub fn new(
+        app_state: Entity<AppStateEntity>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder("Text to find"));
+
+        let subscription = cx.subscribe_in(&input, window, |this, _, event, _, cx| {
+            if let InputEvent::PressEnter { .. } = event {
+                this.start(cx);
+            }
+        });
+
+        Self {
+            app_state,
+            visible: false,
+            input,
+            mode: DataSearchMode::default(),
+            target: None,
+            run: None,
+            hits: Vec::new(),
+            message: None,
+            _subscriptions: vec![subscription],
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows the modal for `database` of `profile_id`. Reopening it for the
+    /// same target keeps the last search and its hits.
+    pub fn open(
+        &mut self,
+        profile_id: Uuid,
+        database: Option<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let target = Some((profile_id, database));
+        if self.target != target {
+            self.stop(cx);
+            self.target = target;
+            self.run = None;
+            self.hits.clear();
+            self.message = None;
+        }
+
+        self.visible = true;
+        self.input.update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    /// Hides the modal. A running search keeps going in the tasks panel.
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.visible = false;
+        cx.notify();
+    }
+
+    fn is_running(&self) -> bool {
+        self.run.as_ref().is_some_and(|run| !run.finished)
+    }
+
+    fn stop(&mut self, cx: &mut Context<Self>) {
+        let Some(run) = self.run.as_mut().filter(|run| !run.finished) else {
+            return;
+        };
+
+        run.finished = true;
+        let task_id = run.task_id;
+        self.app_state.update(cx, |state, cx| {
+            state.tasks_mut().cancel(task_id);
+            cx.emit(AppStateChanged);
+        });
+        cx.notify();
+    }
+
+    fn start(&mut self, cx: &mut Context<Self>) {
+        let text = self.input.read(cx).value().trim().to_string();
+        let Some((profile_id, database)) = self.target.clone() else {
+            return;
+        };
+        if text.is_empty() {
+            return;
+        }
+
+        self.stop(cx);
+        self.hits.clear();
+        self.message = None;
+
+        let mode = self.mode;
+        let (conn, tables) = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(&profile_id) else {
+                self.message = Some("The connection is no longer open".to_string());
+                cx.notify();
+                return;
+            };
+            let conn = match database.as_deref() {
+                Some(database) => connected.connection_for_database(database),
+                None => connected.connection.clone(),
+            };
+            (conn, database_tables(connected, database.as_deref()))
+        };
+
+        if mode == DataSearchMode::Regex && conn.dialect().regex_match_expr("c", "").is_none() {
+            self.message = Some("This database has no regular expression operator".to_string());
+            cx.notify();
+            return;
+        }
+
+        if tables.is_empty() {
+            self.message = Some("No tables are loaded for this database".to_string());
+            cx.notify();
+            return;
+        }
+
+        let description = format!("Search data for \"{}\"", text);
+        let (task_id, cancel_token, admission) = self.app_state.update(cx, |state, cx| {
+            let started = state.enqueue_task_for_target(
+                TaskKind::DataSearch,
+                description,
+                TaskTarget {
+                    profile_id,
+                    database: database.clone(),
+                },
+            );
+            cx.emit(AppStateChanged);
+            started
+        });
+
+        let total = tables.len();
+        self.run = Some(SearchRun {
+            task_id,
+            cancel_token: cancel_token.clone(),
+            total,
+            searched: 0,
+            failed: 0,
+            finished: false,
+        });
+        cx.notify();
+
+        let matcher = TextMatcher::new(&text, mode);
+
+        cx.spawn(async move |this, cx| {
+            // A cancelled queued search runs no table and just finishes.
+            let admitted = admission.await;
+
+            for table in tables.into_iter().take_while(|_| admitted) {
+                if cancel_token.is_cancelled() {
+                    break;
+                }
+
+                let conn = conn.clone();
+                let matcher = matcher.clone();
+                let text = text.clone();
+                let database = database.clone();
+                let result = cx
+                    .background_executor()
+                    .spawn(async move {
+                        search_table(&*conn, database.as_deref(), table, &text, mode, &matcher)
+                    })
+                    .await;
+
+                let keep_going = this
+                    .update(cx, |modal, cx| {
+                        modal.apply_table_result(task_id, result, cx)
+                    })
+                    .unwrap_or(false);
+                if !keep_going {
+                    break;
+                }
+            }
+
+            let _ = this.update(cx, |modal, cx| modal.finish(task_id, cx));
+        })
+        .detach();
+    }
+
+    /// Records the hits of one table. Returns whether the search goes on.
+    fn apply_table_result(
+        &mut self,
+        task_id: TaskId,
+        result: Result<Vec<DataSearchHit>, DbError>,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(run) = self.run.as_mut().filter(|run| run.task_id == task_id) else {
+            return false;
+        };
+
+        run.searched += 1;
+        match result {
+            Ok(hits) => self.hits.extend(hits),
+            Err(error) => {
+                run.failed += 1;
+                log::warn!("Data search skipped a table: {}", error);
+            }
+        }
+
+        let (searched, total) = (run.searched as u64, run.total as u64);
+        self.app_state.update(cx, |state, cx| {
+            state
+                .tasks_mut()
+                .update_units(task_id, ProgressUnit::Tables, searched, Some(total));
+            cx.emit(AppStateChanged);
+        });
+        cx.notify();
+
+        !run.finished && self.hits.len() < MAX_HITS
+    }
+
+    fn finish(&mut self, task_id: TaskId, cx: &mut Context<Self>) {
+        let Some(run) = self.run.as_mut().filter(|run| run.task_id == task_id) else {
+            return;
+        };
+
+        let cancelled = run.finished || run.cancel_token.is_cancelled();
+        run.finished = true;
+
+        if cancelled {
+            self.message = Some(format!(
+                "Stopped after {} of {} tables",
+                run.searched, run.total
+            ));
+        } else {
+            let mut summary = format!(
+                "{} matching rows in {} tables",
+                self.hits.len(),
+                run.searched
+            );
+            if self.hits.len() >= MAX_HITS {
+                summary.push_str(&format!(" (stopped at {} rows)", MAX_HITS));
+            }
+            if run.failed > 0 {
+                summary.push_str(&format!(", {} tables could not be searched", run.failed));
+            }
+
+            self.app_state.update(cx, |state, cx| {
+                state.complete_task_with_details(task_id, summary.clone());
+                cx.emit(AppStateChanged);
+            });
+            self.message = Some(summary);
+        }
+
+        cx.notify();
+    }
+
+    fn open_hit(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let (Some((profile_id, database)), Some(hit)) = (self.target.clone(), self.hits.get(ix))
+        else {
+            return;
+        };
+
+        cx.emit(DataSearchEvent::OpenRow {
+            profile_id,
+            database,
+            table: hit.table.clone(),
+            filter: hit.filter.clone(),
+        });
+        self.close(cx);
+    }
+}
+
+/// Tables of `database`, from its lazily loaded schema when the driver keeps
+/// one per database, or from the connection's schema snapshot otherwise.
+pub(crate) fn database_tables(
+    connected: &ConnectedProfile,
+    database: Option<&str>,
+) -> Vec<TableInfo> {
+    if let Some(schema) = database.and_then(|db| connected.database_schemas.get(db)) {
+        return schema.tables.clone();
+    }
+
+    let snapshot = match database {
+        Some(database) => connected.schema_for_target_database(database),
+        None => connected.schema.as_ref(),
+    };
+
+    snapshot
+        .and_then(|snapshot| snapshot.as_relational())
+        .map(|relational| {
+            relational
+                .schemas
+                .iter()
+                .flat_map(|schema| schema.tables.iter())
+                .chain(relational.tables.iter())
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Runs the search query of one table, loading its columns first when the
+/// schema has not yet.
+fn search_table(
+    conn: &dyn Connection,
+    database: Option<&str>,
+    table: TableInfo,
+    text: &str,
+    mode: DataSearchMode,
+    matcher: &TextMatcher,
+) -> Result<Vec<DataSearchHit>, DbError> {
+    let table_ref = TableRef {
+        schema: table.schema.clone(),
+        name: table.name.clone(),
+    };
+
+    let columns = match table.columns {
+        Some(columns) => columns,
+        None => conn
+            .table_details(
+                database.unwrap_or("default"),
+                table.schema.as_deref(),
+                &table.name,
+            )?
+            .columns
+            .unwrap_or_default(),
+    };
+
+    let (text_columns, key_columns) = search_columns(&columns);
+    let dialect = conn.dialect();
+    let Some(sql) = table_search_query(
+        dialect,
+        &table_ref,
+        &text_columns,
+        &key_columns,
+        text,
+        mode,
+        ROWS_PER_TABLE,
+    ) else {
+        return Ok(Vec::new());
+    };
+
+    let mut request = QueryRequest::new(sql);
+    request.database = database.map(str::to_string);
+    let result = conn.execute(&request)?;
+
+    Ok(collect_search_hits(
+        dialect,
+        &table_ref,
+        &result,
+        &text_columns,
+        &key_columns,
+        |value| matcher.is_match(value),
+    ))
+}
+
+impl EventEmitter<DataSearchEvent> for DataSearchModal {}
+
+impl Render for DataSearchModal {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let theme = cx.theme().clone();
+        let running = self.is_running();
+
+        let mode_chips = DataSearchMode::ALL.iter().enumerate().map(|(ix, &mode)| {
+            let active = self.mode == mode;
+            div()
+                .id(("data-search-mode", ix))
+                .px(Spacing::XS)
+                .rounded(Radii::SM)
+                .text_size(FontSizes::XS)
+                .cursor_pointer()
+                .when(active, |d| {
+                    d.bg(theme.accent.opacity(0.15))
+                        .text_color(theme.foreground)
+                })
+                .when(!active, |d| {
+                    d.text_color(theme.muted_foreground)
+                        .hover(|d| d.bg(theme.secondary))
+                })
+                .child(mode.label())
+                .on_click(cx.listener(move |this, _, _, cx| {
+                    this.mode = mode;
+                    cx.notify();
+                }))
+        });
+
+        let status = match &self.run {
+            Some(run) if !run.finished => Some(format!(
+                "Searching {} of {} tables — {} rows found",
+                run.searched.min(run.total - 1) + 1,
+                run.total,
+                self.hits.len()
+            )),
+            _ => self.message.clone(),
+        };
+
+        let hit_rows = self.hits.iter().enumerate().map(|(ix, hit)| {
+            let table = match &hit.table.schema {
+                Some(schema) => format!("{}.{}", schema, hit.table.name),
+                None => hit.table.name.clone(),
+            };
+
+            div()
+                .id(("data-search-hit", ix))
+                .flex()
+                .items_center()
+                .gap(Spacing::SM)
+                .px(Spacing::XS)
+                .rounded(Radii::SM)
+                .cursor_pointer()
+                .hover(|d| d.bg(theme.secondary))
+                .child(Text::label_sm(format!("{} · {}", table, hit.column)))
+                .child(
+                    div()
+                        .flex_1()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .child(Text::caption(hit.value.clone()).muted_foreground()),
+                )
+                .on_click(cx.listener(move |this, _, _, cx| this.open_hit(ix, cx)))
+        });
+
+        let body = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::SM)
+            .child(Input::new(&self.input))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::XS)
+                    .children(mode_chips),
+            )
+            .when_some(status, |el, status| el.child(Text::caption(status)))
+            .child(
+                div()
+                    .id("data-search-hits")
+                    .max_h(px(320.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .children(hit_rows),
+            );
+
+        let on_search = cx.listener(|this, _: &ClickEvent, _, cx| {
+            if this.is_running() {
+                this.stop(cx);
+            } else {
+                this.start(cx);
+            }
+        });
+        let on_close = cx.listener(|this, _: &ClickEvent, _, cx| this.close(cx));
+
+        let footer = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(Button::new("data-search-close", "Close").on_click(on_close))
+            .child(
+                Button::new("data-search-run", if running { "Stop" } else { "Search" })
+                    .primary()
+                    .on_click(on_search),
+            );
+
+        let close_for_x = cx.entity().clone();
+
+        ModalShell::new(
+            "Search data",
+            body.into_any_element(),
+            footer.into_any_element(),
+        )
+        .width(px(560.0))
+        .on_close(move |_window, cx| {
+            close_for_x.update(cx, |this, cx| this.close(cx));
+        })
+        .into_any_element()
+    }
+}
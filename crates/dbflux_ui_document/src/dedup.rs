@@ -80,6 +80,10 @@ pub enum DocumentKey {
     /// The synthesized read-only "Instance Overview" dashboard opened from the
     /// sidebar leaf. Deduplicated by `profile_id` — one per connection.
     InstanceOverview { profile_id: Uuid },
+
+    /// The users and roles administration document. Deduplicated by
+    /// `profile_id` — one per connection.
+    UserAdmin { profile_id: Uuid },
 }
 
 #[cfg(test)]
@@ -53,4 +53,29 @@ pub enum DocumentEvent {
         profile_id: uuid::Uuid,
         sql: String,
     },
+    /// A grid foreign-key action asked for a table to be opened (or focused)
+    /// with its WHERE filter replaced by `filter`.
+    OpenTableFiltered {
+        profile_id: uuid::Uuid,
+        database: Option<String>,
+        table: dbflux_core::TableRef,
+        filter: String,
+    },
+    /// The document needs a connection to `profile_id` that is not open yet
+    /// (e.g. to re-run a history entry in its original context).
+    RequestConnect {
+        profile_id: uuid::Uuid,
+    },
+    /// A query or script run finished (successfully or not). The workspace
+    /// uses it to notify about long runs that finished out of view.
+    QueryCompleted(QueryCompletion),
+}
+
+/// Outcome of a finished query or script run.
+#[derive(Clone, Debug)]
+pub struct QueryCompletion {
+    pub elapsed: std::time::Duration,
+    /// Rows returned or affected; `None` for scripts and failed runs.
+    pub row_count: Option<u64>,
+    pub error: Option<String>,
 }
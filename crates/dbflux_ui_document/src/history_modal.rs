@@ -1,3 +1,4 @@
+use crate::chrome::ToolbarButton;
 use dbflux_app::keymap::ContextId;
 use dbflux_components::actions::{
     Cancel, Delete, Execute, FocusSearch, Rename, SaveQuery, SelectNext, SelectPrev, ToggleFavorite,
@@ -7,7 +8,10 @@ use dbflux_components::helpers::text_color_for_active;
 use dbflux_components::icons::AppIcon;
 use dbflux_components::primitives::{Icon, Text, overlay_bg, surface_modal_container};
 use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
-use dbflux_core::{HistoryEntry, SavedQuery};
+use dbflux_core::{
+    HistoryEntry, HistoryFilter, SavedQuery, SavedQueryFolder, SavedQueryParam, SavedQuerySearch,
+    format_saved_query_params, parse_saved_query_params, saved_query_folder_path,
+};
 use dbflux_ui_base::toast::{Toast, now_hms};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
@@ -22,6 +26,13 @@ pub struct HistoryQuerySelected {
     pub saved_query_id: Option<Uuid>,
 }
 
+/// Re-run a history entry in the connection, database and schema it was
+/// originally executed against.
+#[derive(Clone)]
+pub struct HistoryRerunRequested {
+    pub entry: HistoryEntry,
+}
+
 #[derive(Clone)]
 pub struct HistoryModalClosed;
 
@@ -41,18 +52,95 @@ pub enum HistoryTab {
 
 enum ModalMode {
     Browse,
-    Save { sql: String },
+    Save {
+        sql: String,
+    },
+    /// Editing the folder, tags and parameters of a saved query.
+    Organize {
+        id: Uuid,
+    },
+}
+
+/// History entries fetched per page; more are loaded as the selection or the
+/// "Load more" row reaches the end of the list.
+const HISTORY_PAGE_SIZE: usize = 50;
+
+/// Time window for the Recent tab, cycled from the filter bar.
+#[derive(Default, Clone, Copy, PartialEq)]
+enum HistoryPeriod {
+    #[default]
+    AllTime,
+    Day,
+    Week,
+    Month,
+}
+
+impl HistoryPeriod {
+    fn next(self) -> Self {
+        match self {
+            Self::AllTime => Self::Day,
+            Self::Day => Self::Week,
+            Self::Week => Self::Month,
+            Self::Month => Self::AllTime,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::AllTime => "Any time",
+            Self::Day => "Last 24 hours",
+            Self::Week => "Last 7 days",
+            Self::Month => "Last 30 days",
+        }
+    }
+
+    fn since(self, now: i64) -> Option<i64> {
+        const DAY: i64 = 24 * 60 * 60;
+
+        match self {
+            Self::AllTime => None,
+            Self::Day => Some(now - DAY),
+            Self::Week => Some(now - 7 * DAY),
+            Self::Month => Some(now - 30 * DAY),
+        }
+    }
+}
+
+/// Which executions the Recent tab shows, relative to the owning document's
+/// connection.
+#[derive(Default, Clone, Copy, PartialEq)]
+enum HistoryScope {
+    #[default]
+    AllConnections,
+    Connection,
+    Database,
+}
+
+impl HistoryScope {
+    fn label(self) -> &'static str {
+        match self {
+            Self::AllConnections => "All connections",
+            Self::Connection => "This connection",
+            Self::Database => "This database",
+        }
+    }
 }
 
 // Type aliases for the callback closure signatures used in HistoryModalCallbacks.
 // These keep the struct field types short enough to satisfy the type_complexity lint.
-type HistoryProviderFn = Box<dyn Fn(&App) -> Vec<HistoryEntry>>;
+type HistoryPageFn = Box<dyn Fn(&HistoryFilter, usize, usize, &App) -> Vec<HistoryEntry>>;
+type HistoryCountFn = Box<dyn Fn(&HistoryFilter, &App) -> usize>;
 type SavedProviderFn = Box<dyn Fn(&App) -> Vec<SavedQuery>>;
 type OnSaveFn = Box<dyn Fn(SavedQuery, &mut App)>;
 type OnRenameFn = Box<dyn Fn(Uuid, String, String, &mut App)>;
 type OnDeleteFn = Box<dyn Fn(Uuid, &mut App)>;
 type OnToggleFavoriteFn = Box<dyn Fn(Uuid, &mut App)>;
 type OnMarkUsedFn = Box<dyn Fn(Uuid, &mut App)>;
+type OnToggleHistoryPinFn = Box<dyn Fn(Uuid, &mut App) -> bool>;
+type FoldersProviderFn = Box<dyn Fn(&App) -> Vec<SavedQueryFolder>>;
+type ResolveFolderFn = Box<dyn Fn(&str, &mut App) -> Option<Uuid>>;
+type OnOrganizeFn = Box<dyn Fn(Uuid, Option<Uuid>, Vec<String>, Vec<SavedQueryParam>, &mut App)>;
+type DriverNamesFn = Box<dyn Fn(Uuid, &App) -> Vec<String>>;
 
 /// Injected callbacks that give `HistoryModal` read and write access to the
 /// owner's `AppStateEntity` without holding a direct entity reference.
@@ -60,8 +148,10 @@ type OnMarkUsedFn = Box<dyn Fn(Uuid, &mut App)>;
 /// This decouples `HistoryModal` from `AppStateEntity` so it can move into
 /// `dbflux_ui_document` in Step 3b without dragging the full app-state seam.
 pub struct HistoryModalCallbacks {
-    /// Returns a snapshot of the current query history.
-    pub history_provider: HistoryProviderFn,
+    /// Returns one page of query history (filter, offset, limit), newest first.
+    pub history_page: HistoryPageFn,
+    /// Counts the query history entries matching a filter.
+    pub history_count: HistoryCountFn,
     /// Returns a snapshot of the current saved queries.
     pub saved_provider: SavedProviderFn,
     /// Persists a new saved query.
@@ -74,6 +164,18 @@ pub struct HistoryModalCallbacks {
     pub on_toggle_favorite: OnToggleFavoriteFn,
     /// Records that a saved query was used (updates last-used timestamp).
     pub on_mark_used: OnMarkUsedFn,
+    /// Toggles the pinned flag on a history entry and returns the new state.
+    pub on_toggle_history_pin: OnToggleHistoryPinFn,
+    /// Returns a snapshot of the saved-query folders.
+    pub folders_provider: FoldersProviderFn,
+    /// Resolves a `Parent/Child` folder path, creating missing folders.
+    pub resolve_folder: ResolveFolderFn,
+    /// Moves a saved query to a folder and replaces its tags and parameter
+    /// declarations.
+    pub on_organize: OnOrganizeFn,
+    /// Names the driver of a connection profile is known by, for `driver:`
+    /// search filters.
+    pub driver_names: DriverNamesFn,
 }
 
 pub struct HistoryModal {
@@ -87,6 +189,20 @@ pub struct HistoryModal {
     editing_id: Option<Uuid>,
     mode: ModalMode,
     save_name_input: Entity<InputState>,
+    folder_input: Entity<InputState>,
+    tags_input: Entity<InputState>,
+    params_input: Entity<InputState>,
+    list_scroll: ScrollHandle,
+    /// Loaded pages of the Recent tab for the current search and filters.
+    history_entries: Vec<HistoryEntry>,
+    history_total: usize,
+    history_status: Option<bool>,
+    history_period: HistoryPeriod,
+    history_scope: HistoryScope,
+    /// Connection profile and database of the owning document, used by the
+    /// connection and database scopes.
+    connection_profile_id: Option<Uuid>,
+    database: Option<String>,
 }
 
 impl HistoryModal {
@@ -98,6 +214,15 @@ impl HistoryModal {
         let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Search..."));
         let rename_input = cx.new(|cx| InputState::new(window, cx));
         let save_name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Query name"));
+        let folder_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder("Folder, e.g. Reports/Monthly (optional)")
+        });
+        let tags_input = cx
+            .new(|cx| InputState::new(window, cx).placeholder("Tags, comma separated (optional)"));
+        let params_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Parameters, e.g. id:integer=1, since:date (optional)")
+        });
 
         cx.subscribe_in(
             &search_input,
@@ -105,6 +230,10 @@ impl HistoryModal {
             |this, entity, event: &InputEvent, _, cx| {
                 if let InputEvent::Change = event {
                     this.search_query = entity.read(cx).value().to_string();
+                    this.selected_index = Some(0);
+                    if this.active_tab == HistoryTab::Recent {
+                        this.reload_history(cx);
+                    }
                     cx.notify();
                 }
             },
@@ -122,16 +251,18 @@ impl HistoryModal {
         )
         .detach();
 
-        cx.subscribe_in(
-            &save_name_input,
-            window,
-            |this, _entity, event: &InputEvent, window, cx| {
-                if let InputEvent::PressEnter { .. } = event {
-                    this.confirm_save(window, cx);
-                }
-            },
-        )
-        .detach();
+        for input in [&save_name_input, &folder_input, &tags_input, &params_input] {
+            cx.subscribe_in(
+                input,
+                window,
+                |this, _entity, event: &InputEvent, window, cx| {
+                    if let InputEvent::PressEnter { .. } = event {
+                        this.execute_selected(window, cx);
+                    }
+                },
+            )
+            .detach();
+        }
 
         Self {
             callbacks,
@@ -144,6 +275,32 @@ impl HistoryModal {
             editing_id: None,
             mode: ModalMode::Browse,
             save_name_input,
+            folder_input,
+            tags_input,
+            params_input,
+            list_scroll: ScrollHandle::new(),
+            history_entries: Vec::new(),
+            history_total: 0,
+            history_status: None,
+            history_period: HistoryPeriod::default(),
+            history_scope: HistoryScope::default(),
+            connection_profile_id: None,
+            database: None,
+        }
+    }
+
+    /// Updates the connection the scope filter refers to. Falls back to all
+    /// connections when the document no longer has the scoped target.
+    pub fn set_connection_context(
+        &mut self,
+        connection_profile_id: Option<Uuid>,
+        database: Option<String>,
+    ) {
+        self.connection_profile_id = connection_profile_id;
+        self.database = database;
+
+        if !self.scope_available(self.history_scope) {
+            self.history_scope = HistoryScope::AllConnections;
         }
     }
 
@@ -155,7 +312,10 @@ impl HistoryModal {
     /// (save mode or renaming). In this case, navigation keys should not be processed.
     #[allow(dead_code)]
     pub fn is_input_mode(&self) -> bool {
-        matches!(self.mode, ModalMode::Save { .. }) || self.editing_id.is_some()
+        matches!(
+            self.mode,
+            ModalMode::Save { .. } | ModalMode::Organize { .. }
+        ) || self.editing_id.is_some()
     }
 
     pub fn open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
@@ -169,6 +329,7 @@ impl HistoryModal {
         });
         self.search_query.clear();
         self.editing_id = None;
+        self.reload_history(cx);
         cx.notify();
     }
 
@@ -190,11 +351,21 @@ impl HistoryModal {
     pub fn open_save(&mut self, sql: String, window: &mut Window, cx: &mut Context<Self>) {
         self.visible = true;
         self.mode = ModalMode::Save { sql };
+        self.reset_save_form(window, cx);
+        cx.notify();
+    }
+
+    fn reset_save_form(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.folder_input
+            .update(cx, |state, cx| state.set_value("", window, cx));
+        self.tags_input
+            .update(cx, |state, cx| state.set_value("", window, cx));
+        self.params_input
+            .update(cx, |state, cx| state.set_value("", window, cx));
         self.save_name_input.update(cx, |state, cx| {
             state.set_value("", window, cx);
             state.focus(window, cx);
         });
-        cx.notify();
     }
 
     pub fn close(&mut self, cx: &mut Context<Self>) {
@@ -218,7 +389,13 @@ impl HistoryModal {
             Some(idx) => (idx + 1).min(count.saturating_sub(1)),
             None => 0,
         };
+
+        if self.active_tab == HistoryTab::Recent && next + 1 == count {
+            self.load_more_history(cx);
+        }
+
         self.selected_index = Some(next);
+        self.list_scroll.scroll_to_item(next);
         cx.notify();
     }
 
@@ -233,6 +410,7 @@ impl HistoryModal {
             None => count.saturating_sub(1),
         };
         self.selected_index = Some(prev);
+        self.list_scroll.scroll_to_item(prev);
         cx.notify();
     }
 
@@ -244,13 +422,11 @@ impl HistoryModal {
                 };
 
                 let (sql, name, saved_query_id) = match self.active_tab {
-                    HistoryTab::Recent => {
-                        let entries = self.filtered_history_entries(cx);
-                        entries
-                            .get(idx)
-                            .map(|e| (e.sql.clone(), None, None))
-                            .unwrap_or_default()
-                    }
+                    HistoryTab::Recent => self
+                        .history_entries
+                        .get(idx)
+                        .map(|e| (e.sql.clone(), None, None))
+                        .unwrap_or_default(),
                     HistoryTab::Saved => {
                         let entries = self.filtered_saved_queries(cx);
                         if let Some(entry) = entries.get(idx) {
@@ -276,9 +452,33 @@ impl HistoryModal {
             ModalMode::Save { .. } => {
                 self.confirm_save(window, cx);
             }
+            ModalMode::Organize { id } => {
+                self.confirm_organize(id, window, cx);
+            }
         }
     }
 
+    /// Asks the owner to re-run the selected Recent entry in its original
+    /// context instead of just loading its SQL.
+    pub fn rerun_selected(&mut self, cx: &mut Context<Self>) {
+        if !matches!(self.mode, ModalMode::Browse) || self.active_tab != HistoryTab::Recent {
+            return;
+        }
+
+        if let Some(idx) = self.selected_index {
+            self.rerun_history_entry(idx, cx);
+        }
+    }
+
+    fn rerun_history_entry(&mut self, idx: usize, cx: &mut Context<Self>) {
+        let Some(entry) = self.history_entries.get(idx).cloned() else {
+            return;
+        };
+
+        cx.emit(HistoryRerunRequested { entry });
+        self.close(cx);
+    }
+
     pub fn delete_selected(&mut self, cx: &mut Context<Self>) {
         if !matches!(self.mode, ModalMode::Browse) || self.active_tab != HistoryTab::Saved {
             return;
@@ -303,8 +503,17 @@ impl HistoryModal {
         }
     }
 
+    /// Toggles the favorite flag of the selected saved query, or the pin of
+    /// the selected history entry on the Recent tab.
     pub fn toggle_favorite_selected(&mut self, cx: &mut Context<Self>) {
-        if !matches!(self.mode, ModalMode::Browse) || self.active_tab != HistoryTab::Saved {
+        if !matches!(self.mode, ModalMode::Browse) {
+            return;
+        }
+
+        if self.active_tab == HistoryTab::Recent {
+            if let Some(idx) = self.selected_index {
+                self.toggle_history_pin(idx, cx);
+            }
             return;
         }
 
@@ -319,6 +528,27 @@ impl HistoryModal {
         }
     }
 
+    fn toggle_history_pin(&mut self, idx: usize, cx: &mut Context<Self>) {
+        let Some(id) = self.history_entries.get(idx).map(|entry| entry.id) else {
+            return;
+        };
+
+        let pinned = (self.callbacks.on_toggle_history_pin)(id, cx);
+        if let Some(entry) = self.history_entries.get_mut(idx) {
+            entry.is_pinned = pinned;
+        }
+        cx.notify();
+    }
+
+    /// `Rename` re-runs on the Recent tab, where entries have no name.
+    pub fn rename_or_rerun_selected(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.active_tab == HistoryTab::Recent {
+            self.rerun_selected(cx);
+        } else {
+            self.start_rename_selected(window, cx);
+        }
+    }
+
     pub fn start_rename_selected(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if !matches!(self.mode, ModalMode::Browse) || self.active_tab != HistoryTab::Saved {
             return;
@@ -339,6 +569,51 @@ impl HistoryModal {
         }
     }
 
+    /// Opens the folder, tags and parameters form for the selected saved query.
+    pub fn start_organize_selected(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !matches!(self.mode, ModalMode::Browse) || self.active_tab != HistoryTab::Saved {
+            return;
+        }
+
+        let Some(idx) = self.selected_index else {
+            return;
+        };
+
+        if let Some(entry) = self.filtered_saved_queries(cx).get(idx) {
+            self.start_organize(entry.id, window, cx);
+        }
+    }
+
+    fn start_organize(&mut self, id: Uuid, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(entry) = (self.callbacks.saved_provider)(cx)
+            .into_iter()
+            .find(|q| q.id == id)
+        else {
+            return;
+        };
+
+        let folders = (self.callbacks.folders_provider)(cx);
+        let folder_path = entry
+            .folder_id
+            .and_then(|folder_id| saved_query_folder_path(&folders, folder_id))
+            .map(|path| path.replace(" / ", "/"))
+            .unwrap_or_default();
+        let tags = entry.tags.join(", ");
+        let params = format_saved_query_params(&entry.params);
+
+        self.editing_id = None;
+        self.mode = ModalMode::Organize { id };
+        self.tags_input
+            .update(cx, |state, cx| state.set_value(&tags, window, cx));
+        self.params_input
+            .update(cx, |state, cx| state.set_value(&params, window, cx));
+        self.folder_input.update(cx, |state, cx| {
+            state.set_value(&folder_path, window, cx);
+            state.focus(window, cx);
+        });
+        cx.notify();
+    }
+
     pub fn focus_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if !matches!(self.mode, ModalMode::Browse) {
             return;
@@ -353,18 +628,14 @@ impl HistoryModal {
             return;
         }
 
-        let entries = self.filtered_history_entries(cx);
         let Some(idx) = self.selected_index else {
             return;
         };
 
-        if let Some(entry) = entries.get(idx) {
+        if let Some(entry) = self.history_entries.get(idx) {
             let sql = entry.sql.clone();
             self.mode = ModalMode::Save { sql };
-            self.save_name_input.update(cx, |state, cx| {
-                state.set_value("", window, cx);
-                state.focus(window, cx);
-            });
+            self.reset_save_form(window, cx);
             cx.notify();
         }
     }
@@ -408,8 +679,17 @@ impl HistoryModal {
             return;
         }
 
+        let Some(params) = self.read_params_input(cx) else {
+            return;
+        };
+
         let name = name.to_string();
-        let query = SavedQuery::new(name.clone(), sql.clone(), None);
+        let sql = sql.clone();
+        let (folder_id, tags) = self.read_organize_inputs(cx);
+        let query = SavedQuery::new(name.clone(), sql, None)
+            .with_folder(folder_id)
+            .with_tags(tags)
+            .with_params(params);
         let id = query.id;
         (self.callbacks.on_save)(query, cx);
         cx.emit(QuerySaved { id, name });
@@ -417,21 +697,156 @@ impl HistoryModal {
         self.close(cx);
     }
 
+    fn confirm_organize(&mut self, id: Uuid, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(params) = self.read_params_input(cx) else {
+            return;
+        };
+
+        let (folder_id, tags) = self.read_organize_inputs(cx);
+        (self.callbacks.on_organize)(id, folder_id, tags, params, cx);
+
+        self.mode = ModalMode::Browse;
+        self.search_input
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    /// Reads the folder and tags inputs, creating the folder path if needed.
+    fn read_organize_inputs(&self, cx: &mut Context<Self>) -> (Option<Uuid>, Vec<String>) {
+        let folder_path = self.folder_input.read(cx).value().to_string();
+        let tags = self
+            .tags_input
+            .read(cx)
+            .value()
+            .split([',', ' '])
+            .map(str::to_string)
+            .collect();
+
+        ((self.callbacks.resolve_folder)(&folder_path, cx), tags)
+    }
+
+    /// Parses the parameter declarations, warning and returning `None` when
+    /// they are invalid so the form stays open.
+    fn read_params_input(&self, cx: &mut Context<Self>) -> Option<Vec<SavedQueryParam>> {
+        let input = self.params_input.read(cx).value().to_string();
+
+        match parse_saved_query_params(&input) {
+            Ok(params) => Some(params),
+            Err(error) => {
+                Toast::warning(error.to_string())
+                    .meta_right(now_hms())
+                    .push(cx);
+                None
+            }
+        }
+    }
+
     fn current_list_count(&self, cx: &Context<Self>) -> usize {
         match self.active_tab {
-            HistoryTab::Recent => self.filtered_history_entries(cx).len(),
+            HistoryTab::Recent => self.history_entries.len(),
             HistoryTab::Saved => self.filtered_saved_queries(cx).len(),
         }
     }
 
-    fn filtered_history_entries(&self, cx: &Context<Self>) -> Vec<HistoryEntry> {
-        let entries = (self.callbacks.history_provider)(cx);
-        filter_history_entries(entries.as_slice(), &self.search_query, 50)
+    fn scope_available(&self, scope: HistoryScope) -> bool {
+        match scope {
+            HistoryScope::AllConnections => true,
+            HistoryScope::Connection => self.connection_profile_id.is_some(),
+            HistoryScope::Database => {
+                self.connection_profile_id.is_some() && self.database.is_some()
+            }
+        }
+    }
+
+    fn history_filter(&self) -> HistoryFilter {
+        let scoped_profile = match self.history_scope {
+            HistoryScope::AllConnections => None,
+            HistoryScope::Connection | HistoryScope::Database => self.connection_profile_id,
+        };
+        let scoped_database = match self.history_scope {
+            HistoryScope::Database => self.database.clone(),
+            HistoryScope::AllConnections | HistoryScope::Connection => None,
+        };
+
+        HistoryFilter {
+            text: self.search_query.clone(),
+            connection_profile_id: scoped_profile,
+            database: scoped_database,
+            since: self
+                .history_period
+                .since(dbflux_core::chrono::Utc::now().timestamp()),
+            until: None,
+            succeeded: self.history_status,
+        }
+    }
+
+    /// Re-queries the first page of the Recent tab for the current search and
+    /// filters.
+    fn reload_history(&mut self, cx: &mut Context<Self>) {
+        let filter = self.history_filter();
+        self.history_entries = (self.callbacks.history_page)(&filter, 0, HISTORY_PAGE_SIZE, cx);
+        self.history_total = (self.callbacks.history_count)(&filter, cx);
+        self.list_scroll.scroll_to_item(0);
+    }
+
+    fn load_more_history(&mut self, cx: &mut Context<Self>) {
+        if self.history_entries.len() >= self.history_total {
+            return;
+        }
+
+        let filter = self.history_filter();
+        let page = (self.callbacks.history_page)(
+            &filter,
+            self.history_entries.len(),
+            HISTORY_PAGE_SIZE,
+            cx,
+        );
+        self.history_entries.extend(page);
+    }
+
+    fn cycle_history_status(&mut self, cx: &mut Context<Self>) {
+        self.history_status = match self.history_status {
+            None => Some(true),
+            Some(true) => Some(false),
+            Some(false) => None,
+        };
+        self.selected_index = Some(0);
+        self.reload_history(cx);
+        cx.notify();
+    }
+
+    fn cycle_history_period(&mut self, cx: &mut Context<Self>) {
+        self.history_period = self.history_period.next();
+        self.selected_index = Some(0);
+        self.reload_history(cx);
+        cx.notify();
+    }
+
+    fn cycle_history_scope(&mut self, cx: &mut Context<Self>) {
+        let mut scope = self.history_scope;
+        loop {
+            scope = match scope {
+                HistoryScope::AllConnections => HistoryScope::Connection,
+                HistoryScope::Connection => HistoryScope::Database,
+                HistoryScope::Database => HistoryScope::AllConnections,
+            };
+            if self.scope_available(scope) {
+                break;
+            }
+        }
+
+        self.history_scope = scope;
+        self.selected_index = Some(0);
+        self.reload_history(cx);
+        cx.notify();
     }
 
     fn filtered_saved_queries(&self, cx: &Context<Self>) -> Vec<SavedQuery> {
         let queries = (self.callbacks.saved_provider)(cx);
-        filter_saved_queries(queries.as_slice(), &self.search_query)
+        let folders = (self.callbacks.folders_provider)(cx);
+        filter_saved_queries(queries.as_slice(), &folders, &self.search_query, |id| {
+            (self.callbacks.driver_names)(id, cx)
+        })
     }
 
     fn render_browse(&self, _window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
@@ -472,7 +887,7 @@ impl HistoryModal {
                 this.toggle_favorite_selected(cx);
             }))
             .on_action(cx.listener(|this, _: &Rename, window, cx| {
-                this.start_rename_selected(window, cx);
+                this.rename_or_rerun_selected(window, cx);
             }))
             .on_action(cx.listener(|this, _: &FocusSearch, window, cx| {
                 this.focus_search(window, cx);
@@ -502,7 +917,10 @@ impl HistoryModal {
                             .border_b_1()
                             .border_color(theme.border)
                             .child(self.render_tabs(cx))
-                            .child(Input::new(&search_input).small().cleanable(true)),
+                            .child(Input::new(&search_input).small().cleanable(true))
+                            .when(self.active_tab == HistoryTab::Recent, |d| {
+                                d.child(self.render_history_filters(cx))
+                            }),
                     )
                     .child(self.render_list(&rename_input, selected, cx))
                     .child(self.render_footer(cx)),
@@ -548,6 +966,7 @@ impl HistoryModal {
                         cx.listener(|this, _, _, cx| {
                             this.active_tab = HistoryTab::Recent;
                             this.selected_index = Some(0);
+                            this.reload_history(cx);
                             cx.notify();
                         }),
                     ),
@@ -590,6 +1009,45 @@ impl HistoryModal {
             )
     }
 
+    fn render_history_filters(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let status_label = match self.history_status {
+            None => "Any status",
+            Some(true) => "Succeeded",
+            Some(false) => "Failed",
+        };
+        let has_connection = self.connection_profile_id.is_some();
+
+        div()
+            .flex()
+            .items_center()
+            .gap(Spacing::XS)
+            .child(
+                ToolbarButton::new("history-filter-status")
+                    .label(status_label)
+                    .tooltip("Filter by execution status")
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.cycle_history_status(cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("history-filter-period")
+                    .label(self.history_period.label())
+                    .tooltip("Filter by execution time")
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.cycle_history_period(cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("history-filter-scope")
+                    .label(self.history_scope.label())
+                    .tooltip("Filter by this document's connection or database")
+                    .disabled(!has_connection)
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.cycle_history_scope(cx);
+                    })),
+            )
+    }
+
     fn render_list(
         &self,
         rename_input: &Entity<InputState>,
@@ -600,13 +1058,23 @@ impl HistoryModal {
 
         match self.active_tab {
             HistoryTab::Recent => {
-                let entries = self.filtered_history_entries(cx);
+                let entries = &self.history_entries;
+                let has_more = entries.len() < self.history_total;
+                let empty_label = if self.history_filter().is_empty() {
+                    "No history yet"
+                } else {
+                    "No matching history"
+                };
+
                 div()
+                    .id("history-list")
                     .flex_1()
-                    .overflow_y_hidden()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.list_scroll)
                     .children(entries.iter().enumerate().map(|(idx, entry)| {
                         let is_selected = idx == selected;
                         let sql = entry.sql.clone();
+                        let is_pinned = entry.is_pinned;
 
                         div()
                             .id(("history-entry", idx))
@@ -629,9 +1097,61 @@ impl HistoryModal {
                             }))
                             .child(
                                 div()
-                                    .overflow_hidden()
-                                    .text_ellipsis()
-                                    .child(Text::body(entry.sql_preview(60))),
+                                    .flex()
+                                    .items_center()
+                                    .justify_between()
+                                    .gap(Spacing::SM)
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .overflow_hidden()
+                                            .text_ellipsis()
+                                            .child(Text::body(entry.sql_preview(60))),
+                                    )
+                                    .child(
+                                        div()
+                                            .id(("history-rerun", idx))
+                                            .w(Heights::ICON_SM)
+                                            .h(Heights::ICON_SM)
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .rounded(Radii::SM)
+                                            .hover(|d| d.bg(theme.secondary))
+                                            .on_click(cx.listener(move |this, _, _, cx| {
+                                                cx.stop_propagation();
+                                                this.rerun_history_entry(idx, cx);
+                                            }))
+                                            .child(
+                                                Icon::new(AppIcon::Play)
+                                                    .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                                                    .color(theme.muted_foreground),
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .id(("history-pin", idx))
+                                            .w(Heights::ICON_SM)
+                                            .h(Heights::ICON_SM)
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .rounded(Radii::SM)
+                                            .hover(|d| d.bg(theme.secondary))
+                                            .on_click(cx.listener(move |this, _, _, cx| {
+                                                cx.stop_propagation();
+                                                this.toggle_history_pin(idx, cx);
+                                            }))
+                                            .child(
+                                                Icon::new(AppIcon::Pin)
+                                                    .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                                                    .color(if is_pinned {
+                                                        theme.warning
+                                                    } else {
+                                                        theme.muted_foreground
+                                                    }),
+                                            ),
+                                    ),
                             )
                             .child(
                                 div()
@@ -642,6 +1162,18 @@ impl HistoryModal {
                                         Text::caption(entry.formatted_timestamp())
                                             .font_size(FontSizes::XS),
                                     )
+                                    .when(entry.run_count > 1, |d| {
+                                        d.child(
+                                            Text::caption(format!("×{}", entry.run_count))
+                                                .font_size(FontSizes::XS),
+                                        )
+                                    })
+                                    .when_some(entry.connection_name.clone(), |d, name| {
+                                        d.child(Text::caption(name).font_size(FontSizes::XS))
+                                    })
+                                    .when_some(entry.database.clone(), |d, database| {
+                                        d.child(Text::caption(database).font_size(FontSizes::XS))
+                                    })
                                     .when_some(entry.row_count, |d, count| {
                                         d.child(
                                             Text::caption(format!("{} rows", count))
@@ -651,22 +1183,46 @@ impl HistoryModal {
                                     .child(
                                         Text::caption(format!("{}ms", entry.execution_time_ms))
                                             .font_size(FontSizes::XS),
-                                    ),
+                                    )
+                                    .when(!entry.succeeded, |d| {
+                                        d.child(
+                                            Text::caption("Failed")
+                                                .font_size(FontSizes::XS)
+                                                .color(theme.danger),
+                                        )
+                                    }),
                             )
                     }))
+                    .when(has_more, |d| {
+                        d.child(
+                            div()
+                                .id("history-load-more")
+                                .px(Spacing::SM)
+                                .py(Spacing::XS)
+                                .text_center()
+                                .cursor_pointer()
+                                .hover(|d| d.bg(theme.secondary))
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.load_more_history(cx);
+                                    cx.notify();
+                                }))
+                                .child(Text::caption("Load more")),
+                        )
+                    })
                     .when(entries.is_empty(), |d| {
                         d.child(
                             div()
                                 .px(Spacing::SM)
                                 .py(Spacing::LG)
                                 .text_center()
-                                .child(Text::muted("No history yet")),
+                                .child(Text::muted(empty_label)),
                         )
                     })
                     .into_any_element()
             }
             HistoryTab::Saved => {
                 let entries = self.filtered_saved_queries(cx);
+                let folders = (self.callbacks.folders_provider)(cx);
                 let rename_input = rename_input.clone();
 
                 div()
@@ -680,6 +1236,25 @@ impl HistoryModal {
                         let is_favorite = entry.is_favorite;
                         let is_editing = self.editing_id == Some(id);
                         let rename_input = rename_input.clone();
+                        let folder_path = entry
+                            .folder_id
+                            .and_then(|folder_id| saved_query_folder_path(&folders, folder_id));
+                        let tags_label = (!entry.tags.is_empty()).then(|| {
+                            entry
+                                .tags
+                                .iter()
+                                .map(|tag| format!("#{}", tag))
+                                .collect::<Vec<_>>()
+                                .join(" ")
+                        });
+                        let params_label = (!entry.params.is_empty()).then(|| {
+                            entry
+                                .params
+                                .iter()
+                                .map(|param| format!(":{}", param.name))
+                                .collect::<Vec<_>>()
+                                .join(" ")
+                        });
 
                         div()
                             .id(("saved-query", idx))
@@ -721,42 +1296,91 @@ impl HistoryModal {
                                             }),
                                     )
                                     .child(
-                                        div().flex().items_center().gap(Spacing::XS).child(
-                                            div()
-                                                .id(SharedString::from(format!("favorite-{}", id)))
-                                                .w(Heights::ICON_SM)
-                                                .h(Heights::ICON_SM)
-                                                .flex()
-                                                .items_center()
-                                                .justify_center()
-                                                .rounded(Radii::SM)
-                                                .hover(|d| d.bg(theme.secondary))
-                                                .on_click(cx.listener(move |this, _, _, cx| {
-                                                    (this.callbacks.on_toggle_favorite)(id, cx);
-                                                    cx.notify();
-                                                }))
-                                                .child(
-                                                    Text::body(if is_favorite {
-                                                        "★"
-                                                    } else {
-                                                        "☆"
-                                                    })
-                                                    .font_size(FontSizes::SM)
-                                                    .color(if is_favorite {
-                                                        cx.theme().warning
-                                                    } else {
-                                                        theme.muted_foreground
-                                                    }),
-                                                ),
-                                        ),
+                                        div()
+                                            .flex()
+                                            .items_center()
+                                            .gap(Spacing::XS)
+                                            .child(
+                                                div()
+                                                    .id(SharedString::from(format!(
+                                                        "organize-{}",
+                                                        id
+                                                    )))
+                                                    .w(Heights::ICON_SM)
+                                                    .h(Heights::ICON_SM)
+                                                    .flex()
+                                                    .items_center()
+                                                    .justify_center()
+                                                    .rounded(Radii::SM)
+                                                    .hover(|d| d.bg(theme.secondary))
+                                                    .on_click(cx.listener(
+                                                        move |this, _, window, cx| {
+                                                            cx.stop_propagation();
+                                                            this.start_organize(id, window, cx);
+                                                        },
+                                                    ))
+                                                    .child(
+                                                        Icon::new(AppIcon::Folder)
+                                                            .size(px(12.0)) // guardrail-allow: 12px icon size, no ICON_XS token
+                                                            .color(theme.muted_foreground),
+                                                    ),
+                                            )
+                                            .child(
+                                                div()
+                                                    .id(SharedString::from(format!(
+                                                        "favorite-{}",
+                                                        id
+                                                    )))
+                                                    .w(Heights::ICON_SM)
+                                                    .h(Heights::ICON_SM)
+                                                    .flex()
+                                                    .items_center()
+                                                    .justify_center()
+                                                    .rounded(Radii::SM)
+                                                    .hover(|d| d.bg(theme.secondary))
+                                                    .on_click(cx.listener(move |this, _, _, cx| {
+                                                        (this.callbacks.on_toggle_favorite)(id, cx);
+                                                        cx.notify();
+                                                    }))
+                                                    .child(
+                                                        Text::body(if is_favorite {
+                                                            "★"
+                                                        } else {
+                                                            "☆"
+                                                        })
+                                                        .font_size(FontSizes::SM)
+                                                        .color(if is_favorite {
+                                                            cx.theme().warning
+                                                        } else {
+                                                            theme.muted_foreground
+                                                        }),
+                                                    ),
+                                            ),
                                     ),
                             )
                             .child(Text::caption(entry.sql_preview(80)).font_size(FontSizes::XS))
                             .child(
-                                div().flex().items_center().gap(Spacing::SM).child(
-                                    Text::caption(entry.formatted_last_used_at())
-                                        .font_size(FontSizes::XS),
-                                ),
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap(Spacing::SM)
+                                    .child(
+                                        Text::caption(entry.formatted_last_used_at())
+                                            .font_size(FontSizes::XS),
+                                    )
+                                    .when_some(folder_path, |d, path| {
+                                        d.child(Text::caption(path).font_size(FontSizes::XS))
+                                    })
+                                    .when_some(tags_label, |d, tags| {
+                                        d.child(
+                                            Text::caption(tags)
+                                                .font_size(FontSizes::XS)
+                                                .color(theme.primary),
+                                        )
+                                    })
+                                    .when_some(params_label, |d, params| {
+                                        d.child(Text::caption(params).font_size(FontSizes::XS))
+                                    }),
                             )
                     }))
                     .when(entries.is_empty(), |d| {
@@ -775,12 +1399,21 @@ impl HistoryModal {
 
     fn render_footer(&self, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = cx.theme();
-        let count = self.current_list_count(cx);
+        let count_label = match self.active_tab {
+            HistoryTab::Recent => format!(
+                "{} of {} items",
+                self.history_entries.len(),
+                self.history_total
+            ),
+            HistoryTab::Saved => format!("{} items", self.filtered_saved_queries(cx).len()),
+        };
 
         let shortcuts = match self.active_tab {
-            HistoryTab::Recent => "C-j/k Navigate  Enter Load  C-s Save  Esc Close",
+            HistoryTab::Recent => {
+                "C-j/k Navigate  Enter Load  C-r Re-run  C-s Save  C-f Pin  Esc Close"
+            }
             HistoryTab::Saved => {
-                "C-j/k Navigate  Enter Load  C-d Delete  C-f Favorite  C-r Rename  Esc Close"
+                "C-j/k Navigate  Enter Load  C-d Delete  C-f Favorite  C-r Rename  C-e Organize  Esc Close"
             }
         };
 
@@ -793,12 +1426,38 @@ impl HistoryModal {
             .items_center()
             .justify_between()
             .child(Text::caption(shortcuts))
-            .child(Text::caption(format!("{} items", count)))
+            .child(Text::caption(count_label))
+    }
+
+    /// Leaves the organize form for the list; the save form closes the modal.
+    fn cancel_form(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let ModalMode::Organize { .. } = self.mode {
+            self.mode = ModalMode::Browse;
+            self.search_input
+                .update(cx, |state, cx| state.focus(window, cx));
+            cx.notify();
+        } else {
+            self.close(cx);
+        }
     }
 
+    /// Renders the Save form and, without the name field, the folder, tags and
+    /// parameters form of an existing saved query.
     fn render_save(&self, _window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         let theme = cx.theme();
         let input = self.save_name_input.clone();
+        let folder_input = self.folder_input.clone();
+        let tags_input = self.tags_input.clone();
+        let params_input = self.params_input.clone();
+        let is_save = matches!(self.mode, ModalMode::Save { .. });
+        let (title, hint) = if is_save {
+            ("Save Query", "Enter to save, Esc to cancel")
+        } else {
+            (
+                "Folder, Tags and Parameters",
+                "Enter to apply, Esc to go back",
+            )
+        };
 
         div()
             .id("save-query-modal")
@@ -812,15 +1471,15 @@ impl HistoryModal {
             .pt(px(120.0))
             .on_mouse_down(
                 MouseButton::Left,
-                cx.listener(|this, _, _, cx| {
-                    this.close(cx);
+                cx.listener(|this, _, window, cx| {
+                    this.cancel_form(window, cx);
                 }),
             )
             .on_action(cx.listener(|this, _: &Execute, window, cx| {
-                this.confirm_save(window, cx);
+                this.execute_selected(window, cx);
             }))
-            .on_action(cx.listener(|this, _: &Cancel, _, cx| {
-                this.close(cx);
+            .on_action(cx.listener(|this, _: &Cancel, window, cx| {
+                this.cancel_form(window, cx);
             }))
             .child(
                 surface_modal_container(cx)
@@ -837,16 +1496,26 @@ impl HistoryModal {
                             .py(Spacing::SM)
                             .border_b_1()
                             .border_color(theme.border)
-                            .child(Text::body("Save Query")),
+                            .child(Text::body(title)),
+                    )
+                    .child(
+                        div()
+                            .p(Spacing::MD)
+                            .flex()
+                            .flex_col()
+                            .gap(Spacing::SM)
+                            .when(is_save, |d| d.child(Input::new(&input).w_full()))
+                            .child(Input::new(&folder_input).w_full())
+                            .child(Input::new(&tags_input).w_full())
+                            .child(Input::new(&params_input).w_full()),
                     )
-                    .child(div().p(Spacing::MD).child(Input::new(&input).w_full()))
                     .child(
                         div()
                             .px(Spacing::MD)
                             .py(Spacing::SM)
                             .border_t_1()
                             .border_color(theme.border)
-                            .child(Text::caption("Enter to save, Esc to cancel")),
+                            .child(Text::caption(hint)),
                     ),
             )
             .into_any_element()
@@ -861,12 +1530,13 @@ impl Render for HistoryModal {
 
         match self.mode {
             ModalMode::Browse => self.render_browse(window, cx),
-            ModalMode::Save { .. } => self.render_save(window, cx),
+            ModalMode::Save { .. } | ModalMode::Organize { .. } => self.render_save(window, cx),
         }
     }
 }
 
 impl EventEmitter<HistoryQuerySelected> for HistoryModal {}
+impl EventEmitter<HistoryRerunRequested> for HistoryModal {}
 impl EventEmitter<HistoryModalClosed> for HistoryModal {}
 impl EventEmitter<QuerySaved> for HistoryModal {}
 
@@ -932,38 +1602,42 @@ mod source_contract_tests {
     }
 }
 
-fn filter_history_entries(
-    entries: &[HistoryEntry],
+/// Filters saved queries by a [`SavedQuerySearch`]: `#tag` and `driver:`
+/// tokens must all match, and the remaining text is looked up in the name,
+/// SQL and folder path.
+fn filter_saved_queries(
+    entries: &[SavedQuery],
+    folders: &[SavedQueryFolder],
     query: &str,
-    max_entries: usize,
-) -> Vec<HistoryEntry> {
-    if query.trim().is_empty() {
-        return entries.iter().take(max_entries).cloned().collect();
-    }
+    driver_names: impl Fn(Uuid) -> Vec<String>,
+) -> Vec<SavedQuery> {
+    let search = SavedQuerySearch::parse(query);
+    let text = search.text.to_lowercase();
 
-    let query_lower = query.to_lowercase();
-    entries
+    let mut filtered: Vec<SavedQuery> = entries
         .iter()
-        .filter(|entry| entry.sql.to_lowercase().contains(&query_lower))
-        .take(max_entries)
-        .cloned()
-        .collect()
-}
+        .filter(|entry| {
+            let names = if search.driver.is_some() {
+                entry.connection_id.map(&driver_names).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            let names: Vec<&str> = names.iter().map(String::as_str).collect();
 
-fn filter_saved_queries(entries: &[SavedQuery], query: &str) -> Vec<SavedQuery> {
-    let mut filtered: Vec<SavedQuery> = if query.trim().is_empty() {
-        entries.to_vec()
-    } else {
-        let query_lower = query.to_lowercase();
-        entries
-            .iter()
-            .filter(|entry| {
-                entry.name.to_lowercase().contains(&query_lower)
-                    || entry.sql.to_lowercase().contains(&query_lower)
-            })
-            .cloned()
-            .collect()
-    };
+            if !search.matches_filters(entry, &names) {
+                return false;
+            }
+
+            text.is_empty()
+                || entry.name.to_lowercase().contains(&text)
+                || entry.sql.to_lowercase().contains(&text)
+                || entry
+                    .folder_id
+                    .and_then(|id| saved_query_folder_path(folders, id))
+                    .is_some_and(|path| path.to_lowercase().contains(&text))
+        })
+        .cloned()
+        .collect();
 
     filtered.sort_by(|a, b| {
         b.is_favorite
@@ -976,47 +1650,61 @@ fn filter_saved_queries(entries: &[SavedQuery], query: &str) -> Vec<SavedQuery>
 
 #[cfg(test)]
 mod tests {
-    use super::{filter_history_entries, filter_saved_queries};
-    use dbflux_core::{HistoryEntry, SavedQuery};
-    use std::time::Duration;
+    use super::filter_saved_queries;
+    use dbflux_core::{SavedQuery, SavedQueryFolder};
+    use uuid::Uuid;
 
     #[test]
-    fn filters_history_entries_by_query() {
+    fn filters_saved_queries_by_query() {
         let entries = vec![
-            HistoryEntry::new(
-                "SELECT 1".to_string(),
-                None,
-                None,
-                Duration::from_millis(10),
-                None,
-            ),
-            HistoryEntry::new(
-                "SELECT 2".to_string(),
-                None,
-                None,
-                Duration::from_millis(10),
+            SavedQuery::new("Users".to_string(), "SELECT * FROM users".to_string(), None),
+            SavedQuery::new(
+                "Orders".to_string(),
+                "SELECT * FROM orders".to_string(),
                 None,
             ),
         ];
 
-        let filtered = filter_history_entries(&entries, "2", 10);
+        let filtered = filter_saved_queries(&entries, &[], "orders", |_| Vec::new());
         assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].sql, "SELECT 2");
+        assert_eq!(filtered[0].name, "Orders");
     }
 
     #[test]
-    fn filters_saved_queries_by_query() {
+    fn filters_saved_queries_by_tag_folder_and_driver() {
+        let reports = SavedQueryFolder::new("Reports".to_string(), None);
+        let postgres_profile = Uuid::new_v4();
         let entries = vec![
-            SavedQuery::new("Users".to_string(), "SELECT * FROM users".to_string(), None),
             SavedQuery::new(
-                "Orders".to_string(),
-                "SELECT * FROM orders".to_string(),
-                None,
-            ),
+                "Revenue".to_string(),
+                "SELECT sum(total) FROM orders".to_string(),
+                Some(postgres_profile),
+            )
+            .with_folder(Some(reports.id))
+            .with_tags(vec!["billing".to_string()]),
+            SavedQuery::new("Users".to_string(), "SELECT * FROM users".to_string(), None)
+                .with_tags(vec!["admin".to_string()]),
         ];
+        let folders = vec![reports];
+        let drivers = |id: Uuid| {
+            if id == postgres_profile {
+                vec!["postgres".to_string(), "PostgreSQL".to_string()]
+            } else {
+                Vec::new()
+            }
+        };
 
-        let filtered = filter_saved_queries(&entries, "orders");
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "Orders");
+        let by_tag = filter_saved_queries(&entries, &folders, "#billing", drivers);
+        assert_eq!(by_tag.len(), 1);
+        assert_eq!(by_tag[0].name, "Revenue");
+
+        let by_folder = filter_saved_queries(&entries, &folders, "reports", drivers);
+        assert_eq!(by_folder.len(), 1);
+
+        let by_driver = filter_saved_queries(&entries, &folders, "driver:postgres", drivers);
+        assert_eq!(by_driver.len(), 1);
+
+        let none = filter_saved_queries(&entries, &folders, "#admin driver:postgres", drivers);
+        assert!(none.is_empty());
     }
 }
@@ -0,0 +1,442 @@
+//! "Index advisor": suggests indexes for the slow queries of a database.
+//!
+//! Slow statements come from the query history and, where the server tracks
+//! them, from its own statistics such as `pg_stat_statements`. The analysis
+//! runs in the background as a task; each suggestion shows the `CREATE INDEX`
+//! statement the driver generates, which can be opened in a new query tab.
+
+use crate::data_search_modal::database_tables;
+use dbflux_components::controls::Button;
+use dbflux_components::modals::shell::ModalShell;
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{FontSizes, Radii, Spacing};
+use dbflux_components::typography::AppFonts;
+use dbflux_core::{
+    Connection, CreateIndexRequest, DbError, HistoryFilter, IndexSuggestion, QueryTableRef,
+    TableInfo, TaskKind, TaskTarget, WorkloadQuery, extract_referenced_tables, suggest_indexes,
+};
+use dbflux_ui_base::{AppStateChanged, AppStateEntity};
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use uuid::Uuid;
+
+/// History entries scanned for slow queries.
+const HISTORY_ENTRIES: usize = 500;
+
+/// Statements read from the server's own statistics.
+const SERVER_STATEMENTS: usize = 200;
+
+/// Mean execution time from which a statement counts as slow.
+const SLOW_QUERY_MS: f64 = 100.0;
+
+/// Suggestions listed at most.
+const MAX_SUGGESTIONS: usize = 20;
+
+/// Emitted when a suggestion's statement is opened.
+#[derive(Clone, Debug)]
+pub enum IndexAdvisorEvent {
+    OpenStatement { sql: String },
+}
+
+/// A suggestion with the statement that creates it, when the driver can
+/// generate one.
+struct AdvisorRow {
+    suggestion: IndexSuggestion,
+    statement: Option<String>,
+}
+
+/// The outcome of one analysis.
+struct AdvisorReport {
+    rows: Vec<AdvisorRow>,
+    slow_queries: usize,
+    from_server: usize,
+}
+
+pub struct IndexAdvisorModal {
+    app_state: Entity<AppStateEntity>,
+    visible: bool,
+    target: Option<(Uuid, Option<String>)>,
+    running: bool,
+    rows: Vec<AdvisorRow>,
+    message: Option<String>,
+}
+
+impl IndexAdvisorModal {
+    pub fn new(app_state: Entity<AppStateEntity>) -> Self {
+        Self {
+            app_state,
+            visible: false,
+            target: None,
+            running: false,
+            rows: Vec::new(),
+            message: None,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows the advisor for `database` of `profile_id`, analyzing it unless
+    /// the last analysis was for the same target.
+    pub fn open(&mut self, profile_id: Uuid, database: Option<String>, cx: &mut Context<Self>) {
+        let target = Some((profile_id, database));
+        let stale = self.target != target;
+
+        self.visible = true;
+        if stale {
+            self.target = target;
+            self.rows.clear();
+            self.message = None;
+            self.analyze(cx);
+        }
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.visible = false;
+        cx.notify();
+    }
+
+    fn analyze(&mut self, cx: &mut Context<Self>) {
+        let Some((profile_id, database)) = self.target.clone() else {
+            return;
+        };
+        if self.running {
+            return;
+        }
+
+        let (conn, tables, history) = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(&profile_id) else {
+                self.message = Some("The connection is no longer open".to_string());
+                cx.notify();
+                return;
+            };
+            let conn = match database.as_deref() {
+                Some(database) => connected.connection_for_database(database),
+                None => connected.connection.clone(),
+            };
+
+            let filter = HistoryFilter {
+                connection_profile_id: Some(profile_id),
+                succeeded: Some(true),
+                ..Default::default()
+            };
+            let mut entries = state.history_page(&filter, 0, HISTORY_ENTRIES);
+            entries.retain(|entry| {
+                entry.database.is_none() || database.is_none() || entry.database == database
+            });
+
+            (
+                conn,
+                database_tables(connected, database.as_deref()),
+                WorkloadQuery::from_history(&entries),
+            )
+        };
+
+        let (task_id, _cancel_token) = self.app_state.update(cx, |state, cx| {
+            let started = state.start_task_for_target(
+                TaskKind::IndexAdvice,
+                "Analyze slow queries for missing indexes".to_string(),
+                Some(TaskTarget {
+                    profile_id,
+                    database: database.clone(),
+                }),
+            );
+            cx.emit(AppStateChanged);
+            started
+        });
+
+        self.running = true;
+        self.rows.clear();
+        self.message = None;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { advise(&*conn, database.as_deref(), tables, history) })
+                .await;
+
+            let _ = this.update(cx, |modal, cx| {
+                modal.running = false;
+
+                match result {
+                    Ok(report) => {
+                        let summary = report_summary(&report);
+                        modal.app_state.update(cx, |state, cx| {
+                            state.complete_task_with_details(task_id, summary.clone());
+                            cx.emit(AppStateChanged);
+                        });
+                        modal.rows = report.rows;
+                        modal.message = Some(summary);
+                    }
+                    Err(error) => {
+                        modal.app_state.update(cx, |state, cx| {
+                            state.fail_task(task_id, error.to_string());
+                            cx.emit(AppStateChanged);
+                        });
+                        modal.message = Some(format!("Analysis failed: {}", error));
+                    }
+                }
+
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    fn open_statement(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(sql) = self.rows.get(ix).and_then(|row| row.statement.clone()) else {
+            return;
+        };
+
+        cx.emit(IndexAdvisorEvent::OpenStatement { sql });
+        self.close(cx);
+    }
+}
+
+/// Gathers the slow statements, loads the indexes of the tables they use and
+/// ranks the indexes they lack.
+fn advise(
+    conn: &dyn Connection,
+    database: Option<&str>,
+    tables: Vec<TableInfo>,
+    history: Vec<WorkloadQuery>,
+) -> Result<AdvisorReport, DbError> {
+    let server = conn
+        .query_statistics(SERVER_STATEMENTS)
+        .unwrap_or_else(|error| {
+            log::debug!("Server query statistics unavailable: {}", error);
+            Vec::new()
+        });
+    let from_server = server.len();
+
+    let workload: Vec<WorkloadQuery> = history
+        .into_iter()
+        .chain(server)
+        .filter(|query| query.mean_time_ms() >= SLOW_QUERY_MS)
+        .collect();
+
+    let mut loaded: Vec<TableInfo> = Vec::new();
+    for reference in workload
+        .iter()
+        .flat_map(|query| extract_referenced_tables(&query.sql))
+    {
+        let Some(table) = find_table(&tables, &reference) else {
+            continue;
+        };
+        if find_table(&loaded, &reference).is_some() {
+            continue;
+        }
+
+        let details = if table.columns.is_some() && table.indexes.is_some() {
+            table.clone()
+        } else {
+            conn.table_details(
+                database.unwrap_or("default"),
+                table.schema.as_deref(),
+                &table.name,
+            )?
+        };
+        loaded.push(details);
+    }
+
+    let generator = conn.code_generator();
+    let rows = suggest_indexes(&workload, |reference| find_table(&loaded, reference))
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|suggestion| {
+            let index_name = suggestion.index_name();
+            let statement = generator.generate_create_index(&CreateIndexRequest {
+                index_name: &index_name,
+                table_name: &suggestion.table.table,
+                schema_name: suggestion.table.schema.as_deref(),
+                columns: &suggestion.columns,
+                unique: false,
+            });
+            AdvisorRow {
+                suggestion,
+                statement,
+            }
+        })
+        .collect();
+
+    Ok(AdvisorReport {
+        rows,
+        slow_queries: workload.len(),
+        from_server,
+    })
+}
+
+/// The listed table `reference` names, matching its schema when qualified.
+fn find_table<'a>(tables: &'a [TableInfo], reference: &QueryTableRef) -> Option<&'a TableInfo> {
+    tables.iter().find(|table| {
+        table.name.eq_ignore_ascii_case(&reference.table)
+            && reference.schema.as_deref().is_none_or(|schema| {
+                table
+                    .schema
+                    .as_deref()
+                    .is_none_or(|own| own.eq_ignore_ascii_case(schema))
+            })
+    })
+}
+
+fn report_summary(report: &AdvisorReport) -> String {
+    let mut summary = format!(
+        "{} suggested indexes from {} slow queries",
+        report.rows.len(),
+        report.slow_queries
+    );
+    if report.from_server > 0 {
+        summary.push_str(&format!(
+            " ({} statements read from server statistics)",
+            report.from_server
+        ));
+    }
+    summary
+}
+
+/// `1.2 s` or `350 ms`.
+fn format_duration_ms(ms: f64) -> String {
+    if ms >= 1_000.0 {
+        format!("{:.1} s", ms / 1_000.0)
+    } else {
+        format!("{:.0} ms", ms)
+    }
+}
+
+impl EventEmitter<IndexAdvisorEvent> for IndexAdvisorModal {}
+
+impl Render for IndexAdvisorModal {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let theme = cx.theme().clone();
+
+        let status = if self.running {
+            Some("Analyzing slow queries…".to_string())
+        } else {
+            self.message.clone()
+        };
+
+        let rows = self.rows.iter().enumerate().map(|(ix, row)| {
+            let suggestion = &row.suggestion;
+            let table = match &suggestion.table.schema {
+                Some(schema) => format!("{}.{}", schema, suggestion.table.table),
+                None => suggestion.table.table.clone(),
+            };
+            let benefit = format!(
+                "Up to {} across {} runs of {} {}",
+                format_duration_ms(suggestion.estimated_benefit_ms),
+                suggestion.calls,
+                suggestion.queries,
+                if suggestion.queries == 1 {
+                    "query"
+                } else {
+                    "queries"
+                }
+            );
+
+            div()
+                .id(("index-advisor-row", ix))
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .p(Spacing::XS)
+                .rounded(Radii::SM)
+                .hover(|d| d.bg(theme.secondary))
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap(Spacing::SM)
+                        .child(Text::label_sm(format!(
+                            "{} ({})",
+                            table,
+                            suggestion.columns.join(", ")
+                        )))
+                        .child(div().flex_1())
+                        .when(row.statement.is_some(), |el| {
+                            el.child(
+                                Button::new(("index-advisor-open", ix), "Open in Editor")
+                                    .small()
+                                    .ghost()
+                                    .on_click(cx.listener(move |this, _, _, cx| {
+                                        this.open_statement(ix, cx)
+                                    })),
+                            )
+                        }),
+                )
+                .child(Text::caption(benefit).muted_foreground())
+                .child(
+                    div()
+                        .text_size(FontSizes::XS)
+                        .font_family(AppFonts::MONO)
+                        .text_color(theme.foreground)
+                        .child(row.statement.clone().unwrap_or_else(|| {
+                            "This driver cannot generate CREATE INDEX statements".to_string()
+                        })),
+                )
+        });
+
+        let body = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::SM)
+            .when_some(status, |el, status| el.child(Text::caption(status)))
+            .child(
+                div()
+                    .id("index-advisor-rows")
+                    .max_h(px(420.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::XS)
+                    .children(rows),
+            );
+
+        let on_analyze = cx.listener(|this, _: &ClickEvent, _, cx| this.analyze(cx));
+        let on_close = cx.listener(|this, _: &ClickEvent, _, cx| this.close(cx));
+
+        let footer = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(Button::new("index-advisor-close", "Close").on_click(on_close))
+            .child(
+                Button::new("index-advisor-analyze", "Analyze Again")
+                    .primary()
+                    .disabled(self.running)
+                    .on_click(on_analyze),
+            );
+
+        let close_for_x = cx.entity().clone();
+
+        ModalShell::new(
+            "Index advisor",
+            body.into_any_element(),
+            footer.into_any_element(),
+        )
+        .width(px(640.0))
+        .on_close(move |_window, cx| {
+            close_for_x.update(cx, |this, cx| this.close(cx));
+        })
+        .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_switch_to_seconds_from_one_second() {
+        assert_eq!(format_duration_ms(350.4), "350 ms");
+        assert_eq!(format_duration_ms(1_240.0), "1.2 s");
+    }
+}
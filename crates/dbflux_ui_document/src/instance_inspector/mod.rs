@@ -865,6 +865,7 @@ mod tests {
             rows: vec![vec![dbflux_core::Value::Int(1)]],
             affected_rows: None,
             execution_time: Duration::ZERO,
+            timing: Default::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
@@ -967,6 +968,7 @@ mod tests {
             rows: vec![vec![dbflux_core::Value::Int(42)]],
             affected_rows: None,
             execution_time: Duration::ZERO,
+            timing: Default::default(),
             text_body: None,
             raw_bytes: None,
             next_page_token: None,
@@ -11,6 +11,7 @@ pub(crate) mod completion_support;
 pub mod dashboard;
 mod data_document;
 mod data_grid_panel;
+mod data_search_modal;
 mod data_view;
 pub mod data_view_trait;
 pub mod dedup;
@@ -22,16 +23,22 @@ mod governance;
 
 mod handle;
 pub mod history_modal;
+mod index_advisor_modal;
 pub mod instance_inspector;
 mod key_value;
 mod new_key_modal;
+pub mod notebook;
 pub mod pane;
 pub mod refresh;
 mod result_view;
+mod scheduled_exports;
 pub mod tab_bar;
+mod tab_history;
 mod tab_manager;
+pub mod table_export;
 mod task_runner;
 mod types;
+pub mod user_admin;
 
 pub use instance_inspector::InspectorPanel;
 
@@ -41,20 +48,25 @@ pub use code::CodeDocument;
 pub use dashboard::{DashboardDocument, DashboardPanelSlot, PanelGridPos};
 pub use data_document::DataDocument;
 pub use data_grid_panel::{DataGridEvent, DataGridPanel, DataSource};
+pub use data_search_modal::{DataSearchEvent, DataSearchModal};
 pub use data_view::{DataViewConfig, DataViewMode};
 pub use data_view_trait::DataView;
+pub use index_advisor_modal::{IndexAdvisorEvent, IndexAdvisorModal};
+pub use scheduled_exports::{ExportScheduler, ScheduledExportsModal};
 
 #[cfg(feature = "mcp")]
 pub use governance::McpApprovalsView;
 
 pub use dedup::DocumentKey;
-pub use handle::DocumentEvent;
+pub use handle::{DocumentEvent, QueryCompletion};
 pub use key_value::KeyValueDocument;
+pub use notebook::NotebookDocument;
 pub use pane::{BoxedDocEventCallback, CodeSessionTabSnapshot, PaneHandle};
 pub use result_view::ResultViewMode;
 pub use tab_bar::{TabBar, TabBarEvent};
-pub use tab_manager::{Tab, TabManager, TabManagerEvent};
+pub use tab_manager::{ClosedTab, Tab, TabManager, TabManagerEvent};
 pub use task_runner::{DocumentTaskRunner, MutationCancelHandle};
 pub use types::{
     DataSourceKind, DocumentIcon, DocumentId, DocumentKind, DocumentMetaSnapshot, DocumentState,
 };
+pub use user_admin::UserAdminDocument;
@@ -0,0 +1,190 @@
+//! Minimal block-level markdown parser for notebook narrative cells.
+//!
+//! Only the block structure is recognized (headings, bullet and numbered
+//! lists, fenced code, horizontal rules, paragraphs); inline markup is kept
+//! verbatim. That covers what a notebook annotation typically needs without
+//! pulling in a full CommonMark implementation.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MarkdownBlock {
+    /// `#` to `######`; `level` is 1-based.
+    Heading {
+        level: u8,
+        text: String,
+    },
+    /// One `-`, `*`, `+` or `1.` list item.
+    ListItem {
+        marker: String,
+        text: String,
+    },
+    /// Text between two ``` fences, without the fences.
+    Code(String),
+    Rule,
+    /// Consecutive non-blank lines joined with a space.
+    Paragraph(String),
+}
+
+pub(crate) fn parse_markdown(source: &str) -> Vec<MarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+
+        if let Some(code_lines) = code.as_mut() {
+            if trimmed.starts_with("```") {
+                blocks.push(MarkdownBlock::Code(code_lines.join("\n")));
+                code = None;
+            } else {
+                code_lines.push(line);
+            }
+            continue;
+        }
+
+        let block = if trimmed.starts_with("```") {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            code = Some(Vec::new());
+            continue;
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            continue;
+        } else if let Some(heading) = parse_heading(trimmed) {
+            heading
+        } else if is_rule(trimmed) {
+            MarkdownBlock::Rule
+        } else if let Some(item) = parse_list_item(trimmed) {
+            item
+        } else {
+            paragraph.push(trimmed);
+            continue;
+        };
+
+        flush_paragraph(&mut paragraph, &mut blocks);
+        blocks.push(block);
+    }
+
+    // An unterminated fence still shows its content.
+    if let Some(code_lines) = code {
+        blocks.push(MarkdownBlock::Code(code_lines.join("\n")));
+    }
+
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(paragraph: &mut Vec<&str>, blocks: &mut Vec<MarkdownBlock>) {
+    if !paragraph.is_empty() {
+        blocks.push(MarkdownBlock::Paragraph(paragraph.join(" ")));
+        paragraph.clear();
+    }
+}
+
+fn parse_heading(line: &str) -> Option<MarkdownBlock> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+
+    Some(MarkdownBlock::Heading {
+        level: level as u8,
+        text: rest.trim().trim_end_matches('#').trim_end().to_string(),
+    })
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|marker| compact.chars().all(|c| c == *marker))
+}
+
+fn parse_list_item(line: &str) -> Option<MarkdownBlock> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some(MarkdownBlock::ListItem {
+                marker: "•".to_string(),
+                text: text.trim().to_string(),
+            });
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+
+    let text = line[digits..].strip_prefix(". ")?;
+    Some(MarkdownBlock::ListItem {
+        marker: line[..digits + 1].to_string(),
+        text: text.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_headings_lists_and_paragraphs() {
+        let blocks = parse_markdown(
+            "# Monthly revenue\n\nNumbers come from\nthe orders table.\n\n- paid only\n2. by month\n",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Heading {
+                    level: 1,
+                    text: "Monthly revenue".into()
+                },
+                MarkdownBlock::Paragraph("Numbers come from the orders table.".into()),
+                MarkdownBlock::ListItem {
+                    marker: "•".into(),
+                    text: "paid only".into()
+                },
+                MarkdownBlock::ListItem {
+                    marker: "2.".into(),
+                    text: "by month".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn code_fences_keep_their_content_verbatim() {
+        let blocks = parse_markdown("```sql\nSELECT *\n  FROM t\n```\n# After the fence");
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Code("SELECT *\n  FROM t".into()),
+                MarkdownBlock::Heading {
+                    level: 1,
+                    text: "After the fence".into()
+                },
+            ]
+        );
+
+        assert_eq!(
+            parse_markdown("```\n# open"),
+            vec![MarkdownBlock::Code("# open".into())]
+        );
+    }
+
+    #[test]
+    fn hash_without_space_and_rules() {
+        assert_eq!(
+            parse_markdown("#hashtag"),
+            vec![MarkdownBlock::Paragraph("#hashtag".into())]
+        );
+        assert_eq!(parse_markdown("- - -"), vec![MarkdownBlock::Rule]);
+        assert_eq!(parse_markdown("***"), vec![MarkdownBlock::Rule]);
+    }
+}
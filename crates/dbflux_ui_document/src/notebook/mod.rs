@@ -0,0 +1,898 @@
+//! `NotebookDocument` — an ordered list of query and markdown cells bound to
+//! one connection and persisted as a `.dbnb` file under the scripts directory.
+//!
+//! Query cells run one at a time through the document's primary task slot and
+//! render their result inline below the editor. "Run All" queues every
+//! non-blank query cell and stops at the first failure. Markdown cells toggle
+//! between an editor and a rendered preview. Every edit is written back to the
+//! file after the configured auto-save debounce; results are never persisted.
+
+mod markdown;
+pub mod pane;
+mod render;
+
+use super::data_grid_panel::DataGridPanel;
+use super::handle::DocumentEvent;
+use super::task_runner::DocumentTaskRunner;
+use super::types::{DocumentId, DocumentState};
+use crate::code::{evaluate_dangerous_with_effective_settings, task_target_for_execution};
+use dbflux_app::keymap::{Command, ContextId};
+use dbflux_components::controls::{InputEvent, InputState};
+use dbflux_core::{
+    DangerousAction, DangerousQueryKind, DbError, EditorLanguageProfile, HistoryEntry, Notebook,
+    NotebookCell, NotebookCellKind, QueryLanguage, QueryRequest, QueryResult, RefreshPolicy,
+    TaskId, TaskKind,
+};
+use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
+use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error_async};
+use dbflux_ui_base::{AppStateChanged, AppStateEntity};
+use gpui::{
+    App, AppContext, Context, Entity, EventEmitter, FocusHandle, Subscription, Task, Window,
+};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+use uuid::Uuid;
+
+/// What a query cell shows below its editor.
+pub(crate) enum CellOutput {
+    Empty,
+    Running,
+    /// Waiting for the user to confirm a statement flagged as dangerous.
+    ConfirmDangerous {
+        query: String,
+        kind: DangerousQueryKind,
+    },
+    Rows {
+        grid: Entity<DataGridPanel>,
+        summary: String,
+    },
+    /// A statement without a result set (DML, DDL, Redis `SET`...).
+    Message(String),
+    Error(String),
+}
+
+pub(crate) struct CellView {
+    pub(crate) id: Uuid,
+    pub(crate) kind: NotebookCellKind,
+    pub(crate) input: Entity<InputState>,
+    /// Markdown cells only: `false` shows the rendered preview.
+    pub(crate) editing: bool,
+    pub(crate) output: CellOutput,
+    _subscription: Subscription,
+}
+
+struct RunningCell {
+    cell_id: Uuid,
+    task_id: TaskId,
+}
+
+struct PendingCellResult {
+    cell_id: Uuid,
+    task_id: TaskId,
+    query: String,
+    started_at: Instant,
+    result: Result<QueryResult, DbError>,
+}
+
+pub struct NotebookDocument {
+    id: DocumentId,
+    app_state: Entity<AppStateEntity>,
+    path: PathBuf,
+    connection_id: Option<Uuid>,
+    database: Option<String>,
+    pub(crate) cells: Vec<CellView>,
+    pub(crate) focused_cell: Option<Uuid>,
+    running: Option<RunningCell>,
+    /// Cells still to run in the current "Run All" pass.
+    run_queue: VecDeque<Uuid>,
+    pending_result: Option<PendingCellResult>,
+    runner: DocumentTaskRunner,
+    state: DocumentState,
+    focus_handle: FocusHandle,
+    _save_debounce: Option<Task<()>>,
+    _app_state_sub: Subscription,
+}
+
+impl EventEmitter<DocumentEvent> for NotebookDocument {}
+
+impl NotebookDocument {
+    /// Build a document for the notebook stored at `path`.
+    ///
+    /// The notebook's own connection is kept when it is still a known
+    /// profile; otherwise the active connection is used.
+    pub fn new(
+        app_state: Entity<AppStateEntity>,
+        path: PathBuf,
+        notebook: Notebook,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let (connection_id, database) = {
+            let state = app_state.read(cx);
+            let known = notebook
+                .connection_id
+                .filter(|id| state.profiles().iter().any(|profile| profile.id == *id));
+
+            match known {
+                Some(id) => (Some(id), notebook.database.clone()),
+                None => (state.active_connection_id(), None),
+            }
+        };
+
+        let mut runner = DocumentTaskRunner::new(app_state.clone());
+        if let Some(id) = connection_id {
+            runner.set_profile_id(id);
+        }
+
+        let app_state_sub = cx.subscribe(&app_state, |_this, _, _: &AppStateChanged, cx| {
+            cx.notify();
+        });
+
+        let mut document = Self {
+            id: DocumentId::new(),
+            app_state,
+            path,
+            connection_id,
+            database,
+            cells: Vec::new(),
+            focused_cell: None,
+            running: None,
+            run_queue: VecDeque::new(),
+            pending_result: None,
+            runner,
+            state: DocumentState::Clean,
+            focus_handle: cx.focus_handle(),
+            _save_debounce: None,
+            _app_state_sub: app_state_sub,
+        };
+
+        document.cells = notebook
+            .cells
+            .into_iter()
+            .map(|cell| document.create_cell_view(cell, window, cx))
+            .collect();
+
+        document
+    }
+
+    fn editor_profile(&self, cx: &App) -> EditorLanguageProfile {
+        self.connection_id
+            .and_then(|id| self.app_state.read(cx).connections().get(&id))
+            .map(|connected| connected.connection.metadata().editor_profile())
+            .unwrap_or_else(|| EditorLanguageProfile::from_language(&QueryLanguage::Sql))
+    }
+
+    fn create_cell_view(
+        &self,
+        cell: NotebookCell,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> CellView {
+        let profile = self.editor_profile(cx);
+        let source = cell.source;
+
+        let input = cx.new(|cx| {
+            let mut state = match cell.kind {
+                NotebookCellKind::Query => InputState::new(window, cx)
+                    .code_editor(profile.editor_mode.clone())
+                    .line_number(false)
+                    .soft_wrap(true)
+                    .placeholder(profile.placeholder.clone()),
+                NotebookCellKind::Markdown => InputState::new(window, cx)
+                    .auto_grow(2, usize::MAX)
+                    .soft_wrap(true)
+                    .placeholder("Markdown: # heading, - list, ``` code"),
+            };
+
+            state.set_value(&source, window, cx);
+            state
+        });
+
+        let cell_id = cell.id;
+        let subscription = cx.subscribe_in(
+            &input,
+            window,
+            move |this, _input, event: &InputEvent, _window, cx| match event {
+                InputEvent::Change => this.schedule_save(cx),
+                InputEvent::Focus => this.focused_cell = Some(cell_id),
+                InputEvent::PressEnter { .. } | InputEvent::Blur => {}
+            },
+        );
+
+        CellView {
+            id: cell.id,
+            kind: cell.kind,
+            input,
+            // Empty markdown cells open in the editor; written ones as preview.
+            editing: cell.kind == NotebookCellKind::Markdown && source.trim().is_empty(),
+            output: CellOutput::Empty,
+            _subscription: subscription,
+        }
+    }
+
+    // === PaneHandle surface ===
+
+    pub fn id(&self) -> DocumentId {
+        self.id
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn title(&self) -> String {
+        self.path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("Notebook")
+            .to_string()
+    }
+
+    pub fn state(&self) -> DocumentState {
+        self.state
+    }
+
+    pub fn connection_id(&self) -> Option<Uuid> {
+        self.connection_id
+    }
+
+    /// Notebooks are saved continuously, so closing never loses edits.
+    pub fn can_close(&self) -> bool {
+        true
+    }
+
+    /// Cell editors take typed keys; everything else goes through the editor
+    /// keymap so Ctrl+Enter runs the focused cell.
+    pub fn active_context(&self) -> ContextId {
+        ContextId::Editor
+    }
+
+    pub fn refresh_policy(&self) -> RefreshPolicy {
+        RefreshPolicy::Manual
+    }
+
+    pub fn set_refresh_policy(&mut self, _policy: RefreshPolicy, _cx: &mut Context<Self>) {}
+
+    pub fn set_active_tab(&mut self, _active: bool) {}
+
+    pub fn focus(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let target = self
+            .focused_cell
+            .and_then(|id| self.cells.iter().find(|cell| cell.id == id))
+            .or_else(|| self.cells.first());
+
+        match target {
+            Some(cell) => cell.input.update(cx, |state, cx| state.focus(window, cx)),
+            None => self.focus_handle.focus(window),
+        }
+    }
+
+    pub fn dispatch_command(
+        &mut self,
+        cmd: Command,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        match cmd {
+            Command::RunQuery => {
+                if let Some(cell_id) = self.focused_cell {
+                    self.run_cell(cell_id, window, cx);
+                }
+                true
+            }
+            Command::CancelQuery => {
+                self.cancel_run(cx);
+                true
+            }
+            Command::SaveQuery => {
+                self.flush_auto_save(cx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // === Cells ===
+
+    /// Inserts a new cell below the focused one (or at the end) and focuses it.
+    pub(crate) fn add_cell(
+        &mut self,
+        kind: NotebookCellKind,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let index = self
+            .focused_cell
+            .and_then(|id| self.cell_index(id))
+            .map_or(self.cells.len(), |index| index + 1);
+
+        let view = self.create_cell_view(NotebookCell::new(kind, ""), window, cx);
+        view.input.update(cx, |state, cx| state.focus(window, cx));
+        self.focused_cell = Some(view.id);
+        self.cells.insert(index, view);
+
+        self.schedule_save(cx);
+        cx.notify();
+    }
+
+    pub(crate) fn move_cell(&mut self, cell_id: Uuid, offset: isize, cx: &mut Context<Self>) {
+        let mut notebook = self.to_notebook(cx);
+        if !notebook.move_cell(cell_id, offset) {
+            return;
+        }
+
+        // Mirror the model's order so cell views keep their editors and outputs.
+        let mut views: Vec<Option<CellView>> = self.cells.drain(..).map(Some).collect();
+        for cell in &notebook.cells {
+            if let Some(view) = views
+                .iter_mut()
+                .find(|view| view.as_ref().is_some_and(|v| v.id == cell.id))
+                .and_then(Option::take)
+            {
+                self.cells.push(view);
+            }
+        }
+
+        self.schedule_save(cx);
+        cx.notify();
+    }
+
+    pub(crate) fn remove_cell(&mut self, cell_id: Uuid, cx: &mut Context<Self>) {
+        if self
+            .running
+            .as_ref()
+            .is_some_and(|running| running.cell_id == cell_id)
+        {
+            self.cancel_run(cx);
+        }
+
+        self.run_queue.retain(|id| *id != cell_id);
+        self.cells.retain(|cell| cell.id != cell_id);
+        if self.focused_cell == Some(cell_id) {
+            self.focused_cell = None;
+        }
+
+        self.schedule_save(cx);
+        cx.notify();
+    }
+
+    pub(crate) fn toggle_markdown_editing(
+        &mut self,
+        cell_id: Uuid,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(cell) = self.cells.iter_mut().find(|cell| cell.id == cell_id) else {
+            return;
+        };
+
+        cell.editing = !cell.editing;
+        if cell.editing {
+            cell.input.update(cx, |state, cx| state.focus(window, cx));
+        }
+        cx.notify();
+    }
+
+    fn cell_index(&self, cell_id: Uuid) -> Option<usize> {
+        self.cells.iter().position(|cell| cell.id == cell_id)
+    }
+
+    fn cell_mut(&mut self, cell_id: Uuid) -> Option<&mut CellView> {
+        self.cells.iter_mut().find(|cell| cell.id == cell_id)
+    }
+
+    // === Connection ===
+
+    pub(crate) fn connection_label(&self, cx: &App) -> Option<String> {
+        let connection_id = self.connection_id?;
+        let state = self.app_state.read(cx);
+
+        let name = state
+            .profiles()
+            .iter()
+            .find(|profile| profile.id == connection_id)
+            .map(|profile| profile.name.clone())?;
+
+        let database = self.database.clone().or_else(|| {
+            state
+                .connections()
+                .get(&connection_id)
+                .and_then(|connected| connected.active_database.clone())
+        });
+
+        Some(match database {
+            Some(database) => format!("{name} / {database}"),
+            None => name,
+        })
+    }
+
+    pub(crate) fn is_connected(&self, cx: &App) -> bool {
+        self.connection_id
+            .is_some_and(|id| self.app_state.read(cx).connections().contains_key(&id))
+    }
+
+    /// Rebinds the notebook to the active connection and its database.
+    pub(crate) fn use_active_connection(&mut self, cx: &mut Context<Self>) {
+        let Some(connection_id) = self.app_state.read(cx).active_connection_id() else {
+            Toast::warning("No active connection")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        };
+
+        self.connection_id = Some(connection_id);
+        self.database = None;
+        self.runner.set_profile_id(connection_id);
+
+        self.schedule_save(cx);
+        cx.emit(DocumentEvent::MetaChanged);
+        cx.notify();
+    }
+
+    pub(crate) fn request_connect(&mut self, cx: &mut Context<Self>) {
+        if let Some(profile_id) = self.connection_id {
+            cx.emit(DocumentEvent::RequestConnect { profile_id });
+        }
+    }
+
+    // === Execution ===
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    pub(crate) fn running_cell(&self) -> Option<Uuid> {
+        self.running.as_ref().map(|running| running.cell_id)
+    }
+
+    /// Queues every non-blank query cell and runs the first one.
+    pub(crate) fn run_all(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_running() {
+            return;
+        }
+
+        self.run_queue = self.to_notebook(cx).runnable_cells().into();
+        self.run_next_queued(window, cx);
+    }
+
+    fn run_next_queued(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(cell_id) = self.run_queue.pop_front() {
+            self.run_cell(cell_id, window, cx);
+        }
+    }
+
+    pub(crate) fn run_cell(&mut self, cell_id: Uuid, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_running() {
+            Toast::warning("Another cell is still running")
+                .meta_right(now_hms())
+                .push(cx);
+            return;
+        }
+
+        let Some(cell) = self.cells.iter().find(|cell| cell.id == cell_id) else {
+            return;
+        };
+
+        if cell.kind != NotebookCellKind::Query {
+            return;
+        }
+
+        let query = cell.input.read(cx).value().to_string();
+        if query.trim().is_empty() {
+            self.run_next_queued(window, cx);
+            return;
+        }
+
+        match self.check_dangerous(&query, cx) {
+            DangerousAction::Allow => self.execute_cell(cell_id, query, cx),
+            DangerousAction::Confirm(kind) => {
+                if let Some(cell) = self.cell_mut(cell_id) {
+                    cell.output = CellOutput::ConfirmDangerous { query, kind };
+                }
+                cx.notify();
+            }
+            DangerousAction::Block(msg) => {
+                self.run_queue.clear();
+                if let Some(cell) = self.cell_mut(cell_id) {
+                    cell.output = CellOutput::Error(msg);
+                }
+                cx.notify();
+            }
+        }
+    }
+
+    fn check_dangerous(&self, query: &str, cx: &App) -> DangerousAction {
+        let state = self.app_state.read(cx);
+
+        let Some(kind) = self
+            .connection_id
+            .and_then(|id| state.connections().get(&id))
+            .and_then(|connected| {
+                connected
+                    .connection
+                    .language_service()
+                    .detect_dangerous(query)
+            })
+        else {
+            return DangerousAction::Allow;
+        };
+
+        let is_suppressed = state.dangerous_query_suppressions().is_suppressed(kind);
+        let effective = state.effective_settings_for_connection(self.connection_id);
+        let allow_redis_flush = effective
+            .driver_values
+            .get("allow_flush")
+            .is_some_and(|value| value == "true");
+
+        evaluate_dangerous_with_effective_settings(
+            kind,
+            is_suppressed,
+            &effective,
+            allow_redis_flush,
+        )
+    }
+
+    pub(crate) fn confirm_dangerous_cell(&mut self, cell_id: Uuid, cx: &mut Context<Self>) {
+        let Some(cell) = self.cell_mut(cell_id) else {
+            return;
+        };
+
+        let CellOutput::ConfirmDangerous { query, .. } =
+            std::mem::replace(&mut cell.output, CellOutput::Empty)
+        else {
+            return;
+        };
+
+        self.execute_cell(cell_id, query, cx);
+    }
+
+    pub(crate) fn cancel_dangerous_cell(&mut self, cell_id: Uuid, cx: &mut Context<Self>) {
+        self.run_queue.clear();
+        if let Some(cell) = self.cell_mut(cell_id) {
+            cell.output = CellOutput::Empty;
+        }
+        cx.notify();
+    }
+
+    fn execute_cell(&mut self, cell_id: Uuid, query: String, cx: &mut Context<Self>) {
+        let Some(conn_id) = self.connection_id else {
+            self.fail_before_start(cell_id, "No active connection".to_string(), cx);
+            return;
+        };
+
+        let resolved = {
+            let state = self.app_state.read(cx);
+            state.connections().get(&conn_id).map(|connected| {
+                let database = self
+                    .database
+                    .clone()
+                    .or_else(|| connected.active_database.clone());
+
+                connected
+                    .resolve_connection_for_execution(database.as_deref())
+                    .map(|connection| {
+                        let target =
+                            task_target_for_execution(conn_id, connected, database.as_deref());
+                        (connection, database, target)
+                    })
+            })
+        };
+
+        let (connection, database, target) = match resolved {
+            None => {
+                self.fail_before_start(cell_id, "Connect to run this notebook".to_string(), cx);
+                return;
+            }
+            Some(Err(dbflux_core::ConnectionResolutionError::PendingDatabaseConnection {
+                database,
+            })) => {
+                self.fail_before_start(
+                    cell_id,
+                    format!("Connecting to database '{}', please wait...", database),
+                    cx,
+                );
+                return;
+            }
+            Some(Ok(resolved)) => resolved,
+        };
+
+        let description = dbflux_core::truncate_string_safe(query.trim(), 80);
+        let (task_id, cancel_token) =
+            self.runner
+                .start_primary_for_target(TaskKind::Query, description, Some(target), cx);
+
+        self.running = Some(RunningCell { cell_id, task_id });
+        if let Some(cell) = self.cell_mut(cell_id) {
+            cell.output = CellOutput::Running;
+        }
+
+        self.state = DocumentState::Executing;
+        cx.emit(DocumentEvent::ExecutionStarted);
+        cx.notify();
+
+        let request = QueryRequest::new(query.clone()).with_database(database);
+        let started_at = Instant::now();
+
+        let task = cx.background_executor().spawn({
+            let connection = connection.clone();
+            async move { connection.execute(&request) }
+        });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+
+            if cancel_token.is_cancelled() {
+                if let Err(error) = connection.cleanup_after_cancel() {
+                    log::warn!("Cleanup after cancel failed: {}", error);
+                }
+                return;
+            }
+
+            this.update(cx, |doc, cx| {
+                doc.pending_result = Some(PendingCellResult {
+                    cell_id,
+                    task_id,
+                    query,
+                    started_at,
+                    result,
+                });
+                cx.notify();
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn fail_before_start(&mut self, cell_id: Uuid, message: String, cx: &mut Context<Self>) {
+        self.run_queue.clear();
+        if let Some(cell) = self.cell_mut(cell_id) {
+            cell.output = CellOutput::Error(message);
+        }
+        cx.notify();
+    }
+
+    /// Cancels the running cell and drops the rest of a "Run All" pass.
+    pub(crate) fn cancel_run(&mut self, cx: &mut Context<Self>) {
+        self.run_queue.clear();
+
+        let Some(running) = self.running.take() else {
+            return;
+        };
+
+        self.runner.cancel_primary(cx);
+        if let Some(cell) = self.cell_mut(running.cell_id) {
+            cell.output = CellOutput::Error("Cancelled".to_string());
+        }
+
+        self.state = DocumentState::Clean;
+        cx.emit(DocumentEvent::ExecutionFinished);
+        cx.notify();
+    }
+
+    /// Applies a finished cell run. Needs `Window` to build the result grid,
+    /// so it is called from `render`.
+    fn process_pending_result(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(pending) = self.pending_result.take() else {
+            return;
+        };
+
+        if self
+            .running
+            .as_ref()
+            .is_none_or(|running| running.task_id != pending.task_id)
+        {
+            return;
+        }
+
+        self.running = None;
+        self.state = DocumentState::Clean;
+        cx.emit(DocumentEvent::ExecutionFinished);
+
+        let output = match pending.result {
+            Ok(result) => {
+                self.runner.complete_primary(pending.task_id, cx);
+
+                let row_count = result.affected_rows.unwrap_or(result.rows.len() as u64);
+                self.record_history_entry(
+                    &pending.query,
+                    result.execution_time,
+                    Some(row_count as usize),
+                    None,
+                    cx,
+                );
+
+                self.result_output(result, pending.query, window, cx)
+            }
+            Err(error) => {
+                let message = error.to_string();
+                self.runner
+                    .fail_primary(pending.task_id, message.clone(), cx);
+                self.record_history_entry(
+                    &pending.query,
+                    pending.started_at.elapsed(),
+                    None,
+                    Some(message.clone()),
+                    cx,
+                );
+
+                if !self.run_queue.is_empty() {
+                    self.run_queue.clear();
+                    Toast::error("Run All stopped at a failing cell")
+                        .meta_right(now_hms())
+                        .action(copy_action(message.clone()))
+                        .push(cx);
+                }
+
+                self.state = DocumentState::Error;
+                CellOutput::Error(message)
+            }
+        };
+
+        if let Some(cell) = self.cell_mut(pending.cell_id) {
+            cell.output = output;
+        }
+
+        cx.notify();
+        self.run_next_queued(window, cx);
+    }
+
+    fn result_output(
+        &self,
+        result: QueryResult,
+        query: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> CellOutput {
+        let elapsed_ms = result.execution_time.as_millis();
+
+        if result.columns.is_empty() {
+            let message = match (result.affected_rows, result.text_body.as_deref()) {
+                (_, Some(body)) if !body.trim().is_empty() => body.trim().to_string(),
+                (Some(affected), _) => format!("{affected} rows affected in {elapsed_ms} ms"),
+                _ => format!("Done in {elapsed_ms} ms"),
+            };
+            return CellOutput::Message(message);
+        }
+
+        let summary = format!("{} rows in {} ms", result.rows.len(), elapsed_ms);
+        let connection_id = self.connection_id;
+        let app_state = self.app_state.clone();
+        let grid = cx.new(|cx| {
+            DataGridPanel::new_for_result(
+                Arc::new(result),
+                query,
+                connection_id,
+                app_state,
+                window,
+                cx,
+            )
+        });
+
+        CellOutput::Rows { grid, summary }
+    }
+
+    fn record_history_entry(
+        &self,
+        query: &str,
+        execution_time: std::time::Duration,
+        row_count: Option<usize>,
+        error: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(connection_id) = self.connection_id else {
+            return;
+        };
+
+        let (database, connection_name) = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(&connection_id)
+            .map(|connected| {
+                (
+                    self.database
+                        .clone()
+                        .or_else(|| connected.active_database.clone()),
+                    Some(connected.profile.name.clone()),
+                )
+            })
+            .unwrap_or((None, None));
+
+        let mut entry = HistoryEntry::new(
+            query.to_string(),
+            database,
+            connection_name,
+            execution_time,
+            row_count,
+        )
+        .with_connection_profile(connection_id);
+
+        if let Some(error) = error {
+            entry = entry.with_error(error);
+        }
+
+        self.app_state.update(cx, |state, _| {
+            state.add_history_entry(entry);
+        });
+    }
+
+    // === Persistence ===
+
+    fn to_notebook(&self, cx: &App) -> Notebook {
+        Notebook {
+            connection_id: self.connection_id,
+            database: self.database.clone(),
+            cells: self
+                .cells
+                .iter()
+                .map(|cell| NotebookCell {
+                    id: cell.id,
+                    kind: cell.kind,
+                    source: cell.input.read(cx).value().to_string(),
+                })
+                .collect(),
+            ..Notebook::default()
+        }
+    }
+
+    /// Write the notebook back to its file after the auto-save debounce.
+    /// Resets on each call.
+    fn schedule_save(&mut self, cx: &mut Context<Self>) {
+        let content = match self.to_notebook(cx).to_json() {
+            Ok(content) => content,
+            Err(e) => {
+                log::error!("Failed to serialize notebook: {}", e);
+                return;
+            }
+        };
+
+        let path = self.path.clone();
+        let auto_save_ms = self
+            .app_state
+            .read(cx)
+            .general_settings()
+            .auto_save_interval_ms;
+
+        self._save_debounce = Some(cx.spawn(async move |_this, cx| {
+            cx.background_executor()
+                .timer(std::time::Duration::from_millis(auto_save_ms))
+                .await;
+
+            let write_result = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move { std::fs::write(&path, &content) }
+                })
+                .await;
+
+            if let Err(e) = write_result {
+                report_error_async(
+                    UserFacingError::new(
+                        ErrorKind::Storage,
+                        format!("Failed to save notebook {}", path.display()),
+                    )
+                    .with_cause(format!("{e}")),
+                    cx,
+                );
+            }
+        }));
+    }
+
+    /// Write the notebook synchronously (called before closing the tab).
+    pub fn flush_auto_save(&self, cx: &App) {
+        let content = match self.to_notebook(cx).to_json() {
+            Ok(content) => content,
+            Err(e) => {
+                log::error!("Failed to serialize notebook: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = std::fs::write(&self.path, content) {
+            log::error!("Flush notebook failed for {}: {}", self.path.display(), e);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! `PaneHandle` constructor for `NotebookDocument`.
+//!
+//! `NotebookDocument::into_pane` converts a typed `Entity<NotebookDocument>`
+//! into the type-erased `PaneHandle` shell. All closures capture the entity by
+//! clone; `Window` and `App` are always passed as per-call parameters.
+
+use super::NotebookDocument;
+use crate::dedup::DocumentKey;
+use crate::handle::DocumentEvent;
+use crate::pane::{BoxedDocEventCallback, PaneHandle};
+use crate::types::{DocumentIcon, DocumentKind, DocumentMetaSnapshot};
+use gpui::{App, Entity, IntoElement};
+
+impl NotebookDocument {
+    /// Wrap a typed `Entity<NotebookDocument>` in a `PaneHandle`.
+    ///
+    /// Reads the document ID synchronously from `cx` then seals all operations
+    /// behind `Box<dyn Fn>` closures that capture `entity` by clone.
+    pub fn into_pane(entity: Entity<Self>, cx: &App) -> PaneHandle {
+        let id = entity.read(cx).id();
+
+        PaneHandle::new_chart(
+            id,
+            DocumentKind::Notebook,
+            // render
+            {
+                let e = entity.clone();
+                Box::new(move |_w, _cx| e.clone().into_any_element())
+            },
+            // focus
+            {
+                let e = entity.clone();
+                Box::new(move |w, cx| e.update(cx, |d, cx| d.focus(w, cx)))
+            },
+            // dispatch_command
+            {
+                let e = entity.clone();
+                Box::new(move |cmd, w, cx| e.update(cx, |d, cx| d.dispatch_command(cmd, w, cx)))
+            },
+            // meta_snapshot
+            {
+                let e = entity.clone();
+                Box::new(move |cx| {
+                    let d = e.read(cx);
+                    DocumentMetaSnapshot {
+                        id,
+                        kind: DocumentKind::Notebook,
+                        title: d.title(),
+                        icon: DocumentIcon::Notebook,
+                        state: d.state(),
+                        closable: true,
+                        connection_id: d.connection_id(),
+                    }
+                })
+            },
+            // tab_title
+            {
+                let e = entity.clone();
+                Box::new(move |cx| e.read(cx).title())
+            },
+            // can_close
+            {
+                let e = entity.clone();
+                Box::new(move |cx| e.read(cx).can_close())
+            },
+            // connection_id
+            {
+                let e = entity.clone();
+                Box::new(move |cx| e.read(cx).connection_id())
+            },
+            // active_context
+            {
+                let e = entity.clone();
+                Box::new(move |cx| e.read(cx).active_context())
+            },
+            // change_summary — notebooks save continuously
+            Box::new(|_cx| None),
+            // refresh_policy
+            {
+                let e = entity.clone();
+                Box::new(move |cx| e.read(cx).refresh_policy())
+            },
+            // flush_auto_save
+            {
+                let e = entity.clone();
+                Box::new(move |cx| e.read(cx).flush_auto_save(cx))
+            },
+            // set_active_tab
+            {
+                let e = entity.clone();
+                Box::new(move |active, cx| e.update(cx, |d, _| d.set_active_tab(active)))
+            },
+            // set_refresh_policy
+            {
+                let e = entity.clone();
+                Box::new(move |policy, cx| e.update(cx, |d, cx| d.set_refresh_policy(policy, cx)))
+            },
+            // matches_dedup_key
+            {
+                let e = entity.clone();
+                Box::new(move |key, cx| match key {
+                    DocumentKey::File { path } => e.read(cx).path() == path.as_path(),
+                    _ => false,
+                })
+            },
+            // subscribe — NotebookDocument emits DocumentEvent directly
+            {
+                let e = entity.clone();
+                Box::new(move |cx, cb: BoxedDocEventCallback| {
+                    cx.subscribe(&e, move |_, ev: &DocumentEvent, cx| cb(ev, cx))
+                })
+            },
+        )
+    }
+}
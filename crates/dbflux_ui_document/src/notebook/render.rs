@@ -0,0 +1,390 @@
+use super::markdown::{MarkdownBlock, parse_markdown};
+use super::{CellOutput, CellView, NotebookDocument};
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant, compact_top_bar};
+use dbflux_components::controls::GpuiInput as Input;
+use dbflux_components::icons::AppIcon;
+use dbflux_components::primitives::{BannerBlock, BannerVariant, Icon, Text};
+use dbflux_components::tokens::{Heights, Spacing};
+use dbflux_core::NotebookCellKind;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use gpui_component::scroll::ScrollableElement;
+use uuid::Uuid;
+
+/// Approximate code-editor line height used to size query cells to their
+/// content.
+const CELL_LINE_HEIGHT: f32 = 20.0;
+const CELL_MIN_LINES: usize = 3;
+const CELL_MAX_LINES: usize = 20;
+
+fn cell_element_id(prefix: &str, cell_id: Uuid) -> ElementId {
+    ElementId::Name(format!("{prefix}-{cell_id}").into())
+}
+
+impl NotebookDocument {
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let is_running = self.is_running();
+        let connected = self.is_connected(cx);
+        let has_connection = self.connection_id.is_some();
+
+        let connection_label = self
+            .connection_label(cx)
+            .unwrap_or_else(|| "No connection".to_string());
+
+        compact_top_bar(&theme, std::iter::empty::<AnyElement>())
+            .id("notebook-toolbar")
+            .child(if is_running {
+                ToolbarButton::new("notebook-cancel-btn")
+                    .icon(AppIcon::X)
+                    .label("Cancel")
+                    .variant(ToolbarButtonVariant::Danger)
+                    .on_click(cx.listener(|this, _, _window, cx| this.cancel_run(cx)))
+            } else {
+                ToolbarButton::new("notebook-run-all-btn")
+                    .icon(AppIcon::Play)
+                    .label("Run All")
+                    .variant(ToolbarButtonVariant::Primary)
+                    .disabled(!connected)
+                    .on_click(cx.listener(|this, _, window, cx| this.run_all(window, cx)))
+            })
+            .child(
+                ToolbarButton::new("notebook-add-query-btn")
+                    .icon(AppIcon::Plus)
+                    .label("Query")
+                    .tooltip("Add a query cell below the focused cell")
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.add_cell(NotebookCellKind::Query, window, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("notebook-add-markdown-btn")
+                    .icon(AppIcon::Plus)
+                    .label("Markdown")
+                    .tooltip("Add a markdown cell below the focused cell")
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.add_cell(NotebookCellKind::Markdown, window, cx);
+                    })),
+            )
+            .child(div().flex_1())
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::XS)
+                    .child(Icon::new(AppIcon::Database).size(Heights::ICON_SM).muted())
+                    .child(if connected {
+                        Text::caption(connection_label)
+                    } else {
+                        Text::caption(connection_label).muted_foreground()
+                    }),
+            )
+            .when(has_connection && !connected, |el| {
+                el.child(
+                    ToolbarButton::new("notebook-connect-btn")
+                        .icon(AppIcon::Plug)
+                        .label("Connect")
+                        .on_click(cx.listener(|this, _, _window, cx| this.request_connect(cx))),
+                )
+            })
+            .child(
+                ToolbarButton::new("notebook-use-active-btn")
+                    .icon(AppIcon::RefreshCcw)
+                    .tooltip("Use the active connection")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .disabled(is_running)
+                    .on_click(cx.listener(|this, _, _window, cx| this.use_active_connection(cx))),
+            )
+    }
+
+    fn render_cell(&self, index: usize, cell: &CellView, cx: &mut Context<Self>) -> AnyElement {
+        let theme = cx.theme().clone();
+        let cell_id = cell.id;
+        let is_focused = self.focused_cell == Some(cell_id);
+        let is_running = self.running_cell() == Some(cell_id);
+        let is_first = index == 0;
+        let is_last = index + 1 == self.cells.len();
+
+        let label = match cell.kind {
+            NotebookCellKind::Query => format!("[{}]", index + 1),
+            NotebookCellKind::Markdown => "Markdown".to_string(),
+        };
+
+        let primary_action = match cell.kind {
+            NotebookCellKind::Query => ToolbarButton::new(cell_element_id("nb-run", cell_id))
+                .icon(if is_running {
+                    AppIcon::Loader
+                } else {
+                    AppIcon::Play
+                })
+                .tooltip("Run cell")
+                .variant(ToolbarButtonVariant::Ghost)
+                .disabled(self.is_running())
+                .on_click(cx.listener(move |this, _, window, cx| {
+                    this.run_cell(cell_id, window, cx);
+                })),
+            NotebookCellKind::Markdown => ToolbarButton::new(cell_element_id("nb-edit", cell_id))
+                .icon(if cell.editing {
+                    AppIcon::Eye
+                } else {
+                    AppIcon::Pencil
+                })
+                .tooltip(if cell.editing { "Preview" } else { "Edit" })
+                .variant(ToolbarButtonVariant::Ghost)
+                .on_click(cx.listener(move |this, _, window, cx| {
+                    this.toggle_markdown_editing(cell_id, window, cx);
+                })),
+        };
+
+        let header = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::XS)
+            .child(Text::caption(label).muted_foreground())
+            .child(div().flex_1())
+            .child(primary_action)
+            .child(
+                ToolbarButton::new(cell_element_id("nb-up", cell_id))
+                    .icon(AppIcon::ArrowUp)
+                    .tooltip("Move up")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .disabled(is_first)
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.move_cell(cell_id, -1, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new(cell_element_id("nb-down", cell_id))
+                    .icon(AppIcon::ArrowDown)
+                    .tooltip("Move down")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .disabled(is_last)
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.move_cell(cell_id, 1, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new(cell_element_id("nb-delete", cell_id))
+                    .icon(AppIcon::Delete)
+                    .tooltip("Delete cell")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.remove_cell(cell_id, cx);
+                    })),
+            );
+
+        let body = match cell.kind {
+            NotebookCellKind::Query => {
+                let lines = cell
+                    .input
+                    .read(cx)
+                    .value()
+                    .lines()
+                    .count()
+                    .clamp(CELL_MIN_LINES, CELL_MAX_LINES);
+
+                div()
+                    .h(px(lines as f32 * CELL_LINE_HEIGHT) + Spacing::SM * 2.0)
+                    .p(Spacing::XS)
+                    .border_1()
+                    .border_color(theme.border)
+                    .bg(theme.background)
+                    .child(Input::new(&cell.input).appearance(false).w_full().h_full())
+                    .into_any_element()
+            }
+            NotebookCellKind::Markdown if cell.editing => {
+                Input::new(&cell.input).w_full().into_any_element()
+            }
+            NotebookCellKind::Markdown => self.render_markdown_preview(cell, cx),
+        };
+
+        div()
+            .id(cell_element_id("nb-cell", cell_id))
+            .flex()
+            .flex_col()
+            .flex_shrink_0()
+            .gap(Spacing::XS)
+            .p(Spacing::SM)
+            .border_1()
+            .border_color(if is_focused { theme.ring } else { theme.border })
+            .bg(theme.secondary)
+            .child(header)
+            .child(body)
+            .when(cell.kind == NotebookCellKind::Query, |el| {
+                el.child(self.render_output(cell_id, &cell.output, cx))
+            })
+            .into_any_element()
+    }
+
+    fn render_markdown_preview(&self, cell: &CellView, cx: &mut Context<Self>) -> AnyElement {
+        let theme = cx.theme().clone();
+        let cell_id = cell.id;
+        let source = cell.input.read(cx).value().to_string();
+        let blocks = parse_markdown(&source);
+
+        let content: Vec<AnyElement> = if blocks.is_empty() {
+            vec![
+                Text::caption("Empty markdown cell — click to edit")
+                    .muted_foreground()
+                    .into_any_element(),
+            ]
+        } else {
+            blocks
+                .into_iter()
+                .map(|block| match block {
+                    MarkdownBlock::Heading { level: 1, text } => {
+                        Text::headline_1(text).into_any_element()
+                    }
+                    MarkdownBlock::Heading { level: 2, text } => {
+                        Text::headline_2(text).into_any_element()
+                    }
+                    MarkdownBlock::Heading { text, .. } => {
+                        Text::headline_3(text).into_any_element()
+                    }
+                    MarkdownBlock::ListItem { marker, text } => div()
+                        .flex()
+                        .gap(Spacing::SM)
+                        .pl(Spacing::SM)
+                        .child(Text::body(marker).muted_foreground())
+                        .child(Text::body(text))
+                        .into_any_element(),
+                    MarkdownBlock::Code(code) => div()
+                        .p(Spacing::SM)
+                        .bg(theme.background)
+                        .border_1()
+                        .border_color(theme.border)
+                        .child(Text::code(code))
+                        .into_any_element(),
+                    MarkdownBlock::Rule => div()
+                        .w_full()
+                        .border_b_1()
+                        .border_color(theme.border)
+                        .into_any_element(),
+                    MarkdownBlock::Paragraph(text) => Text::body(text).into_any_element(),
+                })
+                .collect()
+        };
+
+        div()
+            .id(cell_element_id("nb-preview", cell_id))
+            .flex()
+            .flex_col()
+            .gap(Spacing::XS)
+            .px(Spacing::XS)
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _, window, cx| {
+                this.toggle_markdown_editing(cell_id, window, cx);
+            }))
+            .children(content)
+            .into_any_element()
+    }
+
+    fn render_output(
+        &self,
+        cell_id: Uuid,
+        output: &CellOutput,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let theme = cx.theme().clone();
+
+        match output {
+            CellOutput::Empty => div().into_any_element(),
+            CellOutput::Running => BannerBlock::new(BannerVariant::Info, "Running…")
+                .with_icon(Icon::new(AppIcon::Loader).size(Heights::ICON_SM))
+                .into_any_element(),
+            CellOutput::ConfirmDangerous { kind, .. } => {
+                BannerBlock::new(BannerVariant::Warning, "Confirm dangerous statement")
+                    .with_icon(Icon::new(AppIcon::TriangleAlert).size(Heights::ICON_SM))
+                    .with_body(kind.message())
+                    .with_actions(
+                        div()
+                            .flex()
+                            .gap(Spacing::XS)
+                            .child(
+                                ToolbarButton::new(cell_element_id("nb-danger-run", cell_id))
+                                    .label("Run Anyway")
+                                    .variant(ToolbarButtonVariant::Danger)
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.confirm_dangerous_cell(cell_id, cx);
+                                    })),
+                            )
+                            .child(
+                                ToolbarButton::new(cell_element_id("nb-danger-cancel", cell_id))
+                                    .label("Cancel")
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.cancel_dangerous_cell(cell_id, cx);
+                                    })),
+                            ),
+                    )
+                    .into_any_element()
+            }
+            CellOutput::Rows { grid, summary } => div()
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .child(Text::caption(summary.clone()).muted_foreground())
+                .child(
+                    div()
+                        .h(Heights::RESULTS_PANEL)
+                        .border_1()
+                        .border_color(theme.border)
+                        .overflow_hidden()
+                        .child(grid.clone()),
+                )
+                .into_any_element(),
+            CellOutput::Message(message) => Text::caption(message.clone())
+                .muted_foreground()
+                .into_any_element(),
+            CellOutput::Error(error) => BannerBlock::new(BannerVariant::Danger, "Query Error")
+                .with_icon(Icon::new(AppIcon::CircleX).size(Heights::ICON_SM))
+                .with_pre(error.clone())
+                .into_any_element(),
+        }
+    }
+}
+
+impl Render for NotebookDocument {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.process_pending_result(window, cx);
+
+        let toolbar = self.render_toolbar(cx).into_any_element();
+        let cells: Vec<AnyElement> = self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| self.render_cell(index, cell, cx))
+            .collect();
+
+        let is_empty = cells.is_empty();
+        let bg = cx.theme().background;
+
+        div()
+            .id(ElementId::Name(format!("notebook-doc-{}", self.id.0).into()))
+            .size_full()
+            .flex()
+            .flex_col()
+            .min_h_0()
+            .bg(bg)
+            .track_focus(&self.focus_handle)
+            .child(toolbar)
+            .child(
+                div()
+                    .id("notebook-cells")
+                    .flex_1()
+                    .min_h_0()
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::SM)
+                    .p(Spacing::SM)
+                    .overflow_y_scrollbar()
+                    .when(is_empty, |el| {
+                        el.child(
+                            Text::caption("This notebook has no cells. Add a query or markdown cell from the toolbar.")
+                                .muted_foreground(),
+                        )
+                    })
+                    .children(cells),
+            )
+    }
+}
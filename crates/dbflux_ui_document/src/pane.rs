@@ -14,20 +14,21 @@ use super::handle::DocumentEvent;
 use super::types::{DocumentId, DocumentKind, DocumentMetaSnapshot};
 use dbflux_app::keymap::{Command, ContextId};
 use dbflux_core::RefreshPolicy;
-use gpui::{AnyElement, App, Subscription, Window};
+use gpui::{AnyElement, App, Keystroke, Subscription, Window};
 
 /// Type-erased callback for document events, used by the `subscribe` closure.
 pub type BoxedDocEventCallback = Box<dyn Fn(&DocumentEvent, &mut App) + 'static>;
 
-/// A snapshot of a code document's session state, used to reconstruct tabs
-/// on next launch and to write the session manifest.
+/// A snapshot of a document's session state, used to reconstruct tabs on
+/// next launch and to write the session manifest.
 ///
 /// Fields carry all data that `write_session_manifest` previously read directly
 /// from `DocumentHandle::Code { entity, .. }`. The `kind` field maps to the
-/// `tab_kind` column in `WorkspaceTab` (values: `"FileBacked"`, `"Scratch"`).
+/// `tab_kind` column in `WorkspaceTab` (values: `"FileBacked"`, `"Scratch"`,
+/// `"Routine"`, and `"Data"` for table and collection documents).
 #[derive(Clone)]
 pub struct CodeSessionTabSnapshot {
-    /// `"FileBacked"` or `"Scratch"` — maps to `WorkspaceTab::tab_kind`.
+    /// Maps to `WorkspaceTab::tab_kind`.
     pub kind: &'static str,
     pub id: super::types::DocumentId,
     pub title: String,
@@ -36,6 +37,10 @@ pub struct CodeSessionTabSnapshot {
     pub file_path: Option<std::path::PathBuf>,
     pub scratch_path: Option<std::path::PathBuf>,
     pub shadow_path: Option<std::path::PathBuf>,
+    /// Artifact holding the document's kept result tabs.
+    pub results_path: Option<std::path::PathBuf>,
+    /// Browse state of `"Data"` tabs.
+    pub data_state: Option<dbflux_core::DataTabState>,
 }
 
 /// Type-erased shell for an open document.
@@ -92,6 +97,18 @@ pub struct PaneHandle {
     /// `None` on the outer `Option` means the document does not support this operation.
     pub set_correlation_filter: Option<Box<dyn Fn(Option<String>, &mut App)>>,
 
+    /// Replaces the WHERE filter of table documents and reloads them.
+    pub set_table_filter: Option<Box<dyn Fn(&str, &mut Window, &mut App)>>,
+
+    /// Inserts text at the editor cursor of code documents. Returns `false`
+    /// when the document is read-only.
+    pub insert_text: Option<Box<dyn Fn(&str, &mut Window, &mut App) -> bool>>,
+
+    /// Points code documents at another connection and, optionally, one of
+    /// its databases. The connection must already be established.
+    pub switch_execution_context:
+        Option<Box<dyn Fn(uuid::Uuid, Option<String>, &mut Window, &mut App)>>,
+
     /// Returns true when this pane matches a given event-stream target.
     pub matches_event_stream:
         Option<Box<dyn Fn(uuid::Uuid, &dbflux_core::EventStreamTarget, &App) -> bool>>,
@@ -100,13 +117,18 @@ pub struct PaneHandle {
     /// (used by the empty-file-close cleanup in `actions.rs`).
     pub is_file_backed_empty: Option<Box<dyn Fn(&App) -> Option<std::path::PathBuf>>>,
 
-    /// Returns a session snapshot for code documents (used by session manifest).
+    /// Returns a session snapshot for documents restored with the session
+    /// (code, routine, table and collection documents).
     pub session_tab_snapshot: Option<Box<dyn Fn(&App) -> Option<CodeSessionTabSnapshot>>>,
 
     /// Tells the document that the workspace inspector was dismissed by the
     /// user (× button or ESC). Documents that own inspector state clear it
     /// here so the rail stays closed on subsequent tab activations.
     pub mark_inspector_closed: Option<Box<dyn Fn(&mut App)>>,
+
+    /// Offers a keystroke that no keymap binding claimed to the document
+    /// (the code editor's Vim layer). Returns `true` when it was consumed.
+    pub handle_unbound_keystroke: Option<Box<dyn Fn(&Keystroke, &mut Window, &mut App) -> bool>>,
 }
 
 impl PaneHandle {
@@ -155,10 +177,14 @@ impl PaneHandle {
             subscribe,
             set_category_filter: None,
             set_correlation_filter: None,
+            set_table_filter: None,
+            insert_text: None,
+            switch_execution_context: None,
             matches_event_stream: None,
             is_file_backed_empty: None,
             session_tab_snapshot: None,
             mark_inspector_closed: None,
+            handle_unbound_keystroke: None,
         }
     }
 
@@ -281,6 +307,8 @@ mod tests {
             file_path: None,
             scratch_path: Some(std::path::PathBuf::from("/tmp/scratch.sql")),
             shadow_path: None,
+            results_path: None,
+            data_state: None,
         };
 
         let cloned = snap.clone();
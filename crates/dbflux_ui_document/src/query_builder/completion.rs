@@ -427,6 +427,8 @@ mod tests {
             is_primary_key: false,
             default_value: None,
             enum_values: None,
+            comment: None,
+            definition: None,
         }
     }
 
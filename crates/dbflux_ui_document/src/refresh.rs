@@ -1,9 +1,12 @@
-//! Shared refresh constants used by both `ChartDocument` and `DashboardDocument`.
+//! Shared refresh constants used by both `ChartDocument` and `DashboardDocument`,
+//! and the auto-refresh countdown shared by `CodeDocument` and `DataGridPanel`.
 //!
-//! Centralising here prevents the two call sites from drifting to different
+//! Centralising here prevents the call sites from drifting to different
 //! interval lists or floor values.
 
 use dbflux_components::SavedChartRefreshPolicy;
+use dbflux_components::controls::DropdownItem;
+use dbflux_core::RefreshPolicy;
 
 /// Minimum floor for any auto-refresh interval (10 seconds).
 ///
@@ -24,10 +27,173 @@ pub const REFRESH_POLICY_OPTIONS: &[(SavedChartRefreshPolicy, &str)] = &[
     (SavedChartRefreshPolicy::Interval { every_secs: 300 }, "5m"),
 ];
 
+/// Dropdown index of the "Custom…" entry that follows the presets of
+/// `RefreshPolicy::ALL` in the query and table auto-refresh dropdowns.
+pub const CUSTOM_REFRESH_INDEX: usize = RefreshPolicy::ALL.len();
+
+/// Items of the query and table auto-refresh dropdowns: every preset, then
+/// "Custom…", which asks for an interval of its own.
+pub fn auto_refresh_dropdown_items() -> Vec<DropdownItem> {
+    RefreshPolicy::ALL
+        .iter()
+        .map(|policy| DropdownItem::new(policy.label()))
+        .chain(std::iter::once(DropdownItem::new("Custom…")))
+        .collect()
+}
+
+/// Dropdown index showing `policy`; intervals that are not a preset select
+/// the "Custom…" entry.
+pub fn auto_refresh_dropdown_index(policy: RefreshPolicy) -> usize {
+    RefreshPolicy::ALL
+        .iter()
+        .position(|preset| *preset == policy)
+        .unwrap_or(CUSTOM_REFRESH_INDEX)
+}
+
+/// Parse a custom refresh interval such as `45`, `45s`, `2m` or `1h` into
+/// seconds. Bare numbers are seconds; zero and malformed input are rejected.
+pub fn parse_refresh_interval(text: &str) -> Option<u32> {
+    let text = text.trim().to_ascii_lowercase();
+    let (digits, unit_secs) = match text.strip_suffix('h') {
+        Some(rest) => (rest, 3600),
+        None => match text.strip_suffix('m') {
+            Some(rest) => (rest, 60),
+            None => (text.strip_suffix('s').unwrap_or(&text), 1),
+        },
+    };
+
+    digits
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .and_then(|value| value.checked_mul(unit_secs))
+        .filter(|secs| *secs > 0)
+}
+
+/// Short label for an auto-refresh interval: `45s`, `2m`, `1m 30s`.
+pub fn format_refresh_secs(secs: u32) -> String {
+    match (secs / 60, secs % 60) {
+        (0, secs) => format!("{}s", secs),
+        (mins, 0) => format!("{}m", mins),
+        (mins, secs) => format!("{}m {:02}s", mins, secs),
+    }
+}
+
+/// Seconds left until the next automatic refresh of a query or table.
+///
+/// The owner's timer ticks it once per second; a paused countdown keeps its
+/// remaining time until it is resumed. A countdown that was never reset to an
+/// interval is not running.
+#[derive(Debug, Default)]
+pub struct RefreshCountdown {
+    remaining_secs: u32,
+    paused: bool,
+}
+
+impl RefreshCountdown {
+    /// Restart the countdown from the full interval of `policy`.
+    pub fn reset(&mut self, policy: RefreshPolicy) {
+        self.remaining_secs = policy.every_secs().unwrap_or(0);
+    }
+
+    /// Advance by one second. Returns `true` when the refresh is due, in
+    /// which case the countdown restarts from the full interval.
+    pub fn tick(&mut self, policy: RefreshPolicy) -> bool {
+        if self.paused || !policy.is_auto() {
+            return false;
+        }
+
+        self.remaining_secs = self.remaining_secs.saturating_sub(1);
+
+        if self.remaining_secs == 0 {
+            self.reset(policy);
+            return true;
+        }
+
+        false
+    }
+
+    /// Whether a countdown to an automatic refresh is under way.
+    pub fn is_running(&self) -> bool {
+        self.remaining_secs > 0
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Toolbar caption for the refresh control: the time left, "Paused", or
+    /// the interval of `policy` while no countdown is running.
+    pub fn label(&self, policy: RefreshPolicy) -> String {
+        match policy.every_secs() {
+            Some(every_secs) if !self.is_running() => format_refresh_secs(every_secs),
+            Some(_) if self.paused => "Paused".to_string(),
+            Some(_) => format_refresh_secs(self.remaining_secs),
+            None => "Refresh".to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn custom_intervals_select_the_custom_entry() {
+        let preset = RefreshPolicy::Interval { every_secs: 30 };
+        let custom = RefreshPolicy::Interval { every_secs: 45 };
+
+        assert_eq!(
+            auto_refresh_dropdown_items().len(),
+            CUSTOM_REFRESH_INDEX + 1
+        );
+        assert_eq!(auto_refresh_dropdown_index(preset), preset.index());
+        assert_eq!(auto_refresh_dropdown_index(custom), CUSTOM_REFRESH_INDEX);
+    }
+
+    #[test]
+    fn parse_refresh_interval_accepts_units() {
+        assert_eq!(parse_refresh_interval("45"), Some(45));
+        assert_eq!(parse_refresh_interval(" 45s "), Some(45));
+        assert_eq!(parse_refresh_interval("2m"), Some(120));
+        assert_eq!(parse_refresh_interval("1H"), Some(3600));
+        assert_eq!(parse_refresh_interval("0"), None);
+        assert_eq!(parse_refresh_interval("soon"), None);
+        assert_eq!(parse_refresh_interval("-5"), None);
+    }
+
+    #[test]
+    fn countdown_fires_once_per_interval_and_holds_while_paused() {
+        let policy = RefreshPolicy::Interval { every_secs: 3 };
+        let mut countdown = RefreshCountdown::default();
+        assert!(!countdown.is_running());
+        assert_eq!(countdown.label(policy), "3s");
+        countdown.reset(policy);
+
+        assert!(!countdown.tick(policy));
+        assert_eq!(countdown.label(policy), "2s");
+
+        countdown.set_paused(true);
+        assert!(!countdown.tick(policy));
+        assert_eq!(countdown.label(policy), "Paused");
+
+        countdown.set_paused(false);
+        assert!(!countdown.tick(policy));
+        assert!(countdown.tick(policy));
+        assert_eq!(countdown.label(policy), "3s");
+    }
+
+    #[test]
+    fn format_refresh_secs_uses_minutes_past_a_minute() {
+        assert_eq!(format_refresh_secs(9), "9s");
+        assert_eq!(format_refresh_secs(120), "2m");
+        assert_eq!(format_refresh_secs(90), "1m 30s");
+    }
+
     #[test]
     fn refresh_policy_options_minimum_interval_is_at_least_10s() {
         for (policy, label) in REFRESH_POLICY_OPTIONS {
@@ -0,0 +1,382 @@
+//! Scheduled exports: runs saved queries on their cron schedule while the app
+//! is open and writes each result to a timestamped file.
+//!
+//! Every run shows up in the tasks panel and is recorded in the export's run
+//! history; failures also raise a toast. A schedule whose previous run is
+//! still going skips its next slot instead of piling up runs.
+
+mod modal;
+
+pub use modal::ScheduledExportsModal;
+
+use dbflux_core::chrono::{DateTime, Local, Utc};
+use dbflux_core::{
+    Connection, QueryRequest, ScheduledExport, ScheduledExportRun, TaskId, TaskKind, TaskTarget,
+    bind_saved_query_params, substitute_query_variables,
+};
+use dbflux_export::ExportFormat;
+use dbflux_ui_base::toast::{Toast, now_hms};
+use dbflux_ui_base::{AppStateChanged, AppStateEntity};
+use gpui::*;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// How often due schedules are checked. Cron has minute granularity.
+const TICK: Duration = Duration::from_secs(15);
+
+/// Emitted when a run starts or finishes, so views can refresh.
+#[derive(Clone, Debug)]
+pub struct ScheduledExportsChanged;
+
+/// Everything a run needs, resolved on the UI thread.
+struct PreparedRun {
+    conn: Arc<dyn Connection>,
+    sql: String,
+    database: Option<String>,
+    format: ExportFormat,
+}
+
+pub struct ExportScheduler {
+    app_state: Entity<AppStateEntity>,
+    /// Next fire time of each enabled schedule, with the expression it was
+    /// computed from so edits are picked up.
+    next_runs: HashMap<Uuid, (String, DateTime<Local>)>,
+    running: HashSet<Uuid>,
+    _timer: Task<()>,
+}
+
+impl ExportScheduler {
+    pub fn new(app_state: Entity<AppStateEntity>, cx: &mut Context<Self>) -> Self {
+        let timer = cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor().timer(TICK).await;
+
+                if this
+                    .update(cx, |scheduler, cx| scheduler.run_due(cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        let mut scheduler = Self {
+            app_state,
+            next_runs: HashMap::new(),
+            running: HashSet::new(),
+            _timer: timer,
+        };
+        scheduler.reschedule(cx);
+        scheduler
+    }
+
+    /// When `id` fires next, if it is enabled and its schedule is valid.
+    pub fn next_run(&self, id: Uuid) -> Option<DateTime<Local>> {
+        self.next_runs.get(&id).map(|(_, next)| *next)
+    }
+
+    pub fn is_running(&self, id: Uuid) -> bool {
+        self.running.contains(&id)
+    }
+
+    /// Syncs the next fire times with the stored schedules: new and edited
+    /// ones are planned from now, disabled and deleted ones are dropped.
+    pub fn reschedule(&mut self, cx: &mut Context<Self>) {
+        let now = Local::now();
+        let mut next_runs = HashMap::new();
+
+        for export in self.app_state.read(cx).scheduled_exports() {
+            if !export.enabled {
+                continue;
+            }
+
+            if let Some(planned) = self
+                .next_runs
+                .remove(&export.id)
+                .filter(|(schedule, _)| *schedule == export.schedule)
+            {
+                next_runs.insert(export.id, planned);
+                continue;
+            }
+
+            match export.cron() {
+                Ok(cron) => {
+                    if let Some(next) = cron.next_after(&now) {
+                        next_runs.insert(export.id, (export.schedule.clone(), next));
+                    }
+                }
+                Err(error) => {
+                    log::warn!(
+                        "Scheduled export '{}' has an invalid schedule: {}",
+                        export.name,
+                        error
+                    );
+                }
+            }
+        }
+
+        if next_runs != self.next_runs {
+            self.next_runs = next_runs;
+            cx.emit(ScheduledExportsChanged);
+            cx.notify();
+        }
+    }
+
+    fn run_due(&mut self, cx: &mut Context<Self>) {
+        self.reschedule(cx);
+
+        let now = Local::now();
+        let due: Vec<Uuid> = self
+            .next_runs
+            .iter()
+            .filter(|(_, (_, next))| *next <= now)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in due {
+            if let Some((schedule, next)) = self.next_runs.get_mut(&id) {
+                match dbflux_core::CronSchedule::parse(schedule)
+                    .ok()
+                    .and_then(|cron| cron.next_after(&now))
+                {
+                    Some(following) => *next = following,
+                    None => {
+                        self.next_runs.remove(&id);
+                    }
+                }
+            }
+
+            if self.running.contains(&id) {
+                log::info!("Skipping scheduled export {}: previous run still going", id);
+                continue;
+            }
+
+            self.run(id, cx);
+        }
+    }
+
+    /// Runs `id` immediately, outside its schedule.
+    pub fn run_now(&mut self, id: Uuid, cx: &mut Context<Self>) {
+        if !self.running.contains(&id) {
+            self.run(id, cx);
+        }
+    }
+
+    fn run(&mut self, id: Uuid, cx: &mut Context<Self>) {
+        let Some(export) = self.app_state.read(cx).get_scheduled_export(id).cloned() else {
+            return;
+        };
+
+        let profile_id = self
+            .app_state
+            .read(cx)
+            .get_saved_query(export.saved_query_id)
+            .and_then(|query| query.connection_id);
+
+        let (task_id, cancel_token, admission) = self.app_state.update(cx, |state, cx| {
+            let description = format!("Scheduled export: {}", export.name);
+            let started = match profile_id {
+                Some(profile_id) => {
+                    let (task_id, cancel_token, admission) = state.enqueue_task_for_target(
+                        TaskKind::Export,
+                        description,
+                        TaskTarget {
+                            profile_id,
+                            database: None,
+                        },
+                    );
+                    (task_id, cancel_token, Some(admission))
+                }
+                None => {
+                    let (task_id, cancel_token) =
+                        state.start_task_for_target(TaskKind::Export, description, None);
+                    (task_id, cancel_token, None)
+                }
+            };
+            cx.emit(AppStateChanged);
+            started
+        });
+
+        let started_at = Utc::now().timestamp();
+        let started = Instant::now();
+
+        let prepared = match self.prepare(&export, cx) {
+            Ok(prepared) => prepared,
+            Err(error) => {
+                self.finish(&export, task_id, started_at, started, Err(error), cx);
+                return;
+            }
+        };
+
+        self.running.insert(id);
+        cx.emit(ScheduledExportsChanged);
+        cx.notify();
+
+        let path = export.output_path(&Local::now(), prepared.format.extension());
+
+        cx.spawn(async move |this, cx| {
+            let admitted = match admission {
+                Some(admission) => admission.await,
+                None => true,
+            };
+
+            let result = if admitted {
+                cx.background_executor()
+                    .spawn(async move { execute_and_write(prepared, path) })
+                    .await
+            } else {
+                Err("Cancelled while queued".to_string())
+            };
+
+            if cancel_token.is_cancelled() {
+                let _ = this.update(cx, |scheduler, cx| {
+                    scheduler.running.remove(&id);
+                    cx.emit(ScheduledExportsChanged);
+                    cx.notify();
+                });
+                return;
+            }
+
+            let _ = this.update(cx, |scheduler, cx| {
+                scheduler.running.remove(&id);
+                scheduler.finish(&export, task_id, started_at, started, result, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// Resolves the saved query, its connection and the final SQL, with
+    /// parameters bound to their last-used or default values and `{{name}}`
+    /// variables substituted.
+    fn prepare(&self, export: &ScheduledExport, cx: &App) -> Result<PreparedRun, String> {
+        let format = ExportFormat::from_key(&export.format)
+            .ok_or_else(|| format!("Unknown export format '{}'", export.format))?;
+
+        let state = self.app_state.read(cx);
+        let query = state
+            .get_saved_query(export.saved_query_id)
+            .ok_or("The saved query no longer exists")?;
+        let profile_id = query
+            .connection_id
+            .ok_or("The saved query is not tied to a connection")?;
+
+        let Some(connected) = state.connections().get(&profile_id) else {
+            let name = state
+                .profiles()
+                .iter()
+                .find(|profile| profile.id == profile_id)
+                .map(|profile| profile.name.clone())
+                .unwrap_or_else(|| "Its connection".to_string());
+            return Err(format!("{} is not connected", name));
+        };
+
+        let database = connected.active_database.clone().or_else(|| {
+            connected
+                .schema
+                .as_ref()
+                .and_then(|schema| schema.as_relational())
+                .and_then(|relational| relational.current_database.clone())
+        });
+        let conn = match database.as_deref() {
+            Some(database) => connected.connection_for_database(database),
+            None => connected.connection.clone(),
+        };
+
+        let mut sql = query.sql.clone();
+        if !query.params.is_empty() {
+            let inputs: HashMap<String, String> = query
+                .params
+                .iter()
+                .map(|param| (param.name.clone(), param.initial_value().to_string()))
+                .collect();
+            sql = bind_saved_query_params(&sql, &query.params, &inputs, conn.dialect())
+                .map_err(|error| error.to_string())?;
+        }
+
+        let sql =
+            substitute_query_variables(&sql, &state.resolved_query_variables(Some(profile_id)))
+                .map_err(|error| error.to_string())?;
+
+        Ok(PreparedRun {
+            conn,
+            sql,
+            database,
+            format,
+        })
+    }
+
+    fn finish(
+        &mut self,
+        export: &ScheduledExport,
+        task_id: TaskId,
+        started_at: i64,
+        started: Instant,
+        result: Result<(PathBuf, usize), String>,
+        cx: &mut Context<Self>,
+    ) {
+        let run = ScheduledExportRun {
+            schedule_id: export.id,
+            started_at,
+            duration_ms: started.elapsed().as_millis() as u64,
+            succeeded: result.is_ok(),
+            detail: match &result {
+                Ok((path, rows)) => format!("{} rows written to {}", rows, path.display()),
+                Err(error) => error.clone(),
+            },
+        };
+
+        self.app_state.update(cx, |state, cx| {
+            state.record_scheduled_export_run(&run);
+            if run.succeeded {
+                state.complete_task_with_details(task_id, run.detail.clone());
+            } else {
+                state.fail_task(task_id, run.detail.clone());
+            }
+            cx.emit(AppStateChanged);
+        });
+
+        if let Err(error) = result {
+            log::warn!("Scheduled export '{}' failed: {}", export.name, error);
+            Toast::error(format!(
+                "Scheduled export \"{}\" failed: {}",
+                export.name, error
+            ))
+            .meta_right(now_hms())
+            .push(cx);
+        }
+
+        cx.emit(ScheduledExportsChanged);
+        cx.notify();
+    }
+}
+
+/// Runs the query and writes its result to `path`, returning the path and
+/// the number of rows written. Runs on the background executor.
+fn execute_and_write(prepared: PreparedRun, path: PathBuf) -> Result<(PathBuf, usize), String> {
+    let request = QueryRequest::new(prepared.sql).with_database(prepared.database);
+    let result = prepared
+        .conn
+        .execute(&request)
+        .map_err(|error| error.to_string())?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|error| format!("Cannot create {}: {}", dir.display(), error))?;
+    }
+
+    let file = std::fs::File::create(&path)
+        .map_err(|error| format!("Cannot create {}: {}", path.display(), error))?;
+    let mut writer = BufWriter::new(file);
+
+    dbflux_export::export(&result, prepared.format, &mut writer)
+        .map_err(|error| error.to_string())?;
+    writer.flush().map_err(|error| error.to_string())?;
+
+    Ok((path, result.row_count()))
+}
+
+impl EventEmitter<ScheduledExportsChanged> for ExportScheduler {}
@@ -0,0 +1,688 @@
+//! "Scheduled exports" modal: lists the schedules with their next and last
+//! runs, and adds, edits, pauses, runs and deletes them.
+
+use super::{ExportScheduler, ScheduledExportsChanged};
+use dbflux_components::controls::{Button, Dropdown, DropdownItem, GpuiInput as Input, InputState};
+use dbflux_components::modals::shell::ModalShell;
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{FontSizes, Radii, Spacing};
+use dbflux_core::{CronSchedule, QueryResultShape, ScheduledExport, ScheduledExportRun};
+use dbflux_export::{ExportFormat, available_formats};
+use dbflux_ui_base::AppStateEntity;
+use dbflux_ui_base::async_ext::AsyncUpdateResultExt;
+use dbflux_ui_base::file_dialog::{fallback_export_dir, is_native_file_dialog_available};
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Runs listed when a schedule's history is expanded.
+const HISTORY_RUNS: usize = 10;
+
+pub struct ScheduledExportsModal {
+    app_state: Entity<AppStateEntity>,
+    scheduler: Entity<ExportScheduler>,
+    visible: bool,
+    name_input: Entity<InputState>,
+    schedule_input: Entity<InputState>,
+    dir_input: Entity<InputState>,
+    query_dropdown: Entity<Dropdown>,
+    format_dropdown: Entity<Dropdown>,
+    /// The schedule being edited; `None` while the form adds a new one.
+    editing: Option<Uuid>,
+    form_error: Option<String>,
+    /// Latest run of each schedule, refreshed when a run finishes.
+    last_runs: HashMap<Uuid, ScheduledExportRun>,
+    /// The schedule whose history is expanded, with its runs.
+    history: Option<(Uuid, Vec<ScheduledExportRun>)>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ScheduledExportsModal {
+    pub fn new(
+        app_state: Entity<AppStateEntity>,
+        scheduler: Entity<ExportScheduler>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Daily sales"));
+        let schedule_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("0 6 * * *  or  @daily"));
+        let dir_input = cx.new(|cx| InputState::new(window, cx).placeholder("Output folder"));
+
+        let query_dropdown =
+            cx.new(|_| Dropdown::new("scheduled-export-query").placeholder("Saved query"));
+        let format_dropdown = cx.new(|_| {
+            Dropdown::new("scheduled-export-format")
+                .items(
+                    table_formats()
+                        .iter()
+                        .map(|format| DropdownItem::with_value(format.name(), format.key()))
+                        .collect(),
+                )
+                .selected_index(Some(0))
+        });
+
+        let subscription = cx.subscribe(&scheduler, |this, _, _: &ScheduledExportsChanged, cx| {
+            if this.visible {
+                this.refresh_runs(cx);
+            }
+        });
+
+        Self {
+            app_state,
+            scheduler,
+            visible: false,
+            name_input,
+            schedule_input,
+            dir_input,
+            query_dropdown,
+            format_dropdown,
+            editing: None,
+            form_error: None,
+            last_runs: HashMap::new(),
+            history: None,
+            _subscriptions: vec![subscription],
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.visible = true;
+        self.reset_form(window, cx);
+        self.refresh_runs(cx);
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.visible = false;
+        cx.notify();
+    }
+
+    fn refresh_runs(&mut self, cx: &mut Context<Self>) {
+        let state = self.app_state.read(cx);
+
+        self.last_runs = state
+            .scheduled_exports()
+            .iter()
+            .filter_map(|export| {
+                state
+                    .scheduled_export_runs(export.id, 1)
+                    .into_iter()
+                    .next()
+                    .map(|run| (export.id, run))
+            })
+            .collect();
+
+        if let Some((id, runs)) = self.history.as_mut() {
+            *runs = state.scheduled_export_runs(*id, HISTORY_RUNS);
+        }
+
+        cx.notify();
+    }
+
+    /// Saved queries that can run unattended: those tied to a connection.
+    fn schedulable_queries(&self, cx: &App) -> Vec<DropdownItem> {
+        self.app_state
+            .read(cx)
+            .saved_queries()
+            .iter()
+            .filter(|query| query.connection_id.is_some())
+            .map(|query| DropdownItem::with_value(query.name.clone(), query.id.to_string()))
+            .collect()
+    }
+
+    fn reset_form(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.editing = None;
+        self.form_error = None;
+
+        let default_dir = fallback_export_dir()
+            .map(|dir| dir.display().to_string())
+            .unwrap_or_default();
+
+        self.name_input
+            .update(cx, |state, cx| state.set_value("", window, cx));
+        self.schedule_input
+            .update(cx, |state, cx| state.set_value("@daily", window, cx));
+        self.dir_input
+            .update(cx, |state, cx| state.set_value(default_dir, window, cx));
+
+        let queries = self.schedulable_queries(cx);
+        self.query_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_items(queries, cx);
+            dropdown.set_selected_index(None, cx);
+        });
+        self.format_dropdown
+            .update(cx, |dropdown, cx| dropdown.set_selected_index(Some(0), cx));
+
+        self.name_input
+            .update(cx, |state, cx| state.focus(window, cx));
+        cx.notify();
+    }
+
+    fn edit(&mut self, id: Uuid, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(export) = self.app_state.read(cx).get_scheduled_export(id).cloned() else {
+            return;
+        };
+
+        let queries = self.schedulable_queries(cx);
+        let query_index = queries
+            .iter()
+            .position(|item| item.value.as_ref() == export.saved_query_id.to_string());
+        let format_index = table_formats()
+            .iter()
+            .position(|format| format.key() == export.format);
+
+        self.name_input.update(cx, |state, cx| {
+            state.set_value(export.name.clone(), window, cx)
+        });
+        self.schedule_input.update(cx, |state, cx| {
+            state.set_value(export.schedule.clone(), window, cx)
+        });
+        self.dir_input.update(cx, |state, cx| {
+            state.set_value(export.output_dir.display().to_string(), window, cx)
+        });
+        self.query_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_items(queries, cx);
+            dropdown.set_selected_index(query_index, cx);
+        });
+        self.format_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_selected_index(format_index.or(Some(0)), cx)
+        });
+
+        self.editing = Some(id);
+        self.form_error = None;
+        cx.notify();
+    }
+
+    fn save(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        match self.form_export(cx) {
+            Ok(export) => {
+                self.app_state
+                    .update(cx, |state, _| state.save_scheduled_export(export));
+                self.scheduler
+                    .update(cx, |scheduler, cx| scheduler.reschedule(cx));
+                self.reset_form(window, cx);
+                self.refresh_runs(cx);
+            }
+            Err(error) => {
+                self.form_error = Some(error);
+                cx.notify();
+            }
+        }
+    }
+
+    /// Builds the schedule described by the form, keeping the id, creation
+    /// time and enabled flag of the one being edited.
+    fn form_export(&self, cx: &App) -> Result<ScheduledExport, String> {
+        let name = self.name_input.read(cx).value().trim().to_string();
+        let schedule = self.schedule_input.read(cx).value().trim().to_string();
+        let output_dir = self.dir_input.read(cx).value().trim().to_string();
+
+        if name.is_empty() {
+            return Err("Give the export a name".to_string());
+        }
+
+        let saved_query_id = self
+            .query_dropdown
+            .read(cx)
+            .selected_value()
+            .and_then(|value| Uuid::parse_str(&value).ok())
+            .ok_or("Pick the saved query to export")?;
+
+        CronSchedule::parse(&schedule).map_err(|error| format!("Invalid schedule: {}", error))?;
+
+        if output_dir.is_empty() {
+            return Err("Choose the folder to write the files to".to_string());
+        }
+
+        let format = self
+            .format_dropdown
+            .read(cx)
+            .selected_value()
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| ExportFormat::Csv.key().to_string());
+
+        let mut export = ScheduledExport::new(
+            name,
+            saved_query_id,
+            schedule,
+            format,
+            PathBuf::from(output_dir),
+        );
+
+        if let Some(existing) = self
+            .editing
+            .and_then(|id| self.app_state.read(cx).get_scheduled_export(id))
+        {
+            export.id = existing.id;
+            export.created_at = existing.created_at;
+            export.enabled = existing.enabled;
+        }
+
+        Ok(export)
+    }
+
+    fn browse_output_dir(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !is_native_file_dialog_available() {
+            self.form_error = Some("No folder picker is available; type the path".to_string());
+            cx.notify();
+            return;
+        }
+
+        cx.spawn_in(window, async move |this, cx| {
+            let Some(folder) = rfd::AsyncFileDialog::new()
+                .set_title("Folder for scheduled export files")
+                .pick_folder()
+                .await
+            else {
+                return;
+            };
+            let path = folder.path().display().to_string();
+
+            this.update_in(cx, |this, window, cx| {
+                this.dir_input
+                    .update(cx, |state, cx| state.set_value(path, window, cx));
+            })
+            .log_if_dropped();
+        })
+        .detach();
+    }
+
+    fn toggle_enabled(&mut self, id: Uuid, cx: &mut Context<Self>) {
+        let Some(mut export) = self.app_state.read(cx).get_scheduled_export(id).cloned() else {
+            return;
+        };
+
+        export.enabled = !export.enabled;
+        self.app_state
+            .update(cx, |state, _| state.save_scheduled_export(export));
+        self.scheduler
+            .update(cx, |scheduler, cx| scheduler.reschedule(cx));
+        cx.notify();
+    }
+
+    fn delete(&mut self, id: Uuid, window: &mut Window, cx: &mut Context<Self>) {
+        self.app_state.update(cx, |state, _| {
+            state.remove_scheduled_export(id);
+        });
+        self.scheduler
+            .update(cx, |scheduler, cx| scheduler.reschedule(cx));
+
+        if self.editing == Some(id) {
+            self.reset_form(window, cx);
+        }
+        if self.history.as_ref().is_some_and(|(shown, _)| *shown == id) {
+            self.history = None;
+        }
+        self.refresh_runs(cx);
+    }
+
+    fn toggle_history(&mut self, id: Uuid, cx: &mut Context<Self>) {
+        if self.history.as_ref().is_some_and(|(shown, _)| *shown == id) {
+            self.history = None;
+        } else {
+            let runs = self
+                .app_state
+                .read(cx)
+                .scheduled_export_runs(id, HISTORY_RUNS);
+            self.history = Some((id, runs));
+        }
+        cx.notify();
+    }
+
+    fn render_schedule_row(
+        &self,
+        ix: usize,
+        export: &ScheduledExport,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let theme = cx.theme().clone();
+        let id = export.id;
+        let scheduler = self.scheduler.read(cx);
+        let running = scheduler.is_running(id);
+
+        let query_name = self
+            .app_state
+            .read(cx)
+            .get_saved_query(export.saved_query_id)
+            .map(|query| query.name.clone())
+            .unwrap_or_else(|| "(missing query)".to_string());
+        let format_name = ExportFormat::from_key(&export.format)
+            .map(ExportFormat::name)
+            .unwrap_or(export.format.as_str());
+
+        let next = if running {
+            "Running…".to_string()
+        } else if !export.enabled {
+            "Paused".to_string()
+        } else {
+            match (export.cron(), scheduler.next_run(id)) {
+                (Err(error), _) => format!("Invalid schedule: {}", error),
+                (Ok(_), Some(next)) => format!("Next run {}", next.format("%Y-%m-%d %H:%M")),
+                (Ok(_), None) => "Never runs".to_string(),
+            }
+        };
+
+        let last = self.last_runs.get(&id).map(|run| {
+            format!(
+                "Last run {}: {}",
+                run.formatted_started_at(),
+                if run.succeeded { "succeeded" } else { "failed" }
+            )
+        });
+        let last_failed = self.last_runs.get(&id).is_some_and(|run| !run.succeeded);
+
+        let history = self
+            .history
+            .as_ref()
+            .filter(|(shown, _)| *shown == id)
+            .map(|(_, runs)| runs);
+
+        div()
+            .id(("scheduled-export-row", ix))
+            .flex()
+            .flex_col()
+            .gap(Spacing::XS)
+            .p(Spacing::XS)
+            .rounded(Radii::SM)
+            .hover(|d| d.bg(theme.secondary))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::XS)
+                    .child(Text::label_sm(export.name.clone()))
+                    .child(div().flex_1())
+                    .child(
+                        Button::new(("scheduled-export-run", ix), "Run Now")
+                            .small()
+                            .ghost()
+                            .disabled(running)
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.scheduler
+                                    .update(cx, |scheduler, cx| scheduler.run_now(id, cx));
+                            })),
+                    )
+                    .child(
+                        Button::new(
+                            ("scheduled-export-toggle", ix),
+                            if export.enabled { "Pause" } else { "Resume" },
+                        )
+                        .small()
+                        .ghost()
+                        .on_click(cx.listener(move |this, _, _, cx| this.toggle_enabled(id, cx))),
+                    )
+                    .child(
+                        Button::new(("scheduled-export-history", ix), "History")
+                            .small()
+                            .ghost()
+                            .on_click(
+                                cx.listener(move |this, _, _, cx| this.toggle_history(id, cx)),
+                            ),
+                    )
+                    .child(
+                        Button::new(("scheduled-export-edit", ix), "Edit")
+                            .small()
+                            .ghost()
+                            .on_click(
+                                cx.listener(move |this, _, window, cx| this.edit(id, window, cx)),
+                            ),
+                    )
+                    .child(
+                        Button::new(("scheduled-export-delete", ix), "Delete")
+                            .small()
+                            .ghost()
+                            .on_click(
+                                cx.listener(move |this, _, window, cx| this.delete(id, window, cx)),
+                            ),
+                    ),
+            )
+            .child(
+                Text::caption(format!(
+                    "{} · {} · {} → {}",
+                    query_name,
+                    export.schedule,
+                    format_name,
+                    export.output_dir.display()
+                ))
+                .muted_foreground(),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap(Spacing::SM)
+                    .text_size(FontSizes::XS)
+                    .child(div().text_color(theme.muted_foreground).child(next))
+                    .when_some(last, |el, last| {
+                        el.child(
+                            div()
+                                .text_color(if last_failed {
+                                    theme.danger
+                                } else {
+                                    theme.muted_foreground
+                                })
+                                .child(last),
+                        )
+                    }),
+            )
+            .when_some(history, |el, runs| el.child(render_history(runs, &theme)))
+            .into_any_element()
+    }
+}
+
+/// Formats offered for scheduled exports: those that fit a table result.
+fn table_formats() -> &'static [ExportFormat] {
+    available_formats(&QueryResultShape::Table)
+}
+
+fn render_history(runs: &[ScheduledExportRun], theme: &gpui_component::Theme) -> Div {
+    let mut list = div()
+        .flex()
+        .flex_col()
+        .gap(px(2.0))
+        .pl(Spacing::SM)
+        .text_size(FontSizes::XS);
+
+    if runs.is_empty() {
+        return list.child(
+            div()
+                .text_color(theme.muted_foreground)
+                .child("No runs yet"),
+        );
+    }
+
+    for run in runs {
+        list = list.child(
+            div()
+                .flex()
+                .gap(Spacing::SM)
+                .child(
+                    div()
+                        .text_color(if run.succeeded {
+                            theme.success
+                        } else {
+                            theme.danger
+                        })
+                        .child(if run.succeeded { "✓" } else { "✗" }),
+                )
+                .child(div().text_color(theme.muted_foreground).child(format!(
+                    "{} · {} ms",
+                    run.formatted_started_at(),
+                    run.duration_ms
+                )))
+                .child(
+                    div()
+                        .flex_1()
+                        .overflow_hidden()
+                        .text_color(theme.foreground)
+                        .child(run.detail.clone()),
+                ),
+        );
+    }
+
+    list
+}
+
+impl Render for ScheduledExportsModal {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let theme = cx.theme().clone();
+
+        let exports: Vec<ScheduledExport> = self.app_state.read(cx).scheduled_exports().to_vec();
+        let rows: Vec<AnyElement> = exports
+            .iter()
+            .enumerate()
+            .map(|(ix, export)| self.render_schedule_row(ix, export, cx))
+            .collect();
+
+        let field = |label: &'static str, control: AnyElement| {
+            div()
+                .flex_1()
+                .flex()
+                .flex_col()
+                .gap(Spacing::XS)
+                .child(Text::caption(label))
+                .child(control)
+        };
+
+        let form = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::SM)
+            .pt(Spacing::SM)
+            .border_t_1()
+            .border_color(theme.border)
+            .child(Text::label_sm(if self.editing.is_some() {
+                "Edit scheduled export"
+            } else {
+                "New scheduled export"
+            }))
+            .child(
+                div()
+                    .flex()
+                    .gap(Spacing::SM)
+                    .child(field(
+                        "Name",
+                        Input::new(&self.name_input).small().into_any_element(),
+                    ))
+                    .child(field(
+                        "Saved query",
+                        self.query_dropdown.clone().into_any_element(),
+                    )),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap(Spacing::SM)
+                    .child(field(
+                        "Schedule (cron)",
+                        Input::new(&self.schedule_input).small().into_any_element(),
+                    ))
+                    .child(field(
+                        "Format",
+                        self.format_dropdown.clone().into_any_element(),
+                    )),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_end()
+                    .gap(Spacing::SM)
+                    .child(field(
+                        "Folder",
+                        Input::new(&self.dir_input).small().into_any_element(),
+                    ))
+                    .child(
+                        Button::new("scheduled-export-browse", "Browse…")
+                            .small()
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.browse_output_dir(window, cx)
+                            })),
+                    ),
+            )
+            .child(
+                Text::caption(
+                    "Fields: minute hour day month weekday. Runs only while DBFlux is open; \
+                     query parameters use their last or default values.",
+                )
+                .muted_foreground(),
+            )
+            .when_some(self.form_error.clone(), |el, error| {
+                el.child(
+                    div()
+                        .text_size(FontSizes::XS)
+                        .text_color(theme.danger)
+                        .child(error),
+                )
+            });
+
+        let body = div()
+            .flex()
+            .flex_col()
+            .gap(Spacing::SM)
+            .child(
+                div()
+                    .id("scheduled-export-rows")
+                    .max_h(px(320.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::XS)
+                    .when(rows.is_empty(), |el| {
+                        el.child(Text::caption("No scheduled exports yet").muted_foreground())
+                    })
+                    .children(rows),
+            )
+            .child(form);
+
+        let on_close = cx.listener(|this, _: &ClickEvent, _, cx| this.close(cx));
+        let on_save = cx.listener(|this, _: &ClickEvent, window, cx| this.save(window, cx));
+
+        let footer = div()
+            .flex()
+            .items_center()
+            .gap(Spacing::SM)
+            .child(Button::new("scheduled-export-close", "Close").on_click(on_close))
+            .when(self.editing.is_some(), |el| {
+                el.child(
+                    Button::new("scheduled-export-cancel-edit", "Cancel Edit").on_click(
+                        cx.listener(|this, _: &ClickEvent, window, cx| this.reset_form(window, cx)),
+                    ),
+                )
+            })
+            .child(
+                Button::new(
+                    "scheduled-export-save",
+                    if self.editing.is_some() {
+                        "Save Changes"
+                    } else {
+                        "Add Schedule"
+                    },
+                )
+                .primary()
+                .on_click(on_save),
+            );
+
+        let close_for_x = cx.entity().clone();
+
+        ModalShell::new(
+            "Scheduled exports",
+            body.into_any_element(),
+            footer.into_any_element(),
+        )
+        .width(px(720.0))
+        .on_close(move |_window, cx| {
+            close_for_x.update(cx, |this, cx| this.close(cx));
+        })
+        .into_any_element()
+    }
+}
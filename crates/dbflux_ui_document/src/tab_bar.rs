@@ -16,6 +16,33 @@ use gpui_component::tooltip::Tooltip;
 
 const TAB_BAR_HEIGHT: Pixels = Heights::TAB;
 
+/// Drag payload of a tab. Dropping it on another tab moves it there, on a
+/// group label moves it to that group, and on the empty end of the bar
+/// starts a new group.
+#[derive(Clone)]
+struct DraggedTab {
+    /// Tab manager the tab belongs to; drops on other windows are ignored.
+    manager: EntityId,
+    id: DocumentId,
+    title: SharedString,
+}
+
+impl Render for DraggedTab {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+
+        div()
+            .bg(theme.tab_bar)
+            .border_1()
+            .border_color(theme.drag_border)
+            .rounded(Radii::SM)
+            .px(Spacing::SM)
+            .py(Spacing::XS)
+            .shadow_md()
+            .child(MonoMeta::new(self.title.clone()).color(theme.foreground))
+    }
+}
+
 #[allow(dead_code)]
 pub struct TabBar {
     tab_manager: Entity<TabManager>,
@@ -48,6 +75,9 @@ pub const TAB_MENU_CLOSE_ALL: usize = 2;
 pub const TAB_MENU_SEPARATOR: usize = 3;
 pub const TAB_MENU_CLOSE_LEFT: usize = 4;
 pub const TAB_MENU_CLOSE_RIGHT: usize = 5;
+#[allow(dead_code)]
+pub const TAB_MENU_SEPARATOR_WINDOW: usize = 6;
+pub const TAB_MENU_OPEN_IN_NEW_WINDOW: usize = 7;
 
 impl TabBar {
     pub fn new(tab_manager: Entity<TabManager>, cx: &mut Context<Self>) -> Self {
@@ -92,6 +122,7 @@ impl TabBar {
             TAB_MENU_CLOSE_ALL => cx.emit(TabBarEvent::CloseAllTabs),
             TAB_MENU_CLOSE_LEFT => cx.emit(TabBarEvent::CloseTabsToLeft(tab_id)),
             TAB_MENU_CLOSE_RIGHT => cx.emit(TabBarEvent::CloseTabsToRight(tab_id)),
+            TAB_MENU_OPEN_IN_NEW_WINDOW => cx.emit(TabBarEvent::OpenInNewWindow(tab_id)),
             _ => {}
         }
 
@@ -106,6 +137,8 @@ impl TabBar {
             MenuItem::separator(),
             MenuItem::new("Close to the Left").icon(AppIcon::ChevronLeft),
             MenuItem::new("Close to the Right").icon(AppIcon::ChevronRight),
+            MenuItem::separator(),
+            MenuItem::new("Open in New Window").icon(AppIcon::ExternalLink),
         ]
     }
 
@@ -202,11 +235,34 @@ impl Render for TabBar {
         let tab_data: Vec<_> = manager
             .documents()
             .iter()
-            .map(|doc| (doc.meta_snapshot(cx), doc.change_summary(cx)))
+            .enumerate()
+            .filter(|(_, doc)| !manager.is_detached(doc.id()))
+            .map(|(idx, doc)| {
+                (
+                    idx,
+                    manager.group_of(doc.id()),
+                    doc.meta_snapshot(cx),
+                    doc.change_summary(cx),
+                )
+            })
             .collect();
 
+        // Group labels are only shown once the tabs are split into groups.
+        let mut groups = manager.docked_groups();
+        groups.dedup();
+        let show_groups = groups.len() > 1;
+
         let mut tabs: Vec<AnyElement> = Vec::with_capacity(tab_data.len());
-        for (idx, (meta, change_summary)) in tab_data.into_iter().enumerate() {
+        let mut current_group = None;
+        for (idx, group, meta, change_summary) in tab_data {
+            if show_groups && current_group != Some(group) {
+                tabs.push(
+                    self.render_group_label(group, current_group.is_some(), cx)
+                        .into_any_element(),
+                );
+                current_group = Some(group);
+            }
+
             tabs.push(
                 self.render_tab(meta, change_summary, idx, active_id, drop_target_index, cx)
                     .into_any_element(),
@@ -235,7 +291,7 @@ impl Render for TabBar {
                     .children(tabs)
                     .child(new_tab_btn),
             )
-            .child(div().flex_1())
+            .child(self.render_new_group_drop_zone(cx))
     }
 }
 
@@ -283,9 +339,18 @@ impl TabBar {
             super::types::DocumentIcon::Audit => AppIcon::ScrollText,
             super::types::DocumentIcon::Chart => AppIcon::ChartSpline,
             super::types::DocumentIcon::Dashboard => AppIcon::ChartSpline,
+            super::types::DocumentIcon::Notebook => AppIcon::Layers,
+            super::types::DocumentIcon::Users => AppIcon::KeyRound,
         };
 
         let center_x = self.active_tab_center_x.clone();
+        let manager_id = tab_manager.entity_id();
+        let dragged = DraggedTab {
+            manager: manager_id,
+            id,
+            title: title.clone().into(),
+        };
+        let drop_border = cx.theme().accent;
 
         div()
             .id(ElementId::Name(format!("tab-{}", id.0).into()))
@@ -326,6 +391,25 @@ impl TabBar {
             .when(is_drop_target, |el| {
                 el.border_l_2().border_color(cx.theme().accent)
             })
+            // Dragging a tab onto another one moves it in front of it.
+            .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+            .drag_over::<DraggedTab>(move |style, dragged, _, _| {
+                if dragged.manager == manager_id && dragged.id != id {
+                    style.border_l_2().border_color(drop_border)
+                } else {
+                    style
+                }
+            })
+            .on_drop({
+                let tab_manager = tab_manager.clone();
+                move |dragged: &DraggedTab, _, cx| {
+                    if dragged.manager == manager_id {
+                        tab_manager.update(cx, |mgr, cx| {
+                            mgr.move_tab_before(dragged.id, id, cx);
+                        });
+                    }
+                }
+            })
             // Click to activate
             .on_click({
                 let tab_manager = tab_manager.clone();
@@ -442,6 +526,68 @@ impl TabBar {
             })
     }
 
+    fn render_group_label(
+        &self,
+        group: usize,
+        separated: bool,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let tab_manager = self.tab_manager.clone();
+        let manager_id = tab_manager.entity_id();
+        let theme = cx.theme();
+        let drop_bg = theme.drop_target;
+
+        div()
+            .id(("tab-group", group))
+            .h_full()
+            .flex()
+            .flex_shrink_0()
+            .items_center()
+            .px(Spacing::SM)
+            .when(separated, |el| el.border_l_1().border_color(theme.border))
+            .child(MonoMeta::new(format!("Group {}", group + 1)).color(theme.muted_foreground))
+            .drag_over::<DraggedTab>(move |style, dragged, _, _| {
+                if dragged.manager == manager_id {
+                    style.bg(drop_bg)
+                } else {
+                    style
+                }
+            })
+            .on_drop(move |dragged: &DraggedTab, _, cx| {
+                if dragged.manager == manager_id {
+                    tab_manager.update(cx, |mgr, cx| {
+                        mgr.move_tab_to_group(dragged.id, group, cx);
+                    });
+                }
+            })
+    }
+
+    /// Empty end of the tab bar: dropping a tab here starts a new group.
+    fn render_new_group_drop_zone(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let tab_manager = self.tab_manager.clone();
+        let manager_id = tab_manager.entity_id();
+        let drop_bg = cx.theme().drop_target;
+
+        div()
+            .id("tab-bar-new-group")
+            .flex_1()
+            .h_full()
+            .drag_over::<DraggedTab>(move |style, dragged, _, _| {
+                if dragged.manager == manager_id {
+                    style.bg(drop_bg)
+                } else {
+                    style
+                }
+            })
+            .on_drop(move |dragged: &DraggedTab, _, cx| {
+                if dragged.manager == manager_id {
+                    tab_manager.update(cx, |mgr, cx| {
+                        mgr.move_tab_to_new_group(dragged.id, cx);
+                    });
+                }
+            })
+    }
+
     fn render_new_tab_button(&self, cx: &mut Context<Self>) -> impl IntoElement {
         div()
             .id("new-tab-btn")
@@ -469,14 +615,15 @@ pub enum TabBarEvent {
     CloseAllTabs,
     CloseTabsToLeft(DocumentId),
     CloseTabsToRight(DocumentId),
+    OpenInNewWindow(DocumentId),
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
         TAB_MENU_CLOSE, TAB_MENU_CLOSE_ALL, TAB_MENU_CLOSE_LEFT, TAB_MENU_CLOSE_OTHERS,
-        TAB_MENU_CLOSE_RIGHT, TAB_MENU_SEPARATOR, TabBar, next_actionable_index,
-        prev_actionable_index,
+        TAB_MENU_CLOSE_RIGHT, TAB_MENU_OPEN_IN_NEW_WINDOW, TAB_MENU_SEPARATOR,
+        TAB_MENU_SEPARATOR_WINDOW, TabBar, next_actionable_index, prev_actionable_index,
     };
     use dbflux_components::theme;
     use dbflux_components::tokens::FontSizes;
@@ -488,7 +635,7 @@ mod tests {
     fn build_tab_menu_items_returns_correct_structure() {
         let items = TabBar::build_tab_menu_items();
 
-        assert_eq!(items.len(), 6);
+        assert_eq!(items.len(), 8);
         assert_eq!(items[TAB_MENU_CLOSE].label.as_ref(), "Close");
         assert_eq!(items[TAB_MENU_CLOSE_OTHERS].label.as_ref(), "Close Others");
         assert_eq!(items[TAB_MENU_CLOSE_ALL].label.as_ref(), "Close All");
@@ -501,6 +648,11 @@ mod tests {
             items[TAB_MENU_CLOSE_RIGHT].label.as_ref(),
             "Close to the Right"
         );
+        assert!(items[TAB_MENU_SEPARATOR_WINDOW].is_separator);
+        assert_eq!(
+            items[TAB_MENU_OPEN_IN_NEW_WINDOW].label.as_ref(),
+            "Open in New Window"
+        );
     }
 
     #[test]
@@ -530,24 +682,26 @@ mod tests {
     fn next_actionable_skips_separator() {
         let items = TabBar::build_tab_menu_items();
 
-        // 0 -> 1 -> 2 -> 4 (skip separator at 3) -> 5
+        // 0 -> 1 -> 2 -> 4 (skip separator at 3) -> 5 -> 7 (skip separator at 6)
         assert_eq!(next_actionable_index(0, &items), 1);
         assert_eq!(next_actionable_index(1, &items), 2);
         assert_eq!(next_actionable_index(2, &items), 4);
         assert_eq!(next_actionable_index(4, &items), 5);
+        assert_eq!(next_actionable_index(5, &items), 7);
     }
 
     #[test]
     fn next_actionable_stays_at_end() {
         let items = TabBar::build_tab_menu_items();
-        assert_eq!(next_actionable_index(5, &items), 5);
+        assert_eq!(next_actionable_index(7, &items), 7);
     }
 
     #[test]
     fn prev_actionable_skips_separator() {
         let items = TabBar::build_tab_menu_items();
 
-        // 5 -> 4 -> 2 (skip separator at 3) -> 1 -> 0
+        // 7 -> 5 (skip separator at 6) -> 4 -> 2 (skip separator at 3) -> 1 -> 0
+        assert_eq!(prev_actionable_index(7, &items), 5);
         assert_eq!(prev_actionable_index(5, &items), 4);
         assert_eq!(prev_actionable_index(4, &items), 2);
         assert_eq!(prev_actionable_index(2, &items), 1);
@@ -0,0 +1,122 @@
+//! Back/forward history of the tabs the user moved between.
+
+use super::types::DocumentId;
+
+/// Entries kept; the oldest are dropped first.
+const MAX_HISTORY: usize = 100;
+
+/// Tabs in the order they were activated, with a cursor on the current one.
+///
+/// Closed tabs are not removed: their ids are never reused, so navigation
+/// simply skips entries the caller no longer has open.
+#[derive(Default)]
+pub(crate) struct TabHistory {
+    entries: Vec<DocumentId>,
+    current: usize,
+}
+
+impl TabHistory {
+    /// Records a tab the user moved to. Entries ahead of the cursor are
+    /// dropped, as in a browser.
+    pub fn record(&mut self, id: DocumentId) {
+        if self.entries.get(self.current) == Some(&id) {
+            return;
+        }
+
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.current + 1);
+        }
+
+        self.entries.push(id);
+        if self.entries.len() > MAX_HISTORY {
+            self.entries.remove(0);
+        }
+
+        self.current = self.entries.len() - 1;
+    }
+
+    /// Moves the cursor to the closest earlier entry `available` accepts.
+    pub fn back(&mut self, available: impl Fn(DocumentId) -> bool) -> Option<DocumentId> {
+        let idx = (0..self.current)
+            .rev()
+            .find(|&idx| available(self.entries[idx]))?;
+
+        self.current = idx;
+        Some(self.entries[idx])
+    }
+
+    /// Moves the cursor to the closest later entry `available` accepts.
+    pub fn forward(&mut self, available: impl Fn(DocumentId) -> bool) -> Option<DocumentId> {
+        let idx =
+            (self.current + 1..self.entries.len()).find(|&idx| available(self.entries[idx]))?;
+
+        self.current = idx;
+        Some(self.entries[idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn ids(n: usize) -> Vec<DocumentId> {
+        (0..n).map(|_| DocumentId(Uuid::new_v4())).collect()
+    }
+
+    #[test]
+    fn back_and_forward_walk_the_recorded_tabs() {
+        let ids = ids(3);
+        let mut history = TabHistory::default();
+        for &id in &ids {
+            history.record(id);
+        }
+
+        assert_eq!(history.back(|_| true), Some(ids[1]));
+        assert_eq!(history.back(|_| true), Some(ids[0]));
+        assert_eq!(history.back(|_| true), None);
+        assert_eq!(history.forward(|_| true), Some(ids[1]));
+        assert_eq!(history.forward(|_| true), Some(ids[2]));
+        assert_eq!(history.forward(|_| true), None);
+    }
+
+    #[test]
+    fn recording_after_going_back_drops_forward_entries() {
+        let ids = ids(4);
+        let mut history = TabHistory::default();
+        history.record(ids[0]);
+        history.record(ids[1]);
+        history.record(ids[2]);
+
+        history.back(|_| true);
+        history.record(ids[3]);
+
+        assert_eq!(history.forward(|_| true), None);
+        assert_eq!(history.back(|_| true), Some(ids[1]));
+    }
+
+    #[test]
+    fn navigation_skips_unavailable_tabs() {
+        let ids = ids(3);
+        let mut history = TabHistory::default();
+        for &id in &ids {
+            history.record(id);
+        }
+
+        let closed = ids[1];
+        assert_eq!(history.back(|id| id != closed), Some(ids[0]));
+        assert_eq!(history.forward(|id| id != closed), Some(ids[2]));
+    }
+
+    #[test]
+    fn recording_the_current_tab_again_is_ignored() {
+        let ids = ids(2);
+        let mut history = TabHistory::default();
+        history.record(ids[0]);
+        history.record(ids[1]);
+        history.record(ids[1]);
+
+        assert_eq!(history.back(|_| true), Some(ids[0]));
+        assert_eq!(history.back(|_| true), None);
+    }
+}
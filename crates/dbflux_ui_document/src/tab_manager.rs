@@ -1,13 +1,27 @@
 #![allow(clippy::type_complexity)]
 
 use super::dedup::DocumentKey;
-use super::handle::DocumentEvent;
-use super::pane::PaneHandle;
+use super::handle::{DocumentEvent, QueryCompletion};
+use super::pane::{CodeSessionTabSnapshot, PaneHandle};
+use super::tab_history::TabHistory;
 use super::types::{DocumentId, DocumentKind, DocumentMetaSnapshot};
 use dbflux_app::keymap::{Command, ContextId};
 use dbflux_core::RefreshPolicy;
-use gpui::{AnyElement, App, Context, EventEmitter, Subscription, Window};
-use std::collections::HashMap;
+use gpui::{AnyElement, App, Context, EventEmitter, Keystroke, Subscription, Window};
+use std::collections::{HashMap, HashSet};
+
+/// Number of tab groups a pane can hold.
+pub const MAX_TAB_GROUPS: usize = 4;
+
+/// Closed tabs remembered for "Reopen Closed Tab".
+const MAX_RECENTLY_CLOSED: usize = 20;
+
+/// A closed tab, with what is needed to open it again.
+#[derive(Clone)]
+pub struct ClosedTab {
+    pub snapshot: CodeSessionTabSnapshot,
+    pub group: usize,
+}
 
 /// Wrapper around a `PaneHandle` representing one open workspace tab.
 ///
@@ -57,6 +71,20 @@ impl Tab {
         }
     }
 
+    pub fn handle_unbound_keystroke(
+        &self,
+        keystroke: &Keystroke,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> bool {
+        match self {
+            Tab::Pane(p) => p
+                .handle_unbound_keystroke
+                .as_ref()
+                .is_some_and(|handler| handler(keystroke, window, cx)),
+        }
+    }
+
     // --- Pure reads ---
 
     pub fn meta_snapshot(&self, cx: &App) -> DocumentMetaSnapshot {
@@ -173,8 +201,9 @@ impl Tab {
     }
 
     /// Returns a session snapshot for this tab if it is a code document with
-    /// a persistent backing (file-backed or scratch). Returns `None` for all
-    /// other document types and for ephemeral tabs with no backing path.
+    /// a persistent backing (file-backed or scratch), a routine, or a table or
+    /// collection document. Returns `None` for all other document types and
+    /// for ephemeral tabs with no backing path.
     pub fn session_tab_snapshot(&self, cx: &App) -> Option<super::pane::CodeSessionTabSnapshot> {
         match self {
             Tab::Pane(p) => p.session_tab_snapshot.as_ref().and_then(|f| f(cx)),
@@ -189,6 +218,9 @@ impl Tab {
 /// - Track active document
 /// - Maintain MRU (Most Recently Used) order for Ctrl+Tab navigation
 /// - Handle document subscriptions for cleanup on close
+/// - Track documents detached into their own window or a split pane
+/// - Keep tabs ordered by tab group
+/// - Remember closed tabs and the back/forward tab history
 pub struct TabManager {
     /// Documents in visual order (left to right in tab bar).
     documents: Vec<Tab>,
@@ -201,6 +233,28 @@ pub struct TabManager {
 
     /// Subscriptions per document (for cleanup on close).
     subscriptions: HashMap<DocumentId, Subscription>,
+
+    /// Documents shown in their own window or in the workspace's split pane.
+    /// They stay open here, so session, dirty checks and events keep working,
+    /// but are left out of the tab bar and never become the active tab.
+    detached: HashSet<DocumentId>,
+
+    /// Detached document in the split pane while that pane has focus.
+    /// Commands meant for the focused document go to it instead of the
+    /// active tab.
+    split_focus: Option<DocumentId>,
+
+    /// Tab group of each document; documents missing here are in group 0.
+    /// `documents` is kept sorted by group, so each group is a contiguous
+    /// run of tabs.
+    groups: HashMap<DocumentId, usize>,
+
+    /// Tabs the user moved between, for back/forward navigation.
+    history: TabHistory,
+
+    /// Closed tabs that can be reopened (last = most recently closed). Only
+    /// documents with a session snapshot are kept.
+    recently_closed: Vec<ClosedTab>,
 }
 
 impl TabManager {
@@ -210,10 +264,16 @@ impl TabManager {
             active_index: None,
             mru_order: Vec::new(),
             subscriptions: HashMap::new(),
+            detached: HashSet::new(),
+            split_focus: None,
+            groups: HashMap::new(),
+            history: TabHistory::default(),
+            recently_closed: Vec::new(),
         }
     }
 
-    /// Opens a new document and activates it.
+    /// Opens a new document and activates it. It joins the group of the
+    /// active tab, at the end of that group.
     pub fn open(&mut self, doc: Tab, cx: &mut Context<Self>) {
         let id = doc.id();
 
@@ -273,17 +333,45 @@ impl TabManager {
                         sql: sql.clone(),
                     });
                 }
+                DocumentEvent::OpenTableFiltered {
+                    profile_id,
+                    database,
+                    table,
+                    filter,
+                } => {
+                    cx.emit(TabManagerEvent::OpenTableFiltered {
+                        profile_id: *profile_id,
+                        database: database.clone(),
+                        table: table.clone(),
+                        filter: filter.clone(),
+                    });
+                }
+                DocumentEvent::RequestConnect { profile_id } => {
+                    cx.emit(TabManagerEvent::RequestConnect {
+                        profile_id: *profile_id,
+                    });
+                }
+                DocumentEvent::QueryCompleted(completion) => {
+                    cx.emit(TabManagerEvent::QueryCompleted {
+                        id,
+                        completion: completion.clone(),
+                    });
+                }
                 _ => {}
             });
         });
 
         self.subscriptions.insert(id, subscription);
-        self.documents.push(doc);
-        let new_index = self.documents.len() - 1;
+
+        let group = self.active_id().map_or(0, |active| self.group_of(active));
+        self.set_group(id, group);
+        let new_index = self.group_insert_index(group);
+        self.documents.insert(new_index, doc);
         self.active_index = Some(new_index);
 
         // Add to front of MRU
         self.mru_order.insert(0, id);
+        self.history.record(id);
 
         cx.emit(TabManagerEvent::Opened(id));
         cx.notify();
@@ -296,13 +384,35 @@ impl TabManager {
         };
 
         self.documents[idx].flush_auto_save(cx);
+
+        if let Some(snapshot) = self.documents[idx].session_tab_snapshot(cx) {
+            self.recently_closed.push(ClosedTab {
+                snapshot,
+                group: self.group_of(id),
+            });
+            if self.recently_closed.len() > MAX_RECENTLY_CLOSED {
+                self.recently_closed.remove(0);
+            }
+        }
+
         self.remove_document(idx, id, cx);
         true
     }
 
+    /// Takes the most recently closed tab, to be opened again by the
+    /// workspace.
+    pub fn pop_recently_closed(&mut self) -> Option<ClosedTab> {
+        self.recently_closed.pop()
+    }
+
     fn remove_document(&mut self, idx: usize, id: DocumentId, cx: &mut Context<Self>) {
         self.documents.remove(idx);
         self.subscriptions.remove(&id);
+        self.detached.remove(&id);
+        self.groups.remove(&id);
+        if self.split_focus == Some(id) {
+            self.split_focus = None;
+        }
         self.mru_order.retain(|&i| i != id);
         self.active_index = self.compute_new_active_after_close(idx);
 
@@ -318,23 +428,72 @@ impl TabManager {
 
         // Try to activate the next in MRU order
         for mru_id in &self.mru_order {
+            if self.detached.contains(mru_id) {
+                continue;
+            }
             if let Some(idx) = self.index_of(*mru_id) {
                 return Some(idx);
             }
         }
 
-        // Fallback: the closest tab visually
-        Some(closed_idx.min(self.documents.len() - 1))
+        // Fallback: the closest docked tab visually
+        let ids: Vec<DocumentId> = self.documents.iter().map(Tab::id).collect();
+        closest_docked_index(&ids, &self.detached, closed_idx)
     }
 
-    /// Activates a document by ID.
+    /// Activates a document by ID. Detached documents are brought to the
+    /// front in their own window by the workspace instead.
     pub fn activate(&mut self, id: DocumentId, cx: &mut Context<Self>) {
+        if self.activate_inner(id, cx) {
+            self.history.record(id);
+        }
+    }
+
+    /// Goes back to the tab active before the current one.
+    pub fn navigate_back(&mut self, cx: &mut Context<Self>) {
+        let candidates = self.history_candidates();
+        if let Some(id) = self.history.back(|id| candidates.contains(&id)) {
+            self.activate_inner(id, cx);
+        }
+    }
+
+    /// Undoes [`Self::navigate_back`].
+    pub fn navigate_forward(&mut self, cx: &mut Context<Self>) {
+        let candidates = self.history_candidates();
+        if let Some(id) = self.history.forward(|id| candidates.contains(&id)) {
+            self.activate_inner(id, cx);
+        }
+    }
+
+    /// Open documents that back/forward navigation can move to.
+    fn history_candidates(&self) -> HashSet<DocumentId> {
+        let active = self.active_id();
+        self.documents
+            .iter()
+            .map(Tab::id)
+            .filter(|&id| Some(id) != active)
+            .collect()
+    }
+
+    /// Activates a document without touching the back/forward history.
+    /// Returns whether a docked tab became the active one.
+    fn activate_inner(&mut self, id: DocumentId, cx: &mut Context<Self>) -> bool {
         let Some(idx) = self.index_of(id) else {
-            return;
+            return false;
         };
 
+        if self.detached.contains(&id) {
+            cx.emit(TabManagerEvent::DetachedActivated(id));
+            return false;
+        }
+
+        // Picking a tab from the tab bar moves focus back to the main pane.
+        if self.split_focus.take().is_some() {
+            cx.notify();
+        }
+
         if self.active_index == Some(idx) {
-            return; // Already active
+            return false; // Already active
         }
 
         self.active_index = Some(idx);
@@ -345,62 +504,148 @@ impl TabManager {
 
         cx.emit(TabManagerEvent::Activated(id));
         cx.notify();
+        true
     }
 
     /// Navigates to the next tab in VISUAL order (Ctrl+PgDn).
     pub fn next_visual_tab(&mut self, cx: &mut Context<Self>) {
-        if self.documents.len() <= 1 {
+        let docked = self.docked_ids();
+        if docked.len() <= 1 {
             return;
         }
 
-        if let Some(active) = self.active_index {
-            let next = (active + 1) % self.documents.len();
-            let id = self.documents[next].id();
-            self.activate(id, cx);
+        if let Some(active) = self.active_id()
+            && let Some(next) = docked_neighbor(&docked, active, true)
+        {
+            self.activate(next, cx);
         }
     }
 
     /// Navigates to the previous tab in VISUAL order (Ctrl+PgUp).
     pub fn prev_visual_tab(&mut self, cx: &mut Context<Self>) {
-        if self.documents.len() <= 1 {
+        let docked = self.docked_ids();
+        if docked.len() <= 1 {
             return;
         }
 
-        if let Some(active) = self.active_index {
-            let prev = if active == 0 {
-                self.documents.len() - 1
-            } else {
-                active - 1
-            };
-            let id = self.documents[prev].id();
-            self.activate(id, cx);
+        if let Some(active) = self.active_id()
+            && let Some(prev) = docked_neighbor(&docked, active, false)
+        {
+            self.activate(prev, cx);
         }
     }
 
     /// Navigates to the next tab in MRU order (Ctrl+Tab).
     pub fn next_mru_tab(&mut self, cx: &mut Context<Self>) {
-        if self.mru_order.len() <= 1 {
+        let mru = self.docked_mru();
+        if mru.len() <= 1 {
             return;
         }
 
         // The second in MRU is the "next" most recent
-        if let Some(&next_id) = self.mru_order.get(1) {
+        if let Some(&next_id) = mru.get(1) {
             self.activate(next_id, cx);
         }
     }
 
     /// Navigates to the previous tab in MRU order (Ctrl+Shift+Tab).
     pub fn prev_mru_tab(&mut self, cx: &mut Context<Self>) {
-        if self.mru_order.len() <= 1 {
+        let mru = self.docked_mru();
+        if mru.len() <= 1 {
             return;
         }
 
         // The last in MRU is the "least recent"
-        if let Some(&prev_id) = self.mru_order.last() {
+        if let Some(&prev_id) = mru.last() {
             self.activate(prev_id, cx);
         }
     }
 
+    /// Detaches a document into its own window: it leaves the tab bar and,
+    /// when it was the active tab, the most recent docked tab takes over.
+    pub fn detach(&mut self, id: DocumentId, cx: &mut Context<Self>) {
+        let Some(idx) = self.index_of(id) else {
+            return;
+        };
+
+        if !self.detached.insert(id) {
+            return;
+        }
+
+        if self.active_index == Some(idx) {
+            self.active_index = self.compute_new_active_after_close(idx);
+
+            if let Some(new_id) = self.active_id() {
+                self.mru_order.retain(|&i| i != new_id);
+                self.mru_order.insert(0, new_id);
+                cx.emit(TabManagerEvent::Activated(new_id));
+            }
+        }
+
+        cx.emit(TabManagerEvent::Detached(id));
+        cx.notify();
+    }
+
+    /// Puts a detached document back into the tab bar and activates it.
+    pub fn redock(&mut self, id: DocumentId, cx: &mut Context<Self>) {
+        if !self.detached.remove(&id) {
+            return;
+        }
+
+        if self.split_focus == Some(id) {
+            self.split_focus = None;
+        }
+
+        cx.emit(TabManagerEvent::Redocked(id));
+        self.activate(id, cx);
+        cx.notify();
+    }
+
+    pub fn is_detached(&self, id: DocumentId) -> bool {
+        self.detached.contains(&id)
+    }
+
+    /// Gives keyboard focus to a detached document shown in the split pane,
+    /// or back to the active tab with `None`.
+    pub fn set_split_focus(&mut self, id: Option<DocumentId>, cx: &mut Context<Self>) {
+        let id = id.filter(|id| self.detached.contains(id));
+        if self.split_focus != id {
+            self.split_focus = id;
+            cx.notify();
+        }
+    }
+
+    pub fn split_focus(&self) -> Option<DocumentId> {
+        self.split_focus
+    }
+
+    /// Renders a document by ID, for the window it was detached into.
+    pub fn render_document(
+        &self,
+        id: DocumentId,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<AnyElement> {
+        Some(self.document(id)?.render(window, cx))
+    }
+
+    /// IDs of the documents shown in the tab bar, in visual order.
+    pub fn docked_ids(&self) -> Vec<DocumentId> {
+        self.documents
+            .iter()
+            .map(|d| d.id())
+            .filter(|id| !self.detached.contains(id))
+            .collect()
+    }
+
+    fn docked_mru(&self) -> Vec<DocumentId> {
+        self.mru_order
+            .iter()
+            .copied()
+            .filter(|id| !self.detached.contains(id))
+            .collect()
+    }
+
     pub fn close_others(&mut self, keep_id: DocumentId, cx: &mut Context<Self>) {
         let ids_to_close: Vec<DocumentId> = self
             .documents
@@ -462,11 +707,11 @@ impl TabManager {
 
     /// Switches to tab by 1-based number (Ctrl+1 through Ctrl+9).
     pub fn switch_to_tab(&mut self, n: usize, cx: &mut Context<Self>) {
-        if n == 0 || n > self.documents.len() {
+        let docked = self.docked_ids();
+        if n == 0 || n > docked.len() {
             return;
         }
-        let id = self.documents[n - 1].id();
-        self.activate(id, cx);
+        self.activate(docked[n - 1], cx);
     }
 
     /// Finds a document by ID.
@@ -479,6 +724,20 @@ impl TabManager {
         self.active_index.and_then(|i| self.documents.get(i))
     }
 
+    /// Returns the tab that receives commands: the split pane's document
+    /// while that pane has focus, otherwise the active tab.
+    pub fn focused_tab(&self) -> Option<&Tab> {
+        match self.split_focus {
+            Some(id) => self.document(id),
+            None => self.active_tab(),
+        }
+    }
+
+    /// Returns the ID of [`Self::focused_tab`].
+    pub fn focused_id(&self) -> Option<DocumentId> {
+        self.focused_tab().map(|d| d.id())
+    }
+
     /// Renders the active tab.
     ///
     /// Returns `None` when no tab is active.
@@ -486,20 +745,34 @@ impl TabManager {
         Some(self.active_tab()?.render(window, cx))
     }
 
-    /// Dispatches a command to the active tab.
+    /// Dispatches a command to the focused tab (see [`Self::focused_tab`]).
     ///
     /// Returns `true` when the command was handled, `false` when there is no
     /// active tab or the tab declined the command.
     pub fn dispatch_active(&self, cmd: Command, window: &mut Window, cx: &mut App) -> bool {
-        match self.active_tab() {
+        match self.focused_tab() {
             Some(tab) => tab.dispatch_command(cmd, window, cx),
             None => false,
         }
     }
 
-    /// Focuses the active tab. No-ops when no tab is active.
+    /// Offers a keystroke no keymap binding claimed to the focused tab.
+    ///
+    /// Returns `true` when the tab consumed it.
+    pub fn handle_unbound_keystroke_active(
+        &self,
+        keystroke: &Keystroke,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> bool {
+        self.focused_tab()
+            .is_some_and(|tab| tab.handle_unbound_keystroke(keystroke, window, cx))
+    }
+
+    /// Focuses the focused tab (see [`Self::focused_tab`]). No-ops when no
+    /// tab is active.
     pub fn focus_active(&self, window: &mut Window, cx: &mut App) {
-        if let Some(tab) = self.active_tab() {
+        if let Some(tab) = self.focused_tab() {
             tab.focus(window, cx);
         }
     }
@@ -583,6 +856,133 @@ impl TabManager {
         cx.emit(TabManagerEvent::Reordered);
         cx.notify();
     }
+
+    /// Tab group of a document (0-based).
+    pub fn group_of(&self, id: DocumentId) -> usize {
+        self.groups.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Tab groups of the tabs in the tab bar, in visual order.
+    pub fn docked_groups(&self) -> Vec<usize> {
+        self.documents
+            .iter()
+            .map(Tab::id)
+            .filter(|id| !self.detached.contains(id))
+            .map(|id| self.group_of(id))
+            .collect()
+    }
+
+    /// Moves a tab to the end of another group.
+    pub fn move_tab_to_group(&mut self, id: DocumentId, group: usize, cx: &mut Context<Self>) {
+        let group = group.min(MAX_TAB_GROUPS - 1);
+        let Some(from) = self.index_of(id) else {
+            return;
+        };
+
+        if self.group_of(id) == group {
+            return;
+        }
+
+        let active = self.active_id();
+        let doc = self.documents.remove(from);
+        self.set_group(id, group);
+        let to = self.group_insert_index(group);
+        self.documents.insert(to, doc);
+        self.active_index = active.and_then(|active| self.index_of(active));
+
+        cx.emit(TabManagerEvent::Reordered);
+        cx.notify();
+    }
+
+    /// Moves a tab into a group of its own after the last one in use, or
+    /// into the last group when all of them are taken.
+    pub fn move_tab_to_new_group(&mut self, id: DocumentId, cx: &mut Context<Self>) {
+        let next = self
+            .documents
+            .iter()
+            .map(Tab::id)
+            .filter(|&other| other != id)
+            .map(|other| self.group_of(other))
+            .max()
+            .map_or(0, |last| last + 1);
+
+        self.move_tab_to_group(id, next, cx);
+    }
+
+    /// Moves a tab in front of another one (for drag & drop), joining the
+    /// target's group.
+    pub fn move_tab_before(&mut self, id: DocumentId, target: DocumentId, cx: &mut Context<Self>) {
+        if id == target {
+            return;
+        }
+
+        let Some(from) = self.index_of(id) else {
+            return;
+        };
+        if self.index_of(target).is_none() {
+            return;
+        }
+
+        let active = self.active_id();
+        let doc = self.documents.remove(from);
+        self.set_group(id, self.group_of(target));
+        let to = self.index_of(target).unwrap_or(self.documents.len());
+        self.documents.insert(to, doc);
+        self.active_index = active.and_then(|active| self.index_of(active));
+
+        cx.emit(TabManagerEvent::Reordered);
+        cx.notify();
+    }
+
+    fn set_group(&mut self, id: DocumentId, group: usize) {
+        if group == 0 {
+            self.groups.remove(&id);
+        } else {
+            self.groups.insert(id, group);
+        }
+    }
+
+    fn group_insert_index(&self, group: usize) -> usize {
+        let groups: Vec<usize> = self
+            .documents
+            .iter()
+            .map(|doc| self.group_of(doc.id()))
+            .collect();
+        group_insert_index(&groups, group)
+    }
+}
+
+/// Index at which a tab joins `group` so tabs stay sorted by group: right
+/// after the last tab of that group or of any group before it.
+fn group_insert_index(groups: &[usize], group: usize) -> usize {
+    groups
+        .iter()
+        .rposition(|&g| g <= group)
+        .map_or(0, |idx| idx + 1)
+}
+
+/// Index of the docked tab closest to `closed_idx`, looking right first and
+/// then left, skipping documents in `detached`.
+fn closest_docked_index(
+    ids: &[DocumentId],
+    detached: &HashSet<DocumentId>,
+    closed_idx: usize,
+) -> Option<usize> {
+    let closest = closed_idx.min(ids.len().checked_sub(1)?);
+    (closest..ids.len())
+        .chain((0..closest).rev())
+        .find(|&idx| !detached.contains(&ids[idx]))
+}
+
+/// The docked tab after (`forward`) or before `active`, wrapping around.
+fn docked_neighbor(docked: &[DocumentId], active: DocumentId, forward: bool) -> Option<DocumentId> {
+    let pos = docked.iter().position(|&id| id == active)?;
+    let next = if forward {
+        (pos + 1) % docked.len()
+    } else {
+        pos.checked_sub(1).unwrap_or(docked.len() - 1)
+    };
+    Some(docked[next])
 }
 
 impl Default for TabManager {
@@ -624,6 +1024,13 @@ pub enum TabManagerEvent {
     Closed(DocumentId),
     Activated(DocumentId),
     Reordered,
+    /// A document moved out of the tab bar into its own window.
+    Detached(DocumentId),
+    /// A detached document went back into the tab bar.
+    Redocked(DocumentId),
+    /// A detached document was activated; the workspace brings its window or
+    /// split pane forward.
+    DetachedActivated(DocumentId),
     /// A document requested focus (user clicked on it).
     DocumentRequestedFocus,
     /// A document requested SQL preview modal.
@@ -661,11 +1068,31 @@ pub enum TabManagerEvent {
         profile_id: uuid::Uuid,
         sql: String,
     },
+    /// A grid foreign-key action asked for a filtered table tab.
+    OpenTableFiltered {
+        profile_id: uuid::Uuid,
+        database: Option<String>,
+        table: dbflux_core::TableRef,
+        filter: String,
+    },
+    /// A document asked for a profile to be connected.
+    RequestConnect {
+        profile_id: uuid::Uuid,
+    },
+    /// A document finished running a query or script.
+    QueryCompleted {
+        id: DocumentId,
+        completion: QueryCompletion,
+    },
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{DocumentId, ids_to_close_left, ids_to_close_others, ids_to_close_right};
+    use super::{
+        DocumentId, closest_docked_index, docked_neighbor, group_insert_index, ids_to_close_left,
+        ids_to_close_others, ids_to_close_right,
+    };
+    use std::collections::HashSet;
     use uuid::Uuid;
 
     fn make_ids(n: usize) -> Vec<DocumentId> {
@@ -752,4 +1179,57 @@ mod tests {
             "structural: close-right from first keeps 2 tabs"
         );
     }
+    #[test]
+    fn group_insert_index_appends_to_end_of_group() {
+        let groups = [0, 0, 1, 1, 3];
+        assert_eq!(group_insert_index(&groups, 0), 2);
+        assert_eq!(group_insert_index(&groups, 1), 4);
+        assert_eq!(group_insert_index(&groups, 2), 4);
+        assert_eq!(group_insert_index(&groups, 3), 5);
+    }
+
+    #[test]
+    fn group_insert_index_before_every_later_group() {
+        assert_eq!(group_insert_index(&[2, 3], 1), 0);
+        assert_eq!(group_insert_index(&[], 2), 0);
+    }
+
+    #[test]
+    fn closest_docked_index_skips_detached_tabs() {
+        let ids = make_ids(4);
+        let detached: HashSet<DocumentId> = [ids[1], ids[2]].into_iter().collect();
+
+        assert_eq!(closest_docked_index(&ids, &detached, 1), Some(3));
+        assert_eq!(closest_docked_index(&ids, &detached, 9), Some(3));
+
+        let detached: HashSet<DocumentId> = [ids[2], ids[3]].into_iter().collect();
+        assert_eq!(closest_docked_index(&ids, &detached, 2), Some(1));
+    }
+
+    #[test]
+    fn closest_docked_index_is_none_when_every_tab_is_detached() {
+        let ids = make_ids(2);
+        let detached: HashSet<DocumentId> = ids.iter().copied().collect();
+
+        assert_eq!(closest_docked_index(&ids, &detached, 0), None);
+        assert_eq!(closest_docked_index(&[], &HashSet::new(), 0), None);
+    }
+
+    #[test]
+    fn docked_neighbor_wraps_in_both_directions() {
+        let docked = make_ids(3);
+
+        assert_eq!(docked_neighbor(&docked, docked[0], true), Some(docked[1]));
+        assert_eq!(docked_neighbor(&docked, docked[2], true), Some(docked[0]));
+        assert_eq!(docked_neighbor(&docked, docked[0], false), Some(docked[2]));
+        assert_eq!(docked_neighbor(&docked, docked[2], false), Some(docked[1]));
+    }
+
+    #[test]
+    fn docked_neighbor_of_a_detached_tab_is_none() {
+        let docked = make_ids(2);
+        let detached = DocumentId(Uuid::new_v4());
+
+        assert_eq!(docked_neighbor(&docked, detached, true), None);
+    }
 }
@@ -0,0 +1,225 @@
+//! Exports the data of several tables at once: one CSV file per table in a
+//! directory the user picks, written in the background as a cancellable task.
+
+use dbflux_core::{Connection, ProgressUnit, QueryRequest, TableRef, TaskKind, TaskTarget};
+use dbflux_export::{ExportError, ExportFormat};
+use dbflux_ui_base::AppStateEntity;
+use dbflux_ui_base::async_ext::AsyncUpdateResultExt;
+use dbflux_ui_base::file_dialog::{
+    fallback_export_dir, is_native_file_dialog_available, unique_path_in,
+};
+use dbflux_ui_base::toast::{Toast, now_hms};
+use gpui::*;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Asks for a target directory, then writes `SELECT *` of every table of
+/// `tables` into it as `<schema>.<table>.csv`.
+pub fn export_tables(
+    app_state: Entity<AppStateEntity>,
+    profile_id: Uuid,
+    database: Option<String>,
+    tables: Vec<TableRef>,
+    cx: &mut App,
+) {
+    if tables.is_empty() {
+        return;
+    }
+
+    let conn = {
+        let state = app_state.read(cx);
+        let Some(connected) = state.connections().get(&profile_id) else {
+            return;
+        };
+        match database.as_deref() {
+            Some(database) => connected.connection_for_database(database),
+            None => connected.connection.clone(),
+        }
+    };
+
+    let dialog_available = is_native_file_dialog_available();
+    let total = tables.len();
+
+    cx.spawn(async move |cx| {
+        let dir = if dialog_available {
+            let picked = rfd::AsyncFileDialog::new()
+                .set_title(format!("Export {} tables to", total))
+                .pick_folder()
+                .await;
+
+            // A cancelled dialog is not a failure.
+            let Some(picked) = picked else {
+                return;
+            };
+            picked.path().to_path_buf()
+        } else {
+            match fallback_export_dir() {
+                Ok(dir) => dir,
+                Err(error) => {
+                    cx.update(|cx| {
+                        Toast::error(format!("Export failed: {}", error))
+                            .meta_right(now_hms())
+                            .push(cx);
+                    })
+                    .log_if_dropped();
+                    return;
+                }
+            }
+        };
+
+        let Ok((task_id, cancel_token, admission)) = cx.update(|cx| {
+            app_state.update(cx, |state, cx| {
+                let started = state.enqueue_task_for_target(
+                    TaskKind::Export,
+                    format!("Export {} tables", total),
+                    TaskTarget {
+                        profile_id,
+                        database: database.clone(),
+                    },
+                );
+                cx.emit(dbflux_ui_base::AppStateChanged);
+                started
+            })
+        }) else {
+            return;
+        };
+
+        // Cancelled while waiting for other tasks on the connection.
+        if !admission.await {
+            return;
+        }
+
+        let mut exported = 0;
+        let mut failures = Vec::new();
+
+        for (ix, table) in tables.into_iter().enumerate() {
+            if cancel_token.is_cancelled() {
+                break;
+            }
+
+            let conn = conn.clone();
+            let database = database.clone();
+            let dir = dir.clone();
+            let name = table.qualified_name();
+            let result = cx
+                .background_executor()
+                .spawn(async move { export_table(&*conn, database, &table, &dir) })
+                .await;
+
+            match result {
+                Ok(()) => exported += 1,
+                Err(error) => {
+                    log::warn!("Export of table {} failed: {}", name, error);
+                    failures.push(name);
+                }
+            }
+
+            cx.update(|cx| {
+                app_state.update(cx, |state, cx| {
+                    state.tasks_mut().update_units(
+                        task_id,
+                        ProgressUnit::Tables,
+                        (ix + 1) as u64,
+                        Some(total as u64),
+                    );
+                    cx.emit(dbflux_ui_base::AppStateChanged);
+                });
+            })
+            .log_if_dropped();
+        }
+
+        let summary = format!(
+            "Exported {} of {} tables to {}",
+            exported,
+            total,
+            dir.display()
+        );
+
+        cx.update(|cx| {
+            app_state.update(cx, |state, cx| {
+                if !cancel_token.is_cancelled() {
+                    if failures.is_empty() {
+                        state.complete_task_with_details(task_id, summary.clone());
+                    } else {
+                        state.fail_task(
+                            task_id,
+                            format!("Could not export {}", failures.join(", ")),
+                        );
+                    }
+                }
+                cx.emit(dbflux_ui_base::AppStateChanged);
+            });
+
+            let toast = if failures.is_empty() {
+                Toast::success(summary)
+            } else {
+                Toast::error(format!(
+                    "{} — could not export {}",
+                    summary,
+                    failures.join(", ")
+                ))
+            };
+            toast.meta_right(now_hms()).push(cx);
+        })
+        .log_if_dropped();
+    })
+    .detach();
+}
+
+/// Fetches every row of `table` and writes them as CSV into `dir`.
+fn export_table(
+    conn: &dyn Connection,
+    database: Option<String>,
+    table: &TableRef,
+    dir: &Path,
+) -> Result<(), String> {
+    let mut request = QueryRequest::new(format!(
+        "SELECT * FROM {}",
+        table.quoted_with(conn.dialect())
+    ));
+    request.database = database;
+    let result = conn.execute(&request).map_err(|e| e.to_string())?;
+
+    let path = unique_path_in(dir, &export_file_name(table));
+    let write = || {
+        let mut writer = BufWriter::new(File::create(&path)?);
+        dbflux_export::export(&result, ExportFormat::Csv, &mut writer)?;
+        Ok::<_, ExportError>(())
+    };
+    write().map_err(|e| e.to_string())
+}
+
+/// `<schema>.<table>.csv`, with path separators replaced so every table
+/// lands directly in the target directory.
+fn export_file_name(table: &TableRef) -> String {
+    let stem = match table.schema.as_deref() {
+        Some(schema) if !schema.is_empty() => format!("{}.{}", schema, table.name),
+        _ => table.name.clone(),
+    };
+    let stem: String = stem
+        .chars()
+        .map(|c| if matches!(c, '/' | '\\') { '_' } else { c })
+        .collect();
+
+    format!("{}.{}", stem, ExportFormat::Csv.extension())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_names_are_schema_qualified_and_flat() {
+        assert_eq!(
+            export_file_name(&TableRef::with_schema("public", "users")),
+            "public.users.csv"
+        );
+        assert_eq!(export_file_name(&TableRef::new("a/b")), "a_b.csv");
+        assert_eq!(
+            export_file_name(&TableRef::with_schema("", "users")),
+            "users.csv"
+        );
+    }
+}
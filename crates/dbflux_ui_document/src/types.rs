@@ -26,6 +26,10 @@ pub enum DocumentKind {
     Chart,
     // Dashboard document (named collection of chart panels)
     Dashboard,
+    // Notebook of query and markdown cells backed by a `.dbnb` file
+    Notebook,
+    // Users and roles administration for one connection
+    UserAdmin,
 }
 
 /// Source kind for DataDocument (affects icon and behavior).
@@ -53,6 +57,8 @@ pub enum DocumentIcon {
     Audit,
     Chart,
     Dashboard,
+    Notebook,
+    Users,
 }
 
 impl DocumentIcon {
@@ -69,6 +75,8 @@ impl DocumentIcon {
             Self::Audit => "shield",
             Self::Chart => "bar-chart-2",
             Self::Dashboard => "layout-dashboard",
+            Self::Notebook => "notebook-pen",
+            Self::Users => "users",
         }
     }
 }
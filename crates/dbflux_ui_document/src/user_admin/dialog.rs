@@ -0,0 +1,581 @@
+//! Guided dialogs that build user administration statements.
+
+use super::UserAdminDocument;
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant};
+use dbflux_components::controls::{Button, Checkbox, Input, InputEvent, InputState};
+use dbflux_components::modals::shell::{ModalShell, ModalVariant};
+use dbflux_components::primitives::Text;
+use dbflux_components::tokens::{Radii, Spacing};
+use dbflux_components::typography::AppFonts;
+use dbflux_core::{DbError, GrantSpec, GrantTarget, UserAccount, UserAdminDialect, UserSpec};
+use gpui::prelude::*;
+use gpui::{AnyElement, App, Context, Entity, Subscription, Window, div, px};
+use gpui_component::ActiveTheme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum UserDialogKind {
+    Create,
+    Alter,
+    Drop,
+    Grant,
+    Revoke,
+}
+
+/// Object kind a grant dialog targets; the name comes from `target_input`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TargetKind {
+    Role,
+    Database,
+    Schema,
+    Table,
+    AllTablesInSchema,
+}
+
+impl TargetKind {
+    fn label(self, dialect: UserAdminDialect) -> &'static str {
+        match self {
+            Self::Role => "Role",
+            Self::Database => "Database",
+            Self::Schema if dialect.uses_hosts() => "Database (schema)",
+            Self::Schema => "Schema",
+            Self::Table => "Table",
+            Self::AllTablesInSchema => "All tables",
+        }
+    }
+
+    /// Kinds offered by `dialect`. MySQL schemas are databases, so only one
+    /// of the two is shown there.
+    fn offered(dialect: UserAdminDialect) -> &'static [TargetKind] {
+        if dialect.uses_hosts() {
+            &[Self::Role, Self::Database, Self::Table]
+        } else {
+            &[
+                Self::Role,
+                Self::Database,
+                Self::Schema,
+                Self::Table,
+                Self::AllTablesInSchema,
+            ]
+        }
+    }
+}
+
+/// A `CREATE`/`ALTER USER` that sets a password. Its SQL is built only once
+/// the server's quoting rules are known, and it runs without passing through
+/// a SQL editor, so the password never reaches query history or the saved
+/// session.
+pub(super) struct PasswordStatement {
+    kind: UserDialogKind,
+    spec: UserSpec,
+}
+
+impl PasswordStatement {
+    pub(super) fn sql(&self, dialect: UserAdminDialect) -> Result<String, DbError> {
+        match self.kind {
+            UserDialogKind::Alter => dialect.alter_user_sql(&self.spec),
+            _ => dialect.create_user_sql(&self.spec),
+        }
+    }
+}
+
+/// Stand-in for the password in the statement preview.
+const MASKED_PASSWORD: &str = "********";
+
+/// State of the open statement dialog.
+pub(super) struct UserDialog {
+    kind: UserDialogKind,
+    /// Attributes of the user the dialog was opened on; `Alter` only emits
+    /// the settings that differ from these.
+    original: UserSpec,
+
+    name_input: Entity<InputState>,
+    host_input: Entity<InputState>,
+    password_input: Entity<InputState>,
+    connection_limit_input: Entity<InputState>,
+    privileges_input: Entity<InputState>,
+    target_input: Entity<InputState>,
+
+    can_login: bool,
+    superuser: bool,
+    create_db: bool,
+    create_role: bool,
+    with_grant_option: bool,
+    target_kind: TargetKind,
+
+    /// A password statement is running on the connection.
+    pub(super) running: bool,
+    /// Why the last password statement failed.
+    pub(super) run_error: Option<String>,
+
+    _subscriptions: Vec<Subscription>,
+}
+
+impl UserDialog {
+    pub(super) fn new(
+        kind: UserDialogKind,
+        original: Option<UserSpec>,
+        window: &mut Window,
+        cx: &mut Context<UserAdminDocument>,
+    ) -> Self {
+        let original = original.unwrap_or_else(|| UserSpec {
+            can_login: Some(true),
+            ..UserSpec::default()
+        });
+
+        let name = original.account.name.clone();
+        let host = original.account.host.clone().unwrap_or_default();
+        let connection_limit = original
+            .connection_limit
+            .map(|limit| limit.to_string())
+            .unwrap_or_default();
+
+        let name_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("name")
+                .default_value(name)
+        });
+        let host_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("% (any host)")
+                .default_value(host)
+        });
+        let password_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(if kind == UserDialogKind::Alter {
+                    "unchanged"
+                } else {
+                    "password"
+                })
+                .masked(true)
+        });
+        let connection_limit_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("no limit")
+                .default_value(connection_limit)
+        });
+        let privileges_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("SELECT, INSERT, UPDATE")
+                .default_value("SELECT")
+        });
+        let target_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("name, or schema.table"));
+
+        // Re-render on every edit so the statement preview stays current.
+        let _subscriptions = [
+            &name_input,
+            &host_input,
+            &password_input,
+            &connection_limit_input,
+            &privileges_input,
+            &target_input,
+        ]
+        .into_iter()
+        .map(|input| cx.subscribe(input, |_, _, _: &InputEvent, cx| cx.notify()))
+        .collect();
+
+        let focus = if kind == UserDialogKind::Create || original.account.name.is_empty() {
+            &name_input
+        } else if matches!(kind, UserDialogKind::Grant | UserDialogKind::Revoke) {
+            &target_input
+        } else {
+            &password_input
+        };
+        focus.update(cx, |state, cx| state.focus(window, cx));
+
+        Self {
+            kind,
+            can_login: original.can_login.unwrap_or(false),
+            superuser: original.superuser.unwrap_or(false),
+            create_db: original.create_db.unwrap_or(false),
+            create_role: original.create_role.unwrap_or(false),
+            original,
+            name_input,
+            host_input,
+            password_input,
+            connection_limit_input,
+            privileges_input,
+            target_input,
+            with_grant_option: false,
+            target_kind: TargetKind::Role,
+            running: false,
+            run_error: None,
+            _subscriptions,
+        }
+    }
+
+    fn title(&self, dialect: UserAdminDialect) -> &'static str {
+        match (self.kind, dialect.uses_hosts()) {
+            (UserDialogKind::Create, true) => "Create User",
+            (UserDialogKind::Create, false) => "Create Role",
+            (UserDialogKind::Alter, true) => "Alter User",
+            (UserDialogKind::Alter, false) => "Alter Role",
+            (UserDialogKind::Drop, true) => "Drop User",
+            (UserDialogKind::Drop, false) => "Drop Role",
+            (UserDialogKind::Grant, _) => "Grant",
+            (UserDialogKind::Revoke, _) => "Revoke",
+        }
+    }
+
+    /// Whether the dialog needs the user typed in, rather than taken from
+    /// the row it was opened on.
+    fn asks_for_account(&self) -> bool {
+        self.kind == UserDialogKind::Create || self.original.account.name.is_empty()
+    }
+
+    fn account(&self, cx: &App) -> UserAccount {
+        if !self.asks_for_account() {
+            return self.original.account.clone();
+        }
+
+        let host = self.host_input.read(cx).value().trim().to_string();
+
+        UserAccount::new(self.name_input.read(cx).value().trim())
+            .with_host(Some(host).filter(|host| !host.is_empty()))
+    }
+
+    /// The connection limit as typed; empty means no limit.
+    fn connection_limit(&self, cx: &App) -> Result<Option<i32>, DbError> {
+        let text = self
+            .connection_limit_input
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        if text.is_empty() {
+            return Ok(None);
+        }
+
+        text.parse()
+            .map(Some)
+            .map_err(|_| DbError::Parse(format!("'{text}' is not a connection limit")))
+    }
+
+    fn user_spec(&self, cx: &App) -> Result<UserSpec, DbError> {
+        let password = self.password_input.read(cx).value().to_string();
+        let password = Some(password).filter(|password| !password.is_empty());
+        let connection_limit = self.connection_limit(cx)?;
+
+        if self.kind == UserDialogKind::Create {
+            return Ok(UserSpec {
+                account: self.account(cx),
+                password,
+                can_login: Some(self.can_login),
+                superuser: self.superuser.then_some(true),
+                create_db: self.create_db.then_some(true),
+                create_role: self.create_role.then_some(true),
+                connection_limit,
+            });
+        }
+
+        // Only what the user changed; a cleared limit resets to unlimited.
+        let changed = |value: bool, original: Option<bool>| {
+            (Some(value) != original && (value || original.is_some())).then_some(value)
+        };
+        let connection_limit = match (connection_limit, self.original.connection_limit) {
+            (limit, original) if limit == original => None,
+            (None, Some(_)) => Some(-1),
+            (limit, _) => limit,
+        };
+
+        Ok(UserSpec {
+            account: self.account(cx),
+            password,
+            can_login: changed(self.can_login, self.original.can_login),
+            superuser: changed(self.superuser, self.original.superuser),
+            create_db: changed(self.create_db, self.original.create_db),
+            create_role: changed(self.create_role, self.original.create_role),
+            connection_limit,
+        })
+    }
+
+    fn grant_spec(&self, cx: &App) -> GrantSpec {
+        let name = self.target_input.read(cx).value().trim().to_string();
+        let target = match self.target_kind {
+            TargetKind::Role => GrantTarget::Role(name),
+            TargetKind::Database => GrantTarget::Database(name),
+            TargetKind::Schema => GrantTarget::Schema(name),
+            TargetKind::AllTablesInSchema => GrantTarget::AllTablesInSchema(name),
+            TargetKind::Table => match name.split_once('.') {
+                Some((schema, table)) => GrantTarget::Table {
+                    schema: Some(schema.to_string()),
+                    name: table.to_string(),
+                },
+                None => GrantTarget::Table { schema: None, name },
+            },
+        };
+
+        let privileges = self
+            .privileges_input
+            .read(cx)
+            .value()
+            .split(',')
+            .map(|privilege| privilege.trim().to_string())
+            .collect();
+
+        GrantSpec {
+            privileges,
+            target,
+            grantee: self.account(cx),
+            with_grant_option: self.with_grant_option,
+        }
+    }
+
+    /// The statement the dialog currently describes, without a terminator.
+    pub(super) fn statement(&self, dialect: UserAdminDialect, cx: &App) -> Result<String, DbError> {
+        match self.kind {
+            UserDialogKind::Create => dialect.create_user_sql(&self.user_spec(cx)?),
+            UserDialogKind::Alter => dialect.alter_user_sql(&self.user_spec(cx)?),
+            UserDialogKind::Drop => dialect.drop_user_sql(&self.account(cx)),
+            UserDialogKind::Grant => dialect.grant_sql(&self.grant_spec(cx)),
+            UserDialogKind::Revoke => dialect.revoke_sql(&self.grant_spec(cx)),
+        }
+    }
+
+    /// The dialog's statement when it sets a password, or `None` when it
+    /// can open in an editor.
+    pub(super) fn password_statement(
+        &self,
+        cx: &App,
+    ) -> Result<Option<PasswordStatement>, DbError> {
+        if !matches!(self.kind, UserDialogKind::Create | UserDialogKind::Alter) {
+            return Ok(None);
+        }
+
+        let spec = self.user_spec(cx)?;
+        Ok(spec.password.is_some().then_some(PasswordStatement {
+            kind: self.kind,
+            spec,
+        }))
+    }
+
+    /// The statement as previewed, with the password masked.
+    fn preview(&self, dialect: UserAdminDialect, cx: &App) -> Result<String, DbError> {
+        match self.password_statement(cx)? {
+            Some(mut statement) => {
+                statement.spec.password = Some(MASKED_PASSWORD.to_string());
+                statement.sql(dialect)
+            }
+            None => self.statement(dialect, cx),
+        }
+    }
+}
+
+impl UserAdminDocument {
+    pub(super) fn render_dialog(&self, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let dialog = self.dialog.as_ref()?;
+        let dialect = self.dialect;
+        let theme = cx.theme().clone();
+
+        let mut body = div().flex().flex_col().gap(Spacing::SM);
+
+        if dialog.asks_for_account() {
+            let name_label = match dialog.kind {
+                UserDialogKind::Grant => "Grant to",
+                UserDialogKind::Revoke => "Revoke from",
+                _ => "Name",
+            };
+            body = body.child(field(name_label, &dialog.name_input));
+            if dialect.uses_hosts() {
+                body = body.child(field("Host", &dialog.host_input));
+            }
+        }
+
+        match dialog.kind {
+            UserDialogKind::Create | UserDialogKind::Alter => {
+                body = body
+                    .child(field("Password", &dialog.password_input))
+                    .child(field("Connection limit", &dialog.connection_limit_input));
+
+                if dialect.supports_role_attributes() {
+                    body = body.child(
+                        div()
+                            .flex()
+                            .flex_wrap()
+                            .gap(Spacing::MD)
+                            .child(attribute_checkbox(
+                                "user-admin-can-login",
+                                "Can log in",
+                                dialog.can_login,
+                                |dialog, checked| dialog.can_login = checked,
+                                cx,
+                            ))
+                            .child(attribute_checkbox(
+                                "user-admin-superuser",
+                                "Superuser",
+                                dialog.superuser,
+                                |dialog, checked| dialog.superuser = checked,
+                                cx,
+                            ))
+                            .child(attribute_checkbox(
+                                "user-admin-create-db",
+                                "Create databases",
+                                dialog.create_db,
+                                |dialog, checked| dialog.create_db = checked,
+                                cx,
+                            ))
+                            .child(attribute_checkbox(
+                                "user-admin-create-role",
+                                "Create roles",
+                                dialog.create_role,
+                                |dialog, checked| dialog.create_role = checked,
+                                cx,
+                            )),
+                    );
+                }
+            }
+            UserDialogKind::Drop => {
+                body = body.child(Text::muted(
+                    "Objects owned by this user must be reassigned or dropped first.",
+                ));
+            }
+            UserDialogKind::Grant | UserDialogKind::Revoke => {
+                let mut kinds = div().flex().flex_wrap().gap(Spacing::XS);
+                for &kind in TargetKind::offered(dialect) {
+                    kinds = kinds.child(
+                        ToolbarButton::new(("user-admin-target", kind as usize))
+                            .label(kind.label(dialect))
+                            .variant(if dialog.target_kind == kind {
+                                ToolbarButtonVariant::Default
+                            } else {
+                                ToolbarButtonVariant::Ghost
+                            })
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.set_target_kind(kind, cx);
+                            })),
+                    );
+                }
+
+                body = body.child(kinds);
+                if dialog.target_kind != TargetKind::Role {
+                    body = body.child(field("Privileges", &dialog.privileges_input));
+                }
+                body = body.child(field(
+                    dialog.target_kind.label(dialect),
+                    &dialog.target_input,
+                ));
+
+                if dialog.kind == UserDialogKind::Grant {
+                    let option_label = if dialog.target_kind == TargetKind::Role {
+                        "With admin option"
+                    } else {
+                        "With grant option"
+                    };
+                    body = body.child(attribute_checkbox(
+                        "user-admin-grant-option",
+                        option_label,
+                        dialog.with_grant_option,
+                        |dialog, checked| dialog.with_grant_option = checked,
+                        cx,
+                    ));
+                }
+            }
+        }
+
+        let statement = dialog.preview(dialect, cx);
+        let sets_password = matches!(dialog.password_statement(cx), Ok(Some(_)));
+        let preview = match &statement {
+            Ok(sql) => Text::caption(format!("{sql};")).into_any_element(),
+            Err(error) => Text::caption(error.to_string())
+                .color(theme.danger)
+                .into_any_element(),
+        };
+        body = body.child(
+            div()
+                .p(Spacing::SM)
+                .rounded(Radii::SM)
+                .bg(theme.secondary)
+                .font_family(AppFonts::MONO)
+                .child(preview),
+        );
+        if sets_password {
+            body = body.child(Text::muted(
+                "Statements that set a password run directly, so the password is not kept \
+                 in an editor, the query history or the saved session.",
+            ));
+        }
+        if let Some(error) = &dialog.run_error {
+            body = body.child(Text::caption(error.clone()).color(theme.danger));
+        }
+
+        let entity_cancel = cx.entity();
+        let entity_open = cx.entity();
+        let entity_close = cx.entity();
+
+        let footer = div()
+            .flex()
+            .gap(Spacing::SM)
+            .child(
+                Button::new("user-admin-dialog-cancel-btn", "Cancel").on_click(move |_, _, cx| {
+                    entity_cancel.update(cx, |doc, cx| doc.close_dialog(cx));
+                }),
+            )
+            .child({
+                let label = if sets_password {
+                    "Run"
+                } else {
+                    "Open in Editor"
+                };
+                let button = Button::new("user-admin-dialog-open-btn", label)
+                    .disabled(statement.is_err() || dialog.running)
+                    .on_click(move |_, _, cx| {
+                        entity_open.update(cx, |doc, cx| doc.open_dialog_statement(cx));
+                    });
+                if dialog.kind == UserDialogKind::Drop {
+                    button.danger()
+                } else {
+                    button.primary()
+                }
+            })
+            .into_any_element();
+
+        let variant = if dialog.kind == UserDialogKind::Drop {
+            ModalVariant::Danger
+        } else {
+            ModalVariant::Default
+        };
+
+        let modal = ModalShell::new(dialog.title(dialect), body.into_any_element(), footer)
+            .width(px(480.0))
+            .variant(variant)
+            .on_close(move |_, cx| {
+                entity_close.update(cx, |doc, cx| doc.close_dialog(cx));
+            });
+
+        Some(modal.into_any_element())
+    }
+
+    fn set_target_kind(&mut self, kind: TargetKind, cx: &mut Context<Self>) {
+        if let Some(dialog) = self.dialog.as_mut() {
+            dialog.target_kind = kind;
+            cx.notify();
+        }
+    }
+}
+
+fn field(label: &'static str, input: &Entity<InputState>) -> impl IntoElement {
+    div()
+        .flex()
+        .flex_col()
+        .gap(Spacing::XS)
+        .child(Text::label(label))
+        .child(Input::new(input))
+}
+
+fn attribute_checkbox(
+    id: &'static str,
+    label: &'static str,
+    checked: bool,
+    set: impl Fn(&mut UserDialog, bool) + 'static,
+    cx: &mut Context<UserAdminDocument>,
+) -> impl IntoElement {
+    Checkbox::new(id)
+        .checked(checked)
+        .label(label)
+        .on_click(cx.listener(move |this, &checked, _, cx| {
+            if let Some(dialog) = this.dialog.as_mut() {
+                set(dialog, checked);
+                cx.notify();
+            }
+        }))
+}
@@ -0,0 +1,572 @@
+//! `UserAdminDocument` — lists a connection's users and roles with their
+//! attributes and memberships, and builds `CREATE`/`ALTER`/`DROP USER` and
+//! `GRANT`/`REVOKE` statements through guided dialogs.
+//!
+//! Generated statements open in a new SQL editor so they go through the
+//! usual review and dangerous-query checks. Statements that set a password
+//! are the exception: they run from the dialog, so the password is never
+//! written to an editor, the query history or the saved session.
+
+mod dialog;
+pub mod pane;
+
+use super::DataGridPanel;
+use super::data_grid_panel::DataGridEvent;
+use super::handle::DocumentEvent;
+use super::task_runner::DocumentTaskRunner;
+use super::types::{DocumentId, DocumentState};
+use crate::chrome::{ToolbarButton, ToolbarButtonVariant, compact_top_bar};
+use dbflux_app::keymap::{Command, ContextId};
+use dbflux_components::icons::AppIcon;
+use dbflux_components::primitives::Text;
+use dbflux_components::result_panel::ResultPanel;
+use dbflux_core::{
+    Connection, DbError, InspectorRowAction, QueryRequest, QueryResult, UserAccount,
+    UserAdminDialect, UserSpec, Value,
+};
+use dbflux_ui_base::AppStateEntity;
+use dialog::{PasswordStatement, UserDialog, UserDialogKind};
+use gpui::prelude::*;
+use gpui::{AnyElement, Context, Entity, EventEmitter, FocusHandle, Subscription, Window, div};
+use gpui_component::ActiveTheme;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const ACTION_ALTER: &str = "user-admin.alter";
+const ACTION_DROP: &str = "user-admin.drop";
+const ACTION_GRANT: &str = "user-admin.grant";
+const ACTION_REVOKE: &str = "user-admin.revoke";
+
+struct PendingResult {
+    task_id: dbflux_core::TaskId,
+    result: Result<QueryResult, dbflux_core::DbError>,
+}
+
+/// Administration document for the users and roles of one connection.
+pub struct UserAdminDocument {
+    id: DocumentId,
+    state: DocumentState,
+
+    profile_id: Uuid,
+    dialect: UserAdminDialect,
+
+    result: Option<Arc<QueryResult>>,
+    last_error: Option<String>,
+
+    runner: DocumentTaskRunner,
+    app_state: Entity<AppStateEntity>,
+    pending_result: Option<PendingResult>,
+
+    focus_handle: FocusHandle,
+
+    /// Built on the first render after a successful fetch, since
+    /// `DataGridPanel::new_for_result` needs a `Window`.
+    data_grid: Option<Entity<DataGridPanel>>,
+    pending_grid_result: Option<Arc<QueryResult>>,
+    result_panel: Option<Entity<ResultPanel>>,
+    _data_grid_subscription: Option<Subscription>,
+
+    /// Open statement dialog, if any.
+    dialog: Option<UserDialog>,
+    /// Dialog requested outside render; built on the next render because its
+    /// inputs need a `Window`.
+    pending_dialog: Option<(UserDialogKind, Option<UserSpec>)>,
+}
+
+impl EventEmitter<DocumentEvent> for UserAdminDocument {}
+
+impl UserAdminDocument {
+    pub fn new(
+        profile_id: Uuid,
+        dialect: UserAdminDialect,
+        app_state: Entity<AppStateEntity>,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let mut runner = DocumentTaskRunner::new(app_state.clone());
+        runner.set_profile_id(profile_id);
+
+        Self {
+            id: DocumentId::new(),
+            state: DocumentState::Clean,
+            profile_id,
+            dialect,
+            result: None,
+            last_error: None,
+            runner,
+            app_state,
+            pending_result: None,
+            focus_handle: cx.focus_handle(),
+            data_grid: None,
+            pending_grid_result: None,
+            result_panel: None,
+            _data_grid_subscription: None,
+            dialog: None,
+            pending_dialog: None,
+        }
+    }
+
+    pub fn id(&self) -> DocumentId {
+        self.id
+    }
+
+    pub fn title(&self) -> String {
+        "Users and Roles".to_string()
+    }
+
+    pub fn state(&self) -> DocumentState {
+        self.state
+    }
+
+    pub fn connection_id(&self) -> Option<Uuid> {
+        Some(self.profile_id)
+    }
+
+    pub fn active_context(&self) -> ContextId {
+        if self.dialog.is_some() {
+            ContextId::TextInput
+        } else {
+            ContextId::Global
+        }
+    }
+
+    pub fn focus(&mut self, window: &mut Window, _cx: &mut Context<Self>) {
+        self.focus_handle.focus(window);
+    }
+
+    pub fn dispatch_command(
+        &mut self,
+        cmd: Command,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        match cmd {
+            Command::Cancel if self.dialog.is_some() => {
+                self.close_dialog(cx);
+                true
+            }
+            Command::RefreshSchema => {
+                self.refresh(cx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The connection statements run on.
+    fn connection(&self, cx: &Context<Self>) -> Result<Arc<dyn Connection>, String> {
+        let state = self.app_state.read(cx);
+        let Some(connected) = state.connections().get(&self.profile_id) else {
+            return Err("Connection not found".to_string());
+        };
+
+        connected
+            .resolve_connection_for_execution(None)
+            .map_err(|error| format!("Connection error: {:?}", error))
+    }
+
+    /// Reloads the user list from the connection.
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let conn = match self.connection(cx) {
+            Ok(connection) => connection,
+            Err(error) => {
+                self.last_error = Some(error);
+                self.state = DocumentState::Error;
+                cx.notify();
+                return;
+            }
+        };
+
+        let request = QueryRequest::new(self.dialect.list_users_sql());
+        let (task_id, cancel_token) =
+            self.runner
+                .start_primary(dbflux_core::TaskKind::Query, "List users and roles", cx);
+
+        self.state = DocumentState::Loading;
+        cx.notify();
+
+        let task = cx
+            .background_executor()
+            .spawn(async move { conn.execute(&request) });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+
+            cx.update(|cx| {
+                if cancel_token.is_cancelled() {
+                    return;
+                }
+
+                let Some(entity) = this.upgrade() else { return };
+                entity.update(cx, |doc, cx| {
+                    doc.pending_result = Some(PendingResult { task_id, result });
+                    cx.notify();
+                });
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn apply_result(&mut self, pending: PendingResult, cx: &mut Context<Self>) {
+        self.runner.complete_primary(pending.task_id, cx);
+
+        match pending.result {
+            Ok(result) => {
+                self.state = DocumentState::Clean;
+                self.last_error = None;
+
+                let result = Arc::new(result);
+                if let Some(grid) = &self.data_grid {
+                    grid.update(cx, |grid, cx| grid.set_result((*result).clone(), cx));
+                } else {
+                    self.pending_grid_result = Some(result.clone());
+                }
+                self.result = Some(result);
+            }
+            Err(error) => {
+                self.state = DocumentState::Error;
+                self.last_error = Some(error.to_string());
+            }
+        }
+
+        cx.emit(DocumentEvent::ExecutionFinished);
+        cx.notify();
+    }
+
+    /// Opens a statement dialog, pre-filled from `user` if given.
+    fn request_dialog(
+        &mut self,
+        kind: UserDialogKind,
+        user: Option<UserSpec>,
+        cx: &mut Context<Self>,
+    ) {
+        self.pending_dialog = Some((kind, user));
+        cx.notify();
+    }
+
+    fn close_dialog(&mut self, cx: &mut Context<Self>) {
+        self.dialog = None;
+        cx.notify();
+    }
+
+    /// Hands the dialog's statement to a new SQL editor and closes it, or
+    /// runs it when it sets a password.
+    fn open_dialog_statement(&mut self, cx: &mut Context<Self>) {
+        let Some(dialog) = &self.dialog else {
+            return;
+        };
+
+        match dialog.password_statement(cx) {
+            Ok(Some(statement)) => {
+                self.run_password_statement(statement, cx);
+                return;
+            }
+            Ok(None) => {}
+            Err(_) => return,
+        }
+
+        let Ok(sql) = dialog.statement(self.dialect, cx) else {
+            return;
+        };
+
+        cx.emit(DocumentEvent::OpenEditorWithContent {
+            profile_id: self.profile_id,
+            sql: format!("{sql};"),
+        });
+        self.close_dialog(cx);
+    }
+
+    /// Runs a statement that sets a password, quoting it for the server's
+    /// `sql_mode` where that matters, and refreshes the list once it
+    /// succeeds.
+    fn run_password_statement(&mut self, statement: PasswordStatement, cx: &mut Context<Self>) {
+        let conn = match self.connection(cx) {
+            Ok(connection) => connection,
+            Err(error) => {
+                if let Some(dialog) = self.dialog.as_mut() {
+                    dialog.run_error = Some(error);
+                }
+                cx.notify();
+                return;
+            }
+        };
+
+        let dialect = self.dialect;
+        let (task_id, _cancel) =
+            self.runner
+                .start_mutation(dbflux_core::TaskKind::Query, "Set user password", cx);
+
+        if let Some(dialog) = self.dialog.as_mut() {
+            dialog.running = true;
+            dialog.run_error = None;
+        }
+        cx.notify();
+
+        let task = cx.background_executor().spawn(async move {
+            let dialect = match dialect.sql_mode_sql() {
+                Some(sql_mode_sql) => {
+                    let result = conn.execute(&QueryRequest::new(sql_mode_sql))?;
+                    let sql_mode = result
+                        .rows
+                        .first()
+                        .and_then(|row| row.first())
+                        .map(Value::as_display_string)
+                        .unwrap_or_default();
+                    dialect.with_sql_mode(&sql_mode)
+                }
+                None => dialect,
+            };
+
+            conn.execute(&QueryRequest::new(statement.sql(dialect)?))
+        });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+
+            cx.update(|cx| {
+                let Some(entity) = this.upgrade() else { return };
+                entity.update(cx, |doc, cx| {
+                    doc.finish_password_statement(task_id, result.map(|_| ()), cx);
+                });
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn finish_password_statement(
+        &mut self,
+        task_id: dbflux_core::TaskId,
+        result: Result<(), DbError>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(()) => {
+                self.runner.complete_mutation(task_id, cx);
+                self.close_dialog(cx);
+                self.refresh(cx);
+            }
+            Err(error) => {
+                self.runner.fail_mutation(task_id, error.to_string(), cx);
+                if let Some(dialog) = self.dialog.as_mut() {
+                    dialog.running = false;
+                    dialog.run_error = Some(error.to_string());
+                }
+                cx.notify();
+            }
+        }
+    }
+
+    /// The user a row of the list describes, with the attributes the list
+    /// reports for it.
+    fn spec_from_row(&self, row: &[Value]) -> Option<UserSpec> {
+        let result = self.result.as_ref()?;
+        let text = |column| row_text(result, row, column);
+        let name = text("name")?;
+        let host = self.dialect.uses_hosts().then(|| text("host")).flatten();
+        let flag = |column| text(column).map(|value| is_truthy(&value));
+
+        Some(UserSpec {
+            account: UserAccount::new(name).with_host(host),
+            password: None,
+            can_login: flag("can_login"),
+            superuser: flag("superuser"),
+            create_db: flag("create_db"),
+            create_role: flag("create_role"),
+            connection_limit: text("connection_limit")
+                .and_then(|value| value.parse().ok())
+                .filter(|limit| *limit > 0),
+        })
+    }
+
+    fn row_actions(&self) -> Vec<InspectorRowAction> {
+        let (alter, drop) = if self.dialect.uses_hosts() {
+            ("Alter User…", "Drop User…")
+        } else {
+            ("Alter Role…", "Drop Role…")
+        };
+
+        [
+            (ACTION_ALTER, alter, false),
+            (ACTION_GRANT, "Grant…", false),
+            (ACTION_REVOKE, "Revoke…", false),
+            (ACTION_DROP, drop, true),
+        ]
+        .into_iter()
+        .map(|(id, label, is_destructive)| InspectorRowAction {
+            id: id.to_string(),
+            label: label.to_string(),
+            description: None,
+            is_destructive,
+        })
+        .collect()
+    }
+
+    fn ensure_grid(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.data_grid.is_some() {
+            return;
+        }
+        let Some(result) = self.pending_grid_result.take() else {
+            return;
+        };
+
+        let profile_id = self.profile_id;
+        let app_state = self.app_state.clone();
+        let grid = cx.new(|cx| {
+            DataGridPanel::new_for_result(
+                result,
+                "users".to_string(),
+                Some(profile_id),
+                app_state,
+                window,
+                cx,
+            )
+        });
+
+        let actions = self.row_actions();
+        grid.update(cx, |grid, _cx| {
+            grid.set_row_action_provider(Arc::new(move |_| actions.clone()));
+        });
+
+        let subscription = cx.subscribe(&grid, |this, _grid, event: &DataGridEvent, cx| {
+            if let DataGridEvent::RowActionRequested {
+                action_id,
+                row_values,
+                ..
+            } = event
+            {
+                let kind = match action_id.as_str() {
+                    ACTION_ALTER => UserDialogKind::Alter,
+                    ACTION_DROP => UserDialogKind::Drop,
+                    ACTION_GRANT => UserDialogKind::Grant,
+                    ACTION_REVOKE => UserDialogKind::Revoke,
+                    _ => return,
+                };
+                let user = this.spec_from_row(row_values);
+                this.request_dialog(kind, user, cx);
+            }
+        });
+
+        let view_handle = DataGridPanel::into_view_handle(grid.clone(), cx);
+        self.result_panel = Some(cx.new(|cx| ResultPanel::new(view_handle, cx)));
+        self.data_grid = Some(grid);
+        self._data_grid_subscription = Some(subscription);
+    }
+
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let noun = if self.dialect.uses_hosts() {
+            "User"
+        } else {
+            "Role"
+        };
+
+        compact_top_bar(&theme, std::iter::empty::<AnyElement>())
+            .id("user-admin-toolbar")
+            .child(
+                ToolbarButton::new("user-admin-create-btn")
+                    .icon(AppIcon::Plus)
+                    .label(format!("New {noun}"))
+                    .variant(ToolbarButtonVariant::Primary)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.request_dialog(UserDialogKind::Create, None, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("user-admin-grant-btn")
+                    .icon(AppIcon::KeyRound)
+                    .label("Grant")
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.request_dialog(UserDialogKind::Grant, None, cx);
+                    })),
+            )
+            .child(
+                ToolbarButton::new("user-admin-revoke-btn")
+                    .icon(AppIcon::Undo)
+                    .label("Revoke")
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.request_dialog(UserDialogKind::Revoke, None, cx);
+                    })),
+            )
+            .child(div().flex_1())
+            .child(
+                Text::caption("Right-click a row to alter, drop, grant or revoke")
+                    .muted_foreground(),
+            )
+            .child(
+                ToolbarButton::new("user-admin-refresh-btn")
+                    .icon(AppIcon::RefreshCcw)
+                    .tooltip("Refresh")
+                    .variant(ToolbarButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.refresh(cx);
+                    })),
+            )
+    }
+}
+
+impl Render for UserAdminDocument {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if let Some(pending) = self.pending_result.take() {
+            self.apply_result(pending, cx);
+        }
+
+        self.ensure_grid(window, cx);
+
+        if let Some((kind, user)) = self.pending_dialog.take() {
+            self.dialog = Some(UserDialog::new(kind, user, window, cx));
+        }
+
+        let body = if let Some(panel) = self.result_panel.clone() {
+            div().flex_1().min_h_0().child(panel).into_any_element()
+        } else {
+            let message = if let Some(error) = &self.last_error {
+                format!("Error: {error}")
+            } else if self.state == DocumentState::Loading {
+                "Loading…".to_string()
+            } else {
+                "No data. Connect and click Refresh.".to_string()
+            };
+
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(Text::muted(message))
+                .into_any_element()
+        };
+
+        div()
+            .size_full()
+            .relative()
+            .flex()
+            .flex_col()
+            .track_focus(&self.focus_handle)
+            .child(self.render_toolbar(cx))
+            .child(body)
+            .children(self.render_dialog(cx))
+    }
+}
+
+/// Display text of the `column` cell of `row`, or `None` when it is empty.
+fn row_text(result: &QueryResult, row: &[Value], column: &str) -> Option<String> {
+    let index = result
+        .columns
+        .iter()
+        .position(|meta| meta.name.eq_ignore_ascii_case(column))?;
+    let value = row.get(index)?;
+
+    if value.is_null() {
+        return None;
+    }
+
+    Some(value.as_display_string()).filter(|text| !text.is_empty())
+}
+
+fn is_truthy(text: &str) -> bool {
+    matches!(
+        text.to_ascii_lowercase().as_str(),
+        "true" | "t" | "1" | "yes" | "y"
+    )
+}
@@ -0,0 +1,208 @@
+use super::*;
+use dbflux_core::MutationCategory;
+
+/// Code generators offered on a multi-selection: generator id, label, and
+/// whether the generated statement is destructive.
+const SELECTED_CODE_GENERATORS: &[(&str, &str, bool)] = &[
+    ("select_star", "SELECT *", false),
+    ("truncate", "TRUNCATE", true),
+    ("drop_table", "DROP TABLE", true),
+];
+
+impl Sidebar {
+    /// Selected tables and views, in tree order.
+    fn selected_table_ids(&self) -> Vec<String> {
+        if self.active_tab != SidebarTab::Connections {
+            return Vec::new();
+        }
+
+        self.visible_rows
+            .ids
+            .iter()
+            .filter(|id| self.multi_selection.contains(*id))
+            .filter(|id| {
+                matches!(
+                    parse_node_kind(id),
+                    SchemaNodeKind::Table | SchemaNodeKind::View
+                )
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The selected tables and views when the right-clicked `item_id` is one
+    /// of at least two of them, which turns its menu into the bulk menu.
+    fn bulk_selection_for(&self, item_id: &str) -> Option<Vec<String>> {
+        let ids = self.selected_table_ids();
+        (ids.len() > 1 && ids.iter().any(|id| id == item_id)).then_some(ids)
+    }
+
+    /// Connection and database shared by every item of `ids`. Scripts and
+    /// exports run against a single one, so mixed selections only open.
+    fn shared_selection_target(ids: &[String]) -> Option<(Uuid, Option<String>)> {
+        let mut parts = ids.iter().map(|id| {
+            parse_node_id(id)
+                .as_ref()
+                .and_then(ItemIdParts::from_node_id)
+        });
+
+        let first = parts.next()??;
+        let target = (first.profile_id, first.database);
+
+        parts
+            .all(|part| part.is_some_and(|part| (part.profile_id, part.database) == target))
+            .then_some(target)
+    }
+
+    pub(super) fn build_bulk_menu_items(
+        &self,
+        item_id: &str,
+        cx: &App,
+    ) -> Option<Vec<ContextMenuItem>> {
+        let ids = self.bulk_selection_for(item_id)?;
+        let count = ids.len();
+
+        let mut items = Vec::new();
+        Self::append_menu_section(
+            &mut items,
+            [ContextMenuItem::item(
+                format!("Open {count} Objects"),
+                ContextMenuAction::OpenSelected,
+            )],
+        );
+
+        let Some((profile_id, _)) = Self::shared_selection_target(&ids) else {
+            return Some(items);
+        };
+
+        let state = self.app_state.read(cx);
+        let Some(conn) = state.connections().get(&profile_id) else {
+            return Some(items);
+        };
+
+        let speaks_sql = conn.connection.query_generator().is_some_and(|generator| {
+            generator
+                .supported_categories()
+                .contains(&MutationCategory::Sql)
+        });
+        let only_tables = ids
+            .iter()
+            .all(|id| parse_node_kind(id) == SchemaNodeKind::Table);
+        let driver_generators = conn.connection.code_generators();
+
+        let generators: Vec<ContextMenuItem> = SELECTED_CODE_GENERATORS
+            .iter()
+            .filter(|(generator_id, _, _)| match *generator_id {
+                "select_star" => speaks_sql,
+                _ => only_tables && driver_generators.iter().any(|g| g.id == *generator_id),
+            })
+            .map(|(generator_id, label, destructive)| {
+                let action = ContextMenuAction::GenerateSelectedCode(generator_id.to_string());
+                if *destructive {
+                    ContextMenuItem::danger(label.to_string(), action)
+                } else {
+                    ContextMenuItem::item(label.to_string(), action)
+                }
+            })
+            .collect();
+
+        if !generators.is_empty() {
+            Self::append_menu_section(
+                &mut items,
+                [ContextMenuItem::item(
+                    format!("Generate SQL for {count} Objects"),
+                    ContextMenuAction::Submenu(generators),
+                )
+                .with_icon(AppIcon::Code)],
+            );
+        }
+
+        if speaks_sql && only_tables {
+            Self::append_menu_section(
+                &mut items,
+                [ContextMenuItem::item(
+                    format!("Export {count} Tables\u{2026}"),
+                    ContextMenuAction::ExportSelected,
+                )],
+            );
+        }
+
+        Some(items)
+    }
+
+    pub(super) fn open_selected_tables(&mut self, cx: &mut Context<Self>) {
+        for item_id in self.selected_table_ids() {
+            self.browse_table(&item_id, cx);
+        }
+    }
+
+    /// Applies `generator_id` to every selected table and opens the
+    /// statements as one script in a new query tab.
+    pub(super) fn generate_selected_code(&mut self, generator_id: &str, cx: &mut Context<Self>) {
+        let ids = self.selected_table_ids();
+        let Some((profile_id, _)) = Self::shared_selection_target(&ids) else {
+            return;
+        };
+
+        let generated = {
+            let state = self.app_state.read(cx);
+            let Some(conn) = state.connections().get(&profile_id) else {
+                return;
+            };
+
+            ids.iter()
+                .filter_map(|id| {
+                    let parts = ItemIdParts::from_node_id(&parse_node_id(id)?)?;
+                    if parse_node_kind(id) == SchemaNodeKind::View {
+                        Self::resolve_view_info(&parts, conn)
+                    } else {
+                        Self::resolve_table_info(&parts, conn)
+                    }
+                })
+                .map(|table| conn.connection.generate_code(generator_id, &table))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        match generated {
+            Ok(statements) if !statements.is_empty() => {
+                cx.emit(SidebarEvent::OpenNewQueryWithContent {
+                    profile_id,
+                    language: QueryLanguage::Sql,
+                    query: statements.join("\n\n"),
+                });
+            }
+            Ok(_) => log::warn!("Code generation found none of the selected tables"),
+            Err(e) => {
+                log::error!("Code generation failed: {}", e);
+                self.pending_toast = Some(PendingToast {
+                    message: format!("Code generation failed: {}", e),
+                    is_error: true,
+                });
+                cx.notify();
+            }
+        }
+    }
+
+    pub(super) fn export_selected_tables(&mut self, cx: &mut Context<Self>) {
+        let ids = self.selected_table_ids();
+        let Some((profile_id, database)) = Self::shared_selection_target(&ids) else {
+            return;
+        };
+
+        let tables = ids
+            .iter()
+            .filter_map(|id| {
+                parse_node_id(id)
+                    .as_ref()
+                    .and_then(ItemIdParts::from_node_id)
+            })
+            .map(|parts| TableRef::with_schema(&parts.schema_name, &parts.object_name))
+            .collect();
+
+        cx.emit(SidebarEvent::RequestExportTables {
+            profile_id,
+            database,
+            tables,
+        });
+    }
+}
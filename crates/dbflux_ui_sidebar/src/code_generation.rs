@@ -1,5 +1,6 @@
 use super::*;
-use dbflux_core::MutationCategory;
+use crate::object_tooltip::{loaded_column, table_comment};
+use dbflux_core::{MutationCategory, SetCommentRequest};
 use dbflux_ui_base::sql_preview_modal::SqlGenerationType;
 
 impl Sidebar {
@@ -148,27 +149,7 @@ impl Sidebar {
             return;
         };
 
-        let view_from_db_schemas = conn
-            .database_schemas
-            .get(&parts.schema_name)
-            .and_then(|db_schema| db_schema.views.iter().find(|v| v.name == parts.object_name));
-
-        let view_from_per_db = || {
-            parts
-                .database
-                .as_deref()
-                .and_then(|db| conn.database_connections.get(db))
-                .and_then(|dc| dc.schema.as_ref())
-                .and_then(|schema| {
-                    Self::find_view_in_schema(&parts.schema_name, &parts.object_name, schema)
-                })
-        };
-
-        let view = view_from_db_schemas
-            .or_else(view_from_per_db)
-            .or_else(|| Self::find_view_for_item(&parts, &conn.schema));
-
-        let Some(view) = view else {
+        let Some(table_info) = Self::resolve_view_info(&parts, conn) else {
             log::warn!(
                 "Code generation for view '{}' failed: view not found",
                 parts.object_name
@@ -176,19 +157,6 @@ impl Sidebar {
             return;
         };
 
-        // Create a TableInfo from the ViewInfo for code generation
-        let table_info = TableInfo {
-            name: view.name.clone(),
-            schema: view.schema.clone(),
-            columns: None,
-            indexes: None,
-            foreign_keys: None,
-            constraints: None,
-            sample_fields: None,
-            presentation: dbflux_core::CollectionPresentation::DataGrid,
-            child_items: None,
-        };
-
         if let Some(gen_type) = SqlGenerationType::from_generator_id(generator_id) {
             cx.emit(SidebarEvent::RequestSqlPreview {
                 profile_id: parts.profile_id,
@@ -232,33 +200,7 @@ impl Sidebar {
                 return;
             };
 
-            let cache_db = parts.cache_database();
-            let cache_key = (cache_db.to_string(), parts.object_name.clone());
-
-            if let Some(table) = conn.table_details.get(&cache_key) {
-                Some(table.clone())
-            } else {
-                let from_db_schemas = conn
-                    .database_schemas
-                    .get(&parts.schema_name)
-                    .and_then(|ds| ds.tables.iter().find(|t| t.name == parts.object_name));
-
-                let from_per_db = || {
-                    parts
-                        .database
-                        .as_deref()
-                        .and_then(|db| conn.database_connections.get(db))
-                        .and_then(|dc| dc.schema.as_ref())
-                        .and_then(|s| {
-                            Self::find_table_in_schema(&parts.schema_name, &parts.object_name, s)
-                        })
-                };
-
-                from_db_schemas
-                    .or_else(from_per_db)
-                    .or_else(|| Self::find_table_for_item(&parts, &conn.schema))
-                    .cloned()
-            }
+            Self::resolve_table_info(&parts, conn)
         };
 
         let Some(table) = resolved else {
@@ -294,6 +236,79 @@ impl Sidebar {
         }
     }
 
+    /// Metadata of the table behind `parts`: its loaded details when cached,
+    /// otherwise the entry of the schema it was listed in.
+    pub(super) fn resolve_table_info(
+        parts: &ItemIdParts,
+        conn: &ConnectedProfile,
+    ) -> Option<TableInfo> {
+        let cache_db = parts.cache_database();
+        let cache_key = (cache_db.to_string(), parts.object_name.clone());
+
+        if let Some(table) = conn.table_details.get(&cache_key) {
+            return Some(table.clone());
+        }
+
+        let from_db_schemas = conn
+            .database_schemas
+            .get(&parts.schema_name)
+            .and_then(|ds| ds.tables.iter().find(|t| t.name == parts.object_name));
+
+        let from_per_db = || {
+            parts
+                .database
+                .as_deref()
+                .and_then(|db| conn.database_connections.get(db))
+                .and_then(|dc| dc.schema.as_ref())
+                .and_then(|s| Self::find_table_in_schema(&parts.schema_name, &parts.object_name, s))
+        };
+
+        from_db_schemas
+            .or_else(from_per_db)
+            .or_else(|| Self::find_table_for_item(parts, &conn.schema))
+            .cloned()
+    }
+
+    /// The view behind `parts` as a column-less `TableInfo`, which is all the
+    /// code generators need from a view.
+    pub(super) fn resolve_view_info(
+        parts: &ItemIdParts,
+        conn: &ConnectedProfile,
+    ) -> Option<TableInfo> {
+        let view_from_db_schemas = conn
+            .database_schemas
+            .get(&parts.schema_name)
+            .and_then(|db_schema| db_schema.views.iter().find(|v| v.name == parts.object_name));
+
+        let view_from_per_db = || {
+            parts
+                .database
+                .as_deref()
+                .and_then(|db| conn.database_connections.get(db))
+                .and_then(|dc| dc.schema.as_ref())
+                .and_then(|schema| {
+                    Self::find_view_in_schema(&parts.schema_name, &parts.object_name, schema)
+                })
+        };
+
+        let view = view_from_db_schemas
+            .or_else(view_from_per_db)
+            .or_else(|| Self::find_view_for_item(parts, &conn.schema))?;
+
+        Some(TableInfo {
+            name: view.name.clone(),
+            schema: view.schema.clone(),
+            columns: None,
+            indexes: None,
+            foreign_keys: None,
+            constraints: None,
+            sample_fields: None,
+            presentation: dbflux_core::CollectionPresentation::DataGrid,
+            child_items: None,
+            comment: None,
+        })
+    }
+
     /// Search for a table within a specific schema of a `SchemaSnapshot`.
     fn find_table_in_schema<'a>(
         schema_name: &str,
@@ -494,6 +509,64 @@ impl Sidebar {
         }
     }
 
+    /// Opens the statement that sets the comment of a table or column,
+    /// pre-filled with the current comment for editing.
+    pub(super) fn generate_comment_sql(&mut self, item_id: &str, cx: &mut Context<Self>) {
+        let state = self.app_state.read(cx);
+
+        let sql = match parse_node_id(item_id) {
+            Some(SchemaNodeId::Table {
+                profile_id,
+                database,
+                schema,
+                name,
+            }) => {
+                let Some(conn) = state.connections().get(&profile_id) else {
+                    return;
+                };
+
+                conn.connection
+                    .code_generator()
+                    .generate_set_comment(&SetCommentRequest {
+                        table_name: &name,
+                        schema_name: Some(&schema),
+                        column: None,
+                        comment: Some(
+                            table_comment(conn, database.as_deref(), &schema, &name)
+                                .unwrap_or_default(),
+                        ),
+                    })
+            }
+            Some(SchemaNodeId::Column {
+                profile_id,
+                table,
+                name,
+            }) => {
+                let Some(conn) = state.connections().get(&profile_id) else {
+                    return;
+                };
+                let Some((table_info, column)) = loaded_column(conn, &table, &name) else {
+                    log::warn!("Column {}.{} is not loaded", table, name);
+                    return;
+                };
+
+                conn.connection
+                    .code_generator()
+                    .generate_set_comment(&SetCommentRequest {
+                        table_name: &table_info.name,
+                        schema_name: table_info.schema.as_deref(),
+                        column: Some(column),
+                        comment: Some(column.comment.as_deref().unwrap_or_default()),
+                    })
+            }
+            _ => None,
+        };
+
+        if let Some(sql) = sql {
+            cx.emit(SidebarEvent::GenerateSql(sql));
+        }
+    }
+
     pub(super) fn generate_foreign_key_sql(
         &mut self,
         item_id: &str,
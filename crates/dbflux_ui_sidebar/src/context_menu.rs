@@ -1,8 +1,8 @@
 use super::*;
-use dbflux_core::DdlCapabilities;
+use dbflux_core::{DdlCapabilities, UserAdminDialect};
 
 impl Sidebar {
-    fn append_menu_section(
+    pub(super) fn append_menu_section(
         items: &mut Vec<ContextMenuItem>,
         section: impl IntoIterator<Item = ContextMenuItem>,
     ) {
@@ -124,6 +124,11 @@ impl Sidebar {
     ) -> Vec<ContextMenuItem> {
         match node_kind {
             SchemaNodeKind::Table | SchemaNodeKind::View => {
+                // Right-clicking one of several selected tables acts on all of them.
+                if let Some(items) = self.build_bulk_menu_items(item_id, cx) {
+                    return items;
+                }
+
                 let mut items = Vec::new();
 
                 Self::append_menu_section(
@@ -141,11 +146,18 @@ impl Sidebar {
                     );
                 }
 
+                let favorite_label = if self.is_favorite_node(item_id, cx) {
+                    "Remove from Favorites"
+                } else {
+                    "Add to Favorites"
+                };
+
                 Self::append_menu_section(
                     &mut items,
                     [
                         ContextMenuItem::item("View Schema", ContextMenuAction::ViewSchema),
                         ContextMenuItem::item("Refresh", ContextMenuAction::RefreshObject),
+                        ContextMenuItem::item(favorite_label, ContextMenuAction::ToggleFavorite),
                     ],
                 );
 
@@ -162,6 +174,26 @@ impl Sidebar {
                     );
                 }
 
+                if node_kind == SchemaNodeKind::Table {
+                    let caps = self.get_capabilities_for_item(item_id, cx);
+                    let mut table_items = Vec::new();
+
+                    if caps.contains(CodeGenCapabilities::RENAME) {
+                        table_items.push(ContextMenuItem::item(
+                            "Rename Table\u{2026}",
+                            ContextMenuAction::RenameObject,
+                        ));
+                    }
+                    if caps.contains(CodeGenCapabilities::COMMENTS) {
+                        table_items.push(ContextMenuItem::item(
+                            "Edit Comment",
+                            ContextMenuAction::EditComment,
+                        ));
+                    }
+
+                    Self::append_menu_section(&mut items, table_items);
+                }
+
                 // Drop items gated on DDL capabilities
                 if let Some(ddl) = self.get_ddl_capabilities(item_id, cx) {
                     let drop_allowed = match node_kind {
@@ -285,26 +317,82 @@ impl Sidebar {
                 items
             }
             SchemaNodeKind::Profile => {
-                let is_connected =
-                    if let Some(SchemaNodeId::Profile { profile_id }) = parse_node_id(item_id) {
-                        self.app_state
+                let connected_profile = match parse_node_id(item_id) {
+                    Some(SchemaNodeId::Profile { profile_id })
+                        if self
+                            .app_state
                             .read(cx)
                             .connections()
-                            .contains_key(&profile_id)
-                    } else {
-                        false
-                    };
+                            .contains_key(&profile_id) =>
+                    {
+                        Some(profile_id)
+                    }
+                    _ => None,
+                };
+
+                let active_tasks = match parse_node_id(item_id) {
+                    Some(SchemaNodeId::Profile { profile_id }) => self
+                        .app_state
+                        .read(cx)
+                        .tasks()
+                        .active_tasks_for_profile(profile_id)
+                        .len(),
+                    _ => 0,
+                };
 
                 let mut items = Vec::new();
 
-                if is_connected {
+                if active_tasks > 0 {
+                    Self::append_menu_section(
+                        &mut items,
+                        [ContextMenuItem::danger(
+                            format!("Cancel All Tasks ({})", active_tasks),
+                            ContextMenuAction::CancelProfileTasks,
+                        )],
+                    );
+                }
+
+                if let Some(profile_id) = connected_profile {
+                    let hides_system_objects = self
+                        .app_state
+                        .read(cx)
+                        .effective_settings_for_connection(Some(profile_id))
+                        .hide_system_objects;
+                    let system_objects_label = if hides_system_objects {
+                        "Show System Objects"
+                    } else {
+                        "Hide System Objects"
+                    };
+
                     Self::append_menu_section(
                         &mut items,
                         [
                             ContextMenuItem::item("Disconnect", ContextMenuAction::Disconnect),
                             ContextMenuItem::item("Refresh", ContextMenuAction::Refresh),
+                            ContextMenuItem::item(
+                                system_objects_label,
+                                ContextMenuAction::ToggleSystemObjects,
+                            ),
                         ],
                     );
+
+                    let manages_users = self
+                        .app_state
+                        .read(cx)
+                        .connections()
+                        .get(&profile_id)
+                        .and_then(|connected| UserAdminDialect::for_kind(connected.profile.kind()))
+                        .is_some();
+                    if manages_users {
+                        Self::append_menu_section(
+                            &mut items,
+                            [ContextMenuItem::item(
+                                "Users and Roles",
+                                ContextMenuAction::ManageUsers,
+                            )
+                            .with_icon(AppIcon::KeyRound)],
+                        );
+                    }
                 } else {
                     Self::append_menu_section(
                         &mut items,
@@ -322,6 +410,14 @@ impl Sidebar {
                     ],
                 );
 
+                Self::append_menu_section(
+                    &mut items,
+                    [ContextMenuItem::item(
+                        "Open in New Window",
+                        ContextMenuAction::OpenInNewWindow,
+                    )],
+                );
+
                 // Add "Move to..." submenu with available folders
                 let move_to_items = self.build_move_to_submenu(item_id, cx);
                 if !move_to_items.is_empty() {
@@ -455,6 +551,26 @@ impl Sidebar {
                 items
             }
 
+            SchemaNodeKind::Column => {
+                let caps = self.get_capabilities_for_item(item_id, cx);
+                let mut items = Vec::new();
+
+                if caps.contains(CodeGenCapabilities::RENAME) {
+                    items.push(ContextMenuItem::item(
+                        "Rename Column\u{2026}",
+                        ContextMenuAction::RenameObject,
+                    ));
+                }
+                if caps.contains(CodeGenCapabilities::COMMENTS) {
+                    items.push(ContextMenuItem::item(
+                        "Edit Comment",
+                        ContextMenuAction::EditComment,
+                    ));
+                }
+
+                items
+            }
+
             SchemaNodeKind::Index | SchemaNodeKind::SchemaIndex => {
                 let caps = self.get_capabilities_for_item(item_id, cx);
                 let mut submenu = Vec::new();
@@ -762,6 +878,25 @@ impl Sidebar {
                 items
             }
 
+            SchemaNodeKind::FavoriteItem => {
+                let mut items = Vec::new();
+
+                Self::append_menu_section(
+                    &mut items,
+                    [ContextMenuItem::item("Open", ContextMenuAction::Open)],
+                );
+
+                Self::append_menu_section(
+                    &mut items,
+                    [ContextMenuItem::item(
+                        "Remove from Favorites",
+                        ContextMenuAction::RemoveFavorite,
+                    )],
+                );
+
+                items
+            }
+
             SchemaNodeKind::DatabasesFolder => {
                 vec![ContextMenuItem::item("Refresh", ContextMenuAction::Refresh)]
             }
@@ -986,12 +1121,24 @@ impl Sidebar {
                             sample_fields: collection.sample_fields.clone(),
                             presentation: collection.presentation,
                             child_items: collection.child_items.clone(),
+                            comment: None,
                         })
                     })
                 })
             })
     }
 
+    fn is_favorite_node(&self, item_id: &str, cx: &App) -> bool {
+        parse_node_id(item_id)
+            .and_then(favorite_target_for_node)
+            .is_some_and(|(profile_id, target)| {
+                self.app_state
+                    .read(cx)
+                    .connection_tree()
+                    .is_favorite(profile_id, &target)
+            })
+    }
+
     fn collection_supports_child_picker(&self, item_id: &str, cx: &App) -> bool {
         self.collection_info_for_item(item_id, cx)
             .is_some_and(|collection| {
@@ -1211,7 +1358,9 @@ impl Sidebar {
                     SchemaNodeKind::Collection => {
                         self.browse_collection(&item_id, cx);
                     }
-                    SchemaNodeKind::DashboardItem | SchemaNodeKind::SavedChartItem => {
+                    SchemaNodeKind::DashboardItem
+                    | SchemaNodeKind::SavedChartItem
+                    | SchemaNodeKind::FavoriteItem => {
                         // Delegate to execute_item which emits the correct sidebar event.
                         self.execute_item(&item_id, cx);
                     }
@@ -1239,6 +1388,11 @@ impl Sidebar {
                     self.disconnect_profile(profile_id, cx);
                 }
             }
+            ContextMenuAction::CancelProfileTasks => {
+                if let Some(SchemaNodeId::Profile { profile_id }) = parse_node_id(&item_id) {
+                    self.cancel_profile_tasks(profile_id, cx);
+                }
+            }
             ContextMenuAction::Refresh => {
                 let profile_id = match parse_node_id(&item_id) {
                     Some(SchemaNodeId::Profile { profile_id }) => Some(profile_id),
@@ -1259,6 +1413,16 @@ impl Sidebar {
                     cx.emit(SidebarEvent::RequestExportConnection { profile_id });
                 }
             }
+            ContextMenuAction::OpenInNewWindow => {
+                if let Some(SchemaNodeId::Profile { profile_id }) = parse_node_id(&item_id) {
+                    cx.emit(SidebarEvent::RequestOpenInNewWindow { profile_id });
+                }
+            }
+            ContextMenuAction::ManageUsers => {
+                if let Some(SchemaNodeId::Profile { profile_id }) = parse_node_id(&item_id) {
+                    cx.emit(SidebarEvent::OpenUserAdmin { profile_id });
+                }
+            }
             ContextMenuAction::Duplicate => {
                 self.duplicate_profile(&item_id, cx);
             }
@@ -1302,6 +1466,12 @@ impl Sidebar {
             ContextMenuAction::GenerateCollectionCode(kind) => {
                 self.generate_collection_code(&item_id, kind, cx);
             }
+            ContextMenuAction::EditComment => {
+                self.generate_comment_sql(&item_id, cx);
+            }
+            ContextMenuAction::RenameObject => {
+                self.show_rename_modal(&item_id, cx);
+            }
             ContextMenuAction::QueryCollection => {
                 self.query_collection(&item_id, cx);
             }
@@ -1424,6 +1594,24 @@ impl Sidebar {
                     cx.emit(SidebarEvent::RequestDuplicateSavedChart { chart_id });
                 }
             }
+            ContextMenuAction::ToggleFavorite => {
+                self.toggle_table_favorite(&item_id, cx);
+            }
+            ContextMenuAction::RemoveFavorite => {
+                self.remove_favorite_item(&item_id, cx);
+            }
+            ContextMenuAction::ToggleSystemObjects => {
+                self.toggle_system_objects(&item_id, cx);
+            }
+            ContextMenuAction::OpenSelected => {
+                self.open_selected_tables(cx);
+            }
+            ContextMenuAction::GenerateSelectedCode(generator_id) => {
+                self.generate_selected_code(&generator_id, cx);
+            }
+            ContextMenuAction::ExportSelected => {
+                self.export_selected_tables(cx);
+            }
             ContextMenuAction::RefreshInstanceCatalog => {
                 if let Some(profile_id) = parse_node_id(&item_id).and_then(|n| n.profile_id()) {
                     self.clear_instance_catalog_cache(profile_id);
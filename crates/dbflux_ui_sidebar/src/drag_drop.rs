@@ -86,6 +86,45 @@ impl Sidebar {
         }
     }
 
+    /// Reorders a dragged favorite before or after the favorite under the
+    /// drop target. Favorites only move within their own connection.
+    pub(super) fn handle_favorite_drop(
+        &mut self,
+        drag_state: &FavoriteDragState,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(drop_target) = self.drop_target.take() else {
+            return;
+        };
+        cx.notify();
+
+        let Some(SchemaNodeId::FavoriteItem {
+            profile_id,
+            favorite_id: target_id,
+        }) = parse_node_id(&drop_target.item_id)
+        else {
+            return;
+        };
+
+        if profile_id != drag_state.profile_id || target_id == drag_state.favorite_id {
+            return;
+        }
+
+        let after = drop_target.position == DropPosition::After;
+        let mut moved = false;
+
+        self.app_state.update(cx, |state, cx| {
+            moved = state.move_favorite(drag_state.favorite_id, target_id, after);
+            if moved {
+                cx.emit(AppStateChanged);
+            }
+        });
+
+        if moved {
+            self.refresh_tree(cx);
+        }
+    }
+
     fn resolve_connection_drag_nodes(
         &self,
         drag_state: &SidebarDragState,
@@ -773,7 +773,8 @@ impl Sidebar {
 
         let items = self.build_tree_items_with_overrides(cx);
         self.prune_connection_selection(&items);
-        self.visible_entry_count = Self::count_visible_entries(&items);
+        self.visible_rows = VisibleRows::index(&items);
+        self.visible_entry_count = self.visible_rows.len();
         self.gutter_metadata = compute_gutter_map(&items);
 
         self.syncing_expansion = true;
@@ -813,12 +814,15 @@ impl Sidebar {
         for profile_id in stale_metric_ids {
             self.clear_instance_catalog_cache(profile_id);
         }
+        self.changed_schema_objects
+            .retain(|profile_id, _| connected_profile_ids.contains(profile_id));
 
         self.cleanup_stale_overrides(cx);
 
         let items = self.build_tree_items_with_overrides(cx);
         self.prune_connection_selection(&items);
-        self.visible_entry_count = Self::count_visible_entries(&items);
+        self.visible_rows = VisibleRows::index(&items);
+        self.visible_entry_count = self.visible_rows.len();
         self.gutter_metadata = compute_gutter_map(&items);
 
         if let Some(ref menu) = self.context_menu
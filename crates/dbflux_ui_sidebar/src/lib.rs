@@ -1,16 +1,20 @@
+mod bulk_actions;
 mod code_generation;
 mod context_menu;
 mod deletion;
 mod drag_drop;
 mod expansion;
+mod object_tooltip;
 pub mod operations;
 mod render;
 mod render_footer;
 mod render_overlays;
 mod render_tree;
+mod schema_refresh;
 mod selection;
 mod style_guardrails;
 mod table_loading;
+mod table_prefetch;
 mod tree_builder;
 
 use dbflux_app::AppState;
@@ -25,10 +29,10 @@ use dbflux_core::{
     CollectionChildInfo, CollectionIndexInfo, CollectionPresentation, CollectionRef,
     ConnectionTreeNode, ConnectionTreeNodeKind, ConstraintKind, CreateIndexRequest,
     CreateTypeRequest, CustomTypeInfo, CustomTypeKind, DatabaseCategory, DriverCapabilities,
-    DropForeignKeyRequest, DropIndexRequest, DropTypeRequest, EventStreamTarget, IndexData,
-    IndexDirection, QueryLanguage, ReindexRequest, RelationRef, RoutineInfo, SchemaCacheKey,
-    SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaNodeId, SchemaNodeKind,
-    SchemaSnapshot, TableInfo, TableRef, TaskId, TypeDefinition, ViewInfo,
+    DropForeignKeyRequest, DropIndexRequest, DropTypeRequest, EventStreamTarget, FavoriteTarget,
+    IndexData, IndexDirection, QueryLanguage, ReindexRequest, RelationRef, RoutineInfo,
+    SchemaCacheKey, SchemaForeignKeyInfo, SchemaIndexInfo, SchemaLoadingStrategy, SchemaNodeId,
+    SchemaNodeKind, SchemaSnapshot, TableInfo, TableRef, TaskId, TypeDefinition, ViewInfo,
 };
 use dbflux_ui_base::app_state_entity::{AppStateChanged, AppStateEntity};
 use gpui::prelude::FluentBuilder;
@@ -106,6 +110,13 @@ pub enum SidebarEvent {
     RequestExportConnection {
         profile_id: Uuid,
     },
+    /// Request to export the data of several tables of one connection, one
+    /// file per table.
+    RequestExportTables {
+        profile_id: Uuid,
+        database: Option<String>,
+        tables: Vec<TableRef>,
+    },
     /// Request to open the delete-connection modal for a specific connection profile.
     RequestDeleteConnection {
         connection_name: String,
@@ -119,6 +130,11 @@ pub enum SidebarEvent {
         schema_name: Option<String>,
         dependents: Vec<dbflux_core::RelationRef>,
     },
+    /// Request to open the rename modal for a table or column.
+    RequestRenameObject {
+        item_id: String,
+        request: dbflux_components::modals::RenameObjectRequest,
+    },
     /// Open a new metric chart pre-populated with the selected metric's defaults.
     ///
     /// Emitted when the user clicks a `MetricLeaf` node in the sidebar tree.
@@ -151,6 +167,13 @@ pub enum SidebarEvent {
         chart_id: Uuid,
     },
 
+    /// Open a saved query in a new editor tab.
+    ///
+    /// Emitted when the user clicks a starred saved query under `FavoritesFolder`.
+    OpenSavedQuery {
+        query_id: Uuid,
+    },
+
     /// Request to open the "New Dashboard" creation modal for a specific profile.
     RequestCreateDashboard {
         profile_id: Uuid,
@@ -214,6 +237,13 @@ pub enum SidebarEvent {
         profile_id: Uuid,
     },
 
+    /// Open or focus the users and roles administration document.
+    ///
+    /// Emitted by the "Users and Roles" item of a connected profile's menu.
+    OpenUserAdmin {
+        profile_id: Uuid,
+    },
+
     /// Request to prompt the user for an SSH tunnel passphrase.
     ///
     /// Emitted when a connection attempt fails with a passphrase-required error
@@ -248,9 +278,11 @@ pub enum SidebarEvent {
     RequestOpenConnectionManager,
 
     /// Request to open the Connection Manager window pre-loaded for editing
-    /// an existing connection profile.
+    /// an existing connection profile, optionally on the field that fixes a
+    /// failed connection.
     RequestEditConnection {
         profile_id: Uuid,
+        fix: Option<dbflux_core::ConnectionFixTarget>,
     },
 
     /// Request to open the Connection Manager window with a specific folder
@@ -258,6 +290,13 @@ pub enum SidebarEvent {
     RequestOpenConnectionManagerInFolder {
         folder_id: Uuid,
     },
+
+    /// Request to open a new workspace window limited to one connection.
+    ///
+    /// Same layering rationale as `RequestOpenSettings`.
+    RequestOpenInNewWindow {
+        profile_id: Uuid,
+    },
 }
 
 /// Sentinel value for IDs that don't correspond to schema tree nodes
@@ -280,6 +319,40 @@ fn parse_node_id(id: &str) -> Option<SchemaNodeId> {
     id.parse().ok()
 }
 
+/// The profile and favorite target of a table or view node; `None` for any
+/// other node.
+fn favorite_target_for_node(node: SchemaNodeId) -> Option<(Uuid, FavoriteTarget)> {
+    match node {
+        SchemaNodeId::Table {
+            profile_id,
+            database,
+            schema,
+            name,
+        } => Some((
+            profile_id,
+            FavoriteTarget::Table {
+                database,
+                schema,
+                name,
+            },
+        )),
+        SchemaNodeId::View {
+            profile_id,
+            database,
+            schema,
+            name,
+        } => Some((
+            profile_id,
+            FavoriteTarget::View {
+                database,
+                schema,
+                name,
+            },
+        )),
+        _ => None,
+    }
+}
+
 #[derive(Clone)]
 pub struct ContextMenuItem {
     pub label: String,
@@ -367,6 +440,12 @@ pub enum ContextMenuAction {
     GenerateCode(String),
     Connect,
     Disconnect,
+    /// Cancel every running and queued task of a connection.
+    CancelProfileTasks,
+    /// Open a new window showing only this connection.
+    OpenInNewWindow,
+    /// Open the users and roles administration document.
+    ManageUsers,
     Refresh,
     Edit,
     Duplicate,
@@ -386,6 +465,10 @@ pub enum ContextMenuAction {
     GenerateForeignKeySql(ForeignKeySqlAction),
     GenerateTypeSql(TypeSqlAction),
     GenerateCollectionCode(CollectionCodeKind),
+    /// Open the statement that sets the comment of a table or column.
+    EditComment,
+    /// Rename a table or column after confirming the generated statement.
+    RenameObject,
     /// Open a new code document pre-seeded with a query template for this collection.
     ///
     /// Available for any `DatabaseCategory::TimeSeries` measurement. The template
@@ -422,6 +505,20 @@ pub enum ContextMenuAction {
     RenameSavedChart,
     DeleteSavedChart,
     DuplicateSavedChart,
+    // Favorite actions
+    /// Star or unstar a table or view.
+    ToggleFavorite,
+    /// Unstar the object behind a `FavoriteItem`.
+    RemoveFavorite,
+    /// Show or hide system schemas and internal objects for a connection.
+    ToggleSystemObjects,
+    // Multi-selection actions on tables and views
+    /// Open every selected table or view as a data document.
+    OpenSelected,
+    /// Generate one script applying a code generator to every selected table.
+    GenerateSelectedCode(String),
+    /// Export the data of every selected table.
+    ExportSelected,
     // Instance catalog actions
     /// Invalidate the cached instance catalog for a profile and re-fetch it.
     RefreshInstanceCatalog,
@@ -484,6 +581,8 @@ impl ContextMenuAction {
             Self::GenerateForeignKeySql(_) => Some(AppIcon::Code),
             Self::GenerateTypeSql(_) => Some(AppIcon::Code),
             Self::GenerateCollectionCode(_) => Some(AppIcon::Code),
+            Self::EditComment => Some(AppIcon::Pencil),
+            Self::RenameObject => Some(AppIcon::Pencil),
             Self::QueryCollection => Some(AppIcon::Code),
             Self::NewQueryForDatabase => Some(AppIcon::Code),
             Self::RefreshDatabase => Some(AppIcon::RefreshCcw),
@@ -510,6 +609,14 @@ impl ContextMenuAction {
             Self::RenameSavedChart => Some(AppIcon::Pencil),
             Self::DeleteSavedChart => Some(AppIcon::Delete),
             Self::DuplicateSavedChart => Some(AppIcon::Copy),
+            // Favorite actions
+            Self::ToggleFavorite => Some(AppIcon::Star),
+            Self::ToggleSystemObjects => Some(AppIcon::Eye),
+            // Multi-selection actions
+            Self::OpenSelected => Some(AppIcon::Eye),
+            Self::GenerateSelectedCode(_) => Some(AppIcon::Code),
+            Self::ExportSelected => Some(AppIcon::ArrowUp),
+            Self::RemoveFavorite => Some(AppIcon::Star),
             // Instance catalog actions
             Self::RefreshInstanceCatalog => Some(AppIcon::RefreshCcw),
             Self::CopyItemId => Some(AppIcon::Copy),
@@ -534,6 +641,13 @@ impl SidebarDragState {
     }
 }
 
+#[derive(Clone)]
+struct FavoriteDragState {
+    favorite_id: Uuid,
+    profile_id: Uuid,
+    label: String,
+}
+
 #[derive(Clone)]
 struct ScriptsDragState {
     path: std::path::PathBuf,
@@ -786,6 +900,48 @@ fn compute_gutter_map(items: &[TreeItem]) -> HashMap<String, GutterInfo> {
     map
 }
 
+/// Row layout of the connections tree as last handed to `tree_state`: the
+/// visible rows in order, with each row's index and expansion. Selection and
+/// click handling read it instead of rebuilding the tree.
+#[derive(Default)]
+struct VisibleRows {
+    ids: Vec<String>,
+    rows: HashMap<String, (usize, bool)>,
+}
+
+impl VisibleRows {
+    fn index(items: &[TreeItem]) -> Self {
+        fn walk(items: &[TreeItem], out: &mut VisibleRows) {
+            for item in items {
+                let id = item.id.to_string();
+                out.rows
+                    .insert(id.clone(), (out.ids.len(), item.is_expanded()));
+                out.ids.push(id);
+
+                if item.is_expanded() && item.is_folder() {
+                    walk(&item.children, out);
+                }
+            }
+        }
+
+        let mut rows = Self::default();
+        walk(items, &mut rows);
+        rows
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn position(&self, item_id: &str) -> Option<usize> {
+        self.rows.get(item_id).map(|&(index, _)| index)
+    }
+
+    fn is_expanded(&self, item_id: &str) -> Option<bool> {
+        self.rows.get(item_id).map(|&(_, expanded)| expanded)
+    }
+}
+
 pub struct Sidebar {
     app_state: Entity<AppStateEntity>,
     tree_state: Entity<TreeState>,
@@ -799,6 +955,7 @@ pub struct Sidebar {
     connections_focused: bool,
     search_input_focused: bool,
     visible_entry_count: usize,
+    visible_rows: VisibleRows,
     /// User overrides for expansion state (item_id -> is_expanded)
     expansion_overrides: HashMap<String, bool>,
     /// State for the keyboard-triggered context menu
@@ -875,6 +1032,17 @@ pub struct Sidebar {
     /// A single fetch populates both `instance_metrics_cache` and
     /// `instance_inspectors_cache` because the catalog returns both in one round-trip.
     pending_instance_catalog_fetches: HashMap<Uuid, Task<()>>,
+    /// Interval the schema refresh timer runs at; `Manual` while it is off.
+    schema_refresh_policy: dbflux_core::RefreshPolicy,
+    _schema_refresh_timer: Option<Task<()>>,
+    /// Profiles with a background schema refresh in flight.
+    schema_refreshes_in_flight: HashSet<Uuid>,
+    /// Tables and views a background schema refresh found added or altered,
+    /// per profile. Rendered with a "changed" badge until clicked.
+    changed_schema_objects: HashMap<Uuid, HashSet<dbflux_core::SchemaObjectRef>>,
+    /// Connections shown in the tree; `None` shows all of them. Set for
+    /// windows opened on a subset of the connections.
+    profile_scope: Option<HashSet<Uuid>>,
 }
 
 use dbflux_ui_base::toast::PendingToast;
@@ -918,7 +1086,8 @@ impl Sidebar {
         cx: &mut Context<Self>,
     ) -> Self {
         let items = Self::build_tree_items(app_state.read(cx));
-        let visible_entry_count = Self::count_visible_entries(&items);
+        let visible_rows = VisibleRows::index(&items);
+        let visible_entry_count = visible_rows.len();
         let gutter_metadata = compute_gutter_map(&items);
         let tree_state = cx.new(|cx| TreeState::new(cx).items(items));
         let connections_search_input = cx
@@ -950,11 +1119,19 @@ impl Sidebar {
                     this.reconnect_profile_after_edit(profile_id, cx);
                 }
 
+                this.sync_schema_refresh_timer(cx);
                 this.refresh_tree(cx);
                 this.refresh_scripts_tree(cx);
             },
         );
 
+        let window_activation_subscription =
+            cx.observe_window_activation(window, |this, window, cx| {
+                if window.is_window_active() {
+                    this.refresh_schemas_on_focus(cx);
+                }
+            });
+
         let rename_subscription = cx.subscribe_in(
             &rename_input,
             window,
@@ -1019,6 +1196,7 @@ impl Sidebar {
                 this.syncing_expansion = true;
 
                 let entry = tree_state.read(cx).selected_entry().cloned();
+                let mut toggled = false;
 
                 if let Some(entry) = entry
                     && entry.is_folder()
@@ -1030,6 +1208,7 @@ impl Sidebar {
                     if known != Some(tree_expanded) {
                         this.expansion_overrides
                             .insert(item_id.clone(), tree_expanded);
+                        toggled = this.visible_rows.is_expanded(&item_id) != Some(tree_expanded);
 
                         if tree_expanded && !this.trigger_expansion_fetch(&item_id, cx) {
                             this.expansion_overrides.remove(&item_id);
@@ -1038,9 +1217,15 @@ impl Sidebar {
                 }
 
                 this.syncing_expansion = false;
+
+                // Collapsed nodes only hold a placeholder; rebuild so an
+                // expanded node gets its children and the row layout is current.
+                if toggled {
+                    this.rebuild_tree_with_overrides(cx);
+                }
             });
 
-        Self {
+        let mut sidebar = Self {
             app_state,
             tree_state,
             connections_search_input,
@@ -1053,6 +1238,7 @@ impl Sidebar {
             connections_focused: false,
             search_input_focused: false,
             visible_entry_count,
+            visible_rows,
             expansion_overrides: HashMap::new(),
             context_menu: None,
             pending_actions: HashMap::new(),
@@ -1066,6 +1252,7 @@ impl Sidebar {
                 connections_search_subscription,
                 scripts_search_subscription,
                 tree_expansion_subscription,
+                window_activation_subscription,
             ],
             editing_id: None,
             editing_is_folder: false,
@@ -1097,7 +1284,15 @@ impl Sidebar {
             instance_metrics_cache: HashMap::new(),
             instance_inspectors_cache: HashMap::new(),
             pending_instance_catalog_fetches: HashMap::new(),
-        }
+            schema_refresh_policy: dbflux_core::RefreshPolicy::Manual,
+            _schema_refresh_timer: None,
+            schema_refreshes_in_flight: HashSet::new(),
+            changed_schema_objects: HashMap::new(),
+            profile_scope: None,
+        };
+
+        sidebar.sync_schema_refresh_timer(cx);
+        sidebar
     }
 
     /// Return the profile ID that is currently awaiting SSH passphrase input, if any.
@@ -1144,6 +1339,14 @@ impl Sidebar {
         cx.notify();
     }
 
+    /// Limits the connections tree to `scope`, or shows every connection
+    /// again with `None`. Folders left without connections are hidden.
+    pub fn set_profile_scope(&mut self, scope: Option<HashSet<Uuid>>, cx: &mut Context<Self>) {
+        self.profile_scope = scope;
+        self.refresh_tree(cx);
+        cx.notify();
+    }
+
     pub fn set_active_tab(&mut self, tab: SidebarTab, cx: &mut Context<Self>) {
         if self.active_tab != tab {
             self.active_tab = tab;
@@ -1384,6 +1587,12 @@ impl Sidebar {
             SchemaNodeId::SavedChartItem { chart_id, .. } => {
                 cx.emit(SidebarEvent::OpenSavedChart { chart_id });
             }
+            SchemaNodeId::FavoriteItem {
+                profile_id,
+                favorite_id,
+            } => {
+                self.open_favorite(profile_id, favorite_id, cx);
+            }
             SchemaNodeId::DashboardsFolder { .. } | SchemaNodeId::SavedChartsFolder { .. } => {
                 // Folder clicks toggle expansion via the tree component's built-in
                 // expand/collapse mechanism; no navigation event is emitted here.
@@ -1409,6 +1618,7 @@ impl Sidebar {
         cx: &mut Context<Self>,
     ) {
         cx.emit(SidebarEvent::RequestFocus);
+        self.acknowledge_schema_change(item_id);
 
         // Shift+Click: select visible range from anchor in active tab.
         if with_shift && click_count == 1 {
@@ -1539,6 +1749,102 @@ impl Sidebar {
         }
     }
 
+    /// Opens what a favorite points at: the table or view browser, or the
+    /// saved query in an editor tab.
+    fn open_favorite(&mut self, profile_id: Uuid, favorite_id: Uuid, cx: &mut Context<Self>) {
+        let Some(target) = self
+            .app_state
+            .read(cx)
+            .connection_tree()
+            .find_favorite(favorite_id)
+            .map(|favorite| favorite.target.clone())
+        else {
+            return;
+        };
+
+        match target {
+            FavoriteTarget::Table {
+                database,
+                schema,
+                name,
+            }
+            | FavoriteTarget::View {
+                database,
+                schema,
+                name,
+            } => {
+                cx.emit(SidebarEvent::OpenTable {
+                    profile_id,
+                    table: TableRef::with_schema(&schema, &name),
+                    database,
+                });
+            }
+            FavoriteTarget::SavedQuery { query_id } => {
+                cx.emit(SidebarEvent::OpenSavedQuery { query_id });
+            }
+        }
+    }
+
+    /// Stars or unstars the table or view behind `item_id`.
+    fn toggle_table_favorite(&mut self, item_id: &str, cx: &mut Context<Self>) {
+        let Some((profile_id, target)) = parse_node_id(item_id).and_then(favorite_target_for_node)
+        else {
+            return;
+        };
+
+        self.app_state.update(cx, |state, cx| {
+            state.toggle_favorite(profile_id, target);
+            cx.emit(AppStateChanged);
+        });
+        self.refresh_tree(cx);
+    }
+
+    /// Flips whether the connection behind a profile node hides its system
+    /// schemas and internal objects.
+    fn toggle_system_objects(&mut self, item_id: &str, cx: &mut Context<Self>) {
+        let Some(SchemaNodeId::Profile { profile_id }) = parse_node_id(item_id) else {
+            return;
+        };
+
+        self.app_state.update(cx, |state, cx| {
+            let hidden = state
+                .effective_settings_for_connection(Some(profile_id))
+                .hide_system_objects;
+            state.set_hide_system_objects(profile_id, !hidden);
+            cx.emit(AppStateChanged);
+        });
+        self.refresh_tree(cx);
+    }
+
+    /// Unstars the favorite behind a `FavoriteItem` node. Saved queries go
+    /// through their own star so the history list stays in sync.
+    fn remove_favorite_item(&mut self, item_id: &str, cx: &mut Context<Self>) {
+        let Some(SchemaNodeId::FavoriteItem { favorite_id, .. }) = parse_node_id(item_id) else {
+            return;
+        };
+
+        self.app_state.update(cx, |state, cx| {
+            let saved_query = match state.connection_tree().find_favorite(favorite_id) {
+                Some(favorite) => match favorite.target {
+                    FavoriteTarget::SavedQuery { query_id } => Some(query_id),
+                    _ => None,
+                },
+                None => return,
+            };
+
+            match saved_query {
+                Some(query_id) => {
+                    state.toggle_saved_query_favorite(query_id);
+                }
+                None => {
+                    state.remove_favorite(favorite_id);
+                }
+            }
+            cx.emit(AppStateChanged);
+        });
+        self.refresh_tree(cx);
+    }
+
     fn browse_collection(&mut self, item_id: &str, cx: &mut Context<Self>) {
         if let Some(SchemaNodeId::Collection {
             profile_id,
@@ -1581,11 +1887,14 @@ impl Sidebar {
         // this item. Script folders are not present in the connections tree,
         // so falling back to that source always yielded `false` and made
         // every chevron click try to expand — never collapse.
-        let items = match self.active_tab {
-            SidebarTab::Connections => self.build_tree_items_with_overrides(cx),
-            SidebarTab::Scripts => self.build_scripts_tree_items_with_overrides(cx),
-        };
-        let currently_expanded = Self::find_item_expanded(&items, item_id).unwrap_or(false);
+        let currently_expanded = match self.active_tab {
+            SidebarTab::Connections => self.visible_rows.is_expanded(item_id),
+            SidebarTab::Scripts => {
+                let items = self.build_scripts_tree_items_with_overrides(cx);
+                Self::find_item_expanded(&items, item_id)
+            }
+        }
+        .unwrap_or(false);
         self.set_expanded(item_id, !currently_expanded, cx);
     }
 
@@ -1617,12 +1926,16 @@ impl Sidebar {
 #[cfg(test)]
 mod tests {
     use super::Sidebar;
-    use super::{ContextMenuAction, ContextMenuItem, ItemIdParts, NODE_KIND_NONE, parse_node_kind};
+    use super::{
+        ContextMenuAction, ContextMenuItem, ItemIdParts, NODE_KIND_NONE, VisibleRows,
+        favorite_target_for_node, parse_node_kind,
+    };
     use crate::operations::{connect_prepare_error_toast, format_connect_prepare_error};
     use dbflux_app::{ExternalDriverDiagnostic, ExternalDriverStage};
     use dbflux_core::PrepareConnectError;
-    use dbflux_core::{SchemaNodeId, SchemaNodeKind};
+    use dbflux_core::{FavoriteTarget, SchemaNodeId, SchemaNodeKind};
     use gpui_component::tree::TreeItem;
+    use std::collections::HashSet;
     use uuid::Uuid;
 
     fn test_uuid() -> Uuid {
@@ -2160,6 +2473,43 @@ mod tests {
         assert!(filtered[0].children[0].children[0].is_expanded());
     }
 
+    #[test]
+    fn profile_scope_keeps_scoped_connections_and_their_folders() {
+        let prod = Uuid::new_v4();
+        let dev = Uuid::new_v4();
+        let profile = |profile_id: Uuid, name: &str| {
+            TreeItem::new(
+                SchemaNodeId::Profile { profile_id }.to_string(),
+                name.to_string(),
+            )
+        };
+        let folder = |name: &str, children: Vec<TreeItem>| {
+            TreeItem::new(
+                SchemaNodeId::ConnectionFolder {
+                    node_id: Uuid::new_v4(),
+                }
+                .to_string(),
+                name.to_string(),
+            )
+            .expanded(true)
+            .children(children)
+        };
+
+        let items = vec![
+            folder("Production", vec![profile(prod, "prod")]),
+            folder("Development", vec![profile(dev, "dev")]),
+            profile(dev, "dev copy"),
+        ];
+
+        let scoped = Sidebar::apply_profile_scope(items, &HashSet::from([prod]));
+
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].label.as_ref(), "Production");
+        assert!(scoped[0].is_expanded());
+        assert_eq!(scoped[0].children.len(), 1);
+        assert_eq!(scoped[0].children[0].label.as_ref(), "prod");
+    }
+
     // ---- T18.1: tree filter works for metric node variants ----
 
     /// T18.1: Verify that `apply_tree_filter` correctly includes/excludes
@@ -2443,4 +2793,52 @@ mod tests {
             super::SidebarEvent::RequestDuplicateSavedChart { .. }
         ));
     }
+
+    #[test]
+    fn favorite_target_covers_tables_and_views_only() {
+        let profile_id = test_uuid();
+        let table = SchemaNodeId::Table {
+            profile_id,
+            database: Some("shop".to_string()),
+            schema: "public".to_string(),
+            name: "orders".to_string(),
+        };
+
+        assert_eq!(
+            favorite_target_for_node(table),
+            Some((
+                profile_id,
+                FavoriteTarget::Table {
+                    database: Some("shop".to_string()),
+                    schema: "public".to_string(),
+                    name: "orders".to_string(),
+                }
+            ))
+        );
+        assert_eq!(
+            favorite_target_for_node(SchemaNodeId::DatabasesFolder { profile_id }),
+            None
+        );
+    }
+
+    #[test]
+    fn visible_rows_skip_collapsed_children() {
+        let items = vec![
+            TreeItem::new("a", "a")
+                .expanded(true)
+                .children(vec![TreeItem::new("a1", "a1"), TreeItem::new("a2", "a2")]),
+            TreeItem::new("b", "b")
+                .expanded(false)
+                .children(vec![TreeItem::new("b1", "b1")]),
+            TreeItem::new("c", "c"),
+        ];
+
+        let rows = VisibleRows::index(&items);
+
+        assert_eq!(rows.ids, vec!["a", "a1", "a2", "b", "c"]);
+        assert_eq!(rows.position("b"), Some(3));
+        assert_eq!(rows.position("b1"), None);
+        assert_eq!(rows.is_expanded("a"), Some(true));
+        assert_eq!(rows.is_expanded("b"), Some(false));
+    }
 }
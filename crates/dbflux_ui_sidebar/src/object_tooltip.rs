@@ -0,0 +1,147 @@
+//! Hover text of table and column rows: the object's comment and, for
+//! tables, the cached statistics. Built when the tooltip opens, so rendering
+//! the tree never walks the schema for it.
+
+use super::*;
+use dbflux_core::{ColumnInfo, DbSchemaInfo, TableStatistics};
+
+impl Sidebar {
+    pub(super) fn object_tooltip(&self, node: &SchemaNodeId, cx: &App) -> Option<String> {
+        let state = self.app_state.read(cx);
+
+        match node {
+            SchemaNodeId::Table {
+                profile_id,
+                database,
+                schema,
+                name,
+            } => {
+                let connected = state.connections().get(profile_id)?;
+                let key = (
+                    database.as_deref().unwrap_or(schema).to_string(),
+                    name.clone(),
+                );
+
+                table_tooltip_text(
+                    table_comment(connected, database.as_deref(), schema, name),
+                    connected.table_statistics.get(&key),
+                )
+            }
+            SchemaNodeId::Column {
+                profile_id,
+                table,
+                name,
+            } => {
+                let connected = state.connections().get(profile_id)?;
+                let (_, column) = loaded_column(connected, table, name)?;
+                column.comment.clone()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Comment of a table, from its loaded details or else its listing entry.
+pub(super) fn table_comment<'a>(
+    connected: &'a ConnectedProfile,
+    database: Option<&str>,
+    schema: &str,
+    name: &str,
+) -> Option<&'a str> {
+    let key = (database.unwrap_or(schema).to_string(), name.to_string());
+
+    match connected.table_details.get(&key) {
+        Some(details) => details.comment.as_deref(),
+        None => listed_table(connected, database, schema, name)
+            .and_then(|table| table.comment.as_deref()),
+    }
+}
+
+/// A column of a table whose details are loaded, with that table. Column
+/// nodes only carry the table name, so the first loaded match wins.
+pub(super) fn loaded_column<'a>(
+    connected: &'a ConnectedProfile,
+    table: &str,
+    column: &str,
+) -> Option<(&'a TableInfo, &'a ColumnInfo)> {
+    connected
+        .table_details
+        .values()
+        .filter(|details| details.name == table)
+        .find_map(|details| {
+            details
+                .columns
+                .as_ref()?
+                .iter()
+                .find(|candidate| candidate.name == column)
+                .map(|candidate| (details, candidate))
+        })
+}
+
+/// The listing entry of a table whose details have not been loaded.
+fn listed_table<'a>(
+    connected: &'a ConnectedProfile,
+    database: Option<&str>,
+    schema: &str,
+    name: &str,
+) -> Option<&'a TableInfo> {
+    let find_in = |listing: &'a DbSchemaInfo| listing.tables.iter().find(|t| t.name == name);
+
+    if let Some(table) = connected
+        .database_schemas
+        .get(database.unwrap_or(schema))
+        .and_then(find_in)
+    {
+        return Some(table);
+    }
+
+    let snapshot = match database {
+        Some(database) => connected.schema_for_target_database(database),
+        None => connected.schema.as_ref(),
+    }?;
+
+    snapshot
+        .schemas()
+        .iter()
+        .find(|listing| listing.name == schema)
+        .and_then(find_in)
+        .or_else(|| snapshot.tables().iter().find(|t| t.name == name))
+}
+
+fn table_tooltip_text(
+    comment: Option<&str>,
+    statistics: Option<&TableStatistics>,
+) -> Option<String> {
+    let lines: Vec<String> = [
+        comment.map(str::to_string),
+        statistics.and_then(TableStatistics::summary),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_tooltip_puts_the_comment_above_the_statistics() {
+        let statistics = TableStatistics {
+            row_estimate: Some(1_200),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            table_tooltip_text(Some("Customer orders"), Some(&statistics)).as_deref(),
+            Some("Customer orders\n~1,200 rows")
+        );
+        assert_eq!(
+            table_tooltip_text(None, Some(&statistics)).as_deref(),
+            Some("~1,200 rows")
+        );
+        assert_eq!(table_tooltip_text(None, None), None);
+    }
+}
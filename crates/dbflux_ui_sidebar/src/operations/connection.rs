@@ -12,7 +12,7 @@ use dbflux_core::{
     execute_streaming_process, output_channel,
 };
 use dbflux_ssh::is_passphrase_required_error_str;
-use dbflux_ui_base::toast::PendingToast;
+use dbflux_ui_base::toast::{PendingToast, Toast, ToastAction, copy_action, now_hms};
 use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
 use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
 use std::sync::Arc;
@@ -103,6 +103,33 @@ pub(crate) fn connect_prepare_error_toast(
     }
 }
 
+/// Error toast for a connection failure with a known fix: besides Copy it
+/// offers a link that opens the profile editor on the field to change.
+fn connect_error_fix_toast(
+    sidebar: Entity<Sidebar>,
+    profile_id: Uuid,
+    error: String,
+    fix: dbflux_core::ConnectionErrorFix,
+    cx: &mut App,
+) {
+    let fix_action = ToastAction::new("connect-error-fix", fix.action_label())
+        .primary()
+        .on_click(move |cx| {
+            sidebar.update(cx, |_sidebar, cx| {
+                cx.emit(SidebarEvent::RequestEditConnection {
+                    profile_id,
+                    fix: Some(fix.target()),
+                });
+            });
+        });
+
+    Toast::error(error.clone())
+        .meta_right(now_hms())
+        .action(fix_action)
+        .action(copy_action(error))
+        .push(cx);
+}
+
 fn hook_task_details(
     hook: &ConnectionHook,
     phase: HookPhase,
@@ -1395,6 +1422,9 @@ impl Sidebar {
                                     sidebar.refresh_tree(cx);
                                 });
                             }
+                        } else if let Some(fix) = dbflux_core::ConnectionErrorFix::detect(&error) {
+                            connect_error_fix_toast(sidebar.clone(), profile_id, error, fix, cx);
+                            sidebar.update(cx, |sidebar, cx| sidebar.refresh_tree(cx));
                         } else {
                             sidebar.update(cx, |sidebar, cx| {
                                 sidebar.pending_toast = Some(PendingToast {
@@ -1534,6 +1564,13 @@ impl Sidebar {
                         connected.proxy_tunnel,
                         false,
                     );
+                    for database_schema in connected.database_schemas {
+                        state.set_database_schema(
+                            profile_id,
+                            database_schema.name.clone(),
+                            database_schema,
+                        );
+                    }
                     cx.emit(AppStateChanged);
                     cx.notify();
                 });
@@ -1555,6 +1592,7 @@ impl Sidebar {
                         is_error: false,
                     });
                     sidebar.refresh_tree(cx);
+                    sidebar.prefetch_recent_table_details(profile_id, cx);
                 });
             }) {
                 log::warn!(
@@ -1566,6 +1604,26 @@ impl Sidebar {
         .detach();
     }
 
+    /// Cancels every running and queued task of a connection, e.g. several
+    /// heavy queries started against the wrong server.
+    pub fn cancel_profile_tasks(&mut self, profile_id: Uuid, cx: &mut Context<Self>) {
+        let cancelled = self.app_state.update(cx, |state, cx| {
+            let cancelled = state.cancel_tasks_for_profile(profile_id);
+            cx.emit(AppStateChanged);
+            cancelled
+        });
+
+        self.pending_toast = Some(PendingToast {
+            message: match cancelled {
+                1 => "Cancelled 1 task".to_string(),
+                n => format!("Cancelled {} tasks", n),
+            },
+            is_error: false,
+        });
+        self.refresh_tree(cx);
+        cx.notify();
+    }
+
     pub fn disconnect_profile(&mut self, profile_id: Uuid, cx: &mut Context<Self>) {
         let Some(profile) = self
             .app_state
@@ -1904,6 +1962,9 @@ impl Sidebar {
             return;
         }
 
-        cx.emit(SidebarEvent::RequestEditConnection { profile_id });
+        cx.emit(SidebarEvent::RequestEditConnection {
+            profile_id,
+            fix: None,
+        });
     }
 }
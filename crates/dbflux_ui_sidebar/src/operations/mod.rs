@@ -3,6 +3,7 @@ use crate::*;
 mod connection;
 mod dnd;
 mod pipeline;
+mod rename;
 mod script_ops;
 mod tree_edit;
 mod tree_ops;
@@ -433,6 +433,14 @@ impl Sidebar {
                         }
                     };
 
+                    // Database schemas listed up front for `LazyPerDatabase` drivers.
+                    let prefetched = schema
+                        .as_ref()
+                        .map(|schema| {
+                            dbflux_core::prefetch_database_schemas(connection.as_ref(), schema)
+                        })
+                        .unwrap_or_default();
+
                     let tunnel_handle: Option<Box<dyn std::any::Any + Send + Sync>> =
                         if access_handle.is_tunneled() {
                             Some(Box::new(access_handle))
@@ -440,11 +448,11 @@ impl Sidebar {
                             None
                         };
 
-                    Ok::<_, String>((profile, connection, schema, tunnel_handle))
+                    Ok::<_, String>((profile, connection, schema, prefetched, tunnel_handle))
                 })
                 .await;
 
-            let (profile, connection, schema, tunnel_handle) = match connect_result {
+            let (profile, connection, schema, prefetched, tunnel_handle) = match connect_result {
                 Ok(values) => values,
                 Err(error) => {
                     let _ = state_tx.send(dbflux_core::PipelineState::Failed {
@@ -637,6 +645,13 @@ impl Sidebar {
                         tunnel_handle,
                         false,
                     );
+                    for database_schema in prefetched {
+                        state.set_database_schema(
+                            profile_id,
+                            database_schema.name.clone(),
+                            database_schema,
+                        );
+                    }
                     cx.emit(dbflux_ui_base::AppStateChanged);
                     cx.notify();
                 });
@@ -658,6 +673,7 @@ impl Sidebar {
                         is_error: false,
                     });
                     sidebar.refresh_tree(cx);
+                    sidebar.prefetch_recent_table_details(profile_id, cx);
                 });
             }) {
                 log::warn!(
@@ -0,0 +1,247 @@
+use crate::*;
+use dbflux_components::modals::{RenameObjectKind, RenameObjectRequest, RenameStatementFn};
+use dbflux_core::{Connection, QueryRequest, RenameRequest, TaskKind, TaskTarget};
+use dbflux_ui_base::AsyncUpdateResultExt;
+use dbflux_ui_base::toast::PendingToast;
+use std::sync::Arc;
+
+/// The table or column behind a rename action, resolved from the cache.
+#[derive(Clone)]
+struct RenameTarget {
+    profile_id: Uuid,
+    /// Database part of the `ConnectedProfile::table_details` key.
+    cache_database: String,
+    schema: String,
+    table: String,
+    column: Option<String>,
+    connection: Arc<dyn Connection>,
+}
+
+impl RenameTarget {
+    fn statement(&self, new_name: &str) -> Option<String> {
+        self.connection
+            .code_generator()
+            .generate_rename(&RenameRequest {
+                table_name: &self.table,
+                schema_name: Some(&self.schema),
+                column: self.column.as_deref(),
+                new_name,
+            })
+    }
+
+    fn current_name(&self) -> &str {
+        self.column.as_deref().unwrap_or(&self.table)
+    }
+
+    fn qualified_name(&self) -> String {
+        match &self.column {
+            Some(column) => format!("{}.{}", self.table, column),
+            None => format!("{}.{}", self.schema, self.table),
+        }
+    }
+}
+
+impl Sidebar {
+    fn resolve_rename_target(&self, item_id: &str, cx: &App) -> Option<RenameTarget> {
+        let state = self.app_state.read(cx);
+
+        match parse_node_id(item_id)? {
+            SchemaNodeId::Table {
+                profile_id,
+                database,
+                schema,
+                name,
+            } => {
+                let connected = state.connections().get(&profile_id)?;
+                let cache_database = database.unwrap_or_else(|| schema.clone());
+
+                Some(RenameTarget {
+                    profile_id,
+                    connection: connected.connection_for_database(&cache_database),
+                    cache_database,
+                    schema,
+                    table: name,
+                    column: None,
+                })
+            }
+            SchemaNodeId::Column {
+                profile_id,
+                table,
+                name,
+            } => {
+                let connected = state.connections().get(&profile_id)?;
+
+                // Column nodes only carry the table name, so the first loaded
+                // table with that column wins.
+                let ((cache_database, _), details) =
+                    connected
+                        .table_details
+                        .iter()
+                        .find(|((_, table_name), details)| {
+                            *table_name == table
+                                && details
+                                    .columns
+                                    .as_deref()
+                                    .is_some_and(|columns| columns.iter().any(|c| c.name == name))
+                        })?;
+
+                Some(RenameTarget {
+                    profile_id,
+                    connection: connected.connection_for_database(cache_database),
+                    cache_database: cache_database.clone(),
+                    schema: details
+                        .schema
+                        .clone()
+                        .unwrap_or_else(|| cache_database.clone()),
+                    table,
+                    column: Some(name),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Opens the rename modal for a table or column, listing the cached
+    /// objects that reference it.
+    pub(crate) fn show_rename_modal(&mut self, item_id: &str, cx: &mut Context<Self>) {
+        let Some(target) = self.resolve_rename_target(item_id, cx) else {
+            log::warn!("Cannot rename {}: its details are not loaded", item_id);
+            return;
+        };
+
+        let dependents = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(&target.profile_id)
+            .map(|connected| {
+                connected.rename_dependents(
+                    &target.cache_database,
+                    &target.schema,
+                    &target.table,
+                    target.column.as_deref(),
+                )
+            })
+            .unwrap_or_default();
+
+        let statement: RenameStatementFn = {
+            let target = target.clone();
+            Arc::new(move |new_name| target.statement(new_name))
+        };
+
+        cx.emit(SidebarEvent::RequestRenameObject {
+            item_id: item_id.to_string(),
+            request: RenameObjectRequest {
+                kind: if target.column.is_some() {
+                    RenameObjectKind::Column
+                } else {
+                    RenameObjectKind::Table
+                },
+                current_name: target.current_name().to_string(),
+                qualified_name: target.qualified_name(),
+                dependents,
+                statement,
+            },
+        });
+    }
+
+    /// Runs the rename confirmed in the modal and applies it to the cached
+    /// schema once the server accepted it.
+    pub fn execute_rename(&mut self, item_id: &str, new_name: &str, cx: &mut Context<Self>) {
+        let Some(target) = self.resolve_rename_target(item_id, cx) else {
+            return;
+        };
+        let Some(sql) = target.statement(new_name) else {
+            return;
+        };
+
+        if self.app_state.read(cx).is_background_task_limit_reached() {
+            self.pending_toast = Some(PendingToast {
+                message: "Too many background tasks running, please wait".to_string(),
+                is_error: true,
+            });
+            self.refresh_tree(cx);
+            cx.notify();
+            return;
+        }
+
+        let (task_id, _cancel_token) = self.app_state.update(cx, |state, cx| {
+            let task = state.start_task_for_target(
+                TaskKind::SchemaRename,
+                format!("Renaming {} to {}", target.qualified_name(), new_name),
+                Some(TaskTarget {
+                    profile_id: target.profile_id,
+                    database: Some(target.cache_database.clone()),
+                }),
+            );
+            cx.emit(AppStateChanged);
+            task
+        });
+
+        let new_name = new_name.to_string();
+
+        let operation_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let connection = target.connection.clone();
+                    let sql = sql.clone();
+                    async move { connection.execute(&QueryRequest::new(sql)) }
+                })
+                .await;
+
+            this.update(cx, |sidebar, cx| {
+                sidebar.clear_tracked_operation_task(task_id);
+
+                match result {
+                    Ok(_) => {
+                        sidebar.app_state.update(cx, |state, cx| {
+                            if let Some(connected) =
+                                state.connections_mut().get_mut(&target.profile_id)
+                            {
+                                match target.column.as_deref() {
+                                    Some(column) => connected.rename_cached_column(
+                                        &target.cache_database,
+                                        &target.table,
+                                        column,
+                                        &new_name,
+                                    ),
+                                    None => connected.rename_cached_table(
+                                        &target.cache_database,
+                                        &target.schema,
+                                        &target.table,
+                                        &new_name,
+                                    ),
+                                }
+                            }
+
+                            state.complete_task_with_details(task_id, sql);
+                            cx.emit(AppStateChanged);
+                        });
+
+                        sidebar.pending_toast = Some(PendingToast {
+                            message: format!("Renamed {} to {}", target.current_name(), new_name),
+                            is_error: false,
+                        });
+                    }
+                    Err(error) => {
+                        sidebar.app_state.update(cx, |state, cx| {
+                            state.fail_task_with_details(task_id, error.to_string(), sql);
+                            cx.emit(AppStateChanged);
+                        });
+
+                        sidebar.pending_toast = Some(PendingToast {
+                            message: format!("Failed to rename: {}", error),
+                            is_error: true,
+                        });
+                    }
+                }
+
+                sidebar.refresh_tree(cx);
+            })
+            .log_if_dropped();
+        });
+
+        self.track_operation_task(task_id, operation_task);
+    }
+}
@@ -1347,6 +1347,12 @@ impl Sidebar {
                                     result.table.clone(),
                                     result.details,
                                 );
+                                state.set_table_statistics(
+                                    result.profile_id,
+                                    result.database.clone(),
+                                    result.table.clone(),
+                                    result.statistics,
+                                );
                                 state.set_dependents(
                                     result.profile_id,
                                     result.database,
@@ -266,6 +266,7 @@ impl Sidebar {
             gutter_metadata: self.scripts_gutter_metadata.clone(),
             line_color: tree_nav::tree_line_color(theme),
             hovered_item_id: self.hovered_item_id.clone(),
+            changed_objects: HashMap::new(),
             color_teal: SyntaxColors::table(),
             color_yellow: SyntaxColors::view(),
             color_blue: SyntaxColors::column(),
@@ -385,6 +386,7 @@ impl Render for Sidebar {
             gutter_metadata: self.gutter_metadata.clone(),
             line_color: tree_nav::tree_line_color(theme),
             hovered_item_id: self.hovered_item_id.clone(),
+            changed_objects: self.changed_schema_objects.clone(),
             color_teal: SyntaxColors::table(),
             color_yellow: SyntaxColors::view(),
             color_blue: SyntaxColors::column(),
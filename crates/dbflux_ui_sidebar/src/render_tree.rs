@@ -2,7 +2,10 @@ use super::*;
 use dbflux_components::icons::AppIcon;
 use dbflux_components::primitives::{Icon, StatusDot, StatusDotVariant, Text};
 use dbflux_components::typography::MonoLabel;
+use dbflux_core::SchemaObjectRef;
+use dbflux_ui_base::schema_drag::{DraggedSchemaObject, SchemaObjectDrag};
 use gpui::FontWeight;
+use gpui_component::tooltip::Tooltip;
 
 fn sidebar_tree_label(
     label: SharedString,
@@ -58,6 +61,8 @@ pub(super) struct TreeRenderParams {
     /// Item ID of the currently hovered tree row. Used to show the ⋯ button
     /// only while a row is hovered.
     pub hovered_item_id: Option<SharedString>,
+    /// Tables and views flagged by the background schema refresh, per profile.
+    pub changed_objects: HashMap<Uuid, HashSet<SchemaObjectRef>>,
 }
 
 pub(super) fn render_tree_item(
@@ -167,6 +172,7 @@ pub(super) fn render_tree_item(
                 | SchemaNodeKind::DashboardsFolder
                 | SchemaNodeKind::RemoteDashboardsFolder
                 | SchemaNodeKind::SavedChartsFolder
+                | SchemaNodeKind::FavoritesFolder
                 | SchemaNodeKind::InstanceMetricsFolder
                 | SchemaNodeKind::InstanceInspectorsFolder
         ));
@@ -202,6 +208,41 @@ pub(super) fn render_tree_item(
 
     let label_color = resolve_label_color(node_kind, theme, params);
 
+    let is_changed = match &parsed_id {
+        Some(
+            SchemaNodeId::Table {
+                profile_id,
+                schema,
+                name,
+                ..
+            }
+            | SchemaNodeId::View {
+                profile_id,
+                schema,
+                name,
+                ..
+            },
+        ) => params
+            .changed_objects
+            .get(profile_id)
+            .is_some_and(|objects| {
+                objects.contains(&SchemaObjectRef::new(schema.clone(), name.clone()))
+            }),
+        _ => false,
+    };
+
+    // Tables and columns get a tooltip with their comment and statistics,
+    // looked up only when it opens.
+    let tooltip_node = parsed_id
+        .clone()
+        .filter(|node| {
+            matches!(
+                node,
+                SchemaNodeId::Table { .. } | SchemaNodeId::Column { .. }
+            )
+        })
+        .map(|node| (node, item.label.clone()));
+
     let is_being_renamed = match &parsed_id {
         Some(SchemaNodeId::ConnectionFolder { node_id }) => {
             params.editing_id.as_ref() == Some(node_id)
@@ -272,6 +313,16 @@ pub(super) fn render_tree_item(
         .child(
             div()
                 .id(SharedString::from(format!("row-{}", item_id)))
+                .when_some(tooltip_node, |el, (node, label)| {
+                    let sidebar = sidebar_entity.clone();
+                    el.tooltip(move |window, cx| {
+                        let text = sidebar
+                            .read(cx)
+                            .object_tooltip(&node, cx)
+                            .unwrap_or_else(|| label.to_string());
+                        Tooltip::new(text).build(window, cx)
+                    })
+                })
                 .w_full()
                 .flex()
                 .items_center()
@@ -422,6 +473,9 @@ pub(super) fn render_tree_item(
                             )),
                     )
                 })
+                .when(is_changed && !is_being_renamed, |el| {
+                    el.child(Text::caption("changed").color(theme.warning))
+                })
                 .when(
                     matches!(
                         node_kind,
@@ -488,11 +542,55 @@ pub(super) fn render_tree_item(
                         }
                     },
                 )
+                // Tables, views and columns drag into SQL documents
+                .when_some(
+                    match &parsed_id {
+                        Some(
+                            SchemaNodeId::Table {
+                                profile_id,
+                                database,
+                                schema,
+                                name,
+                            }
+                            | SchemaNodeId::View {
+                                profile_id,
+                                database,
+                                schema,
+                                name,
+                            },
+                        ) => Some(SchemaObjectDrag {
+                            profile_id: *profile_id,
+                            object: DraggedSchemaObject::Table {
+                                database: database.clone(),
+                                schema: schema.clone(),
+                                name: name.clone(),
+                            },
+                            label: name.clone(),
+                        }),
+                        Some(SchemaNodeId::Column {
+                            profile_id, name, ..
+                        }) => Some(SchemaObjectDrag {
+                            profile_id: *profile_id,
+                            object: DraggedSchemaObject::Column { name: name.clone() },
+                            label: name.clone(),
+                        }),
+                        _ => None,
+                    },
+                    |el, payload| {
+                        el.on_drag(payload, |payload, _, _, cx| {
+                            cx.new(|_| DragPreview {
+                                label: payload.label.clone(),
+                            })
+                        })
+                    },
+                )
                 // Drop indicator
                 .when(
                     matches!(
                         node_kind,
-                        SchemaNodeKind::Profile | SchemaNodeKind::ConnectionFolder
+                        SchemaNodeKind::Profile
+                            | SchemaNodeKind::ConnectionFolder
+                            | SchemaNodeKind::FavoriteItem
                     ),
                     |el| {
                         let is_drop_into = current_drop_target
@@ -653,6 +751,62 @@ pub(super) fn render_tree_item(
                         el
                     }
                 })
+                // Favorites reorder within their connection (before/after zones)
+                .when(node_kind == SchemaNodeKind::FavoriteItem, |el| {
+                    let Some(SchemaNodeId::FavoriteItem {
+                        profile_id,
+                        favorite_id,
+                    }) = parsed_id.clone()
+                    else {
+                        return el;
+                    };
+
+                    let item_id_for_move = item_id.to_string();
+                    let sidebar_for_drop = sidebar_entity.clone();
+                    let sidebar_for_move = sidebar_entity.clone();
+                    let item_ix = ix;
+
+                    el.on_drag(
+                        FavoriteDragState {
+                            favorite_id,
+                            profile_id,
+                            label: item.label.to_string(),
+                        },
+                        |state, _, _, cx| {
+                            cx.new(|_| DragPreview {
+                                label: state.label.clone(),
+                            })
+                        },
+                    )
+                    .drag_over::<FavoriteDragState>(move |style, _, _, _| style)
+                    .on_drag_move::<FavoriteDragState>(move |event, _, cx| {
+                        let drag_state = event.drag(cx);
+                        if drag_state.profile_id != profile_id
+                            || drag_state.favorite_id == favorite_id
+                        {
+                            sidebar_for_move.update(cx, |this, cx| this.clear_drop_target(cx));
+                            return;
+                        }
+
+                        let middle = event.bounds.origin.y + event.bounds.size.height / 2.0;
+                        let drop_position = if event.event.position.y < middle {
+                            DropPosition::Before
+                        } else {
+                            DropPosition::After
+                        };
+
+                        sidebar_for_move.update(cx, |this, cx| {
+                            this.set_drop_target(item_id_for_move.clone(), drop_position, cx);
+                            this.check_auto_scroll(item_ix, cx);
+                        });
+                    })
+                    .on_drop(move |state: &FavoriteDragState, _, cx| {
+                        sidebar_for_drop.update(cx, |this, cx| {
+                            this.stop_auto_scroll(cx);
+                            this.handle_favorite_drop(state, cx);
+                        });
+                    })
+                })
                 // Scripts drag source (files and subfolders, not root)
                 .when(
                     matches!(
@@ -852,6 +1006,7 @@ pub(super) fn render_tree_item(
                             | SchemaNodeKind::SavedChartsFolder
                             | SchemaNodeKind::DashboardItem
                             | SchemaNodeKind::SavedChartItem
+                            | SchemaNodeKind::FavoriteItem
                     ),
                     |el| {
                         let sidebar_for_menu = sidebar_entity.clone();
@@ -920,6 +1075,7 @@ pub(super) fn render_tree_item(
                             | SchemaNodeKind::SavedChartsFolder
                             | SchemaNodeKind::DashboardItem
                             | SchemaNodeKind::SavedChartItem
+                            | SchemaNodeKind::FavoriteItem
                     ),
                     |el| {
                         let sidebar_for_ctx = sidebar_entity.clone();
@@ -1027,6 +1183,8 @@ pub(crate) fn icon_for_node_kind(
         SchemaNodeKind::RemoteDashboardItem => Some(AppIcon::ChartColumnBig),
         SchemaNodeKind::SavedChartsFolder => Some(AppIcon::ChartArea),
         SchemaNodeKind::SavedChartItem => Some(AppIcon::ChartArea),
+        SchemaNodeKind::FavoritesFolder => Some(AppIcon::Star),
+        SchemaNodeKind::FavoriteItem => Some(AppIcon::Star),
         SchemaNodeKind::InstanceMetricsFolder => Some(AppIcon::ChartSpline),
         SchemaNodeKind::InstanceMetricLeaf => Some(AppIcon::ChartSpline),
         SchemaNodeKind::InstanceInspectorsFolder => Some(AppIcon::Server),
@@ -1142,6 +1300,8 @@ fn resolve_node_icon(
         }
         SchemaNodeKind::SavedChartsFolder => (Some(AppIcon::ChartArea), "", params.color_orange),
         SchemaNodeKind::SavedChartItem => (Some(AppIcon::ChartArea), "", theme.muted_foreground),
+        SchemaNodeKind::FavoritesFolder => (Some(AppIcon::Star), "", params.color_yellow),
+        SchemaNodeKind::FavoriteItem => (Some(AppIcon::Star), "", theme.muted_foreground),
         SchemaNodeKind::InstanceMetricsFolder => {
             (Some(AppIcon::ChartSpline), "", params.color_orange)
         }
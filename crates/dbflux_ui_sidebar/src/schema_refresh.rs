@@ -0,0 +1,240 @@
+//! Background re-introspection of connected schemas, on an interval and when
+//! the window regains focus. Each pass diffs the fresh snapshot against the
+//! cached one and swaps in only what changed, so the tree keeps its expansion
+//! state; added and altered tables carry a "changed" badge until clicked.
+
+use super::*;
+use dbflux_core::{Connection, DbError, SchemaObjectRef, check_drift_sync, diff_snapshots};
+use dbflux_ui_base::AsyncUpdateResultExt;
+use std::sync::Arc;
+
+/// Key of `ConnectedProfile::table_details`: database and table name.
+type TableKey = (String, String);
+
+struct SchemaRefreshOutcome {
+    schema: SchemaSnapshot,
+    /// Loaded table details whose structure changed, with their fresh version.
+    altered: Vec<(TableKey, TableInfo)>,
+}
+
+impl Sidebar {
+    /// Restarts the refresh timer when the configured interval changed.
+    pub(super) fn sync_schema_refresh_timer(&mut self, cx: &mut Context<Self>) {
+        let policy = self
+            .app_state
+            .read(cx)
+            .general_settings()
+            .resolve_schema_refresh_policy();
+
+        if policy == self.schema_refresh_policy {
+            return;
+        }
+
+        self.schema_refresh_policy = policy;
+        self._schema_refresh_timer = None;
+
+        let Some(duration) = policy.duration() else {
+            return;
+        };
+
+        self._schema_refresh_timer = Some(cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor().timer(duration).await;
+
+                if this
+                    .update(cx, |sidebar, cx| sidebar.refresh_connected_schemas(cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        }));
+    }
+
+    pub(super) fn refresh_schemas_on_focus(&mut self, cx: &mut Context<Self>) {
+        if self
+            .app_state
+            .read(cx)
+            .general_settings()
+            .schema_refresh_on_focus
+        {
+            self.refresh_connected_schemas(cx);
+        }
+    }
+
+    /// Re-introspects every connection with a cached relational schema,
+    /// skipping those already refreshing or busy with another operation.
+    fn refresh_connected_schemas(&mut self, cx: &mut Context<Self>) {
+        let profile_ids: Vec<Uuid> = {
+            let state = self.app_state.read(cx);
+            state
+                .connections()
+                .iter()
+                .filter(|(profile_id, connected)| {
+                    connected
+                        .schema
+                        .as_ref()
+                        .is_some_and(SchemaSnapshot::is_relational)
+                        && !state.is_operation_pending(**profile_id, None)
+                })
+                .map(|(profile_id, _)| *profile_id)
+                .collect()
+        };
+
+        for profile_id in profile_ids {
+            if self.schema_refreshes_in_flight.insert(profile_id) {
+                self.refresh_profile_schema(profile_id, cx);
+            }
+        }
+    }
+
+    fn refresh_profile_schema(&mut self, profile_id: Uuid, cx: &mut Context<Self>) {
+        let Some((connection, loaded)) = self
+            .app_state
+            .read(cx)
+            .connections()
+            .get(&profile_id)
+            .map(|connected| {
+                let loaded: Vec<(TableKey, TableInfo)> = connected
+                    .table_details
+                    .iter()
+                    .filter(|(_, table)| table.columns.is_some())
+                    .map(|(key, table)| (key.clone(), table.clone()))
+                    .collect();
+                (connected.connection.clone(), loaded)
+            })
+        else {
+            self.schema_refreshes_in_flight.remove(&profile_id);
+            return;
+        };
+
+        cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { Self::introspect_schema_changes(&connection, loaded) })
+                .await;
+
+            this.update(cx, |sidebar, cx| {
+                sidebar.schema_refreshes_in_flight.remove(&profile_id);
+
+                match result {
+                    Ok(outcome) => sidebar.apply_schema_refresh(profile_id, outcome, cx),
+                    Err(error) => {
+                        log::warn!("Schema auto-refresh of {} failed: {}", profile_id, error)
+                    }
+                }
+            })
+            .log_if_dropped();
+        })
+        .detach();
+    }
+
+    /// Fetches a fresh snapshot and re-fetches the `loaded` table details,
+    /// keeping those whose structure drifted. Runs on the background executor.
+    fn introspect_schema_changes(
+        connection: &Arc<dyn Connection>,
+        loaded: Vec<(TableKey, TableInfo)>,
+    ) -> Result<SchemaRefreshOutcome, DbError> {
+        let schema = connection.schema()?;
+
+        let altered = loaded
+            .into_iter()
+            .filter_map(|((database, name), cached)| {
+                let fresh = connection
+                    .table_details(&database, cached.schema.as_deref(), &name)
+                    .ok()?;
+
+                // An empty result means the lookup missed (e.g. the table was
+                // dropped); the snapshot diff accounts for removed tables.
+                if fresh
+                    .columns
+                    .as_deref()
+                    .is_none_or(|columns| columns.is_empty())
+                {
+                    return None;
+                }
+
+                check_drift_sync(&cached, &fresh).map(|_| ((database, name), fresh))
+            })
+            .collect();
+
+        Ok(SchemaRefreshOutcome { schema, altered })
+    }
+
+    fn apply_schema_refresh(
+        &mut self,
+        profile_id: Uuid,
+        outcome: SchemaRefreshOutcome,
+        cx: &mut Context<Self>,
+    ) {
+        let changes = self.app_state.update(cx, |state, cx| {
+            let connected = state.connections_mut().get_mut(&profile_id)?;
+            let diff = diff_snapshots(connected.schema.as_ref()?, &outcome.schema);
+
+            if diff.is_empty() && outcome.altered.is_empty() {
+                return None;
+            }
+
+            connected.table_details.retain(|(database, name), table| {
+                let schema = table.schema.as_deref().unwrap_or(database);
+                !diff
+                    .objects_removed
+                    .iter()
+                    .any(|object| object.name == *name && object.schema == schema)
+            });
+            let table_details = &connected.table_details;
+            connected
+                .table_statistics
+                .retain(|key, _| table_details.contains_key(key));
+
+            let mut changed = diff.objects_added;
+            changed.extend(diff.objects_altered);
+            for ((database, name), fresh) in outcome.altered {
+                let schema = fresh.schema.clone().unwrap_or_else(|| database.clone());
+                changed.push(SchemaObjectRef::new(schema, name.clone()));
+                connected.table_details.insert((database, name), fresh);
+            }
+
+            connected.schema = Some(outcome.schema);
+            cx.emit(AppStateChanged);
+
+            Some((changed, diff.objects_removed))
+        });
+
+        let Some((changed, removed)) = changes else {
+            return;
+        };
+
+        let objects = self.changed_schema_objects.entry(profile_id).or_default();
+        for object in &removed {
+            objects.remove(object);
+        }
+        objects.extend(changed);
+        cx.notify();
+    }
+
+    /// Clears the "changed" badge of the table or view behind `item_id`.
+    pub(super) fn acknowledge_schema_change(&mut self, item_id: &str) {
+        let Some(
+            SchemaNodeId::Table {
+                profile_id,
+                schema,
+                name,
+                ..
+            }
+            | SchemaNodeId::View {
+                profile_id,
+                schema,
+                name,
+                ..
+            },
+        ) = parse_node_id(item_id)
+        else {
+            return;
+        };
+
+        if let Some(objects) = self.changed_schema_objects.get_mut(&profile_id) {
+            objects.remove(&SchemaObjectRef::new(schema, name));
+        }
+    }
+}
@@ -211,6 +211,7 @@ impl Sidebar {
             Some(SchemaNodeId::Profile { .. }) | Some(SchemaNodeId::ConnectionFolder { .. }) => {
                 true
             }
+            Some(SchemaNodeId::Table { .. }) | Some(SchemaNodeId::View { .. }) => true,
             Some(SchemaNodeId::ScriptFile { .. }) => true,
             Some(SchemaNodeId::ScriptsFolder { path: Some(_) }) => true,
             Some(SchemaNodeId::ScriptsFolder { path: None }) => false,
@@ -303,15 +304,15 @@ impl Sidebar {
     }
 
     fn active_visible_entry_count(&self, cx: &Context<Self>) -> usize {
-        self.active_visible_item_ids(cx).len()
+        match self.active_tab {
+            SidebarTab::Connections => self.visible_rows.len(),
+            SidebarTab::Scripts => self.active_visible_item_ids(cx).len(),
+        }
     }
 
     fn active_visible_item_ids(&self, cx: &Context<Self>) -> Vec<String> {
         match self.active_tab {
-            SidebarTab::Connections => {
-                let items = self.build_tree_items_with_overrides(cx);
-                Self::collect_visible_item_ids(&items)
-            }
+            SidebarTab::Connections => self.visible_rows.ids.clone(),
             SidebarTab::Scripts => {
                 let state = self.app_state.read(cx);
                 let entries = match state.scripts_directory() {
@@ -364,6 +364,12 @@ impl Sidebar {
                         res.table.clone(),
                         res.details,
                     );
+                    state.set_table_statistics(
+                        res.profile_id,
+                        res.database.clone(),
+                        res.table.clone(),
+                        res.statistics,
+                    );
                     state.set_dependents(res.profile_id, res.database, res.table, res.dependents);
                     cx.emit(AppStateChanged);
                 });
@@ -0,0 +1,262 @@
+//! Warms `table_details` for the tables a profile's recent queries used, so
+//! expanding one or generating SQL for it right after connecting does not wait
+//! on a lazy fetch. Runs once per connect, one table at a time, after the tree
+//! has painted.
+
+use super::*;
+use dbflux_core::{Connection, DbSchemaInfo, HistoryFilter, QueryTableRef, recently_used_tables};
+use dbflux_ui_base::AsyncUpdateResultExt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// History entries scanned for table references.
+const PREFETCH_HISTORY_ENTRIES: usize = 100;
+
+/// Tables warmed per connect.
+const PREFETCH_TABLE_LIMIT: usize = 10;
+
+/// Head start given to the first tree paint before the prefetch begins.
+const PREFETCH_DELAY: Duration = Duration::from_millis(500);
+
+/// A table to warm, keyed the way `ConnectedProfile::table_details` is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PrefetchTarget {
+    cache_database: String,
+    schema: String,
+    table: String,
+}
+
+impl Sidebar {
+    pub(super) fn prefetch_recent_table_details(
+        &mut self,
+        profile_id: Uuid,
+        cx: &mut Context<Self>,
+    ) {
+        let targets: Vec<(PrefetchTarget, Arc<dyn Connection>)> = {
+            let state = self.app_state.read(cx);
+            let Some(connected) = state.connections().get(&profile_id) else {
+                return;
+            };
+            let Some(schema) = connected.schema.as_ref().filter(|s| s.is_relational()) else {
+                return;
+            };
+
+            let filter = HistoryFilter {
+                connection_profile_id: Some(profile_id),
+                succeeded: Some(true),
+                ..Default::default()
+            };
+            let entries = state.history_page(&filter, 0, PREFETCH_HISTORY_ENTRIES);
+
+            let mut databases: Vec<&DbSchemaInfo> = connected.database_schemas.values().collect();
+            databases.sort_by(|a, b| a.name.cmp(&b.name));
+
+            let listings: Vec<&DbSchemaInfo> = schema.schemas().iter().chain(databases).collect();
+
+            recently_used_tables(&entries, usize::MAX)
+                .iter()
+                .filter_map(|table| resolve_prefetch_target(table, &listings))
+                .filter(|target| {
+                    connected
+                        .table_details
+                        .get(&(target.cache_database.clone(), target.table.clone()))
+                        .is_none_or(|details| details.columns.is_none())
+                })
+                .take(PREFETCH_TABLE_LIMIT)
+                .map(|target| {
+                    let connection = connected.connection_for_database(&target.cache_database);
+                    (target, connection)
+                })
+                .collect()
+        };
+
+        if targets.is_empty() {
+            return;
+        }
+
+        cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(PREFETCH_DELAY).await;
+
+            let mut warmed = 0;
+
+            for (target, connection) in targets {
+                let fetch_target = target.clone();
+                let result = cx
+                    .background_executor()
+                    .spawn(async move {
+                        connection.table_details(
+                            &fetch_target.cache_database,
+                            Some(&fetch_target.schema),
+                            &fetch_target.table,
+                        )
+                    })
+                    .await;
+
+                let details = match result {
+                    Ok(details) => details,
+                    Err(e) => {
+                        log::debug!("Prefetch of {} skipped: {}", target.table, e);
+                        continue;
+                    }
+                };
+
+                let stored = this.update(cx, |sidebar, cx| {
+                    sidebar.app_state.update(cx, |state, _| {
+                        // The user may have expanded the table meanwhile.
+                        if !state.needs_table_details(
+                            profile_id,
+                            &target.cache_database,
+                            &target.table,
+                        ) {
+                            return false;
+                        }
+
+                        state.set_table_details(
+                            profile_id,
+                            target.cache_database,
+                            target.table,
+                            details,
+                        );
+                        true
+                    })
+                });
+
+                match stored {
+                    Ok(true) => warmed += 1,
+                    Ok(false) => {}
+                    Err(_) => return,
+                }
+            }
+
+            if warmed > 0 {
+                log::debug!("Prefetched details of {} tables", warmed);
+                this.update(cx, |sidebar, cx| {
+                    sidebar
+                        .app_state
+                        .update(cx, |_, cx| cx.emit(AppStateChanged));
+                })
+                .log_if_dropped();
+            }
+        })
+        .detach();
+    }
+}
+
+/// Finds the listed table `table` refers to: in its schema when qualified,
+/// then in the database it ran against, then anywhere on the connection.
+fn resolve_prefetch_target(
+    table: &QueryTableRef,
+    listings: &[&DbSchemaInfo],
+) -> Option<PrefetchTarget> {
+    let lookup = |scope: Option<&str>| {
+        listings.iter().find_map(|listing| {
+            if scope.is_some_and(|scope| !listing.name.eq_ignore_ascii_case(scope)) {
+                return None;
+            }
+
+            listing
+                .tables
+                .iter()
+                .find(|candidate| candidate.name.eq_ignore_ascii_case(&table.table))
+                .map(|candidate| PrefetchTarget {
+                    cache_database: listing.name.clone(),
+                    schema: listing.name.clone(),
+                    table: candidate.name.clone(),
+                })
+        })
+    };
+
+    match (table.schema.as_deref(), table.database.as_deref()) {
+        (Some(schema), _) if listings.iter().any(|l| l.name.eq_ignore_ascii_case(schema)) => {
+            lookup(Some(schema))
+        }
+        (_, Some(database))
+            if listings
+                .iter()
+                .any(|l| l.name.eq_ignore_ascii_case(database)) =>
+        {
+            lookup(Some(database)).or_else(|| lookup(None))
+        }
+        _ => lookup(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(name: &str, tables: &[&str]) -> DbSchemaInfo {
+        DbSchemaInfo {
+            name: name.to_string(),
+            tables: tables
+                .iter()
+                .map(|table| TableInfo {
+                    name: table.to_string(),
+                    schema: Some(name.to_string()),
+                    columns: None,
+                    indexes: None,
+                    foreign_keys: None,
+                    constraints: None,
+                    sample_fields: None,
+                    presentation: CollectionPresentation::DataGrid,
+                    child_items: None,
+                    comment: None,
+                })
+                .collect(),
+            views: Vec::new(),
+            custom_types: None,
+        }
+    }
+
+    fn reference(database: Option<&str>, schema: Option<&str>, table: &str) -> QueryTableRef {
+        QueryTableRef {
+            database: database.map(String::from),
+            schema: schema.map(String::from),
+            table: table.to_string(),
+        }
+    }
+
+    fn target(schema: &str, table: &str) -> Option<PrefetchTarget> {
+        Some(PrefetchTarget {
+            cache_database: schema.to_string(),
+            schema: schema.to_string(),
+            table: table.to_string(),
+        })
+    }
+
+    #[test]
+    fn qualified_references_resolve_in_their_schema() {
+        let public = listing("public", &["users"]);
+        let audit = listing("audit", &["users"]);
+        let listings = [&public, &audit];
+
+        assert_eq!(
+            resolve_prefetch_target(&reference(Some("app"), Some("audit"), "users"), &listings),
+            target("audit", "users")
+        );
+        assert_eq!(
+            resolve_prefetch_target(&reference(Some("app"), Some("audit"), "log"), &listings),
+            None
+        );
+    }
+
+    #[test]
+    fn unqualified_references_prefer_the_database_they_ran_against() {
+        let shop = listing("shop", &["orders"]);
+        let staging = listing("staging", &["orders", "users"]);
+        let listings = [&shop, &staging];
+
+        assert_eq!(
+            resolve_prefetch_target(&reference(Some("staging"), None, "orders"), &listings),
+            target("staging", "orders")
+        );
+        assert_eq!(
+            resolve_prefetch_target(&reference(Some("shop"), None, "USERS"), &listings),
+            target("staging", "users")
+        );
+        assert_eq!(
+            resolve_prefetch_target(&reference(None, None, "missing"), &listings),
+            None
+        );
+    }
+}
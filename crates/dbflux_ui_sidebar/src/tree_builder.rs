@@ -8,13 +8,19 @@ impl Sidebar {
             &self.instance_metrics_cache,
             &self.instance_inspectors_cache,
         );
-        let items = self.apply_expansion_overrides(items);
+        let items = match &self.profile_scope {
+            Some(scope) => Self::apply_profile_scope(items, scope),
+            None => items,
+        };
+        // The filter has to see collapsed subtrees to find matches inside them.
+        let query = self.connections_search_query.trim();
+        let items = self.apply_expansion_overrides(items, query.is_empty());
 
-        if self.connections_search_query.trim().is_empty() {
+        if query.is_empty() {
             return items;
         }
 
-        Self::apply_tree_filter(items, self.connections_search_query.trim())
+        Self::apply_tree_filter(items, query)
     }
 
     pub(super) fn extract_active_databases(state: &AppState) -> HashMap<Uuid, String> {
@@ -39,6 +45,36 @@ impl Sidebar {
             .collect()
     }
 
+    /// Keeps the connections in `scope` and the folders leading to them.
+    pub(crate) fn apply_profile_scope(
+        items: Vec<TreeItem>,
+        scope: &HashSet<Uuid>,
+    ) -> Vec<TreeItem> {
+        items
+            .into_iter()
+            .filter_map(|item| match parse_node_id(item.id.as_ref()) {
+                Some(SchemaNodeId::Profile { profile_id }) => {
+                    scope.contains(&profile_id).then_some(item)
+                }
+                Some(SchemaNodeId::ConnectionFolder { .. }) => {
+                    let expanded = item.is_expanded();
+                    let children = Self::apply_profile_scope(item.children, scope);
+
+                    if children.is_empty() {
+                        return None;
+                    }
+
+                    Some(
+                        TreeItem::new(item.id, item.label)
+                            .expanded(expanded)
+                            .children(children),
+                    )
+                }
+                _ => Some(item),
+            })
+            .collect()
+    }
+
     fn filter_tree_item(item: TreeItem, query: &str) -> Option<TreeItem> {
         let item_id = item.id.to_string();
         let item_label = item.label.clone();
@@ -70,46 +106,28 @@ impl Sidebar {
         )
     }
 
-    fn apply_expansion_overrides(&self, items: Vec<TreeItem>) -> Vec<TreeItem> {
+    fn apply_expansion_overrides(
+        &self,
+        items: Vec<TreeItem>,
+        prune_collapsed: bool,
+    ) -> Vec<TreeItem> {
         items
             .into_iter()
-            .map(|item| self.apply_override_recursive(item))
+            .map(|item| {
+                apply_expansion_override(
+                    item,
+                    &self.expansion_overrides,
+                    &self.loading_items,
+                    prune_collapsed,
+                )
+            })
             .collect()
     }
 
     /// Public re-export for callers that build their own tree (e.g. scripts)
     /// and need user collapse/expand overrides applied on top.
     pub(super) fn apply_expansion_overrides_public(&self, items: Vec<TreeItem>) -> Vec<TreeItem> {
-        self.apply_expansion_overrides(items)
-    }
-
-    fn apply_override_recursive(&self, item: TreeItem) -> TreeItem {
-        let item_id = item.id.to_string();
-        let default_expanded = item.is_expanded();
-
-        let mut children: Vec<TreeItem> = item
-            .children
-            .into_iter()
-            .map(|c| self.apply_override_recursive(c))
-            .collect();
-
-        if self.loading_items.contains(&item_id) && children.is_empty() {
-            children.push(TreeItem::new(
-                format!("{}_loading", item_id),
-                "Loading...".to_string(),
-            ));
-        }
-
-        // Apply override if exists, otherwise keep default
-        let expanded = self
-            .expansion_overrides
-            .get(&item_id)
-            .copied()
-            .unwrap_or(default_expanded);
-
-        TreeItem::new(item_id, item.label.clone())
-            .children(children)
-            .expanded(expanded)
+        self.apply_expansion_overrides(items, false)
     }
 
     pub(super) fn build_tree_items(state: &AppStateEntity) -> Vec<TreeItem> {
@@ -261,6 +279,10 @@ impl Sidebar {
             let conn_metadata = connected.connection.metadata();
             let conn_capabilities = conn_metadata.capabilities;
 
+            if let Some(favorites) = Self::build_favorites_folder_item(profile_id, state) {
+                profile_children.push(favorites);
+            }
+
             // Surface the per-profile Dashboards / Saved Charts folders only
             // for drivers that opt in via `CHART_AUTHORING`. Drivers without
             // a natural chart-authoring UX (e.g. plain relational stores) keep
@@ -284,6 +306,9 @@ impl Sidebar {
             let conn_category = conn_metadata.category;
             let supports_routines = conn_capabilities.contains(DriverCapabilities::ROUTINES);
             let metric_cache = state.metric_catalog_cache().clone();
+            let hide_system_objects = state
+                .effective_settings_for_connection(Some(profile_id))
+                .hide_system_objects;
 
             if schema.is_key_value() {
                 let kv_items = build_kv_database_children(profile_id, connected, state);
@@ -302,13 +327,20 @@ impl Sidebar {
                     is_document_db,
                     is_time_series_db,
                     uses_lazy_loading,
+                    hide_system_objects,
                 );
 
                 // See `should_collapse_database_wrapper`: when the connection
                 // exposes a single trivial database the wrapper adds no information.
                 // In that case extend profile_children with the DB's direct children,
                 // not the wrapper node itself.
-                let collapse_single_db = should_collapse_database_wrapper(schema.databases());
+                let visible_databases: Vec<dbflux_core::DatabaseInfo> = schema
+                    .databases()
+                    .iter()
+                    .filter(|db| !(hide_system_objects && dbflux_core::is_system_schema(&db.name)))
+                    .cloned()
+                    .collect();
+                let collapse_single_db = should_collapse_database_wrapper(&visible_databases);
 
                 if collapse_single_db {
                     for db_item in named_items {
@@ -326,7 +358,7 @@ impl Sidebar {
                     .or_else(|| schema.schemas().first().map(|s| s.name.as_str()))
                     .unwrap_or("default");
 
-                profile_children = Self::build_schema_children(
+                profile_children.extend(Self::build_schema_children(
                     profile_id,
                     database_name,
                     None,
@@ -338,7 +370,8 @@ impl Sidebar {
                     &connected.schema_routines,
                     supports_routines,
                     &connected.dependents_cache,
-                );
+                    hide_system_objects,
+                ));
             }
 
             // Instance overview, metrics, and inspectors — appended after databases.
@@ -459,6 +492,54 @@ impl Sidebar {
             .collect()
     }
 
+    /// Build the `FavoritesFolder` tree node for a connected profile, in the
+    /// user's order. `None` while the profile has no favorites, so the folder
+    /// only appears once something is starred.
+    fn build_favorites_folder_item(profile_id: Uuid, state: &AppStateEntity) -> Option<TreeItem> {
+        let children: Vec<TreeItem> = state
+            .connection_tree()
+            .favorites_of(profile_id)
+            .into_iter()
+            .filter_map(|favorite| {
+                let label = match &favorite.target {
+                    FavoriteTarget::Table { schema, name, .. }
+                    | FavoriteTarget::View { schema, name, .. } => {
+                        if schema.is_empty() {
+                            name.clone()
+                        } else {
+                            format!("{schema}.{name}")
+                        }
+                    }
+                    FavoriteTarget::SavedQuery { query_id } => {
+                        state.get_saved_query(*query_id)?.name.clone()
+                    }
+                };
+
+                Some(TreeItem::new(
+                    SchemaNodeId::FavoriteItem {
+                        profile_id,
+                        favorite_id: favorite.id,
+                    }
+                    .to_string(),
+                    label,
+                ))
+            })
+            .collect();
+
+        if children.is_empty() {
+            return None;
+        }
+
+        Some(
+            TreeItem::new(
+                SchemaNodeId::FavoritesFolder { profile_id }.to_string(),
+                "Favorites".to_string(),
+            )
+            .expanded(true)
+            .children(children),
+        )
+    }
+
     /// Build the `SavedChartsFolder` tree node for a connected profile.
     ///
     /// Children are one `SavedChartItem` per chart returned by the manager,
@@ -726,26 +807,9 @@ impl Sidebar {
         "Untitled chart".to_string()
     }
 
-    pub(super) fn count_visible_entries(items: &[TreeItem]) -> usize {
-        fn count_recursive(item: &TreeItem) -> usize {
-            let mut count = 1;
-            if item.is_expanded() && item.is_folder() {
-                for child in &item.children {
-                    count += count_recursive(child);
-                }
-            }
-            count
-        }
-
-        items.iter().map(count_recursive).sum()
-    }
-
     pub(super) fn find_item_index(&self, item_id: &str, cx: &Context<Self>) -> Option<usize> {
         match self.active_tab {
-            SidebarTab::Connections => {
-                let items = self.build_tree_items_with_overrides(cx);
-                Self::find_item_index_in_tree(&items, item_id, &mut 0)
-            }
+            SidebarTab::Connections => self.visible_rows.position(item_id),
             SidebarTab::Scripts => {
                 let state = self.app_state.read(cx);
                 let entries = match state.scripts_directory() {
@@ -795,10 +859,15 @@ impl Sidebar {
         schema_routines: &HashMap<SchemaCacheKey, Vec<RoutineInfo>>,
         supports_routines: bool,
         dependents_cache: &HashMap<(String, String), Vec<RelationRef>>,
+        hide_system_objects: bool,
     ) -> Vec<TreeItem> {
         let mut children = Vec::new();
 
         for db_schema in snapshot.schemas() {
+            if hide_system_objects && dbflux_core::is_system_schema(&db_schema.name) {
+                continue;
+            }
+
             let schema_content = Self::build_db_schema_content(
                 profile_id,
                 database_name,
@@ -811,6 +880,7 @@ impl Sidebar {
                 schema_routines,
                 supports_routines,
                 dependents_cache,
+                hide_system_objects,
             );
 
             children.push(
@@ -1194,6 +1264,7 @@ impl Sidebar {
         schema_routines: &HashMap<SchemaCacheKey, Vec<RoutineInfo>>,
         supports_routines: bool,
         dependents_cache: &HashMap<(String, String), Vec<RelationRef>>,
+        hide_system_objects: bool,
     ) -> Vec<TreeItem> {
         let mut content = Vec::new();
         let schema_name = &db_schema.name;
@@ -1205,6 +1276,7 @@ impl Sidebar {
             &db_schema.tables,
             table_details,
             dependents_cache,
+            hide_system_objects,
         ) {
             content.push(folder);
         }
@@ -1411,6 +1483,55 @@ impl Sidebar {
     }
 }
 
+/// Id of the placeholder that stands in for the children of a collapsed node.
+fn collapsed_placeholder_id(item_id: &str) -> String {
+    format!("collapsed:{item_id}")
+}
+
+/// `item` with the user's expansion overrides applied, and a "Loading..."
+/// child on nodes still fetching their children.
+///
+/// With `prune_collapsed`, a collapsed node keeps a single placeholder child
+/// instead of its subtree, so only expanded nodes are materialized and the
+/// chevron still shows. Expanding the node rebuilds the tree, which then
+/// materializes its children.
+fn apply_expansion_override(
+    item: TreeItem,
+    overrides: &HashMap<String, bool>,
+    loading_items: &HashSet<String>,
+    prune_collapsed: bool,
+) -> TreeItem {
+    let item_id = item.id.to_string();
+    let expanded = overrides
+        .get(&item_id)
+        .copied()
+        .unwrap_or(item.is_expanded());
+
+    if prune_collapsed && !expanded && !item.children.is_empty() {
+        let placeholder = TreeItem::new(collapsed_placeholder_id(&item_id), String::new());
+        return TreeItem::new(item_id, item.label.clone())
+            .children(vec![placeholder])
+            .expanded(false);
+    }
+
+    let mut children: Vec<TreeItem> = item
+        .children
+        .into_iter()
+        .map(|child| apply_expansion_override(child, overrides, loading_items, prune_collapsed))
+        .collect();
+
+    if loading_items.contains(&item_id) && children.is_empty() {
+        children.push(TreeItem::new(
+            format!("{}_loading", item_id),
+            "Loading...".to_string(),
+        ));
+    }
+
+    TreeItem::new(item_id, item.label.clone())
+        .children(children)
+        .expanded(expanded)
+}
+
 fn build_kv_database_children(
     profile_id: Uuid,
     connected: &dbflux_core::ConnectedProfile,
@@ -1503,10 +1624,15 @@ fn build_named_db_children(
     is_document_db: bool,
     is_time_series_db: bool,
     uses_lazy_loading: bool,
+    hide_system_objects: bool,
 ) -> Vec<TreeItem> {
     let mut named_db_items: Vec<TreeItem> = Vec::new();
 
     for db in schema.databases() {
+        if hide_system_objects && dbflux_core::is_system_schema(&db.name) {
+            continue;
+        }
+
         let is_pending = state.is_operation_pending(profile_id, Some(&db.name));
         let is_active_db = connected.active_database.as_deref() == Some(&db.name);
 
@@ -1525,6 +1651,7 @@ fn build_named_db_children(
             is_pending,
             &db.name,
             db.is_current,
+            hide_system_objects,
         );
 
         named_db_items.push(build_named_db_item(
@@ -1564,6 +1691,7 @@ fn resolve_db_children(
     is_pending: bool,
     db_name: &str,
     is_current: bool,
+    hide_system_objects: bool,
 ) -> Vec<TreeItem> {
     if uses_lazy_loading {
         if let Some(db_schema) = connected.database_schemas.get(db_name) {
@@ -1598,6 +1726,7 @@ fn resolve_db_children(
                     &connected.schema_routines,
                     supports_routines,
                     &connected.dependents_cache,
+                    hide_system_objects,
                 )
             }
         } else if is_pending {
@@ -1626,6 +1755,7 @@ fn resolve_db_children(
                 &connected.schema_routines,
                 supports_routines,
                 &connected.dependents_cache,
+                hide_system_objects,
             )
         } else {
             Vec::new()
@@ -1649,6 +1779,7 @@ fn resolve_db_children(
                     sample_fields: collection.sample_fields.clone(),
                     presentation: collection.presentation,
                     child_items: collection.child_items.clone(),
+                    comment: None,
                 })
                 .collect::<Vec<_>>();
 
@@ -1687,6 +1818,7 @@ fn resolve_db_children(
                 &connected.schema_routines,
                 supports_routines,
                 &connected.dependents_cache,
+                hide_system_objects,
             )
         }
     } else if is_pending {
@@ -2185,7 +2317,13 @@ fn build_schema_tables_folder(
     tables: &[TableInfo],
     table_details: &HashMap<(String, String), TableInfo>,
     dependents_cache: &HashMap<(String, String), Vec<RelationRef>>,
+    hide_system_objects: bool,
 ) -> Option<TreeItem> {
+    let tables: Vec<&TableInfo> = tables
+        .iter()
+        .filter(|table| !(hide_system_objects && dbflux_core::is_internal_object(&table.name)))
+        .collect();
+
     if tables.is_empty() {
         return None;
     }
@@ -2846,6 +2984,7 @@ mod tests {
                 sample_fields: None,
                 presentation: CollectionPresentation::DataGrid,
                 child_items: None,
+                comment: None,
             },
             &Default::default(),
             &Default::default(),
@@ -2885,6 +3024,7 @@ mod tests {
                     last_event_ts_ms: Some(1_776_777_600_000),
                     presentation: CollectionPresentation::EventStream,
                 }]),
+                comment: None,
             },
             &Default::default(),
             &Default::default(),
@@ -2926,6 +3066,7 @@ mod tests {
                 sample_fields: None,
                 presentation: CollectionPresentation::EventStream,
                 child_items: None,
+                comment: None,
             },
             &Default::default(),
             &child_cache,
@@ -3024,6 +3165,7 @@ mod tests {
                     sample_fields: None,
                     presentation: CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 },
                 TableInfo {
                     name: "employees".to_string(),
@@ -3035,6 +3177,7 @@ mod tests {
                     sample_fields: None,
                     presentation: CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 },
                 TableInfo {
                     name: "fallback".to_string(),
@@ -3046,6 +3189,7 @@ mod tests {
                     sample_fields: None,
                     presentation: CollectionPresentation::DataGrid,
                     child_items: None,
+                    comment: None,
                 },
             ],
             views: vec![ViewInfo {
@@ -3082,6 +3226,7 @@ mod tests {
             &Default::default(),
             false,
             &Default::default(),
+            false,
         );
 
         let tables_folder = content
@@ -3501,6 +3646,7 @@ mod tests {
             schema_foreign_keys: HashMap::new(),
             schema_routines: HashMap::new(),
             dependents_cache: HashMap::new(),
+            table_statistics: HashMap::new(),
             active_database: None,
             redis_key_cache: dbflux_core::RedisKeyCache::default(),
             database_connections: HashMap::new(),
@@ -3958,4 +4104,37 @@ mod tests {
             "leaf must carry InstanceOverviewLeaf node ID: {node_id:?}"
         );
     }
+
+    #[test]
+    fn collapsed_nodes_hold_a_placeholder_until_expanded() {
+        use super::apply_expansion_override;
+        use gpui_component::tree::TreeItem;
+        use std::collections::HashSet;
+
+        let tables = || {
+            TreeItem::new("tables", "Tables (2)")
+                .expanded(true)
+                .children(vec![
+                    TreeItem::new("users", "users"),
+                    TreeItem::new("orders", "orders"),
+                ])
+        };
+        let mut overrides = HashMap::new();
+        overrides.insert("tables".to_string(), false);
+        let loading = HashSet::new();
+
+        let pruned = apply_expansion_override(tables(), &overrides, &loading, true);
+        assert!(!pruned.is_expanded());
+        assert_eq!(pruned.children.len(), 1);
+        assert_eq!(pruned.children[0].id.as_ref(), "collapsed:tables");
+
+        // Without pruning (e.g. while filtering) the subtree stays searchable.
+        let full = apply_expansion_override(tables(), &overrides, &loading, false);
+        assert_eq!(full.children.len(), 2);
+
+        overrides.insert("tables".to_string(), true);
+        let expanded = apply_expansion_override(tables(), &overrides, &loading, true);
+        assert!(expanded.is_expanded());
+        assert_eq!(expanded.children.len(), 2);
+    }
 }
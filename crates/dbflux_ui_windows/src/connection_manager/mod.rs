@@ -156,6 +156,7 @@ enum FormFocus {
     SettingsConfirmDangerous,
     SettingsRequiresWhere,
     SettingsRequiresPreview,
+    SettingsHideSystemObjects,
     SettingsDriverField(u8),
     // Actions (shared between tabs)
     TestConnection,
@@ -305,6 +306,7 @@ struct SettingsTabState {
     conn_confirm_dangerous_dropdown: Entity<Dropdown>,
     conn_requires_where_dropdown: Entity<Dropdown>,
     conn_requires_preview_dropdown: Entity<Dropdown>,
+    conn_hide_system_objects_dropdown: Entity<Dropdown>,
     conn_pre_hook_dropdown: Entity<Dropdown>,
     conn_post_hook_dropdown: Entity<Dropdown>,
     conn_pre_disconnect_hook_dropdown: Entity<Dropdown>,
@@ -392,23 +394,7 @@ impl ConnectionManagerWindow {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Self {
-        let available_drivers: Vec<DriverInfo> = app_state
-            .read(cx)
-            .drivers()
-            .iter()
-            .map(|(driver_id, driver)| {
-                let metadata = driver.metadata();
-                DriverInfo {
-                    id: driver_id.clone(),
-                    icon: metadata.icon,
-                    name: driver.display_name().to_string(),
-                    description: driver.description().to_string(),
-                    category: metadata.category,
-                    default_port: metadata.default_port,
-                    uri_scheme: metadata.uri_scheme.clone(),
-                }
-            })
-            .collect();
+        let available_drivers = Self::driver_infos(app_state.read(cx));
 
         let input_name = cx.new(|cx| InputState::new(window, cx).placeholder("Connection name"));
         let driver_filter_input =
@@ -496,6 +482,8 @@ impl ConnectionManagerWindow {
             cx.new(|_cx| Dropdown::new("conn-requires-where").placeholder("Use Driver Default"));
         let conn_requires_preview_dropdown =
             cx.new(|_cx| Dropdown::new("conn-requires-preview").placeholder("Use Driver Default"));
+        let conn_hide_system_objects_dropdown = cx
+            .new(|_cx| Dropdown::new("conn-hide-system-objects").placeholder("Use Driver Default"));
         let conn_pre_hook_dropdown =
             cx.new(|_cx| Dropdown::new("conn-pre-hook").placeholder("No hook"));
         let conn_post_hook_dropdown =
@@ -764,6 +752,7 @@ impl ConnectionManagerWindow {
                 conn_confirm_dangerous_dropdown,
                 conn_requires_where_dropdown,
                 conn_requires_preview_dropdown,
+                conn_hide_system_objects_dropdown,
                 conn_pre_hook_dropdown,
                 conn_post_hook_dropdown,
                 conn_pre_disconnect_hook_dropdown,
@@ -1838,9 +1827,15 @@ impl ConnectionManagerWindow {
         self.settings_tab
             .conn_requires_preview_dropdown
             .update(cx, |dropdown, cx| {
-                dropdown.set_items(boolean_items, cx);
+                dropdown.set_items(boolean_items.clone(), cx);
                 dropdown.set_selected_index(Some(bool_index(overrides.requires_preview)), cx);
             });
+        self.settings_tab
+            .conn_hide_system_objects_dropdown
+            .update(cx, |dropdown, cx| {
+                dropdown.set_items(boolean_items, cx);
+                dropdown.set_selected_index(Some(bool_index(overrides.hide_system_objects)), cx);
+            });
 
         let mut hook_items = vec![dbflux_components::controls::DropdownItem::with_value(
             "No hook", "",
@@ -2041,6 +2036,8 @@ impl ConnectionManagerWindow {
             parse_boolean_dropdown(&self.settings_tab.conn_requires_where_dropdown, cx);
         overrides.requires_preview =
             parse_boolean_dropdown(&self.settings_tab.conn_requires_preview_dropdown, cx);
+        overrides.hide_system_objects =
+            parse_boolean_dropdown(&self.settings_tab.conn_hide_system_objects_dropdown, cx);
 
         if overrides.is_empty() {
             None
@@ -2577,7 +2574,31 @@ impl ConnectionManagerWindow {
         }
     }
 
+    /// Drivers offered in the driver picker, built-in and external.
+    fn driver_infos(app_state: &AppStateEntity) -> Vec<DriverInfo> {
+        app_state
+            .drivers()
+            .iter()
+            .map(|(driver_id, driver)| {
+                let metadata = driver.metadata();
+                DriverInfo {
+                    id: driver_id.clone(),
+                    icon: metadata.icon,
+                    name: driver.display_name().to_string(),
+                    description: driver.description().to_string(),
+                    category: metadata.category,
+                    default_port: metadata.default_port,
+                    uri_scheme: metadata.uri_scheme.clone(),
+                }
+            })
+            .collect()
+    }
+
     fn handle_app_state_changed(&mut self, cx: &mut Context<Self>) {
+        // External drivers can be registered or removed while the window is
+        // open; keep the picker in sync.
+        self.available_drivers = Self::driver_infos(self.app_state.read(cx));
+
         let current_profiles = self.app_state.read(cx).list_auth_profiles();
         let current_ids = current_profiles
             .iter()
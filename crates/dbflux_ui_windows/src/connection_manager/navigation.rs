@@ -2,7 +2,7 @@ use crate::settings::SettingsSectionId;
 use crate::ssh_shared::SshAuthSelection;
 use dbflux_app::keymap::{Command, ContextId};
 use dbflux_components::controls::DropdownItem;
-use dbflux_core::FormFieldKind;
+use dbflux_core::{ConnectionFixTarget, FormFieldKind};
 use dbflux_ui_base::keymap::key_chord_from_gpui;
 use gpui::*;
 
@@ -730,7 +730,8 @@ impl FormFocus {
             SettingsRefreshInterval => SettingsConfirmDangerous,
             SettingsConfirmDangerous => SettingsRequiresWhere,
             SettingsRequiresWhere => SettingsRequiresPreview,
-            SettingsRequiresPreview => {
+            SettingsRequiresPreview => SettingsHideSystemObjects,
+            SettingsHideSystemObjects => {
                 if driver_field_count > 0 {
                     SettingsDriverField(0)
                 } else {
@@ -760,13 +761,14 @@ impl FormFocus {
             SettingsConfirmDangerous => SettingsRefreshInterval,
             SettingsRequiresWhere => SettingsConfirmDangerous,
             SettingsRequiresPreview => SettingsRequiresWhere,
-            SettingsDriverField(0) => SettingsRequiresPreview,
+            SettingsHideSystemObjects => SettingsRequiresPreview,
+            SettingsDriverField(0) => SettingsHideSystemObjects,
             SettingsDriverField(idx) => SettingsDriverField(idx - 1),
             TestConnection => {
                 if driver_field_count > 0 {
                     SettingsDriverField(driver_field_count - 1)
                 } else {
-                    SettingsRequiresPreview
+                    SettingsHideSystemObjects
                 }
             }
             Save => TestConnection,
@@ -1575,7 +1577,10 @@ impl ConnectionManagerWindow {
             },
             ActiveTab::Settings => match self.form_focus {
                 SettingsRefreshPolicy | SettingsRefreshInterval => 0,
-                SettingsConfirmDangerous | SettingsRequiresWhere | SettingsRequiresPreview => 1,
+                SettingsConfirmDangerous
+                | SettingsRequiresWhere
+                | SettingsRequiresPreview
+                | SettingsHideSystemObjects => 1,
                 SettingsDriverField(idx) => 2 + idx as usize,
                 _ => 0,
             },
@@ -1707,6 +1712,31 @@ impl ConnectionManagerWindow {
         }
     }
 
+    /// Moves the form to the field a connection error hint points at.
+    pub fn focus_connection_fix(
+        &mut self,
+        target: ConnectionFixTarget,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.active_tab = ActiveTab::Main;
+
+        match target {
+            ConnectionFixTarget::Host => {
+                self.enter_edit_mode_for_field(FormFocus::Host, window, cx)
+            }
+            ConnectionFixTarget::SslMode => {
+                // The SSL mode control has no focus stop; it closes the Main
+                // tab, so bring the end of the form into view.
+                self.edit_state = EditState::Navigating;
+                self.form_focus = FormFocus::Name;
+                window.focus(&self.focus_handle);
+                self.form_scroll_handle.scroll_to_bottom();
+                cx.notify();
+            }
+        }
+    }
+
     pub(super) fn open_settings_section(
         &mut self,
         section: SettingsSectionId,
@@ -2030,7 +2060,8 @@ impl ConnectionManagerWindow {
             }
             FormFocus::SettingsConfirmDangerous
             | FormFocus::SettingsRequiresWhere
-            | FormFocus::SettingsRequiresPreview => {
+            | FormFocus::SettingsRequiresPreview
+            | FormFocus::SettingsHideSystemObjects => {
                 // These are dropdowns — no toggle action needed in navigate mode
             }
 
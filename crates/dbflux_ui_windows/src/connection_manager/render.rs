@@ -9,7 +9,7 @@ use dbflux_components::primitives::{
 use dbflux_components::semantic::BannerColors as SemBannerColors;
 use dbflux_components::tokens::{FontSizes, Heights, Radii, Spacing};
 use dbflux_components::typography::{Body, Headline, SubSectionLabel};
-use dbflux_core::{FormFieldDef, FormFieldKind, FormTab};
+use dbflux_core::{ConnectionErrorFix, FormFieldDef, FormFieldKind, FormTab};
 use dbflux_ui_base::platform;
 use gpui::prelude::*;
 use gpui::*;
@@ -398,13 +398,29 @@ impl ConnectionManagerWindow {
                             TestStatus::Failed => {
                                 let message =
                                     test_error.unwrap_or_else(|| "Connection failed".to_string());
-                                BannerBlock::new(BannerVariant::Danger, "Connection failed")
-                                    .with_body(message)
-                                    .with_icon(
-                                        AppIconElement::new(AppIcon::Info)
-                                            .size(Heights::ICON_SM)
-                                            .color(banners.error_fg),
-                                    )
+                                let fix = ConnectionErrorFix::detect(&message);
+
+                                let mut banner =
+                                    BannerBlock::new(BannerVariant::Danger, "Connection failed")
+                                        .with_body(message)
+                                        .with_icon(
+                                            AppIconElement::new(AppIcon::Info)
+                                                .size(Heights::ICON_SM)
+                                                .color(banners.error_fg),
+                                        );
+
+                                if let Some(fix) = fix {
+                                    banner = banner.with_actions(
+                                        Button::new("test-connection-fix", fix.action_label())
+                                            .ghost()
+                                            .small()
+                                            .on_click(cx.listener(move |this, _, window, cx| {
+                                                this.focus_connection_fix(fix.target(), window, cx);
+                                            })),
+                                    );
+                                }
+
+                                banner
                             }
                             TestStatus::None => unreachable!("guarded by when condition"),
                         };
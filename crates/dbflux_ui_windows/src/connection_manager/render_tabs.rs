@@ -537,6 +537,38 @@ impl ConnectionManagerWindow {
                             "Off"
                         }
                     ))),
+            )
+            // Hide system objects
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .rounded(Radii::SM)
+                    .border_2()
+                    .when(
+                        show_focus && focus == FormFocus::SettingsHideSystemObjects,
+                        |d| d.border_color(ring_color),
+                    )
+                    .when(
+                        !(show_focus && focus == FormFocus::SettingsHideSystemObjects),
+                        |d| d.border_color(gpui::transparent_black()),
+                    )
+                    .p(px(2.0))
+                    .child(div().w(px(200.0)).text_sm().child("Hide system objects"))
+                    .child(
+                        div()
+                            .min_w(px(160.0))
+                            .child(self.settings_tab.conn_hide_system_objects_dropdown.clone()),
+                    )
+                    .child(Text::caption(format!(
+                        "Default: {}",
+                        if effective.hide_system_objects {
+                            "On"
+                        } else {
+                            "Off"
+                        }
+                    ))),
             );
 
         sections.push(
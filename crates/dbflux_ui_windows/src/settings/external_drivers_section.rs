@@ -0,0 +1,443 @@
+use super::{SettingsSection, SettingsSectionId};
+use dbflux_app::driver_manifests::{
+    DriverManifestCatalog, DriverManifestEntry, install_driver_manifest, remove_driver_manifest,
+    set_driver_manifest_enabled,
+};
+use dbflux_app::keymap::Modifiers;
+use dbflux_components::icons::AppIcon;
+use dbflux_components::primitives::Icon as PrimitiveIcon;
+use dbflux_components::tokens::{Heights, Radii};
+use dbflux_components::typography::{Body, MonoCaption, MonoLabel, MonoMeta};
+use dbflux_core::DriverManifest;
+use dbflux_ui_base::keymap::key_chord_from_gpui;
+use dbflux_ui_base::toast::{Toast, now_hms};
+use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
+use dbflux_ui_base::{AppStateChanged, AppStateEntity};
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::ActiveTheme;
+use gpui_component::Icon;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::checkbox::Checkbox;
+use gpui_component::dialog::Dialog;
+use gpui_component::scroll::ScrollableElement;
+
+/// How a manifest's driver is doing, for the status line of its row.
+#[derive(Debug, Clone, PartialEq)]
+enum ManifestStatus {
+    Disabled,
+    Registered,
+    Failed(String),
+    /// Not handshaked with yet; drivers are picked up within seconds.
+    Pending,
+}
+
+pub(super) struct ExternalDriversSection {
+    app_state: Entity<AppStateEntity>,
+    catalog: DriverManifestCatalog,
+    selected_idx: Option<usize>,
+    pending_remove_idx: Option<usize>,
+    content_focused: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ExternalDriversSection {
+    pub(super) fn new(app_state: Entity<AppStateEntity>, cx: &mut Context<Self>) -> Self {
+        let catalog = app_state.read(cx).driver_manifests();
+
+        let subscription = cx.subscribe(&app_state, |this, _, _: &AppStateChanged, cx| {
+            this.reload(cx);
+        });
+
+        Self {
+            app_state,
+            catalog,
+            selected_idx: None,
+            pending_remove_idx: None,
+            content_focused: false,
+            _subscriptions: vec![subscription],
+        }
+    }
+
+    fn reload(&mut self, cx: &mut Context<Self>) {
+        self.catalog = self.app_state.read(cx).driver_manifests();
+        self.selected_idx = self
+            .selected_idx
+            .filter(|_| !self.catalog.entries.is_empty())
+            .map(|idx| idx.min(self.catalog.entries.len() - 1));
+        cx.notify();
+    }
+
+    fn status(&self, manifest: &DriverManifest, cx: &App) -> ManifestStatus {
+        if !manifest.enabled {
+            return ManifestStatus::Disabled;
+        }
+
+        let state = self.app_state.read(cx);
+        if state
+            .drivers()
+            .contains_key(&format!("rpc:{}", manifest.socket))
+        {
+            return ManifestStatus::Registered;
+        }
+
+        match state.external_driver_diagnostic(&manifest.socket) {
+            Some(diagnostic) => ManifestStatus::Failed(diagnostic.summary.clone()),
+            None => ManifestStatus::Pending,
+        }
+    }
+
+    fn add_manifest(&mut self, cx: &mut Context<Self>) {
+        let this = cx.entity().clone();
+        let dir = self.app_state.read(cx).driver_manifests_dir();
+
+        let task = cx.background_executor().spawn(async move {
+            let source = rfd::FileDialog::new()
+                .set_title("Add Driver Manifest")
+                .add_filter("Driver manifest", &["json"])
+                .pick_file()?;
+
+            Some(install_driver_manifest(&dir, &source))
+        });
+
+        cx.spawn(async move |_this, cx| {
+            let Some(result) = task.await else {
+                return;
+            };
+
+            if let Err(error) = cx.update(|cx| {
+                this.update(cx, |this, cx| match result {
+                    Ok(_) => {
+                        Toast::info("Driver manifest added. The driver will be available shortly.")
+                            .meta_right(now_hms())
+                            .push(cx);
+                        this.reload(cx);
+                    }
+                    Err(e) => report_error(
+                        UserFacingError::new(
+                            ErrorKind::Config,
+                            format!("Failed to add driver manifest: {e}"),
+                        ),
+                        cx,
+                    ),
+                });
+            }) {
+                log::warn!(
+                    "Failed to apply added driver manifest to settings state: {:?}",
+                    error
+                );
+            }
+        })
+        .detach();
+    }
+
+    fn set_enabled(&mut self, idx: usize, enabled: bool, cx: &mut Context<Self>) {
+        let Some(entry) = self.catalog.entries.get(idx) else {
+            return;
+        };
+
+        if let Err(e) = set_driver_manifest_enabled(&entry.path, enabled) {
+            report_error(
+                UserFacingError::new(
+                    ErrorKind::Config,
+                    format!("Failed to update driver manifest: {e}"),
+                ),
+                cx,
+            );
+            return;
+        }
+
+        Toast::info(if enabled {
+            "Driver enabled. It will be available shortly."
+        } else {
+            "Driver disabled. Open connections keep working until closed."
+        })
+        .meta_right(now_hms())
+        .push(cx);
+        self.reload(cx);
+    }
+
+    fn confirm_remove(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = self
+            .pending_remove_idx
+            .take()
+            .and_then(|idx| self.catalog.entries.get(idx))
+            .map(|entry| entry.path.clone())
+        else {
+            return;
+        };
+
+        if let Err(e) = remove_driver_manifest(&path) {
+            report_error(
+                UserFacingError::new(
+                    ErrorKind::Config,
+                    format!("Failed to remove driver manifest: {e}"),
+                ),
+                cx,
+            );
+            return;
+        }
+
+        Toast::info("Driver manifest removed.")
+            .meta_right(now_hms())
+            .push(cx);
+        self.reload(cx);
+    }
+
+    fn move_selection(&mut self, forward: bool, cx: &mut Context<Self>) {
+        let count = self.catalog.entries.len();
+        if count == 0 {
+            return;
+        }
+
+        self.selected_idx = Some(match (self.selected_idx, forward) {
+            (None, _) => 0,
+            (Some(idx), true) => (idx + 1).min(count - 1),
+            (Some(idx), false) => idx.saturating_sub(1),
+        });
+        cx.notify();
+    }
+
+    fn render_entry(
+        &self,
+        idx: usize,
+        entry: &DriverManifestEntry,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let manifest = &entry.manifest;
+        let status = self.status(manifest, cx);
+        let is_focused = self.content_focused && self.selected_idx == Some(idx);
+
+        let launch = match &manifest.binary {
+            Some(binary) => format!("{} · {}", manifest.socket, binary),
+            None => format!("{} · started outside DBFlux", manifest.socket),
+        };
+
+        let capabilities = match manifest.declared_capabilities() {
+            Ok(Some(declared)) => DriverManifest::capability_names(declared).join(", "),
+            _ => "Capabilities reported by the driver".to_string(),
+        };
+
+        let (status_label, status_color) = match &status {
+            ManifestStatus::Disabled => ("Disabled".to_string(), theme.muted_foreground),
+            ManifestStatus::Registered => ("Available".to_string(), theme.success),
+            ManifestStatus::Failed(summary) => (summary.clone(), theme.danger),
+            ManifestStatus::Pending => ("Starting…".to_string(), theme.muted_foreground),
+        };
+
+        div()
+            .id(SharedString::from(format!("external-driver-{idx}")))
+            .px_3()
+            .py_2()
+            .rounded(Radii::SM)
+            .bg(theme.list_even)
+            .border_1()
+            .border_color(if is_focused {
+                theme.primary
+            } else {
+                transparent_black()
+            })
+            .flex()
+            .items_start()
+            .gap_2()
+            .child(
+                div().mt(px(2.0)).child(
+                    PrimitiveIcon::new(AppIcon::Database)
+                        .size(Heights::ICON_SM)
+                        .muted(),
+                ),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(MonoLabel::new(manifest.name.clone()))
+                    .child(MonoMeta::new(launch))
+                    .child(MonoCaption::new(capabilities))
+                    .child(Body::new(status_label).color(status_color)),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        Checkbox::new(SharedString::from(format!("external-driver-enabled-{idx}")))
+                            .checked(manifest.enabled)
+                            .label("Enabled")
+                            .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                                this.set_enabled(idx, *checked, cx);
+                            })),
+                    )
+                    .child(
+                        Button::new(SharedString::from(format!("external-driver-remove-{idx}")))
+                            .label("Remove")
+                            .small()
+                            .danger()
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.pending_remove_idx = Some(idx);
+                                cx.notify();
+                            })),
+                    ),
+            )
+    }
+}
+
+impl SettingsSection for ExternalDriversSection {
+    fn section_id(&self) -> SettingsSectionId {
+        SettingsSectionId::ExternalDrivers
+    }
+
+    fn handle_key_event(
+        &mut self,
+        event: &KeyDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.pending_remove_idx.is_some() || !self.content_focused {
+            return;
+        }
+
+        let chord = key_chord_from_gpui(&event.keystroke);
+        if chord.modifiers != Modifiers::none() {
+            return;
+        }
+
+        match chord.key.as_str() {
+            "j" | "down" => self.move_selection(true, cx),
+            "k" | "up" => self.move_selection(false, cx),
+            "space" | "enter" => {
+                if let Some(idx) = self.selected_idx
+                    && let Some(entry) = self.catalog.entries.get(idx)
+                {
+                    let enabled = !entry.manifest.enabled;
+                    self.set_enabled(idx, enabled, cx);
+                }
+            }
+            "a" => self.add_manifest(cx),
+            "d" | "delete" => {
+                if self.selected_idx.is_some() {
+                    self.pending_remove_idx = self.selected_idx;
+                    cx.notify();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn focus_in(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.content_focused = true;
+        if self.selected_idx.is_none() && !self.catalog.entries.is_empty() {
+            self.selected_idx = Some(0);
+        }
+        cx.notify();
+    }
+
+    fn focus_out(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.content_focused = false;
+        cx.notify();
+    }
+}
+
+impl Render for ExternalDriversSection {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let folder = self.app_state.read(cx).driver_manifests_dir();
+        let entries = self.catalog.entries.clone();
+        let errors = self.catalog.errors.clone();
+
+        let remove_name = self
+            .pending_remove_idx
+            .and_then(|idx| self.catalog.entries.get(idx))
+            .map(|entry| entry.manifest.name.clone());
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .child(dbflux_components::composites::section_header(
+                "External Drivers",
+                "Third-party drivers described by manifest files",
+                cx,
+            ))
+            .child(
+                div()
+                    .flex_1()
+                    .min_h_0()
+                    .overflow_y_scrollbar()
+                    .p_6()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .gap_3()
+                            .child(MonoMeta::new(folder.display().to_string()))
+                            .child(
+                                Button::new("add-driver-manifest")
+                                    .icon(Icon::new(AppIcon::Plus))
+                                    .label("Add manifest")
+                                    .small()
+                                    .on_click(cx.listener(|this, _, _, cx| {
+                                        this.add_manifest(cx);
+                                    })),
+                            ),
+                    )
+                    .when(entries.is_empty(), |container| {
+                        container.child(
+                            Body::new(
+                                "No external drivers. Add a manifest to register a \
+                                 third-party driver.",
+                            )
+                            .color(theme.muted_foreground),
+                        )
+                    })
+                    .children(
+                        entries
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, entry)| self.render_entry(idx, entry, cx)),
+                    )
+                    .children(
+                        errors
+                            .into_iter()
+                            .map(|error| Body::new(error).color(theme.danger)),
+                    ),
+            )
+            .when_some(remove_name, |element, name| {
+                let entity = cx.entity().clone();
+                let entity_cancel = entity.clone();
+
+                element.child(
+                    Dialog::new(window, cx)
+                        .title("Remove Driver")
+                        .confirm()
+                        .on_ok(move |_, _, cx| {
+                            entity.update(cx, |section, cx| section.confirm_remove(cx));
+                            true
+                        })
+                        .on_cancel(move |_, _, cx| {
+                            entity_cancel.update(cx, |section, cx| {
+                                section.pending_remove_idx = None;
+                                cx.notify();
+                            });
+                            true
+                        })
+                        .child(
+                            div()
+                                .text_sm()
+                                .child(format!("Remove \"{name}\"? Its manifest file is deleted.")),
+                        ),
+                )
+            })
+    }
+}
@@ -4,9 +4,11 @@ use dbflux_components::controls::Dropdown;
 use dbflux_components::controls::{GpuiInput as Input, InputState};
 use dbflux_components::tokens::Radii;
 use dbflux_components::typography::{Body, FieldLabel, SubSectionLabel};
+use dbflux_core::i18n::{tr, tr_args};
 use dbflux_ui_base::keymap::key_chord_from_gpui;
 use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
+use dbflux_ui_base::user_themes::{USER_THEMES_DIR, apply_configured_theme, user_theme_catalog};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
 use gpui_component::ActiveTheme;
@@ -21,8 +23,17 @@ impl GeneralSection {
     pub(super) fn has_unsaved_general_changes(&self, cx: &App) -> bool {
         let saved = self.app_state.read(cx).general_settings();
 
-        if self.gen_settings.theme != saved.theme
+        if self.gen_settings.language != saved.language
+            || self.gen_settings.theme != saved.theme
+            || self.gen_settings.custom_theme != saved.custom_theme
             || self.gen_settings.style != saved.style
+            || self.gen_settings.ui_zoom_percent != saved.ui_zoom_percent
+            || self.gen_settings.editor_font_size != saved.editor_font_size
+            || self.gen_settings.grid_font_size != saved.grid_font_size
+            || self.gen_settings.display_timezone != saved.display_timezone
+            || self.gen_settings.null_marker != saved.null_marker
+            || self.gen_settings.null_style != saved.null_style
+            || self.gen_settings.display_format != saved.display_format
             || self.gen_settings.restore_session_on_startup != saved.restore_session_on_startup
             || self.gen_settings.reopen_last_connections != saved.reopen_last_connections
             || self.gen_settings.default_focus_on_startup != saved.default_focus_on_startup
@@ -32,6 +43,12 @@ impl GeneralSection {
             || self.gen_settings.confirm_dangerous_queries != saved.confirm_dangerous_queries
             || self.gen_settings.dangerous_requires_where != saved.dangerous_requires_where
             || self.gen_settings.dangerous_requires_preview != saved.dangerous_requires_preview
+            || self.gen_settings.editor_vim_mode != saved.editor_vim_mode
+            || self.gen_settings.hide_system_objects != saved.hide_system_objects
+            || self.gen_settings.schema_refresh_interval_secs != saved.schema_refresh_interval_secs
+            || self.gen_settings.schema_refresh_on_focus != saved.schema_refresh_on_focus
+            || self.gen_settings.notify_long_query_secs != saved.notify_long_query_secs
+            || self.gen_settings.history_collapse_duplicates != saved.history_collapse_duplicates
         {
             return true;
         }
@@ -40,6 +57,12 @@ impl GeneralSection {
             return true;
         }
 
+        if self.input_history_max_age.read(cx).value().trim()
+            != saved.history_max_age_days.to_string()
+        {
+            return true;
+        }
+
         if self.input_auto_save.read(cx).value().trim() != saved.auto_save_interval_ms.to_string() {
             return true;
         }
@@ -56,12 +79,28 @@ impl GeneralSection {
 
     pub(super) fn gen_form_rows(&self) -> Vec<GeneralFormRow> {
         let mut rows = vec![
+            GeneralFormRow::Language,
             GeneralFormRow::Theme,
             GeneralFormRow::Style,
+            GeneralFormRow::UiZoom,
+            GeneralFormRow::EditorFontSize,
+            GeneralFormRow::GridFontSize,
+            GeneralFormRow::DisplayTimezone,
+            GeneralFormRow::NullMarker,
+            GeneralFormRow::NullStyle,
+            GeneralFormRow::ThousandsSeparator,
+            GeneralFormRow::DecimalPlaces,
+            GeneralFormRow::DateFormat,
+            GeneralFormRow::TimeFormat,
+            GeneralFormRow::TimestampFormat,
+            GeneralFormRow::ScientificThreshold,
+            GeneralFormRow::FormattedOutput,
             GeneralFormRow::RestoreSession,
             GeneralFormRow::ReopenConnections,
             GeneralFormRow::DefaultFocus,
             GeneralFormRow::MaxHistory,
+            GeneralFormRow::HistoryMaxAge,
+            GeneralFormRow::CollapseHistoryDuplicates,
             GeneralFormRow::AutoSaveInterval,
             GeneralFormRow::DefaultRefreshPolicy,
             GeneralFormRow::DefaultRefreshInterval,
@@ -71,6 +110,11 @@ impl GeneralSection {
             GeneralFormRow::ConfirmDangerous,
             GeneralFormRow::RequiresWhere,
             GeneralFormRow::RequiresPreview,
+            GeneralFormRow::EditorVimMode,
+            GeneralFormRow::HideSystemObjects,
+            GeneralFormRow::SchemaRefreshInterval,
+            GeneralFormRow::SchemaRefreshOnFocus,
+            GeneralFormRow::NotifyLongQuery,
         ];
 
         // The shared-database toggle only makes sense on nightly, which is the
@@ -138,6 +182,11 @@ impl GeneralSection {
         cx: &mut Context<Self>,
     ) {
         match self.gen_current_row() {
+            Some(GeneralFormRow::Language) => {
+                self.dropdown_language
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
             Some(GeneralFormRow::Theme) => {
                 self.dropdown_theme
                     .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
@@ -148,6 +197,71 @@ impl GeneralSection {
                     .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
                 cx.notify();
             }
+            Some(GeneralFormRow::UiZoom) => {
+                self.dropdown_ui_zoom
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::EditorFontSize) => {
+                self.dropdown_editor_font_size
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::GridFontSize) => {
+                self.dropdown_grid_font_size
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::DisplayTimezone) => {
+                self.dropdown_display_timezone
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::NullMarker) => {
+                self.dropdown_null_marker
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::NullStyle) => {
+                self.dropdown_null_style
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::ThousandsSeparator) => {
+                self.dropdown_thousands_separator
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::DecimalPlaces) => {
+                self.dropdown_decimal_places
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::DateFormat) => {
+                self.dropdown_date_format
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::TimeFormat) => {
+                self.dropdown_time_format
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::TimestampFormat) => {
+                self.dropdown_timestamp_format
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::ScientificThreshold) => {
+                self.dropdown_scientific_threshold
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::FormattedOutput) => {
+                self.gen_settings.display_format.formatted_output =
+                    !self.gen_settings.display_format.formatted_output;
+                cx.notify();
+            }
             Some(GeneralFormRow::RestoreSession) => {
                 self.gen_settings.restore_session_on_startup =
                     !self.gen_settings.restore_session_on_startup;
@@ -193,11 +307,40 @@ impl GeneralSection {
                     !self.gen_settings.dangerous_requires_preview;
                 cx.notify();
             }
+            Some(GeneralFormRow::CollapseHistoryDuplicates) => {
+                self.gen_settings.history_collapse_duplicates =
+                    !self.gen_settings.history_collapse_duplicates;
+                cx.notify();
+            }
+            Some(GeneralFormRow::EditorVimMode) => {
+                self.gen_settings.editor_vim_mode = !self.gen_settings.editor_vim_mode;
+                cx.notify();
+            }
+            Some(GeneralFormRow::HideSystemObjects) => {
+                self.gen_settings.hide_system_objects = !self.gen_settings.hide_system_objects;
+                cx.notify();
+            }
+            Some(GeneralFormRow::SchemaRefreshInterval) => {
+                self.dropdown_schema_refresh
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
+            Some(GeneralFormRow::SchemaRefreshOnFocus) => {
+                self.gen_settings.schema_refresh_on_focus =
+                    !self.gen_settings.schema_refresh_on_focus;
+                cx.notify();
+            }
+            Some(GeneralFormRow::NotifyLongQuery) => {
+                self.dropdown_notify_long_query
+                    .update(cx, |dropdown, cx| dropdown.toggle_open(cx));
+                cx.notify();
+            }
             Some(GeneralFormRow::ShareStableDb) => {
                 self.set_share_stable_db(!self.gen_share_stable_db, cx);
                 cx.notify();
             }
             Some(GeneralFormRow::MaxHistory)
+            | Some(GeneralFormRow::HistoryMaxAge)
             | Some(GeneralFormRow::AutoSaveInterval)
             | Some(GeneralFormRow::DefaultRefreshInterval)
             | Some(GeneralFormRow::MaxBackgroundTasks) => {
@@ -218,6 +361,10 @@ impl GeneralSection {
                 self.input_max_history
                     .update(cx, |state, cx| state.focus(window, cx));
             }
+            Some(GeneralFormRow::HistoryMaxAge) => {
+                self.input_history_max_age
+                    .update(cx, |state, cx| state.focus(window, cx));
+            }
             Some(GeneralFormRow::AutoSaveInterval) => {
                 self.input_auto_save
                     .update(cx, |state, cx| state.focus(window, cx));
@@ -248,10 +395,25 @@ impl GeneralSection {
 
     fn current_dropdown(&self) -> Option<&Entity<Dropdown>> {
         match self.gen_current_row() {
+            Some(GeneralFormRow::Language) => Some(&self.dropdown_language),
             Some(GeneralFormRow::Theme) => Some(&self.dropdown_theme),
             Some(GeneralFormRow::Style) => Some(&self.dropdown_style),
+            Some(GeneralFormRow::UiZoom) => Some(&self.dropdown_ui_zoom),
+            Some(GeneralFormRow::EditorFontSize) => Some(&self.dropdown_editor_font_size),
+            Some(GeneralFormRow::GridFontSize) => Some(&self.dropdown_grid_font_size),
+            Some(GeneralFormRow::DisplayTimezone) => Some(&self.dropdown_display_timezone),
+            Some(GeneralFormRow::NullMarker) => Some(&self.dropdown_null_marker),
+            Some(GeneralFormRow::NullStyle) => Some(&self.dropdown_null_style),
+            Some(GeneralFormRow::ThousandsSeparator) => Some(&self.dropdown_thousands_separator),
+            Some(GeneralFormRow::DecimalPlaces) => Some(&self.dropdown_decimal_places),
+            Some(GeneralFormRow::DateFormat) => Some(&self.dropdown_date_format),
+            Some(GeneralFormRow::TimeFormat) => Some(&self.dropdown_time_format),
+            Some(GeneralFormRow::TimestampFormat) => Some(&self.dropdown_timestamp_format),
+            Some(GeneralFormRow::ScientificThreshold) => Some(&self.dropdown_scientific_threshold),
             Some(GeneralFormRow::DefaultFocus) => Some(&self.dropdown_default_focus),
             Some(GeneralFormRow::DefaultRefreshPolicy) => Some(&self.dropdown_refresh_policy),
+            Some(GeneralFormRow::SchemaRefreshInterval) => Some(&self.dropdown_schema_refresh),
+            Some(GeneralFormRow::NotifyLongQuery) => Some(&self.dropdown_notify_long_query),
             _ => None,
         }
     }
@@ -389,6 +551,25 @@ impl GeneralSection {
             }
         };
 
+        let max_age_str = self
+            .input_history_max_age
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        let history_max_age = match max_age_str.parse::<u32>() {
+            Ok(value) => value,
+            Err(_) => {
+                Toast::error("History max age must be a number of days (0 keeps forever)")
+                    .meta_right(now_hms())
+                    .action(copy_action(
+                        "History max age must be a number of days (0 keeps forever)",
+                    ))
+                    .push(cx);
+                return;
+            }
+        };
+
         let auto_save_str = self.input_auto_save.read(cx).value().trim().to_string();
         let auto_save_ms = match auto_save_str.parse::<u64>() {
             Ok(value) if value >= 500 => value,
@@ -431,6 +612,7 @@ impl GeneralSection {
         };
 
         self.gen_settings.max_history_entries = max_history;
+        self.gen_settings.history_max_age_days = history_max_age;
         self.gen_settings.auto_save_interval_ms = auto_save_ms;
         self.gen_settings.default_refresh_interval_secs = refresh_interval;
         self.gen_settings.max_concurrent_background_tasks = max_bg_tasks;
@@ -453,17 +635,32 @@ impl GeneralSection {
             state.update_general_settings(self.gen_settings.clone());
         });
 
-        // Update the density global so cx-based accessors reflect the new style immediately.
+        // Update the density and zoom globals so cx-based accessors reflect
+        // the new style and sizes immediately.
         dbflux_components::density::set_style(cx, self.gen_settings.style);
-
-        dbflux_components::theme::apply_theme(
-            self.gen_settings.theme,
-            self.gen_settings.style,
-            Some(window),
+        dbflux_components::density::set_font_scale(
             cx,
+            dbflux_components::density::FontScale::from_settings(&self.gen_settings),
         );
 
-        Toast::success("Settings saved. Some changes apply on next startup.")
+        // Labels drawn from here on use the new language; ones built when a
+        // view was created change when it is reopened.
+        dbflux_core::i18n::set_language(self.gen_settings.language);
+
+        // Grids built from here on show timestamps in the new time zone.
+        dbflux_core::timezone::set_display_timezone(self.gen_settings.display_timezone);
+
+        // Open grids pick the NULL marker and style up on their next frame.
+        dbflux_core::null_display::set_null_marker(&self.gen_settings.null_marker);
+        dbflux_core::null_display::set_null_style(self.gen_settings.null_style);
+
+        // Grids built from here on show numbers and dates in the new format.
+        dbflux_core::display_format::set_display_format(self.gen_settings.display_format.clone());
+
+        apply_configured_theme(&self.gen_settings, Some(window), cx);
+        cx.refresh_windows();
+
+        Toast::success(tr("settings-saved-toast"))
             .meta_right(now_hms())
             .push(cx);
     }
@@ -482,17 +679,29 @@ impl GeneralSection {
 
         layout::single_form_section_shell(
             dbflux_components::composites::section_header(
-                "General",
-                "Configure startup, session, refresh, and safety behavior",
+                tr("settings-general-title"),
+                tr("settings-general-description"),
                 cx,
             ),
             div()
                 .flex()
                 .flex_col()
                 .gap_6()
-                .child(self.render_gen_group_header("Appearance", border, muted_fg))
+                .child(self.render_gen_group_header(
+                    tr("settings-group-appearance"),
+                    border,
+                    muted_fg,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-language"),
+                    &self.dropdown_language,
+                    is_at(GeneralFormRow::Language),
+                    primary,
+                    GeneralFormRow::Language,
+                    cx,
+                ))
                 .child(self.render_gen_dropdown(
-                    "Theme",
+                    tr("settings-theme"),
                     &self.dropdown_theme,
                     is_at(GeneralFormRow::Theme),
                     primary,
@@ -500,17 +709,123 @@ impl GeneralSection {
                     cx,
                 ))
                 .child(self.render_gen_dropdown(
-                    "Style",
+                    tr("settings-style"),
                     &self.dropdown_style,
                     is_at(GeneralFormRow::Style),
                     primary,
                     GeneralFormRow::Style,
                     cx,
                 ))
-                .child(self.render_gen_group_header("Startup & Session", border, muted_fg))
+                .child(self.render_gen_dropdown(
+                    tr("settings-ui-zoom"),
+                    &self.dropdown_ui_zoom,
+                    is_at(GeneralFormRow::UiZoom),
+                    primary,
+                    GeneralFormRow::UiZoom,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-editor-font-size"),
+                    &self.dropdown_editor_font_size,
+                    is_at(GeneralFormRow::EditorFontSize),
+                    primary,
+                    GeneralFormRow::EditorFontSize,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-grid-font-size"),
+                    &self.dropdown_grid_font_size,
+                    is_at(GeneralFormRow::GridFontSize),
+                    primary,
+                    GeneralFormRow::GridFontSize,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-display-timezone"),
+                    &self.dropdown_display_timezone,
+                    is_at(GeneralFormRow::DisplayTimezone),
+                    primary,
+                    GeneralFormRow::DisplayTimezone,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-null-marker"),
+                    &self.dropdown_null_marker,
+                    is_at(GeneralFormRow::NullMarker),
+                    primary,
+                    GeneralFormRow::NullMarker,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-null-style"),
+                    &self.dropdown_null_style,
+                    is_at(GeneralFormRow::NullStyle),
+                    primary,
+                    GeneralFormRow::NullStyle,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-thousands-separator"),
+                    &self.dropdown_thousands_separator,
+                    is_at(GeneralFormRow::ThousandsSeparator),
+                    primary,
+                    GeneralFormRow::ThousandsSeparator,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-decimal-places"),
+                    &self.dropdown_decimal_places,
+                    is_at(GeneralFormRow::DecimalPlaces),
+                    primary,
+                    GeneralFormRow::DecimalPlaces,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-date-format"),
+                    &self.dropdown_date_format,
+                    is_at(GeneralFormRow::DateFormat),
+                    primary,
+                    GeneralFormRow::DateFormat,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-time-format"),
+                    &self.dropdown_time_format,
+                    is_at(GeneralFormRow::TimeFormat),
+                    primary,
+                    GeneralFormRow::TimeFormat,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-timestamp-format"),
+                    &self.dropdown_timestamp_format,
+                    is_at(GeneralFormRow::TimestampFormat),
+                    primary,
+                    GeneralFormRow::TimestampFormat,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-scientific-threshold"),
+                    &self.dropdown_scientific_threshold,
+                    is_at(GeneralFormRow::ScientificThreshold),
+                    primary,
+                    GeneralFormRow::ScientificThreshold,
+                    cx,
+                ))
+                .child(self.render_gen_checkbox(
+                    "formatted-output",
+                    tr("settings-formatted-output"),
+                    self.gen_settings.display_format.formatted_output,
+                    is_at(GeneralFormRow::FormattedOutput),
+                    GeneralFormRow::FormattedOutput,
+                    |this, value, _cx| this.gen_settings.display_format.formatted_output = value,
+                    cx,
+                ))
+                .child(self.render_theme_preview(cx))
+                .child(self.render_gen_group_header(tr("settings-group-startup"), border, muted_fg))
                 .child(self.render_gen_checkbox(
                     "restore-session",
-                    "Restore session on startup",
+                    tr("settings-restore-session"),
                     self.gen_settings.restore_session_on_startup,
                     is_at(GeneralFormRow::RestoreSession),
                     GeneralFormRow::RestoreSession,
@@ -519,7 +834,7 @@ impl GeneralSection {
                 ))
                 .child(self.render_gen_checkbox(
                     "reopen-conns",
-                    "Reopen last connections",
+                    tr("settings-reopen-connections"),
                     self.gen_settings.reopen_last_connections,
                     is_at(GeneralFormRow::ReopenConnections),
                     GeneralFormRow::ReopenConnections,
@@ -527,7 +842,7 @@ impl GeneralSection {
                     cx,
                 ))
                 .child(self.render_gen_dropdown(
-                    "Default focus",
+                    tr("settings-default-focus"),
                     &self.dropdown_default_focus,
                     is_at(GeneralFormRow::DefaultFocus),
                     primary,
@@ -535,7 +850,7 @@ impl GeneralSection {
                     cx,
                 ))
                 .child(self.render_gen_input_field(
-                    "Max history entries",
+                    tr("settings-max-history"),
                     &self.input_max_history,
                     is_at(GeneralFormRow::MaxHistory),
                     primary,
@@ -543,16 +858,33 @@ impl GeneralSection {
                     cx,
                 ))
                 .child(self.render_gen_input_field(
-                    "Auto-save interval (ms)",
+                    tr("settings-history-max-age"),
+                    &self.input_history_max_age,
+                    is_at(GeneralFormRow::HistoryMaxAge),
+                    primary,
+                    GeneralFormRow::HistoryMaxAge,
+                    cx,
+                ))
+                .child(self.render_gen_checkbox(
+                    "collapse-history-duplicates",
+                    tr("settings-collapse-history"),
+                    self.gen_settings.history_collapse_duplicates,
+                    is_at(GeneralFormRow::CollapseHistoryDuplicates),
+                    GeneralFormRow::CollapseHistoryDuplicates,
+                    |this, value, _cx| this.gen_settings.history_collapse_duplicates = value,
+                    cx,
+                ))
+                .child(self.render_gen_input_field(
+                    tr("settings-auto-save-interval"),
                     &self.input_auto_save,
                     is_at(GeneralFormRow::AutoSaveInterval),
                     primary,
                     GeneralFormRow::AutoSaveInterval,
                     cx,
                 ))
-                .child(self.render_gen_group_header("Refresh & Background", border, muted_fg))
+                .child(self.render_gen_group_header(tr("settings-group-refresh"), border, muted_fg))
                 .child(self.render_gen_dropdown(
-                    "Default refresh policy",
+                    tr("settings-refresh-policy"),
                     &self.dropdown_refresh_policy,
                     is_at(GeneralFormRow::DefaultRefreshPolicy),
                     primary,
@@ -560,7 +892,7 @@ impl GeneralSection {
                     cx,
                 ))
                 .child(self.render_gen_input_field(
-                    "Default refresh interval (seconds)",
+                    tr("settings-refresh-interval"),
                     &self.input_refresh_interval,
                     is_at(GeneralFormRow::DefaultRefreshInterval),
                     primary,
@@ -568,7 +900,7 @@ impl GeneralSection {
                     cx,
                 ))
                 .child(self.render_gen_input_field(
-                    "Max concurrent background tasks",
+                    tr("settings-max-background-tasks"),
                     &self.input_max_bg_tasks,
                     is_at(GeneralFormRow::MaxBackgroundTasks),
                     primary,
@@ -577,7 +909,7 @@ impl GeneralSection {
                 ))
                 .child(self.render_gen_checkbox(
                     "pause-on-error",
-                    "Pause auto-refresh on error",
+                    tr("settings-pause-refresh-on-error"),
                     self.gen_settings.auto_refresh_pause_on_error,
                     is_at(GeneralFormRow::PauseRefreshOnError),
                     GeneralFormRow::PauseRefreshOnError,
@@ -586,17 +918,17 @@ impl GeneralSection {
                 ))
                 .child(self.render_gen_checkbox(
                     "refresh-visible",
-                    "Auto-refresh only if tab is visible",
+                    tr("settings-refresh-only-if-visible"),
                     self.gen_settings.auto_refresh_only_if_visible,
                     is_at(GeneralFormRow::RefreshOnlyIfVisible),
                     GeneralFormRow::RefreshOnlyIfVisible,
                     |this, value, _cx| this.gen_settings.auto_refresh_only_if_visible = value,
                     cx,
                 ))
-                .child(self.render_gen_group_header("Execution Safety", border, muted_fg))
+                .child(self.render_gen_group_header(tr("settings-group-safety"), border, muted_fg))
                 .child(self.render_gen_checkbox(
                     "confirm-dangerous",
-                    "Confirm dangerous queries",
+                    tr("settings-confirm-dangerous"),
                     self.gen_settings.confirm_dangerous_queries,
                     is_at(GeneralFormRow::ConfirmDangerous),
                     GeneralFormRow::ConfirmDangerous,
@@ -605,7 +937,7 @@ impl GeneralSection {
                 ))
                 .child(self.render_gen_checkbox(
                     "requires-where",
-                    "Require WHERE for DELETE/UPDATE",
+                    tr("settings-requires-where"),
                     self.gen_settings.dangerous_requires_where,
                     is_at(GeneralFormRow::RequiresWhere),
                     GeneralFormRow::RequiresWhere,
@@ -614,19 +946,73 @@ impl GeneralSection {
                 ))
                 .child(self.render_gen_checkbox(
                     "requires-preview",
-                    "Always require preview (ignore suppressions)",
+                    tr("settings-requires-preview"),
                     self.gen_settings.dangerous_requires_preview,
                     is_at(GeneralFormRow::RequiresPreview),
                     GeneralFormRow::RequiresPreview,
                     |this, value, _cx| this.gen_settings.dangerous_requires_preview = value,
                     cx,
                 ))
+                .child(self.render_gen_group_header(tr("settings-group-editor"), border, muted_fg))
+                .child(self.render_gen_checkbox(
+                    "editor-vim-mode",
+                    tr("settings-vim-mode"),
+                    self.gen_settings.editor_vim_mode,
+                    is_at(GeneralFormRow::EditorVimMode),
+                    GeneralFormRow::EditorVimMode,
+                    |this, value, _cx| this.gen_settings.editor_vim_mode = value,
+                    cx,
+                ))
+                .child(self.render_gen_group_header(tr("settings-group-sidebar"), border, muted_fg))
+                .child(self.render_gen_checkbox(
+                    "hide-system-objects",
+                    tr("settings-hide-system-objects"),
+                    self.gen_settings.hide_system_objects,
+                    is_at(GeneralFormRow::HideSystemObjects),
+                    GeneralFormRow::HideSystemObjects,
+                    |this, value, _cx| this.gen_settings.hide_system_objects = value,
+                    cx,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-schema-refresh"),
+                    &self.dropdown_schema_refresh,
+                    is_at(GeneralFormRow::SchemaRefreshInterval),
+                    primary,
+                    GeneralFormRow::SchemaRefreshInterval,
+                    cx,
+                ))
+                .child(self.render_gen_checkbox(
+                    "schema-refresh-on-focus",
+                    tr("settings-schema-refresh-on-focus"),
+                    self.gen_settings.schema_refresh_on_focus,
+                    is_at(GeneralFormRow::SchemaRefreshOnFocus),
+                    GeneralFormRow::SchemaRefreshOnFocus,
+                    |this, value, _cx| this.gen_settings.schema_refresh_on_focus = value,
+                    cx,
+                ))
+                .child(self.render_gen_group_header(
+                    tr("settings-group-notifications"),
+                    border,
+                    muted_fg,
+                ))
+                .child(self.render_gen_dropdown(
+                    tr("settings-long-query-notification"),
+                    &self.dropdown_notify_long_query,
+                    is_at(GeneralFormRow::NotifyLongQuery),
+                    primary,
+                    GeneralFormRow::NotifyLongQuery,
+                    cx,
+                ))
                 .when(Self::is_nightly(), |column| {
                     column
-                        .child(self.render_gen_group_header("Storage", border, muted_fg))
+                        .child(self.render_gen_group_header(
+                            tr("settings-group-storage"),
+                            border,
+                            muted_fg,
+                        ))
                         .child(self.render_gen_checkbox(
                             "share-stable-db",
-                            "Use the stable database",
+                            tr("settings-share-stable-db"),
                             self.gen_share_stable_db,
                             is_at(GeneralFormRow::ShareStableDb),
                             GeneralFormRow::ShareStableDb,
@@ -635,18 +1021,71 @@ impl GeneralSection {
                         ))
                         .child(
                             div().px_2().child(
-                                Body::new(
-                                    "Applied on next launch. Shares the stable database \
-                                     (dbflux.db) instead of this nightly build's \
-                                     dbflux-nightly.db.",
-                                )
-                                .color(muted_fg),
+                                Body::new(tr("settings-share-stable-db-hint")).color(muted_fg),
                             ),
                         )
                 }),
         )
     }
 
+    /// Swatches of the active palette (which includes an unsaved preview),
+    /// the user themes folder, and the theme files that failed to load.
+    fn render_theme_preview(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let border = theme.border;
+        let muted_fg = theme.muted_foreground;
+        let danger = theme.danger;
+        let swatches = [
+            theme.background,
+            theme.foreground,
+            theme.primary,
+            theme.secondary,
+            theme.selection,
+            theme.border,
+            theme.success,
+            theme.warning,
+            theme.danger,
+            theme.info,
+        ];
+
+        let folder = self
+            .user_themes_dir
+            .clone()
+            .unwrap_or_else(|| USER_THEMES_DIR.to_string());
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .px_2()
+            .child(
+                div()
+                    .flex()
+                    .gap_1()
+                    .children(swatches.into_iter().map(|color| {
+                        div()
+                            .size(px(20.0))
+                            .rounded(Radii::SM)
+                            .border_1()
+                            .border_color(border)
+                            .bg(color)
+                    })),
+            )
+            .child(
+                Body::new(tr_args(
+                    "settings-custom-themes-hint",
+                    &[("folder", &folder)],
+                ))
+                .color(muted_fg),
+            )
+            .children(
+                user_theme_catalog(cx)
+                    .errors
+                    .into_iter()
+                    .map(move |error| Body::new(error).color(danger)),
+            )
+    }
+
     pub(super) fn render_general_footer_actions(&self, cx: &mut Context<Self>) -> AnyElement {
         let is_save_focused = self.content_focused
             && self.gen_form_rows().get(self.gen_form_cursor).copied()
@@ -659,7 +1098,7 @@ impl GeneralSection {
             .child(layout::footer_action_frame(
                 is_save_focused,
                 cx.theme().primary,
-                FluxButton::new("save-general", "Save")
+                FluxButton::new("save-general", tr("settings-save"))
                     .small()
                     .primary()
                     .w_full()
@@ -3,19 +3,121 @@ use super::SettingsSectionId;
 use super::section_trait::SectionFocusEvent;
 use dbflux_components::controls::{Dropdown, DropdownItem, DropdownSelectionChanged};
 use dbflux_components::controls::{InputEvent, InputState};
-use dbflux_core::{AppStyle, GeneralSettings, RefreshPolicySetting, StartupFocus, ThemeSetting};
+use dbflux_core::chrono::{FixedOffset, NaiveDate, NaiveDateTime};
+use dbflux_core::display_format::DisplayFormat;
+use dbflux_core::i18n::Language;
+use dbflux_core::null_display::{DEFAULT_NULL_MARKER, NULL_MARKERS, NullStyle};
+use dbflux_core::timezone::DisplayTimezone;
+use dbflux_core::{
+    AppStyle, DEFAULT_EDITOR_FONT_SIZE, DEFAULT_GRID_FONT_SIZE, DEFAULT_UI_ZOOM_PERCENT,
+    GeneralSettings, MAX_EDITOR_FONT_SIZE, MAX_GRID_FONT_SIZE, MAX_UI_ZOOM_PERCENT,
+    MIN_EDITOR_FONT_SIZE, MIN_GRID_FONT_SIZE, MIN_UI_ZOOM_PERCENT, RefreshPolicySetting,
+    StartupFocus, ThemeSetting, UI_ZOOM_STEP_PERCENT,
+};
 use dbflux_ui_base::AppStateEntity;
+use dbflux_ui_base::user_themes::{apply_configured_theme, user_theme_catalog, user_themes_dir};
 use gpui::prelude::*;
 use gpui::*;
+use std::ops::RangeInclusive;
+
+/// Intervals offered for the sidebar schema auto-refresh, in seconds; 0 keeps
+/// it manual.
+const SCHEMA_REFRESH_INTERVALS: &[(u32, &str)] = &[
+    (0, "Manual"),
+    (60, "Every minute"),
+    (300, "Every 5 minutes"),
+    (900, "Every 15 minutes"),
+    (1800, "Every 30 minutes"),
+];
+
+/// Fixed offsets offered after UTC and local time, in minutes east of UTC.
+const TIMEZONE_OFFSET_MINUTES: &[i32] = &[
+    -720, -660, -600, -570, -540, -480, -420, -360, -300, -240, -210, -180, -120, -60, 60, 120,
+    180, 210, 240, 270, 300, 330, 345, 360, 390, 420, 480, 525, 540, 570, 600, 630, 660, 720, 765,
+    780, 840,
+];
+
+/// Digit group separators offered in the settings; `None` leaves digits
+/// ungrouped.
+const THOUSANDS_SEPARATORS: &[Option<char>] = &[None, Some(','), Some('.'), Some(' '), Some('\'')];
+
+/// Fraction digits offered in the settings; `None` shows them as stored.
+const DECIMAL_PLACES: &[Option<u8>] = &[
+    None,
+    Some(0),
+    Some(1),
+    Some(2),
+    Some(3),
+    Some(4),
+    Some(5),
+    Some(6),
+];
+
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y", "%m/%d/%Y", "%d.%m.%Y", "%b %-d, %Y"];
+
+const TIME_FORMATS: &[&str] = &["%H:%M:%S", "%H:%M:%S%.3f", "%I:%M:%S %p", "%H:%M"];
+
+const TIMESTAMP_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M:%S%.3f",
+    "%d/%m/%Y %H:%M:%S",
+    "%m/%d/%Y %I:%M:%S %p",
+    "%Y-%m-%dT%H:%M:%S",
+];
+
+/// Powers of ten from which floats switch to scientific notation; `None`
+/// never switches.
+const SCIENTIFIC_THRESHOLDS: &[Option<u8>] = &[None, Some(6), Some(9), Some(12), Some(15)];
+
+/// Built-in entries of the theme dropdown, ahead of the user themes.
+const BUILTIN_THEMES: &[(ThemeSetting, &str)] = &[
+    (ThemeSetting::Dark, "Ayu Dark"),
+    (ThemeSetting::Mirage, "Ayu Mirage"),
+    (ThemeSetting::Light, "Ayu Light"),
+    (ThemeSetting::System, "Follow System"),
+];
+
+/// What the theme dropdown selects: a built-in theme or a user theme by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum ThemeChoice {
+    Builtin(ThemeSetting),
+    Custom(String),
+}
+
+/// Run times after which a finished query raises a desktop notification, in
+/// seconds; 0 turns the notification off.
+const LONG_QUERY_THRESHOLDS: &[(u32, &str)] = &[
+    (0, "Never"),
+    (10, "10 seconds"),
+    (30, "30 seconds"),
+    (60, "1 minute"),
+    (300, "5 minutes"),
+];
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub(super) enum GeneralFormRow {
+    Language,
     Theme,
     Style,
+    UiZoom,
+    EditorFontSize,
+    GridFontSize,
+    DisplayTimezone,
+    NullMarker,
+    NullStyle,
+    ThousandsSeparator,
+    DecimalPlaces,
+    DateFormat,
+    TimeFormat,
+    TimestampFormat,
+    ScientificThreshold,
+    FormattedOutput,
     RestoreSession,
     ReopenConnections,
     DefaultFocus,
     MaxHistory,
+    HistoryMaxAge,
+    CollapseHistoryDuplicates,
     AutoSaveInterval,
     DefaultRefreshPolicy,
     DefaultRefreshInterval,
@@ -25,6 +127,11 @@ pub(super) enum GeneralFormRow {
     ConfirmDangerous,
     RequiresWhere,
     RequiresPreview,
+    EditorVimMode,
+    HideSystemObjects,
+    SchemaRefreshInterval,
+    SchemaRefreshOnFocus,
+    NotifyLongQuery,
     ShareStableDb,
     SaveButton,
 }
@@ -37,11 +144,30 @@ pub(super) struct GeneralSection {
     /// Nightly-only: whether this build is opted into the stable database.
     /// Backed by a pre-database marker file, applied on the next launch.
     pub(super) gen_share_stable_db: bool,
+    /// User themes listed after the built-ins in the theme dropdown.
+    pub(super) user_theme_names: Vec<String>,
+    pub(super) user_themes_dir: Option<String>,
+    pub(super) dropdown_language: Entity<Dropdown>,
     pub(super) dropdown_theme: Entity<Dropdown>,
     pub(super) dropdown_style: Entity<Dropdown>,
+    pub(super) dropdown_ui_zoom: Entity<Dropdown>,
+    pub(super) dropdown_editor_font_size: Entity<Dropdown>,
+    pub(super) dropdown_grid_font_size: Entity<Dropdown>,
+    pub(super) dropdown_display_timezone: Entity<Dropdown>,
+    pub(super) dropdown_null_marker: Entity<Dropdown>,
+    pub(super) dropdown_null_style: Entity<Dropdown>,
+    pub(super) dropdown_thousands_separator: Entity<Dropdown>,
+    pub(super) dropdown_decimal_places: Entity<Dropdown>,
+    pub(super) dropdown_date_format: Entity<Dropdown>,
+    pub(super) dropdown_time_format: Entity<Dropdown>,
+    pub(super) dropdown_timestamp_format: Entity<Dropdown>,
+    pub(super) dropdown_scientific_threshold: Entity<Dropdown>,
     pub(super) dropdown_default_focus: Entity<Dropdown>,
     pub(super) dropdown_refresh_policy: Entity<Dropdown>,
+    pub(super) dropdown_schema_refresh: Entity<Dropdown>,
+    pub(super) dropdown_notify_long_query: Entity<Dropdown>,
     pub(super) input_max_history: Entity<InputState>,
+    pub(super) input_history_max_age: Entity<InputState>,
     pub(super) input_auto_save: Entity<InputState>,
     pub(super) input_refresh_interval: Entity<InputState>,
     pub(super) input_max_bg_tasks: Entity<InputState>,
@@ -59,19 +185,77 @@ impl GeneralSection {
         cx: &mut Context<Self>,
     ) -> Self {
         let settings = app_state.read(cx).general_settings().clone();
-        let theme_index = Self::theme_index(settings.theme);
+        let user_theme_names = user_theme_catalog(cx).names();
+        let language_index = Self::language_index(settings.language);
+        let theme_index = Self::theme_index(
+            settings.theme,
+            settings.custom_theme.as_deref(),
+            &user_theme_names,
+        );
+        let theme_items = Self::theme_items(&user_theme_names);
         let style_index = Self::style_index(settings.style);
+        let ui_zoom_index = Self::ui_zoom_index(settings.ui_zoom_percent);
+        let editor_font_index = Self::font_size_index(
+            settings.editor_font_size,
+            MIN_EDITOR_FONT_SIZE..=MAX_EDITOR_FONT_SIZE,
+            DEFAULT_EDITOR_FONT_SIZE,
+        );
+        let grid_font_index = Self::font_size_index(
+            settings.grid_font_size,
+            MIN_GRID_FONT_SIZE..=MAX_GRID_FONT_SIZE,
+            DEFAULT_GRID_FONT_SIZE,
+        );
+        let display_timezone_index = Self::display_timezone_index(settings.display_timezone);
+        let null_markers = Self::null_markers(&settings.null_marker);
+        let null_marker_index = null_markers
+            .iter()
+            .position(|marker| *marker == settings.null_marker)
+            .unwrap_or(0);
+        let null_style_index = Self::null_style_index(settings.null_style);
+        let display_format = &settings.display_format;
+        let separators = Self::with_current(
+            THOUSANDS_SEPARATORS.to_vec(),
+            display_format.thousands_separator,
+        );
+        let decimal_places =
+            Self::with_current(DECIMAL_PLACES.to_vec(), display_format.decimal_places);
+        let date_formats = Self::with_current(
+            DATE_FORMATS.iter().map(|p| p.to_string()).collect(),
+            display_format.date_format.clone(),
+        );
+        let time_formats = Self::with_current(
+            TIME_FORMATS.iter().map(|p| p.to_string()).collect(),
+            display_format.time_format.clone(),
+        );
+        let timestamp_formats = Self::with_current(
+            TIMESTAMP_FORMATS.iter().map(|p| p.to_string()).collect(),
+            display_format.timestamp_format.clone(),
+        );
+        let scientific_thresholds = Self::with_current(
+            SCIENTIFIC_THRESHOLDS.to_vec(),
+            display_format.scientific_threshold,
+        );
         let startup_focus_index = Self::startup_focus_index(settings.default_focus_on_startup);
         let refresh_policy_index = Self::refresh_policy_index(settings.default_refresh_policy);
+        let schema_refresh_index =
+            Self::schema_refresh_index(settings.schema_refresh_interval_secs);
+        let long_query_index = Self::long_query_index(settings.notify_long_query_secs);
         let max_history = settings.max_history_entries.to_string();
+        let history_max_age = settings.history_max_age_days.to_string();
         let auto_save_interval = settings.auto_save_interval_ms.to_string();
         let refresh_interval = settings.default_refresh_interval_secs.to_string();
         let max_background_tasks = settings.max_concurrent_background_tasks.to_string();
 
+        let dropdown_language = cx.new(move |_cx| {
+            Dropdown::new("general-language")
+                .placeholder("Language")
+                .items(Self::language_items())
+                .selected_index(Some(language_index))
+        });
         let dropdown_theme = cx.new(move |_cx| {
             Dropdown::new("general-theme")
                 .placeholder("Theme")
-                .items(Self::theme_items())
+                .items(theme_items)
                 .selected_index(Some(theme_index))
         });
         let dropdown_style = cx.new(move |_cx| {
@@ -80,6 +264,140 @@ impl GeneralSection {
                 .items(Self::style_items())
                 .selected_index(Some(style_index))
         });
+        let dropdown_ui_zoom = cx.new(move |_cx| {
+            Dropdown::new("general-ui-zoom")
+                .placeholder("UI zoom")
+                .items(Self::ui_zoom_items())
+                .selected_index(Some(ui_zoom_index))
+        });
+        let dropdown_editor_font_size = cx.new(move |_cx| {
+            Dropdown::new("general-editor-font-size")
+                .placeholder("Editor font size")
+                .items(Self::font_size_items(
+                    MIN_EDITOR_FONT_SIZE..=MAX_EDITOR_FONT_SIZE,
+                ))
+                .selected_index(Some(editor_font_index))
+        });
+        let dropdown_grid_font_size = cx.new(move |_cx| {
+            Dropdown::new("general-grid-font-size")
+                .placeholder("Grid font size")
+                .items(Self::font_size_items(
+                    MIN_GRID_FONT_SIZE..=MAX_GRID_FONT_SIZE,
+                ))
+                .selected_index(Some(grid_font_index))
+        });
+        let dropdown_display_timezone = cx.new(move |_cx| {
+            Dropdown::new("general-display-timezone")
+                .placeholder("Display time zone")
+                .items(Self::display_timezone_items())
+                .selected_index(Some(display_timezone_index))
+        });
+        let null_marker_items: Vec<DropdownItem> = null_markers
+            .iter()
+            .map(|marker| DropdownItem::new(marker.clone()))
+            .collect();
+        let dropdown_null_marker = cx.new(move |_cx| {
+            Dropdown::new("general-null-marker")
+                .placeholder("NULL marker")
+                .items(null_marker_items)
+                .selected_index(Some(null_marker_index))
+        });
+        let dropdown_null_style = cx.new(move |_cx| {
+            Dropdown::new("general-null-style")
+                .placeholder("NULL style")
+                .items(Self::null_style_items())
+                .selected_index(Some(null_style_index))
+        });
+        let dropdown_thousands_separator = cx.new(|_cx| {
+            Dropdown::new("general-thousands-separator")
+                .placeholder("Thousands separator")
+                .items(
+                    separators
+                        .iter()
+                        .map(|option| DropdownItem::new(Self::separator_label(option)))
+                        .collect(),
+                )
+                .selected_index(
+                    separators
+                        .iter()
+                        .position(|option| *option == display_format.thousands_separator),
+                )
+        });
+        let dropdown_decimal_places = cx.new(|_cx| {
+            Dropdown::new("general-decimal-places")
+                .placeholder("Decimal places")
+                .items(
+                    decimal_places
+                        .iter()
+                        .map(|option| DropdownItem::new(Self::decimal_places_label(option)))
+                        .collect(),
+                )
+                .selected_index(
+                    decimal_places
+                        .iter()
+                        .position(|option| *option == display_format.decimal_places),
+                )
+        });
+        let dropdown_date_format = cx.new(|_cx| {
+            Dropdown::new("general-date-format")
+                .placeholder("Date format")
+                .items(
+                    date_formats
+                        .iter()
+                        .map(|option| DropdownItem::new(Self::date_format_label(option)))
+                        .collect(),
+                )
+                .selected_index(
+                    date_formats
+                        .iter()
+                        .position(|option| *option == display_format.date_format),
+                )
+        });
+        let dropdown_time_format = cx.new(|_cx| {
+            Dropdown::new("general-time-format")
+                .placeholder("Time format")
+                .items(
+                    time_formats
+                        .iter()
+                        .map(|option| DropdownItem::new(Self::time_format_label(option)))
+                        .collect(),
+                )
+                .selected_index(
+                    time_formats
+                        .iter()
+                        .position(|option| *option == display_format.time_format),
+                )
+        });
+        let dropdown_timestamp_format = cx.new(|_cx| {
+            Dropdown::new("general-timestamp-format")
+                .placeholder("Timestamp format")
+                .items(
+                    timestamp_formats
+                        .iter()
+                        .map(|option| DropdownItem::new(Self::timestamp_format_label(option)))
+                        .collect(),
+                )
+                .selected_index(
+                    timestamp_formats
+                        .iter()
+                        .position(|option| *option == display_format.timestamp_format),
+                )
+        });
+        let dropdown_scientific_threshold = cx.new(|_cx| {
+            Dropdown::new("general-scientific-threshold")
+                .placeholder("Scientific notation")
+                .items(
+                    scientific_thresholds
+                        .iter()
+                        .map(|option| DropdownItem::new(Self::scientific_threshold_label(option)))
+                        .collect(),
+                )
+                .selected_index(
+                    scientific_thresholds
+                        .iter()
+                        .position(|option| *option == display_format.scientific_threshold),
+                )
+        });
         let dropdown_default_focus = cx.new(move |_cx| {
             Dropdown::new("general-default-focus")
                 .placeholder("Default focus")
@@ -92,12 +410,29 @@ impl GeneralSection {
                 .items(Self::refresh_policy_items())
                 .selected_index(Some(refresh_policy_index))
         });
+        let dropdown_schema_refresh = cx.new(move |_cx| {
+            Dropdown::new("general-schema-refresh")
+                .placeholder("Schema refresh")
+                .items(Self::schema_refresh_items())
+                .selected_index(Some(schema_refresh_index))
+        });
+        let dropdown_notify_long_query = cx.new(move |_cx| {
+            Dropdown::new("general-notify-long-query")
+                .placeholder("Long query notification")
+                .items(Self::long_query_items())
+                .selected_index(Some(long_query_index))
+        });
 
         let input_max_history = cx.new(|cx| {
             InputState::new(window, cx)
                 .placeholder("1000")
                 .default_value(max_history.clone())
         });
+        let input_history_max_age = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("0")
+                .default_value(history_max_age.clone())
+        });
         let input_auto_save = cx.new(|cx| {
             InputState::new(window, cx)
                 .placeholder("2000")
@@ -114,10 +449,46 @@ impl GeneralSection {
                 .default_value(max_background_tasks.clone())
         });
 
-        let theme_subscription = cx.subscribe(
+        let theme_subscription = cx.subscribe_in(
             &dropdown_theme,
+            window,
+            |this, _, event: &DropdownSelectionChanged, window, cx| {
+                match Self::theme_for_index(event.index, &this.user_theme_names) {
+                    ThemeChoice::Builtin(theme) => {
+                        this.gen_settings.theme = theme;
+                        this.gen_settings.custom_theme = None;
+                    }
+                    ThemeChoice::Custom(name) => {
+                        // Storage keeps only the user theme's name, which
+                        // loads back with the Dark fallback.
+                        this.gen_settings.theme = ThemeSetting::Dark;
+                        this.gen_settings.custom_theme = Some(name);
+                    }
+                }
+
+                // Preview right away; leaving without saving restores the
+                // saved theme.
+                apply_configured_theme(&this.gen_settings, Some(window), cx);
+                cx.refresh_windows();
+                cx.notify();
+            },
+        );
+
+        cx.on_release(|this, cx| {
+            let saved = this.app_state.read(cx).general_settings().clone();
+            if this.gen_settings.theme != saved.theme
+                || this.gen_settings.custom_theme != saved.custom_theme
+            {
+                apply_configured_theme(&saved, None, cx);
+                cx.refresh_windows();
+            }
+        })
+        .detach();
+
+        let language_subscription = cx.subscribe(
+            &dropdown_language,
             |this, _, event: &DropdownSelectionChanged, cx| {
-                this.gen_settings.theme = Self::theme_for_index(event.index);
+                this.gen_settings.language = Self::language_for_index(event.index);
                 cx.notify();
             },
         );
@@ -130,6 +501,125 @@ impl GeneralSection {
             },
         );
 
+        let ui_zoom_subscription = cx.subscribe(
+            &dropdown_ui_zoom,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.ui_zoom_percent = Self::ui_zoom_for_index(event.index);
+                cx.notify();
+            },
+        );
+
+        let editor_font_subscription = cx.subscribe(
+            &dropdown_editor_font_size,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.editor_font_size = Self::font_size_for_index(
+                    event.index,
+                    MIN_EDITOR_FONT_SIZE..=MAX_EDITOR_FONT_SIZE,
+                    DEFAULT_EDITOR_FONT_SIZE,
+                );
+                cx.notify();
+            },
+        );
+
+        let grid_font_subscription = cx.subscribe(
+            &dropdown_grid_font_size,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.grid_font_size = Self::font_size_for_index(
+                    event.index,
+                    MIN_GRID_FONT_SIZE..=MAX_GRID_FONT_SIZE,
+                    DEFAULT_GRID_FONT_SIZE,
+                );
+                cx.notify();
+            },
+        );
+
+        let display_timezone_subscription = cx.subscribe(
+            &dropdown_display_timezone,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.display_timezone = Self::display_timezone_for_index(event.index);
+                cx.notify();
+            },
+        );
+
+        let null_marker_subscription = cx.subscribe(
+            &dropdown_null_marker,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.null_marker = null_markers
+                    .get(event.index)
+                    .cloned()
+                    .unwrap_or_else(|| DEFAULT_NULL_MARKER.to_string());
+                cx.notify();
+            },
+        );
+
+        let null_style_subscription = cx.subscribe(
+            &dropdown_null_style,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.null_style = Self::null_style_for_index(event.index);
+                cx.notify();
+            },
+        );
+
+        let thousands_separator_subscription = cx.subscribe(
+            &dropdown_thousands_separator,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                if let Some(option) = separators.get(event.index) {
+                    this.gen_settings.display_format.thousands_separator = *option;
+                    cx.notify();
+                }
+            },
+        );
+
+        let decimal_places_subscription = cx.subscribe(
+            &dropdown_decimal_places,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                if let Some(option) = decimal_places.get(event.index) {
+                    this.gen_settings.display_format.decimal_places = *option;
+                    cx.notify();
+                }
+            },
+        );
+
+        let date_format_subscription = cx.subscribe(
+            &dropdown_date_format,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                if let Some(option) = date_formats.get(event.index) {
+                    this.gen_settings.display_format.date_format = option.clone();
+                    cx.notify();
+                }
+            },
+        );
+
+        let time_format_subscription = cx.subscribe(
+            &dropdown_time_format,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                if let Some(option) = time_formats.get(event.index) {
+                    this.gen_settings.display_format.time_format = option.clone();
+                    cx.notify();
+                }
+            },
+        );
+
+        let timestamp_format_subscription = cx.subscribe(
+            &dropdown_timestamp_format,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                if let Some(option) = timestamp_formats.get(event.index) {
+                    this.gen_settings.display_format.timestamp_format = option.clone();
+                    cx.notify();
+                }
+            },
+        );
+
+        let scientific_threshold_subscription = cx.subscribe(
+            &dropdown_scientific_threshold,
+            move |this, _, event: &DropdownSelectionChanged, cx| {
+                if let Some(option) = scientific_thresholds.get(event.index) {
+                    this.gen_settings.display_format.scientific_threshold = *option;
+                    cx.notify();
+                }
+            },
+        );
+
         let focus_subscription = cx.subscribe(
             &dropdown_default_focus,
             |this, _, event: &DropdownSelectionChanged, cx| {
@@ -148,6 +638,23 @@ impl GeneralSection {
             },
         );
 
+        let schema_refresh_subscription = cx.subscribe(
+            &dropdown_schema_refresh,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.schema_refresh_interval_secs =
+                    Self::schema_refresh_for_index(event.index);
+                cx.notify();
+            },
+        );
+
+        let long_query_subscription = cx.subscribe(
+            &dropdown_notify_long_query,
+            |this, _, event: &DropdownSelectionChanged, cx| {
+                this.gen_settings.notify_long_query_secs = Self::long_query_for_index(event.index);
+                cx.notify();
+            },
+        );
+
         let blur_max_history =
             cx.subscribe(&input_max_history, |this, _, event: &InputEvent, cx| {
                 if matches!(event, InputEvent::Blur) {
@@ -159,6 +666,17 @@ impl GeneralSection {
                 }
             });
 
+        let blur_history_max_age =
+            cx.subscribe(&input_history_max_age, |this, _, event: &InputEvent, cx| {
+                if matches!(event, InputEvent::Blur) {
+                    if this.switching_input {
+                        this.switching_input = false;
+                        return;
+                    }
+                    cx.emit(SectionFocusEvent::RequestFocusReturn);
+                }
+            });
+
         let blur_auto_save = cx.subscribe(&input_auto_save, |this, _, event: &InputEvent, cx| {
             if matches!(event, InputEvent::Blur) {
                 if this.switching_input {
@@ -199,22 +717,56 @@ impl GeneralSection {
             gen_form_cursor: 0,
             gen_editing_field: false,
             gen_share_stable_db: dbflux_storage::paths::nightly_shares_stable_db(),
+            user_theme_names,
+            user_themes_dir: user_themes_dir().map(|dir| dir.display().to_string()),
+            dropdown_language,
             dropdown_theme,
             dropdown_style,
+            dropdown_ui_zoom,
+            dropdown_editor_font_size,
+            dropdown_grid_font_size,
+            dropdown_display_timezone,
+            dropdown_null_marker,
+            dropdown_null_style,
+            dropdown_thousands_separator,
+            dropdown_decimal_places,
+            dropdown_date_format,
+            dropdown_time_format,
+            dropdown_timestamp_format,
+            dropdown_scientific_threshold,
             dropdown_default_focus,
             dropdown_refresh_policy,
+            dropdown_schema_refresh,
+            dropdown_notify_long_query,
             input_max_history,
+            input_history_max_age,
             input_auto_save,
             input_refresh_interval,
             input_max_bg_tasks,
             content_focused: false,
             switching_input: false,
             _subscriptions: vec![
+                language_subscription,
                 theme_subscription,
                 style_subscription,
+                ui_zoom_subscription,
+                editor_font_subscription,
+                grid_font_subscription,
+                display_timezone_subscription,
+                null_marker_subscription,
+                null_style_subscription,
+                thousands_separator_subscription,
+                decimal_places_subscription,
+                date_format_subscription,
+                time_format_subscription,
+                timestamp_format_subscription,
+                scientific_threshold_subscription,
                 focus_subscription,
                 refresh_policy_subscription,
+                schema_refresh_subscription,
+                long_query_subscription,
                 blur_max_history,
+                blur_history_max_age,
                 blur_auto_save,
                 blur_refresh_interval,
                 blur_max_bg_tasks,
@@ -222,12 +774,56 @@ impl GeneralSection {
         }
     }
 
-    fn theme_items() -> Vec<DropdownItem> {
-        vec![
-            DropdownItem::new("Ayu Dark"),
-            DropdownItem::new("Ayu Mirage"),
-            DropdownItem::new("Ayu Light"),
-        ]
+    /// Languages by their own names, so each is findable whatever the
+    /// current one is.
+    fn language_items() -> Vec<DropdownItem> {
+        Language::ALL
+            .into_iter()
+            .map(|language| DropdownItem::new(language.native_name()))
+            .collect()
+    }
+
+    fn language_index(language: Language) -> usize {
+        Language::ALL
+            .iter()
+            .position(|candidate| *candidate == language)
+            .unwrap_or(0)
+    }
+
+    fn language_for_index(index: usize) -> Language {
+        Language::ALL.get(index).copied().unwrap_or_default()
+    }
+
+    fn theme_items(user_themes: &[String]) -> Vec<DropdownItem> {
+        BUILTIN_THEMES
+            .iter()
+            .map(|(_, label)| DropdownItem::new(*label))
+            .chain(
+                user_themes
+                    .iter()
+                    .map(|name| DropdownItem::new(name.clone())),
+            )
+            .collect()
+    }
+
+    /// Picks up themes added, renamed or removed while the page is open.
+    pub(super) fn sync_user_themes(&mut self, cx: &mut Context<Self>) {
+        let names = user_theme_catalog(cx).names();
+        if names == self.user_theme_names {
+            return;
+        }
+
+        let index = Self::theme_index(
+            self.gen_settings.theme,
+            self.gen_settings.custom_theme.as_deref(),
+            &names,
+        );
+        let items = Self::theme_items(&names);
+        self.dropdown_theme.update(cx, |dropdown, cx| {
+            dropdown.set_items(items, cx);
+            dropdown.set_selected_index(Some(index), cx);
+        });
+        self.user_theme_names = names;
     }
 
     fn style_items() -> Vec<DropdownItem> {
@@ -237,6 +833,47 @@ impl GeneralSection {
         ]
     }
 
+    /// Zoom levels offered in the dropdown, one zoom step apart.
+    fn ui_zoom_levels() -> impl Iterator<Item = u16> {
+        (MIN_UI_ZOOM_PERCENT..=MAX_UI_ZOOM_PERCENT).step_by(usize::from(UI_ZOOM_STEP_PERCENT))
+    }
+
+    fn ui_zoom_items() -> Vec<DropdownItem> {
+        Self::ui_zoom_levels()
+            .map(|percent| DropdownItem::new(format!("{percent}%")))
+            .collect()
+    }
+
+    /// Index of `percent`; levels between steps fall back to 100%.
+    fn ui_zoom_index(percent: u16) -> usize {
+        Self::ui_zoom_levels()
+            .position(|level| level == percent)
+            .or_else(|| Self::ui_zoom_levels().position(|level| level == DEFAULT_UI_ZOOM_PERCENT))
+            .unwrap_or(0)
+    }
+
+    fn ui_zoom_for_index(index: usize) -> u16 {
+        Self::ui_zoom_levels()
+            .nth(index)
+            .unwrap_or(DEFAULT_UI_ZOOM_PERCENT)
+    }
+
+    fn font_size_items(sizes: RangeInclusive<u16>) -> Vec<DropdownItem> {
+        sizes
+            .map(|size| DropdownItem::new(format!("{size} px")))
+            .collect()
+    }
+
+    /// Index of `size` in `sizes`; sizes outside it fall back to `default`.
+    fn font_size_index(size: u16, sizes: RangeInclusive<u16>, default: u16) -> usize {
+        let size = if sizes.contains(&size) { size } else { default };
+        usize::from(size - sizes.start())
+    }
+
+    fn font_size_for_index(index: usize, sizes: RangeInclusive<u16>, default: u16) -> u16 {
+        sizes.clone().nth(index).unwrap_or(default)
+    }
+
     fn startup_focus_items() -> Vec<DropdownItem> {
         vec![DropdownItem::new("Sidebar"), DropdownItem::new("Last Tab")]
     }
@@ -245,19 +882,161 @@ impl GeneralSection {
         vec![DropdownItem::new("Manual"), DropdownItem::new("Interval")]
     }
 
-    fn theme_index(theme: ThemeSetting) -> usize {
-        match theme {
-            ThemeSetting::Dark => 0,
-            ThemeSetting::Mirage => 1,
-            ThemeSetting::Light => 2,
+    fn display_timezones() -> impl Iterator<Item = DisplayTimezone> {
+        [DisplayTimezone::Utc, DisplayTimezone::Local]
+            .into_iter()
+            .chain(TIMEZONE_OFFSET_MINUTES.iter().filter_map(|minutes| {
+                FixedOffset::east_opt(minutes * 60).map(DisplayTimezone::Offset)
+            }))
+    }
+
+    fn display_timezone_items() -> Vec<DropdownItem> {
+        Self::display_timezones()
+            .map(|timezone| DropdownItem::new(timezone.label()))
+            .collect()
+    }
+
+    /// Index of `timezone`; unlisted offsets fall back to UTC.
+    fn display_timezone_index(timezone: DisplayTimezone) -> usize {
+        Self::display_timezones()
+            .position(|candidate| candidate == timezone)
+            .unwrap_or(0)
+    }
+
+    fn display_timezone_for_index(index: usize) -> DisplayTimezone {
+        Self::display_timezones().nth(index).unwrap_or_default()
+    }
+
+    /// The preset markers, plus `current` when it was set outside the list.
+    fn null_markers(current: &str) -> Vec<String> {
+        let mut markers: Vec<String> = NULL_MARKERS.iter().map(|m| m.to_string()).collect();
+        if !markers.iter().any(|marker| marker == current) {
+            markers.push(current.to_string());
         }
+        markers
     }
 
-    fn theme_for_index(index: usize) -> ThemeSetting {
-        match index {
-            1 => ThemeSetting::Mirage,
-            2 => ThemeSetting::Light,
-            _ => ThemeSetting::Dark,
+    fn null_style_items() -> Vec<DropdownItem> {
+        NullStyle::ALL
+            .into_iter()
+            .map(|style| DropdownItem::new(style.label()))
+            .collect()
+    }
+
+    fn null_style_index(style: NullStyle) -> usize {
+        NullStyle::ALL
+            .iter()
+            .position(|candidate| *candidate == style)
+            .unwrap_or(0)
+    }
+
+    fn null_style_for_index(index: usize) -> NullStyle {
+        NullStyle::ALL.get(index).copied().unwrap_or_default()
+    }
+
+    /// The presets, plus `current` when it was set outside the list.
+    fn with_current<T: PartialEq>(mut options: Vec<T>, current: T) -> Vec<T> {
+        if !options.contains(&current) {
+            options.push(current);
+        }
+        options
+    }
+
+    /// Sample moment the date and time format labels are rendered with.
+    fn format_sample() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 1)
+            .and_then(|date| date.and_hms_milli_opt(13, 45, 30, 250))
+            .unwrap_or_default()
+    }
+
+    fn separator_label(separator: &Option<char>) -> String {
+        match separator {
+            None => "None (1234567)".to_string(),
+            Some(_) => DisplayFormat {
+                thousands_separator: *separator,
+                ..DisplayFormat::default()
+            }
+            .format_int(1_234_567),
+        }
+    }
+
+    fn decimal_places_label(places: &Option<u8>) -> String {
+        match places {
+            None => "As stored".to_string(),
+            Some(places) => {
+                let sample = DisplayFormat {
+                    decimal_places: Some(*places),
+                    ..DisplayFormat::default()
+                }
+                .format_float(1234.5678);
+                format!("{places} ({sample})")
+            }
+        }
+    }
+
+    fn date_format_label(pattern: &str) -> String {
+        let sample = DisplayFormat {
+            date_format: pattern.to_string(),
+            ..DisplayFormat::default()
+        }
+        .format_date(&Self::format_sample().date());
+        format!("{sample} ({pattern})")
+    }
+
+    fn time_format_label(pattern: &str) -> String {
+        let sample = DisplayFormat {
+            time_format: pattern.to_string(),
+            ..DisplayFormat::default()
+        }
+        .format_time(&Self::format_sample().time());
+        format!("{sample} ({pattern})")
+    }
+
+    fn timestamp_format_label(pattern: &str) -> String {
+        let sample = DisplayFormat {
+            timestamp_format: pattern.to_string(),
+            ..DisplayFormat::default()
+        }
+        .format_datetime(&Self::format_sample());
+        format!("{sample} ({pattern})")
+    }
+
+    fn scientific_threshold_label(threshold: &Option<u8>) -> String {
+        match threshold {
+            None => "Never".to_string(),
+            Some(exponent) => format!("From 1e{exponent}"),
+        }
+    }
+
+    fn schema_refresh_items() -> Vec<DropdownItem> {
+        SCHEMA_REFRESH_INTERVALS
+            .iter()
+            .map(|(_, label)| DropdownItem::new(*label))
+            .collect()
+    }
+
+    /// Dropdown index of the selected theme. A user theme that is not
+    /// loaded shows the built-in it falls back to.
+    fn theme_index(theme: ThemeSetting, custom: Option<&str>, user_themes: &[String]) -> usize {
+        let custom_index = custom.and_then(|name| user_themes.iter().position(|n| n == name));
+
+        match custom_index {
+            Some(position) => BUILTIN_THEMES.len() + position,
+            None => BUILTIN_THEMES
+                .iter()
+                .position(|(setting, _)| *setting == theme)
+                .unwrap_or(0),
+        }
+    }
+
+    fn theme_for_index(index: usize, user_themes: &[String]) -> ThemeChoice {
+        if let Some((setting, _)) = BUILTIN_THEMES.get(index) {
+            return ThemeChoice::Builtin(*setting);
+        }
+
+        match user_themes.get(index - BUILTIN_THEMES.len()) {
+            Some(name) => ThemeChoice::Custom(name.clone()),
+            None => ThemeChoice::Builtin(ThemeSetting::Dark),
         }
     }
 
@@ -302,6 +1081,40 @@ impl GeneralSection {
             _ => RefreshPolicySetting::Manual,
         }
     }
+
+    fn schema_refresh_index(interval_secs: u32) -> usize {
+        SCHEMA_REFRESH_INTERVALS
+            .iter()
+            .position(|(secs, _)| *secs == interval_secs)
+            .unwrap_or(0)
+    }
+
+    fn schema_refresh_for_index(index: usize) -> u32 {
+        SCHEMA_REFRESH_INTERVALS
+            .get(index)
+            .map_or(0, |(secs, _)| *secs)
+    }
+
+    fn long_query_items() -> Vec<DropdownItem> {
+        LONG_QUERY_THRESHOLDS
+            .iter()
+            .map(|(_, label)| DropdownItem::new(*label))
+            .collect()
+    }
+
+    /// Index of `threshold_secs`; unlisted values fall back to 30 seconds.
+    fn long_query_index(threshold_secs: u32) -> usize {
+        LONG_QUERY_THRESHOLDS
+            .iter()
+            .position(|(secs, _)| *secs == threshold_secs)
+            .unwrap_or(2)
+    }
+
+    fn long_query_for_index(index: usize) -> u32 {
+        LONG_QUERY_THRESHOLDS
+            .get(index)
+            .map_or(30, |(secs, _)| *secs)
+    }
 }
 
 impl SettingsSection for GeneralSection {
@@ -345,35 +1158,167 @@ impl SettingsSection for GeneralSection {
 
 impl Render for GeneralSection {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.sync_user_themes(cx);
         self.render_general_section(cx)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::GeneralSection;
+    use super::{GeneralSection, ThemeChoice};
+    use dbflux_core::i18n::Language;
+    use dbflux_core::null_display::NullStyle;
+    use dbflux_core::timezone::DisplayTimezone;
     use dbflux_core::{AppStyle, ThemeSetting};
 
     #[test]
-    fn theme_dropdown_exposes_exactly_three_ayu_labels() {
-        let labels: Vec<_> = GeneralSection::theme_items()
+    fn language_dropdown_lists_native_names_and_round_trips() {
+        let labels: Vec<_> = GeneralSection::language_items()
             .into_iter()
             .map(|item| item.label)
             .collect();
+        assert_eq!(labels, vec!["English", "Español"]);
 
-        assert_eq!(labels, vec!["Ayu Dark", "Ayu Mirage", "Ayu Light"]);
+        for language in Language::ALL {
+            let index = GeneralSection::language_index(language);
+            assert_eq!(GeneralSection::language_for_index(index), language);
+        }
+        assert_eq!(GeneralSection::language_for_index(99), Language::English);
     }
 
     #[test]
-    fn theme_index_and_reverse_mapping_cover_all_supported_ayu_themes() {
-        assert_eq!(GeneralSection::theme_index(ThemeSetting::Dark), 0);
-        assert_eq!(GeneralSection::theme_index(ThemeSetting::Mirage), 1);
-        assert_eq!(GeneralSection::theme_index(ThemeSetting::Light), 2);
+    fn display_timezone_dropdown_lists_utc_local_then_offsets() {
+        let labels: Vec<_> = GeneralSection::display_timezone_items()
+            .into_iter()
+            .map(|item| item.label)
+            .collect();
+        assert_eq!(
+            labels.iter().take(3).cloned().collect::<Vec<_>>(),
+            vec!["UTC", "Local time", "UTC-12:00"]
+        );
+        assert!(labels.iter().any(|label| label == "UTC+05:30"));
+        assert_eq!(labels.last().map(|label| label.as_ref()), Some("UTC+14:00"));
+
+        for timezone in GeneralSection::display_timezones() {
+            let index = GeneralSection::display_timezone_index(timezone);
+            assert_eq!(GeneralSection::display_timezone_for_index(index), timezone);
+        }
+
+        let unlisted = DisplayTimezone::from_code("+02:15").expect("valid offset");
+        assert_eq!(GeneralSection::display_timezone_index(unlisted), 0);
+        assert_eq!(
+            GeneralSection::display_timezone_for_index(999),
+            DisplayTimezone::Utc
+        );
+    }
+
+    #[test]
+    fn null_marker_and_style_dropdowns_keep_custom_values() {
+        assert_eq!(GeneralSection::null_markers("NULL").len(), 5);
+        assert_eq!(
+            GeneralSection::null_markers("n/a")
+                .last()
+                .map(String::as_str),
+            Some("n/a")
+        );
+
+        for style in NullStyle::ALL {
+            let index = GeneralSection::null_style_index(style);
+            assert_eq!(GeneralSection::null_style_for_index(index), style);
+        }
+        assert_eq!(GeneralSection::null_style_for_index(99), NullStyle::Muted);
+    }
+
+    #[test]
+    fn display_format_dropdowns_label_samples_and_keep_custom_values() {
+        assert_eq!(
+            GeneralSection::with_current(vec![None, Some(2)], Some(7)),
+            vec![None, Some(2), Some(7)]
+        );
+        assert_eq!(
+            GeneralSection::with_current(vec![None, Some(2)], Some(2)),
+            vec![None, Some(2)]
+        );
+
+        assert_eq!(GeneralSection::separator_label(&Some('.')), "1.234.567");
+        assert_eq!(
+            GeneralSection::decimal_places_label(&Some(2)),
+            "2 (1234.57)"
+        );
+        assert_eq!(
+            GeneralSection::date_format_label("%d/%m/%Y"),
+            "01/03/2026 (%d/%m/%Y)"
+        );
+        assert_eq!(
+            GeneralSection::time_format_label("%I:%M:%S %p"),
+            "01:45:30 PM (%I:%M:%S %p)"
+        );
+        assert_eq!(
+            GeneralSection::scientific_threshold_label(&Some(6)),
+            "From 1e6"
+        );
+    }
+
+    #[test]
+    fn theme_dropdown_lists_builtins_then_user_themes() {
+        let labels: Vec<_> = GeneralSection::theme_items(&["Solarized".to_string()])
+            .into_iter()
+            .map(|item| item.label)
+            .collect();
+
+        assert_eq!(
+            labels,
+            vec![
+                "Ayu Dark",
+                "Ayu Mirage",
+                "Ayu Light",
+                "Follow System",
+                "Solarized"
+            ]
+        );
+    }
+
+    #[test]
+    fn theme_index_and_reverse_mapping_cover_builtin_themes() {
+        let builtins = [
+            ThemeSetting::Dark,
+            ThemeSetting::Mirage,
+            ThemeSetting::Light,
+            ThemeSetting::System,
+        ];
+
+        for (index, theme) in builtins.into_iter().enumerate() {
+            assert_eq!(GeneralSection::theme_index(theme, None, &[]), index);
+            assert_eq!(
+                GeneralSection::theme_for_index(index, &[]),
+                ThemeChoice::Builtin(theme)
+            );
+        }
 
-        assert_eq!(GeneralSection::theme_for_index(0), ThemeSetting::Dark);
-        assert_eq!(GeneralSection::theme_for_index(1), ThemeSetting::Mirage);
-        assert_eq!(GeneralSection::theme_for_index(2), ThemeSetting::Light);
-        assert_eq!(GeneralSection::theme_for_index(99), ThemeSetting::Dark);
+        assert_eq!(
+            GeneralSection::theme_for_index(99, &[]),
+            ThemeChoice::Builtin(ThemeSetting::Dark)
+        );
+    }
+
+    #[test]
+    fn theme_index_maps_user_themes_and_falls_back_when_missing() {
+        let user_themes = vec!["Nord".to_string(), "Solarized".to_string()];
+
+        assert_eq!(
+            GeneralSection::theme_index(ThemeSetting::Dark, Some("Solarized"), &user_themes),
+            5
+        );
+        assert_eq!(
+            GeneralSection::theme_for_index(5, &user_themes),
+            ThemeChoice::Custom("Solarized".to_string())
+        );
+
+        // A deleted theme shows the built-in it falls back to.
+        assert_eq!(
+            GeneralSection::theme_index(ThemeSetting::Light, Some("Gone"), &user_themes),
+            2
+        );
     }
 
     #[test]
@@ -396,4 +1341,65 @@ mod tests {
         // Out-of-range falls back to Default
         assert_eq!(GeneralSection::style_for_index(99), AppStyle::Default);
     }
+
+    #[test]
+    fn ui_zoom_index_round_trips_and_falls_back_to_default() {
+        let labels: Vec<_> = GeneralSection::ui_zoom_items()
+            .into_iter()
+            .map(|item| item.label)
+            .collect();
+        assert_eq!(labels[0], "50%");
+        assert_eq!(labels[labels.len() - 1], "200%");
+
+        for index in 0..labels.len() {
+            let percent = GeneralSection::ui_zoom_for_index(index);
+            assert_eq!(GeneralSection::ui_zoom_index(percent), index);
+        }
+
+        assert_eq!(GeneralSection::ui_zoom_for_index(99), 100);
+        assert_eq!(
+            GeneralSection::ui_zoom_for_index(GeneralSection::ui_zoom_index(105)),
+            100
+        );
+    }
+
+    #[test]
+    fn font_size_index_round_trips_and_falls_back_to_default() {
+        let sizes = 10..=24;
+
+        for index in 0..GeneralSection::font_size_items(sizes.clone()).len() {
+            let size = GeneralSection::font_size_for_index(index, sizes.clone(), 13);
+            assert_eq!(
+                GeneralSection::font_size_index(size, sizes.clone(), 13),
+                index
+            );
+        }
+
+        assert_eq!(GeneralSection::font_size_index(40, sizes.clone(), 13), 3);
+        assert_eq!(GeneralSection::font_size_for_index(99, sizes, 13), 13);
+    }
+
+    #[test]
+    fn schema_refresh_index_round_trips_and_falls_back_to_manual() {
+        for index in 0..GeneralSection::schema_refresh_items().len() {
+            let secs = GeneralSection::schema_refresh_for_index(index);
+            assert_eq!(GeneralSection::schema_refresh_index(secs), index);
+        }
+
+        assert_eq!(GeneralSection::schema_refresh_for_index(0), 0);
+        assert_eq!(GeneralSection::schema_refresh_for_index(99), 0);
+        assert_eq!(GeneralSection::schema_refresh_index(42), 0);
+    }
+
+    #[test]
+    fn long_query_index_round_trips_and_falls_back_to_default() {
+        for index in 0..GeneralSection::long_query_items().len() {
+            let secs = GeneralSection::long_query_for_index(index);
+            assert_eq!(GeneralSection::long_query_index(secs), index);
+        }
+
+        assert_eq!(GeneralSection::long_query_for_index(0), 0);
+        assert_eq!(GeneralSection::long_query_for_index(99), 30);
+        assert_eq!(GeneralSection::long_query_index(42), 2);
+    }
 }
@@ -4,7 +4,7 @@ use dbflux_components::icons::AppIcon;
 use dbflux_components::primitives::{BannerBlock, BannerVariant, Chord, Icon as FluxIcon};
 use dbflux_components::tokens::{Heights, Radii, Spacing};
 use dbflux_components::typography::{Body, FieldLabel, MonoCaption};
-use dbflux_ui_base::keymap::default_keymap;
+use dbflux_ui_base::keymap::{USER_KEYMAP_FILE, default_keymap, user_keymap_report};
 use gpui::prelude::*;
 use gpui::*;
 use gpui_component::ActiveTheme;
@@ -160,6 +160,7 @@ impl KeybindingsSection {
                         ),
                 ),
             )
+            .child(Self::render_user_keymap_report(border))
             .child(
                 div()
                     .id("keybindings-scroll-container")
@@ -368,6 +369,53 @@ impl KeybindingsSection {
         parts
     }
 
+    /// Where the user keymap file lives and what went wrong loading it.
+    fn render_user_keymap_report(border: Hsla) -> impl IntoElement {
+        let report = user_keymap_report();
+        let path = report
+            .path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| USER_KEYMAP_FILE.to_string());
+
+        let status = if report.found {
+            format!("Custom bindings loaded from {path}; edits apply after a restart")
+        } else {
+            format!("Custom bindings: create {path} to override these shortcuts")
+        };
+
+        let errors = (!report.errors.is_empty()).then(|| {
+            BannerBlock::new(
+                BannerVariant::Danger,
+                format!("{} keymap entries could not be loaded", report.errors.len()),
+            )
+            .with_pre(report.errors.join("\n"))
+        });
+
+        let conflicts = (!report.conflicts.is_empty()).then(|| {
+            let lines: Vec<String> = report.conflicts.iter().map(|c| c.describe()).collect();
+
+            BannerBlock::new(
+                BannerVariant::Warning,
+                format!("{} keymap conflicts", report.conflicts.len()),
+            )
+            .with_body("Custom bindings that replace or hide other bindings.")
+            .with_pre(lines.join("\n"))
+        });
+
+        div()
+            .px_4()
+            .py_2()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .border_b_1()
+            .border_color(border)
+            .child(MonoCaption::new(status))
+            .children(errors)
+            .children(conflicts)
+    }
+
     fn render_conflict_warning(chord: &KeyChord, others: &[String]) -> impl IntoElement {
         let other_list = if others.is_empty() {
             "another binding".to_string()
@@ -227,6 +227,10 @@ impl SettingsCoordinator {
                 let section = cx.new(|cx| ServicesSection::new(app_state, window, cx));
                 (ActiveSettingsSection::Services(section), vec![])
             }
+            SettingsSectionId::ExternalDrivers => {
+                let section = cx.new(|cx| ExternalDriversSection::new(app_state, cx));
+                (ActiveSettingsSection::ExternalDrivers(section), vec![])
+            }
             SettingsSectionId::Hooks => {
                 let section = cx.new(|cx| HooksSection::new(app_state, window, cx));
                 let subscription = cx.subscribe(&section, |this, _, event: &SettingsEvent, cx| {
@@ -3,6 +3,7 @@ mod audit_section;
 mod auth_profiles_section;
 mod drivers;
 mod drivers_section;
+mod external_drivers_section;
 mod form_nav;
 mod form_section;
 mod general;
@@ -38,6 +39,7 @@ use dbflux_components::components::tree_nav::TreeNav;
 use dbflux_components::tokens::Spacing;
 use dbflux_ui_base::AppStateEntity;
 use drivers_section::DriversSection;
+use external_drivers_section::ExternalDriversSection;
 use general_section::GeneralSection;
 use gpui::prelude::*;
 use gpui::*;
@@ -70,6 +72,7 @@ enum ActiveSettingsSection {
     Audit(Entity<AuditSection>),
     AuthProfiles(Entity<AuthProfilesSection>),
     Drivers(Entity<DriversSection>),
+    ExternalDrivers(Entity<ExternalDriversSection>),
     General(Entity<GeneralSection>),
     Hooks(Entity<HooksSection>),
     Keybindings(Entity<KeybindingsSection>),
@@ -91,6 +94,7 @@ impl ActiveSettingsSection {
             Self::Audit(section) => AnyView::from(section.clone()),
             Self::AuthProfiles(section) => AnyView::from(section.clone()),
             Self::Drivers(section) => AnyView::from(section.clone()),
+            Self::ExternalDrivers(section) => AnyView::from(section.clone()),
             Self::General(section) => AnyView::from(section.clone()),
             Self::Hooks(section) => AnyView::from(section.clone()),
             Self::Keybindings(section) => AnyView::from(section.clone()),
@@ -131,6 +135,11 @@ impl ActiveSettingsSection {
                     section.handle_key_event(event, window, cx)
                 });
             }
+            Self::ExternalDrivers(section) => {
+                section.update(cx, |section, cx| {
+                    section.handle_key_event(event, window, cx)
+                });
+            }
             Self::General(section) => {
                 section.update(cx, |section, cx| {
                     section.handle_key_event(event, window, cx)
@@ -184,6 +193,9 @@ impl ActiveSettingsSection {
             Self::Drivers(section) => {
                 section.update(cx, |section, cx| section.focus_in(window, cx));
             }
+            Self::ExternalDrivers(section) => {
+                section.update(cx, |section, cx| section.focus_in(window, cx));
+            }
             Self::General(section) => {
                 section.update(cx, |section, cx| section.focus_in(window, cx));
             }
@@ -223,6 +235,9 @@ impl ActiveSettingsSection {
             Self::Drivers(section) => {
                 section.update(cx, |section, cx| section.focus_out(window, cx));
             }
+            Self::ExternalDrivers(section) => {
+                section.update(cx, |section, cx| section.focus_out(window, cx));
+            }
             Self::General(section) => {
                 section.update(cx, |section, cx| section.focus_out(window, cx));
             }
@@ -254,6 +269,7 @@ impl ActiveSettingsSection {
             Self::Audit(section) => section.read(cx).is_dirty(cx),
             Self::AuthProfiles(section) => section.read(cx).is_dirty(cx),
             Self::Drivers(section) => section.read(cx).is_dirty(cx),
+            Self::ExternalDrivers(section) => section.read(cx).is_dirty(cx),
             Self::General(section) => section.read(cx).is_dirty(cx),
             Self::Hooks(section) => section.read(cx).is_dirty(cx),
             Self::Keybindings(section) => section.read(cx).is_dirty(cx),
@@ -285,6 +301,9 @@ impl ActiveSettingsSection {
             Self::Drivers(section) => {
                 section.update(cx, |section, cx| section.render_footer_actions(window, cx))
             }
+            Self::ExternalDrivers(section) => {
+                section.update(cx, |section, cx| section.render_footer_actions(window, cx))
+            }
             Self::General(section) => {
                 section.update(cx, |section, cx| section.render_footer_actions(window, cx))
             }
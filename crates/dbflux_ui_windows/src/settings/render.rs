@@ -46,6 +46,7 @@ impl SettingsCoordinator {
             super::SettingsSectionId::SshTunnels => "SSH Tunnels",
             super::SettingsSectionId::AuthProfiles => "Auth Profiles",
             super::SettingsSectionId::Services => "RPC Services",
+            super::SettingsSectionId::ExternalDrivers => "External Drivers",
             super::SettingsSectionId::Hooks => "Hooks",
             super::SettingsSectionId::Drivers => "Drivers",
             super::SettingsSectionId::About => "About",
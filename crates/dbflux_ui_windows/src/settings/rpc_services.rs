@@ -3,7 +3,7 @@ use dbflux_components::icons::AppIcon;
 use dbflux_components::primitives::{Icon as PrimitiveIcon, Label};
 use dbflux_components::tokens::{Heights, Radii};
 use dbflux_components::typography::{Body, MonoCaption, MonoLabel, MonoMeta, PanelTitle};
-use dbflux_core::{RpcServiceKind, ServiceConfig};
+use dbflux_core::{RemoteServiceConfig, RpcServiceKind, ServiceConfig};
 use dbflux_storage::bootstrap::StorageRuntime;
 use dbflux_ui_base::toast::{Toast, copy_action, now_hms};
 use dbflux_ui_base::user_error::{ErrorKind, UserFacingError, report_error};
@@ -24,7 +24,17 @@ fn services_section_title() -> &'static str {
 }
 
 fn services_section_description() -> &'static str {
-    "Manage configured RPC services. Changes require restart."
+    "Manage configured RPC services. Driver changes apply within seconds; \
+     auth providers require restart."
+}
+
+fn service_change_toast(action: &str, kind: RpcServiceKind) -> String {
+    match kind {
+        RpcServiceKind::Driver => format!("RPC service {action}. Drivers update shortly."),
+        RpcServiceKind::AuthProvider => {
+            format!("RPC service {action}. Restart required to apply changes.")
+        }
+    }
 }
 
 fn empty_services_message() -> &'static str {
@@ -65,6 +75,7 @@ fn build_service_config(
         startup_timeout_ms,
         kind,
         api_contract: None,
+        remote: None,
     }
 }
 
@@ -77,6 +88,43 @@ fn preserved_api_contract_for_edit(
         .and_then(|service| service.api_contract.clone())
 }
 
+/// The remote endpoint entered in the form; an empty address means the
+/// service runs locally.
+fn remote_from_form(
+    address: &str,
+    tls: bool,
+    token_env: &str,
+) -> Result<Option<RemoteServiceConfig>, &'static str> {
+    if address.is_empty() {
+        return Ok(None);
+    }
+
+    if token_env.is_empty() {
+        return Err("A remote host needs the environment variable holding its token");
+    }
+
+    Ok(Some(RemoteServiceConfig {
+        address: address.to_string(),
+        tls,
+        token_env: token_env.to_string(),
+    }))
+}
+
+/// What the service list shows under a service's socket id.
+fn service_subtitle(service: &ServiceConfig) -> String {
+    if let Some(remote) = &service.remote {
+        let scheme = if remote.tls { "tls" } else { "tcp" };
+        return format!("{scheme}://{}", remote.address);
+    }
+
+    service
+        .command
+        .as_deref()
+        .filter(|value| !value.is_empty())
+        .unwrap_or("(default)")
+        .to_string()
+}
+
 fn service_form_rows(
     arg_count: usize,
     env_count: usize,
@@ -88,6 +136,9 @@ fn service_form_rows(
         ServiceFormRow::Timeout,
         ServiceFormRow::Kind,
         ServiceFormRow::Enabled,
+        ServiceFormRow::RemoteAddress,
+        ServiceFormRow::RemoteTls,
+        ServiceFormRow::RemoteTokenEnv,
     ];
 
     for index in 0..arg_count {
@@ -133,11 +184,28 @@ impl ServicesSection {
                 .map(|value| value.to_string())
                 .unwrap_or_default();
 
+            let remote_address = self
+                .input_svc_remote_address
+                .read(cx)
+                .value()
+                .trim()
+                .to_string();
+            let remote_token_env = self
+                .input_svc_remote_token_env
+                .read(cx)
+                .value()
+                .trim()
+                .to_string();
+            let saved_remote = saved.remote.as_ref();
+
             if socket_id != saved.socket_id
                 || command != saved_command
                 || timeout != saved_timeout
                 || self.svc_kind != saved.kind
                 || self.svc_enabled != saved.enabled
+                || remote_address != saved_remote.map_or("", |r| r.address.as_str())
+                || remote_token_env != saved_remote.map_or("", |r| r.token_env.as_str())
+                || self.svc_remote_tls != saved_remote.is_some_and(|r| r.tls)
             {
                 return true;
             }
@@ -180,6 +248,19 @@ impl ServicesSection {
         !self.input_socket_id.read(cx).value().trim().is_empty()
             || !self.input_svc_command.read(cx).value().trim().is_empty()
             || !self.input_svc_timeout.read(cx).value().trim().is_empty()
+            || !self
+                .input_svc_remote_address
+                .read(cx)
+                .value()
+                .trim()
+                .is_empty()
+            || !self
+                .input_svc_remote_token_env
+                .read(cx)
+                .value()
+                .trim()
+                .is_empty()
+            || self.svc_remote_tls
             || !self.svc_arg_inputs.is_empty()
             || !self.svc_env_key_inputs.is_empty()
             || !self.svc_env_value_inputs.is_empty()
@@ -198,6 +279,7 @@ impl ServicesSection {
         self.editing_svc_idx = None;
         self.svc_kind = RpcServiceKind::Driver;
         self.svc_enabled = true;
+        self.svc_remote_tls = false;
         self.svc_form_cursor = 0;
         self.svc_env_col = 0;
         self.svc_editing_field = false;
@@ -211,6 +293,10 @@ impl ServicesSection {
             .update(_cx, |s, cx| s.set_value("", _window, cx));
         self.input_svc_timeout
             .update(_cx, |s, cx| s.set_value("", _window, cx));
+        self.input_svc_remote_address
+            .update(_cx, |s, cx| s.set_value("", _window, cx));
+        self.input_svc_remote_token_env
+            .update(_cx, |s, cx| s.set_value("", _window, cx));
 
         _cx.notify();
     }
@@ -240,6 +326,22 @@ impl ServicesSection {
         self.input_svc_timeout
             .update(cx, |s, cx| s.set_value(&timeout_str, window, cx));
 
+        let remote_address = service
+            .remote
+            .as_ref()
+            .map(|remote| remote.address.clone())
+            .unwrap_or_default();
+        let remote_token_env = service
+            .remote
+            .as_ref()
+            .map(|remote| remote.token_env.clone())
+            .unwrap_or_default();
+        self.svc_remote_tls = service.remote.as_ref().is_some_and(|remote| remote.tls);
+        self.input_svc_remote_address
+            .update(cx, |s, cx| s.set_value(&remote_address, window, cx));
+        self.input_svc_remote_token_env
+            .update(cx, |s, cx| s.set_value(&remote_token_env, window, cx));
+
         self.svc_arg_inputs = service
             .args
             .iter()
@@ -317,6 +419,30 @@ impl ServicesSection {
             }
         };
 
+        let remote_address = self
+            .input_svc_remote_address
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        let remote_token_env = self
+            .input_svc_remote_token_env
+            .read(cx)
+            .value()
+            .trim()
+            .to_string();
+        let remote = match remote_from_form(&remote_address, self.svc_remote_tls, &remote_token_env)
+        {
+            Ok(remote) => remote,
+            Err(msg) => {
+                Toast::error(msg)
+                    .meta_right(now_hms())
+                    .action(copy_action(msg))
+                    .push(cx);
+                return;
+            }
+        };
+
         let command_str = self.input_svc_command.read(cx).value().trim().to_string();
         let command = if command_str.is_empty() {
             None
@@ -357,7 +483,9 @@ impl ServicesSection {
 
         service.api_contract =
             preserved_api_contract_for_edit(&self.svc_services, self.editing_svc_idx);
+        service.remote = remote;
 
+        let saved_kind = service.kind;
         let saved_idx = if let Some(idx) = self.editing_svc_idx {
             if idx < self.svc_services.len() {
                 self.svc_services[idx] = service;
@@ -379,7 +507,7 @@ impl ServicesSection {
             );
             return;
         }
-        Toast::info("RPC service saved. Restart required to apply changes.")
+        Toast::info(service_change_toast("saved", saved_kind))
             .meta_right(now_hms())
             .push(cx);
 
@@ -403,7 +531,7 @@ impl ServicesSection {
             return;
         }
 
-        self.svc_services.remove(idx);
+        let deleted_kind = self.svc_services.remove(idx).kind;
 
         if self.editing_svc_idx == Some(idx) {
             self.clear_svc_form(window, cx);
@@ -435,7 +563,7 @@ impl ServicesSection {
             );
             return;
         }
-        Toast::info("RPC service deleted. Restart required to apply changes.")
+        Toast::info(service_change_toast("deleted", deleted_kind))
             .meta_right(now_hms())
             .push(cx);
         cx.notify();
@@ -683,6 +811,14 @@ impl ServicesSection {
                 self.input_svc_timeout
                     .update(cx, |s, cx| s.focus(window, cx));
             }
+            Some(ServiceFormRow::RemoteAddress) => {
+                self.input_svc_remote_address
+                    .update(cx, |s, cx| s.focus(window, cx));
+            }
+            Some(ServiceFormRow::RemoteTokenEnv) => {
+                self.input_svc_remote_token_env
+                    .update(cx, |s, cx| s.focus(window, cx));
+            }
             Some(ServiceFormRow::Kind) => {
                 self.svc_editing_field = false;
             }
@@ -722,6 +858,8 @@ impl ServicesSection {
             Some(ServiceFormRow::SocketId)
             | Some(ServiceFormRow::Command)
             | Some(ServiceFormRow::Timeout)
+            | Some(ServiceFormRow::RemoteAddress)
+            | Some(ServiceFormRow::RemoteTokenEnv)
             | Some(ServiceFormRow::EnvValue(_)) => {
                 self.svc_focus_current_field(window, cx);
             }
@@ -764,6 +902,11 @@ impl ServicesSection {
                 cx.notify();
             }
 
+            Some(ServiceFormRow::RemoteTls) => {
+                self.svc_remote_tls = !self.svc_remote_tls;
+                cx.notify();
+            }
+
             Some(ServiceFormRow::AddArg) => {
                 self.add_arg_row(window, cx);
             }
@@ -877,11 +1020,7 @@ impl ServicesSection {
                         let is_focused = is_list_focused && self.svc_selected_idx == Some(idx);
                         let is_disabled = !service.enabled;
 
-                        let subtitle = service
-                            .command
-                            .as_deref()
-                            .filter(|value| !value.is_empty())
-                            .unwrap_or("(default)");
+                        let subtitle = service_subtitle(service);
 
                         div()
                             .id(SharedString::from(format!("svc-item-{}", idx)))
@@ -944,7 +1083,7 @@ impl ServicesSection {
                                                         )
                                                     }),
                                             )
-                                            .child(MonoMeta::new(subtitle.to_string())),
+                                            .child(MonoMeta::new(subtitle)),
                                     ),
                             )
                     })),
@@ -1004,6 +1143,27 @@ impl ServicesSection {
                     primary,
                     cx,
                 ))
+                .child(self.render_svc_input_field(
+                    "Remote Address (host:port)",
+                    &self.input_svc_remote_address,
+                    is_row_focused(ServiceFormRow::RemoteAddress),
+                    primary,
+                    ServiceFormRow::RemoteAddress,
+                    cx,
+                ))
+                .child(self.render_svc_remote_tls_checkbox(
+                    is_row_focused(ServiceFormRow::RemoteTls),
+                    primary,
+                    cx,
+                ))
+                .child(self.render_svc_input_field(
+                    "Remote Token Variable",
+                    &self.input_svc_remote_token_env,
+                    is_row_focused(ServiceFormRow::RemoteTokenEnv),
+                    primary,
+                    ServiceFormRow::RemoteTokenEnv,
+                    cx,
+                ))
                 .child(self.render_svc_args_section(is_form_focused, cursor, &rows, primary, cx))
                 .child(self.render_svc_env_section(is_form_focused, cursor, &rows, primary, cx)),
             None,
@@ -1129,6 +1289,36 @@ impl ServicesSection {
             .child(Body::new("Enable this service"))
     }
 
+    fn render_svc_remote_tls_checkbox(
+        &self,
+        is_focused: bool,
+        primary: Hsla,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_2()
+            .py_1()
+            .rounded(Radii::SM)
+            .border_1()
+            .border_color(if is_focused {
+                primary
+            } else {
+                transparent_black()
+            })
+            .child(
+                Checkbox::new("svc-remote-tls")
+                    .checked(self.svc_remote_tls)
+                    .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                        this.svc_remote_tls = *checked;
+                        cx.notify();
+                    })),
+            )
+            .child(Body::new("Connect to the remote host over TLS"))
+    }
+
     fn render_radio_button(selected: bool, primary: Hsla, border: Hsla) -> Div {
         div()
             .size_4()
@@ -1480,9 +1670,10 @@ impl ServicesSection {
 mod tests {
     use super::{
         ServiceFormRow, build_service_config, editable_service_kind,
-        preserved_api_contract_for_edit, service_form_rows, service_row_max_col,
+        preserved_api_contract_for_edit, remote_from_form, service_change_toast, service_form_rows,
+        service_row_max_col, service_subtitle,
     };
-    use dbflux_core::{RpcServiceKind, ServiceConfig, ServiceRpcApiContract};
+    use dbflux_core::{RemoteServiceConfig, RpcServiceKind, ServiceConfig, ServiceRpcApiContract};
     use std::collections::HashMap;
 
     #[test]
@@ -1497,6 +1688,9 @@ mod tests {
                 ServiceFormRow::Timeout,
                 ServiceFormRow::Kind,
                 ServiceFormRow::Enabled,
+                ServiceFormRow::RemoteAddress,
+                ServiceFormRow::RemoteTls,
+                ServiceFormRow::RemoteTokenEnv,
                 ServiceFormRow::AddArg,
                 ServiceFormRow::AddEnv,
                 ServiceFormRow::SaveButton,
@@ -1521,6 +1715,7 @@ mod tests {
             startup_timeout_ms: Some(5000),
             kind: RpcServiceKind::AuthProvider,
             api_contract: None,
+            remote: None,
         };
 
         assert_eq!(
@@ -1557,6 +1752,7 @@ mod tests {
             startup_timeout_ms: Some(5000),
             kind: RpcServiceKind::AuthProvider,
             api_contract: Some(api_contract.clone()),
+            remote: None,
         }];
 
         assert_eq!(
@@ -1564,4 +1760,44 @@ mod tests {
             Some(api_contract)
         );
     }
+
+    #[test]
+    fn remote_from_form_requires_a_token_variable_for_remote_hosts() {
+        assert_eq!(remote_from_form("", true, ""), Ok(None));
+        assert!(remote_from_form("db-box.internal:7420", false, "").is_err());
+
+        let remote = remote_from_form("db-box.internal:7420", true, "DBFLUX_REMOTE_TOKEN")
+            .expect("complete endpoint")
+            .expect("remote service");
+        assert_eq!(remote.address, "db-box.internal:7420");
+        assert!(remote.tls);
+        assert_eq!(remote.token_env, "DBFLUX_REMOTE_TOKEN");
+    }
+
+    #[test]
+    fn service_subtitle_shows_the_remote_address() {
+        let mut service = build_service_config(
+            "remote-pg".into(),
+            true,
+            None,
+            Vec::new(),
+            HashMap::new(),
+            None,
+            RpcServiceKind::Driver,
+        );
+        assert_eq!(service_subtitle(&service), "(default)");
+
+        service.remote = Some(RemoteServiceConfig {
+            address: "db-box.internal:7420".into(),
+            tls: true,
+            token_env: "DBFLUX_REMOTE_TOKEN".into(),
+        });
+        assert_eq!(service_subtitle(&service), "tls://db-box.internal:7420");
+    }
+
+    #[test]
+    fn service_change_toast_mentions_restart_only_for_auth_providers() {
+        assert!(!service_change_toast("saved", RpcServiceKind::Driver).contains("Restart"));
+        assert!(service_change_toast("deleted", RpcServiceKind::AuthProvider).contains("Restart"));
+    }
 }
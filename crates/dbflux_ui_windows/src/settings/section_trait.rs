@@ -16,6 +16,7 @@ pub enum SettingsSectionId {
     SshTunnels,
     AuthProfiles,
     Services,
+    ExternalDrivers,
     Hooks,
     Drivers,
     About,
@@ -24,6 +24,9 @@ pub(super) enum ServiceFormRow {
     Timeout,
     Kind,
     Enabled,
+    RemoteAddress,
+    RemoteTls,
+    RemoteTokenEnv,
     Arg(usize),
     #[allow(dead_code)]
     ArgDelete(usize),
@@ -54,8 +57,11 @@ pub(super) struct ServicesSection {
     pub(super) input_socket_id: Entity<InputState>,
     pub(super) input_svc_command: Entity<InputState>,
     pub(super) input_svc_timeout: Entity<InputState>,
+    pub(super) input_svc_remote_address: Entity<InputState>,
+    pub(super) input_svc_remote_token_env: Entity<InputState>,
     pub(super) svc_kind: RpcServiceKind,
     pub(super) svc_enabled: bool,
+    pub(super) svc_remote_tls: bool,
 
     pub(super) svc_arg_inputs: Vec<Entity<InputState>>,
     pub(super) svc_env_key_inputs: Vec<Entity<InputState>>,
@@ -79,6 +85,10 @@ impl ServicesSection {
         let input_svc_command =
             cx.new(|cx| InputState::new(window, cx).placeholder("dbflux-driver-host"));
         let input_svc_timeout = cx.new(|cx| InputState::new(window, cx).placeholder("5000"));
+        let input_svc_remote_address =
+            cx.new(|cx| InputState::new(window, cx).placeholder("db-box.internal:7420"));
+        let input_svc_remote_token_env =
+            cx.new(|cx| InputState::new(window, cx).placeholder("DBFLUX_REMOTE_TOKEN"));
 
         let mut section = Self {
             app_state,
@@ -94,8 +104,11 @@ impl ServicesSection {
             input_socket_id,
             input_svc_command,
             input_svc_timeout,
+            input_svc_remote_address,
+            input_svc_remote_token_env,
             svc_kind: RpcServiceKind::Driver,
             svc_enabled: true,
+            svc_remote_tls: false,
             svc_arg_inputs: Vec::new(),
             svc_env_key_inputs: Vec::new(),
             svc_env_value_inputs: Vec::new(),
@@ -311,6 +324,8 @@ impl FormSection for ServicesSection {
             ServiceFormRow::SocketId
                 | ServiceFormRow::Command
                 | ServiceFormRow::Timeout
+                | ServiceFormRow::RemoteAddress
+                | ServiceFormRow::RemoteTokenEnv
                 | ServiceFormRow::Arg(_)
                 | ServiceFormRow::EnvKey(_)
                 | ServiceFormRow::EnvValue(_)
@@ -1,6 +1,7 @@
 use super::{SettingsCoordinator, SettingsFocus, SettingsSectionId};
 use dbflux_components::components::tree_nav::{TreeNav, TreeNavNode};
 use dbflux_components::icons::AppIcon;
+use dbflux_core::i18n::tr;
 use gpui::SharedString;
 use std::collections::HashSet;
 
@@ -11,48 +12,85 @@ impl SettingsCoordinator {
         let nodes = vec![
             TreeNavNode::group(
                 "general-group",
-                "General",
+                tr("settings-nav-general-group"),
                 Some(AppIcon::Settings),
                 vec![
-                    TreeNavNode::leaf("general", "General", Some(AppIcon::Settings)),
-                    TreeNavNode::leaf("keybindings", "Keybindings", Some(AppIcon::Keyboard)),
-                    TreeNavNode::leaf("audit", "Audit", Some(AppIcon::History)),
-                    TreeNavNode::leaf("about", "About", Some(AppIcon::Info)),
+                    TreeNavNode::leaf(
+                        "general",
+                        tr("settings-nav-general"),
+                        Some(AppIcon::Settings),
+                    ),
+                    TreeNavNode::leaf(
+                        "keybindings",
+                        tr("settings-nav-keybindings"),
+                        Some(AppIcon::Keyboard),
+                    ),
+                    TreeNavNode::leaf("audit", tr("settings-nav-audit"), Some(AppIcon::History)),
+                    TreeNavNode::leaf("about", tr("settings-nav-about"), Some(AppIcon::Info)),
                 ],
             ),
             TreeNavNode::group(
                 "network",
-                "Network",
+                tr("settings-nav-network"),
                 Some(AppIcon::Server),
                 vec![
                     TreeNavNode::leaf(
                         "ssh-tunnels",
-                        "SSH Tunnels",
+                        tr("settings-nav-ssh-tunnels"),
                         Some(AppIcon::FingerprintPattern),
                     ),
-                    TreeNavNode::leaf("proxies", "Proxy", Some(AppIcon::Server)),
-                    TreeNavNode::leaf("auth-profiles", "Access Providers", Some(AppIcon::KeyRound)),
+                    TreeNavNode::leaf("proxies", tr("settings-nav-proxies"), Some(AppIcon::Server)),
+                    TreeNavNode::leaf(
+                        "auth-profiles",
+                        tr("settings-nav-auth-profiles"),
+                        Some(AppIcon::KeyRound),
+                    ),
                 ],
             ),
             TreeNavNode::group(
                 "connection",
-                "Connection",
+                tr("settings-nav-connection"),
                 Some(AppIcon::Link2),
                 vec![
-                    TreeNavNode::leaf("hooks", "Hooks", Some(AppIcon::SquareTerminal)),
-                    TreeNavNode::leaf("drivers", "Drivers", Some(AppIcon::Database)),
-                    TreeNavNode::leaf("services", "RPC Services", Some(AppIcon::Plug)),
+                    TreeNavNode::leaf(
+                        "hooks",
+                        tr("settings-nav-hooks"),
+                        Some(AppIcon::SquareTerminal),
+                    ),
+                    TreeNavNode::leaf(
+                        "drivers",
+                        tr("settings-nav-drivers"),
+                        Some(AppIcon::Database),
+                    ),
+                    TreeNavNode::leaf("services", tr("settings-nav-services"), Some(AppIcon::Plug)),
+                    TreeNavNode::leaf(
+                        "external-drivers",
+                        tr("settings-nav-external-drivers"),
+                        Some(AppIcon::Plug),
+                    ),
                 ],
             ),
             #[cfg(feature = "mcp")]
             TreeNavNode::group(
                 "mcp-governance",
-                "MCP Governance",
+                tr("settings-nav-mcp-governance"),
                 Some(AppIcon::Bot),
                 vec![
-                    TreeNavNode::leaf("mcp-clients", "Clients", Some(AppIcon::Plug)),
-                    TreeNavNode::leaf("mcp-roles", "Roles", Some(AppIcon::KeyRound)),
-                    TreeNavNode::leaf("mcp-policies", "Policies", Some(AppIcon::ScrollText)),
+                    TreeNavNode::leaf(
+                        "mcp-clients",
+                        tr("settings-nav-mcp-clients"),
+                        Some(AppIcon::Plug),
+                    ),
+                    TreeNavNode::leaf(
+                        "mcp-roles",
+                        tr("settings-nav-mcp-roles"),
+                        Some(AppIcon::KeyRound),
+                    ),
+                    TreeNavNode::leaf(
+                        "mcp-policies",
+                        tr("settings-nav-mcp-policies"),
+                        Some(AppIcon::ScrollText),
+                    ),
                 ],
             ),
         ];
@@ -82,6 +120,7 @@ impl SettingsCoordinator {
             "ssh-tunnels" => Some(SettingsSectionId::SshTunnels),
             "auth-profiles" => Some(SettingsSectionId::AuthProfiles),
             "services" => Some(SettingsSectionId::Services),
+            "external-drivers" => Some(SettingsSectionId::ExternalDrivers),
             "hooks" => Some(SettingsSectionId::Hooks),
             "drivers" => Some(SettingsSectionId::Drivers),
             "about" => Some(SettingsSectionId::About),
@@ -104,6 +143,7 @@ impl SettingsCoordinator {
             SettingsSectionId::SshTunnels => "ssh-tunnels",
             SettingsSectionId::AuthProfiles => "auth-profiles",
             SettingsSectionId::Services => "services",
+            SettingsSectionId::ExternalDrivers => "external-drivers",
             SettingsSectionId::Hooks => "hooks",
             SettingsSectionId::Drivers => "drivers",
             SettingsSectionId::About => "about",
@@ -168,6 +208,7 @@ mod tests {
             SettingsSectionId::SshTunnels,
             SettingsSectionId::AuthProfiles,
             SettingsSectionId::Services,
+            SettingsSectionId::ExternalDrivers,
             SettingsSectionId::Hooks,
             SettingsSectionId::Drivers,
             SettingsSectionId::About,
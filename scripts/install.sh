@@ -470,6 +470,11 @@ install_files() {
                 -e "s|@APP_ID@|dbflux|g" \
                 "$PREFIX/share/applications/dbflux.desktop"
             chmod 644 "$PREFIX/share/applications/dbflux.desktop"
+
+            # Registers the desktop entry as a handler for its SQL MIME types.
+            if command -v update-desktop-database &>/dev/null; then
+                update-desktop-database "$PREFIX/share/applications" 2>/dev/null || true
+            fi
         fi
     fi
 